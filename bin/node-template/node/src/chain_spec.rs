@@ -7,6 +7,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::traits::{Verify, IdentifyAccount};
 use sc_service::ChainType;
+use std::path::Path;
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -125,6 +126,25 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
+/// A development chain of the runtime in the given Wasm file, with its default genesis config.
+pub fn runtime_dev_config(runtime: &Path) -> Result<ChainSpec, String> {
+	let code = std::fs::read(runtime)
+		.map_err(|e| format!("Error reading runtime file {}: {}", runtime.display(), e))?;
+
+	ChainSpec::from_runtime_code(
+		// Name
+		"Development",
+		// ID
+		"dev",
+		ChainType::Development,
+		&code,
+		// Properties
+		None,
+		// Extensions
+		None,
+	)
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
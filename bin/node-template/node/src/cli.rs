@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use structopt::StructOpt;
 use sc_cli::RunCmd;
 
@@ -8,6 +9,15 @@ pub struct Cli {
 
 	#[structopt(flatten)]
 	pub run: RunCmd,
+
+	/// Start a development chain of the runtime in the given Wasm file, instead of the runtime of
+	/// the node.
+	///
+	/// The genesis state is built from the default genesis config of the runtime, which must
+	/// implement the `GenesisBuilder` runtime api, and a block is sealed as soon as a transaction
+	/// is received.
+	#[structopt(long, value_name = "PATH", parse(from_os_str))]
+	pub runtime: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt)]
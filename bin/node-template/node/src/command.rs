@@ -47,6 +47,10 @@ impl SubstrateCli for Cli {
 	}
 
 	fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+		if let Some(runtime) = &self.runtime {
+			return Ok(Box::new(chain_spec::runtime_dev_config(runtime)?))
+		}
+
 		Ok(match id {
 			"dev" => Box::new(chain_spec::development_config()?),
 			"" | "local" => Box::new(chain_spec::local_testnet_config()?),
@@ -127,9 +131,14 @@ pub fn run() -> sc_cli::Result<()> {
 		},
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
+			let dev_runtime = cli.runtime.is_some();
 			runner.run_node_until_exit(|config| async move {
 				match config.role {
+					Role::Light if dev_runtime => {
+						Err("A light client cannot run a development runtime".into())
+					},
 					Role::Light => service::new_light(config),
+					_ if dev_runtime => service::new_dev(config),
 					_ => service::new_full(config),
 				}.map_err(sc_cli::Error::Service)
 			})
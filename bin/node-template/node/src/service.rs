@@ -64,13 +64,8 @@ pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponen
 
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
-	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
-		config.transaction_pool.clone(),
-		config.role.is_authority().into(),
-		config.prometheus_registry(),
-		task_manager.spawn_handle(),
-		client.clone(),
-	);
+	let transaction_pool =
+		sc_service::new_full_transaction_pool(&config, client.clone(), &task_manager);
 
 	let (grandpa_block_import, grandpa_link) = sc_finality_grandpa::block_import(
 		client.clone(),
@@ -160,6 +155,8 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			block_announce_validator_builder: None,
 		})?;
 
+	transaction_pool.set_sync_oracle(network.clone());
+
 	if config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
 			&config, task_manager.spawn_handle(), client.clone(), network.clone(),
@@ -302,6 +299,99 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 	Ok(task_manager)
 }
 
+/// Builds a new service for a full client of a development chain of an arbitrary runtime, which
+/// seals a block as soon as a transaction is received.
+pub fn new_dev(config: Configuration) -> Result<TaskManager, ServiceError> {
+	let (client, backend, keystore_container, mut task_manager) =
+		sc_service::new_full_parts::<Block, RuntimeApi, Executor>(&config, None)?;
+	let client = Arc::new(client);
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+	let transaction_pool =
+		sc_service::new_full_transaction_pool(&config, client.clone(), &task_manager);
+
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(client.clone()),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	);
+
+	let (network, system_rpc_tx, network_starter) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			on_demand: None,
+			block_announce_validator_builder: None,
+		})?;
+
+	let prometheus_registry = config.prometheus_registry().cloned();
+
+	let rpc_extensions_builder = {
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+
+		Box::new(move |deny_unsafe, _| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+			};
+
+			crate::rpc::create_full(deps)
+		})
+	};
+
+	let _rpc_handlers = sc_service::spawn_tasks(
+		sc_service::SpawnTasksParams {
+			network,
+			client: client.clone(),
+			keystore: keystore_container.sync_keystore(),
+			task_manager: &mut task_manager,
+			transaction_pool: transaction_pool.clone(),
+			rpc_extensions_builder,
+			on_demand: None,
+			remote_blockchain: None,
+			backend,
+			system_rpc_tx,
+			config,
+			telemetry: None,
+		},
+	)?;
+
+	let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+		None,
+	);
+
+	let authorship_future = sc_consensus_manual_seal::run_instant_seal(
+		sc_consensus_manual_seal::InstantSealParams {
+			block_import: client.clone(),
+			env: proposer_factory,
+			client,
+			pool: transaction_pool.pool().clone(),
+			select_chain,
+			consensus_data_provider: None,
+			create_inherent_data_providers: move |_, ()| async move {
+				Ok(sp_timestamp::InherentDataProvider::from_system_time())
+			},
+		},
+	);
+
+	// the authorship task is considered essential, i.e. if it fails we take down the service
+	// with it.
+	task_manager.spawn_essential_handle().spawn_blocking("instant-seal", authorship_future);
+
+	network_starter.start_network();
+	Ok(task_manager)
+}
+
 /// Builds a new service for a light client.
 pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError> {
 	let telemetry = config.telemetry_endpoints.clone()
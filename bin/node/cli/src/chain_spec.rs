@@ -74,7 +74,10 @@ fn session_keys(
 	im_online: ImOnlineId,
 	authority_discovery: AuthorityDiscoveryId,
 ) -> SessionKeys {
-	SessionKeys { grandpa, babe, im_online, authority_discovery }
+	// The mixnet key of the predefined authorities is their authority discovery key, which
+	// `--alice` and co. also insert under the mixnet key type.
+	let mixnet = sr25519::Public::from(authority_discovery.clone()).into();
+	SessionKeys { grandpa, babe, im_online, authority_discovery, mixnet }
 }
 
 fn staging_testnet_config_genesis() -> GenesisConfig {
@@ -447,7 +450,7 @@ pub(crate) mod tests {
 			integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, transaction_pool, .. }
-					= new_full_base(config,|_, _| ())?;
+					= new_full_base(config, None, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(task_manager, client, network, transaction_pool))
 			},
 			|config| {
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use sc_cli::{RunCmd, KeySubcommand, SignCmd, VanityCmd, VerifyCmd};
+use sc_cli::{RunCmd, KeySubcommand, MixnetParams, SignCmd, VanityCmd, VerifyCmd};
 use structopt::StructOpt;
 
 /// An overarching CLI command definition.
@@ -28,6 +28,9 @@ pub struct Cli {
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub run: RunCmd,
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub mixnet_params: MixnetParams,
 }
 
 /// Possible subcommands of the main binary.
@@ -81,4 +84,10 @@ pub enum Subcommand {
 
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
+
+	/// Write a consistent copy of the database to a directory.
+	BackupDb(sc_cli::BackupDbCmd),
+
+	/// Report the storage usage of each pallet at a given block.
+	StorageInfo(substrate_frame_cli::StorageInfoCmd),
 }
@@ -44,6 +44,7 @@ type LightClient = sc_service::TLightClient<Block, RuntimeApi, Executor>;
 
 pub fn new_partial(
 	config: &Configuration,
+	mixnet_config: Option<&sc_mixnet::Config>,
 ) -> Result<sc_service::PartialComponents<
 	FullClient, FullBackend, FullSelectChain,
 	sp_consensus::DefaultImportQueue<Block, FullClient>,
@@ -60,6 +61,8 @@ pub fn new_partial(
 		),
 		grandpa::SharedVoterState,
 		Option<Telemetry>,
+		Arc<sc_statement_store::Store>,
+		Option<sc_mixnet::ApiBackend>,
 	)
 >, ServiceError> {
 	let telemetry = config.telemetry_endpoints.clone()
@@ -86,13 +89,8 @@ pub fn new_partial(
 
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
-	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
-		config.transaction_pool.clone(),
-		config.role.is_authority().into(),
-		config.prometheus_registry(),
-		task_manager.spawn_handle(),
-		client.clone(),
-	);
+	let transaction_pool =
+		sc_service::new_full_transaction_pool(&config, client.clone(), &task_manager);
 
 	let (grandpa_block_import, grandpa_link) = grandpa::block_import(
 		client.clone(),
@@ -139,6 +137,23 @@ pub fn new_partial(
 
 	let import_setup = (block_import, grandpa_link, babe_link);
 
+	let statement_store_path = config.database.path()
+		.ok_or_else(|| ServiceError::Other("The statement store requires a database path".into()))?
+		.with_file_name("statements");
+	let statement_store = sc_statement_store::Store::open(
+		&statement_store_path,
+		client.clone(),
+		Default::default(),
+	).map_err(|e| ServiceError::Other(format!("Failed to open the statement store: {}", e)))?;
+
+	let (mixnet_api, mixnet_api_backend) = match mixnet_config {
+		Some(_) => {
+			let (api, api_backend) = sc_mixnet::api();
+			(Some(api), Some(api_backend))
+		},
+		None => (None, None),
+	};
+
 	let (rpc_extensions_builder, rpc_setup) = {
 		let (_, grandpa_link, babe_link) = &import_setup;
 
@@ -160,6 +175,7 @@ pub fn new_partial(
 		let select_chain = select_chain.clone();
 		let keystore = keystore_container.sync_keystore();
 		let chain_spec = config.chain_spec.cloned_box();
+		let statement_store = statement_store.clone();
 
 		let rpc_extensions_builder = move |deny_unsafe, subscription_executor| {
 			let deps = node_rpc::FullDeps {
@@ -180,6 +196,8 @@ pub fn new_partial(
 					subscription_executor,
 					finality_provider: finality_proof_provider.clone(),
 				},
+				statement_store: statement_store.clone(),
+				mixnet_api: mixnet_api.clone(),
 			};
 
 			node_rpc::create_full(deps)
@@ -196,7 +214,14 @@ pub fn new_partial(
 		select_chain,
 		import_queue,
 		transaction_pool,
-		other: (rpc_extensions_builder, import_setup, rpc_setup, telemetry),
+		other: (
+			rpc_extensions_builder,
+			import_setup,
+			rpc_setup,
+			telemetry,
+			statement_store,
+			mixnet_api_backend,
+		),
 	})
 }
 
@@ -207,9 +232,11 @@ pub struct NewFullBase {
 	pub transaction_pool: Arc<sc_transaction_pool::FullPool<Block, FullClient>>,
 }
 
-/// Creates a full service from the configuration.
+/// Creates a full service from the configuration, running the mixnet service if
+/// `mixnet_config` is given.
 pub fn new_full_base(
 	mut config: Configuration,
+	mixnet_config: Option<sc_mixnet::Config>,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -223,13 +250,28 @@ pub fn new_full_base(
 		keystore_container,
 		select_chain,
 		transaction_pool,
-		other: (rpc_extensions_builder, import_setup, rpc_setup, mut telemetry),
-	} = new_partial(&config)?;
+		other: (
+			rpc_extensions_builder,
+			import_setup,
+			rpc_setup,
+			mut telemetry,
+			statement_store,
+			mixnet_api_backend,
+		),
+	} = new_partial(&config, mixnet_config.as_ref())?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 
 	config.network.extra_sets.push(grandpa::grandpa_peers_set_config());
+	config.network.extra_sets.push(sc_statement_store::gossip::statement_peers_set_config());
+
+	let mixnet_protocol = mixnet_config.as_ref().map(|_| {
+		let protocol_id = config.protocol_id();
+		let (protocol_config, packets) = sc_mixnet::request_response_config(&protocol_id);
+		config.network.request_response_protocols.push(protocol_config);
+		(protocol_id, packets)
+	});
 
 	#[cfg(feature = "cli")]
 	config.network.request_response_protocols.push(
@@ -252,12 +294,42 @@ pub fn new_full_base(
 			block_announce_validator_builder: None,
 		})?;
 
+	transaction_pool.set_sync_oracle(network.clone());
+
 	if config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
 			&config, task_manager.spawn_handle(), client.clone(), network.clone(),
 		);
 	}
 
+	task_manager.spawn_handle().spawn(
+		"statement-gossip",
+		sc_statement_store::gossip::run(
+			network.clone(),
+			statement_store,
+			task_manager.spawn_handle(),
+			config.prometheus_registry(),
+		),
+	);
+
+	if let (Some(mixnet_config), Some(api_backend), Some((protocol_id, packets))) =
+		(mixnet_config, mixnet_api_backend, mixnet_protocol)
+	{
+		let mixnet_network = sc_mixnet::MixnetNetwork::new(network.clone(), &protocol_id)
+			.map_err(ServiceError::Other)?;
+		task_manager.spawn_handle().spawn(
+			"mixnet",
+			sc_mixnet::run(
+				mixnet_config,
+				api_backend,
+				client.clone(),
+				transaction_pool.clone(),
+				mixnet_network,
+				packets,
+			),
+		);
+	}
+
 	let role = config.role.clone();
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks =
@@ -421,8 +493,9 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(
 	config: Configuration,
+	mixnet_config: Option<sc_mixnet::Config>,
 ) -> Result<TaskManager, ServiceError> {
-	new_full_base(config, |_, _| ()).map(|NewFullBase { task_manager, .. }| {
+	new_full_base(config, mixnet_config, |_, _| ()).map(|NewFullBase { task_manager, .. }| {
 		task_manager
 	})
 }
@@ -661,7 +734,7 @@ mod tests {
 				let mut setup_handles = None;
 				let NewFullBase {
 					task_manager, client, network, transaction_pool, ..
-				} = new_full_base(config,
+				} = new_full_base(config, None,
 					|
 						block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						babe_link: &sc_consensus_babe::BabeLink<Block>,
@@ -834,7 +907,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, transaction_pool, .. }
-					= new_full_base(config,|_, _| ())?;
+					= new_full_base(config, None, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(task_manager, client, network, transaction_pool))
 			},
 			|config| {
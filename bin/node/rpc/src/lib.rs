@@ -49,6 +49,7 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sc_rpc::SubscriptionTaskExecutor;
 use sp_transaction_pool::TransactionPool;
+use sp_statement_store::StatementStore;
 use sc_client_api::AuxStore;
 
 /// Light client extra dependencies.
@@ -103,6 +104,10 @@ pub struct FullDeps<C, P, SC, B> {
 	pub babe: BabeDeps,
 	/// GRANDPA specific dependencies.
 	pub grandpa: GrandpaDeps<B>,
+	/// The statement store of the node.
+	pub statement_store: Arc<dyn StatementStore>,
+	/// The handle to the mixnet service, if the mixnet is enabled.
+	pub mixnet_api: Option<sc_mixnet::Api>,
 }
 
 /// A IO handler that uses all Full RPC extensions.
@@ -129,6 +134,8 @@ pub fn create_full<C, P, SC, B>(
 	use pallet_contracts_rpc::{Contracts, ContractsApi};
 	use pallet_mmr_rpc::{MmrApi, Mmr};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+	use sc_rpc::statement::{StatementApi, StatementStoreApi};
+	use sc_rpc::mixnet::{Mixnet, MixnetApi};
 
 	let mut io = jsonrpc_core::IoHandler::default();
 	let FullDeps {
@@ -139,6 +146,8 @@ pub fn create_full<C, P, SC, B>(
 		deny_unsafe,
 		babe,
 		grandpa,
+		statement_store,
+		mixnet_api,
 	} = deps;
 
 	let BabeDeps {
@@ -169,6 +178,12 @@ pub fn create_full<C, P, SC, B>(
 	io.extend_with(
 		TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
 	);
+	io.extend_with(
+		StatementApi::to_delegate(StatementStoreApi::new(statement_store, deny_unsafe))
+	);
+	if let Some(mixnet_api) = mixnet_api {
+		io.extend_with(MixnetApi::to_delegate(Mixnet::new(mixnet_api)));
+	}
 	io.extend_with(
 		sc_consensus_babe_rpc::BabeApi::to_delegate(
 			BabeRpcHandler::new(
@@ -40,16 +40,18 @@ mod multiplier_tests {
 	};
 	use frame_support::weights::{Weight, WeightToFeePolynomial, DispatchClass};
 
-	fn max_normal() -> Weight {
+	// The multiplier only depends on the reference time of the weights.
+	fn max_normal() -> u64 {
 		BlockWeights::get().get(DispatchClass::Normal).max_total
 			.unwrap_or_else(|| BlockWeights::get().max_block)
+			.ref_time()
 	}
 
 	fn min_multiplier() -> Multiplier {
 		MinimumMultiplier::get()
 	}
 
-	fn target() -> Weight {
+	fn target() -> u64 {
 		TargetBlockFullness::get() * max_normal()
 	}
 
@@ -64,7 +66,7 @@ mod multiplier_tests {
 	}
 
 	// update based on reference impl.
-	fn truth_value_update(block_weight: Weight, previous: Multiplier) -> Multiplier  {
+	fn truth_value_update(block_weight: u64, previous: Multiplier) -> Multiplier  {
 		let accuracy = Multiplier::accuracy() as f64;
 		let previous_float = previous.into_inner() as f64 / accuracy;
 		// bump if it is zero.
@@ -87,11 +89,11 @@ mod multiplier_tests {
 		Multiplier::from_float(next_float)
 	}
 
-	fn run_with_system_weight<F>(w: Weight, assertions: F) where F: Fn() -> () {
+	fn run_with_system_weight<F>(w: u64, assertions: F) where F: Fn() -> () {
 		let mut t: sp_io::TestExternalities =
 			frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap().into();
 		t.execute_with(|| {
-			System::set_block_consumed_resources(w, 0);
+			System::set_block_consumed_resources(Weight::from_ref_time(w), 0);
 			assertions()
 		});
 	}
@@ -184,7 +186,8 @@ mod multiplier_tests {
 		// `cargo test congested_chain_simulation -- --nocapture` to get some insight.
 
 		// almost full. The entire quota of normal transactions is taken.
-		let block_weight = BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap() - 100;
+		let block_weight =
+			BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap().ref_time() - 100;
 
 		// Default substrate weight.
 		let tx_weight = frame_support::weights::constants::ExtrinsicBaseWeight::get();
@@ -305,7 +308,7 @@ mod multiplier_tests {
 
 	#[test]
 	fn weight_to_fee_should_not_overflow_on_large_weights() {
-		let kb = 1024 as Weight;
+		let kb: u64 = 1024;
 		let mb = kb * kb;
 		let max_fm = Multiplier::saturating_from_integer(i128::max_value());
 
@@ -322,10 +325,10 @@ mod multiplier_tests {
 			10 * mb,
 			2147483647,
 			4294967295,
-			BlockWeights::get().max_block / 2,
-			BlockWeights::get().max_block,
-			Weight::max_value() / 2,
-			Weight::max_value(),
+			BlockWeights::get().max_block.ref_time() / 2,
+			BlockWeights::get().max_block.ref_time(),
+			u64::max_value() / 2,
+			u64::max_value(),
 		].into_iter().for_each(|i| {
 			run_with_system_weight(i, || {
 				let next = runtime_multiplier_update(Multiplier::one());
@@ -32,8 +32,8 @@ use frame_support::{
 		DispatchClass,
 	},
 	traits::{
-		Currency, Imbalance, KeyOwnerProofSystem, OnUnbalanced, LockIdentifier,
-		U128CurrencyToVote, MaxEncodedLen,
+		Currency, KeyOwnerProofSystem, LockIdentifier, U128CurrencyToVote, MaxEncodedLen,
+		SplitTwoWays, DealWithFees as SplitFeesAndTips,
 	},
 };
 use frame_system::{
@@ -67,7 +67,7 @@ use pallet_grandpa::{AuthorityId as GrandpaId, AuthorityList as GrandpaAuthority
 use pallet_grandpa::fg_primitives;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
-use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use pallet_transaction_payment::{CallDescription, FeeDetails, RuntimeDispatchInfo};
 pub use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment, CurrencyAdapter};
 use pallet_session::{historical as pallet_session_historical};
 use sp_inherents::{InherentData, CheckInherentsResult};
@@ -90,6 +90,9 @@ use impls::Author;
 /// Constant values used within the runtime.
 pub mod constants;
 use constants::{time::*, currency::*};
+
+/// The thresholds of the voter bags.
+mod voter_bags;
 use sp_runtime::generic::Era;
 
 // Make the WASM binary available.
@@ -138,21 +141,11 @@ pub fn native_version() -> NativeVersion {
 
 type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
-pub struct DealWithFees;
-impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item=NegativeImbalance>) {
-		if let Some(fees) = fees_then_tips.next() {
-			// for fees, 80% to treasury, 20% to author
-			let mut split = fees.ration(80, 20);
-			if let Some(tips) = fees_then_tips.next() {
-				// for tips, if any, 80% to treasury, 20% to author (though this can be anything)
-				tips.ration_merge_into(80, 20, &mut split);
-			}
-			Treasury::on_unbalanced(split.0);
-			Author::on_unbalanced(split.1);
-		}
-	}
-}
+/// For fees, 80% to treasury, 20% to author.
+pub type FeesSplit = SplitTwoWays<Balance, NegativeImbalance, _4, Treasury, _1, Author>;
+
+/// Fees are split between the treasury and the author, and tips go entirely to the author.
+pub type DealWithFees = SplitFeesAndTips<FeesSplit, Author>;
 
 /// We assume that ~10% of the block weight is consumed by `on_initialize` handlers.
 /// This is used to limit the maximal weight of a single extrinsic.
@@ -160,8 +153,10 @@ const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
 /// We allow `Normal` extrinsics to fill up the block up to 75%, the rest can be used
 /// by  Operational  extrinsics.
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
-/// We allow for 2 seconds of compute with a 6 second average block time.
-const MAXIMUM_BLOCK_WEIGHT: Weight = 2 * WEIGHT_PER_SECOND;
+/// We allow for 2 seconds of compute with a 6 second average block time, with no limit on the
+/// proof size as a standalone chain.
+const MAXIMUM_BLOCK_WEIGHT: Weight =
+	Weight::from_parts(2 * WEIGHT_PER_SECOND.ref_time(), u64::max_value());
 
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 2400;
@@ -220,6 +215,7 @@ impl frame_system::Config for Runtime {
 impl pallet_utility::Config for Runtime {
 	type Event = Event;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
 }
 
@@ -309,6 +305,22 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+parameter_types! {
+	// Large enough for the calls referenced by hash, well below the normal block length.
+	pub const PreimageMaxSize: u32 = 256 * 1024;
+	pub const PreimageBaseDeposit: Balance = 1 * DOLLARS;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Event = Event;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type MaxSize = PreimageMaxSize;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		RuntimeBlockWeights::get().max_block;
@@ -324,6 +336,9 @@ impl pallet_scheduler::Config for Runtime {
 	type ScheduleOrigin = EnsureRoot<AccountId>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
 	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type Preimages = Preimage;
+	type NoPreimagePostponement = ();
+	type MaxPostponements = ();
 }
 
 parameter_types! {
@@ -358,6 +373,15 @@ impl pallet_babe::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const RandomnessHistoryDepth: u32 = 28;
+}
+
+impl pallet_vrf_randomness::Config for Runtime {
+	type VrfOutputSource = pallet_vrf_randomness::BabeVrfOutputs<Runtime>;
+	type HistoryDepth = RandomnessHistoryDepth;
+}
+
 parameter_types! {
 	pub const IndexDeposit: Balance = 1 * DOLLARS;
 }
@@ -376,12 +400,25 @@ parameter_types! {
 	// This number may need to be adjusted in the future if this assumption no longer holds true.
 	pub const MaxLocks: u32 = 50;
 	pub const MaxReserves: u32 = 50;
+	pub const MaxHolds: u32 = 50;
+	pub const MaxFreezes: u32 = 50;
+}
+
+pub struct BalancesHoldsAndFreezes;
+impl pallet_balances::HoldsAndFreezes for BalancesHoldsAndFreezes {
+	type HoldReason = [u8; 8];
+	type FreezeIdentifier = LockIdentifier;
+	type MaxHolds = MaxHolds;
+	type MaxFreezes = MaxFreezes;
+	// No pallet of the runtime has moved from locks to freezes yet.
+	type LockMigration = ();
 }
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = BalancesHoldsAndFreezes;
 	type Balance = Balance;
 	type DustRemoval = ();
 	type Event = Event;
@@ -433,6 +470,7 @@ impl_opaque_keys! {
 		pub babe: Babe,
 		pub im_online: ImOnline,
 		pub authority_discovery: AuthorityDiscovery,
+		pub mixnet: Mixnet,
 	}
 }
 
@@ -501,14 +539,44 @@ impl pallet_staking::Config for Runtime {
 	type NextNewSession = Session;
 	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
 	type ElectionProvider = ElectionProviderMultiPhase;
+	type SortedListProvider = BagsList;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const BagThresholds: &'static [u64] = &voter_bags::THRESHOLDS;
+}
+
+impl pallet_bags_list::Config for Runtime {
+	type Event = Event;
+	type VoteWeightProvider = Staking;
+	type BagThresholds = BagThresholds;
+	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const FastUnstakeDeposit: Balance = 1 * DOLLARS;
+}
+
+impl pallet_fast_unstake::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Deposit = FastUnstakeDeposit;
+	type ControlOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = pallet_fast_unstake::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	// phase durations. 1/4 of the last session for each.
 	pub const SignedPhase: u32 = EPOCH_DURATION_IN_BLOCKS / 4;
 	pub const UnsignedPhase: u32 = EPOCH_DURATION_IN_BLOCKS / 4;
 
+	// signed config
+	pub const SignedMaxSubmissions: u32 = 10;
+	pub const SignedRewardBase: Balance = 1 * DOLLARS;
+	pub const SignedDepositBase: Balance = 1 * DOLLARS;
+	pub const SignedDepositByte: Balance = 1 * CENTS;
+
 	// fallback: no need to do on-chain phragmen initially.
 	pub const Fallback: pallet_election_provider_multi_phase::FallbackStrategy =
 		pallet_election_provider_multi_phase::FallbackStrategy::OnChain;
@@ -527,6 +595,13 @@ parameter_types! {
 		*RuntimeBlockLength::get()
 		.max
 		.get(DispatchClass::Normal);
+	// The snapshot of voters and targets is bounded to 20_000 voters and 2_000 targets, and may
+	// occupy at most 4MB.
+	pub ElectionBounds: frame_election_provider_support::ElectionBounds =
+		frame_election_provider_support::ElectionBounds::default()
+			.voters_count(20_000)
+			.voters_size(4 * 1024 * 1024)
+			.targets_count(2_000);
 }
 
 sp_npos_elections::generate_solution_type!(
@@ -546,6 +621,13 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type Currency = Balances;
 	type SignedPhase = SignedPhase;
 	type UnsignedPhase = UnsignedPhase;
+	type SignedMaxSubmissions = SignedMaxSubmissions;
+	type SignedRewardBase = SignedRewardBase;
+	type SignedDepositBase = SignedDepositBase;
+	type SignedDepositByte = SignedDepositByte;
+	type SignedDepositWeight = ();
+	type SlashHandler = (); // burn slashes
+	type RewardHandler = (); // nothing to do upon rewards
 	type SolutionImprovementThreshold = SolutionImprovementThreshold;
 	type OffchainRepeat = OffchainRepeat;
 	type MinerMaxIterations = MinerMaxIterations;
@@ -553,6 +635,7 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type MinerMaxLength = MinerMaxLength;
 	type MinerTxPriority = MultiPhaseUnsignedPriority;
 	type DataProvider = Staking;
+	type ElectionBounds = ElectionBounds;
 	type OnChainAccuracy = Perbill;
 	type CompactSolution = NposCompactSolution16;
 	type Fallback = Fallback;
@@ -726,6 +809,9 @@ parameter_types! {
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
 	pub const BountyValueMinimum: Balance = 5 * DOLLARS;
 	pub const MaxApprovals: u32 = 100;
+	pub const MaxActiveChildBountyCount: u32 = 5;
+	pub const ChildBountyValueMinimum: Balance = 1 * DOLLARS;
+	pub const ChildBountyCuratorDepositBase: Permill = Permill::from_percent(10);
 }
 
 impl pallet_treasury::Config for Runtime {
@@ -763,6 +849,15 @@ impl pallet_bounties::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type MaximumReasonLength = MaximumReasonLength;
 	type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
+	type ChildBountyManager = ChildBounties;
+}
+
+impl pallet_child_bounties::Config for Runtime {
+	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type ChildBountyCuratorDepositBase = ChildBountyCuratorDepositBase;
+	type Event = Event;
+	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_tips::Config for Runtime {
@@ -777,16 +872,12 @@ impl pallet_tips::Config for Runtime {
 }
 
 parameter_types! {
-	pub TombstoneDeposit: Balance = deposit(
+	pub DepositPerContract: Balance = deposit(
 		1,
 		<pallet_contracts::Pallet<Runtime>>::contract_info_size(),
 	);
-	pub DepositPerContract: Balance = TombstoneDeposit::get();
 	pub const DepositPerStorageByte: Balance = deposit(0, 1);
 	pub const DepositPerStorageItem: Balance = deposit(1, 0);
-	pub RentFraction: Perbill = Perbill::from_rational(1u32, 30 * DAYS);
-	pub const SurchargeReward: Balance = 150 * MILLICENTS;
-	pub const SignedClaimHandicap: u32 = 2;
 	pub const MaxValueSize: u32 = 16 * 1024;
 	// The lazy deletion runs inside on_initialize.
 	pub DeletionWeightLimit: Weight = AVERAGE_ON_INITIALIZE_RATIO *
@@ -802,17 +893,12 @@ parameter_types! {
 
 impl pallet_contracts::Config for Runtime {
 	type Time = Timestamp;
-	type Randomness = RandomnessCollectiveFlip;
+	type Randomness = VrfRandomness;
 	type Currency = Balances;
 	type Event = Event;
-	type RentPayment = ();
-	type SignedClaimHandicap = SignedClaimHandicap;
-	type TombstoneDeposit = TombstoneDeposit;
 	type DepositPerContract = DepositPerContract;
 	type DepositPerStorageByte = DepositPerStorageByte;
 	type DepositPerStorageItem = DepositPerStorageItem;
-	type RentFraction = RentFraction;
-	type SurchargeReward = SurchargeReward;
 	type CallStack = [pallet_contracts::Frame<Self>; 31];
 	type WeightPrice = pallet_transaction_payment::Module<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
@@ -909,6 +995,30 @@ impl pallet_offences::Config for Runtime {
 
 impl pallet_authority_discovery::Config for Runtime {}
 
+parameter_types! {
+	pub const MixnetMaxAuthorities: u32 = 100;
+	pub const MaxExternalAddressSize: u32 = 128;
+	pub const MaxExternalAddressesPerMixnode: u32 = 16;
+	pub const NumCoverToCurrentBlocks: BlockNumber = 3;
+	pub const NumRequestsToCurrentBlocks: BlockNumber = 3;
+	pub const NumCoverToPrevBlocks: BlockNumber = 3;
+	pub const MinMixnodes: u32 = 7;
+	/// Mixnode registrations come right after the im-online heartbeats.
+	pub const MixnetRegistrationPriority: TransactionPriority = TransactionPriority::max_value() - 1;
+}
+
+impl pallet_mixnet::Config for Runtime {
+	type MaxAuthorities = MixnetMaxAuthorities;
+	type MaxExternalAddressSize = MaxExternalAddressSize;
+	type MaxExternalAddressesPerMixnode = MaxExternalAddressesPerMixnode;
+	type NumCoverToCurrentBlocks = NumCoverToCurrentBlocks;
+	type NumRequestsToCurrentBlocks = NumRequestsToCurrentBlocks;
+	type NumCoverToPrevBlocks = NumCoverToPrevBlocks;
+	type MinMixnodes = MinMixnodes;
+	type RegistrationPriority = MixnetRegistrationPriority;
+	type WeightInfo = pallet_mixnet::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_grandpa::Config for Runtime {
 	type Event = Event;
 	type Call = Call;
@@ -986,7 +1096,7 @@ impl pallet_society::Config for Runtime {
 	type Event = Event;
 	type PalletId = SocietyPalletId;
 	type Currency = Balances;
-	type Randomness = RandomnessCollectiveFlip;
+	type Randomness = VrfRandomness;
 	type CandidateDeposit = CandidateDeposit;
 	type WrongSideDeduction = WrongSideDeduction;
 	type MaxStrikes = MaxStrikes;
@@ -1002,6 +1112,7 @@ impl pallet_society::Config for Runtime {
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * DOLLARS;
+	pub const MaxVestingSchedules: u32 = 28;
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -1009,6 +1120,7 @@ impl pallet_vesting::Config for Runtime {
 	type Currency = Balances;
 	type BlockNumberToBalance = ConvertInto;
 	type MinVestedTransfer = MinVestedTransfer;
+	type MaxVestingSchedules = MaxVestingSchedules;
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1031,7 +1143,7 @@ impl pallet_lottery::Config for Runtime {
 	type PalletId = LotteryPalletId;
 	type Call = Call;
 	type Currency = Balances;
-	type Randomness = RandomnessCollectiveFlip;
+	type Randomness = VrfRandomness;
 	type Event = Event;
 	type ManagerOrigin = EnsureRoot<AccountId>;
 	type MaxCalls = MaxCalls;
@@ -1046,6 +1158,7 @@ parameter_types! {
 	pub const StringLimit: u32 = 50;
 	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
 	pub const MetadataDepositPerByte: Balance = 1 * DOLLARS;
+	pub const RemoveItemsLimit: u32 = 1000;
 }
 
 impl pallet_assets::Config for Runtime {
@@ -1061,6 +1174,7 @@ impl pallet_assets::Config for Runtime {
 	type StringLimit = StringLimit;
 	type Freezer = ();
 	type Extra = ();
+	type RemoveItemsLimit = RemoveItemsLimit;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1098,6 +1212,7 @@ parameter_types! {
 	pub const InstanceDeposit: Balance = 1 * DOLLARS;
 	pub const KeyLimit: u32 = 32;
 	pub const ValueLimit: u32 = 256;
+	pub const MaxDeadlineDuration: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -1114,6 +1229,7 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1125,6 +1241,40 @@ impl pallet_transaction_storage::Config for Runtime {
 	type WeightInfo = pallet_transaction_storage::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const StatementCost: Balance = 1 * DOLLARS;
+	pub const StatementByteCost: Balance = 100 * MILLICENTS;
+	pub const MinAllowedStatements: u32 = 4;
+	pub const MaxAllowedStatements: u32 = 10;
+	pub const MinAllowedBytes: u32 = 1024;
+	pub const MaxAllowedBytes: u32 = 4096;
+}
+
+/// The statement store allowance of the signer of `statement`, which grows with the free balance
+/// of the signer.
+fn validate_statement(
+	statement: sp_statement_store::Statement,
+) -> Result<sp_statement_store::ValidStatement, sp_statement_store::InvalidStatement> {
+	use sp_statement_store::{InvalidStatement, SignatureVerificationResult, ValidStatement};
+
+	let account: AccountId = match statement.verify_signature() {
+		SignatureVerificationResult::Valid(account) => account.into(),
+		SignatureVerificationResult::Invalid => return Err(InvalidStatement::BadProof),
+		SignatureVerificationResult::NoSignature => return Err(InvalidStatement::NoProof),
+	};
+	if !System::account_exists(&account) {
+		return Err(InvalidStatement::NoAllowance)
+	}
+
+	let free = Balances::free_balance(&account);
+	let max_count: u32 = (free / StatementCost::get()).saturated_into();
+	let max_size: u32 = (free / StatementByteCost::get()).saturated_into();
+	Ok(ValidStatement {
+		max_count: max_count.max(MinAllowedStatements::get()).min(MaxAllowedStatements::get()),
+		max_size: max_size.max(MinAllowedBytes::get()).min(MaxAllowedBytes::get()),
+	})
+}
+
 construct_runtime!(
 	pub enum Runtime where
 		Block = Block,
@@ -1161,16 +1311,22 @@ construct_runtime!(
 		Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
+		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
 		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>},
 		Bounties: pallet_bounties::{Pallet, Call, Storage, Event<T>},
 		Tips: pallet_tips::{Pallet, Call, Storage, Event<T>},
+		ChildBounties: pallet_child_bounties::{Pallet, Call, Storage, Event<T>},
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Mmr: pallet_mmr::{Pallet, Storage},
 		Lottery: pallet_lottery::{Pallet, Call, Storage, Event<T>},
 		Gilt: pallet_gilt::{Pallet, Call, Storage, Event<T>, Config},
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		TransactionStorage: pallet_transaction_storage::{Pallet, Call, Storage, Inherent, Config<T>, Event<T>},
+		BagsList: pallet_bags_list::{Pallet, Call, Storage, Event<T>},
+		VrfRandomness: pallet_vrf_randomness::{Pallet, Storage},
+		FastUnstake: pallet_fast_unstake::{Pallet, Call, Storage, Event<T>},
+		Mixnet: pallet_mixnet::{Pallet, Call, Storage, ValidateUnsigned},
 	}
 );
 
@@ -1204,6 +1360,13 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signatu
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
+/// The runtime migrations, executed on a runtime upgrade before the hooks of the pallets.
+pub type Migrations = (
+	pallet_balances::migrations::MigrateToBoundedVecs<Runtime>,
+	pallet_staking::migrations::InjectNominatorsIntoSortedListProvider<Runtime>,
+	pallet_vesting::migrations::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1211,7 +1374,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
-	(),
+	Migrations,
 >;
 
 /// MMR helper types.
@@ -1276,6 +1439,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_statement_store::ValidateStatement<Block> for Runtime {
+		fn validate_statement(
+			_source: sp_statement_store::StatementSource,
+			statement: sp_statement_store::Statement,
+		) -> Result<sp_statement_store::ValidStatement, sp_statement_store::InvalidStatement> {
+			validate_statement(statement)
+		}
+	}
+
 	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
 		fn offchain_worker(header: &<Block as BlockT>::Header) {
 			Executive::offchain_worker(header)
@@ -1373,6 +1545,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_mixnet::MixnetApi<Block> for Runtime {
+		fn session_status() -> sp_mixnet::SessionStatus {
+			Mixnet::session_status()
+		}
+
+		fn prev_mixnodes() -> Result<Vec<sp_mixnet::Mixnode>, sp_mixnet::MixnodesErr> {
+			Mixnet::prev_mixnodes()
+		}
+
+		fn current_mixnodes() -> Result<Vec<sp_mixnet::Mixnode>, sp_mixnet::MixnodesErr> {
+			Mixnet::current_mixnodes()
+		}
+
+		fn maybe_register(
+			session_index: sp_mixnet::SessionIndex,
+			mixnode: sp_mixnet::Mixnode,
+		) -> bool {
+			Mixnet::maybe_register(session_index, mixnode)
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index> for Runtime {
 		fn account_nonce(account: AccountId) -> Index {
 			System::account_nonce(account)
@@ -1389,21 +1582,40 @@ impl_runtime_apis! {
 			dest: AccountId,
 			value: Balance,
 			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
-		) -> pallet_contracts_primitives::ContractExecResult {
-			Contracts::bare_call(origin, dest, value, gas_limit, input_data, true)
+		) -> pallet_contracts_primitives::ContractExecResult<Balance> {
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				Weight::from_ref_time(gas_limit),
+				storage_deposit_limit,
+				input_data,
+				true,
+			)
 		}
 
 		fn instantiate(
 			origin: AccountId,
 			endowment: Balance,
 			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
 			code: pallet_contracts_primitives::Code<Hash>,
 			data: Vec<u8>,
 			salt: Vec<u8>,
-		) -> pallet_contracts_primitives::ContractInstantiateResult<AccountId, BlockNumber>
+		) -> pallet_contracts_primitives::ContractInstantiateResult<AccountId, Balance>
 		{
-			Contracts::bare_instantiate(origin, endowment, gas_limit, code, data, salt, true, true)
+			Contracts::bare_instantiate(
+				origin,
+				endowment,
+				Weight::from_ref_time(gas_limit),
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				true,
+			)
 		}
 
 		fn get_storage(
@@ -1412,12 +1624,6 @@ impl_runtime_apis! {
 		) -> pallet_contracts_primitives::GetStorageResult {
 			Contracts::get_storage(address, key)
 		}
-
-		fn rent_projection(
-			address: AccountId,
-		) -> pallet_contracts_primitives::RentProjectionResult<BlockNumber> {
-			Contracts::rent_projection(address)
-		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -1430,6 +1636,19 @@ impl_runtime_apis! {
 		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
 			TransactionPayment::query_fee_details(uxt, len)
 		}
+		fn query_fee_details_with_tip(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+		) -> FeeDetails<Balance> {
+			TransactionPayment::query_fee_details_with_tip(uxt, len, tip)
+		}
+		fn query_weight_to_fee(weight: Weight) -> Balance {
+			TransactionPayment::query_weight_to_fee(weight)
+		}
+		fn decode_call(call: Vec<u8>) -> Option<CallDescription> {
+			TransactionPayment::decode_call::<Call>(&call)
+		}
 	}
 
 	impl pallet_mmr::primitives::MmrApi<
@@ -1520,13 +1739,16 @@ impl_runtime_apis! {
 
 			add_benchmark!(params, batches, pallet_assets, Assets);
 			add_benchmark!(params, batches, pallet_babe, Babe);
+			add_benchmark!(params, batches, pallet_bags_list, BagsList);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_bounties, Bounties);
+			add_benchmark!(params, batches, pallet_child_bounties, ChildBounties);
 			add_benchmark!(params, batches, pallet_collective, Council);
 			add_benchmark!(params, batches, pallet_contracts, Contracts);
 			add_benchmark!(params, batches, pallet_democracy, Democracy);
 			add_benchmark!(params, batches, pallet_election_provider_multi_phase, ElectionProviderMultiPhase);
 			add_benchmark!(params, batches, pallet_elections_phragmen, Elections);
+			add_benchmark!(params, batches, pallet_fast_unstake, FastUnstake);
 			add_benchmark!(params, batches, pallet_gilt, Gilt);
 			add_benchmark!(params, batches, pallet_grandpa, Grandpa);
 			add_benchmark!(params, batches, pallet_identity, Identity);
@@ -1536,6 +1758,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_membership, TechnicalMembership);
 			add_benchmark!(params, batches, pallet_mmr, Mmr);
 			add_benchmark!(params, batches, pallet_multisig, Multisig);
+			add_benchmark!(params, batches, pallet_preimage, Preimage);
 			add_benchmark!(params, batches, pallet_offences, OffencesBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_proxy, Proxy);
 			add_benchmark!(params, batches, pallet_scheduler, Scheduler);
@@ -1569,4 +1792,50 @@ mod tests {
 
 		is_submit_signed_transaction::<Runtime>();
 	}
+
+	#[test]
+	fn statement_allowance_depends_on_the_free_balance() {
+		use frame_support::traits::GenesisBuild;
+		use sp_core::Pair;
+		use sp_statement_store::{InvalidStatement, Statement, ValidStatement};
+
+		let poor = sp_core::sr25519::Pair::from_seed(&[1; 32]);
+		let rich = sp_core::sr25519::Pair::from_seed(&[2; 32]);
+		let unknown = sp_core::sr25519::Pair::from_seed(&[3; 32]);
+		let account = |pair: &sp_core::sr25519::Pair| AccountId::from(pair.public());
+		let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(account(&poor), 1 * DOLLARS), (account(&rich), 1_000 * DOLLARS)],
+		}.assimilate_storage(&mut storage).unwrap();
+		let signed = |pair: &sp_core::sr25519::Pair| {
+			let mut statement = Statement {
+				proof: None,
+				decryption_key: None,
+				priority: 0,
+				topics: Vec::new(),
+				data: vec![1, 2, 3],
+			};
+			statement.sign_sr25519_private(pair);
+			statement
+		};
+
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			assert_eq!(
+				validate_statement(signed(&poor)),
+				Ok(ValidStatement { max_count: 4, max_size: 1024 }),
+			);
+			assert_eq!(
+				validate_statement(signed(&rich)),
+				Ok(ValidStatement { max_count: 10, max_size: 4096 }),
+			);
+			assert_eq!(validate_statement(signed(&unknown)), Err(InvalidStatement::NoAllowance));
+
+			let mut tampered = signed(&rich);
+			tampered.data.push(4);
+			assert_eq!(validate_statement(tampered), Err(InvalidStatement::BadProof));
+			let mut unsigned = signed(&rich);
+			unsigned.proof = None;
+			assert_eq!(validate_statement(unsigned), Err(InvalidStatement::NoProof));
+		});
+	}
 }
@@ -0,0 +1,228 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The voter bags of the node runtime.
+//!
+//! The thresholds follow a geometric progression, from the existential deposit to `u64::MAX`, so
+//! that each bag covers the same relative range of vote weights.
+
+/// The existential weight, i.e. the vote weight of the existential deposit.
+pub const EXISTENTIAL_WEIGHT: u64 = 100_000_000_000_000;
+
+/// The upper thresholds of the voter bags.
+pub const THRESHOLDS: [u64; 200] = [
+	100_000_000_000_000,
+	106_282_535_907_434,
+	112_959_774_389_150,
+	120_056_512_776_105,
+	127_599_106_300_477,
+	135_615_565_971_369,
+	144_135_662_599_591,
+	153_191_037_357_828,
+	162_815_319_286_804,
+	173_044_250_183_801,
+	183_915_817_337_349,
+	195_470_394_601_018,
+	207_750_892_330_231,
+	220_802_916_738_892,
+	234_674_939_267_675,
+	249_418_476_592_915,
+	265_088_281_944_640,
+	281_742_548_444_213,
+	299_443_125_216_740,
+	318_255_747_080_825,
+	338_250_278_668_650,
+	359_500_973_883_004,
+	382_086_751_654_779,
+	406_091_489_025_039,
+	431_604_332_640_071,
+	458_720_029_816_224,
+	487_539_280_404_021,
+	518_169_110_758_249,
+	550_723_271_202_868,
+	585_322_658_466_784,
+	622_095_764_659_307,
+	661_179_154_452_655,
+	702_717_972_243_612,
+	746_866_481_177_810,
+	793_788_636_038_395,
+	843_658_692_126_638,
+	896_661_852_395_683,
+	952_994_955_240_706,
+	1_012_867_205_499_738,
+	1_076_500_951_379_884,
+	1_144_132_510_194_194,
+	1_216_013_045_975_771,
+	1_292_409_502_228_282,
+	1_373_605_593_276_864,
+	1_459_902_857_901_006,
+	1_551_621_779_162_293,
+	1_649_102_974_585_732,
+	1_752_708_461_114_644,
+	1_862_822_999_536_807,
+	1_979_855_523_374_647,
+	2_104_240_657_545_977,
+	2_236_440_332_435_130,
+	2_376_945_499_368_705,
+	2_526_277_953_866_682,
+	2_684_992_273_439_947,
+	2_853_677_877_130_643,
+	3_032_961_214_443_878,
+	3_223_508_091_799_864,
+	3_426_026_145_146_233,
+	3_641_267_467_913_125,
+	3_870_031_404_070_483,
+	4_113_167_516_660_187,
+	4_371_578_742_827_278,
+	4_646_224_747_067_157,
+	4_938_125_485_141_739,
+	5_248_364_991_899_923,
+	5_578_095_407_069_235,
+	5_928_541_253_969_291,
+	6_301_003_987_036_955,
+	6_696_866_825_051_405,
+	7_117_599_888_008_300,
+	7_564_765_656_719_910,
+	8_040_024_775_416_580,
+	8_545_142_218_898_723,
+	9_081_993_847_142_345,
+	9_652_573_371_700_017,
+	10_258_999_759_768_491,
+	10_903_525_103_419_523,
+	11_588_542_983_217_944,
+	12_316_597_357_287_044,
+	13_090_392_008_832_680,
+	13_912_800_587_211_475,
+	14_786_877_279_832_736,
+	15_715_868_154_526_440,
+	16_703_223_214_499_561,
+	17_752_609_210_649_362,
+	18_867_923_258_814_858,
+	20_053_307_312_537_009,
+	21_313_163_545_075_251,
+	22_652_170_697_804_755,
+	24_075_301_455_707_600,
+	25_587_840_914_485_431,
+	27_195_406_207_875_088,
+	28_903_967_368_057_398,
+	30_719_869_496_628_634,
+	32_649_856_328_471_218,
+	34_701_095_276_033_062,
+	36_881_204_047_022_751,
+	39_198_278_934_370_988,
+	41_660_924_883_519_015,
+	44_278_287_448_695_239,
+	47_060_086_756_856_396,
+	50_016_653_605_425_531,
+	53_158_967_827_883_314,
+	56_498_699_069_691_415,
+	60_048_250_125_977_904,
+	63_820_803_001_928_298,
+	67_830_367_866_937_213,
+	72_091_835_084_322_174,
+	76_621_030_509_822_877,
+	81_434_774_264_248_523,
+	86_550_943_198_537_827,
+	91_988_537_283_208_843,
+	97_767_750_168_749_828,
+	103_910_044_178_991_982,
+	110_438_230_015_967_773,
+	117_376_551_472_255_594,
+	124_750_775_465_407_901,
+	132_588_287_728_824_621,
+	140_918_194_514_440_046,
+	149_771_430_684_917_549,
+	159_180_874_596_775_243,
+	169_181_470_201_085_266,
+	179_810_356_815_193_342,
+	191_107_007_047_393_212,
+	203_113_373_386_768_274,
+	215_874_044_002_592_654,
+	229_436_408_331_885_591,
+	243_850_833_070_063_388,
+	259_170_849_218_267_267,
+	275_453_350_882_006_754,
+	292_758_806_559_399_227,
+	311_151_483_703_668_971,
+	330_699_687_393_865_885,
+	351_476_014_000_157_773,
+	373_557_620_785_735_731,
+	397_026_512_446_556_025,
+	421_969_845_653_044_158,
+	448_480_252_724_740_836,
+	476_656_185_639_923_803,
+	506_602_281_657_757_678,
+	538_429_751_910_786_655,
+	572_256_794_410_890_079,
+	608_209_033_002_485_489,
+	646_419_983_893_124_254,
+	687_031_551_494_039_403,
+	730_194_555_412_053_912,
+	776_069_290_549_944_864,
+	824_826_122_395_314_095,
+	876_646_119_708_695_904,
+	931_721_726_960_522_302,
+	990_257_479_014_182_064,
+	1_052_470_760_709_299_584,
+	1_118_592_614_166_105_978,
+	1_188_868_596_808_997_179,
+	1_263_559_693_295_730_163,
+	1_342_943_284_738_898_383,
+	1_427_314_178_819_094_418,
+	1_516_985_704_615_301_946,
+	1_612_290_876_218_400_229,
+	1_713_583_629_449_104_832,
+	1_821_240_136_273_157_050,
+	1_935_660_201_795_119_572,
+	2_057_268_749_018_808_996,
+	2_186_517_396_888_335_641,
+	2_323_886_137_470_138_335,
+	2_469_885_118_504_582_759,
+	2_625_056_537_947_004_032,
+	2_789_976_657_533_970_573,
+	2_965_257_942_852_571_517,
+	3_151_551_337_860_325_553,
+	3_349_548_682_302_619_749,
+	3_559_985_281_005_267_021,
+	3_783_642_634_583_791_073,
+	4_021_351_341_710_502_708,
+	4_273_994_183_717_547_324,
+	4_542_509_402_991_246_477,
+	4_827_894_187_332_741_143,
+	5_131_208_373_224_843_534,
+	5_453_578_381_757_958_621,
+	5_796_201_401_831_964_983,
+	6_160_349_836_169_255_881,
+	6_547_376_026_650_146_105,
+	6_958_717_276_519_172_489,
+	7_395_901_188_113_308_610,
+	7_860_551_335_934_871_211,
+	8_354_393_296_137_269_030,
+	8_879_261_054_815_358_827,
+	9_437_103_818_898_944_087,
+	10_029_993_254_943_103_394,
+	10_660_131_182_698_120_443,
+	11_329_857_752_030_707_480,
+	12_041_660_133_563_240_720,
+	12_798_181_755_305_526_312,
+	13_602_232_119_581_273_650,
+	14_456_797_236_706_499_363,
+	15_365_050_714_167_523_513,
+	16_330_365_542_480_555_233,
+	17_356_326_621_502_138_844,
+	18_446_744_073_709_551_615,
+];
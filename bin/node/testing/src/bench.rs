@@ -410,6 +410,7 @@ impl BenchDb {
 		let db_config = sc_client_db::DatabaseSettings {
 			state_cache_size: 16*1024*1024,
 			state_cache_child_ratio: Some((0, 100)),
+			trie_cache_maximum_size: Some(16 * 1024 * 1024),
 			state_pruning: PruningMode::ArchiveAll,
 			source: database_type.into_settings(dir.into()),
 			keep_blocks: sc_client_db::KeepBlocks::All,
@@ -594,15 +595,14 @@ impl BenchKeyring {
 						key.sign(b)
 					}
 				}).into();
-				UncheckedExtrinsic {
-					signature: Some((sp_runtime::MultiAddress::Id(signed), signature, extra)),
-					function: payload.0,
-				}
+				UncheckedExtrinsic::new_signed(
+					payload.0,
+					sp_runtime::MultiAddress::Id(signed),
+					signature,
+					extra,
+				)
 			}
-			None => UncheckedExtrinsic {
-				signature: None,
-				function: xt.function,
-			},
+			None => UncheckedExtrinsic::new_unsigned(xt.function),
 		}
 	}
 
@@ -628,20 +628,8 @@ pub enum Profile {
 impl Profile {
 	fn into_execution_strategies(self) -> ExecutionStrategies {
 		match self {
-			Profile::Wasm => ExecutionStrategies {
-				syncing: ExecutionStrategy::AlwaysWasm,
-				importing: ExecutionStrategy::AlwaysWasm,
-				block_construction: ExecutionStrategy::AlwaysWasm,
-				offchain_worker: ExecutionStrategy::AlwaysWasm,
-				other: ExecutionStrategy::AlwaysWasm,
-			},
-			Profile::Native => ExecutionStrategies {
-				syncing: ExecutionStrategy::NativeElseWasm,
-				importing: ExecutionStrategy::NativeElseWasm,
-				block_construction: ExecutionStrategy::NativeElseWasm,
-				offchain_worker: ExecutionStrategy::NativeElseWasm,
-				other: ExecutionStrategy::NativeElseWasm,
-			}
+			Profile::Wasm => ExecutionStrategies::all(ExecutionStrategy::AlwaysWasm),
+			Profile::Native => ExecutionStrategies::all(ExecutionStrategy::NativeElseWasm),
 		}
 	}
 }
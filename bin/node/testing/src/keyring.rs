@@ -64,6 +64,7 @@ pub fn to_session_keys(
 		babe: sr25519_keyring.to_owned().public().into(),
 		im_online: sr25519_keyring.to_owned().public().into(),
 		authority_discovery: sr25519_keyring.to_owned().public().into(),
+		mixnet: sr25519_keyring.to_owned().public().into(),
 	}
 }
 
@@ -93,14 +94,13 @@ pub fn sign(xt: CheckedExtrinsic, spec_version: u32, tx_version: u32, genesis_ha
 					key.sign(b)
 				}
 			}).into();
-			UncheckedExtrinsic {
-				signature: Some((sp_runtime::MultiAddress::Id(signed), signature, extra)),
-				function: payload.0,
-			}
+			UncheckedExtrinsic::new_signed(
+				payload.0,
+				sp_runtime::MultiAddress::Id(signed),
+				signature,
+				extra,
+			)
 		}
-		None => UncheckedExtrinsic {
-			signature: None,
-			function: xt.function,
-		},
+		None => UncheckedExtrinsic::new_unsigned(xt.function),
 	}
 }
@@ -18,7 +18,7 @@
 
 use structopt::StructOpt;
 use sc_cli::{
-	Error, VanityCmd, SignCmd, VerifyCmd, GenerateNodeKeyCmd, GenerateCmd, InspectKeyCmd,
+	Error, VanityCmd, SignCmd, SignTransactionCmd, VerifyCmd, GenerateNodeKeyCmd, GenerateCmd, InspectKeyCmd,
 	InspectNodeKeyCmd
 };
 
@@ -45,6 +45,9 @@ pub enum Subkey {
 	/// Sign a message, with a given (secret) key.
 	Sign(SignCmd),
 
+	/// Sign a call and print the signed extrinsic, optionally submitting it.
+	SignTransaction(SignTransactionCmd),
+
 	/// Generate a seed that provides a vanity address.
 	Vanity(VanityCmd),
 
@@ -62,5 +65,6 @@ pub fn run() -> Result<(), Error> {
 		Subkey::Vanity(cmd) => cmd.run(),
 		Subkey::Verify(cmd) => cmd.run(),
 		Subkey::Sign(cmd) => cmd.run(),
+		Subkey::SignTransaction(cmd) => cmd.run(),
 	}
 }
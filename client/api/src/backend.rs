@@ -20,6 +20,7 @@
 
 use std::sync::Arc;
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use sp_core::ChangesTrieConfigurationRange;
 use sp_core::offchain::OffchainStorage;
 use sp_runtime::{generic::BlockId, Justification, Justifications, Storage};
@@ -504,6 +505,14 @@ pub trait Backend<Block: BlockT>: AuxStore + Send + Sync {
 	/// something that the import of a block would interfere with, e.g. importing
 	/// a new block or calculating the best head.
 	fn get_import_lock(&self) -> &RwLock<()>;
+
+	/// Write a consistent copy of the backend database to the directory `target`.
+	///
+	/// The copy can be made while the node is running, the backend acquires the import lock while
+	/// the copy is made. Returns an error if the backend doesn't support backups.
+	fn backup(&self, _target: &Path) -> sp_blockchain::Result<()> {
+		Err(sp_blockchain::Error::Backend("Backup is not supported by this backend".into()))
+	}
 }
 
 /// Changes trie storage that supports pruning.
@@ -26,6 +26,7 @@ use sp_runtime::{
 	Justifications,
 };
 use sp_consensus::BlockOrigin;
+use sp_version::RuntimeVersion;
 
 use crate::blockchain::Info;
 use crate::notifications::StorageEventStream;
@@ -38,6 +39,10 @@ pub type ImportNotifications<Block> = TracingUnboundedReceiver<BlockImportNotifi
 /// A stream of block finality notifications.
 pub type FinalityNotifications<Block> = TracingUnboundedReceiver<FinalityNotification<Block>>;
 
+/// A stream of runtime upgrade notifications.
+pub type RuntimeUpgradeNotifications<Block> =
+	TracingUnboundedReceiver<RuntimeUpgradeNotification<Block>>;
+
 /// Expected hashes of blocks at given heights.
 ///
 /// This may be used as chain spec extension to set trusted checkpoints, i.e.
@@ -66,6 +71,10 @@ pub trait BlockchainEvents<Block: BlockT> {
 	/// finalized block.
 	fn finality_notification_stream(&self) -> FinalityNotifications<Block>;
 
+	/// Get a stream of runtime upgrade notifications, fired when the runtime of the new best
+	/// block differs from the runtime of the previous best block.
+	fn runtime_upgrade_notification_stream(&self) -> RuntimeUpgradeNotifications<Block>;
+
 	/// Get storage changes event stream.
 	///
 	/// Passing `None` as `filter_keys` subscribes to all storage changes.
@@ -183,6 +192,8 @@ pub struct StateDbMemoryInfo {
 pub struct MemoryInfo {
 	/// Size of state cache.
 	pub state_cache: MemorySize,
+	/// Size of the trie node cache.
+	pub trie_cache: MemorySize,
 	/// Size of backend database cache.
 	pub database_cache: MemorySize,
 	/// Size of the state db.
@@ -214,6 +225,52 @@ pub struct IoInfo {
 	pub state_writes_cache: u64,
 	/// State write (trie nodes) to backend db.
 	pub state_writes_nodes: u64,
+	/// State reads (trie nodes), only tracked when the trie cache is enabled.
+	pub trie_nodes_reads: u64,
+	/// State reads (trie nodes) from the trie cache.
+	pub trie_nodes_reads_cache: u64,
+}
+
+/// State access statistics of an imported block.
+///
+/// The statistics are accumulated from the import of the previous block, so they also include
+/// the state accesses made in the meantime, e.g. by RPC queries or block authorship.
+#[derive(Default, Clone, Debug)]
+pub struct BlockUsageInfo {
+	/// State reads (keys).
+	pub state_reads: u64,
+	/// State reads (keys) from cache.
+	pub state_reads_cache: u64,
+	/// State reads (trie nodes), only tracked when the trie cache is enabled.
+	pub trie_nodes_reads: u64,
+	/// State reads (trie nodes) from the trie cache.
+	pub trie_nodes_reads_cache: u64,
+	/// Trie nodes read from disk, only tracked when the trie cache is enabled.
+	pub database_reads: u64,
+	/// Bytes of the trie nodes read from disk.
+	pub database_bytes_read: u64,
+	/// Key writes to disk by the import of the block.
+	pub database_writes: u64,
+	/// Bytes written to disk by the import of the block.
+	pub database_bytes_written: u64,
+}
+
+impl fmt::Display for BlockUsageInfo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}/{} key cache reads/total, {}/{} trie cache reads/total, \
+			 db: ({} reads, {} read, {} writes, {} written)",
+			self.state_reads_cache,
+			self.state_reads,
+			self.trie_nodes_reads_cache,
+			self.trie_nodes_reads,
+			self.database_reads,
+			MemorySize::from_bytes(self.database_bytes_read as usize),
+			self.database_writes,
+			MemorySize::from_bytes(self.database_bytes_written as usize),
+		)
+	}
 }
 
 /// Usage statistics for running client instance.
@@ -227,16 +284,20 @@ pub struct UsageInfo {
 	pub memory: MemoryInfo,
 	/// I/O statistics.
 	pub io: IoInfo,
+	/// State access statistics of the last imported block, if tracked by the backend.
+	pub last_block: Option<BlockUsageInfo>,
 }
 
 impl fmt::Display for UsageInfo {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"caches: ({} state, {} db overlay), \
+			"caches: ({} state, {} trie, {} db overlay), \
 			 state db: ({} non-canonical, {} pruning, {} pinned), \
-			 i/o: ({} tx, {} write, {} read, {} avg tx, {}/{} key cache reads/total, {} trie nodes writes)",
+			 i/o: ({} tx, {} write, {} read, {} avg tx, {}/{} key cache reads/total, \
+			 {}/{} trie cache reads/total, {} trie nodes writes)",
 			self.memory.state_cache,
+			self.memory.trie_cache,
 			self.memory.database_cache,
 			self.memory.state_db.non_canonical,
 			self.memory.state_db.pruning.unwrap_or_default(),
@@ -247,6 +308,8 @@ impl fmt::Display for UsageInfo {
 			self.io.average_transaction_size,
 			self.io.state_reads_cache,
 			self.io.state_reads,
+			self.io.trie_nodes_reads_cache,
+			self.io.trie_nodes_reads,
 			self.io.state_writes_nodes,
 		)
 	}
@@ -278,6 +341,15 @@ pub struct FinalityNotification<Block: BlockT> {
 	pub header: Block::Header,
 }
 
+/// Summary of an upgrade of the runtime of the best chain.
+#[derive(Clone, Debug)]
+pub struct RuntimeUpgradeNotification<Block: BlockT> {
+	/// Hash of the new best block, whose state contains the new runtime.
+	pub hash: Block::Hash,
+	/// Version of the new runtime.
+	pub version: RuntimeVersion,
+}
+
 impl<B: BlockT> TryFrom<BlockImportNotification<B>> for sp_transaction_pool::ChainEvent<B> {
 	type Error = ();
 
@@ -53,14 +53,73 @@ pub struct ExecutionStrategies {
 	pub other: ExecutionStrategy,
 }
 
+impl ExecutionStrategies {
+	/// Use the given strategy for every execution context.
+	pub fn all(strategy: ExecutionStrategy) -> Self {
+		ExecutionStrategies {
+			syncing: strategy,
+			importing: strategy,
+			block_construction: strategy,
+			offchain_worker: strategy,
+			other: strategy,
+		}
+	}
+}
+
+/// Use the wasm runtime for every execution context, native execution must be opted in
+/// explicitly.
 impl Default for ExecutionStrategies {
 	fn default() -> ExecutionStrategies {
-		ExecutionStrategies {
-			syncing: ExecutionStrategy::NativeElseWasm,
-			importing: ExecutionStrategy::NativeElseWasm,
-			block_construction: ExecutionStrategy::AlwaysWasm,
-			offchain_worker: ExecutionStrategy::NativeWhenPossible,
-			other: ExecutionStrategy::NativeElseWasm,
+		ExecutionStrategies::all(ExecutionStrategy::AlwaysWasm)
+	}
+}
+
+/// The capabilities granted to the runtime calls of each execution context.
+///
+/// The capabilities decide which extensions are registered for a call, see
+/// [`ExecutionExtensions::extensions`]. They are granted by the identity of the caller, i.e. the
+/// execution context: only [`ExecutionContext::OffchainWorker`] is granted the capabilities of the
+/// offchain workers, and an offchain call requesting its own capabilities is only granted the
+/// ones allowed to the other offchain calls.
+#[derive(Debug, Clone)]
+pub struct ExtensionsRegistry {
+	/// Capabilities granted when syncing.
+	pub syncing: offchain::Capabilities,
+	/// Capabilities granted when importing blocks.
+	pub importing: offchain::Capabilities,
+	/// Capabilities granted when constructing blocks.
+	pub block_construction: offchain::Capabilities,
+	/// Capabilities granted to offchain workers.
+	pub offchain_worker: offchain::Capabilities,
+	/// Capabilities granted to the other offchain calls, e.g. those made through RPC.
+	pub other: offchain::Capabilities,
+}
+
+impl ExtensionsRegistry {
+	/// Get the capabilities granted to a runtime call in the given execution `context`.
+	pub fn capabilities_for(&self, context: &ExecutionContext) -> offchain::Capabilities {
+		match context {
+			ExecutionContext::Syncing => self.syncing,
+			ExecutionContext::Importing => self.importing,
+			ExecutionContext::BlockConstruction => self.block_construction,
+			ExecutionContext::OffchainWorker(_) => self.offchain_worker,
+			ExecutionContext::OffchainCall(Some((_, requested))) =>
+				requested.intersection(self.other),
+			ExecutionContext::OffchainCall(None) => self.other,
+		}
+	}
+}
+
+/// All the capabilities for the offchain workers and none in the other cases, the other offchain
+/// calls must be granted their capabilities explicitly.
+impl Default for ExtensionsRegistry {
+	fn default() -> ExtensionsRegistry {
+		ExtensionsRegistry {
+			syncing: offchain::Capabilities::none(),
+			importing: offchain::Capabilities::none(),
+			block_construction: offchain::Capabilities::none(),
+			offchain_worker: offchain::Capabilities::all(),
+			other: offchain::Capabilities::none(),
 		}
 	}
 }
@@ -96,6 +155,7 @@ impl<T: offchain::DbExternalities + Clone + Sync + Send + 'static> DbExternaliti
 /// for each call, based on required `Capabilities`.
 pub struct ExecutionExtensions<Block: traits::Block> {
 	strategies: ExecutionStrategies,
+	registry: ExtensionsRegistry,
 	keystore: Option<SyncCryptoStorePtr>,
 	offchain_db: Option<Box<dyn DbExternalitiesFactory>>,
 	// FIXME: these two are only RwLock because of https://github.com/paritytech/substrate/issues/4587
@@ -112,6 +172,7 @@ impl<Block: traits::Block> Default for ExecutionExtensions<Block> {
 	fn default() -> Self {
 		Self {
 			strategies: Default::default(),
+			registry: Default::default(),
 			keystore: None,
 			offchain_db: None,
 			transaction_pool: RwLock::new(None),
@@ -131,6 +192,7 @@ impl<Block: traits::Block> ExecutionExtensions<Block> {
 		let extensions_factory = Box::new(());
 		Self {
 			strategies,
+			registry: Default::default(),
 			keystore,
 			offchain_db,
 			extensions_factory: RwLock::new(extensions_factory),
@@ -138,11 +200,22 @@ impl<Block: traits::Block> ExecutionExtensions<Block> {
 		}
 	}
 
+	/// Replace the capabilities granted to each execution context.
+	pub fn with_registry(mut self, registry: ExtensionsRegistry) -> Self {
+		self.registry = registry;
+		self
+	}
+
 	/// Get a reference to the execution strategies.
 	pub fn strategies(&self) -> &ExecutionStrategies {
 		&self.strategies
 	}
 
+	/// Get a reference to the capabilities granted to each execution context.
+	pub fn registry(&self) -> &ExtensionsRegistry {
+		&self.registry
+	}
+
 	/// Set the new extensions_factory
 	pub fn set_extensions_factory(&self, maker: Box<dyn ExtensionsFactory>) {
 		*self.extensions_factory.write() = maker;
@@ -155,10 +228,10 @@ impl<Block: traits::Block> ExecutionExtensions<Block> {
 		*self.transaction_pool.write() = Some(Arc::downgrade(&pool) as _);
 	}
 
-	/// Based on the execution context and capabilities it produces
-	/// the extensions object to support desired set of APIs.
+	/// Based on the execution context and the capabilities granted to it by the registry, it
+	/// produces the extensions object to support desired set of APIs.
 	pub fn extensions(&self, at: &BlockId<Block>, context: ExecutionContext) -> Extensions {
-		let capabilities = context.capabilities();
+		let capabilities = self.registry.capabilities_for(&context);
 
 		let mut extensions = self.extensions_factory.read().extensions_for(capabilities);
 
@@ -194,10 +267,12 @@ impl<Block: traits::Block> ExecutionExtensions<Block> {
 			}
 		}
 
-		if let ExecutionContext::OffchainCall(Some(ext)) = context {
-			extensions.register(
-				OffchainWorkerExt::new(offchain::LimitedExternalities::new(capabilities, ext.0)),
-			);
+		match context {
+			ExecutionContext::OffchainCall(Some((ext, _))) | ExecutionContext::OffchainWorker(ext) =>
+				extensions.register(
+					OffchainWorkerExt::new(offchain::LimitedExternalities::new(capabilities, ext)),
+				),
+			_ => {},
 		}
 
 		extensions
@@ -222,7 +297,7 @@ impl<Block: traits::Block> ExecutionExtensions<Block> {
 				self.strategies.syncing.get_manager(),
 			ExecutionContext::Importing =>
 				self.strategies.importing.get_manager(),
-			ExecutionContext::OffchainCall(Some((_, capabilities))) if capabilities.has_all() =>
+			ExecutionContext::OffchainWorker(_) =>
 				self.strategies.offchain_worker.get_manager(),
 			ExecutionContext::OffchainCall(_) =>
 				self.strategies.other.get_manager(),
@@ -251,3 +326,85 @@ impl<Block: traits::Block> offchain::TransactionPool for TransactionPoolAdapter<
 		self.pool.submit_at(&self.at, xt)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::any::TypeId;
+	use sp_keystore::testing::KeyStore;
+	use substrate_test_runtime::Block;
+
+	fn execution_extensions(registry: ExtensionsRegistry) -> ExecutionExtensions<Block> {
+		ExecutionExtensions::new(Default::default(), Some(Arc::new(KeyStore::new())), None)
+			.with_registry(registry)
+	}
+
+	fn has_keystore(extensions: &ExecutionExtensions<Block>, context: ExecutionContext) -> bool {
+		extensions
+			.extensions(&BlockId::Number(0), context)
+			.get_mut(TypeId::of::<KeystoreExt>())
+			.is_some()
+	}
+
+	fn offchain_ext() -> Box<dyn offchain::Externalities> {
+		Box::new(offchain::testing::TestOffchainExt::new().0)
+	}
+
+	#[test]
+	fn default_registry_grants_keystore_to_offchain_workers_only() {
+		let extensions = execution_extensions(Default::default());
+
+		assert!(!has_keystore(&extensions, ExecutionContext::Importing));
+		assert!(!has_keystore(&extensions, ExecutionContext::Syncing));
+		assert!(!has_keystore(&extensions, ExecutionContext::BlockConstruction));
+		assert!(!has_keystore(&extensions, ExecutionContext::OffchainCall(None)));
+		assert!(has_keystore(&extensions, ExecutionContext::OffchainWorker(offchain_ext())));
+	}
+
+	#[test]
+	fn requesting_all_capabilities_does_not_grant_offchain_worker_ones() {
+		let registry = ExtensionsRegistry::default();
+		let context = ExecutionContext::OffchainCall(Some((
+			offchain_ext(),
+			offchain::Capabilities::all(),
+		)));
+
+		assert_eq!(registry.capabilities_for(&context), offchain::Capabilities::none());
+		assert!(!has_keystore(&execution_extensions(registry), context));
+	}
+
+	#[test]
+	fn registry_decides_capabilities_of_each_context() {
+		let registry = ExtensionsRegistry {
+			importing: [offchain::Capability::Keystore][..].into(),
+			other: offchain::Capabilities::none(),
+			..Default::default()
+		};
+		let extensions = execution_extensions(registry);
+
+		assert!(has_keystore(&extensions, ExecutionContext::Importing));
+		assert!(!has_keystore(&extensions, ExecutionContext::OffchainCall(None)));
+	}
+
+	#[test]
+	fn requested_capabilities_are_limited_by_registry() {
+		let registry = ExtensionsRegistry {
+			other: [offchain::Capability::TransactionPool][..].into(),
+			..Default::default()
+		};
+		let requested: offchain::Capabilities = [offchain::Capability::Keystore][..].into();
+		let context = || ExecutionContext::OffchainCall(Some((offchain_ext(), requested)));
+
+		assert_eq!(
+			registry.capabilities_for(&context()),
+			offchain::Capabilities::none(),
+		);
+		assert!(!has_keystore(&execution_extensions(registry), context()));
+
+		let registry = ExtensionsRegistry {
+			other: offchain::Capabilities::rich_offchain_call(),
+			..Default::default()
+		};
+		assert!(has_keystore(&execution_extensions(registry), context()));
+	}
+}
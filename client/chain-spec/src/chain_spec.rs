@@ -24,7 +24,10 @@ use serde::{Serialize, Deserialize};
 use sp_core::{storage::{StorageKey, StorageData, ChildInfo, Storage, StorageChild}, Bytes};
 use sp_runtime::BuildStorage;
 use serde_json as json;
-use crate::{RuntimeGenesis, ChainType, extension::GetExtension, Properties};
+use crate::{
+	RuntimeGenesis, ChainType, extension::GetExtension, Properties,
+	GenesisConfigBuilderRuntimeCaller,
+};
 use sc_network::config::MultiaddrWithPeerId;
 use sc_telemetry::TelemetryEndpoints;
 use sp_runtime::traits::{Block as BlockT, NumberFor};
@@ -264,6 +267,40 @@ impl<G, E> ChainSpec<G, E> {
 		}
 	}
 
+	/// Create a spec whose genesis is built from the default `GenesisConfig` of the runtime
+	/// `code`, with its `GenesisBuilder` api.
+	pub fn from_runtime_code(
+		name: &str,
+		id: &str,
+		chain_type: ChainType,
+		code: &[u8],
+		properties: Option<Properties>,
+		extensions: E,
+	) -> Result<Self, String> {
+		let caller = GenesisConfigBuilderRuntimeCaller::new(code);
+		let storage = caller.get_storage_for_config(caller.get_default_config()?)?;
+
+		let client_spec = ClientSpec {
+			name: name.to_owned(),
+			id: id.to_owned(),
+			chain_type,
+			boot_nodes: Vec::new(),
+			telemetry_endpoints: None,
+			protocol_id: None,
+			properties,
+			extensions,
+			consensus_engine: (),
+			genesis: Default::default(),
+			light_sync_state: None,
+			code_substitutes: HashMap::new(),
+		};
+
+		Ok(ChainSpec {
+			client_spec,
+			genesis: GenesisSource::Storage(storage),
+		})
+	}
+
 	/// Type of the chain.
 	fn chain_type(&self) -> ChainType {
 		self.client_spec.chain_type.clone()
@@ -0,0 +1,89 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Building the genesis state of a runtime from its Wasm blob, with its `GenesisBuilder` api.
+
+use codec::{Decode, Encode};
+use sc_executor::{sp_wasm_interface::HostFunctions, WasmExecutionMethod, WasmExecutor};
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use serde_json as json;
+use sp_core::storage::{well_known_keys, Storage};
+use sp_state_machine::BasicExternalities;
+use std::borrow::Cow;
+
+/// Calls into the `GenesisBuilder` api of a runtime given as a Wasm blob.
+pub struct GenesisConfigBuilderRuntimeCaller<'a> {
+	code: Cow<'a, [u8]>,
+	executor: WasmExecutor,
+}
+
+impl<'a> GenesisConfigBuilderRuntimeCaller<'a> {
+	/// Creates a caller of the runtime `code`.
+	pub fn new(code: &'a [u8]) -> Self {
+		GenesisConfigBuilderRuntimeCaller {
+			code: code.into(),
+			executor: WasmExecutor::new(
+				WasmExecutionMethod::Interpreted,
+				None,
+				sp_io::SubstrateHostFunctions::host_functions(),
+				1,
+				None,
+			),
+		}
+	}
+
+	fn call(
+		&self,
+		ext: &mut BasicExternalities,
+		method: &str,
+		data: &[u8],
+	) -> Result<Vec<u8>, String> {
+		let runtime_blob = RuntimeBlob::uncompress_if_needed(&self.code)
+			.map_err(|e| format!("Invalid runtime code: {}", e))?;
+		self.executor.uncached_call(runtime_blob, ext, false, method, data)
+	}
+
+	/// Returns the default `GenesisConfig` of the runtime.
+	pub fn get_default_config(&self) -> Result<json::Value, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self.call(&mut ext, "GenesisBuilder_create_default_config", &[])?;
+		let default_config = Vec::<u8>::decode(&mut &call_result[..])
+			.map_err(|e| format!("Failed to decode the default config: {}", e))?;
+		json::from_slice(&default_config)
+			.map_err(|e| format!("Invalid JSON default config: {}", e))
+	}
+
+	/// Builds the genesis storage of the runtime from the given `GenesisConfig`.
+	///
+	/// The runtime code is part of the returned storage.
+	pub fn get_storage_for_config(&self, config: json::Value) -> Result<Storage, String> {
+		let mut ext = BasicExternalities::new_empty();
+		let call_result = self.call(
+			&mut ext,
+			"GenesisBuilder_build_config",
+			&config.to_string().into_bytes().encode(),
+		)?;
+		sp_genesis_builder::Result::decode(&mut &call_result[..])
+			.map_err(|e| format!("Failed to decode the genesis build result: {}", e))?
+			.map_err(|e| format!("Failed to build the genesis storage: {}", e))?;
+
+		let mut storage = ext.into_storages();
+		storage.top.insert(well_known_keys::CODE.to_vec(), self.code.to_vec());
+		Ok(storage)
+	}
+}
@@ -109,10 +109,12 @@
 
 mod chain_spec;
 mod extension;
+mod genesis_config_builder;
 
 pub use chain_spec::{
 	ChainSpec as GenericChainSpec, NoExtension, LightSyncState, SerializableLightSyncState,
 };
+pub use genesis_config_builder::GenesisConfigBuilderRuntimeCaller;
 pub use extension::{Group, Fork, Forks, Extension, GetExtension, get_extension};
 pub use sc_chain_spec_derive::{ChainSpecExtension, ChainSpecGroup};
 pub use sp_chain_spec::{Properties, ChainType};
@@ -166,6 +166,38 @@ impl ExecutionStrategy {
 	}
 }
 
+arg_enum! {
+	/// A capability which can be granted to the offchain calls.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	pub enum OffchainCapability {
+		TransactionPool,
+		Http,
+		Keystore,
+		Randomness,
+		NetworkState,
+		OffchainDbRead,
+		OffchainDbWrite,
+		NodeAuthorization,
+	}
+}
+
+impl Into<sp_core::offchain::Capability> for OffchainCapability {
+	fn into(self) -> sp_core::offchain::Capability {
+		use sp_core::offchain::Capability;
+
+		match self {
+			OffchainCapability::TransactionPool => Capability::TransactionPool,
+			OffchainCapability::Http => Capability::Http,
+			OffchainCapability::Keystore => Capability::Keystore,
+			OffchainCapability::Randomness => Capability::Randomness,
+			OffchainCapability::NetworkState => Capability::NetworkState,
+			OffchainCapability::OffchainDbRead => Capability::OffchainDbRead,
+			OffchainCapability::OffchainDbWrite => Capability::OffchainDbWrite,
+			OffchainCapability::NodeAuthorization => Capability::NodeAuthorization,
+		}
+	}
+}
+
 arg_enum! {
 	/// Available RPC methods.
 	#[allow(missing_docs)]
@@ -232,15 +264,7 @@ arg_enum! {
 	}
 }
 
-/// Default value for the `--execution-syncing` parameter.
-pub const DEFAULT_EXECUTION_SYNCING: ExecutionStrategy = ExecutionStrategy::NativeElseWasm;
-/// Default value for the `--execution-import-block` parameter.
-pub const DEFAULT_EXECUTION_IMPORT_BLOCK: ExecutionStrategy = ExecutionStrategy::NativeElseWasm;
-/// Default value for the `--execution-import-block` parameter when the node is a validator.
-pub const DEFAULT_EXECUTION_IMPORT_BLOCK_VALIDATOR: ExecutionStrategy = ExecutionStrategy::Wasm;
-/// Default value for the `--execution-block-construction` parameter.
-pub const DEFAULT_EXECUTION_BLOCK_CONSTRUCTION: ExecutionStrategy = ExecutionStrategy::Wasm;
-/// Default value for the `--execution-offchain-worker` parameter.
-pub const DEFAULT_EXECUTION_OFFCHAIN_WORKER: ExecutionStrategy = ExecutionStrategy::Native;
-/// Default value for the `--execution-other` parameter.
-pub const DEFAULT_EXECUTION_OTHER: ExecutionStrategy = ExecutionStrategy::Native;
+/// Default execution strategy of all the execution contexts.
+///
+/// Native execution must be explicitly opted in for each execution context.
+pub const DEFAULT_EXECUTION: ExecutionStrategy = ExecutionStrategy::Wasm;
@@ -0,0 +1,65 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error;
+use crate::params::{DatabaseParams, SharedParams};
+use crate::CliConfiguration;
+use sc_service::DatabaseConfig;
+use sp_runtime::traits::Block as BlockT;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// The `backup-db` command used to write a consistent copy of the database to a directory.
+///
+/// A RocksDB database can be copied while the node is running, it is opened as a secondary
+/// instance. A ParityDB database can only be copied while the node is stopped.
+#[derive(Debug, StructOpt)]
+pub struct BackupDbCmd {
+	/// Directory to write the copy of the database to, must not exist or be empty.
+	#[structopt(parse(from_os_str))]
+	pub target: PathBuf,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl BackupDbCmd {
+	/// Run the backup-db command
+	pub fn run<B: BlockT>(&self, database_config: DatabaseConfig) -> error::Result<()> {
+		log::info!("Writing a copy of the database to {}", self.target.display());
+		sc_service::backup_database::<B>(&database_config, &self.target)?;
+		log::info!("Database copy written to {}", self.target.display());
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for BackupDbCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
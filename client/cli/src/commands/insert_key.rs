@@ -21,7 +21,7 @@ use crate::{
 	Error, KeystoreParams, CryptoSchemeFlag, SharedParams, utils, with_crypto_scheme,
 	SubstrateCli,
 };
-use std::{sync::Arc, convert::TryFrom};
+use std::{sync::Arc, convert::TryFrom, path::PathBuf};
 use structopt::StructOpt;
 use sp_core::{crypto::KeyTypeId, crypto::SecretString};
 use sp_keystore::{SyncCryptoStorePtr, SyncCryptoStore};
@@ -42,6 +42,9 @@ pub struct InsertKeyCmd {
 	suri: Option<String>,
 
 	/// Key type, examples: "gran", or "imon"
+	///
+	/// The key is stored in the keystore in a file named after the hex encoded key type and
+	/// public key.
 	#[structopt(long)]
 	key_type: String,
 
@@ -62,12 +65,20 @@ impl InsertKeyCmd {
 	/// Run the command
 	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
 		let suri = utils::read_uri(self.suri.as_ref())?;
-		let base_path = self.shared_params
-			.base_path()
-			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
-		let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
-		let chain_spec = cli.load_spec(&chain_id)?;
-		let config_dir = base_path.config_dir(chain_spec.id());
+		let key_type = KeyTypeId::try_from(self.key_type.as_str())
+			.map_err(|_| Error::KeyTypeInvalid)?;
+
+		let config_dir = if self.keystore_params.keystore_path.is_some() {
+			// The keystore is not in the config directory, no need to load the chain spec.
+			PathBuf::new()
+		} else {
+			let base_path = self.shared_params
+				.base_path()
+				.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+			let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+			let chain_spec = cli.load_spec(&chain_id)?;
+			base_path.config_dir(chain_spec.id())
+		};
 
 		let (keystore, public) = match self.keystore_params.keystore_config(&config_dir)? {
 			(_, KeystoreConfig::Path { path, password }) => {
@@ -81,8 +92,6 @@ impl InsertKeyCmd {
 			_ => unreachable!("keystore_config always returns path and password; qed")
 		};
 
-		let key_type = KeyTypeId::try_from(self.key_type.as_str()).map_err(|_| Error::KeyTypeInvalid)?;
-
 		SyncCryptoStore::insert_unknown(&*keystore, key_type, &suri, &public[..])
 			.map_err(|_| Error::KeyStoreOperation)?;
 
@@ -170,4 +179,24 @@ mod tests {
 		).unwrap();
 		assert!(keystore.has_keys(&[(key.public().to_raw_vec(), KeyTypeId(*b"test"))]));
 	}
+
+	#[test]
+	fn insert_with_custom_keystore_path() {
+		let path = TempDir::new().unwrap();
+		let path_str = format!("{}", path.path().display());
+		let (key, uri, _) = Pair::generate_with_phrase(None);
+
+		let insert = InsertKeyCmd::from_iter(
+			&["insert-key", "--keystore-path", &path_str, "--key-type", "test", "--suri", &uri],
+		);
+		assert!(insert.run(&Cli).is_ok());
+
+		let file_name = format!("{}{}", hex::encode(b"test"), hex::encode(key.public()));
+		assert!(path.path().join(file_name).exists());
+
+		let invalid = InsertKeyCmd::from_iter(
+			&["insert-key", "--keystore-path", &path_str, "--key-type", "invalid", "--suri", &uri],
+		);
+		assert!(matches!(invalid.run(&Cli), Err(Error::KeyTypeInvalid)));
+	}
 }
@@ -15,6 +15,7 @@
 
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
+mod backup_db_cmd;
 mod build_spec_cmd;
 mod check_block_cmd;
 mod export_blocks_cmd;
@@ -22,6 +23,7 @@ mod export_state_cmd;
 mod import_blocks_cmd;
 mod purge_chain_cmd;
 mod sign;
+mod sign_transaction;
 mod verify;
 mod vanity;
 mod revert_cmd;
@@ -35,6 +37,7 @@ mod key;
 pub mod utils;
 
 pub use self::{
+	backup_db_cmd::BackupDbCmd,
 	build_spec_cmd::BuildSpecCmd,
 	check_block_cmd::CheckBlockCmd,
 	export_blocks_cmd::ExportBlocksCmd,
@@ -42,6 +45,7 @@ pub use self::{
 	import_blocks_cmd::ImportBlocksCmd,
 	purge_chain_cmd::PurgeChainCmd,
 	sign::SignCmd,
+	sign_transaction::SignTransactionCmd,
 	generate::GenerateCmd,
 	insert_key::InsertKeyCmd,
 	inspect_key::InspectKeyCmd,
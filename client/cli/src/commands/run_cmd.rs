@@ -27,7 +27,7 @@ use crate::params::TransactionPoolParams;
 use crate::CliConfiguration;
 use regex::Regex;
 use sc_service::{
-	config::{BasePath, PrometheusConfig, TransactionPoolOptions},
+	config::{BasePath, MultiaddrWithPeerId, PrometheusConfig, TransactionPoolOptions},
 	ChainSpec, Role,
 };
 use sc_telemetry::TelemetryEndpoints;
@@ -47,6 +47,24 @@ pub struct RunCmd {
 	)]
 	pub validator: bool,
 
+	/// Enable sentry mode.
+	///
+	/// The node will be reported as an authority on the network without participating in any
+	/// consensus task, and will always be connected to the given validators.
+	#[structopt(
+		long = "sentry",
+		value_name = "VALIDATOR_ADDR",
+		conflicts_with_all = &["validator", "light"],
+		min_values = 1,
+	)]
+	pub sentry: Vec<MultiaddrWithPeerId>,
+
+	/// Addresses of the sentry nodes of this validator.
+	///
+	/// The validator only connects to its sentry nodes and to the reserved nodes.
+	#[structopt(long = "sentry-nodes", value_name = "ADDR", conflicts_with = "light")]
+	pub sentry_nodes: Vec<MultiaddrWithPeerId>,
+
 	/// Disable GRANDPA voter when running in validator mode, otherwise disable the GRANDPA observer.
 	#[structopt(long)]
 	pub no_grandpa: bool,
@@ -170,6 +188,10 @@ pub struct RunCmd {
 	#[structopt(flatten)]
 	pub offchain_worker_params: OffchainWorkerParams,
 
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub async_runtime_params: AsyncRuntimeParams,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub shared_params: SharedParams,
@@ -291,6 +313,10 @@ impl CliConfiguration for RunCmd {
 		Some(&self.offchain_worker_params)
 	}
 
+	fn async_runtime_params(&self) -> Option<&AsyncRuntimeParams> {
+		Some(&self.async_runtime_params)
+	}
+
 	fn node_name(&self) -> Result<String> {
 		let name: String = match (self.name.as_ref(), self.get_keyring()) {
 			(Some(name), _) => name.to_string(),
@@ -341,8 +367,10 @@ impl CliConfiguration for RunCmd {
 
 		Ok(if is_light {
 			sc_service::Role::Light
+		} else if !self.sentry.is_empty() {
+			sc_service::Role::Sentry { validators: self.sentry.clone() }
 		} else if is_authority {
-			sc_service::Role::Authority
+			sc_service::Role::Authority { sentry_nodes: self.sentry_nodes.clone() }
 		} else {
 			sc_service::Role::Full
 		})
@@ -597,4 +625,23 @@ mod tests {
 		assert!(is_node_name_valid("www.visit.me").is_err());
 		assert!(is_node_name_valid("email@domain").is_err());
 	}
+
+	#[test]
+	fn sentry_roles() {
+		let addr = "/ip4/127.0.0.1/tcp/30333/p2p/QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV";
+
+		let cmd = RunCmd::from_iter(&["run", "--sentry", addr]);
+		assert!(matches!(
+			cmd.role(false).unwrap(),
+			Role::Sentry { validators } if validators.len() == 1
+		));
+
+		let cmd = RunCmd::from_iter(&["run", "--validator", "--sentry-nodes", addr]);
+		assert!(matches!(
+			cmd.role(false).unwrap(),
+			Role::Authority { sentry_nodes } if sentry_nodes.len() == 1
+		));
+
+		assert!(RunCmd::from_iter_safe(&["run", "--validator", "--sentry", addr]).is_err());
+	}
 }
@@ -0,0 +1,470 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2018-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `sign-transaction` subcommand
+
+use crate::{error, utils, with_crypto_scheme, CryptoSchemeFlag, KeystoreParams};
+use parity_scale_codec::{Compact, Encode};
+use sp_core::{blake2_256, crypto::Ss58Codec, Bytes, H256, Public};
+use sp_runtime::{
+	generic::{self, Era, Header},
+	traits::{BlakeTwo256, IdentifyAccount},
+	AccountId32, MultiAddress, MultiSignature, MultiSigner,
+};
+use sp_version::RuntimeVersion;
+use structopt::StructOpt;
+
+/// The version of the signed extrinsics built by the command.
+const EXTRINSIC_VERSION: u8 = 5;
+
+/// Payloads longer than this are hashed before being signed, like in `SignedPayload`.
+const MAX_UNHASHED_PAYLOAD_LEN: usize = 256;
+
+/// The signed extensions of the Substrate node and node template.
+const DEFAULT_SIGNED_EXTENSIONS: &str =
+	"CheckSpecVersion,CheckTxVersion,CheckGenesis,CheckMortality,CheckNonce,CheckWeight,\
+	ChargeTransactionPayment";
+
+jsonrpsee_proc_macros::rpc_client_api! {
+	RpcApi {
+		#[rpc(method = "system_accountNextIndex", positional_params)]
+		fn account_next_index(account: String) -> u64;
+		#[rpc(method = "chain_getBlockHash", positional_params)]
+		fn block_hash(number: Option<u64>) -> Option<H256>;
+		#[rpc(method = "chain_getFinalizedHead", positional_params)]
+		fn finalized_head() -> H256;
+		#[rpc(method = "chain_getHeader", positional_params)]
+		fn header(hash: Option<H256>) -> Option<Header<u64, BlakeTwo256>>;
+		#[rpc(method = "state_getRuntimeVersion", positional_params)]
+		fn runtime_version() -> RuntimeVersion;
+		#[rpc(method = "author_submitExtrinsic", positional_params)]
+		fn submit_extrinsic(extrinsic: Bytes) -> H256;
+	}
+}
+
+/// The `sign-transaction` command
+///
+/// The signed extrinsic uses a `MultiAddress` as address, a `MultiSignature` as signature and the
+/// signed extensions given with `--signed-extensions`, by default the ones of the Substrate node
+/// and node template.
+#[derive(Debug, StructOpt, Clone)]
+#[structopt(
+	name = "sign-transaction",
+	about = "Sign a call and print the signed extrinsic, optionally submitting it"
+)]
+pub struct SignTransactionCmd {
+	/// The SCALE-encoded call to sign, hex-encoded.
+	#[structopt(long)]
+	call: String,
+
+	/// The secret key URI of the signer.
+	/// If the value is a file, the file content is used as URI.
+	/// If neither this nor `--signer` is given, you will be prompted for the URI.
+	#[structopt(long, conflicts_with = "signer")]
+	suri: Option<String>,
+
+	/// The public key of the signer, hex or SS58-encoded, when the payload is signed elsewhere,
+	/// e.g. on a hardware wallet.
+	#[structopt(long)]
+	signer: Option<String>,
+
+	/// The signature of the payload by `--signer`, hex-encoded.
+	#[structopt(long, requires = "signer")]
+	signature: Option<String>,
+
+	/// Only print the signing payload, hex-encoded, to be signed elsewhere.
+	///
+	/// The payload is printed as is, also when it is longer than 256 bytes and thus hashed
+	/// before being signed.
+	#[structopt(long, conflicts_with = "signature")]
+	payload_only: bool,
+
+	/// The nonce of the signer, fetched from `--url` if not given.
+	#[structopt(long)]
+	nonce: Option<u64>,
+
+	/// The tip for the block author.
+	#[structopt(long, default_value = "0")]
+	tip: u128,
+
+	/// Make the transaction mortal, valid for the given number of blocks after `--block-number`.
+	/// The transaction is immortal if not given.
+	#[structopt(long)]
+	mortality: Option<u64>,
+
+	/// The hash of the block from which a mortal transaction is valid, the last finalized block
+	/// of `--url` if not given.
+	#[structopt(long, requires_all = &["mortality", "block-number"])]
+	block_hash: Option<String>,
+
+	/// The number of the block from which a mortal transaction is valid.
+	#[structopt(long, requires = "block-hash")]
+	block_number: Option<u64>,
+
+	/// The genesis hash of the chain, fetched from `--url` if not given.
+	#[structopt(long)]
+	genesis_hash: Option<String>,
+
+	/// The spec version of the runtime, fetched from `--url` if not given.
+	#[structopt(long)]
+	spec_version: Option<u32>,
+
+	/// The transaction version of the runtime, fetched from `--url` if not given.
+	#[structopt(long)]
+	transaction_version: Option<u32>,
+
+	/// The identifiers of the signed extensions of the runtime, in order.
+	///
+	/// The supported extensions are `CheckSpecVersion`, `CheckTxVersion`, `CheckGenesis`,
+	/// `CheckMortality`, `CheckNonce`, `CheckWeight` and `ChargeTransactionPayment`.
+	#[structopt(long, use_delimiter = true, default_value = DEFAULT_SIGNED_EXTENSIONS)]
+	signed_extensions: Vec<String>,
+
+	/// The websocket URL of a node from which the missing parameters are fetched.
+	#[structopt(long)]
+	url: Option<String>,
+
+	/// Submit the signed extrinsic to `--url`.
+	#[structopt(long, requires = "url", conflicts_with = "payload-only")]
+	submit: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub crypto_scheme: CryptoSchemeFlag,
+}
+
+impl SignTransactionCmd {
+	/// Run the command
+	pub fn run(&self) -> error::Result<()> {
+		let output = self.output()?;
+		println!("0x{}", hex::encode(&output));
+
+		if self.submit {
+			let hash = self.with_rpc(|client| async move {
+				RpcApi::submit_extrinsic(&client, Bytes(output)).await
+			})?;
+			println!("Submitted with hash {:?}", hash);
+		}
+
+		Ok(())
+	}
+
+	/// The signed extrinsic, or the signing payload with `--payload-only`.
+	fn output(&self) -> error::Result<Vec<u8>> {
+		let call = utils::decode_hex(&self.call)?;
+
+		with_crypto_scheme!(
+			self.crypto_scheme.scheme,
+			sign_transaction(self, call)
+		)
+	}
+
+	/// Run `f` with an RPC client connected to `--url`.
+	fn with_rpc<T, F>(&self, f: impl FnOnce(jsonrpsee_ws_client::WsClient) -> F) -> error::Result<T>
+	where
+		F: std::future::Future<Output = Result<T, jsonrpsee_ws_client::Error>>,
+	{
+		let url = self.url.as_ref()
+			.ok_or("Missing parameters, give them explicitly or give `--url` to fetch them")?;
+		let mut runtime = tokio::runtime::Runtime::new()?;
+		runtime.block_on(async {
+			let client = jsonrpsee_ws_client::WsClientBuilder::default().build(url).await?;
+			f(client).await
+		}).map_err(|e| error::Error::Rpc(e.to_string()))
+	}
+
+	/// The mortality of the transaction and the hash of the block it is checked against.
+	fn era(&self, genesis_hash: H256) -> error::Result<(Era, H256)> {
+		let period = match self.mortality {
+			Some(period) => period,
+			None => return Ok((Era::Immortal, genesis_hash)),
+		};
+
+		let (hash, number) = match (&self.block_hash, self.block_number) {
+			(Some(hash), Some(number)) => (parse_hash(hash)?, number),
+			_ => self.with_rpc(|client| async move {
+				let hash = RpcApi::finalized_head(&client).await?;
+				let number = RpcApi::header(&client, Some(hash)).await?.map(|header| header.number);
+				Ok((hash, number))
+			}).and_then(|(hash, number)| {
+				Ok((hash, number.ok_or("The finalized header of `--url` is not available")?))
+			})?,
+		};
+
+		Ok((Era::mortal(period, number), hash))
+	}
+}
+
+fn sign_transaction<P>(cmd: &SignTransactionCmd, call: Vec<u8>) -> error::Result<Vec<u8>>
+where
+	P: sp_core::Pair,
+	P::Public: Into<MultiSigner>,
+	P::Signature: Into<MultiSignature> + Default + AsMut<[u8]>,
+{
+	let (signer, pair) = match &cmd.signer {
+		Some(signer) => (parse_public::<P>(signer)?, None),
+		None => {
+			let suri = utils::read_uri(cmd.suri.as_ref())?;
+			let password = cmd.keystore_params.read_password()?;
+			let pair = utils::pair_from_suri::<P>(&suri, password)?;
+			(pair.public(), Some(pair))
+		},
+	};
+	let account: AccountId32 = signer.clone().into().into_account();
+
+	let genesis_hash = match &cmd.genesis_hash {
+		Some(hash) => parse_hash(hash)?,
+		None => cmd.with_rpc(|client| async move { RpcApi::block_hash(&client, Some(0)).await })?
+			.ok_or("The genesis hash of `--url` is not available")?,
+	};
+	let (spec_version, transaction_version) = match (cmd.spec_version, cmd.transaction_version) {
+		(Some(spec_version), Some(transaction_version)) => (spec_version, transaction_version),
+		_ => {
+			let version = cmd.with_rpc(|client| async move {
+				RpcApi::runtime_version(&client).await
+			})?;
+			(
+				cmd.spec_version.unwrap_or(version.spec_version),
+				cmd.transaction_version.unwrap_or(version.transaction_version),
+			)
+		},
+	};
+	let nonce = match cmd.nonce {
+		Some(nonce) => nonce,
+		None => {
+			let account = account.to_ss58check();
+			cmd.with_rpc(|client| async move {
+				RpcApi::account_next_index(&client, account).await
+			})?
+		},
+	};
+	let (era, era_hash) = cmd.era(genesis_hash)?;
+
+	// The data of the signed extensions included in the extrinsic, and the data which are only
+	// signed.
+	let mut extra = Vec::new();
+	let mut additional_signed = Vec::new();
+	for extension in &cmd.signed_extensions {
+		match extension.as_str() {
+			"CheckSpecVersion" => spec_version.encode_to(&mut additional_signed),
+			"CheckTxVersion" => transaction_version.encode_to(&mut additional_signed),
+			"CheckGenesis" => genesis_hash.encode_to(&mut additional_signed),
+			"CheckMortality" => {
+				era.encode_to(&mut extra);
+				era_hash.encode_to(&mut additional_signed);
+			},
+			"CheckNonce" => Compact(nonce).encode_to(&mut extra),
+			"CheckWeight" => {},
+			"ChargeTransactionPayment" => Compact(cmd.tip).encode_to(&mut extra),
+			other => return Err(format!("Unsupported signed extension `{}`", other).into()),
+		}
+	}
+	let payload = [&call[..], &extra[..], &additional_signed[..]].concat();
+
+	if cmd.payload_only {
+		return Ok(payload)
+	}
+
+	let signature = match (&cmd.signature, pair) {
+		(Some(signature), _) => parse_signature::<P>(signature)?,
+		(None, Some(pair)) => if payload.len() > MAX_UNHASHED_PAYLOAD_LEN {
+			pair.sign(&blake2_256(&payload))
+		} else {
+			pair.sign(&payload)
+		},
+		(None, None) => return Err("Either `--suri` or `--signature` must be given".into()),
+	};
+	let valid = if payload.len() > MAX_UNHASHED_PAYLOAD_LEN {
+		P::verify(&signature, &blake2_256(&payload)[..], &signer)
+	} else {
+		P::verify(&signature, &payload, &signer)
+	};
+	if !valid {
+		return Err(error::Error::SignatureInvalid)
+	}
+
+	let mut extrinsic = vec![EXTRINSIC_VERSION | 0b1000_0000, generic::EXTENSION_VERSION];
+	MultiAddress::<AccountId32, ()>::Id(account).encode_to(&mut extrinsic);
+	signature.into().encode_to(&mut extrinsic);
+	extrinsic.extend(extra);
+	extrinsic.extend(call);
+	// The extrinsic is prefixed with its length.
+	Ok(extrinsic.encode())
+}
+
+fn parse_hash(hash: &str) -> error::Result<H256> {
+	let hash = utils::decode_hex(hash)?;
+	if hash.len() != H256::len_bytes() {
+		return Err(format!("Invalid hash length, expected {} bytes", H256::len_bytes()).into())
+	}
+	Ok(H256::from_slice(&hash))
+}
+
+fn parse_public<P: sp_core::Pair>(public: &str) -> error::Result<P::Public> {
+	if public.starts_with("0x") {
+		let public_data = utils::decode_hex(public)?;
+		let mut public = P::Public::default();
+		if public_data.len() != public.as_ref().len() {
+			return Err(format!(
+				"Invalid public key length, expected {} bytes",
+				public.as_ref().len(),
+			).into())
+		}
+		public.as_mut().copy_from_slice(&public_data);
+		Ok(public)
+	} else {
+		Ok(P::Public::from_ss58check(public)?)
+	}
+}
+
+fn parse_signature<P>(signature: &str) -> error::Result<P::Signature>
+where
+	P: sp_core::Pair,
+	P::Signature: Default + AsMut<[u8]>,
+{
+	let signature_data = utils::decode_hex(signature)?;
+	let mut signature = P::Signature::default();
+	if signature_data.len() != signature.as_ref().len() {
+		return Err(error::Error::SignatureInvalidLength {
+			read: signature_data.len(),
+			expected: signature.as_ref().len(),
+		})
+	}
+	signature.as_mut().copy_from_slice(&signature_data);
+	Ok(signature)
+}
+
+#[cfg(test)]
+mod test {
+	use super::SignTransactionCmd;
+	use parity_scale_codec::{Compact, Decode, Encode};
+	use sp_core::{sr25519, Pair, H256};
+	use sp_runtime::{
+		generic::{Era, UncheckedExtrinsic},
+		traits::{SignedExtension, Verify},
+		transaction_validity::TransactionValidityError,
+		AccountId32, MultiAddress, MultiSignature,
+	};
+	use structopt::StructOpt;
+
+	const GENESIS_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+	/// The data of the default signed extensions which is included in the extrinsic.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+	struct Extra(Era, Compact<u64>, Compact<u128>);
+
+	impl SignedExtension for Extra {
+		const IDENTIFIER: &'static str = "Extra";
+		type AccountId = AccountId32;
+		type Call = ();
+		type AdditionalSigned = ();
+		type Pre = ();
+
+		fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+			Ok(())
+		}
+	}
+
+	type Extrinsic =
+		UncheckedExtrinsic<MultiAddress<AccountId32, ()>, (u8, u8), MultiSignature, Extra>;
+
+	fn alice() -> sr25519::Pair {
+		sr25519::Pair::from_string("//Alice", None).unwrap()
+	}
+
+	fn offline_cmd(extra_args: &[&str]) -> SignTransactionCmd {
+		let mut args = vec![
+			"sign-transaction",
+			"--call", "0x0001",
+			"--nonce", "5",
+			"--genesis-hash", GENESIS_HASH,
+			"--spec-version", "100",
+			"--transaction-version", "1",
+		];
+		args.extend(extra_args);
+		SignTransactionCmd::from_iter(&args)
+	}
+
+	/// Decode the signed extrinsic `output` and check that it is signed by Alice with `era`.
+	fn assert_signed_by_alice(output: Vec<u8>, era: Era) {
+		let genesis_hash = H256::from_low_u64_be(1);
+		let extrinsic = Extrinsic::decode(&mut &output[..]).unwrap();
+		let (address, signature, extra) = extrinsic.signature.unwrap();
+		let alice = AccountId32::from(alice().public());
+
+		assert_eq!(address, MultiAddress::Id(alice.clone()));
+		assert_eq!(extrinsic.function, (0, 1));
+		assert_eq!(extra, Extra(era, Compact(5), Compact(0)));
+
+		let additional_signed = (100u32, 1u32, genesis_hash, genesis_hash);
+		let payload = (extrinsic.function, extra, additional_signed).encode();
+		assert!(signature.verify(&payload[..], &alice));
+	}
+
+	#[test]
+	fn sign_transaction_offline() {
+		let output = offline_cmd(&["--suri", "//Alice"]).output().unwrap();
+		assert_signed_by_alice(output, Era::Immortal);
+
+		let output = offline_cmd(&[
+			"--suri", "//Alice",
+			"--mortality", "64",
+			"--block-hash", GENESIS_HASH,
+			"--block-number", "0",
+		]).output().unwrap();
+		assert_signed_by_alice(output, Era::mortal(64, 0));
+	}
+
+	#[test]
+	fn external_signature_is_verified() {
+		let alice_ss58 = "5GrwvaEF5zXb26Fz9rcQpDWS55tT4ySA3hkchWxDXPDhmo3v";
+		let payload = offline_cmd(&["--signer", alice_ss58, "--payload-only"]).output().unwrap();
+		let signature = format!("0x{}", hex::encode(alice().sign(&payload)));
+		let output = offline_cmd(&["--signer", alice_ss58, "--signature", &signature])
+			.output()
+			.unwrap();
+		assert_signed_by_alice(output, Era::Immortal);
+
+		let invalid_signature = format!("0x{}", "00".repeat(64));
+		let cmd = offline_cmd(&["--signer", alice_ss58, "--signature", &invalid_signature]);
+		assert!(cmd.output().is_err());
+	}
+
+	#[test]
+	fn signer_public_key_length_is_checked() {
+		let cmd = offline_cmd(&["--signer", "0x0102", "--payload-only"]);
+		assert!(cmd.output().is_err());
+	}
+
+	#[test]
+	fn signed_extensions_are_configurable() {
+		let payload = offline_cmd(&[
+			"--suri", "//Alice",
+			"--payload-only",
+			"--signed-extensions", "CheckGenesis,CheckNonce",
+		]).output().unwrap();
+		assert_eq!(payload, ((0u8, 1u8), Compact(5u64), H256::from_low_u64_be(1)).encode());
+
+		let cmd = offline_cmd(&["--suri", "//Alice", "--signed-extensions", "CheckUnknown"]);
+		assert!(cmd.output().is_err());
+	}
+}
@@ -112,6 +112,11 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		None
 	}
 
+	/// Get the AsyncRuntimeParams for this object
+	fn async_runtime_params(&self) -> Option<&AsyncRuntimeParams> {
+		None
+	}
+
 	/// Get the NodeKeyParams for this object
 	fn node_key_params(&self) -> Option<&NodeKeyParams> {
 		self.network_params().map(|x| &x.node_key_params)
@@ -247,6 +252,15 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			.unwrap_or_default())
 	}
 
+	/// Get the maximum size of the trie cache.
+	///
+	/// By default this is retrieved from `ImportParams` if it is available. Otherwise the trie
+	/// cache is disabled.
+	fn trie_cache_maximum_size(&self) -> Result<Option<usize>> {
+		Ok(self.import_params()
+			.and_then(|x| x.trie_cache_maximum_size()))
+	}
+
 	/// Get the state cache child ratio (if any).
 	///
 	/// By default this is `None`.
@@ -310,15 +324,11 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 	/// Get the execution strategies.
 	///
 	/// By default this is retrieved from `ImportParams` if it is available. Otherwise its
-	/// `ExecutionStrategies::default()`.
-	fn execution_strategies(
-		&self,
-		is_dev: bool,
-		is_validator: bool,
-	) -> Result<ExecutionStrategies> {
+	/// `ExecutionStrategies::default()`, i.e. wasm for every execution context.
+	fn execution_strategies(&self) -> Result<ExecutionStrategies> {
 		Ok(self
 			.import_params()
-			.map(|x| x.execution_strategies(is_dev, is_validator))
+			.map(|x| x.execution_strategies())
 			.unwrap_or_default())
 	}
 
@@ -453,9 +463,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 	///
 	/// By default this is retrieved from `NodeKeyParams` if it is available. Otherwise its
 	/// `NodeKeyConfig::default()`.
-	fn node_key(&self, net_config_dir: &PathBuf) -> Result<NodeKeyConfig> {
+	fn node_key(
+		&self,
+		net_config_dir: &PathBuf,
+		keystore: &KeystoreConfig,
+	) -> Result<NodeKeyConfig> {
 		self.node_key_params()
-			.map(|x| x.node_key(net_config_dir))
+			.map(|x| x.node_key(net_config_dir, keystore))
 			.unwrap_or_else(|| Ok(Default::default()))
 	}
 
@@ -490,11 +504,11 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let client_id = C::client_id();
 		let database_cache_size = self.database_cache_size()?.unwrap_or(128);
 		let database = self.database()?.unwrap_or(Database::RocksDb);
-		let node_key = self.node_key(&net_config_dir)?;
+		let (keystore_remote, keystore) = self.keystore_config(&config_dir)?;
+		let node_key = self.node_key(&net_config_dir, &keystore)?;
 		let role = self.role(is_dev)?;
 		let max_runtime_instances = self.max_runtime_instances()?.unwrap_or(8);
 		let is_validator = role.is_authority();
-		let (keystore_remote, keystore) = self.keystore_config(&config_dir)?;
 		let telemetry_endpoints = self.telemetry_endpoints(&chain_spec)?;
 
 		let unsafe_pruning = self
@@ -522,12 +536,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			database: self.database_config(&config_dir, database_cache_size, database)?,
 			state_cache_size: self.state_cache_size()?,
 			state_cache_child_ratio: self.state_cache_child_ratio()?,
+			trie_cache_maximum_size: self.trie_cache_maximum_size()?,
 			state_pruning: self.state_pruning(unsafe_pruning, &role)?,
 			keep_blocks: self.keep_blocks()?,
 			transaction_storage: self.database_transaction_storage()?,
 			wasm_method: self.wasm_method()?,
 			wasm_runtime_overrides: self.wasm_runtime_overrides(),
-			execution_strategies: self.execution_strategies(is_dev, is_validator)?,
+			execution_strategies: self.execution_strategies()?,
 			rpc_http: self.rpc_http(DCV::rpc_http_listen_port())?,
 			rpc_ws: self.rpc_ws(DCV::rpc_ws_listen_port())?,
 			rpc_ipc: self.rpc_ipc()?,
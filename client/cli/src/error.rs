@@ -71,6 +71,9 @@ pub enum Error {
 	#[error("Key storage issue encountered")]
 	KeyStorage(#[from] sc_keystore::Error),
 
+	#[error("RPC request failed: {0}")]
+	Rpc(String),
+
 	#[error("Invalid hexadecimal string data")]
 	HexDataConversion(#[from] hex::FromHexError),
 
@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error;
+use structopt::StructOpt;
+
+/// Parameters of the async runtime which executes the tasks of the node.
+#[derive(Debug, StructOpt, Clone, Default)]
+pub struct AsyncRuntimeParams {
+	/// Number of threads of the async runtime which execute the non-blocking tasks.
+	///
+	/// Defaults to the number of CPU cores.
+	#[structopt(long = "tokio-worker-threads", value_name = "COUNT")]
+	pub worker_threads: Option<usize>,
+
+	/// Maximum number of threads of the async runtime which execute the blocking tasks, e.g. the
+	/// block import or the network worker.
+	///
+	/// Defaults to 512 threads minus the worker threads, the default of the async runtime.
+	#[structopt(long = "tokio-max-blocking-threads", value_name = "COUNT")]
+	pub max_blocking_threads: Option<usize>,
+}
+
+impl AsyncRuntimeParams {
+	/// Build the async runtime with these parameters.
+	pub fn build_runtime(&self) -> error::Result<tokio::runtime::Runtime> {
+		if self.worker_threads == Some(0) {
+			return Err(error::Error::Input("The number of worker threads can't be 0".into()))
+		}
+		if self.max_blocking_threads == Some(0) {
+			return Err(error::Error::Input("The number of blocking threads can't be 0".into()))
+		}
+
+		Ok(crate::build_runtime_with(self.worker_threads, self.max_blocking_threads)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn thread_counts_are_parsed() {
+		let params = AsyncRuntimeParams::from_iter(&[
+			"test",
+			"--tokio-worker-threads",
+			"2",
+			"--tokio-max-blocking-threads",
+			"8",
+		]);
+		assert_eq!(params.worker_threads, Some(2));
+		assert_eq!(params.max_blocking_threads, Some(8));
+		assert!(params.build_runtime().is_ok());
+	}
+
+	#[test]
+	fn zero_threads_are_rejected() {
+		let params = AsyncRuntimeParams { worker_threads: Some(0), max_blocking_threads: None };
+		assert!(params.build_runtime().is_err());
+
+		let params = AsyncRuntimeParams { worker_threads: None, max_blocking_threads: Some(0) };
+		assert!(params.build_runtime().is_err());
+	}
+}
@@ -16,11 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arg_enums::{
-	ExecutionStrategy, WasmExecutionMethod, DEFAULT_EXECUTION_BLOCK_CONSTRUCTION,
-	DEFAULT_EXECUTION_IMPORT_BLOCK, DEFAULT_EXECUTION_IMPORT_BLOCK_VALIDATOR,
-	DEFAULT_EXECUTION_OFFCHAIN_WORKER, DEFAULT_EXECUTION_OTHER, DEFAULT_EXECUTION_SYNCING,
-};
+use crate::arg_enums::{ExecutionStrategy, WasmExecutionMethod, DEFAULT_EXECUTION};
 use crate::params::DatabaseParams;
 use crate::params::PruningParams;
 use sc_client_api::execution_extensions::ExecutionStrategies;
@@ -79,6 +75,17 @@ pub struct ImportParams {
 		default_value = "67108864"
 	)]
 	pub state_cache_size: usize,
+
+	/// Specify the size of the cache of trie nodes shared by block import, RPC and block
+	/// authorship.
+	///
+	/// Providing `0` disables the cache.
+	#[structopt(
+		long = "trie-cache-size",
+		value_name = "Bytes",
+		default_value = "67108864"
+	)]
+	pub trie_cache_size: usize,
 }
 
 impl ImportParams {
@@ -87,6 +94,15 @@ impl ImportParams {
 		self.state_cache_size
 	}
 
+	/// Specify the maximum size of the trie cache, `None` if it is disabled.
+	pub fn trie_cache_maximum_size(&self) -> Option<usize> {
+		if self.trie_cache_size == 0 {
+			None
+		} else {
+			Some(self.trie_cache_size)
+		}
+	}
+
 	/// Get the WASM execution method from the parameters
 	pub fn wasm_method(&self) -> sc_service::config::WasmExecutionMethod {
 		self.wasm_method.into()
@@ -98,38 +114,30 @@ impl ImportParams {
 		self.wasm_runtime_overrides.clone()
 	}
 
-	/// Get execution strategies for the parameters
-	pub fn execution_strategies(&self, is_dev: bool, is_validator: bool) -> ExecutionStrategies {
+	/// Get execution strategies for the parameters.
+	///
+	/// Every execution context uses [`DEFAULT_EXECUTION`], i.e. wasm, unless a strategy is given
+	/// for it or for all the contexts.
+	pub fn execution_strategies(&self) -> ExecutionStrategies {
 		let exec = &self.execution_strategies;
-		let exec_all_or = |strat: Option<ExecutionStrategy>, default: ExecutionStrategy| {
-			let default = if is_dev {
-				ExecutionStrategy::Native
-			} else {
-				default
-			};
-
-			exec.execution.unwrap_or_else(|| strat.unwrap_or(default)).into()
-		};
-
-		let default_execution_import_block = if is_validator {
-			DEFAULT_EXECUTION_IMPORT_BLOCK_VALIDATOR
-		} else {
-			DEFAULT_EXECUTION_IMPORT_BLOCK
+		let exec_all_or = |strat: Option<ExecutionStrategy>| {
+			exec.execution.or(strat).unwrap_or(DEFAULT_EXECUTION).into()
 		};
 
 		ExecutionStrategies {
-			syncing: exec_all_or(exec.execution_syncing, DEFAULT_EXECUTION_SYNCING),
-			importing: exec_all_or(exec.execution_import_block, default_execution_import_block),
-			block_construction:
-				exec_all_or(exec.execution_block_construction, DEFAULT_EXECUTION_BLOCK_CONSTRUCTION),
-			offchain_worker:
-				exec_all_or(exec.execution_offchain_worker, DEFAULT_EXECUTION_OFFCHAIN_WORKER),
-			other: exec_all_or(exec.execution_other, DEFAULT_EXECUTION_OTHER),
+			syncing: exec_all_or(exec.execution_syncing),
+			importing: exec_all_or(exec.execution_import_block),
+			block_construction: exec_all_or(exec.execution_block_construction),
+			offchain_worker: exec_all_or(exec.execution_offchain_worker),
+			other: exec_all_or(exec.execution_other),
 		}
 	}
 }
 
 /// Execution strategies parameters.
+///
+/// All the execution contexts use the wasm runtime by default, the native runtime must be
+/// explicitly opted in for each context, or for all of them with `--execution`.
 #[derive(Debug, StructOpt, Clone)]
 pub struct ExecutionStrategiesParams {
 	/// The means of execution used when calling into the runtime for importing blocks as
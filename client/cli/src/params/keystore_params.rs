@@ -45,6 +45,8 @@ pub struct KeystoreParams {
 	pub password_interactive: bool,
 
 	/// Password used by the keystore.
+	///
+	/// The keys written to the keystore are encrypted with this password.
 	#[structopt(
 		long = "password",
 		parse(try_from_str = secret_string_from_str),
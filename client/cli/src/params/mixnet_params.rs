@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use sc_mixnet::Config;
+use structopt::StructOpt;
+
+/// Parameters used to configure the mixnet service.
+#[derive(Debug, StructOpt, Clone)]
+pub struct MixnetParams {
+	/// Enable the mixnet service.
+	///
+	/// This allows submitting extrinsics anonymously through the `mixnet_submitExtrinsic` RPC,
+	/// and forwarding the packets of the other nodes.
+	#[structopt(long = "mixnet")]
+	pub enabled: bool,
+
+	/// Register as a mixnode when the node is an authority of the following session.
+	///
+	/// Implies `--mixnet`.
+	#[structopt(long = "mixnode")]
+	pub mixnode: bool,
+
+	/// The address at which the mixnode can be reached, published in its registrations.
+	///
+	/// Can be given multiple times.
+	#[structopt(long = "mixnode-external-address", value_name = "MULTIADDR")]
+	pub external_addresses: Vec<String>,
+
+	/// The number of mixnodes through which the extrinsics are routed.
+	#[structopt(long = "mixnet-num-hops", value_name = "COUNT", default_value = "3")]
+	pub num_hops: usize,
+}
+
+impl MixnetParams {
+	/// The configuration of the mixnet service, or `None` if it is disabled.
+	pub fn config(&self) -> Option<Config> {
+		if !self.enabled && !self.mixnode {
+			return None
+		}
+		Some(Config {
+			mixnode: self.mixnode,
+			external_addresses: self.external_addresses.clone(),
+			num_hops: self.num_hops,
+			..Default::default()
+		})
+	}
+}
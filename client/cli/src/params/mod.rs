@@ -15,9 +15,11 @@
 
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
+mod async_runtime_params;
 mod database_params;
 mod import_params;
 mod keystore_params;
+mod mixnet_params;
 mod network_params;
 mod node_key_params;
 mod offchain_worker_params;
@@ -31,9 +33,11 @@ use sp_core::crypto::Ss58AddressFormat;
 use crate::arg_enums::{OutputType, CryptoScheme};
 use structopt::StructOpt;
 
+pub use crate::params::async_runtime_params::*;
 pub use crate::params::database_params::*;
 pub use crate::params::import_params::*;
 pub use crate::params::keystore_params::*;
+pub use crate::params::mixnet_params::*;
 pub use crate::params::network_params::*;
 pub use crate::params::node_key_params::*;
 pub use crate::params::offchain_worker_params::*;
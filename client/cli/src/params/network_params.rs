@@ -18,7 +18,10 @@
 
 use crate::params::node_key_params::NodeKeyParams;
 use sc_network::{
-	config::{NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, SetConfig, TransportConfig},
+	config::{
+		NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, SetConfig, TransportConfig,
+		TransportKind,
+	},
 	multiaddr::Protocol,
 };
 use sc_service::{ChainSpec, ChainType, config::{Multiaddr, MultiaddrWithPeerId}};
@@ -29,6 +32,8 @@ use structopt::StructOpt;
 #[derive(Debug, StructOpt, Clone)]
 pub struct NetworkParams {
 	/// Specify a list of bootnodes.
+	///
+	/// DNS addresses are resolved again periodically and after repeated dial failures.
 	#[structopt(long = "bootnodes", value_name = "ADDR")]
 	pub bootnodes: Vec<MultiaddrWithPeerId>,
 
@@ -125,6 +130,18 @@ pub struct NetworkParams {
 	/// Join the IPFS network and serve transactions over bitswap protocol.
 	#[structopt(long)]
 	pub ipfs_server: bool,
+
+	/// The transports whose addresses are dialed first when connecting to a peer, most preferred
+	/// first, e.g. `ws,tcp`.
+	///
+	/// The addresses of the other transports are dialed last.
+	#[structopt(
+		long = "dial-preference",
+		value_name = "TRANSPORTS",
+		use_delimiter = true,
+		default_value = "tcp,ws"
+	)]
+	pub dial_preference: Vec<TransportKind>,
 }
 
 impl NetworkParams {
@@ -215,6 +232,7 @@ impl NetworkParams {
 			max_parallel_downloads: self.max_parallel_downloads,
 			enable_dht_random_walk: !self.reserved_only,
 			allow_non_globals_in_dht,
+			dial_preference: self.dial_preference.clone(),
 			kademlia_disjoint_query_paths: self.kademlia_disjoint_query_paths,
 			yamux_window_size: None,
 			ipfs_server: self.ipfs_server,
@@ -16,8 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use sc_keystore::LocalKeystore;
 use sc_network::{config::identity::ed25519, config::NodeKeyConfig};
-use sp_core::H256;
+use sc_service::config::KeystoreConfig;
+use sp_core::{H256, crypto::key_types::NODE_KEY};
+use sp_keystore::SyncCryptoStore;
 use std::{path::PathBuf, str::FromStr};
 use structopt::StructOpt;
 
@@ -60,6 +63,9 @@ pub struct NodeKeyParams {
 	///   * If the `--node-key-file` option is given, the secret key is read from the
 	///     specified file. See the documentation for `--node-key-file`.
 	///
+	///   * If the `--node-key-from-keystore` flag is given, the secret key is read from
+	///     the keystore. See the documentation for `--node-key-from-keystore`.
+	///
 	///   * Otherwise, the secret key is read from a file with a predetermined,
 	///     type-specific name from the chain-specific network config directory
 	///     inside the base directory specified by `--base-dir`. If this file does
@@ -86,19 +92,40 @@ pub struct NodeKeyParams {
 	///   The file must contain an unencoded 32 byte or hex encoded Ed25519 secret key.
 	///
 	/// If the file does not exist, it is created with a newly generated secret key of
-	/// the chosen type.
+	/// the chosen type, readable by its owner only. The file is then reused on every
+	/// start, so that the peer ID of the node, as returned by `system_localPeerId`,
+	/// stays the same across restarts and re-deployments.
+	///
+	/// On unix, a file which is readable by all users is deprecated and a warning is logged.
 	#[structopt(long = "node-key-file", value_name = "FILE")]
 	pub node_key_file: Option<PathBuf>,
+
+	/// Use an Ed25519 key of the keystore as the node's secret key for libp2p networking.
+	///
+	/// The first key of the `node` key type of the keystore is used. If there is none, a
+	/// new key is generated in the keystore. The peer ID of the node then follows the
+	/// keystore, e.g. when the keystore is restored on another machine.
+	#[structopt(
+		long = "node-key-from-keystore",
+		conflicts_with_all = &["node-key", "node-key-file"]
+	)]
+	pub node_key_from_keystore: bool,
 }
 
 impl NodeKeyParams {
 	/// Create a `NodeKeyConfig` from the given `NodeKeyParams` in the context
-	/// of an optional network config storage directory.
-	pub fn node_key(&self, net_config_dir: &PathBuf) -> error::Result<NodeKeyConfig> {
+	/// of an optional network config storage directory and of the keystore.
+	pub fn node_key(
+		&self,
+		net_config_dir: &PathBuf,
+		keystore: &KeystoreConfig,
+	) -> error::Result<NodeKeyConfig> {
 		Ok(match self.node_key_type {
 			NodeKeyType::Ed25519 => {
 				let secret = if let Some(node_key) = self.node_key.as_ref() {
 					parse_ed25519_secret(node_key)?
+				} else if self.node_key_from_keystore {
+					sc_network::config::Secret::Input(keystore_ed25519_secret(keystore)?)
 				} else {
 					sc_network::config::Secret::File(
 						self.node_key_file
@@ -118,6 +145,28 @@ fn invalid_node_key(e: impl std::fmt::Display) -> error::Error {
 	error::Error::Input(format!("Invalid node key: {}", e))
 }
 
+/// Get the Ed25519 secret key of the `node` key type from the keystore, generating it in the
+/// keystore if there is none.
+fn keystore_ed25519_secret(keystore: &KeystoreConfig) -> error::Result<ed25519::SecretKey> {
+	let keystore = match keystore {
+		KeystoreConfig::Path { path, password } =>
+			LocalKeystore::open(path.clone(), password.clone()).map_err(invalid_node_key)?,
+		KeystoreConfig::InMemory =>
+			return Err(invalid_node_key("the keystore is in memory, it doesn't persist keys")),
+	};
+
+	let public = match SyncCryptoStore::ed25519_public_keys(&keystore, NODE_KEY).into_iter().next() {
+		Some(public) => public,
+		None => SyncCryptoStore::ed25519_generate_new(&keystore, NODE_KEY, None)
+			.map_err(invalid_node_key)?,
+	};
+	let pair = keystore.ed25519_key_pair(NODE_KEY, &public)
+		.map_err(invalid_node_key)?
+		.ok_or_else(|| invalid_node_key("the key is missing from the keystore"))?;
+
+	ed25519::SecretKey::from_bytes(pair.seed().to_vec()).map_err(invalid_node_key)
+}
+
 /// Parse a Ed25519 secret key from a hex string into a `sc_network::Secret`.
 fn parse_ed25519_secret(hex: &str) -> error::Result<sc_network::config::Ed25519Secret> {
 	H256::from_str(&hex)
@@ -147,8 +196,9 @@ mod tests {
 					node_key_type,
 					node_key: Some(format!("{:x}", H256::from_slice(sk.as_ref()))),
 					node_key_file: None,
+					node_key_from_keystore: false,
 				};
-				params.node_key(net_config_dir).and_then(|c| match c {
+				params.node_key(net_config_dir, &KeystoreConfig::InMemory).and_then(|c| match c {
 					NodeKeyConfig::Ed25519(sc_network::config::Secret::Input(ref ski))
 						if node_key_type == NodeKeyType::Ed25519 && &sk[..] == ski.as_ref() =>
 					{
@@ -169,9 +219,10 @@ mod tests {
 				node_key_type: NodeKeyType::Ed25519,
 				node_key: None,
 				node_key_file: Some(file),
+				node_key_from_keystore: false,
 			};
 
-			let node_key = params.node_key(&PathBuf::from("not-used"))
+			let node_key = params.node_key(&PathBuf::from("not-used"), &KeystoreConfig::InMemory)
 				.expect("Creates node key config")
 				.into_keypair()
 				.expect("Creates node key pair");
@@ -206,6 +257,7 @@ mod tests {
 					node_key_type,
 					node_key: None,
 					node_key_file: None,
+					node_key_from_keystore: false,
 				})
 			})
 		}
@@ -215,7 +267,7 @@ mod tests {
 				let dir = PathBuf::from(net_config_dir.clone());
 				let typ = params.node_key_type;
 				params
-					.node_key(net_config_dir)
+					.node_key(net_config_dir, &KeystoreConfig::InMemory)
 					.and_then(move |c| match c {
 						NodeKeyConfig::Ed25519(sc_network::config::Secret::File(ref f))
 						if typ == NodeKeyType::Ed25519
@@ -230,4 +282,41 @@ mod tests {
 
 		assert!(some_config_dir(&PathBuf::from_str("x").unwrap()).is_ok());
 	}
+
+	#[test]
+	fn test_node_key_config_keystore() {
+		let tmp = tempfile::Builder::new().prefix("alice").tempdir().expect("Creates tempfile");
+		let keystore = KeystoreConfig::Path { path: tmp.path().join("keystore"), password: None };
+		let params = NodeKeyParams {
+			node_key_type: NodeKeyType::Ed25519,
+			node_key: None,
+			node_key_file: None,
+			node_key_from_keystore: true,
+		};
+		let peer_id = |params: &NodeKeyParams| params
+			.node_key(&PathBuf::from("not-used"), &keystore)
+			.expect("Creates node key config")
+			.into_keypair()
+			.expect("Creates node key pair")
+			.public()
+			.into_peer_id();
+
+		// The key is generated in the keystore, and reused afterwards.
+		let generated = peer_id(&params);
+		assert_eq!(peer_id(&params), generated);
+
+		let public = LocalKeystore::open(tmp.path().join("keystore"), None)
+			.unwrap()
+			.ed25519_public_keys(NODE_KEY);
+		assert_eq!(public.len(), 1);
+		assert_eq!(
+			generated,
+			sc_network::config::PublicKey::Ed25519(
+				ed25519::PublicKey::decode(public[0].as_ref()).unwrap(),
+			).into_peer_id(),
+		);
+
+		// An in-memory keystore doesn't keep the node key.
+		assert!(params.node_key(&PathBuf::from("not-used"), &KeystoreConfig::InMemory).is_err());
+	}
 }
@@ -23,12 +23,14 @@
 //! targeted at handling input parameter parsing providing
 //! a reasonable abstraction.
 
+use sc_client_api::execution_extensions::ExtensionsRegistry;
 use sc_network::config::Role;
 use sc_service::config::OffchainWorkerConfig;
+use sp_core::offchain::Capability;
 use structopt::StructOpt;
 
 use crate::error;
-use crate::OffchainWorkerEnabled;
+use crate::{OffchainCapability, OffchainWorkerEnabled};
 
 /// Offchain worker related parameters.
 #[derive(Debug, StructOpt, Clone)]
@@ -54,6 +56,19 @@ pub struct OffchainWorkerParams {
 		value_name = "ENABLE_OFFCHAIN_INDEXING"
 	)]
 	pub indexing_enabled: bool,
+
+	/// Grant a capability to the offchain calls which are not made by the offchain workers, e.g.
+	/// those made through RPC.
+	///
+	/// They are granted no capability by default. For example the MMR RPC requires
+	/// `OffchainDbRead`. Can be given multiple times.
+	#[structopt(
+		long = "offchain-call-capability",
+		value_name = "CAPABILITY",
+		possible_values = &OffchainCapability::variants(),
+		case_insensitive = true,
+	)]
+	pub offchain_call_capabilities: Vec<OffchainCapability>,
 }
 
 impl OffchainWorkerParams {
@@ -67,9 +82,17 @@ impl OffchainWorkerParams {
 		};
 
 		let indexing_enabled = self.indexing_enabled;
+		let offchain_call_capabilities = self.offchain_call_capabilities
+			.iter()
+			.map(|capability| (*capability).into())
+			.collect::<Vec<Capability>>();
 		Ok(OffchainWorkerConfig {
 			enabled,
 			indexing_enabled,
+			extensions_registry: ExtensionsRegistry {
+				other: offchain_call_capabilities[..].into(),
+				..Default::default()
+			},
 		})
 	}
 }
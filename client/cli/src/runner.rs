@@ -25,7 +25,9 @@ use futures::select;
 use futures::{future, future::FutureExt, Future};
 use log::info;
 use sc_service::{Configuration, TaskType, TaskManager};
-use sp_utils::metrics::{TOKIO_THREADS_ALIVE, TOKIO_THREADS_TOTAL};
+use sp_utils::metrics::{
+	TOKIO_THREADS_ALIVE, TOKIO_THREADS_TOTAL, TOKIO_WORKER_THREADS, TOKIO_MAX_BLOCKING_THREADS,
+};
 use std::marker::PhantomData;
 use sc_service::Error as ServiceError;
 use crate::error::Error as CliError;
@@ -77,10 +79,42 @@ where
 	Ok(())
 }
 
+/// The default maximum number of threads of the tokio runtime, including the worker threads.
+const TOKIO_DEFAULT_MAX_THREADS: usize = 512;
+
 /// Build a tokio runtime with all features
 pub fn build_runtime() -> std::result::Result<tokio::runtime::Runtime, std::io::Error> {
-	tokio::runtime::Builder::new()
-		.threaded_scheduler()
+	build_runtime_with(None, None)
+}
+
+/// Build a tokio runtime with all features and the given number of worker threads and of blocking
+/// threads, the tokio defaults being used for the ones which are not given.
+pub fn build_runtime_with(
+	worker_threads: Option<usize>,
+	max_blocking_threads: Option<usize>,
+) -> std::result::Result<tokio::runtime::Runtime, std::io::Error> {
+	let mut builder = tokio::runtime::Builder::new();
+	builder.threaded_scheduler();
+
+	if let Some(worker_threads) = worker_threads {
+		builder.core_threads(worker_threads.max(1));
+	}
+	let worker_threads = worker_threads.unwrap_or_else(num_cpus::get).max(1);
+
+	// This version of tokio has no separate maximum of blocking threads, its maximum number of
+	// threads includes the worker threads.
+	let max_blocking_threads = match max_blocking_threads {
+		Some(max_blocking_threads) => {
+			let max_blocking_threads = max_blocking_threads.max(1);
+			builder.max_threads(worker_threads + max_blocking_threads);
+			max_blocking_threads
+		},
+		None => TOKIO_DEFAULT_MAX_THREADS.saturating_sub(worker_threads),
+	};
+	TOKIO_WORKER_THREADS.set(worker_threads as u64);
+	TOKIO_MAX_BLOCKING_THREADS.set(max_blocking_threads as u64);
+
+	builder
 		.on_thread_start(|| {
 			TOKIO_THREADS_ALIVE.inc();
 			TOKIO_THREADS_TOTAL.inc();
@@ -123,7 +157,10 @@ impl<C: SubstrateCli> Runner<C> {
 		cli: &C,
 		command: &T,
 	) -> Result<Runner<C>> {
-		let tokio_runtime = build_runtime()?;
+		let tokio_runtime = match command.async_runtime_params() {
+			Some(params) => params.build_runtime()?,
+			None => build_runtime()?,
+		};
 		let runtime_handle = tokio_runtime.handle().clone();
 
 		let task_executor = move |fut, task_type| {
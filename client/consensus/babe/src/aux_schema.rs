@@ -26,11 +26,12 @@ use sp_blockchain::{Result as ClientResult, Error as ClientError};
 use sp_runtime::traits::Block as BlockT;
 use sp_consensus_babe::{BabeBlockWeight, BabeGenesisConfiguration};
 use sc_consensus_epochs::{EpochChangesFor, SharedEpochChanges, migration::EpochChangesForV0};
-use crate::{Epoch, migration::EpochV0};
+use crate::{Epoch, migration::EpochV0, future_blocks::FutureBlock};
 
 const BABE_EPOCH_CHANGES_VERSION: &[u8] = b"babe_epoch_changes_version";
 const BABE_EPOCH_CHANGES_KEY: &[u8] = b"babe_epoch_changes";
 const BABE_EPOCH_CHANGES_CURRENT_VERSION: u32 = 2;
+const BABE_FUTURE_BLOCKS_KEY: &[u8] = b"babe_future_blocks";
 
 fn block_weight_key<H: Encode>(block_hash: H) -> Vec<u8> {
 	(b"block_weight", block_hash).encode()
@@ -134,6 +135,23 @@ pub fn load_block_weight<H: Encode, B: AuxStore>(
 	load_decode(backend, block_weight_key(block_hash).as_slice())
 }
 
+/// Load the blocks which were too far in the future to be imported.
+pub(crate) fn load_future_blocks<Block: BlockT, B: AuxStore>(
+	backend: &B,
+) -> ClientResult<Vec<FutureBlock<Block>>> {
+	load_decode(backend, BABE_FUTURE_BLOCKS_KEY).map(Option::unwrap_or_default)
+}
+
+/// Update the blocks which were too far in the future to be imported.
+pub(crate) fn write_future_blocks<Block: BlockT, F, R>(
+	future_blocks: &[FutureBlock<Block>],
+	write_aux: F,
+) -> R where
+	F: FnOnce(&[(&'static [u8], &[u8])]) -> R,
+{
+	future_blocks.using_encoded(|s| write_aux(&[(BABE_FUTURE_BLOCKS_KEY, s)]))
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -0,0 +1,199 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Blocks which were too far in the future to be imported.
+//!
+//! The verifier rejects the blocks whose slot is after the current slot, although their author
+//! may only have a clock slightly ahead of ours. Such blocks are kept in the aux store and
+//! scheduled for import again once their slot is reached, instead of being downloaded again.
+
+use std::{sync::Arc, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use codec::{Decode, Encode};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use sc_client_api::backend::AuxStore;
+use sp_blockchain::Result as ClientResult;
+use sp_consensus::{BlockOrigin, import_queue::{BasicQueueHandle, IncomingBlock}};
+use sp_consensus_slots::Slot;
+use sp_runtime::{Justifications, traits::{Block as BlockT, Header}};
+
+use crate::aux_schema;
+
+/// The maximum number of blocks kept, the ones with the nearest slots are preferred.
+const MAX_FUTURE_BLOCKS: usize = 32;
+
+/// A block which was too far in the future to be imported.
+#[derive(Encode, Decode)]
+pub(crate) struct FutureBlock<Block: BlockT> {
+	/// The slot of the block.
+	pub slot: Slot,
+	/// The header of the block.
+	pub header: Block::Header,
+	/// The body of the block.
+	pub body: Vec<Block::Extrinsic>,
+	/// The justifications of the block.
+	pub justifications: Option<Justifications>,
+}
+
+/// The blocks which were too far in the future to be imported, ordered by slot.
+pub(crate) struct FutureBlocks<Block: BlockT, Client> {
+	client: Arc<Client>,
+	blocks: Arc<Mutex<Vec<FutureBlock<Block>>>>,
+}
+
+impl<Block: BlockT, Client> Clone for FutureBlocks<Block, Client> {
+	fn clone(&self) -> Self {
+		FutureBlocks {
+			client: self.client.clone(),
+			blocks: self.blocks.clone(),
+		}
+	}
+}
+
+impl<Block: BlockT, Client: AuxStore> FutureBlocks<Block, Client> {
+	/// Load the blocks kept by a previous run of the node.
+	pub(crate) fn load(client: Arc<Client>) -> ClientResult<Self> {
+		let blocks = aux_schema::load_future_blocks(&*client)?;
+		Ok(FutureBlocks { client, blocks: Arc::new(Mutex::new(blocks)) })
+	}
+
+	/// Keep a block whose slot is too far in the future.
+	///
+	/// The block with the farthest slot is dropped when more than [`MAX_FUTURE_BLOCKS`] are kept.
+	pub(crate) fn insert(&self, block: FutureBlock<Block>) -> ClientResult<()> {
+		let mut blocks = self.blocks.lock();
+		let hash = block.header.hash();
+		if blocks.iter().any(|kept| kept.header.hash() == hash) {
+			return Ok(())
+		}
+
+		blocks.push(block);
+		blocks.sort_by_key(|block| block.slot);
+		blocks.truncate(MAX_FUTURE_BLOCKS);
+		self.write(&blocks)
+	}
+
+	/// Remove and return the blocks whose slot is not after `slot`.
+	pub(crate) fn take_due(&self, slot: Slot) -> ClientResult<Vec<FutureBlock<Block>>> {
+		let mut blocks = self.blocks.lock();
+		let due = blocks.iter().position(|block| block.slot > slot).unwrap_or(blocks.len());
+		if due == 0 {
+			return Ok(Vec::new())
+		}
+
+		let due = blocks.drain(..due).collect();
+		self.write(&blocks)?;
+		Ok(due)
+	}
+
+	fn write(&self, blocks: &[FutureBlock<Block>]) -> ClientResult<()> {
+		aux_schema::write_future_blocks(blocks, |values| self.client.insert_aux(values, &[]))
+	}
+}
+
+/// The current slot according to the system time.
+fn current_slot(slot_duration: Duration) -> Slot {
+	let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+	Slot::from((now.as_millis() / slot_duration.as_millis()) as u64)
+}
+
+/// Schedule the kept blocks for import in `queue` once their slot is reached.
+pub(crate) async fn import_future_blocks<Block: BlockT, Client: AuxStore>(
+	future_blocks: FutureBlocks<Block, Client>,
+	slot_duration: Duration,
+	queue: BasicQueueHandle<Block>,
+) {
+	loop {
+		futures_timer::Delay::new(slot_duration).await;
+
+		// The verifier accepts the blocks of the next slot to allow for some small drift.
+		let due = match future_blocks.take_due(current_slot(slot_duration) + 1) {
+			Ok(due) => due,
+			Err(e) => {
+				warn!(target: "babe", "Failed to update the blocks too far in the future: {}", e);
+				continue
+			},
+		};
+		if due.is_empty() {
+			continue
+		}
+
+		debug!(target: "babe", "Importing {} blocks which were too far in the future", due.len());
+		let blocks = due.into_iter().map(|block| IncomingBlock {
+			hash: block.header.hash(),
+			header: Some(block.header),
+			body: Some(block.body),
+			justifications: block.justifications,
+			origin: None,
+			allow_missing_state: false,
+			import_existing: false,
+		}).collect();
+
+		if queue.import_blocks(BlockOrigin::NetworkBroadcast, blocks).is_err() {
+			return
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_network_test::Block as TestBlock;
+
+	fn future_block(slot: u64) -> FutureBlock<TestBlock> {
+		FutureBlock {
+			slot: slot.into(),
+			header: Header::new(
+				slot,
+				Default::default(),
+				Default::default(),
+				Default::default(),
+				Default::default(),
+			),
+			body: Vec::new(),
+			justifications: None,
+		}
+	}
+
+	fn slots(blocks: Vec<FutureBlock<TestBlock>>) -> Vec<u64> {
+		blocks.into_iter().map(|block| block.slot.into()).collect()
+	}
+
+	#[test]
+	fn keeps_the_nearest_blocks_until_their_slot() {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let future_blocks = FutureBlocks::<TestBlock, _>::load(client.clone()).unwrap();
+
+		for slot in (1..=MAX_FUTURE_BLOCKS as u64 + 1).rev() {
+			future_blocks.insert(future_block(slot)).unwrap();
+		}
+		// The same block is only kept once.
+		future_blocks.insert(future_block(1)).unwrap();
+
+		assert_eq!(slots(future_blocks.take_due(2.into()).unwrap()), vec![1, 2]);
+		assert!(future_blocks.take_due(2.into()).unwrap().is_empty());
+
+		// The remaining blocks are persisted, without the farthest one.
+		let future_blocks = FutureBlocks::<TestBlock, _>::load(client).unwrap();
+		assert_eq!(
+			slots(future_blocks.take_due(u64::MAX.into()).unwrap()),
+			(3..=MAX_FUTURE_BLOCKS as u64).collect::<Vec<_>>(),
+		);
+	}
+}
@@ -123,9 +123,11 @@ use schnorrkel::SignatureError;
 use codec::{Encode, Decode};
 use sp_api::ApiExt;
 use sp_consensus_slots::Slot;
+use future_blocks::{FutureBlock, FutureBlocks};
 
 mod verification;
 mod migration;
+mod future_blocks;
 
 pub mod aux_schema;
 pub mod authorship;
@@ -958,6 +960,7 @@ pub struct BabeVerifier<Block: BlockT, Client, SelectChain, CAW, CIDP> {
 	epoch_changes: SharedEpochChanges<Block, Epoch>,
 	can_author_with: CAW,
 	telemetry: Option<TelemetryHandle>,
+	future_blocks: FutureBlocks<Block, Client>,
 }
 
 impl<Block, Client, SelectChain, CAW, CIDP> BabeVerifier<Block, Client, SelectChain, CAW, CIDP>
@@ -1225,6 +1228,20 @@ where
 					"babe.header_too_far_in_future";
 					"hash" => ?hash, "a" => ?a, "b" => ?b
 				);
+
+				// keep the block to import it again once its slot is reached.
+				if let Some(body) = body {
+					let future_block = FutureBlock { slot: b, header: a, body, justifications };
+					if let Err(e) = self.future_blocks.insert(future_block) {
+						warn!(
+							target: "babe",
+							"Failed to keep block {:?} too far in the future: {}",
+							hash,
+							e,
+						);
+					}
+				}
+
 				Err(Error::<Block>::TooFarInFuture(hash).into())
 			}
 		}
@@ -1634,6 +1651,8 @@ pub fn import_queue<Block: BlockT, Client, SelectChain, Inner, CAW, CIDP>(
 	CIDP: CreateInherentDataProviders<Block, ()> + Send + Sync + 'static,
 	CIDP::InherentDataProviders: InherentDataProviderExt + Send + Sync,
 {
+	let future_blocks = FutureBlocks::load(client.clone())?;
+	let slot_duration = babe_link.config.slot_duration();
 	let verifier = BabeVerifier {
 		select_chain,
 		create_inherent_data_providers,
@@ -1642,13 +1661,21 @@ pub fn import_queue<Block: BlockT, Client, SelectChain, Inner, CAW, CIDP>(
 		can_author_with,
 		telemetry,
 		client,
+		future_blocks: future_blocks.clone(),
 	};
 
-	Ok(BasicQueue::new(
+	let queue = BasicQueue::new(
 		verifier,
 		Box::new(block_import),
 		justification_import,
 		spawner,
 		registry,
-	))
+	);
+
+	spawner.spawn_essential(
+		"babe-future-blocks",
+		future_blocks::import_future_blocks(future_blocks, slot_duration, queue.handle()).boxed(),
+	);
+
+	Ok(queue)
 }
@@ -354,6 +354,7 @@ impl TestNetFactory for BabeTestNet {
 				epoch_changes: data.link.epoch_changes.clone(),
 				can_author_with: AlwaysCanAuthor,
 				telemetry: None,
+				future_blocks: FutureBlocks::load(client.clone()).unwrap(),
 			},
 			mutator: MUTATOR.with(|m| m.borrow().clone()),
 		}
@@ -0,0 +1,221 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Consistent copies of the database.
+//!
+//! RocksDB databases are copied key by key to a new database, from a secondary instance of the
+//! database: the secondary instance doesn't see the writes made after it was opened, so the node
+//! can keep running while the copy is written. ParityDB hashes the keys of most columns and has
+//! no snapshots, so its files are copied while the node is stopped.
+
+use std::{io, path::Path};
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+use std::{path::PathBuf, sync::{Arc, atomic::{AtomicUsize, Ordering}}};
+
+use sp_blockchain::{Error as ClientError, Result as ClientResult};
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+use sp_database::{Database, Transaction};
+use sp_runtime::traits::Block as BlockT;
+
+use crate::DatabaseSettingsSrc;
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+use crate::{DbHash, utils::NUM_COLUMNS};
+#[cfg(any(feature = "with-kvdb-rocksdb", feature = "with-parity-db", test))]
+use crate::{
+	DatabaseSettings, KeepBlocks, PruningMode, TransactionStorageMode,
+	utils::{self, DatabaseType},
+};
+
+/// Number of key/value pairs copied per transaction when backing up the database.
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+const BACKUP_BATCH_SIZE: usize = 4096;
+
+/// Write a copy of the database `source` to the directory `target`, which must not exist or be
+/// empty.
+///
+/// This is meant to be used from outside of the node. A RocksDB database is opened as a secondary
+/// instance, so the node can keep running: the copy contains all the writes of the node up to
+/// the opening. A ParityDB database can only be copied when the node is not running.
+pub fn backup_database<Block: BlockT>(
+	source: &DatabaseSettingsSrc,
+	target: &Path,
+) -> ClientResult<()> {
+	ensure_empty(target)?;
+
+	match source {
+		#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+		DatabaseSettingsSrc::RocksDb { path, cache_size } =>
+			Secondary::open(path)?.copy_to::<Block>(target, *cache_size),
+		#[cfg(feature = "with-parity-db")]
+		DatabaseSettingsSrc::ParityDb { path } => {
+			// Opening the database fails while the node is running. Closing it waits for the
+			// background flush of its logs, so the files don't change while they are copied.
+			let db = utils::open_database::<Block>(&settings(source.clone()), DatabaseType::Full)
+				.map_err(|e| ClientError::Backend(format!(
+					"Failed to open the database, the node must be stopped to back up a ParityDB \
+					database: {}",
+					e,
+				)))?;
+			drop(db);
+			copy_files(path, target)
+		},
+		_ => Err(ClientError::Backend(
+			format!("Backup is not supported for {} databases", source),
+		)),
+	}
+}
+
+/// A RocksDB secondary instance of a database, whose files are removed when it is dropped.
+///
+/// The secondary instance is caught up with the primary when it is opened and doesn't see the
+/// later writes of the primary, it is a consistent snapshot of the database.
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+pub(crate) struct Secondary {
+	db: Option<Arc<dyn Database<DbHash>>>,
+	path: PathBuf,
+}
+
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+impl Secondary {
+	/// Open the RocksDB database at `path` as a secondary instance, keeping its own files in a
+	/// temporary directory.
+	pub(crate) fn open(path: &Path) -> ClientResult<Self> {
+		static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+		let to_str = |path: &Path| path.to_str()
+			.map(String::from)
+			.ok_or_else(|| ClientError::Backend("Invalid database path".into()));
+
+		let secondary_path = std::env::temp_dir().join(format!(
+			"substrate-backup-{}-{}",
+			std::process::id(),
+			NEXT_ID.fetch_add(1, Ordering::Relaxed),
+		));
+		let mut db_config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+		db_config.secondary = Some(to_str(&secondary_path)?);
+		// Removes the files of the secondary instance if it fails to open.
+		let mut secondary = Secondary { db: None, path: secondary_path };
+
+		let db = kvdb_rocksdb::Database::open(&db_config, &to_str(path)?)
+			.map_err(|e| ClientError::Backend(format!("{}", e)))?;
+		db.try_catch_up_with_primary().map_err(|e| ClientError::Backend(format!("{}", e)))?;
+
+		let db = sp_database::as_database(db);
+		utils::check_database_type(&*db, DatabaseType::Full)?;
+		secondary.db = Some(db);
+		Ok(secondary)
+	}
+
+	/// Write a copy of the snapshot to a new RocksDB database in the directory `target`, which
+	/// must not exist or be empty.
+	pub(crate) fn copy_to<Block: BlockT>(
+		&self,
+		target: &Path,
+		cache_size: usize,
+	) -> ClientResult<()> {
+		ensure_empty(target)?;
+
+		let target_db = utils::open_database::<Block>(
+			&settings(DatabaseSettingsSrc::RocksDb { path: target.to_path_buf(), cache_size }),
+			DatabaseType::Full,
+		)?;
+		let db = self.db.as_ref().expect("The database is set once opened; qed");
+		copy_columns(&**db, &*target_db)
+	}
+}
+
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+impl Drop for Secondary {
+	fn drop(&mut self) {
+		// The database is closed before its files are removed.
+		self.db = None;
+		let _ = std::fs::remove_dir_all(&self.path);
+	}
+}
+
+/// The settings to open the database `source` with, as is.
+#[cfg(any(feature = "with-kvdb-rocksdb", feature = "with-parity-db", test))]
+fn settings(source: DatabaseSettingsSrc) -> DatabaseSettings {
+	DatabaseSettings {
+		state_cache_size: 0,
+		state_cache_child_ratio: None,
+		trie_cache_maximum_size: None,
+		state_pruning: PruningMode::ArchiveAll,
+		source,
+		keep_blocks: KeepBlocks::All,
+		transaction_storage: TransactionStorageMode::BlockBody,
+	}
+}
+
+/// Copy all the key/value pairs of `source` to `target`, column by column.
+#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+fn copy_columns(source: &dyn Database<DbHash>, target: &dyn Database<DbHash>) -> ClientResult<()> {
+	for col in 0..NUM_COLUMNS {
+		let mut transaction = Transaction::new();
+		let mut pending = 0;
+		let mut commit_result = Ok(());
+		source.iter_column(col, &mut |key, value| {
+			if commit_result.is_err() {
+				return
+			}
+			transaction.set(col, key, value);
+			pending += 1;
+			if pending == BACKUP_BATCH_SIZE {
+				commit_result = target.commit(std::mem::take(&mut transaction));
+				pending = 0;
+			}
+		})?;
+		commit_result?;
+		target.commit(transaction)?;
+	}
+
+	Ok(())
+}
+
+/// Copy the files of the closed database in the directory `source` to the directory `target`.
+#[cfg(feature = "with-parity-db")]
+fn copy_files(source: &Path, target: &Path) -> ClientResult<()> {
+	let io_err = |e: io::Error| ClientError::Backend(format!("Failed to copy the database: {}", e));
+
+	std::fs::create_dir_all(target).map_err(io_err)?;
+	for entry in std::fs::read_dir(source).map_err(io_err)? {
+		let entry = entry.map_err(io_err)?;
+		if entry.file_type().map_err(io_err)?.is_file() {
+			std::fs::copy(entry.path(), target.join(entry.file_name())).map_err(io_err)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Ensure that the backup directory `target` doesn't exist or is empty.
+pub(crate) fn ensure_empty(target: &Path) -> ClientResult<()> {
+	let is_empty = match std::fs::read_dir(target) {
+		Ok(mut entries) => entries.next().is_none(),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => true,
+		Err(e) => return Err(ClientError::Backend(format!(
+			"Failed to read backup directory {}: {}", target.display(), e,
+		))),
+	};
+	if !is_empty {
+		return Err(ClientError::Backend(format!(
+			"Backup directory {} is not empty", target.display(),
+		)))
+	}
+
+	Ok(())
+}
@@ -34,10 +34,12 @@ pub mod offchain;
 #[cfg(any(feature = "with-kvdb-rocksdb", test))]
 pub mod bench;
 
+mod backup;
 mod children;
 mod cache;
 mod changes_tries_storage;
 mod storage_cache;
+mod trie_cache;
 #[cfg(any(feature = "with-kvdb-rocksdb", test))]
 mod upgrade;
 mod utils;
@@ -66,7 +68,7 @@ use sp_blockchain::{
 use codec::{Decode, Encode};
 use hash_db::Prefix;
 use sp_trie::{MemoryDB, PrefixedMemoryDB, prefixed_key};
-use sp_database::Transaction;
+use sp_database::{Change, Transaction};
 use sp_core::ChangesTrieConfiguration;
 use sp_core::offchain::OffchainOverlayedChange;
 use sp_core::storage::{well_known_keys, ChildInfo};
@@ -85,11 +87,14 @@ use crate::changes_tries_storage::{DbChangesTrieStorage, DbChangesTrieStorageTra
 use sc_state_db::StateDb;
 use sp_blockchain::{CachedHeaderMetadata, HeaderMetadata, HeaderMetadataCache};
 use crate::storage_cache::{CachingState, SyncingCachingState, SharedCache, new_shared_cache};
-use crate::stats::StateUsageStats;
+use crate::stats::{BlockUsageStats, StateUsageStats};
+use crate::trie_cache::SharedTrieCache;
 
 // Re-export the Database trait so that one can pass an implementation of it.
 pub use sp_database::Database;
 pub use sc_state_db::PruningMode;
+pub use trie_cache::{DEFAULT_TRIE_CACHE_SIZE, TrieCacheStats};
+pub use backup::backup_database;
 
 #[cfg(any(feature = "with-kvdb-rocksdb", test))]
 pub use bench::BenchmarkingState;
@@ -126,14 +131,21 @@ pub struct RefTrackingState<Block: BlockT> {
 	state: DbState<Block>,
 	storage: Arc<StorageDb<Block>>,
 	parent_hash: Option<Block::Hash>,
+	trie_cache: Option<SharedTrieCache<HashFor<Block>>>,
 }
 
 impl<B: BlockT> RefTrackingState<B> {
-	fn new(state: DbState<B>, storage: Arc<StorageDb<B>>, parent_hash: Option<B::Hash>) -> Self {
+	fn new(
+		state: DbState<B>,
+		storage: Arc<StorageDb<B>>,
+		parent_hash: Option<B::Hash>,
+		trie_cache: Option<SharedTrieCache<HashFor<B>>>,
+	) -> Self {
 		RefTrackingState {
 			state,
 			parent_hash,
 			storage,
+			trie_cache,
 		}
 	}
 }
@@ -158,7 +170,11 @@ impl<B: BlockT> StateBackend<HashFor<B>> for RefTrackingState<B> {
 	type TrieBackendStorage = <DbState<B> as StateBackend<HashFor<B>>>::TrieBackendStorage;
 
 	fn storage(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
-		self.state.storage(key)
+		match &self.trie_cache {
+			Some(trie_cache) =>
+				trie_cache.value(self.state.root(), key, || self.state.storage(key)),
+			None => self.state.storage(key),
+		}
 	}
 
 	fn storage_hash(&self, key: &[u8]) -> Result<Option<B::Hash>, Self::Error> {
@@ -274,6 +290,10 @@ pub struct DatabaseSettings {
 	pub state_cache_size: usize,
 	/// Ratio of cache size dedicated to child tries.
 	pub state_cache_child_ratio: Option<(usize, usize)>,
+	/// Maximum size of the cache of trie nodes shared by all the states, in bytes.
+	///
+	/// `None` disables the cache.
+	pub trie_cache_maximum_size: Option<usize>,
 	/// State pruning mode.
 	pub state_pruning: PruningMode,
 	/// Where to find the database.
@@ -973,12 +993,15 @@ pub struct Backend<Block: BlockT> {
 	blockchain: BlockchainDb<Block>,
 	canonicalization_delay: u64,
 	shared_cache: SharedCache<Block>,
+	trie_cache: Option<SharedTrieCache<HashFor<Block>>>,
 	import_lock: Arc<RwLock<()>>,
 	is_archive: bool,
 	keep_blocks: KeepBlocks,
 	transaction_storage: TransactionStorageMode,
-	io_stats: FrozenForDuration<(kvdb::IoStats, StateUsageInfo)>,
+	io_stats: FrozenForDuration<(kvdb::IoStats, StateUsageInfo, TrieCacheStats)>,
 	state_usage: Arc<StateUsageStats>,
+	block_usage: Mutex<BlockUsageStats>,
+	source: DatabaseSettingsSrc,
 }
 
 impl<Block: BlockT> Backend<Block> {
@@ -1012,6 +1035,7 @@ impl<Block: BlockT> Backend<Block> {
 		let db_setting = DatabaseSettings {
 			state_cache_size: 16777216,
 			state_cache_child_ratio: Some((50, 100)),
+			trie_cache_maximum_size: Some(16777216),
 			state_pruning: PruningMode::keep_blocks(keep_blocks),
 			source: DatabaseSettingsSrc::Custom(db),
 			keep_blocks: KeepBlocks::Some(keep_blocks),
@@ -1067,15 +1091,55 @@ impl<Block: BlockT> Backend<Block> {
 				config.state_cache_size,
 				config.state_cache_child_ratio.unwrap_or(DEFAULT_CHILD_RATIO),
 			),
+			trie_cache: config.trie_cache_maximum_size.map(SharedTrieCache::new),
 			import_lock: Default::default(),
 			is_archive: is_archive_pruning,
 			io_stats: FrozenForDuration::new(std::time::Duration::from_secs(1)),
 			state_usage: Arc::new(StateUsageStats::new()),
+			block_usage: Default::default(),
 			keep_blocks: config.keep_blocks.clone(),
 			transaction_storage: config.transaction_storage.clone(),
+			source: config.source.clone(),
 		})
 	}
 
+	/// The trie node storage for a new state, reading through the trie cache if it is enabled.
+	fn trie_storage(&self) -> Arc<dyn sp_state_machine::Storage<HashFor<Block>>> {
+		match &self.trie_cache {
+			Some(trie_cache) => Arc::new(trie_cache.local_cache(self.storage.clone())),
+			None => self.storage.clone(),
+		}
+	}
+
+	/// Copy the database to a new database of the same kind in the directory `target`, which
+	/// must not exist or be empty.
+	///
+	/// A RocksDB database is opened as a secondary instance while the import lock is held, so
+	/// that no block is half imported or finalized in the snapshot. The lock is released before
+	/// the snapshot is copied. A ParityDB database has no snapshots and can only be copied while
+	/// the node is stopped.
+	fn backup_database(&self, target: &Path) -> ClientResult<()> {
+		backup::ensure_empty(target)?;
+
+		match &self.source {
+			#[cfg(any(feature = "with-kvdb-rocksdb", test))]
+			DatabaseSettingsSrc::RocksDb { path, cache_size } => {
+				let snapshot = {
+					let _import_lock = self.import_lock.write();
+					backup::Secondary::open(path)?
+				};
+				snapshot.copy_to::<Block>(target, *cache_size)
+			},
+			DatabaseSettingsSrc::ParityDb { .. } => Err(ClientError::Backend(
+				"A ParityDB database can't be backed up while the node is running, stop the node \
+				and use the `backup-db` command".into(),
+			)),
+			_ => Err(ClientError::Backend(
+				format!("Backup is not supported for {} databases", self.source),
+			)),
+		}
+	}
+
 	/// Handle setting head within a transaction. `route_to` should be the last
 	/// block that existed in the database. `best_to` should be the best block
 	/// to be set.
@@ -1456,11 +1520,29 @@ impl<Block: BlockT> Backend<Block> {
 			None
 		};
 
+		let transaction_size = transaction.0.iter().map(|change| match change {
+			Change::Set(_, key, value) => key.len() + value.len(),
+			Change::Remove(_, key) => key.len(),
+			Change::Store(_, hash, value) => hash.as_ref().len() + value.len(),
+			Change::Reference(_, hash) | Change::Release(_, hash) => hash.as_ref().len(),
+		}).sum::<usize>();
+		let transaction_writes = transaction.0.len();
+
 		self.storage.db.commit(transaction)?;
 
 		// Apply all in-memory state changes.
 		// Code beyond this point can't fail.
 
+		if let Some((_, number, hash, ..)) = &imported {
+			let usage = self.block_usage.lock().note_block(
+				self.state_usage.totals(),
+				self.trie_cache.as_ref().map(|cache| cache.stats()).unwrap_or_default(),
+				transaction_writes as u64,
+				transaction_size as u64,
+			);
+			debug!(target: "state-usage", "Imported #{} ({}): {}", number, hash, usage);
+		}
+
 		if let Some((
 			header,
 			number,
@@ -1867,22 +1949,27 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 	}
 
 	fn usage_info(&self) -> Option<UsageInfo> {
-		let (io_stats, state_stats) = self.io_stats.take_or_else(||
+		let (io_stats, state_stats, trie_stats) = self.io_stats.take_or_else(||
 			(
 				// TODO: implement DB stats and cache size retrieval
 				kvdb::IoStats::empty(),
 				self.state_usage.take(),
+				self.trie_cache.as_ref().map(|cache| cache.take_stats()).unwrap_or_default(),
 			)
 		);
 		let database_cache = MemorySize::from_bytes(0);
 		let state_cache = MemorySize::from_bytes(
 			(*&self.shared_cache).lock().used_storage_cache_size(),
 		);
+		let trie_cache = MemorySize::from_bytes(
+			self.trie_cache.as_ref().map_or(0, |cache| cache.used_size()),
+		);
 		let state_db = self.storage.state_db.memory_info();
 
 		Some(UsageInfo {
 			memory: MemoryInfo {
 				state_cache,
+				trie_cache,
 				database_cache,
 				state_db,
 			},
@@ -1898,7 +1985,10 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 				state_writes_cache: state_stats.overlay_writes.ops,
 				state_reads_cache: state_stats.cache_reads.ops,
 				state_writes_nodes: state_stats.nodes_writes.ops,
+				trie_nodes_reads: trie_stats.hits + trie_stats.misses,
+				trie_nodes_reads_cache: trie_stats.hits,
 			},
+			last_block: self.block_usage.lock().last_block(),
 		})
 	}
 
@@ -2066,7 +2156,7 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 				let genesis_storage = DbGenesisStorage::<Block>::new();
 				let root = genesis_storage.0.clone();
 				let db_state = DbState::<Block>::new(Arc::new(genesis_storage), root);
-				let state = RefTrackingState::new(db_state, self.storage.clone(), None);
+				let state = RefTrackingState::new(db_state, self.storage.clone(), None, None);
 				let caching_state = CachingState::new(
 					state,
 					self.shared_cache.clone(),
@@ -2100,11 +2190,12 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 				}
 				if let Ok(()) = self.storage.state_db.pin(&hash) {
 					let root = hdr.state_root;
-					let db_state = DbState::<Block>::new(self.storage.clone(), root);
+					let db_state = DbState::<Block>::new(self.trie_storage(), root);
 					let state = RefTrackingState::new(
 						db_state,
 						self.storage.clone(),
 						Some(hash.clone()),
+						self.trie_cache.clone(),
 					);
 					let caching_state = CachingState::new(
 						state,
@@ -2149,6 +2240,10 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 	fn get_import_lock(&self) -> &RwLock<()> {
 		&*self.import_lock
 	}
+
+	fn backup(&self, target: &Path) -> ClientResult<()> {
+		self.backup_database(target)
+	}
 }
 
 impl<Block: BlockT> sc_client_api::backend::LocalBackend<Block> for Backend<Block> {}
@@ -2288,6 +2383,7 @@ pub(crate) mod tests {
 		let backend = Backend::<Block>::new(DatabaseSettings {
 			state_cache_size: 16777216,
 			state_cache_child_ratio: Some((50, 100)),
+			trie_cache_maximum_size: Some(16777216),
 			state_pruning: PruningMode::keep_blocks(1),
 			source: DatabaseSettingsSrc::Custom(backing),
 			keep_blocks: KeepBlocks::All,
@@ -3124,4 +3220,65 @@ pub(crate) mod tests {
 		assert_eq!(None, backend.blockchain().header(BlockId::hash(prev_hash.clone())).unwrap());
 		assert!(!backend.have_state_at(&prev_hash, 1));
 	}
+
+	#[test]
+	fn backup_works() {
+		let settings = |path: &Path| DatabaseSettings {
+			state_cache_size: 16777216,
+			state_cache_child_ratio: Some((50, 100)),
+			trie_cache_maximum_size: Some(16777216),
+			state_pruning: PruningMode::keep_blocks(1),
+			source: DatabaseSettingsSrc::RocksDb { path: path.to_owned(), cache_size: 16 },
+			keep_blocks: KeepBlocks::All,
+			transaction_storage: TransactionStorageMode::BlockBody,
+		};
+		let db_dir = tempfile::TempDir::new().unwrap();
+		let backup_dir = tempfile::TempDir::new().unwrap();
+		let backup_path = backup_dir.path().join("backup");
+		let secondary_backup_path = backup_dir.path().join("secondary");
+
+		let hash = {
+			let backend = Backend::<Block>::new(settings(db_dir.path()), 0).unwrap();
+			let genesis = insert_header(&backend, 0, Default::default(), None, Default::default());
+			let hash = insert_header(&backend, 1, genesis, None, Default::default());
+
+			backend.backup(&backup_path).unwrap();
+			// The backup directory is not empty anymore.
+			assert!(backend.backup(&backup_path).is_err());
+
+			// The database is copied from outside of the backend while it is open.
+			backup_database::<Block>(&settings(db_dir.path()).source, &secondary_backup_path)
+				.unwrap();
+			hash
+		};
+
+		for path in &[backup_path, secondary_backup_path] {
+			let backup = Backend::<Block>::new(settings(path), 0).unwrap();
+			assert_eq!(backup.blockchain().info().best_hash, hash);
+			assert!(backup.blockchain().header(BlockId::Hash(hash)).unwrap().is_some());
+		}
+
+		let custom = Backend::<Block>::new_test(1, 0);
+		assert!(custom.backup(&backup_dir.path().join("custom")).is_err());
+	}
+
+	#[test]
+	fn last_block_usage_is_tracked() {
+		let backend = Backend::<Block>::new_test(1, 0);
+		assert!(backend.usage_info().unwrap().last_block.is_none());
+
+		let genesis = insert_header(&backend, 0, Default::default(), None, Default::default());
+		let usage = backend.usage_info().unwrap().last_block.unwrap();
+		assert!(usage.database_writes > 0);
+		assert!(usage.database_bytes_written > 0);
+
+		let state = backend.state_at(BlockId::Hash(genesis)).unwrap();
+		assert_eq!(state.storage(b"key").unwrap(), None);
+		drop(state);
+
+		insert_header(&backend, 1, genesis, None, Default::default());
+		let usage = backend.usage_info().unwrap().last_block.unwrap();
+		assert!(usage.state_reads >= 1);
+		assert!(usage.database_writes > 0);
+	}
 }
@@ -571,6 +571,7 @@ impl<Block> Storage<Block> for LightStorage<Block>
 			memory: MemoryInfo {
 				database_cache,
 				state_cache: Default::default(),
+				trie_cache: Default::default(),
 				state_db: Default::default(),
 			},
 			io: IoInfo {
@@ -586,7 +587,10 @@ impl<Block> Storage<Block> for LightStorage<Block>
 				state_reads_cache: 0,
 				state_writes_cache: 0,
 				state_writes_nodes: 0,
-			}
+				trie_nodes_reads: 0,
+				trie_nodes_reads_cache: 0,
+			},
+			last_block: None,
 		})
 	}
 
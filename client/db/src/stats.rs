@@ -19,6 +19,10 @@
 //! Database usage statistics
 
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use parking_lot::Mutex;
+use sc_client_api::BlockUsageInfo;
+use sp_state_machine::{UsageInfo, UsageUnit};
+use crate::trie_cache::TrieCacheStats;
 
 /// Accumulated usage statistics for state queries.
 pub struct StateUsageStats {
@@ -33,6 +37,7 @@ pub struct StateUsageStats {
 	bytes_removed_nodes: AtomicU64,
 	reads_cache: AtomicU64,
 	bytes_read_cache: AtomicU64,
+	taken: Mutex<Option<UsageInfo>>,
 }
 
 impl StateUsageStats {
@@ -50,6 +55,7 @@ impl StateUsageStats {
 			bytes_removed_nodes: 0.into(),
 			reads_cache: 0.into(),
 			bytes_read_cache: 0.into(),
+			taken: Mutex::new(None),
 		}
 	}
 
@@ -110,18 +116,16 @@ impl StateUsageStats {
 		self.bytes_read_cache.fetch_add(info.cache_reads.bytes, AtomicOrdering::Relaxed);
 	}
 
-	/// Returns the collected `UsageInfo` and resets the internal state.
-	pub fn take(&self) -> sp_state_machine::UsageInfo {
-		use sp_state_machine::UsageUnit;
-
+	/// Returns the `UsageInfo` accumulated since the creation of the stats.
+	pub fn totals(&self) -> UsageInfo {
 		fn unit(ops: &AtomicU64, bytes: &AtomicU64) -> UsageUnit {
 			UsageUnit {
-				ops: ops.swap(0, AtomicOrdering::Relaxed),
-				bytes: bytes.swap(0, AtomicOrdering::Relaxed),
+				ops: ops.load(AtomicOrdering::Relaxed),
+				bytes: bytes.load(AtomicOrdering::Relaxed),
 			}
 		}
 
-		sp_state_machine::UsageInfo {
+		UsageInfo {
 			reads: unit(&self.reads, &self.bytes_read),
 			writes: unit(&self.writes, &self.bytes_written),
 			nodes_writes: unit(&self.writes_nodes, &self.bytes_written_nodes),
@@ -137,4 +141,85 @@ impl StateUsageStats {
 			span: self.started.elapsed(),
 		}
 	}
+
+	/// Returns the `UsageInfo` collected since the last call.
+	pub fn take(&self) -> UsageInfo {
+		let totals = self.totals();
+		let previous = std::mem::replace(&mut *self.taken.lock(), Some(totals.clone()));
+		match previous {
+			Some(previous) => usage_since(&totals, &previous),
+			None => totals,
+		}
+	}
+}
+
+/// The usage in `totals` which is not in `previous`, both being accumulated usages.
+fn usage_since(totals: &UsageInfo, previous: &UsageInfo) -> UsageInfo {
+	fn unit(totals: &UsageUnit, previous: &UsageUnit) -> UsageUnit {
+		UsageUnit {
+			ops: totals.ops.saturating_sub(previous.ops),
+			bytes: totals.bytes.saturating_sub(previous.bytes),
+		}
+	}
+
+	UsageInfo {
+		reads: unit(&totals.reads, &previous.reads),
+		writes: unit(&totals.writes, &previous.writes),
+		nodes_writes: unit(&totals.nodes_writes, &previous.nodes_writes),
+		removed_nodes: unit(&totals.removed_nodes, &previous.removed_nodes),
+		cache_reads: unit(&totals.cache_reads, &previous.cache_reads),
+		modified_reads: unit(&totals.modified_reads, &previous.modified_reads),
+		overlay_writes: unit(&totals.overlay_writes, &previous.overlay_writes),
+		memory: totals.memory,
+		started: totals.started,
+		span: totals.span,
+	}
+}
+
+/// Usage statistics of each imported block, i.e. accumulated since the previous imported block.
+#[derive(Default)]
+pub struct BlockUsageStats {
+	state: Option<UsageInfo>,
+	trie: TrieCacheStats,
+	last_block: Option<BlockUsageInfo>,
+}
+
+impl BlockUsageStats {
+	/// Note the import of a block, given the accumulated statistics of the state and trie node
+	/// reads and the writes of the import.
+	///
+	/// Returns the usage of the block.
+	pub fn note_block(
+		&mut self,
+		state: UsageInfo,
+		trie: TrieCacheStats,
+		database_writes: u64,
+		database_bytes_written: u64,
+	) -> BlockUsageInfo {
+		let state_usage = match &self.state {
+			Some(previous) => usage_since(&state, previous),
+			None => state.clone(),
+		};
+		let trie_usage = trie.since(&self.trie);
+		self.state = Some(state);
+		self.trie = trie;
+
+		let usage = BlockUsageInfo {
+			state_reads: state_usage.reads.ops,
+			state_reads_cache: state_usage.cache_reads.ops,
+			trie_nodes_reads: trie_usage.hits + trie_usage.misses,
+			trie_nodes_reads_cache: trie_usage.hits,
+			database_reads: trie_usage.misses,
+			database_bytes_read: trie_usage.bytes_read,
+			database_writes,
+			database_bytes_written,
+		};
+		self.last_block = Some(usage.clone());
+		usage
+	}
+
+	/// The usage of the last imported block, if any.
+	pub fn last_block(&self) -> Option<BlockUsageInfo> {
+		self.last_block.clone()
+	}
 }
@@ -0,0 +1,410 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Cache of trie nodes and values.
+//!
+//! Trie nodes are addressed by the hash of their content, so a cached node never becomes stale and
+//! a single [`SharedTrieCache`] is used by all the states of the backend (block import, RPC, block
+//! authorship) without any invalidation.
+//!
+//! Every state reads its nodes through a [`LocalTrieCache`], which keeps the nodes read during the
+//! lifetime of the state (usually the execution of one block) and only merges the nodes fetched
+//! from the database into the shared cache when it is dropped. This way the lock of the shared
+//! cache is not taken for nodes which are read repeatedly by the same state. The lock is never
+//! held while reading the database.
+//!
+//! The shared cache also keeps the values of the top trie read by the states, identified by the
+//! storage root of their state and their key, so that reading a value again does not walk the
+//! trie. A cached value never becomes stale either.
+
+use std::{
+	collections::HashMap,
+	hash::Hash as StdHash,
+	sync::{Arc, atomic::{AtomicU64, Ordering}},
+};
+use hash_db::{Hasher, Prefix};
+use linked_hash_map::LinkedHashMap;
+use parking_lot::Mutex;
+use sp_state_machine::{DBValue, Storage};
+
+/// Default maximum size of the shared trie cache, in bytes.
+pub const DEFAULT_TRIE_CACHE_SIZE: usize = 64 * 1024 * 1024;
+
+/// A local cache may grow up to `1 / LOCAL_CACHE_RATIO` of the maximum size of the shared cache.
+const LOCAL_CACHE_RATIO: usize = 8;
+
+/// The values may use up to `1 / VALUE_CACHE_RATIO` of the maximum size of the shared cache, in
+/// addition to the nodes.
+const VALUE_CACHE_RATIO: usize = 4;
+
+/// Estimation of the memory used by a cached node in addition to its data, i.e. its hash and the
+/// bookkeeping of the map.
+const NODE_OVERHEAD: usize = 64;
+
+fn node_size(node: &DBValue) -> usize {
+	node.len() + NODE_OVERHEAD
+}
+
+/// Estimation of the memory used by a cached value of `key`.
+fn value_size(key: &[u8], value: &Option<Vec<u8>>) -> usize {
+	key.len() + value.as_ref().map_or(0, |value| value.len()) + NODE_OVERHEAD
+}
+
+/// Reads of trie nodes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrieCacheStats {
+	/// Number of nodes found in a cache.
+	pub hits: u64,
+	/// Number of nodes read from the database.
+	pub misses: u64,
+	/// Accumulated size of the nodes read from the database, in bytes.
+	pub bytes_read: u64,
+}
+
+impl TrieCacheStats {
+	/// The reads in `self` which are not in `previous`, both being accumulated statistics.
+	pub fn since(&self, previous: &Self) -> Self {
+		TrieCacheStats {
+			hits: self.hits.saturating_sub(previous.hits),
+			misses: self.misses.saturating_sub(previous.misses),
+			bytes_read: self.bytes_read.saturating_sub(previous.bytes_read),
+		}
+	}
+}
+
+/// Least recently used entries, bounded by their estimated size.
+struct Lru<K, V> {
+	entries: LinkedHashMap<K, V>,
+	size: usize,
+	max_size: usize,
+	entry_size: fn(&K, &V) -> usize,
+}
+
+impl<K: Eq + StdHash, V: Clone> Lru<K, V> {
+	fn new(max_size: usize, entry_size: fn(&K, &V) -> usize) -> Self {
+		Lru { entries: LinkedHashMap::new(), size: 0, max_size, entry_size }
+	}
+
+	fn get(&mut self, key: &K) -> Option<V> {
+		self.entries.get_refresh(key).map(|value| value.clone())
+	}
+
+	fn insert(&mut self, key: K, value: V) {
+		let size = (self.entry_size)(&key, &value);
+		if size > self.max_size {
+			return
+		}
+
+		self.size += size;
+		if let Some(old) = self.entries.remove(&key) {
+			self.size -= (self.entry_size)(&key, &old);
+		}
+		self.entries.insert(key, value);
+
+		while self.size > self.max_size {
+			match self.entries.pop_front() {
+				Some((key, value)) => self.size -= (self.entry_size)(&key, &value),
+				None => break,
+			}
+		}
+	}
+}
+
+/// Least recently used nodes, by their hash.
+type LruNodes<H> = Lru<H, DBValue>;
+
+/// Least recently used values of the top trie, by the storage root of their state and their key.
+type LruValues<H> = Lru<(H, Vec<u8>), Option<Vec<u8>>>;
+
+/// Cache of trie nodes shared by all the states of a backend.
+pub struct SharedTrieCache<H: Hasher> {
+	nodes: Arc<Mutex<LruNodes<H::Out>>>,
+	values: Arc<Mutex<LruValues<H::Out>>>,
+	hits: Arc<AtomicU64>,
+	misses: Arc<AtomicU64>,
+	bytes_read: Arc<AtomicU64>,
+	taken: Arc<Mutex<TrieCacheStats>>,
+}
+
+impl<H: Hasher> Clone for SharedTrieCache<H> {
+	fn clone(&self) -> Self {
+		SharedTrieCache {
+			nodes: self.nodes.clone(),
+			values: self.values.clone(),
+			hits: self.hits.clone(),
+			misses: self.misses.clone(),
+			bytes_read: self.bytes_read.clone(),
+			taken: self.taken.clone(),
+		}
+	}
+}
+
+impl<H: Hasher> SharedTrieCache<H> {
+	/// Create a new cache holding up to `max_size` bytes of nodes, and a quarter of it of values.
+	pub fn new(max_size: usize) -> Self {
+		SharedTrieCache {
+			nodes: Arc::new(Mutex::new(Lru::new(max_size, |_, node| node_size(node)))),
+			values: Arc::new(Mutex::new(Lru::new(
+				max_size / VALUE_CACHE_RATIO,
+				|(_, key), value| value_size(key, value),
+			))),
+			hits: Default::default(),
+			misses: Default::default(),
+			bytes_read: Default::default(),
+			taken: Default::default(),
+		}
+	}
+
+	/// Estimation of the memory used by the cached nodes, in bytes.
+	pub fn used_size(&self) -> usize {
+		self.nodes.lock().size
+	}
+
+	/// Returns the value of `key` in the top trie of the state with storage root `root`, reading
+	/// it with `fetch` if it is not cached.
+	pub fn value<E>(
+		&self,
+		root: &H::Out,
+		key: &[u8],
+		fetch: impl FnOnce() -> Result<Option<Vec<u8>>, E>,
+	) -> Result<Option<Vec<u8>>, E> {
+		let cache_key = (*root, key.to_vec());
+		let cached = self.values.lock().get(&cache_key);
+		if let Some(value) = cached {
+			return Ok(value)
+		}
+
+		// The lock is not held while reading the state.
+		let value = fetch()?;
+		self.values.lock().insert(cache_key, value.clone());
+		Ok(value)
+	}
+
+	/// Returns the statistics of the reads since the creation of the cache.
+	pub fn stats(&self) -> TrieCacheStats {
+		TrieCacheStats {
+			hits: self.hits.load(Ordering::Relaxed),
+			misses: self.misses.load(Ordering::Relaxed),
+			bytes_read: self.bytes_read.load(Ordering::Relaxed),
+		}
+	}
+
+	/// Returns the statistics of the reads since the last call.
+	pub fn take_stats(&self) -> TrieCacheStats {
+		let stats = self.stats();
+		let previous = std::mem::replace(&mut *self.taken.lock(), stats);
+		stats.since(&previous)
+	}
+
+	/// Create a local cache reading the nodes missing from the caches from `storage`.
+	pub fn local_cache(&self, storage: Arc<dyn Storage<H>>) -> LocalTrieCache<H> {
+		let max_size = self.nodes.lock().max_size / LOCAL_CACHE_RATIO;
+		LocalTrieCache {
+			storage,
+			shared: self.clone(),
+			local: Mutex::new(LocalNodes { nodes: HashMap::new(), size: 0, max_size }),
+		}
+	}
+}
+
+/// The nodes read by a state, with whether they were fetched from the database.
+struct LocalNodes<H> {
+	nodes: HashMap<H, (DBValue, bool)>,
+	size: usize,
+	max_size: usize,
+}
+
+/// Trie node storage of a single state, backed by the local cache of the state, the shared cache
+/// and the database.
+pub struct LocalTrieCache<H: Hasher> {
+	storage: Arc<dyn Storage<H>>,
+	shared: SharedTrieCache<H>,
+	local: Mutex<LocalNodes<H::Out>>,
+}
+
+impl<H: Hasher> Storage<H> for LocalTrieCache<H> {
+	fn get(&self, key: &H::Out, prefix: Prefix) -> Result<Option<DBValue>, String> {
+		if let Some((node, _)) = self.local.lock().nodes.get(key) {
+			self.shared.hits.fetch_add(1, Ordering::Relaxed);
+			return Ok(Some(node.clone()))
+		}
+
+		// The lock of the shared cache must be released before reading the database.
+		let cached = self.shared.nodes.lock().get(key);
+		let (node, fetched) = match cached {
+			Some(node) => {
+				self.shared.hits.fetch_add(1, Ordering::Relaxed);
+				(node, false)
+			},
+			None => {
+				self.shared.misses.fetch_add(1, Ordering::Relaxed);
+				match self.storage.get(key, prefix)? {
+					Some(node) => {
+						self.shared.bytes_read.fetch_add(node.len() as u64, Ordering::Relaxed);
+						(node, true)
+					},
+					None => return Ok(None),
+				}
+			},
+		};
+
+		let mut local = self.local.lock();
+		let size = node_size(&node);
+		if local.size + size <= local.max_size {
+			local.size += size;
+			local.nodes.insert(*key, (node.clone(), fetched));
+		} else if fetched {
+			// The local cache is full, share the node right away.
+			self.shared.nodes.lock().insert(*key, node.clone());
+		}
+
+		Ok(Some(node))
+	}
+}
+
+impl<H: Hasher> Drop for LocalTrieCache<H> {
+	fn drop(&mut self) {
+		let local = std::mem::take(&mut self.local.get_mut().nodes);
+		let mut shared = self.shared.nodes.lock();
+		for (hash, (node, fetched)) in local {
+			if fetched {
+				shared.insert(hash, node);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::Blake2Hasher;
+	use hash_db::EMPTY_PREFIX;
+
+	type Hash = <Blake2Hasher as Hasher>::Out;
+
+	#[derive(Default)]
+	struct CountingStorage {
+		nodes: HashMap<Hash, DBValue>,
+		reads: Mutex<u32>,
+	}
+
+	impl Storage<Blake2Hasher> for CountingStorage {
+		fn get(&self, key: &Hash, _prefix: Prefix) -> Result<Option<DBValue>, String> {
+			*self.reads.lock() += 1;
+			Ok(self.nodes.get(key).cloned())
+		}
+	}
+
+	fn storage_with(nodes: &[&[u8]]) -> (Arc<CountingStorage>, Vec<Hash>) {
+		let mut storage = CountingStorage::default();
+		let hashes = nodes.iter().map(|node| {
+			let hash = Blake2Hasher::hash(node);
+			storage.nodes.insert(hash, node.to_vec());
+			hash
+		}).collect();
+		(Arc::new(storage), hashes)
+	}
+
+	#[test]
+	fn nodes_are_shared_when_local_cache_is_dropped() {
+		let (storage, hashes) = storage_with(&[b"node1", b"node2"]);
+		let shared = SharedTrieCache::<Blake2Hasher>::new(DEFAULT_TRIE_CACHE_SIZE);
+
+		let local = shared.local_cache(storage.clone());
+		assert_eq!(local.get(&hashes[0], EMPTY_PREFIX).unwrap(), Some(b"node1".to_vec()));
+		assert_eq!(local.get(&hashes[0], EMPTY_PREFIX).unwrap(), Some(b"node1".to_vec()));
+		assert_eq!(*storage.reads.lock(), 1);
+		assert_eq!(shared.used_size(), 0);
+		drop(local);
+
+		assert_eq!(shared.used_size(), node_size(&b"node1".to_vec()));
+		let local = shared.local_cache(storage.clone());
+		assert_eq!(local.get(&hashes[0], EMPTY_PREFIX).unwrap(), Some(b"node1".to_vec()));
+		assert_eq!(local.get(&hashes[1], EMPTY_PREFIX).unwrap(), Some(b"node2".to_vec()));
+		assert_eq!(local.get(&Default::default(), EMPTY_PREFIX).unwrap(), None);
+		assert_eq!(*storage.reads.lock(), 3);
+		assert_eq!(shared.take_stats(), TrieCacheStats { hits: 2, misses: 3, bytes_read: 10 });
+		assert_eq!(shared.take_stats(), TrieCacheStats::default());
+		assert_eq!(shared.stats(), TrieCacheStats { hits: 2, misses: 3, bytes_read: 10 });
+	}
+
+	/// Reads the nodes while checking the size of the shared cache, which locks it.
+	struct LockingStorage {
+		inner: Arc<CountingStorage>,
+		shared: SharedTrieCache<Blake2Hasher>,
+	}
+
+	impl Storage<Blake2Hasher> for LockingStorage {
+		fn get(&self, key: &Hash, prefix: Prefix) -> Result<Option<DBValue>, String> {
+			let _ = self.shared.used_size();
+			self.inner.get(key, prefix)
+		}
+	}
+
+	#[test]
+	fn shared_cache_is_not_locked_while_reading_the_database() {
+		let (storage, hashes) = storage_with(&[b"node1"]);
+		let shared = SharedTrieCache::<Blake2Hasher>::new(DEFAULT_TRIE_CACHE_SIZE);
+		let storage = Arc::new(LockingStorage { inner: storage, shared: shared.clone() });
+
+		let local = shared.local_cache(storage);
+		assert_eq!(local.get(&hashes[0], EMPTY_PREFIX).unwrap(), Some(b"node1".to_vec()));
+	}
+
+	#[test]
+	fn values_are_cached_by_root_and_key() {
+		let shared = SharedTrieCache::<Blake2Hasher>::new(DEFAULT_TRIE_CACHE_SIZE);
+		let (root1, root2) = (Blake2Hasher::hash(b"root1"), Blake2Hasher::hash(b"root2"));
+		let reads = Mutex::new(0);
+		let read = |value: Option<&[u8]>| {
+			*reads.lock() += 1;
+			Ok::<_, ()>(value.map(|value| value.to_vec()))
+		};
+
+		assert_eq!(
+			shared.value(&root1, b"key", || read(Some(b"value1"))),
+			Ok(Some(b"value1".to_vec())),
+		);
+		assert_eq!(shared.value(&root1, b"key", || read(None)), Ok(Some(b"value1".to_vec())));
+		assert_eq!(shared.value(&root2, b"key", || read(None)), Ok(None));
+		assert_eq!(shared.value(&root2, b"key", || read(Some(b"value2"))), Ok(None));
+		assert_eq!(*reads.lock(), 2);
+
+		// Failed reads are not cached.
+		assert_eq!(shared.value(&root1, b"other", || Err(())), Err(()));
+		assert_eq!(shared.value(&root1, b"other", || read(None)), Ok(None));
+	}
+
+	#[test]
+	fn shared_cache_is_bounded() {
+		let (storage, hashes) = storage_with(&[b"node1", b"node2", b"node3"]);
+		let shared = SharedTrieCache::<Blake2Hasher>::new(2 * node_size(&b"node1".to_vec()));
+
+		for hash in &hashes {
+			let local = shared.local_cache(storage.clone());
+			local.get(hash, EMPTY_PREFIX).unwrap();
+		}
+		assert_eq!(shared.used_size(), 2 * node_size(&b"node1".to_vec()));
+
+		// The least recently used node was evicted.
+		let local = shared.local_cache(storage.clone());
+		local.get(&hashes[0], EMPTY_PREFIX).unwrap();
+		local.get(&hashes[2], EMPTY_PREFIX).unwrap();
+		assert_eq!(*storage.reads.lock(), 4);
+	}
+}
@@ -154,6 +154,7 @@ mod tests {
 		crate::utils::open_database::<Block>(&DatabaseSettings {
 			state_cache_size: 0,
 			state_cache_child_ratio: None,
+			trie_cache_maximum_size: None,
 			state_pruning: PruningMode::ArchiveAll,
 			source: DatabaseSettingsSrc::RocksDb { path: db_path.to_owned(), cache_size: 128 },
 			keep_blocks: KeepBlocks::All,
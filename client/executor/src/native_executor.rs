@@ -196,7 +196,6 @@ impl WasmExecutor {
 	///
 	/// In case of problems with during creation of the runtime or instantation, a `Err` is returned.
 	/// that describes the message.
-	#[doc(hidden)] // We use this function for tests across multiple crates.
 	pub fn uncached_call(
 		&self,
 		runtime_blob: RuntimeBlob,
@@ -499,7 +498,10 @@ impl<D: NativeExecutionDispatch + 'static> CodeExecutor for NativeExecutor<D> {
 					|| Error::ApiError("Unknown version".into())
 				)?;
 
-				let can_call_with = onchain_version.can_call_with(&self.native_version.runtime_version);
+				// The native runtime is only used when it has the implementation of the on-chain
+				// runtime, the wasm runtime is used on any mismatch.
+				let can_call_with = onchain_version
+					.can_execute_natively_with(&self.native_version.runtime_version);
 
 				match (
 					use_native,
@@ -1695,7 +1695,7 @@ mod tests {
 			justification_period: 256,
 			keystore: None,
 			name: None,
-			local_role: Role::Authority,
+			local_role: Role::Authority { sentry_nodes: Vec::new() },
 			observer_enabled: true,
 			telemetry: None,
 		}
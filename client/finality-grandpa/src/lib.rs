@@ -488,7 +488,7 @@ impl<Block: BlockT, E> GenesisAuthoritySetProvider<Block> for Arc<dyn ExecutorPr
 				&BlockId::Number(Zero::zero()),
 				"GrandpaApi_grandpa_authorities",
 				&[],
-				ExecutionStrategy::NativeElseWasm,
+				ExecutionStrategy::AlwaysWasm,
 				None,
 			)
 			.and_then(|call_result| {
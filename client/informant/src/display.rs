@@ -101,6 +101,12 @@ impl<B: BlockT> InformantDisplay<B> {
 				format!("Syncing{}", speed),
 				format!(", target=#{}", n),
 			),
+			(SyncState::Importing, None) => ("⚙️ ", format!("Importing{}", speed), "".into()),
+			(SyncState::Importing, Some(n)) => (
+				"⚙️ ",
+				format!("Importing{}", speed),
+				format!(", target=#{}", n),
+			),
 		};
 
 		if self.format.enable_color {
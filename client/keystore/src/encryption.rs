@@ -0,0 +1,202 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encryption of the key files at rest.
+//!
+//! When the keystore has a password, the secret URIs are encrypted with XChaCha20-Poly1305 before
+//! being written to the key files. The encryption key is derived from the password and a random
+//! salt with Argon2id. Key files written without a password contain the secret URI in plain text
+//! and are still read as before.
+//!
+//! Argon2 is deliberately slow, so the keys derived from the password are cached by the
+//! [`KeyFileCipher`] of the keystore: the key files it writes share a salt, chosen when the
+//! keystore is opened, and the key of any other salt is only derived the first time a key file
+//! using it is read.
+
+use std::collections::HashMap;
+use chacha20poly1305::{
+	aead::{Aead, NewAead},
+	Key, XChaCha20Poly1305, XNonce,
+};
+use parking_lot::Mutex;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::{ExposeSecret, SecretString};
+use crate::{Error, Result};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// The content of a key file.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyFile {
+	/// The secret URI in plain text.
+	Plain(String),
+	/// The encrypted secret URI.
+	Encrypted(EncryptedSuri),
+}
+
+/// A secret URI encrypted with a password, all the fields are hex encoded.
+#[derive(Serialize, Deserialize)]
+pub struct EncryptedSuri {
+	salt: String,
+	nonce: String,
+	ciphertext: String,
+}
+
+impl KeyFile {
+	/// The content of the key file of `suri`, encrypted if a `cipher` is given.
+	pub fn new(suri: &str, cipher: Option<&KeyFileCipher>) -> Result<Self> {
+		match cipher {
+			Some(cipher) => cipher.encrypt(suri).map(KeyFile::Encrypted),
+			None => Ok(KeyFile::Plain(suri.to_owned())),
+		}
+	}
+
+	/// The secret URI of the key file, decrypted with `cipher` if it is encrypted.
+	pub fn into_suri(self, cipher: Option<&KeyFileCipher>) -> Result<String> {
+		match (self, cipher) {
+			(KeyFile::Plain(suri), _) => Ok(suri),
+			(KeyFile::Encrypted(encrypted), Some(cipher)) => cipher.decrypt(encrypted),
+			(KeyFile::Encrypted(_), None) => Err(Error::InvalidPassword),
+		}
+	}
+}
+
+/// Encrypts and decrypts the key files of a keystore with its password.
+pub struct KeyFileCipher {
+	password: SecretString,
+	/// The salt of the key files written by the keystore.
+	salt: [u8; SALT_LEN],
+	/// The ciphers derived from the password, by salt.
+	ciphers: Mutex<HashMap<Vec<u8>, XChaCha20Poly1305>>,
+}
+
+impl KeyFileCipher {
+	/// Create the cipher of a keystore protected by `password`.
+	pub fn new(password: &str) -> Self {
+		let mut salt = [0u8; SALT_LEN];
+		OsRng.fill_bytes(&mut salt);
+		KeyFileCipher {
+			password: SecretString::new(password.to_owned()),
+			salt,
+			ciphers: Default::default(),
+		}
+	}
+
+	/// Run `f` with the cipher of `salt`, deriving it from the password if it isn't cached yet.
+	fn with_cipher<R>(&self, salt: &[u8], f: impl FnOnce(&XChaCha20Poly1305) -> R) -> Result<R> {
+		let mut ciphers = self.ciphers.lock();
+		if let Some(cipher) = ciphers.get(salt) {
+			return Ok(f(cipher))
+		}
+
+		let config = argon2::Config { variant: argon2::Variant::Argon2id, ..Default::default() };
+		let key = argon2::hash_raw(self.password.expose_secret().as_bytes(), salt, &config)
+			.map_err(|_| Error::Encryption)?;
+		let cipher = XChaCha20Poly1305::new(Key::from_slice(&key));
+		let result = f(&cipher);
+		ciphers.insert(salt.to_vec(), cipher);
+		Ok(result)
+	}
+
+	fn encrypt(&self, suri: &str) -> Result<EncryptedSuri> {
+		let mut nonce = [0u8; NONCE_LEN];
+		OsRng.fill_bytes(&mut nonce);
+
+		let ciphertext = self.with_cipher(&self.salt, |cipher| {
+			cipher.encrypt(XNonce::from_slice(&nonce), suri.as_bytes())
+		})?.map_err(|_| Error::Encryption)?;
+
+		Ok(EncryptedSuri {
+			salt: hex::encode(self.salt),
+			nonce: hex::encode(nonce),
+			ciphertext: hex::encode(ciphertext),
+		})
+	}
+
+	fn decrypt(&self, encrypted: EncryptedSuri) -> Result<String> {
+		let decode = |field: &str| hex::decode(field).map_err(|_| Error::Encryption);
+		let salt = decode(&encrypted.salt)?;
+		let nonce = decode(&encrypted.nonce)?;
+		let ciphertext = decode(&encrypted.ciphertext)?;
+		if nonce.len() != NONCE_LEN {
+			return Err(Error::Encryption)
+		}
+
+		let suri = self.with_cipher(&salt, |cipher| {
+			cipher.decrypt(XNonce::from_slice(&nonce), &ciphertext[..])
+		})?.map_err(|_| Error::InvalidPassword)?;
+
+		String::from_utf8(suri).map_err(|_| Error::Encryption)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encrypted_suri_is_decrypted_with_the_password() {
+		let key_file = KeyFile::new("//Alice", Some(&KeyFileCipher::new("password"))).unwrap();
+		let content = serde_json::to_string(&key_file).unwrap();
+		assert!(!content.contains("Alice"));
+
+		// Decrypted by another keystore, which derives the key of the salt of the file.
+		let read = |cipher: Option<&KeyFileCipher>| {
+			serde_json::from_str::<KeyFile>(&content).unwrap().into_suri(cipher)
+		};
+		assert_eq!(read(Some(&KeyFileCipher::new("password"))).unwrap(), "//Alice");
+		assert!(matches!(read(Some(&KeyFileCipher::new("wrong"))), Err(Error::InvalidPassword)));
+		assert!(matches!(read(None), Err(Error::InvalidPassword)));
+	}
+
+	#[test]
+	fn derived_keys_are_cached() {
+		let cipher = KeyFileCipher::new("password");
+		let alice = KeyFile::new("//Alice", Some(&cipher)).unwrap();
+		let bob = KeyFile::new("//Bob", Some(&cipher)).unwrap();
+		assert_eq!(cipher.ciphers.lock().len(), 1);
+
+		// The files written by the keystore share its salt, but not their nonce.
+		match (&alice, &bob) {
+			(KeyFile::Encrypted(alice), KeyFile::Encrypted(bob)) => {
+				assert_eq!(alice.salt, bob.salt);
+				assert_ne!(alice.nonce, bob.nonce);
+			},
+			_ => panic!("The key files are encrypted"),
+		}
+		assert_eq!(alice.into_suri(Some(&cipher)).unwrap(), "//Alice");
+		assert_eq!(bob.into_suri(Some(&cipher)).unwrap(), "//Bob");
+		assert_eq!(cipher.ciphers.lock().len(), 1);
+
+		let other = KeyFile::new("//Charlie", Some(&KeyFileCipher::new("password"))).unwrap();
+		assert_eq!(other.into_suri(Some(&cipher)).unwrap(), "//Charlie");
+		assert_eq!(cipher.ciphers.lock().len(), 2);
+	}
+
+	#[test]
+	fn plain_suri_is_compatible_with_previous_key_files() {
+		let content = serde_json::to_string(&KeyFile::new("//Alice", None).unwrap()).unwrap();
+		assert_eq!(content, serde_json::to_string("//Alice").unwrap());
+
+		let key_file: KeyFile = serde_json::from_str(&content).unwrap();
+		assert_eq!(key_file.into_suri(Some("password")).unwrap(), "//Alice");
+	}
+}
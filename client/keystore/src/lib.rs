@@ -25,6 +25,8 @@ use sp_keystore::Error as TraitError;
 
 /// Local keystore implementation
 mod local;
+/// Encryption of the key files
+mod encryption;
 pub use local::LocalKeystore;
 
 /// Keystore error.
@@ -49,6 +51,9 @@ pub enum Error {
 	/// Keystore unavailable
 	#[display(fmt="Keystore unavailable")]
 	Unavailable,
+	/// A key file could not be encrypted or decrypted
+	#[display(fmt="Invalid encrypted key file")]
+	Encryption,
 }
 
 /// Keystore Result
@@ -62,6 +67,7 @@ impl From<Error> for TraitError {
 				TraitError::ValidationError(error.to_string())
 			},
 			Error::Unavailable => TraitError::Unavailable,
+			Error::Encryption => TraitError::Other(error.to_string()),
 			Error::Io(e) => TraitError::Other(e.to_string()),
 			Error::Json(e) => TraitError::Other(e.to_string()),
 		}
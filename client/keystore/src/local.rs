@@ -40,13 +40,16 @@ use sp_keystore::{
 };
 use sp_application_crypto::{ed25519, sr25519, ecdsa, AppPair, AppKey, IsWrappedBy};
 
-use crate::{Result, Error};
+use crate::{Result, Error, encryption::{KeyFile, KeyFileCipher}};
 
 /// A local based keystore that is either memory-based or filesystem-based.
 pub struct LocalKeystore(RwLock<KeystoreInner>);
 
 impl LocalKeystore {
 	/// Create a local keystore from filesystem.
+	///
+	/// If a `password` is given, the keys are derived with it and the key files written by the
+	/// keystore are encrypted with it.
 	pub fn open<T: Into<PathBuf>>(path: T, password: Option<SecretString>) -> Result<Self> {
 		let inner = KeystoreInner::open(path, password)?;
 		Ok(Self(RwLock::new(inner)))
@@ -65,6 +68,18 @@ impl LocalKeystore {
 	pub fn key_pair<Pair: AppPair>(&self, public: &<Pair as AppKey>::Public) -> Result<Option<Pair>> {
 		self.0.read().key_pair::<Pair>(public)
 	}
+
+	/// Get the ed25519 key pair of the given key type for the given public key.
+	///
+	/// Returns `Ok(None)` if the key doesn't exist, `Ok(Some(_))` if the key exists and
+	/// `Err(_)` when something failed.
+	pub fn ed25519_key_pair(
+		&self,
+		key_type: KeyTypeId,
+		public: &ed25519::Public,
+	) -> Result<Option<ed25519::Pair>> {
+		self.0.read().key_pair_by_type::<ed25519::Pair>(public, key_type)
+	}
 }
 
 #[async_trait]
@@ -346,6 +361,8 @@ struct KeystoreInner {
 	/// Map over `(KeyTypeId, Raw public key)` -> `Key phrase/seed`
 	additional: HashMap<(KeyTypeId, Vec<u8>), String>,
 	password: Option<SecretString>,
+	/// Encrypts the key files with the password, if any.
+	cipher: Option<KeyFileCipher>,
 }
 
 impl KeystoreInner {
@@ -356,7 +373,8 @@ impl KeystoreInner {
 		let path = path.into();
 		fs::create_dir_all(&path)?;
 
-		let instance = Self { path: Some(path), additional: HashMap::new(), password };
+		let cipher = password.as_ref().map(|p| KeyFileCipher::new(p.expose_secret()));
+		let instance = Self { path: Some(path), additional: HashMap::new(), password, cipher };
 		Ok(instance)
 	}
 
@@ -372,7 +390,8 @@ impl KeystoreInner {
 		Self {
 			path: None,
 			additional: HashMap::new(),
-			password: None
+			password: None,
+			cipher: None,
 		}
 	}
 
@@ -399,9 +418,7 @@ impl KeystoreInner {
 	/// Places it into the file system store, if a path is configured.
 	fn insert_unknown(&self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<()> {
 		if let Some(path) = self.key_file_path(public, key_type) {
-			let mut file = File::create(path).map_err(Error::Io)?;
-			serde_json::to_writer(&file, &suri).map_err(Error::Json)?;
-			file.flush().map_err(Error::Io)?;
+			self.write_to_file(path, suri)?;
 		}
 		Ok(())
 	}
@@ -413,9 +430,7 @@ impl KeystoreInner {
 	fn generate_by_type<Pair: PairT>(&mut self, key_type: KeyTypeId) -> Result<Pair> {
 		let (pair, phrase, _) = Pair::generate_with_phrase(self.password());
 		if let Some(path) = self.key_file_path(pair.public().as_slice(), key_type) {
-			let mut file = File::create(path)?;
-			serde_json::to_writer(&file, &phrase)?;
-			file.flush()?;
+			self.write_to_file(path, &phrase)?;
 		} else {
 			self.insert_ephemeral_pair(&pair, &phrase, key_type);
 		}
@@ -449,8 +464,9 @@ impl KeystoreInner {
 
 		if path.exists() {
 			let file = File::open(path)?;
+			let content: KeyFile = serde_json::from_reader(&file)?;
 
-			serde_json::from_reader(&file).map_err(Into::into).map(Some)
+			content.into_suri(self.cipher.as_ref()).map(Some)
 		} else {
 			Ok(None)
 		}
@@ -480,6 +496,35 @@ impl KeystoreInner {
 		}
 	}
 
+	/// Write the secret URI of a key to `file`, only readable and writable by its owner.
+	///
+	/// The secret URI is encrypted if the store has a password.
+	fn write_to_file(&self, file: PathBuf, suri: &str) -> Result<()> {
+		let content = KeyFile::new(suri, self.cipher.as_ref())?;
+		let mut options = fs::OpenOptions::new();
+		options.write(true).create(true).truncate(true);
+
+		// Create the file with the restricted permissions, so that it's never readable by others.
+		#[cfg(target_family = "unix")]
+		{
+			use std::os::unix::fs::OpenOptionsExt;
+			options.mode(0o600);
+		}
+
+		let mut file = options.open(file)?;
+
+		// The mode only applies to new files, restrict an overwritten file before writing to it.
+		#[cfg(target_family = "unix")]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			file.set_permissions(fs::Permissions::from_mode(0o600))?;
+		}
+
+		serde_json::to_writer(&file, &content)?;
+		file.flush()?;
+		Ok(())
+	}
+
 	/// Get the file path for the given public key and key type.
 	///
 	/// Returns `None` if the keystore only exists in-memory and there isn't any path to provide.
@@ -704,6 +749,30 @@ mod tests {
 		assert_eq!(key_pair.public(), store_key_pair.public());
 	}
 
+	#[cfg(target_family = "unix")]
+	#[test]
+	fn stored_keys_are_only_accessible_by_owner() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let temp_dir = TempDir::new().unwrap();
+		let store = KeystoreInner::open(temp_dir.path(), None).unwrap();
+
+		let key_pair = sr25519::AppPair::from_string("//Alice", None).expect("Generates key pair");
+		store.insert_unknown(SR25519, "//Alice", key_pair.public().as_ref())
+			.expect("Inserts unknown key");
+
+		let path = store.key_file_path(key_pair.public().as_ref(), SR25519).unwrap();
+		let mode = fs::metadata(&path).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o600);
+
+		// An existing key file readable by others is restricted when it's overwritten.
+		fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+		store.insert_unknown(SR25519, "//Alice", key_pair.public().as_ref())
+			.expect("Inserts unknown key");
+		let mode = fs::metadata(&path).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o600);
+	}
+
 	#[test]
 	fn store_ignores_files_with_invalid_name() {
 		let temp_dir = TempDir::new().unwrap();
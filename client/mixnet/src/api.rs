@@ -0,0 +1,59 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The handle through which extrinsics are submitted to the mixnet service.
+
+use crate::error::Error;
+use futures::channel::oneshot;
+use sp_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+
+/// A request to the mixnet service.
+pub(crate) struct Request {
+	pub(crate) extrinsic: Vec<u8>,
+	pub(crate) reply: oneshot::Sender<Result<(), Error>>,
+}
+
+/// Create a handle to the mixnet service, and the backend to pass to [`run`](crate::run).
+pub fn api() -> (Api, ApiBackend) {
+	let (sender, receiver) = tracing_unbounded("mpsc_mixnet_api");
+	(Api { sender }, ApiBackend { receiver })
+}
+
+/// A handle to the mixnet service, used to submit extrinsics through the mixnet.
+#[derive(Clone)]
+pub struct Api {
+	sender: TracingUnboundedSender<Request>,
+}
+
+impl Api {
+	/// Submit `extrinsic` through the mixnet.
+	///
+	/// Resolves once the extrinsic has been sent to the first mixnode of its route. Whether it
+	/// reaches the transaction pool of the last mixnode is not reported.
+	pub async fn submit_extrinsic(&self, extrinsic: Vec<u8>) -> Result<(), Error> {
+		let (reply, reply_receiver) = oneshot::channel();
+		self.sender
+			.unbounded_send(Request { extrinsic, reply })
+			.map_err(|_| Error::ServiceUnavailable)?;
+		reply_receiver.await.map_err(|_| Error::ServiceUnavailable)?
+	}
+}
+
+/// The receiving end of the [`Api`] handles, consumed by the mixnet service.
+pub struct ApiBackend {
+	pub(crate) receiver: TracingUnboundedReceiver<Request>,
+}
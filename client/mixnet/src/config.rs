@@ -0,0 +1,54 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Configuration of the mixnet service.
+
+use std::time::Duration;
+
+/// Configuration of the mixnet service.
+#[derive(Clone, Debug)]
+pub struct Config {
+	/// Whether to register as a mixnode, when the node is an authority of the following
+	/// session.
+	pub mixnode: bool,
+	/// The addresses at which the node can be reached, as multiaddrs. Published in the mixnode
+	/// registrations.
+	pub external_addresses: Vec<String>,
+	/// The number of mixnodes through which requests are routed. Capped by
+	/// [`packet::MAX_HOPS`](crate::packet::MAX_HOPS) and by the size of the mixnode set.
+	pub num_hops: usize,
+	/// The mean delay added by each mixnode before forwarding a packet.
+	pub mean_forwarding_delay: Duration,
+	/// The mean interval between two cover packets.
+	pub mean_cover_interval: Duration,
+	/// The maximum number of packets waiting to be forwarded. Packets received when the queue
+	/// is full are dropped.
+	pub max_forward_queue: usize,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			mixnode: false,
+			external_addresses: Vec::new(),
+			num_hops: 3,
+			mean_forwarding_delay: Duration::from_millis(500),
+			mean_cover_interval: Duration::from_secs(2),
+			max_forward_queue: 1024,
+		}
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Mixnet service errors.
+
+use sp_mixnet::MixnodesErr;
+
+/// The error returned when an extrinsic cannot be submitted through the mixnet.
+#[derive(Debug, derive_more::Display)]
+pub enum Error {
+	/// The mixnode set through which requests are routed cannot be used.
+	#[display(fmt = "The mixnode set cannot be used: {}", _0)]
+	Mixnodes(MixnodesErr),
+	/// The mixnode sets are not known yet.
+	#[display(fmt = "The mixnode sets are not known yet")]
+	NoSession,
+	/// The extrinsic does not fit in a packet.
+	#[display(fmt = "The extrinsic is too large: {} bytes, the maximum is {}", size, max)]
+	ExtrinsicTooLarge {
+		/// The size of the extrinsic.
+		size: usize,
+		/// The maximum size of an extrinsic.
+		max: usize,
+	},
+	/// The mixnet service is not running.
+	#[display(fmt = "The mixnet service is not running")]
+	ServiceUnavailable,
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+//! Substrate mixnet service.
+//!
+//! The mixnet lets users submit extrinsics to the network without revealing their IP address.
+//! An extrinsic submitted through the mixnet is wrapped in a Sphinx packet (see [`packet`]) and
+//! routed through several mixnodes, each of which only learns the previous and the next hop, and
+//! delays the packet by a random amount of time. The last mixnode imports the extrinsic into its
+//! transaction pool. All the nodes send cover packets at random intervals, which cannot be told
+//! apart from requests.
+//!
+//! The mixnodes of each session are the authorities of the session which registered with the
+//! mixnet pallet, whose registry is read through the [`MixnetApi`](sp_mixnet::MixnetApi) runtime
+//! API. The service is driven by [`run`], and extrinsics are submitted through the [`Api`]
+//! handle returned by [`api`]. The packets are exchanged through a request-response protocol of
+//! `sc-network`, see [`request_response_config`] and [`MixnetNetwork`].
+
+mod api;
+mod config;
+mod error;
+mod mixnet;
+mod network;
+pub mod packet;
+mod run;
+
+pub use api::{api, Api, ApiBackend};
+pub use config::Config;
+pub use error::Error;
+pub use mixnet::{Handled, Mixnet};
+pub use network::{generate_protocol_name, request_response_config, MixnetNetwork};
+pub use run::{run, Network};
@@ -0,0 +1,346 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The mixnet state machine, independent of the network and of the runtime.
+
+use crate::{config::Config, error::Error, packet::{self, Action, Hop, ReplayTag}};
+use rand::{Rng, seq::index::sample, thread_rng};
+use rand_distr::{Distribution, Exp};
+use sp_mixnet::{
+	KxPublic, Mixnode, MixnodePeerId, MixnodesErr, SessionIndex, SessionPhase, SessionStatus,
+};
+use std::{collections::{BTreeMap, HashSet}, time::Duration};
+
+/// The kind of a payload, the first byte of the plaintext payload.
+const PAYLOAD_COVER: u8 = 0;
+const PAYLOAD_EXTRINSIC: u8 = 1;
+/// The size of the kind and of the length of the data, at the start of the plaintext payload.
+const PAYLOAD_PREFIX_SIZE: usize = 3;
+
+/// The maximum size of an extrinsic submitted through the mixnet.
+pub const MAX_EXTRINSIC_SIZE: usize = packet::PAYLOAD_SIZE - PAYLOAD_PREFIX_SIZE;
+
+/// What to do with a packet received from the network.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Handled {
+	/// Send `packet` to `peer_id` once `delay` elapsed.
+	Forward {
+		/// The peer ID of the next mixnode.
+		peer_id: MixnodePeerId,
+		/// The packet to forward.
+		packet: Vec<u8>,
+		/// The delay after which to forward the packet.
+		delay: Duration,
+	},
+	/// Import the extrinsic, the packet reached the end of its route.
+	DeliverExtrinsic(Vec<u8>),
+}
+
+struct Session {
+	index: SessionIndex,
+	mixnodes: Result<Vec<Mixnode>, MixnodesErr>,
+	replay_filter: HashSet<ReplayTag>,
+}
+
+impl Session {
+	fn new(index: SessionIndex, mixnodes: Result<Vec<Mixnode>, MixnodesErr>) -> Self {
+		Self { index, mixnodes, replay_filter: HashSet::new() }
+	}
+}
+
+/// The mixnet state of a node.
+///
+/// Follows the sessions and their mixnode sets, builds the request and cover packets and
+/// handles the packets received from the network.
+pub struct Mixnet {
+	config: Config,
+	status: Option<SessionStatus>,
+	current: Option<Session>,
+	prev: Option<Session>,
+	/// The key-exchange secret keys of the local mixnode, by session.
+	kx_secrets: BTreeMap<SessionIndex, [u8; 32]>,
+}
+
+impl Mixnet {
+	/// Create the mixnet state, with no session known yet.
+	pub fn new(config: Config) -> Self {
+		Self { config, status: None, current: None, prev: None, kx_secrets: BTreeMap::new() }
+	}
+
+	/// The configuration of the mixnet.
+	pub fn config(&self) -> &Config {
+		&self.config
+	}
+
+	/// The last session status passed to [`Mixnet::update_session`].
+	pub fn session_status(&self) -> Option<SessionStatus> {
+		self.status
+	}
+
+	/// Update the session status and the mixnode sets of the current and previous sessions.
+	///
+	/// The replay filters are kept as long as the session stays known.
+	pub fn update_session(
+		&mut self,
+		status: SessionStatus,
+		prev_mixnodes: Result<Vec<Mixnode>, MixnodesErr>,
+		current_mixnodes: Result<Vec<Mixnode>, MixnodesErr>,
+	) {
+		let index = status.current_index;
+		let (mut prev, mut current) = (None, None);
+		for session in self.prev.take().into_iter().chain(self.current.take()) {
+			if session.index + 1 == index {
+				prev = Some(session);
+			} else if session.index == index {
+				current = Some(session);
+			}
+		}
+
+		let mut prev = prev.unwrap_or_else(|| Session::new(index.saturating_sub(1), Ok(vec![])));
+		prev.mixnodes = prev_mixnodes;
+		let mut current = current.unwrap_or_else(|| Session::new(index, Ok(vec![])));
+		current.mixnodes = current_mixnodes;
+
+		if status.phase == SessionPhase::DisconnectFromPrev {
+			prev.mixnodes = Ok(Vec::new());
+		}
+
+		self.prev = Some(prev);
+		self.current = Some(current);
+		self.status = Some(status);
+		// Keep the keys of the previous, current and following sessions.
+		self.kx_secrets = self.kx_secrets.split_off(&index.saturating_sub(1));
+	}
+
+	/// The key-exchange public key to register the local mixnode with for the session
+	/// following `session_index`.
+	///
+	/// The secret key is generated on the first call and kept until the session after the
+	/// following session ends.
+	pub fn kx_public_for_registration(&mut self, session_index: SessionIndex) -> KxPublic {
+		let secret = *self.kx_secrets
+			.entry(session_index + 1)
+			.or_insert_with(|| packet::gen_kx_secret(&mut thread_rng()).0);
+		x25519_dalek::x25519(secret, x25519_dalek::X25519_BASEPOINT_BYTES)
+	}
+
+	/// The session whose mixnode set requests are routed through.
+	fn request_session(&self) -> Option<&Session> {
+		match self.status?.phase {
+			SessionPhase::CoverToCurrent => self.prev.as_ref(),
+			_ => self.current.as_ref(),
+		}
+	}
+
+	/// The mixnode set a cover packet is routed through.
+	///
+	/// Until [`SessionPhase::DisconnectFromPrev`], cover traffic is split between the mixnode
+	/// sets of the previous and of the current sessions, so that the traffic through both sets
+	/// stays steady while requests switch to the current set.
+	fn cover_mixnodes(&self) -> Option<&[Mixnode]> {
+		let usable = |session: &Option<Session>| session
+			.as_ref()
+			.and_then(|session| session.mixnodes.as_ref().ok())
+			.filter(|mixnodes| !mixnodes.is_empty())
+			.map(Vec::as_slice);
+		let prev = match self.status?.phase {
+			SessionPhase::DisconnectFromPrev => None,
+			_ => usable(&self.prev),
+		};
+		match (prev, usable(&self.current)) {
+			(Some(prev), Some(current)) => Some(if thread_rng().gen() { prev } else { current }),
+			(prev, current) => current.or(prev),
+		}
+	}
+
+	/// Build a packet carrying `extrinsic` through the mixnet.
+	///
+	/// Returns the first mixnode to send the packet to, and the packet.
+	pub fn build_request(&self, extrinsic: &[u8]) -> Result<(MixnodePeerId, Vec<u8>), Error> {
+		if extrinsic.len() > MAX_EXTRINSIC_SIZE {
+			return Err(Error::ExtrinsicTooLarge { size: extrinsic.len(), max: MAX_EXTRINSIC_SIZE })
+		}
+		let session = self.request_session().ok_or(Error::NoSession)?;
+		let mixnodes = session.mixnodes.as_ref().map_err(|err| Error::Mixnodes(*err))?;
+		self.build_packet(mixnodes, PAYLOAD_EXTRINSIC, extrinsic).ok_or(Error::NoSession)
+	}
+
+	/// Build a cover packet, if a mixnode set is known.
+	///
+	/// Returns the first mixnode to send the packet to, and the packet.
+	pub fn build_cover(&self) -> Option<(MixnodePeerId, Vec<u8>)> {
+		self.build_packet(self.cover_mixnodes()?, PAYLOAD_COVER, &[])
+	}
+
+	fn build_packet(
+		&self,
+		mixnodes: &[Mixnode],
+		kind: u8,
+		data: &[u8],
+	) -> Option<(MixnodePeerId, Vec<u8>)> {
+		if mixnodes.is_empty() || mixnodes.len() > u16::MAX as usize {
+			return None
+		}
+		let num_hops = self.config.num_hops.min(packet::MAX_HOPS).min(mixnodes.len()).max(1);
+
+		let mut rng = thread_rng();
+		let route: Vec<_> = sample(&mut rng, mixnodes.len(), num_hops)
+			.into_iter()
+			.map(|index| Hop { kx_public: mixnodes[index].kx_public, mixnode_index: index as u16 })
+			.collect();
+
+		let mut payload = Vec::with_capacity(PAYLOAD_PREFIX_SIZE + data.len());
+		payload.push(kind);
+		payload.extend_from_slice(&(data.len() as u16).to_le_bytes());
+		payload.extend_from_slice(data);
+
+		let first_hop = &mixnodes[route[0].mixnode_index as usize];
+		Some((first_hop.peer_id, packet::build(&mut rng, &route, &payload)))
+	}
+
+	/// Handle a packet received from the network.
+	///
+	/// Returns `None` if the packet must be dropped: it is not meant for the local mixnode, it
+	/// was replayed, it is malformed or it is a cover packet which reached the end of its route.
+	pub fn handle_packet(&mut self, packet: &[u8]) -> Option<Handled> {
+		let mean_forwarding_delay = self.config.mean_forwarding_delay;
+		let kx_secrets = &self.kx_secrets;
+		let session = self.current.iter_mut().chain(self.prev.iter_mut()).find_map(|session| {
+			let secret = kx_secrets.get(&session.index)?;
+			packet::peel(secret, packet).ok().map(|peeled| (session, peeled))
+		});
+		let (session, (action, replay_tag)) = match session {
+			Some(session) => session,
+			None => {
+				log::trace!(target: "mixnet", "Dropping a packet which cannot be peeled");
+				return None
+			},
+		};
+
+		if !session.replay_filter.insert(replay_tag) {
+			log::debug!(target: "mixnet", "Dropping a replayed packet");
+			return None
+		}
+
+		match action {
+			Action::Forward { mixnode_index, packet } => {
+				let mixnodes = session.mixnodes.as_ref().ok()?;
+				let peer_id = mixnodes.get(mixnode_index as usize)?.peer_id;
+				let delay = random_delay(mean_forwarding_delay);
+				Some(Handled::Forward { peer_id, packet, delay })
+			},
+			Action::Deliver(payload) => {
+				let mut len = [0; 2];
+				len.copy_from_slice(&payload[1..PAYLOAD_PREFIX_SIZE]);
+				let end = PAYLOAD_PREFIX_SIZE + u16::from_le_bytes(len) as usize;
+				let data = payload.get(PAYLOAD_PREFIX_SIZE..end)?;
+				match payload[0] {
+					PAYLOAD_EXTRINSIC => Some(Handled::DeliverExtrinsic(data.to_vec())),
+					_ => None,
+				}
+			},
+		}
+	}
+
+	/// The delay before sending the next cover packet.
+	pub fn next_cover_delay(&self) -> Duration {
+		random_delay(self.config.mean_cover_interval)
+	}
+}
+
+/// An exponentially distributed delay with the given mean.
+fn random_delay(mean: Duration) -> Duration {
+	match Exp::new(1.0 / mean.as_secs_f64().max(f64::EPSILON)) {
+		Ok(distribution) => Duration::from_secs_f64(distribution.sample(&mut thread_rng())),
+		Err(_) => mean,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn status(current_index: SessionIndex, phase: SessionPhase) -> SessionStatus {
+		SessionStatus { current_index, phase }
+	}
+
+	/// Create `n` mixnets, registered as the mixnodes of session 1.
+	fn mixnodes(n: u8) -> (Vec<Mixnet>, Vec<Mixnode>) {
+		let mut mixnets: Vec<_> = (0..n).map(|_| Mixnet::new(Config::default())).collect();
+		let mixnodes = mixnets.iter_mut().enumerate().map(|(index, mixnet)| Mixnode {
+			kx_public: mixnet.kx_public_for_registration(0),
+			peer_id: [index as u8; 32],
+			external_addresses: Vec::new(),
+		}).collect::<Vec<_>>();
+		for mixnet in &mut mixnets {
+			mixnet.update_session(
+				status(1, SessionPhase::RequestsToCurrent),
+				Ok(Vec::new()),
+				Ok(mixnodes.clone()),
+			);
+		}
+		(mixnets, mixnodes)
+	}
+
+	#[test]
+	fn requests_are_delivered_through_the_mixnodes() {
+		let (mut mixnets, _) = mixnodes(4);
+		let (mut peer_id, mut packet) = mixnets[0].build_request(b"extrinsic").unwrap();
+
+		let mut hops = 0;
+		let extrinsic = loop {
+			hops += 1;
+			match mixnets[peer_id[0] as usize].handle_packet(&packet) {
+				Some(Handled::Forward { peer_id: next, packet: next_packet, .. }) => {
+					peer_id = next;
+					packet = next_packet;
+				},
+				Some(Handled::DeliverExtrinsic(extrinsic)) => break extrinsic,
+				None => panic!("The packet was dropped"),
+			}
+		};
+		assert_eq!(extrinsic, b"extrinsic".to_vec());
+		assert_eq!(hops, Config::default().num_hops);
+
+		// Replayed packets are dropped.
+		assert_eq!(mixnets[peer_id[0] as usize].handle_packet(&packet), None);
+	}
+
+	#[test]
+	fn cover_packets_are_not_delivered() {
+		let (mut mixnets, _) = mixnodes(1);
+		let (_, packet) = mixnets[0].build_cover().unwrap();
+		assert_eq!(mixnets[0].handle_packet(&packet), None);
+	}
+
+	#[test]
+	fn requests_follow_the_session_phases() {
+		let mut mixnet = Mixnet::new(Config::default());
+		assert!(matches!(mixnet.build_request(b"xt"), Err(Error::NoSession)));
+
+		let err = MixnodesErr::InsufficientRegistrations { num: 0, min: 1 };
+		mixnet.update_session(status(2, SessionPhase::CoverToCurrent), Err(err), Ok(Vec::new()));
+		assert!(matches!(mixnet.build_request(b"xt"), Err(Error::Mixnodes(_))));
+
+		mixnet.update_session(status(2, SessionPhase::RequestsToCurrent), Err(err), Err(err));
+		assert!(matches!(mixnet.build_request(b"xt"), Err(Error::Mixnodes(_))));
+		assert!(matches!(
+			mixnet.build_request(&[0; MAX_EXTRINSIC_SIZE + 1]),
+			Err(Error::ExtrinsicTooLarge { .. })
+		));
+	}
+}
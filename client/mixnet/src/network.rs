@@ -0,0 +1,182 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The mixnet protocol of `sc-network`.
+//!
+//! Packets are sent as requests of a request-response protocol, answered with an empty
+//! response as soon as they are received.
+
+use crate::{packet::PACKET_SIZE, run::Network};
+use futures::{channel::mpsc, Stream, StreamExt};
+use libp2p::multihash::{Code, Multihash};
+use sc_network::{
+	config::{
+		identity::ed25519, IncomingRequest, OutgoingResponse, ProtocolId, PublicKey,
+		RequestResponseConfig,
+	},
+	ExHashT, IfDisconnected, Multiaddr, NetworkService, PeerId,
+};
+use sp_mixnet::MixnodePeerId;
+use sp_runtime::traits::Block as BlockT;
+use std::{borrow::Cow, sync::Arc, time::Duration};
+
+const LOG_TARGET: &str = "mixnet";
+
+/// The number of incoming packets buffered before new ones are declined.
+const INBOUND_QUEUE_SIZE: usize = 1024;
+
+/// Generate the mixnet protocol name from the chain specific protocol identifier.
+pub fn generate_protocol_name(protocol_id: &ProtocolId) -> String {
+	format!("/{}/mixnet/1", protocol_id.as_ref())
+}
+
+/// Generate the mixnet request-response protocol configuration, to push to
+/// `NetworkConfiguration::request_response_protocols`, and the stream of the packets received
+/// through it, to pass to [`run`](crate::run).
+pub fn request_response_config(
+	protocol_id: &ProtocolId,
+) -> (RequestResponseConfig, impl Stream<Item = Vec<u8>> + Unpin) {
+	let (sender, receiver) = mpsc::channel(INBOUND_QUEUE_SIZE);
+	let config = RequestResponseConfig {
+		name: generate_protocol_name(protocol_id).into(),
+		max_request_size: PACKET_SIZE as u64,
+		max_response_size: 0,
+		request_timeout: Duration::from_secs(10),
+		inbound_queue: Some(sender),
+	};
+	let packets = receiver.map(|request: IncomingRequest| {
+		let _ = request.pending_response.send(OutgoingResponse {
+			result: Ok(Vec::new()),
+			reputation_changes: Vec::new(),
+			sent_feedback: None,
+		});
+		request.payload
+	});
+	(config, packets)
+}
+
+/// The [`Network`] of the mixnet service over a [`NetworkService`].
+pub struct MixnetNetwork<B: BlockT + 'static, H: ExHashT> {
+	service: Arc<NetworkService<B, H>>,
+	protocol_name: Cow<'static, str>,
+	local_peer_id: MixnodePeerId,
+}
+
+impl<B: BlockT + 'static, H: ExHashT> MixnetNetwork<B, H> {
+	/// Create the mixnet network over `service`, on which the protocol returned by
+	/// [`request_response_config`] has been registered.
+	///
+	/// Fails if the local peer ID isn't an ed25519 public key, which the mixnode registrations
+	/// require.
+	pub fn new(
+		service: Arc<NetworkService<B, H>>,
+		protocol_id: &ProtocolId,
+	) -> Result<Self, String> {
+		let local_peer_id = to_mixnode_peer_id(*service.local_peer_id())
+			.ok_or_else(|| "The mixnet requires an ed25519 node key".to_string())?;
+		Ok(Self {
+			service,
+			protocol_name: generate_protocol_name(protocol_id).into(),
+			local_peer_id,
+		})
+	}
+}
+
+impl<B: BlockT + 'static, H: ExHashT> Network for MixnetNetwork<B, H> {
+	fn local_peer_id(&self) -> MixnodePeerId {
+		self.local_peer_id
+	}
+
+	fn add_known_addresses(&self, peer_id: MixnodePeerId, addresses: &[Vec<u8>]) {
+		let peer_id = match to_peer_id(&peer_id) {
+			Some(peer_id) => peer_id,
+			None => return,
+		};
+		for address in addresses {
+			let address = std::str::from_utf8(address)
+				.ok()
+				.and_then(|address| address.parse::<Multiaddr>().ok());
+			match address {
+				Some(address) => self.service.add_known_address(peer_id, address),
+				None => log::debug!(
+					target: LOG_TARGET,
+					"Ignoring an invalid address of mixnode {}",
+					peer_id,
+				),
+			}
+		}
+	}
+
+	fn send_packet(&self, peer_id: MixnodePeerId, packet: Vec<u8>) {
+		let peer_id = match to_peer_id(&peer_id) {
+			Some(peer_id) => peer_id,
+			None => {
+				log::debug!(target: LOG_TARGET, "Dropping a packet for an invalid mixnode");
+				return
+			},
+		};
+		// Nothing is learnt from the response, so the receiving end is dropped.
+		let (tx, _) = futures::channel::oneshot::channel();
+		self.service.start_request(
+			peer_id,
+			self.protocol_name.clone(),
+			packet,
+			tx,
+			IfDisconnected::TryConnect,
+		);
+	}
+}
+
+/// The libp2p peer ID of the mixnode with the given ed25519 public key.
+fn to_peer_id(peer_id: &MixnodePeerId) -> Option<PeerId> {
+	let public = ed25519::PublicKey::decode(peer_id).ok()?;
+	Some(PublicKey::Ed25519(public).into_peer_id())
+}
+
+/// The ed25519 public key of the node with the given libp2p peer ID, if the peer ID embeds it.
+fn to_mixnode_peer_id(peer_id: PeerId) -> Option<MixnodePeerId> {
+	let multihash = Multihash::from(peer_id);
+	if multihash.code() != u64::from(Code::Identity) {
+		return None
+	}
+	match PublicKey::from_protobuf_encoding(multihash.digest()).ok()? {
+		PublicKey::Ed25519(public) => Some(public.encode()),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_network::config::identity::Keypair;
+
+	#[test]
+	fn peer_id_conversion_roundtrips() {
+		let keypair = ed25519::Keypair::generate();
+		let peer_id = PublicKey::Ed25519(keypair.public()).into_peer_id();
+
+		let mixnode_peer_id = to_mixnode_peer_id(peer_id).unwrap();
+		assert_eq!(mixnode_peer_id, keypair.public().encode());
+		assert_eq!(to_peer_id(&mixnode_peer_id), Some(peer_id));
+	}
+
+	#[test]
+	fn only_ed25519_peer_ids_are_mixnodes() {
+		let keypair = Keypair::generate_secp256k1();
+		assert_eq!(to_mixnode_peer_id(keypair.public().into_peer_id()), None);
+	}
+}
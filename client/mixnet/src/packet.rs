@@ -0,0 +1,305 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The format of the mixnet packets.
+//!
+//! Packets follow the Sphinx format: a header made of a blinded X25519 public key, a MAC and the
+//! onion-encrypted routing information of every hop, followed by an onion-encrypted payload.
+//! All the packets have the same size, whatever their route and content, and each hop can only
+//! learn the next hop from them.
+//!
+//! The routing information and the payload are encrypted with ChaCha20, with keys derived from
+//! the shared secret of each hop. The payload is not authenticated, a modified payload is only
+//! detected by the last hop, when decoding it.
+
+use rand::{CryptoRng, Rng};
+use sp_core::hashing::{blake2_128, blake2_256};
+use sp_mixnet::KxPublic;
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+use chacha20::{ChaCha20, Key, Nonce, stream_cipher::{NewStreamCipher, SyncStreamCipher}};
+
+/// The maximum number of hops of a route.
+pub const MAX_HOPS: usize = 5;
+
+/// The size of the payload of a packet, in bytes.
+pub const PAYLOAD_SIZE: usize = 2048;
+
+const KX_PUBLIC_SIZE: usize = 32;
+const MAC_SIZE: usize = 16;
+const ACTION_SIZE: usize = 2;
+const SLOT_SIZE: usize = ACTION_SIZE + MAC_SIZE;
+const ROUTING_INFO_SIZE: usize = MAX_HOPS * SLOT_SIZE;
+const HEADER_SIZE: usize = KX_PUBLIC_SIZE + MAC_SIZE + ROUTING_INFO_SIZE;
+
+/// The size of a packet, in bytes.
+pub const PACKET_SIZE: usize = HEADER_SIZE + PAYLOAD_SIZE;
+
+/// The action telling the last hop to deliver the payload.
+const DELIVER: u16 = u16::MAX;
+
+/// The tag of a peeled packet, used to detect replayed packets.
+pub type ReplayTag = [u8; 16];
+
+/// A hop of the route of a packet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hop {
+	/// The key-exchange public key of the mixnode.
+	pub kx_public: KxPublic,
+	/// The index of the mixnode in the mixnode set of the session.
+	pub mixnode_index: u16,
+}
+
+/// What a mixnode must do with a packet it peeled.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Action {
+	/// Forward the packet to the mixnode with the given index in the mixnode set.
+	Forward {
+		/// The index of the next mixnode.
+		mixnode_index: u16,
+		/// The packet to forward.
+		packet: Vec<u8>,
+	},
+	/// Deliver the payload, the packet reached the end of its route.
+	Deliver(Vec<u8>),
+}
+
+/// The error returned when a packet cannot be peeled.
+#[derive(Debug, PartialEq, Eq, derive_more::Display)]
+pub enum PeelError {
+	/// The packet does not have the expected size.
+	#[display(fmt = "The packet has a bad size")]
+	BadSize,
+	/// The MAC of the packet does not match, it was modified or it is not meant for this key.
+	#[display(fmt = "The packet has a bad MAC")]
+	BadMac,
+}
+
+struct HopKeys {
+	routing: [u8; 32],
+	mac: [u8; 32],
+	payload: [u8; 32],
+}
+
+impl HopKeys {
+	fn derive(shared_secret: &[u8; 32]) -> Self {
+		let derive = |domain: &[u8]| blake2_256(&[domain, &shared_secret[..]].concat());
+		Self {
+			routing: derive(b"routing"),
+			mac: derive(b"mac"),
+			payload: derive(b"payload"),
+		}
+	}
+}
+
+fn blinding_factor(kx_public: &[u8; 32], shared_secret: &[u8; 32]) -> [u8; 32] {
+	blake2_256(&[&b"blinding"[..], &kx_public[..], &shared_secret[..]].concat())
+}
+
+fn replay_tag(shared_secret: &[u8; 32]) -> ReplayTag {
+	blake2_128(&[&b"replay"[..], &shared_secret[..]].concat())
+}
+
+fn mac(key: &[u8; 32], data: &[u8]) -> [u8; MAC_SIZE] {
+	blake2_128(&[&key[..], data].concat())
+}
+
+/// XOR `data` with the keystream of `key`, starting at `offset`.
+fn apply_keystream(key: &[u8; 32], offset: usize, data: &mut [u8]) {
+	let mut stream = vec![0; offset + data.len()];
+	ChaCha20::new(Key::from_slice(key), Nonce::from_slice(&[0; 12])).apply_keystream(&mut stream);
+	data.iter_mut().zip(&stream[offset..]).for_each(|(byte, key_byte)| *byte ^= key_byte);
+}
+
+/// Generate a key-exchange secret key and its public key.
+pub fn gen_kx_secret(rng: &mut (impl Rng + CryptoRng)) -> ([u8; 32], KxPublic) {
+	let secret: [u8; 32] = rng.gen();
+	(secret, x25519(secret, X25519_BASEPOINT_BYTES))
+}
+
+/// Build a packet carrying `payload` along `route`.
+///
+/// `payload` must be at most [`PAYLOAD_SIZE`] bytes long, it is padded with zeros. The route
+/// must have between 1 and [`MAX_HOPS`] hops.
+pub fn build(rng: &mut (impl Rng + CryptoRng), route: &[Hop], payload: &[u8]) -> Vec<u8> {
+	assert!(!route.is_empty() && route.len() <= MAX_HOPS, "Invalid route length");
+	assert!(payload.len() <= PAYLOAD_SIZE, "Payload too large");
+
+	// Compute the shared secret of every hop. The key-exchange public key seen by each hop is
+	// blinded by all the previous hops, so that the hops cannot link the packets they see.
+	let (secret, first_kx_public) = gen_kx_secret(rng);
+	let mut kx_public = first_kx_public;
+	let mut blinding_factors: Vec<[u8; 32]> = Vec::with_capacity(route.len());
+	let mut keys = Vec::with_capacity(route.len());
+	for hop in route {
+		let shared_secret = blinding_factors.iter()
+			.fold(x25519(secret, hop.kx_public), |shared, factor| x25519(*factor, shared));
+		let factor = blinding_factor(&kx_public, &shared_secret);
+		kx_public = x25519(factor, kx_public);
+		blinding_factors.push(factor);
+		keys.push(HopKeys::derive(&shared_secret));
+	}
+
+	// The filler is what the hops append to the routing information when they shift it, as it
+	// is seen by the last hop.
+	let mut filler = Vec::with_capacity((route.len() - 1) * SLOT_SIZE);
+	for hop_keys in &keys[..route.len() - 1] {
+		filler.extend_from_slice(&[0; SLOT_SIZE]);
+		let offset = ROUTING_INFO_SIZE + SLOT_SIZE - filler.len();
+		apply_keystream(&hop_keys.routing, offset, &mut filler);
+	}
+
+	// Build the routing information from the last hop to the first one.
+	let last_keys = keys.last().expect("The route is not empty; qed");
+	let mut routing_info = vec![0; ROUTING_INFO_SIZE];
+	let padded_len = ROUTING_INFO_SIZE - filler.len();
+	routing_info[..ACTION_SIZE].copy_from_slice(&DELIVER.to_le_bytes());
+	rng.fill(&mut routing_info[SLOT_SIZE..padded_len]);
+	apply_keystream(&last_keys.routing, 0, &mut routing_info[..padded_len]);
+	routing_info[padded_len..].copy_from_slice(&filler);
+	let mut routing_mac = mac(&last_keys.mac, &routing_info);
+
+	for (hop_keys, next_hop) in keys.iter().zip(&route[1..]).rev() {
+		let mut prev_routing_info = vec![0; ROUTING_INFO_SIZE];
+		prev_routing_info[..ACTION_SIZE].copy_from_slice(&next_hop.mixnode_index.to_le_bytes());
+		prev_routing_info[ACTION_SIZE..SLOT_SIZE].copy_from_slice(&routing_mac);
+		prev_routing_info[SLOT_SIZE..]
+			.copy_from_slice(&routing_info[..ROUTING_INFO_SIZE - SLOT_SIZE]);
+		apply_keystream(&hop_keys.routing, 0, &mut prev_routing_info);
+		routing_info = prev_routing_info;
+		routing_mac = mac(&hop_keys.mac, &routing_info);
+	}
+
+	let mut packet = Vec::with_capacity(PACKET_SIZE);
+	packet.extend_from_slice(&first_kx_public);
+	packet.extend_from_slice(&routing_mac);
+	packet.extend_from_slice(&routing_info);
+	let payload_start = packet.len();
+	packet.extend_from_slice(payload);
+	packet.resize(PACKET_SIZE, 0);
+	for hop_keys in &keys {
+		apply_keystream(&hop_keys.payload, 0, &mut packet[payload_start..]);
+	}
+	packet
+}
+
+/// Peel a layer of `packet` with the key-exchange secret key of the local mixnode.
+///
+/// Returns what to do with the packet, and the tag to check against the previously peeled
+/// packets to reject replayed packets.
+pub fn peel(kx_secret: &[u8; 32], packet: &[u8]) -> Result<(Action, ReplayTag), PeelError> {
+	if packet.len() != PACKET_SIZE {
+		return Err(PeelError::BadSize)
+	}
+
+	let mut kx_public = [0; KX_PUBLIC_SIZE];
+	kx_public.copy_from_slice(&packet[..KX_PUBLIC_SIZE]);
+	let routing_mac = &packet[KX_PUBLIC_SIZE..KX_PUBLIC_SIZE + MAC_SIZE];
+	let routing_info = &packet[KX_PUBLIC_SIZE + MAC_SIZE..HEADER_SIZE];
+
+	let shared_secret = x25519(*kx_secret, kx_public);
+	let keys = HopKeys::derive(&shared_secret);
+	if mac(&keys.mac, routing_info) != routing_mac {
+		return Err(PeelError::BadMac)
+	}
+
+	let mut payload = packet[HEADER_SIZE..].to_vec();
+	apply_keystream(&keys.payload, 0, &mut payload);
+
+	// Shift the routing information by a slot, the last slot is filled with the keystream.
+	let mut shifted = routing_info.to_vec();
+	shifted.extend_from_slice(&[0; SLOT_SIZE]);
+	apply_keystream(&keys.routing, 0, &mut shifted);
+
+	let mut action = [0; ACTION_SIZE];
+	action.copy_from_slice(&shifted[..ACTION_SIZE]);
+	let action = match u16::from_le_bytes(action) {
+		DELIVER => Action::Deliver(payload),
+		mixnode_index => {
+			let next_kx_public = x25519(blinding_factor(&kx_public, &shared_secret), kx_public);
+			let mut packet = Vec::with_capacity(PACKET_SIZE);
+			packet.extend_from_slice(&next_kx_public);
+			packet.extend_from_slice(&shifted[ACTION_SIZE..]);
+			packet.extend_from_slice(&payload);
+			Action::Forward { mixnode_index, packet }
+		},
+	};
+	Ok((action, replay_tag(&shared_secret)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::thread_rng;
+
+	fn route(num_hops: usize) -> (Vec<[u8; 32]>, Vec<Hop>) {
+		(0..num_hops)
+			.map(|index| {
+				let (secret, kx_public) = gen_kx_secret(&mut thread_rng());
+				(secret, Hop { kx_public, mixnode_index: index as u16 })
+			})
+			.unzip()
+	}
+
+	#[test]
+	fn packets_are_peeled_along_their_route() {
+		for num_hops in 1..=MAX_HOPS {
+			let (secrets, route) = route(num_hops);
+			let payload = b"extrinsic".to_vec();
+			let mut packet = build(&mut thread_rng(), &route, &payload);
+			let mut replay_tags = Vec::new();
+
+			for (hop, secret) in secrets.iter().enumerate() {
+				assert_eq!(packet.len(), PACKET_SIZE);
+				let (action, replay_tag) = peel(secret, &packet).unwrap();
+				replay_tags.push(replay_tag);
+				match action {
+					Action::Forward { mixnode_index, packet: next } => {
+						assert_eq!(mixnode_index as usize, hop + 1);
+						packet = next;
+					},
+					Action::Deliver(delivered) => {
+						assert_eq!(hop, num_hops - 1);
+						assert_eq!(&delivered[..payload.len()], &payload[..]);
+						assert!(delivered[payload.len()..].iter().all(|byte| *byte == 0));
+					},
+				}
+			}
+
+			replay_tags.sort();
+			replay_tags.dedup();
+			assert_eq!(replay_tags.len(), num_hops);
+		}
+	}
+
+	#[test]
+	fn modified_packets_and_wrong_keys_are_rejected() {
+		let (secrets, route) = route(3);
+		let packet = build(&mut thread_rng(), &route, b"extrinsic");
+
+		assert_eq!(peel(&secrets[1], &packet), Err(PeelError::BadMac));
+		assert_eq!(peel(&secrets[0], &packet[1..]), Err(PeelError::BadSize));
+
+		let mut modified = packet.clone();
+		modified[KX_PUBLIC_SIZE + MAC_SIZE] ^= 1;
+		assert_eq!(peel(&secrets[0], &modified), Err(PeelError::BadMac));
+
+		// The same packet always gives the same replay tag.
+		let (_, tag) = peel(&secrets[0], &packet).unwrap();
+		assert_eq!(peel(&secrets[0], &packet).unwrap().1, tag);
+	}
+}
@@ -0,0 +1,223 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The mixnet service future.
+
+use crate::{api::{ApiBackend, Request}, config::Config, mixnet::{Handled, Mixnet}};
+use codec::Decode;
+use futures::{FutureExt, Stream, StreamExt, stream::FuturesUnordered};
+use futures_timer::Delay;
+use sc_client_api::BlockchainEvents;
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_core::ExecutionContext;
+use sp_mixnet::{MixnetApi, Mixnode, MixnodePeerId, SessionIndex};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_transaction_pool::{TransactionPool, TransactionSource};
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "mixnet";
+
+/// The network through which the mixnet packets are exchanged.
+pub trait Network: Send + 'static {
+	/// The peer ID of the local node, published in the mixnode registrations.
+	fn local_peer_id(&self) -> MixnodePeerId;
+
+	/// Add the `addresses` published by the mixnode with the given peer ID to the addresses
+	/// the network dials it on.
+	fn add_known_addresses(&self, peer_id: MixnodePeerId, addresses: &[Vec<u8>]);
+
+	/// Send `packet` to the mixnode with the given peer ID, connecting to it if needed.
+	fn send_packet(&self, peer_id: MixnodePeerId, packet: Vec<u8>);
+}
+
+/// Run the mixnet service.
+///
+/// Follows the sessions through the best blocks imported by `client`, registers the local
+/// node as a mixnode when configured to, sends the extrinsics submitted through the [`Api`]
+/// handles and the cover packets, and handles the `incoming_packets` received by the network.
+/// The extrinsics delivered by the mixnet are imported into `pool`.
+///
+/// With `sc-network`, `network` is a [`MixnetNetwork`] and `incoming_packets` the stream
+/// returned by [`request_response_config`].
+///
+/// [`Api`]: crate::Api
+/// [`MixnetNetwork`]: crate::MixnetNetwork
+/// [`request_response_config`]: crate::request_response_config
+pub async fn run<B, C, P, N, I>(
+	config: Config,
+	api_backend: ApiBackend,
+	client: Arc<C>,
+	pool: Arc<P>,
+	network: N,
+	incoming_packets: I,
+) where
+	B: BlockT,
+	C: BlockchainEvents<B> + ProvideRuntimeApi<B> + Send + Sync + 'static,
+	C::Api: MixnetApi<B>,
+	P: TransactionPool<Block = B> + 'static,
+	N: Network,
+	I: Stream<Item = Vec<u8>> + Unpin,
+{
+	let mut incoming_packets = incoming_packets.fuse();
+	let mut mixnet = Mixnet::new(config);
+	let mut best_hash = None;
+	let mut registered_for: Option<SessionIndex> = None;
+
+	let mut import_notifications = client.import_notification_stream();
+	let mut requests = api_backend.receiver;
+	let mut cover_timer = Delay::new(mixnet.next_cover_delay()).fuse();
+	let mut forward_queue = FuturesUnordered::new();
+	let mut pending_imports = FuturesUnordered::new();
+
+	loop {
+		futures::select! {
+			notification = import_notifications.next() => {
+				let notification = match notification {
+					Some(notification) => notification,
+					// The client is shutting down.
+					None => return,
+				};
+				if !notification.is_new_best {
+					continue
+				}
+				best_hash = Some(notification.hash);
+
+				let at = BlockId::Hash(notification.hash);
+				if let Err(err) = update_session(&*client, &at, &network, &mut mixnet) {
+					log::warn!(target: LOG_TARGET, "Failed to update the mixnet session: {}", err);
+					continue
+				}
+				let session_index = match mixnet.session_status() {
+					Some(status) if mixnet.config().mixnode => status.current_index,
+					_ => continue,
+				};
+				if registered_for != Some(session_index) {
+					registered_for = Some(session_index);
+					let mixnode = Mixnode {
+						kx_public: mixnet.kx_public_for_registration(session_index),
+						peer_id: network.local_peer_id(),
+						external_addresses: mixnet.config().external_addresses
+							.iter()
+							.map(|address| address.as_bytes().to_vec())
+							.collect(),
+					};
+					match client.runtime_api().maybe_register_with_context(
+						&at,
+						ExecutionContext::OffchainCall(None),
+						session_index,
+						mixnode,
+					) {
+						Ok(true) => log::info!(
+							target: LOG_TARGET,
+							"Registered as a mixnode for session {}",
+							session_index + 1,
+						),
+						Ok(false) => {},
+						Err(err) => log::warn!(
+							target: LOG_TARGET,
+							"Failed to register as a mixnode: {}",
+							err,
+						),
+					}
+				}
+			},
+			request = requests.select_next_some() => {
+				let Request { extrinsic, reply } = request;
+				let result = mixnet.build_request(&extrinsic)
+					.map(|(peer_id, packet)| network.send_packet(peer_id, packet));
+				let _ = reply.send(result);
+			},
+			packet = incoming_packets.select_next_some() => {
+				match mixnet.handle_packet(&packet) {
+					Some(Handled::Forward { peer_id, packet, delay }) => {
+						if forward_queue.len() >= mixnet.config().max_forward_queue {
+							log::debug!(target: LOG_TARGET, "Forward queue full, dropping packet");
+							continue
+						}
+						forward_queue.push(async move {
+							Delay::new(delay).await;
+							(peer_id, packet)
+						});
+					},
+					Some(Handled::DeliverExtrinsic(extrinsic)) => {
+						let xt = B::Extrinsic::decode(&mut &extrinsic[..]);
+						let (xt, at) = match (xt, best_hash) {
+							(Ok(xt), Some(hash)) => (xt, BlockId::Hash(hash)),
+							(Err(err), _) => {
+								log::debug!(
+									target: LOG_TARGET,
+									"Dropping an undecodable extrinsic: {}",
+									err,
+								);
+								continue
+							},
+							(_, None) => continue,
+						};
+						pending_imports.push(pool.submit_one(&at, TransactionSource::External, xt));
+					},
+					None => {},
+				}
+			},
+			(peer_id, packet) = forward_queue.select_next_some() => {
+				network.send_packet(peer_id, packet);
+			},
+			result = pending_imports.select_next_some() => {
+				if let Err(err) = result {
+					log::debug!(
+						target: LOG_TARGET,
+						"Failed to import an extrinsic delivered by the mixnet: {}",
+						err,
+					);
+				}
+			},
+			_ = cover_timer => {
+				if let Some((peer_id, packet)) = mixnet.build_cover() {
+					network.send_packet(peer_id, packet);
+				}
+				cover_timer = Delay::new(mixnet.next_cover_delay()).fuse();
+			},
+		}
+	}
+}
+
+/// Update the session status and the mixnode sets of `mixnet` from the runtime, if the session
+/// status changed. The addresses of the mixnodes are passed on to `network`.
+fn update_session<B, C, N>(
+	client: &C,
+	at: &BlockId<B>,
+	network: &N,
+	mixnet: &mut Mixnet,
+) -> Result<(), ApiError>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: MixnetApi<B>,
+	N: Network,
+{
+	let api = client.runtime_api();
+	let status = api.session_status(at)?;
+	if mixnet.session_status() != Some(status) {
+		let prev_mixnodes = api.prev_mixnodes(at)?;
+		let current_mixnodes = api.current_mixnodes(at)?;
+		let mixnodes = prev_mixnodes.iter().chain(current_mixnodes.iter()).flatten();
+		for mixnode in mixnodes {
+			network.add_known_addresses(mixnode.peer_id, &mixnode.external_addresses);
+		}
+		mixnet.update_session(status, prev_mixnodes, current_mixnodes);
+	}
+	Ok(())
+}
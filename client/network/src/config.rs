@@ -28,6 +28,7 @@ pub use crate::request_responses::{
 	OutgoingResponse,
 	ProtocolConfig as RequestResponseConfig,
 };
+pub use crate::transport::TransportKind;
 pub use libp2p::{identity, core::PublicKey, wasm_ext::ExtTransport, build_multiaddr};
 
 // Note: this re-export shouldn't be part of the public API of the crate and will be removed in
@@ -132,8 +133,18 @@ pub enum Role {
 	Full,
 	/// Regular light node.
 	Light,
+	/// Sentry node guarding authorities. Reported as an authority on the network.
+	Sentry {
+		/// Addresses of the authorities guarded by this sentry, which are always connected to as
+		/// reserved peers.
+		validators: Vec<MultiaddrWithPeerId>,
+	},
 	/// Actual authority.
-	Authority,
+	Authority {
+		/// Addresses of the sentries of this authority. If not empty, the authority only connects
+		/// to its sentries and to the reserved peers.
+		sentry_nodes: Vec<MultiaddrWithPeerId>,
+	},
 }
 
 impl Role {
@@ -142,10 +153,31 @@ impl Role {
 		matches!(self, Role::Authority { .. })
 	}
 
+	/// True for `Role::Sentry`
+	pub fn is_sentry(&self) -> bool {
+		matches!(self, Role::Sentry { .. })
+	}
+
+	/// True for `Role::Authority` and `Role::Sentry`, i.e. the roles reported as an authority on
+	/// the network.
+	pub fn is_network_authority(&self) -> bool {
+		matches!(self, Role::Authority { .. } | Role::Sentry { .. })
+	}
+
 	/// True for `Role::Light`
 	pub fn is_light(&self) -> bool {
 		matches!(self, Role::Light { .. })
 	}
+
+	/// The peers the node must always be connected to because of its role, i.e. the validators
+	/// of a sentry or the sentries of an authority.
+	pub fn reserved_nodes(&self) -> &[MultiaddrWithPeerId] {
+		match self {
+			Role::Sentry { validators } => validators,
+			Role::Authority { sentry_nodes } => sentry_nodes,
+			Role::Full | Role::Light => &[],
+		}
+	}
 }
 
 impl fmt::Display for Role {
@@ -153,6 +185,7 @@ impl fmt::Display for Role {
 		match self {
 			Role::Full => write!(f, "FULL"),
 			Role::Light => write!(f, "LIGHT"),
+			Role::Sentry { .. } => write!(f, "SENTRY"),
 			Role::Authority { .. } => write!(f, "AUTHORITY"),
 		}
 	}
@@ -382,7 +415,11 @@ pub struct NetworkConfiguration {
 	pub listen_addresses: Vec<Multiaddr>,
 	/// Multiaddresses to advertise. Detected automatically if empty.
 	pub public_addresses: Vec<Multiaddr>,
-	/// List of initial node addresses
+	/// List of initial node addresses.
+	///
+	/// DNS addresses (e.g. `/dns/bootnode.example.com/tcp/30333/p2p/...`) are resolved again
+	/// periodically and after repeated dial failures, so a bootnode whose IP address changes behind
+	/// its DNS name stays reachable.
 	pub boot_nodes: Vec<MultiaddrWithPeerId>,
 	/// The node key configuration, which determines the node's network identity keypair.
 	pub node_key: NodeKeyConfig,
@@ -409,6 +446,10 @@ pub struct NetworkConfiguration {
 	/// Should we insert non-global addresses into the DHT?
 	pub allow_non_globals_in_dht: bool,
 
+	/// The transports whose addresses are dialed first when connecting to a peer, most preferred
+	/// first. The addresses of the other transports are dialed last.
+	pub dial_preference: Vec<TransportKind>,
+
 	/// Require iterative Kademlia DHT queries to use disjoint paths for increased resiliency in
 	/// the presence of potentially adversarial nodes.
 	pub kademlia_disjoint_query_paths: bool,
@@ -464,6 +505,7 @@ impl NetworkConfiguration {
 			max_parallel_downloads: 5,
 			enable_dht_random_walk: true,
 			allow_non_globals_in_dht: false,
+			dial_preference: vec![TransportKind::Tcp, TransportKind::WebSocket],
 			kademlia_disjoint_query_paths: false,
 			yamux_window_size: None,
 			ipfs_server: false,
@@ -660,8 +702,9 @@ pub enum Secret<K> {
 	/// Use the given secret key `K`.
 	Input(K),
 	/// Read the secret key from a file. If the file does not exist,
-	/// it is created with a newly generated secret key `K`. The format
-	/// of the file is determined by `K`:
+	/// it is created with a newly generated secret key `K`, readable by
+	/// its owner only. On unix, an existing file which is readable by all
+	/// users is deprecated. The format of the file is determined by `K`:
 	///
 	///   * `ed25519::SecretKey`: An unencoded 32 bytes Ed25519 secret key.
 	File(PathBuf),
@@ -685,8 +728,8 @@ impl NodeKeyConfig {
 	///  * If the secret is configured as input, the corresponding keypair is returned.
 	///
 	///  * If the secret is configured as a file, it is read from that file, if it exists.
-	///    Otherwise a new secret is generated and stored. In either case, the
-	///    keypair obtained from the secret is returned.
+	///    Otherwise a new secret is generated and stored. In either case, the keypair
+	///    obtained from the secret is returned.
 	///
 	///  * If the secret is configured to be new, it is generated and the corresponding
 	///    keypair is returned.
@@ -729,6 +772,8 @@ impl NodeKeyConfig {
 /// Load a secret key from a file, if it exists, or generate a
 /// new secret key and write it to that file. In either case,
 /// the secret key is returned.
+///
+/// A warning is logged if an existing file is readable by all users.
 fn get_secret<P, F, G, E, W, K>(file: P, parse: F, generate: G, serialize: W) -> io::Result<K>
 where
 	P: AsRef<Path>,
@@ -737,7 +782,8 @@ where
 	E: Error + Send + Sync + 'static,
 	W: Fn(&K) -> Vec<u8>,
 {
-	std::fs::read(&file)
+	check_secret_file_permissions(&file)
+		.and_then(|_| std::fs::read(&file))
 		.and_then(|mut sk_bytes|
 			parse(&mut sk_bytes)
 				.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
@@ -755,6 +801,62 @@ where
 		})
 }
 
+/// Warns if an existing file containing a secret key is readable by all users.
+///
+/// Such a file is still accepted, but this is deprecated and will be rejected in the future.
+#[cfg(unix)]
+fn check_secret_file_permissions<P>(path: P) -> io::Result<()>
+where
+	P: AsRef<Path>
+{
+	use std::os::unix::fs::PermissionsExt;
+	let mode = fs::metadata(&path)?.permissions().mode();
+	if mode & 0o004 != 0 {
+		log::warn!(
+			target: "sub-libp2p",
+			"⚠️ The secret key file {} is readable by all users. This is deprecated and will be \
+			rejected in a future release, restrict its permissions, e.g. with `chmod 600`.",
+			path.as_ref().display(),
+		);
+	}
+	Ok(())
+}
+
+/// Warns if an existing file containing a secret key is readable by all users.
+#[cfg(not(unix))]
+fn check_secret_file_permissions<P>(path: P) -> io::Result<()>
+where
+	P: AsRef<Path>
+{
+	fs::metadata(&path).map(|_| ())
+}
+
+/// The name of the file of the network config directory which keeps the peer ID of the node.
+const LOCAL_PEER_ID_FILE: &str = "local_peer_id";
+
+/// Check that `local_peer_id` is the peer ID the node had on its previous run, as kept in the
+/// network config directory `net_config_path`, and keep it for the next run.
+///
+/// Returns the previous peer ID if it is different, i.e. if the node key was replaced.
+pub(crate) fn check_local_peer_id(
+	net_config_path: &Path,
+	local_peer_id: &PeerId,
+) -> io::Result<Option<PeerId>> {
+	let path = net_config_path.join(LOCAL_PEER_ID_FILE);
+	let previous = match fs::read_to_string(&path) {
+		Ok(previous) => previous.trim().parse::<PeerId>().ok(),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+		Err(e) => return Err(e),
+	};
+
+	if previous.as_ref() == Some(local_peer_id) {
+		return Ok(None)
+	}
+
+	fs::write(&path, local_peer_id.to_base58())?;
+	Ok(previous)
+}
+
 /// Write secret bytes to a file.
 fn write_secret_file<P>(path: P, sk_bytes: &[u8]) -> io::Result<()>
 where
@@ -807,6 +909,16 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn dns_addresses_are_valid_bootnodes() {
+		let peer_id = PeerId::random();
+		for addr in &["/dns/example.com/tcp/30333", "/dns4/example.com/tcp/30333/ws"] {
+			let bootnode: MultiaddrWithPeerId = format!("{}/p2p/{}", addr, peer_id).parse().unwrap();
+			assert_eq!(bootnode.peer_id, peer_id);
+			assert_eq!(bootnode.multiaddr, addr.parse::<Multiaddr>().unwrap());
+		}
+	}
+
 	#[test]
 	fn test_secret_file() {
 		let tmp = tempdir_with_prefix("x");
@@ -817,6 +929,40 @@ mod tests {
 		assert!(file.is_file() && secret_bytes(&kp1) == secret_bytes(&kp2))
 	}
 
+	#[cfg(unix)]
+	#[test]
+	fn secret_file_is_created_readable_by_its_owner_only() {
+		use std::os::unix::fs::PermissionsExt;
+		let tmp = tempdir_with_prefix("x");
+		let file = tmp.path().join("x").to_path_buf();
+		NodeKeyConfig::Ed25519(Secret::File(file.clone())).into_keypair().unwrap();
+		assert_eq!(std::fs::metadata(&file).unwrap().permissions().mode() & 0o777, 0o600);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn world_readable_secret_file_is_still_accepted() {
+		use std::os::unix::fs::PermissionsExt;
+		let tmp = tempdir_with_prefix("x");
+		let file = tmp.path().join("x").to_path_buf();
+		let kp1 = NodeKeyConfig::Ed25519(Secret::File(file.clone())).into_keypair().unwrap();
+
+		std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o644)).unwrap();
+		let kp2 = NodeKeyConfig::Ed25519(Secret::File(file.clone())).into_keypair().unwrap();
+		assert_eq!(kp1.public().into_peer_id(), kp2.public().into_peer_id());
+	}
+
+	#[test]
+	fn local_peer_id_change_is_detected() {
+		let tmp = tempdir_with_prefix("x");
+		let (peer_id1, peer_id2) = (PeerId::random(), PeerId::random());
+
+		assert_eq!(check_local_peer_id(tmp.path(), &peer_id1).unwrap(), None);
+		assert_eq!(check_local_peer_id(tmp.path(), &peer_id1).unwrap(), None);
+		assert_eq!(check_local_peer_id(tmp.path(), &peer_id2).unwrap(), Some(peer_id1));
+		assert_eq!(check_local_peer_id(tmp.path(), &peer_id2).unwrap(), None);
+	}
+
 	#[test]
 	fn test_secret_input() {
 		let sk = ed25519::SecretKey::generate();
@@ -48,6 +48,7 @@
 //!
 
 use crate::config::ProtocolId;
+use crate::transport::{self, TransportKind};
 use crate::utils::LruHashSet;
 use futures::prelude::*;
 use futures_timer::Delay;
@@ -62,6 +63,8 @@ use libp2p::kad::QueryId;
 use libp2p::kad::record::{self, store::{MemoryStore, RecordStore}};
 #[cfg(not(target_os = "unknown"))]
 use libp2p::mdns::{Mdns, MdnsConfig, MdnsEvent};
+#[cfg(not(target_os = "unknown"))]
+use futures::channel::oneshot;
 use libp2p::multiaddr::Protocol;
 use log::{debug, info, trace, warn};
 use std::{cmp, collections::{HashMap, HashSet, VecDeque}, io, num::NonZeroUsize, time::Duration};
@@ -73,6 +76,15 @@ use sp_core::hexdisplay::HexDisplay;
 /// a given address.
 const MAX_KNOWN_EXTERNAL_ADDRESSES: usize = 32;
 
+/// Interval at which the DNS names of the user-defined addresses are resolved again.
+#[cfg(not(target_os = "unknown"))]
+const DNS_RESOLUTION_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Number of dial failures of a peer with a user-defined DNS address after which its name is
+/// resolved again without waiting for [`DNS_RESOLUTION_INTERVAL`].
+#[cfg(not(target_os = "unknown"))]
+const DIAL_FAILURES_BEFORE_DNS_RESOLUTION: u32 = 3;
+
 /// `DiscoveryBehaviour` configuration.
 ///
 /// Note: In order to discover nodes or load and store values via Kademlia one has to add at least
@@ -87,6 +99,7 @@ pub struct DiscoveryConfig {
 	enable_mdns: bool,
 	kademlia_disjoint_query_paths: bool,
 	protocol_ids: HashSet<ProtocolId>,
+	dial_preference: Vec<TransportKind>,
 }
 
 impl DiscoveryConfig {
@@ -101,7 +114,8 @@ impl DiscoveryConfig {
 			discovery_only_if_under_num: std::u64::MAX,
 			enable_mdns: false,
 			kademlia_disjoint_query_paths: false,
-			protocol_ids: HashSet::new()
+			protocol_ids: HashSet::new(),
+			dial_preference: Vec::new(),
 		}
 	}
 
@@ -167,6 +181,13 @@ impl DiscoveryConfig {
 		self
 	}
 
+	/// Set the transports whose addresses are returned first by `addresses_of_peer`, most
+	/// preferred first.
+	pub fn with_dial_preference(&mut self, value: Vec<TransportKind>) -> &mut Self {
+		self.dial_preference = value;
+		self
+	}
+
 	/// Create a `DiscoveryBehaviour` from this config.
 	pub fn finish(self) -> DiscoveryBehaviour {
 		let DiscoveryConfig {
@@ -179,6 +200,7 @@ impl DiscoveryConfig {
 			enable_mdns,
 			kademlia_disjoint_query_paths,
 			protocol_ids,
+			dial_preference,
 		} = self;
 
 		let kademlias = protocol_ids.into_iter()
@@ -204,7 +226,16 @@ impl DiscoveryConfig {
 			})
 			.collect();
 
+		#[cfg(not(target_os = "unknown"))]
+		let mut dns = DnsResolution::new();
+		#[cfg(not(target_os = "unknown"))]
+		for (peer_id, addr) in &user_defined {
+			dns.add_address(peer_id.clone(), addr.clone());
+		}
+
 		DiscoveryBehaviour {
+			#[cfg(not(target_os = "unknown"))]
+			dns,
 			user_defined,
 			kademlias,
 			next_kad_random_query: if dht_random_walk {
@@ -218,6 +249,7 @@ impl DiscoveryConfig {
 			num_connections: 0,
 			allow_private_ipv4,
 			discovery_only_if_under_num,
+			dial_preference,
 			#[cfg(not(target_os = "unknown"))]
 			mdns: if enable_mdns {
 				MdnsWrapper::Instantiating(Mdns::new(MdnsConfig::default()).boxed())
@@ -243,6 +275,9 @@ pub struct DiscoveryBehaviour {
 	/// Discovers nodes on the local network.
 	#[cfg(not(target_os = "unknown"))]
 	mdns: MdnsWrapper,
+	/// Resolves the DNS names of the user-defined addresses.
+	#[cfg(not(target_os = "unknown"))]
+	dns: DnsResolution,
 	/// Stream that fires when we need to perform the next random Kademlia query. `None` if
 	/// random walking is disabled.
 	next_kad_random_query: Option<Delay>,
@@ -259,6 +294,8 @@ pub struct DiscoveryBehaviour {
 	allow_private_ipv4: bool,
 	/// Number of active connections over which we interrupt the discovery process.
 	discovery_only_if_under_num: u64,
+	/// The transports whose addresses are returned first by `addresses_of_peer`.
+	dial_preference: Vec<TransportKind>,
 	/// Should non-global addresses be added to the DHT?
 	allow_non_globals_in_dht: bool,
 	/// A cache of discovered external addresses. Only used for logging purposes.
@@ -292,6 +329,8 @@ impl DiscoveryBehaviour {
 				k.add_address(&peer_id, addr.clone());
 			}
 			self.pending_events.push_back(DiscoveryOut::Discovered(peer_id.clone()));
+			#[cfg(not(target_os = "unknown"))]
+			self.dns.add_address(peer_id.clone(), addr.clone());
 			self.user_defined.push((peer_id, addr));
 		}
 	}
@@ -475,6 +514,9 @@ impl NetworkBehaviour for DiscoveryBehaviour {
 			.filter_map(|(p, a)| if p == peer_id { Some(a.clone()) } else { None })
 			.collect::<Vec<_>>();
 
+		#[cfg(not(target_os = "unknown"))]
+		list.extend(self.dns.addresses_of_peer(peer_id));
+
 		{
 			let mut list_to_filter = Vec::new();
 			for k in self.kademlias.values_mut() {
@@ -499,6 +541,8 @@ impl NetworkBehaviour for DiscoveryBehaviour {
 			list.extend(list_to_filter);
 		}
 
+		transport::sort_by_preference(&mut list, &self.dial_preference);
+
 		trace!(target: "sub-libp2p", "Addresses of {:?}: {:?}", peer_id, list);
 
 		list
@@ -512,6 +556,9 @@ impl NetworkBehaviour for DiscoveryBehaviour {
 	}
 
 	fn inject_connected(&mut self, peer_id: &PeerId) {
+		#[cfg(not(target_os = "unknown"))]
+		self.dns.inject_connected(peer_id);
+
 		for k in self.kademlias.values_mut() {
 			NetworkBehaviour::inject_connected(k, peer_id)
 		}
@@ -589,6 +636,9 @@ impl NetworkBehaviour for DiscoveryBehaviour {
 	}
 
 	fn inject_dial_failure(&mut self, peer_id: &PeerId) {
+		#[cfg(not(target_os = "unknown"))]
+		self.dns.inject_dial_failure(peer_id);
+
 		for k in self.kademlias.values_mut() {
 			NetworkBehaviour::inject_dial_failure(k, peer_id)
 		}
@@ -633,6 +683,10 @@ impl NetworkBehaviour for DiscoveryBehaviour {
 			return Poll::Ready(NetworkBehaviourAction::GenerateEvent(ev));
 		}
 
+		// Resolve the DNS names of the user-defined addresses when needed.
+		#[cfg(not(target_os = "unknown"))]
+		self.dns.poll(cx);
+
 		// Poll the stream that fires when we need to start a random Kademlia query.
 		if let Some(next_kad_random_query) = self.next_kad_random_query.as_mut() {
 			while let Poll::Ready(_) = next_kad_random_query.poll_unpin(cx) {
@@ -855,6 +909,165 @@ impl MdnsWrapper {
 	}
 }
 
+/// Periodically resolves the DNS names of the user-defined addresses to IP addresses, which are
+/// dialed along with the DNS addresses.
+///
+/// The names are resolved again every [`DNS_RESOLUTION_INTERVAL`], and as soon as a peer fails to
+/// be dialed [`DIAL_FAILURES_BEFORE_DNS_RESOLUTION`] times in a row, so that a bootnode whose IP
+/// address changes behind its DNS name doesn't strand a long-running node.
+#[cfg(not(target_os = "unknown"))]
+struct DnsResolution {
+	/// The user-defined addresses starting with a DNS name.
+	addresses: Vec<(PeerId, Multiaddr)>,
+	/// The IP addresses of each peer, according to the last successful resolution.
+	resolved: HashMap<PeerId, Vec<Multiaddr>>,
+	/// The number of dial failures of each peer since it was last connected.
+	dial_failures: HashMap<PeerId, u32>,
+	/// Fires when the names must be resolved again.
+	next_resolution: Delay,
+	/// The ongoing resolution, if any.
+	pending: Option<oneshot::Receiver<HashMap<PeerId, Vec<Multiaddr>>>>,
+}
+
+#[cfg(not(target_os = "unknown"))]
+impl DnsResolution {
+	fn new() -> Self {
+		DnsResolution {
+			addresses: Vec::new(),
+			resolved: HashMap::new(),
+			dial_failures: HashMap::new(),
+			next_resolution: Delay::new(Duration::new(0, 0)),
+			pending: None,
+		}
+	}
+
+	/// Resolve the name of `addr` from now on, if it starts with one.
+	fn add_address(&mut self, peer_id: PeerId, addr: Multiaddr) {
+		if matches!(
+			addr.iter().next(),
+			Some(Protocol::Dns(_)) | Some(Protocol::Dns4(_)) | Some(Protocol::Dns6(_))
+		) {
+			self.addresses.push((peer_id, addr));
+			self.next_resolution = Delay::new(Duration::new(0, 0));
+		}
+	}
+
+	fn addresses_of_peer(&self, peer_id: &PeerId) -> Vec<Multiaddr> {
+		self.resolved.get(peer_id).cloned().unwrap_or_default()
+	}
+
+	fn inject_connected(&mut self, peer_id: &PeerId) {
+		self.dial_failures.remove(peer_id);
+	}
+
+	fn inject_dial_failure(&mut self, peer_id: &PeerId) {
+		if !self.addresses.iter().any(|(p, _)| p == peer_id) {
+			return
+		}
+
+		let failures = self.dial_failures.entry(peer_id.clone()).or_default();
+		*failures += 1;
+		if *failures >= DIAL_FAILURES_BEFORE_DNS_RESOLUTION {
+			debug!(
+				target: "sub-libp2p",
+				"Failed to dial {:?} {} times, resolving its DNS addresses again",
+				peer_id,
+				failures,
+			);
+			self.dial_failures.clear();
+			self.next_resolution = Delay::new(Duration::new(0, 0));
+		}
+	}
+
+	fn poll(&mut self, cx: &mut Context) {
+		if self.addresses.is_empty() {
+			return
+		}
+
+		loop {
+			if let Some(pending) = self.pending.as_mut() {
+				match pending.poll_unpin(cx) {
+					Poll::Ready(Ok(resolved)) => {
+						trace!(target: "sub-libp2p", "Resolved DNS addresses: {:?}", resolved);
+						self.resolved.extend(resolved);
+						self.pending = None;
+					},
+					// The resolution thread failed to start.
+					Poll::Ready(Err(_)) => self.pending = None,
+					Poll::Pending => return,
+				}
+			}
+
+			if self.next_resolution.poll_unpin(cx).is_pending() {
+				return
+			}
+			self.next_resolution = Delay::new(DNS_RESOLUTION_INTERVAL);
+			self.pending = Some(self.start_resolution());
+		}
+	}
+
+	/// Resolve the names in a background thread, as the system resolver is blocking.
+	fn start_resolution(&self) -> oneshot::Receiver<HashMap<PeerId, Vec<Multiaddr>>> {
+		let (tx, rx) = oneshot::channel();
+		let addresses = self.addresses.clone();
+		let spawned = std::thread::Builder::new()
+			.name("dns-resolution".into())
+			.spawn(move || {
+				let mut resolved = HashMap::<_, Vec<_>>::new();
+				for (peer_id, addr) in addresses {
+					match resolve_dns(&addr) {
+						Ok(ips) => resolved.entry(peer_id).or_default().extend(ips),
+						Err(e) => warn!(
+							target: "sub-libp2p",
+							"Failed to resolve the DNS address {}: {}",
+							addr,
+							e,
+						),
+					}
+				}
+				let _ = tx.send(resolved);
+			});
+
+		if let Err(e) = spawned {
+			warn!(target: "sub-libp2p", "Failed to start the DNS resolution: {}", e);
+		}
+
+		rx
+	}
+}
+
+/// Resolve the DNS name at the start of `addr` with the system resolver, and return `addr` with
+/// each of the IP addresses it resolved to in place of the name.
+#[cfg(not(target_os = "unknown"))]
+fn resolve_dns(addr: &Multiaddr) -> io::Result<Vec<Multiaddr>> {
+	use std::net::{IpAddr, ToSocketAddrs};
+
+	let (name, keep): (_, fn(&IpAddr) -> bool) = match addr.iter().next() {
+		Some(Protocol::Dns(name)) => (name, |_| true),
+		Some(Protocol::Dns4(name)) => (name, IpAddr::is_ipv4),
+		Some(Protocol::Dns6(name)) => (name, IpAddr::is_ipv6),
+		_ => return Ok(Vec::new()),
+	};
+
+	// The port is irrelevant, the protocols following the name are kept as they are.
+	let mut ips = (name.as_ref(), 0).to_socket_addrs()?
+		.map(|socket_addr| socket_addr.ip())
+		.filter(keep)
+		.collect::<Vec<_>>();
+	ips.sort();
+	ips.dedup();
+
+	Ok(ips.into_iter()
+		.map(|ip| {
+			let mut resolved = Multiaddr::from(ip);
+			for protocol in addr.iter().skip(1) {
+				resolved.push(protocol);
+			}
+			resolved
+		})
+		.collect())
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::config::ProtocolId;
@@ -867,7 +1080,7 @@ mod tests {
 	use libp2p::swarm::Swarm;
 	use libp2p::yamux;
 	use std::{collections::HashSet, task::Poll};
-	use super::{DiscoveryConfig, DiscoveryOut, protocol_name_from_protocol_id};
+	use super::{DiscoveryConfig, DiscoveryOut, protocol_name_from_protocol_id, resolve_dns};
 
 	#[test]
 	fn discovery_working() {
@@ -1063,4 +1276,18 @@ mod tests {
 			"Expected remote peer not to be added to `protocol_b` Kademlia instance.",
 		);
 	}
+
+	#[test]
+	fn dns_addresses_resolved_to_ip_addresses() {
+		let resolve = |addr: &str| resolve_dns(&addr.parse().unwrap()).unwrap();
+		let localhost_v4: Multiaddr = "/ip4/127.0.0.1/tcp/30333/ws".parse().unwrap();
+
+		assert!(resolve("/dns/localhost/tcp/30333/ws").contains(&localhost_v4));
+
+		let resolved_v4 = resolve("/dns4/localhost/tcp/30333/ws");
+		assert!(resolved_v4.contains(&localhost_v4));
+		assert!(resolved_v4.iter().all(|addr| addr.to_string().starts_with("/ip4/")));
+
+		assert!(resolve("/ip4/1.2.3.4/tcp/30333").is_empty());
+	}
 }
@@ -268,7 +268,11 @@ pub mod transactions;
 
 #[doc(inline)]
 pub use libp2p::{multiaddr, Multiaddr, PeerId};
-pub use protocol::{event::{DhtEvent, Event, ObservedRole}, sync::SyncState, PeerInfo};
+pub use protocol::{
+	event::{DhtEvent, Event, ObservedRole},
+	sync::{SyncState, Status as SyncStatus},
+	PeerInfo,
+};
 pub use service::{
 	NetworkService, NetworkWorker, RequestFailure, OutboundFailure, NotificationSender,
 	NotificationSenderReady, IfDisconnected,
@@ -105,6 +105,8 @@ mod rep {
 	pub const BAD_ROLE: Rep = Rep::new_fatal("Unsupported role");
 	/// Peer send us a block announcement that failed at validation.
 	pub const BAD_BLOCK_ANNOUNCEMENT: Rep = Rep::new(-(1 << 12), "Bad block announcement");
+	/// Peer sent us block announcements faster than we could validate them.
+	pub const BLOCK_ANNOUNCEMENT_FLOOD: Rep = Rep::new(-(1 << 6), "Too many block announcements");
 }
 
 struct Metrics {
@@ -463,6 +465,11 @@ impl<B: BlockT> Protocol<B> {
 		self.sync.status().state
 	}
 
+	/// Returns the current sync status.
+	pub fn sync_status(&self) -> sync::Status<B> {
+		self.sync.status()
+	}
+
 	/// Target sync block number.
 	pub fn best_seen_block(&self) -> Option<NumberFor<B>> {
 		self.sync.status().best_seen_block
@@ -906,6 +913,10 @@ impl<B: BlockT> Protocol<B> {
 				self.report_peer(who, rep::BAD_BLOCK_ANNOUNCEMENT);
 				return CustomMessageOutcome::None
 			}
+			sync::PollBlockAnnounceValidation::Throttled { who } => {
+				self.report_peer(who, rep::BLOCK_ANNOUNCEMENT_FLOOD);
+				return CustomMessageOutcome::None
+			}
 		};
 
 		let number = *header.number();
@@ -1036,6 +1047,14 @@ impl<B: BlockT> Protocol<B> {
 		self.behaviour.reserved_peers(HARDCODED_PEERSETS_SYNC)
 	}
 
+	/// Returns, for each notifications protocol, whether it only accepts reserved peers, and its
+	/// reserved peers along with whether a substream is open with them.
+	pub fn reserved_peers_state(
+		&self,
+	) -> impl Iterator<Item = (&Cow<'static, str>, bool, Vec<(&PeerId, bool)>)> {
+		self.behaviour.reserved_peers_state()
+	}
+
 	/// Adds a `PeerId` to the list of reserved peers for syncing purposes.
 	pub fn add_reserved_peer(&self, peer: PeerId) {
 		self.peerset_handle.add_reserved_peer(HARDCODED_PEERSETS_SYNC, peer.clone());
@@ -1059,6 +1078,32 @@ impl<B: BlockT> Protocol<B> {
 		}
 	}
 
+	/// Marks a `PeerId` as not occupying a slot of the set of `protocol`.
+	pub fn add_set_no_slot_peer(&self, protocol: Cow<'static, str>, peer: PeerId) {
+		if let Some(index) = self.notification_protocols.iter().position(|p| *p == protocol) {
+			self.peerset_handle.add_no_slot_peer(sc_peerset::SetId::from(index + NUM_HARDCODED_PEERSETS), peer);
+		} else {
+			log::error!(
+				target: "sub-libp2p",
+				"add_set_no_slot_peer with unknown protocol: {}",
+				protocol
+			);
+		}
+	}
+
+	/// Makes a `PeerId` occupy a slot of the set of `protocol` again.
+	pub fn remove_set_no_slot_peer(&self, protocol: Cow<'static, str>, peer: PeerId) {
+		if let Some(index) = self.notification_protocols.iter().position(|p| *p == protocol) {
+			self.peerset_handle.remove_no_slot_peer(sc_peerset::SetId::from(index + NUM_HARDCODED_PEERSETS), peer);
+		} else {
+			log::error!(
+				target: "sub-libp2p",
+				"remove_set_no_slot_peer with unknown protocol: {}",
+				protocol
+			);
+		}
+	}
+
 	/// Adds a `PeerId` to the list of reserved peers.
 	pub fn add_set_reserved_peer(&self, protocol: Cow<'static, str>, peer: PeerId) {
 		if let Some(index) = self.notification_protocols.iter().position(|p| *p == protocol) {
@@ -579,6 +579,20 @@ impl Notifications {
 		self.peerset.reserved_peers(set_id)
 	}
 
+	/// Returns, for each set, the name of its protocol, whether it only accepts reserved peers,
+	/// and its reserved peers along with whether we have an open substream with them.
+	pub fn reserved_peers_state<'a>(&'a self)
+		-> impl Iterator<Item = (&'a Cow<'static, str>, bool, Vec<(&'a PeerId, bool)>)> + 'a
+	{
+		self.notif_protocols.iter().enumerate().map(move |(index, protocol)| {
+			let set_id = sc_peerset::SetId::from(index);
+			let peers = self.peerset.reserved_peers(set_id)
+				.map(|peer_id| (peer_id, self.is_open(peer_id, set_id)))
+				.collect();
+			(&protocol.name, self.peerset.is_reserved_only(set_id), peers)
+		})
+	}
+
 	/// Sends a notification to a peer.
 	///
 	/// Has no effect if the custom protocol is not open with the given peer.
@@ -295,7 +295,20 @@ pub enum SyncState {
 	/// Initial sync is complete, keep-up sync is active.
 	Idle,
 	/// Actively catching up with the chain.
-	Downloading
+	Downloading,
+	/// The blocks are downloaded, but a large number of them is still waiting to be imported.
+	Importing,
+}
+
+impl SyncState {
+	/// Are we in the process of catching up with the chain, i.e. downloading or importing a
+	/// large number of blocks?
+	pub fn is_major_syncing(&self) -> bool {
+		match self {
+			SyncState::Idle => false,
+			SyncState::Downloading | SyncState::Importing => true,
+		}
+	}
 }
 
 /// Syncing status and statistics.
@@ -374,6 +387,14 @@ pub enum PollBlockAnnounceValidation<H> {
 		/// The announcement.
 		announce: BlockAnnounce<H>,
 	},
+	/// The block announcement was not validated, because the peer already has the maximum
+	/// number of block announcements being validated.
+	///
+	/// The peer should back off, its reputation should be slightly decreased.
+	Throttled {
+		/// Who sent the block announcement?
+		who: PeerId,
+	},
 	/// The block announcement should be skipped.
 	Skip,
 }
@@ -408,6 +429,12 @@ enum PreValidateBlockAnnounce<H> {
 	Error {
 		who: PeerId,
 	},
+	/// The peer already has the maximum number of block announcements being validated.
+	///
+	/// No slot was registered for this block announcement validation.
+	Throttled {
+		who: PeerId,
+	},
 	/// The block announcement should be skipped.
 	///
 	/// This should *only* be returned when there wasn't a slot registered
@@ -488,8 +515,15 @@ impl<B: BlockT> ChainSync<B> {
 			if let Some(n) = best_seen {
 				// A chain is classified as downloading if the provided best block is
 				// more than `MAJOR_SYNC_BLOCKS` behind the best queued block.
+				let best_imported = self.client.info().best_number;
 				if n > self.best_queued_number && n - self.best_queued_number > MAJOR_SYNC_BLOCKS.into() {
 					SyncState::Downloading
+				} else if self.best_queued_number > best_imported &&
+					self.best_queued_number - best_imported > MAJOR_SYNC_BLOCKS.into()
+				{
+					// All the blocks are downloaded, but the best imported block is still far
+					// behind the best queued one.
+					SyncState::Importing
 				} else {
 					SyncState::Idle
 				}
@@ -725,7 +759,7 @@ impl<B: BlockT> ChainSync<B> {
 			trace!(target: "sync", "Too many blocks in the queue.");
 			return Either::Left(std::iter::empty())
 		}
-		let major_sync = self.status().state == SyncState::Downloading;
+		let major_sync = self.status().state.is_major_syncing();
 		let blocks = &mut self.blocks;
 		let attrs = &self.required_block_attributes;
 		let fork_targets = &mut self.fork_targets;
@@ -1004,7 +1038,7 @@ impl<B: BlockT> ChainSync<B> {
 			debug!(target: "sync", "Ignoring {} blocks that are already queued", orig_len - new_blocks.len());
 		}
 
-		let origin = if self.status().state != SyncState::Downloading {
+		let origin = if !self.status().state.is_major_syncing() {
 			BlockOrigin::NetworkBroadcast
 		} else {
 			BlockOrigin::NetworkInitialSync
@@ -1344,14 +1378,14 @@ impl<B: BlockT> ChainSync<B> {
 			}
 			HasSlotForBlockAnnounceValidation::MaximumPeerSlotsReached => {
 				self.block_announce_validation.push(async move {
-					warn!(
+					debug!(
 						target: "sync",
-						"💔 Ignored block (#{} -- {}) announcement from {} because all validation slots for this peer are occupied.",
+						"Ignored block (#{} -- {}) announcement from {} because all validation slots for this peer are occupied.",
 						number,
 						hash,
 						who,
 					);
-					PreValidateBlockAnnounce::Skip
+					PreValidateBlockAnnounce::Throttled { who }
 				}.boxed());
 				return
 			}
@@ -1423,7 +1457,7 @@ impl<B: BlockT> ChainSync<B> {
 			PreValidateBlockAnnounce::Failure { who, .. } |
 			PreValidateBlockAnnounce::Process { who, .. } |
 			PreValidateBlockAnnounce::Error { who } => who,
-			PreValidateBlockAnnounce::Skip => return,
+			PreValidateBlockAnnounce::Throttled { .. } | PreValidateBlockAnnounce::Skip => return,
 		};
 
 		match self.block_announce_validation_per_peer_stats.entry(peer.clone()) {
@@ -1458,6 +1492,9 @@ impl<B: BlockT> ChainSync<B> {
 				);
 				return PollBlockAnnounceValidation::Failure { who, disconnect }
 			},
+			PreValidateBlockAnnounce::Throttled { who } => {
+				return PollBlockAnnounceValidation::Throttled { who }
+			},
 			PreValidateBlockAnnounce::Process { announce, is_new_best, who } => {
 				(announce, is_new_best, who)
 			},
@@ -1484,6 +1521,21 @@ impl<B: BlockT> ChainSync<B> {
 		let known_parent = parent_status != BlockStatus::Unknown;
 		let ancient_parent = parent_status == BlockStatus::InChainPruned;
 
+		// Peers announcing a known bad block, or a block built on top of one, are on a fork we
+		// refuse to follow, so there is no point in staying connected to them.
+		if parent_status == BlockStatus::KnownBad ||
+			self.block_status(&hash).map_or(false, |status| status == BlockStatus::KnownBad)
+		{
+			info!(
+				target: "sync",
+				"💔 Peer {} announced known bad block (#{} -- {}) or one of its descendants",
+				who,
+				number,
+				hash,
+			);
+			return PollBlockAnnounceValidation::Failure { who, disconnect: true }
+		}
+
 		let known = self.is_known(&hash);
 		let peer = if let Some(peer) = self.peers.get_mut(&who) {
 			peer
@@ -1979,13 +2031,27 @@ mod test {
 	use super::*;
 	use sc_block_builder::BlockBuilderProvider;
 	use sp_blockchain::HeaderBackend;
-	use sp_consensus::block_validation::DefaultBlockAnnounceValidator;
+	use sp_consensus::block_validation::{DefaultBlockAnnounceValidator, Validation};
 	use substrate_test_runtime_client::{
 		runtime::{Block, Hash, Header},
 		ClientBlockImportExt, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
 		BlockBuilderExt, TestClient, ClientExt,
 	};
-	use futures::{future::poll_fn, executor::block_on};
+	use futures::{future::{self, poll_fn}, executor::block_on};
+	use std::{error::Error, pin::Pin};
+
+	/// A block announce validator which never finishes the validations.
+	struct PendingBlockAnnounceValidator;
+
+	impl BlockAnnounceValidator<Block> for PendingBlockAnnounceValidator {
+		fn validate(
+			&mut self,
+			_: &Header,
+			_: &[u8],
+		) -> Pin<Box<dyn Future<Output = Result<Validation, Box<dyn Error + Send>>> + Send>> {
+			future::pending().boxed()
+		}
+	}
 
 	#[test]
 	fn processes_empty_response_on_justification_request_for_unknown_block() {
@@ -2169,6 +2235,50 @@ mod test {
 		}))
 	}
 
+	#[test]
+	fn disconnects_peers_announcing_known_bad_blocks() {
+		let bad_block = TestClientBuilder::new().build()
+			.new_block(Default::default()).unwrap().build().unwrap().block;
+		let client = Arc::new(
+			TestClientBuilder::new()
+				.set_block_rules(None, Some(vec![bad_block.hash()].into_iter().collect()))
+				.build()
+		);
+		let info = client.info();
+
+		let mut sync = ChainSync::new(
+			Roles::AUTHORITY,
+			client.clone(),
+			&info,
+			Box::new(DefaultBlockAnnounceValidator),
+			1,
+		);
+
+		// A new peer whose best block is known to be bad is refused.
+		let peer_id1 = PeerId::random();
+		assert!(sync.new_peer(peer_id1, bad_block.hash(), 1).is_err());
+
+		// A connected peer announcing a known bad block is disconnected.
+		let peer_id2 = PeerId::random();
+		sync.new_peer(peer_id2.clone(), info.best_hash, info.best_number).unwrap();
+		sync.push_block_announce_validation(
+			peer_id2.clone(),
+			bad_block.hash(),
+			BlockAnnounce {
+				header: bad_block.header().clone(),
+				state: Some(BlockState::Best),
+				data: Some(Vec::new()),
+			},
+			true,
+		);
+
+		let result = block_on(poll_fn(|cx| sync.poll_block_announce_validation(cx)));
+		assert_eq!(
+			result,
+			PollBlockAnnounceValidation::Failure { who: peer_id2, disconnect: true },
+		);
+	}
+
 	/// Create a block response from the given `blocks`.
 	fn create_block_response(blocks: Vec<Block>) -> BlockResponse<Block> {
 		BlockResponse::<Block> {
@@ -2584,6 +2694,36 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn status_is_importing_while_best_queued_block_is_far_ahead_of_best_imported_block() {
+		sp_tracing::try_init_simple();
+		let mut client = Arc::new(TestClientBuilder::new().build());
+		let info = client.info();
+
+		let mut sync = ChainSync::new(
+			Roles::AUTHORITY,
+			client.clone(),
+			&info,
+			Box::new(DefaultBlockAnnounceValidator),
+			1,
+		);
+		assert_eq!(sync.status().state, SyncState::Idle);
+
+		let peer_id = PeerId::random();
+		sync.new_peer(peer_id.clone(), Hash::random(), 10).unwrap();
+		assert_eq!(sync.status().state, SyncState::Downloading);
+
+		// All the blocks are queued, none is imported.
+		sync.best_queued_number = 10;
+		assert_eq!(sync.status().state, SyncState::Importing);
+
+		// The best imported block is close enough to the best queued one.
+		for _ in 0..(10 - MAJOR_SYNC_BLOCKS) {
+			build_block(&mut client, None, false);
+		}
+		assert_eq!(sync.status().state, SyncState::Idle);
+	}
+
 	#[test]
 	fn removes_target_fork_on_disconnect() {
 		sp_tracing::try_init_simple();
@@ -2616,4 +2756,44 @@ mod test {
 		sync.peer_disconnected(&peer_id1);
 		assert!(sync.fork_targets.len() == 0);
 	}
+
+	#[test]
+	fn peer_is_throttled_when_all_its_validation_slots_are_occupied() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let info = client.info();
+
+		let mut sync = ChainSync::new(
+			Roles::AUTHORITY,
+			client.clone(),
+			&info,
+			Box::new(PendingBlockAnnounceValidator),
+			1,
+		);
+
+		let peer_id = PeerId::random();
+		let header = client.new_block(Default::default()).unwrap().build().unwrap().block.header;
+		for _ in 0..=MAX_CONCURRENT_BLOCK_ANNOUNCE_VALIDATIONS_PER_PEER {
+			let announce = BlockAnnounce {
+				header: header.clone(),
+				state: Some(BlockState::Best),
+				data: Some(Vec::new()),
+			};
+			sync.push_block_announce_validation(peer_id.clone(), header.hash(), announce, true);
+		}
+
+		block_on(poll_fn(|cx| {
+			assert_eq!(
+				sync.poll_block_announce_validation(cx),
+				Poll::Ready(PollBlockAnnounceValidation::Throttled { who: peer_id.clone() }),
+			);
+			assert!(sync.poll_block_announce_validation(cx).is_pending());
+			Poll::Ready(())
+		}));
+
+		// The slots of the peer are still occupied by the pending validations.
+		assert_eq!(
+			sync.block_announce_validation_per_peer_stats.get(&peer_id),
+			Some(&MAX_CONCURRENT_BLOCK_ANNOUNCE_VALIDATIONS_PER_PEER),
+		);
+	}
 }
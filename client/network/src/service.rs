@@ -30,7 +30,10 @@
 use crate::{
 	ExHashT, NetworkStateInfo, NetworkStatus,
 	behaviour::{self, Behaviour, BehaviourOut},
-	config::{parse_str_addr, Params, TransportConfig},
+	config::{
+		check_local_peer_id, parse_str_addr, NonReservedPeerMode, Params, Role, TransportConfig,
+		TransportKind,
+	},
 	DhtEvent,
 	discovery::DiscoveryConfig,
 	error::Error,
@@ -48,7 +51,7 @@ use crate::{
 		Protocol,
 		Ready,
 		event::Event,
-		sync::SyncState,
+		sync::{SyncState, Status as SyncStatus},
 	},
 	transactions,
 	transport, ReputationChange,
@@ -119,6 +122,8 @@ pub struct NetworkService<B: BlockT + 'static, H: ExHashT> {
 	external_addresses: Arc<Mutex<Vec<Multiaddr>>>,
 	/// Are we actively catching up with the chain?
 	is_major_syncing: Arc<AtomicBool>,
+	/// The latest sync status.
+	sync_status: Arc<Mutex<SyncStatus<B>>>,
 	/// Local copy of the `PeerId` of the local node.
 	local_peer_id: PeerId,
 	/// Bandwidth logging system. Can be queried to know the average bandwidth consumed.
@@ -146,6 +151,21 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 	/// for the network processing to advance. From it, you can extract a `NetworkService` using
 	/// `worker.service()`. The `NetworkService` can be shared through the codebase.
 	pub fn new(mut params: Params<B, H>) -> Result<NetworkWorker<B, H>, Error> {
+		// The validators of a sentry and the sentries of an authority are always connected to. An
+		// authority with sentries doesn't connect to anyone else but its reserved peers.
+		for node in params.role.reserved_nodes() {
+			let reserved_nodes = &mut params.network_config.default_peers_set.reserved_nodes;
+			if !reserved_nodes.iter().any(|r| r.peer_id == node.peer_id) {
+				reserved_nodes.push(node.clone());
+			}
+		}
+		if let Role::Authority { sentry_nodes } = &params.role {
+			if !sentry_nodes.is_empty() {
+				params.network_config.default_peers_set.non_reserved_mode =
+					NonReservedPeerMode::Deny;
+			}
+		}
+
 		// Ensure the listen addresses are consistent with the transport.
 		ensure_addresses_consistent_with_transport(
 			params.network_config.listen_addresses.iter(),
@@ -190,6 +210,22 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			"🏷 Local node identity is: {}",
 			local_peer_id.to_base58(),
 		);
+		if let Some(path) = &params.network_config.net_config_path {
+			match check_local_peer_id(path, &local_peer_id) {
+				Ok(Some(previous)) => warn!(
+					target: "sub-libp2p",
+					"⚠️ The local node identity changed since the previous run, from {}. Other nodes \
+					know this node by its previous identity, check the node key configuration.",
+					previous.to_base58(),
+				),
+				Ok(None) => {},
+				Err(e) => warn!(
+					target: "sub-libp2p",
+					"Failed to check the local node identity: {}",
+					e,
+				),
+			}
+		}
 
 		let default_notif_handshake_message = Roles::from(&params.role).encode();
 		let (protocol, peerset_handle, mut known_addresses) = Protocol::new(
@@ -268,6 +304,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 				config.with_dht_random_walk(params.network_config.enable_dht_random_walk);
 				config.allow_non_globals_in_dht(params.network_config.allow_non_globals_in_dht);
 				config.use_kademlia_disjoint_query_paths(params.network_config.kademlia_disjoint_query_paths);
+				config.with_dial_preference(params.network_config.dial_preference.clone());
 
 				match params.network_config.transport {
 					TransportConfig::MemoryOnly => {
@@ -399,6 +436,9 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 		}
 
 		let external_addresses = Arc::new(Mutex::new(Vec::new()));
+		let sync_status = Arc::new(Mutex::new(
+			swarm.behaviour().user_protocol().sync_status(),
+		));
 		let peers_notifications_sinks = Arc::new(Mutex::new(HashMap::new()));
 
 		let service = Arc::new(NetworkService {
@@ -406,6 +446,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			external_addresses: external_addresses.clone(),
 			num_connected: num_connected.clone(),
 			is_major_syncing: is_major_syncing.clone(),
+			sync_status: sync_status.clone(),
 			peerset: peerset_handle,
 			local_peer_id,
 			to_worker,
@@ -427,6 +468,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			external_addresses,
 			num_connected,
 			is_major_syncing,
+			sync_status,
 			network_service: swarm,
 			service,
 			import_queue: params.import_queue,
@@ -624,6 +666,14 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 	pub fn reserved_peers(&self) -> impl Iterator<Item = &PeerId> {
 		self.network_service.behaviour().user_protocol().reserved_peers()
 	}
+
+	/// Returns, for each notifications protocol, whether it only accepts reserved peers, and its
+	/// reserved peers along with whether a substream is open with them.
+	pub fn reserved_peers_state(
+		&self,
+	) -> impl Iterator<Item = (&Cow<'static, str>, bool, Vec<(&PeerId, bool)>)> {
+		self.network_service.behaviour().user_protocol().reserved_peers_state()
+	}
 }
 
 impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
@@ -988,6 +1038,13 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 		self.is_major_syncing.load(Ordering::Relaxed)
 	}
 
+	/// The sync status, as of the last time the network worker was polled.
+	///
+	/// Unlike [`NetworkService::status`], this doesn't wait for the network worker.
+	pub fn sync_status(&self) -> SyncStatus<B> {
+		self.sync_status.lock().clone()
+	}
+
 	/// Start getting a value from the DHT.
 	///
 	/// This will generate either a `ValueFound` or a `ValueNotFound` event and pass it as an
@@ -1102,6 +1159,29 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkService<B, H> {
 		Ok(())
 	}
 
+	/// Marks peers as not occupying a slot of the peer set of `protocol` when connected.
+	///
+	/// Unlike the reserved peers, these peers are not connected to automatically. This is meant
+	/// for the peers needed by auxiliary protocols, so that they don't take the slots of the
+	/// other peers.
+	pub fn add_no_slot_peers(&self, protocol: Cow<'static, str>, peers: HashSet<PeerId>) {
+		for peer_id in peers {
+			let _ = self
+				.to_worker
+				.unbounded_send(ServiceToWorkerMsg::AddSetNoSlot(protocol.clone(), peer_id));
+		}
+	}
+
+	/// Makes peers previously added with [`NetworkService::add_no_slot_peers`] occupy a slot of
+	/// the peer set of `protocol` again.
+	pub fn remove_no_slot_peers(&self, protocol: Cow<'static, str>, peers: HashSet<PeerId>) {
+		for peer_id in peers {
+			let _ = self
+				.to_worker
+				.unbounded_send(ServiceToWorkerMsg::RemoveSetNoSlot(protocol.clone(), peer_id));
+		}
+	}
+
 	/// Configure an explicit fork sync request.
 	/// Note that this function should not be used for recent blocks.
 	/// Sync should be able to download all the recent forks normally.
@@ -1351,6 +1431,8 @@ enum ServiceToWorkerMsg<B: BlockT, H: ExHashT> {
 	SetReserved(HashSet<PeerId>),
 	AddSetReserved(Cow<'static, str>, PeerId),
 	RemoveSetReserved(Cow<'static, str>, PeerId),
+	AddSetNoSlot(Cow<'static, str>, PeerId),
+	RemoveSetNoSlot(Cow<'static, str>, PeerId),
 	AddToPeersSet(Cow<'static, str>, PeerId),
 	RemoveFromPeersSet(Cow<'static, str>, PeerId),
 	SyncFork(Vec<PeerId>, B::Hash, NumberFor<B>),
@@ -1383,6 +1465,8 @@ pub struct NetworkWorker<B: BlockT + 'static, H: ExHashT> {
 	num_connected: Arc<AtomicUsize>,
 	/// Updated by the `NetworkWorker` and loaded by the `NetworkService`.
 	is_major_syncing: Arc<AtomicBool>,
+	/// Updated by the `NetworkWorker` and loaded by the `NetworkService`.
+	sync_status: Arc<Mutex<SyncStatus<B>>>,
 	/// The network service that can be extracted and shared through the codebase.
 	service: Arc<NetworkService<B, H>>,
 	/// The *actual* network.
@@ -1484,6 +1568,10 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 					this.network_service.behaviour_mut().user_protocol_mut().add_set_reserved_peer(protocol, peer_id),
 				ServiceToWorkerMsg::RemoveSetReserved(protocol, peer_id) =>
 					this.network_service.behaviour_mut().user_protocol_mut().remove_set_reserved_peer(protocol, peer_id),
+				ServiceToWorkerMsg::AddSetNoSlot(protocol, peer_id) =>
+					this.network_service.behaviour_mut().user_protocol_mut().add_set_no_slot_peer(protocol, peer_id),
+				ServiceToWorkerMsg::RemoveSetNoSlot(protocol, peer_id) =>
+					this.network_service.behaviour_mut().user_protocol_mut().remove_set_no_slot_peer(protocol, peer_id),
 				ServiceToWorkerMsg::AddKnownAddress(peer_id, addr) =>
 					this.network_service.behaviour_mut().add_known_address(peer_id, addr),
 				ServiceToWorkerMsg::AddToPeersSet(protocol, peer_id) =>
@@ -1717,11 +1805,14 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 					debug!(target: "sub-libp2p", "Libp2p => Connected({:?})", peer_id);
 
 					if let Some(metrics) = this.metrics.as_ref() {
-						let direction = match endpoint {
-							ConnectedPoint::Dialer { .. } => "out",
-							ConnectedPoint::Listener { .. } => "in",
+						let (direction, address) = match &endpoint {
+							ConnectedPoint::Dialer { address } => ("out", address),
+							ConnectedPoint::Listener { local_addr, .. } => ("in", local_addr),
 						};
-						metrics.connections_opened_total.with_label_values(&[direction]).inc();
+						let transport = TransportKind::of(address).as_str();
+						metrics.connections_opened_total
+							.with_label_values(&[direction, transport])
+							.inc();
 
 						if num_established.get() == 1 {
 							metrics.distinct_peers_connections_opened_total.inc();
@@ -1731,10 +1822,11 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 				Poll::Ready(SwarmEvent::ConnectionClosed { peer_id, cause, endpoint, num_established }) => {
 					debug!(target: "sub-libp2p", "Libp2p => Disconnected({:?}, {:?})", peer_id, cause);
 					if let Some(metrics) = this.metrics.as_ref() {
-						let direction = match endpoint {
-							ConnectedPoint::Dialer { .. } => "out",
-							ConnectedPoint::Listener { .. } => "in",
+						let (direction, address) = match &endpoint {
+							ConnectedPoint::Dialer { address } => ("out", address),
+							ConnectedPoint::Listener { local_addr, .. } => ("in", local_addr),
 						};
+						let transport = TransportKind::of(address).as_str();
 						let reason = match cause {
 							Some(ConnectionError::IO(_)) => "transport-error",
 							Some(ConnectionError::Handler(NodeHandlerWrapperError::Handler(EitherError::A(EitherError::A(
@@ -1747,7 +1839,9 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 							Some(ConnectionError::Handler(NodeHandlerWrapperError::KeepAliveTimeout)) => "keep-alive-timeout",
 							None => "actively-closed",
 						};
-						metrics.connections_closed_total.with_label_values(&[direction, reason]).inc();
+						metrics.connections_closed_total
+							.with_label_values(&[direction, reason, transport])
+							.inc();
 
 						// `num_established` represents the number of *remaining* connections.
 						if num_established == 0 {
@@ -1869,10 +1963,9 @@ impl<B: BlockT + 'static, H: ExHashT> Future for NetworkWorker<B, H> {
 			*this.external_addresses.lock() = external_addresses;
 		}
 
-		let is_major_syncing = match this.network_service.behaviour_mut().user_protocol_mut().sync_state() {
-			SyncState::Idle => false,
-			SyncState::Downloading => true,
-		};
+		let sync_status = this.network_service.behaviour_mut().user_protocol_mut().sync_status();
+		let is_major_syncing = sync_status.state.is_major_syncing();
+		*this.sync_status.lock() = sync_status;
 
 		this.tx_handler_controller.set_gossip_enabled(!is_major_syncing);
 
@@ -1954,9 +2047,14 @@ fn ensure_addresses_consistent_with_transport<'a>(
 			});
 		}
 	} else {
+		// The normal transport doesn't support QUIC either.
 		let addresses: Vec<_> = addresses
 			.filter(|x| x.iter()
-				.any(|y| matches!(y, libp2p::core::multiaddr::Protocol::Memory(_)))
+				.any(|y| matches!(
+					y,
+					libp2p::core::multiaddr::Protocol::Memory(_) |
+						libp2p::core::multiaddr::Protocol::Quic
+				))
 			)
 			.cloned()
 			.collect();
@@ -87,16 +87,16 @@ impl Metrics {
 			connections_closed_total: prometheus::register(CounterVec::new(
 				Opts::new(
 					"sub_libp2p_connections_closed_total",
-					"Total number of connections closed, by direction and reason"
+					"Total number of connections closed, by direction, reason and transport"
 				),
-				&["direction", "reason"]
+				&["direction", "reason", "transport"]
 			)?, registry)?,
 			connections_opened_total: prometheus::register(CounterVec::new(
 				Opts::new(
 					"sub_libp2p_connections_opened_total",
-					"Total number of connections opened by direction"
+					"Total number of connections opened, by direction and transport"
 				),
-				&["direction"]
+				&["direction", "transport"]
 			)?, registry)?,
 			distinct_peers_connections_closed_total: prometheus::register(Counter::new(
 					"sub_libp2p_distinct_peers_connections_closed_total",
@@ -558,6 +558,17 @@ fn ensure_listen_addresses_consistent_with_transport_not_memory() {
 	});
 }
 
+#[test]
+#[should_panic(expected = "don't match the transport")]
+fn ensure_listen_addresses_consistent_with_transport_not_quic() {
+	let listen_addr = config::build_multiaddr![Ip4([127, 0, 0, 1]), Udp(0_u16), Quic];
+
+	let _ = build_test_full_node(config::NetworkConfiguration {
+		listen_addresses: vec![listen_addr.clone()],
+		.. config::NetworkConfiguration::new("test-node", "test-client", Default::default(), None)
+	});
+}
+
 #[test]
 #[should_panic(expected = "don't match the transport")]
 fn ensure_boot_node_addresses_consistent_with_transport_memory() {
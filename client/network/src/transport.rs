@@ -17,19 +17,95 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use libp2p::{
-	PeerId, Transport,
+	Multiaddr, PeerId, Transport,
 	core::{
-		self, either::EitherTransport, muxing::StreamMuxerBox,
+		self, either::EitherTransport, multiaddr::Protocol, muxing::StreamMuxerBox,
 		transport::{Boxed, OptionalTransport}, upgrade
 	},
 	mplex, identity, bandwidth, wasm_ext, noise
 };
 #[cfg(not(target_os = "unknown"))]
 use libp2p::{tcp, dns, websocket};
-use std::{sync::Arc, time::Duration};
+use std::{fmt, str::FromStr, sync::Arc, time::Duration};
 
 pub use self::bandwidth::BandwidthSinks;
 
+/// The transport protocol of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+	/// Plain TCP, e.g. `/ip4/1.2.3.4/tcp/30333`.
+	Tcp,
+	/// WebSocket over TCP, e.g. `/ip4/1.2.3.4/tcp/30333/ws`.
+	WebSocket,
+	/// QUIC over UDP, e.g. `/ip4/1.2.3.4/udp/30333/quic`.
+	///
+	/// The transport built by [`build_transport`] doesn't support QUIC, such addresses can't be
+	/// listened on and are dialed last unless they are preferred.
+	Quic,
+	/// In-process memory transport, e.g. `/memory/1`.
+	Memory,
+	/// Any other address.
+	Other,
+}
+
+impl TransportKind {
+	/// Returns the transport protocol of `address`, given by its outermost transport protocol.
+	pub fn of(address: &Multiaddr) -> Self {
+		let mut kind = TransportKind::Other;
+		for protocol in address.iter() {
+			kind = match protocol {
+				Protocol::Tcp(_) => TransportKind::Tcp,
+				Protocol::Ws(_) | Protocol::Wss(_) => TransportKind::WebSocket,
+				Protocol::Quic => TransportKind::Quic,
+				Protocol::Memory(_) => TransportKind::Memory,
+				_ => continue,
+			};
+		}
+		kind
+	}
+
+	/// Returns the name of the transport, as used in the command line and the metrics.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			TransportKind::Tcp => "tcp",
+			TransportKind::WebSocket => "ws",
+			TransportKind::Quic => "quic",
+			TransportKind::Memory => "memory",
+			TransportKind::Other => "other",
+		}
+	}
+}
+
+impl fmt::Display for TransportKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for TransportKind {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"tcp" => Ok(TransportKind::Tcp),
+			"ws" => Ok(TransportKind::WebSocket),
+			"quic" => Ok(TransportKind::Quic),
+			"memory" => Ok(TransportKind::Memory),
+			_ => Err(format!("Unknown transport `{}`, expected one of tcp, ws, quic, memory", s)),
+		}
+	}
+}
+
+/// Sort `addresses` by the index of their transport in `preference`, keeping the order of the
+/// addresses of a same transport. The addresses of the transports missing from `preference` come
+/// last.
+pub fn sort_by_preference(addresses: &mut [Multiaddr], preference: &[TransportKind]) {
+	addresses.sort_by_key(|address| {
+		let kind = TransportKind::of(address);
+		preference.iter().position(|preferred| *preferred == kind).unwrap_or(preference.len())
+	});
+}
+
 /// Builds the transport that serves as a common ground for all connections.
 ///
 /// If `memory_only` is true, then only communication within the same process are allowed. Only
@@ -125,3 +201,38 @@ pub fn build_transport(
 
 	(transport, bandwidth)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn transport_kind_of_address() {
+		let kind = |address: &str| TransportKind::of(&address.parse().unwrap());
+
+		assert_eq!(kind("/ip4/1.2.3.4/tcp/30333"), TransportKind::Tcp);
+		assert_eq!(kind("/dns/example.com/tcp/30333/ws"), TransportKind::WebSocket);
+		assert_eq!(kind("/ip6/::1/udp/30333/quic"), TransportKind::Quic);
+		assert_eq!(kind("/memory/1"), TransportKind::Memory);
+		assert_eq!(kind("/ip4/1.2.3.4/udp/30333"), TransportKind::Other);
+	}
+
+	#[test]
+	fn addresses_sorted_by_preference() {
+		let mut addresses: Vec<Multiaddr> = vec![
+			"/ip4/1.2.3.4/tcp/1/ws".parse().unwrap(),
+			"/ip4/1.2.3.4/udp/2/quic".parse().unwrap(),
+			"/ip4/1.2.3.4/tcp/3".parse().unwrap(),
+			"/ip4/1.2.3.4/tcp/4/ws".parse().unwrap(),
+		];
+
+		sort_by_preference(&mut addresses, &[TransportKind::Tcp, TransportKind::WebSocket]);
+
+		assert_eq!(addresses, vec![
+			"/ip4/1.2.3.4/tcp/3".parse::<Multiaddr>().unwrap(),
+			"/ip4/1.2.3.4/tcp/1/ws".parse().unwrap(),
+			"/ip4/1.2.3.4/tcp/4/ws".parse().unwrap(),
+			"/ip4/1.2.3.4/udp/2/quic".parse().unwrap(),
+		]);
+	}
+}
@@ -776,7 +776,11 @@ pub trait TestNetFactory: Sized where <Self::BlockImport as BlockImport<Block>>:
 		};
 
 		let network = NetworkWorker::new(sc_network::config::Params {
-			role: if config.is_authority { Role::Authority } else { Role::Full },
+			role: if config.is_authority {
+				Role::Authority { sentry_nodes: Vec::new() }
+			} else {
+				Role::Full
+			},
 			executor: None,
 			transactions_handler_executor: Box::new(|task| { async_std::task::spawn(task); }),
 			network_config,
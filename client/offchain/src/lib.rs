@@ -46,7 +46,7 @@ use sp_api::{ApiExt, ProvideRuntimeApi};
 use futures::future::Future;
 use log::{debug, warn};
 use sc_network::{ExHashT, NetworkService, NetworkStateInfo, PeerId};
-use sp_core::{offchain, ExecutionContext, traits::SpawnNamed};
+use sp_core::{ExecutionContext, traits::SpawnNamed};
 use sp_runtime::{generic::BlockId, traits::{self, Header}};
 use futures::{prelude::*, future::ready};
 
@@ -156,9 +156,7 @@ impl<Client, Block> OffchainWorkers<
 				let runtime = client.runtime_api();
 				let api = Box::new(api);
 				debug!("Running offchain workers at {:?}", at);
-				let context = ExecutionContext::OffchainCall(Some(
-					(api, offchain::Capabilities::all())
-				));
+				let context = ExecutionContext::OffchainWorker(api);
 				let run = if version == 2 {
 					runtime.offchain_worker_with_context(&at, context, &header)
 				} else {
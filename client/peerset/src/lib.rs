@@ -58,6 +58,8 @@ enum Action {
 	RemoveReservedPeer(SetId, PeerId),
 	SetReservedPeers(SetId, HashSet<PeerId>),
 	SetReservedOnly(SetId, bool),
+	AddNoSlotPeer(SetId, PeerId),
+	RemoveNoSlotPeer(SetId, PeerId),
 	ReportPeer(PeerId, ReputationChange),
 	AddToPeersSet(SetId, PeerId),
 	RemoveFromPeersSet(SetId, PeerId),
@@ -147,6 +149,24 @@ impl PeersetHandle {
 		let _ = self.tx.unbounded_send(Action::SetReservedPeers(set_id, peer_ids));
 	}
 
+	/// Marks a peer as not occupying a slot of the set when connected, without making it a
+	/// reserved peer.
+	///
+	/// This is meant for the peers that are needed by auxiliary protocols, so that they don't
+	/// take the slots of the other peers of the set.
+	///
+	/// Has no effect if the node was already marked.
+	pub fn add_no_slot_peer(&self, set_id: SetId, peer_id: PeerId) {
+		let _ = self.tx.unbounded_send(Action::AddNoSlotPeer(set_id, peer_id));
+	}
+
+	/// Makes a peer previously added with [`PeersetHandle::add_no_slot_peer`] occupy a slot again.
+	///
+	/// Has no effect if the node was not marked.
+	pub fn remove_no_slot_peer(&self, set_id: SetId, peer_id: PeerId) {
+		let _ = self.tx.unbounded_send(Action::RemoveNoSlotPeer(set_id, peer_id));
+	}
+
 	/// Reports an adjustment to the reputation of the given peer.
 	pub fn report_peer(&self, peer_id: PeerId, score_diff: ReputationChange) {
 		let _ = self.tx.unbounded_send(Action::ReportPeer(peer_id, score_diff));
@@ -239,9 +259,13 @@ pub struct Peerset {
 	/// Underlying data structure for the nodes's states.
 	data: peersstate::PeersState,
 	/// For each set, lists of nodes that don't occupy slots and that we should try to always be
-	/// connected to, and whether only reserved nodes are accepted. Is kept in sync with the list
-	/// of non-slot-occupying nodes in [`Peerset::data`].
+	/// connected to, and whether only reserved nodes are accepted.
 	reserved_nodes: Vec<(HashSet<PeerId>, bool)>,
+	/// For each set, lists of nodes that don't occupy slots but which are not reserved.
+	///
+	/// Together with [`Peerset::reserved_nodes`], is kept in sync with the list of
+	/// non-slot-occupying nodes in [`Peerset::data`].
+	no_slot_nodes: Vec<HashSet<PeerId>>,
 	/// Receiver for messages from the `PeersetHandle` and from `tx`.
 	rx: TracingUnboundedReceiver<Action>,
 	/// Sending side of `rx`.
@@ -279,6 +303,7 @@ impl Peerset {
 				reserved_nodes: config.sets.iter().map(|set| {
 					(set.reserved_nodes.clone(), set.reserved_only)
 				}).collect(),
+				no_slot_nodes: config.sets.iter().map(|_| HashSet::new()).collect(),
 				message_queue: VecDeque::new(),
 				created: now,
 				latest_time_update: now,
@@ -322,7 +347,9 @@ impl Peerset {
 			return;
 		}
 
-		self.data.remove_no_slot_node(set_id.0, &peer_id);
+		if !self.no_slot_nodes[set_id.0].contains(&peer_id) {
+			self.data.remove_no_slot_node(set_id.0, &peer_id);
+		}
 
 		// Nothing more to do if not in reserved-only mode.
 		if !self.reserved_nodes[set_id.0].1 {
@@ -340,6 +367,27 @@ impl Peerset {
 		}
 	}
 
+	fn on_add_no_slot_peer(&mut self, set_id: SetId, peer_id: PeerId) {
+		if !self.no_slot_nodes[set_id.0].insert(peer_id.clone()) {
+			return;
+		}
+
+		// The peer frees its slot if it is connected.
+		self.data.add_no_slot_node(set_id.0, peer_id);
+		self.alloc_slots(set_id);
+	}
+
+	fn on_remove_no_slot_peer(&mut self, set_id: SetId, peer_id: PeerId) {
+		if !self.no_slot_nodes[set_id.0].remove(&peer_id) {
+			return;
+		}
+
+		// Reserved peers never occupy a slot.
+		if !self.reserved_nodes[set_id.0].0.contains(&peer_id) {
+			self.data.remove_no_slot_node(set_id.0, &peer_id);
+		}
+	}
+
 	fn on_set_reserved_peers(&mut self, set_id: SetId, peer_ids: HashSet<PeerId>) {
 		// Determine the difference between the current group and the new list.
 		let (to_insert, to_remove) = {
@@ -388,6 +436,11 @@ impl Peerset {
 		self.reserved_nodes[set_id.0].0.iter()
 	}
 
+	/// Returns true if only the reserved peers are accepted in the given set.
+	pub fn is_reserved_only(&self, set_id: SetId) -> bool {
+		self.reserved_nodes[set_id.0].1
+	}
+
 	/// Adds a node to the given set. The peerset will, if possible and not already the case,
 	/// try to connect to it.
 	///
@@ -726,6 +779,10 @@ impl Stream for Peerset {
 					self.on_set_reserved_peers(set_id, peer_ids),
 				Action::SetReservedOnly(set_id, reserved) =>
 					self.on_set_reserved_only(set_id, reserved),
+				Action::AddNoSlotPeer(set_id, peer_id) =>
+					self.on_add_no_slot_peer(set_id, peer_id),
+				Action::RemoveNoSlotPeer(set_id, peer_id) =>
+					self.on_remove_no_slot_peer(set_id, peer_id),
 				Action::ReportPeer(peer_id, score_diff) =>
 					self.on_report_peer(peer_id, score_diff),
 				Action::AddToPeersSet(sets_name, peer_id) =>
@@ -829,6 +886,39 @@ mod tests {
 		]);
 	}
 
+	#[test]
+	fn test_peerset_no_slot_peer_incoming() {
+		let incoming = PeerId::random();
+		let no_slot = PeerId::random();
+		let incoming2 = PeerId::random();
+		let config = PeersetConfig {
+			sets: vec![SetConfig {
+				in_peers: 1,
+				out_peers: 0,
+				bootnodes: vec![],
+				reserved_nodes: Default::default(),
+				reserved_only: false,
+			}],
+		};
+
+		let (mut peerset, _handle) = Peerset::from_config(config);
+		peerset.on_add_no_slot_peer(SetId::from(0), no_slot.clone());
+		peerset.incoming(SetId::from(0), incoming, IncomingIndex(1));
+		peerset.incoming(SetId::from(0), no_slot.clone(), IncomingIndex(2));
+		peerset.incoming(SetId::from(0), incoming2.clone(), IncomingIndex(3));
+
+		// Once the peer occupies a slot again, the slots are over-subscribed.
+		peerset.on_remove_no_slot_peer(SetId::from(0), no_slot);
+		peerset.incoming(SetId::from(0), incoming2, IncomingIndex(4));
+
+		assert_messages(peerset, vec![
+			Message::Accept(IncomingIndex(1)),
+			Message::Accept(IncomingIndex(2)),
+			Message::Reject(IncomingIndex(3)),
+			Message::Reject(IncomingIndex(4)),
+		]);
+	}
+
 	#[test]
 	fn test_peerset_reject_incoming_in_reserved_only() {
 		let incoming = PeerId::random();
@@ -102,6 +102,8 @@ const UNSUPPORTED_KEY_TYPE: i64 = POOL_INVALID_TX + 7;
 /// The transaction was not included to the pool since it is unactionable,
 /// it is not propagable and the local node does not author blocks.
 const POOL_UNACTIONABLE: i64 = POOL_INVALID_TX + 8;
+/// The transaction can't be validated since the node is still syncing.
+const POOL_NOT_SYNCED: i64 = POOL_INVALID_TX + 9;
 
 impl From<Error> for rpc::Error {
 	fn from(e: Error) -> Self {
@@ -169,6 +171,14 @@ impl From<Error> for rpc::Error {
 					 the local node does not author blocks".into(),
 				),
 			},
+			Error::Pool(PoolError::NotSynced) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(POOL_NOT_SYNCED),
+				message: "Node Not Synced".into(),
+				data: Some(
+					"The node is syncing and can't validate transactions yet, \
+					 please retry once it is synced".into(),
+				),
+			},
 			Error::UnsupportedKeyType => rpc::Error {
 				code: rpc::ErrorCode::ServerError(UNSUPPORTED_KEY_TYPE),
 				message: "Unknown key type crypto" .into(),
@@ -39,6 +39,13 @@ pub trait AuthorApi<Hash, BlockHash> {
 	#[rpc(name = "author_submitExtrinsic")]
 	fn submit_extrinsic(&self, extrinsic: Bytes) -> FutureResult<Hash>;
 
+	/// Submit hex-encoded extrinsic for inclusion in block, as a transaction of the node itself.
+	///
+	/// The extrinsic is validated with the `Local` transaction source, so the runtime can accept
+	/// transactions that it only accepts from the node itself and mark them as not propagated.
+	#[rpc(name = "author_submitLocalExtrinsic")]
+	fn submit_local_extrinsic(&self, extrinsic: Bytes) -> FutureResult<Hash>;
+
 	/// Insert a key into the keystore.
 	#[rpc(name = "author_insertKey")]
 	fn insert_key(
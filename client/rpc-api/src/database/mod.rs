@@ -0,0 +1,38 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate database API.
+
+pub mod error;
+
+use jsonrpc_derive::rpc;
+use self::error::Result;
+
+pub use self::gen_client::Client as DatabaseClient;
+
+/// Substrate database RPC API
+#[rpc]
+pub trait DatabaseApi {
+	/// Write a consistent copy of the database of the node to the directory `target` of the node,
+	/// which must not exist or be empty.
+	///
+	/// Block import is paused while a snapshot of the database is taken. A ParityDB database has
+	/// no snapshots and can't be backed up while the node is running.
+	#[rpc(name = "database_backup")]
+	fn backup(&self, target: String) -> Result<()>;
+}
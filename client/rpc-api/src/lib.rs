@@ -34,7 +34,10 @@ pub use policy::DenyUnsafe;
 
 pub mod author;
 pub mod chain;
+pub mod database;
+pub mod mixnet;
 pub mod offchain;
 pub mod state;
+pub mod statement;
 pub mod child_state;
 pub mod system;
@@ -0,0 +1,51 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Mixnet RPC errors.
+
+use jsonrpc_core as rpc;
+
+/// Mixnet RPC Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Mixnet RPC future Result type.
+pub type FutureResult<T> = Box<dyn rpc::futures::Future<Item = T, Error = Error> + Send>;
+
+/// Mixnet RPC errors.
+#[derive(Debug, derive_more::Display)]
+pub enum Error {
+	/// The extrinsic could not be submitted through the mixnet.
+	#[display(fmt="Mixnet error: {}", _0)]
+	Mixnet(Box<dyn std::error::Error + Send>),
+}
+
+impl std::error::Error for Error {}
+
+/// Base error code for all mixnet errors.
+const BASE_ERROR: i64 = 6000;
+
+impl From<Error> for rpc::Error {
+	fn from(e: Error) -> Self {
+		match e {
+			Error::Mixnet(e) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 1),
+				message: e.to_string(),
+				data: None,
+			},
+		}
+	}
+}
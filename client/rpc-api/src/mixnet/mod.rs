@@ -0,0 +1,37 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate mixnet API.
+
+pub mod error;
+
+use jsonrpc_derive::rpc;
+use sp_core::Bytes;
+use self::error::FutureResult;
+
+pub use self::gen_client::Client as MixnetClient;
+
+/// Substrate mixnet RPC API
+#[rpc]
+pub trait MixnetApi {
+	/// Submit encoded extrinsic through the mixnet, without revealing the IP address of the
+	/// sender to the node importing it.
+	///
+	/// Returns once the extrinsic has been sent to the first mixnode of its route.
+	#[rpc(name = "mixnet_submitExtrinsic")]
+	fn submit_extrinsic(&self, extrinsic: Bytes) -> FutureResult<()>;
+}
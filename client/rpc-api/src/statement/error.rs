@@ -0,0 +1,76 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Statement store RPC errors.
+
+use jsonrpc_core as rpc;
+
+/// Statement store RPC Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Statement store RPC errors.
+#[derive(Debug, derive_more::Display, derive_more::From)]
+pub enum Error {
+	/// The statement could not be decoded.
+	#[display(fmt="Bad statement encoding: {}", _0)]
+	#[from(ignore)]
+	Decode(String),
+	/// The statement was not accepted by the store.
+	#[display(fmt="Statement rejected: {}", _0)]
+	#[from(ignore)]
+	Rejected(String),
+	/// The statement store could not be accessed.
+	#[display(fmt="Statement store error: {}", _0)]
+	StatementStore(Box<dyn std::error::Error + Send>),
+	/// Call to an unsafe RPC was denied.
+	UnsafeRpcCalled(crate::policy::UnsafeRpcError),
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::UnsafeRpcCalled(err) => Some(err),
+			_ => None,
+		}
+	}
+}
+
+/// Base error code for all statement store errors.
+const BASE_ERROR: i64 = 7000;
+
+impl From<Error> for rpc::Error {
+	fn from(e: Error) -> Self {
+		match e {
+			Error::Decode(_) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 1),
+				message: e.to_string(),
+				data: None,
+			},
+			Error::Rejected(_) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 2),
+				message: e.to_string(),
+				data: None,
+			},
+			Error::StatementStore(_) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 3),
+				message: e.to_string(),
+				data: None,
+			},
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
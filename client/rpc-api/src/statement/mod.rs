@@ -0,0 +1,50 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate statement store API.
+
+pub mod error;
+
+use jsonrpc_derive::rpc;
+use sp_core::{Bytes, H256};
+use self::error::Result;
+
+pub use self::gen_client::Client as StatementClient;
+
+/// Substrate statement store RPC API
+#[rpc]
+pub trait StatementApi {
+	/// Submit an encoded statement to the statement store, to be gossiped to the network.
+	#[rpc(name = "statement_submit")]
+	fn submit(&self, encoded: Bytes) -> Result<()>;
+
+	/// Return all the encoded statements in the statement store.
+	///
+	/// This method is only available to trusted users.
+	#[rpc(name = "statement_dump")]
+	fn dump(&self) -> Result<Vec<Bytes>>;
+
+	/// Return the data of all the statements which have all of the given topics.
+	#[rpc(name = "statement_broadcasts")]
+	fn broadcasts(&self, match_all_topics: Vec<H256>) -> Result<Vec<Bytes>>;
+
+	/// Remove the statement with the given hash from the statement store.
+	///
+	/// This method is only available to trusted users.
+	#[rpc(name = "statement_remove")]
+	fn remove(&self, hash: H256) -> Result<()>;
+}
@@ -73,6 +73,28 @@ pub struct PeerInfo<Hash, Number> {
 	pub best_number: Number,
 }
 
+/// A reserved peer of a protocol.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservedPeer {
+	/// Peer ID
+	pub peer_id: String,
+	/// Is a substream of the protocol open with the peer
+	pub is_connected: bool,
+}
+
+/// The reserved peers of a notifications protocol.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservedPeersSet {
+	/// Name of the notifications protocol
+	pub protocol: String,
+	/// Whether only the reserved peers are accepted
+	pub reserved_only: bool,
+	/// The reserved peers of the protocol
+	pub peers: Vec<ReservedPeer>,
+}
+
 /// The role the node is running as
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum NodeRole {
@@ -82,6 +104,8 @@ pub enum NodeRole {
 	LightClient,
 	/// The node is an authority
 	Authority,
+	/// The node is a sentry of authorities
+	Sentry,
 }
 
 /// The state of the syncing of the node.
@@ -145,4 +169,19 @@ mod tests {
 			r#"{"startingBlock":12,"currentBlock":50}"#,
 		);
 	}
+
+	#[test]
+	fn should_serialize_reserved_peers_set() {
+		assert_eq!(
+			::serde_json::to_string(&ReservedPeersSet {
+				protocol: "/dot/block-announces/1".into(),
+				reserved_only: true,
+				peers: vec![ReservedPeer { peer_id: "2".into(), is_connected: false }],
+			}).unwrap(),
+			concat!(
+				r#"{"protocol":"/dot/block-announces/1","reservedOnly":true,"#,
+				r#""peers":[{"peerId":"2","isConnected":false}]}"#,
+			),
+		);
+	}
 }
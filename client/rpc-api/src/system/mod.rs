@@ -27,7 +27,9 @@ use futures::{future::BoxFuture, compat::Compat};
 
 use self::error::Result as SystemResult;
 
-pub use self::helpers::{SystemInfo, Health, PeerInfo, NodeRole, SyncState};
+pub use self::helpers::{
+	SystemInfo, Health, PeerInfo, NodeRole, SyncState, ReservedPeer, ReservedPeersSet,
+};
 pub use self::gen_client::Client as SystemClient;
 
 /// Substrate system RPC API
@@ -106,6 +108,14 @@ pub trait SystemApi<Hash, Number> {
 	#[rpc(name = "system_reservedPeers", returns = "Vec<String>")]
 	fn system_reserved_peers(&self) -> Receiver<Vec<String>>;
 
+	/// Returns, for each notifications protocol, its reserved peers and whether the node is
+	/// currently connected to them.
+	///
+	/// Lets operators check that the reserved topology of the node, e.g. a validator behind
+	/// sentries, is actually in effect.
+	#[rpc(name = "system_reservedPeersState", returns = "Vec<ReservedPeersSet>")]
+	fn system_reserved_peers_state(&self) -> Receiver<Vec<ReservedPeersSet>>;
+
 	/// Returns the roles the node is running as.
 	#[rpc(name = "system_nodeRoles", returns = "Vec<NodeRole>")]
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>>;
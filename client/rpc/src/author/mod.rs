@@ -79,13 +79,31 @@ impl<P, Client> Author<P, Client> {
 	}
 }
 
-/// Currently we treat all RPC transactions as externals.
-///
-/// Possibly in the future we could allow opt-in for special treatment
-/// of such transactions, so that the block authors can inject
-/// some unique transactions via RPC and have them included in the pool.
+/// We treat the RPC transactions as externals, unless they are explicitly submitted as local
+/// transactions with `author_submitLocalExtrinsic`.
 const TX_SOURCE: TransactionSource = TransactionSource::External;
 
+impl<P, Client> Author<P, Client>
+	where
+		P: TransactionPool + Sync + Send + 'static,
+		Client: HeaderBackend<P::Block> + Send + Sync + 'static,
+{
+	fn submit(&self, ext: Bytes, source: TransactionSource) -> FutureResult<TxHash<P>> {
+		let xt = match Decode::decode(&mut &ext[..]) {
+			Ok(xt) => xt,
+			Err(err) => return Box::new(result(Err(err.into()))),
+		};
+		let best_block_hash = self.client.info().best_hash;
+		Box::new(self.pool
+			.submit_one(&generic::BlockId::hash(best_block_hash), source, xt)
+			.compat()
+			.map_err(|e| e.into_pool_error()
+				.map(Into::into)
+				.unwrap_or_else(|e| error::Error::Verification(Box::new(e)).into()))
+		)
+	}
+}
+
 impl<P, Client> AuthorApi<TxHash<P>, BlockHash<P>> for Author<P, Client>
 	where
 		P: TransactionPool + Sync + Send + 'static,
@@ -139,18 +157,15 @@ impl<P, Client> AuthorApi<TxHash<P>, BlockHash<P>> for Author<P, Client>
 	}
 
 	fn submit_extrinsic(&self, ext: Bytes) -> FutureResult<TxHash<P>> {
-		let xt = match Decode::decode(&mut &ext[..]) {
-			Ok(xt) => xt,
-			Err(err) => return Box::new(result(Err(err.into()))),
-		};
-		let best_block_hash = self.client.info().best_hash;
-		Box::new(self.pool
-			.submit_one(&generic::BlockId::hash(best_block_hash), TX_SOURCE, xt)
-			.compat()
-			.map_err(|e| e.into_pool_error()
-				.map(Into::into)
-				.unwrap_or_else(|e| error::Error::Verification(Box::new(e)).into()))
-		)
+		self.submit(ext, TX_SOURCE)
+	}
+
+	fn submit_local_extrinsic(&self, ext: Bytes) -> FutureResult<TxHash<P>> {
+		if let Err(err) = self.deny_unsafe.check_if_safe() {
+			return Box::new(result(Err(err.into())))
+		}
+
+		self.submit(ext, TransactionSource::Local)
 	}
 
 	fn pending_extrinsics(&self) -> Result<Vec<Bytes>> {
@@ -120,6 +120,28 @@ fn submit_rich_transaction_should_not_cause_error() {
 	);
 }
 
+#[test]
+fn submit_local_transaction_should_be_unsafe() {
+	let setup = TestSetup::default();
+	let xt = uxt(AccountKeyring::Alice, 0).encode();
+	let h: H256 = blake2_256(&xt).into();
+
+	let mut p = setup.author();
+	p.deny_unsafe = DenyUnsafe::Yes;
+	assert_matches!(
+		AuthorApi::submit_local_extrinsic(&p, xt.clone().into()).wait(),
+		Err(Error::UnsafeRpcCalled(_))
+	);
+	assert_eq!(setup.pool.status().ready, 0);
+
+	let p = setup.author();
+	assert_matches!(
+		AuthorApi::submit_local_extrinsic(&p, xt.into()).wait(),
+		Ok(h2) if h == h2
+	);
+	assert_eq!(setup.pool.status().ready, 1);
+}
+
 #[test]
 fn should_watch_extrinsic() {
 	//given
@@ -0,0 +1,57 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate database API.
+
+#[cfg(test)]
+mod tests;
+
+pub use sc_rpc_api::database::*;
+use sc_rpc_api::DenyUnsafe;
+use self::error::{Error, Result};
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, path::Path, sync::Arc};
+
+/// Database API
+pub struct Database<Block, Backend> {
+	backend: Arc<Backend>,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<Block>,
+}
+
+impl<Block, Backend> Database<Block, Backend> {
+	/// Create new instance of Database API.
+	pub fn new(backend: Arc<Backend>, deny_unsafe: DenyUnsafe) -> Self {
+		Database {
+			backend,
+			deny_unsafe,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<Block, Backend> DatabaseApi for Database<Block, Backend> where
+	Block: BlockT,
+	Backend: sc_client_api::Backend<Block> + 'static,
+{
+	fn backup(&self, target: String) -> Result<()> {
+		self.deny_unsafe.check_if_safe()?;
+
+		self.backend.backup(Path::new(&target)).map_err(|e| Error::BackupFailed(e.to_string()))
+	}
+}
@@ -0,0 +1,38 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use assert_matches::assert_matches;
+use substrate_test_runtime_client::{runtime::Block, TestClientBuilder};
+
+#[test]
+fn backup_is_unsafe() {
+	let backend = TestClientBuilder::new().backend();
+	let database = Database::<Block, _>::new(backend, DenyUnsafe::Yes);
+
+	assert_matches!(database.backup("backup".into()), Err(Error::UnsafeRpcCalled(_)));
+}
+
+#[test]
+fn backup_reports_backend_errors() {
+	// The test backend is an in-memory database, which can't be backed up.
+	let backend = TestClientBuilder::new().backend();
+	let database = Database::<Block, _>::new(backend, DenyUnsafe::No);
+
+	assert_matches!(database.backup("backup".into()), Err(Error::BackupFailed(_)));
+}
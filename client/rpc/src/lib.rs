@@ -32,8 +32,11 @@ pub use rpc::IoHandlerExtension as RpcExtension;
 
 pub mod author;
 pub mod chain;
+pub mod database;
+pub mod mixnet;
 pub mod offchain;
 pub mod state;
+pub mod statement;
 pub mod system;
 
 #[cfg(any(test, feature = "test-helpers"))]
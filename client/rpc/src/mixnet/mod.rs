@@ -0,0 +1,46 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate mixnet API.
+
+use futures::{FutureExt, TryFutureExt};
+/// Re-export the API for backward compatibility.
+pub use sc_rpc_api::mixnet::*;
+use self::error::{Error, FutureResult};
+use sp_core::Bytes;
+
+/// Mixnet API
+pub struct Mixnet {
+	api: sc_mixnet::Api,
+}
+
+impl Mixnet {
+	/// Create new instance of Mixnet API.
+	pub fn new(api: sc_mixnet::Api) -> Self {
+		Mixnet { api }
+	}
+}
+
+impl MixnetApi for Mixnet {
+	fn submit_extrinsic(&self, extrinsic: Bytes) -> FutureResult<()> {
+		let api = self.api.clone();
+		Box::new(async move { api.submit_extrinsic(extrinsic.0).await }
+			.map_err(|e| Error::Mixnet(Box::new(e)))
+			.boxed()
+			.compat())
+	}
+}
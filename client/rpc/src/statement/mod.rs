@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate statement store API.
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+/// Re-export the API for backward compatibility.
+pub use sc_rpc_api::statement::*;
+use sc_rpc_api::DenyUnsafe;
+use self::error::{Error, Result};
+use sp_core::{Bytes, H256};
+use sp_statement_store::{Statement, StatementSource, StatementStore, SubmitResult};
+use std::sync::Arc;
+
+/// Statement store API
+pub struct StatementStoreApi {
+	store: Arc<dyn StatementStore>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl StatementStoreApi {
+	/// Create new instance of the statement store API.
+	pub fn new(store: Arc<dyn StatementStore>, deny_unsafe: DenyUnsafe) -> Self {
+		StatementStoreApi { store, deny_unsafe }
+	}
+}
+
+impl StatementApi for StatementStoreApi {
+	fn submit(&self, encoded: Bytes) -> Result<()> {
+		let statement = Statement::decode(&mut &*encoded)
+			.map_err(|e| Error::Decode(e.to_string()))?;
+		match self.store.submit(statement, StatementSource::Local) {
+			SubmitResult::New | SubmitResult::Known => Ok(()),
+			SubmitResult::KnownExpired =>
+				Err(Error::Rejected("The statement has left the store".into())),
+			SubmitResult::Ignored =>
+				Err(Error::Rejected("The signer has no room left for the statement".into())),
+			SubmitResult::Bad(e) => Err(Error::Rejected(e.into())),
+			SubmitResult::Invalid(e) => Err(Error::Rejected(e.to_string())),
+			SubmitResult::InternalError(e) => Err(Error::StatementStore(Box::new(e))),
+		}
+	}
+
+	fn dump(&self) -> Result<Vec<Bytes>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let statements = self.store.statements().map_err(|e| Error::StatementStore(Box::new(e)))?;
+		Ok(statements.into_iter().map(|(_, statement)| statement.encode().into()).collect())
+	}
+
+	fn broadcasts(&self, match_all_topics: Vec<H256>) -> Result<Vec<Bytes>> {
+		let topics = match_all_topics.into_iter().map(|topic| topic.0).collect::<Vec<_>>();
+		let data = self.store.broadcasts(&topics).map_err(|e| Error::StatementStore(Box::new(e)))?;
+		Ok(data.into_iter().map(Into::into).collect())
+	}
+
+	fn remove(&self, hash: H256) -> Result<()> {
+		self.deny_unsafe.check_if_safe()?;
+
+		self.store.remove(&hash.0).map_err(|e| Error::StatementStore(Box::new(e)))
+	}
+}
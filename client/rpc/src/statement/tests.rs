@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use assert_matches::assert_matches;
+use parking_lot::Mutex;
+use sp_statement_store::Hash;
+
+/// A store which keeps every statement with some data, and refuses the others.
+#[derive(Default)]
+struct TestStore {
+	statements: Mutex<Vec<Statement>>,
+}
+
+impl StatementStore for TestStore {
+	fn statements(&self) -> sp_statement_store::Result<Vec<(Hash, Statement)>> {
+		Ok(self.statements.lock().iter().map(|s| (s.hash(), s.clone())).collect())
+	}
+
+	fn statement(&self, hash: &Hash) -> sp_statement_store::Result<Option<Statement>> {
+		Ok(self.statements.lock().iter().find(|s| s.hash() == *hash).cloned())
+	}
+
+	fn broadcasts(
+		&self,
+		match_all_topics: &[[u8; 32]],
+	) -> sp_statement_store::Result<Vec<Vec<u8>>> {
+		Ok(self.statements.lock()
+			.iter()
+			.filter(|s| match_all_topics.iter().all(|topic| s.topics.contains(topic)))
+			.map(|s| s.data.clone())
+			.collect())
+	}
+
+	fn submit(&self, statement: Statement, _source: StatementSource) -> SubmitResult {
+		if statement.data.is_empty() {
+			return SubmitResult::Bad("No data")
+		}
+		self.statements.lock().push(statement);
+		SubmitResult::New
+	}
+
+	fn remove(&self, hash: &Hash) -> sp_statement_store::Result<()> {
+		self.statements.lock().retain(|s| s.hash() != *hash);
+		Ok(())
+	}
+}
+
+fn statement(topics: &[[u8; 32]], data: &[u8]) -> Statement {
+	Statement { topics: topics.to_vec(), data: data.to_vec(), ..Default::default() }
+}
+
+#[test]
+fn statements_are_submitted_and_queried() {
+	let api = StatementStoreApi::new(Arc::new(TestStore::default()), DenyUnsafe::No);
+	let first = statement(&[[1; 32], [2; 32]], b"first");
+	let second = statement(&[[1; 32]], b"second");
+
+	assert_matches!(api.submit(first.encode().into()), Ok(()));
+	assert_matches!(api.submit(second.encode().into()), Ok(()));
+	assert_matches!(api.submit(statement(&[], b"").encode().into()), Err(Error::Rejected(_)));
+	assert_matches!(api.submit(vec![0xff].into()), Err(Error::Decode(_)));
+
+	assert_eq!(
+		api.broadcasts(vec![H256([1; 32]), H256([2; 32])]).unwrap(),
+		vec![Bytes(b"first".to_vec())],
+	);
+	assert_eq!(api.dump().unwrap(), vec![first.encode().into(), second.encode().into()]);
+
+	assert_matches!(api.remove(H256(first.hash())), Ok(()));
+	assert_eq!(api.dump().unwrap(), vec![Bytes(second.encode())]);
+}
+
+#[test]
+fn dump_and_remove_considered_unsafe() {
+	let api = StatementStoreApi::new(Arc::new(TestStore::default()), DenyUnsafe::Yes);
+
+	assert_matches!(api.dump(), Err(Error::UnsafeRpcCalled(_)));
+	assert_matches!(api.remove(H256([0; 32])), Err(Error::UnsafeRpcCalled(_)));
+}
@@ -31,7 +31,9 @@ use sp_runtime::traits::{self, Header as HeaderT};
 use self::error::Result;
 
 pub use sc_rpc_api::system::*;
-pub use self::helpers::{SystemInfo, Health, PeerInfo, NodeRole, SyncState};
+pub use self::helpers::{
+	SystemInfo, Health, PeerInfo, NodeRole, SyncState, ReservedPeer, ReservedPeersSet,
+};
 pub use self::gen_client::Client as SystemClient;
 
 /// Early exit for RPCs that require `--rpc-methods=Unsafe` to be enabled
@@ -69,6 +71,8 @@ pub enum Request<B: traits::Block> {
 	NetworkRemoveReservedPeer(String, oneshot::Sender<Result<()>>),
 	/// Must return the list of reserved peers
 	NetworkReservedPeers(oneshot::Sender<Vec<String>>),
+	/// Must return the reserved peers of every notifications protocol and their connectivity.
+	NetworkReservedPeersState(oneshot::Sender<Vec<ReservedPeersSet>>),
 	/// Must return the node role.
 	NodeRoles(oneshot::Sender<Vec<NodeRole>>),
 	/// Must return the state of the node syncing.
@@ -196,6 +200,12 @@ impl<B: traits::Block> SystemApi<B::Hash, <B::Header as HeaderT>::Number> for Sy
 		Receiver(Compat::new(rx))
 	}
 
+	fn system_reserved_peers_state(&self) -> Receiver<Vec<ReservedPeersSet>> {
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::NetworkReservedPeersState(tx));
+		Receiver(Compat::new(rx))
+	}
+
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>> {
 		let (tx, rx) = oneshot::channel();
 		let _ = self.send_back.unbounded_send(Request::NodeRoles(tx));
@@ -104,6 +104,16 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 				Request::NetworkReservedPeers(sender) => {
 					let _ = sender.send(vec!["QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".to_string()]);
 				}
+				Request::NetworkReservedPeersState(sender) => {
+					let _ = sender.send(vec![ReservedPeersSet {
+						protocol: "/sup/block-announces/1".into(),
+						reserved_only: false,
+						peers: vec![ReservedPeer {
+							peer_id: "QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".into(),
+							is_connected: true,
+						}],
+					}]);
+				}
 				Request::NodeRoles(sender) => {
 					let _ = sender.send(vec![NodeRole::Authority]);
 				}
@@ -345,6 +355,21 @@ fn system_network_reserved_peers() {
 	);
 }
 
+#[test]
+fn system_network_reserved_peers_state() {
+	assert_eq!(
+		wait_receiver(api(None).system_reserved_peers_state()),
+		vec![ReservedPeersSet {
+			protocol: "/sup/block-announces/1".into(),
+			reserved_only: false,
+			peers: vec![ReservedPeer {
+				peer_id: "QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".into(),
+				is_connected: true,
+			}],
+		}]
+	);
+}
+
 #[test]
 fn test_add_reset_log_filter() {
 	const EXPECTED_BEFORE_ADD: &'static str = "EXPECTED_BEFORE_ADD";
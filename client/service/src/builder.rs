@@ -19,12 +19,13 @@
 use crate::{
 	error::Error, MallocSizeOfWasm, RpcHandlers,
 	start_rpc_servers, build_network_future, TransactionPoolAdapter, TaskManager, SpawnTaskHandle,
+	DEFAULT_GROUP_NAME, NETWORK_GROUP_NAME,
 	metrics::MetricsService,
 	client::{light, Client, ClientConfig},
 	config::{Configuration, KeystoreConfig, PrometheusConfig},
 };
 use sc_client_api::{
-	light::RemoteBlockchain, ForkBlocks, BadBlocks, UsageProvider, ExecutorProvider,
+	light::RemoteBlockchain, ForkBlocks, BadBlocks, UsageProvider, ExecutorProvider, CallExecutor,
 };
 use sp_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
 use sc_chain_spec::get_extension;
@@ -39,7 +40,7 @@ use futures::{
 	channel::oneshot,
 };
 use sc_keystore::LocalKeystore;
-use log::info;
+use log::{info, warn};
 use sc_network::config::{Role, OnDemand};
 use sc_network::NetworkService;
 use sc_network::block_request_handler::{self, BlockRequestHandler};
@@ -302,28 +303,65 @@ pub fn new_full_client<TBl, TRtApi, TExecDisp>(
 	new_full_parts(config, telemetry).map(|parts| parts.0)
 }
 
-/// Create the initial parts of a full node.
-pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
-	config: &Configuration,
-	telemetry: Option<TelemetryHandle>,
-) -> Result<TFullParts<TBl, TRtApi, TExecDisp>, Error> where
-	TBl: BlockT,
+/// Create the task manager of a node, registering its metrics in the Prometheus registry of the
+/// configuration.
+///
+/// On shutdown, the networking tasks are stopped first, so that no more blocks or requests reach
+/// the other tasks while they are stopped.
+pub fn new_task_manager(config: &Configuration) -> Result<TaskManager, Error> {
+	let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
+	let mut task_manager = TaskManager::new(config.task_executor.clone(), registry)?;
+	task_manager.set_shutdown_order(&[NETWORK_GROUP_NAME, DEFAULT_GROUP_NAME]);
+	Ok(task_manager)
+}
+
+/// Create the native executor of a node, with the wasm execution settings of the configuration.
+pub fn new_native_executor<TExecDisp>(config: &Configuration) -> NativeExecutor<TExecDisp> where
 	TExecDisp: NativeExecutionDispatch + 'static,
-	TBl::Hash: FromStr,
 {
-	let keystore_container = KeystoreContainer::new(&config.keystore)?;
-
-	let task_manager = {
-		let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
-		TaskManager::new(config.task_executor.clone(), registry)?
-	};
-
-	let executor = NativeExecutor::<TExecDisp>::new(
+	NativeExecutor::<TExecDisp>::new(
 		config.wasm_method,
 		config.default_heap_pages,
 		config.max_runtime_instances,
-	);
+	)
+}
+
+/// The database settings of the configuration.
+fn database_settings(config: &Configuration) -> DatabaseSettings {
+	DatabaseSettings {
+		state_cache_size: config.state_cache_size,
+		state_cache_child_ratio: config.state_cache_child_ratio.map(|v| (v, 100)),
+		trie_cache_maximum_size: config.trie_cache_maximum_size,
+		state_pruning: config.state_pruning.clone(),
+		source: config.database.clone(),
+		keep_blocks: config.keep_blocks.clone(),
+		transaction_storage: config.transaction_storage.clone(),
+	}
+}
+
+/// Create the database backend of a full node with the database settings of the configuration.
+pub fn new_full_backend<TBl>(config: &Configuration) -> Result<Arc<TFullBackend<TBl>>, Error> where
+	TBl: BlockT,
+{
+	Ok(new_db_backend(database_settings(config))?)
+}
 
+/// Create the client of a full node on top of the given `backend` and `executor`.
+///
+/// The keystore of `keystore_container` is made available to the runtime, and the tasks of the
+/// client are spawned with `task_manager`.
+pub fn new_full_client_with_backend<TBl, TRtApi, TExecDisp>(
+	config: &Configuration,
+	backend: Arc<TFullBackend<TBl>>,
+	executor: NativeExecutor<TExecDisp>,
+	keystore_container: &KeystoreContainer,
+	task_manager: &TaskManager,
+	telemetry: Option<TelemetryHandle>,
+) -> Result<TFullClient<TBl, TRtApi, TExecDisp>, Error> where
+	TBl: BlockT,
+	TExecDisp: NativeExecutionDispatch + 'static,
+	TBl::Hash: FromStr,
+{
 	let chain_spec = &config.chain_spec;
 	let fork_blocks = get_extension::<ForkBlocks<TBl>>(chain_spec.extensions())
 		.cloned()
@@ -333,56 +371,66 @@ pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
 		.cloned()
 		.unwrap_or_default();
 
-	let (client, backend) = {
-		let db_config = sc_client_db::DatabaseSettings {
-			state_cache_size: config.state_cache_size,
-			state_cache_child_ratio:
-			config.state_cache_child_ratio.map(|v| (v, 100)),
-			state_pruning: config.state_pruning.clone(),
-			source: config.database.clone(),
-			keep_blocks: config.keep_blocks.clone(),
-			transaction_storage: config.transaction_storage.clone(),
-		};
-
-
-		let backend = new_db_backend(db_config)?;
-
-		let extensions = sc_client_api::execution_extensions::ExecutionExtensions::new(
-			config.execution_strategies.clone(),
-			Some(keystore_container.sync_keystore()),
-			sc_offchain::OffchainDb::factory_from_backend(&*backend),
-		);
+	let extensions = sc_client_api::execution_extensions::ExecutionExtensions::new(
+		config.execution_strategies.clone(),
+		Some(keystore_container.sync_keystore()),
+		sc_offchain::OffchainDb::factory_from_backend(&*backend),
+	).with_registry(config.offchain_worker.extensions_registry.clone());
+
+	let wasm_runtime_substitutes = config.chain_spec.code_substitutes().into_iter().map(|(h, c)| {
+		let hash = TBl::Hash::from_str(&h)
+			.map_err(|_|
+				 Error::Application(Box::from(
+					format!("Failed to parse `{}` as block hash for code substitutes.", h)
+				))
+			)?;
+		Ok((hash, c))
+	}).collect::<Result<std::collections::HashMap<_, _>, Error>>()?;
+
+	Ok(new_client(
+		backend,
+		executor,
+		chain_spec.as_storage_builder(),
+		fork_blocks,
+		bad_blocks,
+		extensions,
+		Box::new(task_manager.spawn_handle()),
+		config.prometheus_config.as_ref().map(|config| config.registry.clone()),
+		telemetry,
+		ClientConfig {
+			offchain_worker_enabled : config.offchain_worker.enabled,
+			offchain_indexing_api: config.offchain_worker.indexing_enabled,
+			wasm_runtime_overrides: config.wasm_runtime_overrides.clone(),
+			wasm_runtime_substitutes,
+		},
+	)?)
+}
 
-		let wasm_runtime_substitutes = config.chain_spec.code_substitutes().into_iter().map(|(h, c)| {
-			let hash = TBl::Hash::from_str(&h)
-				.map_err(|_|
-					 Error::Application(Box::from(
-						format!("Failed to parse `{}` as block hash for code substitutes.", h)
-					))
-				)?;
-			Ok((hash, c))
-		}).collect::<Result<std::collections::HashMap<_, _>, Error>>()?;
-
-		let client = new_client(
-			backend.clone(),
-			executor,
-			chain_spec.as_storage_builder(),
-			fork_blocks,
-			bad_blocks,
-			extensions,
-			Box::new(task_manager.spawn_handle()),
-			config.prometheus_config.as_ref().map(|config| config.registry.clone()),
-			telemetry,
-			ClientConfig {
-				offchain_worker_enabled : config.offchain_worker.enabled,
-				offchain_indexing_api: config.offchain_worker.indexing_enabled,
-				wasm_runtime_overrides: config.wasm_runtime_overrides.clone(),
-				wasm_runtime_substitutes,
-			},
-		)?;
-
-		(client, backend)
-	};
+/// Create the initial parts of a full node.
+///
+/// This is a shortcut for [`new_task_manager`], [`new_native_executor`], [`new_full_backend`]
+/// and [`new_full_client_with_backend`], which can be called individually to replace one of
+/// the parts.
+pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
+	config: &Configuration,
+	telemetry: Option<TelemetryHandle>,
+) -> Result<TFullParts<TBl, TRtApi, TExecDisp>, Error> where
+	TBl: BlockT,
+	TExecDisp: NativeExecutionDispatch + 'static,
+	TBl::Hash: FromStr,
+{
+	let keystore_container = KeystoreContainer::new(&config.keystore)?;
+	let task_manager = new_task_manager(config)?;
+	let executor = new_native_executor::<TExecDisp>(config);
+	let backend = new_full_backend(config)?;
+	let client = new_full_client_with_backend(
+		config,
+		backend.clone(),
+		executor,
+		&keystore_container,
+		&task_manager,
+		telemetry,
+	)?;
 
 	Ok((
 		client,
@@ -392,6 +440,29 @@ pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
 	))
 }
 
+/// Create the transaction pool of a full node, with the pool options of the configuration.
+///
+/// The pool only accepts transactions which can't be propagated if the node is an authority.
+pub fn new_full_transaction_pool<TBl, TCl>(
+	config: &Configuration,
+	client: Arc<TCl>,
+	task_manager: &TaskManager,
+) -> Arc<sc_transaction_pool::FullPool<TBl, TCl>> where
+	TBl: BlockT,
+	TCl: ProvideRuntimeApi<TBl> + BlockBackend<TBl> + HeaderBackend<TBl> + BlockIdTo<TBl>
+		+ ExecutorProvider<TBl> + UsageProvider<TBl> + Send + Sync + 'static,
+	<TCl as ProvideRuntimeApi<TBl>>::Api:
+		sp_transaction_pool::runtime_api::TaggedTransactionQueue<TBl>,
+{
+	sc_transaction_pool::BasicPool::new_full(
+		config.transaction_pool.clone(),
+		config.role.is_authority().into(),
+		config.prometheus_registry(),
+		task_manager.spawn_handle(),
+		client,
+	)
+}
+
 /// Create the initial parts of a light node.
 pub fn new_light_parts<TBl, TRtApi, TExecDisp>(
 	config: &Configuration,
@@ -401,29 +472,10 @@ pub fn new_light_parts<TBl, TRtApi, TExecDisp>(
 	TExecDisp: NativeExecutionDispatch + 'static,
 {
 	let keystore_container = KeystoreContainer::new(&config.keystore)?;
-	let task_manager = {
-		let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
-		TaskManager::new(config.task_executor.clone(), registry)?
-	};
+	let task_manager = new_task_manager(config)?;
+	let executor = new_native_executor::<TExecDisp>(config);
 
-	let executor = NativeExecutor::<TExecDisp>::new(
-		config.wasm_method,
-		config.default_heap_pages,
-		config.max_runtime_instances,
-	);
-
-	let db_storage = {
-		let db_settings = sc_client_db::DatabaseSettings {
-			state_cache_size: config.state_cache_size,
-			state_cache_child_ratio:
-				config.state_cache_child_ratio.map(|v| (v, 100)),
-			state_pruning: config.state_pruning.clone(),
-			source: config.database.clone(),
-			keep_blocks: config.keep_blocks.clone(),
-			transaction_storage: config.transaction_storage.clone(),
-		};
-		sc_client_db::light::LightStorage::new(db_settings)?
-	};
+	let db_storage = sc_client_db::light::LightStorage::new(database_settings(config))?;
 	let light_blockchain = sc_light::new_light_blockchain(db_storage);
 	let fetch_checker = Arc::new(
 		sc_light::new_fetch_checker::<_, TBl, _>(
@@ -556,6 +608,40 @@ pub fn build_offchain_workers<TBl, TCl>(
 	offchain_workers
 }
 
+/// Compare the native runtime with the runtime of the chain at block `at`.
+///
+/// The native runtime is only used when it has the implementation of the on-chain runtime, see
+/// `RuntimeVersion::can_execute_natively_with`, the wasm runtime is used otherwise. This makes
+/// the mismatch visible when the node starts, instead of silently executing everything in wasm.
+fn check_native_runtime_version<TBl, TCl>(client: &TCl, at: &BlockId<TBl>)
+	where
+		TBl: BlockT,
+		TCl: ExecutorProvider<TBl> + CallApiAt<TBl>,
+{
+	let native = match client.executor().native_runtime_version() {
+		Some(native) => &native.runtime_version,
+		None => return,
+	};
+	let onchain = match client.runtime_version_at(at) {
+		Ok(onchain) => onchain,
+		Err(e) => {
+			warn!("Unable to check the native runtime, failed to get the on-chain version: {:?}", e);
+			return
+		},
+	};
+
+	if !onchain.can_execute_natively_with(native) {
+		warn!(
+			"⚠️  The native runtime ({}) does not match the on-chain runtime ({}), \
+			the wasm runtime is used instead. Upgrade the node to use the native runtime.",
+			native,
+			onchain,
+		);
+	} else {
+		info!("🔗 The native runtime matches the on-chain runtime ({})", onchain);
+	}
+}
+
 /// Spawn the tasks that are required to run a node.
 pub fn spawn_tasks<TBl, TBackend, TExPool, TRpc, TCl>(
 	params: SpawnTasksParams<TBl, TCl, TExPool, TRpc, TBackend>,
@@ -613,6 +699,8 @@ pub fn spawn_tasks<TBl, TBackend, TExPool, TRpc, TCl>(
 
 	info!("📦 Highest known block at #{}", chain_info.best_number);
 
+	check_native_runtime_version(&*client, &BlockId::Hash(chain_info.best_hash));
+
 	let spawn_handle = task_manager.spawn_handle();
 
 	// Inform the tx pool about imported and finalized blocks.
@@ -663,7 +751,7 @@ pub fn spawn_tasks<TBl, TBackend, TExPool, TRpc, TCl>(
 		deny_unsafe, rpc_middleware, &config, task_manager.spawn_handle(),
 		client.clone(), transaction_pool.clone(), keystore.clone(),
 		on_demand.clone(), remote_blockchain.clone(), &*rpc_extensions_builder,
-		backend.offchain_storage(), system_rpc_tx.clone()
+		backend.clone(), system_rpc_tx.clone()
 	);
 	let rpc_metrics = sc_rpc_server::RpcMetrics::new(config.prometheus_registry())?;
 	let rpc = start_rpc_servers(&config, gen_handler, rpc_metrics.clone())?;
@@ -728,6 +816,7 @@ fn init_telemetry<TBl: BlockT, TCl: BlockBackend<TBl>>(
 		chain: config.chain_spec.name().to_owned(),
 		genesis_hash: format!("{:?}", genesis_hash),
 		authority: config.role.is_authority(),
+		role: config.role.to_string(),
 		startup_time: SystemTime::UNIX_EPOCH.elapsed()
 			.map(|dur| dur.as_millis())
 			.unwrap_or(0).to_string(),
@@ -750,7 +839,7 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 	on_demand: Option<Arc<OnDemand<TBl>>>,
 	remote_blockchain: Option<Arc<dyn RemoteBlockchain<TBl>>>,
 	rpc_extensions_builder: &(dyn RpcExtensionBuilder<Output = TRpc> + Send),
-	offchain_storage: Option<<TBackend as sc_client_api::backend::Backend<TBl>>::OffchainStorage>,
+	backend: Arc<TBackend>,
 	system_rpc_tx: TracingUnboundedSender<sc_rpc::system::Request<TBl>>
 ) -> sc_rpc_server::RpcHandler<sc_rpc::Metadata>
 	where
@@ -766,7 +855,7 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 			sp_session::SessionKeys<TBl> +
 			sp_api::Metadata<TBl>,
 {
-	use sc_rpc::{chain, state, author, system, offchain};
+	use sc_rpc::{chain, state, author, system, offchain, database};
 
 	let system_info = sc_rpc::system::SystemInfo {
 		chain_name: config.chain_spec.name().into(),
@@ -817,10 +906,11 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 	);
 	let system = system::System::new(system_info, system_rpc_tx, deny_unsafe);
 
-	let maybe_offchain_rpc = offchain_storage.map(|storage| {
+	let maybe_offchain_rpc = backend.offchain_storage().map(|storage| {
 		let offchain = sc_rpc::offchain::Offchain::new(storage, deny_unsafe);
 		offchain::OffchainApi::to_delegate(offchain)
 	});
+	let database = database::Database::new(backend, deny_unsafe);
 
 	sc_rpc_server::rpc_handler(
 		(
@@ -828,6 +918,7 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 			state::ChildStateApi::to_delegate(child_state),
 			chain::ChainApi::to_delegate(chain),
 			maybe_offchain_rpc,
+			database::DatabaseApi::to_delegate(database),
 			author::AuthorApi::to_delegate(author),
 			system::SystemApi::to_delegate(system),
 			rpc_extensions_builder.build(deny_unsafe, task_executor),
@@ -906,7 +997,7 @@ pub fn build_network<TBl, TExPool, TImpQu, TCl>(
 				config.network.default_peers_set.in_peers as usize
 					+ config.network.default_peers_set.out_peers as usize,
 			);
-			spawn_handle.spawn("block_request_handler", handler.run());
+			spawn_handle.spawn_in_group("block_request_handler", NETWORK_GROUP_NAME, handler.run());
 			protocol_config
 		}
 	};
@@ -921,7 +1012,11 @@ pub fn build_network<TBl, TExPool, TImpQu, TCl>(
 				&protocol_id,
 				client.clone(),
 			);
-			spawn_handle.spawn("light_client_request_handler", handler.run());
+			spawn_handle.spawn_in_group(
+				"light_client_request_handler",
+				NETWORK_GROUP_NAME,
+				handler.run(),
+			);
 			protocol_config
 		}
 	};
@@ -931,13 +1026,13 @@ pub fn build_network<TBl, TExPool, TImpQu, TCl>(
 		executor: {
 			let spawn_handle = Clone::clone(&spawn_handle);
 			Some(Box::new(move |fut| {
-				spawn_handle.spawn("libp2p-node", fut);
+				spawn_handle.spawn_in_group("libp2p-node", NETWORK_GROUP_NAME, fut);
 			}))
 		},
 		transactions_handler_executor: {
 			let spawn_handle = Clone::clone(&spawn_handle);
 			Box::new(move |fut| {
-				spawn_handle.spawn("network-transactions-handler", fut);
+				spawn_handle.spawn_in_group("network-transactions-handler", NETWORK_GROUP_NAME, fut);
 			})
 		},
 		network_config: config.network.clone(),
@@ -990,7 +1085,7 @@ pub fn build_network<TBl, TExPool, TImpQu, TCl>(
 	// issue, and ideally we would like to fix the network future to take as little time as
 	// possible, but we also take the extra harm-prevention measure to execute the networking
 	// future using `spawn_blocking`.
-	spawn_handle.spawn_blocking("network-worker", async move {
+	spawn_handle.spawn_blocking_in_group("network-worker", NETWORK_GROUP_NAME, async move {
 		if network_start_rx.await.is_err() {
 			debug_assert!(false);
 			log::warn!(
@@ -62,6 +62,11 @@ impl<B: BlockT> BlockRules<B> {
 		self.bad.insert(hash);
 	}
 
+	/// Check if the given block is known to be bad.
+	pub fn is_bad(&self, hash: &B::Hash) -> bool {
+		self.bad.contains(hash)
+	}
+
 	/// Check if there's any rule affecting the given block.
 	pub fn lookup(&self, number: NumberFor<B>, hash: &B::Hash) -> LookupResult<B> {
 		if let Some(hash_for_height) = self.forks.get(&number) {
@@ -81,7 +81,7 @@ use sc_client_api::{
 	client::{
 		ImportNotifications, FinalityNotification, FinalityNotifications, BlockImportNotification,
 		ClientInfo, BlockchainEvents, BlockBackend, ProvideUncles, BadBlocks, ForkBlocks,
-		BlockOf,
+		BlockOf, RuntimeUpgradeNotification, RuntimeUpgradeNotifications,
 	},
 	execution_extensions::ExecutionExtensions,
 	notifications::{StorageNotifications, StorageEventStream},
@@ -114,6 +114,7 @@ pub struct Client<B, E, Block, RA> where Block: BlockT {
 	storage_notifications: Mutex<StorageNotifications<Block>>,
 	import_notification_sinks: NotificationSinks<BlockImportNotification<Block>>,
 	finality_notification_sinks: NotificationSinks<FinalityNotification<Block>>,
+	runtime_upgrade_notification_sinks: NotificationSinks<RuntimeUpgradeNotification<Block>>,
 	// holds the block hash currently being imported. TODO: replace this with block queue
 	importing_block: RwLock<Option<Block::Hash>>,
 	block_rules: BlockRules<Block>,
@@ -350,6 +351,7 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 			storage_notifications: Mutex::new(StorageNotifications::new(prometheus_registry)),
 			import_notification_sinks: Default::default(),
 			finality_notification_sinks: Default::default(),
+			runtime_upgrade_notification_sinks: Default::default(),
 			importing_block: Default::default(),
 			block_rules: BlockRules::new(fork_blocks, bad_blocks),
 			execution_extensions,
@@ -1089,9 +1091,60 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 		self.import_notification_sinks.lock()
 			.retain(|sink| sink.unbounded_send(notification.clone()).is_ok());
 
+		if notification.is_new_best {
+			self.notify_runtime_upgrade(&notification);
+		}
+
 		Ok(())
 	}
 
+	/// Notify the runtime upgrade sinks if the code of the new best block differs from the code of
+	/// the previous best block.
+	fn notify_runtime_upgrade(&self, notification: &BlockImportNotification<Block>) {
+		let mut sinks = self.runtime_upgrade_notification_sinks.lock();
+		sinks.retain(|sink| !sink.is_closed());
+		if sinks.is_empty() {
+			return
+		}
+
+		let previous_best = match notification.tree_route {
+			Some(ref tree_route) => tree_route.retracted().first()
+				.unwrap_or_else(|| tree_route.common_block())
+				.hash,
+			None => *notification.header.parent_hash(),
+		};
+		let code_key = StorageKey(well_known_keys::CODE.to_vec());
+		let code_hash = |hash| StorageProvider::storage_hash(self, &BlockId::Hash(hash), &code_key);
+
+		let upgraded = code_hash(previous_best)
+			.and_then(|previous| code_hash(notification.hash).map(|new| previous != new));
+		match upgraded {
+			Ok(true) => (),
+			Ok(false) => return,
+			Err(e) => {
+				warn!("Failed to compare the runtime of block {}: {:?}", notification.hash, e);
+				return
+			},
+		}
+
+		let version = match self.runtime_version_at(&BlockId::Hash(notification.hash)) {
+			Ok(version) => version,
+			Err(e) => {
+				warn!("Failed to get the new runtime version at {}: {:?}", notification.hash, e);
+				return
+			},
+		};
+		info!(
+			"⬆️  Runtime upgraded to {}-{} at block {}",
+			version.spec_name,
+			version.spec_version,
+			notification.hash,
+		);
+
+		let notification = RuntimeUpgradeNotification { hash: notification.hash, version };
+		sinks.retain(|sink| sink.unbounded_send(notification.clone()).is_ok());
+	}
+
 	/// Attempts to revert the chain by `n` blocks guaranteeing that no block is
 	/// reverted past the last finalized block. Returns the number of blocks
 	/// that were successfully reverted.
@@ -1132,6 +1185,9 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 	pub fn block_status(&self, id: &BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
 		// this can probably be implemented more efficiently
 		if let BlockId::Hash(ref h) = id {
+			if self.block_rules.is_bad(h) {
+				return Ok(BlockStatus::KnownBad);
+			}
 			if self.importing_block.read().as_ref().map_or(false, |importing| h == importing) {
 				return Ok(BlockStatus::Queued);
 			}
@@ -1932,6 +1988,12 @@ where
 		stream
 	}
 
+	fn runtime_upgrade_notification_stream(&self) -> RuntimeUpgradeNotifications<Block> {
+		let (sink, stream) = tracing_unbounded("mpsc_runtime_upgrade_notification_stream");
+		self.runtime_upgrade_notification_sinks.lock().push(sink);
+		stream
+	}
+
 	/// Get storage changes event stream.
 	fn storage_changes_notification_stream(
 		&self,
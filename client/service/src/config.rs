@@ -29,7 +29,9 @@ pub use sc_network::config::{
 	RequestResponseConfig, IncomingRequest, OutgoingResponse,
 };
 pub use sc_executor::WasmExecutionMethod;
-pub use sc_client_api::execution_extensions::{ExecutionStrategies, ExecutionStrategy};
+pub use sc_client_api::execution_extensions::{
+	ExecutionStrategies, ExecutionStrategy, ExtensionsRegistry,
+};
 
 use std::{io, future::Future, path::{PathBuf, Path}, pin::Pin, net::SocketAddr, sync::Arc};
 pub use sc_transaction_pool::txpool::Options as TransactionPoolOptions;
@@ -65,6 +67,8 @@ pub struct Configuration {
 	pub state_cache_size: usize,
 	/// Size in percent of cache size dedicated to child tries
 	pub state_cache_child_ratio: Option<usize>,
+	/// Maximum size of the shared cache of trie nodes in Bytes, `None` disables it.
+	pub trie_cache_maximum_size: Option<usize>,
 	/// State pruning settings.
 	pub state_pruning: PruningMode,
 	/// Number of blocks to keep in the db.
@@ -173,6 +177,8 @@ pub struct OffchainWorkerConfig {
 	pub enabled: bool,
 	/// allow writes from the runtime to the offchain worker database.
 	pub indexing_enabled: bool,
+	/// The capabilities granted to the runtime calls of each execution context.
+	pub extensions_registry: ExtensionsRegistry,
 }
 
 /// Configuration of the Prometheus endpoint.
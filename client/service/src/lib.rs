@@ -51,7 +51,8 @@ use sp_utils::mpsc::TracingUnboundedReceiver;
 pub use self::error::Error;
 pub use self::builder::{
 	new_full_client, new_db_backend, new_client, new_full_parts, new_light_parts,
-	spawn_tasks, build_network, build_offchain_workers,
+	new_task_manager, new_native_executor, new_full_backend, new_full_client_with_backend,
+	new_full_transaction_pool, spawn_tasks, build_network, build_offchain_workers,
 	BuildNetworkParams, KeystoreContainer, NetworkStarter, SpawnTasksParams, TFullClient, TLightClient,
 	TFullBackend, TLightBackend, TLightBackendWithHash, TLightClientWithBackend,
 	TFullCallExecutor, TLightCallExecutor, RpcExtensionBuilder, NoopRpcExtensionBuilder,
@@ -60,6 +61,7 @@ pub use config::{
 	BasePath, Configuration, DatabaseConfig, PruningMode, Role, RpcMethods, TaskExecutor, TaskType,
 	KeepBlocks, TransactionStorageMode,
 };
+pub use sc_client_db::backup_database;
 pub use sc_chain_spec::{
 	ChainSpec, GenericChainSpec, Properties, RuntimeGenesis, Extension as ChainSpecExtension,
 	NoExtension, ChainType,
@@ -78,6 +80,7 @@ pub use sc_network::config::{
 pub use sc_tracing::TracingReceiver;
 pub use task_manager::SpawnTaskHandle;
 pub use task_manager::TaskManager;
+pub use task_manager::{DEFAULT_GROUP_NAME, DEFAULT_SHUTDOWN_TIMEOUT, NETWORK_GROUP_NAME};
 pub use sp_consensus::import_queue::ImportQueue;
 pub use self::client::{LocalCallExecutor, ClientConfig};
 use sc_client_api::{blockchain::HeaderBackend, BlockchainEvents};
@@ -143,6 +146,78 @@ pub struct PartialComponents<Client, Backend, SelectChain, ImportQueue, Transact
 	pub other: Other,
 }
 
+impl<Client, Backend, SelectChain, ImportQueue, TransactionPool, Other>
+	PartialComponents<Client, Backend, SelectChain, ImportQueue, TransactionPool, Other>
+{
+	/// Replace the chain selection algorithm.
+	pub fn with_select_chain<S>(
+		self,
+		select_chain: S,
+	) -> PartialComponents<Client, Backend, S, ImportQueue, TransactionPool, Other> {
+		PartialComponents {
+			client: self.client,
+			backend: self.backend,
+			task_manager: self.task_manager,
+			keystore_container: self.keystore_container,
+			select_chain,
+			import_queue: self.import_queue,
+			transaction_pool: self.transaction_pool,
+			other: self.other,
+		}
+	}
+
+	/// Replace the import queue.
+	pub fn with_import_queue<Q>(
+		self,
+		import_queue: Q,
+	) -> PartialComponents<Client, Backend, SelectChain, Q, TransactionPool, Other> {
+		PartialComponents {
+			client: self.client,
+			backend: self.backend,
+			task_manager: self.task_manager,
+			keystore_container: self.keystore_container,
+			select_chain: self.select_chain,
+			import_queue,
+			transaction_pool: self.transaction_pool,
+			other: self.other,
+		}
+	}
+
+	/// Replace the transaction pool.
+	pub fn with_transaction_pool<P>(
+		self,
+		transaction_pool: Arc<P>,
+	) -> PartialComponents<Client, Backend, SelectChain, ImportQueue, P, Other> {
+		PartialComponents {
+			client: self.client,
+			backend: self.backend,
+			task_manager: self.task_manager,
+			keystore_container: self.keystore_container,
+			select_chain: self.select_chain,
+			import_queue: self.import_queue,
+			transaction_pool,
+			other: self.other,
+		}
+	}
+
+	/// Replace the other components.
+	pub fn with_other<O>(
+		self,
+		other: O,
+	) -> PartialComponents<Client, Backend, SelectChain, ImportQueue, TransactionPool, O> {
+		PartialComponents {
+			client: self.client,
+			backend: self.backend,
+			task_manager: self.task_manager,
+			keystore_container: self.keystore_container,
+			select_chain: self.select_chain,
+			import_queue: self.import_queue,
+			transaction_pool: self.transaction_pool,
+			other,
+		}
+	}
+}
+
 /// Builds a never-ending future that continuously polls the network.
 ///
 /// The `status_sink` contain a list of senders to send a periodic network status to.
@@ -270,11 +345,30 @@ async fn build_network_future<
 
 						let _ = sender.send(reserved_peers);
 					}
+					sc_rpc::system::Request::NetworkReservedPeersState(sender) => {
+						use sc_rpc::system::{ReservedPeer, ReservedPeersSet};
+
+						let sets = network.reserved_peers_state()
+							.map(|(protocol, reserved_only, peers)| ReservedPeersSet {
+								protocol: protocol.to_string(),
+								reserved_only,
+								peers: peers.into_iter()
+									.map(|(peer_id, is_connected)| ReservedPeer {
+										peer_id: peer_id.to_base58(),
+										is_connected,
+									})
+									.collect(),
+							})
+							.collect();
+
+						let _ = sender.send(sets);
+					}
 					sc_rpc::system::Request::NodeRoles(sender) => {
 						use sc_rpc::system::NodeRole;
 
 						let node_role = match role {
 							Role::Authority { .. } => NodeRole::Authority,
+							Role::Sentry { .. } => NodeRole::Sentry,
 							Role::Light => NodeRole::LightClient,
 							Role::Full => NodeRole::Full,
 						};
@@ -41,7 +41,10 @@ struct PrometheusMetrics {
 	// I/O
 	database_cache: Gauge<U64>,
 	state_cache: Gauge<U64>,
+	trie_cache: Gauge<U64>,
+	trie_cache_reads: GaugeVec<U64>,
 	state_db: GaugeVec<U64>,
+	last_block_usage: GaugeVec<U64>,
 }
 
 impl PrometheusMetrics {
@@ -95,10 +98,27 @@ impl PrometheusMetrics {
 			state_cache: register(Gauge::new(
 				"state_cache_bytes", "State cache size in bytes",
 			)?, registry)?,
+			trie_cache: register(Gauge::new(
+				"trie_cache_bytes", "Trie node cache size in bytes",
+			)?, registry)?,
+			trie_cache_reads: register(GaugeVec::new(
+				Opts::new(
+					"trie_cache_reads",
+					"Trie node reads since the previous update, by whether they hit the cache",
+				),
+				&["result"]
+			)?, registry)?,
 			state_db: register(GaugeVec::new(
 				Opts::new("state_db_cache_bytes", "State DB cache in bytes"),
 				&["subtype"]
 			)?, registry)?,
+			last_block_usage: register(GaugeVec::new(
+				Opts::new(
+					"last_block_state_usage",
+					"State accesses between the import of the last block and of its parent",
+				),
+				&["stat"]
+			)?, registry)?,
 		})
 	}
 }
@@ -137,6 +157,7 @@ impl MetricsService {
 		let role_bits = match config.role {
 			Role::Full => 1u64,
 			Role::Light => 2u64,
+			Role::Sentry { .. } => 3u64,
 			Role::Authority { .. } => 4u64,
 		};
 
@@ -238,6 +259,13 @@ impl MetricsService {
 			if let Some(info) = info.usage.as_ref() {
 				metrics.database_cache.set(info.memory.database_cache.as_bytes() as u64);
 				metrics.state_cache.set(info.memory.state_cache.as_bytes() as u64);
+				metrics.trie_cache.set(info.memory.trie_cache.as_bytes() as u64);
+				metrics.trie_cache_reads
+					.with_label_values(&["hit"])
+					.set(info.io.trie_nodes_reads_cache);
+				metrics.trie_cache_reads
+					.with_label_values(&["miss"])
+					.set(info.io.trie_nodes_reads.saturating_sub(info.io.trie_nodes_reads_cache));
 
 				metrics.state_db.with_label_values(&["non_canonical"]).set(
 					info.memory.state_db.non_canonical.as_bytes() as u64,
@@ -248,6 +276,22 @@ impl MetricsService {
 				metrics.state_db.with_label_values(&["pinned"]).set(
 					info.memory.state_db.pinned.as_bytes() as u64,
 				);
+
+				if let Some(block) = info.last_block.as_ref() {
+					let stats = [
+						("state_reads", block.state_reads),
+						("state_reads_cache", block.state_reads_cache),
+						("trie_nodes_reads", block.trie_nodes_reads),
+						("trie_nodes_reads_cache", block.trie_nodes_reads_cache),
+						("database_reads", block.database_reads),
+						("database_bytes_read", block.database_bytes_read),
+						("database_writes", block.database_writes),
+						("database_bytes_written", block.database_bytes_written),
+					];
+					for (stat, value) in stats.iter() {
+						metrics.last_block_usage.with_label_values(&[stat]).set(*value);
+					}
+				}
 			}
 		}
 
@@ -18,9 +18,11 @@
 
 //! Substrate service tasks management module.
 
-use std::{panic, result::Result, pin::Pin};
+use std::{collections::HashMap, panic, result::Result, pin::Pin, sync::Arc, time::Duration};
 use exit_future::Signal;
-use log::{debug, error};
+use log::{debug, error, warn};
+use parking_lot::Mutex;
+use futures_timer::Delay;
 use futures::{
 	Future, FutureExt, StreamExt,
 	future::{select, Either, BoxFuture, join_all, try_join_all, pending},
@@ -39,6 +41,36 @@ mod prometheus_future;
 #[cfg(test)]
 mod tests;
 
+/// The group of the tasks which are spawned without a group.
+pub const DEFAULT_GROUP_NAME: &str = "default";
+
+/// The group of the networking tasks, which the node shuts down before the other tasks.
+pub const NETWORK_GROUP_NAME: &str = "network";
+
+/// The default time given to each step of [`TaskManager::clean_shutdown`].
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The part of a group of the shutdown order shared with the spawn handles.
+#[derive(Clone)]
+struct GroupExit {
+	/// Resolves when the tasks of the group must end.
+	on_exit: exit_future::Exit,
+	/// Every task of the group holds a clone of this sender until it is dropped.
+	alive: TracingUnboundedSender<()>,
+}
+
+/// The groups of the shutdown order, by name.
+type OrderedGroups = Arc<Mutex<HashMap<&'static str, GroupExit>>>;
+
+/// A group of the shutdown order, see [`TaskManager::set_shutdown_order`].
+struct OrderedGroup {
+	name: &'static str,
+	/// Makes the `on_exit` of the group resolve.
+	signal: Signal,
+	/// Ends when the senders of the group, held by its tasks, are all dropped.
+	ended: TracingUnboundedReceiver<()>,
+}
+
 /// An handle for spawning tasks in the service.
 #[derive(Clone)]
 pub struct SpawnTaskHandle {
@@ -46,6 +78,7 @@ pub struct SpawnTaskHandle {
 	executor: TaskExecutor,
 	metrics: Option<Metrics>,
 	task_notifier: TracingUnboundedSender<JoinFuture>,
+	groups: OrderedGroups,
 }
 
 impl SpawnTaskHandle {
@@ -58,18 +91,43 @@ impl SpawnTaskHandle {
 	/// In other words, it would be a bad idea for someone to do for example
 	/// `spawn(format!("{:?}", some_public_key))`.
 	pub fn spawn(&self, name: &'static str, task: impl Future<Output = ()> + Send + 'static) {
-		self.spawn_inner(name, task, TaskType::Async)
+		self.spawn_inner(name, DEFAULT_GROUP_NAME, task, TaskType::Async)
 	}
 
 	/// Spawns the blocking task with the given name. See also `spawn`.
 	pub fn spawn_blocking(&self, name: &'static str, task: impl Future<Output = ()> + Send + 'static) {
-		self.spawn_inner(name, task, TaskType::Blocking)
+		self.spawn_inner(name, DEFAULT_GROUP_NAME, task, TaskType::Blocking)
+	}
+
+	/// Spawns the given task with the given name in the given group.
+	///
+	/// The tasks of the groups of [`TaskManager::set_shutdown_order`] are shut down group by
+	/// group, the tasks of the other groups are interrupted by [`TaskManager::terminate`].
+	pub fn spawn_in_group(
+		&self,
+		name: &'static str,
+		group: &'static str,
+		task: impl Future<Output = ()> + Send + 'static,
+	) {
+		self.spawn_inner(name, group, task, TaskType::Async)
+	}
+
+	/// Spawns the blocking task with the given name in the given group. See also
+	/// `spawn_in_group`.
+	pub fn spawn_blocking_in_group(
+		&self,
+		name: &'static str,
+		group: &'static str,
+		task: impl Future<Output = ()> + Send + 'static,
+	) {
+		self.spawn_inner(name, group, task, TaskType::Blocking)
 	}
 
 	/// Helper function that implements the spawning logic. See `spawn` and `spawn_blocking`.
 	fn spawn_inner(
 		&self,
 		name: &'static str,
+		group: &'static str,
 		task: impl Future<Output = ()> + Send + 'static,
 		task_type: TaskType,
 	) {
@@ -78,7 +136,10 @@ impl SpawnTaskHandle {
 			return;
 		}
 
-		let on_exit = self.on_exit.clone();
+		let (on_exit, alive) = match self.groups.lock().get(group) {
+			Some(group) => (group.on_exit.clone(), Some(group.alive.clone())),
+			None => (self.on_exit.clone(), None),
+		};
 		let metrics = self.metrics.clone();
 
 		// Note that we increase the started counter here and not within the future. This way,
@@ -90,6 +151,9 @@ impl SpawnTaskHandle {
 		}
 
 		let future = async move {
+			// Dropped with the task, which lets the shutdown know that it ended.
+			let _alive = alive;
+
 			if let Some(metrics) = metrics {
 				// Add some wrappers around `task`.
 				let task = {
@@ -149,17 +213,17 @@ impl sp_core::traits::SpawnNamed for SpawnTaskHandle {
 /// A wrapper over `SpawnTaskHandle` that will notify a receiver whenever any
 /// task spawned through it fails. The service should be on the receiver side
 /// and will shut itself down whenever it receives any message, i.e. an
-/// essential task has failed.
+/// essential task has failed. The message is the reason of the failure.
 #[derive(Clone)]
 pub struct SpawnEssentialTaskHandle {
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<String>,
 	inner: SpawnTaskHandle,
 }
 
 impl SpawnEssentialTaskHandle {
 	/// Creates a new `SpawnEssentialTaskHandle`.
 	pub fn new(
-		essential_failed_tx: TracingUnboundedSender<()>,
+		essential_failed_tx: TracingUnboundedSender<String>,
 		spawn_task_handle: SpawnTaskHandle,
 	) -> SpawnEssentialTaskHandle {
 		SpawnEssentialTaskHandle {
@@ -172,7 +236,7 @@ impl SpawnEssentialTaskHandle {
 	///
 	/// See also [`SpawnTaskHandle::spawn`].
 	pub fn spawn(&self, name: &'static str, task: impl Future<Output = ()> + Send + 'static) {
-		self.spawn_inner(name, task, TaskType::Async)
+		self.spawn_inner(name, DEFAULT_GROUP_NAME, task, TaskType::Async)
 	}
 
 	/// Spawns the blocking task with the given name.
@@ -183,24 +247,53 @@ impl SpawnEssentialTaskHandle {
 		name: &'static str,
 		task: impl Future<Output = ()> + Send + 'static,
 	) {
-		self.spawn_inner(name, task, TaskType::Blocking)
+		self.spawn_inner(name, DEFAULT_GROUP_NAME, task, TaskType::Blocking)
+	}
+
+	/// Spawns the given task with the given name in the given group.
+	///
+	/// See also [`SpawnTaskHandle::spawn_in_group`].
+	pub fn spawn_in_group(
+		&self,
+		name: &'static str,
+		group: &'static str,
+		task: impl Future<Output = ()> + Send + 'static,
+	) {
+		self.spawn_inner(name, group, task, TaskType::Async)
+	}
+
+	/// Spawns the blocking task with the given name in the given group.
+	///
+	/// See also [`SpawnTaskHandle::spawn_blocking_in_group`].
+	pub fn spawn_blocking_in_group(
+		&self,
+		name: &'static str,
+		group: &'static str,
+		task: impl Future<Output = ()> + Send + 'static,
+	) {
+		self.spawn_inner(name, group, task, TaskType::Blocking)
 	}
 
 	fn spawn_inner(
 		&self,
 		name: &'static str,
+		group: &'static str,
 		task: impl Future<Output = ()> + Send + 'static,
 		task_type: TaskType,
 	) {
 		let essential_failed = self.essential_failed_tx.clone();
 		let essential_task = std::panic::AssertUnwindSafe(task)
 			.catch_unwind()
-			.map(move |_| {
-				log::error!("Essential task `{}` failed. Shutting down service.", name);
-				let _ = essential_failed.close_channel();
+			.map(move |result| {
+				let reason = match result {
+					Ok(()) => format!("Essential task `{}` ended.", name),
+					Err(_) => format!("Essential task `{}` panicked.", name),
+				};
+				log::error!("{} Shutting down service.", reason);
+				let _ = essential_failed.unbounded_send(reason);
 			});
 
-		let _ = self.inner.spawn_inner(name, essential_task, task_type);
+		let _ = self.inner.spawn_inner(name, group, essential_task, task_type);
 	}
 }
 
@@ -225,11 +318,11 @@ pub struct TaskManager {
 	executor: TaskExecutor,
 	/// Prometheus metric where to report the polling times.
 	metrics: Option<Metrics>,
-	/// Send a signal when a spawned essential task has concluded. The next time
+	/// Send the reason when a spawned essential task has concluded. The next time
 	/// the service future is polled it should complete with an error.
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<String>,
 	/// A receiver for spawned essential-tasks concluding.
-	essential_failed_rx: TracingUnboundedReceiver<()>,
+	essential_failed_rx: TracingUnboundedReceiver<String>,
 	/// Things to keep alive until the task manager is dropped.
 	keep_alive: Box<dyn std::any::Any + Send + Sync>,
 	/// A sender to a stream of background tasks. This is used for the completion future.
@@ -240,6 +333,12 @@ pub struct TaskManager {
 	/// terminates and gracefully shutdown. Also ends the parent `future()` if a child's essential
 	/// task fails.
 	children: Vec<TaskManager>,
+	/// The groups of the shutdown order, shared with the spawn handles.
+	groups: OrderedGroups,
+	/// The groups which are shut down one after the other by `clean_shutdown`.
+	shutdown_order: Vec<OrderedGroup>,
+	/// The time given to each step of `clean_shutdown`.
+	shutdown_timeout: Duration,
 }
 
 impl TaskManager {
@@ -276,6 +375,9 @@ impl TaskManager {
 			task_notifier,
 			completion_future,
 			children: Vec::new(),
+			groups: Default::default(),
+			shutdown_order: Vec::new(),
+			shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
 		})
 	}
 
@@ -286,6 +388,7 @@ impl TaskManager {
 			executor: self.executor.clone(),
 			metrics: self.metrics.clone(),
 			task_notifier: self.task_notifier.clone(),
+			groups: self.groups.clone(),
 		}
 	}
 
@@ -303,15 +406,35 @@ impl TaskManager {
 	///
 	/// This is an issue in some cases as some of our dependencies do require that we drop all the
 	/// objects properly otherwise it triggers a SIGABRT on exit.
+	///
+	/// After the children, the groups of [`TaskManager::set_shutdown_order`] are shut down one
+	/// after the other, then all the remaining tasks are awaited. Each of these steps is given at
+	/// most the shutdown timeout, after which the shutdown moves on.
 	pub fn clean_shutdown(mut self) -> Pin<Box<dyn Future<Output = ()> + Send>> {
 		self.terminate();
 		let children_shutdowns = self.children.into_iter().map(|x| x.clean_shutdown());
 		let keep_alive = self.keep_alive;
 		let completion_future = self.completion_future;
+		let groups = self.groups;
+		let shutdown_order = self.shutdown_order;
+		let timeout = self.shutdown_timeout;
 
 		Box::pin(async move {
 			join_all(children_shutdowns).await;
-			completion_future.await;
+
+			for group in shutdown_order {
+				// Drop the sender of the group, only its tasks hold it now.
+				groups.lock().remove(&group.name);
+				let _ = group.signal.fire();
+				let mut ended = group.ended;
+				if !with_timeout(ended.next().map(drop), timeout).await {
+					warn!("Tasks of the group `{}` did not end within {:?}", group.name, timeout);
+				}
+			}
+
+			if !with_timeout(completion_future, timeout).await {
+				warn!("Tasks did not end within {:?}", timeout);
+			}
 			drop(keep_alive);
 		})
 	}
@@ -335,14 +458,17 @@ impl TaskManager {
 			).fuse();
 
 			futures::select! {
-				_ = t1 => Err(Error::Other("Essential task failed.".into())),
+				reason = t1 => Err(Error::Other(
+					reason.unwrap_or_else(|| "Essential task failed.".into())
+				)),
 				_ = t2 => Ok(()),
 				res = t3 => Err(res.map(|_| ()).expect_err("this future never ends; qed")),
 			}
 		})
 	}
 
-	/// Signal to terminate all the running tasks.
+	/// Signal to terminate all the running tasks, except the ones of the groups of the shutdown
+	/// order which are only shut down by `clean_shutdown`.
 	pub fn terminate(&mut self) {
 		if let Some(signal) = self.signal.take() {
 			let _ = signal.fire();
@@ -368,6 +494,41 @@ impl TaskManager {
 	pub fn add_child(&mut self, child: TaskManager) {
 		self.children.push(child);
 	}
+
+	/// Set groups of tasks which are shut down one after the other, in the given order, by
+	/// `clean_shutdown`, e.g. to stop the networking before the tasks which use the database.
+	///
+	/// Their tasks keep running after `terminate`. Only the tasks spawned after this call are
+	/// affected. Can be called multiple times, the groups are appended to the order. The tasks
+	/// spawned without a group are ordered with [`DEFAULT_GROUP_NAME`].
+	pub fn set_shutdown_order(&mut self, groups: &[&'static str]) {
+		let mut shared = self.groups.lock();
+		for &name in groups {
+			if shared.contains_key(name) {
+				continue
+			}
+
+			let (signal, on_exit) = exit_future::signal();
+			let (alive, ended) = tracing_unbounded("mpsc_task_group");
+			shared.insert(name, GroupExit { on_exit, alive });
+			self.shutdown_order.push(OrderedGroup { name, signal, ended });
+		}
+	}
+
+	/// Set the time given to each step of `clean_shutdown`, [`DEFAULT_SHUTDOWN_TIMEOUT`] by
+	/// default.
+	pub fn set_shutdown_timeout(&mut self, timeout: Duration) {
+		self.shutdown_timeout = timeout;
+	}
+}
+
+/// Await `future` for at most `timeout`, returns whether it completed.
+async fn with_timeout(future: impl Future<Output = ()>, timeout: Duration) -> bool {
+	futures::pin_mut!(future);
+	match select(future, Delay::new(timeout)).await {
+		Either::Left(((), _)) => true,
+		Either::Right(((), _)) => false,
+	}
 }
 
 #[derive(Clone)]
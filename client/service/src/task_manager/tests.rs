@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::config::TaskExecutor;
-use crate::task_manager::TaskManager;
+use crate::task_manager::{TaskManager, DEFAULT_GROUP_NAME, NETWORK_GROUP_NAME};
 use futures::{future::FutureExt, pin_mut, select};
 use parking_lot::Mutex;
 use std::{any::Any, sync::Arc, time::Duration};
@@ -310,3 +310,110 @@ fn ensure_task_manager_future_continues_when_childs_not_essential_task_fails() {
 	runtime.block_on(task_manager.clean_shutdown());
 	assert_eq!(drop_tester, 0);
 }
+
+#[test]
+fn ensure_groups_are_shut_down_in_order() {
+	#[derive(Clone)]
+	struct DropRecorder(Arc<Mutex<Vec<&'static str>>>);
+
+	struct DropRecorderRef(DropRecorder, &'static str);
+
+	impl Drop for DropRecorderRef {
+		fn drop(&mut self) {
+			(self.0).0.lock().push(self.1);
+		}
+	}
+
+	let mut runtime = tokio::runtime::Runtime::new().unwrap();
+	let handle = runtime.handle().clone();
+	let task_executor: TaskExecutor = (move |future, _| handle.spawn(future).map(|_| ())).into();
+
+	let mut task_manager = new_task_manager(task_executor);
+	task_manager.set_shutdown_order(&["second", "first"]);
+	task_manager.set_shutdown_order(&["first", "third"]);
+	let spawn_handle = task_manager.spawn_handle();
+	let recorder = DropRecorder(Default::default());
+	spawn_handle.spawn_in_group(
+		"task1",
+		"third",
+		run_background_task(DropRecorderRef(recorder.clone(), "third")),
+	);
+	spawn_handle.spawn_in_group(
+		"task2",
+		"first",
+		run_background_task(DropRecorderRef(recorder.clone(), "first")),
+	);
+	spawn_handle.spawn_in_group(
+		"task3",
+		"second",
+		run_background_task(DropRecorderRef(recorder.clone(), "second")),
+	);
+	spawn_handle.spawn_in_group(
+		"task4",
+		"other",
+		run_background_task(DropRecorderRef(recorder.clone(), "other")),
+	);
+	// allow the tasks to even start
+	runtime.block_on(async { tokio::time::delay_for(Duration::from_secs(1)).await });
+
+	// Only the tasks which are not in the shutdown order are interrupted.
+	task_manager.terminate();
+	runtime.block_on(async { tokio::time::delay_for(Duration::from_secs(1)).await });
+	assert_eq!(*recorder.0.lock(), vec!["other"]);
+
+	runtime.block_on(task_manager.clean_shutdown());
+	assert_eq!(*recorder.0.lock(), vec!["other", "second", "first", "third"]);
+}
+
+#[test]
+fn ensure_default_group_is_shut_down_after_the_ordered_groups() {
+	#[derive(Clone)]
+	struct DropRecorder(Arc<Mutex<Vec<&'static str>>>);
+
+	struct DropRecorderRef(DropRecorder, &'static str);
+
+	impl Drop for DropRecorderRef {
+		fn drop(&mut self) {
+			(self.0).0.lock().push(self.1);
+		}
+	}
+
+	let mut runtime = tokio::runtime::Runtime::new().unwrap();
+	let handle = runtime.handle().clone();
+	let task_executor: TaskExecutor = (move |future, _| handle.spawn(future).map(|_| ())).into();
+
+	let mut task_manager = new_task_manager(task_executor);
+	task_manager.set_shutdown_order(&[NETWORK_GROUP_NAME, DEFAULT_GROUP_NAME]);
+	let spawn_handle = task_manager.spawn_handle();
+	let recorder = DropRecorder(Default::default());
+	spawn_handle.spawn("task1", run_background_task(DropRecorderRef(recorder.clone(), "default")));
+	spawn_handle.spawn_in_group(
+		"task2",
+		NETWORK_GROUP_NAME,
+		run_background_task(DropRecorderRef(recorder.clone(), "network")),
+	);
+	// allow the tasks to even start
+	runtime.block_on(async { tokio::time::delay_for(Duration::from_secs(1)).await });
+
+	// Nothing is interrupted before the clean shutdown.
+	task_manager.terminate();
+	runtime.block_on(async { tokio::time::delay_for(Duration::from_secs(1)).await });
+	assert!(recorder.0.lock().is_empty());
+
+	runtime.block_on(task_manager.clean_shutdown());
+	assert_eq!(*recorder.0.lock(), vec!["network", "default"]);
+}
+
+#[test]
+fn ensure_task_manager_future_reports_the_failed_essential_task() {
+	let mut runtime = tokio::runtime::Runtime::new().unwrap();
+	let handle = runtime.handle().clone();
+	let task_executor: TaskExecutor = (move |future, _| handle.spawn(future).map(|_| ())).into();
+
+	let mut task_manager = new_task_manager(task_executor);
+	let spawn_essential_handle = task_manager.spawn_essential_handle();
+	spawn_essential_handle.spawn_in_group("task1", "group", async {});
+	let error = runtime.block_on(task_manager.future()).expect_err("future()'s Result must be Err");
+	assert_eq!(error.to_string(), "Other: Essential task `task1` ended.");
+	runtime.block_on(task_manager.clean_shutdown());
+}
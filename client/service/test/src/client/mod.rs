@@ -1281,6 +1281,7 @@ fn doesnt_import_blocks_that_revert_finality() {
 		DatabaseSettings {
 			state_cache_size: 1 << 20,
 			state_cache_child_ratio: None,
+			trie_cache_maximum_size: None,
 			state_pruning: PruningMode::ArchiveAll,
 			keep_blocks: KeepBlocks::All,
 			transaction_storage: TransactionStorageMode::BlockBody,
@@ -1485,6 +1486,7 @@ fn returns_status_for_pruned_blocks() {
 		DatabaseSettings {
 			state_cache_size: 1 << 20,
 			state_cache_child_ratio: None,
+			trie_cache_maximum_size: None,
 			state_pruning: PruningMode::keep_blocks(1),
 			keep_blocks: KeepBlocks::All,
 			transaction_storage: TransactionStorageMode::BlockBody,
@@ -251,6 +251,7 @@ fn node_config<G: RuntimeGenesis + 'static, E: ChainSpecExtension + Clone + 'sta
 		},
 		state_cache_size: 16777216,
 		state_cache_child_ratio: None,
+		trie_cache_maximum_size: None,
 		state_pruning: Default::default(),
 		keep_blocks: KeepBlocks::All,
 		transaction_storage: TransactionStorageMode::BlockBody,
@@ -336,7 +337,7 @@ impl<G, E, F, L, U> TestNet<G, E, F, L, U> where
 			let node_config = node_config(
 				self.nodes,
 				&self.chain_spec,
-				Role::Authority,
+				Role::Authority { sentry_nodes: Vec::new() },
 				task_executor.clone(),
 				Some(key),
 				self.base_port,
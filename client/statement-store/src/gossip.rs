@@ -0,0 +1,225 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! The gossip protocol through which the statements are exchanged with the other nodes.
+//!
+//! All the statements are gossiped under a single topic. The gossip validator only decodes the
+//! statements received from the peers: they are validated by the store, which calls the runtime,
+//! on a blocking task so that the gossip engine is never held up. The statements accepted by the
+//! store are then propagated to the peers, like the statements submitted to the store locally.
+//! Gossiped messages expire as soon as their statement leaves the store.
+
+use crate::{Statement, StatementSource, StatementStore, Store, SubmitResult, LOG_TARGET};
+use codec::{Decode, Encode};
+use futures::{channel::mpsc, future, prelude::*};
+use prometheus_endpoint::Registry;
+use sc_network::{PeerId, ReputationChange};
+use sc_network_gossip::{GossipEngine, Network, ValidationResult, Validator, ValidatorContext};
+use sp_core::traits::SpawnNamed;
+use sp_runtime::traits::{Block as BlockT, Hash as HashT, Header as HeaderT};
+use sp_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
+use std::{sync::Arc, task::Poll, time::Duration};
+
+/// The name of the statement gossip protocol.
+pub const STATEMENT_PROTOCOL_NAME: &str = "/paritytech/statement/1";
+
+/// How often the statements which have been in the store for too long are purged.
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The maximum number of statements received from the peers which wait for validation. The
+/// statements received while the queue is full are dropped.
+const MAX_PENDING_STATEMENTS: usize = 8192;
+
+/// The reputation change of a peer which sent a statement rejected by the store.
+const BAD_STATEMENT_COST: ReputationChange = ReputationChange::new(-(1 << 10), "Bad statement");
+
+/// Returns the configuration value to put in
+/// [`sc_network::config::NetworkConfiguration::extra_sets`].
+pub fn statement_peers_set_config() -> sc_network::config::NonDefaultSetConfig {
+	sc_network::config::NonDefaultSetConfig {
+		notifications_protocol: STATEMENT_PROTOCOL_NAME.into(),
+		fallback_names: Vec::new(),
+		max_notification_size: 1024 * 1024,
+		set_config: sc_network::config::SetConfig {
+			in_peers: 0,
+			out_peers: 0,
+			reserved_nodes: Vec::new(),
+			non_reserved_mode: sc_network::config::NonReservedPeerMode::Deny,
+		},
+	}
+}
+
+/// The gossip topic of all the statements.
+fn topic<B: BlockT>() -> B::Hash {
+	<<B::Header as HeaderT>::Hashing as HashT>::hash(b"statements")
+}
+
+/// Lets through the gossiped statements which are not in the store yet, to be validated by the
+/// store off the gossip engine.
+struct GossipValidator {
+	store: Arc<Store>,
+}
+
+impl<B: BlockT> Validator<B> for GossipValidator {
+	fn validate(
+		&self,
+		_context: &mut dyn ValidatorContext<B>,
+		sender: &PeerId,
+		mut data: &[u8],
+	) -> ValidationResult<B::Hash> {
+		match Statement::decode(&mut data) {
+			// The statement is only propagated once the store accepted it.
+			Ok(statement) => {
+				let hash = statement.hash();
+				if self.store.has(&hash) || self.store.is_expired(&hash) {
+					ValidationResult::Discard
+				} else {
+					ValidationResult::ProcessAndDiscard(topic::<B>())
+				}
+			},
+			Err(e) => {
+				log::debug!(target: LOG_TARGET, "Undecodable statement from {}: {:?}", sender, e);
+				ValidationResult::Discard
+			},
+		}
+	}
+
+	fn message_expired<'a>(&'a self) -> Box<dyn FnMut(B::Hash, &[u8]) -> bool + 'a> {
+		Box::new(move |_topic, mut data| match Statement::decode(&mut data) {
+			Ok(statement) => !self.store.has(&statement.hash()),
+			Err(_) => true,
+		})
+	}
+}
+
+/// The outcome of the validation of a statement received from a peer.
+enum Validated {
+	/// The statement was added to the store and is to be propagated.
+	New(Statement),
+	/// The statement was rejected by the store, the peer which sent it is to be reported.
+	Rejected(PeerId),
+}
+
+/// Submit the statements received from the peers to `store`, sending the outcomes to `results`.
+///
+/// This calls the runtime and is meant to be run as a blocking task.
+async fn validate_statements(
+	store: Arc<Store>,
+	mut pending: mpsc::Receiver<(Statement, Option<PeerId>)>,
+	results: TracingUnboundedSender<Validated>,
+) {
+	while let Some((statement, sender)) = pending.next().await {
+		let result = match store.submit(statement.clone(), StatementSource::Network) {
+			SubmitResult::New => Some(Validated::New(statement)),
+			SubmitResult::Known | SubmitResult::KnownExpired | SubmitResult::Ignored => None,
+			result => {
+				log::debug!(
+					target: LOG_TARGET,
+					"Statement from {:?} rejected: {:?}",
+					sender,
+					result,
+				);
+				sender.map(Validated::Rejected)
+			},
+		};
+		if let Some(result) = result {
+			if results.unbounded_send(result).is_err() {
+				return
+			}
+		}
+	}
+}
+
+/// Run the statement gossip protocol over `network`.
+///
+/// The statements received from the peers are validated on a blocking task spawned with
+/// `spawner`. The returned future only completes when the network service shuts down.
+pub fn run<B, N>(
+	network: N,
+	store: Arc<Store>,
+	spawner: impl SpawnNamed,
+	metrics_registry: Option<&Registry>,
+) -> impl Future<Output = ()> + Send + 'static
+where
+	B: BlockT,
+	N: Network<B> + Send + Clone + 'static,
+{
+	let validator = Arc::new(GossipValidator { store: store.clone() });
+	let mut engine =
+		GossipEngine::new(network, STATEMENT_PROTOCOL_NAME, validator, metrics_registry);
+	match store.statements() {
+		Ok(statements) => for (_, statement) in statements {
+			engine.register_gossip_message(topic::<B>(), statement.encode());
+		},
+		Err(e) => log::warn!(target: LOG_TARGET, "Error reading the statements: {}", e),
+	}
+
+	let (mut pending_sink, pending) = mpsc::channel(MAX_PENDING_STATEMENTS);
+	let (results_sink, mut results) = tracing_unbounded("mpsc_statement_validation_results");
+	spawner.spawn_blocking(
+		"statement-validation",
+		validate_statements(store.clone(), pending, results_sink).boxed(),
+	);
+
+	let mut received = engine.messages_for(topic::<B>());
+	let mut imported = store.import_notification_stream();
+	let mut maintenance = futures_timer::Delay::new(MAINTENANCE_INTERVAL);
+	future::poll_fn(move |cx| {
+		while let Poll::Ready(notification) = received.poll_next_unpin(cx) {
+			let notification = match notification {
+				Some(notification) => notification,
+				None => return Poll::Ready(()),
+			};
+			let statement = match Statement::decode(&mut &notification.message[..]) {
+				Ok(statement) => statement,
+				Err(_) => continue,
+			};
+			if pending_sink.try_send((statement, notification.sender)).is_err() {
+				log::debug!(target: LOG_TARGET, "Too many statements to validate, dropping one");
+			}
+		}
+
+		loop {
+			match results.poll_next_unpin(cx) {
+				Poll::Ready(Some(Validated::New(statement))) =>
+					engine.gossip_message(topic::<B>(), statement.encode(), false),
+				Poll::Ready(Some(Validated::Rejected(peer))) =>
+					engine.report(peer, BAD_STATEMENT_COST),
+				Poll::Ready(None) => return Poll::Ready(()),
+				Poll::Pending => break,
+			}
+		}
+
+		loop {
+			match imported.poll_next_unpin(cx) {
+				Poll::Ready(Some(statement)) =>
+					engine.gossip_message(topic::<B>(), statement.encode(), false),
+				Poll::Ready(None) => return Poll::Ready(()),
+				Poll::Pending => break,
+			}
+		}
+
+		while maintenance.poll_unpin(cx).is_ready() {
+			if let Err(e) = store.maintain() {
+				log::warn!(target: LOG_TARGET, "Error purging statements: {}", e);
+			}
+			maintenance.reset(MAINTENANCE_INTERVAL);
+		}
+
+		engine.poll_unpin(cx)
+	})
+}
@@ -0,0 +1,637 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+//! Substrate statement store.
+//!
+//! The [`Store`] keeps the signed statements known to the node in a database. Each statement is
+//! validated by the runtime through the [`ValidateStatement`] runtime API, which also sets how
+//! many statements, and how many bytes of them, each signer may keep in the store. When a signer
+//! runs out of room, its statements of lowest priority are evicted to make room for statements
+//! of higher priority. Statements are purged from the store [`Options::purge_after_sec`] seconds
+//! after they were added, by [`Store::maintain`].
+//!
+//! The statements which leave the store, because they are purged, evicted or removed, leave a
+//! tombstone behind for another [`Options::purge_after_sec`] seconds, so that the peers which
+//! still have them don't gossip them back into the store.
+//!
+//! The statements are exchanged with the other nodes by the gossip protocol driven by
+//! [`gossip::run`].
+
+pub mod gossip;
+
+use codec::{Decode, Encode};
+use kvdb::{DBTransaction, KeyValueDB};
+use parking_lot::{Mutex, RwLock};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_statement_store::{
+	AccountId, Hash, InvalidStatement, SignatureVerificationResult, Topic, ValidStatement,
+	MAX_TOPICS,
+};
+use sp_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use std::{
+	collections::{BTreeMap, HashMap, HashSet},
+	path::Path,
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+pub use sp_statement_store::{
+	Error, Result, Statement, StatementSource, StatementStore, SubmitResult, ValidateStatement,
+};
+
+const LOG_TARGET: &str = "statement-store";
+
+/// The column of the database in which the statements are kept, by hash.
+const COL_STATEMENTS: u32 = 0;
+/// The column of the database in which the tombstones of the statements which left the store
+/// are kept, by hash, with the time they left the store.
+const COL_EXPIRED: u32 = 1;
+/// The number of columns of the database.
+const NUM_COLUMNS: u32 = 2;
+
+/// The function used to validate statements, usually by calling the runtime.
+pub type ValidateFn = Box<
+	dyn Fn(StatementSource, Statement) -> std::result::Result<ValidStatement, InvalidStatement>
+		+ Send
+		+ Sync,
+>;
+
+/// Statement store options.
+#[derive(Clone, Debug)]
+pub struct Options {
+	/// The number of seconds after which a statement is purged from the store.
+	pub purge_after_sec: u64,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Options { purge_after_sec: 2 * 24 * 60 * 60 }
+	}
+}
+
+/// A statement as kept in the database.
+#[derive(Encode, Decode)]
+struct StoredStatement {
+	/// When the statement was added, in seconds since the UNIX epoch.
+	timestamp: u64,
+	statement: Statement,
+}
+
+/// What the index knows about a statement in the store.
+struct EntryMeta {
+	account: AccountId,
+	priority: u32,
+	size: usize,
+	timestamp: u64,
+	topics: Vec<Topic>,
+}
+
+/// The statements of a signer.
+#[derive(Default)]
+struct AccountEntries {
+	/// The encoded sizes of the statements, by priority and hash.
+	by_priority: BTreeMap<(u32, Hash), usize>,
+	total_size: usize,
+}
+
+/// The in-memory index of the statements in the store.
+#[derive(Default)]
+struct Index {
+	entries: HashMap<Hash, EntryMeta>,
+	by_topic: HashMap<Topic, HashSet<Hash>>,
+	accounts: HashMap<AccountId, AccountEntries>,
+	/// The tombstones of the statements which left the store, with the time they left it.
+	expired: HashMap<Hash, u64>,
+}
+
+impl Index {
+	fn insert(&mut self, hash: Hash, meta: EntryMeta) {
+		for topic in &meta.topics {
+			self.by_topic.entry(*topic).or_default().insert(hash);
+		}
+		let account = self.accounts.entry(meta.account).or_default();
+		account.by_priority.insert((meta.priority, hash), meta.size);
+		account.total_size += meta.size;
+		self.entries.insert(hash, meta);
+	}
+
+	fn remove(&mut self, hash: &Hash) -> Option<EntryMeta> {
+		let meta = self.entries.remove(hash)?;
+		for topic in &meta.topics {
+			if let Some(hashes) = self.by_topic.get_mut(topic) {
+				hashes.remove(hash);
+				if hashes.is_empty() {
+					self.by_topic.remove(topic);
+				}
+			}
+		}
+		if let Some(account) = self.accounts.get_mut(&meta.account) {
+			account.by_priority.remove(&(meta.priority, *hash));
+			account.total_size -= meta.size;
+			if account.by_priority.is_empty() {
+				self.accounts.remove(&meta.account);
+			}
+		}
+		Some(meta)
+	}
+
+	/// The statements of `account` to evict to make room for a statement of the given priority
+	/// and size, or `None` if there is no way to make room for it.
+	fn evictions(
+		&self,
+		account: &AccountId,
+		priority: u32,
+		size: usize,
+		allowance: &ValidStatement,
+	) -> Option<Vec<Hash>> {
+		let (max_count, max_size) = (allowance.max_count as usize, allowance.max_size as usize);
+		if max_count == 0 || size > max_size {
+			return None
+		}
+		let entries = match self.accounts.get(account) {
+			Some(entries) => entries,
+			None => return Some(Vec::new()),
+		};
+		let mut count = entries.by_priority.len();
+		let mut total_size = entries.total_size;
+		let mut evicted = Vec::new();
+		for ((entry_priority, hash), entry_size) in &entries.by_priority {
+			if count < max_count && total_size + size <= max_size {
+				break
+			}
+			if *entry_priority >= priority {
+				return None
+			}
+			evicted.push(*hash);
+			count -= 1;
+			total_size -= entry_size;
+		}
+		Some(evicted)
+	}
+
+	/// The statements added no later than `timestamp`.
+	fn added_before(&self, timestamp: u64) -> Vec<Hash> {
+		self.entries.iter()
+			.filter(|(_, meta)| meta.timestamp <= timestamp)
+			.map(|(hash, _)| *hash)
+			.collect()
+	}
+
+	/// The tombstones of the statements which left the store no later than `timestamp`.
+	fn expired_before(&self, timestamp: u64) -> Vec<Hash> {
+		self.expired.iter()
+			.filter(|(_, expired_at)| **expired_at <= timestamp)
+			.map(|(hash, _)| *hash)
+			.collect()
+	}
+
+	/// Remove the statement with the given hash, leaving a tombstone behind.
+	fn expire(&mut self, hash: &Hash, now: u64) {
+		self.remove(hash);
+		self.expired.insert(*hash, now);
+	}
+}
+
+/// The statement store.
+pub struct Store {
+	db: Arc<dyn KeyValueDB>,
+	index: RwLock<Index>,
+	validate_fn: ValidateFn,
+	options: Options,
+	import_sinks: Mutex<Vec<TracingUnboundedSender<Statement>>>,
+}
+
+fn timestamp() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+impl Store {
+	/// Open the store kept in the database at `path`, validating the statements against the
+	/// best block of `client`.
+	pub fn open<Block, Client>(
+		path: &Path,
+		client: Arc<Client>,
+		options: Options,
+	) -> Result<Arc<Store>>
+	where
+		Block: BlockT,
+		Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+		Client::Api: ValidateStatement<Block>,
+	{
+		let config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+		let path = path.to_str().ok_or_else(|| Error::Db("Invalid database path".into()))?;
+		let db = kvdb_rocksdb::Database::open(&config, path).map_err(|e| Error::Db(e.to_string()))?;
+
+		let validate_fn = Box::new(move |source, statement| {
+			let at = BlockId::Hash(client.info().best_hash);
+			client.runtime_api().validate_statement(&at, source, statement).unwrap_or_else(|e| {
+				log::debug!(target: LOG_TARGET, "Error calling the runtime: {:?}", e);
+				Err(InvalidStatement::InternalError)
+			})
+		});
+		Ok(Arc::new(Store::new(Arc::new(db), validate_fn, options)?))
+	}
+
+	/// Create a store on top of `db`, which may already contain statements.
+	///
+	/// The statements and tombstones which are already due to be purged are removed from the
+	/// database.
+	pub fn new(
+		db: Arc<dyn KeyValueDB>,
+		validate_fn: ValidateFn,
+		options: Options,
+	) -> Result<Store> {
+		let mut index = Index::default();
+		for (key, value) in db.iter(COL_EXPIRED) {
+			let expired_at = u64::decode(&mut &value[..]).map_err(|e| Error::Decode(e.to_string()))?;
+			let mut hash = Hash::default();
+			hash.copy_from_slice(&key);
+			index.expired.insert(hash, expired_at);
+		}
+		for (key, value) in db.iter(COL_STATEMENTS) {
+			let stored = StoredStatement::decode(&mut &value[..])
+				.map_err(|e| Error::Decode(e.to_string()))?;
+			let mut hash = Hash::default();
+			hash.copy_from_slice(&key);
+			let account = match stored.statement.account_id() {
+				Some(account) => account,
+				None => continue,
+			};
+			index.insert(hash, EntryMeta {
+				account,
+				priority: stored.statement.priority,
+				size: stored.statement.encoded_size(),
+				timestamp: stored.timestamp,
+				topics: stored.statement.topics,
+			});
+		}
+		log::debug!(target: LOG_TARGET, "Loaded {} statements", index.entries.len());
+
+		let store = Store {
+			db,
+			index: RwLock::new(index),
+			validate_fn,
+			options,
+			import_sinks: Mutex::new(Vec::new()),
+		};
+		store.maintain()?;
+		Ok(store)
+	}
+
+	/// Whether the statement with the given hash is in the store.
+	pub fn has(&self, hash: &Hash) -> bool {
+		self.index.read().entries.contains_key(hash)
+	}
+
+	/// A stream of the statements added to the store, except for those received from the
+	/// network.
+	pub fn import_notification_stream(&self) -> TracingUnboundedReceiver<Statement> {
+		let (sink, stream) = tracing_unbounded("mpsc_statement_import_notification_stream");
+		self.import_sinks.lock().push(sink);
+		stream
+	}
+
+	/// Purge the statements which have been in the store for too long, and the tombstones which
+	/// have been kept for long enough.
+	///
+	/// Returns the number of statements purged.
+	pub fn maintain(&self) -> Result<usize> {
+		self.maintain_at(timestamp())
+	}
+
+	fn maintain_at(&self, now: u64) -> Result<usize> {
+		let mut index = self.index.write();
+		let before = now.saturating_sub(self.options.purge_after_sec);
+		let purged = index.added_before(before);
+		let tombstones = index.expired_before(before);
+		if purged.is_empty() && tombstones.is_empty() {
+			return Ok(0)
+		}
+		let mut tx = DBTransaction::new();
+		for hash in &purged {
+			tx.delete(COL_STATEMENTS, hash);
+			tx.put_vec(COL_EXPIRED, hash, now.encode());
+		}
+		for hash in &tombstones {
+			tx.delete(COL_EXPIRED, hash);
+		}
+		self.db.write(tx).map_err(|e| Error::Db(e.to_string()))?;
+		for hash in &tombstones {
+			index.expired.remove(hash);
+		}
+		for hash in &purged {
+			index.expire(hash, now);
+		}
+		log::debug!(
+			target: LOG_TARGET,
+			"Purged {} statements and {} tombstones",
+			purged.len(),
+			tombstones.len(),
+		);
+		Ok(purged.len())
+	}
+
+	/// Whether the statement with the given hash left the store and its tombstone is still kept.
+	pub fn is_expired(&self, hash: &Hash) -> bool {
+		self.index.read().expired.contains_key(hash)
+	}
+
+	fn submit_at(&self, statement: Statement, source: StatementSource, now: u64) -> SubmitResult {
+		let hash = statement.hash();
+		if self.has(&hash) {
+			return SubmitResult::Known
+		}
+		if self.is_expired(&hash) {
+			return SubmitResult::KnownExpired
+		}
+		if statement.topics.len() > MAX_TOPICS {
+			return SubmitResult::Bad("Too many topics")
+		}
+		let account = match statement.verify_signature() {
+			SignatureVerificationResult::Valid(account) => account,
+			SignatureVerificationResult::Invalid => return SubmitResult::Bad("Bad statement proof"),
+			SignatureVerificationResult::NoSignature =>
+				return SubmitResult::Bad("Missing statement proof"),
+		};
+		let allowance = match (self.validate_fn)(source, statement.clone()) {
+			Ok(allowance) => allowance,
+			Err(e) => return SubmitResult::Invalid(e),
+		};
+
+		let size = statement.encoded_size();
+		let mut index = self.index.write();
+		if index.entries.contains_key(&hash) {
+			return SubmitResult::Known
+		}
+		if index.expired.contains_key(&hash) {
+			return SubmitResult::KnownExpired
+		}
+		let evicted = match index.evictions(&account, statement.priority, size, &allowance) {
+			Some(evicted) => evicted,
+			None => return SubmitResult::Ignored,
+		};
+
+		let mut tx = DBTransaction::new();
+		for hash in &evicted {
+			tx.delete(COL_STATEMENTS, hash);
+			tx.put_vec(COL_EXPIRED, hash, now.encode());
+		}
+		let stored = StoredStatement { timestamp: now, statement };
+		tx.put_vec(COL_STATEMENTS, &hash, stored.encode());
+		if let Err(e) = self.db.write(tx) {
+			return SubmitResult::InternalError(Error::Db(e.to_string()))
+		}
+		for hash in &evicted {
+			index.expire(hash, now);
+		}
+		let statement = stored.statement;
+		index.insert(hash, EntryMeta {
+			account,
+			priority: statement.priority,
+			size,
+			timestamp: now,
+			topics: statement.topics.clone(),
+		});
+		drop(index);
+
+		if source != StatementSource::Network {
+			self.import_sinks.lock().retain(|sink| sink.unbounded_send(statement.clone()).is_ok());
+		}
+		SubmitResult::New
+	}
+}
+
+impl StatementStore for Store {
+	fn statements(&self) -> Result<Vec<(Hash, Statement)>> {
+		let hashes = self.index.read().entries.keys().cloned().collect::<Vec<_>>();
+		let mut statements = Vec::with_capacity(hashes.len());
+		for hash in hashes {
+			if let Some(statement) = self.statement(&hash)? {
+				statements.push((hash, statement));
+			}
+		}
+		Ok(statements)
+	}
+
+	fn statement(&self, hash: &Hash) -> Result<Option<Statement>> {
+		let value = self.db.get(COL_STATEMENTS, hash).map_err(|e| Error::Db(e.to_string()))?;
+		value
+			.map(|value| StoredStatement::decode(&mut &value[..])
+				.map(|stored| stored.statement)
+				.map_err(|e| Error::Decode(e.to_string())))
+			.transpose()
+	}
+
+	fn broadcasts(&self, match_all_topics: &[Topic]) -> Result<Vec<Vec<u8>>> {
+		let hashes = {
+			let index = self.index.read();
+			match match_all_topics.split_first() {
+				None => index.entries.keys().cloned().collect::<Vec<_>>(),
+				Some((first, rest)) => index.by_topic.get(first)
+					.into_iter()
+					.flatten()
+					.filter(|hash| rest.iter().all(|topic| index.by_topic
+						.get(topic)
+						.map_or(false, |hashes| hashes.contains(*hash))))
+					.cloned()
+					.collect(),
+			}
+		};
+		let mut data = Vec::with_capacity(hashes.len());
+		for hash in hashes {
+			if let Some(statement) = self.statement(&hash)? {
+				data.push(statement.data);
+			}
+		}
+		Ok(data)
+	}
+
+	fn submit(&self, statement: Statement, source: StatementSource) -> SubmitResult {
+		self.submit_at(statement, source, timestamp())
+	}
+
+	fn remove(&self, hash: &Hash) -> Result<()> {
+		let mut index = self.index.write();
+		if !index.entries.contains_key(hash) {
+			return Ok(())
+		}
+		let now = timestamp();
+		let mut tx = DBTransaction::new();
+		tx.delete(COL_STATEMENTS, hash);
+		tx.put_vec(COL_EXPIRED, hash, now.encode());
+		self.db.write(tx).map_err(|e| Error::Db(e.to_string()))?;
+		index.expire(hash, now);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{sr25519, Pair};
+
+	const ALICE_ALLOWANCE: ValidStatement = ValidStatement { max_count: 2, max_size: 1000 };
+
+	fn alice() -> sr25519::Pair {
+		sr25519::Pair::from_string("//Alice", None).unwrap()
+	}
+
+	fn validate(_source: StatementSource, statement: Statement)
+		-> std::result::Result<ValidStatement, InvalidStatement>
+	{
+		if statement.account_id() == Some(alice().public().0) {
+			Ok(ALICE_ALLOWANCE)
+		} else {
+			Err(InvalidStatement::NoAllowance)
+		}
+	}
+
+	fn store(db: Arc<dyn KeyValueDB>) -> Store {
+		Store::new(db, Box::new(validate), Options::default()).unwrap()
+	}
+
+	fn statement(key: &sr25519::Pair, priority: u32, topics: &[Topic], data: &[u8]) -> Statement {
+		let mut statement = Statement {
+			priority,
+			topics: topics.to_vec(),
+			data: data.to_vec(),
+			..Default::default()
+		};
+		statement.sign_sr25519_private(key);
+		statement
+	}
+
+	#[test]
+	fn statements_are_submitted_and_queried() {
+		let store = store(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+		let imported = store.import_notification_stream();
+
+		let first = statement(&alice(), 1, &[[1; 32], [2; 32]], b"first");
+		let second = statement(&alice(), 1, &[[1; 32]], b"second");
+		assert_eq!(store.submit(first.clone(), StatementSource::Local), SubmitResult::New);
+		assert_eq!(store.submit(second.clone(), StatementSource::Network), SubmitResult::New);
+		assert_eq!(store.submit(first.clone(), StatementSource::Network), SubmitResult::Known);
+
+		assert_eq!(store.statement(&first.hash()).unwrap(), Some(first.clone()));
+		assert_eq!(store.statements().unwrap().len(), 2);
+		assert_eq!(store.broadcasts(&[[1; 32], [2; 32]]).unwrap(), vec![b"first".to_vec()]);
+		let mut data = store.broadcasts(&[[1; 32]]).unwrap();
+		data.sort();
+		assert_eq!(data, vec![b"first".to_vec(), b"second".to_vec()]);
+		assert!(store.broadcasts(&[[3; 32]]).unwrap().is_empty());
+
+		// Only the statement submitted locally is notified.
+		drop(store);
+		assert_eq!(futures::executor::block_on_stream(imported).collect::<Vec<_>>(), vec![first]);
+	}
+
+	#[test]
+	fn bad_and_invalid_statements_are_rejected() {
+		let store = store(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+
+		let unsigned = Statement { data: b"data".to_vec(), ..Default::default() };
+		assert_eq!(
+			store.submit(unsigned, StatementSource::Local),
+			SubmitResult::Bad("Missing statement proof"),
+		);
+
+		let mut tampered = statement(&alice(), 1, &[], b"data");
+		tampered.priority = 2;
+		assert_eq!(
+			store.submit(tampered, StatementSource::Local),
+			SubmitResult::Bad("Bad statement proof"),
+		);
+
+		let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+		assert_eq!(
+			store.submit(statement(&bob, 1, &[], b"data"), StatementSource::Local),
+			SubmitResult::Invalid(InvalidStatement::NoAllowance),
+		);
+
+		let too_large = statement(&alice(), 1, &[], &[0; 1000]);
+		assert_eq!(store.submit(too_large, StatementSource::Local), SubmitResult::Ignored);
+		assert!(store.statements().unwrap().is_empty());
+	}
+
+	#[test]
+	fn statements_of_lowest_priority_are_evicted() {
+		let store = store(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+		let low = statement(&alice(), 1, &[], b"low");
+		let mid = statement(&alice(), 2, &[], b"mid");
+		let high = statement(&alice(), 3, &[], b"high");
+		assert_eq!(store.submit(mid.clone(), StatementSource::Local), SubmitResult::New);
+		assert_eq!(store.submit(low.clone(), StatementSource::Local), SubmitResult::New);
+
+		assert_eq!(store.submit(high.clone(), StatementSource::Local), SubmitResult::New);
+		assert!(!store.has(&low.hash()));
+		assert!(store.has(&mid.hash()));
+		assert!(store.has(&high.hash()));
+		// The evicted statement is not gossiped back into the store.
+		assert_eq!(store.submit(low, StatementSource::Network), SubmitResult::KnownExpired);
+
+		// There is no statement of lower priority left to evict.
+		let lowest = statement(&alice(), 0, &[], b"lowest");
+		assert_eq!(store.submit(lowest, StatementSource::Local), SubmitResult::Ignored);
+		assert_eq!(store.statements().unwrap().len(), 2);
+	}
+
+	#[test]
+	fn statements_are_persisted_and_purged() {
+		let db: Arc<dyn KeyValueDB> = Arc::new(kvdb_memorydb::create(NUM_COLUMNS));
+		let store = store(db.clone());
+		let first = statement(&alice(), 1, &[[1; 32]], b"first");
+		let second = statement(&alice(), 1, &[[1; 32]], b"second");
+		let now = timestamp();
+		assert_eq!(store.submit_at(first.clone(), StatementSource::Local, now), SubmitResult::New);
+		assert_eq!(
+			store.submit_at(second.clone(), StatementSource::Local, now + 1000),
+			SubmitResult::New,
+		);
+		drop(store);
+
+		let options = Options { purge_after_sec: 1500 };
+		let store = Store::new(db.clone(), Box::new(validate), options.clone()).unwrap();
+		assert!(store.has(&first.hash()));
+		assert_eq!(store.maintain_at(now + 1499).unwrap(), 0);
+		assert_eq!(store.maintain_at(now + 1500).unwrap(), 1);
+		assert!(!store.has(&first.hash()));
+		assert_eq!(store.broadcasts(&[[1; 32]]).unwrap(), vec![b"second".to_vec()]);
+
+		store.remove(&second.hash()).unwrap();
+		assert!(store.statements().unwrap().is_empty());
+		drop(store);
+
+		// The tombstones are persisted, and purged after another `purge_after_sec`.
+		let store = Store::new(db, Box::new(validate), options).unwrap();
+		assert_eq!(
+			store.submit_at(first.clone(), StatementSource::Network, now + 1501),
+			SubmitResult::KnownExpired,
+		);
+		assert_eq!(
+			store.submit_at(second, StatementSource::Network, now + 1501),
+			SubmitResult::KnownExpired,
+		);
+		assert_eq!(store.maintain_at(now + 3000).unwrap(), 0);
+		assert_eq!(
+			store.submit_at(first, StatementSource::Network, now + 3000),
+			SubmitResult::New,
+		);
+	}
+}
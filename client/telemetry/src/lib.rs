@@ -96,6 +96,8 @@ pub struct ConnectionMessage {
 	pub genesis_hash: String,
 	/// Node is an authority.
 	pub authority: bool,
+	/// Node's role, e.g. `AUTHORITY` or `SENTRY`.
+	pub role: String,
 	/// Node's startup time.
 	pub startup_time: String,
 	/// Node's network ID.
@@ -29,7 +29,7 @@ use sp_runtime::{
 		TransactionValidity, TransactionTag as Tag, TransactionValidityError, TransactionSource,
 	},
 };
-use sp_transaction_pool::error;
+use sp_transaction_pool::error::{self, IntoPoolError};
 use wasm_timer::Instant;
 use futures::channel::mpsc::Receiver;
 
@@ -415,7 +415,13 @@ impl<B: ChainApi> Pool<B> {
 
 		let status = match validation_result {
 			Ok(status) => status,
-			Err(e) => return (hash, ValidatedTransaction::Invalid(hash, e)),
+			// The transaction may be valid once the node is synced, so don't ban it.
+			Err(e) => return match e.into_pool_error() {
+				Ok(error::Error::NotSynced) =>
+					(hash, ValidatedTransaction::Unknown(hash, error::Error::NotSynced.into())),
+				Ok(e) => (hash, ValidatedTransaction::Invalid(hash, e.into())),
+				Err(e) => (hash, ValidatedTransaction::Invalid(hash, e)),
+			},
 		};
 
 		let validity = match status {
@@ -20,6 +20,7 @@
 
 use std::{marker::PhantomData, pin::Pin, sync::Arc};
 use codec::{Decode, Encode};
+use parking_lot::Mutex;
 use futures::{
 	channel::oneshot, executor::{ThreadPool, ThreadPoolBuilder}, future::{Future, FutureExt, ready, Ready},
 };
@@ -31,8 +32,9 @@ use sp_runtime::{
 	generic::BlockId, traits::{self, Block as BlockT, BlockIdTo, Header as HeaderT, Hash as HashT},
 	transaction_validity::{TransactionValidity, TransactionSource},
 };
-use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+use sp_transaction_pool::{error::Error as TxPoolError, runtime_api::TaggedTransactionQueue};
 use sp_api::{ProvideRuntimeApi, ApiExt};
+use sp_consensus::{BlockStatus, SyncOracle};
 use prometheus_endpoint::Registry as PrometheusRegistry;
 
 use crate::{metrics::{ApiMetrics, ApiMetricsExt}, error::{self, Error}};
@@ -43,6 +45,7 @@ pub struct FullChainApi<Client, Block> {
 	pool: ThreadPool,
 	_marker: PhantomData<Block>,
 	metrics: Option<Arc<ApiMetrics>>,
+	sync_oracle: Mutex<Option<Box<dyn SyncOracle + Send>>>,
 }
 
 impl<Client, Block> FullChainApi<Client, Block> {
@@ -74,8 +77,17 @@ impl<Client, Block> FullChainApi<Client, Block> {
 				.expect("Failed to spawn verifier threads, that are critical for node operation."),
 			_marker: Default::default(),
 			metrics,
+			sync_oracle: Mutex::new(None),
 		}
 	}
+
+	/// Set the oracle telling whether the node is major syncing, transactions are rejected with
+	/// `NotSynced` instead of being validated while it is.
+	///
+	/// The oracle is usually the network service, which is built after the transaction pool.
+	pub fn set_sync_oracle(&self, sync_oracle: impl SyncOracle + Send + 'static) {
+		*self.sync_oracle.lock() = Some(Box::new(sync_oracle));
+	}
 }
 
 impl<Client, Block> sc_transaction_graph::ChainApi for FullChainApi<Client, Block>
@@ -102,6 +114,10 @@ where
 		source: TransactionSource,
 		uxt: sc_transaction_graph::ExtrinsicFor<Self>,
 	) -> Self::ValidationFuture {
+		if let Err(e) = self.ensure_synced(at) {
+			return Box::pin(ready(Err(e)))
+		}
+
 		let (tx, rx) = oneshot::channel();
 		let client = self.client.clone();
 		let at = at.clone();
@@ -208,14 +224,33 @@ where
 	/// `validate_transaction` but blocks the current thread when performing
 	/// validation. Only implemented for `FullChainApi` since we can call into
 	/// the runtime locally.
+	///
+	/// Like `validate_transaction`, this returns `NotSynced` while the node is major syncing.
 	pub fn validate_transaction_blocking(
 		&self,
 		at: &BlockId<Block>,
 		source: TransactionSource,
 		uxt: sc_transaction_graph::ExtrinsicFor<Self>,
 	) -> error::Result<TransactionValidity> {
+		self.ensure_synced(at)?;
 		validate_transaction_blocking(&*self.client, at, source, uxt)
 	}
+
+	/// Returns `NotSynced` if the node is major syncing, or if the state at `at` is not available
+	/// yet, e.g. during warp sync, so that the runtime can't be called to validate transactions.
+	fn ensure_synced(&self, at: &BlockId<Block>) -> error::Result<()> {
+		let is_major_syncing = self.sync_oracle.lock()
+			.as_mut()
+			.map_or(false, |sync_oracle| sync_oracle.is_major_syncing());
+		if is_major_syncing {
+			return Err(TxPoolError::NotSynced.into())
+		}
+
+		match self.client.block_status(at)? {
+			BlockStatus::InChainPruned => Err(TxPoolError::NotSynced.into()),
+			_ => Ok(()),
+		}
+	}
 }
 
 /// The transaction pool logic for light client.
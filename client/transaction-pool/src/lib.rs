@@ -46,9 +46,9 @@ use sp_core::traits::SpawnNamed;
 use sp_transaction_pool::{
 	TransactionPool, PoolStatus, ImportNotificationStream, TxHash, TransactionFor,
 	TransactionStatusStreamFor, MaintainedTransactionPool, PoolFuture, ChainEvent,
-	TransactionSource,
+	TransactionSource, error::{Error as PoolError, IntoPoolError},
 };
-use sc_transaction_graph::{ChainApi, ExtrinsicHash};
+use sc_transaction_graph::{ChainApi, ExtrinsicHash, ExtrinsicFor};
 use wasm_timer::Instant;
 
 use prometheus_endpoint::Registry as PrometheusRegistry;
@@ -80,6 +80,9 @@ pub struct BasicPool<PoolApi, Block>
 	revalidation_strategy: Arc<Mutex<RevalidationStrategy<NumberFor<Block>>>>,
 	revalidation_queue: Arc<revalidation::RevalidationQueue<PoolApi>>,
 	ready_poll: Arc<Mutex<ReadyPoll<ReadyIteratorFor<PoolApi>, Block>>>,
+	/// The transactions of the retracted blocks which couldn't be resubmitted because the node was
+	/// not synced, they are resubmitted again on the next new best block.
+	not_synced_retracted: Arc<Mutex<Vec<ExtrinsicFor<PoolApi>>>>,
 	metrics: PrometheusMetrics,
 }
 
@@ -180,6 +183,7 @@ impl<PoolApi, Block> BasicPool<PoolApi, Block>
 				revalidation_queue: Arc::new(revalidation_queue),
 				revalidation_strategy: Arc::new(Mutex::new(RevalidationStrategy::Always)),
 				ready_poll: Default::default(),
+				not_synced_retracted: Default::default(),
 				metrics: Default::default(),
 			},
 			background_task,
@@ -222,6 +226,7 @@ impl<PoolApi, Block> BasicPool<PoolApi, Block>
 				}
 			)),
 			ready_poll: Arc::new(Mutex::new(ReadyPoll::new(best_block_number))),
+			not_synced_retracted: Default::default(),
 			metrics: PrometheusMetrics::new(prometheus),
 		}
 	}
@@ -253,7 +258,7 @@ impl<PoolApi, Block> TransactionPool for BasicPool<PoolApi, Block>
 		let pool = self.pool.clone();
 		let at = *at;
 
-		self.metrics.report(|metrics| metrics.submitted_transactions.inc_by(xts.len() as u64));
+		self.metrics.report(|metrics| metrics.report_submitted(source, xts.len() as u64));
 
 		async move { pool.submit_at(&at, source, xts).await }.boxed()
 	}
@@ -267,7 +272,7 @@ impl<PoolApi, Block> TransactionPool for BasicPool<PoolApi, Block>
 		let pool = self.pool.clone();
 		let at = *at;
 
-		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
+		self.metrics.report(|metrics| metrics.report_submitted(source, 1));
 
 		async move { pool.submit_one(&at, source, xt).await }.boxed()
 	}
@@ -281,7 +286,7 @@ impl<PoolApi, Block> TransactionPool for BasicPool<PoolApi, Block>
 		let at = *at;
 		let pool = self.pool.clone();
 
-		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
+		self.metrics.report(|metrics| metrics.report_submitted(source, 1));
 
 		async move {
 			pool.submit_and_watch(&at, source, xt)
@@ -412,6 +417,13 @@ where
 
 		pool
 	}
+
+	/// Reject the submitted transactions with `NotSynced` while `sync_oracle` reports major sync.
+	///
+	/// See [`FullChainApi::set_sync_oracle`].
+	pub fn set_sync_oracle(&self, sync_oracle: impl sp_consensus::SyncOracle + Send + 'static) {
+		self.api.set_sync_oracle(sync_oracle)
+	}
 }
 
 impl<Block, Client> sp_transaction_pool::LocalTransactionPool
@@ -621,6 +633,7 @@ impl<PoolApi, Block> MaintainedTransactionPool for BasicPool<PoolApi, Block>
 				let revalidation_strategy = self.revalidation_strategy.clone();
 				let revalidation_queue = self.revalidation_queue.clone();
 				let ready_poll = self.ready_poll.clone();
+				let not_synced_retracted = self.not_synced_retracted.clone();
 				let metrics = self.metrics.clone();
 
 				async move {
@@ -661,10 +674,11 @@ impl<PoolApi, Block> MaintainedTransactionPool for BasicPool<PoolApi, Block>
 						|metrics| metrics.block_transactions_pruned.inc_by(pruned_log.len() as u64)
 					);
 
-					if let (true, Some(tree_route)) = (next_action.resubmit, tree_route) {
-						let mut resubmit_transactions = Vec::new();
+					if next_action.resubmit {
+						let mut resubmit_transactions =
+							std::mem::take(&mut *not_synced_retracted.lock());
 
-						for retracted in tree_route.retracted() {
+						for retracted in tree_route.iter().flat_map(|route| route.retracted()) {
 							let hash = retracted.hash.clone();
 
 							let block_transactions = api.block_body(&BlockId::hash(hash))
@@ -704,19 +718,35 @@ impl<PoolApi, Block> MaintainedTransactionPool for BasicPool<PoolApi, Block>
 							);
 						}
 
-						if let Err(e) = pool.resubmit_at(
+						match pool.resubmit_at(
 							&id,
 							// These transactions are coming from retracted blocks, we should
 							// simply consider them external.
 							TransactionSource::External,
-							resubmit_transactions,
+							resubmit_transactions.clone(),
 						).await {
-							log::debug!(
+							Ok(results) => {
+								// The node is not synced for all the transactions or for none.
+								let not_synced = results.into_iter().any(|result| matches!(
+									result.map_err(IntoPoolError::into_pool_error),
+									Err(Ok(PoolError::NotSynced)),
+								));
+								if not_synced {
+									log::debug!(
+										target: "txpool",
+										"[{:?}] Not synced, keeping {} retracted transactions",
+										id,
+										resubmit_transactions.len(),
+									);
+									*not_synced_retracted.lock() = resubmit_transactions;
+								}
+							},
+							Err(e) => log::debug!(
 								target: "txpool",
 								"[{:?}] Error re-submitting transactions: {:?}",
 								id,
 								e,
-							)
+							),
 						}
 					}
 
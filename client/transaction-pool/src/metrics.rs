@@ -20,7 +20,8 @@
 
 use std::sync::Arc;
 
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{register, Counter, CounterVec, Opts, PrometheusError, Registry, U64};
+use sp_runtime::transaction_validity::TransactionSource;
 
 #[derive(Clone, Default)]
 pub struct MetricsLink(Arc<Option<Metrics>>);
@@ -45,7 +46,7 @@ impl MetricsLink {
 
 /// Transaction pool Prometheus metrics.
 pub struct Metrics {
-	pub submitted_transactions: Counter<U64>,
+	pub submitted_transactions: CounterVec<U64>,
 	pub validations_invalid: Counter<U64>,
 	pub block_transactions_pruned: Counter<U64>,
 	pub block_transactions_resubmitted: Counter<U64>,
@@ -55,9 +56,12 @@ impl Metrics {
 	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
 		Ok(Self {
 			submitted_transactions: register(
-				Counter::new(
-					"sub_txpool_submitted_transactions",
-					"Total number of transactions submitted",
+				CounterVec::new(
+					Opts::new(
+						"sub_txpool_submitted_transactions",
+						"Total number of transactions submitted",
+					),
+					&["source"],
 				)?,
 				registry,
 			)?,
@@ -84,6 +88,16 @@ impl Metrics {
 			)?,
 		})
 	}
+
+	/// Report `count` transactions submitted from `source`.
+	pub fn report_submitted(&self, source: TransactionSource, count: u64) {
+		let source = match source {
+			TransactionSource::InBlock => "in_block",
+			TransactionSource::Local => "local",
+			TransactionSource::External => "external",
+		};
+		self.submitted_transactions.with_label_values(&[source]).inc_by(count);
+	}
 }
 
 /// Transaction pool api Prometheus metrics.
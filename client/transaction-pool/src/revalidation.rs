@@ -24,6 +24,7 @@ use sc_transaction_graph::{ChainApi, Pool, ExtrinsicHash, NumberFor, ValidatedTr
 use sp_runtime::traits::{Zero, SaturatedConversion};
 use sp_runtime::generic::BlockId;
 use sp_runtime::transaction_validity::TransactionValidityError;
+use sp_transaction_pool::error::{Error as PoolError, IntoPoolError};
 use sp_utils::mpsc::{tracing_unbounded, TracingUnboundedSender, TracingUnboundedReceiver};
 
 use futures::prelude::*;
@@ -101,14 +102,24 @@ async fn batch_revalidate<Api: ChainApi>(
 					)
 				);
 			},
-			Err(validation_err) => {
-				log::debug!(
-					target: "txpool",
-					"[{:?}]: Error during revalidation: {:?}. Removing.",
-					ext_hash,
-					validation_err
-				);
-				invalid_hashes.push(ext_hash);
+			Err(validation_err) => match validation_err.into_pool_error() {
+				Ok(PoolError::NotSynced) => {
+					// keeping the transaction, it will be revalidated once the node is synced.
+					log::trace!(
+						target: "txpool",
+						"[{:?}]: Not synced during revalidation",
+						ext_hash,
+					);
+				},
+				validation_err => {
+					log::debug!(
+						target: "txpool",
+						"[{:?}]: Error during revalidation: {:?}. Removing.",
+						ext_hash,
+						validation_err
+					);
+					invalid_hashes.push(ext_hash);
+				},
 			}
 		}
 	}
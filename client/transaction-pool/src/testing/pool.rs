@@ -266,6 +266,27 @@ fn should_resubmit_from_retracted_during_maintenance() {
 	assert_eq!(pool.status().ready, 1);
 }
 
+#[test]
+fn should_keep_retracted_transactions_while_not_synced() {
+	let xt = uxt(Alice, 209);
+
+	let (pool, _guard, _notifier) = maintained_pool();
+
+	let header = pool.api.push_block(1, vec![], true);
+	let fork_header = pool.api.push_block(1, vec![xt], false);
+
+	pool.api.set_not_synced(true);
+	let event = block_event_with_retracted(header.clone(), fork_header.hash(), &*pool.api);
+	block_on(pool.maintain(event));
+	assert_eq!(pool.status().ready, 0);
+
+	// The transaction of the retracted block is resubmitted once the node is synced.
+	pool.api.set_not_synced(false);
+	let header = pool.api.push_block_with_parent(header.hash(), vec![], true);
+	block_on(pool.maintain(block_event(header)));
+	assert_eq!(pool.status().ready, 1);
+}
+
 
 #[test]
 fn should_not_resubmit_from_retracted_during_maintenance_if_tx_is_also_in_enacted() {
@@ -941,6 +962,41 @@ fn should_not_accept_old_signatures() {
 	);
 }
 
+#[test]
+fn should_reject_transactions_while_major_syncing() {
+	use std::sync::atomic::{AtomicBool, Ordering};
+
+	struct TestSyncOracle(Arc<AtomicBool>);
+
+	impl sp_consensus::SyncOracle for TestSyncOracle {
+		fn is_major_syncing(&mut self) -> bool {
+			self.0.load(Ordering::Relaxed)
+		}
+
+		fn is_offline(&mut self) -> bool {
+			false
+		}
+	}
+
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let api = Arc::new(FullChainApi::new(client, None));
+	let pool = BasicPool::new_test(api.clone()).0;
+
+	let is_major_syncing = Arc::new(AtomicBool::new(true));
+	api.set_sync_oracle(TestSyncOracle(is_major_syncing.clone()));
+
+	assert_matches::assert_matches!(
+		block_on(pool.submit_one(&BlockId::number(0), SOURCE, uxt(Alice, 0))),
+		Err(error::Error::Pool(sp_transaction_pool::error::Error::NotSynced))
+	);
+	assert_eq!(pool.status().ready, 0);
+
+	// The transaction wasn't banned, it is accepted once the node is synced.
+	is_major_syncing.store(false, Ordering::Relaxed);
+	block_on(pool.submit_one(&BlockId::number(0), SOURCE, uxt(Alice, 0))).expect("Imported");
+	assert_eq!(pool.status().ready, 1);
+}
+
 #[test]
 fn import_notification_to_pool_maintain_works() {
 	let mut client = Arc::new(substrate_test_runtime_client::new());
@@ -85,6 +85,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return DepositConsequence::UnknownAsset,
 		};
+		if details.status == AssetStatus::Destroying {
+			return DepositConsequence::UnknownAsset
+		}
 		if details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
@@ -122,8 +125,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if details.supply.checked_sub(&amount).is_none() {
 			return Underflow
 		}
-		if details.is_frozen {
-			return Frozen
+		match details.status {
+			AssetStatus::Live => {},
+			AssetStatus::Frozen => return Frozen,
+			AssetStatus::Destroying => return UnknownAsset,
 		}
 		let account = Account::<T, I>::get(id, who);
 		if account.is_frozen {
@@ -164,7 +169,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		keep_alive: bool,
 	) -> Result<T::Balance, DispatchError> {
 		let details = Asset::<T, I>::get(id).ok_or_else(|| Error::<T, I>::Unknown)?;
-		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
+		ensure!(details.status != AssetStatus::Frozen, Error::<T, I>::Frozen);
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 
 		let account = Account::<T, I>::get(id, who);
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
@@ -476,4 +482,102 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), credit));
 		Ok(credit)
 	}
+
+	/// Start the destruction of the asset `id`, checking that it is owned by `maybe_check_owner`
+	/// if given.
+	pub(super) fn do_start_destroy(
+		id: T::AssetId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+			details.status = AssetStatus::Destroying;
+
+			Self::deposit_event(Event::DestructionStarted(id));
+			Ok(())
+		})
+	}
+
+	/// Remove up to `max_items` accounts of the asset `id`, which must be being destroyed.
+	///
+	/// Returns the number of accounts removed.
+	pub(super) fn do_destroy_accounts(
+		id: T::AssetId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> Result<u32, DispatchError> {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+			let mut removed = 0;
+			for (who, v) in Account::<T, I>::drain_prefix(id).take(max_items as usize) {
+				details.supply = details.supply.saturating_sub(v.balance);
+				Self::dead_account(id, &who, details, v.sufficient);
+				removed += 1;
+			}
+
+			Self::deposit_event(Event::AccountsDestroyed(id, removed, details.accounts));
+			Ok(removed)
+		})
+	}
+
+	/// Remove up to `max_items` approvals of the asset `id`, which must be being destroyed,
+	/// returning their deposits.
+	///
+	/// Returns the number of approvals removed.
+	pub(super) fn do_destroy_approvals(
+		id: T::AssetId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> Result<u32, DispatchError> {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+			let mut removed = 0;
+			for ((owner, _), approval) in Approvals::<T, I>::drain_prefix((id,))
+				.take(max_items as usize)
+			{
+				T::Currency::unreserve(&owner, approval.deposit);
+				details.approvals = details.approvals.saturating_sub(1);
+				removed += 1;
+			}
+
+			Self::deposit_event(Event::ApprovalsDestroyed(id, removed, details.approvals));
+			Ok(removed)
+		})
+	}
+
+	/// Remove the asset `id`, which must be being destroyed and have no accounts or approvals
+	/// left, returning the deposits of the asset and of its metadata.
+	pub(super) fn do_finish_destroy(id: T::AssetId) -> DispatchResult {
+		Asset::<T, I>::try_mutate_exists(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			ensure!(details.accounts == 0, Error::<T, I>::InUse);
+			// Approvals created before they were counted are not reflected in `approvals`.
+			ensure!(Approvals::<T, I>::iter_prefix((id,)).next().is_none(), Error::<T, I>::InUse);
+
+			let metadata = Metadata::<T, I>::take(&id);
+			T::Currency::unreserve(
+				&details.owner,
+				details.deposit.saturating_add(metadata.deposit),
+			);
+
+			Self::deposit_event(Event::Destroyed(id));
+			Ok(())
+		})
+	}
+
+	/// Account for an approval of the asset `id` having been removed.
+	pub(super) fn approval_removed(id: T::AssetId) {
+		Asset::<T, I>::mutate(id, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.approvals = details.approvals.saturating_sub(1);
+			}
+		});
+	}
 }
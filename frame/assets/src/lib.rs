@@ -94,7 +94,10 @@
 //! * `force_cancel_approval`: Rescind a previous approval.
 //!
 //! ### Privileged Functions
-//! * `destroy`: Destroys an entire asset class; called by the asset class's Owner.
+//! * `start_destroy`: Starts the destruction of an entire asset class; called by the asset class's
+//!   Owner. The accounts and approvals of the asset class are then removed in batches by anyone
+//!   calling `destroy_accounts` and `destroy_approvals`, and the asset class itself by
+//!   `finish_destroy`.
 //! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
 //! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin.
 //! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
@@ -211,6 +214,11 @@ pub mod pallet {
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
+		/// The maximum number of accounts or approvals removed by a single `destroy_accounts` or
+		/// `destroy_approvals` call.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -297,6 +305,14 @@ pub mod pallet {
 		AssetThawed(T::AssetId),
 		/// An asset class was destroyed.
 		Destroyed(T::AssetId),
+		/// The destruction of an asset class has started. \[asset_id\]
+		DestructionStarted(T::AssetId),
+		/// Accounts of an asset class being destroyed were removed.
+		/// \[asset_id, accounts_destroyed, accounts_remaining\]
+		AccountsDestroyed(T::AssetId, u32, u32),
+		/// Approvals of an asset class being destroyed were removed.
+		/// \[asset_id, approvals_destroyed, approvals_remaining\]
+		ApprovalsDestroyed(T::AssetId, u32, u32),
 		/// Some asset class was force-created. \[asset_id, owner\]
 		ForceCreated(T::AssetId, T::AccountId),
 		/// New metadata has been set for an asset. \[asset_id, name, symbol, decimals, is_frozen\]
@@ -344,6 +360,10 @@ pub mod pallet {
 		Unapproved,
 		/// The source account would not survive the transfer and it needs to stay alive.
 		WouldDie,
+		/// The asset is being destroyed.
+		AssetNotLive,
+		/// The asset is not being destroyed.
+		IncorrectStatus,
 	}
 
 	#[pallet::call]
@@ -397,7 +417,7 @@ pub mod pallet {
 					accounts: 0,
 					sufficients: 0,
 					approvals: 0,
-					is_frozen: false,
+					status: AssetStatus::Live,
 				},
 			);
 			Self::deposit_event(Event::Created(id, owner, admin));
@@ -451,68 +471,99 @@ pub mod pallet {
 					accounts: 0,
 					sufficients: 0,
 					approvals: 0,
-					is_frozen: false,
+					status: AssetStatus::Live,
 				},
 			);
 			Self::deposit_event(Event::ForceCreated(id, owner));
 			Ok(())
 		}
 
-		/// Destroy a class of fungible assets.
+		/// Start the destruction of a class of fungible assets.
 		///
 		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
 		/// owner of the asset `id`.
 		///
+		/// Once the destruction has started, the asset can no longer be minted, transferred or
+		/// approved for transfer. Its accounts and approvals are then removed in batches by
+		/// `destroy_accounts` and `destroy_approvals`, and the asset class itself by
+		/// `finish_destroy`.
+		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		/// asset.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// Emits `DestructionStarted` event when successful.
 		///
-		/// Weight: `O(c + p + a)` where:
-		/// - `c = (witness.accounts - witness.sufficients)`
-		/// - `s = witness.sufficients`
-		/// - `a = witness.approvals`
-		#[pallet::weight(T::WeightInfo::destroy(
-			witness.accounts.saturating_sub(witness.sufficients),
- 			witness.sufficients,
- 			witness.approvals,
- 		))]
-		pub(super) fn destroy(
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub(super) fn start_destroy(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			witness: DestroyWitness,
 		) -> DispatchResult {
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
 				Err(origin) => Some(ensure_signed(origin)?),
 			};
-			Asset::<T, I>::try_mutate_exists(id, |maybe_details| {
-				let mut details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
-				if let Some(check_owner) = maybe_check_owner {
-					ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
-				}
-				ensure!(details.accounts == witness.accounts, Error::<T, I>::BadWitness);
-				ensure!(details.sufficients == witness.sufficients, Error::<T, I>::BadWitness);
-				ensure!(details.approvals == witness.approvals, Error::<T, I>::BadWitness);
-
-				for (who, v) in Account::<T, I>::drain_prefix(id) {
-					Self::dead_account(id, &who, &mut details, v.sufficient);
-				}
-				debug_assert_eq!(details.accounts, 0);
-				debug_assert_eq!(details.sufficients, 0);
+			Self::do_start_destroy(id, maybe_check_owner)
+		}
 
-				let metadata = Metadata::<T, I>::take(&id);
-				T::Currency::unreserve(
-					&details.owner,
-					details.deposit.saturating_add(metadata.deposit),
-				);
+		/// Remove up to `RemoveItemsLimit` accounts of an asset class being destroyed.
+		///
+		/// The origin must be Signed; anyone may help destroying an asset.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		///
+		/// Emits `AccountsDestroyed` event when successful.
+		///
+		/// Weight: `O(c)` where `c` is the number of accounts removed, at most `RemoveItemsLimit`.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub(super) fn destroy_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let removed = Self::do_destroy_accounts(id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_accounts(removed)).into())
+		}
 
-				Approvals::<T, I>::remove_prefix((&id,));
-				Self::deposit_event(Event::Destroyed(id));
+		/// Remove up to `RemoveItemsLimit` approvals of an asset class being destroyed, returning
+		/// their deposits.
+		///
+		/// The origin must be Signed; anyone may help destroying an asset.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		///
+		/// Emits `ApprovalsDestroyed` event when successful.
+		///
+		/// Weight: `O(a)` where `a` is the number of approvals removed, at most
+		/// `RemoveItemsLimit`.
+		#[pallet::weight(T::WeightInfo::destroy_approvals(T::RemoveItemsLimit::get()))]
+		pub(super) fn destroy_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let removed = Self::do_destroy_approvals(id, T::RemoveItemsLimit::get())?;
+			Ok(Some(T::WeightInfo::destroy_approvals(removed)).into())
+		}
 
-				// NOTE: could use postinfo to reflect the actual number of accounts/sufficient/approvals
-				Ok(())
-			})
+		/// Complete the destruction of an asset class, once all its accounts and approvals have
+		/// been removed.
+		///
+		/// The origin must be Signed; anyone may help destroying an asset. The deposits of the
+		/// asset class and of its metadata are returned to the owner.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub(super) fn finish_destroy(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
 		}
 
 		/// Mint assets of a particular class.
@@ -765,8 +816,9 @@ pub mod pallet {
 			Asset::<T, I>::try_mutate(id, |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(&origin == &d.freezer, Error::<T, I>::NoPermission);
+				ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 
-				d.is_frozen = true;
+				d.status = AssetStatus::Frozen;
 
 				Self::deposit_event(Event::<T, I>::AssetFrozen(id));
 				Ok(())
@@ -792,8 +844,9 @@ pub mod pallet {
 			Asset::<T, I>::try_mutate(id, |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				ensure!(&origin == &d.admin, Error::<T, I>::NoPermission);
+				ensure!(d.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 
-				d.is_frozen = false;
+				d.status = AssetStatus::Live;
 
 				Self::deposit_event(Event::<T, I>::AssetThawed(id));
 				Ok(())
@@ -1090,13 +1143,14 @@ pub mod pallet {
 
 			Asset::<T, I>::try_mutate(id, |maybe_asset| {
 				let mut asset = maybe_asset.take().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(asset.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 				asset.owner = T::Lookup::lookup(owner)?;
 				asset.issuer = T::Lookup::lookup(issuer)?;
 				asset.admin = T::Lookup::lookup(admin)?;
 				asset.freezer = T::Lookup::lookup(freezer)?;
 				asset.min_balance = min_balance;
 				asset.is_sufficient = is_sufficient;
-				asset.is_frozen = is_frozen;
+				asset.status = if is_frozen { AssetStatus::Frozen } else { AssetStatus::Live };
 				*maybe_asset = Some(asset);
 
 				Self::deposit_event(Event::AssetStatusChanged(id));
@@ -1134,8 +1188,16 @@ pub mod pallet {
 			let owner = ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
 
+			let mut details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
 			Approvals::<T, I>::try_mutate((id, &owner, &delegate), |maybe_approved| -> DispatchResult {
-				let mut approved = maybe_approved.take().unwrap_or_default();
+				let mut approved = match maybe_approved.take() {
+					Some(approved) => approved,
+					None => {
+						details.approvals = details.approvals.saturating_add(1);
+						Default::default()
+					},
+				};
 				let deposit_required = T::ApprovalDeposit::get();
 				if approved.deposit < deposit_required {
 					T::Currency::reserve(&owner, deposit_required - approved.deposit)?;
@@ -1145,6 +1207,7 @@ pub mod pallet {
 				*maybe_approved = Some(approved);
 				Ok(())
 			})?;
+			Asset::<T, I>::insert(id, details);
 			Self::deposit_event(Event::ApprovedTransfer(id, owner, delegate, amount));
 
 			Ok(())
@@ -1173,6 +1236,7 @@ pub mod pallet {
 			let delegate = T::Lookup::lookup(delegate)?;
 			let approval = Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
 			T::Currency::unreserve(&owner, approval.deposit);
+			Self::approval_removed(id);
 
 			Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
 			Ok(())
@@ -1212,6 +1276,7 @@ pub mod pallet {
 
 			let approval = Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
 			T::Currency::unreserve(&owner, approval.deposit);
+			Self::approval_removed(id);
 
 			Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
 			Ok(())
@@ -1263,6 +1328,7 @@ pub mod pallet {
 
 				if remaining.is_zero() {
 					T::Currency::unreserve(&owner, approved.deposit);
+					Self::approval_removed(id);
 				} else {
 					approved.amount = remaining;
 					*maybe_approved = Some(approved);
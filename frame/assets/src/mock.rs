@@ -82,6 +82,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 }
 
 parameter_types! {
@@ -90,6 +91,7 @@ parameter_types! {
 	pub const StringLimit: u32 = 50;
 	pub const MetadataDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const RemoveItemsLimit: u32 = 5;
 }
 
 impl Config for Test {
@@ -104,6 +106,7 @@ impl Config for Test {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = TestFreezer;
+	type RemoveItemsLimit = RemoveItemsLimit;
 	type WeightInfo = ();
 	type Extra = ();
 }
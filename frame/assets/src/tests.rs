@@ -145,8 +145,10 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(Origin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(Origin::signed(1), 0));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
@@ -165,8 +167,10 @@ fn lifecycle_should_work() {
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 2);
 
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::destroy(Origin::root(), 0, w));
+		assert_ok!(Assets::start_destroy(Origin::root(), 0));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		assert_ok!(Assets::destroy_approvals(Origin::signed(2), 0));
+		assert_ok!(Assets::finish_destroy(Origin::signed(2), 0));
 		assert_eq!(Balances::reserved_balance(&1), 0);
 
 		assert!(!Asset::<Test>::contains_key(0));
@@ -176,13 +180,51 @@ fn lifecycle_should_work() {
 }
 
 #[test]
-fn destroy_with_bad_witness_should_not_work() {
+fn destroying_in_stages_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
 		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
-		assert_noop!(Assets::destroy(Origin::signed(1), 0, w), Error::<Test>::BadWitness);
+		for who in 10..17 {
+			assert_ok!(Assets::mint(Origin::signed(1), 0, who, 100));
+		}
+		Balances::make_free_balance_be(&10, 100);
+		Balances::make_free_balance_be(&11, 100);
+		assert_ok!(Assets::approve_transfer(Origin::signed(10), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(11), 0, 2, 50));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 2);
+
+		assert_noop!(Assets::destroy_accounts(Origin::signed(1), 0), Error::<Test>::IncorrectStatus);
+		assert_noop!(Assets::finish_destroy(Origin::signed(1), 0), Error::<Test>::IncorrectStatus);
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_noop!(Assets::start_destroy(Origin::signed(1), 0), Error::<Test>::AssetNotLive);
+
+		// The asset can no longer be used.
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 10, 100), TokenError::UnknownAsset);
+		assert_noop!(Assets::transfer(Origin::signed(10), 0, 11, 50), Error::<Test>::AssetNotLive);
+		let e = Error::<Test>::AssetNotLive;
+		assert_noop!(Assets::approve_transfer(Origin::signed(10), 0, 3, 50), e);
+		assert_noop!(Assets::thaw_asset(Origin::signed(1), 0), Error::<Test>::AssetNotLive);
+
+		// At most `RemoveItemsLimit` accounts are removed at a time.
+		assert_noop!(Assets::finish_destroy(Origin::signed(1), 0), Error::<Test>::InUse);
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::AccountsDestroyed(0, 5, 2)));
+		assert_ok!(Assets::destroy_accounts(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::AccountsDestroyed(0, 2, 0)));
+		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
+
+		// The approval deposits are returned.
+		assert_noop!(Assets::finish_destroy(Origin::signed(1), 0), Error::<Test>::InUse);
+		assert_eq!(Balances::reserved_balance(&10), 1);
+		assert_ok!(Assets::destroy_approvals(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::ApprovalsDestroyed(0, 2, 0)));
+		assert_eq!(Balances::reserved_balance(&10), 0);
+		assert_eq!(Balances::reserved_balance(&11), 0);
+
+		assert_ok!(Assets::finish_destroy(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::Assets(crate::Event::Destroyed(0)));
+		assert!(!Asset::<Test>::contains_key(0));
+		assert_eq!(Approvals::<Test>::iter_prefix((0,)).count(), 0);
 	});
 }
 
@@ -318,8 +360,7 @@ fn origin_guards_should_work() {
 		assert_noop!(Assets::mint(Origin::signed(2), 0, 2, 100), Error::<Test>::NoPermission);
 		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 100), Error::<Test>::NoPermission);
 		assert_noop!(Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100), Error::<Test>::NoPermission);
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_noop!(Assets::destroy(Origin::signed(2), 0, w), Error::<Test>::NoPermission);
+		assert_noop!(Assets::start_destroy(Origin::signed(2), 0), Error::<Test>::NoPermission);
 	});
 }
 
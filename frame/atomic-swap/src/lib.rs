@@ -225,7 +225,9 @@ pub mod pallet {
 		/// - `duration`: Locked duration of the atomic swap. For safety reasons, it is recommended
 		///   that the revealer uses a shorter duration than the counterparty, to prevent the
 		///   situation where the revealer reveals the proof too late around the end block.
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000))]
+		#[pallet::weight(
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(Weight::from_ref_time(40_000_000))
+		)]
 		pub(crate) fn create_swap(
 			origin: OriginFor<T>,
 			target: T::AccountId,
@@ -264,8 +266,8 @@ pub mod pallet {
 		///   the operation fails. This is used for weight calculation.
 		#[pallet::weight(
 			T::DbWeight::get().reads_writes(1, 1)
-				.saturating_add(40_000_000)
-				.saturating_add((proof.len() as Weight).saturating_mul(100))
+				.saturating_add(Weight::from_ref_time(40_000_000))
+				.saturating_add(Weight::from_ref_time(100).saturating_mul(proof.len() as u64))
 				.saturating_add(action.weight())
 		)]
 		pub(crate) fn claim_swap(
@@ -302,7 +304,9 @@ pub mod pallet {
 		///
 		/// - `target`: Target of the original atomic swap.
 		/// - `hashed_proof`: Hashed proof of the original atomic swap.
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000))]
+		#[pallet::weight(
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(Weight::from_ref_time(40_000_000))
+		)]
 		pub(crate) fn cancel_swap(
 			origin: OriginFor<T>,
 			target: T::AccountId,
@@ -28,7 +28,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -62,6 +64,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = Event;
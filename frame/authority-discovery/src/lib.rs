@@ -220,7 +220,9 @@ mod tests {
 		pub const UncleGenerations: u64 = 0;
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(
+				frame_support::weights::Weight::from_ref_time(1024),
+			);
 	}
 
 	impl frame_system::Config for Test {
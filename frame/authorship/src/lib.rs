@@ -177,7 +177,7 @@ pub mod pallet {
 
 			T::EventHandler::note_author(Self::author());
 
-			0
+			Weight::zero()
 		}
 
 		fn on_finalize(_: T::BlockNumber) {
@@ -441,7 +441,9 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(
+				frame_support::weights::Weight::from_ref_time(1024),
+			);
 	}
 
 	impl frame_system::Config for Test {
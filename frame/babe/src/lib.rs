@@ -323,7 +323,7 @@ pub mod pallet {
 		/// Initialization
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
 			Self::do_initialize(now);
-			0
+			Weight::zero()
 		}
 
 		/// Block finalization
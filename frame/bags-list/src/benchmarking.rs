@@ -0,0 +1,80 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Benchmarks for the bags list pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelist_account};
+use frame_system::RawOrigin;
+
+use crate::Pallet as BagsList;
+
+const SEED: u32 = 0;
+
+/// Insert `name` into the list with the vote weight `weight`.
+fn insert_with_weight<T: Config>(name: &'static str, weight: VoteWeight) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	T::VoteWeightProvider::set_vote_weight_of(&who, weight);
+	assert!(List::<T>::insert(who.clone(), weight).is_ok());
+	who
+}
+
+benchmarks! {
+	rebag {
+		// the worst case is moving a node out of the middle of a bag, to the end of another
+		// non-empty bag.
+		let origin_weight = T::BagThresholds::get().first().copied().unwrap_or(VoteWeight::MAX);
+		let dest_weight = VoteWeight::MAX;
+
+		let _origin_head = insert_with_weight::<T>("origin_head", origin_weight);
+		let origin_middle = insert_with_weight::<T>("origin_middle", origin_weight);
+		let _origin_tail = insert_with_weight::<T>("origin_tail", origin_weight);
+		let _dest_head = insert_with_weight::<T>("dest_head", dest_weight);
+
+		T::VoteWeightProvider::set_vote_weight_of(&origin_middle, dest_weight);
+		let caller: T::AccountId = account("caller", 0, SEED);
+		whitelist_account!(caller);
+	}: _(RawOrigin::Signed(caller), origin_middle.clone())
+	verify {
+		assert_eq!(
+			List::<T>::get_node(&origin_middle).map(|node| node.bag_upper),
+			Some(notional_bag_for::<T>(dest_weight)),
+		);
+	}
+
+	put_in_front_of {
+		// the worst case is moving the tail of a bag in front of its head.
+		let weight = T::BagThresholds::get().first().copied().unwrap_or(VoteWeight::MAX);
+
+		let lighter = insert_with_weight::<T>("lighter", weight);
+		let _middle = insert_with_weight::<T>("middle", weight);
+		let heavier = insert_with_weight::<T>("heavier", weight);
+
+		T::VoteWeightProvider::set_vote_weight_of(&lighter, weight.saturating_sub(1));
+		whitelist_account!(heavier);
+	}: _(RawOrigin::Signed(heavier.clone()), lighter.clone())
+	verify {
+		assert_eq!(List::<T>::get_node(&heavier).and_then(|node| node.next), Some(lighter));
+	}
+}
+
+impl_benchmark_test_suite!(
+	BagsList,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
@@ -0,0 +1,257 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Bags List Pallet
+//!
+//! A semi-sorted list of accounts, where the accounts are put into bags according to their vote
+//! weight.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Each bag covers a range of vote weights, delimited by the thresholds of
+//! [`Config::BagThresholds`]. Within a bag, the accounts are kept in a doubly linked list in
+//! insertion order. Iterating the bags from the heaviest to the lightest thus yields the accounts
+//! roughly sorted by vote weight, without ever reading the whole list, which makes the pallet
+//! suitable to pick the top voters of an election.
+//!
+//! The pallet implements [`SortedListProvider`], through which another pallet, e.g. staking,
+//! inserts, updates and removes the accounts. The vote weights themselves are read from
+//! [`Config::VoteWeightProvider`].
+//!
+//! An account whose vote weight changed without the pallet being notified, e.g. because of a
+//! slash or a reward, can be moved to its correct bag by anyone with [`Pallet::rebag`]. An
+//! account can also move itself in front of a lighter account of its bag with
+//! [`Pallet::put_in_front_of`], so that the order within a bag gets closer to the actual order of
+//! the vote weights.
+//!
+//! ## Choosing the thresholds
+//!
+//! The thresholds should be sorted in increasing order, and roughly follow a geometric progression
+//! between the existential deposit and the total issuance, so that each bag covers a similar
+//! relative range of vote weights. The more thresholds, the more accurate the order of the list,
+//! at the cost of more storage. A bag with the threshold `VoteWeight::MAX` is implied.
+//!
+//! ## Migrating
+//!
+//! Adding this pallet to a running chain requires populating it once with
+//! [`SortedListProvider::regenerate`] in a runtime upgrade, which is what
+//! `pallet_staking::migrations::InjectNominatorsIntoSortedListProvider` does with the nominators.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod list;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_election_provider_support::{SortedListProvider, VoteWeight, VoteWeightProvider};
+use list::List;
+use sp_std::prelude::*;
+pub use list::{notional_bag_for, Bag, ListError, Node};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub(crate) const LOG_TARGET: &'static str = "runtime::bags_list";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The source of truth of the vote weights of the accounts.
+		type VoteWeightProvider: VoteWeightProvider<Self::AccountId>;
+
+		/// The upper vote weight thresholds of the bags, in increasing order.
+		///
+		/// An account belongs to the bag of the first threshold which is at least its vote weight.
+		/// The accounts heavier than all the thresholds belong to an implied last bag of threshold
+		/// `VoteWeight::MAX`.
+		///
+		/// Changing the thresholds requires a migration of the accounts which are not in the right
+		/// bag anymore, until then they stay in their bag, and can be moved with
+		/// [`Pallet::rebag`].
+		#[pallet::constant]
+		type BagThresholds: Get<&'static [VoteWeight]>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config> {
+		/// An account was moved from one bag to another. \[who, from, to\]
+		Rebagged(T::AccountId, VoteWeight, VoteWeight),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not in the list.
+		IdNotFound,
+		/// The accounts are not in the same bag.
+		NotInSameBag,
+		/// The account to put in front is not heavier than the other one.
+		NotHeavier,
+	}
+
+	/// The number of accounts in the list.
+	#[pallet::storage]
+	pub type CounterForListNodes<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The nodes of the list, by account.
+	#[pallet::storage]
+	pub type ListNodes<T: Config> = StorageMap<
+		_,
+		Twox64Concat, T::AccountId,
+		Node<T::AccountId>,
+	>;
+
+	/// The non-empty bags of the list, by upper vote weight threshold.
+	#[pallet::storage]
+	pub type ListBags<T: Config> = StorageMap<
+		_,
+		Twox64Concat, VoteWeight,
+		Bag<T::AccountId>,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			let thresholds = T::BagThresholds::get();
+			assert!(
+				thresholds.windows(2).all(|window| window[0] < window[1]),
+				"the bag thresholds must be strictly increasing",
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Move `dislocated` into the bag matching its current vote weight.
+		///
+		/// Anyone can call this, e.g. after the vote weight of `dislocated` changed without the
+		/// list being notified. Does nothing if `dislocated` is already in the right bag.
+		#[pallet::weight(T::WeightInfo::rebag())]
+		pub fn rebag(origin: OriginFor<T>, dislocated: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let weight = T::VoteWeightProvider::vote_weight(&dislocated);
+			Self::do_rebag(&dislocated, weight).ok_or(Error::<T>::IdNotFound)?;
+			Ok(())
+		}
+
+		/// Move the caller right in front of `lighter`, in their common bag.
+		///
+		/// The caller must be strictly heavier than `lighter`, according to
+		/// [`Config::VoteWeightProvider`].
+		#[pallet::weight(T::WeightInfo::put_in_front_of())]
+		pub fn put_in_front_of(origin: OriginFor<T>, lighter: T::AccountId) -> DispatchResult {
+			let heavier = ensure_signed(origin)?;
+			ensure!(
+				T::VoteWeightProvider::vote_weight(&heavier) >
+					T::VoteWeightProvider::vote_weight(&lighter),
+				Error::<T>::NotHeavier,
+			);
+			List::<T>::put_in_front_of(&lighter, &heavier).map_err(|e| match e {
+				ListError::NotInSameBag => Error::<T>::NotInSameBag,
+				ListError::Duplicate | ListError::NodeNotFound => Error::<T>::IdNotFound,
+			})?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Move `account` into the bag matching `new_weight`, if it is not in it already.
+	///
+	/// Returns `None` if `account` is not in the list, and the upper thresholds of the old and of
+	/// the new bag if it was moved.
+	pub fn do_rebag(
+		account: &T::AccountId,
+		new_weight: VoteWeight,
+	) -> Option<Option<(VoteWeight, VoteWeight)>> {
+		let node = List::<T>::get_node(account)?;
+		let moved = List::<T>::update_position_for(node, new_weight);
+		if let Some((from, to)) = moved {
+			Self::deposit_event(Event::<T>::Rebagged(account.clone(), from, to));
+		}
+		Some(moved)
+	}
+}
+
+impl<T: Config> SortedListProvider<T::AccountId> for Pallet<T> {
+	type Error = ListError;
+
+	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
+		Box::new(List::<T>::iter())
+	}
+
+	fn count() -> u32 {
+		List::<T>::count()
+	}
+
+	fn contains(id: &T::AccountId) -> bool {
+		List::<T>::contains(id)
+	}
+
+	fn on_insert(id: T::AccountId, weight: VoteWeight) -> Result<(), ListError> {
+		List::<T>::insert(id, weight)
+	}
+
+	fn on_update(id: &T::AccountId, weight: VoteWeight) {
+		if Self::do_rebag(id, weight).is_none() {
+			log::warn!(target: LOG_TARGET, "updating the vote weight of {:?}, not in the list", id);
+		}
+	}
+
+	fn on_remove(id: &T::AccountId) {
+		List::<T>::remove(id)
+	}
+
+	fn regenerate(
+		all: impl IntoIterator<Item = T::AccountId>,
+		weight_of: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
+	) -> u32 {
+		List::<T>::regenerate(all, weight_of)
+	}
+
+	fn clear() {
+		List::<T>::clear()
+	}
+
+	fn sanity_check() -> Result<(), &'static str> {
+		List::<T>::sanity_check()
+	}
+}
@@ -0,0 +1,329 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The semi-sorted list of ids, partitioned into bags of vote weight.
+//!
+//! A bag is identified by its upper vote weight threshold, and contains the ids whose vote weight
+//! is higher than the threshold of the previous bag, and lower than or equal to its own threshold.
+//! The ids of a bag are kept in a doubly linked list, in no particular order. The bags themselves
+//! are ordered by their thresholds, which is what makes the whole list semi-sorted.
+//!
+//! All the functions of this module keep [`ListNodes`], [`ListBags`] and [`CounterForListNodes`]
+//! consistent with each other, they should be the only ones writing to them.
+
+use crate::{Config, CounterForListNodes, ListBags, ListNodes};
+use codec::{Decode, Encode};
+use frame_election_provider_support::VoteWeight;
+use frame_support::{traits::Get, RuntimeDebug};
+use sp_std::{
+	collections::btree_set::BTreeSet,
+	iter,
+	marker::PhantomData,
+	prelude::*,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The errors of the list operations.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ListError {
+	/// The id is already in the list.
+	Duplicate,
+	/// The id is not in the list.
+	NodeNotFound,
+	/// The two ids are not in the same bag.
+	NotInSameBag,
+}
+
+/// The upper threshold of the bag in which an id of vote weight `weight` belongs.
+///
+/// This is the first threshold of [`Config::BagThresholds`] which is at least `weight`, or
+/// `VoteWeight::MAX` if `weight` is above all the thresholds.
+pub fn notional_bag_for<T: Config>(weight: VoteWeight) -> VoteWeight {
+	let thresholds = T::BagThresholds::get();
+	let index = match thresholds.binary_search(&weight) {
+		Ok(index) | Err(index) => index,
+	};
+	thresholds.get(index).copied().unwrap_or(VoteWeight::MAX)
+}
+
+/// A bag of the list.
+///
+/// Only the ends of the linked list of the bag are stored, the links are in the [`Node`]s.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Bag<AccountId> {
+	/// The first id of the bag.
+	pub head: Option<AccountId>,
+	/// The last id of the bag.
+	pub tail: Option<AccountId>,
+}
+
+impl<AccountId> Default for Bag<AccountId> {
+	fn default() -> Self {
+		Self { head: None, tail: None }
+	}
+}
+
+/// A node of the list, i.e. an id and its links to its neighbours within its bag.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Node<AccountId> {
+	/// The id of the node.
+	pub id: AccountId,
+	/// The previous id of the bag, if any.
+	pub prev: Option<AccountId>,
+	/// The next id of the bag, if any.
+	pub next: Option<AccountId>,
+	/// The upper threshold of the bag of the node.
+	pub bag_upper: VoteWeight,
+}
+
+impl<AccountId> Node<AccountId> {
+	/// Whether the node is not in the bag matching `current_weight`.
+	pub fn is_misplaced<T: Config>(&self, current_weight: VoteWeight) -> bool {
+		notional_bag_for::<T>(current_weight) != self.bag_upper
+	}
+}
+
+/// The operations on the list, over the storage of the pallet.
+pub struct List<T: Config>(PhantomData<T>);
+
+impl<T: Config> List<T> {
+	/// Whether `id` is in the list.
+	pub fn contains(id: &T::AccountId) -> bool {
+		ListNodes::<T>::contains_key(id)
+	}
+
+	/// The node of `id`, if it is in the list.
+	pub fn get_node(id: &T::AccountId) -> Option<Node<T::AccountId>> {
+		ListNodes::<T>::get(id)
+	}
+
+	/// The number of ids in the list.
+	pub fn count() -> u32 {
+		CounterForListNodes::<T>::get()
+	}
+
+	/// Iterate over the ids of the list, from the heaviest bag to the lightest.
+	///
+	/// The ids of a bag are iterated in the order of the bag.
+	pub fn iter() -> impl Iterator<Item = T::AccountId> {
+		let thresholds = T::BagThresholds::get();
+		let uppers = thresholds.iter().copied();
+		// the last bag has no threshold, unless the thresholds already end with it.
+		let last = if thresholds.last() == Some(&VoteWeight::MAX) {
+			None
+		} else {
+			Some(VoteWeight::MAX)
+		};
+		uppers
+			.chain(last)
+			.rev()
+			.filter_map(ListBags::<T>::get)
+			.flat_map(|bag| Self::iter_bag(bag))
+	}
+
+	/// Iterate over the ids of `bag`, in order.
+	fn iter_bag(bag: Bag<T::AccountId>) -> impl Iterator<Item = T::AccountId> {
+		iter::successors(
+			bag.head.and_then(|head| ListNodes::<T>::get(&head)),
+			|node| node.next.as_ref().and_then(|next| ListNodes::<T>::get(next)),
+		)
+		.map(|node| node.id)
+	}
+
+	/// Insert `id` of vote weight `weight` at the end of its bag.
+	pub fn insert(id: T::AccountId, weight: VoteWeight) -> Result<(), ListError> {
+		if Self::contains(&id) {
+			return Err(ListError::Duplicate)
+		}
+
+		Self::insert_into_bag(id, notional_bag_for::<T>(weight));
+		CounterForListNodes::<T>::mutate(|count| *count = count.saturating_add(1));
+		Ok(())
+	}
+
+	/// Remove `id` from the list. Does nothing if it is not in the list.
+	pub fn remove(id: &T::AccountId) {
+		if let Some(node) = ListNodes::<T>::take(id) {
+			Self::excise(&node);
+			CounterForListNodes::<T>::mutate(|count| *count = count.saturating_sub(1));
+		}
+	}
+
+	/// Move `node` into the bag matching `new_weight`, if it is not in it already.
+	///
+	/// Returns the upper thresholds of the old and of the new bag if the node was moved.
+	pub fn update_position_for(
+		node: Node<T::AccountId>,
+		new_weight: VoteWeight,
+	) -> Option<(VoteWeight, VoteWeight)> {
+		if !node.is_misplaced::<T>(new_weight) {
+			return None
+		}
+
+		let old_bag_upper = node.bag_upper;
+		let new_bag_upper = notional_bag_for::<T>(new_weight);
+		Self::excise(&node);
+		Self::insert_into_bag(node.id, new_bag_upper);
+		Some((old_bag_upper, new_bag_upper))
+	}
+
+	/// Move `heavier` right in front of `lighter`, which must both be in the same bag.
+	///
+	/// It is up to the caller to check that `heavier` is indeed heavier than `lighter`.
+	pub fn put_in_front_of(
+		lighter: &T::AccountId,
+		heavier: &T::AccountId,
+	) -> Result<(), ListError> {
+		let lighter_node = ListNodes::<T>::get(lighter).ok_or(ListError::NodeNotFound)?;
+		let heavier_node = ListNodes::<T>::get(heavier).ok_or(ListError::NodeNotFound)?;
+		if lighter_node.bag_upper != heavier_node.bag_upper {
+			return Err(ListError::NotInSameBag)
+		}
+
+		Self::excise(&heavier_node);
+
+		// excising `heavier` may have changed the links of `lighter`, so it is read again.
+		let mut lighter_node = ListNodes::<T>::get(lighter).ok_or(ListError::NodeNotFound)?;
+		let bag_upper = lighter_node.bag_upper;
+		let heavier_node = Node {
+			id: heavier.clone(),
+			prev: lighter_node.prev.clone(),
+			next: Some(lighter.clone()),
+			bag_upper,
+		};
+		if let Some(prev) = &lighter_node.prev {
+			Self::set_next(prev, Some(heavier.clone()));
+		}
+		lighter_node.prev = Some(heavier.clone());
+
+		let mut bag = ListBags::<T>::get(bag_upper).unwrap_or_default();
+		if bag.head.as_ref() == Some(lighter) {
+			bag.head = Some(heavier.clone());
+		}
+
+		ListNodes::<T>::insert(lighter, lighter_node);
+		ListNodes::<T>::insert(heavier, heavier_node);
+		ListBags::<T>::insert(bag_upper, bag);
+		Ok(())
+	}
+
+	/// Remove all the ids from the list and insert the ids of `all` instead.
+	///
+	/// Returns the number of ids inserted.
+	pub fn regenerate(
+		all: impl IntoIterator<Item = T::AccountId>,
+		weight_of: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
+	) -> u32 {
+		Self::clear();
+		all.into_iter()
+			.filter(|id| Self::insert(id.clone(), weight_of(id)).is_ok())
+			.count() as u32
+	}
+
+	/// Remove all the ids from the list.
+	pub fn clear() {
+		ListNodes::<T>::remove_all();
+		ListBags::<T>::remove_all();
+		CounterForListNodes::<T>::kill();
+	}
+
+	/// Check the consistency of the list.
+	///
+	/// Every node must be reachable from its bag exactly once, the bag ends must have no outer
+	/// links, and the counter must match the number of nodes.
+	pub fn sanity_check() -> Result<(), &'static str> {
+		let mut seen = BTreeSet::new();
+		for (bag_upper, bag) in ListBags::<T>::iter() {
+			if bag.head.is_none() || bag.tail.is_none() {
+				return Err("empty bag in storage")
+			}
+			let head = bag.head.as_ref().and_then(|head| ListNodes::<T>::get(head));
+			if head.map_or(true, |head| head.prev.is_some()) {
+				return Err("bag head is missing or has a previous node")
+			}
+			let tail = bag.tail.as_ref().and_then(|tail| ListNodes::<T>::get(tail));
+			if tail.map_or(true, |tail| tail.next.is_some()) {
+				return Err("bag tail is missing or has a next node")
+			}
+			for id in Self::iter_bag(bag) {
+				if ListNodes::<T>::get(&id).map(|node| node.bag_upper) != Some(bag_upper) {
+					return Err("node is in the wrong bag")
+				}
+				if !seen.insert(id) {
+					return Err("node is in the list twice")
+				}
+			}
+		}
+
+		let count = Self::count() as usize;
+		if seen.len() != count || ListNodes::<T>::iter().count() != count {
+			return Err("the counter does not match the number of nodes")
+		}
+		Ok(())
+	}
+
+	/// Insert `id` at the end of the bag of upper threshold `bag_upper`.
+	///
+	/// `id` must not be in any bag.
+	fn insert_into_bag(id: T::AccountId, bag_upper: VoteWeight) {
+		let mut bag = ListBags::<T>::get(bag_upper).unwrap_or_default();
+		let node = Node { id: id.clone(), prev: bag.tail.clone(), next: None, bag_upper };
+
+		match &bag.tail {
+			Some(tail) => Self::set_next(tail, Some(id.clone())),
+			None => bag.head = Some(id.clone()),
+		}
+		bag.tail = Some(id.clone());
+
+		ListNodes::<T>::insert(&id, node);
+		ListBags::<T>::insert(bag_upper, bag);
+	}
+
+	/// Unlink `node` from its neighbours and its bag, without removing it from [`ListNodes`].
+	fn excise(node: &Node<T::AccountId>) {
+		if let Some(prev) = &node.prev {
+			Self::set_next(prev, node.next.clone());
+		}
+		if let Some(next) = &node.next {
+			ListNodes::<T>::mutate(next, |next| if let Some(next) = next {
+				next.prev = node.prev.clone();
+			});
+		}
+
+		let mut bag = ListBags::<T>::get(node.bag_upper).unwrap_or_default();
+		if bag.head.as_ref() == Some(&node.id) {
+			bag.head = node.next.clone();
+		}
+		if bag.tail.as_ref() == Some(&node.id) {
+			bag.tail = node.prev.clone();
+		}
+		if bag.head.is_none() {
+			ListBags::<T>::remove(node.bag_upper);
+		} else {
+			ListBags::<T>::insert(node.bag_upper, bag);
+		}
+	}
+
+	/// Set the next id of the node of `id`.
+	fn set_next(id: &T::AccountId, next: Option<T::AccountId>) {
+		ListNodes::<T>::mutate(id, |node| if let Some(node) = node {
+			node.next = next;
+		});
+	}
+}
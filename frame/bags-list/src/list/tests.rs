@@ -0,0 +1,185 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::mock::*;
+use frame_support::assert_storage_noop;
+
+#[test]
+fn notional_bag_for_works() {
+	assert_eq!(notional_bag_for::<Test>(0), 10);
+	assert_eq!(notional_bag_for::<Test>(10), 10);
+	assert_eq!(notional_bag_for::<Test>(11), 20);
+	assert_eq!(notional_bag_for::<Test>(999), 1_000);
+	assert_eq!(notional_bag_for::<Test>(10_000), 10_000);
+	assert_eq!(notional_bag_for::<Test>(10_001), VoteWeight::MAX);
+	assert_eq!(notional_bag_for::<Test>(VoteWeight::MAX), VoteWeight::MAX);
+}
+
+#[test]
+fn setup_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+		assert_eq!(get_list_as_ids(), vec![2, 3, 4, 1]);
+		assert_eq!(List::<Test>::count(), 4);
+		assert_eq!(
+			List::<Test>::get_node(&3),
+			Some(Node { id: 3, prev: Some(2), next: Some(4), bag_upper: 1_000 }),
+		);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn insert_works() {
+	new_test_ext().execute_with(|| {
+		// into a new bag.
+		assert_eq!(List::<Test>::insert(5, 15), Ok(()));
+		// at the end of an existing bag.
+		assert_eq!(List::<Test>::insert(6, 1_000), Ok(()));
+		// above all the thresholds.
+		assert_eq!(List::<Test>::insert(7, 20_000), Ok(()));
+
+		assert_eq!(
+			bag_as_ids(),
+			vec![
+				(10, vec![1]),
+				(20, vec![5]),
+				(1_000, vec![2, 3, 4, 6]),
+				(VoteWeight::MAX, vec![7]),
+			],
+		);
+		assert_eq!(get_list_as_ids(), vec![7, 2, 3, 4, 6, 5, 1]);
+		assert_eq!(List::<Test>::count(), 7);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+
+		assert_storage_noop!(assert_eq!(List::<Test>::insert(3, 20), Err(ListError::Duplicate)));
+	});
+}
+
+#[test]
+fn remove_works() {
+	new_test_ext().execute_with(|| {
+		// from the middle of a bag.
+		List::<Test>::remove(&3);
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![2, 4])]);
+		assert_eq!(List::<Test>::get_node(&3), None);
+
+		// from both ends of a bag.
+		List::<Test>::remove(&2);
+		List::<Test>::remove(&4);
+		assert_eq!(bag_as_ids(), vec![(10, vec![1])]);
+
+		// the last one of the list.
+		List::<Test>::remove(&1);
+		assert!(bag_as_ids().is_empty());
+		assert_eq!(List::<Test>::count(), 0);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+
+		// unknown ids are ignored.
+		assert_storage_noop!(List::<Test>::remove(&42));
+	});
+}
+
+#[test]
+fn update_position_for_works() {
+	new_test_ext().execute_with(|| {
+		// still in the right bag.
+		let node = List::<Test>::get_node(&3).unwrap();
+		assert_storage_noop!(assert_eq!(List::<Test>::update_position_for(node, 600), None));
+
+		// to a heavier bag.
+		let node = List::<Test>::get_node(&3).unwrap();
+		assert_eq!(List::<Test>::update_position_for(node, 1_500), Some((1_000, 2_000)));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![2, 4]), (2_000, vec![3])]);
+
+		// to a lighter, existing bag.
+		let node = List::<Test>::get_node(&2).unwrap();
+		assert_eq!(List::<Test>::update_position_for(node, 5), Some((1_000, 10)));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1, 2]), (1_000, vec![4]), (2_000, vec![3])]);
+
+		assert_eq!(get_list_as_ids(), vec![3, 4, 1, 2]);
+		assert_eq!(List::<Test>::count(), 4);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn put_in_front_of_works() {
+	new_test_ext().execute_with(|| {
+		// the tail in front of the head.
+		assert_eq!(List::<Test>::put_in_front_of(&2, &4), Ok(()));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![4, 2, 3])]);
+
+		// next to each other.
+		assert_eq!(List::<Test>::put_in_front_of(&2, &3), Ok(()));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![4, 3, 2])]);
+
+		// already in front.
+		assert_eq!(List::<Test>::put_in_front_of(&2, &3), Ok(()));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![4, 3, 2])]);
+
+		// the head behind the tail.
+		assert_eq!(List::<Test>::put_in_front_of(&3, &4), Ok(()));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![4, 3, 2])]);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn put_in_front_of_fails_across_bags() {
+	new_test_ext().execute_with(|| {
+		assert_storage_noop!(assert_eq!(
+			List::<Test>::put_in_front_of(&1, &2),
+			Err(ListError::NotInSameBag),
+		));
+		assert_storage_noop!(assert_eq!(
+			List::<Test>::put_in_front_of(&42, &2),
+			Err(ListError::NodeNotFound),
+		));
+	});
+}
+
+#[test]
+fn regenerate_and_clear_work() {
+	new_test_ext().execute_with(|| {
+		let weight_of = Box::new(|id: &AccountId| *id * 10);
+		assert_eq!(List::<Test>::regenerate(vec![1, 5, 6, 6, 200], weight_of), 4);
+		assert_eq!(
+			bag_as_ids(),
+			vec![(10, vec![1]), (50, vec![5]), (60, vec![6]), (2_000, vec![200])],
+		);
+		assert_eq!(List::<Test>::count(), 4);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+
+		List::<Test>::clear();
+		assert!(bag_as_ids().is_empty());
+		assert!(get_list_as_ids().is_empty());
+		assert_eq!(List::<Test>::count(), 0);
+	});
+}
+
+#[test]
+fn sanity_check_detects_corruption() {
+	new_test_ext().execute_with(|| {
+		CounterForListNodes::<Test>::put(5);
+		assert!(List::<Test>::sanity_check().is_err());
+		CounterForListNodes::<Test>::put(4);
+
+		ListNodes::<Test>::mutate(&3, |node| node.as_mut().unwrap().bag_upper = 2_000);
+		assert!(List::<Test>::sanity_check().is_err());
+	});
+}
@@ -0,0 +1,144 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use super::*;
+use crate as pallet_bags_list;
+use frame_support::parameter_types;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::{BlakeTwo256, IdentityLookup}};
+use std::{cell::RefCell, collections::HashMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		BagsList: pallet_bags_list::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+thread_local! {
+	static VOTE_WEIGHTS: RefCell<HashMap<AccountId, VoteWeight>> = RefCell::new(HashMap::new());
+}
+
+/// Vote weights kept in memory, 0 for the unknown accounts.
+pub struct StakingMock;
+impl StakingMock {
+	pub fn set_vote_weight(who: AccountId, weight: VoteWeight) {
+		VOTE_WEIGHTS.with(|weights| weights.borrow_mut().insert(who, weight));
+	}
+}
+impl VoteWeightProvider<AccountId> for StakingMock {
+	fn vote_weight(who: &AccountId) -> VoteWeight {
+		VOTE_WEIGHTS.with(|weights| weights.borrow().get(who).copied().unwrap_or_default())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_vote_weight_of(who: &AccountId, weight: VoteWeight) {
+		Self::set_vote_weight(*who, weight)
+	}
+}
+
+pub const THRESHOLDS: [VoteWeight; 9] = [10, 20, 30, 40, 50, 60, 1_000, 2_000, 10_000];
+
+parameter_types! {
+	pub const BagThresholds: &'static [VoteWeight] = &THRESHOLDS;
+}
+
+impl Config for Test {
+	type Event = Event;
+	type VoteWeightProvider = StakingMock;
+	type BagThresholds = BagThresholds;
+	type WeightInfo = ();
+}
+
+/// The accounts of the list at genesis, with their vote weights.
+pub const GENESIS_IDS: [(AccountId, VoteWeight); 4] = [(1, 10), (2, 1_000), (3, 1_000), (4, 1_000)];
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for (id, weight) in GENESIS_IDS.iter() {
+			StakingMock::set_vote_weight(*id, *weight);
+			assert_eq!(List::<Test>::insert(*id, *weight), Ok(()));
+		}
+	});
+	ext
+}
+
+/// The ids of each non-empty bag, from the lightest bag to the heaviest.
+pub fn bag_as_ids() -> Vec<(VoteWeight, Vec<AccountId>)> {
+	let mut bags = ListBags::<Test>::iter()
+		.map(|(bag_upper, bag)| {
+			let mut ids = Vec::new();
+			let mut next = bag.head;
+			while let Some(id) = next {
+				ids.push(id);
+				next = ListNodes::<Test>::get(id).unwrap().next;
+			}
+			(bag_upper, ids)
+		})
+		.collect::<Vec<_>>();
+	bags.sort();
+	bags
+}
+
+/// The ids of the list, in iteration order.
+pub fn get_list_as_ids() -> Vec<AccountId> {
+	List::<Test>::iter().collect()
+}
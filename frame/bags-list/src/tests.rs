@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, assert_storage_noop};
+
+#[test]
+fn rebag_works() {
+	new_test_ext().execute_with(|| {
+		// the vote weight of 3 increased without the list being notified.
+		StakingMock::set_vote_weight(3, 1_500);
+		assert_ok!(BagsList::rebag(Origin::signed(42), 3));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![2, 4]), (2_000, vec![3])]);
+		System::assert_last_event(mock::Event::BagsList(crate::Event::Rebagged(3, 1_000, 2_000)));
+
+		// nothing happens when the account is in the right bag already.
+		System::reset_events();
+		assert_storage_noop!(assert_ok!(BagsList::rebag(Origin::signed(42), 3)));
+		assert!(System::events().is_empty());
+
+		// the last account of a bag removes the bag.
+		StakingMock::set_vote_weight(1, 45);
+		assert_ok!(BagsList::rebag(Origin::signed(42), 1));
+		assert_eq!(bag_as_ids(), vec![(50, vec![1]), (1_000, vec![2, 4]), (2_000, vec![3])]);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn rebag_fails_for_unknown_accounts() {
+	new_test_ext().execute_with(|| {
+		StakingMock::set_vote_weight(42, 1_500);
+		assert_noop!(BagsList::rebag(Origin::signed(1), 42), Error::<Test>::IdNotFound);
+	});
+}
+
+#[test]
+fn put_in_front_of_works() {
+	new_test_ext().execute_with(|| {
+		StakingMock::set_vote_weight(4, 1_001);
+		assert_ok!(BagsList::put_in_front_of(Origin::signed(4), 2));
+		assert_eq!(bag_as_ids(), vec![(10, vec![1]), (1_000, vec![4, 2, 3])]);
+		assert_eq!(get_list_as_ids(), vec![4, 2, 3, 1]);
+		assert_eq!(List::<Test>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn put_in_front_of_fails_if_not_heavier() {
+	new_test_ext().execute_with(|| {
+		// same weight.
+		assert_noop!(BagsList::put_in_front_of(Origin::signed(4), 2), Error::<Test>::NotHeavier);
+		// itself.
+		assert_noop!(BagsList::put_in_front_of(Origin::signed(4), 4), Error::<Test>::NotHeavier);
+		// lighter.
+		StakingMock::set_vote_weight(4, 999);
+		assert_noop!(BagsList::put_in_front_of(Origin::signed(4), 2), Error::<Test>::NotHeavier);
+	});
+}
+
+#[test]
+fn put_in_front_of_fails_across_bags_or_outside_the_list() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(BagsList::put_in_front_of(Origin::signed(2), 1), Error::<Test>::NotInSameBag);
+
+		StakingMock::set_vote_weight(42, 2_000);
+		assert_noop!(BagsList::put_in_front_of(Origin::signed(42), 2), Error::<Test>::IdNotFound);
+	});
+}
+
+#[test]
+fn sorted_list_provider_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<BagsList as SortedListProvider<_>>::count(), 4);
+		assert!(<BagsList as SortedListProvider<_>>::contains(&1));
+		assert!(!<BagsList as SortedListProvider<_>>::contains(&5));
+
+		assert_ok!(<BagsList as SortedListProvider<_>>::on_insert(5, 15));
+		assert_eq!(
+			<BagsList as SortedListProvider<_>>::on_insert(5, 15),
+			Err(ListError::Duplicate),
+		);
+		<BagsList as SortedListProvider<_>>::on_update(&5, 3_000);
+		System::assert_last_event(mock::Event::BagsList(crate::Event::Rebagged(5, 20, 10_000)));
+		<BagsList as SortedListProvider<_>>::on_remove(&2);
+
+		assert_eq!(
+			<BagsList as SortedListProvider<_>>::iter().collect::<Vec<_>>(),
+			vec![5, 3, 4, 1],
+		);
+		assert_eq!(<BagsList as SortedListProvider<_>>::count(), 4);
+		assert_eq!(<BagsList as SortedListProvider<_>>::sanity_check(), Ok(()));
+	});
+}
+
+#[test]
+fn integrity_test_works() {
+	new_test_ext().execute_with(|| {
+		<BagsList as frame_support::traits::IntegrityTest>::integrity_test();
+	});
+}
@@ -144,6 +144,32 @@ benchmarks_instance_pallet! {
 		assert_eq!(Balances::<T, I>::free_balance(&recipient), transfer_amount);
 	}
 
+	// Benchmark `upgrade_accounts` with accounts which all have a lock to migrate, if the runtime
+	// replaces the benchmark lock by a freeze.
+	upgrade_accounts {
+		let u in 1 .. 1_000;
+		let caller = whitelisted_caller();
+		let balance = T::ExistentialDeposit::get().saturating_mul(ED_MULTIPLIER.into());
+		let who: Vec<T::AccountId> = (0 .. u).map(|i| {
+			let who = account("who", i, SEED);
+			let _ = <Balances<T, I> as Currency<_>>::make_free_balance_be(&who, balance);
+			<Balances<T, I> as LockableCurrency<_>>::set_lock(
+				*b"benchmrk",
+				&who,
+				balance,
+				WithdrawReasons::all(),
+			);
+			who
+		}).collect();
+	}: _(RawOrigin::Signed(caller), who.clone())
+	verify {
+		for who in who {
+			assert!(Balances::<T, I>::locks(&who)
+				.iter()
+				.all(|lock| LockMigrationOf::<T, I>::convert(lock.id).is_none()));
+		}
+	}
+
 	// This benchmark performs the same operation as `transfer` in the worst case scenario,
 	// but additionally introduces many new users into the storage, increasing the the merkle
 	// trie and PoV size.
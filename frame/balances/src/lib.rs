@@ -35,6 +35,7 @@
 //! - Account creation and removal.
 //! - Managing total issuance.
 //! - Setting and managing locks.
+//! - Placing funds on hold and freezing funds.
 //!
 //! ### Terminology
 //!
@@ -65,6 +66,14 @@
 //! - **Lock:** A freeze on a specified amount of an account's free balance until a specified block number. Multiple
 //! locks always operate over the same funds, so they "overlay" rather than "stack".
 //!
+//! - **Hold:** Some funds of an account set aside for a `HoldReason`, and which can only be released or
+//! transferred for the same reason. The held funds are part of the reserved balance.
+//!
+//! - **Freeze:** Like a lock, an amount below which the free balance may not drop, but identified by a
+//! `FreezeIdentifier` and in effect whatever the reasons of the withdrawal. The locks replaced by a freeze
+//! according to `HoldsAndFreezes::LockMigration` are migrated lazily, when the freezes of their account are
+//! first accessed, or through the `upgrade_accounts` call.
+//!
 //! ### Implementations
 //!
 //! The Balances pallet provides implementations for the following traits. If these traits provide the functionality
@@ -77,6 +86,11 @@
 //! Functions for dealing with assets that can be reserved from an account.
 //! - [`LockableCurrency`](frame_support::traits::LockableCurrency): Functions for
 //! dealing with accounts that allow liquidity restrictions.
+//! - [`fungible::MutateHold`](frame_support::traits::tokens::fungible::MutateHold):
+//! - [`fungible::MutateFreeze`](frame_support::traits::tokens::fungible::MutateFreeze):
+//! Functions for holding and freezing funds, which supersede `ReservableCurrency` and
+//! `LockableCurrency`. The latter are kept for compatibility: they do not know about holds, and
+//! unreserving funds may release held funds.
 //! - [`Imbalance`](frame_support::traits::Imbalance): Functions for handling
 //! imbalances between total issuance in the system and account balances. Must be used when a function
 //! creates new funds (e.g. a reward) or destroys some funds (e.g. a system fee).
@@ -87,6 +101,7 @@
 //!
 //! - `transfer` - Transfer some liquid free balance to another account.
 //! - `set_balance` - Set the balances of a given account. The origin of this call must be root.
+//! - `upgrade_accounts` - Migrate the locks of some accounts which are replaced by freezes.
 //!
 //! ## Usage
 //!
@@ -154,13 +169,14 @@ mod tests_local;
 mod tests_composite;
 mod tests_reentrancy;
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 use sp_std::prelude::*;
 use sp_std::{cmp, result, mem, fmt::Debug, ops::BitOr};
 use codec::{Codec, Encode, Decode};
 use frame_support::{
-	ensure, WeakBoundedVec,
+	ensure, BoundedVec, WeakBoundedVec, Parameter,
 	traits::{
 		Currency, OnUnbalanced, TryDrop, StoredMap, MaxEncodedLen,
 		WithdrawReasons, LockIdentifier, LockableCurrency, ExistenceRequirement,
@@ -174,8 +190,8 @@ use frame_support::traits::GenesisBuild;
 use sp_runtime::{
 	RuntimeDebug, DispatchResult, DispatchError, ArithmeticError,
 	traits::{
-		Zero, AtLeast32BitUnsigned, StaticLookup, CheckedAdd, CheckedSub,
-		MaybeSerializeDeserialize, Saturating, Bounded, StoredMapError,
+		Zero, AtLeast32BitUnsigned, StaticLookup, CheckedAdd, CheckedSub, Member,
+		MaybeSerializeDeserialize, Saturating, Bounded, StoredMapError, Convert,
 	},
 };
 use frame_system as system;
@@ -213,14 +229,23 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 
 		/// The maximum number of locks that should exist on an account.
-		/// Not strictly enforced, but used for weight estimation.
+		///
+		/// Creating a lock beyond it with `try_set_lock` or `try_extend_lock` fails with
+		/// `TooManyLocks`. It is not enforced by `set_lock` and `extend_lock`, which cannot fail,
+		/// but still used for weight estimation. Prefer freezes, which are strictly bounded by
+		/// `MaxFreezes`.
 		type MaxLocks: Get<u32>;
 
 		/// The maximum number of named reserves that can exist on an account.
+		///
+		/// Reserving under a new identifier beyond it fails with `TooManyReserves`.
 		type MaxReserves: Get<u32>;
 
 		/// The id type for named reserves.
 		type ReserveIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The configuration of the holds and freezes, `()` to disable them.
+		type HoldsAndFreezes: HoldsAndFreezes;
 	}
 
 	#[pallet::pallet]
@@ -369,6 +394,24 @@ pub mod pallet {
 			<Self as Currency<_>>::transfer(&transactor, &dest, value, KeepAlive)?;
 			Ok(().into())
 		}
+
+		/// Migrate the locks of the accounts `who` which are replaced by freezes, according to
+		/// `HoldsAndFreezes::LockMigration`.
+		///
+		/// The locks are otherwise migrated when the freezes of their account are first accessed.
+		///
+		/// The dispatch origin for this call must be `Signed`.
+		#[pallet::weight(T::WeightInfo::upgrade_accounts(who.len() as u32))]
+		pub fn upgrade_accounts(
+			origin: OriginFor<T>,
+			who: Vec<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			for who in who.iter() {
+				Self::migrate_locks(who)?;
+			}
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -418,6 +461,12 @@ pub mod pallet {
 		DeadAccount,
 		/// Number of named reserves exceed MaxReserves
 		TooManyReserves,
+		/// Number of locks exceed MaxLocks
+		TooManyLocks,
+		/// Number of holds exceed MaxHolds
+		TooManyHolds,
+		/// Number of freezes exceed MaxFreezes
+		TooManyFreezes,
 	}
 
 	/// The total units issued in the system.
@@ -464,9 +513,36 @@ pub mod pallet {
 		ValueQuery
 	>;
 
+	/// Holds on account balances.
+	///
+	/// The held funds are part of the reserved balance of the account.
+	#[pallet::storage]
+	#[pallet::getter(fn holds)]
+	pub type Holds<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<HoldReasonOf<T, I>, T::Balance>, MaxHoldsOf<T, I>>,
+		ValueQuery
+	>;
+
+	/// Freezes on account balances.
+	///
+	/// Like locks, freezes overlap and the free balance of the account may not drop below the
+	/// largest of them.
+	#[pallet::storage]
+	#[pallet::getter(fn freezes)]
+	pub type Freezes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<FreezeIdentifierOf<T, I>, T::Balance>, MaxFreezesOf<T, I>>,
+		ValueQuery
+	>;
+
 	/// Storage version of the pallet.
 	///
-	/// This is set to v2.0.0 for new networks.
+	/// This is set to v3.0.0 for new networks.
 	#[pallet::storage]
 	pub(super) type StorageVersion<T: Config<I>, I: 'static = ()> = StorageValue<
 		_,
@@ -496,7 +572,7 @@ pub mod pallet {
 				.fold(Zero::zero(), |acc: T::Balance, &(_, n)| acc + n);
 			<TotalIssuance<T, I>>::put(total);
 
-			<StorageVersion<T, I>>::put(Releases::V2_0_0);
+			<StorageVersion<T, I>>::put(Releases::V3_0_0);
 
 			for (_, balance) in &self.balances {
 				assert!(
@@ -589,6 +665,90 @@ pub struct ReserveData<ReserveIdentifier, Balance> {
 	pub amount: Balance,
 }
 
+/// The configuration of the holds and freezes of the accounts.
+///
+/// `()` disables them: no hold or freeze can be placed and no lock is migrated.
+pub trait HoldsAndFreezes {
+	/// The reason for which funds are placed on hold, usually an enum aggregating the hold
+	/// reasons of all the pallets of the runtime.
+	type HoldReason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+	/// The identifier of a freeze, usually an enum aggregating the freeze identifiers of all the
+	/// pallets of the runtime.
+	type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+	/// The maximum number of holds that can exist on an account.
+	type MaxHolds: Get<u32>;
+
+	/// The maximum number of freezes that can exist on an account.
+	///
+	/// When it is not zero, every update of the locks of an account also reads its freezes. That
+	/// read is part of the benchmarked weights of the pallets using `LockableCurrency`.
+	type MaxFreezes: Get<u32>;
+
+	/// The freeze replacing a lock, for the locks of the pallets which moved from
+	/// `LockableCurrency` to `fungible::MutateFreeze`.
+	///
+	/// The locks with a replacement are migrated to freezes lazily, when the freezes of their
+	/// account are first accessed, or through [`Pallet::upgrade_accounts`].
+	type LockMigration: Convert<LockIdentifier, Option<Self::FreezeIdentifier>>;
+}
+
+impl HoldsAndFreezes for () {
+	type HoldsAndFreezes = ();
+}
+
+/// The reason of a hold of the balances pallet instance `I`.
+pub type HoldReasonOf<T, I = ()> =
+	<<T as Config<I>>::HoldsAndFreezes as HoldsAndFreezes>::HoldReason;
+/// The identifier of a freeze of the balances pallet instance `I`.
+pub type FreezeIdentifierOf<T, I = ()> =
+	<<T as Config<I>>::HoldsAndFreezes as HoldsAndFreezes>::FreezeIdentifier;
+/// The maximum number of holds of an account in the balances pallet instance `I`.
+pub type MaxHoldsOf<T, I = ()> = <<T as Config<I>>::HoldsAndFreezes as HoldsAndFreezes>::MaxHolds;
+/// The maximum number of freezes of an account in the balances pallet instance `I`.
+pub type MaxFreezesOf<T, I = ()> =
+	<<T as Config<I>>::HoldsAndFreezes as HoldsAndFreezes>::MaxFreezes;
+/// The migration of the locks to freezes of the balances pallet instance `I`.
+pub type LockMigrationOf<T, I = ()> =
+	<<T as Config<I>>::HoldsAndFreezes as HoldsAndFreezes>::LockMigration;
+
+/// An amount identified by an id: a hold or a freeze.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+pub struct IdAmount<Id, Balance> {
+	/// The identifier of the hold or freeze.
+	pub id: Id,
+	/// The amount held or frozen.
+	pub amount: Balance,
+}
+
+/// Set the amount of `id` in the sorted `entries` to `f` of its current amount, inserting or
+/// removing the entry as needed. Fails with `error` if the entry cannot be inserted.
+fn mutate_id_amount<Id: Ord + Copy, Balance: Zero + Copy, S: Get<u32>>(
+	entries: &mut BoundedVec<IdAmount<Id, Balance>, S>,
+	id: Id,
+	f: impl FnOnce(Balance) -> Balance,
+	error: DispatchError,
+) -> DispatchResult {
+	match entries.binary_search_by_key(&id, |entry| entry.id) {
+		Ok(index) => {
+			let amount = f(entries[index].amount);
+			if amount.is_zero() {
+				entries.remove(index);
+			} else {
+				entries[index].amount = amount;
+			}
+		},
+		Err(index) => {
+			let amount = f(Zero::zero());
+			if !amount.is_zero() {
+				entries.try_insert(index, IdAmount { id, amount }).map_err(|_| error)?;
+			}
+		},
+	}
+	Ok(())
+}
+
 /// All balance information for an account.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen)]
 pub struct AccountData<Balance> {
@@ -641,6 +801,8 @@ impl<Balance: Saturating + Copy + Ord> AccountData<Balance> {
 enum Releases {
 	V1_0_0,
 	V2_0_0,
+	/// The locks and the named reserves are stored as bounded vectors.
+	V3_0_0,
 }
 
 impl Default for Releases {
@@ -855,6 +1017,51 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Update the frozen balances of the account entry for `who`, given its locks and freezes.
+	fn update_frozen(
+		who: &T::AccountId,
+		locks: &[BalanceLock<T::Balance>],
+		freezes: &[IdAmount<FreezeIdentifierOf<T, I>, T::Balance>],
+	) {
+		// No way this can fail since we do not alter the existential balances.
+		let res = Self::mutate_account(who, |b| {
+			// Freezes apply whatever the reasons of the withdrawal.
+			let frozen = freezes
+				.iter()
+				.fold(Zero::zero(), |frozen: T::Balance, freeze| frozen.max(freeze.amount));
+			b.misc_frozen = frozen;
+			b.fee_frozen = frozen;
+			for l in locks.iter() {
+				if l.reasons == Reasons::All || l.reasons == Reasons::Misc {
+					b.misc_frozen = b.misc_frozen.max(l.amount);
+				}
+				if l.reasons == Reasons::All || l.reasons == Reasons::Fee {
+					b.fee_frozen = b.fee_frozen.max(l.amount);
+				}
+			}
+		});
+		debug_assert!(res.is_ok());
+	}
+
+	/// Ensure that setting the lock `id` on `who` does not create a lock beyond `MaxLocks`.
+	fn ensure_can_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		// Setting such a lock is a no-op.
+		if amount.is_zero() || reasons.is_empty() {
+			return Ok(())
+		}
+		let locks = Self::locks(who);
+		ensure!(
+			locks.iter().any(|l| l.id == id) || (locks.len() as u32) < T::MaxLocks::get(),
+			Error::<T, I>::TooManyLocks,
+		);
+		Ok(())
+	}
+
 	/// Update the account entry for `who`, given the locks.
 	fn update_locks(who: &T::AccountId, locks: &[BalanceLock<T::Balance>]) {
 		let bounded_locks = WeakBoundedVec::<_, T::MaxLocks>::force_from(
@@ -869,20 +1076,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				A runtime configuration adjustment may be needed."
 			);
 		}
-		// No way this can fail since we do not alter the existential balances.
-		let res = Self::mutate_account(who, |b| {
-			b.misc_frozen = Zero::zero();
-			b.fee_frozen = Zero::zero();
-			for l in locks.iter() {
-				if l.reasons == Reasons::All || l.reasons == Reasons::Misc {
-					b.misc_frozen = b.misc_frozen.max(l.amount);
-				}
-				if l.reasons == Reasons::All || l.reasons == Reasons::Fee {
-					b.fee_frozen = b.fee_frozen.max(l.amount);
-				}
-			}
-		});
-		debug_assert!(res.is_ok());
+		// Do not read the freezes when they are disabled.
+		let freezes = if MaxFreezesOf::<T, I>::get() == 0 {
+			Default::default()
+		} else {
+			Self::freezes(who)
+		};
+		Self::update_frozen(who, locks, &freezes);
 
 		let existed = Locks::<T, I>::contains_key(who);
 		if locks.is_empty() {
@@ -909,6 +1109,88 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Update the account entry for `who`, given the freezes.
+	fn update_freezes(
+		who: &T::AccountId,
+		freezes: BoundedVec<IdAmount<FreezeIdentifierOf<T, I>, T::Balance>, MaxFreezesOf<T, I>>,
+	) {
+		Self::update_frozen(who, &Self::locks(who), &freezes);
+
+		let existed = Freezes::<T, I>::contains_key(who);
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(who);
+			if existed {
+				system::Pallet::<T>::dec_consumers(who);
+			}
+		} else {
+			Freezes::<T, I>::insert(who, freezes);
+			if !existed && system::Pallet::<T>::inc_consumers(who).is_err() {
+				// As for the locks, the frozen funds are stored in the account and therefore need
+				// a provider reference.
+				log::warn!(
+					target: "runtime::balances",
+					"Warning: Attempt to introduce freeze consumer reference, yet no providers. \
+					This is unexpected but should be safe."
+				);
+			}
+		}
+	}
+
+	/// Set the freeze `id` of `who` to `f` of its current amount, removing it if zero.
+	fn mutate_freeze(
+		id: &FreezeIdentifierOf<T, I>,
+		who: &T::AccountId,
+		f: impl FnOnce(T::Balance) -> T::Balance,
+	) -> DispatchResult {
+		Self::migrate_locks(who)?;
+		let mut freezes = Self::freezes(who);
+		mutate_id_amount(&mut freezes, *id, f, Error::<T, I>::TooManyFreezes.into())?;
+		Self::update_freezes(who, freezes);
+		Ok(())
+	}
+
+	/// Store the holds of `who`, removing the entry if there are none.
+	fn put_holds(
+		who: &T::AccountId,
+		holds: BoundedVec<IdAmount<HoldReasonOf<T, I>, T::Balance>, MaxHoldsOf<T, I>>,
+	) {
+		if holds.is_empty() {
+			Holds::<T, I>::remove(who);
+		} else {
+			Holds::<T, I>::insert(who, holds);
+		}
+	}
+
+	/// Migrate the locks of `who` which are replaced by freezes, according to
+	/// `HoldsAndFreezes::LockMigration`.
+	///
+	/// A migrated lock becomes a freeze of the same amount whatever its reasons, or extends the
+	/// freeze if it exists already. This is a no-op if no lock of `who` has a replacement.
+	pub fn migrate_locks(who: &T::AccountId) -> DispatchResult {
+		let mut freezes = Self::freezes(who);
+		let mut locks = Vec::new();
+		let mut migrated = false;
+		for lock in Self::locks(who) {
+			match LockMigrationOf::<T, I>::convert(lock.id) {
+				Some(id) => {
+					mutate_id_amount(
+						&mut freezes,
+						id,
+						|frozen| frozen.max(lock.amount),
+						Error::<T, I>::TooManyFreezes.into(),
+					)?;
+					migrated = true;
+				},
+				None => locks.push(lock),
+			}
+		}
+
+		if migrated {
+			Self::update_freezes(who, freezes);
+			Self::update_locks(who, &locks[..]);
+		}
+		Ok(())
+	}
 
 	/// Move the reserved balance of one account into the balance of another, according to `status`.
 	///
@@ -1043,10 +1325,27 @@ impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T,
 }
 
 impl<T: Config<I>, I: 'static> fungible::InspectHold<T::AccountId> for Pallet<T, I> {
-	fn balance_on_hold(who: &T::AccountId) -> T::Balance {
-		Self::account(who).reserved
+	type Reason = HoldReasonOf<T, I>;
+
+	fn total_balance_on_hold(who: &T::AccountId) -> T::Balance {
+		Self::holds(who)
+			.iter()
+			.fold(Zero::zero(), |total: T::Balance, hold| total.saturating_add(hold.amount))
+	}
+	fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+		let holds = Self::holds(who);
+		holds
+			.binary_search_by_key(reason, |hold| hold.id)
+			.map(|index| holds[index].amount)
+			.unwrap_or_default()
 	}
-	fn can_hold(who: &T::AccountId, amount: T::Balance) -> bool {
+	fn can_hold(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) -> bool {
+		let holds = Self::holds(who);
+		if holds.binary_search_by_key(reason, |hold| hold.id).is_err()
+			&& holds.len() >= MaxHoldsOf::<T, I>::get() as usize
+		{
+			return false
+		}
 		let a = Self::account(who);
 		let min_balance = T::ExistentialDeposit::get().max(a.frozen(Reasons::All));
 		if a.reserved.checked_add(&amount).is_none() { return false }
@@ -1059,40 +1358,137 @@ impl<T: Config<I>, I: 'static> fungible::InspectHold<T::AccountId> for Pallet<T,
 		a.free >= required_free
 	}
 }
+
 impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T, I> {
-	fn hold(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+	fn hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
 		if amount.is_zero() { return Ok(()) }
 		ensure!(Self::can_reserve(who, amount), Error::<T, I>::InsufficientBalance);
+		let mut holds = Self::holds(who);
+		mutate_id_amount(
+			&mut holds,
+			*reason,
+			|held| held.saturating_add(amount),
+			Error::<T, I>::TooManyHolds.into(),
+		)?;
 		Self::mutate_account(who, |a| {
 			a.free -= amount;
 			a.reserved += amount;
 		})?;
+		Self::put_holds(who, holds);
 		Ok(())
 	}
-	fn release(who: &T::AccountId, amount: Self::Balance, best_effort: bool)
-		-> Result<T::Balance, DispatchError>
-	{
+	fn release(
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
 		if amount.is_zero() { return Ok(amount) }
-		// Done on a best-effort basis.
-		Self::try_mutate_account(who, |a, _| {
-			let new_free = a.free.saturating_add(amount.min(a.reserved));
-			let actual = new_free - a.free;
-			ensure!(best_effort || actual == amount, Error::<T, I>::InsufficientBalance);
-			// ^^^ Guaranteed to be <= amount and <= a.reserved
-			a.free = new_free;
-			a.reserved = a.reserved.saturating_sub(actual.clone());
+		let mut holds = Self::holds(who);
+		let mut actual = Zero::zero();
+		mutate_id_amount(
+			&mut holds,
+			*reason,
+			|held| {
+				actual = held.min(amount);
+				held - actual
+			},
+			Error::<T, I>::TooManyHolds.into(),
+		)?;
+		ensure!(best_effort || actual == amount, Error::<T, I>::InsufficientBalance);
+		let actual = Self::try_mutate_account(who, |a, _| -> Result<_, DispatchError> {
+			// The held funds are part of the reserved balance, this is only defensive.
+			let actual = actual.min(a.reserved);
+			a.free = a.free.checked_add(&actual).ok_or(ArithmeticError::Overflow)?;
+			a.reserved -= actual;
 			Ok(actual)
-		})
+		})?;
+		Self::put_holds(who, holds);
+		Ok(actual)
 	}
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &T::AccountId,
 		dest: &T::AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError> {
+		let held = <Self as fungible::InspectHold<_>>::balance_on_hold(reason, source);
+		ensure!(best_effort || amount <= held, Error::<T, I>::InsufficientBalance);
+		if source == dest {
+			return if on_hold {
+				Ok(amount.min(held))
+			} else {
+				<Self as fungible::MutateHold<_>>::release(reason, source, amount, best_effort)
+			}
+		}
+
+		let mut dest_holds = Self::holds(dest);
+		if on_hold {
+			ensure!(
+				dest_holds.binary_search_by_key(reason, |hold| hold.id).is_ok()
+					|| dest_holds.len() < MaxHoldsOf::<T, I>::get() as usize,
+				Error::<T, I>::TooManyHolds,
+			);
+		}
+
 		let status = if on_hold { Status::Reserved } else { Status::Free };
-		Self::do_transfer_reserved(source, dest, amount, best_effort, status)
+		let actual =
+			Self::do_transfer_reserved(source, dest, amount.min(held), best_effort, status)?;
+
+		let mut source_holds = Self::holds(source);
+		mutate_id_amount(
+			&mut source_holds,
+			*reason,
+			|held| held.saturating_sub(actual),
+			Error::<T, I>::TooManyHolds.into(),
+		)?;
+		Self::put_holds(source, source_holds);
+		if on_hold {
+			mutate_id_amount(
+				&mut dest_holds,
+				*reason,
+				|held| held.saturating_add(actual),
+				Error::<T, I>::TooManyHolds.into(),
+			)?;
+			Self::put_holds(dest, dest_holds);
+		}
+		Ok(actual)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = FreezeIdentifierOf<T, I>;
+
+	fn balance_frozen(id: &Self::Id, who: &T::AccountId) -> T::Balance {
+		let freezes = Self::freezes(who);
+		let frozen = freezes
+			.binary_search_by_key(id, |freeze| freeze.id)
+			.map(|index| freezes[index].amount)
+			.unwrap_or_default();
+		// The locks which are not migrated yet count as the freeze replacing them.
+		Self::locks(who)
+			.iter()
+			.filter(|lock| LockMigrationOf::<T, I>::convert(lock.id).as_ref() == Some(id))
+			.fold(frozen, |frozen, lock| frozen.max(lock.amount))
+	}
+	fn can_freeze(id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Self::freezes(who);
+		freezes.binary_search_by_key(id, |freeze| freeze.id).is_ok()
+			|| freezes.len() < MaxFreezesOf::<T, I>::get() as usize
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::mutate_freeze(id, who, |_| amount)
+	}
+	fn extend_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::mutate_freeze(id, who, |frozen| frozen.max(amount))
+	}
+	fn thaw(id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Self::mutate_freeze(id, who, |_| Zero::zero())
 	}
 }
 
@@ -1922,6 +2318,28 @@ where
 		Self::update_locks(who, &locks[..]);
 	}
 
+	fn try_set_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		Self::ensure_can_lock(id, who, amount, reasons)?;
+		Self::set_lock(id, who, amount, reasons);
+		Ok(())
+	}
+
+	fn try_extend_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		Self::ensure_can_lock(id, who, amount, reasons)?;
+		Self::extend_lock(id, who, amount, reasons);
+		Ok(())
+	}
+
 	fn remove_lock(
 		id: LockIdentifier,
 		who: &T::AccountId,
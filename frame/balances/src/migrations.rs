@@ -0,0 +1,79 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations for the Balances pallet.
+
+use super::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use sp_std::{convert::TryFrom, marker::PhantomData};
+
+/// Translate the locks and the named reserves stored by the previous versions of the pallet as
+/// unbounded vectors into vectors bounded by `MaxLocks` and `MaxReserves`.
+///
+/// The locks exceeding `MaxLocks` are kept, as the locks are only weakly bounded. The named
+/// reserves exceeding `MaxReserves` are dropped, with the largest identifiers, and their funds are
+/// unreserved so that they do not stay reserved without any identifier to release them.
+pub struct MigrateToBoundedVecs<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToBoundedVecs<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::<T, I>::get() == Releases::V3_0_0 {
+			log::info!(target: "runtime::balances", "MigrateToBoundedVecs should be removed");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: u64 = 0;
+		Locks::<T, I>::translate::<Vec<BalanceLock<T::Balance>>, _>(|_, locks| {
+			translated += 1;
+			Some(WeakBoundedVec::force_from(locks, Some("Balances MigrateToBoundedVecs")))
+		});
+		let mut truncated = 0u32;
+		Reserves::<T, I>::translate::<Vec<ReserveData<T::ReserveIdentifier, T::Balance>>, _>(
+			|who, mut reserves| {
+				translated += 1;
+				if reserves.len() > T::MaxReserves::get() as usize {
+					truncated += 1;
+					for dropped in reserves.split_off(T::MaxReserves::get() as usize) {
+						let remaining = Pallet::<T, I>::unreserve(&who, dropped.amount);
+						if !remaining.is_zero() {
+							log::error!(
+								target: "runtime::balances",
+								"Failed to unreserve {:?} of a dropped named reserve of {:?}",
+								remaining,
+								who,
+							);
+						}
+					}
+				}
+				BoundedVec::try_from(reserves).ok()
+			},
+		);
+		StorageVersion::<T, I>::put(Releases::V3_0_0);
+
+		if truncated > 0 {
+			log::warn!(
+				target: "runtime::balances",
+				"Dropped and unreserved the named reserves exceeding MaxReserves of {} accounts",
+				truncated,
+			);
+		}
+		log::info!(target: "runtime::balances", "Migrated {} locks and named reserves", translated);
+		// Each truncation also unreserves from the account.
+		let accesses = translated.saturating_add(truncated as u64).saturating_add(1);
+		T::DbWeight::get().reads_writes(accesses, accesses)
+	}
+}
@@ -162,14 +162,14 @@ macro_rules! decl_tests {
 						ChargeTransactionPayment::from(1),
 						&1,
 						CALL,
-						&info_from_weight(1),
+						&info_from_weight(Weight::from_ref_time(1)),
 						1,
 					).is_err());
 					assert_ok!(<ChargeTransactionPayment<$test> as SignedExtension>::pre_dispatch(
 						ChargeTransactionPayment::from(0),
 						&1,
 						CALL,
-						&info_from_weight(1),
+						&info_from_weight(Weight::from_ref_time(1)),
 						1,
 					));
 
@@ -180,14 +180,14 @@ macro_rules! decl_tests {
 						ChargeTransactionPayment::from(1),
 						&1,
 						CALL,
-						&info_from_weight(1),
+						&info_from_weight(Weight::from_ref_time(1)),
 						1,
 					).is_err());
 					assert!(<ChargeTransactionPayment<$test> as SignedExtension>::pre_dispatch(
 						ChargeTransactionPayment::from(0),
 						&1,
 						CALL,
-						&info_from_weight(1),
+						&info_from_weight(Weight::from_ref_time(1)),
 						1,
 					).is_err());
 				});
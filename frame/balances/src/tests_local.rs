@@ -23,10 +23,10 @@ use sp_runtime::{
 	traits::IdentityLookup,
 	testing::Header,
 };
-use sp_core::H256;
+use sp_core::{H256, u32_trait::{_0, _1, _4}};
 use sp_io;
 use frame_support::parameter_types;
-use frame_support::traits::StorageMapShim;
+use frame_support::traits::{StorageMapShim, Get, OnUnbalanced, SplitTwoWays, DealWithFees};
 use frame_support::weights::{Weight, DispatchInfo, IdentityFee};
 use crate::{
 	self as pallet_balances,
@@ -51,7 +51,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_ref_time(1024),
+		);
 	pub static ExistentialDeposit: u64 = 0;
 }
 impl frame_system::Config for Test {
@@ -91,7 +93,35 @@ impl pallet_transaction_payment::Config for Test {
 parameter_types! {
 	pub const MaxLocks: u32 = 50;
 	pub const MaxReserves: u32 = 2;
+	pub const MaxHolds: u32 = 2;
+	pub const MaxFreezes: u32 = 2;
 }
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, MaxEncodedLen)]
+pub enum TestId {
+	Foo,
+	Bar,
+	Baz,
+}
+
+const MIGRATED_LOCK: LockIdentifier = *b"migrated";
+
+pub struct TestLockMigration;
+impl Convert<LockIdentifier, Option<TestId>> for TestLockMigration {
+	fn convert(id: LockIdentifier) -> Option<TestId> {
+		if id == MIGRATED_LOCK { Some(TestId::Foo) } else { None }
+	}
+}
+
+pub struct TestHoldsAndFreezes;
+impl HoldsAndFreezes for TestHoldsAndFreezes {
+	type HoldReason = TestId;
+	type FreezeIdentifier = TestId;
+	type MaxHolds = MaxHolds;
+	type MaxFreezes = MaxFreezes;
+	type LockMigration = TestLockMigration;
+}
+
 impl Config for Test {
 	type Balance = u64;
 	type DustRemoval = ();
@@ -106,6 +136,7 @@ impl Config for Test {
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = TestHoldsAndFreezes;
 	type WeightInfo = ();
 }
 
@@ -196,3 +227,241 @@ fn emit_events_with_no_existential_deposit_suicide_with_dust() {
 			);
 		});
 }
+
+parameter_types! {
+	pub const TreasuryAccount: u64 = 10;
+	pub const AuthorAccount: u64 = 11;
+}
+
+pub struct ResolveTo<Who>(sp_std::marker::PhantomData<Who>);
+impl<Who: Get<u64>> OnUnbalanced<NegativeImbalance<Test>> for ResolveTo<Who> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance<Test>) {
+		Balances::resolve_creating(&Who::get(), amount);
+	}
+}
+
+type ToTreasury = ResolveTo<TreasuryAccount>;
+type ToAuthor = ResolveTo<AuthorAccount>;
+
+#[test]
+fn split_two_ways_splits_by_ratio_without_losing_the_remainder() {
+	<ExtBuilder>::default().build().execute_with(|| {
+		SplitTwoWays::<u64, NegativeImbalance<Test>, _4, ToTreasury, _1, ToAuthor>::on_unbalanced(
+			NegativeImbalance::new(103),
+		);
+		assert_eq!(Balances::free_balance(10), 82);
+		assert_eq!(Balances::free_balance(11), 21);
+
+		// Everything goes to the second target when both parts are zero.
+		SplitTwoWays::<u64, NegativeImbalance<Test>, _0, ToTreasury, _0, ToAuthor>::on_unbalanced(
+			NegativeImbalance::new(10),
+		);
+		assert_eq!(Balances::free_balance(10), 82);
+		assert_eq!(Balances::free_balance(11), 31);
+	});
+}
+
+#[test]
+fn split_two_ways_does_not_saturate_large_amounts() {
+	<ExtBuilder>::default().build().execute_with(|| {
+		SplitTwoWays::<u64, NegativeImbalance<Test>, _4, ToTreasury, _1, ToAuthor>::on_unbalanced(
+			NegativeImbalance::new(u64::max_value()),
+		);
+		assert_eq!(Balances::free_balance(10), u64::max_value() / 5 * 4);
+		assert_eq!(Balances::free_balance(11), u64::max_value() / 5);
+	});
+}
+
+#[test]
+fn deal_with_fees_handles_fees_and_tips_separately() {
+	<ExtBuilder>::default().build().execute_with(|| {
+		type FeesSplit = SplitTwoWays<u64, NegativeImbalance<Test>, _4, ToTreasury, _1, ToAuthor>;
+		type Handler = DealWithFees<FeesSplit, ToAuthor>;
+		Handler::on_unbalanceds(vec![
+			NegativeImbalance::<Test>::new(100),
+			NegativeImbalance::new(7),
+			NegativeImbalance::new(3),
+		].into_iter());
+		assert_eq!(Balances::free_balance(10), 80);
+		assert_eq!(Balances::free_balance(11), 30);
+
+		// A fee without tips.
+		Handler::on_unbalanced(NegativeImbalance::<Test>::new(10));
+		assert_eq!(Balances::free_balance(10), 88);
+		assert_eq!(Balances::free_balance(11), 32);
+	});
+}
+
+#[test]
+fn holds_are_kept_by_reason() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		use fungible::{InspectHold, MutateHold};
+
+		assert_ok!(Balances::hold(&TestId::Foo, &1, 3));
+		assert_ok!(Balances::hold(&TestId::Bar, &1, 2));
+		assert_ok!(Balances::hold(&TestId::Foo, &1, 1));
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &1), 4);
+		assert_eq!(Balances::balance_on_hold(&TestId::Bar, &1), 2);
+		assert_eq!(Balances::total_balance_on_hold(&1), 6);
+		assert_eq!(Balances::reserved_balance(1), 6);
+		assert_eq!(Balances::free_balance(1), 4);
+
+		assert!(!Balances::can_hold(&TestId::Baz, &1, 1));
+		assert_noop!(Balances::hold(&TestId::Baz, &1, 1), Error::<Test, _>::TooManyHolds);
+		assert_noop!(Balances::hold(&TestId::Foo, &1, 5), Error::<Test, _>::InsufficientBalance);
+
+		assert_noop!(
+			Balances::release(&TestId::Bar, &1, 3, false),
+			Error::<Test, _>::InsufficientBalance
+		);
+		assert_eq!(Balances::release(&TestId::Bar, &1, 3, true), Ok(2));
+		assert_eq!(Balances::release(&TestId::Foo, &1, 1, false), Ok(1));
+		assert_eq!(Balances::holds(1).len(), 1);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Balances::free_balance(1), 7);
+
+		assert_eq!(Balances::release(&TestId::Foo, &1, 3, false), Ok(3));
+		assert!(!Holds::<Test>::contains_key(1));
+		assert_eq!(Balances::free_balance(1), 10);
+	});
+}
+
+#[test]
+fn held_funds_can_be_transferred() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		use fungible::{InspectHold, MutateHold};
+
+		assert_ok!(Balances::hold(&TestId::Foo, &1, 5));
+		assert_noop!(
+			Balances::transfer_held(&TestId::Foo, &1, &2, 6, false, true),
+			Error::<Test, _>::InsufficientBalance
+		);
+
+		assert_eq!(Balances::transfer_held(&TestId::Foo, &1, &2, 2, false, true), Ok(2));
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &1), 3);
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &2), 2);
+		assert_eq!(Balances::reserved_balance(2), 2);
+
+		assert_eq!(Balances::transfer_held(&TestId::Foo, &1, &2, 5, true, false), Ok(3));
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &1), 0);
+		assert_eq!(Balances::total_balance(&1), 5);
+		assert_eq!(Balances::free_balance(2), 23);
+		assert_eq!(Balances::balance_on_hold(&TestId::Foo, &2), 2);
+	});
+}
+
+#[test]
+fn freezes_restrict_the_free_balance() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		use fungible::{InspectFreeze, MutateFreeze};
+
+		assert_ok!(Balances::set_freeze(&TestId::Foo, &1, 4));
+		assert_ok!(Balances::extend_freeze(&TestId::Foo, &1, 2));
+		assert_ok!(Balances::set_freeze(&TestId::Bar, &1, 6));
+		assert_eq!(Balances::balance_frozen(&TestId::Foo, &1), 4);
+		assert_eq!(System::consumers(&1), 1);
+
+		assert!(!Balances::can_freeze(&TestId::Baz, &1));
+		assert_noop!(Balances::set_freeze(&TestId::Baz, &1, 1), Error::<Test, _>::TooManyFreezes);
+		assert_noop!(
+			<Balances as Currency<_>>::transfer(&1, &2, 5, AllowDeath),
+			Error::<Test, _>::LiquidityRestrictions
+		);
+		assert_ok!(<Balances as Currency<_>>::transfer(&1, &2, 4, AllowDeath));
+
+		// A lock and the freezes overlap.
+		Balances::set_lock(*b"locked  ", &1, 5, WithdrawReasons::all());
+		assert_ok!(Balances::thaw(&TestId::Bar, &1));
+		assert_noop!(
+			<Balances as Currency<_>>::transfer(&1, &2, 2, AllowDeath),
+			Error::<Test, _>::LiquidityRestrictions
+		);
+		Balances::remove_lock(*b"locked  ", &1);
+		assert_ok!(<Balances as Currency<_>>::transfer(&1, &2, 2, AllowDeath));
+
+		assert_ok!(Balances::thaw(&TestId::Foo, &1));
+		assert!(!Freezes::<Test>::contains_key(1));
+		assert_eq!(System::consumers(&1), 0);
+	});
+}
+
+#[test]
+fn locks_are_migrated_to_freezes() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		use fungible::{InspectFreeze, MutateFreeze};
+
+		Balances::set_lock(MIGRATED_LOCK, &1, 5, WithdrawReasons::TRANSACTION_PAYMENT);
+		Balances::set_lock(*b"other   ", &1, 3, WithdrawReasons::all());
+		Balances::set_lock(MIGRATED_LOCK, &2, 7, WithdrawReasons::all());
+		// Not migrated yet, but the lock already counts as the freeze.
+		assert_eq!(Balances::balance_frozen(&TestId::Foo, &1), 5);
+
+		assert_ok!(Balances::upgrade_accounts(Origin::signed(3), vec![1]));
+		let lock_ids = Balances::locks(1).iter().map(|lock| lock.id).collect::<Vec<_>>();
+		assert_eq!(lock_ids, vec![*b"other   "]);
+		let freezes = Balances::freezes(1).into_inner();
+		assert_eq!(freezes, vec![IdAmount { id: TestId::Foo, amount: 5 }]);
+		assert_eq!(System::consumers(&1), 2);
+		// The freeze applies whatever the reasons of the lock were.
+		assert_noop!(
+			<Balances as Currency<_>>::transfer(&1, &3, 6, AllowDeath),
+			Error::<Test, _>::LiquidityRestrictions
+		);
+
+		// The locks are also migrated when the freezes of the account are accessed.
+		assert_ok!(Balances::extend_freeze(&TestId::Foo, &2, 3));
+		assert!(Balances::locks(2).is_empty());
+		assert_eq!(Balances::balance_frozen(&TestId::Foo, &2), 7);
+		assert_eq!(System::consumers(&2), 1);
+	});
+}
+
+#[test]
+fn locks_and_reserves_are_migrated_to_bounded_vecs() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+		StorageVersion::<Test>::put(Releases::V2_0_0);
+		Balances::set_lock(ID_1, &1, 5, WithdrawReasons::all());
+		assert_ok!(Balances::reserve(&1, 6));
+		let reserves = (1..=3u64)
+			.map(|amount| ReserveData { id: [amount as u8; 8], amount })
+			.collect::<Vec<_>>();
+		unhashed::put(&Reserves::<Test>::hashed_key_for(1), &reserves);
+		// More named reserves than `MaxReserves` do not decode.
+		assert!(Balances::reserves(1).is_empty());
+
+		migrations::MigrateToBoundedVecs::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V3_0_0);
+		assert_eq!(Balances::locks(1).len(), 1);
+		assert_eq!(Balances::reserves(1).into_inner(), reserves[..2].to_vec());
+		// The funds of the dropped named reserve are unreserved.
+		assert_eq!(Balances::reserved_balance(1), 3);
+	});
+}
+
+#[test]
+fn try_set_lock_enforces_max_locks() {
+	<ExtBuilder>::default().monied(true).build().execute_with(|| {
+		for i in 0..MaxLocks::get() {
+			assert_ok!(Balances::try_set_lock([i as u8; 8], &1, 5, WithdrawReasons::all()));
+		}
+
+		// A new lock is refused, by both functions.
+		let id = [u8::max_value(); 8];
+		assert_noop!(
+			Balances::try_set_lock(id, &1, 5, WithdrawReasons::all()),
+			Error::<Test>::TooManyLocks,
+		);
+		assert_noop!(
+			Balances::try_extend_lock(id, &1, 5, WithdrawReasons::all()),
+			Error::<Test>::TooManyLocks,
+		);
+
+		// Existing locks can still be updated, and setting no lock is always fine.
+		assert_ok!(Balances::try_set_lock([0; 8], &1, 10, WithdrawReasons::all()));
+		assert_ok!(Balances::try_extend_lock([1; 8], &1, 10, WithdrawReasons::all()));
+		assert_ok!(Balances::try_set_lock(id, &1, 0, WithdrawReasons::all()));
+		assert_eq!(Balances::locks(1).len(), MaxLocks::get() as usize);
+	});
+}
@@ -71,6 +71,13 @@
 //! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
 //! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
 //! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
+//!
+//! ### Child Bounties
+//!
+//! The curator of an active bounty can split it into child bounties with `pallet-child-bounties`,
+//! which registers itself as the [`Config::ChildBountyManager`]. A bounty cannot be awarded or
+//! closed while it has active child bounties, and the curator fees of its child bounties are
+//! deducted from the fee of the bounty curator when the bounty is claimed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -131,6 +138,34 @@ pub trait Config: frame_system::Config + pallet_treasury::Config {
 
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
+
+	/// The child bounties of the bounties, `()` if bounties cannot have child bounties.
+	type ChildBountyManager: ChildBountyManager<BalanceOf<Self>>;
+}
+
+/// Keeps track of the child bounties of the bounties.
+pub trait ChildBountyManager<Balance> {
+	/// The number of active child bounties of the parent bounty.
+	fn child_bounties_count(bounty_id: BountyIndex) -> BountyIndex;
+
+	/// The sum of the curator fees of the child bounties of the parent bounty, which are deducted
+	/// from the fee of the parent bounty curator.
+	fn children_curator_fees(bounty_id: BountyIndex) -> Balance;
+
+	/// The parent bounty was claimed or closed.
+	fn bounty_removed(bounty_id: BountyIndex);
+}
+
+impl<Balance: Zero> ChildBountyManager<Balance> for () {
+	fn child_bounties_count(_bounty_id: BountyIndex) -> BountyIndex {
+		Zero::zero()
+	}
+
+	fn children_curator_fees(_bounty_id: BountyIndex) -> Balance {
+		Zero::zero()
+	}
+
+	fn bounty_removed(_bounty_id: BountyIndex) {}
 }
 
 /// An index of a bounty. Just a `u32`.
@@ -153,6 +188,23 @@ pub struct Bounty<AccountId, Balance, BlockNumber> {
 	status: BountyStatus<AccountId, BlockNumber>,
 }
 
+impl<AccountId: Clone, Balance: Copy, BlockNumber: Clone> Bounty<AccountId, Balance, BlockNumber> {
+	/// The (total) amount that should be paid if the bounty is rewarded.
+	pub fn value(&self) -> Balance {
+		self.value
+	}
+
+	/// The curator fee, included in the value.
+	pub fn fee(&self) -> Balance {
+		self.fee
+	}
+
+	/// The status of the bounty.
+	pub fn get_status(&self) -> BountyStatus<AccountId, BlockNumber> {
+		self.status.clone()
+	}
+}
+
 /// The status of a bounty proposal.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
 pub enum BountyStatus<AccountId, BlockNumber> {
@@ -253,6 +305,8 @@ decl_error! {
 		PendingPayout,
 		/// The bounties cannot be claimed/closed because it's still in the countdown period.
 		Premature,
+		/// The bounty cannot be awarded or closed because it has active child bounties.
+		HasActiveChildBounty,
 	}
 }
 
@@ -516,6 +570,10 @@ decl_module! {
 					},
 					_ => return Err(Error::<T>::UnexpectedStatus.into()),
 				}
+				ensure!(
+					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+					Error::<T>::HasActiveChildBounty,
+				);
 				bounty.status = BountyStatus::PendingPayout {
 					curator: signer,
 					beneficiary: beneficiary.clone(),
@@ -548,7 +606,12 @@ decl_module! {
 					let bounty_account = Self::bounty_account_id(bounty_id);
 					let balance = T::Currency::free_balance(&bounty_account);
 					let fee = bounty.fee.min(balance); // just to be safe
+					// The child bounty curators were paid their share of the fee already.
+					let children_fee = T::ChildBountyManager::children_curator_fees(bounty_id);
+					debug_assert!(children_fee <= fee);
+					let fee = fee.saturating_sub(children_fee);
 					let payout = balance.saturating_sub(fee);
+					T::ChildBountyManager::bounty_removed(bounty_id);
 					let err_amount = T::Currency::unreserve(&curator, bounty.curator_deposit);
 					debug_assert!(err_amount.is_zero());
 					let res = T::Currency::transfer(&bounty_account, &curator, fee, AllowDeath); // should not fail
@@ -584,6 +647,10 @@ decl_module! {
 
 			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResultWithPostInfo {
 				let bounty = maybe_bounty.as_ref().ok_or(Error::<T>::InvalidIndex)?;
+				ensure!(
+					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+					Error::<T>::HasActiveChildBounty,
+				);
 
 				match &bounty.status {
 					BountyStatus::Proposed => {
@@ -629,6 +696,7 @@ decl_module! {
 				let balance = T::Currency::free_balance(&bounty_account);
 				let res = T::Currency::transfer(&bounty_account, &Self::account_id(), balance, AllowDeath); // should not fail
 				debug_assert!(res.is_ok());
+				T::ChildBountyManager::bounty_removed(bounty_id);
 				*maybe_bounty = None;
 
 				Self::deposit_event(Event::<T>::BountyCanceled(bounty_id));
@@ -0,0 +1,233 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Child bounties pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::OnInitialize;
+use frame_system::RawOrigin;
+
+use crate::Pallet as ChildBounties;
+use pallet_bounties::Module as Bounties;
+use pallet_treasury::Module as Treasury;
+
+const SEED: u32 = 0;
+
+struct BenchmarkChildBounty<T: Config> {
+	/// Index of the parent bounty.
+	bounty_id: BountyIndex,
+	/// The curator of the parent bounty.
+	curator: T::AccountId,
+	/// The value of the child bounty.
+	child_bounty_value: BalanceOf<T>,
+	/// The curator fee of the child bounty.
+	child_bounty_fee: BalanceOf<T>,
+	/// The curator of the child bounty.
+	child_curator: T::AccountId,
+}
+
+fn setup_pot_account<T: Config>() {
+	let pot_account = Bounties::<T>::account_id();
+	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
+	let _ = T::Currency::make_free_balance_be(&pot_account, value);
+}
+
+// Create an active parent bounty, with its curator assigned.
+fn create_parent_bounty<T: Config>() -> Result<BenchmarkChildBounty<T>, &'static str> {
+	setup_pot_account::<T>();
+
+	let value = T::BountyValueMinimum::get()
+		.max(T::ChildBountyValueMinimum::get())
+		.saturating_mul(100u32.into());
+	let fee = value / 2u32.into();
+	let child_bounty_value = value / 4u32.into();
+	let child_bounty_fee = child_bounty_value / 2u32.into();
+
+	let caller: T::AccountId = account("caller", 0, SEED);
+	let reason = b"parent bounty".to_vec();
+	let deposit = T::BountyDepositBase::get() +
+		T::DataDepositPerByte::get() * (reason.len() as u32).into();
+	let _ = T::Currency::make_free_balance_be(&caller, deposit);
+	let curator: T::AccountId = account("curator", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&curator, fee);
+	let child_curator: T::AccountId = account("child-curator", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&child_curator, child_bounty_fee);
+
+	Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+	let bounty_id = pallet_bounties::BountyCount::get() - 1;
+	Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+	Treasury::<T>::on_initialize(T::BlockNumber::zero());
+	let curator_lookup = T::Lookup::unlookup(curator.clone());
+	Bounties::<T>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
+	Bounties::<T>::accept_curator(RawOrigin::Signed(curator.clone()).into(), bounty_id)?;
+
+	Ok(BenchmarkChildBounty {
+		bounty_id,
+		curator,
+		child_bounty_value,
+		child_bounty_fee,
+		child_curator,
+	})
+}
+
+// Create a child bounty of an active parent bounty, returning its index.
+fn create_child_bounty<T: Config>(
+	setup: &BenchmarkChildBounty<T>,
+) -> Result<BountyIndex, &'static str> {
+	ChildBounties::<T>::add_child_bounty(
+		RawOrigin::Signed(setup.curator.clone()).into(),
+		setup.bounty_id,
+		setup.child_bounty_value,
+		b"child bounty".to_vec(),
+	)?;
+	Ok(ChildBountyCount::<T>::get() - 1)
+}
+
+// Create a child bounty with its curator proposed.
+fn create_proposed_child_bounty<T: Config>(
+	setup: &BenchmarkChildBounty<T>,
+) -> Result<BountyIndex, &'static str> {
+	let child_bounty_id = create_child_bounty::<T>(setup)?;
+	ChildBounties::<T>::propose_curator(
+		RawOrigin::Signed(setup.curator.clone()).into(),
+		setup.bounty_id,
+		child_bounty_id,
+		T::Lookup::unlookup(setup.child_curator.clone()),
+		setup.child_bounty_fee,
+	)?;
+	Ok(child_bounty_id)
+}
+
+// Create a child bounty with its curator assigned.
+fn create_active_child_bounty<T: Config>(
+	setup: &BenchmarkChildBounty<T>,
+) -> Result<BountyIndex, &'static str> {
+	let child_bounty_id = create_proposed_child_bounty::<T>(setup)?;
+	ChildBounties::<T>::accept_curator(
+		RawOrigin::Signed(setup.child_curator.clone()).into(),
+		setup.bounty_id,
+		child_bounty_id,
+	)?;
+	Ok(child_bounty_id)
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	add_child_bounty {
+		let d in 0 .. T::MaximumReasonLength::get();
+
+		let setup = create_parent_bounty::<T>()?;
+		let description = vec![0; d as usize];
+	}: _(RawOrigin::Signed(setup.curator), setup.bounty_id, setup.child_bounty_value, description)
+	verify {
+		assert_last_event::<T>(Event::Added(setup.bounty_id, 0).into())
+	}
+
+	propose_curator {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_child_bounty::<T>(&setup)?;
+		let child_curator_lookup = T::Lookup::unlookup(setup.child_curator.clone());
+	}: _(
+		RawOrigin::Signed(setup.curator),
+		setup.bounty_id,
+		child_bounty_id,
+		child_curator_lookup,
+		setup.child_bounty_fee
+	)
+
+	accept_curator {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_proposed_child_bounty::<T>(&setup)?;
+	}: _(RawOrigin::Signed(setup.child_curator), setup.bounty_id, child_bounty_id)
+
+	// Worst case when the parent bounty curator is inactive and any sender unassigns the child
+	// bounty curator.
+	unassign_curator {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_active_child_bounty::<T>(&setup)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::BountyUpdatePeriod::get() + 1u32.into(),
+		);
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), setup.bounty_id, child_bounty_id)
+
+	award_child_bounty {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_active_child_bounty::<T>(&setup)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+	}: _(
+		RawOrigin::Signed(setup.child_curator),
+		setup.bounty_id,
+		child_bounty_id,
+		beneficiary_lookup
+	)
+	verify {
+		let event = Event::Awarded(setup.bounty_id, child_bounty_id, beneficiary);
+		assert_last_event::<T>(event.into())
+	}
+
+	claim_child_bounty {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_active_child_bounty::<T>(&setup)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		ChildBounties::<T>::award_child_bounty(
+			RawOrigin::Signed(setup.child_curator.clone()).into(),
+			setup.bounty_id,
+			child_bounty_id,
+			T::Lookup::unlookup(beneficiary.clone()),
+		)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::BountyDepositPayoutDelay::get());
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), setup.bounty_id, child_bounty_id)
+	verify {
+		assert!(T::Currency::free_balance(&beneficiary) > Zero::zero());
+	}
+
+	// Close a child bounty which has no curator yet.
+	close_child_bounty_added {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_child_bounty::<T>(&setup)?;
+	}: close_child_bounty(RawOrigin::Root, setup.bounty_id, child_bounty_id)
+	verify {
+		assert_last_event::<T>(Event::Canceled(setup.bounty_id, child_bounty_id).into())
+	}
+
+	// Close a child bounty whose curator is working on it.
+	close_child_bounty_active {
+		let setup = create_parent_bounty::<T>()?;
+		let child_bounty_id = create_active_child_bounty::<T>(&setup)?;
+	}: close_child_bounty(RawOrigin::Root, setup.bounty_id, child_bounty_id)
+	verify {
+		assert_last_event::<T>(Event::Canceled(setup.bounty_id, child_bounty_id).into())
+	}
+}
+
+impl_benchmark_test_suite!(
+	ChildBounties,
+	crate::tests::new_test_ext(),
+	crate::tests::Test,
+);
@@ -0,0 +1,716 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Child Bounties Pallet ( pallet-child-bounties )
+//!
+//! ## Child Bounty
+//!
+//! > NOTE: This pallet is tightly coupled with pallet-treasury and pallet-bounties.
+//!
+//! With child bounties, a large bounty can be divided into smaller chunks, for parallel execution
+//! and for an easier tracking of the spent funds. A child bounty is a smaller piece of work carved
+//! out of an active parent bounty, and funded from the account of the parent bounty. The curator
+//! of the parent bounty adds the child bounties and proposes their curators, who assign a payout
+//! address once the work of the child bounty is completed.
+//!
+//! The curator fees of the child bounties are deducted from the fee of the parent bounty curator,
+//! and a parent bounty cannot be awarded nor closed while it has active child bounties.
+//!
+//! ### Terminology
+//!
+//! - **Parent bounty:** An active bounty of `pallet-bounties` whose funds are split into child
+//!   bounties.
+//! - **Child bounty curator:** An account managing the child bounty and assigning a payout address
+//!   receiving the reward for the completion of work.
+//! - **Child bounty curator deposit:** The share of the child bounty curator fee that is reserved
+//!   from the child bounty curator while they are assigned, see
+//!   [`Config::ChildBountyCuratorDepositBase`].
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! Child bounty protocol:
+//! - `add_child_bounty` - Add a child bounty to an active parent bounty, transferring its value
+//!   from the parent bounty account.
+//! - `propose_curator` - Assign an account to a child bounty as candidate curator.
+//! - `accept_curator` - Accept a child bounty assignment from the parent bounty curator, setting a
+//!   curator deposit.
+//! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
+//! - `award_child_bounty` - Close and pay out the specified amount for the completed work.
+//! - `claim_child_bounty` - Claim a specific child bounty amount from the payout address.
+//! - `close_child_bounty` - Cancel the child bounty, returning its funds to the parent bounty.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::prelude::*;
+
+use codec::{Decode, Encode};
+use frame_support::traits::{
+	Currency, ExistenceRequirement::{AllowDeath, KeepAlive}, Get, OnUnbalanced, ReservableCurrency,
+};
+use pallet_bounties::{BountyIndex, BountyStatus};
+use sp_runtime::{
+	traits::{AccountIdConversion, BadOrigin, Saturating, StaticLookup, Zero},
+	DispatchError, DispatchResult, Permill, RuntimeDebug,
+};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type BalanceOf<T> = pallet_treasury::BalanceOf<T>;
+type BountiesError<T> = pallet_bounties::Error<T>;
+
+/// A child bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ChildBounty<AccountId, Balance, BlockNumber> {
+	/// The parent bounty of this child bounty.
+	parent_bounty: BountyIndex,
+	/// The (total) amount that should be paid if the child bounty is rewarded.
+	value: Balance,
+	/// The child bounty curator fee. Included in value.
+	fee: Balance,
+	/// The deposit of the child bounty curator.
+	curator_deposit: Balance,
+	/// The status of this child bounty.
+	status: ChildBountyStatus<AccountId, BlockNumber>,
+}
+
+/// The status of a child bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ChildBountyStatus<AccountId, BlockNumber> {
+	/// The child bounty is added and waiting for curator assignment.
+	Added,
+	/// A curator has been proposed by the parent bounty curator. Waiting for acceptance from the
+	/// child bounty curator.
+	CuratorProposed {
+		/// The assigned curator of this child bounty.
+		curator: AccountId,
+	},
+	/// The child bounty is active and waiting to be awarded.
+	Active {
+		/// The curator of this child bounty.
+		curator: AccountId,
+	},
+	/// The child bounty is awarded and waiting to be released after a delay.
+	PendingPayout {
+		/// The curator of this child bounty.
+		curator: AccountId,
+		/// The beneficiary of the child bounty.
+		beneficiary: AccountId,
+		/// When the child bounty can be claimed.
+		unlock_at: BlockNumber,
+	},
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_treasury::Config + pallet_bounties::Config
+	{
+		/// Maximum number of active child bounties of a parent bounty.
+		#[pallet::constant]
+		type MaxActiveChildBountyCount: Get<u32>;
+
+		/// Minimum value of a child bounty.
+		#[pallet::constant]
+		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
+
+		/// Percentage of the child bounty curator fee that is reserved as deposit from the child
+		/// bounty curator.
+		#[pallet::constant]
+		type ChildBountyCuratorDepositBase: Get<Permill>;
+
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The parent bounty is not active.
+		ParentBountyNotActive,
+		/// The parent bounty account does not have enough free balance for the child bounty.
+		InsufficientBountyBalance,
+		/// The parent bounty has `MaxActiveChildBountyCount` active child bounties already.
+		TooManyChildBounties,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// A child bounty is added. \[index, child_index\]
+		Added(BountyIndex, BountyIndex),
+		/// A child bounty is awarded to a beneficiary. \[index, child_index, beneficiary\]
+		Awarded(BountyIndex, BountyIndex, T::AccountId),
+		/// A child bounty is claimed by the beneficiary.
+		/// \[index, child_index, payout, beneficiary\]
+		Claimed(BountyIndex, BountyIndex, BalanceOf<T>, T::AccountId),
+		/// A child bounty is cancelled. \[index, child_index\]
+		Canceled(BountyIndex, BountyIndex),
+	}
+
+	/// Number of child bounties that have been added.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounty_count)]
+	pub type ChildBountyCount<T: Config> = StorageValue<_, BountyIndex, ValueQuery>;
+
+	/// Number of active child bounties of each parent bounty.
+	#[pallet::storage]
+	#[pallet::getter(fn parent_child_bounties)]
+	pub type ParentChildBounties<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, u32, ValueQuery>;
+
+	/// Child bounties that have been added, by parent bounty and child bounty index.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounties)]
+	pub type ChildBounties<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat, BountyIndex,
+		Twox64Concat, BountyIndex,
+		ChildBounty<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// The description of each child bounty.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounty_descriptions)]
+	pub type ChildBountyDescriptions<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, Vec<u8>>;
+
+	/// The sum of the curator fees of the child bounties of each parent bounty.
+	#[pallet::storage]
+	pub type ChildrenCuratorFees<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add a new child bounty to an active parent bounty.
+		///
+		/// The dispatch origin for this call must be the curator of the parent bounty.
+		///
+		/// The `value` of the child bounty is transferred from the parent bounty account to the
+		/// child bounty account.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `value`: The total payment amount of the child bounty, curator fee included.
+		/// - `description`: The description of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::add_child_bounty(description.len() as u32))]
+		pub fn add_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] value: BalanceOf<T>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			ensure!(
+				description.len() <= T::MaximumReasonLength::get() as usize,
+				BountiesError::<T>::ReasonTooBig,
+			);
+			ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
+			ensure!(
+				Self::parent_child_bounties(parent_bounty_id) < T::MaxActiveChildBountyCount::get(),
+				Error::<T>::TooManyChildBounties,
+			);
+
+			let (curator, ..) = Self::ensure_bounty_active(parent_bounty_id)?;
+			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+			let child_bounty_id = Self::child_bounty_count();
+			let parent_bounty_account =
+				pallet_bounties::Module::<T>::bounty_account_id(parent_bounty_id);
+			let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+			T::Currency::transfer(&parent_bounty_account, &child_bounty_account, value, KeepAlive)
+				.map_err(|_| Error::<T>::InsufficientBountyBalance)?;
+
+			ChildBountyCount::<T>::put(child_bounty_id.saturating_add(1));
+			ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
+				*count = count.saturating_add(1)
+			});
+			ChildBounties::<T>::insert(parent_bounty_id, child_bounty_id, ChildBounty {
+				parent_bounty: parent_bounty_id,
+				value,
+				fee: Zero::zero(),
+				curator_deposit: Zero::zero(),
+				status: ChildBountyStatus::Added,
+			});
+			ChildBountyDescriptions::<T>::insert(child_bounty_id, description);
+
+			Self::deposit_event(Event::<T>::Added(parent_bounty_id, child_bounty_id));
+			Ok(())
+		}
+
+		/// Propose a curator for an added child bounty.
+		///
+		/// The dispatch origin for this call must be the curator of the parent bounty.
+		///
+		/// The curator fees of all the child bounties of a parent bounty are deducted from the fee
+		/// of the parent bounty curator, so their sum cannot exceed it.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		/// - `curator`: The proposed curator of the child bounty.
+		/// - `fee`: The curator fee of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::propose_curator())]
+		pub fn propose_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let child_bounty_curator = T::Lookup::lookup(curator)?;
+
+			let (curator, _, parent_fee) = Self::ensure_bounty_active(parent_bounty_id)?;
+			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+					ensure!(
+						child_bounty.status == ChildBountyStatus::Added,
+						BountiesError::<T>::UnexpectedStatus,
+					);
+					ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
+
+					// A previously proposed curator may have been unassigned, their fee is
+					// replaced by the new one.
+					let children_fees = ChildrenCuratorFees::<T>::get(parent_bounty_id)
+						.saturating_sub(child_bounty.fee)
+						.saturating_add(fee);
+					ensure!(children_fees <= parent_fee, BountiesError::<T>::InvalidFee);
+					ChildrenCuratorFees::<T>::insert(parent_bounty_id, children_fees);
+
+					child_bounty.fee = fee;
+					child_bounty.status =
+						ChildBountyStatus::CuratorProposed { curator: child_bounty_curator };
+					Ok(())
+				},
+			)
+		}
+
+		/// Accept the curator role of a child bounty.
+		///
+		/// The dispatch origin for this call must be the proposed curator of the child bounty.
+		///
+		/// `ChildBountyCuratorDepositBase` of the child bounty curator fee is reserved from the
+		/// curator, and returned when the child bounty is claimed or closed.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::accept_curator())]
+		pub fn accept_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			Self::ensure_bounty_active(parent_bounty_id)?;
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					if let ChildBountyStatus::CuratorProposed { curator } = &child_bounty.status {
+						ensure!(signer == *curator, BountiesError::<T>::RequireCurator);
+
+						let deposit = T::ChildBountyCuratorDepositBase::get() * child_bounty.fee;
+						T::Currency::reserve(curator, deposit)?;
+						child_bounty.curator_deposit = deposit;
+
+						let curator = curator.clone();
+						child_bounty.status = ChildBountyStatus::Active { curator };
+						Ok(())
+					} else {
+						Err(BountiesError::<T>::UnexpectedStatus.into())
+					}
+				},
+			)
+		}
+
+		/// Unassign the curator of a child bounty.
+		///
+		/// The `RejectOrigin` and the parent bounty curator can always unassign the child bounty
+		/// curator, which slashes their deposit.
+		///
+		/// The child bounty curator can unassign themselves, which returns their deposit.
+		///
+		/// Anyone can unassign the child bounty curator if the parent bounty curator is inactive,
+		/// i.e. their update is overdue, which slashes the deposit of the child bounty curator.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::unassign_curator())]
+		pub fn unassign_curator(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					match child_bounty.status {
+						ChildBountyStatus::Added => {
+							// No curator to unassign at this point.
+							return Err(BountiesError::<T>::UnexpectedStatus.into())
+						},
+						ChildBountyStatus::CuratorProposed { ref curator } => {
+							// A curator has been proposed, but not accepted yet. Either the
+							// `RejectOrigin`, the parent bounty curator or the proposed curator
+							// can unassign the curator.
+							if let Some(sender) = maybe_sender {
+								ensure!(
+									sender == *curator ||
+										Self::is_parent_curator(parent_bounty_id, &sender),
+									BadOrigin,
+								);
+							}
+						},
+						ChildBountyStatus::Active { ref curator } => {
+							match maybe_sender {
+								// The `RejectOrigin` slashes the curator.
+								None => {
+									Self::slash_curator(curator, &mut child_bounty.curator_deposit)
+								},
+								Some(sender) if sender == *curator => {
+									// The curator is willingly giving up their role. Give back
+									// their deposit.
+									let deposit = child_bounty.curator_deposit;
+									let err_amount = T::Currency::unreserve(curator, deposit);
+									debug_assert!(err_amount.is_zero());
+									child_bounty.curator_deposit = Zero::zero();
+								},
+								Some(sender) => {
+									let (parent_curator, update_due, _) =
+										Self::ensure_bounty_active(parent_bounty_id)?;
+									// Anyone else than the parent bounty curator must wait until
+									// the parent bounty curator is inactive.
+									let now = frame_system::Pallet::<T>::block_number();
+									ensure!(
+										sender == parent_curator || update_due < now,
+										BountiesError::<T>::Premature,
+									);
+									Self::slash_curator(curator, &mut child_bounty.curator_deposit);
+								},
+							}
+						},
+						ChildBountyStatus::PendingPayout { ref curator, .. } => {
+							// The child bounty is pending payout, so only the `RejectOrigin` or
+							// the parent bounty curator can unassign the curator, claiming that
+							// the curator is acting maliciously.
+							if let Some(sender) = maybe_sender {
+								ensure!(
+									Self::is_parent_curator(parent_bounty_id, &sender),
+									BadOrigin,
+								);
+							}
+							Self::slash_curator(curator, &mut child_bounty.curator_deposit);
+						},
+					}
+
+					child_bounty.status = ChildBountyStatus::Added;
+					Ok(())
+				},
+			)
+		}
+
+		/// Award a child bounty to a beneficiary. The beneficiary will be able to claim the funds
+		/// after a delay of `BountyDepositPayoutDelay`.
+		///
+		/// The dispatch origin for this call must be the curator of the child bounty or of the
+		/// parent bounty.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		/// - `beneficiary`: The beneficiary account whom will receive the payout.
+		#[pallet::weight(<T as Config>::WeightInfo::award_child_bounty())]
+		pub fn award_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let (parent_curator, ..) = Self::ensure_bounty_active(parent_bounty_id)?;
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let child_bounty =
+						maybe_child_bounty.as_mut().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
+						ensure!(
+							signer == *curator || signer == parent_curator,
+							BountiesError::<T>::RequireCurator,
+						);
+						child_bounty.status = ChildBountyStatus::PendingPayout {
+							curator: curator.clone(),
+							beneficiary: beneficiary.clone(),
+							unlock_at: frame_system::Pallet::<T>::block_number() +
+								T::BountyDepositPayoutDelay::get(),
+						};
+						Ok(())
+					} else {
+						Err(BountiesError::<T>::UnexpectedStatus.into())
+					}
+				},
+			)?;
+
+			Self::deposit_event(
+				Event::<T>::Awarded(parent_bounty_id, child_bounty_id, beneficiary),
+			);
+			Ok(())
+		}
+
+		/// Claim the payout of an awarded child bounty after the payout delay.
+		///
+		/// The dispatch origin for this call may be any signed origin.
+		///
+		/// The curator fee is paid to the child bounty curator, along with their deposit, and the
+		/// rest of the child bounty funds to the beneficiary.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounty())]
+		pub fn claim_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?; // anyone can trigger claim
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResult {
+					let child_bounty =
+						maybe_child_bounty.take().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					if let ChildBountyStatus::PendingPayout { curator, beneficiary, unlock_at } =
+						child_bounty.status
+					{
+						ensure!(
+							frame_system::Pallet::<T>::block_number() >= unlock_at,
+							BountiesError::<T>::Premature,
+						);
+
+						let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+						let balance = T::Currency::free_balance(&child_bounty_account);
+						let fee = child_bounty.fee.min(balance); // just to be safe
+						let payout = balance.saturating_sub(fee);
+						let err_amount =
+							T::Currency::unreserve(&curator, child_bounty.curator_deposit);
+						debug_assert!(err_amount.is_zero());
+						let res = T::Currency::transfer(
+							&child_bounty_account,
+							&curator,
+							fee,
+							AllowDeath,
+						); // should not fail
+						debug_assert!(res.is_ok());
+						let res = T::Currency::transfer(
+							&child_bounty_account,
+							&beneficiary,
+							payout,
+							AllowDeath,
+						); // should not fail
+						debug_assert!(res.is_ok());
+
+						ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
+							*count = count.saturating_sub(1)
+						});
+						ChildBountyDescriptions::<T>::remove(child_bounty_id);
+
+						Self::deposit_event(Event::<T>::Claimed(
+							parent_bounty_id,
+							child_bounty_id,
+							payout,
+							beneficiary,
+						));
+						Ok(())
+					} else {
+						Err(BountiesError::<T>::UnexpectedStatus.into())
+					}
+				},
+			)
+		}
+
+		/// Cancel a child bounty which is not pending payout. All its funds are returned to the
+		/// parent bounty and the deposit of its curator is unreserved.
+		///
+		/// The dispatch origin for this call must be either the `RejectOrigin`, or the curator of
+		/// the parent bounty, as long as the parent bounty is active.
+		///
+		/// The fee of the child bounty curator is given back to the parent bounty curator.
+		///
+		/// - `parent_bounty_id`: Index of the parent bounty.
+		/// - `child_bounty_id`: Index of the child bounty.
+		#[pallet::weight(<T as Config>::WeightInfo::close_child_bounty_added()
+			.max(<T as Config>::WeightInfo::close_child_bounty_active()))]
+		pub fn close_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			if let Some(sender) = maybe_sender {
+				let (parent_curator, ..) = Self::ensure_bounty_active(parent_bounty_id)?;
+				ensure!(sender == parent_curator, BadOrigin);
+			}
+
+			ChildBounties::<T>::try_mutate_exists(
+				parent_bounty_id,
+				child_bounty_id,
+				|maybe_child_bounty| -> DispatchResultWithPostInfo {
+					let child_bounty =
+						maybe_child_bounty.as_ref().ok_or(BountiesError::<T>::InvalidIndex)?;
+
+					let weight = match &child_bounty.status {
+						ChildBountyStatus::Added | ChildBountyStatus::CuratorProposed { .. } => {
+							// Nothing extra to do besides the removal of the child bounty below.
+							<T as Config>::WeightInfo::close_child_bounty_added()
+						},
+						ChildBountyStatus::Active { curator } => {
+							// Refund the deposit of the working curator.
+							let err_amount =
+								T::Currency::unreserve(curator, child_bounty.curator_deposit);
+							debug_assert!(err_amount.is_zero());
+							<T as Config>::WeightInfo::close_child_bounty_active()
+						},
+						ChildBountyStatus::PendingPayout { .. } => {
+							// The curator should be unassigned first, slashing their deposit.
+							return Err(BountiesError::<T>::PendingPayout.into())
+						},
+					};
+
+					ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |fees| {
+						*fees = fees.saturating_sub(child_bounty.fee)
+					});
+					ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
+						*count = count.saturating_sub(1)
+					});
+
+					let parent_bounty_account =
+						pallet_bounties::Module::<T>::bounty_account_id(parent_bounty_id);
+					let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+					let balance = T::Currency::free_balance(&child_bounty_account);
+					let res = T::Currency::transfer(
+						&child_bounty_account,
+						&parent_bounty_account,
+						balance,
+						AllowDeath,
+					); // should not fail
+					debug_assert!(res.is_ok());
+
+					ChildBountyDescriptions::<T>::remove(child_bounty_id);
+					*maybe_child_bounty = None;
+
+					Self::deposit_event(Event::<T>::Canceled(parent_bounty_id, child_bounty_id));
+					Ok(Some(weight).into())
+				},
+			)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account ID of a child bounty account.
+	pub fn child_bounty_account_id(id: BountyIndex) -> T::AccountId {
+		// Like the bounty accounts, but with another prefix, so that a child bounty and a bounty
+		// of the same index do not share their account.
+		T::PalletId::get().into_sub_account(("cb", id))
+	}
+
+	/// The curator, the block number by which the next update of the curator is due and the
+	/// curator fee of the parent bounty, if it is active.
+	fn ensure_bounty_active(
+		bounty_id: BountyIndex,
+	) -> Result<(T::AccountId, T::BlockNumber, BalanceOf<T>), DispatchError> {
+		let bounty = pallet_bounties::Module::<T>::bounties(bounty_id)
+			.ok_or(BountiesError::<T>::InvalidIndex)?;
+		match bounty.get_status() {
+			BountyStatus::Active { curator, update_due } => Ok((curator, update_due, bounty.fee())),
+			_ => Err(Error::<T>::ParentBountyNotActive.into()),
+		}
+	}
+
+	/// Slash the deposit of a child bounty curator.
+	fn slash_curator(curator: &T::AccountId, curator_deposit: &mut BalanceOf<T>) {
+		let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
+		T::OnSlash::on_unbalanced(imbalance);
+		*curator_deposit = Zero::zero();
+	}
+
+	/// Whether `who` is the curator of the parent bounty, which must be active.
+	fn is_parent_curator(bounty_id: BountyIndex, who: &T::AccountId) -> bool {
+		Self::ensure_bounty_active(bounty_id).map_or(false, |(curator, ..)| curator == *who)
+	}
+}
+
+impl<T: Config> pallet_bounties::ChildBountyManager<BalanceOf<T>> for Pallet<T> {
+	fn child_bounties_count(bounty_id: BountyIndex) -> BountyIndex {
+		Self::parent_child_bounties(bounty_id)
+	}
+
+	fn children_curator_fees(bounty_id: BountyIndex) -> BalanceOf<T> {
+		ChildrenCuratorFees::<T>::get(bounty_id)
+	}
+
+	fn bounty_removed(bounty_id: BountyIndex) {
+		ChildrenCuratorFees::<T>::remove(bounty_id);
+		ParentChildBounties::<T>::remove(bounty_id);
+	}
+}
@@ -0,0 +1,494 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Child bounties pallet tests.
+
+#![cfg(test)]
+
+use crate as pallet_child_bounties;
+use super::*;
+
+use frame_support::{
+	assert_noop, assert_ok, parameter_types, traits::OnInitialize, weights::Weight, PalletId,
+};
+
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Bounties: pallet_bounties::{Pallet, Call, Storage, Event<T>},
+		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>},
+		ChildBounties: pallet_child_bounties::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_ref_time(1024);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u128; // u64 is not enough to hold bytes used to generate bounty account
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: u64 = 1;
+	pub const SpendPeriod: u64 = 2;
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const MaxApprovals: u32 = 100;
+}
+impl pallet_treasury::Config for Test {
+	type PalletId = TreasuryPalletId;
+	type Currency = pallet_balances::Pallet<Test>;
+	type ApproveOrigin = frame_system::EnsureRoot<u128>;
+	type RejectOrigin = frame_system::EnsureRoot<u128>;
+	type Event = Event;
+	type OnSlash = ();
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type WeightInfo = ();
+	type SpendFunds = Bounties;
+	type MaxApprovals = MaxApprovals;
+}
+parameter_types! {
+	pub const BountyDepositBase: u64 = 80;
+	pub const BountyDepositPayoutDelay: u64 = 3;
+	pub const BountyUpdatePeriod: u64 = 10;
+	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
+	pub const BountyValueMinimum: u64 = 5;
+	pub const DataDepositPerByte: u64 = 1;
+	pub const MaximumReasonLength: u32 = 300;
+}
+impl pallet_bounties::Config for Test {
+	type Event = Event;
+	type BountyDepositBase = BountyDepositBase;
+	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyValueMinimum = BountyValueMinimum;
+	type DataDepositPerByte = DataDepositPerByte;
+	type MaximumReasonLength = MaximumReasonLength;
+	type WeightInfo = ();
+	type ChildBountyManager = ChildBounties;
+}
+parameter_types! {
+	pub const MaxActiveChildBountyCount: u32 = 2;
+	pub const ChildBountyValueMinimum: u64 = 1;
+	pub const ChildBountyCuratorDepositBase: Permill = Permill::from_percent(50);
+}
+impl Config for Test {
+	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type ChildBountyCuratorDepositBase = ChildBountyCuratorDepositBase;
+	type Event = Event;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test>{
+		// Total issuance will be 200 with treasury account initialized at ED.
+		balances: vec![(0, 100), (1, 98), (2, 1)],
+	}.assimilate_storage(&mut t).unwrap();
+	pallet_treasury::GenesisConfig::default().assimilate_storage::<Test, _>(&mut t).unwrap();
+	t.into()
+}
+
+fn last_event() -> pallet_child_bounties::Event<Test> {
+	System::events().into_iter().map(|r| r.event)
+		.filter_map(|e| {
+			if let Event::ChildBounties(inner) = e { Some(inner) } else { None }
+		})
+		.last()
+		.unwrap()
+}
+
+type BountiesError = pallet_bounties::Error<Test>;
+
+// Create the bounty 0 of value 50, with the account 4 as curator for a fee of 6, and the child
+// bounty 0 of value 10.
+fn setup_child_bounty() {
+	System::set_block_number(1);
+	Balances::make_free_balance_be(&Treasury::account_id(), 101);
+	Balances::make_free_balance_be(&4, 10);
+	Balances::make_free_balance_be(&8, 10);
+
+	assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+	assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+	System::set_block_number(2);
+	<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+	assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 6));
+	assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+	assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+
+	assert_ok!(ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p1".to_vec()));
+}
+
+#[test]
+fn add_child_bounty_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+
+		assert_eq!(last_event(), pallet_child_bounties::Event::Added(0, 0));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 40);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 10);
+
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 0,
+			curator_deposit: 0,
+			status: ChildBountyStatus::Added,
+		});
+		assert_eq!(ChildBounties::child_bounty_descriptions(0).unwrap(), b"12345-p1".to_vec());
+		assert_eq!(ChildBounties::child_bounty_count(), 1);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 1);
+	});
+}
+
+#[test]
+fn add_child_bounty_validation_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(1), 0, 10, b"12345-p2".to_vec()),
+			BountiesError::RequireCurator,
+		);
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 0, 0, b"12345-p2".to_vec()),
+			BountiesError::InvalidValue,
+		);
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, [0; 301].to_vec()),
+			BountiesError::ReasonTooBig,
+		);
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 1, 10, b"12345-p2".to_vec()),
+			BountiesError::InvalidIndex,
+		);
+		// The parent bounty account must be kept alive.
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 0, 40, b"12345-p2".to_vec()),
+			Error::<Test>::InsufficientBountyBalance,
+		);
+
+		assert_ok!(ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p2".to_vec()));
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p3".to_vec()),
+			Error::<Test>::TooManyChildBounties,
+		);
+	});
+}
+
+#[test]
+fn add_child_bounty_requires_active_parent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_noop!(
+			ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p1".to_vec()),
+			Error::<Test>::ParentBountyNotActive,
+		);
+	});
+}
+
+#[test]
+fn child_bounty_assign_curator_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+
+		assert_noop!(
+			ChildBounties::propose_curator(Origin::signed(8), 0, 0, 8, 4),
+			BountiesError::RequireCurator,
+		);
+		assert_noop!(
+			ChildBounties::propose_curator(Origin::signed(4), 0, 1, 8, 4),
+			BountiesError::InvalidIndex,
+		);
+		assert_noop!(
+			ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 10),
+			BountiesError::InvalidFee,
+		);
+		// The fee of the parent bounty curator is 6.
+		assert_ok!(ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p2".to_vec()));
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_noop!(
+			ChildBounties::propose_curator(Origin::signed(4), 0, 1, 8, 3),
+			BountiesError::InvalidFee,
+		);
+
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 4,
+			curator_deposit: 0,
+			status: ChildBountyStatus::CuratorProposed { curator: 8 },
+		});
+		assert_eq!(ChildrenCuratorFees::<Test>::get(0), 4);
+
+		assert_noop!(
+			ChildBounties::accept_curator(Origin::signed(3), 0, 0),
+			BountiesError::RequireCurator,
+		);
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 4,
+			curator_deposit: 2,
+			status: ChildBountyStatus::Active { curator: 8 },
+		});
+		assert_eq!(Balances::free_balance(8), 8);
+		assert_eq!(Balances::reserved_balance(8), 2);
+	});
+}
+
+#[test]
+fn award_and_claim_child_bounty_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+
+		assert_noop!(
+			ChildBounties::award_child_bounty(Origin::signed(3), 0, 0, 9),
+			BountiesError::RequireCurator,
+		);
+		assert_ok!(ChildBounties::award_child_bounty(Origin::signed(8), 0, 0, 9));
+		assert_eq!(last_event(), pallet_child_bounties::Event::Awarded(0, 0, 9));
+
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 4,
+			curator_deposit: 2,
+			status: ChildBountyStatus::PendingPayout { curator: 8, beneficiary: 9, unlock_at: 5 },
+		});
+
+		System::set_block_number(4);
+		assert_noop!(
+			ChildBounties::claim_child_bounty(Origin::signed(7), 0, 0),
+			BountiesError::Premature,
+		);
+
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(Origin::signed(7), 0, 0));
+		assert_eq!(last_event(), pallet_child_bounties::Event::Claimed(0, 0, 6, 9));
+
+		assert_eq!(Balances::free_balance(8), 14);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(9), 6);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+
+		assert_eq!(ChildBounties::child_bounties(0, 0), None);
+		assert_eq!(ChildBounties::child_bounty_descriptions(0), None);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+	});
+}
+
+#[test]
+fn close_child_bounty_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+		assert_ok!(ChildBounties::add_child_bounty(Origin::signed(4), 0, 10, b"12345-p2".to_vec()));
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 1, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 1));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 30);
+
+		assert_noop!(ChildBounties::close_child_bounty(Origin::signed(8), 0, 0), BadOrigin);
+
+		// A child bounty without curator.
+		assert_ok!(ChildBounties::close_child_bounty(Origin::signed(4), 0, 0));
+		assert_eq!(last_event(), pallet_child_bounties::Event::Canceled(0, 0));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 40);
+		assert_eq!(ChildBounties::child_bounties(0, 0), None);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 1);
+
+		// A child bounty with an active curator.
+		assert_ok!(ChildBounties::close_child_bounty(Origin::root(), 0, 1));
+		assert_eq!(last_event(), pallet_child_bounties::Event::Canceled(0, 1));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(1)), 0);
+		assert_eq!(Balances::free_balance(8), 10);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+		assert_eq!(ChildrenCuratorFees::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn close_child_bounty_pending_payout_fails() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(Origin::signed(8), 0, 0, 9));
+
+		assert_noop!(
+			ChildBounties::close_child_bounty(Origin::root(), 0, 0),
+			BountiesError::PendingPayout,
+		);
+	});
+}
+
+#[test]
+fn unassign_curator_works() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+		assert_noop!(
+			ChildBounties::unassign_curator(Origin::signed(4), 0, 0),
+			BountiesError::UnexpectedStatus,
+		);
+
+		// A proposed curator can be unassigned by the parent bounty curator.
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_noop!(ChildBounties::unassign_curator(Origin::signed(3), 0, 0), BadOrigin);
+		assert_ok!(ChildBounties::unassign_curator(Origin::signed(4), 0, 0));
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().status, ChildBountyStatus::Added);
+
+		// The child bounty curator gets their deposit back when unassigning themselves.
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::unassign_curator(Origin::signed(8), 0, 0));
+		assert_eq!(Balances::free_balance(8), 10);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		// Anyone else must wait for the parent bounty curator to be inactive, and the deposit of
+		// the child bounty curator is slashed.
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+		assert_noop!(
+			ChildBounties::unassign_curator(Origin::signed(3), 0, 0),
+			BountiesError::Premature,
+		);
+		System::set_block_number(13);
+		assert_ok!(ChildBounties::unassign_curator(Origin::signed(3), 0, 0));
+		assert_eq!(Balances::free_balance(8), 8);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 4,
+			curator_deposit: 0,
+			status: ChildBountyStatus::Added,
+		});
+	});
+}
+
+#[test]
+fn parent_bounty_with_active_child_bounty_cannot_be_awarded_nor_closed() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+
+		assert_noop!(
+			Bounties::award_bounty(Origin::signed(4), 0, 3),
+			BountiesError::HasActiveChildBounty,
+		);
+		assert_noop!(
+			Bounties::close_bounty(Origin::root(), 0),
+			BountiesError::HasActiveChildBounty,
+		);
+
+		assert_ok!(ChildBounties::close_child_bounty(Origin::signed(4), 0, 0));
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+	});
+}
+
+#[test]
+fn child_bounty_curator_fee_is_deducted_from_parent_curator_fee() {
+	new_test_ext().execute_with(|| {
+		setup_child_bounty();
+		assert_ok!(ChildBounties::propose_curator(Origin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(Origin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(Origin::signed(4), 0, 0, 9));
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(Origin::signed(7), 0, 0));
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+		System::set_block_number(8);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(7), 0));
+
+		// The parent bounty curator is paid their fee of 6, minus the child bounty curator fee.
+		assert_eq!(Balances::free_balance(4), 12);
+		assert_eq!(Balances::free_balance(3), 38);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(ChildrenCuratorFees::<Test>::get(0), 0);
+	});
+}
@@ -268,6 +268,8 @@ decl_error! {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// The prime account is not one of the new members.
+		PrimeAccountNotMember,
 	}
 }
 
@@ -292,7 +294,7 @@ decl_module! {
 		/// Set the collective's membership.
 		///
 		/// - `new_members`: The new member list. Be nice to the chain and provide it sorted.
-		/// - `prime`: The prime member whose vote sets the default.
+		/// - `prime`: The prime member whose vote sets the default, one of the new members.
 		/// - `old_count`: The upper bound for the previous number of members in storage.
 		///                Used for weight estimation.
 		///
@@ -345,6 +347,10 @@ decl_module! {
 					old.len(),
 				);
 			}
+			if let Some(prime) = &prime {
+				ensure!(new_members.contains(prime), Error::<T, I>::PrimeAccountNotMember);
+			}
+
 			let mut new_members = new_members;
 			new_members.sort();
 			<Self as ChangeMembers<T::AccountId>>::set_members_sorted(&new_members, &old);
@@ -612,7 +618,7 @@ decl_module! {
 		fn close(origin,
 			proposal_hash: T::Hash,
 			#[compact] index: ProposalIndex,
-			#[compact] proposal_weight_bound: Weight,
+			proposal_weight_bound: Weight,
 			#[compact] length_bound: u32
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
@@ -705,6 +711,10 @@ decl_module! {
 		#[weight = T::WeightInfo::disapprove_proposal(T::MaxProposals::get())]
 		fn disapprove_proposal(origin, proposal_hash: T::Hash) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
+			ensure!(
+				ProposalOf::<T, I>::contains_key(&proposal_hash),
+				Error::<T, I>::ProposalMissing,
+			);
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			Ok(Some(T::WeightInfo::disapprove_proposal(proposal_count)).into())
 		}
@@ -735,7 +745,7 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		ensure!(proposal_len <= length_bound, Error::<T, I>::WrongProposalLength);
 		let proposal = ProposalOf::<T, I>::get(hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		let proposal_weight = proposal.get_dispatch_info().weight;
-		ensure!(proposal_weight <= weight_bound, Error::<T, I>::WrongProposalWeight);
+		ensure!(proposal_weight.all_lte(&weight_bound), Error::<T, I>::WrongProposalWeight);
 		Ok((proposal, proposal_len as usize))
 	}
 
@@ -983,7 +993,7 @@ mod tests {
 		pub const MaxProposals: u32 = 100;
 		pub const MaxMembers: u32 = 100;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(Weight::from_ref_time(1024));
 	}
 	impl frame_system::Config for Test {
 		type BaseCallFilter = ();
@@ -1128,7 +1138,13 @@ mod tests {
 			// With 1's prime vote, this should pass
 			System::set_block_number(4);
 			assert_noop!(
-				Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight - 100, proposal_len),
+				Collective::close(
+					Origin::signed(4),
+					hash.clone(),
+					0,
+					proposal_weight - Weight::from_ref_time(100),
+					proposal_len,
+				),
 				Error::<Test, Instance1>::WrongProposalWeight
 			);
 			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
@@ -1146,9 +1162,13 @@ mod tests {
 			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
 			// No votes, this proposal wont pass
 			System::set_block_number(4);
-			assert_ok!(
-				Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight - 100, proposal_len)
-			);
+			assert_ok!(Collective::close(
+				Origin::signed(4),
+				hash.clone(),
+				0,
+				proposal_weight - Weight::from_ref_time(100),
+				proposal_len,
+			));
 		})
 	}
 
@@ -1368,7 +1388,11 @@ mod tests {
 				Error::<Test, Instance1>::WrongProposalLength
 			);
 			assert_noop!(
-				Collective::validate_and_get_proposal(&hash, length, weight - 10),
+				Collective::validate_and_get_proposal(
+					&hash,
+					length,
+					weight - Weight::from_ref_time(10),
+				),
 				Error::<Test, Instance1>::WrongProposalWeight
 			);
 			let res = Collective::validate_and_get_proposal(&hash, length, weight);
@@ -1735,6 +1759,24 @@ mod tests {
 				record(Event::Collective(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
 				record(Event::Collective(RawEvent::Disapproved(hash.clone()))),
 			]);
+
+			// A removed proposal can't be disapproved again.
+			assert_noop!(
+				Collective::disapprove_proposal(Origin::root(), hash),
+				Error::<Test, Instance1>::ProposalMissing,
+			);
+		})
+	}
+
+	#[test]
+	fn set_members_rejects_prime_not_member() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Collective::set_members(Origin::root(), vec![1, 2, 3], Some(4), MaxMembers::get()),
+				Error::<Test, Instance1>::PrimeAccountNotMember,
+			);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(3), MaxMembers::get()));
+			assert_eq!(Collective::prime(), Some(3));
 		})
 	}
 }
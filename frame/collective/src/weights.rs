@@ -60,84 +60,84 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_members(m: u32, n: u32, p: u32, ) -> Weight {
-		(0 as Weight)
-			.saturating_add((20_933_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((254_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+		Weight::from_ref_time(0 as u64)
+			.saturating_add(Weight::from_ref_time(20_933_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(254_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(28_233_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn execute(b: u32, m: u32, ) -> Weight {
-		(31_147_000 as Weight)
-			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((115_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(31_147_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(115_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
 
 	}
 	fn propose_execute(b: u32, m: u32, ) -> Weight {
-		(38_774_000 as Weight)
-			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+		Weight::from_ref_time(38_774_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(226_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
 
 	}
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
-		(64_230_000 as Weight)
-			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(64_230_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(138_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(637_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 
 	}
 	fn vote(m: u32, ) -> Weight {
-		(57_051_000 as Weight)
-			.saturating_add((220_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(57_051_000 as u64)
+			.saturating_add(Weight::from_ref_time(220_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 
 	}
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		(61_406_000 as Weight)
-			.saturating_add((225_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((630_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(61_406_000 as u64)
+			.saturating_add(Weight::from_ref_time(225_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(630_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 
 	}
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		(92_864_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((597_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(92_864_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(233_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(597_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 
 	}
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		(67_942_000 as Weight)
-			.saturating_add((232_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((636_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(67_942_000 as u64)
+			.saturating_add(Weight::from_ref_time(232_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(636_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 
 	}
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		(99_742_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((598_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(99_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(233_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(598_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 
 	}
 	fn disapprove_proposal(p: u32, ) -> Weight {
-		(36_628_000 as Weight)
-			.saturating_add((640_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(36_628_000 as u64)
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 
 	}
 
@@ -146,84 +146,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn set_members(m: u32, n: u32, p: u32, ) -> Weight {
-		(0 as Weight)
-			.saturating_add((20_933_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((254_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add((28_233_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+		Weight::from_ref_time(0 as u64)
+			.saturating_add(Weight::from_ref_time(20_933_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(254_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(28_233_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn execute(b: u32, m: u32, ) -> Weight {
-		(31_147_000 as Weight)
-			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((115_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(31_147_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(115_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 
 	}
 	fn propose_execute(b: u32, m: u32, ) -> Weight {
-		(38_774_000 as Weight)
-			.saturating_add((4_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+		Weight::from_ref_time(38_774_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(226_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 
 	}
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
-		(64_230_000 as Weight)
-			.saturating_add((5_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((138_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((637_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(64_230_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(138_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(637_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 
 	}
 	fn vote(m: u32, ) -> Weight {
-		(57_051_000 as Weight)
-			.saturating_add((220_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(57_051_000 as u64)
+			.saturating_add(Weight::from_ref_time(220_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 
 	}
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		(61_406_000 as Weight)
-			.saturating_add((225_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((630_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(61_406_000 as u64)
+			.saturating_add(Weight::from_ref_time(225_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(630_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 
 	}
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		(92_864_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((597_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(92_864_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(233_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(597_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 
 	}
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		(67_942_000 as Weight)
-			.saturating_add((232_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((636_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(67_942_000 as u64)
+			.saturating_add(Weight::from_ref_time(232_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(636_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 
 	}
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		(99_742_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((233_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add((598_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(99_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(b as u64))
+			.saturating_add(Weight::from_ref_time(233_000 as u64).saturating_mul(m as u64))
+			.saturating_add(Weight::from_ref_time(598_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 
 	}
 	fn disapprove_proposal(p: u32, ) -> Weight {
-		(36_628_000 as Weight)
-			.saturating_add((640_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(36_628_000 as u64)
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 
 	}
 
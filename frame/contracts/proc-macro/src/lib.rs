@@ -24,12 +24,12 @@ extern crate alloc;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, Ident};
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, Ident, Type};
 use alloc::string::ToString;
 
-/// This derives `Debug` for a struct where each field must be of some numeric type.
+/// This derives `Debug` for a struct where each field must be of some numeric type or a `Weight`.
 /// It interprets each field as its represents some weight and formats it as times so that
-/// it is readable by humans.
+/// it is readable by humans. Only the computation time of a `Weight` is formatted.
 #[proc_macro_derive(WeightDebug)]
 pub fn derive_weight_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	derive_debug(input, format_weight)
@@ -44,7 +44,7 @@ pub fn derive_schedule_debug(input: proc_macro::TokenStream) -> proc_macro::Toke
 
 fn derive_debug(
 	input: proc_macro::TokenStream,
-	fmt: impl Fn(&Ident) -> TokenStream
+	fmt: impl Fn(&Ident, &Type) -> TokenStream
 ) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 	let name = &input.ident;
@@ -84,7 +84,7 @@ fn derive_debug(
 
 /// This is only used then the `full` feature is activated.
 #[cfg(feature = "full")]
-fn iterate_fields(data: &DataStruct, fmt: impl Fn(&Ident) -> TokenStream) -> TokenStream {
+fn iterate_fields(data: &DataStruct, fmt: impl Fn(&Ident, &Type) -> TokenStream) -> TokenStream {
 	match &data.fields {
 		Fields::Named(fields) => {
 			let recurse = fields.named
@@ -94,7 +94,7 @@ fn iterate_fields(data: &DataStruct, fmt: impl Fn(&Ident) -> TokenStream) -> Tok
 				if name.to_string().starts_with('_') {
 					return None;
 				}
-				let value = fmt(name);
+				let value = fmt(name, &f.ty);
 				let ret = quote_spanned!{ f.span() =>
 					formatter.field(stringify!(#name), #value);
 				};
@@ -112,30 +112,43 @@ fn iterate_fields(data: &DataStruct, fmt: impl Fn(&Ident) -> TokenStream) -> Tok
 	}
 }
 
-fn format_weight(field: &Ident) -> TokenStream {
+fn format_weight(field: &Ident, ty: &Type) -> TokenStream {
+	let value = if is_weight(ty) {
+		quote!(self.#field.ref_time())
+	} else {
+		quote!(self.#field)
+	};
 	quote_spanned! { field.span() =>
-		&if self.#field > 1_000_000_000 {
+		&if #value > 1_000_000_000 {
 			format!(
 				"{:.1?} ms",
-				Fixed::saturating_from_rational(self.#field, 1_000_000_000).to_float()
+				Fixed::saturating_from_rational(#value, 1_000_000_000).to_float()
 			)
-		} else if self.#field > 1_000_000 {
+		} else if #value > 1_000_000 {
 			format!(
 				"{:.1?} µs",
-				Fixed::saturating_from_rational(self.#field, 1_000_000).to_float()
+				Fixed::saturating_from_rational(#value, 1_000_000).to_float()
 			)
-		} else if self.#field > 1_000 {
+		} else if #value > 1_000 {
 			format!(
 				"{:.1?} ns",
-				Fixed::saturating_from_rational(self.#field, 1_000).to_float()
+				Fixed::saturating_from_rational(#value, 1_000).to_float()
 			)
 		} else {
-			format!("{} ps", self.#field)
+			format!("{} ps", #value)
 		}
 	}
 }
 
-fn format_default(field: &Ident) -> TokenStream {
+/// Whether `ty` is the `Weight` struct, as opposed to a plain number.
+fn is_weight(ty: &Type) -> bool {
+	match ty {
+		Type::Path(path) => path.path.segments.last().map_or(false, |s| s.ident == "Weight"),
+		_ => false,
+	}
+}
+
+fn format_default(field: &Ident, _ty: &Type) -> TokenStream {
 	quote_spanned! { field.span() =>
 		&self.#field
 	}
@@ -22,7 +22,6 @@ use std::sync::Arc;
 use codec::Codec;
 use jsonrpc_core::{Error, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use pallet_contracts_primitives::RentProjection;
 use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
@@ -39,7 +38,6 @@ pub use pallet_contracts_rpc_runtime_api::ContractsApi as ContractsRuntimeApi;
 
 const RUNTIME_ERROR: i64 = 1;
 const CONTRACT_DOESNT_EXIST: i64 = 2;
-const CONTRACT_IS_A_TOMBSTONE: i64 = 3;
 
 pub type Weight = u64;
 
@@ -68,11 +66,6 @@ impl From<ContractAccessError> for Error {
 				message: "The specified contract doesn't exist.".into(),
 				data: None,
 			},
-			IsTombstone => Error {
-				code: ErrorCode::ServerError(CONTRACT_IS_A_TOMBSTONE),
-				message: "The contract is a tombstone and doesn't have any storage.".into(),
-				data: None,
-			},
 		}
 	}
 }
@@ -86,6 +79,7 @@ pub struct CallRequest<AccountId> {
 	dest: AccountId,
 	value: NumberOrHex,
 	gas_limit: NumberOrHex,
+	storage_deposit_limit: Option<NumberOrHex>,
 	input_data: Bytes,
 }
 
@@ -97,6 +91,7 @@ pub struct InstantiateRequest<AccountId, Hash> {
 	origin: AccountId,
 	endowment: NumberOrHex,
 	gas_limit: NumberOrHex,
+	storage_deposit_limit: Option<NumberOrHex>,
 	code: Code<Hash>,
 	data: Bytes,
 	salt: Bytes,
@@ -116,7 +111,7 @@ pub trait ContractsApi<BlockHash, BlockNumber, AccountId, Balance, Hash> {
 		&self,
 		call_request: CallRequest<AccountId>,
 		at: Option<BlockHash>,
-	) -> Result<ContractExecResult>;
+	) -> Result<ContractExecResult<Balance>>;
 
 	/// Instantiate a new contract.
 	///
@@ -129,7 +124,7 @@ pub trait ContractsApi<BlockHash, BlockNumber, AccountId, Balance, Hash> {
 		&self,
 		instantiate_request: InstantiateRequest<AccountId, Hash>,
 		at: Option<BlockHash>,
-	) -> Result<ContractInstantiateResult<AccountId, BlockNumber>>;
+	) -> Result<ContractInstantiateResult<AccountId, Balance>>;
 
 	/// Returns the value under a specified storage `key` in a contract given by `address` param,
 	/// or `None` if it is not set.
@@ -140,19 +135,6 @@ pub trait ContractsApi<BlockHash, BlockNumber, AccountId, Balance, Hash> {
 		key: H256,
 		at: Option<BlockHash>,
 	) -> Result<Option<Bytes>>;
-
-	/// Returns the projected time a given contract will be able to sustain paying its rent.
-	///
-	/// The returned projection is relevant for the given block, i.e. it is as if the contract was
-	/// accessed at the beginning of that block.
-	///
-	/// Returns `None` if the contract is exempted from rent.
-	#[rpc(name = "contracts_rentProjection")]
-	fn rent_projection(
-		&self,
-		address: AccountId,
-		at: Option<BlockHash>,
-	) -> Result<Option<BlockNumber>>;
 }
 
 /// An implementation of contract specific RPC methods.
@@ -196,7 +178,7 @@ where
 		&self,
 		call_request: CallRequest<AccountId>,
 		at: Option<<Block as BlockT>::Hash>,
-	) -> Result<ContractExecResult> {
+	) -> Result<ContractExecResult<Balance>> {
 		let api = self.client.runtime_api();
 		let at = BlockId::hash(at.unwrap_or_else(||
 			// If the block hash is not supplied assume the best block.
@@ -207,15 +189,18 @@ where
 			dest,
 			value,
 			gas_limit,
+			storage_deposit_limit,
 			input_data,
 		} = call_request;
 
 		let value: Balance = decode_hex(value, "balance")?;
 		let gas_limit: Weight = decode_hex(gas_limit, "weight")?;
+		let storage_deposit_limit: Option<Balance> =
+			storage_deposit_limit.map(|l| decode_hex(l, "balance")).transpose()?;
 		limit_gas(gas_limit)?;
 
 		let exec_result = api
-			.call(&at, origin, dest, value, gas_limit, input_data.to_vec())
+			.call(&at, origin, dest, value, gas_limit, storage_deposit_limit, input_data.to_vec())
 			.map_err(runtime_error_into_rpc_err)?;
 
 		Ok(exec_result)
@@ -225,7 +210,7 @@ where
 		&self,
 		instantiate_request: InstantiateRequest<AccountId, Hash>,
 		at: Option<<Block as BlockT>::Hash>,
-	) -> Result<ContractInstantiateResult<AccountId, <<Block as BlockT>::Header as HeaderT>::Number>> {
+	) -> Result<ContractInstantiateResult<AccountId, Balance>> {
 		let api = self.client.runtime_api();
 		let at = BlockId::hash(at.unwrap_or_else(||
 			// If the block hash is not supplied assume the best block.
@@ -235,6 +220,7 @@ where
 			origin,
 			endowment,
 			gas_limit,
+			storage_deposit_limit,
 			code,
 			data,
 			salt,
@@ -242,10 +228,21 @@ where
 
 		let endowment: Balance = decode_hex(endowment, "balance")?;
 		let gas_limit: Weight = decode_hex(gas_limit, "weight")?;
+		let storage_deposit_limit: Option<Balance> =
+			storage_deposit_limit.map(|l| decode_hex(l, "balance")).transpose()?;
 		limit_gas(gas_limit)?;
 
 		let exec_result = api
-			.instantiate(&at, origin, endowment, gas_limit, code, data.to_vec(), salt.to_vec())
+			.instantiate(
+				&at,
+				origin,
+				endowment,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data.to_vec(),
+				salt.to_vec(),
+			)
 			.map_err(runtime_error_into_rpc_err)?;
 
 		Ok(exec_result)
@@ -270,27 +267,6 @@ where
 
 		Ok(result)
 	}
-
-	fn rent_projection(
-		&self,
-		address: AccountId,
-		at: Option<<Block as BlockT>::Hash>,
-	) -> Result<Option<<<Block as BlockT>::Header as HeaderT>::Number>> {
-		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or_else(||
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash));
-
-		let result = api
-			.rent_projection(&at, address)
-			.map_err(runtime_error_into_rpc_err)?
-			.map_err(ContractAccessError)?;
-
-		Ok(match result {
-			RentProjection::NoEviction => None,
-			RentProjection::EvictionAt(block_num) => Some(block_num),
-		})
-	}
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -343,10 +319,12 @@ mod tests {
 			"dest": "5DRakbLVnjVrW6niwLfHGW24EeCEvDAFGEXrtaYS5M4ynoom",
 			"value": "0x112210f4B16c1cb1",
 			"gasLimit": 1000000000000,
+			"storageDepositLimit": 5000,
 			"inputData": "0x8c97db39"
 		}
 		"#).unwrap();
 		assert_eq!(req.gas_limit.into_u256(), U256::from(0xe8d4a51000u64));
+		assert_eq!(req.storage_deposit_limit.map(|l| l.into_u256()), Some(5000.into()));
 		assert_eq!(req.value.into_u256(), U256::from(1234567890987654321u128));
 	}
 
@@ -367,6 +345,7 @@ mod tests {
 		assert_eq!(req.origin, "5CiPPseXPECbkjWCa6MnjNokrgYjMqmKndv2rSnekmSK2DjL");
 		assert_eq!(req.endowment.into_u256(), 0x88.into());
 		assert_eq!(req.gas_limit.into_u256(), 42.into());
+		assert_eq!(req.storage_deposit_limit, None);
 		assert_eq!(&*req.data, [0x42, 0x99].as_ref());
 		assert_eq!(&*req.salt, [0x99, 0x88].as_ref());
 		let code = match req.code {
@@ -379,12 +358,15 @@ mod tests {
 	#[test]
 	fn call_result_should_serialize_deserialize_properly() {
 		fn test(expected: &str) {
-			let res: ContractExecResult = serde_json::from_str(expected).unwrap();
+			let res: ContractExecResult<u32> = serde_json::from_str(expected).unwrap();
 			let actual = serde_json::to_string(&res).unwrap();
 			assert_eq!(actual, trim(expected).as_str());
 		}
 		test(r#"{
 			"gasConsumed": 5000,
+			"storageDeposit": {
+				"charge": 200
+			},
 			"debugMessage": "0x68656c704f6b",
 			"result": {
 			  "Ok": {
@@ -395,6 +377,9 @@ mod tests {
 		}"#);
 		test(r#"{
 			"gasConsumed": 3400,
+			"storageDeposit": {
+				"refund": 20
+			},
 			"debugMessage": "0x68656c70457272",
 			"result": {
 			  "Err": "BadOrigin"
@@ -411,6 +396,9 @@ mod tests {
 		}
 		test(r#"{
 			"gasConsumed": 5000,
+			"storageDeposit": {
+				"refund": 12000
+			},
 			"debugMessage": "0x68656c704f6b",
 			"result": {
 			   "Ok": {
@@ -418,13 +406,15 @@ mod tests {
 					 "flags": 5,
 					 "data": "0x1234"
 				  },
-				  "accountId": "5CiPP",
-				  "rentProjection": null
+				  "accountId": "5CiPP"
 			   }
 			}
 		}"#);
 		test(r#"{
 			"gasConsumed": 3400,
+			"storageDeposit": {
+				"charge": 0
+			},
 			"debugMessage": "0x68656c70457272",
 			"result": {
 			  "Err": "BadOrigin"
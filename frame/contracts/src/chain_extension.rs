@@ -29,6 +29,14 @@
 //! required for this endeavour are defined or re-exported in this module. There is an
 //! implementation on `()` which can be used to signal that no chain extension is available.
 //!
+//! # Multiple chain extensions
+//!
+//! Several chain extensions can be declared at once as a tuple of types implementing
+//! [`RegisteredChainExtension`]. The upper 16 bits of the `func_id` passed by the contract then
+//! select the chain extension by its [`ID`](RegisteredChainExtension::ID), which is called with
+//! the whole `func_id`, and is free to interpret its lower 16 bits. Calling an unknown or
+//! disabled chain extension returns [`NoChainExtension`](Error::NoChainExtension).
+//!
 //! # Security
 //!
 //! The chain author alone is responsible for the security of the chain extension.
@@ -132,6 +140,46 @@ impl<C: Config> ChainExtension<C> for () {
 	}
 }
 
+/// A [`ChainExtension`] which can be declared along with others in a tuple.
+///
+/// Consult the [module documentation](self) for how the chain extensions of a tuple are selected.
+pub trait RegisteredChainExtension<C: Config>: ChainExtension<C> {
+	/// The unique id of the chain extension, the upper 16 bits of the `func_id` selecting it.
+	const ID: u16;
+}
+
+/// The id of the chain extension selected by `func_id`.
+pub fn ext_id(func_id: u32) -> u16 {
+	(func_id >> 16) as u16
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 10)]
+#[tuple_types_custom_trait_bound(RegisteredChainExtension<C>)]
+impl<C: Config> ChainExtension<C> for Tuple {
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal>
+	where
+		E: Ext<T = C>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let ext_id = ext_id(func_id);
+		for_tuples!( #(
+			if Tuple::ID == ext_id && Tuple::enabled() {
+				return Tuple::call(func_id, env)
+			}
+		)* );
+		Err(Error::<E::T>::NoChainExtension.into())
+	}
+
+	fn enabled() -> bool {
+		for_tuples!( #(
+			if Tuple::enabled() {
+				return true
+			}
+		)* );
+		false
+	}
+}
+
 /// Determines the exit behaviour and return value of a chain extension.
 pub enum RetVal {
 	/// The chain extensions returns the supplied value to its calling contract.
@@ -17,8 +17,8 @@
 
 use crate::{
 	CodeHash, Event, Config, Pallet as Contracts,
-	BalanceOf, ContractInfo, gas::GasMeter, rent::{Rent, RentStatus}, storage::Storage,
-	Error, ContractInfoOf, Schedule, AliveContractInfo, AccountCounter,
+	BalanceOf, ContractInfo, gas::GasMeter, Error, ContractInfoOf, Schedule, AccountCounter,
+	storage::{Storage, meter::{Diff, Meter as StorageMeter}},
 };
 use sp_core::crypto::UncheckedFrom;
 use sp_std::{
@@ -26,13 +26,13 @@ use sp_std::{
 	marker::PhantomData,
 	mem,
 };
-use sp_runtime::{Perbill, traits::{Convert, Saturating}};
+use sp_runtime::traits::{Convert, Saturating};
 use frame_support::{
 	dispatch::{DispatchResult, DispatchError},
 	storage::{with_transaction, TransactionOutcome},
 	traits::{ExistenceRequirement, Currency, Time, Randomness, Get},
 	weights::Weight,
-	ensure, DefaultNoBound,
+	ensure,
 };
 use pallet_contracts_primitives::{ExecReturnValue};
 use smallvec::{SmallVec, Array};
@@ -81,67 +81,6 @@ impl<T: Into<DispatchError>> From<T> for ExecError {
 	}
 }
 
-/// Information needed for rent calculations that can be requested by a contract.
-#[derive(codec::Encode, DefaultNoBound)]
-#[cfg_attr(test, derive(Debug, PartialEq))]
-pub struct RentParams<T: Config> {
-	/// The total balance of the contract. Includes the balance transferred from the caller.
-	total_balance: BalanceOf<T>,
-	/// The free balance of the contract. Includes the balance transferred from the caller.
-	free_balance: BalanceOf<T>,
-	/// See crate [`Contracts::subsistence_threshold()`].
-	subsistence_threshold: BalanceOf<T>,
-	/// See crate [`Config::DepositPerContract`].
-	deposit_per_contract: BalanceOf<T>,
-	/// See crate [`Config::DepositPerStorageByte`].
-	deposit_per_storage_byte: BalanceOf<T>,
-	/// See crate [`Config::DepositPerStorageItem`].
-	deposit_per_storage_item: BalanceOf<T>,
-	/// See crate [`Ext::rent_allowance()`].
-	rent_allowance: BalanceOf<T>,
-	/// See crate [`Config::RentFraction`].
-	rent_fraction: Perbill,
-	/// See crate [`AliveContractInfo::storage_size`].
-	storage_size: u32,
-	/// See crate [`Executable::aggregate_code_len()`].
-	code_size: u32,
-	/// See crate [`Executable::refcount()`].
-	code_refcount: u32,
-	/// Reserved for backwards compatible changes to this data structure.
-	_reserved: Option<()>,
-}
-
-impl<T> RentParams<T>
-where
-	T: Config,
-	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
-{
-	/// Derive new `RentParams` from the passed in data.
-	///
-	/// `value` is added to the current free and total balance of the contracts' account.
-	fn new<E: Executable<T>>(
-		account_id: &T::AccountId,
-		value: &BalanceOf<T>,
-		contract: &AliveContractInfo<T>,
-		executable: &E
-	) -> Self {
-		Self {
-			total_balance: T::Currency::total_balance(account_id).saturating_add(*value),
-			free_balance: T::Currency::free_balance(account_id).saturating_add(*value),
-			subsistence_threshold: <Contracts<T>>::subsistence_threshold(),
-			deposit_per_contract: T::DepositPerContract::get(),
-			deposit_per_storage_byte: T::DepositPerStorageByte::get(),
-			deposit_per_storage_item: T::DepositPerStorageItem::get(),
-			rent_allowance: contract.rent_allowance,
-			rent_fraction: T::RentFraction::get(),
-			storage_size: contract.storage_size,
-			code_size: executable.aggregate_code_len(),
-			code_refcount: executable.refcount(),
-			_reserved: None,
-		}
-	}
-}
-
 /// An interface that provides access to the external environment in which the
 /// smart-contract is executed.
 ///
@@ -205,25 +144,6 @@ pub trait Ext: sealing::Sealed {
 		beneficiary: &AccountIdOf<Self::T>,
 	) -> Result<u32, (DispatchError, u32)>;
 
-	/// Restores the given destination contract sacrificing the current one.
-	///
-	/// Since this function removes the self contract eagerly, if succeeded, no further actions should
-	/// be performed on this `Ext` instance.
-	///
-	/// This function will fail if the same contract is present
-	/// on the contract call stack.
-	///
-	/// # Return Value
-	///
-	/// Result<(CallerCodeSize, DestCodeSize), (DispatchError, CallerCodeSize, DestCodesize)>
-	fn restore_to(
-		&mut self,
-		dest: AccountIdOf<Self::T>,
-		code_hash: CodeHash<Self::T>,
-		rent_allowance: BalanceOf<Self::T>,
-		delta: Vec<StorageKey>,
-	) -> Result<(u32, u32), (DispatchError, u32, u32)>;
-
 	/// Transfer some amount of funds into the specified account.
 	fn transfer(
 		&mut self,
@@ -261,9 +181,6 @@ pub trait Ext: sealing::Sealed {
 	/// Returns the minimum balance that is required for creating an account.
 	fn minimum_balance(&self) -> BalanceOf<Self::T>;
 
-	/// Returns the deposit required to create a tombstone upon contract eviction.
-	fn tombstone_deposit(&self) -> BalanceOf<Self::T>;
-
 	/// Returns a random number for the current block with the given subject.
 	fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberOf<Self::T>);
 
@@ -272,12 +189,6 @@ pub trait Ext: sealing::Sealed {
 	/// There should not be any duplicates in `topics`.
 	fn deposit_event(&mut self, topics: Vec<TopicOf<Self::T>>, data: Vec<u8>);
 
-	/// Set rent allowance of the contract
-	fn set_rent_allowance(&mut self, rent_allowance: BalanceOf<Self::T>);
-
-	/// Rent allowance of the contract
-	fn rent_allowance(&mut self) -> BalanceOf<Self::T>;
-
 	/// Returns the current block number.
 	fn block_number(&self) -> BlockNumberOf<Self::T>;
 
@@ -290,12 +201,6 @@ pub trait Ext: sealing::Sealed {
 	/// Get a reference to the schedule used by the current call.
 	fn schedule(&self) -> &Schedule<Self::T>;
 
-	/// Information needed for rent calculations.
-	fn rent_params(&self) -> &RentParams<Self::T>;
-
-	/// Information about the required deposit and resulting rent.
-	fn rent_status(&mut self, at_refcount: u32) -> RentStatus<Self::T>;
-
 	/// Get a mutable reference to the nested gas meter.
 	fn gas_meter(&mut self) -> &mut GasMeter<Self::T>;
 
@@ -372,29 +277,6 @@ pub trait Executable<T: Config>: Sized {
 
 	/// Size of the instrumented code in bytes.
 	fn code_len(&self) -> u32;
-
-	/// Sum of instrumented and pristine code len.
-	fn aggregate_code_len(&self) -> u32;
-
-	// The number of contracts using this executable.
-	fn refcount(&self) -> u32;
-
-	/// The storage that is occupied by the instrumented executable and its pristine source.
-	///
-	/// The returned size is already divided by the number of users who share the code.
-	/// This is essentially `aggregate_code_len() / refcount()`.
-	///
-	/// # Note
-	///
-	/// This works with the current in-memory value of refcount. When calling any contract
-	/// without refetching this from storage the result can be inaccurate as it might be
-	/// working with a stale value. Usually this inaccuracy is tolerable.
-	fn occupied_storage(&self) -> u32 {
-		// We disregard the size of the struct itself as the size is completely
-		// dominated by the code size.
-		let len = self.aggregate_code_len();
-		len.checked_div(self.refcount()).unwrap_or(len)
-	}
 }
 
 /// The complete call stack of a contract execution.
@@ -415,6 +297,8 @@ pub struct Stack<'a, T: Config, E> {
 	schedule: &'a Schedule<T>,
 	/// The gas meter where costs are charged to.
 	gas_meter: &'a mut GasMeter<T>,
+	/// The meter where the storage deposits are collected from the [`Self::origin`].
+	storage_meter: &'a mut StorageMeter<T>,
 	/// The timestamp at the point of call stack instantiation.
 	timestamp: MomentOf<T>,
 	/// The block number at the time of call stack instantiation.
@@ -452,8 +336,10 @@ pub struct Frame<T: Config> {
 	contract_info: CachedContract<T>,
 	/// The amount of balance transferred by the caller as part of the call.
 	value_transferred: BalanceOf<T>,
-	/// Snapshotted rent information that can be copied to the contract if requested.
-	rent_params: RentParams<T>,
+	/// The storage the contract gained and lost during this frame.
+	///
+	/// Its deposit is settled when the frame finishes successfully.
+	storage_diff: Diff,
 	/// Determines whether this is a call or instantiate frame.
 	entry_point: ExportedFunction,
 	/// The gas meter capped to the supplied gas limit.
@@ -470,7 +356,7 @@ enum FrameArgs<'a, T: Config, E> {
 		/// The account id of the contract that is to be called.
 		dest: T::AccountId,
 		/// If `None` the contract info needs to be reloaded from storage.
-		cached_info: Option<AliveContractInfo<T>>,
+		cached_info: Option<ContractInfo<T>>,
 	},
 	Instantiate {
 		/// The contract or signed origin which instantiates the new contract.
@@ -487,12 +373,12 @@ enum FrameArgs<'a, T: Config, E> {
 /// Describes the different states of a contract as contained in a `Frame`.
 enum CachedContract<T: Config> {
 	/// The cached contract is up to date with the in-storage value.
-	Cached(AliveContractInfo<T>),
+	Cached(ContractInfo<T>),
 	/// A recursive call into the same contract did write to the contract info.
 	///
 	/// In this case the cached contract is stale and needs to be reloaded from storage.
 	Invalidated,
-	/// The current contract executed `terminate` or `restore_to` and removed the contract.
+	/// The current contract executed `terminate` and removed the contract.
 	///
 	/// In this case a reload is neither allowed nor possible. Please note that recursive
 	/// calls cannot remove a contract as this is checked and denied.
@@ -501,13 +387,8 @@ enum CachedContract<T: Config> {
 
 impl<T: Config> Frame<T> {
 	/// Return the `contract_info` of the current contract.
-	fn contract_info(&mut self) -> &mut AliveContractInfo<T> {
-		self.contract_info.as_alive(&self.account_id)
-	}
-
-	/// Invalidate and return the `contract_info` of the current contract.
-	fn invalidate(&mut self) -> AliveContractInfo<T> {
-		self.contract_info.invalidate(&self.account_id)
+	fn contract_info(&mut self) -> &mut ContractInfo<T> {
+		self.contract_info.get(&self.account_id)
 	}
 
 	/// Terminate and return the `contract_info` of the current contract.
@@ -516,7 +397,7 @@ impl<T: Config> Frame<T> {
 	///
 	/// Under no circumstances the contract is allowed to access the `contract_info` after
 	/// a call to this function. This would constitute a programming error in the exec module.
-	fn terminate(&mut self) -> AliveContractInfo<T> {
+	fn terminate(&mut self) -> ContractInfo<T> {
 		self.contract_info.terminate(&self.account_id)
 	}
 }
@@ -531,7 +412,7 @@ macro_rules! get_cached_or_panic_after_load {
 		} else {
 			panic!(
 				"It is impossible to remove a contract that is on the call stack;\
-				See implementations of terminate and restore_to;\
+				See implementation of terminate;\
 				Therefore fetching a contract will never fail while using an account id
 				that is currently active on the call stack;\
 				qed"
@@ -544,28 +425,20 @@ impl<T: Config> CachedContract<T> {
 	/// Load the `contract_info` from storage if necessary.
 	fn load(&mut self, account_id: &T::AccountId) {
 		if let CachedContract::Invalidated = self {
-			let contract = <ContractInfoOf<T>>::get(&account_id)
-				.and_then(|contract| contract.get_alive());
-			if let Some(contract) = contract {
+			if let Some(contract) = <ContractInfoOf<T>>::get(&account_id) {
 				*self = CachedContract::Cached(contract);
 			}
 		}
 	}
 
-	/// Return the cached contract_info as alive contract info.
-	fn as_alive(&mut self, account_id: &T::AccountId) -> &mut AliveContractInfo<T> {
+	/// Return the cached contract_info.
+	fn get(&mut self, account_id: &T::AccountId) -> &mut ContractInfo<T> {
 		self.load(account_id);
 		get_cached_or_panic_after_load!(self)
 	}
 
-	/// Invalidate and return the contract info.
-	fn invalidate(&mut self, account_id: &T::AccountId) -> AliveContractInfo<T> {
-		self.load(account_id);
-		get_cached_or_panic_after_load!(mem::replace(self, Self::Invalidated))
-	}
-
 	/// Terminate and return the contract info.
-	fn terminate(&mut self, account_id: &T::AccountId) -> AliveContractInfo<T> {
+	fn terminate(&mut self, account_id: &T::AccountId) -> ContractInfo<T> {
 		self.load(account_id);
 		get_cached_or_panic_after_load!(mem::replace(self, Self::Terminated))
 	}
@@ -591,6 +464,7 @@ where
 		origin: T::AccountId,
 		dest: T::AccountId,
 		gas_meter: &'a mut GasMeter<T>,
+		storage_meter: &'a mut StorageMeter<T>,
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
@@ -600,6 +474,7 @@ where
 			FrameArgs::Call{dest, cached_info: None},
 			origin,
 			gas_meter,
+			storage_meter,
 			schedule,
 			value,
 			debug_message,
@@ -621,6 +496,7 @@ where
 		origin: T::AccountId,
 		executable: E,
 		gas_meter: &'a mut GasMeter<T>,
+		storage_meter: &'a mut StorageMeter<T>,
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
@@ -636,6 +512,7 @@ where
 			},
 			origin,
 			gas_meter,
+			storage_meter,
 			schedule,
 			value,
 			debug_message,
@@ -651,6 +528,7 @@ where
 		args: FrameArgs<T, E>,
 		origin: T::AccountId,
 		gas_meter: &'a mut GasMeter<T>,
+		storage_meter: &'a mut StorageMeter<T>,
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		debug_message: Option<&'a mut Vec<u8>>,
@@ -660,6 +538,7 @@ where
 			origin,
 			schedule,
 			gas_meter,
+			storage_meter,
 			timestamp: T::Time::now(),
 			block_number: <frame_system::Pallet<T>>::block_number(),
 			account_counter: None,
@@ -689,25 +568,12 @@ where
 					contract
 				} else {
 					<ContractInfoOf<T>>::get(&dest)
-						.ok_or((<Error<T>>::ContractNotFound.into(), 0))
-						.and_then(|contract|
-							contract.get_alive()
-								.ok_or((<Error<T>>::ContractIsTombstone.into(), 0))
-						)?
+						.ok_or((<Error<T>>::ContractNotFound.into(), 0))?
 				};
 
 				let executable = E::from_storage(contract.code_hash, schedule, gas_meter)
 					.map_err(|e| (e.into(), 0))?;
 
-				// This charges the rent and denies access to a contract that is in need of
-				// eviction by returning `None`. We cannot evict eagerly here because those
-				// changes would be rolled back in case this contract is called by another
-				// contract.
-				// See: https://github.com/paritytech/substrate/issues/6439#issuecomment-648754324
-				let contract = Rent::<T, E>
-					::charge(&dest, contract, executable.occupied_storage())
-					.map_err(|e| (e.into(), executable.code_len()))?
-					.ok_or((Error::<T>::RentNotPaid.into(), executable.code_len()))?;
 				(dest, contract, executable, ExportedFunction::Call)
 			}
 			FrameArgs::Instantiate{sender, trie_seed, executable, salt} => {
@@ -725,10 +591,11 @@ where
 		};
 
 		let frame = Frame {
-			rent_params: RentParams::new(
-				&account_id, &value_transferred, &contract_info, &executable,
-			),
 			value_transferred,
+			storage_diff: Diff {
+				instantiated: entry_point == ExportedFunction::Constructor,
+				..Default::default()
+			},
 			contract_info: CachedContract::Cached(contract_info),
 			account_id,
 			entry_point,
@@ -759,10 +626,7 @@ where
 		if let (CachedContract::Cached(contract), ExportedFunction::Call) =
 			(&frame.contract_info, frame.entry_point)
 		{
-			<ContractInfoOf<T>>::insert(
-				frame.account_id.clone(),
-				ContractInfo::Alive(contract.clone()),
-			);
+			<ContractInfoOf<T>>::insert(frame.account_id.clone(), contract.clone());
 		}
 
 		let nested_meter = &mut self.frames
@@ -789,12 +653,9 @@ where
 		input_data: Vec<u8>
 	) -> Result<(ExecReturnValue, u32), (ExecError, u32)> {
 		let entry_point = self.top_frame().entry_point;
+		// The storage deposits collected by this frame are forgotten if its changes are rolled back.
+		let checkpoint = self.storage_meter.checkpoint();
 		let do_transaction = || {
-			// Cache the value before calling into the constructor because that
-			// consumes the value. If the constructor creates additional contracts using
-			// the same code hash we still charge the "1 block rent" as if they weren't
-			// spawned. This is OK as overcharging is always safe.
-			let occupied_storage = executable.occupied_storage();
 			let code_len = executable.code_len();
 
 			// Every call or instantiate also optionally transferres balance.
@@ -807,28 +668,30 @@ where
 				input_data,
 			).map_err(|e| (ExecError { error: e.error, origin: ErrorOrigin::Callee }, code_len))?;
 
-			// Additional work needs to be performed in case of an instantiation.
-			if output.is_success() && entry_point == ExportedFunction::Constructor {
-				let frame = self.top_frame_mut();
-				let account_id = frame.account_id.clone();
+			// The changes of a reverted execution are rolled back: There is nothing to settle.
+			if !output.is_success() {
+				return Ok((output, code_len));
+			}
+
+			let frame = self.frames.last_mut().unwrap_or(&mut self.first_frame);
+			let account_id = frame.account_id.clone();
 
+			if let CachedContract::Terminated = frame.contract_info {
 				// It is not allowed to terminate a contract inside its constructor.
-				if let CachedContract::Terminated = frame.contract_info {
+				if entry_point == ExportedFunction::Constructor {
 					return Err((Error::<T>::TerminatedInConstructor.into(), code_len));
 				}
+				// Otherwise the whole deposit was already refunded on termination.
+			} else {
+				// Collect the deposit for the storage created by this frame or refund the
+				// deposit of the storage it removed.
+				let diff = mem::take(&mut frame.storage_diff);
+				self.storage_meter.settle(&account_id, frame.contract_info(), diff)
+					.map_err(|e| (e.into(), code_len))?;
+			}
 
-				// Collect the rent for the first block to prevent the creation of very large
-				// contracts that never intended to pay for even one block.
-				// This also makes sure that it is above the subsistence threshold
-				// in order to keep up the guarantuee that we always leave a tombstone behind
-				// with the exception of a contract that called `seal_terminate`.
-				let contract = Rent::<T, E>
-					::charge(&account_id, frame.invalidate(), occupied_storage)
-					.map_err(|e| (e.into(), code_len))?
-					.ok_or((Error::<T>::NewContractNotFunded.into(), code_len))?;
-				frame.contract_info = CachedContract::Cached(contract);
-
-				// Deposit an instantiation event.
+			// Deposit an instantiation event.
+			if entry_point == ExportedFunction::Constructor {
 				deposit_event::<T>(vec![], Event::Instantiated(
 					self.caller().clone(),
 					account_id,
@@ -850,6 +713,9 @@ where
 				_ => TransactionOutcome::Rollback((false, output)),
 			}
 		});
+		if !success {
+			self.storage_meter.revert_to(checkpoint);
+		}
 		self.pop_frame(success);
 		output
 	}
@@ -892,7 +758,7 @@ where
 				// because that case is already handled by the optimization above. Only the first
 				// cache needs to be invalidated because that one will invalidate the next cache
 				// when it is popped from the stack.
-				<ContractInfoOf<T>>::insert(account_id, ContractInfo::Alive(contract));
+				<ContractInfoOf<T>>::insert(account_id, contract);
 				if let Some(c) = self.frames_mut().skip(1).find(|f| f.account_id == *account_id) {
 					c.contract_info = CachedContract::Invalidated;
 				}
@@ -912,10 +778,7 @@ where
 				return;
 			}
 			if let CachedContract::Cached(contract) = &self.first_frame.contract_info {
-				<ContractInfoOf<T>>::insert(
-					&self.first_frame.account_id,
-					ContractInfo::Alive(contract.clone())
-				);
+				<ContractInfoOf<T>>::insert(&self.first_frame.account_id, contract.clone());
 			}
 			if let Some(counter) = self.account_counter {
 				<AccountCounter<T>>::set(counter);
@@ -926,9 +789,10 @@ where
 	/// Transfer some funds from `from` to `to`.
 	///
 	/// We only allow allow for draining all funds of the sender if `allow_death` is
-	/// is specified as `true`. Otherwise, any transfer that would bring the sender below the
-	/// subsistence threshold (for contracts) or the existential deposit (for plain accounts)
-	/// results in an error.
+	/// is specified as `true`. Otherwise, any transfer that would bring the free balance of the
+	/// sender below the subsistence threshold (for contracts) or the existential deposit (for
+	/// plain accounts) results in an error. The storage deposit reserved by a contract does
+	/// not count towards its subsistence threshold.
 	fn transfer(
 		sender_is_contract: bool,
 		allow_death: bool,
@@ -944,7 +808,7 @@ where
 			(true, _) => ExistenceRequirement::AllowDeath,
 			(false, true) => {
 				ensure!(
-					T::Currency::total_balance(from).saturating_sub(value) >=
+					T::Currency::free_balance(from).saturating_sub(value) >=
 						Contracts::<T>::subsistence_threshold(),
 					Error::<T>::BelowSubsistenceThreshold,
 				);
@@ -1127,9 +991,10 @@ where
 		if self.is_recursive() {
 			return Err((Error::<T>::TerminatedWhileReentrant.into(), 0));
 		}
-		let frame = self.top_frame_mut();
-		let info = frame.terminate();
+		let frame = self.frames.last_mut().unwrap_or(&mut self.first_frame);
+		let mut info = frame.terminate();
 		Storage::<T>::queue_trie_for_deletion(&info).map_err(|e| (e, 0))?;
+		self.storage_meter.terminate(&frame.account_id, &mut info).map_err(|e| (e, 0))?;
 		<Stack<'a, T, E>>::transfer(
 			true,
 			true,
@@ -1145,40 +1010,6 @@ where
 		Ok(code_len)
 	}
 
-	fn restore_to(
-		&mut self,
-		dest: AccountIdOf<Self::T>,
-		code_hash: CodeHash<Self::T>,
-		rent_allowance: BalanceOf<Self::T>,
-		delta: Vec<StorageKey>,
-	) -> Result<(u32, u32), (DispatchError, u32, u32)> {
-		if self.is_recursive() {
-			return Err((Error::<T>::TerminatedWhileReentrant.into(), 0, 0));
-		}
-		let origin_contract = self.top_frame_mut().contract_info().clone();
-		let result = Rent::<T, E>::restore_to(
-			&self.top_frame().account_id,
-			origin_contract,
-			dest.clone(),
-			code_hash.clone(),
-			rent_allowance,
-			delta,
-		);
-		if let Ok(_) = result {
-			deposit_event::<Self::T>(
-				vec![],
-				Event::Restored(
-					self.top_frame().account_id.clone(),
-					dest,
-					code_hash,
-					rent_allowance,
-				),
-			);
-			self.top_frame_mut().terminate();
-		}
-		result
-	}
-
 	fn transfer(
 		&mut self,
 		to: &T::AccountId,
@@ -1192,11 +1023,10 @@ where
 	}
 
 	fn set_storage(&mut self, key: StorageKey, value: Option<Vec<u8>>) -> DispatchResult {
-		let block_number = self.block_number;
 		let frame = self.top_frame_mut();
-		Storage::<T>::write(
-			block_number, frame.contract_info(), &key, value,
-		)
+		let diff = Storage::<T>::write(frame.contract_info(), &key, value)?;
+		frame.storage_diff = frame.storage_diff.combine(diff);
+		Ok(())
 	}
 
 	fn address(&self) -> &T::AccountId {
@@ -1227,10 +1057,6 @@ where
 		T::Currency::minimum_balance()
 	}
 
-	fn tombstone_deposit(&self) -> BalanceOf<T> {
-		T::TombstoneDeposit::get()
-	}
-
 	fn deposit_event(&mut self, topics: Vec<T::Hash>, data: Vec<u8>) {
 		deposit_event::<Self::T>(
 			topics,
@@ -1238,14 +1064,6 @@ where
 		);
 	}
 
-	fn set_rent_allowance(&mut self, rent_allowance: BalanceOf<T>) {
-		self.top_frame_mut().contract_info().rent_allowance = rent_allowance;
-	}
-
-	fn rent_allowance(&mut self) -> BalanceOf<T> {
-		self.top_frame_mut().contract_info().rent_allowance
-	}
-
 	fn block_number(&self) -> T::BlockNumber { self.block_number }
 
 	fn max_value_size(&self) -> u32 {
@@ -1260,24 +1078,6 @@ where
 		&self.schedule
 	}
 
-	fn rent_params(&self) -> &RentParams<Self::T> {
-		&self.top_frame().rent_params
-	}
-
-	fn rent_status(&mut self, at_refcount: u32) -> RentStatus<Self::T> {
-		let frame = self.top_frame_mut();
-		let balance = T::Currency::free_balance(&frame.account_id);
-		let code_size = frame.rent_params.code_size;
-		let refcount = frame.rent_params.code_refcount;
-		<Rent<T, E>>::rent_status(
-			&balance,
-			&frame.contract_info(),
-			code_size,
-			refcount,
-			at_refcount,
-		)
-	}
-
 	fn gas_meter(&mut self) -> &mut GasMeter<Self::T> {
 		&mut self.top_frame_mut().nested_meter
 	}
@@ -1342,12 +1142,12 @@ mod tests {
 	use assert_matches::assert_matches;
 	use std::{cell::RefCell, collections::HashMap, rc::Rc};
 	use pretty_assertions::{assert_eq, assert_ne};
-	use pallet_contracts_primitives::ReturnFlags;
-	use frame_support::{assert_ok, assert_err};
+	use pallet_contracts_primitives::{ReturnFlags, StorageDeposit};
+	use frame_support::{assert_ok, assert_err, traits::ReservableCurrency};
 
 	type MockStack<'a> = Stack<'a, Test, MockExecutable>;
 
-	const GAS_LIMIT: Weight = 10_000_000_000;
+	const GAS_LIMIT: Weight = Weight::from_ref_time(10_000_000_000);
 
 	thread_local! {
 		static LOADER: RefCell<MockLoader> = RefCell::new(MockLoader::default());
@@ -1435,7 +1235,7 @@ mod tests {
 					.map
 					.get(code_hash)
 					.expect("code_hash does not exist")
-					.refcount()
+					.refcount as u32
 			})
 		}
 	}
@@ -1499,14 +1299,6 @@ mod tests {
 		fn code_len(&self) -> u32 {
 			0
 		}
-
-		fn aggregate_code_len(&self) -> u32 {
-			0
-		}
-
-		fn refcount(&self) -> u32 {
-			self.refcount as u32
-		}
 	}
 
 	fn exec_success() -> ExecResult {
@@ -1536,7 +1328,14 @@ mod tests {
 
 			assert_matches!(
 				MockStack::run_call(
-					ALICE, BOB, &mut gas_meter, &schedule, value, vec![], None,
+					ALICE,
+					BOB,
+					&mut gas_meter,
+					&mut StorageMeter::new(ALICE, None),
+					&schedule,
+					value,
+					vec![],
+					None,
 				),
 				Ok(_)
 			);
@@ -1591,6 +1390,7 @@ mod tests {
 				origin.clone(),
 				dest.clone(),
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(origin.clone(), None),
 				&schedule,
 				55,
 				vec![],
@@ -1599,9 +1399,7 @@ mod tests {
 
 			assert!(!output.0.is_success());
 			assert_eq!(get_balance(&origin), 100);
-
-			// the rent is still charged
-			assert!(get_balance(&dest) < balance);
+			assert_eq!(get_balance(&dest), balance);
 		});
 	}
 
@@ -1648,9 +1446,10 @@ mod tests {
 			place_contract(&BOB, return_ch);
 
 			let result = MockStack::run_call(
-				origin,
+				origin.clone(),
 				dest,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(origin, None),
 				&schedule,
 				0,
 				vec![],
@@ -1679,9 +1478,10 @@ mod tests {
 			place_contract(&BOB, return_ch);
 
 			let result = MockStack::run_call(
-				origin,
+				origin.clone(),
 				dest,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(origin, None),
 				&schedule,
 				0,
 				vec![],
@@ -1710,6 +1510,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				0,
 				vec![1, 2, 3, 4],
@@ -1735,12 +1536,13 @@ mod tests {
 				input_data_ch, &schedule, &mut gas_meter
 			).unwrap();
 
-			set_balance(&ALICE, subsistence * 10);
+			set_balance(&ALICE, subsistence * 100);
 
 			let result = MockStack::run_instantiate(
 				ALICE,
 				executable,
 				&mut gas_meter,
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				subsistence * 3,
 				vec![1, 2, 3, 4],
@@ -1761,7 +1563,7 @@ mod tests {
 		let value = Default::default();
 		let recurse_ch = MockLoader::insert(Call, |ctx, _| {
 			// Try to call into yourself.
-			let r = ctx.ext.call(0, BOB, 0, vec![], true);
+			let r = ctx.ext.call(Weight::zero(), BOB, 0, vec![], true);
 
 			REACHED_BOTTOM.with(|reached_bottom| {
 				let mut reached_bottom = reached_bottom.borrow_mut();
@@ -1791,6 +1593,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				value,
 				vec![],
@@ -1819,7 +1622,7 @@ mod tests {
 
 			// Call into CHARLIE contract.
 			assert_matches!(
-				ctx.ext.call(0, CHARLIE, 0, vec![], true),
+				ctx.ext.call(Weight::zero(), CHARLIE, 0, vec![], true),
 				Ok(_)
 			);
 			exec_success()
@@ -1841,6 +1644,7 @@ mod tests {
 				origin.clone(),
 				dest.clone(),
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(origin.clone(), None),
 				&schedule,
 				0,
 				vec![],
@@ -1862,7 +1666,7 @@ mod tests {
 
 			// Call into charlie contract.
 			assert_matches!(
-				ctx.ext.call(0, CHARLIE, 0, vec![], true),
+				ctx.ext.call(Weight::zero(), CHARLIE, 0, vec![], true),
 				Ok(_)
 			);
 			exec_success()
@@ -1881,6 +1685,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				0,
 				vec![],
@@ -1907,6 +1712,7 @@ mod tests {
 					ALICE,
 					executable,
 					&mut gas_meter,
+					&mut StorageMeter::new(ALICE, None),
 					&schedule,
 					0, // <- zero endowment
 					vec![],
@@ -1938,6 +1744,7 @@ mod tests {
 					ALICE,
 					executable,
 					&mut gas_meter,
+					&mut StorageMeter::new(ALICE, None),
 					&schedule,
 					100,
 					vec![],
@@ -1951,6 +1758,11 @@ mod tests {
 			// there are instantiation event.
 			assert_eq!(Storage::<Test>::code_hash(&instantiated_contract_address).unwrap(), dummy_ch);
 			assert_eq!(&events(), &[
+				Event::StorageDepositCharged(
+					ALICE,
+					instantiated_contract_address.clone(),
+					<Test as Config>::DepositPerContract::get(),
+				),
 				Event::Instantiated(ALICE, instantiated_contract_address)
 			]);
 		});
@@ -1976,6 +1788,7 @@ mod tests {
 					ALICE,
 					executable,
 					&mut gas_meter,
+					&mut StorageMeter::new(ALICE, None),
 					&schedule,
 					100,
 					vec![],
@@ -2001,7 +1814,7 @@ mod tests {
 			move |ctx, _| {
 				// Instantiate a contract and save it's address in `instantiated_contract_address`.
 				let (address, output, _) = ctx.ext.instantiate(
-					0,
+					Weight::zero(),
 					dummy_ch,
 					Contracts::<Test>::subsistence_threshold() * 3,
 					vec![],
@@ -2020,7 +1833,14 @@ mod tests {
 
 			assert_matches!(
 				MockStack::run_call(
-					ALICE, BOB, &mut GasMeter::<Test>::new(GAS_LIMIT), &schedule, 20, vec![], None,
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut StorageMeter::new(ALICE, None),
+					&schedule,
+					20,
+					vec![],
+					None,
 				),
 				Ok(_)
 			);
@@ -2031,6 +1851,11 @@ mod tests {
 			// there are instantiation event.
 			assert_eq!(Storage::<Test>::code_hash(&instantiated_contract_address).unwrap(), dummy_ch);
 			assert_eq!(&events(), &[
+				Event::StorageDepositCharged(
+					ALICE,
+					instantiated_contract_address.clone(),
+					<Test as Config>::DepositPerContract::get(),
+				),
 				Event::Instantiated(BOB, instantiated_contract_address)
 			]);
 		});
@@ -2047,7 +1872,7 @@ mod tests {
 				// Instantiate a contract and save it's address in `instantiated_contract_address`.
 				assert_matches!(
 					ctx.ext.instantiate(
-						0,
+						Weight::zero(),
 						dummy_ch,
 						Contracts::<Test>::subsistence_threshold(),
 						vec![],
@@ -2071,7 +1896,14 @@ mod tests {
 
 			assert_matches!(
 				MockStack::run_call(
-					ALICE, BOB, &mut GasMeter::<Test>::new(GAS_LIMIT), &schedule, 20, vec![], None,
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut StorageMeter::new(ALICE, None),
+					&schedule,
+					20,
+					vec![],
+					None,
 				),
 				Ok(_)
 			);
@@ -2105,6 +1937,7 @@ mod tests {
 						ALICE,
 						executable,
 						&mut gas_meter,
+						&mut StorageMeter::new(ALICE, None),
 						&schedule,
 						100,
 						vec![],
@@ -2122,206 +1955,198 @@ mod tests {
 	}
 
 	#[test]
-	fn rent_allowance() {
-		let rent_allowance_ch = MockLoader::insert(Constructor, |ctx, _| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
-			let allowance = subsistence * 3;
-			assert_eq!(ctx.ext.rent_allowance(), <BalanceOf<Test>>::max_value());
-			ctx.ext.set_rent_allowance(allowance);
-			assert_eq!(ctx.ext.rent_allowance(), allowance);
+	fn in_memory_changes_not_discarded() {
+		// Call stack: BOB -> CHARLIE (trap) -> BOB' (success)
+		// This tests verfies some edge case of the contract info cache:
+		// We change some value in our contract info before calling into a contract
+		// that calls into ourself. This triggers a case where BOBs contract info
+		// is written to storage and invalidated by the successful execution of BOB'.
+		// The trap of CHARLIE reverts the storage changes to BOB. When the root BOB regains
+		// control it reloads its contract info from storage. We check that changes that
+		// are made before calling into CHARLIE are not discarded.
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			if ctx.input_data[0] == 0 {
+				assert_ok!(ctx.ext.set_storage([1; 32], Some(vec![1, 2, 3])));
+				assert_eq!(
+					ctx.ext
+						.call(Weight::zero(), CHARLIE, 0, vec![], true)
+						.map(|v| v.0)
+						.map_err(|e| e.0),
+					exec_trapped()
+				);
+				assert_eq!(ctx.ext.get_storage(&[1; 32]), Some(vec![1, 2, 3]));
+			}
 			exec_success()
 		});
+		let code_charlie = MockLoader::insert(Call, |ctx, _| {
+			assert!(ctx.ext.call(Weight::zero(), BOB, 0, vec![99], true).is_ok());
+			exec_trapped()
+		});
 
+		// This one tests passing the input data into a contract via call.
 		ExtBuilder::default().build().execute_with(|| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
 			let schedule = <Test as Config>::Schedule::get();
-			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
-			let executable = MockExecutable::from_storage(
-				rent_allowance_ch, &schedule, &mut gas_meter
-			).unwrap();
-			set_balance(&ALICE, subsistence * 10);
+			set_balance(&ALICE, 1000);
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
 
-			let result = MockStack::run_instantiate(
+			let result = MockStack::run_call(
 				ALICE,
-				executable,
-				&mut gas_meter,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
-				subsistence * 5,
-				vec![],
-				&[],
+				0,
+				vec![0],
 				None,
 			);
 			assert_matches!(result, Ok(_));
+			let contract = <ContractInfoOf<Test>>::get(&BOB).unwrap();
+			assert_eq!(contract.pair_count, 1);
+			assert_eq!(contract.storage_size, 3);
 		});
 	}
 
 	#[test]
-	fn rent_params_works() {
-		let code_hash = MockLoader::insert(Call, |ctx, executable| {
-			let address = ctx.ext.address();
-			let contract = <ContractInfoOf<Test>>::get(address)
-				.and_then(|c| c.get_alive())
-				.unwrap();
-			assert_eq!(ctx.ext.rent_params(), &RentParams::new(address, &0, &contract, executable));
+	fn storage_deposit_charged_and_refunded() {
+		let code_hash = MockLoader::insert(Call, |ctx, _| {
+			let value = if ctx.input_data[0] == 0 { Some(vec![1, 2, 3]) } else { None };
+			assert_ok!(ctx.ext.set_storage([1; 32], value));
 			exec_success()
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
 			let schedule = <Test as Config>::Schedule::get();
-			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
-			set_balance(&ALICE, subsistence * 10);
+			// Three bytes in one storage item.
+			let deposit = <Test as Config>::DepositPerStorageByte::get() * 3 +
+				<Test as Config>::DepositPerStorageItem::get();
+			set_balance(&ALICE, 1000);
 			place_contract(&BOB, code_hash);
-			MockStack::run_call(
-				ALICE,
-				BOB,
-				&mut gas_meter,
-				&schedule,
-				0,
-				vec![],
-				None,
-			).unwrap();
-		});
-	}
 
-	#[test]
-	fn rent_params_snapshotted() {
-		let code_hash = MockLoader::insert(Call, |ctx, executable| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
-			let address = ctx.ext.address();
-			let contract = <ContractInfoOf<Test>>::get(address)
-				.and_then(|c| c.get_alive())
-				.unwrap();
-			let rent_params = RentParams::new(address, &0, &contract, executable);
-
-			// Changing the allowance during the call: rent params stay unchanged.
-			let allowance = 42;
-			assert_ne!(allowance, rent_params.rent_allowance);
-			ctx.ext.set_rent_allowance(allowance);
-			assert_eq!(ctx.ext.rent_params(), &rent_params);
-
-			// Creating another instance from the same code_hash increases the refcount.
-			// This is also not reflected in the rent params.
-			assert_eq!(MockLoader::refcount(&executable.code_hash), 1);
-			ctx.ext.instantiate(
-				0,
-				executable.code_hash,
-				subsistence * 25,
-				vec![],
-				&[],
-			).unwrap();
-			assert_eq!(MockLoader::refcount(&executable.code_hash), 2);
-			assert_eq!(ctx.ext.rent_params(), &rent_params);
-
-			exec_success()
-		});
+			let mut storage_meter = StorageMeter::new(ALICE, None);
+			assert_matches!(
+				MockStack::run_call(
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![0],
+					None,
+				),
+				Ok(_)
+			);
+			assert_eq!(storage_meter.deposit(), StorageDeposit::Charge(deposit));
+			assert_eq!(get_balance(&ALICE), 1000 - deposit);
+			assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), deposit);
+			assert_eq!(<ContractInfoOf<Test>>::get(&BOB).unwrap().storage_deposit, deposit);
 
-		ExtBuilder::default().build().execute_with(|| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
-			let schedule = <Test as Config>::Schedule::get();
-			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
-			set_balance(&ALICE, subsistence * 100);
-			place_contract(&BOB, code_hash);
-			MockStack::run_call(
-				ALICE,
-				BOB,
-				&mut gas_meter,
-				&schedule,
-				subsistence * 50,
-				vec![],
-				None,
-			).unwrap();
+			let mut storage_meter = StorageMeter::new(ALICE, None);
+			assert_matches!(
+				MockStack::run_call(
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![1],
+					None,
+				),
+				Ok(_)
+			);
+			assert_eq!(storage_meter.deposit(), StorageDeposit::Refund(deposit));
+			assert_eq!(get_balance(&ALICE), 1000);
+			assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), 0);
+			assert_eq!(<ContractInfoOf<Test>>::get(&BOB).unwrap().storage_deposit, 0);
 		});
 	}
 
 	#[test]
-	fn rent_status_works() {
+	fn storage_deposit_limit_is_enforced() {
 		let code_hash = MockLoader::insert(Call, |ctx, _| {
-			assert_eq!(ctx.ext.rent_status(0), RentStatus {
-				max_deposit: 80000,
-				current_deposit: 80000,
-				custom_refcount_deposit: None,
-				max_rent: 32,
-				current_rent: 32,
-				custom_refcount_rent: None,
-				_reserved: None,
-			});
-			assert_eq!(ctx.ext.rent_status(1), RentStatus {
-				max_deposit: 80000,
-				current_deposit: 80000,
-				custom_refcount_deposit: Some(80000),
-				max_rent: 32,
-				current_rent: 32,
-				custom_refcount_rent: Some(32),
-				_reserved: None,
-			});
+			assert_ok!(ctx.ext.set_storage([1; 32], Some(vec![1, 2, 3])));
 			exec_success()
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
-			let subsistence = Contracts::<Test>::subsistence_threshold();
 			let schedule = <Test as Config>::Schedule::get();
-			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
-			set_balance(&ALICE, subsistence * 10);
+			let deposit = <Test as Config>::DepositPerStorageByte::get() * 3 +
+				<Test as Config>::DepositPerStorageItem::get();
+			set_balance(&ALICE, 1000);
 			place_contract(&BOB, code_hash);
-			MockStack::run_call(
-				ALICE,
-				BOB,
-				&mut gas_meter,
-				&schedule,
-				0,
-				vec![],
-				None,
-			).unwrap();
+
+			let mut storage_meter = StorageMeter::new(ALICE, Some(deposit - 1));
+			assert_matches!(
+				MockStack::run_call(
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+				),
+				Err((ExecError { error, .. }, _))
+					if error == Error::<Test>::StorageDepositLimitExhausted.into()
+			);
+			assert_eq!(storage_meter.deposit(), StorageDeposit::Charge(0));
+			assert_eq!(get_balance(&ALICE), 1000);
+
+			let mut storage_meter = StorageMeter::new(ALICE, Some(deposit));
+			assert_matches!(
+				MockStack::run_call(
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+				),
+				Ok(_)
+			);
+			assert_eq!(storage_meter.deposit(), StorageDeposit::Charge(deposit));
 		});
 	}
 
 	#[test]
-	fn in_memory_changes_not_discarded() {
-		// Call stack: BOB -> CHARLIE (trap) -> BOB' (success)
-		// This tests verfies some edge case of the contract info cache:
-		// We change some value in our contract info before calling into a contract
-		// that calls into ourself. This triggers a case where BOBs contract info
-		// is written to storage and invalidated by the successful execution of BOB'.
-		// The trap of CHARLIE reverts the storage changes to BOB. When the root BOB regains
-		// control it reloads its contract info from storage. We check that changes that
-		// are made before calling into CHARLIE are not discarded.
+	fn storage_deposit_reverted_with_frame() {
 		let code_bob = MockLoader::insert(Call, |ctx, _| {
-			if ctx.input_data[0] == 0 {
-				let original_allowance = ctx.ext.rent_allowance();
-				let changed_allowance = <BalanceOf<Test>>::max_value() / 2;
-				assert_ne!(original_allowance, changed_allowance);
-				ctx.ext.set_rent_allowance(changed_allowance);
-				assert_eq!(
-					ctx.ext.call(0, CHARLIE, 0, vec![], true).map(|v| v.0).map_err(|e| e.0),
-					exec_trapped()
-				);
-				assert_eq!(ctx.ext.rent_allowance(), changed_allowance);
-				assert_ne!(ctx.ext.rent_allowance(), original_allowance);
-			}
-			exec_success()
+			assert_matches!(ctx.ext.call(Weight::zero(), CHARLIE, 0, vec![], true), Ok(_));
+			exec_trapped()
 		});
 		let code_charlie = MockLoader::insert(Call, |ctx, _| {
-			assert!(ctx.ext.call(0, BOB, 0, vec![99], true).is_ok());
-			exec_trapped()
+			assert_ok!(ctx.ext.set_storage([1; 32], Some(vec![1, 2, 3])));
+			exec_success()
 		});
 
-		// This one tests passing the input data into a contract via call.
 		ExtBuilder::default().build().execute_with(|| {
 			let schedule = <Test as Config>::Schedule::get();
+			set_balance(&ALICE, 1000);
 			place_contract(&BOB, code_bob);
 			place_contract(&CHARLIE, code_charlie);
 
-			let result = MockStack::run_call(
-				ALICE,
-				BOB,
-				&mut GasMeter::<Test>::new(GAS_LIMIT),
-				&schedule,
-				0,
-				vec![0],
-				None,
+			let mut storage_meter = StorageMeter::new(ALICE, None);
+			assert_matches!(
+				MockStack::run_call(
+					ALICE,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+				),
+				Err(_)
 			);
-			assert_matches!(result, Ok(_));
+			assert_eq!(storage_meter.deposit(), StorageDeposit::Charge(0));
+			assert_eq!(get_balance(&ALICE), 1000);
+			assert_eq!(<Test as Config>::Currency::reserved_balance(&CHARLIE), 0);
 		});
 	}
 
@@ -2329,7 +2154,7 @@ mod tests {
 	fn recursive_call_during_constructor_fails() {
 		let code = MockLoader::insert(Constructor, |ctx, _| {
 			assert_matches!(
-				ctx.ext.call(0, ctx.ext.address().clone(), 0, vec![], true),
+				ctx.ext.call(Weight::zero(), ctx.ext.address().clone(), 0, vec![], true),
 				Err((ExecError{error, ..}, _)) if error == <Error<Test>>::ContractNotFound.into()
 			);
 			exec_success()
@@ -2344,12 +2169,13 @@ mod tests {
 				code, &schedule, &mut gas_meter
 			).unwrap();
 
-			set_balance(&ALICE, subsistence * 10);
+			set_balance(&ALICE, subsistence * 100);
 
 			let result = MockStack::run_instantiate(
 				ALICE,
 				executable,
 				&mut gas_meter,
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				subsistence * 3,
 				vec![],
@@ -2380,6 +2206,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut gas_meter,
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				0,
 				vec![],
@@ -2410,6 +2237,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut gas_meter,
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				0,
 				vec![],
@@ -2426,7 +2254,7 @@ mod tests {
 		// call the contract passed as input with disabled reentry
 		let code_bob = MockLoader::insert(Call, |ctx, _| {
 			let dest = Decode::decode(&mut ctx.input_data.as_ref()).unwrap();
-			ctx.ext.call(0, dest, 0, vec![], false).map(|v| v.0).map_err(|e| e.0)
+			ctx.ext.call(Weight::zero(), dest, 0, vec![], false).map(|v| v.0).map_err(|e| e.0)
 		});
 
 		let code_charlie = MockLoader::insert(Call, |_, _| {
@@ -2443,6 +2271,7 @@ mod tests {
 				ALICE,
 				BOB,
 				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut StorageMeter::new(ALICE, None),
 				&schedule,
 				0,
 				CHARLIE.encode(),
@@ -2455,6 +2284,7 @@ mod tests {
 					ALICE,
 					BOB,
 					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut StorageMeter::new(ALICE, None),
 					&schedule,
 					0,
 					BOB.encode(),
@@ -2469,7 +2299,10 @@ mod tests {
 	fn call_deny_reentry() {
 		let code_bob = MockLoader::insert(Call, |ctx, _| {
 			if ctx.input_data[0] == 0 {
-				ctx.ext.call(0, CHARLIE, 0, vec![], false).map(|v| v.0).map_err(|e| e.0)
+				ctx.ext
+					.call(Weight::zero(), CHARLIE, 0, vec![], false)
+					.map(|v| v.0)
+					.map_err(|e| e.0)
 			} else {
 				exec_success()
 			}
@@ -2477,7 +2310,7 @@ mod tests {
 
 		// call BOB with input set to '1'
 		let code_charlie = MockLoader::insert(Call, |ctx, _| {
-			ctx.ext.call(0, BOB, 0, vec![1], true).map(|v| v.0).map_err(|e| e.0)
+			ctx.ext.call(Weight::zero(), BOB, 0, vec![1], true).map(|v| v.0).map_err(|e| e.0)
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
@@ -2491,6 +2324,7 @@ mod tests {
 					ALICE,
 					BOB,
 					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut StorageMeter::new(ALICE, None),
 					&schedule,
 					0,
 					vec![0],
@@ -104,7 +104,7 @@ where
 	///
 	/// Passing `0` as amount is interpreted as "all remaining gas".
 	pub fn nested(&mut self, amount: Weight) -> Result<Self, DispatchError> {
-		let amount = if amount == 0 {
+		let amount = if amount.is_zero() {
 			self.gas_left
 		} else {
 			amount
@@ -112,7 +112,7 @@ where
 
 		// NOTE that it is ok to allocate all available gas since it still ensured
 		// by `charge` that it doesn't reach zero.
-		if self.gas_left < amount {
+		if amount.any_gt(&self.gas_left) {
 			Err(<Error<T>>::OutOfGas.into())
 		} else {
 			self.gas_left = self.gas_left - amount;
@@ -147,7 +147,7 @@ where
 		}
 
 		let amount = token.weight();
-		let new_value = self.gas_left.checked_sub(amount);
+		let new_value = self.gas_left.checked_sub(&amount);
 
 		// We always consume the gas even if there is not enough gas.
 		self.gas_left = new_value.unwrap_or_else(Zero::zero);
@@ -212,7 +212,7 @@ where
 
 #[cfg(test)]
 mod tests {
-	use super::{GasMeter, Token};
+	use super::{GasMeter, Token, Weight};
 	use crate::tests::Test;
 
 	/// A simple utility macro that helps to match against a
@@ -256,18 +256,18 @@ mod tests {
 	#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 	struct SimpleToken(u64);
 	impl Token<Test> for SimpleToken {
-		fn weight(&self) -> u64 { self.0 }
+		fn weight(&self) -> Weight { Weight::from_ref_time(self.0) }
 	}
 
 	#[test]
 	fn it_works() {
-		let gas_meter = GasMeter::<Test>::new(50000);
-		assert_eq!(gas_meter.gas_left(), 50000);
+		let gas_meter = GasMeter::<Test>::new(Weight::from_ref_time(50000));
+		assert_eq!(gas_meter.gas_left(), Weight::from_ref_time(50000));
 	}
 
 	#[test]
 	fn tracing() {
-		let mut gas_meter = GasMeter::<Test>::new(50000);
+		let mut gas_meter = GasMeter::<Test>::new(Weight::from_ref_time(50000));
 		assert!(!gas_meter.charge(SimpleToken(1)).is_err());
 
 		let mut tokens = gas_meter.tokens().iter();
@@ -277,7 +277,7 @@ mod tests {
 	// This test makes sure that nothing can be executed if there is no gas.
 	#[test]
 	fn refuse_to_execute_anything_if_zero() {
-		let mut gas_meter = GasMeter::<Test>::new(0);
+		let mut gas_meter = GasMeter::<Test>::new(Weight::from_ref_time(0));
 		assert!(gas_meter.charge(SimpleToken(1)).is_err());
 	}
 
@@ -288,7 +288,7 @@ mod tests {
 	// if the gas meter runs out of gas. However, this is just a nice property to have.
 	#[test]
 	fn overcharge_is_unrecoverable() {
-		let mut gas_meter = GasMeter::<Test>::new(200);
+		let mut gas_meter = GasMeter::<Test>::new(Weight::from_ref_time(200));
 
 		// The first charge is should lead to OOG.
 		assert!(gas_meter.charge(SimpleToken(300)).is_err());
@@ -302,7 +302,7 @@ mod tests {
 	// possible.
 	#[test]
 	fn charge_exact_amount() {
-		let mut gas_meter = GasMeter::<Test>::new(25);
+		let mut gas_meter = GasMeter::<Test>::new(Weight::from_ref_time(25));
 		assert!(!gas_meter.charge(SimpleToken(25)).is_err());
 	}
 }
@@ -49,6 +49,14 @@
 //! then all of B's calls are reverted. Assuming correct error handling by contract A, A's other calls and state
 //! changes still persist.
 //!
+//! ### Storage Deposit
+//!
+//! Contracts do not pay rent for the storage they occupy. Instead, the origin of a call pays a
+//! deposit for every storage item and byte the call adds, and receives the deposit back for every
+//! storage item and byte the call removes. The deposits are held as reserved balance by the
+//! contracts that own the storage. Senders can limit the net deposit that is charged from them
+//! with the `storage_deposit_limit` of every call.
+//!
 //! ### Notable Scenarios
 //!
 //! Contract call failures are not always cascading. When failures occur in a sub-call, they do not "bubble up",
@@ -66,7 +74,6 @@
 //! * [`Pallet::instantiate`] - The same as `instantiate_with_code` but instead of uploading new
 //! code an existing `code_hash` is supplied.
 //! * [`Pallet::call`] - Makes a call to an account, optionally transferring some balance.
-//! * [`Pallet::claim_surcharge`] - Evict a contract that cannot pay rent anymore.
 //!
 //! ## Usage
 //!
@@ -85,7 +92,6 @@ mod gas;
 mod storage;
 mod exec;
 mod wasm;
-mod rent;
 mod benchmarking;
 mod schedule;
 mod migration;
@@ -104,35 +110,28 @@ pub use crate::{
 use crate::{
 	gas::GasMeter,
 	exec::{Stack as ExecStack, Executable},
-	rent::Rent,
-	storage::{Storage, DeletedContract, ContractInfo, AliveContractInfo, TombstoneContractInfo},
+	storage::{Storage, DeletedContract, ContractInfo, meter::Meter as StorageMeter},
 	weights::WeightInfo,
 	wasm::PrefabWasmModule,
 };
 use sp_core::{Bytes, crypto::UncheckedFrom};
 use sp_std::prelude::*;
-use sp_runtime::{
-	traits::{
-		Hash, StaticLookup, Convert, Saturating, Zero,
-	},
-	Perbill,
-};
+use sp_runtime::traits::{Hash, StaticLookup, Convert};
 use frame_support::{
-	traits::{OnUnbalanced, Currency, Get, Time, Randomness},
-	weights::{Weight, PostDispatchInfo, WithPostDispatchInfo},
+	traits::{Currency, ReservableCurrency, Get, Time, Randomness},
+	weights::Weight,
 };
 use frame_system::Pallet as System;
 use pallet_contracts_primitives::{
-	RentProjectionResult, GetStorageResult, ContractAccessError, ContractExecResult,
+	GetStorageResult, ContractAccessError, ContractExecResult,
 	ContractInstantiateResult, Code, InstantiateReturnValue,
 };
+use codec::HasCompact;
 
 type CodeHash<T> = <T as frame_system::Config>::Hash;
 type TrieId = Vec<u8>;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-type NegativeImbalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -149,14 +148,13 @@ pub mod pallet {
 		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 
 		/// The currency in which fees are paid and contract balances are held.
-		type Currency: Currency<Self::AccountId>;
+		///
+		/// The storage deposits are held as reserved balance of the contracts.
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// Handler for rent payments.
-		type RentPayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
-
 		/// Used to answer contracts' queries regarding the current weight price. This is **not**
 		/// used to calculate the actual fee and is only for informational purposes.
 		type WeightPrice: Convert<Weight, BalanceOf<Self>>;
@@ -172,56 +170,25 @@ pub mod pallet {
 		#[pallet::constant]
 		type Schedule: Get<Schedule<Self>>;
 
-		/// Number of block delay an extrinsic claim surcharge has.
+		/// The deposit that the origin pays for every contract it instantiates.
 		///
-		/// When claim surcharge is called by an extrinsic the rent is checked
-		/// for current_block - delay
-		#[pallet::constant]
-		type SignedClaimHandicap: Get<Self::BlockNumber>;
-
-		/// The minimum amount required to generate a tombstone.
-		#[pallet::constant]
-		type TombstoneDeposit: Get<BalanceOf<Self>>;
-
-		/// The balance every contract needs to deposit to stay alive indefinitely.
-		///
-		/// This is different from the [`Self::TombstoneDeposit`] because this only needs to be
-		/// deposited while the contract is alive. Costs for additional storage are added to
-		/// this base cost.
-		///
-		/// This is a simple way to ensure that contracts with empty storage eventually get deleted by
-		/// making them pay rent. This creates an incentive to remove them early in order to save rent.
+		/// This covers the storage that every contract occupies on top of its own storage
+		/// items, e.g. its [`ContractInfo`]. It is refunded when the contract is terminated.
 		#[pallet::constant]
 		type DepositPerContract: Get<BalanceOf<Self>>;
 
-		/// The balance a contract needs to deposit per storage byte to stay alive indefinitely.
+		/// The deposit that the origin pays for every byte of contract storage it adds.
 		///
-		/// Let's suppose the deposit is 1,000 BU (balance units)/byte and the rent is 1 BU/byte/day,
-		/// then a contract with 1,000,000 BU that uses 1,000 bytes of storage would pay no rent.
-		/// But if the balance reduced to 500,000 BU and the storage stayed the same at 1,000,
-		/// then it would pay 500 BU/day.
+		/// It is refunded to the origin of the call which removes the byte again.
 		#[pallet::constant]
 		type DepositPerStorageByte: Get<BalanceOf<Self>>;
 
-		/// The balance a contract needs to deposit per storage item to stay alive indefinitely.
+		/// The deposit that the origin pays for every contract storage item it adds.
 		///
 		/// It works the same as [`Self::DepositPerStorageByte`] but for storage items.
 		#[pallet::constant]
 		type DepositPerStorageItem: Get<BalanceOf<Self>>;
 
-		/// The fraction of the deposit that should be used as rent per block.
-		///
-		/// When a contract hasn't enough balance deposited to stay alive indefinitely it needs
-		/// to pay per block for the storage it consumes that is not covered by the deposit.
-		/// This determines how high this rent payment is per block as a fraction of the deposit.
-		#[pallet::constant]
-		type RentFraction: Get<Perbill>;
-
-		/// Reward that is received by the party whose touch has led
-		/// to removal of a contract.
-		#[pallet::constant]
-		type SurchargeReward: Get<BalanceOf<Self>>;
-
 		/// The type of the call stack determines the maximum nesting depth of contract calls.
 		///
 		/// The allowed depth is `CallStack::size() + 1`.
@@ -238,7 +205,11 @@ pub mod pallet {
 		type DeletionWeightLimit: Get<Weight>;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
@@ -275,6 +246,10 @@ pub mod pallet {
 		/// * If the account is a regular account, any value will be transferred.
 		/// * If no account exists and the call value is not less than `existential_deposit`,
 		/// a regular account will be created and any value will be transferred.
+		///
+		/// The origin pays the storage deposit for the storage added by the call and receives
+		/// the deposit of the storage removed by it. The net amount charged can be capped by
+		/// `storage_deposit_limit`. `None` means that no limit is enforced.
 		#[pallet::weight(T::WeightInfo::call(T::Schedule::get().limits.code_len / 1024)
 			.saturating_add(*gas_limit)
 		)]
@@ -282,15 +257,20 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			dest: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] value: BalanceOf<T>,
-			#[pallet::compact] gas_limit: Weight,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as HasCompact>::Type>,
 			data: Vec<u8>
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(dest)?;
 			let mut gas_meter = GasMeter::new(gas_limit);
+			let mut storage_meter = StorageMeter::new(
+				origin.clone(),
+				storage_deposit_limit.map(Into::into),
+			);
 			let schedule = T::Schedule::get();
 			let (result, code_len) = match ExecStack::<T, PrefabWasmModule<T>>::run_call(
-				origin, dest, &mut gas_meter, &schedule, value, data, None,
+				origin, dest, &mut gas_meter, &mut storage_meter, &schedule, value, data, None,
 			) {
 				Ok((output, len)) => (Ok(output), len),
 				Err((err, len)) => (Err(err), len),
@@ -307,6 +287,9 @@ pub mod pallet {
 		///
 		/// * `endowment`: The balance to transfer from the `origin` to the newly created contract.
 		/// * `gas_limit`: The gas limit enforced when executing the constructor.
+		/// * `storage_deposit_limit`: The maximum amount of balance that can be charged from the
+		///   `origin` for the storage deposit of the new contract and its storage. `None` means
+		///   that no limit is enforced.
 		/// * `code`: The contract code to deploy in raw bytes.
 		/// * `data`: The input data to pass to the contract constructor.
 		/// * `salt`: Used for the address derivation. See [`Pallet::contract_address`].
@@ -329,7 +312,8 @@ pub mod pallet {
 		pub fn instantiate_with_code(
 			origin: OriginFor<T>,
 			#[pallet::compact] endowment: BalanceOf<T>,
-			#[pallet::compact] gas_limit: Weight,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as HasCompact>::Type>,
 			code: Vec<u8>,
 			data: Vec<u8>,
 			salt: Vec<u8>,
@@ -338,12 +322,17 @@ pub mod pallet {
 			let code_len = code.len() as u32;
 			ensure!(code_len <= T::Schedule::get().limits.code_len, Error::<T>::CodeTooLarge);
 			let mut gas_meter = GasMeter::new(gas_limit);
+			let mut storage_meter = StorageMeter::new(
+				origin.clone(),
+				storage_deposit_limit.map(Into::into),
+			);
 			let schedule = T::Schedule::get();
 			let executable = PrefabWasmModule::from_code(code, &schedule)?;
 			let code_len = executable.code_len();
 			ensure!(code_len <= T::Schedule::get().limits.code_len, Error::<T>::CodeTooLarge);
 			let result = ExecStack::<T, PrefabWasmModule<T>>::run_instantiate(
-				origin, executable, &mut gas_meter, &schedule, endowment, data, &salt, None,
+				origin, executable, &mut gas_meter, &mut storage_meter, &schedule,
+				endowment, data, &salt, None,
 			).map(|(_address, output)| output);
 			gas_meter.into_dispatch_result(
 				result,
@@ -365,78 +354,30 @@ pub mod pallet {
 		pub fn instantiate(
 			origin: OriginFor<T>,
 			#[pallet::compact] endowment: BalanceOf<T>,
-			#[pallet::compact] gas_limit: Weight,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as HasCompact>::Type>,
 			code_hash: CodeHash<T>,
 			data: Vec<u8>,
 			salt: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let mut gas_meter = GasMeter::new(gas_limit);
+			let mut storage_meter = StorageMeter::new(
+				origin.clone(),
+				storage_deposit_limit.map(Into::into),
+			);
 			let schedule = T::Schedule::get();
 			let executable = PrefabWasmModule::from_storage(code_hash, &schedule, &mut gas_meter)?;
 			let code_len = executable.code_len();
 			let result = ExecStack::<T, PrefabWasmModule<T>>::run_instantiate(
-				origin, executable, &mut gas_meter, &schedule, endowment, data, &salt, None,
+				origin, executable, &mut gas_meter, &mut storage_meter, &schedule,
+				endowment, data, &salt, None,
 			).map(|(_address, output)| output);
 			gas_meter.into_dispatch_result(
 				result,
 				T::WeightInfo::instantiate(code_len / 1024, salt.len() as u32 / 1024),
 			)
 		}
-
-		/// Allows block producers to claim a small reward for evicting a contract. If a block
-		/// producer fails to do so, a regular users will be allowed to claim the reward.
-		///
-		/// In case of a successful eviction no fees are charged from the sender. However, the
-		/// reward is capped by the total amount of rent that was paid by the contract while
-		/// it was alive.
-		///
-		/// If contract is not evicted as a result of this call, [`Error::ContractNotEvictable`]
-		/// is returned and the sender is not eligible for the reward.
-		#[pallet::weight(T::WeightInfo::claim_surcharge(T::Schedule::get().limits.code_len / 1024))]
-		pub fn claim_surcharge(
-			origin: OriginFor<T>,
-			dest: T::AccountId,
-			aux_sender: Option<T::AccountId>
-		) -> DispatchResultWithPostInfo {
-			let origin = origin.into();
-			let (signed, rewarded) = match (origin, aux_sender) {
-				(Ok(frame_system::RawOrigin::Signed(account)), None) => {
-					(true, account)
-				},
-				(Ok(frame_system::RawOrigin::None), Some(aux_sender)) => {
-					(false, aux_sender)
-				},
-				_ => Err(Error::<T>::InvalidSurchargeClaim)?,
-			};
-
-			// Add some advantage for block producers (who send unsigned extrinsics) by
-			// adding a handicap: for signed extrinsics we use a slightly older block number
-			// for the eviction check. This can be viewed as if we pushed regular users back in past.
-			let handicap = if signed {
-				T::SignedClaimHandicap::get()
-			} else {
-				Zero::zero()
-			};
-
-			// If poking the contract has lead to eviction of the contract, give out the rewards.
-			match Rent::<T, PrefabWasmModule<T>>::try_eviction(&dest, handicap)? {
-				(Some(rent_paid), code_len) => {
-					T::Currency::deposit_into_existing(
-						&rewarded,
-						T::SurchargeReward::get().min(rent_paid),
-					)
-					.map(|_| PostDispatchInfo {
-						actual_weight: Some(T::WeightInfo::claim_surcharge(code_len / 1024)),
-						pays_fee: Pays::No,
-					})
-					.map_err(Into::into)
-				}
-				(None, code_len) => Err(Error::<T>::ContractNotEvictable.with_weight(
-					T::WeightInfo::claim_surcharge(code_len / 1024)
-				)),
-			}
-		}
 	}
 
 	#[pallet::event]
@@ -446,10 +387,7 @@ pub mod pallet {
 		/// Contract deployed by address at the specified address. \[deployer, contract\]
 		Instantiated(T::AccountId, T::AccountId),
 
-		/// Contract has been evicted and is now in tombstone state. \[contract\]
-		Evicted(T::AccountId),
-
-		/// Contract has been terminated without leaving a tombstone.
+		/// Contract has been terminated.
 		/// \[contract, beneficiary\]
 		///
 		/// # Params
@@ -459,21 +397,10 @@ pub mod pallet {
 		///
 		/// # Note
 		///
-		/// The only way for a contract to be removed without a tombstone and emitting
-		/// this event is by calling `seal_terminate`.
+		/// The only way for a contract to be removed and emitting this event is by calling
+		/// `seal_terminate`.
 		Terminated(T::AccountId, T::AccountId),
 
-		/// Restoration of a contract has been successful.
-		/// \[restorer, dest, code_hash, rent_allowance\]
-		///
-		/// # Params
-		///
-		/// - `restorer`: Account ID of the restoring contract.
-		/// - `dest`: Account ID of the restored contract.
-		/// - `code_hash`: Code hash of the restored contract.
-		/// - `rent_allowance`: Rent allowance of the restored contract.
-		Restored(T::AccountId, T::AccountId, T::Hash, BalanceOf<T>),
-
 		/// Code with the specified hash has been stored. \[code_hash\]
 		CodeStored(T::Hash),
 
@@ -498,32 +425,41 @@ pub mod pallet {
 		/// A code with the specified hash was removed.
 		/// \[code_hash\]
 		///
-		/// This happens when the last contract that uses this code hash was removed or evicted.
+		/// This happens when the last contract that uses this code hash was removed.
 		CodeRemoved(T::Hash),
+
+		/// A storage deposit was charged from the origin of a call for the storage it added.
+		/// \[origin, contract, amount\]
+		///
+		/// # Params
+		///
+		/// - `origin`: The origin of the call that paid the deposit.
+		/// - `contract`: The contract that reserved the deposit.
+		/// - `amount`: The amount of the deposit.
+		StorageDepositCharged(T::AccountId, T::AccountId, BalanceOf<T>),
+
+		/// A storage deposit was refunded to the origin of a call for the storage it removed.
+		/// \[contract, origin, amount\]
+		///
+		/// # Params
+		///
+		/// - `contract`: The contract that held the deposit.
+		/// - `origin`: The origin of the call that received the deposit.
+		/// - `amount`: The amount of the deposit.
+		StorageDepositRefunded(T::AccountId, T::AccountId, BalanceOf<T>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// A new schedule must have a greater version than the current one.
 		InvalidScheduleVersion,
-		/// An origin must be signed or inherent and auxiliary sender only provided on inherent.
-		InvalidSurchargeClaim,
-		/// Cannot restore from nonexisting or tombstone contract.
-		InvalidSourceContract,
-		/// Cannot restore to nonexisting or alive contract.
-		InvalidDestinationContract,
-		/// Tombstones don't match.
-		InvalidTombstone,
-		/// An origin TrieId written in the current block.
-		InvalidContractOrigin,
 		/// The executed contract exhausted its gas limit.
 		OutOfGas,
 		/// The output buffer supplied to a contract API call was too small.
 		OutputBufferTooSmall,
 		/// Performing the requested transfer would have brought the contract below
-		/// the subsistence threshold. No transfer is allowed to do this in order to allow
-		/// for a tombstone to be created. Use `seal_terminate` to remove a contract without
-		/// leaving a tombstone behind.
+		/// the subsistence threshold. No transfer is allowed to do this. Use `seal_terminate`
+		/// to remove a contract.
 		BelowSubsistenceThreshold,
 		/// The newly created contract is below the subsistence threshold after executing
 		/// its contructor. No contracts are allowed to exist below that threshold.
@@ -537,18 +473,6 @@ pub mod pallet {
 		MaxCallDepthReached,
 		/// No contract was found at the specified address.
 		ContractNotFound,
-		/// A tombstone exist at the specified address.
-		///
-		/// Tombstone cannot be called. Anyone can use `seal_restore_to` in order to revive
-		/// the contract, though.
-		ContractIsTombstone,
-		/// The called contract does not have enough balance to pay for its storage.
-		///
-		/// The contract ran out of balance and is therefore eligible for eviction into a
-		/// tombstone. Anyone can evict the contract by submitting a `claim_surcharge`
-		/// extrinsic. Alternatively, a plain balance transfer can be used in order to
-		/// increase the contracts funds so that it can be called again.
-		RentNotPaid,
 		/// The code supplied to `instantiate_with_code` exceeds the limit specified in the
 		/// current schedule.
 		CodeTooLarge,
@@ -563,7 +487,7 @@ pub mod pallet {
 		/// The size defined in `T::MaxValueSize` was exceeded.
 		ValueTooLarge,
 		/// Termination of a contract is not allowed while the contract is already
-		/// on the call stack. Can be triggered by `seal_terminate` or `seal_restore_to.
+		/// on the call stack. Can be triggered by `seal_terminate`.
 		TerminatedWhileReentrant,
 		/// `seal_call` forwarded this contracts input. It therefore is no longer available.
 		InputForwarded,
@@ -579,15 +503,10 @@ pub mod pallet {
 		NoChainExtension,
 		/// Removal of a contract failed because the deletion queue is full.
 		///
-		/// This can happen when either calling [`Pallet::claim_surcharge`] or `seal_terminate`.
+		/// This can happen when calling `seal_terminate`.
 		/// The queue is filled by deleting contracts and emptied by a fixed amount each block.
 		/// Trying again during another block is the only way to resolve this issue.
 		DeletionQueueFull,
-		/// A contract could not be evicted because it has enough balance to pay rent.
-		///
-		/// This can be returned from [`Pallet::claim_surcharge`] because the target
-		/// contract has enough balance to pay for its rent.
-		ContractNotEvictable,
 		/// A storage modification exhausted the 32bit type that holds the storage size.
 		///
 		/// This can either happen when the accumulated storage in bytes is too large or
@@ -597,12 +516,16 @@ pub mod pallet {
 		DuplicateContract,
 		/// A contract self destructed in its constructor.
 		///
-		/// This can be triggered by a call to `seal_terminate` or `seal_restore_to`.
+		/// This can be triggered by a call to `seal_terminate`.
 		TerminatedInConstructor,
 		/// The debug message specified to `seal_debug_message` does contain invalid UTF-8.
 		DebugMessageInvalidUTF8,
 		/// A call tried to invoke a contract that is flagged as non-reentrant.
 		ReentranceDenied,
+		/// The origin does not have enough free balance to pay the storage deposit.
+		StorageDepositNotEnoughFunds,
+		/// The storage deposit exceeds the `storage_deposit_limit` of the call.
+		StorageDepositLimitExhausted,
 	}
 
 	/// A mapping from an original code hash to the original code, untouched by instrumentation.
@@ -623,7 +546,7 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ContractInfoOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, ContractInfo<T>>;
 
-	/// Evicted contracts that await child trie deletion.
+	/// Terminated contracts that await child trie deletion.
 	///
 	/// Child trie deletion is a heavy operation depending on the amount of storage items
 	/// stored in said trie. Therefore this operation is performed lazily in `on_initialize`.
@@ -646,16 +569,18 @@ where
 	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
 	/// If set to `true` it returns additional human readable debugging information.
 	///
-	/// It returns the execution result and the amount of used weight.
+	/// It returns the execution result, the amount of used weight and the storage deposit.
 	pub fn bare_call(
 		origin: T::AccountId,
 		dest: T::AccountId,
 		value: BalanceOf<T>,
 		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
 		input_data: Vec<u8>,
 		debug: bool,
-	) -> ContractExecResult {
+	) -> ContractExecResult<BalanceOf<T>> {
 		let mut gas_meter = GasMeter::new(gas_limit);
+		let mut storage_meter = StorageMeter::new(origin.clone(), storage_deposit_limit);
 		let schedule = T::Schedule::get();
 		let mut debug_message = if debug {
 			Some(Vec::new())
@@ -663,11 +588,13 @@ where
 			None
 		};
 		let result = ExecStack::<T, PrefabWasmModule<T>>::run_call(
-			origin, dest, &mut gas_meter, &schedule, value, input_data, debug_message.as_mut(),
+			origin, dest, &mut gas_meter, &mut storage_meter, &schedule,
+			value, input_data, debug_message.as_mut(),
 		);
 		ContractExecResult {
 			result: result.map(|r| r.0).map_err(|r| r.0.error),
-			gas_consumed: gas_meter.gas_spent(),
+			gas_consumed: gas_meter.gas_spent().ref_time(),
+			storage_deposit: storage_meter.deposit(),
 			debug_message: debug_message.unwrap_or_default(),
 		}
 	}
@@ -677,11 +604,8 @@ where
 	/// This function is similar to [`Self::instantiate`], but doesn't perform any address lookups
 	/// and better suitable for calling directly from Rust.
 	///
-	/// It returns the execution result, account id and the amount of used weight.
-	///
-	/// If `compute_projection` is set to `true` the result also contains the rent projection.
-	/// This is optional because some non trivial and stateful work is performed to compute
-	/// the projection. See [`Self::rent_projection`].
+	/// It returns the execution result, account id, the amount of used weight and the
+	/// storage deposit.
 	///
 	/// # Note
 	///
@@ -692,13 +616,14 @@ where
 		origin: T::AccountId,
 		endowment: BalanceOf<T>,
 		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
 		code: Code<CodeHash<T>>,
 		data: Vec<u8>,
 		salt: Vec<u8>,
-		compute_projection: bool,
 		debug: bool,
-	) -> ContractInstantiateResult<T::AccountId, T::BlockNumber> {
+	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>> {
 		let mut gas_meter = GasMeter::new(gas_limit);
+		let mut storage_meter = StorageMeter::new(origin.clone(), storage_deposit_limit);
 		let schedule = T::Schedule::get();
 		let executable = match code {
 			Code::Upload(Bytes(binary)) => PrefabWasmModule::from_code(binary, &schedule),
@@ -708,7 +633,8 @@ where
 			Ok(executable) => executable,
 			Err(error) => return ContractInstantiateResult {
 				result: Err(error.into()),
-				gas_consumed: gas_meter.gas_spent(),
+				gas_consumed: gas_meter.gas_spent().ref_time(),
+				storage_deposit: Default::default(),
 				debug_message: Vec::new(),
 			}
 		};
@@ -718,25 +644,13 @@ where
 			None
 		};
 		let result = ExecStack::<T, PrefabWasmModule<T>>::run_instantiate(
-			origin, executable, &mut gas_meter, &schedule,
+			origin, executable, &mut gas_meter, &mut storage_meter, &schedule,
 			endowment, data, &salt, debug_message.as_mut(),
-		).and_then(|(account_id, result)| {
-			let rent_projection = if compute_projection {
-				Some(Rent::<T, PrefabWasmModule<T>>::compute_projection(&account_id)
-					.map_err(|_| <Error<T>>::NewContractNotFunded)?)
-			} else {
-				None
-			};
-
-			Ok(InstantiateReturnValue {
-				result,
-				account_id,
-				rent_projection,
-			})
-		});
+		).map(|(account_id, result)| InstantiateReturnValue { result, account_id });
 		ContractInstantiateResult {
 			result: result.map_err(|e| e.error),
-			gas_consumed: gas_meter.gas_spent(),
+			gas_consumed: gas_meter.gas_spent().ref_time(),
+			storage_deposit: storage_meter.deposit(),
 			debug_message: debug_message.unwrap_or_default(),
 		}
 	}
@@ -744,20 +658,12 @@ where
 	/// Query storage of a specified contract under a specified key.
 	pub fn get_storage(address: T::AccountId, key: [u8; 32]) -> GetStorageResult {
 		let contract_info = ContractInfoOf::<T>::get(&address)
-			.ok_or(ContractAccessError::DoesntExist)?
-			.get_alive()
-			.ok_or(ContractAccessError::IsTombstone)?;
+			.ok_or(ContractAccessError::DoesntExist)?;
 
 		let maybe_value = Storage::<T>::read(&contract_info.trie_id, &key);
 		Ok(maybe_value)
 	}
 
-	/// Query how many blocks the contract stays alive given that the amount endowment
-	/// and consumed storage does not change.
-	pub fn rent_projection(address: T::AccountId) -> RentProjectionResult<T::BlockNumber> {
-		Rent::<T, PrefabWasmModule<T>>::compute_projection(&address)
-	}
-
 	/// Determine the address of a contract,
 	///
 	/// This is the address generation function used by contract instantiation. Its result
@@ -780,15 +686,15 @@ where
 		UncheckedFrom::unchecked_from(T::Hashing::hash(&buf))
 	}
 
-	/// Subsistence threshold is the extension of the minimum balance (aka existential deposit)
-	/// by the tombstone deposit, required for leaving a tombstone.
+	/// Subsistence threshold is the minimum free balance (aka existential deposit) a contract
+	/// needs to keep.
 	///
-	/// Rent or any contract initiated balance transfer mechanism cannot make the balance lower
-	/// than the subsistence threshold in order to guarantee that a tombstone is created.
+	/// No contract initiated balance transfer can make the free balance lower than the
+	/// subsistence threshold. The storage deposit is held as reserved balance on top of it.
 	///
-	/// The only way to completely kill a contract without a tombstone is calling `seal_terminate`.
+	/// The only way to completely kill a contract is calling `seal_terminate`.
 	pub fn subsistence_threshold() -> BalanceOf<T> {
-		T::Currency::minimum_balance().saturating_add(T::TombstoneDeposit::get())
+		T::Currency::minimum_balance()
 	}
 
 	/// The in-memory size in bytes of the data structure associated with each contract.
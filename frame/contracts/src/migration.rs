@@ -15,14 +15,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Config, Weight, Pallet};
+use crate::{
+	Config, Weight, Pallet, ContractInfoOf, BalanceOf, CodeHash, TrieId, storage::ContractInfo,
+};
+use codec::Decode;
 use frame_support::{
 	storage::migration,
-	traits::{GetPalletVersion, PalletVersion, PalletInfoAccess, Get},
+	traits::{
+		GetPalletVersion, GetStorageVersion, PalletVersion, PalletInfoAccess, Get, StorageVersion,
+	},
 };
+use sp_runtime::traits::Zero;
 
 pub fn migrate<T: Config>() -> Weight {
-	let mut weight: Weight = 0;
+	let mut weight = Weight::zero();
 
 	match <Pallet<T>>::storage_version() {
 		Some(version) if version == PalletVersion::new(3, 0, 0) => {
@@ -36,5 +42,57 @@ pub fn migrate<T: Config>() -> Weight {
 		_ => (),
 	}
 
+	if <Pallet<T>>::on_chain_storage_version() < 4 {
+		weight = weight.saturating_add(v4::migrate::<T>());
+		StorageVersion::new(4).put::<Pallet<T>>();
+	}
+
 	weight
 }
+
+/// Replace the rent based contract infos by the storage deposit based ones.
+///
+/// Tombstones are dropped as they cannot be restored anymore. Their storage was already
+/// removed when they were evicted. The storage deposit of the remaining contracts starts
+/// at zero, their existing storage was paid for by their rent.
+mod v4 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldAliveContractInfo<T: Config> {
+		trie_id: TrieId,
+		storage_size: u32,
+		pair_count: u32,
+		code_hash: CodeHash<T>,
+		_rent_allowance: BalanceOf<T>,
+		_rent_paid: BalanceOf<T>,
+		_deduct_block: T::BlockNumber,
+		_last_write: Option<T::BlockNumber>,
+		_reserved: Option<()>,
+	}
+
+	#[derive(Decode)]
+	enum OldContractInfo<T: Config> {
+		Alive(OldAliveContractInfo<T>),
+		Tombstone(T::Hash),
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let mut translated = 0u64;
+		<ContractInfoOf<T>>::translate::<OldContractInfo<T>, _>(|_, old| {
+			translated = translated.saturating_add(1);
+			match old {
+				OldContractInfo::Alive(old) => Some(ContractInfo::<T> {
+					trie_id: old.trie_id,
+					storage_size: old.storage_size,
+					pair_count: old.pair_count,
+					code_hash: old.code_hash,
+					storage_deposit: Zero::zero(),
+					_reserved: None,
+				}),
+				OldContractInfo::Tombstone(_) => None,
+			}
+		});
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+}
@@ -267,12 +267,6 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_minimum_balance`.
 	pub minimum_balance: Weight,
 
-	/// Weight of calling `seal_tombstone_deposit`.
-	pub tombstone_deposit: Weight,
-
-	/// Weight of calling `seal_rent_allowance`.
-	pub rent_allowance: Weight,
-
 	/// Weight of calling `seal_block_number`.
 	pub block_number: Weight,
 
@@ -303,18 +297,6 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight per byte of the terminated contract.
 	pub terminate_per_code_byte: Weight,
 
-	/// Weight of calling `seal_restore_to`.
-	pub restore_to: Weight,
-
-	/// Weight per byte of the restoring contract.
-	pub restore_to_per_caller_code_byte: Weight,
-
-	/// Weight per byte of the restored contract.
-	pub restore_to_per_tombstone_code_byte: Weight,
-
-	/// Weight per delta key supplied to `seal_restore_to`.
-	pub restore_to_per_delta: Weight,
-
 	/// Weight of calling `seal_random`.
 	pub random: Weight,
 
@@ -330,9 +312,6 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_debug_message`.
 	pub debug_message: Weight,
 
-	/// Weight of calling `seal_set_rent_allowance`.
-	pub set_rent_allowance: Weight,
-
 	/// Weight of calling `seal_set_storage`.
 	pub set_storage: Weight,
 
@@ -428,13 +407,13 @@ macro_rules! cost_args {
 
 macro_rules! cost_batched_args {
 	($name:ident, $( $arg: expr ),+) => {
-		cost_args!($name, $( $arg ),+) / Weight::from(API_BENCHMARK_BATCH_SIZE)
+		cost_args!($name, $( $arg ),+) / u64::from(API_BENCHMARK_BATCH_SIZE)
 	}
 }
 
 macro_rules! cost_instr_no_params_with_batch_size {
 	($name:ident, $batch_size:expr) => {
-		(cost_args!($name, 1) / Weight::from($batch_size)) as u32
+		(cost_args!($name, 1) / u64::from($batch_size)).ref_time() as u32
 	}
 }
 
@@ -577,8 +556,6 @@ impl<T: Config> Default for HostFnWeights<T> {
 			balance: cost_batched!(seal_balance),
 			value_transferred: cost_batched!(seal_value_transferred),
 			minimum_balance: cost_batched!(seal_minimum_balance),
-			tombstone_deposit: cost_batched!(seal_tombstone_deposit),
-			rent_allowance: cost_batched!(seal_rent_allowance),
 			block_number: cost_batched!(seal_block_number),
 			now: cost_batched!(seal_now),
 			weight_to_fee: cost_batched!(seal_weight_to_fee),
@@ -589,16 +566,11 @@ impl<T: Config> Default for HostFnWeights<T> {
 			return_per_byte: cost_byte!(seal_return_per_kb),
 			terminate: cost!(seal_terminate),
 			terminate_per_code_byte: cost_byte!(seal_terminate_per_code_kb),
-			restore_to: cost!(seal_restore_to),
-			restore_to_per_caller_code_byte: cost_byte_args!(seal_restore_to_per_code_kb_delta, 1, 0, 0),
-			restore_to_per_tombstone_code_byte: cost_byte_args!(seal_restore_to_per_code_kb_delta, 0, 1, 0),
-			restore_to_per_delta: cost_batched_args!(seal_restore_to_per_code_kb_delta, 0, 0, 1),
 			random: cost_batched!(seal_random),
 			deposit_event: cost_batched!(seal_deposit_event),
 			deposit_event_per_topic: cost_batched_args!(seal_deposit_event_per_topic_and_kb, 1, 0),
 			deposit_event_per_byte: cost_byte_batched_args!(seal_deposit_event_per_topic_and_kb, 0, 1),
 			debug_message: cost_batched!(seal_debug_message),
-			set_rent_allowance: cost_batched!(seal_set_rent_allowance),
 			set_storage: cost_batched!(seal_set_storage),
 			set_storage_per_byte: cost_byte_batched!(seal_set_storage_per_kb),
 			clear_storage: cost_batched!(seal_clear_storage),
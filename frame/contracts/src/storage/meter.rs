@@ -0,0 +1,214 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! This module contains the meter that collects the storage deposits of a call stack.
+//!
+//! Every contract holds a deposit for the storage it occupies. It is reserved from the balance
+//! of the contract and recorded in [`ContractInfo::storage_deposit`]. The deposit is paid by
+//! the origin of the call stack which created the storage and refunded to the origin of the
+//! call stack which removes it again.
+
+use crate::{BalanceOf, Config, Error, Event, Pallet, storage::ContractInfo};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{BalanceStatus, Currency, ExistenceRequirement, Get, ReservableCurrency},
+};
+use pallet_contracts_primitives::StorageDeposit;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// The storage a contract gained and lost during the execution of a single frame.
+#[derive(Default, Clone, Copy)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct Diff {
+	/// How many bytes were added to the storage of the contract.
+	pub bytes_added: u32,
+	/// How many bytes were removed from the storage of the contract.
+	pub bytes_removed: u32,
+	/// How many storage items were added.
+	pub items_added: u32,
+	/// How many storage items were removed.
+	pub items_removed: u32,
+	/// Whether the contract was instantiated and needs to pay [`Config::DepositPerContract`].
+	pub instantiated: bool,
+}
+
+impl Diff {
+	/// Add the changes of `other` to the changes of `self`.
+	pub fn combine(self, other: Self) -> Self {
+		Self {
+			bytes_added: self.bytes_added.saturating_add(other.bytes_added),
+			bytes_removed: self.bytes_removed.saturating_add(other.bytes_removed),
+			items_added: self.items_added.saturating_add(other.items_added),
+			items_removed: self.items_removed.saturating_add(other.items_removed),
+			instantiated: self.instantiated || other.instantiated,
+		}
+	}
+
+	/// The deposit that is required for the added storage and the one that is released
+	/// by the removed storage, in this order.
+	fn deposits<T: Config>(&self) -> (BalanceOf<T>, BalanceOf<T>) {
+		let per_byte = T::DepositPerStorageByte::get();
+		let per_item = T::DepositPerStorageItem::get();
+		let mut charge = per_byte.saturating_mul(self.bytes_added.into())
+			.saturating_add(per_item.saturating_mul(self.items_added.into()));
+		if self.instantiated {
+			charge = charge.saturating_add(T::DepositPerContract::get());
+		}
+		let refund = per_byte.saturating_mul(self.bytes_removed.into())
+			.saturating_add(per_item.saturating_mul(self.items_removed.into()));
+		(charge, refund)
+	}
+}
+
+/// The amounts collected by a [`Meter`] at some point, so that they can be reverted together
+/// with the storage transaction of a frame.
+#[derive(Clone, Copy)]
+pub struct Checkpoint<Balance> {
+	charged: Balance,
+	refunded: Balance,
+}
+
+/// Collects the storage deposits of all the contracts of a call stack from its origin.
+///
+/// When a frame finishes successfully, the storage its contract gained or lost is settled:
+/// the deposit is either transferred from the origin to the contract, where it is reserved,
+/// or repatriated from the contract to the origin. The net amount charged from the origin
+/// must not exceed the limit passed along with the call.
+pub struct Meter<T: Config> {
+	/// The account paying for and receiving the refunds of the storage deposits.
+	origin: T::AccountId,
+	/// The maximum net amount that can be charged from the origin. `None` means no limit.
+	limit: Option<BalanceOf<T>>,
+	/// The total amount that was charged from the origin.
+	charged: BalanceOf<T>,
+	/// The total amount that was refunded to the origin.
+	refunded: BalanceOf<T>,
+}
+
+impl<T: Config> Meter<T> {
+	/// Create a new meter charging `origin` at most `limit`.
+	pub fn new(origin: T::AccountId, limit: Option<BalanceOf<T>>) -> Self {
+		Self {
+			origin,
+			limit,
+			charged: Zero::zero(),
+			refunded: Zero::zero(),
+		}
+	}
+
+	/// The amounts collected so far.
+	pub fn checkpoint(&self) -> Checkpoint<BalanceOf<T>> {
+		Checkpoint { charged: self.charged, refunded: self.refunded }
+	}
+
+	/// Forget everything collected since `checkpoint` was taken.
+	///
+	/// This must be called when the storage transaction in which the deposits were
+	/// transferred is rolled back.
+	pub fn revert_to(&mut self, checkpoint: Checkpoint<BalanceOf<T>>) {
+		self.charged = checkpoint.charged;
+		self.refunded = checkpoint.refunded;
+	}
+
+	/// The net amount charged from or refunded to the origin.
+	pub fn deposit(&self) -> StorageDeposit<BalanceOf<T>> {
+		if self.charged >= self.refunded {
+			StorageDeposit::Charge(self.charged.saturating_sub(self.refunded))
+		} else {
+			StorageDeposit::Refund(self.refunded.saturating_sub(self.charged))
+		}
+	}
+
+	/// Charge or refund the deposit for the storage `contract` gained or lost according
+	/// to `diff`, updating its `info` accordingly.
+	pub fn settle(
+		&mut self,
+		contract: &T::AccountId,
+		info: &mut ContractInfo<T>,
+		diff: Diff,
+	) -> DispatchResult {
+		let (charge, refund) = diff.deposits::<T>();
+		if charge >= refund {
+			self.charge(contract, info, charge.saturating_sub(refund))
+		} else {
+			self.refund(contract, info, refund.saturating_sub(charge))
+		}
+	}
+
+	/// Refund the whole deposit of `contract` which is about to be removed.
+	pub fn terminate(
+		&mut self,
+		contract: &T::AccountId,
+		info: &mut ContractInfo<T>,
+	) -> DispatchResult {
+		let deposit = info.storage_deposit;
+		self.refund(contract, info, deposit)
+	}
+
+	fn charge(
+		&mut self,
+		contract: &T::AccountId,
+		info: &mut ContractInfo<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let charged = self.charged.saturating_add(amount);
+		if let Some(limit) = self.limit {
+			ensure!(
+				charged.saturating_sub(self.refunded) <= limit,
+				Error::<T>::StorageDepositLimitExhausted,
+			);
+		}
+		T::Currency::transfer(&self.origin, contract, amount, ExistenceRequirement::KeepAlive)
+			.and_then(|_| T::Currency::reserve(contract, amount))
+			.map_err(|_| Error::<T>::StorageDepositNotEnoughFunds)?;
+		info.storage_deposit = info.storage_deposit.saturating_add(amount);
+		self.charged = charged;
+		Pallet::<T>::deposit_event(
+			Event::StorageDepositCharged(self.origin.clone(), contract.clone(), amount),
+		);
+		Ok(())
+	}
+
+	fn refund(
+		&mut self,
+		contract: &T::AccountId,
+		info: &mut ContractInfo<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// Contracts cannot be refunded more than they deposited. This can happen for storage
+		// that was created before deposits were introduced.
+		let amount = amount.min(info.storage_deposit);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let remaining = T::Currency::repatriate_reserved(
+			contract, &self.origin, amount, BalanceStatus::Free,
+		).map_err(|_| Error::<T>::TransferFailed)?;
+		let amount = amount.saturating_sub(remaining);
+		info.storage_deposit = info.storage_deposit.saturating_sub(amount);
+		self.refunded = self.refunded.saturating_add(amount);
+		Pallet::<T>::deposit_event(
+			Event::StorageDepositRefunded(contract.clone(), self.origin.clone(), amount),
+		);
+		Ok(())
+	}
+}
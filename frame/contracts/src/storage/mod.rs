@@ -17,18 +17,21 @@
 
 //! This module contains routines for accessing and altering a contract related state.
 
+pub mod meter;
+
 use crate::{
 	exec::{AccountIdOf, StorageKey},
 	BalanceOf, CodeHash, ContractInfoOf, Config, TrieId, DeletionQueue, Error,
 	weights::WeightInfo,
+	storage::meter::Diff,
 };
-use codec::{Codec, Encode, Decode};
+use codec::{Encode, Decode};
 use sp_std::prelude::*;
-use sp_std::{marker::PhantomData, fmt::Debug};
+use sp_std::marker::PhantomData;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	RuntimeDebug,
-	traits::{Bounded, Saturating, Zero, Hash, Member, MaybeSerializeDeserialize},
+	traits::{Zero, Hash},
 };
 use sp_core::crypto::UncheckedFrom;
 use frame_support::{
@@ -38,53 +41,12 @@ use frame_support::{
 	weights::Weight,
 };
 
-pub type AliveContractInfo<T> =
-	RawAliveContractInfo<CodeHash<T>, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
-pub type TombstoneContractInfo<T> =
-	RawTombstoneContractInfo<<T as frame_system::Config>::Hash, <T as frame_system::Config>::Hashing>;
-
-/// Information for managing an account and its sub trie abstraction.
-/// This is the required info to cache for an account
-#[derive(Encode, Decode, RuntimeDebug)]
-pub enum ContractInfo<T: Config> {
-	Alive(AliveContractInfo<T>),
-	Tombstone(TombstoneContractInfo<T>),
-}
-
-impl<T: Config> ContractInfo<T> {
-	/// If contract is alive then return some alive info
-	pub fn get_alive(self) -> Option<AliveContractInfo<T>> {
-		if let ContractInfo::Alive(alive) = self {
-			Some(alive)
-		} else {
-			None
-		}
-	}
-
-	/// If contract is alive then return some reference to alive info
-	#[cfg(test)]
-	pub fn as_alive(&self) -> Option<&AliveContractInfo<T>> {
-		if let ContractInfo::Alive(ref alive) = self {
-			Some(alive)
-		} else {
-			None
-		}
-	}
-
-	/// If contract is tombstone then return some tombstone info
-	pub fn get_tombstone(self) -> Option<TombstoneContractInfo<T>> {
-		if let ContractInfo::Tombstone(tombstone) = self {
-			Some(tombstone)
-		} else {
-			None
-		}
-	}
-}
+pub type ContractInfo<T> = RawContractInfo<CodeHash<T>, BalanceOf<T>>;
 
 /// Information for managing an account and its sub trie abstraction.
 /// This is the required info to cache for an account.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct RawAliveContractInfo<CodeHash, Balance, BlockNumber> {
+pub struct RawContractInfo<CodeHash, Balance> {
 	/// Unique ID for the subtree encoded as a bytes vector.
 	pub trie_id: TrieId,
 	/// The total number of bytes used by this contract.
@@ -95,21 +57,16 @@ pub struct RawAliveContractInfo<CodeHash, Balance, BlockNumber> {
 	pub pair_count: u32,
 	/// The code associated with a given account.
 	pub code_hash: CodeHash,
-	/// Pay rent at most up to this value.
-	pub rent_allowance: Balance,
-	/// The amount of rent that was paid by the contract over its whole lifetime.
+	/// The balance that is reserved from the contract's account to pay for its storage.
 	///
-	/// A restored contract starts with a value of zero just like a new contract.
-	pub rent_paid: Balance,
-	/// Last block rent has been paid.
-	pub deduct_block: BlockNumber,
-	/// Last block child storage has been written.
-	pub last_write: Option<BlockNumber>,
+	/// It grows and shrinks with the storage of the contract and is returned in full
+	/// when the contract is terminated.
+	pub storage_deposit: Balance,
 	/// This field is reserved for future evolution of format.
 	pub _reserved: Option<()>,
 }
 
-impl<CodeHash, Balance, BlockNumber> RawAliveContractInfo<CodeHash, Balance, BlockNumber> {
+impl<CodeHash, Balance> RawContractInfo<CodeHash, Balance> {
 	/// Associated child trie unique id is built from the hash part of the trie id.
 	pub fn child_trie_info(&self) -> ChildInfo {
 		child_trie_info(&self.trie_id[..])
@@ -121,30 +78,6 @@ fn child_trie_info(trie_id: &[u8]) -> ChildInfo {
 	ChildInfo::new_default(trie_id)
 }
 
-#[derive(Encode, Decode, PartialEq, Eq, RuntimeDebug)]
-pub struct RawTombstoneContractInfo<H, Hasher>(H, PhantomData<Hasher>);
-
-impl<H, Hasher> RawTombstoneContractInfo<H, Hasher>
-where
-	H: Member + MaybeSerializeDeserialize+ Debug
-		+ AsRef<[u8]> + AsMut<[u8]> + Copy + Default
-		+ sp_std::hash::Hash + Codec,
-	Hasher: Hash<Output=H>,
-{
-	pub fn new(storage_root: &[u8], code_hash: H) -> Self {
-		let mut buf = Vec::new();
-		storage_root.using_encoded(|encoded| buf.extend_from_slice(encoded));
-		buf.extend_from_slice(code_hash.as_ref());
-		RawTombstoneContractInfo(<Hasher as Hash>::hash(&buf[..]), PhantomData)
-	}
-}
-
-impl<T: Config> From<AliveContractInfo<T>> for ContractInfo<T> {
-	fn from(alive_info: AliveContractInfo<T>) -> Self {
-		Self::Alive(alive_info)
-	}
-}
-
 #[derive(Encode, Decode)]
 pub struct DeletedContract {
 	pair_count: u32,
@@ -170,29 +103,35 @@ where
 	///
 	/// If the `opt_new_value` is `None` then the kv pair is removed.
 	///
-	/// This function also updates the bookkeeping info such as: number of total non-empty pairs a
-	/// contract owns, the last block the storage was written to, etc. That's why, in contrast to
-	/// `read`, this function also requires the `account` ID.
+	/// This function also updates the bookkeeping info such as the number of total non-empty
+	/// pairs a contract owns. That's why, in contrast to `read`, this function also requires
+	/// the contract info.
+	///
+	/// Returns by how much the storage of the contract grew or shrank. The storage deposit
+	/// for this change is collected by the caller through a [`meter::Meter`].
 	pub fn write(
-		block_number: T::BlockNumber,
-		new_info: &mut AliveContractInfo<T>,
+		new_info: &mut ContractInfo<T>,
 		key: &StorageKey,
 		opt_new_value: Option<Vec<u8>>,
-	) -> DispatchResult {
+	) -> Result<Diff, DispatchError> {
 		let hashed_key = blake2_256(key);
 		let child_trie_info = &child_trie_info(&new_info.trie_id);
 
 		let opt_prev_len = child::len(&child_trie_info, &hashed_key);
 
+		let mut diff = Diff::default();
+
 		// Update the total number of KV pairs and the number of empty pairs.
 		match (&opt_prev_len, &opt_new_value) {
 			(Some(_), None) => {
 				new_info.pair_count = new_info.pair_count.checked_sub(1)
 					.ok_or_else(|| Error::<T>::StorageExhausted)?;
+				diff.items_removed = 1;
 			},
 			(None, Some(_)) => {
 				new_info.pair_count = new_info.pair_count.checked_add(1)
 					.ok_or_else(|| Error::<T>::StorageExhausted)?;
+				diff.items_added = 1;
 			},
 			(Some(_), Some(_)) => {},
 			(None, None) => {},
@@ -209,8 +148,8 @@ where
 			.checked_sub(prev_value_len)
 			.and_then(|val| val.checked_add(new_value_len))
 			.ok_or_else(|| Error::<T>::StorageExhausted)?;
-
-		new_info.last_write = Some(block_number);
+		diff.bytes_added = new_value_len;
+		diff.bytes_removed = prev_value_len;
 
 		// Finally, perform the change on the storage.
 		match opt_new_value {
@@ -218,34 +157,27 @@ where
 			None => child::kill(&child_trie_info, &hashed_key),
 		}
 
-		Ok(())
+		Ok(diff)
 	}
 
 	/// Creates a new contract descriptor in the storage with the given code hash at the given address.
 	///
-	/// Returns `Err` if there is already a contract (or a tombstone) exists at the given address.
+	/// Returns `Err` if there is already a contract at the given address.
 	pub fn new_contract(
 		account: &AccountIdOf<T>,
 		trie_id: TrieId,
 		ch: CodeHash<T>,
-	) -> Result<AliveContractInfo<T>, DispatchError> {
+	) -> Result<ContractInfo<T>, DispatchError> {
 		if <ContractInfoOf<T>>::contains_key(account) {
 			return Err(Error::<T>::DuplicateContract.into());
 		}
 
-		let contract = AliveContractInfo::<T> {
+		let contract = ContractInfo::<T> {
 			code_hash: ch,
 			storage_size: 0,
 			trie_id,
-			deduct_block:
-				// We want to charge rent for the first block in advance. Therefore we
-				// treat the contract as if it was created in the last block and then
-				// charge rent for it during instantiation.
-				<frame_system::Pallet<T>>::block_number().saturating_sub(1u32.into()),
-			rent_allowance: <BalanceOf<T>>::max_value(),
-			rent_paid: <BalanceOf<T>>::zero(),
 			pair_count: 0,
-			last_write: None,
+			storage_deposit: <BalanceOf<T>>::zero(),
 			_reserved: None,
 		};
 
@@ -254,9 +186,8 @@ where
 
 	/// Push a contract's trie to the deletion queue for lazy removal.
 	///
-	/// You must make sure that the contract is also removed or converted into a tombstone
-	/// when queuing the trie for deletion.
-	pub fn queue_trie_for_deletion(contract: &AliveContractInfo<T>) -> DispatchResult {
+	/// You must make sure that the contract is also removed when queuing the trie for deletion.
+	pub fn queue_trie_for_deletion(contract: &ContractInfo<T>) -> DispatchResult {
 		if <DeletionQueue<T>>::decode_len().unwrap_or(0) >= T::DeletionQueueDepth::get() as usize {
 			Err(Error::<T>::DeletionQueueFull.into())
 		} else {
@@ -271,20 +202,24 @@ where
 	/// Calculates the weight that is necessary to remove one key from the trie and how many
 	/// of those keys can be deleted from the deletion queue given the supplied queue length
 	/// and weight limit.
-	pub fn deletion_budget(queue_len: usize, weight_limit: Weight) -> (u64, u32) {
+	///
+	/// Only the computation time is budgeted, it is what the deletion of the keys is
+	/// benchmarked for.
+	pub fn deletion_budget(queue_len: usize, weight_limit: Weight) -> (Weight, u32) {
 		let base_weight = T::WeightInfo::on_initialize();
 		let weight_per_queue_item = T::WeightInfo::on_initialize_per_queue_item(1) -
 			T::WeightInfo::on_initialize_per_queue_item(0);
 		let weight_per_key = T::WeightInfo::on_initialize_per_trie_key(1) -
 			T::WeightInfo::on_initialize_per_trie_key(0);
-		let decoding_weight = weight_per_queue_item.saturating_mul(queue_len as Weight);
+		let decoding_weight = weight_per_queue_item.saturating_mul(queue_len as u64);
 
 		// `weight_per_key` being zero makes no sense and would constitute a failure to
 		// benchmark properly. We opt for not removing any keys at all in this case.
 		let key_budget = weight_limit
 			.saturating_sub(base_weight)
 			.saturating_sub(decoding_weight)
-			.checked_div(weight_per_key)
+			.ref_time()
+			.checked_div(weight_per_key.ref_time())
 			.unwrap_or(0) as u32;
 
 		(weight_per_key, key_budget)
@@ -346,7 +281,7 @@ where
 		}
 
 		<DeletionQueue<T>>::put(queue);
-		weight_limit.saturating_sub(weight_per_key.saturating_mul(remaining_key_budget as Weight))
+		weight_limit.saturating_sub(weight_per_key.saturating_mul(remaining_key_budget as u64))
 	}
 
 	/// This generator uses inner counter for account id and applies the hash over `AccountId +
@@ -363,8 +298,7 @@ where
 	#[cfg(test)]
 	pub fn code_hash(account: &AccountIdOf<T>) -> Option<CodeHash<T>>
 	{
-		<ContractInfoOf<T>>::get(account)
-			.and_then(|i| i.as_alive().map(|i| i.code_hash))
+		<ContractInfoOf<T>>::get(account).map(|i| i.code_hash)
 	}
 
 	/// Fill up the queue in order to exercise the limits during testing.
@@ -21,12 +21,11 @@ use crate::{
 	Error, storage::Storage,
 	chain_extension::{
 		Result as ExtensionResult, Environment, ChainExtension, Ext, SysConfig, RetVal,
-		UncheckedFrom, InitState, ReturnFlags,
+		UncheckedFrom, InitState, ReturnFlags, RegisteredChainExtension,
 	},
-	exec::{AccountIdOf, Executable, Frame}, wasm::PrefabWasmModule,
+	exec::{AccountIdOf, Frame}, wasm::PrefabWasmModule,
 	weights::WeightInfo,
 	wasm::ReturnCode as RuntimeReturnCode,
-	storage::RawAliveContractInfo,
 };
 use assert_matches::assert_matches;
 use codec::Encode;
@@ -34,13 +33,13 @@ use sp_core::Bytes;
 use sp_runtime::{
 	traits::{BlakeTwo256, Hash, IdentityLookup, Convert},
 	testing::{Header, H256},
-	AccountId32, Perbill,
+	AccountId32,
 };
 use sp_io::hashing::blake2_256;
 use frame_support::{
 	assert_ok, assert_err, assert_err_ignore_postinfo,
-	parameter_types, assert_storage_noop,
-	traits::{Currency, ReservableCurrency, OnInitialize},
+	parameter_types,
+	traits::{BalanceStatus, Currency, ReservableCurrency, OnInitialize},
 	weights::{Weight, PostDispatchInfo, DispatchClass, constants::WEIGHT_PER_SECOND},
 	dispatch::DispatchErrorWithPostInfo,
 	storage::child,
@@ -69,10 +68,10 @@ frame_support::construct_runtime!(
 
 #[macro_use]
 pub mod test_utils {
-	use super::{Test, Balances, System};
+	use super::{Test, Balances};
 	use crate::{
 		ContractInfoOf, CodeHash,
-		storage::{Storage, ContractInfo},
+		storage::Storage,
 		exec::{StorageKey, AccountIdOf},
 		Pallet as Contracts,
 		TrieId, AccountCounter,
@@ -80,12 +79,12 @@ pub mod test_utils {
 	use frame_support::traits::Currency;
 
 	pub fn set_storage(addr: &AccountIdOf<Test>, key: &StorageKey, value: Option<Vec<u8>>) {
-		let mut contract_info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
-		let block_number = System::block_number();
-		Storage::<Test>::write(block_number, &mut contract_info, key, value).unwrap();
+		let mut contract_info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
+		Storage::<Test>::write(&mut contract_info, key, value).unwrap();
+		<ContractInfoOf::<Test>>::insert(&addr, contract_info);
 	}
 	pub fn get_storage(addr: &AccountIdOf<Test>, key: &StorageKey) -> Option<Vec<u8>> {
-		let contract_info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
+		let contract_info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
 		Storage::<Test>::read(&contract_info.trie_id, key)
 	}
 	pub fn generate_trie_id(address: &AccountIdOf<Test>) -> TrieId {
@@ -99,7 +98,7 @@ pub mod test_utils {
 		let trie_id = generate_trie_id(address);
 		set_balance(address, Contracts::<Test>::subsistence_threshold() * 10);
 		let contract = Storage::<Test>::new_contract(&address, trie_id, code_hash).unwrap();
-		<ContractInfoOf<Test>>::insert(address, ContractInfo::Alive(contract));
+		<ContractInfoOf<Test>>::insert(address, contract);
 	}
 	pub fn set_balance(who: &AccountIdOf<Test>, amount: u64) {
 		let imbalance = Balances::deposit_creating(who, amount);
@@ -183,7 +182,7 @@ impl ChainExtension<Test> for TestExtension {
 			},
 			2 => {
 				let mut env = env.buf_in_buf_out();
-				let weight = env.read(2)?[1].into();
+				let weight = Weight::from_ref_time(env.read(2)?[1].into());
 				env.charge_weight(weight)?;
 				Ok(RetVal::Converging(func_id))
 			},
@@ -204,6 +203,34 @@ impl ChainExtension<Test> for TestExtension {
 	}
 }
 
+impl RegisteredChainExtension<Test> for TestExtension {
+	const ID: u16 = 0;
+}
+
+/// A chain extension returning the `func_id` it is called with.
+pub struct TempExtension;
+
+impl ChainExtension<Test> for TempExtension {
+	fn call<E>(func_id: u32, _env: Environment<E, InitState>) -> ExtensionResult<RetVal>
+	where
+		E: Ext<T = Test>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		Ok(RetVal::Diverging {
+			flags: ReturnFlags::empty(),
+			data: func_id.to_le_bytes().to_vec(),
+		})
+	}
+
+	fn enabled() -> bool {
+		TEST_EXTENSION.with(|e| e.borrow().enabled)
+	}
+}
+
+impl RegisteredChainExtension<Test> for TempExtension {
+	const ID: u16 = 1;
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
@@ -239,6 +266,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -256,16 +284,12 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 parameter_types! {
-	pub const SignedClaimHandicap: u64 = 2;
-	pub const TombstoneDeposit: u64 = 16;
 	pub const DepositPerContract: u64 = 8 * DepositPerStorageByte::get();
-	pub const DepositPerStorageByte: u64 = 10_000;
-	pub const DepositPerStorageItem: u64 = 10_000;
-	pub RentFraction: Perbill = Perbill::from_rational(4u32, 10_000u32);
-	pub const SurchargeReward: u64 = 500_000;
+	pub const DepositPerStorageByte: u64 = 1;
+	pub const DepositPerStorageItem: u64 = 2;
 	pub const MaxValueSize: u32 = 16_384;
 	pub const DeletionQueueDepth: u32 = 1024;
-	pub const DeletionWeightLimit: Weight = 500_000_000_000;
+	pub const DeletionWeightLimit: Weight = Weight::from_ref_time(500_000_000_000);
 	pub const MaxCodeSize: u32 = 2 * 1024;
 	pub MySchedule: Schedule<Test> = <Schedule<Test>>::default();
 }
@@ -276,7 +300,7 @@ parameter_types! {
 
 impl Convert<Weight, BalanceOf<Self>> for Test {
 	fn convert(w: Weight) -> BalanceOf<Self> {
-		w
+		w.ref_time()
 	}
 }
 
@@ -285,18 +309,13 @@ impl Config for Test {
 	type Randomness = Randomness;
 	type Currency = Balances;
 	type Event = Event;
-	type RentPayment = ();
-	type SignedClaimHandicap = SignedClaimHandicap;
-	type TombstoneDeposit = TombstoneDeposit;
 	type DepositPerContract = DepositPerContract;
 	type DepositPerStorageByte = DepositPerStorageByte;
 	type DepositPerStorageItem = DepositPerStorageItem;
-	type RentFraction = RentFraction;
-	type SurchargeReward = SurchargeReward;
 	type CallStack = [Frame<Self>; 31];
 	type WeightPrice = Self;
 	type WeightInfo = ();
-	type ChainExtension = TestExtension;
+	type ChainExtension = (TestExtension, TempExtension);
 	type DeletionQueueDepth = DeletionQueueDepth;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = MySchedule;
@@ -307,7 +326,7 @@ pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
 pub const CHARLIE: AccountId32 = AccountId32::new([3u8; 32]);
 pub const DJANGO: AccountId32 = AccountId32::new([4u8; 32]);
 
-const GAS_LIMIT: Weight = 10_000_000_000;
+const GAS_LIMIT: Weight = Weight::from_ref_time(10_000_000_000);
 
 pub struct ExtBuilder {
 	existential_deposit: u64,
@@ -365,7 +384,7 @@ fn calling_plain_account_fails() {
 		let base_cost = <<Test as Config>::WeightInfo as WeightInfo>::call(0);
 
 		assert_eq!(
-			Contracts::call(Origin::signed(ALICE), BOB, 0, GAS_LIMIT, Vec::new()),
+			Contracts::call(Origin::signed(ALICE), BOB, 0, GAS_LIMIT, None, Vec::new()),
 			Err(
 				DispatchErrorWithPostInfo {
 					error: Error::<Test>::ContractNotFound.into(),
@@ -391,33 +410,27 @@ fn account_removal_does_not_remove_storage() {
 
 		// Set up two accounts with free balance above the existential threshold.
 		{
-			let alice_contract_info = ContractInfo::Alive(RawAliveContractInfo {
+			let alice_contract_info = ContractInfo::<Test> {
 				trie_id: trie_id1.clone(),
 				storage_size: 0,
 				pair_count: 0,
-				deduct_block: System::block_number(),
 				code_hash: H256::repeat_byte(1),
-				rent_allowance: 40,
-				rent_paid: 0,
-				last_write: None,
+				storage_deposit: 0,
 				_reserved: None,
-			});
+			};
 			let _ = Balances::deposit_creating(&ALICE, 110);
 			ContractInfoOf::<Test>::insert(ALICE, &alice_contract_info);
 			set_storage(&ALICE, &key1, Some(b"1".to_vec()));
 			set_storage(&ALICE, &key2, Some(b"2".to_vec()));
 
-			let bob_contract_info = ContractInfo::Alive(RawAliveContractInfo {
+			let bob_contract_info = ContractInfo::<Test> {
 				trie_id: trie_id2.clone(),
 				storage_size: 0,
 				pair_count: 0,
-				deduct_block: System::block_number(),
 				code_hash: H256::repeat_byte(2),
-				rent_allowance: 40,
-				rent_paid: 0,
-				last_write: None,
+				storage_deposit: 0,
 				_reserved: None,
-			});
+			};
 			let _ = Balances::deposit_creating(&BOB, 110);
 			ContractInfoOf::<Test>::insert(BOB, &bob_contract_info);
 			set_storage(&BOB, &key1, Some(b"3".to_vec()));
@@ -472,11 +485,13 @@ fn instantiate_and_call_and_deposit_event() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
 			);
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+			let deposit = <Test as Config>::DepositPerContract::get();
 
 			assert_eq!(System::events(), vec![
 				EventRecord {
@@ -522,6 +537,25 @@ fn instantiate_and_call_and_deposit_event() {
 					),
 					topics: vec![],
 				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Balances(
+						pallet_balances::Event::Transfer(ALICE, addr.clone(), deposit)
+					),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Balances(pallet_balances::Event::Reserved(addr.clone(), deposit)),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Contracts(
+						crate::Event::StorageDepositCharged(ALICE, addr.clone(), deposit)
+					),
+					topics: vec![],
+				},
 				EventRecord {
 					phase: Phase::Initialization,
 					event: Event::Contracts(crate::Event::Instantiated(ALICE, addr.clone())),
@@ -548,6 +582,7 @@ fn deposit_event_max_value_limit() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -560,6 +595,7 @@ fn deposit_event_max_value_limit() {
 				addr.clone(),
 				0,
 				GAS_LIMIT * 2, // we are copying a huge buffer,
+				None,
 				<Test as Config>::Schedule::get().limits.payload_len.encode(),
 			));
 
@@ -570,6 +606,7 @@ fn deposit_event_max_value_limit() {
 					addr,
 					0,
 					GAS_LIMIT,
+					None,
 					(<Test as Config>::Schedule::get().limits.payload_len + 1).encode(),
 				),
 				Error::<Test>::ValueTooLarge,
@@ -592,6 +629,7 @@ fn run_out_of_gas() {
 				Origin::signed(ALICE),
 				100 * subsistence,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -605,7 +643,8 @@ fn run_out_of_gas() {
 					Origin::signed(ALICE),
 					addr, // newly created account
 					0,
-					67_500_000,
+					Weight::from_ref_time(67_500_000),
+					None,
 					vec![],
 				),
 				Error::<Test>::OutOfGas,
@@ -640,15 +679,13 @@ fn storage_size() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
-				// rent_allowance
 				<Test as pallet_balances::Config>::Balance::from(10_000u32).encode(),
 				vec![],
 			));
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
 			let bob_contract = ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_alive()
 				.unwrap();
 			assert_eq!(
 				bob_contract.storage_size,
@@ -664,11 +701,10 @@ fn storage_size() {
 				addr.clone(),
 				0,
 				GAS_LIMIT,
+				None,
 				call::set_storage_4_byte()
 			));
 			let bob_contract = ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_alive()
 				.unwrap();
 			assert_eq!(
 				bob_contract.storage_size,
@@ -684,11 +720,10 @@ fn storage_size() {
 				addr.clone(),
 				0,
 				GAS_LIMIT,
+				None,
 				call::remove_storage_4_byte()
 			));
 			let bob_contract = ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_alive()
 				.unwrap();
 			assert_eq!(
 				bob_contract.storage_size,
@@ -713,14 +748,13 @@ fn empty_kv_pairs() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
 			));
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
 			let bob_contract = ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_alive()
 				.unwrap();
 
 			assert_eq!(
@@ -744,772 +778,183 @@ fn initialize_block(number: u64) {
 }
 
 #[test]
-fn deduct_blocks() {
+fn storage_deposit_works() {
 	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
-	let endowment: BalanceOf<Test> = 100_000;
-	let allowance: BalanceOf<Test> = 70_000;
+	let byte_deposit = <Test as Config>::DepositPerStorageByte::get();
+	let item_deposit = <Test as Config>::DepositPerStorageItem::get();
 
 	ExtBuilder::default()
 		.existential_deposit(50)
 		.build()
 		.execute_with(|| {
-			// Create
+			// The constructor adds one storage item of 4 bytes.
 			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 			assert_ok!(Contracts::instantiate_with_code(
 				Origin::signed(ALICE),
-				endowment,
-				GAS_LIMIT,
-				wasm,
-				allowance.encode(),
-				vec![],
-			));
-			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
-			let code_len: BalanceOf<Test> =
-				PrefabWasmModule::<Test>::from_storage_noinstr(contract.code_hash)
-					.unwrap()
-					.occupied_storage()
-					.into();
-
-			// The instantiation deducted the rent for one block immediately
-			let rent0 = <Test as Config>::RentFraction::get()
-				// (base_deposit(8) + bytes in storage(4) + size of code) * byte_price
-				// + 1 storage item (10_000) - free_balance
-				.mul_ceil((8 + 4 + code_len) * 10_000 + 10_000 - endowment)
-				// blocks to rent
-				* 1;
-			assert!(rent0 > 0);
-			assert_eq!(contract.rent_allowance, allowance - rent0);
-			assert_eq!(contract.deduct_block, 1);
-			assert_eq!(Balances::free_balance(&addr), endowment - rent0);
-
-			// Advance 4 blocks
-			initialize_block(5);
-
-			// Trigger rent through call
-			assert_ok!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null())
-			);
-
-			// Check result
-			let rent = <Test as Config>::RentFraction::get()
-				.mul_ceil((8 + 4 + code_len) * 10_000 + 10_000 - (endowment - rent0))
-				* 4;
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
-			assert_eq!(contract.rent_allowance, allowance - rent0 - rent);
-			assert_eq!(contract.deduct_block, 5);
-			assert_eq!(Balances::free_balance(&addr), endowment - rent0 - rent);
-
-			// Advance 2 blocks more
-			initialize_block(7);
-
-			// Trigger rent through call
-			assert_ok!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null())
-			);
-
-			// Check result
-			let rent_2 = <Test as Config>::RentFraction::get()
-				.mul_ceil((8 + 4 + code_len) * 10_000 + 10_000 - (endowment - rent0 - rent))
-				* 2;
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
-			assert_eq!(contract.rent_allowance, allowance - rent0 - rent - rent_2);
-			assert_eq!(contract.deduct_block, 7);
-			assert_eq!(Balances::free_balance(&addr), endowment - rent0 - rent - rent_2);
-
-			// Second call on same block should have no effect on rent
-			assert_ok!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null())
-			);
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
-			assert_eq!(contract.rent_allowance, allowance - rent0 - rent - rent_2);
-			assert_eq!(contract.deduct_block, 7);
-			assert_eq!(Balances::free_balance(&addr), endowment - rent0 - rent - rent_2)
-		});
-}
-
-#[test]
-fn inherent_claim_surcharge_contract_removals() {
-	removals(|addr| Contracts::claim_surcharge(Origin::none(), addr, Some(ALICE)).is_ok());
-}
-
-#[test]
-fn signed_claim_surcharge_contract_removals() {
-	removals(|addr| Contracts::claim_surcharge(Origin::signed(ALICE), addr, None).is_ok());
-}
-
-#[test]
-fn claim_surcharge_malus() {
-	// Test surcharge malus for inherent
-	claim_surcharge(8, |addr| Contracts::claim_surcharge(Origin::none(), addr, Some(ALICE)).is_ok(), true);
-	claim_surcharge(7, |addr| Contracts::claim_surcharge(Origin::none(), addr, Some(ALICE)).is_ok(), true);
-	claim_surcharge(6, |addr| Contracts::claim_surcharge(Origin::none(), addr, Some(ALICE)).is_ok(), true);
-	claim_surcharge(5, |addr| Contracts::claim_surcharge(Origin::none(), addr, Some(ALICE)).is_ok(), false);
-
-	// Test surcharge malus for signed
-	claim_surcharge(8, |addr| Contracts::claim_surcharge(Origin::signed(ALICE), addr, None).is_ok(), true);
-	claim_surcharge(7, |addr| Contracts::claim_surcharge(Origin::signed(ALICE), addr, None).is_ok(), false);
-	claim_surcharge(6, |addr| Contracts::claim_surcharge(Origin::signed(ALICE), addr, None).is_ok(), false);
-	claim_surcharge(5, |addr| Contracts::claim_surcharge(Origin::signed(ALICE), addr, None).is_ok(), false);
-}
-
-/// Claim surcharge with the given trigger_call at the given blocks.
-/// If `removes` is true then assert that the contract is a tombstone.
-fn claim_surcharge(blocks: u64, trigger_call: impl Fn(AccountIdOf<Test>) -> bool, removes: bool) {
-	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
-
-	ExtBuilder::default()
-		.existential_deposit(50)
-		.build()
-		.execute_with(|| {
-			// Create
-			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-			assert_ok!(Contracts::instantiate_with_code(
-				Origin::signed(ALICE),
-				100_000,
+				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
-				<Test as pallet_balances::Config>::Balance::from(30_000u32).encode(), // rent allowance
+				<Test as pallet_balances::Config>::Balance::from(10_000u32).encode(),
 				vec![],
 			));
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+			let mut deposit = <Test as Config>::DepositPerContract::get() +
+				4 * byte_deposit + item_deposit;
+			assert_eq!(Balances::free_balance(&ALICE), 1_000_000 - 30_000 - deposit);
+			assert_eq!(Balances::free_balance(&addr), 30_000);
+			assert_eq!(Balances::reserved_balance(&addr), deposit);
+			assert_eq!(ContractInfoOf::<Test>::get(&addr).unwrap().storage_deposit, deposit);
 
-			// Advance blocks
-			initialize_block(blocks);
-
-			// Trigger rent through call
-			assert_eq!(trigger_call(addr.clone()), removes);
-
-			if removes {
-				assert!(ContractInfoOf::<Test>::get(&addr).unwrap().get_tombstone().is_some());
-			} else {
-				assert!(ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().is_some());
-			}
-		});
-}
-
-/// Test for all kind of removals for the given trigger:
-/// * if balance is reached and balance > subsistence threshold
-/// * if allowance is exceeded
-/// * if balance is reached and balance < subsistence threshold
-///	    * this case cannot be triggered by a contract: we check whether a tombstone is left
-fn removals(trigger_call: impl Fn(AccountIdOf<Test>) -> bool) {
-	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
-
-	// Balance reached and superior to subsistence threshold
-	ExtBuilder::default()
-		.existential_deposit(50)
-		.build()
-		.execute_with(|| {
-			// Create
-			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-			assert_ok!(Contracts::instantiate_with_code(
+			// Adding storage charges the caller.
+			initialize_block(2);
+			assert_ok!(Contracts::call(
 				Origin::signed(ALICE),
-				70_000,
+				addr.clone(),
+				0,
 				GAS_LIMIT,
-				wasm.clone(),
-				<Test as pallet_balances::Config>::Balance::from(100_000u32).encode(), // rent allowance
-				vec![],
+				None,
+				call::set_storage_4_byte()
 			));
-			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			let allowance = ContractInfoOf::<Test>::get(&addr)
-				.unwrap().get_alive().unwrap().rent_allowance;
-			let balance = Balances::free_balance(&addr);
-
-			let subsistence_threshold = Pallet::<Test>::subsistence_threshold();
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert_eq!(
-				ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap().rent_allowance,
-				allowance,
-			);
-			assert_eq!(Balances::free_balance(&addr), balance);
-
-			// Advance blocks
-			initialize_block(27);
-
-			// Trigger rent through call (should remove the contract)
-			assert!(trigger_call(addr.clone()));
-			assert!(ContractInfoOf::<Test>::get(&addr).unwrap().get_tombstone().is_some());
-			assert_eq!(Balances::free_balance(&addr), subsistence_threshold);
-
-			// Advance blocks
-			initialize_block(30);
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert!(ContractInfoOf::<Test>::get(&addr).unwrap().get_tombstone().is_some());
-			assert_eq!(Balances::free_balance(&addr), subsistence_threshold);
-		});
+			deposit += 4 * byte_deposit + item_deposit;
+			assert_eq!(Balances::free_balance(&ALICE), 1_000_000 - 30_000 - deposit);
+			assert_eq!(Balances::reserved_balance(&addr), deposit);
+			assert_eq!(System::events(), vec![
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Balances(pallet_balances::Event::Transfer(
+						ALICE, addr.clone(), 4 * byte_deposit + item_deposit,
+					)),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Balances(pallet_balances::Event::Reserved(
+						addr.clone(), 4 * byte_deposit + item_deposit,
+					)),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Contracts(crate::Event::StorageDepositCharged(
+						ALICE, addr.clone(), 4 * byte_deposit + item_deposit,
+					)),
+					topics: vec![],
+				},
+			]);
 
-	// Allowance exceeded
-	ExtBuilder::default()
-		.existential_deposit(50)
-		.build()
-		.execute_with(|| {
-			// Create
-			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-			assert_ok!(Contracts::instantiate_with_code(
+			// Removing storage refunds the caller.
+			assert_ok!(Contracts::call(
 				Origin::signed(ALICE),
-				100_000,
+				addr.clone(),
+				0,
 				GAS_LIMIT,
-				wasm.clone(),
-				<Test as pallet_balances::Config>::Balance::from(70_000u32).encode(), // rent allowance
-				vec![],
+				None,
+				call::remove_storage_4_byte()
 			));
-			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			let allowance = ContractInfoOf::<Test>::get(&addr)
-				.unwrap().get_alive().unwrap().rent_allowance;
-			let balance = Balances::free_balance(&addr);
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert_eq!(
-				ContractInfoOf::<Test>::get(&addr)
-					.unwrap()
-					.get_alive()
-					.unwrap()
-					.rent_allowance,
-				allowance,
-			);
-			assert_eq!(Balances::free_balance(&addr), balance);
-
-			// Advance blocks
-			initialize_block(27);
-
-			// Trigger rent through call
-			assert!(trigger_call(addr.clone()));
-			assert!(ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_tombstone()
-				.is_some());
-			// Balance should be initial balance - initial rent_allowance
-			assert_eq!(Balances::free_balance(&addr), 30_000);
-
-			// Advance blocks
-			initialize_block(20);
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert!(ContractInfoOf::<Test>::get(&addr)
-				.unwrap()
-				.get_tombstone()
-				.is_some());
-			assert_eq!(Balances::free_balance(&addr), 30_000);
-		});
+			deposit -= 4 * byte_deposit + item_deposit;
+			assert_eq!(Balances::free_balance(&ALICE), 1_000_000 - 30_000 - deposit);
+			assert_eq!(Balances::reserved_balance(&addr), deposit);
+			assert_eq!(ContractInfoOf::<Test>::get(&addr).unwrap().storage_deposit, deposit);
 
-	// Balance reached and inferior to subsistence threshold
-	ExtBuilder::default()
-		.existential_deposit(50)
-		.build()
-		.execute_with(|| {
-			// Create
-			let subsistence_threshold = Pallet::<Test>::subsistence_threshold();
-			let _ = Balances::deposit_creating(&ALICE, subsistence_threshold * 1000);
-			assert_ok!(Contracts::instantiate_with_code(
+			// Calls which do not change the storage neither charge nor refund.
+			assert_ok!(Contracts::call(
 				Origin::signed(ALICE),
-				subsistence_threshold * 100,
+				addr.clone(),
+				0,
 				GAS_LIMIT,
-				wasm,
-				(subsistence_threshold * 100).encode(), // rent allowance
-				vec![],
+				None,
+				call::null(),
 			));
-			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			let allowance = ContractInfoOf::<Test>::get(&addr)
-				.unwrap().get_alive().unwrap().rent_allowance;
-			let balance = Balances::free_balance(&addr);
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert_eq!(
-				ContractInfoOf::<Test>::get(&addr)
-					.unwrap()
-					.get_alive()
-					.unwrap()
-					.rent_allowance,
-				allowance,
-			);
-			assert_eq!(
-				Balances::free_balance(&addr),
-				balance,
-			);
-
-			// Make contract have exactly the subsistence threshold
-			Balances::make_free_balance_be(&addr, subsistence_threshold);
-			assert_eq!(Balances::free_balance(&addr), subsistence_threshold);
-
-			// Advance blocks (should remove as balance is exactly subsistence)
-			initialize_block(10);
-
-			// Trigger rent through call
-			assert!(trigger_call(addr.clone()));
-			assert_matches!(ContractInfoOf::<Test>::get(&addr), Some(ContractInfo::Tombstone(_)));
-			assert_eq!(Balances::free_balance(&addr), subsistence_threshold);
-
-			// Advance blocks
-			initialize_block(20);
-
-			// Trigger rent must have no effect
-			assert!(!trigger_call(addr.clone()));
-			assert_matches!(ContractInfoOf::<Test>::get(&addr), Some(ContractInfo::Tombstone(_)));
-			assert_eq!(Balances::free_balance(&addr), subsistence_threshold);
+			assert_eq!(Balances::free_balance(&ALICE), 1_000_000 - 30_000 - deposit);
 		});
 }
 
 #[test]
-fn call_removed_contract() {
+fn storage_deposit_limit_is_enforced() {
 	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
+	let deposit = <Test as Config>::DepositPerStorageByte::get() * 4 +
+		<Test as Config>::DepositPerStorageItem::get();
 
-	// Balance reached and superior to subsistence threshold
 	ExtBuilder::default()
 		.existential_deposit(50)
 		.build()
 		.execute_with(|| {
-			// Create
 			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 			assert_ok!(Contracts::instantiate_with_code(
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
-				// rent allowance
 				<Test as pallet_balances::Config>::Balance::from(10_000u32).encode(),
 				vec![],
 			));
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+			let balance = Balances::free_balance(&ALICE);
 
-			// Calling contract should succeed.
-			assert_ok!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null())
-			);
-
-			// Advance blocks
-			initialize_block(27);
-
-			// Calling contract should deny access because rent cannot be paid.
+			// The limit is below the deposit of the new storage item.
 			assert_err_ignore_postinfo!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null()),
-				Error::<Test>::RentNotPaid,
-			);
-			// No event is generated because the contract is not actually removed.
-			assert_eq!(System::events(), vec![]);
-
-			// Subsequent contract calls should also fail.
-			assert_err_ignore_postinfo!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null()),
-				Error::<Test>::RentNotPaid,
+				Contracts::call(
+					Origin::signed(ALICE),
+					addr.clone(),
+					0,
+					GAS_LIMIT,
+					Some(codec::Compact(deposit - 1)),
+					call::set_storage_4_byte(),
+				),
+				Error::<Test>::StorageDepositLimitExhausted,
 			);
+			assert_eq!(Balances::free_balance(&ALICE), balance);
 
-			// A snitch can now remove the contract
-			assert_ok!(Contracts::claim_surcharge(Origin::none(), addr.clone(), Some(ALICE)));
-			assert!(ContractInfoOf::<Test>::get(&addr).unwrap().get_tombstone().is_some());
-		})
-}
-
-#[test]
-fn default_rent_allowance_on_instantiate() {
-	let (wasm, code_hash) = compile_module::<Test>("check_default_rent_allowance").unwrap();
-
-	ExtBuilder::default()
-		.existential_deposit(50)
-		.build()
-		.execute_with(|| {
-			// Create
-			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-			assert_ok!(Contracts::instantiate_with_code(
+			// The limit covers exactly the deposit.
+			assert_ok!(Contracts::call(
 				Origin::signed(ALICE),
-				30_000,
+				addr.clone(),
+				0,
 				GAS_LIMIT,
-				wasm,
-				vec![],
-				vec![],
+				Some(codec::Compact(deposit)),
+				call::set_storage_4_byte(),
 			));
-			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
-			let code_len: BalanceOf<Test> =
-			PrefabWasmModule::<Test>::from_storage_noinstr(contract.code_hash)
-				.unwrap()
-				.occupied_storage()
-				.into();
-
-			// The instantiation deducted the rent for one block immediately
-			let first_rent = <Test as Config>::RentFraction::get()
-				// (base_deposit(8) + code_len) * byte_price - free_balance
-				.mul_ceil((8 + code_len) * 10_000 - 30_000)
-				// blocks to rent
-				* 1;
-			assert_eq!(contract.rent_allowance, <BalanceOf<Test>>::max_value() - first_rent);
-
-			// Advance blocks
-			initialize_block(5);
-
-			// Trigger rent through call
-			assert_ok!(
-				Contracts::call(Origin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, call::null())
-			);
-
-			// Check contract is still alive
-			let contract = ContractInfoOf::<Test>::get(&addr).unwrap().get_alive();
-			assert!(contract.is_some())
+			assert_eq!(Balances::free_balance(&ALICE), balance - deposit);
 		});
 }
 
 #[test]
-fn restorations_dirty_storage_and_different_storage() {
-	restoration(true, true, false);
-}
-
-#[test]
-fn restorations_dirty_storage() {
-	restoration(false, true, false);
-}
-
-#[test]
-fn restoration_different_storage() {
-	restoration(true, false, false);
-}
-
-#[test]
-fn restoration_code_evicted() {
-	restoration(false, false, true);
-}
-
-#[test]
-fn restoration_success() {
-	restoration(false, false, false);
-}
-
-fn restoration(
-	test_different_storage: bool,
-	test_restore_to_with_dirty_storage: bool,
-	test_code_evicted: bool
-) {
-	let (set_rent_wasm, set_rent_code_hash) = compile_module::<Test>("set_rent").unwrap();
-	let (restoration_wasm, restoration_code_hash) = compile_module::<Test>("restoration").unwrap();
-	let allowance: <Test as pallet_balances::Config>::Balance = 10_000;
+fn storage_deposit_requires_funds() {
+	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
 
 	ExtBuilder::default()
 		.existential_deposit(50)
 		.build()
 		.execute_with(|| {
 			let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-
-			// Create an account with address `BOB` with code `CODE_SET_RENT`.
-			// The input parameter sets the rent allowance to 0.
 			assert_ok!(Contracts::instantiate_with_code(
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
-				set_rent_wasm.clone(),
-				allowance.encode(),
+				None,
+				wasm,
+				<Test as pallet_balances::Config>::Balance::from(10_000u32).encode(),
 				vec![],
 			));
-			let addr_bob = Contracts::contract_address(&ALICE, &set_rent_code_hash, &[]);
-
-			let mut events = vec![
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::System(frame_system::Event::NewAccount(ALICE)),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::Balances(
-						pallet_balances::Event::Endowed(ALICE, 1_000_000)
-					),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::System(frame_system::Event::NewAccount(addr_bob.clone())),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::Balances(
-						pallet_balances::Event::Endowed(addr_bob.clone(), 30_000)
-					),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::Balances(
-						pallet_balances::Event::Transfer(ALICE, addr_bob.clone(), 30_000)
-					),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::Contracts(
-						crate::Event::CodeStored(set_rent_code_hash.into())
-					),
-					topics: vec![],
-				},
-				EventRecord {
-					phase: Phase::Initialization,
-					event: Event::Contracts(
-						crate::Event::Instantiated(ALICE, addr_bob.clone())
-					),
-					topics: vec![],
-				},
-			];
-
-			// Create another contract from the same code in order to increment the codes
-			// refcounter so that it stays on chain.
-			if !test_code_evicted {
-				assert_ok!(Contracts::instantiate_with_code(
-					Origin::signed(ALICE),
-					20_000,
-					GAS_LIMIT,
-					set_rent_wasm,
-					allowance.encode(),
-					vec![1],
-				));
-				assert_refcount!(set_rent_code_hash, 2);
-				let addr_dummy = Contracts::contract_address(&ALICE, &set_rent_code_hash, &[1]);
-				events.extend([
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::System(frame_system::Event::NewAccount(addr_dummy.clone())),
-						topics: vec![],
-					},
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::Balances(
-							pallet_balances::Event::Endowed(addr_dummy.clone(), 20_000)
-						),
-						topics: vec![],
-					},
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::Balances(
-							pallet_balances::Event::Transfer(ALICE, addr_dummy.clone(), 20_000)
-						),
-						topics: vec![],
-					},
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::Contracts(
-							crate::Event::Instantiated(ALICE, addr_dummy.clone())
-						),
-						topics: vec![],
-					},
-				].iter().cloned());
-			}
-
-			assert_eq!(System::events(), events);
-
-			// Check if `BOB` was created successfully and that the rent allowance is below what
-			// we specified as the first rent was already collected.
-			let bob_contract = ContractInfoOf::<Test>::get(&addr_bob).unwrap().get_alive().unwrap();
-			assert!(bob_contract.rent_allowance < allowance);
-
-			if test_different_storage {
-				assert_ok!(Contracts::call(
-					Origin::signed(ALICE),
-					addr_bob.clone(), 0, GAS_LIMIT,
-					call::set_storage_4_byte())
-				);
-			}
-
-			// Advance blocks in order to make the contract run out of money for rent.
-			initialize_block(27);
+			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
 
-			// Call `BOB`, which makes it pay rent. Since the rent allowance is set to 20_000
-			// we expect that it is no longer callable but keeps existing until someone
-			// calls `claim_surcharge`.
+			// BOB can pay the fees but not the storage deposit without being reaped.
+			let _ = Balances::deposit_creating(&BOB, 50);
 			assert_err_ignore_postinfo!(
 				Contracts::call(
-					Origin::signed(ALICE), addr_bob.clone(), 0, GAS_LIMIT, call::null()
-				),
-				Error::<Test>::RentNotPaid,
-			);
-			assert!(System::events().is_empty());
-			assert!(ContractInfoOf::<Test>::get(&addr_bob).unwrap().get_alive().is_some());
-			assert_ok!(Contracts::claim_surcharge(Origin::none(), addr_bob.clone(), Some(ALICE)));
-			assert!(ContractInfoOf::<Test>::get(&addr_bob).unwrap().get_tombstone().is_some());
-			if test_code_evicted {
-				assert_refcount!(set_rent_code_hash, 0);
-			} else {
-				assert_refcount!(set_rent_code_hash, 1);
-			}
-
-			// Create another account with the address `DJANGO` with `CODE_RESTORATION`.
-			//
-			// Note that we can't use `ALICE` for creating `DJANGO` so we create yet another
-			// account `CHARLIE` and create `DJANGO` with it.
-			let _ = Balances::deposit_creating(&CHARLIE, 1_000_000);
-			assert_ok!(Contracts::instantiate_with_code(
-				Origin::signed(CHARLIE),
-				30_000,
-				GAS_LIMIT,
-				restoration_wasm,
-				vec![],
-				vec![],
-			));
-			let addr_django = Contracts::contract_address(&CHARLIE, &restoration_code_hash, &[]);
-
-			// Before performing a call to `DJANGO` save its original trie id.
-			let django_trie_id = ContractInfoOf::<Test>::get(&addr_django).unwrap()
-				.get_alive().unwrap().trie_id;
-
-			// The trie is regarded as 'dirty' when it was written to in the current block.
-			if !test_restore_to_with_dirty_storage {
-				// Advance 1 block.
-				initialize_block(28);
-			}
-
-			// Perform a call to `DJANGO`. This should either perform restoration successfully or
-			// fail depending on the test parameters.
-			let perform_the_restoration = || {
-				Contracts::call(
-					Origin::signed(ALICE),
-					addr_django.clone(),
+					Origin::signed(BOB),
+					addr.clone(),
 					0,
 					GAS_LIMIT,
-					set_rent_code_hash
-						.as_ref()
-						.iter()
-						.chain(AsRef::<[u8]>::as_ref(&addr_bob))
-						.cloned()
-						.collect(),
-				)
-			};
-
-			// The key that is used in the restorer contract but is not in the target contract.
-			// Is supplied as delta to the restoration. We need it to check whether the key
-			// is properly removed on success but still there on failure.
-			let delta_key = {
-				let mut key = [0u8; 32];
-				key[0] = 1;
-				key
-			};
-
-			if test_different_storage || test_restore_to_with_dirty_storage || test_code_evicted {
-				// Parametrization of the test imply restoration failure. Check that `DJANGO` aka
-				// restoration contract is still in place and also that `BOB` doesn't exist.
-				let result = perform_the_restoration();
-				assert!(ContractInfoOf::<Test>::get(&addr_bob).unwrap().get_tombstone().is_some());
-				let django_contract = ContractInfoOf::<Test>::get(&addr_django).unwrap()
-					.get_alive().unwrap();
-				assert_eq!(django_contract.storage_size, 8);
-				assert_eq!(django_contract.trie_id, django_trie_id);
-				assert_eq!(django_contract.deduct_block, System::block_number());
-				assert_eq!(
-					Storage::<Test>::read(&django_trie_id, &delta_key),
-					Some(vec![40, 0, 0, 0]),
-				);
-				match (
-					test_different_storage,
-					test_restore_to_with_dirty_storage,
-					test_code_evicted
-				) {
-					(true, false, false) => {
-						assert_err_ignore_postinfo!(
-							result, Error::<Test>::InvalidTombstone,
-						);
-						assert_eq!(System::events(), vec![]);
-					}
-					(_, true, false) => {
-						assert_err_ignore_postinfo!(
-							result, Error::<Test>::InvalidContractOrigin,
-						);
-						assert_eq!(System::events(), vec![
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Contracts(crate::Event::Evicted(addr_bob)),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::System(frame_system::Event::NewAccount(CHARLIE)),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Balances(pallet_balances::Event::Endowed(CHARLIE, 1_000_000)),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::System(frame_system::Event::NewAccount(addr_django.clone())),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Balances(pallet_balances::Event::Endowed(addr_django.clone(), 30_000)),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Balances(
-									pallet_balances::Event::Transfer(CHARLIE, addr_django.clone(), 30_000)
-								),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Contracts(
-									crate::Event::CodeStored(restoration_code_hash)
-								),
-								topics: vec![],
-							},
-							EventRecord {
-								phase: Phase::Initialization,
-								event: Event::Contracts(
-									crate::Event::Instantiated(CHARLIE, addr_django.clone())
-								),
-								topics: vec![],
-							},
-
-						]);
-					},
-					(false, false, true) => {
-						assert_err_ignore_postinfo!(
-							result, Error::<Test>::CodeNotFound,
-						);
-						assert_refcount!(set_rent_code_hash, 0);
-						assert_eq!(System::events(), vec![]);
-					},
-					_ => unreachable!(),
-				}
-			} else {
-				assert_ok!(perform_the_restoration());
-				assert_refcount!(set_rent_code_hash, 2);
-
-				// Here we expect that the restoration is succeeded. Check that the restoration
-				// contract `DJANGO` ceased to exist and that `BOB` returned back.
-				let bob_contract = ContractInfoOf::<Test>::get(&addr_bob).unwrap()
-					.get_alive().unwrap();
-				assert_eq!(bob_contract.rent_allowance, 50);
-				assert_eq!(bob_contract.storage_size, 4);
-				assert_eq!(bob_contract.trie_id, django_trie_id);
-				assert_eq!(bob_contract.deduct_block, System::block_number());
-				assert!(ContractInfoOf::<Test>::get(&addr_django).is_none());
-				assert_matches!(Storage::<Test>::read(&django_trie_id, &delta_key), None);
-				assert_eq!(System::events(), vec![
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::Contracts(crate::Event::CodeRemoved(restoration_code_hash)),
-						topics: vec![],
-					},
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::System(system::Event::KilledAccount(addr_django.clone())),
-						topics: vec![],
-					},
-					EventRecord {
-						phase: Phase::Initialization,
-						event: Event::Contracts(
-							crate::Event::Restored(
-								addr_django, addr_bob, bob_contract.code_hash, 50
-							)
-						),
-						topics: vec![],
-					},
-				]);
-			}
+					None,
+					call::set_storage_4_byte(),
+				),
+				Error::<Test>::StorageDepositNotEnoughFunds,
+			);
+			assert_eq!(Balances::free_balance(&BOB), 50);
+			assert_eq!(ContractInfoOf::<Test>::get(&addr).unwrap().pair_count, 1);
 		});
 }
 
@@ -1527,12 +972,13 @@ fn storage_max_value_limit() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
 			));
 			let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-			ContractInfoOf::<Test>::get(&addr).unwrap().get_alive().unwrap();
+			ContractInfoOf::<Test>::get(&addr).unwrap();
 
 			// Call contract with allowed storage value.
 			assert_ok!(Contracts::call(
@@ -1540,6 +986,7 @@ fn storage_max_value_limit() {
 				addr.clone(),
 				0,
 				GAS_LIMIT * 2, // we are copying a huge buffer
+				None,
 				<Test as Config>::Schedule::get().limits.payload_len.encode(),
 			));
 
@@ -1550,6 +997,7 @@ fn storage_max_value_limit() {
 					addr,
 					0,
 					GAS_LIMIT,
+					None,
 					(<Test as Config>::Schedule::get().limits.payload_len + 1).encode(),
 				),
 				Error::<Test>::ValueTooLarge,
@@ -1572,6 +1020,7 @@ fn deploy_and_call_other_contract() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				caller_wasm,
 				vec![],
 				vec![],
@@ -1580,6 +1029,7 @@ fn deploy_and_call_other_contract() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				callee_wasm,
 				0u32.to_le_bytes().encode(),
 				vec![42],
@@ -1592,6 +1042,7 @@ fn deploy_and_call_other_contract() {
 				Contracts::contract_address(&ALICE, &caller_code_hash, &[]),
 				0,
 				GAS_LIMIT,
+				None,
 				callee_code_hash.as_ref().to_vec(),
 			));
 		});
@@ -1611,6 +1062,7 @@ fn cannot_self_destruct_through_draning() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -1620,7 +1072,7 @@ fn cannot_self_destruct_through_draning() {
 			// Check that the BOB contract has been instantiated.
 			assert_matches!(
 				ContractInfoOf::<Test>::get(&addr),
-				Some(ContractInfo::Alive(_))
+				Some(_)
 			);
 
 			// Call BOB which makes it send all funds to the zero address
@@ -1631,6 +1083,7 @@ fn cannot_self_destruct_through_draning() {
 					addr,
 					0,
 					GAS_LIMIT,
+					None,
 					vec![],
 				)
 			);
@@ -1651,6 +1104,7 @@ fn cannot_self_destruct_while_live() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -1660,7 +1114,7 @@ fn cannot_self_destruct_while_live() {
 			// Check that the BOB contract has been instantiated.
 			assert_matches!(
 				ContractInfoOf::<Test>::get(&addr),
-				Some(ContractInfo::Alive(_))
+				Some(_)
 			);
 
 			// Call BOB with input data, forcing it make a recursive call to itself to
@@ -1671,6 +1125,7 @@ fn cannot_self_destruct_while_live() {
 					addr.clone(),
 					0,
 					GAS_LIMIT,
+					None,
 					vec![0],
 				),
 				Error::<Test>::ContractTrapped,
@@ -1679,7 +1134,7 @@ fn cannot_self_destruct_while_live() {
 			// Check that BOB is still alive.
 			assert_matches!(
 				ContractInfoOf::<Test>::get(&addr),
-				Some(ContractInfo::Alive(_))
+				Some(_)
 			);
 		});
 }
@@ -1699,6 +1154,7 @@ fn self_destruct_works() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -1708,7 +1164,7 @@ fn self_destruct_works() {
 			// Check that the BOB contract has been instantiated.
 			assert_matches!(
 				ContractInfoOf::<Test>::get(&addr),
-				Some(ContractInfo::Alive(_))
+				Some(_)
 			);
 
 			// Drop all previous events
@@ -1721,12 +1177,28 @@ fn self_destruct_works() {
 					addr.clone(),
 					0,
 					GAS_LIMIT,
+					None,
 					vec![],
 				),
 				Ok(_)
 			);
 
+			let deposit = <Test as Config>::DepositPerContract::get();
 			pretty_assertions::assert_eq!(System::events(), vec![
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Balances(pallet_balances::Event::ReserveRepatriated(
+						addr.clone(), ALICE, deposit, BalanceStatus::Free,
+					)),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: Event::Contracts(
+						crate::Event::StorageDepositRefunded(addr.clone(), ALICE, deposit)
+					),
+					topics: vec![],
+				},
 				EventRecord {
 					phase: Phase::Initialization,
 					event: Event::System(
@@ -1737,7 +1209,7 @@ fn self_destruct_works() {
 				EventRecord {
 					phase: Phase::Initialization,
 					event: Event::Balances(
-						pallet_balances::Event::Transfer(addr.clone(), DJANGO, 93_086)
+						pallet_balances::Event::Transfer(addr.clone(), DJANGO, 100_000)
 					),
 					topics: vec![],
 				},
@@ -1759,8 +1231,10 @@ fn self_destruct_works() {
 			assert!(ContractInfoOf::<Test>::get(&addr).is_none());
 
 			// check that the beneficiary (django) got remaining balance
-			// some rent was deducted before termination
-			assert_eq!(Balances::free_balance(DJANGO), 1_093_086);
+			assert_eq!(Balances::free_balance(DJANGO), 1_100_000);
+
+			// check that the origin got the storage deposit back
+			assert_eq!(Balances::free_balance(ALICE), 1_000_000 - 100_000);
 		});
 }
 
@@ -1781,6 +1255,7 @@ fn destroy_contract_and_transfer_funds() {
 				Origin::signed(ALICE),
 				200_000,
 				GAS_LIMIT,
+				None,
 				callee_wasm,
 				vec![],
 				vec![42]
@@ -1792,6 +1267,7 @@ fn destroy_contract_and_transfer_funds() {
 				Origin::signed(ALICE),
 				200_000,
 				GAS_LIMIT,
+				None,
 				caller_wasm,
 				callee_code_hash.as_ref().to_vec(),
 				vec![],
@@ -1804,7 +1280,7 @@ fn destroy_contract_and_transfer_funds() {
 			// Check that the CHARLIE contract has been instantiated.
 			assert_matches!(
 				ContractInfoOf::<Test>::get(&addr_charlie),
-				Some(ContractInfo::Alive(_))
+				Some(_)
 			);
 
 			// Call BOB, which calls CHARLIE, forcing CHARLIE to self-destruct.
@@ -1813,6 +1289,7 @@ fn destroy_contract_and_transfer_funds() {
 				addr_bob,
 				0,
 				GAS_LIMIT,
+				None,
 				addr_charlie.encode(),
 			));
 
@@ -1836,6 +1313,7 @@ fn cannot_self_destruct_in_constructor() {
 					Origin::signed(ALICE),
 					100_000,
 					GAS_LIMIT,
+					None,
 					wasm,
 					vec![],
 					vec![],
@@ -1860,6 +1338,7 @@ fn crypto_hashes() {
 				Origin::signed(ALICE),
 				100_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -1891,6 +1370,7 @@ fn crypto_hashes() {
 					addr.clone(),
 					0,
 					GAS_LIMIT,
+					None,
 					params,
 					false,
 				).result.unwrap();
@@ -1913,6 +1393,7 @@ fn transfer_return_code() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -1927,6 +1408,7 @@ fn transfer_return_code() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 			false,
 		).result.unwrap();
@@ -1942,6 +1424,7 @@ fn transfer_return_code() {
 			addr,
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 			false,
 		).result.unwrap();
@@ -1963,6 +1446,7 @@ fn call_return_code() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				caller_code,
 				vec![0],
 				vec![],
@@ -1977,6 +1461,7 @@ fn call_return_code() {
 			addr_bob.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			AsRef::<[u8]>::as_ref(&DJANGO).to_vec(),
 			false,
 		).result.unwrap();
@@ -1987,6 +1472,7 @@ fn call_return_code() {
 				Origin::signed(CHARLIE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				callee_code,
 				vec![0],
 				vec![],
@@ -2001,6 +1487,7 @@ fn call_return_code() {
 			addr_bob.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			AsRef::<[u8]>::as_ref(&addr_django).iter().chain(&0u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2016,6 +1503,7 @@ fn call_return_code() {
 			addr_bob.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			AsRef::<[u8]>::as_ref(&addr_django).iter().chain(&0u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2028,6 +1516,7 @@ fn call_return_code() {
 			addr_bob.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			AsRef::<[u8]>::as_ref(&addr_django).iter().chain(&1u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2039,6 +1528,7 @@ fn call_return_code() {
 			addr_bob,
 			0,
 			GAS_LIMIT,
+			None,
 			AsRef::<[u8]>::as_ref(&addr_django).iter().chain(&2u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2062,6 +1552,7 @@ fn instantiate_return_code() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				callee_code,
 				vec![],
 				vec![],
@@ -2073,6 +1564,7 @@ fn instantiate_return_code() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				caller_code,
 				vec![],
 				vec![],
@@ -2087,6 +1579,7 @@ fn instantiate_return_code() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			callee_hash.clone(),
 			false,
 		).result.unwrap();
@@ -2102,6 +1595,7 @@ fn instantiate_return_code() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			callee_hash.clone(),
 			false,
 		).result.unwrap();
@@ -2114,6 +1608,7 @@ fn instantiate_return_code() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![0; 33],
 			false,
 		).result.unwrap();
@@ -2125,6 +1620,7 @@ fn instantiate_return_code() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			callee_hash.iter().chain(&1u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2136,6 +1632,7 @@ fn instantiate_return_code() {
 			addr,
 			0,
 			GAS_LIMIT,
+			None,
 			callee_hash.iter().chain(&2u32.to_le_bytes()).cloned().collect(),
 			false,
 		).result.unwrap();
@@ -2156,6 +1653,7 @@ fn disabled_chain_extension_wont_deploy() {
 				Origin::signed(ALICE),
 				3 * subsistence,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2176,6 +1674,7 @@ fn disabled_chain_extension_errors_on_call() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2189,6 +1688,7 @@ fn disabled_chain_extension_errors_on_call() {
 				addr.clone(),
 				0,
 				GAS_LIMIT,
+				None,
 				vec![],
 			),
 			Error::<Test>::NoChainExtension,
@@ -2207,6 +1707,7 @@ fn chain_extension_works() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2224,6 +1725,7 @@ fn chain_extension_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![0, 99],
 			false,
 		);
@@ -2237,6 +1739,7 @@ fn chain_extension_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![1],
 			false,
 		).result.unwrap();
@@ -2249,6 +1752,7 @@ fn chain_extension_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![2, 42],
 			false,
 		);
@@ -2261,6 +1765,7 @@ fn chain_extension_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![3],
 			false,
 		).result.unwrap();
@@ -2269,6 +1774,63 @@ fn chain_extension_works() {
 	});
 }
 
+#[test]
+fn chain_extension_routing_works() {
+	let (code, hash) = compile_module::<Test>("chain_extension_routing").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let subsistence = Pallet::<Test>::subsistence_threshold();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * subsistence);
+		assert_ok!(
+			Contracts::instantiate_with_code(
+				Origin::signed(ALICE),
+				subsistence * 100,
+				GAS_LIMIT,
+				None,
+				code,
+				vec![],
+				vec![],
+			),
+		);
+		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
+
+		// the chain extension of id 0 is `TestExtension`, whose func_id 0 passes the input
+		// through.
+		let input = [0u32.to_le_bytes().to_vec(), vec![7, 8]].concat();
+		let result = Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, input, false)
+			.result
+			.unwrap();
+		assert_eq!(result.data, Bytes(vec![7, 8]));
+		assert_eq!(TestExtension::last_seen_buffer(), vec![7, 8]);
+
+		// the chain extension of id 1 is `TempExtension`, which gets the whole func_id.
+		let func_id = (1u32 << 16) | 5;
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			func_id.to_le_bytes().to_vec(),
+			false,
+		).result.unwrap();
+		assert_eq!(result.flags, ReturnFlags::empty());
+		assert_eq!(result.data, Bytes(func_id.to_le_bytes().to_vec()));
+
+		// there is no chain extension of id 2.
+		assert_err_ignore_postinfo!(
+			Contracts::call(
+				Origin::signed(ALICE),
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				(2u32 << 16).to_le_bytes().to_vec(),
+			),
+			Error::<Test>::NoChainExtension,
+		);
+	});
+}
+
 #[test]
 fn lazy_removal_works() {
 	let (code, hash) = compile_module::<Test>("self_destruct").unwrap();
@@ -2281,6 +1843,7 @@ fn lazy_removal_works() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2288,7 +1851,7 @@ fn lazy_removal_works() {
 		);
 
 		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
-		let info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
+		let info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
 		let trie = &info.child_trie_info();
 
 		// Put value into the contracts child trie
@@ -2300,6 +1863,7 @@ fn lazy_removal_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 
@@ -2323,7 +1887,7 @@ fn lazy_removal_partial_remove_works() {
 
 	// We create a contract with some extra keys above the weight limit
 	let extra_keys = 7u32;
-	let weight_limit = 5_000_000_000;
+	let weight_limit = Weight::from_ref_time(5_000_000_000);
 	let (_, max_keys) = Storage::<Test>::deletion_budget(1, weight_limit);
 	let vals: Vec<_> = (0..max_keys + extra_keys).map(|i| {
 		(blake2_256(&i.encode()), (i as u32), (i as u32).encode())
@@ -2341,6 +1905,7 @@ fn lazy_removal_partial_remove_works() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2348,7 +1913,7 @@ fn lazy_removal_partial_remove_works() {
 		);
 
 		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
-		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
+		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
 
 		// Put value into the contracts child trie
 		for val in &vals {
@@ -2359,7 +1924,7 @@ fn lazy_removal_partial_remove_works() {
 				Some(val.2.clone()),
 			).unwrap();
 		}
-		<ContractInfoOf<Test>>::insert(&addr, ContractInfo::Alive(info.clone()));
+		<ContractInfoOf<Test>>::insert(&addr, info.clone());
 
 		// Terminate the contract
 		assert_ok!(Contracts::call(
@@ -2367,6 +1932,7 @@ fn lazy_removal_partial_remove_works() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 
@@ -2424,6 +1990,7 @@ fn lazy_removal_does_no_run_on_full_block() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2431,7 +1998,7 @@ fn lazy_removal_does_no_run_on_full_block() {
 		);
 
 		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
-		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
+		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
 		let max_keys = 30;
 
 		// Create some storage items for the contract.
@@ -2449,7 +2016,7 @@ fn lazy_removal_does_no_run_on_full_block() {
 				Some(val.2.clone()),
 			).unwrap();
 		}
-		<ContractInfoOf<Test>>::insert(&addr, ContractInfo::Alive(info.clone()));
+		<ContractInfoOf<Test>>::insert(&addr, info.clone());
 
 		// Terminate the contract
 		assert_ok!(Contracts::call(
@@ -2457,6 +2024,7 @@ fn lazy_removal_does_no_run_on_full_block() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 
@@ -2510,6 +2078,7 @@ fn lazy_removal_does_not_use_all_weight() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2517,8 +2086,8 @@ fn lazy_removal_does_not_use_all_weight() {
 		);
 
 		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
-		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
-		let weight_limit = 5_000_000_000;
+		let mut info = <ContractInfoOf::<Test>>::get(&addr).unwrap();
+		let weight_limit = Weight::from_ref_time(5_000_000_000);
 		let (weight_per_key, max_keys) = Storage::<Test>::deletion_budget(1, weight_limit);
 
 		// We create a contract with one less storage item than we can remove within the limit
@@ -2536,7 +2105,7 @@ fn lazy_removal_does_not_use_all_weight() {
 				Some(val.2.clone()),
 			).unwrap();
 		}
-		<ContractInfoOf<Test>>::insert(&addr, ContractInfo::Alive(info.clone()));
+		<ContractInfoOf<Test>>::insert(&addr, info.clone());
 
 		// Terminate the contract
 		assert_ok!(Contracts::call(
@@ -2544,6 +2113,7 @@ fn lazy_removal_does_not_use_all_weight() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 
@@ -2582,6 +2152,7 @@ fn deletion_queue_full() {
 				Origin::signed(ALICE),
 				subsistence * 100,
 				GAS_LIMIT,
+				None,
 				code,
 				vec![],
 				vec![],
@@ -2600,98 +2171,14 @@ fn deletion_queue_full() {
 				addr.clone(),
 				0,
 				GAS_LIMIT,
+				None,
 				vec![],
 			),
 			Error::<Test>::DeletionQueueFull,
 		);
 
 		// Contract should be alive because removal failed
-		<ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
-
-		// make the contract ripe for eviction
-		initialize_block(5);
-
-		// eviction should fail for the same reason as termination
-		assert_err!(
-			Contracts::claim_surcharge(Origin::none(), addr.clone(), Some(ALICE)),
-			Error::<Test>::DeletionQueueFull,
-		);
-
-		// Contract should be alive because removal failed
-		<ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
-	});
-}
-
-#[test]
-fn not_deployed_if_endowment_too_low_for_first_rent() {
-	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
-
-	// The instantiation deducted the rent for one block immediately
-	let first_rent = <Test as Config>::RentFraction::get()
-		// base_deposit + deploy_set_storage (4 bytes in 1 item) - free_balance
-		.mul_ceil(80_000u32 + 40_000 + 10_000 - 30_000)
-		// blocks to rent
-		* 1;
-
-	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
-		// Create
-		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-		assert_storage_noop!(assert_err_ignore_postinfo!(
-			Contracts::instantiate_with_code(
-				Origin::signed(ALICE),
-				30_000,
-				GAS_LIMIT,
-				wasm,
-				(BalanceOf::<Test>::from(first_rent) - BalanceOf::<Test>::from(1u32))
-					.encode(), // rent allowance
-				vec![],
-			),
-			Error::<Test>::NewContractNotFunded,
-		));
-		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-		assert_matches!(ContractInfoOf::<Test>::get(&addr), None);
-	});
-}
-
-#[test]
-fn surcharge_reward_is_capped() {
-	let (wasm, code_hash) = compile_module::<Test>("set_rent").unwrap();
-	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
-		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
-		assert_ok!(Contracts::instantiate_with_code(
-			Origin::signed(ALICE),
-			30_000,
-			GAS_LIMIT,
-			wasm,
-			<BalanceOf<Test>>::from(10_000u32).encode(), // rent allowance
-			vec![],
-		));
-		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-		let contract = <ContractInfoOf::<Test>>::get(&addr).unwrap().get_alive().unwrap();
-		let balance = Balances::free_balance(&ALICE);
-		let reward = <Test as Config>::SurchargeReward::get();
-
-		// some rent should have paid due to instantiation
-		assert_ne!(contract.rent_paid, 0);
-
-		// the reward should be parameterized sufficiently high to make this test useful
-		assert!(reward > contract.rent_paid);
-
-		// make contract eligible for eviction
-		initialize_block(40);
-
-		// this should have removed the contract
-		assert_ok!(Contracts::claim_surcharge(Origin::none(), addr.clone(), Some(ALICE)));
-
-		// this reward does not take into account the last rent payment collected during eviction
-		let capped_reward = reward.min(contract.rent_paid);
-
-		// this is smaller than the actual reward because it does not take into account the
-		// rent collected during eviction
-		assert!(Balances::free_balance(&ALICE) > balance + capped_reward);
-
-		// the full reward is not paid out because of the cap introduced by rent_paid
-		assert!(Balances::free_balance(&ALICE) < balance + reward);
+		<ContractInfoOf::<Test>>::get(&addr).unwrap();
 	});
 }
 
@@ -2707,6 +2194,7 @@ fn refcounter() {
 			Origin::signed(ALICE),
 			subsistence * 100,
 			GAS_LIMIT,
+			None,
 			wasm.clone(),
 			vec![],
 			vec![0],
@@ -2715,6 +2203,7 @@ fn refcounter() {
 			Origin::signed(ALICE),
 			subsistence * 100,
 			GAS_LIMIT,
+			None,
 			wasm.clone(),
 			vec![],
 			vec![1],
@@ -2726,6 +2215,7 @@ fn refcounter() {
 			Origin::signed(ALICE),
 			subsistence * 100,
 			GAS_LIMIT,
+			None,
 			code_hash,
 			vec![],
 			vec![2],
@@ -2743,22 +2233,34 @@ fn refcounter() {
 			addr0,
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 		assert_refcount!(code_hash, 2);
 
-		// make remaining contracts eligible for eviction
-		initialize_block(40);
-
-		// remove one of them
-		assert_ok!(Contracts::claim_surcharge(Origin::none(), addr1, Some(ALICE)));
+		// remove another one
+		assert_ok!(Contracts::call(
+			Origin::signed(ALICE),
+			addr1,
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+		));
 		assert_refcount!(code_hash, 1);
 
 		// Pristine code should still be there
 		crate::PristineCode::<Test>::get(code_hash).unwrap();
 
 		// remove the last contract
-		assert_ok!(Contracts::claim_surcharge(Origin::none(), addr2, Some(ALICE)));
+		assert_ok!(Contracts::call(
+			Origin::signed(ALICE),
+			addr2,
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+		));
 		assert_refcount!(code_hash, 0);
 
 		// all code should be gone
@@ -2780,6 +2282,7 @@ fn reinstrument_does_charge() {
 			Origin::signed(ALICE),
 			subsistence * 100,
 			GAS_LIMIT,
+			None,
 			wasm,
 			zero.clone(),
 			vec![],
@@ -2794,6 +2297,7 @@ fn reinstrument_does_charge() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			zero.clone(),
 			false,
 		);
@@ -2804,6 +2308,7 @@ fn reinstrument_does_charge() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			zero.clone(),
 			false,
 		);
@@ -2824,6 +2329,7 @@ fn reinstrument_does_charge() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			zero.clone(),
 			false,
 		);
@@ -2831,7 +2337,8 @@ fn reinstrument_does_charge() {
 		assert!(result2.gas_consumed > result1.gas_consumed);
 		assert_eq!(
 			result2.gas_consumed,
-			result1.gas_consumed + <Test as Config>::WeightInfo::instrument(code_len / 1024),
+			result1.gas_consumed +
+				<Test as Config>::WeightInfo::instrument(code_len / 1024).ref_time(),
 		);
 	});
 }
@@ -2848,6 +2355,7 @@ fn debug_message_works() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -2859,6 +2367,7 @@ fn debug_message_works() {
 			addr,
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 			true,
 		);
@@ -2880,6 +2389,7 @@ fn debug_message_logging_disabled() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -2892,6 +2402,7 @@ fn debug_message_logging_disabled() {
 			addr.clone(),
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 			false,
 		);
@@ -2902,6 +2413,7 @@ fn debug_message_logging_disabled() {
 			addr,
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 		));
 		assert!(result.debug_message.is_empty());
@@ -2920,6 +2432,7 @@ fn debug_message_invalid_utf8() {
 				Origin::signed(ALICE),
 				30_000,
 				GAS_LIMIT,
+				None,
 				wasm,
 				vec![],
 				vec![],
@@ -2931,6 +2444,7 @@ fn debug_message_invalid_utf8() {
 			addr,
 			0,
 			GAS_LIMIT,
+			None,
 			vec![],
 			true,
 		);
@@ -326,7 +326,7 @@ mod tests {
 	#[test]
 	fn macro_define_func() {
 		define_func!( Ext seal_gas (_ctx, amount: u32) => {
-			let amount = Weight::from(amount);
+			let amount = Weight::from_ref_time(amount.into());
 			if !amount.is_zero() {
 				Ok(())
 			} else {
@@ -378,7 +378,7 @@ mod tests {
 
 		define_env!(Env, <E: Ext>,
 			[seal0] seal_gas( _ctx, amount: u32 ) => {
-				let amount = Weight::from(amount);
+				let amount = Weight::from_ref_time(amount.into());
 				if !amount.is_zero() {
 					Ok(())
 				} else {
@@ -79,7 +79,7 @@ pub struct PrefabWasmModule<T: Config> {
 	/// The size of the uninstrumented code.
 	///
 	/// We cache this value here in order to avoid the need to pull the pristine code
-	/// from storage when we only need its length for rent calculations.
+	/// from storage when we only need its length.
 	original_code_len: u32,
 	/// The uninstrumented, pristine version of the code.
 	///
@@ -227,14 +227,6 @@ where
 	fn code_len(&self) -> u32 {
 		self.code.len() as u32
 	}
-
-	fn aggregate_code_len(&self) -> u32 {
-		self.original_code_len.saturating_add(self.code_len())
-	}
-
-	fn refcount(&self) -> u32 {
-		self.refcount as u32
-	}
 }
 
 #[cfg(test)]
@@ -244,10 +236,9 @@ mod tests {
 		CodeHash, BalanceOf, Error, Pallet as Contracts,
 		exec::{
 			Ext, StorageKey, AccountIdOf, Executable, SeedOf, BlockNumberOf,
-			RentParams, ExecError, ErrorOrigin,
+			ExecError, ErrorOrigin,
 		},
 		gas::GasMeter,
-		rent::RentStatus,
 		tests::{Test, Call, ALICE, BOB},
 	};
 	use std::collections::HashMap;
@@ -263,14 +254,6 @@ mod tests {
 	#[derive(Debug, PartialEq, Eq)]
 	struct DispatchEntry(Call);
 
-	#[derive(Debug, PartialEq, Eq)]
-	struct RestoreEntry {
-		dest: AccountIdOf<Test>,
-		code_hash: H256,
-		rent_allowance: u64,
-		delta: Vec<StorageKey>,
-	}
-
 	#[derive(Debug, PartialEq, Eq)]
 	struct InstantiateEntry {
 		code_hash: H256,
@@ -301,16 +284,13 @@ mod tests {
 
 	pub struct MockExt {
 		storage: HashMap<StorageKey, Vec<u8>>,
-		rent_allowance: u64,
 		instantiates: Vec<InstantiateEntry>,
 		terminations: Vec<TerminationEntry>,
 		calls: Vec<CallEntry>,
 		transfers: Vec<TransferEntry>,
-		restores: Vec<RestoreEntry>,
 		// (topics, data)
 		events: Vec<(Vec<H256>, Vec<u8>)>,
 		schedule: Schedule<Test>,
-		rent_params: RentParams<Test>,
 		gas_meter: GasMeter<Test>,
 		debug_buffer: Vec<u8>,
 	}
@@ -324,16 +304,13 @@ mod tests {
 		fn default() -> Self {
 			Self {
 				storage: Default::default(),
-				rent_allowance: Default::default(),
 				instantiates: Default::default(),
 				terminations: Default::default(),
 				calls: Default::default(),
 				transfers: Default::default(),
-				restores: Default::default(),
 				events: Default::default(),
 				schedule: Default::default(),
-				rent_params: Default::default(),
-				gas_meter: GasMeter::new(10_000_000_000),
+				gas_meter: GasMeter::new(Weight::from_ref_time(10_000_000_000)),
 				debug_buffer: Default::default(),
 			}
 		}
@@ -370,7 +347,7 @@ mod tests {
 				code_hash: code_hash.clone(),
 				endowment,
 				data: data.to_vec(),
-				gas_left: gas_limit,
+				gas_left: gas_limit.ref_time(),
 				salt: salt.to_vec(),
 			});
 			Ok((
@@ -402,21 +379,6 @@ mod tests {
 			});
 			Ok(0)
 		}
-		fn restore_to(
-			&mut self,
-			dest: AccountIdOf<Self::T>,
-			code_hash: H256,
-			rent_allowance: u64,
-			delta: Vec<StorageKey>,
-		) -> Result<(u32, u32), (DispatchError, u32, u32)> {
-			self.restores.push(RestoreEntry {
-				dest,
-				code_hash,
-				rent_allowance,
-				delta,
-			});
-			Ok((0, 0))
-		}
 		fn get_storage(&mut self, key: &StorageKey) -> Option<Vec<u8>> {
 			self.storage.get(key).cloned()
 		}
@@ -442,35 +404,20 @@ mod tests {
 		fn minimum_balance(&self) -> u64 {
 			666
 		}
-		fn tombstone_deposit(&self) -> u64 {
-			16
-		}
 		fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberOf<Self::T>) {
 			(H256::from_slice(subject), 42)
 		}
 		fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
 			self.events.push((topics, data))
 		}
-		fn set_rent_allowance(&mut self, rent_allowance: u64) {
-			self.rent_allowance = rent_allowance;
-		}
-		fn rent_allowance(&mut self) -> u64 {
-			self.rent_allowance
-		}
 		fn block_number(&self) -> u64 { 121 }
 		fn max_value_size(&self) -> u32 { 16_384 }
 		fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T> {
-			BalanceOf::<Self::T>::from(1312_u32).saturating_mul(weight.into())
+			BalanceOf::<Self::T>::from(1312_u32).saturating_mul(weight.ref_time().into())
 		}
 		fn schedule(&self) -> &Schedule<Self::T> {
 			&self.schedule
 		}
-		fn rent_params(&self) -> &RentParams<Self::T> {
-			&self.rent_params
-		}
-		fn rent_status(&mut self, _at_refcount: u32) -> RentStatus<Self::T> {
-			Default::default()
-		}
 		fn gas_meter(&mut self) -> &mut GasMeter<Self::T> {
 			&mut self.gas_meter
 		}
@@ -1306,7 +1253,7 @@ mod tests {
 	#[test]
 	fn gas_left() {
 		let mut ext = MockExt::default();
-		let gas_limit = ext.gas_meter.gas_left();
+		let gas_limit = ext.gas_meter.gas_left().ref_time();
 
 		let output = execute(
 			CODE_GAS_LEFT,
@@ -1314,8 +1261,8 @@ mod tests {
 			&mut ext,
 		).unwrap();
 
-		let gas_left = Weight::decode(&mut &*output.data).unwrap();
-		let actual_left = ext.gas_meter.gas_left();
+		let gas_left = u64::decode(&mut &*output.data).unwrap();
+		let actual_left = ext.gas_meter.gas_left().ref_time();
 		assert!(gas_left < gas_limit, "gas_left must be less than initial");
 		assert!(gas_left > actual_left, "gas_left must be greater than final");
 	}
@@ -1538,11 +1485,12 @@ mod tests {
 			)
 		)
 
-		;; assert that contents of the buffer is equal to the i64 value of 16.
+		;; assert that contents of the buffer is equal to the i64 value of 0,
+		;; as there are no tombstones anymore.
 		(call $assert
 			(i64.eq
 				(i64.load (i32.const 0))
-				(i64.const 16)
+				(i64.const 0)
 			)
 		)
 	)
@@ -1747,7 +1695,7 @@ mod tests {
 			vec![0x00, 0x01, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe5, 0x14, 0x00])
 		]);
 
-		assert!(mock_ext.gas_meter.gas_left() > 0);
+		assert!(!mock_ext.gas_meter.gas_left().is_zero());
 	}
 
 	const CODE_DEPOSIT_EVENT_MAX_TOPICS: &str = r#"
@@ -2017,93 +1965,6 @@ mod tests {
 		);
 	}
 
-
-
-	#[test]
-	#[cfg(feature = "unstable-interface")]
-	fn rent_params_work() {
-		const CODE_RENT_PARAMS: &str = r#"
-(module
-	(import "__unstable__" "seal_rent_params" (func $seal_rent_params (param i32 i32)))
-	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
-	(import "env" "memory" (memory 1 1))
-
-	;; [0, 4) buffer size = 128 bytes
-	(data (i32.const 0) "\80")
-
-	;; [4; inf) buffer where the result is copied
-
-	(func (export "call")
-		;; Load the rent params into memory
-		(call $seal_rent_params
-			(i32.const 4)		;; Pointer to the output buffer
-			(i32.const 0)		;; Pointer to the size of the buffer
-		)
-
-		;; Return the contents of the buffer
-		(call $seal_return
-			(i32.const 0)				;; return flags
-			(i32.const 4)				;; buffer pointer
-			(i32.load (i32.const 0))	;; buffer size
-		)
-	)
-
-	(func (export "deploy"))
-)
-"#;
-		let output = execute(
-			CODE_RENT_PARAMS,
-			vec![],
-			MockExt::default(),
-		).unwrap();
-		let rent_params = Bytes(<RentParams<Test>>::default().encode());
-		assert_eq!(output, ExecReturnValue { flags: ReturnFlags::empty(), data: rent_params });
-	}
-
-
-
-	#[test]
-	#[cfg(feature = "unstable-interface")]
-	fn rent_status_works() {
-		const CODE_RENT_STATUS: &str = r#"
-(module
-	(import "__unstable__" "seal_rent_status" (func $seal_rent_status (param i32 i32 i32)))
-	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
-	(import "env" "memory" (memory 1 1))
-
-	;; [0, 4) buffer size = 128 bytes
-	(data (i32.const 0) "\80")
-
-	;; [4; inf) buffer where the result is copied
-
-	(func (export "call")
-		;; Load the rent params into memory
-		(call $seal_rent_status
-			(i32.const 1)		;; at_refcount
-			(i32.const 4)		;; Pointer to the output buffer
-			(i32.const 0)		;; Pointer to the size of the buffer
-		)
-
-		;; Return the contents of the buffer
-		(call $seal_return
-			(i32.const 0)				;; return flags
-			(i32.const 4)				;; buffer pointer
-			(i32.load (i32.const 0))	;; buffer size
-		)
-	)
-
-	(func (export "deploy"))
-)
-"#;
-		let output = execute(
-			CODE_RENT_STATUS,
-			vec![],
-			MockExt::default(),
-		).unwrap();
-		let rent_status = Bytes(<RentStatus<Test>>::default().encode());
-		assert_eq!(output, ExecReturnValue { flags: ReturnFlags::empty(), data: rent_status });
-	}
-
 	#[test]
 	#[cfg(feature = "unstable-interface")]
 	fn debug_message_works() {
@@ -30,6 +30,7 @@ use frame_support::{dispatch::DispatchError, ensure, traits::Get, weights::Weigh
 use sp_std::prelude::*;
 use codec::{Decode, DecodeAll, Encode};
 use sp_core::{Bytes, crypto::UncheckedFrom};
+use sp_runtime::traits::{Bounded, Zero};
 use sp_io::hashing::{
 	keccak_256,
 	blake2_256,
@@ -69,8 +70,7 @@ pub enum ReturnCode {
 	NewContractNotFunded = 6,
 	/// No code could be found at the supplied code hash.
 	CodeNotFound = 7,
-	/// The contract that was called is either no contract at all (a plain account)
-	/// or is a tombstone.
+	/// The contract that was called is no contract at all (a plain account).
 	NotCallable = 8,
 	/// The call to `seal_debug_message` had no effect because debug message
 	/// recording was disabled.
@@ -124,8 +124,6 @@ pub enum TrapReason {
 	/// Signals that a trap was generated in response to a successful call to the
 	/// `seal_terminate` host function.
 	Termination,
-	/// Signals that a trap was generated because of a successful restoration.
-	Restoration,
 }
 
 impl<T: Into<DispatchError>> From<T> for TrapReason {
@@ -152,10 +150,6 @@ pub enum RuntimeCosts {
 	ValueTransferred,
 	/// Weight of calling `seal_minimum_balance`.
 	MinimumBalance,
-	/// Weight of calling `seal_tombstone_deposit`.
-	TombstoneDeposit,
-	/// Weight of calling `seal_rent_allowance`.
-	RentAllowance,
 	/// Weight of calling `seal_block_number`.
 	BlockNumber,
 	/// Weight of calling `seal_now`.
@@ -172,19 +166,12 @@ pub enum RuntimeCosts {
 	Terminate,
 	/// Weight that is added to `seal_terminate` for every byte of the terminated contract.
 	TerminateSurchargeCodeSize(u32),
-	/// Weight of calling `seal_restore_to` per number of supplied delta entries.
-	RestoreTo(u32),
-	/// Weight that is added to `seal_restore_to` for the involved code sizes.
-	RestoreToSurchargeCodeSize{caller_code: u32, tombstone_code: u32},
 	/// Weight of calling `seal_random`. It includes the weight for copying the subject.
 	Random,
 	/// Weight of calling `seal_deposit_event` with the given number of topics and event size.
 	DepositEvent{num_topic: u32, len: u32},
 	/// Weight of calling `seal_debug_message`.
-	#[cfg(feature = "unstable-interface")]
 	DebugMessage,
-	/// Weight of calling `seal_set_rent_allowance`.
-	SetRentAllowance,
 	/// Weight of calling `seal_set_storage` for the given storage item size.
 	SetStorage(u32),
 	/// Weight of calling `seal_clear_storage`.
@@ -220,7 +207,7 @@ pub enum RuntimeCosts {
 	/// Weight of calling `seal_hash_blake2_128` for the given input size.
 	HashBlake128(u32),
 	/// Weight charged by a chain extension through `seal_call_chain_extension`.
-	ChainExtension(u64),
+	ChainExtension(Weight),
 	/// Weight charged for copying data from the sandbox.
 	CopyIn(u32),
 }
@@ -233,15 +220,13 @@ impl RuntimeCosts {
 	{
 		use self::RuntimeCosts::*;
 		let weight = match *self {
-			MeteringBlock(amount) => s.gas.saturating_add(amount.into()),
+			MeteringBlock(amount) => s.gas.saturating_add(Weight::from_ref_time(amount.into())),
 			Caller => s.caller,
 			Address => s.address,
 			GasLeft => s.gas_left,
 			Balance => s.balance,
 			ValueTransferred => s.value_transferred,
 			MinimumBalance => s.minimum_balance,
-			TombstoneDeposit => s.tombstone_deposit,
-			RentAllowance => s.rent_allowance,
 			BlockNumber => s.block_number,
 			Now => s.now,
 			WeightToFee => s.weight_to_fee,
@@ -251,19 +236,11 @@ impl RuntimeCosts {
 				.saturating_add(s.return_per_byte.saturating_mul(len.into())),
 			Terminate => s.terminate,
 			TerminateSurchargeCodeSize(len) => s.terminate_per_code_byte.saturating_mul(len.into()),
-			RestoreTo(delta) => s.restore_to
-				.saturating_add(s.restore_to_per_delta.saturating_mul(delta.into())),
-			RestoreToSurchargeCodeSize{caller_code, tombstone_code} =>
-				s.restore_to_per_caller_code_byte.saturating_mul(caller_code.into()).saturating_add(
-					s.restore_to_per_tombstone_code_byte.saturating_mul(tombstone_code.into())
-				),
 			Random => s.random,
 			DepositEvent{num_topic, len} => s.deposit_event
 				.saturating_add(s.deposit_event_per_topic.saturating_mul(num_topic.into()))
 				.saturating_add(s.deposit_event_per_byte.saturating_mul(len.into())),
-			#[cfg(feature = "unstable-interface")]
 			DebugMessage => s.debug_message,
-			SetRentAllowance => s.set_rent_allowance,
 			SetStorage(len) => s.set_storage
 				.saturating_add(s.set_storage_per_byte.saturating_mul(len.into())),
 			ClearStorage => s.clear_storage,
@@ -422,12 +399,6 @@ where
 						data: Bytes(Vec::new()),
 					})
 				},
-				TrapReason::Restoration => {
-					Ok(ExecReturnValue {
-						flags: ReturnFlags::empty(),
-						data: Bytes(Vec::new()),
-					})
-				},
 				TrapReason::SupervisorError(error) => Err(error)?,
 			}
 		}
@@ -643,15 +614,13 @@ where
 		let not_funded = Error::<E::T>::NewContractNotFunded.into();
 		let no_code = Error::<E::T>::CodeNotFound.into();
 		let not_found = Error::<E::T>::ContractNotFound.into();
-		let is_tombstone = Error::<E::T>::ContractIsTombstone.into();
-		let rent_not_paid = Error::<E::T>::RentNotPaid.into();
 
 		match from {
 			x if x == below_sub => Ok(BelowSubsistenceThreshold),
 			x if x == transfer_failed => Ok(TransferFailed),
 			x if x == not_funded => Ok(NewContractNotFunded),
 			x if x == no_code => Ok(CodeNotFound),
-			x if (x == not_found || x == is_tombstone || x == rent_not_paid) => Ok(NotCallable),
+			x if x == not_found => Ok(NotCallable),
 			err => Err(err)
 		}
 	}
@@ -703,7 +672,11 @@ where
 		)?;
 		let ext = &mut self.ext;
 		let call_outcome = ext.call(
-			gas, callee, value, input_data, flags.contains(CallFlags::ALLOW_REENTRY),
+			Weight::from_ref_time(gas),
+			callee,
+			value,
+			input_data,
+			flags.contains(CallFlags::ALLOW_REENTRY),
 		);
 		let code_len = match &call_outcome {
 			Ok((_, len)) => len,
@@ -1020,7 +993,8 @@ define_env!(Env, <E: Ext>,
 			)
 		)?;
 		let ext = &mut ctx.ext;
-		let instantiate_outcome = ext.instantiate(gas, code_hash, value, input_data, &salt);
+		let instantiate_outcome =
+			ext.instantiate(Weight::from_ref_time(gas), code_hash, value, input_data, &salt);
 		let code_len = match &instantiate_outcome {
 			Ok((_, _, code_len)) => code_len,
 			Err((_, code_len)) => code_len,
@@ -1172,8 +1146,9 @@ define_env!(Env, <E: Ext>,
 	// gas can be smaller than one.
 	[seal0] seal_weight_to_fee(ctx, gas: u64, out_ptr: u32, out_len_ptr: u32) => {
 		ctx.charge_gas(RuntimeCosts::WeightToFee)?;
+		let price = ctx.ext.get_weight_price(Weight::from_ref_time(gas)).encode();
 		Ok(ctx.write_sandbox_output(
-			out_ptr, out_len_ptr, &ctx.ext.get_weight_price(gas).encode(), false, already_charged
+			out_ptr, out_len_ptr, &price, false, already_charged
 		)?)
 	},
 
@@ -1187,7 +1162,7 @@ define_env!(Env, <E: Ext>,
 	// The data is encoded as Gas.
 	[seal0] seal_gas_left(ctx, out_ptr: u32, out_len_ptr: u32) => {
 		ctx.charge_gas(RuntimeCosts::GasLeft)?;
-		let gas_left = &ctx.ext.gas_meter().gas_left().encode();
+		let gas_left = &ctx.ext.gas_meter().gas_left().ref_time().encode();
 		Ok(ctx.write_sandbox_output(
 			out_ptr, out_len_ptr, &gas_left, false, already_charged,
 		)?)
@@ -1301,120 +1276,42 @@ define_env!(Env, <E: Ext>,
 		)?)
 	},
 
-	// Stores the tombstone deposit into the supplied buffer.
+	// Was used to store the tombstone deposit into the supplied buffer.
 	//
 	// The value is stored to linear memory at the address pointed to by `out_ptr`.
 	// `out_len_ptr` must point to a u32 value that describes the available space at
 	// `out_ptr`. This call overwrites it with the size of the value. If the available
 	// space at `out_ptr` is less than the size of the value a trap is triggered.
 	//
-	// The data is encoded as T::Balance.
-	//
 	// # Note
 	//
-	// The tombstone deposit is on top of the existential deposit. So in order for
-	// a contract to leave a tombstone the balance of the contract must not go
-	// below the sum of existential deposit and the tombstone deposit. The sum
-	// is commonly referred as subsistence threshold in code.
+	// The state rent functionality was removed. This stub only exists for
+	// backwards compatibility. It always writes zero encoded as T::Balance.
 	[seal0] seal_tombstone_deposit(ctx, out_ptr: u32, out_len_ptr: u32) => {
-		ctx.charge_gas(RuntimeCosts::TombstoneDeposit)?;
-		Ok(ctx.write_sandbox_output(
-			out_ptr, out_len_ptr, &ctx.ext.tombstone_deposit().encode(), false, already_charged
-		)?)
+		ctx.charge_gas(RuntimeCosts::Balance)?;
+		let deposit = <BalanceOf<E::T>>::zero().encode();
+		Ok(ctx.write_sandbox_output(out_ptr, out_len_ptr, &deposit, false, already_charged)?)
 	},
 
-	// Try to restore the given destination contract sacrificing the caller.
-	//
-	// This function will compute a tombstone hash from the caller's storage and the given code hash
-	// and if the hash matches the hash found in the tombstone at the specified address - kill
-	// the caller contract and restore the destination contract and set the specified `rent_allowance`.
-	// All caller's funds are transferred to the destination.
-	//
-	// The tombstone hash is derived as `hash(code_hash, storage_root_hash)`. In order to match
-	// this hash to its own hash the restorer must make its storage equal to the one of the
-	// evicted destination contract. In order to allow for additional storage items in the
-	// restoring contract a delta can be specified to this function. All keys specified as
-	// delta are disregarded when calculating the storage root hash.
-	//
-	// On success, the destination contract is restored. This function is diverging and
-	// stops execution even on success.
-	//
-	// - `dest_ptr`, `dest_len` - the pointer and the length of a buffer that encodes `T::AccountId`
-	//    with the address of the to be restored contract.
-	// - `code_hash_ptr`, `code_hash_len` - the pointer and the length of a buffer that encodes
-	//    a code hash of the to be restored contract.
-	// - `rent_allowance_ptr`, `rent_allowance_len` - the pointer and the length of a buffer that
-	//    encodes the rent allowance that must be set in the case of successful restoration.
-	// - `delta_ptr` is the pointer to the start of a buffer that has `delta_count` storage keys
-	//    laid out sequentially.
+	// Was used to restore the given destination contract sacrificing the caller.
 	//
-	// # Traps
+	// # Note
 	//
-	// - There is no tombstone at the destination address.
-	// - Tombstone hashes do not match.
-	// - The calling contract is already present on the call stack.
-	// - The supplied code_hash does not exist on-chain.
+	// The state rent functionality was removed. This stub only exists for
+	// backwards compatibility.
 	[seal0] seal_restore_to(
 		ctx,
-		dest_ptr: u32,
-		dest_len: u32,
-		code_hash_ptr: u32,
-		code_hash_len: u32,
-		rent_allowance_ptr: u32,
-		rent_allowance_len: u32,
-		delta_ptr: u32,
-		delta_count: u32
+		_dest_ptr: u32,
+		_dest_len: u32,
+		_code_hash_ptr: u32,
+		_code_hash_len: u32,
+		_rent_allowance_ptr: u32,
+		_rent_allowance_len: u32,
+		_delta_ptr: u32,
+		_delta_count: u32
 	) => {
-		ctx.charge_gas(RuntimeCosts::RestoreTo(delta_count))?;
-		let dest: <<E as Ext>::T as frame_system::Config>::AccountId =
-			ctx.read_sandbox_memory_as(dest_ptr, dest_len)?;
-		let code_hash: CodeHash<<E as Ext>::T> =
-			ctx.read_sandbox_memory_as(code_hash_ptr, code_hash_len)?;
-		let rent_allowance: BalanceOf<<E as Ext>::T> =
-			ctx.read_sandbox_memory_as(rent_allowance_ptr, rent_allowance_len)?;
-		let delta = {
-			const KEY_SIZE: usize = 32;
-
-			// We can eagerly allocate because we charged for the complete delta count already
-			// We still need to make sure that the allocation isn't larger than the memory
-			// allocator can handle.
-			ensure!(
-				delta_count
-					.saturating_mul(KEY_SIZE as u32) <= ctx.ext.schedule().limits.max_memory_size(),
-				Error::<E::T>::OutOfBounds,
-			);
-			let mut delta = vec![[0; KEY_SIZE]; delta_count as usize];
-			let mut key_ptr = delta_ptr;
-
-			for i in 0..delta_count {
-				// Read the delta into the provided buffer
-				// This cannot panic because of the loop condition
-				ctx.read_sandbox_memory_into_buf(key_ptr, &mut delta[i as usize])?;
-
-				// Offset key_ptr to the next element.
-				key_ptr = key_ptr.checked_add(KEY_SIZE as u32).ok_or(Error::<E::T>::OutOfBounds)?;
-			}
-
-			delta
-		};
-
-		let max_len = <E::T as Config>::Schedule::get().limits.code_len;
-		let charged = ctx.charge_gas(RuntimeCosts::RestoreToSurchargeCodeSize {
-			caller_code: max_len,
-			tombstone_code: max_len,
-		})?;
-		let (result, caller_code, tombstone_code) = match ctx.ext.restore_to(
-			dest, code_hash, rent_allowance, delta
-		) {
-			Ok((code, tomb)) => (Ok(()), code, tomb),
-			Err((err, code, tomb)) => (Err(err), code, tomb),
-		};
-		ctx.adjust_gas(charged, RuntimeCosts::RestoreToSurchargeCodeSize {
-			caller_code,
-			tombstone_code,
-		});
-		result?;
-		Err(TrapReason::Restoration)
+		ctx.charge_gas(RuntimeCosts::DebugMessage)?;
+		Ok(())
 	},
 
 	// Deposit a contract event with the data buffer and optional list of topics. There is a limit
@@ -1482,31 +1379,26 @@ define_env!(Env, <E: Ext>,
 		Ok(())
 	},
 
-	// Set rent allowance of the contract
+	// Was used to set rent allowance of the contract.
 	//
-	// - value_ptr: a pointer to the buffer with value, how much to allow for rent
-	//   Should be decodable as a `T::Balance`. Traps otherwise.
-	// - value_len: length of the value buffer.
-	[seal0] seal_set_rent_allowance(ctx, value_ptr: u32, value_len: u32) => {
-		ctx.charge_gas(RuntimeCosts::SetRentAllowance)?;
-		let value: BalanceOf<<E as Ext>::T> =
-			ctx.read_sandbox_memory_as(value_ptr, value_len)?;
-		ctx.ext.set_rent_allowance(value);
-
+	// # Note
+	//
+	// The state rent functionality was removed. This stub only exists for
+	// backwards compatibility.
+	[seal0] seal_set_rent_allowance(ctx, _value_ptr: u32, _value_len: u32) => {
+		ctx.charge_gas(RuntimeCosts::DebugMessage)?;
 		Ok(())
 	},
 
-	// Stores the rent allowance into the supplied buffer.
+	// Was used to store the rent allowance into the supplied buffer.
 	//
-	// The value is stored to linear memory at the address pointed to by `out_ptr`.
-	// `out_len_ptr` must point to a u32 value that describes the available space at
-	// `out_ptr`. This call overwrites it with the size of the value. If the available
-	// space at `out_ptr` is less than the size of the value a trap is triggered.
+	// # Note
 	//
-	// The data is encoded as T::Balance.
+	// The state rent functionality was removed. This stub only exists for
+	// backwards compatibility. It always writes the maximum value encoded as T::Balance.
 	[seal0] seal_rent_allowance(ctx, out_ptr: u32, out_len_ptr: u32) => {
-		ctx.charge_gas(RuntimeCosts::RentAllowance)?;
-		let rent_allowance = ctx.ext.rent_allowance().encode();
+		ctx.charge_gas(RuntimeCosts::Balance)?;
+		let rent_allowance = <BalanceOf<E::T>>::max_value().encode();
 		Ok(ctx.write_sandbox_output(
 			out_ptr, out_len_ptr, &rent_allowance, false, already_charged
 		)?)
@@ -1686,53 +1578,4 @@ define_env!(Env, <E: Ext>,
 		}
 		Ok(ReturnCode::LoggingDisabled)
 	},
-
-	// Stores the rent params into the supplied buffer.
-	//
-	// The value is stored to linear memory at the address pointed to by `out_ptr`.
-	// `out_len_ptr` must point to a u32 value that describes the available space at
-	// `out_ptr`. This call overwrites it with the size of the value. If the available
-	// space at `out_ptr` is less than the size of the value a trap is triggered.
-	//
-	// The data is encoded as [`crate::exec::RentParams`].
-	//
-	// # Note
-	//
-	// The returned information was collected and cached when the current contract call
-	// started execution. Any change to those values that happens due to actions of the
-	// current call or contracts that are called by this contract are not considered.
-	//
-	// # Unstable
-	//
-	// This function is unstable and subject to change (or removal) in the future. Do not
-	// deploy a contract using it to a production chain.
-	[__unstable__] seal_rent_params(ctx, out_ptr: u32, out_len_ptr: u32) => {
-		Ok(ctx.write_sandbox_output(
-			out_ptr, out_len_ptr, &ctx.ext.rent_params().encode(), false, already_charged
-		)?)
-	},
-
-	// Stores the rent status into the supplied buffer.
-	//
-	// The value is stored to linear memory at the address pointed to by `out_ptr`.
-	// `out_len_ptr` must point to a u32 value that describes the available space at
-	// `out_ptr`. This call overwrites it with the size of the value. If the available
-	// space at `out_ptr` is less than the size of the value a trap is triggered.
-	//
-	// The data is encoded as [`crate::rent::RentStatus`].
-	//
-	// # Parameters
-	//
-	// - `at_refcount`: The refcount assumed for the returned `custom_refcount_*` fields
-	//
-	// # Unstable
-	//
-	// This function is unstable and subject to change (or removal) in the future. Do not
-	// deploy a contract using it to a production chain.
-	[__unstable__] seal_rent_status(ctx, at_refcount: u32, out_ptr: u32, out_len_ptr: u32) => {
-		let rent_status = ctx.ext.rent_status(at_refcount).encode();
-		Ok(ctx.write_sandbox_output(
-			out_ptr, out_len_ptr, &rent_status, false, already_charged
-		)?)
-	},
 );
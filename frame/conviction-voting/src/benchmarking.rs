@@ -0,0 +1,179 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conviction voting pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelist_account};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+use crate::Pallet as ConvictionVoting;
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	caller
+}
+
+fn account_vote<T: Config>(aye: bool) -> AccountVote<BalanceOf<T>> {
+	let vote = Vote { aye, conviction: Conviction::Locked1x };
+	AccountVote::Standard { vote, balance: 100u32.into() }
+}
+
+/// The first class of polls, and `n` ongoing polls of it.
+fn create_polls<T: Config>(n: u32) -> (ClassOf<T>, Vec<PollIndexOf<T>>) {
+	let class = T::Polls::classes().into_iter().next().expect("there is a class of polls");
+	let polls = (0..n)
+		.map(|_| T::Polls::create_ongoing(class.clone()).expect("the poll is created"))
+		.collect();
+	(class, polls)
+}
+
+/// Vote aye with `who` on each of the `polls`.
+fn vote_on<T: Config>(who: &T::AccountId, polls: &[PollIndexOf<T>]) {
+	for poll in polls {
+		let origin = RawOrigin::Signed(who.clone()).into();
+		assert!(ConvictionVoting::<T>::vote(origin, *poll, account_vote::<T>(true)).is_ok());
+	}
+}
+
+fn votes_of<T: Config>(who: &T::AccountId, class: &ClassOf<T>) -> usize {
+	match VotingFor::<T>::get(who, class) {
+		Voting::Casting(Casting { votes, .. }) => votes.len(),
+		Voting::Delegating(_) => 0,
+	}
+}
+
+benchmarks! {
+	vote_new {
+		let r in 0 .. T::MaxVotes::get() - 1;
+
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let (class, polls) = create_polls::<T>(r + 1);
+		vote_on::<T>(&caller, &polls[..r as usize]);
+		let poll = polls[r as usize];
+	}: vote(RawOrigin::Signed(caller.clone()), poll, account_vote::<T>(true))
+	verify {
+		assert_eq!(votes_of::<T>(&caller, &class), (r + 1) as usize);
+	}
+
+	vote_existing {
+		let r in 1 .. T::MaxVotes::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&caller, &polls);
+		let poll = polls[(r - 1) as usize];
+	}: vote(RawOrigin::Signed(caller.clone()), poll, account_vote::<T>(false))
+	verify {
+		assert_eq!(votes_of::<T>(&caller, &class), r as usize);
+	}
+
+	delegate {
+		let r in 0 .. T::MaxVotes::get();
+
+		let target = funded_account::<T>("target", 0);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&target, &polls);
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		class.clone(),
+		target,
+		Conviction::Locked1x,
+		100u32.into()
+	)
+	verify {
+		assert!(matches!(VotingFor::<T>::get(&caller, &class), Voting::Delegating(_)));
+	}
+
+	undelegate {
+		let r in 0 .. T::MaxVotes::get();
+
+		let target = funded_account::<T>("target", 0);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&target, &polls);
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		assert!(ConvictionVoting::<T>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			target,
+			Conviction::Locked1x,
+			100u32.into(),
+		).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), class.clone())
+	verify {
+		assert!(matches!(VotingFor::<T>::get(&caller, &class), Voting::Casting(_)));
+	}
+
+	unlock {
+		let r in 0 .. T::MaxVotes::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&caller, &polls);
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), caller.clone())
+	verify {
+		assert_eq!(ClassLocksFor::<T>::get(&caller).len(), if r > 0 { 1 } else { 0 });
+	}
+
+	remove_vote {
+		let r in 1 .. T::MaxVotes::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&caller, &polls);
+		let poll = polls[(r - 1) as usize];
+	}: _(RawOrigin::Signed(caller.clone()), Some(class.clone()), poll)
+	verify {
+		assert_eq!(votes_of::<T>(&caller, &class), (r - 1) as usize);
+	}
+
+	// The removed vote is on the losing side of a completed poll.
+	remove_other_vote {
+		let r in 1 .. T::MaxVotes::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let voter = funded_account::<T>("voter", 0);
+		let (class, polls) = create_polls::<T>(r);
+		vote_on::<T>(&voter, &polls);
+		let poll = polls[(r - 1) as usize];
+		assert!(T::Polls::end_ongoing(poll, false).is_ok());
+	}: _(RawOrigin::Signed(caller), voter.clone(), class.clone(), poll)
+	verify {
+		assert_eq!(votes_of::<T>(&voter, &class), (r - 1) as usize);
+	}
+}
+
+impl_benchmark_test_suite!(
+	ConvictionVoting,
+	crate::tests::new_test_ext(),
+	crate::tests::Test,
+);
@@ -0,0 +1,453 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's tests.
+
+use crate as pallet_conviction_voting;
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{GenesisBuild, PollStatus, VoteTally},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	testing::Header,
+	Perbill,
+};
+use sp_std::convert::TryFrom;
+use std::{cell::RefCell, collections::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Voting: pallet_conviction_voting::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+	pub const MaxLocks: u32 = 10;
+}
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
+	type MaxLocks = MaxLocks;
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+/// The state of a poll of the tests.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+	Ongoing(TallyOf<Test>, u8),
+	Completed(u64, bool),
+}
+use TestPollState::*;
+
+thread_local! {
+	/// The polls of the tests: `1`, `3`, `4` and `5` of class `0`, and `2` of class `1`.
+	pub static POLLS: RefCell<BTreeMap<u8, TestPollState>> = RefCell::new(vec![
+		(1, Ongoing(tally(0, 0, 0), 0)),
+		(2, Ongoing(tally(0, 0, 0), 1)),
+		(3, Ongoing(tally(0, 0, 0), 0)),
+		(4, Ongoing(tally(0, 0, 0), 0)),
+		(5, Ongoing(tally(0, 0, 0), 0)),
+	].into_iter().collect());
+}
+
+pub struct TestPolls;
+impl TestPolls {
+	fn set(index: u8, state: TestPollState) {
+		POLLS.with(|p| p.borrow_mut().insert(index, state));
+	}
+
+	fn tally(index: u8) -> TallyOf<Test> {
+		match POLLS.with(|p| p.borrow().get(&index).cloned()) {
+			Some(Ongoing(tally, _)) => tally,
+			_ => panic!("the poll is ongoing"),
+		}
+	}
+}
+
+impl Polling<TallyOf<Test>> for TestPolls {
+	type Index = u8;
+	type Votes = u64;
+	type Class = u8;
+	type Moment = u64;
+
+	fn classes() -> Vec<u8> {
+		vec![0, 1]
+	}
+
+	fn as_ongoing(index: u8) -> Option<(TallyOf<Test>, u8)> {
+		POLLS.with(|p| match p.borrow().get(&index) {
+			Some(Ongoing(tally, class)) => Some((tally.clone(), *class)),
+			_ => None,
+		})
+	}
+
+	fn access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, u64, u8>) -> R,
+	) -> R {
+		let mut polls = POLLS.with(|p| p.borrow().clone());
+		let result = match polls.get_mut(&index) {
+			Some(Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+			Some(Completed(end, approved)) => f(PollStatus::Completed(*end, *approved)),
+			None => f(PollStatus::None),
+		};
+		POLLS.with(|p| *p.borrow_mut() = polls);
+		result
+	}
+
+	fn try_access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, u64, u8>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		let mut polls = POLLS.with(|p| p.borrow().clone());
+		let result = match polls.get_mut(&index) {
+			Some(Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+			Some(Completed(end, approved)) => f(PollStatus::Completed(*end, *approved)),
+			None => f(PollStatus::None),
+		}?;
+		POLLS.with(|p| *p.borrow_mut() = polls);
+		Ok(result)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: u8) -> Result<u8, ()> {
+		let index = POLLS.with(|p| p.borrow().keys().rev().next().map_or(0, |x| x + 1));
+		Self::set(index, Ongoing(tally(0, 0, 0), class));
+		Ok(index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: u8, approved: bool) -> Result<(), ()> {
+		Self::set(index, Completed(frame_system::Pallet::<Test>::block_number(), approved));
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const MaxTurnout: u64 = 100;
+	pub const MaxVotes: u32 = 3;
+	pub const VoteLockingPeriod: u64 = 10;
+}
+impl Config for Test {
+	type Event = Event;
+	type WeightInfo = ();
+	type Currency = pallet_balances::Pallet<Self>;
+	type Polls = TestPolls;
+	type MaxTurnout = MaxTurnout;
+	type MaxVotes = MaxVotes;
+	type VoteLockingPeriod = VoteLockingPeriod;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)],
+	}.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn aye(amount: u64, conviction: u8) -> AccountVote<u64> {
+	let vote = Vote { aye: true, conviction: Conviction::try_from(conviction).unwrap() };
+	AccountVote::Standard { vote, balance: amount }
+}
+
+fn nay(amount: u64, conviction: u8) -> AccountVote<u64> {
+	let vote = Vote { aye: false, conviction: Conviction::try_from(conviction).unwrap() };
+	AccountVote::Standard { vote, balance: amount }
+}
+
+fn tally(ayes: u64, nays: u64, support: u64) -> TallyOf<Test> {
+	Tally::from_parts(ayes, nays, support)
+}
+
+/// The balance locked by the pallet for `who`.
+fn locked(who: u64) -> u64 {
+	Balances::locks(who).iter()
+		.filter(|lock| lock.id == CONVICTION_VOTING_ID)
+		.map(|lock| lock.amount)
+		.sum()
+}
+
+fn run_to(n: u64) {
+	System::set_block_number(n);
+}
+
+#[test]
+fn params_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(42), 0);
+		assert_eq!(Balances::total_issuance(), 210);
+	});
+}
+
+#[test]
+fn basic_voting_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(2, 5)));
+		assert_eq!(TestPolls::tally(1), tally(10, 0, 2));
+		assert_ok!(Voting::vote(Origin::signed(1), 1, nay(2, 5)));
+		assert_eq!(TestPolls::tally(1), tally(0, 10, 0));
+		assert_eq!(locked(1), 2);
+
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(5, 1)));
+		assert_eq!(TestPolls::tally(1), tally(5, 0, 5));
+		assert_ok!(Voting::vote(Origin::signed(1), 1, nay(10, 0)));
+		assert_eq!(TestPolls::tally(1), tally(0, 1, 0));
+		assert_eq!(locked(1), 10);
+
+		assert_ok!(Voting::remove_vote(Origin::signed(1), None, 1));
+		assert_eq!(TestPolls::tally(1), tally(0, 0, 0));
+		assert_ok!(Voting::unlock(Origin::signed(1), 0, 1));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn split_voting_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(Origin::signed(1), 1, AccountVote::Split { aye: 10, nay: 0 }));
+		assert_eq!(TestPolls::tally(1), tally(1, 0, 10));
+		assert_ok!(Voting::vote(Origin::signed(1), 1, AccountVote::Split { aye: 5, nay: 5 }));
+		assert_eq!(TestPolls::tally(1), tally(0, 0, 5));
+		assert_eq!(locked(1), 10);
+
+		assert_ok!(Voting::remove_vote(Origin::signed(1), None, 1));
+		assert_eq!(TestPolls::tally(1), tally(0, 0, 0));
+		assert_ok!(Voting::unlock(Origin::signed(1), 0, 1));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn tally_exposes_approval_and_support() {
+	let tally = tally(30, 10, 25);
+	assert_eq!(VoteTally::<u64, u8>::approval(&tally, 0), Perbill::from_percent(75));
+	assert_eq!(VoteTally::<u64, u8>::support(&tally, 0), Perbill::from_percent(25));
+	assert_eq!(VoteTally::<u64, u8>::ayes(&tally, 0), 30);
+}
+
+#[test]
+fn voting_errors_work() {
+	new_test_ext().execute_with(|| {
+		TestPolls::set(3, Completed(1, true));
+		assert_noop!(Voting::vote(Origin::signed(1), 3, aye(10, 0)), Error::<Test>::NotOngoing);
+		assert_noop!(Voting::vote(Origin::signed(1), 9, aye(10, 0)), Error::<Test>::NotOngoing);
+		assert_noop!(
+			Voting::vote(Origin::signed(1), 1, aye(11, 0)),
+			Error::<Test>::InsufficientFunds,
+		);
+		assert_noop!(Voting::remove_vote(Origin::signed(1), None, 1), Error::<Test>::NotVoter);
+		assert_noop!(Voting::remove_vote(Origin::signed(1), None, 3), Error::<Test>::ClassNeeded);
+	});
+}
+
+#[test]
+fn max_votes_are_enforced_per_class() {
+	new_test_ext().execute_with(|| {
+		for poll in [1, 3, 4].iter() {
+			assert_ok!(Voting::vote(Origin::signed(1), *poll, aye(10, 0)));
+		}
+		assert_noop!(
+			Voting::vote(Origin::signed(1), 5, aye(10, 0)),
+			Error::<Test>::MaxVotesReached,
+		);
+		// changing a vote, or voting within another class, is still possible.
+		assert_ok!(Voting::vote(Origin::signed(1), 4, nay(10, 0)));
+		assert_ok!(Voting::vote(Origin::signed(1), 2, aye(10, 0)));
+	});
+}
+
+#[test]
+fn locks_of_classes_overlay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(Origin::signed(2), 1, aye(5, 1)));
+		assert_ok!(Voting::vote(Origin::signed(2), 2, aye(10, 1)));
+		assert_eq!(ClassLocksFor::<Test>::get(2), vec![(0, 5), (1, 10)]);
+		assert_eq!(locked(2), 10);
+
+		assert_ok!(Voting::remove_vote(Origin::signed(2), Some(1), 2));
+		assert_ok!(Voting::unlock(Origin::signed(1), 1, 2));
+		assert_eq!(ClassLocksFor::<Test>::get(2), vec![(0, 5)]);
+		assert_eq!(locked(2), 5);
+	});
+}
+
+#[test]
+fn winning_votes_stay_locked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(10, 1)));
+		assert_ok!(Voting::vote(Origin::signed(2), 1, nay(20, 1)));
+		TestPolls::set(1, Completed(3, true));
+
+		// the losing vote can be removed by anyone.
+		assert_ok!(Voting::remove_other_vote(Origin::signed(3), 2, 0, 1));
+		assert_ok!(Voting::unlock(Origin::signed(3), 0, 2));
+		assert_eq!(locked(2), 0);
+
+		// the winning vote stays locked for a vote locking period after the end of the poll.
+		assert_noop!(
+			Voting::remove_other_vote(Origin::signed(3), 1, 0, 1),
+			Error::<Test>::NoPermissionYet,
+		);
+		assert_ok!(Voting::remove_vote(Origin::signed(1), Some(0), 1));
+		run_to(12);
+		assert_ok!(Voting::unlock(Origin::signed(1), 0, 1));
+		assert_eq!(locked(1), 10);
+		run_to(13);
+		assert_ok!(Voting::unlock(Origin::signed(1), 0, 1));
+		assert_eq!(locked(1), 0);
+	});
+}
+
+#[test]
+fn removing_others_votes_on_ongoing_polls_is_forbidden() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(10, 1)));
+		assert_noop!(
+			Voting::remove_other_vote(Origin::signed(2), 1, 0, 1),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Voting::remove_other_vote(Origin::signed(1), 1, 0, 1));
+		assert_eq!(TestPolls::tally(1), tally(0, 0, 0));
+	});
+}
+
+#[test]
+fn delegation_works() {
+	new_test_ext().execute_with(|| {
+		// delegating before the delegate votes.
+		assert_ok!(Voting::delegate(Origin::signed(2), 0, 1, Conviction::Locked1x, 20));
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(10, 1)));
+		assert_eq!(TestPolls::tally(1), tally(30, 0, 30));
+		assert_eq!(locked(2), 20);
+
+		// delegating after the delegate voted.
+		assert_ok!(Voting::delegate(Origin::signed(3), 0, 1, Conviction::Locked2x, 30));
+		assert_eq!(TestPolls::tally(1), tally(90, 0, 60));
+
+		// the delegations only count within their class.
+		assert_ok!(Voting::vote(Origin::signed(1), 2, aye(10, 1)));
+		assert_eq!(TestPolls::tally(2), tally(10, 0, 10));
+
+		// changing the vote moves the delegations along.
+		assert_ok!(Voting::vote(Origin::signed(1), 1, nay(10, 1)));
+		assert_eq!(TestPolls::tally(1), tally(0, 90, 0));
+
+		assert_ok!(Voting::undelegate(Origin::signed(3), 0));
+		assert_eq!(TestPolls::tally(1), tally(0, 30, 0));
+		assert_eq!(
+			System::events().into_iter().map(|r| r.event).filter_map(|e| match e {
+				Event::Voting(inner) => Some(inner),
+				_ => None,
+			}).collect::<Vec<_>>(),
+			vec![
+				crate::Event::Delegated(2, 1),
+				crate::Event::Delegated(3, 1),
+				crate::Event::Undelegated(3),
+			],
+		);
+
+		// the undelegated balance stays locked for the lock periods of its conviction.
+		run_to(20);
+		assert_ok!(Voting::unlock(Origin::signed(3), 0, 3));
+		assert_eq!(locked(3), 30);
+		run_to(21);
+		assert_ok!(Voting::unlock(Origin::signed(3), 0, 3));
+		assert_eq!(locked(3), 0);
+	});
+}
+
+#[test]
+fn delegation_errors_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::delegate(Origin::signed(1), 0, 1, Conviction::None, 10),
+			Error::<Test>::Nonsense,
+		);
+		assert_noop!(
+			Voting::delegate(Origin::signed(1), 9, 2, Conviction::None, 10),
+			Error::<Test>::BadClass,
+		);
+		assert_noop!(
+			Voting::delegate(Origin::signed(1), 0, 2, Conviction::None, 11),
+			Error::<Test>::InsufficientFunds,
+		);
+		assert_noop!(Voting::undelegate(Origin::signed(1), 0), Error::<Test>::NotDelegating);
+
+		assert_ok!(Voting::vote(Origin::signed(1), 1, aye(10, 0)));
+		assert_noop!(
+			Voting::delegate(Origin::signed(1), 0, 2, Conviction::None, 10),
+			Error::<Test>::AlreadyVoting,
+		);
+
+		assert_ok!(Voting::delegate(Origin::signed(2), 0, 1, Conviction::None, 10));
+		assert_noop!(
+			Voting::delegate(Origin::signed(2), 0, 3, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating,
+		);
+		assert_noop!(
+			Voting::vote(Origin::signed(2), 1, aye(10, 0)),
+			Error::<Test>::AlreadyDelegating,
+		);
+	});
+}
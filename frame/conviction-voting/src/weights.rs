@@ -0,0 +1,131 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_conviction_voting
+//!
+//! These weights are estimated from the storage accesses of the calls and are meant to be
+//! replaced by the results of benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_conviction_voting.
+pub trait WeightInfo {
+	fn vote_new(r: u32, ) -> Weight;
+	fn vote_existing(r: u32, ) -> Weight;
+	fn delegate(r: u32, ) -> Weight;
+	fn undelegate(r: u32, ) -> Weight;
+	fn unlock(r: u32, ) -> Weight;
+	fn remove_vote(r: u32, ) -> Weight;
+	fn remove_other_vote(r: u32, ) -> Weight;
+}
+
+/// Weights for pallet_conviction_voting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn vote_new(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn vote_existing(r: u32, ) -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn delegate(r: u32, ) -> Weight {
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn undelegate(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn unlock(r: u32, ) -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(200_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn remove_vote(r: u32, ) -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(300_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn remove_other_vote(r: u32, ) -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(300_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn vote_new(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn vote_existing(r: u32, ) -> Weight {
+		Weight::from_ref_time(45_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn delegate(r: u32, ) -> Weight {
+		Weight::from_ref_time(50_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn undelegate(r: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(5_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn unlock(r: u32, ) -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(200_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn remove_vote(r: u32, ) -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(300_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn remove_other_vote(r: u32, ) -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(300_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
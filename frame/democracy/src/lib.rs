@@ -237,7 +237,7 @@ enum Releases {
 pub mod pallet {
 	use sp_runtime::DispatchResult;
 	use frame_support::{
-		pallet_prelude::*, Parameter,
+		pallet_prelude::*, Parameter, transactional,
 		weights::{DispatchClass, Pays}, traits::EnsureOrigin, dispatch::DispatchResultWithPostInfo,
 	};
 	use frame_system::{pallet_prelude::*, ensure_signed, ensure_root};
@@ -615,7 +615,7 @@ pub mod pallet {
 		fn on_initialize(n: T::BlockNumber) -> Weight {
 			Self::begin_block(n).unwrap_or_else(|e| {
 				sp_runtime::print(e);
-				0
+				Weight::zero()
 			})
 		}
 	}
@@ -706,6 +706,7 @@ pub mod pallet {
 			T::WeightInfo::vote_new(T::MaxVotes::get())
 				.max(T::WeightInfo::vote_existing(T::MaxVotes::get()))
 		)]
+		#[transactional]
 		pub(crate) fn vote(
 			origin: OriginFor<T>,
 			#[pallet::compact] ref_index: ReferendumIndex,
@@ -943,6 +944,7 @@ pub mod pallet {
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::weight(T::WeightInfo::delegate(T::MaxVotes::get()))]
+		#[transactional]
 		pub fn delegate(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -1381,12 +1383,12 @@ impl<T: Config> Pallet<T> {
 		})?;
 		// Extend the lock to `balance` (rather than setting it) since we don't know what other
 		// votes are in place.
-		T::Currency::extend_lock(
+		T::Currency::try_extend_lock(
 			DEMOCRACY_ID,
 			who,
 			vote.balance(),
 			WithdrawReasons::TRANSFER
-		);
+		)?;
 		ReferendumInfoOf::<T>::insert(ref_index, ReferendumInfo::Ongoing(status));
 		Ok(())
 	}
@@ -1513,12 +1515,12 @@ impl<T: Config> Pallet<T> {
 			let votes = Self::increase_upstream_delegation(&target, conviction.votes(balance));
 			// Extend the lock to `balance` (rather than setting it) since we don't know what other
 			// votes are in place.
-			T::Currency::extend_lock(
+			T::Currency::try_extend_lock(
 				DEMOCRACY_ID,
 				&who,
 				balance,
 				WithdrawReasons::TRANSFER
-			);
+			)?;
 			Ok(votes)
 		})?;
 		Self::deposit_event(Event::<T>::Delegated(who, target));
@@ -1718,7 +1720,7 @@ impl<T: Config> Pallet<T> {
 	/// # </weight>
 	fn begin_block(now: T::BlockNumber) -> Result<Weight, DispatchError> {
 		let max_block_weight = T::BlockWeights::get().max_block;
-		let mut weight = 0;
+		let mut weight = Weight::zero();
 
 		// pick out another public referendum if it's time.
 		if (now % T::LaunchPeriod::get()).is_zero() {
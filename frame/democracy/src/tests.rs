@@ -78,7 +78,9 @@ impl Filter<Call> for BaseFilter {
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1_000_000);
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_ref_time(1_000_000),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = BaseFilter;
@@ -117,6 +119,9 @@ impl pallet_scheduler::Config for Test {
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ();
 	type WeightInfo = ();
+	type Preimages = ();
+	type NoPreimagePostponement = ();
+	type MaxPostponements = ();
 }
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
@@ -125,6 +130,7 @@ parameter_types! {
 impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type MaxLocks = MaxLocks;
 	type Balance = u64;
 	type Event = Event;
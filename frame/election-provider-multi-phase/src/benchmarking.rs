@@ -140,7 +140,7 @@ fn solution_with_size<T: Config>(
 	let score = compact.clone().score(&winners, stake_of, voter_at, target_at).unwrap();
 	let round = <MultiPhase<T>>::round();
 
-	assert!(score[0] > 0, "score is zero, this probably means that the stakes are not set.");
+	assert!(score.minimal_stake > 0, "score is zero, this probably means that the stakes are not set.");
 	RawSolution { compact, score, round }
 }
 
@@ -188,6 +188,39 @@ frame_benchmarking::benchmarks! {
 		assert!(<MultiPhase<T>>::current_phase().is_unsigned());
 	}
 
+	finalize_signed_phase_accept_solution {
+		let receiver = frame_benchmarking::account("receiver", 0, SEED);
+		let initial_balance = T::Currency::minimum_balance().max(One::one()) * 10u32.into();
+		T::Currency::make_free_balance_be(&receiver, initial_balance);
+		let ready: ReadySolution<T::AccountId> = Default::default();
+		let deposit: BalanceOf<T> = 10u32.into();
+		let reward: BalanceOf<T> = 20u32.into();
+
+		assert_ok!(T::Currency::reserve(&receiver, deposit));
+		assert_eq!(T::Currency::free_balance(&receiver), initial_balance - 10u32.into());
+	}: {
+		<MultiPhase<T>>::finalize_signed_phase_accept_solution(ready, &receiver, deposit, reward)
+	} verify {
+		assert_eq!(T::Currency::free_balance(&receiver), initial_balance + 20u32.into());
+		assert_eq!(T::Currency::reserved_balance(&receiver), 0u32.into());
+	}
+
+	finalize_signed_phase_reject_solution {
+		let receiver = frame_benchmarking::account("receiver", 0, SEED);
+		let initial_balance = T::Currency::minimum_balance().max(One::one()) * 10u32.into();
+		let deposit: BalanceOf<T> = 10u32.into();
+		T::Currency::make_free_balance_be(&receiver, initial_balance);
+		assert_ok!(T::Currency::reserve(&receiver, deposit));
+
+		assert_eq!(T::Currency::free_balance(&receiver), initial_balance - 10u32.into());
+		assert_eq!(T::Currency::reserved_balance(&receiver), 10u32.into());
+	}: {
+		<MultiPhase<T>>::finalize_signed_phase_reject_solution(&receiver, deposit)
+	} verify {
+		assert_eq!(T::Currency::free_balance(&receiver), initial_balance - 10u32.into());
+		assert_eq!(T::Currency::reserved_balance(&receiver), 0u32.into());
+	}
+
 	// a call to `<Pallet as ElectionProvider>::elect` where we only return the queued solution.
 	elect_queued {
 		// assume largest values for the election status. These will merely affect the decoding.
@@ -218,6 +251,40 @@ frame_benchmarking::benchmarks! {
 		assert_eq!(<CurrentPhase<T>>::get(), <Phase<T::BlockNumber>>::Off);
 	}
 
+	submit {
+		let c in 1 .. (T::SignedMaxSubmissions::get() - 1);
+
+		// the solution will be worse than all of them, thus the queue is searched entirely.
+		let solution = RawSolution {
+			score: ElectionScore::from([(10_000_000u128 - 1).into(), 0, 0]),
+			..Default::default()
+		};
+
+		<CurrentPhase<T>>::put(Phase::Signed);
+		<Round<T>>::put(1);
+
+		let signed_submissions = (0..c)
+			.map(|i| {
+				let solution = RawSolution {
+					score: ElectionScore::from([(10_000_000 + i).into(), 0, 0]),
+					..Default::default()
+				};
+				SignedSubmission { solution, ..Default::default() }
+			})
+			.collect::<Vec<_>>();
+		<SignedSubmissions<T>>::put(signed_submissions);
+
+		let caller: T::AccountId = frame_benchmarking::whitelisted_caller();
+		let deposit = <MultiPhase<T>>::deposit_for(&solution, Default::default());
+		T::Currency::make_free_balance_be(
+			&caller,
+			T::Currency::minimum_balance().max(One::one()) * 10u32.into() + deposit,
+		);
+	}: _(RawOrigin::Signed(caller), solution, c)
+	verify {
+		assert_eq!(<MultiPhase<T>>::signed_submissions().len() as u32, c + 1);
+	}
+
 	#[extra]
 	create_snapshot {
 		assert!(<MultiPhase<T>>::snapshot().is_none());
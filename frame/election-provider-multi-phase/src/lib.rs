@@ -18,7 +18,7 @@
 //! # Multi phase, offchain election provider pallet.
 //!
 //! Currently, this election-provider has two distinct phases (see [`Phase`]), **signed** and
-//! **unsigned**.
+//! **unsigned**, and an additional [`Phase::Emergency`] entered when an election fails.
 //!
 //! ## Phases
 //!
@@ -51,7 +51,7 @@
 //!	In the signed phase, solutions (of type [`RawSolution`]) are submitted and queued on chain. A
 //! deposit is reserved, based on the size of the solution, for the cost of keeping this solution
 //! on-chain for a number of blocks, and the potential weight of the solution upon being checked. A
-//! maximum of `pallet::Config::MaxSignedSubmissions` solutions are stored. The queue is always
+//! maximum of [`pallet::Config::SignedMaxSubmissions`] solutions are stored. The queue is always
 //! sorted based on score (worse to best).
 //!
 //! Upon arrival of a new solution:
@@ -117,6 +117,15 @@
 //! reduction post-processing. See [`onchain::OnChainSequentialPhragmen`]. The
 //! [`FallbackStrategy::Nothing`] should probably only be used for testing, and returns an error.
 //!
+//! ### Emergency Phase
+//!
+//! If the fallback strategy fails as well, the election fails and the pallet enters
+//! [`Phase::Emergency`]. The snapshot of the round is kept, no new phase is opened, and every call
+//! to [`ElectionProvider::elect`] is retried with the fallback strategy, until
+//! [`pallet::Config::ForceOrigin`] provides a solution with
+//! [`Pallet::set_emergency_election_result`]. That solution is trusted and returned by the next
+//! call to `elect`, after which the pallet resumes its normal rotation of phases.
+//!
 //! ## Feasible Solution (correct solution)
 //!
 //! All submissions must undergo a feasibility check. Signed solutions are checked on by one at the
@@ -215,11 +224,13 @@ use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
 	weights::Weight,
 };
-use frame_system::{ensure_none, offchain::SendTransactionTypes};
-use frame_election_provider_support::{ElectionDataProvider, ElectionProvider, onchain};
+use frame_system::{ensure_none, ensure_signed, offchain::SendTransactionTypes};
+use frame_election_provider_support::{
+	ElectionBounds, ElectionDataProvider, ElectionProvider, onchain,
+};
 use sp_npos_elections::{
 	assignment_ratio_to_staked_normalized, CompactSolution, ElectionScore,
 	EvaluateSupport, PerThing128, Supports, VoteWeight,
@@ -250,9 +261,14 @@ pub mod helpers;
 
 const LOG_TARGET: &'static str = "runtime::election-provider";
 
+pub mod signed;
 pub mod unsigned;
 pub mod weights;
 
+pub use signed::{
+	BalanceOf, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission, SignedSubmissionOf,
+};
+
 /// The weight declaration of the pallet.
 pub use weights::WeightInfo;
 
@@ -315,6 +331,9 @@ pub enum Phase<Bn> {
 	/// and always compute their own solution. However, by default, when the unsigned phase is passive,
 	/// the offchain workers will not bother running.
 	Unsigned((bool, Bn)),
+	/// The last election failed, and no new phase is opened until a solution is provided by
+	/// [`Config::ForceOrigin`] or the fallback succeeds.
+	Emergency,
 }
 
 impl<Bn> Default for Phase<Bn> {
@@ -348,6 +367,11 @@ impl<Bn: PartialEq + Eq> Phase<Bn> {
 	pub fn is_off(&self) -> bool {
 		matches!(self, Phase::Off)
 	}
+
+	/// Whether the phase is emergency or not.
+	pub fn is_emergency(&self) -> bool {
+		matches!(self, Phase::Emergency)
+	}
 }
 
 /// A configuration for the pallet to indicate what should happen in the case of a fallback i.e.
@@ -372,6 +396,8 @@ pub enum ElectionCompute {
 	Signed,
 	/// Election was computed with an unsigned submission.
 	Unsigned,
+	/// Election was provided by [`Config::ForceOrigin`] during the emergency phase.
+	Emergency,
 }
 
 impl Default for ElectionCompute {
@@ -534,6 +560,27 @@ pub mod pallet {
 		#[pallet::constant]
 		type SignedPhase: Get<Self::BlockNumber>;
 
+		/// Maximum number of signed submissions that can be queued.
+		#[pallet::constant]
+		type SignedMaxSubmissions: Get<u32>;
+		/// Base reward for a signed solution.
+		#[pallet::constant]
+		type SignedRewardBase: Get<BalanceOf<Self>>;
+		/// Base deposit for a signed solution.
+		#[pallet::constant]
+		type SignedDepositBase: Get<BalanceOf<Self>>;
+		/// Per-byte deposit for a signed solution.
+		#[pallet::constant]
+		type SignedDepositByte: Get<BalanceOf<Self>>;
+		/// Per-weight deposit for a signed solution.
+		#[pallet::constant]
+		type SignedDepositWeight: Get<BalanceOf<Self>>;
+
+		/// Handler for the slashed deposits of the invalid signed solutions.
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Handler for the rewards of the accepted signed solutions.
+		type RewardHandler: OnUnbalanced<PositiveImbalanceOf<Self>>;
+
 		/// The minimum amount of improvement to the solution score that defines a solution as
 		/// "better" (in any phase).
 		#[pallet::constant]
@@ -567,6 +614,12 @@ pub mod pallet {
 		/// Something that will provide the election data.
 		type DataProvider: ElectionDataProvider<Self::AccountId, Self::BlockNumber>;
 
+		/// The bounds of the snapshot requested from [`Config::DataProvider`].
+		///
+		/// These are further tightened by the number of voters and targets that can be indexed by
+		/// [`Config::CompactSolution`].
+		type ElectionBounds: Get<ElectionBounds>;
+
 		/// The compact solution type
 		type CompactSolution: codec::Codec
 			+ Default
@@ -582,7 +635,7 @@ pub mod pallet {
 		/// Configuration for the fallback
 		type Fallback: Get<FallbackStrategy>;
 
-		/// Origin that can set the minimum score.
+		/// Origin that can set the minimum score, and the election result in the emergency phase.
 		type ForceOrigin: EnsureOrigin<Self::Origin>;
 
 		/// The configuration of benchmarking.
@@ -626,8 +679,11 @@ pub mod pallet {
 					// determine if followed by signed or not.
 					let (need_snapshot, enabled, signed_weight) = if current_phase == Phase::Signed {
 						// followed by a signed phase: close the signed phase, no need for snapshot.
-						// TODO: proper weight https://github.com/paritytech/substrate/pull/7910.
-						(false, true, Weight::zero())
+						//
+						// The unsigned phase stays enabled regardless of the outcome: the miners
+						// only submit a solution improving on the queued one, if any.
+						let (_found, signed_weight) = Self::finalize_signed_phase();
+						(false, true, signed_weight)
 					} else {
 						// no signed phase: create a new snapshot, definitely `enable` the unsigned
 						// phase.
@@ -794,6 +850,93 @@ pub mod pallet {
 			<MinimumUntrustedScore<T>>::set(maybe_next_score);
 			Ok(())
 		}
+
+		/// Submit a solution for the signed phase.
+		///
+		/// The dispatch origin fo this call must be __signed__.
+		///
+		/// The solution is potentially queued, based on the claimed score and processed at the end
+		/// of the signed phase.
+		///
+		/// A deposit is reserved and recorded for the solution. Based on the outcome, the solution
+		/// might be rewarded, slashed, or get all or a part of the deposit back.
+		///
+		/// `num_signed_submissions` must be at least the number of currently queued submissions,
+		/// as it bounds the weight of the call.
+		#[pallet::weight(T::WeightInfo::submit(*num_signed_submissions))]
+		pub fn submit(
+			origin: OriginFor<T>,
+			solution: RawSolution<CompactOf<T>>,
+			num_signed_submissions: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// ensure solution is timely.
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+
+			let mut signed_submissions = Self::signed_submissions();
+			// ensure witness data is correct.
+			ensure!(
+				num_signed_submissions >= signed_submissions.len() as u32,
+				Error::<T>::SignedInvalidWitness,
+			);
+
+			// ensure the solution is for the current round, with the correct number of winners.
+			ensure!(Self::round() == solution.round, Error::<T>::PreDispatchWrongRound);
+			ensure!(
+				Self::desired_targets().unwrap_or_default()
+					== solution.compact.unique_targets().len() as u32,
+				Error::<T>::PreDispatchWrongWinnerCount,
+			);
+
+			// defensive-only: if phase is signed, snapshot will exist.
+			let size = Self::snapshot_metadata().unwrap_or_default();
+
+			let index = Self::insert_submission(&who, &mut signed_submissions, solution, size)
+				.ok_or(Error::<T>::SignedQueueFull)?;
+
+			// collect deposit. Thereafter, the function cannot fail.
+			let deposit = signed_submissions[index].deposit;
+			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+
+			// remove the weakest solution if the queue is overflowing.
+			if signed_submissions.len() as u32 > T::SignedMaxSubmissions::get() {
+				let SignedSubmission { who, deposit, .. } = signed_submissions.remove(0);
+				let _remaining = T::Currency::unreserve(&who, deposit);
+				debug_assert!(_remaining.is_zero());
+			}
+
+			<SignedSubmissions<T>>::put(signed_submissions);
+			Self::deposit_event(Event::SolutionStored(ElectionCompute::Signed));
+			Ok(())
+		}
+
+		/// Set a solution in the queue, to be handed out to the client of this pallet in the next
+		/// call to `ElectionProvider::elect`.
+		///
+		/// This can only be set by `T::ForceOrigin`, and only when the phase is `Emergency`.
+		///
+		/// The solution is not checked for any feasibility and is assumed to be trustworthy, as any
+		/// feasibility check itself can in principle cause the election process to fail (due to
+		/// memory/weight constrains).
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_emergency_election_result(
+			origin: OriginFor<T>,
+			supports: Supports<T::AccountId>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::current_phase().is_emergency(), Error::<T>::CallNotAllowed);
+
+			let solution = ReadySolution {
+				score: (&supports).evaluate(),
+				supports,
+				compute: ElectionCompute::Emergency,
+			};
+
+			<QueuedSolution<T>>::put(solution);
+			Self::deposit_event(Event::SolutionStored(ElectionCompute::Emergency));
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -829,6 +972,16 @@ pub mod pallet {
 		PreDispatchWeakSubmission,
 		/// OCW submitted solution for wrong round
 		OcwCallWrongEra,
+		/// Submission was for the wrong round.
+		PreDispatchWrongRound,
+		/// The queue was full, and the solution was not better than any of the existing ones.
+		SignedQueueFull,
+		/// The origin failed to pay the deposit.
+		SignedCannotPayDeposit,
+		/// Witness data to dispatchable is invalid.
+		SignedInvalidWitness,
+		/// The call is not allowed at this point.
+		CallNotAllowed,
 	}
 
 	#[pallet::origin]
@@ -854,10 +1007,10 @@ pub mod pallet {
 					.map_err(dispatch_error_to_invalid)?;
 
 				ValidTransaction::with_tag_prefix("OffchainElection")
-					// The higher the score[0], the better a solution is.
+					// The higher the minimal stake, the better a solution is.
 					.priority(
 						T::MinerTxPriority::get().saturating_add(
-							solution.score[0].saturated_into()
+							solution.score.minimal_stake.saturated_into()
 						),
 					)
 					// used to deduplicate unsigned solutions: each validator should produce one
@@ -938,6 +1091,14 @@ pub mod pallet {
 	#[pallet::getter(fn minimum_untrusted_score)]
 	pub type MinimumUntrustedScore<T: Config> = StorageValue<_, ElectionScore>;
 
+	/// Sorted (worse -> best) list of unchecked, signed solutions.
+	///
+	/// Holds at most [`Config::SignedMaxSubmissions`] submissions, and is drained at the end of the
+	/// signed phase.
+	#[pallet::storage]
+	#[pallet::getter(fn signed_submissions)]
+	pub type SignedSubmissions<T: Config> = StorageValue<_, Vec<SignedSubmissionOf<T>>, ValueQuery>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(PhantomData<T>);
@@ -1027,18 +1188,24 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Returns `Ok(consumed_weight)` if operation is okay.
 	pub fn create_snapshot() -> Result<Weight, ElectionError> {
-		let target_limit = <CompactTargetIndexOf<T>>::max_value().saturated_into::<usize>();
-		let voter_limit = <CompactVoterIndexOf<T>>::max_value().saturated_into::<usize>();
+		let ElectionBounds { voters: voter_bounds, targets: target_bounds } =
+			Self::snapshot_bounds();
 
 		let (targets, w1) =
-			T::DataProvider::targets(Some(target_limit)).map_err(ElectionError::DataProvider)?;
+			T::DataProvider::targets(target_bounds).map_err(ElectionError::DataProvider)?;
 		let (voters, w2) =
-			T::DataProvider::voters(Some(voter_limit)).map_err(ElectionError::DataProvider)?;
+			T::DataProvider::voters(voter_bounds).map_err(ElectionError::DataProvider)?;
 		let (desired_targets, w3) =
 			T::DataProvider::desired_targets().map_err(ElectionError::DataProvider)?;
 
 		// defensive-only
-		if targets.len() > target_limit || voters.len() > voter_limit {
+		if target_bounds.exhausted(
+			Some(targets.len().saturated_into::<u32>().into()),
+			Some(targets.encoded_size().saturated_into::<u32>().into()),
+		) || voter_bounds.exhausted(
+			Some(voters.len().saturated_into::<u32>().into()),
+			Some(voters.encoded_size().saturated_into::<u32>().into()),
+		) {
 			debug_assert!(false, "Snapshot limit has not been respected.");
 			return Err(ElectionError::DataProvider("Snapshot too big for submission."));
 		}
@@ -1053,6 +1220,22 @@ impl<T: Config> Pallet<T> {
 		Ok(w1.saturating_add(w2).saturating_add(w3).saturating_add(T::DbWeight::get().writes(3)))
 	}
 
+	/// The bounds with which the snapshot is requested from the data provider.
+	///
+	/// This is [`Config::ElectionBounds`], tightened by the maximum number of voters and targets
+	/// that the compact solution type can index.
+	pub fn snapshot_bounds() -> ElectionBounds {
+		let compact_bounds = ElectionBounds::default()
+			.targets_count(<CompactTargetIndexOf<T>>::max_value().saturated_into::<u32>())
+			.voters_count(<CompactVoterIndexOf<T>>::max_value().saturated_into::<u32>());
+		let configured = T::ElectionBounds::get();
+
+		ElectionBounds {
+			voters: configured.voters.min(compact_bounds.voters),
+			targets: configured.targets.min(compact_bounds.targets),
+		}
+	}
+
 	/// Kill everything created by [`Pallet::create_snapshot`].
 	pub(crate) fn kill_snapshot() {
 		<Snapshot<T>>::kill();
@@ -1085,7 +1268,7 @@ impl<T: Config> Pallet<T> {
 		let submitted_score = solution.score.clone();
 		ensure!(
 			Self::minimum_untrusted_score().map_or(true, |min_score|
-				sp_npos_elections::is_score_better(submitted_score, min_score, Perbill::zero())
+				submitted_score.strict_threshold_better(min_score, Perbill::zero())
 			),
 			FeasibilityError::UntrustedScoreTooLow
 		);
@@ -1184,6 +1367,14 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_elect() -> Result<(Supports<T::AccountId>, Weight), ElectionError> {
+		// an election during the signed phase, e.g. when the unsigned phase has no duration, must
+		// still process the signed submissions.
+		let signed_weight = if Self::current_phase().is_signed() {
+			Self::finalize_signed_phase().1
+		} else {
+			Zero::zero()
+		};
+
 		<QueuedSolution<T>>::take()
 			.map_or_else(
 				|| match T::Fallback::get() {
@@ -1203,7 +1394,7 @@ impl<T: Config> Pallet<T> {
 				if Self::round() != 1 {
 					log!(info, "Finalized election round with compute {:?}.", compute);
 				}
-				(supports, weight)
+				(supports, weight.saturating_add(signed_weight))
 			})
 			.map_err(|err| {
 				Self::deposit_event(Event::ElectionFinalized(None));
@@ -1220,10 +1411,18 @@ impl<T: Config> ElectionProvider<T::AccountId, T::BlockNumber> for Pallet<T> {
 	type DataProvider = T::DataProvider;
 
 	fn elect() -> Result<(Supports<T::AccountId>, Weight), Self::Error> {
-		let outcome_and_weight = Self::do_elect();
-		// IMPORTANT: regardless of if election was `Ok` or `Err`, we shall do some cleanup.
-		Self::post_elect();
-		outcome_and_weight
+		match Self::do_elect() {
+			Ok(outcome_and_weight) => {
+				Self::post_elect();
+				Ok(outcome_and_weight)
+			}
+			Err(why) => {
+				// keep the round and its snapshot around, until an emergency solution is provided.
+				log!(error, "election failed, entering emergency phase: {:?}", why);
+				<CurrentPhase<T>>::put(Phase::Emergency);
+				Err(why)
+			}
+		}
 	}
 }
 
@@ -1231,7 +1430,7 @@ impl<T: Config> ElectionProvider<T::AccountId, T::BlockNumber> for Pallet<T> {
 /// number.
 pub fn dispatch_error_to_invalid(error: DispatchError) -> InvalidTransaction {
 	let error_number = match error {
-		DispatchError::Module { error, .. } => error,
+		DispatchError::Module { error, .. } => error[0],
 		_ => 0,
 	};
 	InvalidTransaction::Custom(error_number)
@@ -1398,7 +1597,7 @@ mod feasibility_check {
 			assert_eq!(MultiPhase::snapshot().unwrap().voters.len(), 8);
 
 			// simply faff with the score.
-			solution.score[0] += 1;
+			solution.score.minimal_stake += 1;
 
 			assert_noop!(
 				MultiPhase::feasibility_check(solution, COMPUTE),
@@ -1611,6 +1810,59 @@ mod tests {
 
 			// zilch solutions thus far.
 			assert_eq!(MultiPhase::elect().unwrap_err(), ElectionError::NoFallbackConfigured);
+			assert_eq!(MultiPhase::current_phase(), Phase::Emergency);
+		})
+	}
+
+	#[test]
+	fn emergency_phase_works() {
+		ExtBuilder::default().fallback(FallbackStrategy::Nothing).build_and_execute(|| {
+			roll_to(25);
+			assert!(MultiPhase::current_phase().is_unsigned());
+
+			// no solution and no fallback, the election fails.
+			assert!(MultiPhase::elect().is_err());
+			assert!(MultiPhase::current_phase().is_emergency());
+			assert_eq!(multi_phase_events().last(), Some(&Event::ElectionFinalized(None)));
+
+			// the round and its snapshot are kept, and no new phase is opened.
+			assert_eq!(MultiPhase::round(), 1);
+			assert!(MultiPhase::snapshot().is_some());
+			roll_to(45);
+			assert!(MultiPhase::current_phase().is_emergency());
+			assert!(MultiPhase::elect().is_err());
+
+			let supports = vec![(30, Support { total: 40, voters: vec![(30, 40)] })];
+			assert_noop!(
+				MultiPhase::set_emergency_election_result(Origin::signed(99), supports.clone()),
+				DispatchError::BadOrigin,
+			);
+			assert_ok!(
+				MultiPhase::set_emergency_election_result(Origin::root(), supports.clone())
+			);
+			assert_eq!(
+				multi_phase_events().last(),
+				Some(&Event::SolutionStored(ElectionCompute::Emergency)),
+			);
+
+			let (elected, _) = MultiPhase::elect().unwrap();
+			assert_eq!(elected, supports);
+			assert!(MultiPhase::current_phase().is_off());
+			assert!(MultiPhase::snapshot().is_none());
+			assert_eq!(MultiPhase::round(), 2);
+		})
+	}
+
+	#[test]
+	fn emergency_result_only_allowed_in_emergency_phase() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_noop!(
+				MultiPhase::set_emergency_election_result(Origin::root(), vec![]),
+				Error::<Runtime>::CallNotAllowed,
+			);
 		})
 	}
 
@@ -1634,6 +1886,31 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn snapshot_creation_respects_election_bounds() {
+		let bounds = ElectionBounds::default().voters_count(4);
+		ExtBuilder::default().election_bounds(bounds).build_and_execute(|| {
+			assert_eq!(MultiPhase::snapshot_bounds().voters.count, Some(4.into()));
+			assert_eq!(
+				MultiPhase::snapshot_bounds().targets.count,
+				Some((TargetIndex::max_value() as u32).into()),
+			);
+
+			// the default voters of the mock do not fit.
+			assert!(Voters::get().len() > 4);
+			roll_to(15);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+			assert!(MultiPhase::snapshot().is_none());
+		});
+
+		let bounds = ElectionBounds::default().voters_count(u32::max_value());
+		ExtBuilder::default().election_bounds(bounds).build_and_execute(|| {
+			roll_to(15);
+			assert_eq!(MultiPhase::current_phase(), Phase::Signed);
+			assert!(MultiPhase::snapshot().is_some());
+		});
+	}
+
 	#[test]
 	fn untrusted_score_verification_is_respected() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -1643,12 +1920,12 @@ mod tests {
 
 			let (solution, _) = MultiPhase::mine_solution(2).unwrap();
 			// default solution has a score of [50, 100, 5000].
-			assert_eq!(solution.score, [50, 100, 5000]);
+			assert_eq!(solution.score, [50, 100, 5000].into());
 
-			<MinimumUntrustedScore<Runtime>>::put([49, 0, 0]);
+			<MinimumUntrustedScore<Runtime>>::put(ElectionScore::from([49, 0, 0]));
 			assert_ok!(MultiPhase::feasibility_check(solution.clone(), ElectionCompute::Signed));
 
-			<MinimumUntrustedScore<Runtime>>::put([51, 0, 0]);
+			<MinimumUntrustedScore<Runtime>>::put(ElectionScore::from([51, 0, 0]));
 			assert_noop!(
 				MultiPhase::feasibility_check(
 					solution,
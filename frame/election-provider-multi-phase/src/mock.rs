@@ -32,7 +32,9 @@ use sp_core::{
 	},
 	H256,
 };
-use frame_election_provider_support::{ElectionDataProvider, data_provider};
+use frame_election_provider_support::{
+	DataProviderBounds, ElectionBounds, ElectionDataProvider, data_provider,
+};
 use sp_npos_elections::{
 	assignment_ratio_to_staked_normalized, seq_phragmen, to_supports, to_without_backing,
 	CompactSolution, ElectionResult, EvaluateSupport,
@@ -188,6 +190,11 @@ pub fn raw_solution() -> RawSolution<CompactOf<Runtime>> {
 	RawSolution { compact, score, round }
 }
 
+/// The free and reserved balance of `who`.
+pub fn balances(who: &AccountId) -> (Balance, Balance) {
+	(Balances::free_balance(who), Balances::reserved_balance(who))
+}
+
 pub fn witness() -> SolutionOrSnapshotSize {
 	MultiPhase::snapshot()
 		.map(|snap| SolutionOrSnapshotSize {
@@ -239,6 +246,7 @@ impl pallet_balances::Config for Runtime {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type WeightInfo = ();
 }
 
@@ -260,7 +268,11 @@ parameter_types! {
 	pub static DesiredTargets: u32 = 2;
 	pub static SignedPhase: u64 = 10;
 	pub static UnsignedPhase: u64 = 5;
-	pub static MaxSignedSubmissions: u32 = 5;
+	pub static SignedMaxSubmissions: u32 = 5;
+	pub static SignedDepositBase: Balance = 5;
+	pub static SignedDepositByte: Balance = 0;
+	pub static SignedDepositWeight: Balance = 0;
+	pub static SignedRewardBase: Balance = 7;
 
 	pub static MinerMaxIterations: u32 = 5;
 	pub static MinerTxPriority: u64 = 100;
@@ -269,6 +281,7 @@ parameter_types! {
 	pub static MinerMaxWeight: Weight = BlockWeights::get().max_block;
 	pub static MinerMaxLength: u32 = 256;
 	pub static MockWeightInfo: bool = false;
+	pub static SnapshotBounds: ElectionBounds = ElectionBounds::default();
 
 	pub static EpochLength: u64 = 30;
 }
@@ -304,6 +317,20 @@ impl multi_phase::weights::WeightInfo for DualMockWeightInfo {
 			<() as multi_phase::weights::WeightInfo>::on_initialize_open_unsigned_without_snapshot()
 		}
 	}
+	fn finalize_signed_phase_accept_solution() -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::finalize_signed_phase_accept_solution()
+		}
+	}
+	fn finalize_signed_phase_reject_solution() -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::finalize_signed_phase_reject_solution()
+		}
+	}
 	fn elect_queued() -> Weight {
 		if MockWeightInfo::get() {
 			Zero::zero()
@@ -311,11 +338,19 @@ impl multi_phase::weights::WeightInfo for DualMockWeightInfo {
 			<() as multi_phase::weights::WeightInfo>::elect_queued()
 		}
 	}
+	fn submit(c: u32) -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::submit(c)
+		}
+	}
 	fn submit_unsigned(v: u32, t: u32, a: u32, d: u32) -> Weight {
 		if MockWeightInfo::get() {
 			// 10 base
 			// 5 per edge.
-			(10 as Weight).saturating_add((5 as Weight).saturating_mul(a as Weight))
+			Weight::from_ref_time(10)
+				.saturating_add(Weight::from_ref_time(5).saturating_mul(a as u64))
 		} else {
 			<() as multi_phase::weights::WeightInfo>::submit_unsigned(v, t, a, d)
 		}
@@ -324,7 +359,8 @@ impl multi_phase::weights::WeightInfo for DualMockWeightInfo {
 		if MockWeightInfo::get() {
 			// 10 base
 			// 5 per edge.
-			(10 as Weight).saturating_add((5 as Weight).saturating_mul(a as Weight))
+			Weight::from_ref_time(10)
+				.saturating_add(Weight::from_ref_time(5).saturating_mul(a as u64))
 		} else {
 			<() as multi_phase::weights::WeightInfo>::feasibility_check(v, t, a, d)
 		}
@@ -336,6 +372,13 @@ impl crate::Config for Runtime {
 	type Currency = Balances;
 	type SignedPhase = SignedPhase;
 	type UnsignedPhase = UnsignedPhase;
+	type SignedMaxSubmissions = SignedMaxSubmissions;
+	type SignedRewardBase = SignedRewardBase;
+	type SignedDepositBase = SignedDepositBase;
+	type SignedDepositByte = SignedDepositByte;
+	type SignedDepositWeight = SignedDepositWeight;
+	type SlashHandler = ();
+	type RewardHandler = ();
 	type SolutionImprovementThreshold = SolutionImprovementThreshold;
 	type OffchainRepeat = OffchainRepeat;
 	type MinerMaxIterations = MinerMaxIterations;
@@ -343,6 +386,7 @@ impl crate::Config for Runtime {
 	type MinerMaxLength = MinerMaxLength;
 	type MinerTxPriority = MinerTxPriority;
 	type DataProvider = StakingMock;
+	type ElectionBounds = SnapshotBounds;
 	type WeightInfo = DualMockWeightInfo;
 	type BenchmarkingConfig = ();
 	type OnChainAccuracy = Perbill;
@@ -367,10 +411,13 @@ pub struct ExtBuilder {}
 pub struct StakingMock;
 impl ElectionDataProvider<AccountId, u64> for StakingMock {
 	const MAXIMUM_VOTES_PER_VOTER: u32 = <TestCompact as CompactSolution>::LIMIT as u32;
-	fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<(Vec<AccountId>, Weight)> {
+	fn targets(bounds: DataProviderBounds) -> data_provider::Result<(Vec<AccountId>, Weight)> {
 		let targets = Targets::get();
 
-		if maybe_max_len.map_or(false, |max_len| targets.len() > max_len) {
+		if bounds.exhausted(
+			Some((targets.len() as u32).into()),
+			Some((targets.encoded_size() as u32).into()),
+		) {
 			return Err("Targets too big");
 		}
 
@@ -378,10 +425,13 @@ impl ElectionDataProvider<AccountId, u64> for StakingMock {
 	}
 
 	fn voters(
-		maybe_max_len: Option<usize>,
+		bounds: DataProviderBounds,
 	) -> data_provider::Result<(Vec<(AccountId, VoteWeight, Vec<AccountId>)>, Weight)> {
 		let voters = Voters::get();
-		if maybe_max_len.map_or(false, |max_len| voters.len() > max_len) {
+		if bounds.exhausted(
+			Some((voters.len() as u32).into()),
+			Some((voters.encoded_size() as u32).into()),
+		) {
 			return Err("Voters too big");
 		}
 
@@ -432,10 +482,28 @@ impl ExtBuilder {
 		<MockWeightInfo>::set(mock);
 		self
 	}
+	pub fn election_bounds(self, bounds: ElectionBounds) -> Self {
+		<SnapshotBounds>::set(bounds);
+		self
+	}
 	pub fn desired_targets(self, t: u32) -> Self {
 		<DesiredTargets>::set(t);
 		self
 	}
+	pub fn signed_max_submission(self, count: u32) -> Self {
+		<SignedMaxSubmissions>::set(count);
+		self
+	}
+	pub fn signed_deposit(self, base: u64, byte: u64, weight: u64) -> Self {
+		<SignedDepositBase>::set(base);
+		<SignedDepositByte>::set(byte);
+		<SignedDepositWeight>::set(weight);
+		self
+	}
+	pub fn signed_reward_base(self, reward: u64) -> Self {
+		<SignedRewardBase>::set(reward);
+		self
+	}
 	pub fn add_voter(self, who: AccountId, stake: Balance, targets: Vec<AccountId>) -> Self {
 		VOTERS.with(|v| v.borrow_mut().push((who, stake, targets)));
 		self
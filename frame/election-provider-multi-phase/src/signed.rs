@@ -0,0 +1,551 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The signed phase implementation.
+
+use crate::{
+	CompactOf, Config, ElectionCompute, Pallet, RawSolution, ReadySolution, SignedSubmissions,
+	SolutionOrSnapshotSize, Weight, WeightInfo, QueuedSolution, Event,
+};
+use codec::{Encode, Decode, HasCompact};
+use frame_support::traits::{Currency, Get, OnUnbalanced, ReservableCurrency};
+use sp_npos_elections::CompactSolution;
+use sp_runtime::{Perbill, RuntimeDebug, SaturatedConversion, traits::{Saturating, Zero}};
+use sp_std::vec::Vec;
+
+/// A raw, unchecked signed submission.
+///
+/// This is just a wrapper around [`RawSolution`] and some additional info.
+#[derive(PartialEq, Eq, Encode, Decode, Clone, RuntimeDebug, Default)]
+pub struct SignedSubmission<AccountId, Balance: HasCompact, CompactSolution> {
+	/// Who submitted this solution.
+	pub who: AccountId,
+	/// The deposit reserved for storing this solution.
+	pub deposit: Balance,
+	/// The reward that should be given to this solution, if chosen as the best one.
+	pub reward: Balance,
+	/// The raw solution itself.
+	pub solution: RawSolution<CompactSolution>,
+}
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::PositiveImbalance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub type SignedSubmissionOf<T> =
+	SignedSubmission<<T as frame_system::Config>::AccountId, BalanceOf<T>, CompactOf<T>>;
+
+impl<T: Config> Pallet<T> {
+	/// Finish the signed phase. Process the signed submissions from best to worse until a valid one
+	/// is found, rewarding the best one and slashing the invalid ones along the way.
+	///
+	/// Returns true if we have a good solution in the signed phase.
+	///
+	/// This drains the [`SignedSubmissions`], potentially storing the best valid one in
+	/// [`QueuedSolution`].
+	pub fn finalize_signed_phase() -> (bool, Weight) {
+		let mut all_submissions: Vec<SignedSubmissionOf<T>> = <SignedSubmissions<T>>::take();
+		let mut found_solution = false;
+		let mut weight = T::DbWeight::get().reads(1);
+
+		let SolutionOrSnapshotSize { voters, targets } =
+			Self::snapshot_metadata().unwrap_or_default();
+
+		while let Some(best) = all_submissions.pop() {
+			let SignedSubmission { solution, who, deposit, reward } = best;
+			let active_voters = solution.compact.voter_count() as u32;
+			let feasibility_weight = {
+				// defensive only: at the end of signed phase, the snapshot exists.
+				let desired_targets = Self::desired_targets().unwrap_or_default();
+				T::WeightInfo::feasibility_check(voters, targets, active_voters, desired_targets)
+			};
+			// the feasibility check itself has some weight
+			weight = weight.saturating_add(feasibility_weight);
+			match Self::feasibility_check(solution, ElectionCompute::Signed) {
+				Ok(ready_solution) => {
+					Self::finalize_signed_phase_accept_solution(
+						ready_solution,
+						&who,
+						deposit,
+						reward,
+					);
+					found_solution = true;
+
+					weight = weight
+						.saturating_add(T::WeightInfo::finalize_signed_phase_accept_solution());
+					break;
+				}
+				Err(_) => {
+					Self::finalize_signed_phase_reject_solution(&who, deposit);
+					weight = weight
+						.saturating_add(T::WeightInfo::finalize_signed_phase_reject_solution());
+				}
+			}
+		}
+
+		// Any unprocessed solution is pointless to even consider. Feasible or malicious,
+		// they didn't end up being used. Unreserve the bonds.
+		let discarded = all_submissions.len();
+		for SignedSubmission { who, deposit, .. } in all_submissions.drain(..) {
+			let _remaining = T::Currency::unreserve(&who, deposit);
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			debug_assert!(_remaining.is_zero());
+		}
+
+		log!(
+			debug,
+			"closed signed phase, found solution? {}, discarded {}",
+			found_solution,
+			discarded
+		);
+		(found_solution, weight)
+	}
+
+	/// Helper function for the case where a solution is accepted in the signed phase.
+	///
+	/// Extracted to facilitate with weight calculation.
+	///
+	/// Infallible
+	pub fn finalize_signed_phase_accept_solution(
+		ready_solution: ReadySolution<T::AccountId>,
+		who: &T::AccountId,
+		deposit: BalanceOf<T>,
+		reward: BalanceOf<T>,
+	) {
+		// write this ready solution.
+		<QueuedSolution<T>>::put(ready_solution);
+
+		// unreserve deposit.
+		let _remaining = T::Currency::unreserve(who, deposit);
+		debug_assert!(_remaining.is_zero());
+
+		// Reward.
+		let positive_imbalance = T::Currency::deposit_creating(who, reward);
+		T::RewardHandler::on_unbalanced(positive_imbalance);
+
+		Self::deposit_event(Event::Rewarded(who.clone()));
+	}
+
+	/// Helper function for the case where a solution is rejected in the signed phase.
+	///
+	/// Extracted to facilitate with weight calculation.
+	///
+	/// Infallible
+	pub fn finalize_signed_phase_reject_solution(who: &T::AccountId, deposit: BalanceOf<T>) {
+		Self::deposit_event(Event::Slashed(who.clone()));
+		let (negative_imbalance, _remaining) = T::Currency::slash_reserved(who, deposit);
+		debug_assert!(_remaining.is_zero());
+		T::SlashHandler::on_unbalanced(negative_imbalance);
+	}
+
+	/// Insert a solution into the queue while maintaining an ordering by solution quality.
+	///
+	/// Solutions are ordered in reverse: strong solutions have high indices.
+	///
+	/// If insertion was successful, the index of the new solution within the queue is returned.
+	/// The queue may then hold one more submission than [`Config::SignedMaxSubmissions`], in which
+	/// case it is up to the caller to remove the weakest one, at index zero.
+	///
+	/// Returns `None` if the queue is full and the solution is not strictly better than any of the
+	/// queued ones. Among solutions of equal score, the earlier submission is thus preferred.
+	pub fn insert_submission(
+		who: &T::AccountId,
+		queue: &mut Vec<SignedSubmissionOf<T>>,
+		solution: RawSolution<CompactOf<T>>,
+		size: SolutionOrSnapshotSize,
+	) -> Option<usize> {
+		// Let's ensure that our input is valid.
+		let max_submissions = T::SignedMaxSubmissions::get();
+		debug_assert!(queue.len() as u32 <= max_submissions);
+
+		// the number of queued solutions that are strictly worse than the new one, which is also
+		// the index at which the new one belongs.
+		let insert_index = queue
+			.iter()
+			.take_while(|s| {
+				solution.score.strict_threshold_better(s.solution.score, Perbill::zero())
+			})
+			.count();
+
+		// a full queue only admits solutions better than its weakest one.
+		if queue.len() as u32 >= max_submissions && insert_index.is_zero() {
+			return None;
+		}
+
+		let reward = T::SignedRewardBase::get();
+		let deposit = Self::deposit_for(&solution, size);
+		let submission = SignedSubmission { who: who.clone(), deposit, reward, solution };
+		queue.insert(insert_index, submission);
+
+		debug_assert!(queue.windows(2).all(|w| {
+			!w[0].solution.score.strict_threshold_better(w[1].solution.score, Perbill::zero())
+		}));
+		Some(insert_index)
+	}
+
+	/// The weight of the given raw solution.
+	pub fn feasibility_weight_of(
+		solution: &RawSolution<CompactOf<T>>,
+		size: SolutionOrSnapshotSize,
+	) -> Weight {
+		T::WeightInfo::feasibility_check(
+			size.voters,
+			size.targets,
+			solution.compact.voter_count() as u32,
+			solution.compact.unique_targets().len() as u32,
+		)
+	}
+
+	/// Collect sufficient deposit to store this solution in this chain.
+	///
+	/// The deposit is composed of 3 main elements:
+	///
+	/// 1. base deposit, fixed for all submissions.
+	/// 2. a per-byte deposit, for renting the state usage.
+	/// 3. a per-weight deposit, for the potential weight usage in an upcoming on_initialize
+	pub fn deposit_for(
+		solution: &RawSolution<CompactOf<T>>,
+		size: SolutionOrSnapshotSize,
+	) -> BalanceOf<T> {
+		let encoded_len: u32 = solution.encoded_size() as u32;
+		let encoded_len: BalanceOf<T> = encoded_len.into();
+		let feasibility_weight = Self::feasibility_weight_of(solution, size);
+
+		let len_deposit = T::SignedDepositByte::get().saturating_mul(encoded_len);
+		let weight_deposit = T::SignedDepositWeight::get()
+			.saturating_mul(feasibility_weight.saturated_into());
+
+		T::SignedDepositBase::get().saturating_add(len_deposit).saturating_add(weight_deposit)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{mock::*, Call, ElectionProvider, Error, Event as MultiPhaseEvent, Phase};
+	use frame_support::{dispatch::DispatchResult, weights::GetDispatchInfo};
+	use sp_npos_elections::ElectionScore;
+
+	fn submit_with_witness(
+		origin: Origin,
+		solution: RawSolution<CompactOf<Runtime>>,
+	) -> DispatchResult {
+		MultiPhase::submit(origin, solution, MultiPhase::signed_submissions().len() as u32)
+	}
+
+	fn with_score(score: [u128; 3]) -> RawSolution<CompactOf<Runtime>> {
+		RawSolution { score: score.into(), ..raw_solution() }
+	}
+
+	fn queued_scores() -> Vec<ElectionScore> {
+		MultiPhase::signed_submissions().into_iter().map(|s| s.solution.score).collect()
+	}
+
+	#[test]
+	fn cannot_submit_too_early() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(2);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+
+			// create a temp snapshot only for this test.
+			MultiPhase::create_snapshot().unwrap();
+			let solution = raw_solution();
+
+			assert_noop!(
+				submit_with_witness(Origin::signed(10), solution),
+				Error::<Runtime>::PreDispatchEarlySubmission,
+			);
+		})
+	}
+
+	#[test]
+	fn wrong_witness_fails() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let solution = raw_solution();
+			assert_ok!(submit_with_witness(Origin::signed(99), solution.clone()));
+
+			// the witness must account for the queued submission.
+			assert_noop!(
+				MultiPhase::submit(Origin::signed(999), solution, 0),
+				Error::<Runtime>::SignedInvalidWitness,
+			);
+		})
+	}
+
+	#[test]
+	fn wrong_round_or_winner_count_fails() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let mut solution = raw_solution();
+			solution.round += 1;
+			assert_noop!(
+				submit_with_witness(Origin::signed(99), solution),
+				Error::<Runtime>::PreDispatchWrongRound,
+			);
+
+			<crate::DesiredTargets<Runtime>>::put(3);
+			assert_noop!(
+				submit_with_witness(Origin::signed(99), raw_solution()),
+				Error::<Runtime>::PreDispatchWrongWinnerCount,
+			);
+		})
+	}
+
+	#[test]
+	fn should_pay_deposit() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let solution = raw_solution();
+			assert_eq!(balances(&99), (100, 0));
+
+			assert_ok!(submit_with_witness(Origin::signed(99), solution));
+
+			assert_eq!(balances(&99), (95, 5));
+			assert_eq!(MultiPhase::signed_submissions().first().unwrap().deposit, 5);
+			assert_eq!(
+				multi_phase_events(),
+				vec![
+					MultiPhaseEvent::SignedPhaseStarted(1),
+					MultiPhaseEvent::SolutionStored(ElectionCompute::Signed),
+				],
+			);
+		})
+	}
+
+	#[test]
+	fn deposit_depends_on_size_and_weight() {
+		ExtBuilder::default().signed_deposit(5, 1, 0).build_and_execute(|| {
+			roll_to(15);
+
+			let solution = raw_solution();
+			let expected = 5 + solution.encoded_size() as u64;
+			assert_eq!(MultiPhase::deposit_for(&solution, witness()), expected);
+		});
+
+		ExtBuilder::default().signed_deposit(5, 0, 1).mock_weight_info(true).build_and_execute(
+			|| {
+				roll_to(15);
+
+				let solution = raw_solution();
+				let weight = MultiPhase::feasibility_weight_of(&solution, witness());
+				assert!(!weight.is_zero());
+				assert_eq!(MultiPhase::deposit_for(&solution, witness()), 5 + weight);
+			},
+		);
+	}
+
+	#[test]
+	fn cannot_submit_without_deposit() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			// account 1 has no balance at all.
+			assert_noop!(
+				submit_with_witness(Origin::signed(1), raw_solution()),
+				Error::<Runtime>::SignedCannotPayDeposit,
+			);
+			assert!(MultiPhase::signed_submissions().is_empty());
+		})
+	}
+
+	#[test]
+	fn good_solution_is_rewarded() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let solution = raw_solution();
+			assert_ok!(submit_with_witness(Origin::signed(99), solution));
+			assert_eq!(balances(&99), (95, 5));
+
+			assert!(MultiPhase::finalize_signed_phase().0);
+			assert_eq!(balances(&99), (100 + 7, 0));
+			assert_eq!(MultiPhase::queued_solution().unwrap().compute, ElectionCompute::Signed);
+			assert!(MultiPhase::signed_submissions().is_empty());
+			assert_eq!(multi_phase_events().last(), Some(&MultiPhaseEvent::Rewarded(99)));
+		})
+	}
+
+	#[test]
+	fn bad_solution_is_slashed() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let mut solution = raw_solution();
+			// make the solution invalid.
+			solution.score.minimal_stake += 1;
+
+			assert_ok!(submit_with_witness(Origin::signed(99), solution));
+			assert_eq!(balances(&99), (95, 5));
+
+			// no good solution was stored.
+			assert!(!MultiPhase::finalize_signed_phase().0);
+			// and the bond is gone.
+			assert_eq!(balances(&99), (95, 0));
+			assert!(MultiPhase::queued_solution().is_none());
+			assert_eq!(multi_phase_events().last(), Some(&MultiPhaseEvent::Slashed(99)));
+		})
+	}
+
+	#[test]
+	fn suppressed_solution_gets_bond_back() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let solution = raw_solution();
+			assert_ok!(submit_with_witness(Origin::signed(99), solution.clone()));
+
+			// submit a better, but invalid, solution.
+			let mut invalid = solution;
+			invalid.score.minimal_stake += 1;
+			assert_ok!(submit_with_witness(Origin::signed(999), invalid));
+
+			// and a weaker one, which is never checked.
+			assert_ok!(submit_with_witness(Origin::signed(9999), with_score([1, 1, 1])));
+
+			assert_eq!(balances(&99), (95, 5));
+			assert_eq!(balances(&999), (95, 5));
+			assert_eq!(balances(&9999), (95, 5));
+
+			assert!(MultiPhase::finalize_signed_phase().0);
+
+			// the invalid one is slashed, the best valid one rewarded, the rest returned.
+			assert_eq!(balances(&999), (95, 0));
+			assert_eq!(balances(&99), (100 + 7, 0));
+			assert_eq!(balances(&9999), (100, 0));
+		})
+	}
+
+	#[test]
+	fn queue_is_always_sorted() {
+		ExtBuilder::default().signed_max_submission(3).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([5, 0, 0])));
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([4, 0, 0])));
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([6, 0, 0])));
+
+			assert_eq!(
+				queued_scores(),
+				vec![[4, 0, 0].into(), [5, 0, 0].into(), [6, 0, 0].into()],
+			);
+		})
+	}
+
+	#[test]
+	fn cannot_submit_worse_with_full_queue() {
+		ExtBuilder::default().signed_max_submission(2).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([5, 0, 0])));
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([6, 0, 0])));
+
+			// an equally good solution does not make it either.
+			assert_noop!(
+				submit_with_witness(Origin::signed(999), with_score([5, 0, 0])),
+				Error::<Runtime>::SignedQueueFull,
+			);
+			assert_noop!(
+				submit_with_witness(Origin::signed(999), with_score([4, 0, 0])),
+				Error::<Runtime>::SignedQueueFull,
+			);
+			assert_eq!(balances(&999), (100, 0));
+		})
+	}
+
+	#[test]
+	fn weakest_is_removed_if_better_provided() {
+		ExtBuilder::default().signed_max_submission(2).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(submit_with_witness(Origin::signed(99), with_score([5, 0, 0])));
+			assert_ok!(submit_with_witness(Origin::signed(999), with_score([6, 0, 0])));
+			assert_eq!(balances(&99), (95, 5));
+
+			assert_ok!(submit_with_witness(Origin::signed(9999), with_score([7, 0, 0])));
+
+			// the weakest one is ejected, and its deposit returned.
+			assert_eq!(queued_scores(), vec![[6, 0, 0].into(), [7, 0, 0].into()]);
+			assert_eq!(balances(&99), (100, 0));
+			assert_eq!(balances(&9999), (95, 5));
+		})
+	}
+
+	#[test]
+	fn signed_phase_is_finalized_with_the_unsigned_phase() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(submit_with_witness(Origin::signed(99), raw_solution()));
+
+			roll_to(25);
+			assert!(MultiPhase::current_phase().is_unsigned());
+			assert!(MultiPhase::signed_submissions().is_empty());
+			assert_eq!(MultiPhase::queued_solution().unwrap().compute, ElectionCompute::Signed);
+			assert_eq!(balances(&99), (100 + 7, 0));
+		})
+	}
+
+	#[test]
+	fn signed_phase_is_finalized_upon_early_election() {
+		ExtBuilder::default().phases(10, 0).build_and_execute(|| {
+			roll_to(20);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(submit_with_witness(Origin::signed(99), raw_solution()));
+
+			roll_to(30);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			assert_ok!(MultiPhase::elect());
+			assert_eq!(
+				multi_phase_events().last(),
+				Some(&MultiPhaseEvent::ElectionFinalized(Some(ElectionCompute::Signed))),
+			);
+			assert!(MultiPhase::signed_submissions().is_empty());
+			assert_eq!(balances(&99), (100 + 7, 0));
+		})
+	}
+
+	#[test]
+	fn submit_weight_depends_on_witness() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to(15);
+
+			let call = |witness| Call::<Runtime>::submit(raw_solution(), witness);
+			assert!(call(10).get_dispatch_info().weight > call(0).get_dispatch_info().weight);
+		})
+	}
+}
@@ -24,10 +24,9 @@ use crate::{
 use codec::{Encode, Decode};
 use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
 use frame_system::offchain::SubmitTransaction;
-use sp_arithmetic::Perbill;
 use sp_npos_elections::{
 	CompactSolution, ElectionResult, assignment_ratio_to_staked_normalized,
-	assignment_staked_to_ratio_normalized, is_score_better, seq_phragmen,
+	assignment_staked_to_ratio_normalized, seq_phragmen,
 };
 use sp_runtime::{offchain::storage::StorageValueRef, traits::TrailingZeroInput, SaturatedConversion};
 use sp_std::{cmp::Ordering, convert::TryFrom, vec::Vec};
@@ -553,16 +552,16 @@ impl<T: Config> Pallet<T> {
 
 		// Time to finish. We might have reduced less than expected due to rounding error. Increase
 		// one last time if we have any room left, the reduce until we are sure we are below limit.
-		while voters + 1 <= max_voters && weight_with(voters + 1) < max_weight {
+		while voters + 1 <= max_voters && weight_with(voters + 1).all_lte(&max_weight) {
 			voters += 1;
 		}
-		while voters.checked_sub(1).is_some() && weight_with(voters) > max_weight {
+		while voters.checked_sub(1).is_some() && weight_with(voters).any_gt(&max_weight) {
 			voters -= 1;
 		}
 
 		let final_decision = voters.min(size.voters);
 		debug_assert!(
-			weight_with(final_decision) <= max_weight,
+			weight_with(final_decision).all_lte(&max_weight),
 			"weight_with({}) <= {}",
 			final_decision,
 			max_weight,
@@ -637,11 +636,9 @@ impl<T: Config> Pallet<T> {
 
 		// ensure score is being improved. Panic henceforth.
 		ensure!(
-			Self::queued_solution().map_or(true, |q: ReadySolution<_>| is_score_better::<Perbill>(
-				solution.score,
-				q.score,
-				T::SolutionImprovementThreshold::get()
-			)),
+			Self::queued_solution().map_or(true, |q: ReadySolution<_>| solution
+				.score
+				.strict_threshold_better(q.score, T::SolutionImprovementThreshold::get())),
 			Error::<T>::PreDispatchWeakSubmission,
 		);
 
@@ -667,13 +664,13 @@ mod max_weight {
 			unreachable!()
 		}
 		fn elect_queued() -> Weight {
-			0
+			Weight::zero()
 		}
 		fn on_initialize_open_unsigned_without_snapshot() -> Weight {
 			unreachable!()
 		}
 		fn submit_unsigned(v: u32, t: u32, a: u32, d: u32) -> Weight {
-			(0 * v + 0 * t + 1000 * a + 0 * d) as Weight
+			Weight::from_ref_time((0 * v + 0 * t + 1000 * a + 0 * d) as u64)
 		}
 		fn feasibility_check(v: u32, _t: u32, a: u32, d: u32) -> Weight {
 			unreachable!()
@@ -748,6 +745,7 @@ mod tests {
 		},
 	};
 	use frame_benchmarking::Zero;
+	use sp_arithmetic::Perbill;
 	use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::OffchainWorker};
 	use sp_npos_elections::IndexAssignment;
 	use sp_runtime::offchain::storage_lock::{StorageLock, BlockAndTime};
@@ -758,7 +756,7 @@ mod tests {
 	#[test]
 	fn validate_unsigned_retracts_wrong_phase() {
 		ExtBuilder::default().desired_targets(0).build_and_execute(|| {
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 			let call = Call::submit_unsigned(solution.clone(), witness());
 
 			// initial
@@ -818,7 +816,7 @@ mod tests {
 			roll_to(25);
 			assert!(MultiPhase::current_phase().is_unsigned());
 
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 			let call = Call::submit_unsigned(solution.clone(), witness());
 
 			// initial
@@ -830,7 +828,7 @@ mod tests {
 			assert!(<MultiPhase as ValidateUnsigned>::pre_dispatch(&call).is_ok());
 
 			// set a better score
-			let ready = ReadySolution { score: [10, 0, 0], ..Default::default() };
+			let ready = ReadySolution { score: [10, 0, 0].into(), ..Default::default() };
 			<QueuedSolution<Runtime>>::put(ready);
 
 			// won't work anymore.
@@ -855,7 +853,7 @@ mod tests {
 			roll_to(25);
 			assert!(MultiPhase::current_phase().is_unsigned());
 
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 			let call = Call::submit_unsigned(solution.clone(), witness());
 			assert_eq!(solution.compact.unique_targets().len(), 0);
 
@@ -877,7 +875,7 @@ mod tests {
 			roll_to(25);
 			assert!(MultiPhase::current_phase().is_unsigned());
 
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 			let call = Call::submit_unsigned(solution.clone(), witness());
 
 			assert_eq!(
@@ -895,7 +893,7 @@ mod tests {
 	#[test]
 	#[should_panic(expected = "Invalid unsigned submission must produce invalid block and \
 	                           deprive validator from their authoring reward.: \
-	                           Module { index: 2, error: 1, message: \
+	                           Module { index: 2, error: [1, 0, 0, 0], message: \
 	                           Some(\"PreDispatchWrongWinnerCount\") }")]
 	fn unfeasible_solution_panics() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -903,7 +901,7 @@ mod tests {
 			assert!(MultiPhase::current_phase().is_unsigned());
 
 			// This is in itself an invalid BS solution.
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 			let call = Call::submit_unsigned(solution.clone(), witness());
 			let outer_call: OuterCall = call.into();
 			let _ = outer_call.dispatch(Origin::none());
@@ -919,7 +917,7 @@ mod tests {
 			assert!(MultiPhase::current_phase().is_unsigned());
 
 			// This solution is unfeasible as well, but we won't even get there.
-			let solution = RawSolution::<TestCompact> { score: [5, 0, 0], ..Default::default() };
+			let solution = RawSolution::<TestCompact> { score: [5, 0, 0].into(), ..Default::default() };
 
 			let mut correct_witness = witness();
 			correct_witness.voters += 1;
@@ -1021,7 +1019,7 @@ mod tests {
 				let (solution, witness) = MultiPhase::prepare_election_result(result).unwrap();
 				assert_ok!(MultiPhase::unsigned_pre_dispatch_checks(&solution));
 				assert_ok!(MultiPhase::submit_unsigned(Origin::none(), solution, witness));
-				assert_eq!(MultiPhase::queued_solution().unwrap().score[0], 10);
+				assert_eq!(MultiPhase::queued_solution().unwrap().score.minimal_stake, 10);
 
 				// trial 1: a solution who's score is only 2, i.e. 20% better in the first element.
 				let result = ElectionResult {
@@ -1037,7 +1035,7 @@ mod tests {
 				};
 				let (solution, _) = MultiPhase::prepare_election_result(result).unwrap();
 				// 12 is not 50% more than 10
-				assert_eq!(solution.score[0], 12);
+				assert_eq!(solution.score.minimal_stake, 12);
 				assert_noop!(
 					MultiPhase::unsigned_pre_dispatch_checks(&solution),
 					Error::<Runtime>::PreDispatchWeakSubmission,
@@ -1058,7 +1056,7 @@ mod tests {
 					],
 				};
 				let (solution, witness) = MultiPhase::prepare_election_result(result).unwrap();
-				assert_eq!(solution.score[0], 17);
+				assert_eq!(solution.score.minimal_stake, 17);
 
 				// and it is fine
 				assert_ok!(MultiPhase::unsigned_pre_dispatch_checks(&solution));
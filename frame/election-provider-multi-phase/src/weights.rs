@@ -48,7 +48,10 @@ pub trait WeightInfo {
 	fn on_initialize_open_signed() -> Weight;
 	fn on_initialize_open_unsigned_with_snapshot() -> Weight;
 	fn on_initialize_open_unsigned_without_snapshot() -> Weight;
+	fn finalize_signed_phase_accept_solution() -> Weight;
+	fn finalize_signed_phase_reject_solution() -> Weight;
 	fn elect_queued() -> Weight;
+	fn submit(c: u32, ) -> Weight;
 	fn submit_unsigned(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
 	fn feasibility_check(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
 }
@@ -57,101 +60,135 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn on_initialize_nothing() -> Weight {
-		(22_730_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+		Weight::from_ref_time(22_730_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
 	}
 	fn on_initialize_open_signed() -> Weight {
-		(112_051_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(112_051_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	fn on_initialize_open_unsigned_with_snapshot() -> Weight {
-		(112_165_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(112_165_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	fn on_initialize_open_unsigned_without_snapshot() -> Weight {
-		(21_039_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(21_039_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn finalize_signed_phase_accept_solution() -> Weight {
+		Weight::from_ref_time(47_783_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn finalize_signed_phase_reject_solution() -> Weight {
+		Weight::from_ref_time(21_277_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn elect_queued() -> Weight {
-		(7_362_949_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+		Weight::from_ref_time(7_362_949_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	fn submit(c: u32, ) -> Weight {
+		Weight::from_ref_time(78_972_000 as u64)
+			// Standard Error: 16_000
+			.saturating_add(Weight::from_ref_time(308_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn submit_unsigned(v: u32, _t: u32, a: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 21_000
-			.saturating_add((3_933_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(3_933_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 21_000
-			.saturating_add((13_520_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(Weight::from_ref_time(13_520_000 as u64).saturating_mul(a as u64))
 			// Standard Error: 107_000
-			.saturating_add((2_880_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(2_880_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn feasibility_check(v: u32, t: u32, a: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 10_000
-			.saturating_add((4_069_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(4_069_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 36_000
-			.saturating_add((503_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(Weight::from_ref_time(503_000 as u64).saturating_mul(t as u64))
 			// Standard Error: 10_000
-			.saturating_add((10_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(Weight::from_ref_time(10_000_000 as u64).saturating_mul(a as u64))
 			// Standard Error: 54_000
-			.saturating_add((3_734_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(Weight::from_ref_time(3_734_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn on_initialize_nothing() -> Weight {
-		(22_730_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+		Weight::from_ref_time(22_730_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
 	}
 	fn on_initialize_open_signed() -> Weight {
-		(112_051_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(112_051_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	fn on_initialize_open_unsigned_with_snapshot() -> Weight {
-		(112_165_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(112_165_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	fn on_initialize_open_unsigned_without_snapshot() -> Weight {
-		(21_039_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(21_039_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn finalize_signed_phase_accept_solution() -> Weight {
+		Weight::from_ref_time(47_783_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn finalize_signed_phase_reject_solution() -> Weight {
+		Weight::from_ref_time(21_277_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn elect_queued() -> Weight {
-		(7_362_949_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+		Weight::from_ref_time(7_362_949_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	fn submit(c: u32, ) -> Weight {
+		Weight::from_ref_time(78_972_000 as u64)
+			// Standard Error: 16_000
+			.saturating_add(Weight::from_ref_time(308_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn submit_unsigned(v: u32, _t: u32, a: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 21_000
-			.saturating_add((3_933_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(3_933_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 21_000
-			.saturating_add((13_520_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(Weight::from_ref_time(13_520_000 as u64).saturating_mul(a as u64))
 			// Standard Error: 107_000
-			.saturating_add((2_880_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(2_880_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn feasibility_check(v: u32, t: u32, a: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 10_000
-			.saturating_add((4_069_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(4_069_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 36_000
-			.saturating_add((503_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(Weight::from_ref_time(503_000 as u64).saturating_mul(t as u64))
 			// Standard Error: 10_000
-			.saturating_add((10_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(Weight::from_ref_time(10_000_000 as u64).saturating_mul(a as u64))
 			// Standard Error: 54_000
-			.saturating_add((3_734_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(Weight::from_ref_time(3_734_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 	}
 }
@@ -0,0 +1,201 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types to express the limits of the data exchanged between an [`crate::ElectionProvider`] and
+//! its [`crate::ElectionDataProvider`].
+//!
+//! Both voters and targets can be bounded by their *count*, i.e. the number of items, and by their
+//! *size*, i.e. the number of bytes that the SCALE encoded items occupy. A bound that is `None` is
+//! unbounded.
+//!
+//! ```
+//! # use frame_election_provider_support::bounds::*;
+//! let bounds = ElectionBounds::default()
+//! 	.voters_count(100)
+//! 	.voters_size(1024)
+//! 	.targets_count(10);
+//!
+//! assert!(bounds.voters.exhausted(Some(101.into()), None));
+//! assert!(bounds.voters.exhausted(None, Some(1025.into())));
+//! assert!(!bounds.targets.exhausted(Some(10.into()), Some(u32::max_value().into())));
+//! ```
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+
+/// A bound on the number of items.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub struct CountBound(pub u32);
+
+impl From<u32> for CountBound {
+	fn from(value: u32) -> Self {
+		CountBound(value)
+	}
+}
+
+/// A bound on the encoded size of a set of items, in bytes.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub struct SizeBound(pub u32);
+
+impl From<u32> for SizeBound {
+	fn from(value: u32) -> Self {
+		SizeBound(value)
+	}
+}
+
+/// The bounds of one data set (i.e. voters or targets) of an election.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct DataProviderBounds {
+	/// The maximum number of items, if any.
+	pub count: Option<CountBound>,
+	/// The maximum encoded size of all items, if any.
+	pub size: Option<SizeBound>,
+}
+
+impl DataProviderBounds {
+	/// Bounds that do not limit anything.
+	pub fn unbounded() -> Self {
+		Self { count: None, size: None }
+	}
+
+	/// Returns true if `given_count` exhausts `self.count`.
+	pub fn count_exhausted(self, given_count: CountBound) -> bool {
+		self.count.map_or(false, |count| given_count > count)
+	}
+
+	/// Returns true if `given_size` exhausts `self.size`.
+	pub fn size_exhausted(self, given_size: SizeBound) -> bool {
+		self.size.map_or(false, |size| given_size > size)
+	}
+
+	/// Returns true if either of the given count or size exhausts the respective bound.
+	pub fn exhausted(self, given_count: Option<CountBound>, given_size: Option<SizeBound>) -> bool {
+		given_count.map_or(false, |count| self.count_exhausted(count)) ||
+			given_size.map_or(false, |size| self.size_exhausted(size))
+	}
+
+	/// Returns the tightest bounds of `self` and `other`, i.e. the minimum of each bound.
+	pub fn min(self, other: Self) -> Self {
+		let min_of = |a: Option<u32>, b: Option<u32>| match (a, b) {
+			(Some(a), Some(b)) => Some(a.min(b)),
+			(a, None) => a,
+			(None, b) => b,
+		};
+
+		Self {
+			count: min_of(self.count.map(|c| c.0), other.count.map(|c| c.0)).map(CountBound),
+			size: min_of(self.size.map(|s| s.0), other.size.map(|s| s.0)).map(SizeBound),
+		}
+	}
+}
+
+/// The bounds of an election, for both voters and targets.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct ElectionBounds {
+	/// The bounds of the voters.
+	pub voters: DataProviderBounds,
+	/// The bounds of the targets.
+	pub targets: DataProviderBounds,
+}
+
+impl ElectionBounds {
+	/// Set the maximum number of voters.
+	pub fn voters_count(mut self, count: u32) -> Self {
+		self.voters.count = Some(count.into());
+		self
+	}
+
+	/// Set the maximum encoded size of all voters.
+	pub fn voters_size(mut self, size: u32) -> Self {
+		self.voters.size = Some(size.into());
+		self
+	}
+
+	/// Set the maximum number of targets.
+	pub fn targets_count(mut self, count: u32) -> Self {
+		self.targets.count = Some(count.into());
+		self
+	}
+
+	/// Set the maximum encoded size of all targets.
+	pub fn targets_size(mut self, size: u32) -> Self {
+		self.targets.size = Some(size.into());
+		self
+	}
+
+	/// Ensure that the given voters count and size are within the voter bounds.
+	pub fn ensure_voters_limits(
+		self,
+		count: CountBound,
+		size: SizeBound,
+	) -> Result<(), &'static str> {
+		if self.voters.exhausted(Some(count), Some(size)) {
+			Err("Ensure voters bounds: bounds exceeded.")
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Ensure that the given targets count and size are within the target bounds.
+	pub fn ensure_targets_limits(
+		self,
+		count: CountBound,
+		size: SizeBound,
+	) -> Result<(), &'static str> {
+		if self.targets.exhausted(Some(count), Some(size)) {
+			Err("Ensure targets bounds: bounds exceeded.")
+		} else {
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn data_provider_bounds_exhaustion_works() {
+		let unbounded = DataProviderBounds::unbounded();
+		assert!(!unbounded.exhausted(Some(u32::max_value().into()), Some(u32::max_value().into())));
+
+		let bounds = DataProviderBounds { count: Some(10.into()), size: Some(100.into()) };
+		assert!(!bounds.exhausted(Some(10.into()), Some(100.into())));
+		assert!(bounds.exhausted(Some(11.into()), Some(100.into())));
+		assert!(bounds.exhausted(Some(10.into()), Some(101.into())));
+		assert!(!bounds.exhausted(None, None));
+	}
+
+	#[test]
+	fn data_provider_bounds_min_works() {
+		let a = DataProviderBounds { count: Some(10.into()), size: None };
+		let b = DataProviderBounds { count: Some(20.into()), size: Some(5.into()) };
+
+		assert_eq!(a.min(b), DataProviderBounds { count: Some(10.into()), size: Some(5.into()) });
+		assert_eq!(a.min(DataProviderBounds::unbounded()), a);
+	}
+
+	#[test]
+	fn election_bounds_ensure_works() {
+		let bounds = ElectionBounds::default().voters_count(5).targets_size(50);
+
+		assert!(bounds.ensure_voters_limits(5.into(), u32::max_value().into()).is_ok());
+		assert!(bounds.ensure_voters_limits(6.into(), 0.into()).is_err());
+		assert!(bounds.ensure_targets_limits(u32::max_value().into(), 50.into()).is_ok());
+		assert!(bounds.ensure_targets_limits(0.into(), 51.into()).is_err());
+	}
+}
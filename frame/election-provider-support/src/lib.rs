@@ -102,15 +102,15 @@
 //!     impl<T: Config> ElectionDataProvider<AccountId, BlockNumber> for Module<T> {
 //!         const MAXIMUM_VOTES_PER_VOTER: u32 = 1;
 //!         fn desired_targets() -> data_provider::Result<(u32, Weight)> {
-//!             Ok((1, 0))
+//!             Ok((1, Weight::zero()))
 //!         }
-//!         fn voters(maybe_max_len: Option<usize>)
+//!         fn voters(bounds: DataProviderBounds)
 //!         -> data_provider::Result<(Vec<(AccountId, VoteWeight, Vec<AccountId>)>, Weight)>
 //!         {
-//!             Ok((Default::default(), 0))
+//!             Ok((Default::default(), Weight::zero()))
 //!         }
-//!         fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<(Vec<AccountId>, Weight)> {
-//!             Ok((vec![10, 20, 30], 0))
+//!         fn targets(bounds: DataProviderBounds) -> data_provider::Result<(Vec<AccountId>, Weight)> {
+//!             Ok((vec![10, 20, 30], Weight::zero()))
 //!         }
 //!         fn next_election_prediction(now: BlockNumber) -> BlockNumber {
 //!             0
@@ -133,7 +133,7 @@
 //!         type DataProvider = T::DataProvider;
 //!
 //!         fn elect() -> Result<(Supports<AccountId>, Weight), Self::Error> {
-//!             Self::DataProvider::targets(None)
+//!             Self::DataProvider::targets(DataProviderBounds::unbounded())
 //!                 .map_err(|_| "failed to elect")
 //!                 .map(|(t, weight)| {
 //! 						(vec![(t[0], Support::default())], weight)
@@ -164,14 +164,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod onchain;
+pub mod bounds;
 use sp_std::{prelude::*, fmt::Debug};
 use frame_support::weights::Weight;
 
 /// Re-export some type as they are used in the interface.
 pub use sp_arithmetic::PerThing;
 pub use sp_npos_elections::{
-	Assignment, ExtendedBalance, PerThing128, Supports, Support, VoteWeight
+	Assignment, ElectionScore, ExtendedBalance, PerThing128, Supports, Support, VoteWeight
 };
+pub use bounds::{CountBound, DataProviderBounds, ElectionBounds, SizeBound};
 
 /// Types that are used by the data provider trait.
 pub mod data_provider {
@@ -186,24 +188,22 @@ pub trait ElectionDataProvider<AccountId, BlockNumber> {
 
 	/// All possible targets for the election, i.e. the candidates.
 	///
-	/// If `maybe_max_len` is `Some(v)` then the resulting vector MUST NOT be longer than `v` items
-	/// long.
+	/// The resulting vector MUST respect `bounds`, both in number of items and in encoded size.
 	///
 	/// It is assumed that this function will only consume a notable amount of weight, when it
 	/// returns `Ok(_)`.
-	fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<(Vec<AccountId>, Weight)>;
+	fn targets(bounds: DataProviderBounds) -> data_provider::Result<(Vec<AccountId>, Weight)>;
 
 	/// All possible voters for the election.
 	///
 	/// Note that if a notion of self-vote exists, it should be represented here.
 	///
-	/// If `maybe_max_len` is `Some(v)` then the resulting vector MUST NOT be longer than `v` items
-	/// long.
+	/// The resulting vector MUST respect `bounds`, both in number of items and in encoded size.
 	///
 	/// It is assumed that this function will only consume a notable amount of weight, when it
 	/// returns `Ok(_)`.
 	fn voters(
-		maybe_max_len: Option<usize>,
+		bounds: DataProviderBounds,
 	) -> data_provider::Result<(Vec<(AccountId, VoteWeight, Vec<AccountId>)>, Weight)>;
 
 	/// The number of targets to elect.
@@ -231,11 +231,11 @@ pub trait ElectionDataProvider<AccountId, BlockNumber> {
 #[cfg(feature = "std")]
 impl<AccountId, BlockNumber> ElectionDataProvider<AccountId, BlockNumber> for () {
 	const MAXIMUM_VOTES_PER_VOTER: u32 = 0;
-	fn targets(_maybe_max_len: Option<usize>) -> data_provider::Result<(Vec<AccountId>, Weight)> {
+	fn targets(_bounds: DataProviderBounds) -> data_provider::Result<(Vec<AccountId>, Weight)> {
 		Ok(Default::default())
 	}
 	fn voters(
-		_maybe_max_len: Option<usize>,
+		_bounds: DataProviderBounds,
 	) -> data_provider::Result<(Vec<(AccountId, VoteWeight, Vec<AccountId>)>, Weight)> {
 		Ok(Default::default())
 	}
@@ -265,6 +265,60 @@ pub trait ElectionProvider<AccountId, BlockNumber> {
 	fn elect() -> Result<(Supports<AccountId>, Weight), Self::Error>;
 }
 
+/// A list of voters which is kept sorted, or at least semi-sorted, by their vote weight.
+///
+/// This allows an [`ElectionDataProvider`] to take the voters with the highest vote weights without
+/// having to read and sort all of them in memory.
+pub trait SortedListProvider<AccountId> {
+	/// The error type returned when an item cannot be inserted into the list.
+	type Error: Debug;
+
+	/// An iterator over the list, which can have `take` called on it.
+	fn iter() -> Box<dyn Iterator<Item = AccountId>>;
+
+	/// The current count of ids in the list.
+	fn count() -> u32;
+
+	/// Return true if the list already contains `id`.
+	fn contains(id: &AccountId) -> bool;
+
+	/// Hook for inserting a new id.
+	fn on_insert(id: AccountId, weight: VoteWeight) -> Result<(), Self::Error>;
+
+	/// Hook for updating the vote weight of an id already in the list.
+	fn on_update(id: &AccountId, weight: VoteWeight);
+
+	/// Hook for removing an id from the list.
+	fn on_remove(id: &AccountId);
+
+	/// Regenerate this list from scratch. Returns the count of items inserted.
+	///
+	/// This should typically only be used at a runtime upgrade, to populate the list from the data
+	/// already in storage.
+	fn regenerate(
+		all: impl IntoIterator<Item = AccountId>,
+		weight_of: Box<dyn Fn(&AccountId) -> VoteWeight>,
+	) -> u32;
+
+	/// Remove all items from the list.
+	fn clear();
+
+	/// Sanity check the internal state of the list. Only meant for debug compilation.
+	fn sanity_check() -> Result<(), &'static str>;
+}
+
+/// Something that can provide the vote weight of an account.
+///
+/// This is the source of truth of the vote weights used by a [`SortedListProvider`].
+pub trait VoteWeightProvider<AccountId> {
+	/// Get the current vote weight of `who`.
+	fn vote_weight(who: &AccountId) -> VoteWeight;
+
+	/// Set the vote weight of `who`, for benchmarks and tests only.
+	#[cfg(any(feature = "runtime-benchmarks", test))]
+	fn set_vote_weight_of(_: &AccountId, _: VoteWeight) {}
+}
+
 #[cfg(feature = "std")]
 impl<AccountId, BlockNumber> ElectionProvider<AccountId, BlockNumber> for () {
 	type Error = &'static str;
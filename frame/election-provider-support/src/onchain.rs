@@ -17,7 +17,7 @@
 
 //! An implementation of [`ElectionProvider`] that does an on-chain sequential phragmen.
 
-use crate::{ElectionDataProvider, ElectionProvider};
+use crate::{DataProviderBounds, ElectionDataProvider, ElectionProvider};
 use sp_npos_elections::*;
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
 use frame_support::{traits::Get, weights::Weight};
@@ -72,8 +72,10 @@ impl<T: Config> ElectionProvider<T::AccountId, T::BlockNumber> for OnChainSequen
 	type DataProvider = T::DataProvider;
 
 	fn elect() -> Result<(Supports<T::AccountId>, Weight), Self::Error> {
-		let (voters, _) = Self::DataProvider::voters(None).map_err(Error::DataProvider)?;
-		let (targets, _) = Self::DataProvider::targets(None).map_err(Error::DataProvider)?;
+		let (voters, _) = Self::DataProvider::voters(DataProviderBounds::unbounded())
+			.map_err(Error::DataProvider)?;
+		let (targets, _) = Self::DataProvider::targets(DataProviderBounds::unbounded())
+			.map_err(Error::DataProvider)?;
 		let (desired_targets, _) =
 			Self::DataProvider::desired_targets().map_err(Error::DataProvider)?;
 
@@ -127,17 +129,20 @@ mod tests {
 		impl ElectionDataProvider<AccountId, BlockNumber> for DataProvider {
 			const MAXIMUM_VOTES_PER_VOTER: u32 = 2;
 			fn voters(
-				_: Option<usize>,
+				_: DataProviderBounds,
 			) -> data_provider::Result<(Vec<(AccountId, VoteWeight, Vec<AccountId>)>, Weight)> {
-				Ok((vec![(1, 10, vec![10, 20]), (2, 20, vec![30, 20]), (3, 30, vec![10, 30])], 0))
+				Ok((
+					vec![(1, 10, vec![10, 20]), (2, 20, vec![30, 20]), (3, 30, vec![10, 30])],
+					Weight::zero(),
+				))
 			}
 
-			fn targets(_: Option<usize>) -> data_provider::Result<(Vec<AccountId>, Weight)> {
-				Ok((vec![10, 20, 30], 0))
+			fn targets(_: DataProviderBounds) -> data_provider::Result<(Vec<AccountId>, Weight)> {
+				Ok((vec![10, 20, 30], Weight::zero()))
 			}
 
 			fn desired_targets() -> data_provider::Result<(u32, Weight)> {
-				Ok((2, 0))
+				Ok((2, Weight::zero()))
 			}
 
 			fn next_election_prediction(_: BlockNumber) -> BlockNumber {
@@ -171,7 +171,7 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, transactional};
 	use frame_system::pallet_prelude::*;
 	use super::*;
 
@@ -251,7 +251,7 @@ pub mod pallet {
 			if !term_duration.is_zero() && (n % term_duration).is_zero() {
 				Self::do_phragmen()
 			} else {
-				0
+				Weight::zero()
 			}
 		}
 	}
@@ -286,6 +286,7 @@ pub mod pallet {
 			.max(T::WeightInfo::vote_less(votes.len() as u32))
 			.max(T::WeightInfo::vote_equal(votes.len() as u32))
 		)]
+		#[transactional]
 		pub(crate) fn vote(
 			origin: OriginFor<T>,
 			votes: Vec<T::AccountId>,
@@ -332,12 +333,12 @@ pub mod pallet {
 
 			// Amount to be locked up.
 			let locked_stake = value.min(T::Currency::total_balance(&who));
-			T::Currency::set_lock(
+			T::Currency::try_set_lock(
 				T::PalletId::get(),
 				&who,
 				locked_stake,
 				WithdrawReasons::all(),
-			);
+			)?;
 
 			Voting::<T>::insert(&who, Voter { votes, deposit: new_deposit, stake: locked_stake });
 			Ok(None.into())
@@ -1119,7 +1120,7 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(Weight::from_ref_time(1024));
 	}
 
 	impl frame_system::Config for Test {
@@ -1150,6 +1151,7 @@ mod tests {
 
 	parameter_types! {
 		pub const ExistentialDeposit: u64 = 1;
+		pub const MaxLocks: u32 = 50;
 	}
 
 	impl pallet_balances::Config for Test {
@@ -1158,9 +1160,10 @@ mod tests {
 		type DustRemoval = ();
 		type ExistentialDeposit = ExistentialDeposit;
 		type AccountStore = frame_system::Pallet<Test>;
-		type MaxLocks = ();
+		type MaxLocks = MaxLocks;
 		type MaxReserves = ();
 		type ReserveIdentifier = [u8; 8];
+		type HoldsAndFreezes = ();
 		type WeightInfo = ();
 	}
 
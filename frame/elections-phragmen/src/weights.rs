@@ -62,169 +62,169 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vote_equal(v: u32, ) -> Weight {
-		(45_157_000 as Weight)
+		Weight::from_ref_time(45_157_000 as u64)
 			// Standard Error: 6_000
-			.saturating_add((399_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(399_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn vote_more(v: u32, ) -> Weight {
-		(69_738_000 as Weight)
+		Weight::from_ref_time(69_738_000 as u64)
 			// Standard Error: 14_000
-			.saturating_add((450_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(450_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn vote_less(v: u32, ) -> Weight {
-		(73_955_000 as Weight)
+		Weight::from_ref_time(73_955_000 as u64)
 			// Standard Error: 38_000
-			.saturating_add((227_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(227_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn remove_voter() -> Weight {
-		(68_398_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(68_398_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn submit_candidacy(c: u32, ) -> Weight {
-		(59_291_000 as Weight)
+		Weight::from_ref_time(59_291_000 as u64)
 			// Standard Error: 2_000
-			.saturating_add((412_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(412_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn renounce_candidacy_candidate(c: u32, ) -> Weight {
-		(55_026_000 as Weight)
+		Weight::from_ref_time(55_026_000 as u64)
 			// Standard Error: 2_000
-			.saturating_add((207_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(207_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn renounce_candidacy_members() -> Weight {
-		(77_840_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(77_840_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	fn renounce_candidacy_runners_up() -> Weight {
-		(54_559_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(54_559_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	fn remove_member_with_replacement() -> Weight {
-		(84_311_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+		Weight::from_ref_time(84_311_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	fn remove_member_wrong_refund() -> Weight {
-		(7_677_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(7_677_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
 	}
 	fn clean_defunct_voters(v: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 55_000
-			.saturating_add((114_815_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(114_815_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 53_000
-			.saturating_add((49_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
-			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(Weight::from_ref_time(49_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(v as u64)))
 	}
 	fn election_phragmen(c: u32, v: u32, e: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 1_940_000
-			.saturating_add((43_557_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(43_557_000 as u64).saturating_mul(c as u64))
 			// Standard Error: 807_000
-			.saturating_add((65_849_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(65_849_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 55_000
-			.saturating_add((4_206_000 as Weight).saturating_mul(e as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(Weight::from_ref_time(4_206_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn vote_equal(v: u32, ) -> Weight {
-		(45_157_000 as Weight)
+		Weight::from_ref_time(45_157_000 as u64)
 			// Standard Error: 6_000
-			.saturating_add((399_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(399_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn vote_more(v: u32, ) -> Weight {
-		(69_738_000 as Weight)
+		Weight::from_ref_time(69_738_000 as u64)
 			// Standard Error: 14_000
-			.saturating_add((450_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(450_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn vote_less(v: u32, ) -> Weight {
-		(73_955_000 as Weight)
+		Weight::from_ref_time(73_955_000 as u64)
 			// Standard Error: 38_000
-			.saturating_add((227_000 as Weight).saturating_mul(v as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(227_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn remove_voter() -> Weight {
-		(68_398_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(68_398_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn submit_candidacy(c: u32, ) -> Weight {
-		(59_291_000 as Weight)
+		Weight::from_ref_time(59_291_000 as u64)
 			// Standard Error: 2_000
-			.saturating_add((412_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(412_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn renounce_candidacy_candidate(c: u32, ) -> Weight {
-		(55_026_000 as Weight)
+		Weight::from_ref_time(55_026_000 as u64)
 			// Standard Error: 2_000
-			.saturating_add((207_000 as Weight).saturating_mul(c as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(Weight::from_ref_time(207_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn renounce_candidacy_members() -> Weight {
-		(77_840_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		Weight::from_ref_time(77_840_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	fn renounce_candidacy_runners_up() -> Weight {
-		(54_559_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(54_559_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	fn remove_member_with_replacement() -> Weight {
-		(84_311_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+		Weight::from_ref_time(84_311_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	fn remove_member_wrong_refund() -> Weight {
-		(7_677_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(7_677_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 	}
 	fn clean_defunct_voters(v: u32, d: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 55_000
-			.saturating_add((114_815_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(114_815_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 53_000
-			.saturating_add((49_000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
-			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(Weight::from_ref_time(49_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(v as u64)))
 	}
 	fn election_phragmen(c: u32, v: u32, e: u32, ) -> Weight {
-		(0 as Weight)
+		Weight::from_ref_time(0 as u64)
 			// Standard Error: 1_940_000
-			.saturating_add((43_557_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(43_557_000 as u64).saturating_mul(c as u64))
 			// Standard Error: 807_000
-			.saturating_add((65_849_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(Weight::from_ref_time(65_849_000 as u64).saturating_mul(v as u64))
 			// Standard Error: 55_000
-			.saturating_add((4_206_000 as Weight).saturating_mul(e as Weight))
-			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(Weight::from_ref_time(4_206_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
 	}
 }
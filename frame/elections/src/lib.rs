@@ -700,7 +700,7 @@ decl_module! {
 				print("Guru meditation");
 				print(e);
 			}
-			0
+			Weight::zero()
 		}
 	}
 }
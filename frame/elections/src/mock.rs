@@ -33,7 +33,9 @@ use crate as elections;
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -68,6 +70,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = Event;
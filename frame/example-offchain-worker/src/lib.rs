@@ -36,7 +36,9 @@
 //! Offchain Worker (OCW) will be triggered after every block, fetch the current price
 //! and prepare either signed or unsigned transaction to feed the result back on chain.
 //! The on-chain logic will simply aggregate the results and store last `64` values to compute
-//! the average price.
+//! the average and median price.
+//! A lock in the Local Storage makes sure that workers running concurrently don't fetch
+//! the price and send transactions at the same time.
 //! Additional logic in OCW is put in place to prevent spamming the network with both signed
 //! and unsigned transactions, and custom `UnsignedValidator` makes sure that there is only
 //! one unsigned transaction floating in the network.
@@ -53,7 +55,11 @@ use frame_support::traits::Get;
 use sp_core::crypto::KeyTypeId;
 use sp_runtime::{
 	RuntimeDebug,
-	offchain::{http, Duration, storage::StorageValueRef},
+	offchain::{
+		http, Duration,
+		storage::StorageValueRef,
+		storage_lock::{BlockAndTime, StorageLock},
+	},
 	traits::Zero,
 	transaction_validity::{InvalidTransaction, ValidTransaction, TransactionValidity},
 };
@@ -73,6 +79,12 @@ mod tests;
 /// The keys can be inserted manually via RPC (see `author_insertKey`).
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"btc!");
 
+/// The number of blocks after which the lock of the offchain worker expires.
+const LOCK_BLOCK_EXPIRATION: u32 = 3;
+
+/// The number of milliseconds after which the lock of the offchain worker expires.
+const LOCK_TIMEOUT_EXPIRATION: u64 = 4_000;
+
 /// Based on the above `KeyTypeId` we need to generate a pallet-specific crypto type wrappers.
 /// We can use from supported crypto kinds (`sr25519`, `ed25519` and `ecdsa`) and augment
 /// the types with this pallet-specific identifier.
@@ -171,7 +183,28 @@ pub mod pallet {
 			// Here we call a helper function to calculate current average price.
 			// This function reads storage entries of the current state.
 			let average: Option<u32> = Self::average_price();
-			log::debug!("Current price: {:?}", average);
+			let median: Option<u32> = Self::median_price();
+			log::debug!("Current price: {:?} (median: {:?})", average, median);
+
+			// Workers of subsequent blocks may run concurrently, for instance when fetching the
+			// price takes longer than a block. We acquire a lock in the Local Storage, so that
+			// only one of them at a time fetches the price and sends a transaction.
+			// The lock expires after `LOCK_BLOCK_EXPIRATION` blocks and `LOCK_TIMEOUT_EXPIRATION`
+			// milliseconds, in case the worker holding it never finishes. Otherwise it is released
+			// when the guard is dropped, at the end of this function.
+			let mut lock =
+				StorageLock::<BlockAndTime<system::Pallet<T>>>::with_block_and_time_deadline(
+					b"example_ocw::lock",
+					LOCK_BLOCK_EXPIRATION,
+					Duration::from_millis(LOCK_TIMEOUT_EXPIRATION),
+				);
+			let _guard = match lock.try_lock() {
+				Ok(guard) => guard,
+				Err(_) => {
+					log::info!("Another offchain worker holds the lock, skipping this run.");
+					return
+				},
+			};
 
 			// For this example we are going to send both signed and unsigned transactions
 			// depending on the block number.
@@ -643,7 +676,9 @@ impl<T: Config> Pallet<T> {
 
 		let average = Self::average_price()
 			.expect("The average is not empty, because it was just mutated; qed");
-		log::info!("Current average price is: {}", average);
+		let median = Self::median_price()
+			.expect("The median is not empty, because it was just mutated; qed");
+		log::info!("Current average price is: {}, median price is: {}", average, median);
 		// here we are raising the NewPrice event
 		Self::deposit_event(Event::NewPrice(price, who));
 	}
@@ -658,6 +693,25 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Calculate current median price.
+	///
+	/// Unlike the average, the median is not skewed by a few outliers, which makes it a better
+	/// way to aggregate prices submitted by many, possibly faulty, workers.
+	fn median_price() -> Option<u32> {
+		let mut prices = <Prices<T>>::get();
+		if prices.is_empty() {
+			return None
+		}
+
+		prices.sort_unstable();
+		let mid = prices.len() / 2;
+		if prices.len() % 2 == 0 {
+			Some(((prices[mid - 1] as u64 + prices[mid] as u64) / 2) as u32)
+		} else {
+			Some(prices[mid])
+		}
+	}
+
 	fn validate_transaction_parameters(
 		block_number: &T::BlockNumber,
 		new_price: &u32,
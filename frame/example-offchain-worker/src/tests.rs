@@ -57,7 +57,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -141,6 +143,25 @@ fn it_aggregates_the_price() {
 	});
 }
 
+#[test]
+fn it_computes_the_median_price() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_eq!(Example::median_price(), None);
+
+		assert_ok!(Example::submit_price(Origin::signed(Default::default()), 30));
+		assert_eq!(Example::median_price(), Some(30));
+
+		// The median of an even number of prices is the average of the two middle ones.
+		assert_ok!(Example::submit_price(Origin::signed(Default::default()), 10));
+		assert_eq!(Example::median_price(), Some(20));
+
+		// Unlike the average, the median is not skewed by outliers.
+		assert_ok!(Example::submit_price(Origin::signed(Default::default()), 1_000));
+		assert_eq!(Example::median_price(), Some(30));
+		assert_eq!(Example::average_price(), Some(346));
+	});
+}
+
 #[test]
 fn should_make_http_call_and_parse_result() {
 	let (offchain, state) = testing::TestOffchainExt::new();
@@ -51,7 +51,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -85,6 +87,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = Event;
@@ -181,7 +184,7 @@ fn weights_work() {
 	let default_call = <pallet_example::Call<Test>>::accumulate_dummy(10);
 	let info1 = default_call.get_dispatch_info();
 	// aka. `let info = <Call<Test> as GetDispatchInfo>::get_dispatch_info(&default_call);`
-	assert!(info1.weight > 0);
+	assert!(!info1.weight.is_zero());
 
 
 	// `set_dummy` is simpler than `accumulate_dummy`, and the weight
@@ -107,31 +107,49 @@
 //! impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 //!     fn on_runtime_upgrade() -> frame_support::weights::Weight {
 //!         // Do whatever you want.
-//!         0
+//!         frame_support::weights::Weight::zero()
 //!     }
 //! }
 //!
 //! pub type Executive = executive::Executive<Runtime, Block, Context, Runtime, AllPallets, CustomOnRuntimeUpgrade>;
 //! ```
+//!
+//! ### Multi-block migrations
+//!
+//! Migrations which are too heavy for a single block can be executed over multiple blocks by a
+//! [`MultiStepMigrator`], e.g. `pallet-migrations`, given as the last generic parameter. Its
+//! migrations are stepped at the beginning of every block, after `on_initialize`, and all the
+//! extrinsics which are not mandatory are rejected with `InvalidTransaction::MigrationsOngoing`
+//! until they are complete. The calls exempted by the migrator, which must include the runtime
+//! upgrades, are always applied so that a failed migration can be fixed.
+//!
+//! ### Mandatory dispatches
+//!
+//! Extrinsics with a `DispatchClass::Mandatory` dispatch are only allowed as inherents, at the
+//! front of the block as checked by `EnsureInherentsAreFirst` which is implemented by
+//! `construct_runtime!`. They are rejected from the transaction pool, and a block containing one
+//! after the inherents, or one whose dispatch fails, is invalid.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::{prelude::*, marker::PhantomData};
 use frame_support::{
-	weights::{GetDispatchInfo, DispatchInfo, DispatchClass},
+	weights::{GetDispatchInfo, DispatchInfo, DispatchClass, Weight},
 	traits::{
-		OnInitialize, OnIdle, OnFinalize, OnRuntimeUpgrade, OffchainWorker, ExecuteBlock,
-		EnsureInherentsAreFirst,
+		OnInitialize, OnInitializeMetered, OnIdle, OnFinalize, OnRuntimeUpgrade, OffchainWorker,
+		ExecuteBlock, EnsureInherentsAreFirst, ExtrinsicCall, MultiStepMigrator, Get,
 	},
 	dispatch::PostDispatchInfo,
 };
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::TryState;
 use sp_runtime::{
-	generic::Digest, ApplyExtrinsicResult,
+	generic::Digest, ApplyExtrinsicResult, Perbill,
 	traits::{
 		self, Header, Zero, One, Checkable, Applyable, CheckEqual, ValidateUnsigned, NumberFor,
 		Dispatchable, Saturating,
 	},
-	transaction_validity::{TransactionValidity, TransactionSource},
+	transaction_validity::{TransactionValidity, TransactionSource, InvalidTransaction},
 };
 use codec::{Codec, Encode};
 use frame_system::DigestOf;
@@ -151,10 +169,40 @@ pub type OriginOf<E, C> = <CallOf<E, C> as Dispatchable>::Origin;
 /// - `OnRuntimeUpgrade`: Custom logic that should be called after a runtime upgrade. Modules are
 ///                       already called by `AllPallets`. It will be called before all modules will
 ///                       be called.
-pub struct Executive<System, Block, Context, UnsignedValidator, AllPallets, OnRuntimeUpgrade = ()>(
-	PhantomData<(System, Block, Context, UnsignedValidator, AllPallets, OnRuntimeUpgrade)>
+/// - `MultiStepMigrator`: The driver of the multi-block migrations, e.g. `pallet-migrations`. Its
+///                        migrations are stepped at the beginning of every block and only the
+///                        mandatory extrinsics and the exempt calls are applied while they are
+///                        ongoing.
+/// - `MaxHookWeightShare`: The share of the maximum block weight above which the weight consumed by
+///                         the `on_initialize` hook of a single pallet is logged as a warning.
+pub struct Executive<
+	System,
+	Block,
+	Context,
+	UnsignedValidator,
+	AllPallets,
+	OnRuntimeUpgrade = (),
+	MultiStepMigrator = (),
+	MaxHookWeightShare = DefaultMaxHookWeightShare,
+>(
+	PhantomData<(
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPallets,
+		OnRuntimeUpgrade,
+		MultiStepMigrator,
+		MaxHookWeightShare,
+	)>
 );
 
+frame_support::parameter_types! {
+	/// The default share of the maximum block weight above which the `on_initialize` hook of a
+	/// pallet is reported as overweight.
+	pub const DefaultMaxHookWeightShare: Perbill = Perbill::from_percent(25);
+}
+
 impl<
 	System: frame_system::Config + EnsureInherentsAreFirst<Block>,
 	Block: traits::Block<Header=System::Header, Hash=System::Hash>,
@@ -163,14 +211,25 @@ impl<
 	AllPallets:
 		OnRuntimeUpgrade +
 		OnInitialize<System::BlockNumber> +
+		OnInitializeMetered<System::BlockNumber> +
 		OnIdle<System::BlockNumber> +
 		OnFinalize<System::BlockNumber> +
 		OffchainWorker<System::BlockNumber>,
 	COnRuntimeUpgrade: OnRuntimeUpgrade,
-> ExecuteBlock<Block> for
-	Executive<System, Block, Context, UnsignedValidator, AllPallets, COnRuntimeUpgrade>
+	CMultiStepMigrator: MultiStepMigrator<<Block::Extrinsic as traits::Extrinsic>::Call>,
+	CMaxHookWeightShare: Get<Perbill>,
+> ExecuteBlock<Block> for Executive<
+	System,
+	Block,
+	Context,
+	UnsignedValidator,
+	AllPallets,
+	COnRuntimeUpgrade,
+	CMultiStepMigrator,
+	CMaxHookWeightShare,
+>
 where
-	Block::Extrinsic: Checkable<Context> + Codec,
+	Block::Extrinsic: Checkable<Context> + ExtrinsicCall + Codec,
 	CheckedOf<Block::Extrinsic, Context>:
 		Applyable +
 		GetDispatchInfo,
@@ -179,10 +238,20 @@ where
 	UnsignedValidator: ValidateUnsigned<Call=CallOf<Block::Extrinsic, Context>>,
 {
 	fn execute_block(block: Block) {
-		Executive::<System, Block, Context, UnsignedValidator, AllPallets>::execute_block(block);
+		Executive::<
+			System,
+			Block,
+			Context,
+			UnsignedValidator,
+			AllPallets,
+			COnRuntimeUpgrade,
+			CMultiStepMigrator,
+			CMaxHookWeightShare,
+		>::execute_block(block);
 	}
 }
 
+#[cfg(feature = "try-runtime")]
 impl<
 		System: frame_system::Config + EnsureInherentsAreFirst<Block>,
 		Block: traits::Block<Header = System::Header, Hash = System::Hash>,
@@ -190,35 +259,36 @@ impl<
 		UnsignedValidator,
 		AllPallets: OnRuntimeUpgrade
 			+ OnInitialize<System::BlockNumber>
+			+ OnInitializeMetered<System::BlockNumber>
 			+ OnIdle<System::BlockNumber>
 			+ OnFinalize<System::BlockNumber>
-			+ OffchainWorker<System::BlockNumber>,
+			+ OffchainWorker<System::BlockNumber>
+			+ TryState<System::BlockNumber>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPallets, COnRuntimeUpgrade>
+		CMultiStepMigrator: MultiStepMigrator<<Block::Extrinsic as traits::Extrinsic>::Call>,
+		CMaxHookWeightShare: Get<Perbill>,
+	> Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPallets,
+		COnRuntimeUpgrade,
+		CMultiStepMigrator,
+		CMaxHookWeightShare,
+	>
 where
-	Block::Extrinsic: Checkable<Context> + Codec,
+	Block::Extrinsic: Checkable<Context> + ExtrinsicCall + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
 	CallOf<Block::Extrinsic, Context>:
 		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 	OriginOf<Block::Extrinsic, Context>: From<Option<System::AccountId>>,
 	UnsignedValidator: ValidateUnsigned<Call = CallOf<Block::Extrinsic, Context>>,
 {
-	/// Execute all `OnRuntimeUpgrade` of this runtime, and return the aggregate weight.
-	pub fn execute_on_runtime_upgrade() -> frame_support::weights::Weight {
-		let mut weight = 0;
-		weight = weight.saturating_add(COnRuntimeUpgrade::on_runtime_upgrade());
-		weight = weight.saturating_add(
-			<frame_system::Pallet<System> as OnRuntimeUpgrade>::on_runtime_upgrade(),
-		);
-		weight = weight.saturating_add(<AllPallets as OnRuntimeUpgrade>::on_runtime_upgrade());
-
-		weight
-	}
-
-	/// Execute all `OnRuntimeUpgrade` of this runtime, including the pre and post migration checks.
+	/// Execute all `OnRuntimeUpgrade` of this runtime, including the pre and post migration checks,
+	/// followed by the `TryState` checks of all pallets.
 	///
 	/// This should only be used for testing.
-	#[cfg(feature = "try-runtime")]
 	pub fn try_runtime_upgrade() -> Result<frame_support::weights::Weight, &'static str> {
 		<
 			(frame_system::Pallet::<System>, COnRuntimeUpgrade, AllPallets)
@@ -234,9 +304,67 @@ where
 			OnRuntimeUpgrade
 		>::post_upgrade()?;
 
+		Self::try_state(<frame_system::Pallet<System>>::block_number())?;
+
 		Ok(weight)
 	}
 
+	/// Execute the `TryState` checks of all pallets, including `frame_system`, at block `n`.
+	///
+	/// This should only be used for testing.
+	pub fn try_state(n: System::BlockNumber) -> Result<(), &'static str> {
+		<
+			(frame_system::Pallet::<System>, AllPallets)
+			as
+			TryState<System::BlockNumber>
+		>::try_state(n)
+	}
+}
+
+impl<
+		System: frame_system::Config + EnsureInherentsAreFirst<Block>,
+		Block: traits::Block<Header = System::Header, Hash = System::Hash>,
+		Context: Default,
+		UnsignedValidator,
+		AllPallets: OnRuntimeUpgrade
+			+ OnInitialize<System::BlockNumber>
+			+ OnInitializeMetered<System::BlockNumber>
+			+ OnIdle<System::BlockNumber>
+			+ OnFinalize<System::BlockNumber>
+			+ OffchainWorker<System::BlockNumber>,
+		COnRuntimeUpgrade: OnRuntimeUpgrade,
+		CMultiStepMigrator: MultiStepMigrator<<Block::Extrinsic as traits::Extrinsic>::Call>,
+		CMaxHookWeightShare: Get<Perbill>,
+	> Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPallets,
+		COnRuntimeUpgrade,
+		CMultiStepMigrator,
+		CMaxHookWeightShare,
+	>
+where
+	Block::Extrinsic: Checkable<Context> + ExtrinsicCall + Codec,
+	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
+	CallOf<Block::Extrinsic, Context>:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	OriginOf<Block::Extrinsic, Context>: From<Option<System::AccountId>>,
+	UnsignedValidator: ValidateUnsigned<Call = CallOf<Block::Extrinsic, Context>>,
+{
+	/// Execute all `OnRuntimeUpgrade` of this runtime, and return the aggregate weight.
+	pub fn execute_on_runtime_upgrade() -> frame_support::weights::Weight {
+		let mut weight = Weight::zero();
+		weight = weight.saturating_add(COnRuntimeUpgrade::on_runtime_upgrade());
+		weight = weight.saturating_add(
+			<frame_system::Pallet<System> as OnRuntimeUpgrade>::on_runtime_upgrade(),
+		);
+		weight = weight.saturating_add(<AllPallets as OnRuntimeUpgrade>::on_runtime_upgrade());
+
+		weight
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize_block(header: &System::Header) {
 		sp_io::init_tracing();
@@ -264,7 +392,7 @@ where
 		parent_hash: &System::Hash,
 		digest: &Digest<System::Hash>,
 	) {
-		let mut weight = 0;
+		let mut weight = Weight::zero();
 		if Self::runtime_upgraded() {
 			weight = weight.saturating_add(Self::execute_on_runtime_upgrade());
 		}
@@ -277,9 +405,14 @@ where
 		weight = weight.saturating_add(
 			<frame_system::Pallet<System> as OnInitialize<System::BlockNumber>>::on_initialize(*block_number)
 		);
-		weight = weight.saturating_add(
-			<AllPallets as OnInitialize<System::BlockNumber>>::on_initialize(*block_number)
-		);
+		// The weight of the pallets is registered for each pallet on its own.
+		Self::on_initialize_metered(*block_number, |_name, pallet_weight| {
+			<frame_system::Pallet::<System>>::register_extra_weight_unchecked(
+				pallet_weight,
+				DispatchClass::Mandatory,
+			);
+		});
+		weight = weight.saturating_add(CMultiStepMigrator::step());
 		weight = weight.saturating_add(
 			<System::BlockWeights as frame_support::traits::Get<_>>::get().base_block
 		);
@@ -288,6 +421,36 @@ where
 		frame_system::Pallet::<System>::note_finished_initialize();
 	}
 
+	/// Execute the `on_initialize` hooks of all the pallets but `frame_system`, calling `report`
+	/// with the name and the weight of each pallet.
+	///
+	/// A warning is logged for every pallet whose hook consumed more than `MaxHookWeightShare` of
+	/// the maximum block weight. This can also be used by benchmarking to find the pallets with
+	/// the heaviest hooks.
+	pub fn on_initialize_metered(
+		block_number: System::BlockNumber,
+		mut report: impl FnMut(&'static str, Weight),
+	) -> Weight {
+		let max_block = <System::BlockWeights as Get<_>>::get().max_block;
+		let max_pallet_weight = CMaxHookWeightShare::get() * max_block;
+
+		<AllPallets as OnInitializeMetered<System::BlockNumber>>::on_initialize_metered(
+			block_number,
+			&mut |name, weight| {
+				if weight.any_gt(&max_pallet_weight) {
+					frame_support::log::warn!(
+						target: "runtime::executive",
+						"on_initialize of {} consumed {:?} weight, more than {:?} of the block",
+						name,
+						weight,
+						CMaxHookWeightShare::get(),
+					);
+				}
+				report(name, weight);
+			},
+		)
+	}
+
 	/// Returns if the runtime was upgraded since the last time this function was called.
 	fn runtime_upgraded() -> bool {
 		let last = frame_system::LastRuntimeUpgrade::<System>::get();
@@ -303,7 +466,8 @@ where
 		}
 	}
 
-	fn initial_checks(block: &Block) {
+	/// Returns the number of inherents at the front of the block.
+	fn initial_checks(block: &Block) -> u32 {
 		sp_tracing::enter_span!(sp_tracing::Level::TRACE, "initial_checks");
 		let header = block.header();
 
@@ -315,8 +479,9 @@ where
 			"Parent hash should be valid.",
 		);
 
-		if let Err(i) = System::ensure_inherents_are_first(block) {
-			panic!("Invalid inherent position for extrinsic at index {}", i);
+		match System::ensure_inherents_are_first(block) {
+			Ok(num_inherents) => num_inherents,
+			Err(i) => panic!("Invalid inherent position for extrinsic at index {}", i),
 		}
 	}
 
@@ -329,13 +494,17 @@ where
 			Self::initialize_block(block.header());
 
 			// any initial checks
-			Self::initial_checks(&block);
+			let num_inherents = Self::initial_checks(&block);
 
 			let signature_batching = sp_runtime::SignatureBatching::start();
 
 			// execute extrinsics
 			let (header, extrinsics) = block.deconstruct();
-			Self::execute_extrinsics_with_book_keeping(extrinsics, *header.number());
+			Self::execute_extrinsics_with_book_keeping(
+				extrinsics,
+				num_inherents as usize,
+				*header.number(),
+			);
 
 			if !signature_batching.verify() {
 				panic!("Signature verification failed.");
@@ -347,13 +516,23 @@ where
 	}
 
 	/// Execute given extrinsics and take care of post-extrinsics book-keeping.
+	///
+	/// Only the first `num_inherents` extrinsics, i.e. the inherents, may have mandatory
+	/// dispatches.
 	fn execute_extrinsics_with_book_keeping(
 		extrinsics: Vec<Block::Extrinsic>,
+		num_inherents: usize,
 		block_number: NumberFor<Block>,
 	) {
-		extrinsics.into_iter().for_each(|e| if let Err(e) = Self::apply_extrinsic(e) {
-			let err: &'static str = e.into();
-			panic!("{}", err)
+		extrinsics.into_iter().enumerate().for_each(|(i, e)| {
+			sp_io::init_tracing();
+			let encoded = e.encode();
+			let encoded_len = encoded.len();
+			let is_inherent = i < num_inherents;
+			if let Err(e) = Self::apply_extrinsic_with_len(e, encoded_len, encoded, is_inherent) {
+				let err: &'static str = e.into();
+				panic!("{}", err)
+			}
 		});
 
 		// post-extrinsics book-keeping
@@ -380,7 +559,7 @@ where
 		let max_weight =  <System::BlockWeights as frame_support::traits::Get<_>>::get().max_block;
 		let mut remaining_weight = max_weight.saturating_sub(weight.total());
 
-		if remaining_weight > 0 {
+		if !remaining_weight.is_zero() {
 			let mut used_weight =
 				<frame_system::Pallet<System> as OnIdle<System::BlockNumber>>::on_idle(
 					block_number,
@@ -407,22 +586,35 @@ where
 		sp_io::init_tracing();
 		let encoded = uxt.encode();
 		let encoded_len = encoded.len();
-		Self::apply_extrinsic_with_len(uxt, encoded_len, encoded)
+		// The position of the inherents is checked when the block is executed.
+		Self::apply_extrinsic_with_len(uxt, encoded_len, encoded, true)
 	}
 
 	/// Actually apply an extrinsic given its `encoded_len`; this doesn't note its hash.
+	///
+	/// Mandatory dispatches are rejected unless `mandatory_allowed`, i.e. unless the extrinsic is
+	/// an inherent.
 	fn apply_extrinsic_with_len(
 		uxt: Block::Extrinsic,
 		encoded_len: usize,
 		to_note: Vec<u8>,
+		mandatory_allowed: bool,
 	) -> ApplyExtrinsicResult {
 		sp_tracing::enter_span!(
 			sp_tracing::info_span!("apply_extrinsic",
 				ext=?sp_core::hexdisplay::HexDisplay::from(&uxt.encode()))
 		);
+		let exempt_from_migrations = CMultiStepMigrator::is_exempt(uxt.call());
 		// Verify that the signature is good.
 		let xt = uxt.check(&Default::default())?;
 
+		// Decode parameters
+		let dispatch_info = xt.get_dispatch_info();
+		Self::ensure_no_ongoing_migrations(exempt_from_migrations, &dispatch_info)?;
+		if !mandatory_allowed && dispatch_info.class == DispatchClass::Mandatory {
+			return Err(InvalidTransaction::MandatoryDispatch.into())
+		}
+
 		// We don't need to make sure to `note_extrinsic` only after we know it's going to be
 		// executed to prevent it from leaking in storage since at this point, it will either
 		// execute or panic (and revert storage changes).
@@ -430,15 +622,36 @@ where
 
 		// AUDIT: Under no circumstances may this function panic from here onwards.
 
-		// Decode parameters and dispatch
-		let dispatch_info = xt.get_dispatch_info();
+		// Dispatch
 		let r = Applyable::apply::<UnsignedValidator>(xt, &dispatch_info, encoded_len)?;
 
+		// Mandatory dispatches are not validated for being overweight, so they are not allowed to
+		// fail, otherwise a block producer could fill the block with useless mandatory extrinsics.
+		if r.is_err() && dispatch_info.class == DispatchClass::Mandatory {
+			return Err(InvalidTransaction::BadMandatory.into())
+		}
+
 		<frame_system::Pallet<System>>::note_applied_extrinsic(&r, dispatch_info);
 
 		Ok(r.map(|_| ()).map_err(|e| e.error))
 	}
 
+	/// Only mandatory extrinsics and the calls exempted by the `MultiStepMigrator`, e.g. runtime
+	/// upgrades, are allowed while multi-block migrations are ongoing.
+	fn ensure_no_ongoing_migrations(
+		exempt: bool,
+		dispatch_info: &DispatchInfo,
+	) -> Result<(), InvalidTransaction> {
+		if dispatch_info.class != DispatchClass::Mandatory &&
+			!exempt &&
+			CMultiStepMigrator::ongoing()
+		{
+			Err(InvalidTransaction::MigrationsOngoing)
+		} else {
+			Ok(())
+		}
+	}
+
 	fn final_checks(header: &System::Header) {
 		sp_tracing::enter_span!(sp_tracing::Level::TRACE, "final_checks");
 		// remove temporaries
@@ -484,6 +697,7 @@ where
 			uxt.using_encoded(|d| d.len())
 		};
 
+		let exempt_from_migrations = CMultiStepMigrator::is_exempt(uxt.call());
 		let xt = within_span!{ sp_tracing::Level::TRACE, "check";
 			uxt.check(&Default::default())
 		}?;
@@ -491,6 +705,11 @@ where
 		let dispatch_info = within_span!{ sp_tracing::Level::TRACE, "dispatch_info";
 			xt.get_dispatch_info()
 		};
+		Self::ensure_no_ongoing_migrations(exempt_from_migrations, &dispatch_info)?;
+		// Only inherents may have mandatory dispatches, and inherents are never validated.
+		if dispatch_info.class == DispatchClass::Mandatory {
+			return Err(InvalidTransaction::MandatoryDispatch.into())
+		}
 
 		within_span! {
 			sp_tracing::Level::TRACE, "validate";
@@ -526,6 +745,7 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use codec::Decode;
 	use sp_core::H256;
 	use sp_runtime::{
 		generic::{Era, DigestItem}, DispatchError, testing::{Digest, Header, Block},
@@ -586,16 +806,22 @@ mod tests {
 					let _ = frame_system::ensure_none(origin)?;
 				}
 
+				#[weight = (0, DispatchClass::Mandatory)]
+				fn mandatory_call(origin, succeed: bool) {
+					frame_system::ensure_none(origin)?;
+					frame_support::ensure!(succeed, "Mandatory call failed");
+				}
+
 				// module hooks.
 				// one with block number arg and one without
 				fn on_initialize(n: T::BlockNumber) -> Weight {
 					println!("on_initialize({})", n);
-					175
+					Weight::from_ref_time(175)
 				}
 
 				fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
-					println!("on_idle{}, {})", n, remaining_weight);
-					175
+					println!("on_idle{}, {:?})", n, remaining_weight);
+					Weight::from_ref_time(175)
 				}
 
 				fn on_finalize() {
@@ -604,7 +830,7 @@ mod tests {
 
 				fn on_runtime_upgrade() -> Weight {
 					sp_io::storage::set(super::TEST_KEY, "module".as_bytes());
-					200
+					Weight::from_ref_time(200)
 				}
 
 				fn offchain_worker(n: T::BlockNumber) {
@@ -653,6 +879,7 @@ mod tests {
 				match call {
 					Call::allowed_unsigned(..) => Ok(()),
 					Call::inherent_call(..) => Ok(()),
+					Call::mandatory_call(..) => Ok(()),
 					_ => Err(UnknownTransaction::NoUnsignedValidator.into()),
 				}
 			}
@@ -675,13 +902,20 @@ mod tests {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
 			frame_system::limits::BlockWeights::builder()
-				.base_block(10)
-				.for_class(DispatchClass::all(), |weights| weights.base_extrinsic = 5)
-				.for_class(DispatchClass::non_mandatory(), |weights| weights.max_total = 1024.into())
+				.base_block(Weight::from_ref_time(10))
+				.for_class(
+					DispatchClass::all(),
+					|weights| weights.base_extrinsic = Weight::from_ref_time(5),
+				)
+				.for_class(
+					DispatchClass::non_mandatory(),
+					|weights| weights.max_total = Weight::from_ref_time(1024).into(),
+				)
 				.build_or_panic();
 		pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight {
 			read: 10,
 			write: 100,
+			proof_size: 0,
 		};
 	}
 	impl frame_system::Config for Runtime {
@@ -723,6 +957,7 @@ mod tests {
 		type MaxLocks = ();
 		type MaxReserves = ();
 		type ReserveIdentifier = [u8; 8];
+		type HoldsAndFreezes = ();
 		type WeightInfo = ();
 	}
 
@@ -767,7 +1002,36 @@ mod tests {
 		fn on_runtime_upgrade() -> Weight {
 			sp_io::storage::set(TEST_KEY, "custom_upgrade".as_bytes());
 			sp_io::storage::set(CUSTOM_ON_RUNTIME_KEY, &true.encode());
-			100
+			Weight::from_ref_time(100)
+		}
+	}
+
+	const MIGRATION_STEPS_KEY: &[u8] = &*b":migration:steps";
+
+	/// Migrations which are complete after the number of steps stored at `MIGRATION_STEPS_KEY`.
+	///
+	/// The runtime upgrades are exempt.
+	struct MockedMigrator;
+	impl MultiStepMigrator<Call> for MockedMigrator {
+		fn ongoing() -> bool {
+			sp_io::storage::get(MIGRATION_STEPS_KEY).is_some()
+		}
+
+		fn is_exempt(call: &Call) -> bool {
+			matches!(call, Call::System(SystemCall::set_code(..)))
+		}
+
+		fn step() -> Weight {
+			let steps = match sp_io::storage::get(MIGRATION_STEPS_KEY) {
+				Some(steps) => u32::decode(&mut &steps[..]).unwrap(),
+				None => return Weight::zero(),
+			};
+			if steps > 1 {
+				sp_io::storage::set(MIGRATION_STEPS_KEY, &(steps - 1).encode());
+			} else {
+				sp_io::storage::clear(MIGRATION_STEPS_KEY);
+			}
+			Weight::from_ref_time(10)
 		}
 	}
 
@@ -777,7 +1041,8 @@ mod tests {
 		ChainContext<Runtime>,
 		Runtime,
 		AllPallets,
-		CustomOnRuntimeUpgrade
+		CustomOnRuntimeUpgrade,
+		MockedMigrator,
 	>;
 
 	fn extra(nonce: u64, fee: Balance) -> SignedExtra {
@@ -904,13 +1169,13 @@ mod tests {
 		// given: TestXt uses the encoded len as fixed Len:
 		let xt = TestXt::new(Call::Balances(BalancesCall::transfer(33, 0)), sign_extra(1, 0, 0));
 		let encoded = xt.encode();
-		let encoded_len = encoded.len() as Weight;
+		let encoded_len = encoded.len() as u64;
 		// on_initialize weight + base block execution weight
 		let block_weights = <Runtime as frame_system::Config>::BlockWeights::get();
-		let base_block_weight = 175 + block_weights.base_block;
+		let base_block_weight = Weight::from_ref_time(175) + block_weights.base_block;
 		let limit = block_weights.get(DispatchClass::Normal).max_total.unwrap()
 			- base_block_weight;
-		let num_to_exhaust_block = limit / (encoded_len + 5);
+		let num_to_exhaust_block = limit.ref_time() / (encoded_len + 5);
 		t.execute_with(|| {
 			Executive::initialize_block(&Header::new(
 				1,
@@ -932,7 +1197,7 @@ mod tests {
 					assert_eq!(
 						<frame_system::Pallet<Runtime>>::block_weight().total(),
 						//--------------------- on_initialize + block_execution + extrinsic_base weight
-						(encoded_len + 5) * (nonce + 1) + base_block_weight,
+						Weight::from_ref_time((encoded_len + 5) * (nonce + 1)) + base_block_weight,
 					);
 					assert_eq!(<frame_system::Pallet<Runtime>>::extrinsic_index(), Some(nonce as u32 + 1));
 				} else {
@@ -951,7 +1216,8 @@ mod tests {
 		let mut t = new_test_ext(1);
 		t.execute_with(|| {
 			// Block execution weight + on_initialize weight from custom module
-			let base_block_weight = 175 + <Runtime as frame_system::Config>::BlockWeights::get().base_block;
+			let base_block_weight = Weight::from_ref_time(175) +
+				<Runtime as frame_system::Config>::BlockWeights::get().base_block;
 
 			Executive::initialize_block(&Header::new(
 				1,
@@ -969,7 +1235,8 @@ mod tests {
 			assert!(Executive::apply_extrinsic(x2.clone()).unwrap().is_ok());
 
 			// default weight for `TestXt` == encoded length.
-			let extrinsic_weight = len as Weight + <Runtime as frame_system::Config>::BlockWeights
+			let extrinsic_weight = Weight::from_ref_time(len as u64) +
+				<Runtime as frame_system::Config>::BlockWeights
 				::get().get(DispatchClass::Normal).base_extrinsic;
 			assert_eq!(
 				<frame_system::Pallet<Runtime>>::block_weight().total(),
@@ -1078,7 +1345,10 @@ mod tests {
 			// NOTE: might need updates over time if new weights are introduced.
 			// For now it only accounts for the base block execution weight and
 			// the `on_initialize` weight defined in the custom test module.
-			assert_eq!(<frame_system::Pallet<Runtime>>::block_weight().total(), 175 + 175  + 10);
+			assert_eq!(
+				<frame_system::Pallet<Runtime>>::block_weight().total(),
+				Weight::from_ref_time(175 + 175 + 10),
+			);
 		})
 	}
 
@@ -1216,6 +1486,22 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn on_initialize_weight_is_reported_per_pallet() {
+		new_test_ext(1).execute_with(|| {
+			let mut reported = Vec::new();
+			let weight = Executive::on_initialize_metered(1, |name, weight| {
+				reported.push((name, weight))
+			});
+
+			assert_eq!(weight, Weight::from_ref_time(175));
+			assert_eq!(
+				reported,
+				vec![("Custom", Weight::from_ref_time(175)), ("Balances", Weight::zero())],
+			);
+		});
+	}
+
 	#[test]
 	fn offchain_worker_works_as_expected() {
 		new_test_ext(1).execute_with(|| {
@@ -1316,4 +1602,108 @@ mod tests {
 			Executive::execute_block(Block::new(header, vec![xt1, xt2]));
 		});
 	}
+
+	#[test]
+	#[should_panic(expected = "Transaction dispatch is mandatory")]
+	fn mandatory_dispatch_after_inherents_fails() {
+		let xt1 = TestXt::new(Call::Balances(BalancesCall::transfer(33, 0)), sign_extra(1, 0, 0));
+		let xt2 = TestXt::new(Call::Custom(custom::Call::mandatory_call(true)), None);
+
+		let header = new_test_ext(1).execute_with(|| {
+			// Let's build some fake block.
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+
+			Executive::apply_extrinsic(xt1.clone()).unwrap().unwrap();
+			Executive::apply_extrinsic(xt2.clone()).unwrap().unwrap();
+
+			Executive::finalize_block()
+		});
+
+		new_test_ext(1).execute_with(|| {
+			Executive::execute_block(Block::new(header, vec![xt1, xt2]));
+		});
+	}
+
+	#[test]
+	fn mandatory_dispatches_are_only_allowed_as_successful_inherents() {
+		let xt = TestXt::new(Call::Custom(custom::Call::mandatory_call(true)), None);
+		let failing_xt = TestXt::new(Call::Custom(custom::Call::mandatory_call(false)), None);
+
+		new_test_ext(1).execute_with(|| {
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+
+			assert_eq!(
+				Executive::validate_transaction(TransactionSource::External, xt.clone()),
+				Err(InvalidTransaction::MandatoryDispatch.into()),
+			);
+			assert_eq!(
+				Executive::apply_extrinsic(failing_xt),
+				Err(InvalidTransaction::BadMandatory.into()),
+			);
+			assert_eq!(Executive::apply_extrinsic(xt), Ok(Ok(())));
+		});
+	}
+
+	#[test]
+	fn transactions_are_rejected_while_migrations_are_ongoing() {
+		let xt = TestXt::new(Call::Balances(BalancesCall::transfer(33, 0)), sign_extra(1, 0, 0));
+
+		new_test_ext(1).execute_with(|| {
+			sp_io::storage::set(MIGRATION_STEPS_KEY, &2u32.encode());
+
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+			assert!(MockedMigrator::ongoing());
+			assert_eq!(
+				Executive::validate_transaction(TransactionSource::External, xt.clone()),
+				Err(InvalidTransaction::MigrationsOngoing.into()),
+			);
+			assert_eq!(
+				Executive::apply_extrinsic(xt.clone()),
+				Err(InvalidTransaction::MigrationsOngoing.into()),
+			);
+			assert_eq!(<frame_system::Pallet<Runtime>>::extrinsic_index(), Some(0));
+
+			// Runtime upgrades are still applied, they are the only way to fix a failed migration.
+			let upgrade =
+				TestXt::new(Call::System(SystemCall::set_code(vec![])), sign_extra(1, 0, 0));
+			assert_ne!(
+				Executive::validate_transaction(TransactionSource::External, upgrade.clone()),
+				Err(InvalidTransaction::MigrationsOngoing.into()),
+			);
+			assert_ne!(
+				Executive::apply_extrinsic(upgrade),
+				Err(InvalidTransaction::MigrationsOngoing.into()),
+			);
+			Executive::finalize_block();
+
+			// The last step is executed in the next block, which accepts transactions again.
+			Executive::initialize_block(&Header::new(
+				2,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+			assert!(!MockedMigrator::ongoing());
+			assert!(Executive::apply_extrinsic(xt).unwrap().is_ok());
+		});
+	}
 }
@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the fast unstake pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelist_account};
+use frame_support::traits::{EnsureOrigin, OnIdle};
+use frame_system::RawOrigin;
+use pallet_staking::{testing_utils::create_stash_controller, Exposure, RewardDestination};
+
+use crate::Pallet as FastUnstake;
+
+const SEED: u32 = 0;
+
+/// Bond a new stash, and register it with the funds for the deposit.
+fn register<T: Config>() -> (T::AccountId, T::AccountId) {
+	let (stash, controller) =
+		create_stash_controller::<T>(1, 100, RewardDestination::Staked).unwrap();
+	let balance = <T as Config>::Deposit::get() * 10u32.into();
+	<T as Config>::Currency::make_free_balance_be(&controller, balance);
+	assert!(FastUnstake::<T>::register_fast_unstake(RawOrigin::Signed(controller.clone()).into())
+		.is_ok());
+	(stash, controller)
+}
+
+benchmarks! {
+	on_idle_unstake {
+		// the eras of the stash are all checked already, the next block unstakes it.
+		pallet_staking::CurrentEra::put(0);
+		let (stash, controller) = register::<T>();
+		let (_, (depositor, deposit)) = Queue::<T>::iter().next().unwrap();
+		Queue::<T>::remove(&stash);
+		CounterForQueue::<T>::put(0);
+		Head::<T>::put(UnstakeRequest { stash, depositor, deposit, checked: vec![0] });
+		ErasToCheckPerBlock::<T>::put(1);
+	}: {
+		FastUnstake::<T>::on_idle(Zero::zero(), Weight::max_value());
+	}
+	verify {
+		assert!(Head::<T>::get().is_none());
+		assert!(pallet_staking::Module::<T>::ledger(&controller).is_none());
+	}
+
+	on_idle_check {
+		// the worst case is checking an era with `e` exposures, none of them of the stash.
+		let e in 1 .. 256;
+		pallet_staking::CurrentEra::put(0);
+		pallet_staking::ValidatorCount::put(e);
+		for i in 0 .. e {
+			let validator: T::AccountId = account("validator", i, SEED);
+			pallet_staking::ErasStakers::<T>::insert(0, validator, Exposure::default());
+		}
+		let (stash, _) = register::<T>();
+		ErasToCheckPerBlock::<T>::put(1);
+	}: {
+		FastUnstake::<T>::on_idle(Zero::zero(), Weight::max_value());
+	}
+	verify {
+		let head = Head::<T>::get().unwrap();
+		assert_eq!(head.stash, stash);
+		assert_eq!(head.checked, vec![0]);
+	}
+
+	register_fast_unstake {
+		let (stash, controller) =
+			create_stash_controller::<T>(1, 100, RewardDestination::Staked)?;
+		let balance = <T as Config>::Deposit::get() * 10u32.into();
+		<T as Config>::Currency::make_free_balance_be(&controller, balance);
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller))
+	verify {
+		assert!(Queue::<T>::contains_key(&stash));
+	}
+
+	deregister {
+		let (stash, controller) = register::<T>();
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller))
+	verify {
+		assert!(!Queue::<T>::contains_key(&stash));
+	}
+
+	control {
+		let call = Call::<T>::control(16);
+		let origin = <T as Config>::ControlOrigin::successful_origin();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(ErasToCheckPerBlock::<T>::get(), 16);
+	}
+}
+
+impl_benchmark_test_suite!(
+	FastUnstake,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test,
+);
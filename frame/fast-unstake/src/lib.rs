@@ -0,0 +1,355 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fast Unstake Pallet
+//!
+//! A pallet letting the stakers who were not exposed in any of the last eras skip the unbonding
+//! period of staking.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! The unbonding period of staking exists so that a staker can still be slashed for the offences
+//! of the eras in which its stake was exposed. A staker whose stake was not exposed in any of the
+//! last `BondingDuration` eras, e.g. a nominator whose nominations were never elected, cannot be
+//! slashed anymore, and can thus be unstaked right away.
+//!
+//! A controller registers its stash with [`Pallet::register_fast_unstake`], which reserves
+//! [`Config::Deposit`], chills the stash and unbonds all of its stake, and puts the stash in a
+//! queue. The exposures of the queued stashes are checked in `on_idle`, a few eras per block as
+//! set by [`Config::ControlOrigin`] with [`Pallet::control`], so that the checks only ever use the
+//! spare weight of the blocks:
+//!
+//! - if the stash was exposed in any of the checked eras, the deposit is slashed, and the stash is
+//!   left on the usual unbonding path.
+//! - once all the eras of the bonding duration are checked, the stash is unstaked right away, and
+//!   the deposit is returned.
+//!
+//! A stash can be removed from the queue with [`Pallet::deregister`], unless its eras are being
+//! checked already. It stays unbonding either way.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	storage::IterableStorageDoubleMap,
+	traits::{Currency, Get, ReservableCurrency, UnfilteredDispatchable},
+	weights::Weight,
+	RuntimeDebug,
+};
+use pallet_staking::EraIndex;
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub(crate) const LOG_TARGET: &'static str = "runtime::fast_unstake";
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// A stash whose exposures are being checked.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct UnstakeRequest<AccountId, Balance> {
+	/// The stash to unstake.
+	pub stash: AccountId,
+	/// The account from which the deposit was reserved.
+	pub depositor: AccountId,
+	/// The deposit of the request.
+	pub deposit: Balance,
+	/// The eras checked so far, only ever among the last `BondingDuration` eras.
+	pub checked: Vec<EraIndex>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, transactional};
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_staking::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency of the deposits, usually the same as the currency of staking.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the controller when registering, slashed if the stash turns
+		/// out to have been exposed.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+
+		/// The origin that can set the number of eras checked per block.
+		type ControlOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// A stash was unstaked. \[stash, result\]
+		Unstaked(T::AccountId, DispatchResult),
+		/// A stash was exposed in the given era, and its deposit slashed. \[stash, era, amount\]
+		Slashed(T::AccountId, EraIndex, BalanceOf<T>),
+		/// Some eras of a stash were checked, without finding any exposure. \[stash, eras\]
+		Checking(T::AccountId, Vec<EraIndex>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The origin is not the controller of a stash.
+		NotController,
+		/// The stash is already queued.
+		AlreadyQueued,
+		/// The stash has some stake unbonding already.
+		NotFullyBonded,
+		/// The stash is not queued.
+		NotQueued,
+		/// The eras of the stash are being checked already.
+		AlreadyHead,
+	}
+
+	/// The stash whose eras are being checked, if any.
+	#[pallet::storage]
+	pub type Head<T: Config> =
+		StorageValue<_, UnstakeRequest<T::AccountId, BalanceOf<T>>>;
+
+	/// The queued stashes, with the account their deposit was reserved from and the deposit.
+	#[pallet::storage]
+	pub type Queue<T: Config> = StorageMap<
+		_,
+		Twox64Concat, T::AccountId,
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	/// The number of queued stashes.
+	#[pallet::storage]
+	pub type CounterForQueue<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of eras checked per block.
+	///
+	/// Zero disables the checks, which is the default.
+	#[pallet::storage]
+	pub type ErasToCheckPerBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_on_idle(remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(
+				!<T as Config>::Deposit::get().is_zero(),
+				"the deposit must not be zero, registering would be free of any risk",
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Queue the stash of the caller to be unstaked without waiting for the unbonding period.
+		///
+		/// The dispatch origin must be the controller of the stash, whose stake must be fully
+		/// bonded, i.e. nothing may be unbonding. The stash is chilled and all of its stake is
+		/// unbonded right away, and [`Config::Deposit`] is reserved from the controller.
+		#[pallet::weight(<T as Config>::WeightInfo::register_fast_unstake())]
+		#[transactional]
+		pub fn register_fast_unstake(origin: OriginFor<T>) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let ledger = pallet_staking::Module::<T>::ledger(&controller)
+				.ok_or(Error::<T>::NotController)?;
+			let stash = ledger.stash.clone();
+
+			ensure!(!Queue::<T>::contains_key(&stash), Error::<T>::AlreadyQueued);
+			ensure!(
+				Head::<T>::get().map_or(true, |head| head.stash != stash),
+				Error::<T>::AlreadyHead,
+			);
+			ensure!(
+				ledger.unlocking.is_empty() && ledger.active == ledger.total,
+				Error::<T>::NotFullyBonded,
+			);
+
+			let deposit = <T as Config>::Deposit::get();
+			<T as Config>::Currency::reserve(&controller, deposit)?;
+
+			let signed: T::Origin = frame_system::RawOrigin::Signed(controller.clone()).into();
+			pallet_staking::Call::<T>::chill()
+				.dispatch_bypass_filter(signed.clone())
+				.map_err(|e| e.error)?;
+			pallet_staking::Call::<T>::unbond(ledger.total)
+				.dispatch_bypass_filter(signed)
+				.map_err(|e| e.error)?;
+
+			Queue::<T>::insert(&stash, (controller, deposit));
+			CounterForQueue::<T>::mutate(|count| *count = count.saturating_add(1));
+			Ok(())
+		}
+
+		/// Remove the stash of the caller from the queue, and return its deposit.
+		///
+		/// The stash stays unbonding. This is not possible once its eras are being checked.
+		#[pallet::weight(<T as Config>::WeightInfo::deregister())]
+		pub fn deregister(origin: OriginFor<T>) -> DispatchResult {
+			let controller = ensure_signed(origin)?;
+			let stash = pallet_staking::Module::<T>::ledger(&controller)
+				.map(|ledger| ledger.stash)
+				.ok_or(Error::<T>::NotController)?;
+
+			ensure!(
+				Head::<T>::get().map_or(true, |head| head.stash != stash),
+				Error::<T>::AlreadyHead,
+			);
+			let (depositor, deposit) = Queue::<T>::take(&stash).ok_or(Error::<T>::NotQueued)?;
+			CounterForQueue::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			let _remaining =
+				<T as Config>::Currency::unreserve(&depositor, deposit);
+			debug_assert!(_remaining.is_zero());
+			Ok(())
+		}
+
+		/// Set the number of eras checked per block.
+		///
+		/// The dispatch origin must be [`Config::ControlOrigin`]. Zero disables the checks.
+		#[pallet::weight(<T as Config>::WeightInfo::control())]
+		pub fn control(origin: OriginFor<T>, eras_to_check: u32) -> DispatchResult {
+			<T as Config>::ControlOrigin::ensure_origin(origin)?;
+			ErasToCheckPerBlock::<T>::put(eras_to_check);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check the next eras of the head of the queue, or unstake it if all of its eras are checked,
+	/// within `remaining_weight`.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn do_on_idle(remaining_weight: Weight) -> Weight {
+		let eras_to_check_per_block = ErasToCheckPerBlock::<T>::get();
+		if eras_to_check_per_block.is_zero() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		// the checks and the unstaking must fit in the remaining weight in the worst case, where
+		// each era has as many exposures as there are validators to elect.
+		let validator_count = pallet_staking::Module::<T>::validator_count();
+		let worst_case = <T as Config>::WeightInfo::on_idle_check(
+			validator_count.saturating_mul(eras_to_check_per_block),
+		)
+		.max(<T as Config>::WeightInfo::on_idle_unstake());
+		if worst_case.any_gt(&remaining_weight) {
+			log::debug!(target: LOG_TARGET, "not enough weight left to check the queue");
+			return T::DbWeight::get().reads(2)
+		}
+
+		let current_era = match pallet_staking::Module::<T>::current_era() {
+			Some(era) => era,
+			None => return T::DbWeight::get().reads(3),
+		};
+
+		let request = Head::<T>::take().or_else(|| {
+			let (stash, (depositor, deposit)) = Queue::<T>::iter().next()?;
+			Queue::<T>::remove(&stash);
+			CounterForQueue::<T>::mutate(|count| *count = count.saturating_sub(1));
+			Some(UnstakeRequest { stash, depositor, deposit, checked: Vec::new() })
+		});
+		let UnstakeRequest { stash, depositor, deposit, mut checked } = match request {
+			Some(request) => request,
+			None => return T::DbWeight::get().reads(4),
+		};
+
+		// only the eras of the bonding duration matter, the older ones are forgotten as the
+		// current era moves on.
+		let bonding_duration = <T as pallet_staking::Config>::BondingDuration::get();
+		let first_era = current_era.saturating_sub(bonding_duration);
+		checked.retain(|era| *era >= first_era);
+
+		let eras_to_check = (first_era..=current_era)
+			.rev()
+			.filter(|era| !checked.contains(era))
+			.take(eras_to_check_per_block as usize)
+			.collect::<Vec<_>>();
+
+		if eras_to_check.is_empty() {
+			let num_slashing_spans = pallet_staking::Module::<T>::slashing_spans(&stash)
+				.map_or(0, |spans| spans.num_spans());
+			let result = pallet_staking::Call::<T>::force_unstake(stash.clone(), num_slashing_spans)
+				.dispatch_bypass_filter(frame_system::RawOrigin::Root.into())
+				.map(|_| ())
+				.map_err(|e| e.error);
+
+			let _remaining =
+				<T as Config>::Currency::unreserve(&depositor, deposit);
+			debug_assert!(_remaining.is_zero());
+			Self::deposit_event(Event::<T>::Unstaked(stash, result));
+			return <T as Config>::WeightInfo::on_idle_unstake()
+		}
+
+		let consumed = <T as Config>::WeightInfo::on_idle_check(
+			validator_count.saturating_mul(eras_to_check.len() as u32),
+		);
+		match eras_to_check.iter().find(|era| Self::is_exposed_in_era(&stash, **era)) {
+			Some(era) => {
+				let (_imbalance, _) =
+					<T as Config>::Currency::slash_reserved(&depositor, deposit);
+				Self::deposit_event(Event::<T>::Slashed(stash, *era, deposit));
+			}
+			None => {
+				checked.extend(eras_to_check.iter().copied());
+				Head::<T>::put(UnstakeRequest {
+					stash: stash.clone(),
+					depositor,
+					deposit,
+					checked,
+				});
+				Self::deposit_event(Event::<T>::Checking(stash, eras_to_check));
+			}
+		}
+		consumed
+	}
+
+	/// Whether `who` was exposed in `era`, as a validator or as a nominator.
+	fn is_exposed_in_era(who: &T::AccountId, era: EraIndex) -> bool {
+		pallet_staking::ErasStakers::<T>::iter_prefix(era).any(|(validator, exposure)| {
+			validator == *who || exposure.others.iter().any(|individual| individual.who == *who)
+		})
+	}
+}
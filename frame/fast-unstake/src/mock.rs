@@ -0,0 +1,295 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use crate as pallet_fast_unstake;
+use frame_election_provider_support::onchain;
+use frame_support::{parameter_types, traits::OnIdle, weights::Weight};
+use pallet_staking::{EraIndex, Exposure, IndividualExposure, RewardDestination};
+use sp_runtime::traits::IdentityLookup;
+
+pub type AccountId = u64;
+pub type AccountIndex = u32;
+pub type BlockNumber = u64;
+pub type Balance = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
+		FastUnstake: pallet_fast_unstake::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = AccountIndex;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = sp_core::H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::testing::Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_session::historical::Config for Test {
+	type FullIdentification = pallet_staking::Exposure<AccountId, Balance>;
+	type FullIdentificationOf = pallet_staking::ExposureOf<Test>;
+}
+
+sp_runtime::impl_opaque_keys! {
+	pub struct SessionKeys {
+		pub foo: sp_runtime::testing::UintAuthorityId,
+	}
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+	const KEY_TYPE_IDS: &'static [sp_runtime::KeyTypeId] = &[];
+
+	fn on_genesis_session<Ks: sp_runtime::traits::OpaqueKeys>(_validators: &[(AccountId, Ks)]) {}
+
+	fn on_new_session<Ks: sp_runtime::traits::OpaqueKeys>(
+		_: bool,
+		_: &[(AccountId, Ks)],
+		_: &[(AccountId, Ks)],
+	) {}
+
+	fn on_disabled(_: usize) {}
+}
+
+impl pallet_session::Config for Test {
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Test, Staking>;
+	type Keys = SessionKeys;
+	type ShouldEndSession = pallet_session::PeriodicSessions<(), ()>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<(), ()>;
+	type SessionHandler = TestSessionHandler;
+	type Event = Event;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = pallet_staking::StashOf<Test>;
+	type DisabledValidatorsThreshold = ();
+	type WeightInfo = ();
+}
+
+pallet_staking_reward_curve::build! {
+	const I_NPOS: sp_runtime::curve::PiecewiseLinear<'static> = curve!(
+		min_inflation: 0_025_000,
+		max_inflation: 0_100_000,
+		ideal_stake: 0_500_000,
+		falloff: 0_050_000,
+		max_piece_count: 40,
+		test_precision: 0_005_000,
+	);
+}
+parameter_types! {
+	pub const RewardCurve: &'static sp_runtime::curve::PiecewiseLinear<'static> = &I_NPOS;
+	pub const BondingDuration: EraIndex = 3;
+	pub const MaxNominatorRewardedPerValidator: u32 = 64;
+}
+
+impl onchain::Config for Test {
+	type AccountId = AccountId;
+	type BlockNumber = BlockNumber;
+	type BlockWeights = ();
+	type Accuracy = sp_runtime::Perbill;
+	type DataProvider = Staking;
+}
+
+impl pallet_staking::Config for Test {
+	const MAX_NOMINATIONS: u32 = 16;
+	type Currency = Balances;
+	type UnixTime = pallet_timestamp::Pallet<Self>;
+	type CurrencyToVote = frame_support::traits::SaturatingCurrencyToVote;
+	type RewardRemainder = ();
+	type Event = Event;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = ();
+	type SlashDeferDuration = ();
+	type SlashCancelOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type BondingDuration = BondingDuration;
+	type SessionInterface = Self;
+	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type NextNewSession = Session;
+	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
+	type ElectionProvider = onchain::OnChainSequentialPhragmen<Self>;
+	type SortedListProvider = pallet_staking::UseNominatorsMap<Self>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const Deposit: Balance = 7;
+}
+
+impl pallet_fast_unstake::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type Deposit = Deposit;
+	type ControlOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+/// The validators exposed in every era, which never register.
+pub const VALIDATORS: [AccountId; 2] = [1, 2];
+
+/// The stash and controller pairs bonded in the genesis, each with [`STAKE`].
+pub const STAKERS: [(AccountId, AccountId); 3] = [(10, 11), (20, 21), (30, 31)];
+
+/// The stake of each staker.
+pub const STAKE: Balance = 100;
+
+pub struct ExtBuilder {
+	eras_to_check_per_block: u32,
+	current_era: EraIndex,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self { eras_to_check_per_block: 1, current_era: 10 }
+	}
+}
+
+impl ExtBuilder {
+	pub fn eras_to_check_per_block(mut self, eras: u32) -> Self {
+		self.eras_to_check_per_block = eras;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut storage =
+			frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		let accounts = VALIDATORS
+			.iter()
+			.copied()
+			.chain(STAKERS.iter().flat_map(|(stash, controller)| vec![*stash, *controller]));
+		pallet_balances::GenesisConfig::<Test> {
+			balances: accounts.map(|who| (who, 1_000)).collect(),
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::from(storage);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for (stash, controller) in STAKERS.iter() {
+				Staking::bond(
+					Origin::signed(*stash),
+					*controller,
+					STAKE,
+					RewardDestination::Staked,
+				)
+				.unwrap();
+			}
+			pallet_staking::ValidatorCount::put(VALIDATORS.len() as u32);
+			pallet_staking::CurrentEra::put(self.current_era);
+			for era in 0..=self.current_era {
+				for validator in VALIDATORS.iter() {
+					expose_in_era(era, *validator, vec![]);
+				}
+			}
+			crate::ErasToCheckPerBlock::<Test>::put(self.eras_to_check_per_block);
+		});
+		ext
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+		self.build().execute_with(test)
+	}
+}
+
+/// Expose `nominators` behind `validator` in `era`.
+pub fn expose_in_era(era: EraIndex, validator: AccountId, nominators: Vec<AccountId>) {
+	let others = nominators
+		.into_iter()
+		.map(|who| IndividualExposure { who, value: STAKE })
+		.collect::<Vec<_>>();
+	let total = STAKE * (others.len() as Balance + 1);
+	pallet_staking::ErasStakers::<Test>::insert(
+		era,
+		validator,
+		Exposure { total, own: STAKE, others },
+	);
+}
+
+/// Run the `on_idle` hook of the next block, with the whole block weight.
+pub fn next_block() -> Weight {
+	let now = System::block_number() + 1;
+	System::set_block_number(now);
+	FastUnstake::on_idle(now, Weight::max_value())
+}
+
+/// The events of the pallet since the last call.
+pub fn fast_unstake_events_since_last_call() -> Vec<pallet_fast_unstake::Event<Test>> {
+	let events = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::FastUnstake(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+	System::reset_events();
+	events
+}
@@ -0,0 +1,280 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::OnIdle};
+use sp_runtime::DispatchError;
+
+fn unbond(controller: AccountId, value: Balance) {
+	let call = pallet_staking::Call::<Test>::unbond(value);
+	assert_ok!(call.dispatch_bypass_filter(Origin::signed(controller)));
+}
+
+#[test]
+fn register_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+
+		// the deposit is reserved from the controller.
+		assert_eq!(Balances::reserved_balance(&11), Deposit::get());
+		assert_eq!(Queue::<Test>::get(&10), Some((11, Deposit::get())));
+		assert_eq!(CounterForQueue::<Test>::get(), 1);
+
+		// the whole stake is unbonding.
+		let ledger = Staking::ledger(&11).unwrap();
+		assert_eq!(ledger.active, 0);
+		assert_eq!(ledger.unlocking.len(), 1);
+		assert_eq!(ledger.unlocking[0].value, STAKE);
+	});
+}
+
+#[test]
+fn register_fails_for_non_controllers() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			FastUnstake::register_fast_unstake(Origin::signed(10)),
+			Error::<Test>::NotController,
+		);
+		assert_noop!(
+			FastUnstake::register_fast_unstake(Origin::signed(42)),
+			Error::<Test>::NotController,
+		);
+	});
+}
+
+#[test]
+fn register_fails_when_already_queued() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		assert_noop!(
+			FastUnstake::register_fast_unstake(Origin::signed(11)),
+			Error::<Test>::AlreadyQueued,
+		);
+
+		// nor once its eras are being checked.
+		next_block();
+		assert!(Head::<Test>::get().is_some());
+		assert_noop!(
+			FastUnstake::register_fast_unstake(Origin::signed(11)),
+			Error::<Test>::AlreadyHead,
+		);
+	});
+}
+
+#[test]
+fn register_fails_when_unbonding() {
+	ExtBuilder::default().build_and_execute(|| {
+		unbond(11, 10);
+		assert_noop!(
+			FastUnstake::register_fast_unstake(Origin::signed(11)),
+			Error::<Test>::NotFullyBonded,
+		);
+	});
+}
+
+#[test]
+fn register_fails_without_the_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		Balances::make_free_balance_be(&11, Deposit::get() - 1);
+		assert_storage_noop!(
+			assert!(FastUnstake::register_fast_unstake(Origin::signed(11)).is_err())
+		);
+	});
+}
+
+#[test]
+fn deregister_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		assert_ok!(FastUnstake::deregister(Origin::signed(11)));
+
+		assert_eq!(Balances::reserved_balance(&11), 0);
+		assert_eq!(Queue::<Test>::get(&10), None);
+		assert_eq!(CounterForQueue::<Test>::get(), 0);
+
+		// the stash stays unbonding.
+		assert_eq!(Staking::ledger(&11).unwrap().active, 0);
+
+		assert_noop!(FastUnstake::deregister(Origin::signed(11)), Error::<Test>::NotQueued);
+	});
+}
+
+#[test]
+fn deregister_fails_once_checking() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		next_block();
+		assert_noop!(FastUnstake::deregister(Origin::signed(11)), Error::<Test>::AlreadyHead);
+		assert_noop!(FastUnstake::deregister(Origin::signed(10)), Error::<Test>::NotController);
+	});
+}
+
+#[test]
+fn control_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(FastUnstake::control(Origin::signed(11), 5), DispatchError::BadOrigin);
+		assert_ok!(FastUnstake::control(Origin::root(), 5));
+		assert_eq!(ErasToCheckPerBlock::<Test>::get(), 5);
+	});
+}
+
+#[test]
+fn nothing_is_checked_when_disabled() {
+	ExtBuilder::default().eras_to_check_per_block(0).build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		assert_storage_noop!(FastUnstake::on_idle(2, Weight::max_value()));
+		assert!(fast_unstake_events_since_last_call().is_empty());
+	});
+}
+
+#[test]
+fn nothing_is_checked_without_enough_weight() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		assert_storage_noop!(FastUnstake::on_idle(2, 1));
+		assert_eq!(Queue::<Test>::get(&10), Some((11, Deposit::get())));
+	});
+}
+
+#[test]
+fn unexposed_stash_is_unstaked() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+
+		// the eras of the bonding duration are checked one per block, from the newest.
+		for era in (7..=10).rev() {
+			next_block();
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![crate::Event::Checking(10, vec![era])],
+			);
+		}
+		assert_eq!(Head::<Test>::get().unwrap().checked, vec![10, 9, 8, 7]);
+		assert_eq!(Queue::<Test>::get(&10), None);
+
+		next_block();
+		assert_eq!(fast_unstake_events_since_last_call(), vec![crate::Event::Unstaked(10, Ok(()))]);
+		assert_eq!(Head::<Test>::get(), None);
+
+		// the stash is free, and the deposit returned.
+		assert_eq!(Staking::ledger(&11), None);
+		assert_eq!(Staking::bonded(&10), None);
+		assert!(pallet_balances::Locks::<Test>::get(&10).is_empty());
+		assert_eq!(Balances::reserved_balance(&11), 0);
+		assert_eq!(Balances::free_balance(&11), 1_000);
+	});
+}
+
+#[test]
+fn exposed_stash_is_slashed() {
+	ExtBuilder::default().eras_to_check_per_block(2).build_and_execute(|| {
+		expose_in_era(8, 1, vec![10]);
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call(),
+			vec![crate::Event::Checking(10, vec![10, 9])],
+		);
+
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call(),
+			vec![crate::Event::Slashed(10, 8, Deposit::get())],
+		);
+		assert_eq!(Head::<Test>::get(), None);
+
+		// the deposit is gone, and the stash stays unbonding.
+		assert_eq!(Balances::reserved_balance(&11), 0);
+		assert_eq!(Balances::free_balance(&11), 1_000 - Deposit::get());
+		assert_eq!(Staking::ledger(&11).unwrap().unlocking.len(), 1);
+	});
+}
+
+#[test]
+fn exposed_validator_is_slashed() {
+	ExtBuilder::default().build_and_execute(|| {
+		expose_in_era(10, 10, vec![]);
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call(),
+			vec![crate::Event::Slashed(10, 10, Deposit::get())],
+		);
+	});
+}
+
+#[test]
+fn new_eras_are_checked_too() {
+	ExtBuilder::default().eras_to_check_per_block(2).build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call(),
+			vec![crate::Event::Checking(10, vec![10, 9])],
+		);
+
+		// a new era starts, the oldest era is out of the bonding duration.
+		pallet_staking::CurrentEra::put(11);
+		for validator in VALIDATORS.iter() {
+			expose_in_era(11, *validator, vec![]);
+		}
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call(),
+			vec![crate::Event::Checking(10, vec![11, 8])],
+		);
+		assert_eq!(Head::<Test>::get().unwrap().checked, vec![10, 9, 11, 8]);
+
+		next_block();
+		assert_eq!(fast_unstake_events_since_last_call(), vec![crate::Event::Unstaked(10, Ok(()))]);
+	});
+}
+
+#[test]
+fn queue_is_checked_one_stash_at_a_time() {
+	ExtBuilder::default().eras_to_check_per_block(4).build_and_execute(|| {
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(11)));
+		assert_ok!(FastUnstake::register_fast_unstake(Origin::signed(21)));
+		assert_eq!(CounterForQueue::<Test>::get(), 2);
+
+		next_block();
+		let first = Head::<Test>::get().unwrap().stash;
+		assert_eq!(CounterForQueue::<Test>::get(), 1);
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call().pop(),
+			Some(crate::Event::Unstaked(first, Ok(()))),
+		);
+
+		next_block();
+		let second = Head::<Test>::get().unwrap().stash;
+		assert_ne!(first, second);
+		assert_eq!(CounterForQueue::<Test>::get(), 0);
+		next_block();
+		assert_eq!(
+			fast_unstake_events_since_last_call().pop(),
+			Some(crate::Event::Unstaked(second, Ok(()))),
+		);
+
+		// nothing is left to check.
+		assert_storage_noop!(FastUnstake::on_idle(6, Weight::max_value()));
+	});
+}
@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_fast_unstake
+//!
+//! These weights are estimated from the storage accesses of the calls and are meant to be
+//! replaced by the results of benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fast_unstake.
+pub trait WeightInfo {
+	fn on_idle_unstake() -> Weight;
+	fn on_idle_check(e: u32, ) -> Weight;
+	fn register_fast_unstake() -> Weight;
+	fn deregister() -> Weight;
+	fn control() -> Weight;
+}
+
+/// Weights for pallet_fast_unstake using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn on_idle_unstake() -> Weight {
+		Weight::from_ref_time(80_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	fn on_idle_check(e: u32, ) -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn register_fast_unstake() -> Weight {
+		Weight::from_ref_time(100_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	fn deregister() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn control() -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn on_idle_unstake() -> Weight {
+		Weight::from_ref_time(80_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	fn on_idle_check(e: u32, ) -> Weight {
+		Weight::from_ref_time(30_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn register_fast_unstake() -> Weight {
+		Weight::from_ref_time(100_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	fn deregister() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn control() -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
@@ -42,7 +42,7 @@ pub fn migrate<
 			target: "runtime::afg",
 			"New pallet name is equal to the old prefix. No migration needs to be done.",
 		);
-		return 0;
+		return Weight::zero();
 	}
 	let maybe_storage_version = <P as GetPalletVersion>::storage_version();
 	log::info!(
@@ -66,7 +66,7 @@ pub fn migrate<
 				"Attempted to apply migration to v3.1 but cancelled because storage version is {:?}",
 				maybe_storage_version,
 			);
-			0
+			Weight::zero()
 		},
 	}
 }
@@ -123,7 +123,7 @@ benchmarks! {
 
 			// Add an initial identity
 			let initial_info = create_identity_info::<T>(1);
-			Identity::<T>::set_identity(caller_origin.clone(), initial_info)?;
+			Identity::<T>::set_identity(caller_origin.clone(), initial_info.clone())?;
 
 			// User requests judgement from all the registrars, and they approve
 			for i in 0..r {
@@ -132,7 +132,8 @@ benchmarks! {
 					RawOrigin::Signed(account("registrar", i, SEED)).into(),
 					i,
 					caller_lookup.clone(),
-					Judgement::Reasonable
+					Judgement::Reasonable,
+					T::Hashing::hash_of(&initial_info),
 				)?;
 			}
 			caller
@@ -194,16 +195,17 @@ benchmarks! {
 		};
 
 		// User requests judgement from all the registrars, and they approve
+		let info = IdentityOf::<T>::get(&caller).ok_or("Identity does not exist.")?.info;
 		for i in 0..r {
 			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
 			Identity::<T>::provide_judgement(
 				RawOrigin::Signed(account("registrar", i, SEED)).into(),
 				i,
 				caller_lookup.clone(),
-				Judgement::Reasonable
+				Judgement::Reasonable,
+				T::Hashing::hash_of(&info),
 			)?;
 		}
-		ensure!(IdentityOf::<T>::contains_key(&caller), "Identity does not exist.");
 	}: _(RawOrigin::Signed(caller.clone()))
 	verify {
 		ensure!(!IdentityOf::<T>::contains_key(&caller), "Identity not cleared.");
@@ -312,7 +314,9 @@ benchmarks! {
 
 		Identity::<T>::add_registrar(RawOrigin::Root.into(), caller.clone())?;
 		Identity::<T>::request_judgement(user_origin.clone(), r, 10u32.into())?;
-	}: _(RawOrigin::Signed(caller), r, user_lookup, Judgement::Reasonable)
+		let info = IdentityOf::<T>::get(&user).ok_or("Identity not set")?.info;
+		let info_hash = T::Hashing::hash_of(&info);
+	}: _(RawOrigin::Signed(caller), r, user_lookup, Judgement::Reasonable, info_hash)
 	verify {
 		assert_last_event::<T>(Event::<T>::JudgementGiven(user, r).into())
 	}
@@ -328,7 +332,7 @@ benchmarks! {
 		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
 
 		let info = create_identity_info::<T>(x);
-		Identity::<T>::set_identity(target_origin.clone(), info)?;
+		Identity::<T>::set_identity(target_origin.clone(), info.clone())?;
 		let _ = add_sub_accounts::<T>(&target, s)?;
 
 		// User requests judgement from all the registrars, and they approve
@@ -338,7 +342,8 @@ benchmarks! {
 				RawOrigin::Signed(account("registrar", i, SEED)).into(),
 				i,
 				target_lookup.clone(),
-				Judgement::Reasonable
+				Judgement::Reasonable,
+				T::Hashing::hash_of(&info),
 			)?;
 		}
 		ensure!(IdentityOf::<T>::contains_key(&target), "Identity not set");
@@ -82,7 +82,7 @@ use sp_std::{fmt::Debug, ops::Add, iter::once};
 use enumflags2::BitFlags;
 use codec::{Encode, Decode};
 use sp_runtime::RuntimeDebug;
-use sp_runtime::traits::{StaticLookup, Zero, AppendZerosInput, Saturating};
+use sp_runtime::traits::{StaticLookup, Zero, AppendZerosInput, Saturating, Hash};
 use frame_support::traits::{Currency, ReservableCurrency, OnUnbalanced, BalanceStatus};
 pub use weights::WeightInfo;
 
@@ -498,7 +498,9 @@ pub mod pallet {
 		/// Sender is not a sub-account.
 		NotSub,
 		/// Sub-account isn't owned by sender.
-		NotOwned
+		NotOwned,
+		/// The judgement was provided for a different identity than the current one of the target.
+		JudgementForDifferentIdentity,
 	}
 
 	#[pallet::event]
@@ -951,6 +953,8 @@ pub mod pallet {
 		/// - `target`: the account whose identity the judgement is upon. This must be an account
 		///   with a registered identity.
 		/// - `judgement`: the judgement of the registrar of index `reg_index` about `target`.
+		/// - `identity`: the hash of the `IdentityInfo` of `target` the judgement is about, so
+		///   that it cannot be given about an identity changed in the meantime.
 		///
 		/// Emits `JudgementGiven` if successful.
 		///
@@ -969,6 +973,7 @@ pub mod pallet {
 			#[pallet::compact] reg_index: RegistrarIndex,
 			target: <T::Lookup as StaticLookup>::Source,
 			judgement: Judgement<BalanceOf<T>>,
+			identity: T::Hash,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
@@ -979,6 +984,10 @@ pub mod pallet {
 				.and_then(|r| if r.account == sender { Some(r) } else { None })
 				.ok_or(Error::<T>::InvalidIndex)?;
 			let mut id = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+			ensure!(
+				T::Hashing::hash_of(&id.info) == identity,
+				Error::<T>::JudgementForDifferentIdentity,
+			);
 
 			let item = (reg_index, judgement);
 			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
@@ -25,7 +25,7 @@ use frame_support::{assert_ok, assert_noop, parameter_types, ord_parameter_types
 use sp_core::H256;
 use frame_system::{EnsureSignedBy, EnsureOneOf, EnsureRoot};
 use sp_runtime::{
-	testing::Header, traits::{BlakeTwo256, IdentityLookup},
+	testing::Header, traits::{BlakeTwo256, Hash, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -46,7 +46,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = ();
@@ -85,6 +87,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type WeightInfo = ();
 }
 parameter_types! {
@@ -147,6 +150,10 @@ fn ten() -> IdentityInfo {
 	}
 }
 
+fn ten_hash() -> H256 {
+	BlakeTwo256::hash_of(&ten())
+}
+
 fn twenty() -> IdentityInfo {
 	IdentityInfo {
 		display: Data::Raw(b"twenty".to_vec()),
@@ -288,27 +295,54 @@ fn registration_should_work() {
 fn uninvited_judgement_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable),
+			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()),
 			Error::<Test>::InvalidIndex
 		);
 
 		assert_ok!(Identity::add_registrar(Origin::signed(1), 3));
 		assert_noop!(
-			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable),
+			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()),
 			Error::<Test>::InvalidTarget
 		);
 
 		assert_ok!(Identity::set_identity(Origin::signed(10), ten()));
 		assert_noop!(
-			Identity::provide_judgement(Origin::signed(10), 0, 10, Judgement::Reasonable),
+			Identity::provide_judgement(Origin::signed(10), 0, 10, Judgement::Reasonable, ten_hash()),
 			Error::<Test>::InvalidIndex
 		);
 		assert_noop!(
-			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::FeePaid(1)),
+			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::FeePaid(1), ten_hash()),
 			Error::<Test>::InvalidJudgement
 		);
 
-		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()));
+		assert_eq!(Identity::identity(10).unwrap().judgements, vec![(0, Judgement::Reasonable)]);
+	});
+}
+
+#[test]
+fn judgement_for_changed_identity_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_registrar(Origin::signed(1), 3));
+		assert_ok!(Identity::set_identity(Origin::signed(10), ten()));
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+
+		// The identity is changed between the request and the judgement.
+		let info = IdentityInfo {
+			display: Data::Raw(b"ten".to_vec()),
+			legal: Data::Raw(b"The Right Ordinal Eleven, Esq.".to_vec()),
+			.. Default::default()
+		};
+		assert_ok!(Identity::set_identity(Origin::signed(10), info.clone()));
+		assert_noop!(
+			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()),
+			Error::<Test>::JudgementForDifferentIdentity
+		);
+
+		let info_hash = BlakeTwo256::hash_of(&info);
+		assert_ok!(
+			Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, info_hash)
+		);
 		assert_eq!(Identity::identity(10).unwrap().judgements, vec![(0, Judgement::Reasonable)]);
 	});
 }
@@ -318,7 +352,7 @@ fn clearing_judgement_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Identity::add_registrar(Origin::signed(1), 3));
 		assert_ok!(Identity::set_identity(Origin::signed(10), ten()));
-		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()));
 		assert_ok!(Identity::clear_identity(Origin::signed(10)));
 		assert_eq!(Identity::identity(10), None);
 	});
@@ -411,7 +445,7 @@ fn cancelling_requested_judgement_should_work() {
 		assert_eq!(Balances::free_balance(10), 90);
 		assert_noop!(Identity::cancel_request(Origin::signed(10), 0), Error::<Test>::NotFound);
 
-		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable, ten_hash()));
 		assert_noop!(Identity::cancel_request(Origin::signed(10), 0), Error::<Test>::JudgementGiven);
 	});
 }
@@ -429,7 +463,7 @@ fn requesting_judgement_should_work() {
 
 		// Re-requesting won't work as we already paid.
 		assert_noop!(Identity::request_judgement(Origin::signed(10), 0, 10), Error::<Test>::StickyJudgement);
-		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Erroneous));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Erroneous, ten_hash()));
 		// Registrar got their payment now.
 		assert_eq!(Balances::free_balance(3), 20);
 
@@ -441,7 +475,7 @@ fn requesting_judgement_should_work() {
 		assert_ok!(Identity::request_judgement(Origin::signed(10), 1, 10));
 
 		// Re-requesting after the judgement has been reduced works.
-		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::OutOfDate));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::OutOfDate, ten_hash()));
 		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
 	});
 }
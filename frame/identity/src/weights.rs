@@ -66,122 +66,122 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_registrar(r: u32, ) -> Weight {
-		(28_965_000 as Weight)
-			.saturating_add((421_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(28_965_000 as u64)
+			.saturating_add(Weight::from_ref_time(421_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_identity(r: u32, x: u32, ) -> Weight {
-		(71_923_000 as Weight)
-			.saturating_add((529_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((1_763_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(71_923_000 as u64)
+			.saturating_add(Weight::from_ref_time(529_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(1_763_000 as u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_subs_new(s: u32, ) -> Weight {
-		(55_550_000 as Weight)
-			.saturating_add((9_760_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(55_550_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_760_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn set_subs_old(p: u32, ) -> Weight {
-		(51_789_000 as Weight)
-			.saturating_add((3_484_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+		Weight::from_ref_time(51_789_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_484_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn clear_identity(r: u32, s: u32, x: u32, ) -> Weight {
-		(65_458_000 as Weight)
-			.saturating_add((230_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((3_437_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_023_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(65_458_000 as u64)
+			.saturating_add(Weight::from_ref_time(230_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(3_437_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_023_000 as u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn request_judgement(r: u32, x: u32, ) -> Weight {
-		(75_299_000 as Weight)
-			.saturating_add((493_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_014_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(75_299_000 as u64)
+			.saturating_add(Weight::from_ref_time(493_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_014_000 as u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel_request(r: u32, x: u32, ) -> Weight {
-		(67_492_000 as Weight)
-			.saturating_add((225_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_003_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(67_492_000 as u64)
+			.saturating_add(Weight::from_ref_time(225_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_003_000 as u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_fee(r: u32, ) -> Weight {
-		(11_375_000 as Weight)
-			.saturating_add((382_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(11_375_000 as u64)
+			.saturating_add(Weight::from_ref_time(382_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_account_id(r: u32, ) -> Weight {
-		(12_898_000 as Weight)
-			.saturating_add((384_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(12_898_000 as u64)
+			.saturating_add(Weight::from_ref_time(384_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_fields(r: u32, ) -> Weight {
-		(11_419_000 as Weight)
-			.saturating_add((381_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(11_419_000 as u64)
+			.saturating_add(Weight::from_ref_time(381_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn provide_judgement(r: u32, x: u32, ) -> Weight {
-		(51_115_000 as Weight)
-			.saturating_add((427_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_001_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(51_115_000 as u64)
+			.saturating_add(Weight::from_ref_time(427_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_001_000 as u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn kill_identity(_r: u32, s: u32, _x: u32, ) -> Weight {
-		(90_911_000 as Weight)
-			.saturating_add((3_450_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(90_911_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_450_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn add_sub(s: u32, ) -> Weight {
-		(76_957_000 as Weight)
-			.saturating_add((261_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(76_957_000 as u64)
+			.saturating_add(Weight::from_ref_time(261_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn rename_sub(s: u32, ) -> Weight {
-		(26_219_000 as Weight)
-			.saturating_add((84_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(26_219_000 as u64)
+			.saturating_add(Weight::from_ref_time(84_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_sub(s: u32, ) -> Weight {
-		(73_130_000 as Weight)
-			.saturating_add((239_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(73_130_000 as u64)
+			.saturating_add(Weight::from_ref_time(239_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn quit_sub(s: u32, ) -> Weight {
-		(48_088_000 as Weight)
-			.saturating_add((237_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(48_088_000 as u64)
+			.saturating_add(Weight::from_ref_time(237_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -190,122 +190,122 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn add_registrar(r: u32, ) -> Weight {
-		(28_965_000 as Weight)
-			.saturating_add((421_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(28_965_000 as u64)
+			.saturating_add(Weight::from_ref_time(421_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_identity(r: u32, x: u32, ) -> Weight {
-		(71_923_000 as Weight)
-			.saturating_add((529_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((1_763_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(71_923_000 as u64)
+			.saturating_add(Weight::from_ref_time(529_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(1_763_000 as u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_subs_new(s: u32, ) -> Weight {
-		(55_550_000 as Weight)
-			.saturating_add((9_760_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(55_550_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_760_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn set_subs_old(p: u32, ) -> Weight {
-		(51_789_000 as Weight)
-			.saturating_add((3_484_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+		Weight::from_ref_time(51_789_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_484_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(p as u64)))
 	}
 	fn clear_identity(r: u32, s: u32, x: u32, ) -> Weight {
-		(65_458_000 as Weight)
-			.saturating_add((230_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((3_437_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_023_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(65_458_000 as u64)
+			.saturating_add(Weight::from_ref_time(230_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(3_437_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_023_000 as u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn request_judgement(r: u32, x: u32, ) -> Weight {
-		(75_299_000 as Weight)
-			.saturating_add((493_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_014_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(75_299_000 as u64)
+			.saturating_add(Weight::from_ref_time(493_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_014_000 as u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel_request(r: u32, x: u32, ) -> Weight {
-		(67_492_000 as Weight)
-			.saturating_add((225_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_003_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(67_492_000 as u64)
+			.saturating_add(Weight::from_ref_time(225_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_003_000 as u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_fee(r: u32, ) -> Weight {
-		(11_375_000 as Weight)
-			.saturating_add((382_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(11_375_000 as u64)
+			.saturating_add(Weight::from_ref_time(382_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_account_id(r: u32, ) -> Weight {
-		(12_898_000 as Weight)
-			.saturating_add((384_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(12_898_000 as u64)
+			.saturating_add(Weight::from_ref_time(384_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn set_fields(r: u32, ) -> Weight {
-		(11_419_000 as Weight)
-			.saturating_add((381_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(11_419_000 as u64)
+			.saturating_add(Weight::from_ref_time(381_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn provide_judgement(r: u32, x: u32, ) -> Weight {
-		(51_115_000 as Weight)
-			.saturating_add((427_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((2_001_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(51_115_000 as u64)
+			.saturating_add(Weight::from_ref_time(427_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(2_001_000 as u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn kill_identity(_r: u32, s: u32, _x: u32, ) -> Weight {
-		(90_911_000 as Weight)
-			.saturating_add((3_450_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+		Weight::from_ref_time(90_911_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_450_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(s as u64)))
 	}
 	fn add_sub(s: u32, ) -> Weight {
-		(76_957_000 as Weight)
-			.saturating_add((261_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(76_957_000 as u64)
+			.saturating_add(Weight::from_ref_time(261_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn rename_sub(s: u32, ) -> Weight {
-		(26_219_000 as Weight)
-			.saturating_add((84_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(26_219_000 as u64)
+			.saturating_add(Weight::from_ref_time(84_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_sub(s: u32, ) -> Weight {
-		(73_130_000 as Weight)
-			.saturating_add((239_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(73_130_000 as u64)
+			.saturating_add(Weight::from_ref_time(239_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn quit_sub(s: u32, ) -> Weight {
-		(48_088_000 as Weight)
-			.saturating_add((237_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(48_088_000 as u64)
+			.saturating_add(Weight::from_ref_time(237_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -50,21 +50,21 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn validate_unsigned_and_then_heartbeat(k: u32, e: u32, ) -> Weight {
-		(114_379_000 as Weight)
-			.saturating_add((219_000 as Weight).saturating_mul(k as Weight))
-			.saturating_add((481_000 as Weight).saturating_mul(e as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(114_379_000 as u64)
+			.saturating_add(Weight::from_ref_time(219_000 as u64).saturating_mul(k as u64))
+			.saturating_add(Weight::from_ref_time(481_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn validate_unsigned_and_then_heartbeat(k: u32, e: u32, ) -> Weight {
-		(114_379_000 as Weight)
-			.saturating_add((219_000 as Weight).saturating_mul(k as Weight))
-			.saturating_add((481_000 as Weight).saturating_mul(e as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(114_379_000 as u64)
+			.saturating_add(Weight::from_ref_time(219_000 as u64).saturating_mul(k as u64))
+			.saturating_add(Weight::from_ref_time(481_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 }
@@ -57,103 +57,103 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn add_member(m: u32, ) -> Weight {
-		(25_448_000 as Weight)
+		Weight::from_ref_time(25_448_000 as u64)
 			// Standard Error: 3_000
-			.saturating_add((257_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(257_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn remove_member(m: u32, ) -> Weight {
-		(31_317_000 as Weight)
+		Weight::from_ref_time(31_317_000 as u64)
 			// Standard Error: 0
-			.saturating_add((215_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn swap_member(m: u32, ) -> Weight {
-		(31_208_000 as Weight)
+		Weight::from_ref_time(31_208_000 as u64)
 			// Standard Error: 0
-			.saturating_add((229_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(229_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn reset_member(m: u32, ) -> Weight {
-		(31_673_000 as Weight)
+		Weight::from_ref_time(31_673_000 as u64)
 			// Standard Error: 1_000
-			.saturating_add((455_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(455_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn change_key(m: u32, ) -> Weight {
-		(33_499_000 as Weight)
+		Weight::from_ref_time(33_499_000 as u64)
 			// Standard Error: 0
-			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(Weight::from_ref_time(226_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	fn set_prime(m: u32, ) -> Weight {
-		(8_865_000 as Weight)
+		Weight::from_ref_time(8_865_000 as u64)
 			// Standard Error: 0
-			.saturating_add((124_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(124_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn clear_prime(m: u32, ) -> Weight {
-		(3_397_000 as Weight)
+		Weight::from_ref_time(3_397_000 as u64)
 			// Standard Error: 0
-			.saturating_add((1_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn add_member(m: u32, ) -> Weight {
-		(25_448_000 as Weight)
+		Weight::from_ref_time(25_448_000 as u64)
 			// Standard Error: 3_000
-			.saturating_add((257_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(257_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn remove_member(m: u32, ) -> Weight {
-		(31_317_000 as Weight)
+		Weight::from_ref_time(31_317_000 as u64)
 			// Standard Error: 0
-			.saturating_add((215_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn swap_member(m: u32, ) -> Weight {
-		(31_208_000 as Weight)
+		Weight::from_ref_time(31_208_000 as u64)
 			// Standard Error: 0
-			.saturating_add((229_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(229_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn reset_member(m: u32, ) -> Weight {
-		(31_673_000 as Weight)
+		Weight::from_ref_time(31_673_000 as u64)
 			// Standard Error: 1_000
-			.saturating_add((455_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(Weight::from_ref_time(455_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn change_key(m: u32, ) -> Weight {
-		(33_499_000 as Weight)
+		Weight::from_ref_time(33_499_000 as u64)
 			// Standard Error: 0
-			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(Weight::from_ref_time(226_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
 	fn set_prime(m: u32, ) -> Weight {
-		(8_865_000 as Weight)
+		Weight::from_ref_time(8_865_000 as u64)
 			// Standard Error: 0
-			.saturating_add((124_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(124_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn clear_prime(m: u32, ) -> Weight {
-		(3_397_000 as Weight)
+		Weight::from_ref_time(3_397_000 as u64)
 			// Standard Error: 0
-			.saturating_add((1_000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 }
@@ -77,6 +77,9 @@ pub trait MmrApi<BlockHash> {
 	///
 	/// Returns the (full) leaf itself and a proof for this leaf (compact encoding, i.e. hash of
 	/// the leaf). Both parameters are SCALE-encoded.
+	///
+	/// The runtime reads the MMR nodes from the offchain database, so the offchain calls must be
+	/// granted the `OffchainDbRead` capability, e.g. with `--offchain-call-capability`.
 	#[rpc(name = "mmr_generateProof")]
 	fn generate_proof(
 		&self,
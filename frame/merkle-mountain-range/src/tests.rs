@@ -105,7 +105,7 @@ fn should_start_empty() {
 			crate::RootHash::<Test>::get(),
 			hex("4320435e8c3318562dba60116bdbcc0b82ffcecb9bb39aae3300cfda3ad0b8b0")
 		);
-		assert!(weight != 0);
+		assert!(!weight.is_zero());
 	});
 }
 
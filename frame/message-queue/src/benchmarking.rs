@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message queue pallet benchmarking.
+//!
+//! The weight of the message processor is not part of these benchmarks, it is added on top of
+//! them when servicing the queues.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+
+use crate::Pallet as MessageQueue;
+
+/// A message of the maximal length.
+fn max_message<T: Config>() -> Vec<u8> {
+	vec![0; MaxMessageLenOf::<T>::get() as usize]
+}
+
+/// Store a page behind the serviced pages of the queue of `origin`, stale enough to be reaped,
+/// holding a single message of the maximal length which is not processed.
+fn setup_stale_page<T: Config>(origin: &MessageOriginOf<T>) {
+	let end = T::MaxStale::get().saturating_add(1);
+	let book = BookState { begin: end, end, count: 1, ..Default::default() };
+	BookStateFor::<T>::insert(origin, &book);
+	Pages::<T>::insert(origin, 0, Page::<T::HeapSize>::from_message(&max_message::<T>()));
+}
+
+benchmarks! {
+	where_clause { where MessageOriginOf<T>: From<u32> }
+
+	// An empty queue.
+	service_queue_base {
+		let origin: MessageOriginOf<T> = 0.into();
+		let mut meter = WeightMeter::from_limit(Weight::max_value());
+	}: {
+		MessageQueue::<T>::service_queue(origin, &mut meter, Weight::max_value());
+	}
+
+	// A page whose only message is processed already.
+	service_page_base {
+		let origin: MessageOriginOf<T> = 0.into();
+		let mut page = Page::<T::HeapSize>::from_message(&max_message::<T>());
+		page.note_processed_at_pos(0);
+		Pages::<T>::insert(&origin, 0, &page);
+		let mut book = BookState { end: 1, count: 1, ..Default::default() };
+		let mut meter = WeightMeter::from_limit(Weight::max_value());
+	}: {
+		MessageQueue::<T>::service_page(&origin, &mut book, &mut meter, Weight::max_value());
+	}
+	verify {
+		assert!(!Pages::<T>::contains_key(&origin, 0));
+	}
+
+	// A message of the maximal length, handed to the processor without any weight.
+	service_message {
+		let origin: MessageOriginOf<T> = 0.into();
+		let message = max_message::<T>();
+		let mut meter = WeightMeter::from_limit(0);
+	}: {
+		MessageQueue::<T>::process_message_payload(
+			origin,
+			0,
+			0,
+			&message,
+			&mut meter,
+			Weight::max_value(),
+		);
+	}
+
+	reap_page {
+		let origin: MessageOriginOf<T> = 0.into();
+		setup_stale_page::<T>(&origin);
+	}: _(RawOrigin::Signed(whitelisted_caller()), origin.clone(), 0)
+	verify {
+		assert!(!Pages::<T>::contains_key(&origin, 0));
+	}
+
+	// The processor may reject the message without any weight, only the storage accesses around
+	// it are measured.
+	execute_overweight {
+		let origin: MessageOriginOf<T> = 0.into();
+		setup_stale_page::<T>(&origin);
+	}: {
+		let _ = MessageQueue::<T>::do_execute_overweight(origin, 0, 0, 0);
+	}
+}
+
+impl_benchmark_test_suite!(
+	MessageQueue,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
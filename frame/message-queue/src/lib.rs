@@ -0,0 +1,795 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Message Queue Pallet
+//!
+//! A pallet keeping a queue of opaque messages for each of their origins, and processing them over
+//! the blocks within a weight limit.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Other subsystems enqueue messages through the [`EnqueueMessage`] implementation of the pallet,
+//! and the messages are handed to [`Config::MessageProcessor`] in the order in which they were
+//! enqueued, one origin after the other.
+//!
+//! The messages of an origin are stored in pages, each of which holds at most
+//! [`Config::HeapSize`] bytes of messages, so that servicing a queue never needs to load more than
+//! one page at a time. The origins with messages to process form a ring, which is serviced in
+//! `on_initialize` with [`Config::ServiceWeight`], starting each block with the origin at which
+//! the previous block stopped.
+//!
+//! The outcome of processing a message depends on the error returned by the processor:
+//!
+//! - the messages which fail with a permanent error are dropped.
+//! - the messages which yield, or which need more weight than is left in the block, are kept and
+//!   processed again in a later block; the rest of their queue waits for them.
+//! - the messages which need more weight than [`Config::ServiceWeight`] could ever provide are
+//!   skipped and kept in their page, from which they can be executed with
+//!   [`Pallet::execute_overweight`] and an explicit weight limit.
+//!
+//! A page whose overweight messages are not executed stays around after its queue has moved on.
+//! Once [`Config::MaxStale`] newer pages were enqueued to the queue, anyone can remove it with
+//! [`Pallet::reap_page`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::bounded_vec::{BoundedSlice, BoundedVec},
+	traits::{EnqueueMessage, Footprint, Get, ProcessMessage, ProcessMessageError},
+	weights::Weight,
+	RuntimeDebug,
+};
+use sp_runtime::traits::Hash;
+use sp_std::{convert::TryFrom, marker::PhantomData, prelude::*};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The index of a page in the queue of an origin.
+pub type PageIndex = u32;
+
+/// The origin of the messages processed by the pallet.
+pub type MessageOriginOf<T> = <<T as Config>::MessageProcessor as ProcessMessage>::Origin;
+
+/// The size of the encoded [`ItemHeader`] preceding each message in the heap of a page.
+const ITEM_HEADER_SIZE: u32 = 5;
+
+/// The header of a message in the heap of a page.
+#[derive(Copy, Clone, Encode, Decode)]
+struct ItemHeader {
+	/// The length of the message which follows the header.
+	payload_len: u32,
+	/// Whether the message was processed, or dropped.
+	is_processed: bool,
+}
+
+/// A page of messages, stored back to back in its heap, each behind its [`ItemHeader`].
+#[derive(Encode, Decode)]
+pub struct Page<HeapSize: Get<u32>> {
+	/// The number of messages not yet processed, including the skipped overweight ones.
+	remaining: u32,
+	/// The total size of the messages not yet processed.
+	remaining_size: u32,
+	/// The offset in the heap of the first message which is not yet serviced.
+	first: u32,
+	/// The index of the first message which is not yet serviced.
+	first_index: u32,
+	/// The messages.
+	heap: BoundedVec<u8, HeapSize>,
+}
+
+impl<HeapSize: Get<u32>> Page<HeapSize> {
+	/// A page holding only `message`.
+	fn from_message(message: &[u8]) -> Self {
+		let mut page = Page {
+			remaining: 0,
+			remaining_size: 0,
+			first: 0,
+			first_index: 0,
+			heap: BoundedVec::default(),
+		};
+		let _appended = page.try_append_message(message);
+		debug_assert!(_appended.is_ok(), "messages are bounded by `MaxMessageLenOf`; qed");
+		page
+	}
+
+	/// Append `message` to the page, unless it does not fit in the heap.
+	fn try_append_message(&mut self, message: &[u8]) -> Result<(), ()> {
+		let size = self.heap.len()
+			.saturating_add(ITEM_HEADER_SIZE as usize)
+			.saturating_add(message.len());
+		if size > HeapSize::get() as usize {
+			return Err(())
+		}
+
+		let header = ItemHeader { payload_len: message.len() as u32, is_processed: false };
+		let mut heap = sp_std::mem::take(&mut self.heap).into_inner();
+		header.encode_to(&mut heap);
+		heap.extend_from_slice(message);
+		self.heap = BoundedVec::try_from(heap).map_err(|_| ())?;
+		self.remaining = self.remaining.saturating_add(1);
+		self.remaining_size = self.remaining_size.saturating_add(message.len() as u32);
+		Ok(())
+	}
+
+	/// The header and the message at `pos` in the heap, if any.
+	fn item_at(&self, pos: usize) -> Option<(ItemHeader, &[u8])> {
+		let payload_start = pos.checked_add(ITEM_HEADER_SIZE as usize)?;
+		let header = ItemHeader::decode(&mut self.heap.get(pos..payload_start)?).ok()?;
+		let payload_end = payload_start.checked_add(header.payload_len as usize)?;
+		Some((header, self.heap.get(payload_start..payload_end)?))
+	}
+
+	/// The first message which is not yet serviced, if any.
+	fn peek_first(&self) -> Option<(ItemHeader, &[u8])> {
+		self.item_at(self.first as usize)
+	}
+
+	/// The position in the heap, the header and the message with the given index, if any.
+	fn peek_index(&self, index: u32) -> Option<(usize, ItemHeader, &[u8])> {
+		let mut pos = 0usize;
+		for _ in 0..index {
+			let (header, _) = self.item_at(pos)?;
+			pos = pos
+				.saturating_add(ITEM_HEADER_SIZE as usize)
+				.saturating_add(header.payload_len as usize);
+		}
+		let (header, payload) = self.item_at(pos)?;
+		Some((pos, header, payload))
+	}
+
+	/// Move past the first message which is not yet serviced, noting it as processed if
+	/// `is_processed`.
+	fn skip_first(&mut self, is_processed: bool) {
+		if let Some((header, _)) = self.peek_first() {
+			if is_processed {
+				self.note_processed_at_pos(self.first as usize);
+			}
+			self.first = self.first
+				.saturating_add(ITEM_HEADER_SIZE)
+				.saturating_add(header.payload_len);
+			self.first_index = self.first_index.saturating_add(1);
+		}
+	}
+
+	/// Note the message at `pos` in the heap as processed.
+	fn note_processed_at_pos(&mut self, pos: usize) {
+		let mut header = match self.item_at(pos) {
+			Some((header, _)) if !header.is_processed => header,
+			_ => return,
+		};
+		header.is_processed = true;
+		self.remaining = self.remaining.saturating_sub(1);
+		self.remaining_size = self.remaining_size.saturating_sub(header.payload_len);
+
+		let encoded = header.encode();
+		self.heap.as_mut()[pos..pos + encoded.len()].copy_from_slice(&encoded);
+	}
+
+	/// Whether all the messages of the page are processed.
+	fn is_complete(&self) -> bool {
+		self.remaining == 0
+	}
+}
+
+/// The neighbours of an origin in the ring of the origins with messages to service.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Neighbours<MessageOrigin> {
+	/// The previous origin in the ring.
+	pub prev: MessageOrigin,
+	/// The next origin in the ring.
+	pub next: MessageOrigin,
+}
+
+/// The state of the queue of an origin.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct BookState<MessageOrigin> {
+	/// The first page which is still serviced. The earlier pages are either removed, or stale.
+	pub begin: PageIndex,
+	/// One more than the last page of the queue.
+	pub end: PageIndex,
+	/// The number of stored pages, including the stale ones.
+	pub count: PageIndex,
+	/// The neighbours of the origin in the ready ring, if the queue has messages to service.
+	pub ready_neighbours: Option<Neighbours<MessageOrigin>>,
+	/// The number of messages not yet processed, including the skipped overweight ones.
+	pub message_count: u64,
+	/// The total size of the messages not yet processed.
+	pub size: u64,
+}
+
+impl<MessageOrigin> Default for BookState<MessageOrigin> {
+	fn default() -> Self {
+		Self { begin: 0, end: 0, count: 0, ready_neighbours: None, message_count: 0, size: 0 }
+	}
+}
+
+/// The maximal length of a message for the heap size of `T`.
+pub struct MaxMessageLenOf<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxMessageLenOf<T> {
+	fn get() -> u32 {
+		T::HeapSize::get().saturating_sub(ITEM_HEADER_SIZE)
+	}
+}
+
+/// Keeps track of the weight consumed out of a limit.
+pub(crate) struct WeightMeter {
+	consumed: Weight,
+	limit: Weight,
+}
+
+impl WeightMeter {
+	fn from_limit(limit: Weight) -> Self {
+		Self { consumed: 0, limit }
+	}
+
+	fn remaining(&self) -> Weight {
+		self.limit.saturating_sub(self.consumed)
+	}
+
+	/// Consume `weight` if it fits in the remaining weight.
+	fn try_consume(&mut self, weight: Weight) -> bool {
+		let consumed = self.consumed.saturating_add(weight);
+		if consumed > self.limit {
+			return false
+		}
+		self.consumed = consumed;
+		true
+	}
+}
+
+/// The outcome of servicing a page.
+enum PageExecutionStatus {
+	/// All the messages of the page are serviced.
+	NoMore,
+	/// The servicing stopped before the end of the page.
+	Bailed,
+}
+
+/// The outcome of processing a message.
+enum MessageExecutionStatus {
+	/// The message was processed.
+	Processed,
+	/// The message failed permanently, and is dropped.
+	Unprocessable,
+	/// The message needs more weight than may ever be available, and is skipped.
+	Overweight,
+	/// The message needs more weight than is left, and is processed again later on.
+	InsufficientWeight,
+	/// The processor yielded, and the message is processed again later on.
+	Yield,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Processes the enqueued messages.
+		type MessageProcessor: ProcessMessage;
+
+		/// The size of the heap of a page in bytes, which also bounds the length of a message.
+		#[pallet::constant]
+		type HeapSize: Get<u32>;
+
+		/// The number of newer pages of a queue after which a page with messages left to execute
+		/// can be reaped by anyone.
+		#[pallet::constant]
+		type MaxStale: Get<u32>;
+
+		/// The weight used to service the queues in `on_initialize`.
+		///
+		/// `None` disables the servicing in `on_initialize`, which is then up to other pallets
+		/// calling [`Pallet::service_queues`].
+		type ServiceWeight: Get<Option<Weight>>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(T::Hash = "Hash", MessageOriginOf<T> = "MessageOrigin")]
+	pub enum Event<T: Config> {
+		/// A message failed permanently, and was dropped. \[hash, origin, error\]
+		ProcessingFailed(T::Hash, MessageOriginOf<T>, ProcessMessageError),
+		/// A message was processed. \[hash, origin, weight_used\]
+		Processed(T::Hash, MessageOriginOf<T>, Weight),
+		/// A message needs more weight than the servicing of the queues may ever provide, and was
+		/// skipped. It can be executed with `execute_overweight`. \[hash, origin, page, index\]
+		OverweightEnqueued(T::Hash, MessageOriginOf<T>, PageIndex, u32),
+		/// A stale page was removed. \[origin, page\]
+		PageReaped(MessageOriginOf<T>, PageIndex),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The page is not stale enough to be reaped.
+		NotReapable,
+		/// The page does not exist.
+		NoPage,
+		/// The message does not exist.
+		NoMessage,
+		/// The message is processed already.
+		AlreadyProcessed,
+		/// The message is still queued, and will be serviced with the rest of its queue.
+		Queued,
+		/// The weight limit is too low to execute the message.
+		InsufficientWeight,
+		/// The message processor yielded, the message can be executed again later on.
+		TemporarilyUnprocessable,
+	}
+
+	/// The state of the queue of each origin.
+	#[pallet::storage]
+	pub type BookStateFor<T: Config> = StorageMap<
+		_,
+		Twox64Concat, MessageOriginOf<T>,
+		BookState<MessageOriginOf<T>>,
+		ValueQuery,
+	>;
+
+	/// The origin from which the servicing of the queues starts, if any origin has messages to
+	/// service.
+	#[pallet::storage]
+	pub type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>>;
+
+	/// The pages of the queue of each origin.
+	#[pallet::storage]
+	pub type Pages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat, MessageOriginOf<T>,
+		Twox64Concat, PageIndex,
+		Page<T::HeapSize>,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			match T::ServiceWeight::get() {
+				Some(weight_limit) => Self::service_queues(weight_limit),
+				None => 0,
+			}
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::HeapSize::get() > ITEM_HEADER_SIZE,
+				"the heap of a page must be large enough for a message",
+			);
+			assert!(T::MaxStale::get() > 0, "pages must not be reapable right away");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Remove a stale page, and the messages left to execute in it.
+		///
+		/// The dispatch origin must be signed. The page must be behind the serviced pages of its
+		/// queue, and at least [`Config::MaxStale`] newer pages must have been enqueued after it.
+		#[pallet::weight(T::WeightInfo::reap_page())]
+		pub fn reap_page(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			page_index: PageIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_reap_page(&message_origin, page_index)
+		}
+
+		/// Execute an overweight message, using no more than `weight_limit`.
+		///
+		/// The dispatch origin must be signed. Only the messages which were skipped as overweight
+		/// by the servicing of their queue can be executed.
+		#[pallet::weight(T::WeightInfo::execute_overweight().saturating_add(*weight_limit))]
+		pub fn execute_overweight(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			page: PageIndex,
+			index: u32,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let weight_used =
+				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
+			Ok(Some(T::WeightInfo::execute_overweight().saturating_add(weight_used)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Service the queues of the origins in the ready ring, one after the other, within
+	/// `weight_limit`.
+	///
+	/// Returns the consumed weight.
+	pub fn service_queues(weight_limit: Weight) -> Weight {
+		let mut meter = WeightMeter::from_limit(weight_limit);
+		let mut next = match ServiceHead::<T>::get() {
+			Some(head) => head,
+			None => return Weight::zero(),
+		};
+
+		// the first origin of the current streak of origins without progress; the servicing stops
+		// once the whole ring is visited without any progress.
+		let mut first_without_progress = None;
+		loop {
+			let (progressed, maybe_next) =
+				Self::service_queue(next.clone(), &mut meter, weight_limit);
+			let n = match maybe_next {
+				Some(n) => n,
+				None => {
+					if ServiceHead::<T>::exists() {
+						ServiceHead::<T>::put(&next);
+					}
+					break
+				},
+			};
+
+			if progressed {
+				first_without_progress = None;
+			} else if *first_without_progress.get_or_insert_with(|| next.clone()) == n {
+				ServiceHead::<T>::put(&n);
+				break
+			}
+			next = n;
+		}
+		meter.consumed
+	}
+
+	/// Service the queue of `origin` with the weight left in `meter`.
+	///
+	/// Returns whether any message was serviced, and the next origin of the ready ring, unless
+	/// the ring is empty or no weight is left.
+	fn service_queue(
+		origin: MessageOriginOf<T>,
+		meter: &mut WeightMeter,
+		overweight_limit: Weight,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		if !meter.try_consume(T::WeightInfo::service_queue_base()) {
+			return (false, None)
+		}
+
+		let mut book = BookStateFor::<T>::get(&origin);
+		let mut serviced = 0;
+		while book.end > book.begin {
+			let (page_serviced, status) =
+				Self::service_page(&origin, &mut book, meter, overweight_limit);
+			serviced += page_serviced;
+			if let PageExecutionStatus::Bailed = status {
+				break
+			}
+		}
+
+		let next = match book.ready_neighbours.clone() {
+			Some(neighbours) if book.begin >= book.end => {
+				book.ready_neighbours = None;
+				Self::ready_ring_unknit(&origin, neighbours.clone());
+				Some(neighbours.next).filter(|next| *next != origin)
+			},
+			neighbours => neighbours.map(|n| n.next),
+		};
+		BookStateFor::<T>::insert(&origin, &book);
+		(serviced > 0, next)
+	}
+
+	/// Service the first page of the queue of `origin` with the weight left in `meter`.
+	///
+	/// Returns the number of serviced messages.
+	fn service_page(
+		origin: &MessageOriginOf<T>,
+		book: &mut BookState<MessageOriginOf<T>>,
+		meter: &mut WeightMeter,
+		overweight_limit: Weight,
+	) -> (u32, PageExecutionStatus) {
+		if !meter.try_consume(T::WeightInfo::service_page_base()) {
+			return (0, PageExecutionStatus::Bailed)
+		}
+
+		let page_index = book.begin;
+		let mut page = match Pages::<T>::get(origin, page_index) {
+			Some(page) => page,
+			None => {
+				debug_assert!(false, "the pages between `begin` and `end` are stored; qed");
+				book.begin = book.begin.saturating_add(1);
+				return (0, PageExecutionStatus::NoMore)
+			},
+		};
+
+		let mut serviced = 0;
+		let status = loop {
+			let (header, message) = match page.peek_first() {
+				Some(item) => item,
+				None => break PageExecutionStatus::NoMore,
+			};
+			if header.is_processed {
+				page.skip_first(false);
+				continue
+			}
+			if !meter.try_consume(T::WeightInfo::service_message()) {
+				break PageExecutionStatus::Bailed
+			}
+
+			let message_len = message.len() as u64;
+			let status = Self::process_message_payload(
+				origin.clone(),
+				page_index,
+				page.first_index,
+				message,
+				meter,
+				overweight_limit,
+			);
+			match status {
+				MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => {
+					page.skip_first(true);
+					book.message_count = book.message_count.saturating_sub(1);
+					book.size = book.size.saturating_sub(message_len);
+				},
+				MessageExecutionStatus::Overweight => page.skip_first(false),
+				MessageExecutionStatus::InsufficientWeight | MessageExecutionStatus::Yield =>
+					break PageExecutionStatus::Bailed,
+			}
+			serviced += 1;
+		};
+
+		match status {
+			PageExecutionStatus::NoMore if page.is_complete() => {
+				Pages::<T>::remove(origin, page_index);
+				book.count = book.count.saturating_sub(1);
+				book.begin = book.begin.saturating_add(1);
+			},
+			PageExecutionStatus::NoMore => {
+				// the page keeps its overweight messages, and becomes stale.
+				Pages::<T>::insert(origin, page_index, &page);
+				book.begin = book.begin.saturating_add(1);
+			},
+			PageExecutionStatus::Bailed => Pages::<T>::insert(origin, page_index, &page),
+		}
+		(serviced, status)
+	}
+
+	/// Hand `message` to the message processor with the weight left in `meter`.
+	///
+	/// Messages needing more than `overweight_limit` are reported as overweight.
+	fn process_message_payload(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		message_index: u32,
+		message: &[u8],
+		meter: &mut WeightMeter,
+		overweight_limit: Weight,
+	) -> MessageExecutionStatus {
+		let hash = T::Hashing::hash(message);
+		match T::MessageProcessor::process_message(message, origin.clone(), meter.remaining()) {
+			Ok(weight_used) => {
+				meter.consumed = meter.consumed.saturating_add(weight_used);
+				Self::deposit_event(Event::<T>::Processed(hash, origin, weight_used));
+				MessageExecutionStatus::Processed
+			},
+			Err(ProcessMessageError::Overweight(required)) if required.any_gt(&overweight_limit) => {
+				Self::deposit_event(
+					Event::<T>::OverweightEnqueued(hash, origin, page_index, message_index),
+				);
+				MessageExecutionStatus::Overweight
+			},
+			Err(ProcessMessageError::Overweight(_)) => MessageExecutionStatus::InsufficientWeight,
+			Err(ProcessMessageError::Yield) => MessageExecutionStatus::Yield,
+			Err(error) => {
+				debug_assert!(error.is_permanent());
+				Self::deposit_event(Event::<T>::ProcessingFailed(hash, origin, error));
+				MessageExecutionStatus::Unprocessable
+			},
+		}
+	}
+
+	/// Execute the overweight message with the given index in `page_index` of the queue of
+	/// `origin`, using no more than `weight_limit`.
+	///
+	/// Returns the weight used by the message processor.
+	pub(crate) fn do_execute_overweight(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		index: u32,
+		weight_limit: Weight,
+	) -> Result<Weight, Error<T>> {
+		let mut book = BookStateFor::<T>::get(&origin);
+		let mut page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
+		let (pos, header, message) = page.peek_index(index).ok_or(Error::<T>::NoMessage)?;
+		ensure!(!header.is_processed, Error::<T>::AlreadyProcessed);
+		let is_skipped = page_index < book.begin ||
+			(page_index == book.begin && pos < page.first as usize);
+		ensure!(is_skipped, Error::<T>::Queued);
+
+		let message_len = message.len() as u64;
+		let mut meter = WeightMeter::from_limit(weight_limit);
+		let status = Self::process_message_payload(
+			origin.clone(),
+			page_index,
+			index,
+			message,
+			&mut meter,
+			Weight::max_value(),
+		);
+		match status {
+			MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => (),
+			MessageExecutionStatus::Overweight | MessageExecutionStatus::InsufficientWeight =>
+				return Err(Error::<T>::InsufficientWeight),
+			MessageExecutionStatus::Yield => return Err(Error::<T>::TemporarilyUnprocessable),
+		}
+
+		page.note_processed_at_pos(pos);
+		book.message_count = book.message_count.saturating_sub(1);
+		book.size = book.size.saturating_sub(message_len);
+		if page_index < book.begin && page.is_complete() {
+			Pages::<T>::remove(&origin, page_index);
+			book.count = book.count.saturating_sub(1);
+		} else {
+			Pages::<T>::insert(&origin, page_index, &page);
+		}
+		BookStateFor::<T>::insert(&origin, &book);
+		Ok(meter.consumed)
+	}
+
+	/// Remove the stale page `page_index` of the queue of `origin`.
+	pub(crate) fn do_reap_page(
+		origin: &MessageOriginOf<T>,
+		page_index: PageIndex,
+	) -> DispatchResult {
+		let mut book = BookStateFor::<T>::get(origin);
+		ensure!(page_index < book.begin, Error::<T>::NotReapable);
+		ensure!(book.end.saturating_sub(page_index) > T::MaxStale::get(), Error::<T>::NotReapable);
+		let page = Pages::<T>::take(origin, page_index).ok_or(Error::<T>::NoPage)?;
+
+		book.count = book.count.saturating_sub(1);
+		book.message_count = book.message_count.saturating_sub(page.remaining.into());
+		book.size = book.size.saturating_sub(page.remaining_size.into());
+		BookStateFor::<T>::insert(origin, &book);
+		Self::deposit_event(Event::<T>::PageReaped(origin.clone(), page_index));
+		Ok(())
+	}
+
+	/// Append `message` to the queue of `origin`, adding the origin to the ready ring if needed.
+	fn do_enqueue_message(origin: &MessageOriginOf<T>, message: &[u8]) {
+		let mut book = BookStateFor::<T>::get(origin);
+		book.message_count = book.message_count.saturating_add(1);
+		book.size = book.size.saturating_add(message.len() as u64);
+
+		if book.end > book.begin {
+			debug_assert!(book.ready_neighbours.is_some(), "a queue with pages is ready; qed");
+			let last = book.end - 1;
+			if let Some(mut page) = Pages::<T>::get(origin, last) {
+				if page.try_append_message(message).is_ok() {
+					Pages::<T>::insert(origin, last, &page);
+					BookStateFor::<T>::insert(origin, &book);
+					return
+				}
+			}
+		} else {
+			debug_assert!(book.ready_neighbours.is_none(), "an empty queue is not ready; qed");
+			book.ready_neighbours = Some(Self::ready_ring_knit(origin));
+		}
+
+		Pages::<T>::insert(origin, book.end, Page::<T::HeapSize>::from_message(message));
+		book.end = book.end.saturating_add(1);
+		book.count = book.count.saturating_add(1);
+		BookStateFor::<T>::insert(origin, &book);
+	}
+
+	/// Add `origin` to the ready ring, right before the service head.
+	///
+	/// Returns the neighbours of `origin`, which the caller must store in its book.
+	fn ready_ring_knit(origin: &MessageOriginOf<T>) -> Neighbours<MessageOriginOf<T>> {
+		let head = match ServiceHead::<T>::get() {
+			Some(head) => head,
+			None => {
+				ServiceHead::<T>::put(origin);
+				return Neighbours { prev: origin.clone(), next: origin.clone() }
+			},
+		};
+
+		let mut head_book = BookStateFor::<T>::get(&head);
+		let tail = match head_book.ready_neighbours.as_mut() {
+			Some(neighbours) => sp_std::mem::replace(&mut neighbours.prev, origin.clone()),
+			None => {
+				debug_assert!(false, "the service head is in the ready ring; qed");
+				head.clone()
+			},
+		};
+		BookStateFor::<T>::insert(&head, head_book);
+		BookStateFor::<T>::mutate(&tail, |book| {
+			if let Some(neighbours) = book.ready_neighbours.as_mut() {
+				neighbours.next = origin.clone();
+			}
+		});
+		Neighbours { prev: tail, next: head }
+	}
+
+	/// Remove `origin`, whose neighbours are `neighbours`, from the ready ring.
+	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
+		if neighbours.next == *origin {
+			ServiceHead::<T>::kill();
+			return
+		}
+
+		BookStateFor::<T>::mutate(&neighbours.prev, |book| {
+			if let Some(prev_neighbours) = book.ready_neighbours.as_mut() {
+				prev_neighbours.next = neighbours.next.clone();
+			}
+		});
+		BookStateFor::<T>::mutate(&neighbours.next, |book| {
+			if let Some(next_neighbours) = book.ready_neighbours.as_mut() {
+				next_neighbours.prev = neighbours.prev.clone();
+			}
+		});
+		if ServiceHead::<T>::get().as_ref() == Some(origin) {
+			ServiceHead::<T>::put(&neighbours.next);
+		}
+	}
+}
+
+impl<T: Config> EnqueueMessage<MessageOriginOf<T>> for Pallet<T> {
+	type MaxMessageLen = MaxMessageLenOf<T>;
+
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: MessageOriginOf<T>) {
+		Self::do_enqueue_message(&origin, message.into());
+	}
+
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: MessageOriginOf<T>,
+	) {
+		for message in messages {
+			Self::do_enqueue_message(&origin, message.into());
+		}
+	}
+
+	fn sweep_queue(origin: MessageOriginOf<T>) {
+		let book = BookStateFor::<T>::take(&origin);
+		if let Some(neighbours) = book.ready_neighbours {
+			Self::ready_ring_unknit(&origin, neighbours);
+		}
+		Pages::<T>::remove_prefix(&origin);
+	}
+
+	fn footprint(origin: MessageOriginOf<T>) -> Footprint {
+		let book = BookStateFor::<T>::get(&origin);
+		Footprint { count: book.message_count, size: book.size }
+	}
+}
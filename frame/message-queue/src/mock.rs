@@ -0,0 +1,180 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use crate as pallet_message_queue;
+use crate::*;
+use frame_support::{parameter_types, traits::OnInitialize};
+use sp_runtime::traits::IdentityLookup;
+use std::cell::RefCell;
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+pub type MessageOrigin = u32;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = sp_core::H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = sp_runtime::testing::Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+/// Weights which leave the whole weight to the messages.
+pub struct ZeroWeights;
+impl crate::weights::WeightInfo for ZeroWeights {
+	fn service_queue_base() -> Weight {
+		Weight::zero()
+	}
+	fn service_page_base() -> Weight {
+		Weight::zero()
+	}
+	fn service_message() -> Weight {
+		Weight::zero()
+	}
+	fn reap_page() -> Weight {
+		Weight::zero()
+	}
+	fn execute_overweight() -> Weight {
+		Weight::zero()
+	}
+}
+
+thread_local! {
+	/// The messages processed so far, with their origin.
+	pub static MESSAGES_PROCESSED: RefCell<Vec<(Vec<u8>, MessageOrigin)>> = RefCell::new(vec![]);
+}
+
+/// Processes the messages according to their content:
+///
+/// - `yield` yields.
+/// - `badformat` fails with `BadFormat`.
+/// - `weight=<n>` needs a weight of `n`.
+/// - any other message needs a weight of 1.
+pub struct RecordingMessageProcessor;
+impl ProcessMessage for RecordingMessageProcessor {
+	type Origin = MessageOrigin;
+
+	fn process_message(
+		message: &[u8],
+		origin: MessageOrigin,
+		weight_limit: Weight,
+	) -> Result<Weight, ProcessMessageError> {
+		let required = match message {
+			b"yield" => return Err(ProcessMessageError::Yield),
+			b"badformat" => return Err(ProcessMessageError::BadFormat),
+			_ => message
+				.strip_prefix(b"weight=")
+				.and_then(|weight| std::str::from_utf8(weight).ok()?.parse().ok())
+				.unwrap_or(1),
+		};
+		if required.any_gt(&weight_limit) {
+			return Err(ProcessMessageError::Overweight(required))
+		}
+		MESSAGES_PROCESSED.with(|m| m.borrow_mut().push((message.to_vec(), origin)));
+		Ok(required)
+	}
+}
+
+parameter_types! {
+	pub const HeapSize: u32 = 40;
+	pub const MaxStale: u32 = 2;
+	pub static ServiceWeight: Option<Weight> = Some(10);
+}
+
+impl pallet_message_queue::Config for Test {
+	type Event = Event;
+	type WeightInfo = ZeroWeights;
+	type MessageProcessor = RecordingMessageProcessor;
+	type HeapSize = HeapSize;
+	type MaxStale = MaxStale;
+	type ServiceWeight = ServiceWeight;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::from(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Enqueue `message` to the queue of `origin`.
+pub fn enqueue(message: &[u8], origin: MessageOrigin) {
+	let message = BoundedSlice::try_from(message).expect("the message is short enough");
+	MessageQueue::enqueue_message(message, origin);
+}
+
+/// Run `on_initialize` of the next block.
+pub fn next_block() -> Weight {
+	let now = System::block_number() + 1;
+	System::set_block_number(now);
+	MessageQueue::on_initialize(now)
+}
+
+/// The messages processed since the last call.
+pub fn take_messages_processed() -> Vec<(Vec<u8>, MessageOrigin)> {
+	MESSAGES_PROCESSED.with(|m| m.take())
+}
+
+/// The hash of `message`.
+pub fn hash_of(message: &[u8]) -> sp_core::H256 {
+	<Test as frame_system::Config>::Hashing::hash(message)
+}
+
+/// The events of the pallet since the last call.
+pub fn message_queue_events_since_last_call() -> Vec<pallet_message_queue::Event<Test>> {
+	let events = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::MessageQueue(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+	System::reset_events();
+	events
+}
@@ -0,0 +1,287 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the message queue pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+/// A message filling a whole page of the mock.
+fn page_filler() -> Vec<u8> {
+	vec![b'a'; MaxMessageLenOf::<Test>::get() as usize]
+}
+
+#[test]
+fn enqueue_works() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(b"bc", 0);
+		enqueue(b"d", 1);
+
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 2, size: 3 });
+		assert_eq!(MessageQueue::footprint(1), Footprint { count: 1, size: 1 });
+		assert_eq!(MessageQueue::footprint(2), Footprint::default());
+		assert_eq!(ServiceHead::<Test>::get(), Some(0));
+
+		// both small messages share a page.
+		let book = BookStateFor::<Test>::get(0);
+		assert_eq!((book.begin, book.end, book.count), (0, 1, 1));
+		assert_eq!(book.ready_neighbours, Some(Neighbours { prev: 1, next: 1 }));
+
+		// a message which does not fit in the last page goes into a new one.
+		enqueue(&page_filler(), 0);
+		let book = BookStateFor::<Test>::get(0);
+		assert_eq!((book.begin, book.end, book.count), (0, 2, 2));
+		assert!(Pages::<Test>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn enqueue_rejects_too_long_messages() {
+	new_test_ext().execute_with(|| {
+		let message = vec![b'a'; MaxMessageLenOf::<Test>::get() as usize + 1];
+		assert!(BoundedSlice::<u8, MaxMessageLenOf<Test>>::try_from(&message[..]).is_err());
+	});
+}
+
+#[test]
+fn service_queues_processes_messages_in_order() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(b"b", 1);
+		enqueue(b"c", 0);
+		enqueue(&page_filler(), 0);
+
+		assert_eq!(MessageQueue::service_queues(Weight::max_value()), 4);
+		assert_eq!(
+			take_messages_processed(),
+			vec![(b"a".to_vec(), 0), (b"c".to_vec(), 0), (page_filler(), 0), (b"b".to_vec(), 1)],
+		);
+		assert_eq!(
+			message_queue_events_since_last_call()[0],
+			crate::Event::Processed(hash_of(b"a"), 0, 1),
+		);
+
+		// all the queues are empty, and so is the ready ring.
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+		assert_eq!(MessageQueue::footprint(1), Footprint::default());
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_eq!(BookStateFor::<Test>::get(0).ready_neighbours, None);
+
+		// the queues become ready again.
+		enqueue(b"d", 1);
+		assert_eq!(ServiceHead::<Test>::get(), Some(1));
+		assert_eq!(MessageQueue::service_queues(Weight::max_value()), 1);
+		assert_eq!(take_messages_processed(), vec![(b"d".to_vec(), 1)]);
+	});
+}
+
+#[test]
+fn on_initialize_respects_the_service_weight() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			enqueue(b"weight=3", 0);
+		}
+
+		assert_eq!(next_block(), 9);
+		assert_eq!(take_messages_processed().len(), 3);
+		assert_eq!(MessageQueue::footprint(0).count, 1);
+
+		assert_eq!(next_block(), 3);
+		assert_eq!(take_messages_processed().len(), 1);
+		assert_eq!(MessageQueue::footprint(0).count, 0);
+
+		// servicing can be disabled.
+		ServiceWeight::set(None);
+		enqueue(b"a", 0);
+		assert_eq!(next_block(), 0);
+		assert!(take_messages_processed().is_empty());
+	});
+}
+
+#[test]
+fn queues_are_serviced_in_turn() {
+	new_test_ext().execute_with(|| {
+		for origin in 0..2 {
+			enqueue(b"weight=5", origin);
+			enqueue(b"weight=5", origin);
+		}
+
+		let mut origins = vec![];
+		for _ in 0..4 {
+			assert_eq!(MessageQueue::service_queues(5), 5);
+			origins.extend(take_messages_processed().into_iter().map(|(_, origin)| origin));
+		}
+		assert_eq!(origins, vec![0, 1, 0, 1]);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn permanently_failing_messages_are_dropped() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"badformat", 0);
+		enqueue(b"a", 0);
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(take_messages_processed(), vec![(b"a".to_vec(), 0)]);
+		assert_eq!(
+			message_queue_events_since_last_call(),
+			vec![
+				crate::Event::ProcessingFailed(
+					hash_of(b"badformat"),
+					0,
+					ProcessMessageError::BadFormat,
+				),
+				crate::Event::Processed(hash_of(b"a"), 0, 1),
+			],
+		);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+	});
+}
+
+#[test]
+fn yielding_messages_hold_their_queue() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"yield", 0);
+		enqueue(b"a", 0);
+		enqueue(b"b", 1);
+
+		// the other queues are still serviced.
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(take_messages_processed(), vec![(b"b".to_vec(), 1)]);
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 2, size: 6 });
+		assert_eq!(ServiceHead::<Test>::get(), Some(0));
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert!(take_messages_processed().is_empty());
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 2, size: 6 });
+	});
+}
+
+#[test]
+fn overweight_messages_are_skipped_and_can_be_executed() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=20", 0);
+		enqueue(b"a", 0);
+
+		// the message needs more than the whole servicing weight.
+		assert_eq!(MessageQueue::service_queues(10), 1);
+		assert_eq!(take_messages_processed(), vec![(b"a".to_vec(), 0)]);
+		assert_eq!(
+			message_queue_events_since_last_call(),
+			vec![
+				crate::Event::OverweightEnqueued(hash_of(b"weight=20"), 0, 0, 0),
+				crate::Event::Processed(hash_of(b"a"), 0, 1),
+			],
+		);
+		assert_eq!(MessageQueue::footprint(0), Footprint { count: 1, size: 9 });
+		assert_eq!(ServiceHead::<Test>::get(), None);
+
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 1, 20),
+			Error::<Test>::AlreadyProcessed,
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 2, 20),
+			Error::<Test>::NoMessage,
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 1, 0, 20),
+			Error::<Test>::NoPage,
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 19),
+			Error::<Test>::InsufficientWeight,
+		);
+
+		assert_ok!(MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 20));
+		assert_eq!(take_messages_processed(), vec![(b"weight=20".to_vec(), 0)]);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+		assert!(!Pages::<Test>::contains_key(0, 0));
+		assert_eq!(BookStateFor::<Test>::get(0).count, 0);
+	});
+}
+
+#[test]
+fn queued_messages_cannot_be_executed() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), 0, 0, 0, 10),
+			Error::<Test>::Queued,
+		);
+	});
+}
+
+#[test]
+fn reap_page_works() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"weight=20", 0);
+		enqueue(&page_filler(), 0);
+		MessageQueue::service_queues(10);
+		message_queue_events_since_last_call();
+
+		// the page with the overweight message is stale, but not enough.
+		let book = BookStateFor::<Test>::get(0);
+		assert_eq!((book.begin, book.end, book.count), (2, 2, 1));
+		assert_noop!(
+			MessageQueue::reap_page(Origin::signed(1), 0, 0),
+			Error::<Test>::NotReapable,
+		);
+
+		enqueue(&page_filler(), 0);
+		enqueue(&page_filler(), 0);
+		assert_noop!(
+			MessageQueue::reap_page(Origin::signed(1), 0, 2),
+			Error::<Test>::NotReapable,
+		);
+		assert_noop!(
+			MessageQueue::reap_page(Origin::signed(1), 0, 1),
+			Error::<Test>::NoPage,
+		);
+		assert_ok!(MessageQueue::reap_page(Origin::signed(1), 0, 0));
+		assert_eq!(message_queue_events_since_last_call(), vec![crate::Event::PageReaped(0, 0)]);
+		assert!(!Pages::<Test>::contains_key(0, 0));
+		assert_eq!(MessageQueue::footprint(0).count, 2);
+		assert_eq!(BookStateFor::<Test>::get(0).count, 2);
+	});
+}
+
+#[test]
+fn sweep_queue_works() {
+	new_test_ext().execute_with(|| {
+		enqueue(b"a", 0);
+		enqueue(&page_filler(), 0);
+		enqueue(b"b", 1);
+		enqueue(b"c", 2);
+
+		MessageQueue::sweep_queue(0);
+		assert_eq!(MessageQueue::footprint(0), Footprint::default());
+		assert_eq!(Pages::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(ServiceHead::<Test>::get(), Some(1));
+		assert_eq!(
+			BookStateFor::<Test>::get(2).ready_neighbours,
+			Some(Neighbours { prev: 1, next: 1 }),
+		);
+
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(take_messages_processed(), vec![(b"b".to_vec(), 1), (b"c".to_vec(), 2)]);
+	});
+}
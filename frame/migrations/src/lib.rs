@@ -0,0 +1,338 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Migrations Pallet
+//!
+//! Executes multi-block migrations, i.e. migrations which are too heavy to be executed in a
+//! single block.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! The migrations implement [`SteppedMigration`]: they make progress in steps, each step being
+//! bounded by a weight limit and keeping its progress in a cursor. On a runtime upgrade the
+//! pallet starts the [`Config::Migrations`] and executes their steps at the beginning of every
+//! block, spending at most [`Config::ServiceWeight`]. A migration executes at most one step per
+//! block; the next migration is started in the same block if some weight remains.
+//!
+//! The pallet implements [`MultiStepMigrator`] and must be given to the `Executive` of the
+//! runtime, which then rejects all the extrinsics which are not mandatory until the migrations
+//! are complete, except the [`Config::ExemptCalls`]:
+//!
+//! ```ignore
+//! pub type Executive = frame_executive::Executive<
+//! 	Runtime,
+//! 	Block,
+//! 	frame_system::ChainContext<Runtime>,
+//! 	Runtime,
+//! 	AllPallets,
+//! 	(),
+//! 	Migrations,
+//! >;
+//! ```
+//!
+//! Every step is executed in a storage transaction, the changes of a failed step are discarded.
+//! When a migration fails, the [`Config::FailedMigrationHandler`] decides whether the chain stays
+//! stuck, i.e. only mandatory extrinsics and the exempt calls are applied, or the remaining
+//! migrations are abandoned. The exempt calls must include the runtime upgrades, e.g.
+//! [`RuntimeUpgradeCalls`] and their `pallet_sudo::SudoCalls` wrapping, since a runtime upgrade
+//! fixing the failed migration is the only way out: the upgrade resumes the migrations from the
+//! cursor of the failed one.
+//!
+//! A runtime upgrade while migrations are ongoing does not restart them either, they are resumed
+//! from their stored cursor.
+//!
+//! [`SteppedMigration`]: frame_support::traits::SteppedMigration
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::{prelude::*, marker::PhantomData};
+use codec::{Encode, Decode};
+use sp_runtime::RuntimeDebug;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Contains, Get, IsSubType, MultiStepMigrator, SteppedMigrations, SteppedMigrationError},
+	weights::Weight,
+};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The progress of the ongoing migrations.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum MigrationCursor {
+	/// The migration at `index` in [`Config::Migrations`] is ongoing.
+	Active {
+		/// The index of the migration.
+		index: u32,
+		/// The cursor of the migration, `None` if it did not execute any step yet.
+		inner_cursor: Option<Vec<u8>>,
+	},
+	/// The migration at `index` failed and the chain is stuck, only mandatory extrinsics and the
+	/// exempt calls are applied.
+	///
+	/// The migration is resumed from `inner_cursor` after the next runtime upgrade.
+	Stuck {
+		/// The index of the failed migration.
+		index: u32,
+		/// The cursor of the failed migration before its failed step.
+		inner_cursor: Option<Vec<u8>>,
+	},
+}
+
+/// What to do when a migration fails.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum FailedMigrationHandling {
+	/// Keep the chain stuck, only mandatory extrinsics are applied.
+	KeepStuck,
+	/// Abandon the remaining migrations and apply all the extrinsics again.
+	ForceUnstuck,
+}
+
+/// Decide what to do when a migration fails.
+pub trait FailedMigrationHandler {
+	/// The migration at `index` in [`Config::Migrations`] failed.
+	fn failed(index: u32) -> FailedMigrationHandling;
+}
+
+/// Keep the chain stuck until the next runtime upgrade when a migration fails, it is never safe to
+/// run with partially migrated storage.
+pub struct FreezeChainOnFailedMigration;
+
+impl FailedMigrationHandler for FreezeChainOnFailedMigration {
+	fn failed(_index: u32) -> FailedMigrationHandling {
+		FailedMigrationHandling::KeepStuck
+	}
+}
+
+/// Abandon the remaining migrations when a migration fails.
+pub struct ResumeOnFailedMigration;
+
+impl FailedMigrationHandler for ResumeOnFailedMigration {
+	fn failed(_index: u32) -> FailedMigrationHandling {
+		FailedMigrationHandling::ForceUnstuck
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The migrations to execute on a runtime upgrade, in order.
+		type Migrations: SteppedMigrations;
+
+		/// The maximum weight spent on the migrations in every block.
+		type ServiceWeight: Get<Weight>;
+
+		/// What to do when a migration fails.
+		type FailedMigrationHandler: FailedMigrationHandler;
+
+		/// The calls which are applied even while migrations are ongoing or stuck.
+		///
+		/// This must include the runtime upgrades, e.g. with [`RuntimeUpgradeCalls`].
+		type ExemptCalls: Contains<<Self as frame_system::Config>::Call>;
+
+		/// Weight information for the operations of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// The progress of the ongoing migrations, `None` if there is none.
+	#[pallet::storage]
+	#[pallet::getter(fn cursor)]
+	pub type Cursor<T> = StorageValue<_, MigrationCursor, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event {
+		/// The migration at this index is complete.
+		MigrationCompleted(u32),
+		/// The migration at this index failed.
+		MigrationFailed(u32),
+		/// All the migrations are complete, all the extrinsics are applied again.
+		UpgradeCompleted,
+		/// A migration failed and the chain is stuck.
+		UpgradeFailed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			match Cursor::<T>::get() {
+				Some(MigrationCursor::Active { .. }) => {
+					log::warn!(
+						target: "runtime::migrations",
+						"runtime upgraded while migrations are ongoing, they are resumed",
+					);
+				},
+				Some(MigrationCursor::Stuck { index, inner_cursor }) => {
+					log::info!(
+						target: "runtime::migrations",
+						"runtime upgraded while migration {} is stuck, it is resumed",
+						index,
+					);
+					Cursor::<T>::put(MigrationCursor::Active { index, inner_cursor });
+				},
+				None if T::Migrations::len() > 0 => {
+					Cursor::<T>::put(MigrationCursor::Active { index: 0, inner_cursor: None });
+				},
+				None => {},
+			}
+
+			T::WeightInfo::on_runtime_upgrade()
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::ServiceWeight::get().all_lte(&T::BlockWeights::get().max_block),
+				"the service weight of the migrations must fit in a block",
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+}
+
+/// Contains the calls upgrading the runtime, `set_code` and `set_code_without_checks` of
+/// `frame_system`.
+///
+/// Wrap it in `pallet_sudo::SudoCalls` to also exempt the upgrades made by the sudo key.
+pub struct RuntimeUpgradeCalls<T>(PhantomData<T>);
+
+impl<T> Contains<<T as frame_system::Config>::Call> for RuntimeUpgradeCalls<T> where
+	T: frame_system::Config,
+	<T as frame_system::Config>::Call: IsSubType<frame_system::Call<T>>,
+{
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		matches!(
+			call.is_sub_type(),
+			Some(frame_system::Call::set_code(..)) |
+				Some(frame_system::Call::set_code_without_checks(..))
+		)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The migration at `index` failed from `inner_cursor`, let the
+	/// [`Config::FailedMigrationHandler`] decide what to do with the remaining migrations.
+	fn fail(index: u32, inner_cursor: Option<Vec<u8>>, error: SteppedMigrationError) {
+		log::error!(
+			target: "runtime::migrations",
+			"migration {} failed: {:?}",
+			index,
+			error,
+		);
+		Self::deposit_event(Event::MigrationFailed(index));
+
+		match T::FailedMigrationHandler::failed(index) {
+			FailedMigrationHandling::KeepStuck => {
+				Cursor::<T>::put(MigrationCursor::Stuck { index, inner_cursor });
+				Self::deposit_event(Event::UpgradeFailed);
+			},
+			FailedMigrationHandling::ForceUnstuck => Cursor::<T>::kill(),
+		}
+	}
+}
+
+impl<T: Config> MultiStepMigrator<<T as frame_system::Config>::Call> for Pallet<T> {
+	fn ongoing() -> bool {
+		Cursor::<T>::exists()
+	}
+
+	fn is_exempt(call: &<T as frame_system::Config>::Call) -> bool {
+		T::ExemptCalls::contains(call)
+	}
+
+	fn step() -> Weight {
+		let (mut index, mut inner_cursor) = match Cursor::<T>::get() {
+			Some(MigrationCursor::Active { index, inner_cursor }) => (index, inner_cursor),
+			Some(MigrationCursor::Stuck { .. }) | None => return T::WeightInfo::step_idle(),
+		};
+
+		let service_weight = T::ServiceWeight::get();
+		let mut weight = T::WeightInfo::step_base();
+		// The weight limit of the first step executed in a block.
+		let max_weight_limit = service_weight.saturating_sub(weight);
+
+		loop {
+			let weight_limit = service_weight.saturating_sub(weight);
+			// A failed step must not leave partially migrated storage behind, it is retried or
+			// resumed from the cursor before it.
+			let result = with_transaction(|| {
+				match T::Migrations::nth_step(index, inner_cursor.clone(), weight_limit) {
+					Some(Err(error)) => TransactionOutcome::Rollback(Some(Err(error))),
+					result => TransactionOutcome::Commit(result),
+				}
+			});
+			let result = match result {
+				Some(result) => result,
+				None => {
+					Cursor::<T>::kill();
+					Self::deposit_event(Event::UpgradeCompleted);
+					return weight.saturating_add(T::WeightInfo::upgrade_completed())
+				},
+			};
+
+			match result {
+				Ok((Some(next_cursor), used)) => {
+					// The migration continues in the next block.
+					weight = weight.saturating_add(used);
+					inner_cursor = Some(next_cursor);
+					break
+				},
+				Ok((None, used)) => {
+					weight = weight
+						.saturating_add(used)
+						.saturating_add(T::WeightInfo::migration_completed());
+					Self::deposit_event(Event::MigrationCompleted(index));
+					index += 1;
+					inner_cursor = None;
+				},
+				Err(SteppedMigrationError::InsufficientWeight { required })
+					if required.all_lte(&max_weight_limit) &&
+						max_weight_limit.any_gt(&weight_limit) =>
+				{
+					// Retried as the first step of the next block.
+					break
+				},
+				Err(error) => {
+					Self::fail(index, inner_cursor, error);
+					return weight.saturating_add(T::WeightInfo::migration_failed())
+				},
+			}
+		}
+
+		Cursor::<T>::put(MigrationCursor::Active { index, inner_cursor });
+		weight
+	}
+}
@@ -0,0 +1,177 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use super::*;
+use crate as pallet_migrations;
+use frame_support::{parameter_types, traits::{ConstU32, SteppedMigration}};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::{BlakeTwo256, IdentityLookup}};
+use sp_std::marker::PhantomData;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Migrations: pallet_migrations::{Pallet, Call, Storage, Event},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const ServiceWeight: Weight = Weight::from_ref_time(15);
+	pub static FailMigration: bool = false;
+	pub static FailedMigrationHandlingOf: FailedMigrationHandling =
+		FailedMigrationHandling::KeepStuck;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+/// The weight of every step of the mocked migrations.
+pub const STEP_WEIGHT: Weight = Weight::from_ref_time(10);
+
+/// A migration which is complete after `Steps` steps.
+pub struct MockedMigration<Steps>(PhantomData<Steps>);
+
+impl<Steps: Get<u32>> SteppedMigration for MockedMigration<Steps> {
+	type Cursor = u32;
+
+	fn step(
+		cursor: Option<u32>,
+		weight_limit: Weight,
+	) -> Result<(Option<u32>, Weight), SteppedMigrationError> {
+		if STEP_WEIGHT.any_gt(&weight_limit) {
+			return Err(SteppedMigrationError::InsufficientWeight { required: STEP_WEIGHT })
+		}
+
+		let done = cursor.unwrap_or(0) + 1;
+		let next = if done < Steps::get() { Some(done) } else { None };
+		Ok((next, STEP_WEIGHT))
+	}
+}
+
+/// The storage key written by [`MaybeFailingMigration`].
+pub const MIGRATED_KEY: &[u8] = b":migrated:";
+
+/// A migration which writes [`MIGRATED_KEY`] and completes in a single step, unless
+/// `FailMigration` is set, in which case the step fails after the write.
+pub struct MaybeFailingMigration;
+
+impl SteppedMigration for MaybeFailingMigration {
+	type Cursor = ();
+
+	fn step(
+		_cursor: Option<()>,
+		_weight_limit: Weight,
+	) -> Result<(Option<()>, Weight), SteppedMigrationError> {
+		sp_io::storage::set(MIGRATED_KEY, &[1]);
+		if FailMigration::get() {
+			Err(SteppedMigrationError::Failed)
+		} else {
+			Ok((None, Weight::zero()))
+		}
+	}
+}
+
+pub struct MockedFailedMigrationHandler;
+
+impl FailedMigrationHandler for MockedFailedMigrationHandler {
+	fn failed(_index: u32) -> FailedMigrationHandling {
+		FailedMigrationHandlingOf::get()
+	}
+}
+
+/// Weights of zero, so that the tests only account for the weight of the mocked migrations.
+pub struct ZeroWeightInfo;
+
+impl WeightInfo for ZeroWeightInfo {
+	fn on_runtime_upgrade() -> Weight { Weight::zero() }
+	fn step_idle() -> Weight { Weight::zero() }
+	fn step_base() -> Weight { Weight::zero() }
+	fn migration_completed() -> Weight { Weight::zero() }
+	fn migration_failed() -> Weight { Weight::zero() }
+	fn upgrade_completed() -> Weight { Weight::zero() }
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Migrations = (
+		MockedMigration<ConstU32<2>>,
+		MaybeFailingMigration,
+		MockedMigration<ConstU32<1>>,
+	);
+	type ServiceWeight = ServiceWeight;
+	type FailedMigrationHandler = MockedFailedMigrationHandler;
+	type ExemptCalls = RuntimeUpgradeCalls<Test>;
+	type WeightInfo = ZeroWeightInfo;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Start a new block, executing the steps of the ongoing migrations.
+pub fn next_block() -> Weight {
+	System::set_block_number(System::block_number() + 1);
+	System::reset_events();
+	Migrations::step()
+}
+
+/// The events of the migrations pallet deposited in the current block.
+pub fn migrations_events() -> Vec<pallet_migrations::Event> {
+	System::events()
+		.into_iter()
+		.filter_map(|r| match r.event {
+			Event::Migrations(e) => Some(e),
+			_ => None,
+		})
+		.collect()
+}
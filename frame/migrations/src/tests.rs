@@ -0,0 +1,164 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the migrations pallet.
+
+use super::*;
+use crate::{mock::*, Event};
+use codec::Encode;
+use frame_support::traits::OnRuntimeUpgrade;
+
+#[test]
+fn migrations_are_executed_over_multiple_blocks() {
+	new_test_ext().execute_with(|| {
+		assert!(!Migrations::ongoing());
+		Migrations::on_runtime_upgrade();
+		assert!(Migrations::ongoing());
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Active { index: 0, inner_cursor: None }),
+		);
+
+		// The first migration needs two steps.
+		assert_eq!(next_block(), STEP_WEIGHT);
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Active { index: 0, inner_cursor: Some(1u32.encode()) }),
+		);
+		assert!(migrations_events().is_empty());
+
+		// Another upgrade does not restart the ongoing migrations.
+		Migrations::on_runtime_upgrade();
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Active { index: 0, inner_cursor: Some(1u32.encode()) }),
+		);
+
+		// The remaining weight is not enough for the step of the last migration.
+		assert_eq!(next_block(), STEP_WEIGHT);
+		assert_eq!(
+			migrations_events(),
+			vec![Event::MigrationCompleted(0), Event::MigrationCompleted(1)],
+		);
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Active { index: 2, inner_cursor: None }),
+		);
+
+		assert_eq!(next_block(), STEP_WEIGHT);
+		assert_eq!(migrations_events(), vec![Event::MigrationCompleted(2), Event::UpgradeCompleted]);
+		assert!(!Migrations::ongoing());
+
+		// Nothing to do anymore.
+		assert_eq!(next_block(), 0);
+		assert!(migrations_events().is_empty());
+	});
+}
+
+#[test]
+fn failed_migration_keeps_the_chain_stuck() {
+	new_test_ext().execute_with(|| {
+		FailMigration::set(true);
+		FailedMigrationHandlingOf::set(FailedMigrationHandling::KeepStuck);
+		Migrations::on_runtime_upgrade();
+
+		next_block();
+		next_block();
+		assert_eq!(
+			migrations_events(),
+			vec![Event::MigrationCompleted(0), Event::MigrationFailed(1), Event::UpgradeFailed],
+		);
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Stuck { index: 1, inner_cursor: None }),
+		);
+
+		// The chain stays stuck.
+		next_block();
+		assert!(migrations_events().is_empty());
+		assert!(Migrations::ongoing());
+
+		// Until a runtime upgrade fixes the failed migration, which is then resumed.
+		FailMigration::set(false);
+		Migrations::on_runtime_upgrade();
+		assert_eq!(
+			Migrations::cursor(),
+			Some(MigrationCursor::Active { index: 1, inner_cursor: None }),
+		);
+		assert_eq!(next_block(), STEP_WEIGHT);
+		assert_eq!(
+			migrations_events(),
+			vec![
+				Event::MigrationCompleted(1),
+				Event::MigrationCompleted(2),
+				Event::UpgradeCompleted,
+			],
+		);
+		assert!(!Migrations::ongoing());
+	});
+}
+
+#[test]
+fn failed_step_is_rolled_back() {
+	new_test_ext().execute_with(|| {
+		FailMigration::set(true);
+		FailedMigrationHandlingOf::set(FailedMigrationHandling::KeepStuck);
+		Migrations::on_runtime_upgrade();
+
+		// The failing step writes to the storage before failing.
+		next_block();
+		next_block();
+		assert!(migrations_events().contains(&Event::MigrationFailed(1)));
+		assert_eq!(sp_io::storage::get(MIGRATED_KEY), None);
+
+		// The step fixed by a runtime upgrade keeps its writes.
+		FailMigration::set(false);
+		Migrations::on_runtime_upgrade();
+		next_block();
+		assert!(migrations_events().contains(&Event::MigrationCompleted(1)));
+		assert_eq!(sp_io::storage::get(MIGRATED_KEY), Some(vec![1]));
+	});
+}
+
+#[test]
+fn runtime_upgrades_are_exempt() {
+	let upgrade = Call::System(frame_system::Call::set_code(vec![]));
+	let upgrade_without_checks =
+		Call::System(frame_system::Call::set_code_without_checks(vec![]));
+	let remark = Call::System(frame_system::Call::remark(vec![]));
+
+	assert!(Migrations::is_exempt(&upgrade));
+	assert!(Migrations::is_exempt(&upgrade_without_checks));
+	assert!(!Migrations::is_exempt(&remark));
+}
+
+#[test]
+fn failed_migration_can_be_abandoned() {
+	new_test_ext().execute_with(|| {
+		FailMigration::set(true);
+		FailedMigrationHandlingOf::set(FailedMigrationHandling::ForceUnstuck);
+		Migrations::on_runtime_upgrade();
+
+		next_block();
+		next_block();
+		assert_eq!(
+			migrations_events(),
+			vec![Event::MigrationCompleted(0), Event::MigrationFailed(1)],
+		);
+		assert!(!Migrations::ongoing());
+	});
+}
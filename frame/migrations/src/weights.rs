@@ -0,0 +1,99 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_migrations
+//!
+//! These weights are estimated from the storage accesses of the operations and are meant to be
+//! replaced by the results of benchmarks. The weight of the steps of the migrations themselves is
+//! returned by the migrations.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_migrations.
+pub trait WeightInfo {
+	fn on_runtime_upgrade() -> Weight;
+	fn step_idle() -> Weight;
+	fn step_base() -> Weight;
+	fn migration_completed() -> Weight;
+	fn migration_failed() -> Weight;
+	fn upgrade_completed() -> Weight;
+}
+
+/// Weights for pallet_migrations using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn on_runtime_upgrade() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn step_idle() -> Weight {
+		Weight::from_ref_time(2_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	fn step_base() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn migration_completed() -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn migration_failed() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn upgrade_completed() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn on_runtime_upgrade() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn step_idle() -> Weight {
+		Weight::from_ref_time(2_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	fn step_base() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn migration_completed() -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn migration_failed() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn upgrade_completed() -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
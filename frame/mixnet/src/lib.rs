@@ -0,0 +1,432 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Mixnet Pallet
+//!
+//! Keeps the registry of the mixnodes of each session, for the mixnet through which users can
+//! submit extrinsics without revealing their IP address.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! The mixnet authorities of a session are the validators of the session, identified by their
+//! [`AuthorityId`] session key. During a session, the authorities of the following session
+//! register as mixnodes for it, by submitting a [`Registration`] signed with their session key
+//! as an unsigned transaction. This is done automatically by the nodes, through the
+//! [`MixnetApi`](sp_mixnet::MixnetApi) runtime API, for which the pallet provides the
+//! [`Pallet::session_status`], [`Pallet::prev_mixnodes`], [`Pallet::current_mixnodes`] and
+//! [`Pallet::maybe_register`] functions.
+//!
+//! Each session goes through the phases of [`SessionPhase`], whose lengths are configured in
+//! blocks, so that the nodes switch from the mixnode set of the previous session to the one of
+//! the current session without dropping the packets in flight.
+//!
+//! The pallet must be registered as a session handler, and [`AuthorityId`] must be part of the
+//! session keys of the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::{prelude::*, convert::TryFrom};
+use codec::{Encode, Decode};
+use sp_runtime::{RuntimeAppPublic, RuntimeDebug, traits::Saturating};
+use frame_support::{BoundedVec, traits::{Get, OneSessionHandler}};
+use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
+use sp_mixnet::{
+	AuthorityIndex, KxPublic, Mixnode, MixnodePeerId, MixnodesErr, SessionIndex, SessionPhase,
+	SessionStatus,
+};
+pub use sp_mixnet::{AuthorityId, AuthoritySignature};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+/// The number of blocks for which a registration stays valid in the transaction pool.
+const REGISTRATION_LONGEVITY: u64 = 64;
+
+/// A mixnode, with its external addresses bounded in number and size.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct BoundedMixnode<ExternalAddresses> {
+	/// The key-exchange public key of the mixnode.
+	pub kx_public: KxPublic,
+	/// The peer ID of the mixnode.
+	pub peer_id: MixnodePeerId,
+	/// The addresses at which the mixnode can be reached.
+	pub external_addresses: ExternalAddresses,
+}
+
+/// The bounded external addresses of a mixnode.
+pub type ExternalAddressesFor<T> = BoundedVec<
+	BoundedVec<u8, <T as Config>::MaxExternalAddressSize>,
+	<T as Config>::MaxExternalAddressesPerMixnode,
+>;
+
+/// A [`BoundedMixnode`] with the bounds of the runtime.
+pub type BoundedMixnodeFor<T> = BoundedMixnode<ExternalAddressesFor<T>>;
+
+impl<MaxAddressSize, MaxAddresses> From<Mixnode>
+	for BoundedMixnode<BoundedVec<BoundedVec<u8, MaxAddressSize>, MaxAddresses>>
+where
+	MaxAddressSize: Get<u32>,
+	MaxAddresses: Get<u32>,
+{
+	/// Bound the external addresses of `mixnode`, dropping the ones which are too long and the
+	/// ones in excess.
+	fn from(mixnode: Mixnode) -> Self {
+		let external_addresses = mixnode.external_addresses
+			.into_iter()
+			.filter_map(|address| BoundedVec::<u8, MaxAddressSize>::try_from(address).ok())
+			.take(MaxAddresses::get() as usize)
+			.collect::<Vec<_>>();
+		let external_addresses = BoundedVec::try_from(external_addresses)
+			.expect("The number of addresses was just bounded; qed");
+		Self { kx_public: mixnode.kx_public, peer_id: mixnode.peer_id, external_addresses }
+	}
+}
+
+impl<ExternalAddresses> From<BoundedMixnode<ExternalAddresses>> for Mixnode
+where
+	ExternalAddresses: IntoIterator,
+	ExternalAddresses::Item: Into<Vec<u8>>,
+{
+	fn from(mixnode: BoundedMixnode<ExternalAddresses>) -> Self {
+		Self {
+			kx_public: mixnode.kx_public,
+			peer_id: mixnode.peer_id,
+			external_addresses: mixnode.external_addresses.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// The registration of a mixnode for the session following `session_index`, signed by the
+/// authority key of `authority_index`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Registration<BoundedMixnode> {
+	/// The session during which the registration is made.
+	pub session_index: SessionIndex,
+	/// The index of the registering authority in the authority set of the following session.
+	pub authority_index: AuthorityIndex,
+	/// The mixnode to register.
+	pub mixnode: BoundedMixnode,
+}
+
+/// A [`Registration`] with the bounds of the runtime.
+pub type RegistrationFor<T> = Registration<BoundedMixnodeFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		/// The maximum number of authorities in a session.
+		#[pallet::constant]
+		type MaxAuthorities: Get<AuthorityIndex>;
+
+		/// The maximum size of an external address of a mixnode, in bytes.
+		#[pallet::constant]
+		type MaxExternalAddressSize: Get<u32>;
+
+		/// The maximum number of external addresses of a mixnode.
+		#[pallet::constant]
+		type MaxExternalAddressesPerMixnode: Get<u32>;
+
+		/// The number of blocks at the beginning of a session in the
+		/// [`SessionPhase::CoverToCurrent`] phase.
+		#[pallet::constant]
+		type NumCoverToCurrentBlocks: Get<Self::BlockNumber>;
+
+		/// The number of blocks in the [`SessionPhase::RequestsToCurrent`] phase.
+		#[pallet::constant]
+		type NumRequestsToCurrentBlocks: Get<Self::BlockNumber>;
+
+		/// The number of blocks in the [`SessionPhase::CoverToPrev`] phase, after which the
+		/// session is in the [`SessionPhase::DisconnectFromPrev`] phase.
+		#[pallet::constant]
+		type NumCoverToPrevBlocks: Get<Self::BlockNumber>;
+
+		/// The minimum number of mixnodes which must be registered for a session for its mixnode
+		/// set to be used.
+		#[pallet::constant]
+		type MinMixnodes: Get<u32>;
+
+		/// The priority of the registration transactions.
+		#[pallet::constant]
+		type RegistrationPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The index of the current session.
+	#[pallet::storage]
+	pub type CurrentSessionIndex<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+	/// The block at which the current session started.
+	#[pallet::storage]
+	pub type CurrentSessionStartBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The authorities of the following session, which can register as its mixnodes.
+	#[pallet::storage]
+	pub type NextAuthorityIds<T> = StorageMap<_, Identity, AuthorityIndex, AuthorityId>;
+
+	/// The registered mixnodes, by session and by authority index.
+	///
+	/// The mixnodes of the sessions before the previous one are removed.
+	#[pallet::storage]
+	pub type Mixnodes<T: Config> = StorageDoubleMap<
+		_,
+		Identity, SessionIndex,
+		Identity, AuthorityIndex,
+		BoundedMixnodeFor<T>,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a mixnode for the session following the current one.
+		///
+		/// The dispatch origin must be none. The registration is checked by
+		/// `validate_unsigned`, which verifies `signature` against the key of the registering
+		/// authority.
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			registration: RegistrationFor<T>,
+			_signature: AuthoritySignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			// Checked by `validate_unsigned`.
+			debug_assert_eq!(registration.session_index, CurrentSessionIndex::<T>::get());
+			debug_assert!(registration.authority_index < T::MaxAuthorities::get());
+
+			Mixnodes::<T>::insert(
+				registration.session_index.saturating_add(1),
+				registration.authority_index,
+				registration.mixnode,
+			);
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (registration, signature) = match call {
+				Call::register(registration, signature) => (registration, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			let current_session = CurrentSessionIndex::<T>::get();
+			if registration.session_index < current_session {
+				return InvalidTransaction::Stale.into()
+			}
+			if registration.session_index > current_session {
+				return InvalidTransaction::Future.into()
+			}
+
+			let authority_id = match NextAuthorityIds::<T>::get(registration.authority_index) {
+				Some(id) => id,
+				None => return InvalidTransaction::BadProof.into(),
+			};
+
+			if Self::already_registered(registration.session_index, registration.authority_index) {
+				return InvalidTransaction::Stale.into()
+			}
+
+			// Check the signature last, as it is the most expensive check.
+			let signature_valid = registration.using_encoded(|encoded_registration| {
+				authority_id.verify(&encoded_registration, signature)
+			});
+			if !signature_valid {
+				return InvalidTransaction::BadProof.into()
+			}
+
+			ValidTransaction::with_tag_prefix("MixnetRegistration")
+				.priority(T::RegistrationPriority::get())
+				.and_provides((registration.session_index, registration.authority_index))
+				.longevity(REGISTRATION_LONGEVITY)
+				.propagate(true)
+				.build()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the authority `authority_index` of the session following `session_index` already
+	/// registered a mixnode for it.
+	fn already_registered(session_index: SessionIndex, authority_index: AuthorityIndex) -> bool {
+		Mixnodes::<T>::contains_key(session_index.saturating_add(1), authority_index)
+	}
+
+	/// The mixnodes registered for `session_index`, if there are enough of them.
+	fn mixnodes(session_index: SessionIndex) -> Result<Vec<Mixnode>, MixnodesErr> {
+		let mixnodes: Vec<Mixnode> = Mixnodes::<T>::iter_prefix_values(session_index)
+			.map(Into::into)
+			.collect();
+		let min = T::MinMixnodes::get();
+		if (mixnodes.len() as u32) < min {
+			return Err(MixnodesErr::InsufficientRegistrations { num: mixnodes.len() as u32, min })
+		}
+		Ok(mixnodes)
+	}
+
+	/// The index and phase of the current session.
+	pub fn session_status() -> SessionStatus {
+		let now = frame_system::Pallet::<T>::block_number();
+		let elapsed = now.saturating_sub(CurrentSessionStartBlock::<T>::get());
+
+		let cover_to_current_end = T::NumCoverToCurrentBlocks::get();
+		let requests_to_current_end =
+			cover_to_current_end.saturating_add(T::NumRequestsToCurrentBlocks::get());
+		let cover_to_prev_end =
+			requests_to_current_end.saturating_add(T::NumCoverToPrevBlocks::get());
+		let phase = if elapsed < cover_to_current_end {
+			SessionPhase::CoverToCurrent
+		} else if elapsed < requests_to_current_end {
+			SessionPhase::RequestsToCurrent
+		} else if elapsed < cover_to_prev_end {
+			SessionPhase::CoverToPrev
+		} else {
+			SessionPhase::DisconnectFromPrev
+		};
+
+		SessionStatus { current_index: CurrentSessionIndex::<T>::get(), phase }
+	}
+
+	/// The mixnode set of the previous session.
+	pub fn prev_mixnodes() -> Result<Vec<Mixnode>, MixnodesErr> {
+		let current_index = CurrentSessionIndex::<T>::get();
+		let prev_index = current_index.checked_sub(1)
+			.ok_or(MixnodesErr::InsufficientRegistrations { num: 0, min: T::MinMixnodes::get() })?;
+		Self::mixnodes(prev_index)
+	}
+
+	/// The mixnode set of the current session.
+	pub fn current_mixnodes() -> Result<Vec<Mixnode>, MixnodesErr> {
+		Self::mixnodes(CurrentSessionIndex::<T>::get())
+	}
+
+	/// Try to register `mixnode` for the session following `session_index`, with the first
+	/// local authority key of the following session which did not register yet.
+	///
+	/// Meant to be called from an offchain context, through the runtime API. Returns `true` if
+	/// a registration was submitted.
+	pub fn maybe_register(session_index: SessionIndex, mixnode: Mixnode) -> bool {
+		if session_index != CurrentSessionIndex::<T>::get() {
+			log::debug!(
+				target: "runtime::mixnet",
+				"Not registering for session {}, which is not the current session",
+				session_index,
+			);
+			return false
+		}
+
+		let local_keys = AuthorityId::all();
+		let authority = NextAuthorityIds::<T>::iter()
+			.filter(|(index, _)| !Self::already_registered(session_index, *index))
+			.find(|(_, id)| local_keys.contains(id));
+		let (authority_index, authority_id) = match authority {
+			Some(authority) => authority,
+			None => return false,
+		};
+
+		let registration = Registration {
+			session_index,
+			authority_index,
+			mixnode: mixnode.into(),
+		};
+		let signature = match authority_id.sign(&registration.encode()) {
+			Some(signature) => signature,
+			None => return false,
+		};
+
+		let call = Call::register(registration, signature);
+		match SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()) {
+			Ok(()) => true,
+			Err(()) => {
+				log::debug!(
+					target: "runtime::mixnet",
+					"Failed to submit the registration for session {}",
+					session_index,
+				);
+				false
+			},
+		}
+	}
+
+	/// Replace the authorities of the following session with `authority_ids`.
+	fn set_next_authority_ids(authority_ids: impl Iterator<Item = AuthorityId>) {
+		NextAuthorityIds::<T>::remove_all();
+		for (index, id) in authority_ids.take(T::MaxAuthorities::get() as usize).enumerate() {
+			NextAuthorityIds::<T>::insert(index as AuthorityIndex, id);
+		}
+	}
+}
+
+impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
+	type Public = AuthorityId;
+}
+
+impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
+	type Key = AuthorityId;
+
+	fn on_genesis_session<'a, I: 'a>(validators: I)
+		where I: Iterator<Item=(&'a T::AccountId, AuthorityId)>
+	{
+		// The authorities of the second session are not known yet, assume they are the same
+		// as the ones of the first session.
+		Self::set_next_authority_ids(validators.map(|(_, id)| id));
+	}
+
+	fn on_new_session<'a, I: 'a>(_changed: bool, _validators: I, queued_validators: I)
+		where I: Iterator<Item=(&'a T::AccountId, AuthorityId)>
+	{
+		let session_index = CurrentSessionIndex::<T>::mutate(|index| {
+			*index = index.saturating_add(1);
+			*index
+		});
+		CurrentSessionStartBlock::<T>::put(frame_system::Pallet::<T>::block_number());
+		Self::set_next_authority_ids(queued_validators.map(|(_, id)| id));
+
+		// The mixnodes of the previous session are still needed during the first phases of
+		// this session, the ones of the session before it can go.
+		if let Some(old_index) = session_index.checked_sub(2) {
+			Mixnodes::<T>::remove_prefix(old_index);
+		}
+	}
+
+	fn on_disabled(_validator_index: usize) {}
+}
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use super::*;
+use crate as pallet_mixnet;
+use frame_support::parameter_types;
+use sp_core::{H256, offchain::{TransactionPoolExt, testing::{PoolState, TestTransactionPoolExt}}};
+use sp_keystore::{KeystoreExt, testing::KeyStore};
+use sp_runtime::{testing::{Header, TestXt}, traits::{BlakeTwo256, IdentityLookup}};
+use std::sync::Arc;
+use parking_lot::RwLock;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Mixnet: pallet_mixnet::{Pallet, Call, Storage, ValidateUnsigned},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+/// An extrinsic type used for tests.
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+parameter_types! {
+	pub const MaxAuthorities: u32 = 10;
+	pub const MaxExternalAddressSize: u32 = 8;
+	pub const MaxExternalAddressesPerMixnode: u32 = 2;
+	pub const NumCoverToCurrentBlocks: u64 = 3;
+	pub const NumRequestsToCurrentBlocks: u64 = 3;
+	pub const NumCoverToPrevBlocks: u64 = 2;
+	pub const MinMixnodes: u32 = 2;
+	pub const RegistrationPriority: u64 = 1 << 20;
+}
+
+impl Config for Test {
+	type MaxAuthorities = MaxAuthorities;
+	type MaxExternalAddressSize = MaxExternalAddressSize;
+	type MaxExternalAddressesPerMixnode = MaxExternalAddressesPerMixnode;
+	type NumCoverToCurrentBlocks = NumCoverToCurrentBlocks;
+	type NumRequestsToCurrentBlocks = NumRequestsToCurrentBlocks;
+	type NumCoverToPrevBlocks = NumCoverToPrevBlocks;
+	type MinMixnodes = MinMixnodes;
+	type RegistrationPriority = RegistrationPriority;
+	type WeightInfo = ();
+}
+
+/// Build the test externalities, with a keystore and a transaction pool.
+pub fn new_test_ext() -> (sp_io::TestExternalities, Arc<RwLock<PoolState>>) {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	let (pool, state) = TestTransactionPoolExt::new();
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.execute_with(|| System::set_block_number(1));
+	(ext, state)
+}
+
+/// Generate `n` authority keys in the keystore.
+pub fn generate_authorities(n: usize) -> Vec<AuthorityId> {
+	(0..n).map(|_| AuthorityId::generate_pair(None)).collect()
+}
+
+/// Start a new session at the current block, with `queued` as the authorities of the following
+/// session.
+pub fn new_session(queued: &[AuthorityId]) {
+	let queued = queued.iter().map(|id| (&0, id.clone())).collect::<Vec<_>>();
+	Mixnet::on_new_session(true, queued.clone().into_iter(), queued.into_iter());
+}
+
+/// A mixnode with the given key-exchange public key.
+pub fn mixnode(kx_public: u8) -> Mixnode {
+	Mixnode {
+		kx_public: [kx_public; 32],
+		peer_id: [kx_public; 32],
+		external_addresses: vec![b"/ip4/1".to_vec()],
+	}
+}
@@ -0,0 +1,168 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the mixnet pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_ok, unsigned::ValidateUnsigned};
+use sp_runtime::transaction_validity::{
+	InvalidTransaction, TransactionSource, TransactionValidity,
+};
+
+/// Take the registration submitted to the transaction pool.
+fn submitted_registration(
+	state: &parking_lot::RwLock<sp_core::offchain::testing::PoolState>,
+) -> (RegistrationFor<Test>, AuthoritySignature) {
+	let transaction = state.write().transactions.pop().unwrap();
+	let ex: Extrinsic = Decode::decode(&mut &*transaction).unwrap();
+	match ex.call {
+		crate::mock::Call::Mixnet(crate::Call::register(registration, signature)) =>
+			(registration, signature),
+		e => panic!("Unexpected call: {:?}", e),
+	}
+}
+
+fn validate(
+	registration: RegistrationFor<Test>,
+	signature: AuthoritySignature,
+) -> TransactionValidity {
+	Mixnet::validate_unsigned(TransactionSource::External, &Call::register(registration, signature))
+}
+
+#[test]
+fn session_phases_follow_each_other() {
+	let (mut ext, _) = new_test_ext();
+	ext.execute_with(|| {
+		new_session(&[]);
+		let status = |phase| SessionStatus { current_index: 1, phase };
+		assert_eq!(Mixnet::session_status(), status(SessionPhase::CoverToCurrent));
+		System::set_block_number(4);
+		assert_eq!(Mixnet::session_status(), status(SessionPhase::RequestsToCurrent));
+		System::set_block_number(7);
+		assert_eq!(Mixnet::session_status(), status(SessionPhase::CoverToPrev));
+		System::set_block_number(9);
+		assert_eq!(Mixnet::session_status(), status(SessionPhase::DisconnectFromPrev));
+
+		new_session(&[]);
+		assert_eq!(
+			Mixnet::session_status(),
+			SessionStatus { current_index: 2, phase: SessionPhase::CoverToCurrent },
+		);
+	});
+}
+
+#[test]
+fn maybe_register_submits_a_valid_registration() {
+	let (mut ext, state) = new_test_ext();
+	ext.execute_with(|| {
+		let authorities = generate_authorities(2);
+		new_session(&authorities);
+
+		// Only the current session can be registered for.
+		assert!(!Mixnet::maybe_register(0, mixnode(1)));
+		assert!(Mixnet::maybe_register(1, mixnode(1)));
+		let (registration, signature) = submitted_registration(&state);
+		assert_eq!(registration.session_index, 1);
+		assert_eq!(Mixnode::from(registration.mixnode.clone()), mixnode(1));
+		assert!(validate(registration.clone(), signature.clone()).is_ok());
+
+		assert_ok!(Mixnet::register(Origin::none(), registration.clone(), signature.clone()));
+		assert_eq!(validate(registration, signature), InvalidTransaction::Stale.into());
+
+		// The second local authority registers next, then there is nobody left.
+		assert!(Mixnet::maybe_register(1, mixnode(2)));
+		let (registration, signature) = submitted_registration(&state);
+		assert_ok!(Mixnet::register(Origin::none(), registration, signature));
+		assert!(!Mixnet::maybe_register(1, mixnode(3)));
+	});
+}
+
+#[test]
+fn registrations_with_a_bad_signature_or_session_are_rejected() {
+	let (mut ext, state) = new_test_ext();
+	ext.execute_with(|| {
+		let authorities = generate_authorities(1);
+		new_session(&authorities);
+		assert!(Mixnet::maybe_register(1, mixnode(1)));
+		let (registration, signature) = submitted_registration(&state);
+
+		let mut tampered = registration.clone();
+		tampered.mixnode.kx_public = [0; 32];
+		assert_eq!(validate(tampered, signature.clone()), InvalidTransaction::BadProof.into());
+
+		let mut unknown = registration.clone();
+		unknown.authority_index = 1;
+		assert_eq!(validate(unknown, signature.clone()), InvalidTransaction::BadProof.into());
+
+		let mut future = registration.clone();
+		future.session_index = 2;
+		assert_eq!(validate(future, signature.clone()), InvalidTransaction::Future.into());
+
+		new_session(&authorities);
+		assert_eq!(validate(registration, signature), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn mixnode_sets_follow_the_sessions() {
+	let (mut ext, state) = new_test_ext();
+	ext.execute_with(|| {
+		let authorities = generate_authorities(2);
+		new_session(&authorities);
+		for kx_public in 1..=2 {
+			assert!(Mixnet::maybe_register(1, mixnode(kx_public)));
+			let (registration, signature) = submitted_registration(&state);
+			assert_ok!(Mixnet::register(Origin::none(), registration, signature));
+		}
+		assert_eq!(
+			Mixnet::current_mixnodes(),
+			Err(MixnodesErr::InsufficientRegistrations { num: 0, min: 2 }),
+		);
+
+		new_session(&authorities);
+		let mut mixnodes = Mixnet::current_mixnodes().unwrap();
+		mixnodes.sort_by_key(|mixnode| mixnode.kx_public);
+		assert_eq!(mixnodes, vec![mixnode(1), mixnode(2)]);
+
+		new_session(&authorities);
+		assert_eq!(Mixnet::prev_mixnodes().unwrap().len(), 2);
+
+		// The mixnodes of the sessions before the previous one are removed.
+		new_session(&authorities);
+		assert_eq!(Mixnodes::<Test>::iter_prefix_values(2).count(), 0);
+	});
+}
+
+#[test]
+fn external_addresses_are_bounded() {
+	let mixnode = Mixnode {
+		kx_public: [1; 32],
+		peer_id: [1; 32],
+		external_addresses: vec![
+			b"/ip4/1".to_vec(),
+			b"/dns/too.long".to_vec(),
+			b"/ip4/2".to_vec(),
+			b"/ip4/3".to_vec(),
+		],
+	};
+	let bounded: BoundedMixnodeFor<Test> = mixnode.into();
+	assert_eq!(
+		Mixnode::from(bounded).external_addresses,
+		vec![b"/ip4/1".to_vec(), b"/ip4/2".to_vec()],
+	);
+}
@@ -31,13 +31,18 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Calls are never stored on chain: all the approvals but the final one only need the hash of the
+//! call, and the final approval provides the call itself with `as_multi`. The depositor of the
+//! multisig operation gets its deposit back once the call is executed or the operation is
+//! cancelled.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
 //!
 //! * `as_multi` - Approve and if possible dispatch a call from a composite origin formed from a
 //!   number of signed origins.
-//! * `approve_as_multi` - Approve a call from a composite origin.
+//! * `approve_as_multi` - Approve a call from a composite origin, given the hash of the call.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
 //!
 //! [`Call`]: ./enum.Call.html
@@ -48,6 +53,7 @@
 
 mod tests;
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 use sp_std::prelude::*;
@@ -65,8 +71,6 @@ pub use weights::WeightInfo;
 pub use pallet::*;
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-/// Just a bunch of bytes, but they should decode to a valid `Call`.
-pub type OpaqueCall = Vec<u8>;
 
 /// A global extrinsic index, formed as the extrinsic index within a block, together with that
 /// block's height. This allows a transaction in which a multisig operation of a particular
@@ -94,8 +98,8 @@ pub struct Multisig<BlockNumber, Balance, AccountId> {
 
 type CallHash = [u8; 32];
 
-enum CallOrHash {
-	Call(OpaqueCall, bool),
+enum CallOrHash<T: Config> {
+	Call(<T as Config>::Call),
 	Hash([u8; 32]),
 }
 
@@ -105,6 +109,9 @@ pub mod pallet{
 	use frame_system::pallet_prelude::*;
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
@@ -117,8 +124,7 @@ pub mod pallet{
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
-		/// The base amount of currency needed to reserve for creating a multisig execution or to store
-		/// a dispatch call for later.
+		/// The base amount of currency needed to reserve for creating a multisig execution.
 		///
 		/// This is held for an additional storage item whose value size is
 		/// `4 + sizeof((BlockNumber, Balance, AccountId))` bytes and whose key size is
@@ -142,6 +148,7 @@ pub mod pallet{
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The set of open multisig operations.
@@ -155,14 +162,6 @@ pub mod pallet{
 		Multisig<T::BlockNumber, BalanceOf<T>, T::AccountId>,
 	>;
 
-	#[pallet::storage]
-	pub type Calls<T: Config> = StorageMap<
-		_,
-		Identity,
-		[u8; 32],
-		(OpaqueCall, T::AccountId, BalanceOf<T>),
-	>;
-
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -191,8 +190,6 @@ pub mod pallet{
 		UnexpectedTimepoint,
 		/// The maximum weight information provided was too low.
 		MaxWeightTooLow,
-		/// The data to be stored is already stored.
-		AlreadyStored,
 	}
 
 	#[pallet::event]
@@ -297,6 +294,7 @@ pub mod pallet{
 		/// not the first approval, then it must be `Some`, with the timepoint (block number and
 		/// transaction index) of the first approval transaction.
 		/// - `call`: The call to be executed.
+		/// - `max_weight`: The maximum weight of the call, checked before it is executed.
 		///
 		/// NOTE: Unless this is the final approval, you will generally want to use
 		/// `approve_as_multi` instead, since it only requires a hash of the call.
@@ -321,16 +319,15 @@ pub mod pallet{
 		///   `DepositBase + threshold * DepositFactor`.
 		/// -------------------------------
 		/// - DB Weight:
-		///     - Reads: Multisig Storage, [Caller Account], Calls (if `store_call`)
-		///     - Writes: Multisig Storage, [Caller Account], Calls (if `store_call`)
+		///     - Reads: Multisig Storage, [Caller Account]
+		///     - Writes: Multisig Storage, [Caller Account]
 		/// - Plus Call Weight
 		/// # </weight>
 		#[pallet::weight({
 			let s = other_signatories.len() as u32;
-			let z = call.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
 
 			T::WeightInfo::as_multi_create(s, z)
-			.max(T::WeightInfo::as_multi_create_store(s, z))
 			.max(T::WeightInfo::as_multi_approve(s, z))
 			.max(T::WeightInfo::as_multi_complete(s, z))
 			.saturating_add(*max_weight)
@@ -340,12 +337,18 @@ pub mod pallet{
 			threshold: u16,
 			other_signatories: Vec<T::AccountId>,
 			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
-			call: OpaqueCall,
-			store_call: bool,
+			call: Box<<T as Config>::Call>,
 			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(who, threshold, other_signatories, maybe_timepoint, CallOrHash::Call(call, store_call), max_weight)
+			Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
 		}
 
 		/// Register approval for a dispatch to be made from a deterministic composite account if
@@ -365,7 +368,8 @@ pub mod pallet{
 		/// transaction index) of the first approval transaction.
 		/// - `call_hash`: The hash of the call to be executed.
 		///
-		/// NOTE: If this is the final approval, you will want to use `as_multi` instead.
+		/// NOTE: The call is never executed by this function, even if this is the final approval,
+		/// it is then executed by `as_multi` which must be given the call.
 		///
 		/// # <weight>
 		/// - `O(S)`.
@@ -389,8 +393,6 @@ pub mod pallet{
 
 			T::WeightInfo::approve_as_multi_create(s)
 			.max(T::WeightInfo::approve_as_multi_approve(s))
-			.max(T::WeightInfo::approve_as_multi_complete(s))
-			.saturating_add(*max_weight)
 		})]
 		pub(super) fn approve_as_multi(
 			origin: OriginFor<T>,
@@ -398,10 +400,16 @@ pub mod pallet{
 			other_signatories: Vec<T::AccountId>,
 			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
 			call_hash: [u8; 32],
-			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(who, threshold, other_signatories, maybe_timepoint, CallOrHash::Hash(call_hash), max_weight)
+			Self::operate(
+				who,
+				threshold,
+				other_signatories,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				0,
+			)
 		}
 
 		/// Cancel a pre-existing, on-going multisig transaction. Any deposit reserved previously
@@ -427,8 +435,8 @@ pub mod pallet{
 		/// - Storage: removes one item.
 		/// ----------------------------------
 		/// - DB Weight:
-		///     - Read: Multisig Storage, [Caller Account], Refund Account, Calls
-		///     - Write: Multisig Storage, [Caller Account], Refund Account, Calls
+		///     - Read: Multisig Storage, [Caller Account], Refund Account
+		///     - Write: Multisig Storage, [Caller Account], Refund Account
 		/// # </weight>
 		#[pallet::weight(T::WeightInfo::cancel_as_multi(other_signatories.len() as u32))]
 		pub(super) fn cancel_as_multi(
@@ -455,7 +463,6 @@ pub mod pallet{
 			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
 			debug_assert!(err_amount.is_zero());
 			<Multisigs<T>>::remove(&id, &call_hash);
-			Self::clear_call(&call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled(who, timepoint, id, call_hash));
 			Ok(())
@@ -478,7 +485,7 @@ impl<T: Config> Pallet<T> {
 		threshold: u16,
 		other_signatories: Vec<T::AccountId>,
 		maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
-		call_or_hash: CallOrHash,
+		call_or_hash: CallOrHash<T>,
 		max_weight: Weight,
 	) -> DispatchResultWithPostInfo {
 		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
@@ -491,14 +498,14 @@ impl<T: Config> Pallet<T> {
 		let id = Self::multi_account_id(&signatories, threshold);
 
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
-		let (call_hash, call_len, maybe_call, store) = match call_or_hash {
-			CallOrHash::Call(call, should_store) => {
-				let call_hash = blake2_256(&call);
-				let call_len = call.len();
-				(call_hash, call_len, Some(call), should_store)
+		let (call_hash, call_len, maybe_call) = match call_or_hash {
+			CallOrHash::Call(call) => {
+				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
+				(call_hash, call_len, Some(call))
 			}
-			CallOrHash::Hash(h) => (h, 0, None, false),
+			CallOrHash::Hash(h) => (h, 0, None),
 		};
+		let has_call = maybe_call.is_some();
 
 		// Branch on whether the operation has already started or not.
 		if let Some(mut m) = <Multisigs<T>>::get(&id, call_hash) {
@@ -513,19 +520,14 @@ impl<T: Config> Pallet<T> {
 			// Bump approvals if not yet voted and the vote is needed.
 			if maybe_pos.is_some() { approvals += 1; }
 
-			// We only bother fetching/decoding call if we know that we're ready to execute.
-			let maybe_approved_call = if approvals >= threshold {
-				Self::get_call(&call_hash, maybe_call.as_ref().map(|c| c.as_ref()))
-			} else { None };
-
-			if let Some((call, call_len)) = maybe_approved_call {
+			// We can only execute the call if it is given and we have threshold approvals.
+			if let Some(call) = maybe_call.filter(|_| approvals >= threshold) {
 				// verify weight
 				ensure!(call.get_dispatch_info().weight <= max_weight, Error::<T>::MaxWeightTooLow);
 
 				// Clean up storage before executing call to avoid an possibility of reentrancy
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
-				Self::clear_call(&call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
@@ -539,38 +541,25 @@ impl<T: Config> Pallet<T> {
 					).saturating_add(actual_weight)
 				).into())
 			} else {
-				// We cannot dispatch the call now; either it isn't available, or it is, but we
+				// We cannot dispatch the call now; either it isn't given, or it is, but we
 				// don't have threshold approvals even with our signature.
-
-				// Store the call if desired.
-				let stored = if let Some(data) = maybe_call.filter(|_| store) {
-					Self::store_call_and_reserve(who.clone(), &call_hash, data, BalanceOf::<T>::zero())?;
-					true
-				} else {
-					false
-				};
-
 				if let Some(pos) = maybe_pos {
 					// Record approval.
 					m.approvals.insert(pos, who.clone());
 					<Multisigs<T>>::insert(&id, call_hash, m);
 					Self::deposit_event(Event::MultisigApproval(who, timepoint, id, call_hash));
 				} else {
-					// If we already approved and didn't store the Call, then this was useless and
-					// we report an error.
-					ensure!(stored, Error::<T>::AlreadyApproved);
+					// If we already approved, then this was useless and we report an error.
+					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight = if stored {
-					T::WeightInfo::as_multi_approve_store(
-						other_signatories_len as u32,
-						call_len as u32,
-					)
-				} else {
+				let final_weight = if has_call {
 					T::WeightInfo::as_multi_approve(
 						other_signatories_len as u32,
 						call_len as u32,
 					)
+				} else {
+					T::WeightInfo::approve_as_multi_approve(other_signatories_len as u32)
 				};
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
@@ -582,14 +571,7 @@ impl<T: Config> Pallet<T> {
 			// Just start the operation by recording it in storage.
 			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
 
-			// Store the call if desired.
-			let stored = if let Some(data) = maybe_call.filter(|_| store) {
-				Self::store_call_and_reserve(who.clone(), &call_hash, data, deposit)?;
-				true
-			} else {
-				T::Currency::reserve(&who, deposit)?;
-				false
-			};
+			T::Currency::reserve(&who, deposit)?;
 
 			<Multisigs<T>>::insert(&id, call_hash, Multisig {
 				when: Self::timepoint(),
@@ -599,59 +581,19 @@ impl<T: Config> Pallet<T> {
 			});
 			Self::deposit_event(Event::NewMultisig(who, id, call_hash));
 
-			let final_weight = if stored {
-				T::WeightInfo::as_multi_create_store(
-					other_signatories_len as u32,
-					call_len as u32,
-				)
-			} else {
+			let final_weight = if has_call {
 				T::WeightInfo::as_multi_create(
 					other_signatories_len as u32,
 					call_len as u32,
 				)
+			} else {
+				T::WeightInfo::approve_as_multi_create(other_signatories_len as u32)
 			};
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
-	/// Place a call's encoded data in storage, reserving funds as appropriate.
-	///
-	/// We store `data` here because storing `call` would result in needing another `.encode`.
-	///
-	/// Returns a `bool` indicating whether the data did end up being stored.
-	fn store_call_and_reserve(
-		who: T::AccountId,
-		hash: &[u8; 32],
-		data: OpaqueCall,
-		other_deposit: BalanceOf<T>,
-	) -> DispatchResult {
-		ensure!(!Calls::<T>::contains_key(hash), Error::<T>::AlreadyStored);
-		let deposit = other_deposit + T::DepositBase::get()
-			+ T::DepositFactor::get() * BalanceOf::<T>::from(((data.len() + 31) / 32) as u32);
-		T::Currency::reserve(&who, deposit)?;
-		Calls::<T>::insert(&hash, (data, who, deposit));
-		Ok(())
-	}
-
-	/// Attempt to decode and return the call, provided by the user or from storage.
-	fn get_call(hash: &[u8; 32], maybe_known: Option<&[u8]>) -> Option<(<T as Config>::Call, usize)> {
-		maybe_known.map_or_else(|| {
-			Calls::<T>::get(hash).and_then(|(data, ..)| {
-				Decode::decode(&mut &data[..]).ok().map(|d| (d, data.len()))
-			})
-		}, |data| {
-			Decode::decode(&mut &data[..]).ok().map(|d| (d, data.len()))
-		})
-	}
-
-	/// Attempt to remove a call from storage, returning any deposit on it to the owner.
-	fn clear_call(hash: &[u8; 32]) {
-		if let Some((_, who, deposit)) = Calls::<T>::take(hash) {
-			T::Currency::unreserve(&who, deposit);
-		}
-	}
-
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<T::BlockNumber> {
 		Timepoint {
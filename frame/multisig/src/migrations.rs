@@ -0,0 +1,58 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations for the Multisig pallet.
+
+use super::*;
+use frame_support::{
+	storage::migration::storage_iter,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+/// Remove the calls stored by the previous versions of the pallet and return their deposits.
+///
+/// The calls must now be given to `as_multi` by the final approval, the multisig operations which
+/// relied on a stored call are kept and can be completed this way.
+pub struct MigrateToV1<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			log::info!(target: "runtime::multisig", "MigrateToV1 should be removed");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let pallet = <Pallet<T> as PalletInfoAccess>::name();
+		let mut calls = 0u64;
+		let stored_calls = storage_iter::<(Vec<u8>, T::AccountId, BalanceOf<T>)>(
+			pallet.as_bytes(),
+			b"Calls",
+		);
+		for (_, (_, who, deposit)) in stored_calls.drain() {
+			calls += 1;
+			T::Currency::unreserve(&who, deposit);
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "runtime::multisig", "Removed {} stored calls", calls);
+		T::DbWeight::get().reads_writes(
+			calls.saturating_add(1),
+			calls.saturating_mul(2).saturating_add(1),
+		)
+	}
+}
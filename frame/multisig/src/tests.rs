@@ -46,7 +46,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = TestBaseCallFilter;
@@ -80,6 +82,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -140,77 +143,23 @@ fn multisig_deposit_is_taken_and_returned() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data.clone(), false, 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call.clone()), 0));
 		assert_eq!(Balances::free_balance(1), 2);
 		assert_eq!(Balances::reserved_balance(1), 3);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), data, false, call_weight));
-		assert_eq!(Balances::free_balance(1), 5);
-		assert_eq!(Balances::reserved_balance(1), 0);
-	});
-}
-
-#[test]
-fn multisig_deposit_is_taken_and_returned_with_call_storage() {
-	new_test_ext().execute_with(|| {
-		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
-		assert_ok!(Balances::transfer(Origin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(2), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
-
-		let call = Call::Balances(BalancesCall::transfer(6, 15));
-		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data, true, 0));
-		assert_eq!(Balances::free_balance(1), 0);
-		assert_eq!(Balances::reserved_balance(1), 5);
-
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash, call_weight));
-		assert_eq!(Balances::free_balance(1), 5);
-		assert_eq!(Balances::reserved_balance(1), 0);
-	});
-}
-
-#[test]
-fn multisig_deposit_is_taken_and_returned_with_alt_call_storage() {
-	new_test_ext().execute_with(|| {
-		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
-		assert_ok!(Balances::transfer(Origin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(2), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
-
-		let call = Call::Balances(BalancesCall::transfer(6, 15));
-		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_eq!(Balances::free_balance(1), 1);
-		assert_eq!(Balances::reserved_balance(1), 4);
-
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), data, true, 0));
-		assert_eq!(Balances::free_balance(2), 3);
-		assert_eq!(Balances::reserved_balance(2), 2);
-		assert_eq!(Balances::free_balance(1), 1);
-		assert_eq!(Balances::reserved_balance(1), 4);
-
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), hash, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(1), 5);
 		assert_eq!(Balances::reserved_balance(1), 0);
-		assert_eq!(Balances::free_balance(2), 5);
-		assert_eq!(Balances::reserved_balance(2), 0);
 	});
 }
 
 #[test]
 fn cancel_multisig_returns_deposit() {
 	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone(), 0));
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone()));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone()));
 		assert_eq!(Balances::free_balance(1), 6);
 		assert_eq!(Balances::reserved_balance(1), 4);
 		assert_ok!(
@@ -229,48 +178,28 @@ fn timepoint_checking_works() {
 		assert_ok!(Balances::transfer(Origin::signed(2), multi, 5));
 		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
 
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
+		let hash = call.using_encoded(blake2_256);
 
 		assert_noop!(
-			Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash.clone(), 0),
+			Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash.clone()),
 			Error::<Test>::UnexpectedTimepoint,
 		);
 
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash, 0));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash));
 
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], None, call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], None, Box::new(call.clone()), 0),
 			Error::<Test>::NoTimepoint,
 		);
 		let later = Timepoint { index: 1, .. now() };
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(later), call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(later), Box::new(call.clone()), 0),
 			Error::<Test>::WrongTimepoint,
 		);
 	});
 }
 
-#[test]
-fn multisig_2_of_3_works_with_call_storing() {
-	new_test_ext().execute_with(|| {
-		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
-		assert_ok!(Balances::transfer(Origin::signed(1), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(2), multi, 5));
-		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
-
-		let call = Call::Balances(BalancesCall::transfer(6, 15));
-		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data, true, 0));
-		assert_eq!(Balances::free_balance(6), 0);
-
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash, call_weight));
-		assert_eq!(Balances::free_balance(6), 15);
-	});
-}
-
 #[test]
 fn multisig_2_of_3_works() {
 	new_test_ext().execute_with(|| {
@@ -281,12 +210,11 @@ fn multisig_2_of_3_works() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash, 0));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash));
 		assert_eq!(Balances::free_balance(6), 0);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), data, false, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
@@ -301,13 +229,12 @@ fn multisig_3_of_3_works() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone(), 0));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone()));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone()));
 		assert_eq!(Balances::free_balance(6), 0);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), data, false, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
@@ -315,28 +242,10 @@ fn multisig_3_of_3_works() {
 #[test]
 fn cancel_multisig_works() {
 	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone(), 0));
-		assert_noop!(
-			Multisig::cancel_as_multi(Origin::signed(2), 3, vec![1, 3], now(), hash.clone()),
-			Error::<Test>::NotOwner,
-		);
-		assert_ok!(
-			Multisig::cancel_as_multi(Origin::signed(1), 3, vec![2, 3], now(), hash.clone()),
-		);
-	});
-}
-
-#[test]
-fn cancel_multisig_with_call_storage_works() {
-	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 3, vec![2, 3], None, call, true, 0));
-		assert_eq!(Balances::free_balance(1), 4);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone(), 0));
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone()));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone()));
 		assert_noop!(
 			Multisig::cancel_as_multi(Origin::signed(2), 3, vec![1, 3], now(), hash.clone()),
 			Error::<Test>::NotOwner,
@@ -344,22 +253,6 @@ fn cancel_multisig_with_call_storage_works() {
 		assert_ok!(
 			Multisig::cancel_as_multi(Origin::signed(1), 3, vec![2, 3], now(), hash.clone()),
 		);
-		assert_eq!(Balances::free_balance(1), 10);
-	});
-}
-
-#[test]
-fn cancel_multisig_with_alt_call_storage_works() {
-	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_eq!(Balances::free_balance(1), 6);
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), call, true, 0));
-		assert_eq!(Balances::free_balance(2), 8);
-		assert_ok!(Multisig::cancel_as_multi(Origin::signed(1), 3, vec![2, 3], now(), hash));
-		assert_eq!(Balances::free_balance(1), 10);
-		assert_eq!(Balances::free_balance(2), 10);
 	});
 }
 
@@ -373,11 +266,10 @@ fn multisig_2_of_3_as_multi_works() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data.clone(), false, 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call.clone()), 0));
 		assert_eq!(Balances::free_balance(6), 0);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), data, false, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
@@ -392,15 +284,13 @@ fn multisig_2_of_3_as_multi_with_many_calls_works() {
 
 		let call1 = Call::Balances(BalancesCall::transfer(6, 10));
 		let call1_weight = call1.get_dispatch_info().weight;
-		let data1 = call1.encode();
 		let call2 = Call::Balances(BalancesCall::transfer(7, 5));
 		let call2_weight = call2.get_dispatch_info().weight;
-		let data2 = call2.encode();
 
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data1.clone(), false, 0));
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], None, data2.clone(), false, 0));
-		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), data1, false, call1_weight));
-		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), data2, false, call2_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call1.clone()), 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], None, Box::new(call2.clone()), 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), Box::new(call1.clone()), call1_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), Box::new(call2.clone()), call2_weight));
 
 		assert_eq!(Balances::free_balance(6), 10);
 		assert_eq!(Balances::free_balance(7), 5);
@@ -417,14 +307,13 @@ fn multisig_2_of_3_cannot_reissue_same_call() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 10));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data.clone(), false, 0));
-		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), data.clone(), false, call_weight));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call.clone()), 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(multi), 5);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data.clone(), false, 0));
-		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), data.clone(), false, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call.clone()), 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), Box::new(call.clone()), call_weight));
 
 		let err = DispatchError::from(BalancesError::<Test, _>::InsufficientBalance).stripped();
 		System::assert_last_event(pallet_multisig::Event::MultisigExecuted(3, now(), multi, hash, Err(err)).into());
@@ -434,13 +323,13 @@ fn multisig_2_of_3_cannot_reissue_same_call() {
 #[test]
 fn minimum_threshold_check_works() {
 	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(1), 0, vec![2], None, call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(1), 0, vec![2], None, Box::new(call.clone()), 0),
 			Error::<Test>::MinimumThreshold,
 		);
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(1), 1, vec![2], None, call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(1), 1, vec![2], None, Box::new(call.clone()), 0),
 			Error::<Test>::MinimumThreshold,
 		);
 	});
@@ -449,9 +338,9 @@ fn minimum_threshold_check_works() {
 #[test]
 fn too_many_signatories_fails() {
 	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(1), 2, vec![2, 3, 4], None, call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(1), 2, vec![2, 3, 4], None, Box::new(call.clone()), 0),
 			Error::<Test>::TooManySignatories,
 		);
 	});
@@ -460,16 +349,16 @@ fn too_many_signatories_fails() {
 #[test]
 fn duplicate_approvals_are_ignored() {
 	new_test_ext().execute_with(|| {
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash.clone(), 0));
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], None, hash.clone()));
 		assert_noop!(
-			Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], Some(now()), hash.clone(), 0),
+			Multisig::approve_as_multi(Origin::signed(1), 2, vec![2, 3], Some(now()), hash.clone()),
 			Error::<Test>::AlreadyApproved,
 		);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash.clone(), 0));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), hash.clone()));
 		assert_noop!(
-			Multisig::approve_as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), hash.clone(), 0),
+			Multisig::approve_as_multi(Origin::signed(3), 2, vec![1, 2], Some(now()), hash.clone()),
 			Error::<Test>::AlreadyApproved,
 		);
 	});
@@ -483,14 +372,14 @@ fn multisig_1_of_3_works() {
 		assert_ok!(Balances::transfer(Origin::signed(2), multi, 5));
 		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
 
-		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
-		let hash = blake2_256(&call);
+		let call = Call::Balances(BalancesCall::transfer(6, 15));
+		let hash = call.using_encoded(blake2_256);
 		assert_noop!(
-			Multisig::approve_as_multi(Origin::signed(1), 1, vec![2, 3], None, hash.clone(), 0),
+			Multisig::approve_as_multi(Origin::signed(1), 1, vec![2, 3], None, hash.clone()),
 			Error::<Test>::MinimumThreshold,
 		);
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(1), 1, vec![2, 3], None, call.clone(), false, 0),
+			Multisig::as_multi(Origin::signed(1), 1, vec![2, 3], None, Box::new(call.clone()), 0),
 			Error::<Test>::MinimumThreshold,
 		);
 		let boxed_call = Box::new(Call::Balances(BalancesCall::transfer(6, 15)));
@@ -520,12 +409,11 @@ fn weight_check_works() {
 		assert_ok!(Balances::transfer(Origin::signed(3), multi, 5));
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
-		let data = call.encode();
-		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, data.clone(), false, 0));
+		assert_ok!(Multisig::as_multi(Origin::signed(1), 2, vec![2, 3], None, Box::new(call.clone()), 0));
 		assert_eq!(Balances::free_balance(6), 0);
 
 		assert_noop!(
-			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), data, false, 0),
+			Multisig::as_multi(Origin::signed(2), 2, vec![1, 3], Some(now()), Box::new(call.clone()), 0),
 			Error::<Test>::MaxWeightTooLow,
 		);
 	});
@@ -543,14 +431,35 @@ fn multisig_handles_no_preimage_after_all_approve() {
 
 		let call = Call::Balances(BalancesCall::transfer(6, 15));
 		let call_weight = call.get_dispatch_info().weight;
-		let data = call.encode();
-		let hash = blake2_256(&data);
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone(), 0));
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone(), 0));
-		assert_ok!(Multisig::approve_as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), hash.clone(), 0));
+		let hash = call.using_encoded(blake2_256);
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(1), 3, vec![2, 3], None, hash.clone()));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(2), 3, vec![1, 3], Some(now()), hash.clone()));
+		assert_ok!(Multisig::approve_as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), hash.clone()));
 		assert_eq!(Balances::free_balance(6), 0);
 
-		assert_ok!(Multisig::as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), data, false, call_weight));
+		assert_ok!(Multisig::as_multi(Origin::signed(3), 3, vec![1, 2], Some(now()), Box::new(call.clone()), call_weight));
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn migration_to_v1_removes_stored_calls() {
+	use frame_support::{
+		storage::migration::{get_storage_value, put_storage_value},
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Multisig>();
+		let call = Call::Balances(BalancesCall::transfer(6, 15)).encode();
+		let hash = blake2_256(&call);
+		assert_ok!(Balances::reserve(&1, 3));
+		put_storage_value(b"Multisig", b"Calls", &hash, (call, 1u64, 3u64));
+
+		migrations::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(get_storage_value::<(Vec<u8>, u64, u64)>(b"Multisig", b"Calls", &hash), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(StorageVersion::get::<Multisig>(), 1);
+	});
+}
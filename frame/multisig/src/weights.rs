@@ -45,13 +45,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn as_multi_threshold_1(z: u32, ) -> Weight;
 	fn as_multi_create(s: u32, z: u32, ) -> Weight;
-	fn as_multi_create_store(s: u32, z: u32, ) -> Weight;
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight;
-	fn as_multi_approve_store(s: u32, z: u32, ) -> Weight;
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight;
 	fn approve_as_multi_create(s: u32, ) -> Weight;
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
-	fn approve_as_multi_complete(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
 	
 }
@@ -60,76 +57,53 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn as_multi_threshold_1(z: u32, ) -> Weight {
-		(14_183_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
+		Weight::from_ref_time(14_183_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
 			
 	}
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
-		(72_350_000 as Weight)
-			.saturating_add((64_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			
-	}
-	fn as_multi_create_store(s: u32, z: u32, ) -> Weight {
-		(83_175_000 as Weight)
-			.saturating_add((72_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((3_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(72_350_000 as u64)
+			.saturating_add(Weight::from_ref_time(64_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
-		(43_035_000 as Weight)
-			.saturating_add((140_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			
-	}
-	fn as_multi_approve_store(s: u32, z: u32, ) -> Weight {
-		(75_190_000 as Weight)
-			.saturating_add((127_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((3_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(43_035_000 as u64)
+			.saturating_add(Weight::from_ref_time(140_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
-		(92_751_000 as Weight)
-			.saturating_add((282_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((5_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(92_751_000 as u64)
+			.saturating_add(Weight::from_ref_time(282_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(5_000 as u64).saturating_mul(z as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 			
 	}
 	fn approve_as_multi_create(s: u32, ) -> Weight {
-		(71_937_000 as Weight)
-			.saturating_add((87_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(71_937_000 as u64)
+			.saturating_add(Weight::from_ref_time(87_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
-		(44_294_000 as Weight)
-			.saturating_add((89_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-			
-	}
-	fn approve_as_multi_complete(s: u32, ) -> Weight {
-		(163_098_000 as Weight)
-			.saturating_add((276_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(44_294_000 as u64)
+			.saturating_add(Weight::from_ref_time(89_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel_as_multi(s: u32, ) -> Weight {
-		(115_731_000 as Weight)
-			.saturating_add((104_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(115_731_000 as u64)
+			.saturating_add(Weight::from_ref_time(104_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -138,76 +112,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn as_multi_threshold_1(z: u32, ) -> Weight {
-		(14_183_000 as Weight)
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
+		Weight::from_ref_time(14_183_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
 			
 	}
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
-		(72_350_000 as Weight)
-			.saturating_add((64_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			
-	}
-	fn as_multi_create_store(s: u32, z: u32, ) -> Weight {
-		(83_175_000 as Weight)
-			.saturating_add((72_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((3_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(72_350_000 as u64)
+			.saturating_add(Weight::from_ref_time(64_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
-		(43_035_000 as Weight)
-			.saturating_add((140_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			
-	}
-	fn as_multi_approve_store(s: u32, z: u32, ) -> Weight {
-		(75_190_000 as Weight)
-			.saturating_add((127_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((3_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(43_035_000 as u64)
+			.saturating_add(Weight::from_ref_time(140_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(z as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
-		(92_751_000 as Weight)
-			.saturating_add((282_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((5_000 as Weight).saturating_mul(z as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(92_751_000 as u64)
+			.saturating_add(Weight::from_ref_time(282_000 as u64).saturating_mul(s as u64))
+			.saturating_add(Weight::from_ref_time(5_000 as u64).saturating_mul(z as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 			
 	}
 	fn approve_as_multi_create(s: u32, ) -> Weight {
-		(71_937_000 as Weight)
-			.saturating_add((87_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(71_937_000 as u64)
+			.saturating_add(Weight::from_ref_time(87_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
-		(44_294_000 as Weight)
-			.saturating_add((89_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
-			
-	}
-	fn approve_as_multi_complete(s: u32, ) -> Weight {
-		(163_098_000 as Weight)
-			.saturating_add((276_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		Weight::from_ref_time(44_294_000 as u64)
+			.saturating_add(Weight::from_ref_time(89_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel_as_multi(s: u32, ) -> Weight {
-		(115_731_000 as Weight)
-			.saturating_add((104_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(115_731_000 as u64)
+			.saturating_add(Weight::from_ref_time(104_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -36,6 +36,10 @@
 //! * `clear_name` - Remove an account's associated name; the deposit is returned.
 //! * `kill_name` - Forcibly remove the associated name; the deposit is lost.
 //!
+//! The deposits are reserved under the [`Config::ReserveId`] identifier, so that they are never
+//! unreserved by other pallets. The deposits reserved by previous versions of the pallet can be
+//! moved there with [`migrations::MigrateToNamedReserves`].
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
 
@@ -45,11 +49,19 @@ use sp_std::prelude::*;
 use sp_runtime::{
 	traits::{StaticLookup, Zero}
 };
-use frame_support::traits::{Currency, ReservableCurrency, OnUnbalanced};
+use frame_support::traits::{Currency, NamedReservableCurrency, OnUnbalanced, StorageVersion};
 pub use pallet::*;
 
+pub mod migrations;
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+type ReserveIdentifierOf<T> = <<T as Config>::Currency as NamedReservableCurrency<
+	<T as frame_system::Config>::AccountId,
+>>::ReserveIdentifier;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -63,7 +75,10 @@ pub mod pallet {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The currency trait.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		type Currency: NamedReservableCurrency<Self::AccountId>;
+
+		/// The identifier under which the deposits are reserved.
+		type ReserveId: Get<ReserveIdentifierOf<Self>>;
 
 		/// Reservation fee.
 		#[pallet::constant]
@@ -117,6 +132,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::call]
@@ -149,7 +165,7 @@ pub mod pallet {
 				deposit
 			} else {
 				let deposit = T::ReservationFee::get();
-				T::Currency::reserve(&sender, deposit.clone())?;
+				T::Currency::reserve_named(&T::ReserveId::get(), &sender, deposit.clone())?;
 				Self::deposit_event(Event::<T>::NameSet(sender.clone()));
 				deposit
 			};
@@ -174,7 +190,8 @@ pub mod pallet {
 
 			let deposit = <NameOf<T>>::take(&sender).ok_or(Error::<T>::Unnamed)?.1;
 
-			let err_amount = T::Currency::unreserve(&sender, deposit.clone());
+			let id = T::ReserveId::get();
+			let err_amount = T::Currency::unreserve_named(&id, &sender, deposit.clone());
 			debug_assert!(err_amount.is_zero());
 
 			Self::deposit_event(Event::<T>::NameCleared(sender, deposit));
@@ -206,7 +223,9 @@ pub mod pallet {
 			// Grab their deposit (and check that they have one).
 			let deposit = <NameOf<T>>::take(&target).ok_or(Error::<T>::Unnamed)?.1;
 			// Slash their deposit from them.
-			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit.clone()).0);
+			let id = T::ReserveId::get();
+			let (imbalance, _) = T::Currency::slash_reserved_named(&id, &target, deposit.clone());
+			T::Slashed::on_unbalanced(imbalance);
 
 			Self::deposit_event(Event::<T>::NameKilled(target, deposit));
 			Ok(())
@@ -247,7 +266,10 @@ mod tests {
 	use super::*;
 	use crate as pallet_nicks;
 
-	use frame_support::{assert_ok, assert_noop, parameter_types, ord_parameter_types};
+	use frame_support::{
+		assert_ok, assert_noop, parameter_types, ord_parameter_types,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+	};
 	use sp_core::H256;
 	use frame_system::EnsureSignedBy;
 	use sp_runtime::{
@@ -272,7 +294,9 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(
+				frame_support::weights::Weight::from_ref_time(1024),
+			);
 	}
 	impl frame_system::Config for Test {
 		type BaseCallFilter = ();
@@ -301,11 +325,13 @@ mod tests {
 	}
 	parameter_types! {
 		pub const ExistentialDeposit: u64 = 1;
+		pub const MaxReserves: u32 = 2;
 	}
 	impl pallet_balances::Config for Test {
 		type MaxLocks = ();
-		type MaxReserves = ();
+		type MaxReserves = MaxReserves;
 		type ReserveIdentifier = [u8; 8];
+		type HoldsAndFreezes = ();
 		type Balance = u64;
 		type Event = Event;
 		type DustRemoval = ();
@@ -317,6 +343,7 @@ mod tests {
 		pub const ReservationFee: u64 = 2;
 		pub const MinLength: u32 = 3;
 		pub const MaxLength: u32 = 16;
+		pub const NicksReserveId: [u8; 8] = *b"py/nicks";
 	}
 	ord_parameter_types! {
 		pub const One: u64 = 1;
@@ -324,6 +351,7 @@ mod tests {
 	impl Config for Test {
 		type Event = Event;
 		type Currency = Balances;
+		type ReserveId = NicksReserveId;
 		type ReservationFee = ReservationFee;
 		type Slashed = ();
 		type ForceOrigin = EnsureSignedBy<One, u64>;
@@ -388,6 +416,45 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn deposit_is_reserved_apart_from_other_reserves() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Balances::reserve(&1, 3));
+
+			assert_ok!(Nicks::set_name(Origin::signed(1), b"Gav".to_vec()));
+			assert_eq!(Balances::reserved_balance(1), 5);
+			assert_eq!(Balances::reserved_balance_named(&NicksReserveId::get(), &1), 2);
+
+			assert_ok!(Nicks::clear_name(Origin::signed(1)));
+			assert_eq!(Balances::reserved_balance(1), 3);
+			assert_eq!(Balances::reserved_balance_named(&NicksReserveId::get(), &1), 0);
+		});
+	}
+
+	#[test]
+	fn migrate_to_named_reserves_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Balances::reserve(&2, 5));
+			<NameOf<Test>>::insert(2, (b"Dave".to_vec(), 2));
+			<NameOf<Test>>::insert(1, (b"Gav".to_vec(), 0));
+			StorageVersion::new(0).put::<Nicks>();
+
+			migrations::MigrateToNamedReserves::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Balances::reserved_balance(2), 5);
+			assert_eq!(Balances::reserved_balance_named(&NicksReserveId::get(), &2), 2);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Nicks::on_chain_storage_version(), StorageVersion::new(1));
+
+			// The migration is executed only once.
+			migrations::MigrateToNamedReserves::<Test>::on_runtime_upgrade();
+			assert_eq!(Balances::reserved_balance_named(&NicksReserveId::get(), &2), 2);
+
+			assert_ok!(Nicks::clear_name(Origin::signed(2)));
+			assert_eq!(Balances::reserved_balance(2), 3);
+		});
+	}
+
 	#[test]
 	fn error_catching_should_work() {
 		new_test_ext().execute_with(|| {
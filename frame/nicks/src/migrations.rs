@@ -0,0 +1,70 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations for the Nicks pallet.
+
+use frame_support::{
+	traits::{
+		Get, GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency,
+		StorageVersion,
+	},
+	weights::Weight,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::marker::PhantomData;
+use super::{Config, NameOf, Pallet};
+
+/// Move the deposits of the names from the anonymous reserved balance to the reserve identified by
+/// [`Config::ReserveId`].
+///
+/// The deposits were reserved without an identifier by previous versions of the pallet. The
+/// migration is only executed if the storage version of the pallet is below 1, it would otherwise
+/// move funds reserved by other pallets.
+pub struct MigrateToNamedReserves<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToNamedReserves<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let weight = migrate_to_named_reserves::<T>();
+		StorageVersion::new(1).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
+
+fn migrate_to_named_reserves<T: Config>() -> Weight {
+	let id = T::ReserveId::get();
+	let mut names = 0u64;
+
+	for (who, (_, deposit)) in NameOf::<T>::iter() {
+		names += 1;
+		if deposit.is_zero() {
+			continue
+		}
+
+		let missing = T::Currency::unreserve(&who, deposit);
+		let deposit = deposit.saturating_sub(missing);
+		if T::Currency::reserve_named(&id, &who, deposit).is_err() {
+			// The account has too many named reserves, keep the deposit where it was.
+			let _ = T::Currency::reserve(&who, deposit);
+		}
+	}
+
+	T::DbWeight::get().reads_writes(names.saturating_mul(3), names.saturating_mul(2))
+}
@@ -51,10 +51,12 @@ pub trait WeightInfo {
 }
 
 impl WeightInfo for () {
-	fn report_offence_im_online(_r: u32, _o: u32, _n: u32, ) -> Weight { 1_000_000_000 }
-	fn report_offence_grandpa(_r: u32, _n: u32, ) -> Weight { 1_000_000_000 }
-	fn report_offence_babe(_r: u32, _n: u32, ) -> Weight { 1_000_000_000 }
-	fn on_initialize(_d: u32, ) -> Weight { 1_000_000_000 }
+	fn report_offence_im_online(_r: u32, _o: u32, _n: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000_000)
+	}
+	fn report_offence_grandpa(_r: u32, _n: u32, ) -> Weight { Weight::from_ref_time(1_000_000_000) }
+	fn report_offence_babe(_r: u32, _n: u32, ) -> Weight { Weight::from_ref_time(1_000_000_000) }
+	fn on_initialize(_d: u32, ) -> Weight { Weight::from_ref_time(1_000_000_000) }
 }
 
 #[frame_support::pallet]
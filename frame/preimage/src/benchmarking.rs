@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Preimage pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+use crate::Pallet as Preimage;
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(who: T::AccountId) -> T::AccountId {
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value());
+	who
+}
+
+/// Note a preimage of `size` bytes with a deposit from `owner`, returning its hash.
+fn noted_preimage<T: Config>(owner: &T::AccountId, size: u32) -> T::Hash {
+	let preimage = vec![1; size as usize];
+	let hash = T::Hashing::hash(&preimage);
+	Preimage::<T>::note_preimage(RawOrigin::Signed(owner.clone()).into(), preimage)
+		.expect("the owner is funded; qed");
+	hash
+}
+
+benchmarks! {
+	// A signed preimage which reserves a deposit.
+	note_preimage {
+		let s in 0 .. T::MaxSize::get();
+		let caller = funded::<T>(whitelisted_caller());
+		let preimage = vec![1; s as usize];
+		let hash = T::Hashing::hash(&preimage);
+	}: _(RawOrigin::Signed(caller), preimage)
+	verify {
+		assert!(Preimage::<T>::have(&hash));
+	}
+
+	// A noted preimage whose deposit is returned to its owner.
+	unnote_preimage {
+		let caller = funded::<T>(whitelisted_caller());
+		let hash = noted_preimage::<T>(&caller, T::MaxSize::get());
+	}: _(RawOrigin::Signed(caller), hash)
+	verify {
+		assert!(!Preimage::<T>::have(&hash));
+	}
+
+	// A noted preimage whose deposit is returned to its owner by the request.
+	request_preimage {
+		let owner = funded::<T>(account("owner", 0, SEED));
+		let hash = noted_preimage::<T>(&owner, T::MaxSize::get());
+		let origin = T::ManagerOrigin::successful_origin();
+		let call = Call::<T>::request_preimage(hash);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Preimage::<T>::is_requested(&hash));
+	}
+
+	// The last request of a noted preimage, which clears it.
+	unrequest_preimage {
+		let owner = funded::<T>(account("owner", 0, SEED));
+		let hash = noted_preimage::<T>(&owner, T::MaxSize::get());
+		Preimage::<T>::request(&hash);
+		let origin = T::ManagerOrigin::successful_origin();
+		let call = Call::<T>::unrequest_preimage(hash);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Preimage::<T>::have(&hash));
+	}
+}
+
+impl_benchmark_test_suite!(
+	Preimage,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
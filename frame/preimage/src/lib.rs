@@ -0,0 +1,353 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Preimage Pallet
+//!
+//! Stores preimages, i.e. data referenced by its hash, on chain for other pallets to look up.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Any account can note a preimage with [`Pallet::note_preimage`], reserving a deposit of
+//! [`Config::BaseDeposit`] plus [`Config::ByteDeposit`] per byte until it is unnoted with
+//! [`Pallet::unnote_preimage`]. [`Config::ManagerOrigin`] can note preimages without a deposit.
+//!
+//! Preimages can be requested, through [`Pallet::request_preimage`] or by other pallets through
+//! the [`QueryPreimage`] trait. A requested preimage is noted for free, its deposit is returned,
+//! and it is kept until all the requests for it are withdrawn.
+//!
+//! The pallet implements [`QueryPreimage`] and [`StorePreimage`], so that other pallets, such as
+//! the scheduler, can reference calls by their hash.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod benchmarking;
+pub mod weights;
+
+use sp_std::{prelude::*, convert::TryInto};
+use codec::{Encode, Decode};
+use sp_runtime::{RuntimeDebug, DispatchError, traits::{Hash, Saturating}};
+use frame_support::{
+	ensure, BoundedVec,
+	traits::{Currency, Get, ReservableCurrency, QueryPreimage, StorePreimage},
+	weights::Pays,
+};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The request status of a preimage.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum RequestStatus<AccountId, Balance> {
+	/// The preimage is not requested. It was noted by the given account, which reserved the
+	/// given deposit, or without a deposit if `None`.
+	Unrequested(Option<(AccountId, Balance)>),
+	/// The preimage is requested the given number of times. It may or may not be noted yet.
+	Requested(u32),
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency in which the deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The origin which can note preimages without a deposit and request or unrequest them.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum size of a preimage, in bytes.
+		#[pallet::constant]
+		type MaxSize: Get<u32>;
+
+		/// The base deposit for noting a preimage.
+		#[pallet::constant]
+		type BaseDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit per byte of a noted preimage.
+		#[pallet::constant]
+		type ByteDeposit: Get<BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::Hash = "Hash")]
+	pub enum Event<T: Config> {
+		/// A preimage was noted. \[hash\]
+		Noted(T::Hash),
+		/// A preimage was requested. \[hash\]
+		Requested(T::Hash),
+		/// A preimage was cleared. \[hash\]
+		Cleared(T::Hash),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The preimage is larger than [`Config::MaxSize`].
+		TooLarge,
+		/// The preimage is already noted.
+		AlreadyNoted,
+		/// The caller is not allowed to unnote the preimage.
+		NotAuthorized,
+		/// The preimage is not noted.
+		NotNoted,
+		/// The preimage is requested and cannot be unnoted.
+		Requested,
+		/// The preimage is not requested.
+		NotRequested,
+	}
+
+	/// The request status of the preimages, by hash.
+	#[pallet::storage]
+	pub type StatusFor<T: Config> = StorageMap<
+		_,
+		Identity, T::Hash,
+		RequestStatus<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The noted preimages, by hash.
+	#[pallet::storage]
+	pub type PreimageFor<T: Config> = StorageMap<
+		_,
+		Identity, T::Hash,
+		BoundedVec<u8, T::MaxSize>,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Note the preimage `bytes`.
+		///
+		/// A signed caller reserves a deposit for it, unless it is requested, in which case the
+		/// call is free. [`Config::ManagerOrigin`] notes it without a deposit.
+		#[pallet::weight(T::WeightInfo::note_preimage(bytes.len() as u32))]
+		pub fn note_preimage(
+			origin: OriginFor<T>,
+			bytes: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			let (_, was_requested) = Self::do_note_preimage(bytes, maybe_sender.as_ref())?;
+			if was_requested || maybe_sender.is_none() {
+				Ok(Pays::No.into())
+			} else {
+				Ok(().into())
+			}
+		}
+
+		/// Unnote the preimage of `hash` and return its deposit.
+		///
+		/// The dispatch origin must be the account which noted it, or [`Config::ManagerOrigin`].
+		#[pallet::weight(T::WeightInfo::unnote_preimage())]
+		pub fn unnote_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			Self::do_unnote_preimage(&hash, maybe_sender)
+		}
+
+		/// Request the preimage of `hash`, returning the deposit of whoever noted it.
+		///
+		/// The dispatch origin must be [`Config::ManagerOrigin`].
+		#[pallet::weight(T::WeightInfo::request_preimage())]
+		pub fn request_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			Self::do_request_preimage(&hash);
+			Ok(())
+		}
+
+		/// Withdraw a request for the preimage of `hash`.
+		///
+		/// The dispatch origin must be [`Config::ManagerOrigin`].
+		#[pallet::weight(T::WeightInfo::unrequest_preimage())]
+		pub fn unrequest_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			Self::do_unrequest_preimage(&hash)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure that `origin` is either [`Config::ManagerOrigin`], returning `None`, or signed,
+	/// returning the signer.
+	fn ensure_signed_or_manager(origin: T::Origin) -> Result<Option<T::AccountId>, DispatchError> {
+		if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
+			return Ok(None)
+		}
+		let who = frame_system::ensure_signed(origin)?;
+		Ok(Some(who))
+	}
+
+	/// Note the preimage `bytes`, reserving a deposit from `maybe_depositor` unless it is
+	/// requested.
+	///
+	/// Returns its hash and whether it was requested.
+	fn do_note_preimage(
+		bytes: Vec<u8>,
+		maybe_depositor: Option<&T::AccountId>,
+	) -> Result<(T::Hash, bool), DispatchError> {
+		let preimage: BoundedVec<u8, T::MaxSize> =
+			bytes.try_into().map_err(|_| Error::<T>::TooLarge)?;
+		let hash = T::Hashing::hash(&preimage);
+		ensure!(!PreimageFor::<T>::contains_key(hash), Error::<T>::AlreadyNoted);
+
+		let was_requested = match (StatusFor::<T>::get(hash), maybe_depositor) {
+			(Some(RequestStatus::Requested(..)), _) => true,
+			(_, Some(depositor)) => {
+				let length = preimage.len() as u32;
+				let deposit = T::BaseDeposit::get()
+					.saturating_add(T::ByteDeposit::get().saturating_mul(length.into()));
+				T::Currency::reserve(depositor, deposit)?;
+				let status = RequestStatus::Unrequested(Some((depositor.clone(), deposit)));
+				StatusFor::<T>::insert(hash, status);
+				false
+			},
+			(_, None) => {
+				StatusFor::<T>::insert(hash, RequestStatus::Unrequested(None));
+				false
+			},
+		};
+
+		PreimageFor::<T>::insert(hash, preimage);
+		Self::deposit_event(Event::Noted(hash));
+		Ok((hash, was_requested))
+	}
+
+	/// Unnote the preimage of `hash`, returning its deposit.
+	///
+	/// If `maybe_check_owner` is given, it must be the account which noted the preimage.
+	fn do_unnote_preimage(
+		hash: &T::Hash,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> Result<(), DispatchError> {
+		match StatusFor::<T>::get(hash).ok_or(Error::<T>::NotNoted)? {
+			RequestStatus::Unrequested(Some((owner, deposit))) => {
+				if let Some(check_owner) = maybe_check_owner {
+					ensure!(check_owner == owner, Error::<T>::NotAuthorized);
+				}
+				T::Currency::unreserve(&owner, deposit);
+			},
+			RequestStatus::Unrequested(None) => {
+				ensure!(maybe_check_owner.is_none(), Error::<T>::NotAuthorized);
+			},
+			RequestStatus::Requested(..) => return Err(Error::<T>::Requested.into()),
+		}
+
+		StatusFor::<T>::remove(hash);
+		PreimageFor::<T>::remove(hash);
+		Self::deposit_event(Event::Cleared(*hash));
+		Ok(())
+	}
+
+	/// Request the preimage of `hash`, returning the deposit of whoever noted it.
+	fn do_request_preimage(hash: &T::Hash) {
+		let count = match StatusFor::<T>::get(hash) {
+			Some(RequestStatus::Requested(count)) => count.saturating_add(1),
+			Some(RequestStatus::Unrequested(maybe_deposit)) => {
+				if let Some((owner, deposit)) = maybe_deposit {
+					T::Currency::unreserve(&owner, deposit);
+				}
+				1
+			},
+			None => 1,
+		};
+		StatusFor::<T>::insert(hash, RequestStatus::Requested(count));
+		if count == 1 {
+			Self::deposit_event(Event::Requested(*hash));
+		}
+	}
+
+	/// Withdraw a request for the preimage of `hash`, clearing it once it is no longer
+	/// requested.
+	fn do_unrequest_preimage(hash: &T::Hash) -> Result<(), DispatchError> {
+		match StatusFor::<T>::get(hash) {
+			Some(RequestStatus::Requested(count)) if count > 1 => {
+				StatusFor::<T>::insert(hash, RequestStatus::Requested(count - 1));
+			},
+			Some(RequestStatus::Requested(_)) => {
+				StatusFor::<T>::remove(hash);
+				if PreimageFor::<T>::take(hash).is_some() {
+					Self::deposit_event(Event::Cleared(*hash));
+				}
+			},
+			_ => return Err(Error::<T>::NotRequested.into()),
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> QueryPreimage<T::Hash> for Pallet<T> {
+	fn len(hash: &T::Hash) -> Option<u32> {
+		PreimageFor::<T>::decode_len(hash).map(|len| len as u32)
+	}
+
+	fn fetch(hash: &T::Hash) -> Option<Vec<u8>> {
+		PreimageFor::<T>::get(hash).map(|preimage| preimage.into_inner())
+	}
+
+	fn is_requested(hash: &T::Hash) -> bool {
+		matches!(StatusFor::<T>::get(hash), Some(RequestStatus::Requested(..)))
+	}
+
+	fn request(hash: &T::Hash) {
+		Self::do_request_preimage(hash)
+	}
+
+	fn unrequest(hash: &T::Hash) {
+		let res = Self::do_unrequest_preimage(hash);
+		debug_assert!(res.is_ok(), "`unrequest` called on a preimage which is not requested");
+	}
+}
+
+impl<T: Config> StorePreimage<T::Hash> for Pallet<T> {
+	fn note(bytes: Vec<u8>) -> Result<T::Hash, DispatchError> {
+		let hash = T::Hashing::hash(&bytes);
+		if PreimageFor::<T>::contains_key(hash) {
+			return Ok(hash)
+		}
+		Self::do_note_preimage(bytes, None).map(|(hash, _)| hash)
+	}
+
+	fn unnote(hash: &T::Hash) {
+		// Requested preimages are kept, there is nothing else to do about them.
+		let _ = Self::do_unnote_preimage(hash, None);
+	}
+}
@@ -0,0 +1,210 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the preimage pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+use pallet_balances::Error as BalancesError;
+
+#[test]
+fn user_note_preimage_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![1]));
+		assert_eq!(Balances::reserved_balance(2), 3);
+		assert_eq!(Balances::free_balance(2), 97);
+		System::assert_last_event(crate::Event::Noted(hashed([1])).into());
+
+		let h = hashed([1]);
+		assert!(Preimage::have(&h));
+		assert_eq!(Preimage::fetch(&h), Some(vec![1]));
+		assert_eq!(Preimage::len(&h), Some(1));
+
+		assert_noop!(
+			Preimage::note_preimage(Origin::signed(2), vec![1]),
+			Error::<Test>::AlreadyNoted
+		);
+		assert_noop!(
+			Preimage::note_preimage(Origin::signed(0), vec![2]),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn manager_note_preimage_works() {
+	new_test_ext().execute_with(|| {
+		let info = Preimage::note_preimage(Origin::root(), vec![1]).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Preimage::have(&hashed([1])));
+
+		assert_noop!(
+			Preimage::note_preimage(Origin::root(), vec![1]),
+			Error::<Test>::AlreadyNoted
+		);
+	});
+}
+
+#[test]
+fn too_large_preimages_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Preimage::note_preimage(Origin::signed(1), vec![0; 1025]),
+			Error::<Test>::TooLarge
+		);
+		assert_ok!(Preimage::note_preimage(Origin::root(), vec![0; 1024]));
+	});
+}
+
+#[test]
+fn unnote_preimage_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![1]));
+		let h = hashed([1]);
+		assert_noop!(
+			Preimage::unnote_preimage(Origin::signed(1), h),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Preimage::unnote_preimage(Origin::signed(2), hashed([2])),
+			Error::<Test>::NotNoted
+		);
+
+		assert_ok!(Preimage::unnote_preimage(Origin::signed(2), h));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Preimage::have(&h));
+		System::assert_last_event(crate::Event::Cleared(h).into());
+	});
+}
+
+#[test]
+fn manager_unnote_preimage_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(Origin::root(), vec![1]));
+		let h = hashed([1]);
+		assert_noop!(
+			Preimage::unnote_preimage(Origin::signed(2), h),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(Preimage::unnote_preimage(Origin::root(), h));
+		assert!(!Preimage::have(&h));
+
+		// The manager can also unnote the preimages of users, returning their deposit.
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![2]));
+		assert_ok!(Preimage::unnote_preimage(Origin::root(), hashed([2])));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn requested_then_noted_preimage_is_free() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1]);
+		assert_ok!(Preimage::request_preimage(Origin::root(), h));
+		assert!(Preimage::is_requested(&h));
+		assert!(!Preimage::have(&h));
+		System::assert_last_event(crate::Event::Requested(h).into());
+
+		let info = Preimage::note_preimage(Origin::signed(2), vec![1]).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(Preimage::have(&h));
+
+		assert_noop!(Preimage::unnote_preimage(Origin::signed(2), h), Error::<Test>::Requested);
+		assert_noop!(Preimage::unnote_preimage(Origin::root(), h), Error::<Test>::Requested);
+	});
+}
+
+#[test]
+fn request_returns_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![1]));
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		let h = hashed([1]);
+		assert_ok!(Preimage::request_preimage(Origin::root(), h));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(Preimage::is_requested(&h));
+		assert!(Preimage::have(&h));
+	});
+}
+
+#[test]
+fn requests_are_counted() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1]);
+		assert_noop!(
+			Preimage::unrequest_preimage(Origin::root(), h),
+			Error::<Test>::NotRequested
+		);
+
+		assert_ok!(Preimage::request_preimage(Origin::root(), h));
+		Preimage::request(&h);
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![1]));
+
+		assert_ok!(Preimage::unrequest_preimage(Origin::root(), h));
+		assert!(Preimage::is_requested(&h));
+		assert!(Preimage::have(&h));
+
+		Preimage::unrequest(&h);
+		assert!(!Preimage::is_requested(&h));
+		assert!(!Preimage::have(&h));
+		System::assert_last_event(crate::Event::Cleared(h).into());
+
+		assert_noop!(
+			Preimage::unrequest_preimage(Origin::root(), h),
+			Error::<Test>::NotRequested
+		);
+	});
+}
+
+#[test]
+fn manager_origin_is_required_for_requests() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1]);
+		assert_noop!(Preimage::request_preimage(Origin::signed(1), h), DispatchError::BadOrigin);
+		assert_ok!(Preimage::request_preimage(Origin::root(), h));
+		assert_noop!(Preimage::unrequest_preimage(Origin::signed(1), h), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn store_preimage_works() {
+	new_test_ext().execute_with(|| {
+		let h = Preimage::note_encoded(&42u32).unwrap();
+		assert_eq!(h, hashed(42u32.encode()));
+		assert_eq!(Preimage::fetch(&h), Some(42u32.encode()));
+		// Noting it again is fine and keeps it noted by the same account.
+		assert_eq!(Preimage::note(42u32.encode()), Ok(h));
+		assert_eq!(StatusFor::<Test>::get(h), Some(RequestStatus::Unrequested(None)));
+
+		// A preimage noted with a deposit keeps it.
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), vec![1]));
+		assert_eq!(Preimage::note(vec![1]), Ok(hashed([1])));
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		// Requested preimages are not removed by `unnote`.
+		Preimage::request(&h);
+		Preimage::unnote(&h);
+		assert!(Preimage::have(&h));
+
+		Preimage::unrequest(&h);
+		assert!(!Preimage::have(&h));
+	});
+}
@@ -437,6 +437,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::edit_announcements(&who, |ann| ann.real != real || ann.call_hash != call_hash)?;
+			Self::deposit_event(Event::AnnouncementRemoved(real, who, call_hash));
 
 			Ok(().into())
 		}
@@ -467,6 +468,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::edit_announcements(&delegate, |ann| ann.real != who || ann.call_hash != call_hash)?;
+			Self::deposit_event(Event::AnnouncementRejected(who, delegate, call_hash));
 
 			Ok(().into())
 		}
@@ -519,7 +521,12 @@ pub mod pallet {
 	}
 
 	#[pallet::event]
-	#[pallet::metadata(T::AccountId = "AccountId", T::ProxyType = "ProxyType", CallHashOf<T> = "Hash")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		T::ProxyType = "ProxyType",
+		CallHashOf<T> = "Hash",
+		T::BlockNumber = "BlockNumber"
+	)]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config>
 	{
@@ -530,6 +537,15 @@ pub mod pallet {
 		AnonymousCreated(T::AccountId, T::AccountId, T::ProxyType, u16),
 		/// An announcement was placed to make a call in the future. \[real, proxy, call_hash\]
 		Announced(T::AccountId, T::AccountId, CallHashOf<T>),
+		/// An announcement was removed by the proxy that made it. \[real, proxy, call_hash\]
+		AnnouncementRemoved(T::AccountId, T::AccountId, CallHashOf<T>),
+		/// An announcement was rejected by the account it would be made for.
+		/// \[real, proxy, call_hash\]
+		AnnouncementRejected(T::AccountId, T::AccountId, CallHashOf<T>),
+		/// A proxy was added. \[delegator, delegatee, proxy_type, delay\]
+		ProxyAdded(T::AccountId, T::AccountId, T::ProxyType, T::BlockNumber),
+		/// A proxy was removed. \[delegator, delegatee, proxy_type, delay\]
+		ProxyRemoved(T::AccountId, T::AccountId, T::ProxyType, T::BlockNumber),
 	}
 
 	/// Old name generated by `decl_event`.
@@ -637,7 +653,11 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResultWithPostInfo {
 		ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
 		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
+			let proxy_def = ProxyDefinition {
+				delegate: delegatee.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+			};
 			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
 			proxies.try_insert(i, proxy_def).map_err(|_| Error::<T>::TooMany)?;
 			let new_deposit = Self::deposit(proxies.len() as u32);
@@ -647,6 +667,7 @@ impl<T: Config> Pallet<T> {
 				T::Currency::unreserve(delegator, *deposit - new_deposit);
 			}
 			*deposit = new_deposit;
+			Self::deposit_event(Event::ProxyAdded(delegator.clone(), delegatee, proxy_type, delay));
 			Ok(().into())
 		})
 	}
@@ -667,7 +688,11 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResultWithPostInfo {
 		Proxies::<T>::try_mutate_exists(delegator, |x| {
 			let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
+			let proxy_def = ProxyDefinition {
+				delegate: delegatee.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+			};
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
 			let new_deposit = Self::deposit(proxies.len() as u32);
@@ -679,6 +704,9 @@ impl<T: Config> Pallet<T> {
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
+			Self::deposit_event(
+				Event::ProxyRemoved(delegator.clone(), delegatee, proxy_type, delay)
+			);
 			Ok(().into())
 		})
 	}
@@ -48,7 +48,9 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::simple_max(1024);
+		frame_system::limits::BlockWeights::simple_max(
+			frame_support::weights::Weight::from_ref_time(1024),
+		);
 }
 impl frame_system::Config for Test {
 	type BaseCallFilter = BaseFilter;
@@ -82,6 +84,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -92,6 +95,7 @@ impl pallet_balances::Config for Test {
 impl pallet_utility::Config for Test {
 	type Event = Event;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 }
 parameter_types! {
@@ -223,6 +227,7 @@ fn remove_announcement_works() {
 		let e = Error::<Test>::NotFound;
 		assert_noop!(Proxy::remove_announcement(Origin::signed(3), 1, [0; 32].into()), e);
 		assert_ok!(Proxy::remove_announcement(Origin::signed(3), 1, [1; 32].into()));
+		System::assert_last_event(ProxyEvent::AnnouncementRemoved(1, 3, [1; 32].into()).into());
 		let announcements = Announcements::<Test>::get(3);
 		assert_eq!(announcements.0, vec![Announcement {
 			real: 2,
@@ -245,6 +250,7 @@ fn reject_announcement_works() {
 		let e = Error::<Test>::NotFound;
 		assert_noop!(Proxy::reject_announcement(Origin::signed(4), 3, [1; 32].into()), e);
 		assert_ok!(Proxy::reject_announcement(Origin::signed(1), 3, [1; 32].into()));
+		System::assert_last_event(ProxyEvent::AnnouncementRejected(1, 3, [1; 32].into()).into());
 		let announcements = Announcements::<Test>::get(3);
 		assert_eq!(announcements.0, vec![Announcement {
 			real: 2,
@@ -368,6 +374,7 @@ fn filtering_works() {
 fn add_remove_proxies_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0));
+		System::assert_last_event(ProxyEvent::ProxyAdded(1, 2, ProxyType::Any, 0).into());
 		assert_noop!(Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0), Error::<Test>::Duplicate);
 		assert_eq!(Balances::reserved_balance(1), 2);
 		assert_ok!(Proxy::add_proxy(Origin::signed(1), 2, ProxyType::JustTransfer, 0));
@@ -379,6 +386,7 @@ fn add_remove_proxies_works() {
 		assert_noop!(Proxy::add_proxy(Origin::signed(1), 4, ProxyType::Any, 0), Error::<Test>::TooMany);
 		assert_noop!(Proxy::remove_proxy(Origin::signed(1), 3, ProxyType::JustTransfer, 0), Error::<Test>::NotFound);
 		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 4, ProxyType::JustUtility, 0));
+		System::assert_last_event(ProxyEvent::ProxyRemoved(1, 4, ProxyType::JustUtility, 0).into());
 		assert_eq!(Balances::reserved_balance(1), 4);
 		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 3, ProxyType::Any, 0));
 		assert_eq!(Balances::reserved_balance(1), 3);
@@ -60,76 +60,76 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn proxy(p: u32, ) -> Weight {
-		(32_194_000 as Weight)
-			.saturating_add((215_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(32_194_000 as u64)
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			
 	}
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		(67_490_000 as Weight)
-			.saturating_add((859_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((215_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(67_490_000 as u64)
+			.saturating_add(Weight::from_ref_time(859_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn remove_announcement(a: u32, p: u32, ) -> Weight {
-		(40_768_000 as Weight)
-			.saturating_add((882_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((122_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(40_768_000 as u64)
+			.saturating_add(Weight::from_ref_time(882_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(122_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn reject_announcement(a: u32, p: u32, ) -> Weight {
-		(42_742_000 as Weight)
-			.saturating_add((852_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((22_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(42_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(852_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(22_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn announce(a: u32, p: u32, ) -> Weight {
-		(67_967_000 as Weight)
-			.saturating_add((737_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((213_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(67_967_000 as u64)
+			.saturating_add(Weight::from_ref_time(737_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(213_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn add_proxy(p: u32, ) -> Weight {
-		(45_245_000 as Weight)
-			.saturating_add((240_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(45_245_000 as u64)
+			.saturating_add(Weight::from_ref_time(240_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_proxy(p: u32, ) -> Weight {
-		(40_742_000 as Weight)
-			.saturating_add((272_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(40_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(272_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_proxies(p: u32, ) -> Weight {
-		(39_070_000 as Weight)
-			.saturating_add((214_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(39_070_000 as u64)
+			.saturating_add(Weight::from_ref_time(214_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn anonymous(p: u32, ) -> Weight {
-		(64_851_000 as Weight)
-			.saturating_add((37_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(64_851_000 as u64)
+			.saturating_add(Weight::from_ref_time(37_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn kill_anonymous(p: u32, ) -> Weight {
-		(41_831_000 as Weight)
-			.saturating_add((207_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(41_831_000 as u64)
+			.saturating_add(Weight::from_ref_time(207_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	
@@ -138,76 +138,76 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn proxy(p: u32, ) -> Weight {
-		(32_194_000 as Weight)
-			.saturating_add((215_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+		Weight::from_ref_time(32_194_000 as u64)
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			
 	}
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		(67_490_000 as Weight)
-			.saturating_add((859_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((215_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(67_490_000 as u64)
+			.saturating_add(Weight::from_ref_time(859_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(215_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn remove_announcement(a: u32, p: u32, ) -> Weight {
-		(40_768_000 as Weight)
-			.saturating_add((882_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((122_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(40_768_000 as u64)
+			.saturating_add(Weight::from_ref_time(882_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(122_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn reject_announcement(a: u32, p: u32, ) -> Weight {
-		(42_742_000 as Weight)
-			.saturating_add((852_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((22_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(42_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(852_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(22_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn announce(a: u32, p: u32, ) -> Weight {
-		(67_967_000 as Weight)
-			.saturating_add((737_000 as Weight).saturating_mul(a as Weight))
-			.saturating_add((213_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(67_967_000 as u64)
+			.saturating_add(Weight::from_ref_time(737_000 as u64).saturating_mul(a as u64))
+			.saturating_add(Weight::from_ref_time(213_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn add_proxy(p: u32, ) -> Weight {
-		(45_245_000 as Weight)
-			.saturating_add((240_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(45_245_000 as u64)
+			.saturating_add(Weight::from_ref_time(240_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_proxy(p: u32, ) -> Weight {
-		(40_742_000 as Weight)
-			.saturating_add((272_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(40_742_000 as u64)
+			.saturating_add(Weight::from_ref_time(272_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn remove_proxies(p: u32, ) -> Weight {
-		(39_070_000 as Weight)
-			.saturating_add((214_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(39_070_000 as u64)
+			.saturating_add(Weight::from_ref_time(214_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn anonymous(p: u32, ) -> Weight {
-		(64_851_000 as Weight)
-			.saturating_add((37_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(64_851_000 as u64)
+			.saturating_add(Weight::from_ref_time(37_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn kill_anonymous(p: u32, ) -> Weight {
-		(41_831_000 as Weight)
-			.saturating_add((207_000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(41_831_000 as u64)
+			.saturating_add(Weight::from_ref_time(207_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	
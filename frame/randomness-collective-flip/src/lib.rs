@@ -23,6 +23,10 @@
 //! adversaries. Using this pallet as a randomness source is advisable primarily in low-security
 //! situations like testing.
 //!
+//! **This pallet must not be used for anything security-relevant.** Chains with a VRF-based
+//! consensus like BABE should use `pallet-vrf-randomness` instead, whose randomness can only be
+//! influenced by withholding blocks at the end of an epoch.
+//!
 //! ## Public Functions
 //!
 //! See the [`Module`] struct for details of publicly available functions.
@@ -85,7 +89,7 @@ decl_module! {
 				values[index] = parent_hash;
 			});
 
-			0
+			Weight::zero()
 		}
 	}
 }
@@ -165,7 +169,7 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: limits::BlockWeights = limits::BlockWeights
-			::simple_max(1024);
+			::simple_max(frame_support::weights::Weight::from_ref_time(1024));
 		pub BlockLength: limits::BlockLength = limits::BlockLength
 			::max(2 * 1024);
 	}
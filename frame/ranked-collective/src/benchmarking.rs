@@ -0,0 +1,112 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ranked collective pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+
+use crate::Pallet as Collective;
+
+const SEED: u32 = 0;
+
+/// Add a new member at `rank`.
+fn make_member<T: Config<I>, I: 'static>(rank: Rank) -> T::AccountId {
+	let who = account::<T::AccountId>("member", MemberCount::<T, I>::get(0), SEED);
+	assert!(Collective::<T, I>::do_add_member(who.clone()).is_ok());
+	for _ in 0..rank {
+		assert!(Collective::<T, I>::do_promote_member(who.clone(), None).is_ok());
+	}
+	who
+}
+
+benchmarks_instance_pallet! {
+	add_member {
+		let who = account::<T::AccountId>("member", 0, SEED);
+		let origin = T::PromoteOrigin::successful_origin();
+		let call = Call::<T, I>::add_member(who.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Collective::<T, I>::rank_of(&who), Some(0));
+	}
+
+	remove_member {
+		let r in 0 .. 10;
+
+		let who = make_member::<T, I>(r as Rank);
+		let origin = T::DemoteOrigin::successful_origin();
+		let call = Call::<T, I>::remove_member(who.clone(), r as Rank);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Collective::<T, I>::rank_of(&who), None);
+	}
+
+	promote_member {
+		let who = make_member::<T, I>(0);
+		let origin = T::PromoteOrigin::successful_origin();
+		let call = Call::<T, I>::promote_member(who.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Collective::<T, I>::rank_of(&who), Some(1));
+	}
+
+	demote_member {
+		let who = make_member::<T, I>(1);
+		let origin = T::DemoteOrigin::successful_origin();
+		let call = Call::<T, I>::demote_member(who.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Collective::<T, I>::rank_of(&who), Some(0));
+	}
+
+	// The member changes its vote.
+	vote {
+		let caller = make_member::<T, I>(0);
+		let poll = T::Polls::create_ongoing(0).expect("the poll is created");
+		let origin = RawOrigin::Signed(caller.clone()).into();
+		assert!(Collective::<T, I>::vote(origin, poll, true).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), poll, false)
+	verify {
+		assert_eq!(Voting::<T, I>::get(&poll, &caller), Some(VoteRecord::Nay(1)));
+	}
+
+	cleanup_poll {
+		let n in 1 .. 100;
+
+		let poll = T::Polls::create_ongoing(0).expect("the poll is created");
+		for _ in 0..n {
+			let who = make_member::<T, I>(0);
+			assert!(Collective::<T, I>::vote(RawOrigin::Signed(who).into(), poll, true).is_ok());
+		}
+		assert!(T::Polls::end_ongoing(poll, false).is_ok());
+		let caller = account::<T::AccountId>("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), poll, n)
+	verify {
+		assert_eq!(Voting::<T, I>::iter_prefix(&poll).count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(
+	Collective,
+	crate::tests::new_test_ext(),
+	crate::tests::Test,
+);
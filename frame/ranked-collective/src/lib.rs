@@ -0,0 +1,499 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Ranked Collective Pallet
+//!
+//! A collective whose members each have a rank, the rank determining their voting power.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! Members are added at rank zero, and promoted or demoted one rank at a time by the
+//! [`Config::PromoteOrigin`] and the [`Config::DemoteOrigin`], whose success values bound the
+//! ranks they may promote to and demote from. [`EnsureRanked`] lets the members of at least a
+//! given rank dispatch, and [`EnsureLowerRanks`] lets them promote and demote the members of the
+//! ranks below their own.
+//!
+//! The members vote on the polls of [`Config::Polls`], e.g. the referenda of a referenda pallet
+//! whose tally is the [`Tally`] of this pallet. The class of a poll is the minimum rank of its
+//! voters, and a member votes with one vote for each rank it holds from it: the member at that
+//! rank has one vote, the member one rank above it two votes and so on. The approval of a poll is
+//! the proportion of its votes which are ayes, and its support the proportion of its eligible
+//! members which voted aye.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::{EnsureOrigin, PollStatus, Polling, VoteTally},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use sp_runtime::{traits::Saturating, ArithmeticError, Perbill, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The number of members of the collective.
+pub type MemberIndex = u32;
+
+/// The rank of a member.
+pub type Rank = u16;
+
+/// The number of votes of a member, or in a poll.
+pub type Votes = u32;
+
+/// The maximum number of voters of the polls of a class, i.e. of a minimum rank.
+pub trait GetMaxVoters {
+	/// The number of members of at least `rank`.
+	fn get_max_voters(rank: Rank) -> MemberIndex;
+}
+
+/// The votes of the members in an ongoing poll.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct Tally<M: GetMaxVoters> {
+	/// The number of members which voted aye.
+	pub bare_ayes: MemberIndex,
+	/// The number of aye votes, weighted by the ranks of the voters.
+	pub ayes: Votes,
+	/// The number of nay votes, weighted by the ranks of the voters.
+	pub nays: Votes,
+	dummy: PhantomData<M>,
+}
+
+impl<M: GetMaxVoters> Tally<M> {
+	/// Create a tally from its parts.
+	pub fn from_parts(bare_ayes: MemberIndex, ayes: Votes, nays: Votes) -> Self {
+		Tally { bare_ayes, ayes, nays, dummy: PhantomData }
+	}
+}
+
+impl<M: GetMaxVoters> VoteTally<Votes, Rank> for Tally<M> {
+	fn new(_: Rank) -> Self {
+		Self::from_parts(0, 0, 0)
+	}
+
+	fn ayes(&self, _: Rank) -> Votes {
+		self.bare_ayes
+	}
+
+	fn support(&self, class: Rank) -> Perbill {
+		Perbill::from_rational(self.bare_ayes, M::get_max_voters(class))
+	}
+
+	fn approval(&self, _: Rank) -> Perbill {
+		Perbill::from_rational(self.ayes, self.ayes.saturating_add(self.nays))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(class: Rank) -> Self {
+		let max_voters = M::get_max_voters(class);
+		Self::from_parts(max_voters, max_voters, 0)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(class: Rank) -> Self {
+		Self::from_parts(0, 0, M::get_max_voters(class))
+	}
+}
+
+/// The record of a member.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct MemberRecord {
+	/// The rank of the member.
+	pub rank: Rank,
+}
+
+/// The vote of a member in a poll, with its number of votes.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum VoteRecord {
+	/// The member voted aye.
+	Aye(Votes),
+	/// The member voted nay.
+	Nay(Votes),
+}
+
+impl From<(bool, Votes)> for VoteRecord {
+	fn from((aye, votes): (bool, Votes)) -> Self {
+		match aye {
+			true => VoteRecord::Aye(votes),
+			false => VoteRecord::Nay(votes),
+		}
+	}
+}
+
+pub type TallyOf<T, I = ()> = Tally<Pallet<T, I>>;
+pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The origin which may add members and promote them. Its success value is the highest
+		/// rank to which it may promote a member.
+		type PromoteOrigin: EnsureOrigin<Self::Origin, Success = Rank>;
+
+		/// The origin which may demote and remove members. Its success value is the highest rank
+		/// from which it may demote or remove a member.
+		type DemoteOrigin: EnsureOrigin<Self::Origin, Success = Rank>;
+
+		/// The polls on which the members vote, whose class is the minimum rank of their voters.
+		type Polls: Polling<
+			TallyOf<Self, I>,
+			Votes = Votes,
+			Class = Rank,
+			Moment = Self::BlockNumber,
+		>;
+	}
+
+	/// The number of members of at least each rank.
+	#[pallet::storage]
+	pub type MemberCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, Rank, MemberIndex, ValueQuery>;
+
+	/// The records of the members.
+	#[pallet::storage]
+	pub type Members<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, MemberRecord>;
+
+	/// The votes of the members in each poll.
+	#[pallet::storage]
+	pub type Voting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat, PollIndexOf<T, I>,
+		Twox64Concat, T::AccountId,
+		VoteRecord,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A member has been added, at rank zero. \[who\]
+		MemberAdded(T::AccountId),
+		/// The rank of a member has changed. \[who, rank\]
+		RankChanged(T::AccountId, Rank),
+		/// A member has been removed, from the given rank. \[who, rank\]
+		MemberRemoved(T::AccountId, Rank),
+		/// A member has voted in a poll, resulting in the given tally. \[who, poll, vote, tally\]
+		Voted(T::AccountId, PollIndexOf<T, I>, VoteRecord, TallyOf<T, I>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The account is already a member.
+		AlreadyMember,
+		/// The account is not a member.
+		NotMember,
+		/// The poll is not ongoing.
+		NotPolling,
+		/// The poll is completed, its votes may be cleaned up.
+		Completed,
+		/// The poll is ongoing, its votes may not be cleaned up yet.
+		Ongoing,
+		/// There are no further votes of the poll to clean up.
+		NoneRemaining,
+		/// The rank of the member is too low to vote in the poll.
+		RankTooLow,
+		/// The witness of the rank is not the rank of the member.
+		InvalidWitness,
+		/// The origin may not promote to, or demote from, the rank.
+		NoPermission,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Add a member to the collective, at rank zero.
+		///
+		/// The dispatch origin of this call must be the `PromoteOrigin`.
+		///
+		/// - `who`: The account to add, which must not be a member.
+		///
+		/// Emits `MemberAdded`.
+		#[pallet::weight(T::WeightInfo::add_member())]
+		pub fn add_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::PromoteOrigin::ensure_origin(origin)?;
+			Self::do_add_member(who)
+		}
+
+		/// Promote a member one rank up.
+		///
+		/// The dispatch origin of this call must be the `PromoteOrigin`, permitted to promote to
+		/// the new rank of the member.
+		///
+		/// - `who`: The member to promote.
+		///
+		/// Emits `RankChanged`.
+		#[pallet::weight(T::WeightInfo::promote_member())]
+		pub fn promote_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			let max_rank = T::PromoteOrigin::ensure_origin(origin)?;
+			Self::do_promote_member(who, Some(max_rank))
+		}
+
+		/// Demote a member one rank down, removing it from the collective if it is at rank zero.
+		///
+		/// The dispatch origin of this call must be the `DemoteOrigin`, permitted to demote from
+		/// the current rank of the member.
+		///
+		/// - `who`: The member to demote.
+		///
+		/// Emits `RankChanged`, or `MemberRemoved` if the member was at rank zero.
+		#[pallet::weight(T::WeightInfo::demote_member())]
+		pub fn demote_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			let max_rank = T::DemoteOrigin::ensure_origin(origin)?;
+			let rank = Self::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			ensure!(rank <= max_rank, Error::<T, I>::NoPermission);
+			match rank.checked_sub(1) {
+				Some(new_rank) => {
+					MemberCount::<T, I>::mutate(rank, |count| count.saturating_dec());
+					Members::<T, I>::insert(&who, MemberRecord { rank: new_rank });
+					Self::deposit_event(Event::RankChanged(who, new_rank));
+				},
+				None => Self::do_remove_member(who, rank),
+			}
+			Ok(())
+		}
+
+		/// Remove a member from the collective, whatever its rank.
+		///
+		/// The dispatch origin of this call must be the `DemoteOrigin`, permitted to demote from
+		/// the rank of the member.
+		///
+		/// - `who`: The member to remove.
+		/// - `rank`: The rank of the member, as a witness of the weight of the call.
+		///
+		/// Emits `MemberRemoved`.
+		///
+		/// Weight: `O(rank)`.
+		#[pallet::weight(T::WeightInfo::remove_member(*rank as u32))]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			rank: Rank,
+		) -> DispatchResult {
+			let max_rank = T::DemoteOrigin::ensure_origin(origin)?;
+			let actual_rank = Self::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			ensure!(actual_rank == rank, Error::<T, I>::InvalidWitness);
+			ensure!(rank <= max_rank, Error::<T, I>::NoPermission);
+			Self::do_remove_member(who, rank);
+			Ok(())
+		}
+
+		/// Vote in an ongoing poll, or change the vote.
+		///
+		/// The dispatch origin of this call must be _Signed_ by a member of at least the class of
+		/// the poll, i.e. its minimum rank. The vote has one vote for each rank of the member from
+		/// this minimum rank.
+		///
+		/// - `poll`: The index of the poll.
+		/// - `aye`: Whether the vote is an aye.
+		///
+		/// Emits `Voted`.
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(origin: OriginFor<T>, poll: PollIndexOf<T, I>, aye: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let rank = Self::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			T::Polls::try_access_poll(poll, |status| -> DispatchResult {
+				match status {
+					PollStatus::None => Err(Error::<T, I>::NotPolling.into()),
+					PollStatus::Completed(..) => Err(Error::<T, I>::Completed.into()),
+					PollStatus::Ongoing(tally, min_rank) => {
+						let votes = Self::rank_to_votes(rank, min_rank)?;
+						if let Some(old) = Voting::<T, I>::get(&poll, &who) {
+							// Shouldn't be possible to fail, but we handle it gracefully.
+							match old {
+								VoteRecord::Aye(old_votes) => {
+									tally.bare_ayes = tally.bare_ayes.checked_sub(1)
+										.ok_or(ArithmeticError::Underflow)?;
+									tally.ayes = tally.ayes.checked_sub(old_votes)
+										.ok_or(ArithmeticError::Underflow)?;
+								},
+								VoteRecord::Nay(old_votes) => tally.nays = tally.nays
+									.checked_sub(old_votes)
+									.ok_or(ArithmeticError::Underflow)?,
+							}
+						}
+						if aye {
+							tally.bare_ayes.saturating_inc();
+							tally.ayes.saturating_accrue(votes);
+						} else {
+							tally.nays.saturating_accrue(votes);
+						}
+						let vote = VoteRecord::from((aye, votes));
+						Voting::<T, I>::insert(&poll, &who, vote);
+						Self::deposit_event(Event::Voted(who, poll, vote, tally.clone()));
+						Ok(())
+					},
+				}
+			})
+		}
+
+		/// Remove up to `max` votes of a poll which is no longer ongoing.
+		///
+		/// The dispatch origin of this call must be _Signed_, by any account.
+		///
+		/// - `poll`: The index of the poll, which must be completed or cancelled.
+		/// - `max`: The maximum number of votes to remove.
+		///
+		/// Weight: `O(max)`.
+		#[pallet::weight(T::WeightInfo::cleanup_poll(*max))]
+		pub fn cleanup_poll(
+			origin: OriginFor<T>,
+			poll: PollIndexOf<T, I>,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(T::Polls::as_ongoing(poll).is_none(), Error::<T, I>::Ongoing);
+			let count = Voting::<T, I>::drain_prefix(&poll).take(max as usize).count() as u32;
+			ensure!(count > 0, Error::<T, I>::NoneRemaining);
+			Ok(Some(T::WeightInfo::cleanup_poll(count)).into())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The rank of `who`, if it is a member.
+	pub fn rank_of(who: &T::AccountId) -> Option<Rank> {
+		Members::<T, I>::get(who).map(|record| record.rank)
+	}
+
+	/// The votes of a member of `rank` in a poll of `min_rank`.
+	fn rank_to_votes(rank: Rank, min_rank: Rank) -> Result<Votes, DispatchError> {
+		let excess = rank.checked_sub(min_rank).ok_or(Error::<T, I>::RankTooLow)?;
+		Ok(excess as Votes + 1)
+	}
+
+	/// Add `who` at rank zero.
+	pub(crate) fn do_add_member(who: T::AccountId) -> DispatchResult {
+		ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
+		MemberCount::<T, I>::mutate(0, |count| count.saturating_inc());
+		Members::<T, I>::insert(&who, MemberRecord { rank: 0 });
+		Self::deposit_event(Event::MemberAdded(who));
+		Ok(())
+	}
+
+	/// Promote `who` one rank up, to at most `max_rank` if given.
+	pub(crate) fn do_promote_member(who: T::AccountId, max_rank: Option<Rank>) -> DispatchResult {
+		let rank = Self::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+		let new_rank = rank.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+		ensure!(max_rank.map_or(true, |max| new_rank <= max), Error::<T, I>::NoPermission);
+		MemberCount::<T, I>::mutate(new_rank, |count| count.saturating_inc());
+		Members::<T, I>::insert(&who, MemberRecord { rank: new_rank });
+		Self::deposit_event(Event::RankChanged(who, new_rank));
+		Ok(())
+	}
+
+	/// Remove `who`, a member of `rank`.
+	fn do_remove_member(who: T::AccountId, rank: Rank) {
+		for r in 0..=rank {
+			MemberCount::<T, I>::mutate(r, |count| count.saturating_dec());
+		}
+		Members::<T, I>::remove(&who);
+		Self::deposit_event(Event::MemberRemoved(who, rank));
+	}
+}
+
+impl<T: Config<I>, I: 'static> GetMaxVoters for Pallet<T, I> {
+	fn get_max_voters(rank: Rank) -> MemberIndex {
+		MemberCount::<T, I>::get(rank)
+	}
+}
+
+/// Ensure that the origin is signed by a member of at least `MIN_RANK`, whose rank is the success
+/// value.
+pub struct EnsureRanked<T, I, const MIN_RANK: Rank>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static, const MIN_RANK: Rank> EnsureOrigin<T::Origin>
+	for EnsureRanked<T, I, MIN_RANK>
+{
+	type Success = Rank;
+
+	fn try_origin(o: T::Origin) -> Result<Rank, T::Origin> {
+		let who = frame_system::EnsureSigned::<T::AccountId>::try_origin(o)?;
+		match Pallet::<T, I>::rank_of(&who) {
+			Some(rank) if rank >= MIN_RANK => Ok(rank),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		frame_system::RawOrigin::Signed(ranked_account::<T, I>(MIN_RANK)).into()
+	}
+}
+
+/// Ensure that the origin is signed by a member of at least `MIN_RANK`, the success value being the
+/// highest rank below that of the member.
+///
+/// As the `PromoteOrigin` and the `DemoteOrigin`, it lets the members promote to, and demote from,
+/// the ranks below their own only.
+pub struct EnsureLowerRanks<T, I, const MIN_RANK: Rank>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static, const MIN_RANK: Rank> EnsureOrigin<T::Origin>
+	for EnsureLowerRanks<T, I, MIN_RANK>
+{
+	type Success = Rank;
+
+	fn try_origin(o: T::Origin) -> Result<Rank, T::Origin> {
+		let who = frame_system::EnsureSigned::<T::AccountId>::try_origin(o)?;
+		match Pallet::<T, I>::rank_of(&who) {
+			Some(rank) if rank >= MIN_RANK && rank > 0 => Ok(rank - 1),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		frame_system::RawOrigin::Signed(ranked_account::<T, I>(MIN_RANK.max(1))).into()
+	}
+}
+
+/// An account which is a member of at least `rank`, added and promoted as needed.
+#[cfg(feature = "runtime-benchmarks")]
+fn ranked_account<T: Config<I>, I: 'static>(rank: Rank) -> T::AccountId {
+	let who: T::AccountId = frame_benchmarking::account("ranked", rank as u32, 0);
+	if Pallet::<T, I>::rank_of(&who).is_none() {
+		Pallet::<T, I>::do_add_member(who.clone()).expect("the account is not a member");
+	}
+	while Pallet::<T, I>::rank_of(&who).map_or(false, |r| r < rank) {
+		Pallet::<T, I>::do_promote_member(who.clone(), None).expect("the account is a member");
+	}
+	who
+}
@@ -0,0 +1,373 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's tests.
+
+use crate as pallet_ranked_collective;
+use super::*;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	testing::Header,
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Club: pallet_ranked_collective::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+/// The state of a poll of the tests.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+	Ongoing(TallyOf<Test>, Rank),
+	Completed(u64, bool),
+}
+use TestPollState::*;
+
+thread_local! {
+	/// The polls of the tests: `1` of minimum rank `1`, and `2` of minimum rank `3`.
+	pub static POLLS: RefCell<BTreeMap<u8, TestPollState>> = RefCell::new(vec![
+		(1, Ongoing(Tally::from_parts(0, 0, 0), 1)),
+		(2, Ongoing(Tally::from_parts(0, 0, 0), 3)),
+	].into_iter().collect());
+}
+
+pub struct TestPolls;
+impl TestPolls {
+	fn set(index: u8, state: TestPollState) {
+		POLLS.with(|p| p.borrow_mut().insert(index, state));
+	}
+
+	fn tally(index: u8) -> TallyOf<Test> {
+		match POLLS.with(|p| p.borrow().get(&index).cloned()) {
+			Some(Ongoing(tally, _)) => tally,
+			_ => panic!("the poll is ongoing"),
+		}
+	}
+}
+
+impl Polling<TallyOf<Test>> for TestPolls {
+	type Index = u8;
+	type Votes = Votes;
+	type Class = Rank;
+	type Moment = u64;
+
+	fn classes() -> Vec<Rank> {
+		vec![0, 1, 2, 3]
+	}
+
+	fn as_ongoing(index: u8) -> Option<(TallyOf<Test>, Rank)> {
+		POLLS.with(|p| match p.borrow().get(&index) {
+			Some(Ongoing(tally, class)) => Some((tally.clone(), *class)),
+			_ => None,
+		})
+	}
+
+	fn access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, u64, Rank>) -> R,
+	) -> R {
+		let mut polls = POLLS.with(|p| p.borrow().clone());
+		let result = match polls.get_mut(&index) {
+			Some(Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+			Some(Completed(end, approved)) => f(PollStatus::Completed(*end, *approved)),
+			None => f(PollStatus::None),
+		};
+		POLLS.with(|p| *p.borrow_mut() = polls);
+		result
+	}
+
+	fn try_access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, u64, Rank>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		let mut polls = POLLS.with(|p| p.borrow().clone());
+		let result = match polls.get_mut(&index) {
+			Some(Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+			Some(Completed(end, approved)) => f(PollStatus::Completed(*end, *approved)),
+			None => f(PollStatus::None),
+		}?;
+		POLLS.with(|p| *p.borrow_mut() = polls);
+		Ok(result)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Rank) -> Result<u8, ()> {
+		let index = POLLS.with(|p| p.borrow().keys().rev().next().map_or(0, |x| x + 1));
+		Self::set(index, Ongoing(Tally::from_parts(0, 0, 0), class));
+		Ok(index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: u8, approved: bool) -> Result<(), ()> {
+		Self::set(index, Completed(frame_system::Pallet::<Test>::block_number(), approved));
+		Ok(())
+	}
+}
+
+/// Root, which may promote to and demote from any rank, or a member of at least rank one, which
+/// may do so for the ranks below its own.
+pub struct EnsureRootOrLowerRanks;
+impl EnsureOrigin<Origin> for EnsureRootOrLowerRanks {
+	type Success = Rank;
+
+	fn try_origin(o: Origin) -> Result<Rank, Origin> {
+		EnsureRoot::<u64>::try_origin(o)
+			.map(|()| Rank::max_value())
+			.or_else(EnsureLowerRanks::<Test, (), 1>::try_origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::root()
+	}
+}
+
+impl Config for Test {
+	type Event = Event;
+	type WeightInfo = ();
+	type PromoteOrigin = EnsureRootOrLowerRanks;
+	type DemoteOrigin = EnsureRootOrLowerRanks;
+	type Polls = TestPolls;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Add `who` to the collective at `rank`.
+fn add_member_at(who: u64, rank: Rank) {
+	assert_ok!(Club::add_member(Origin::root(), who));
+	for _ in 0..rank {
+		assert_ok!(Club::promote_member(Origin::root(), who));
+	}
+}
+
+fn member_counts() -> Vec<MemberIndex> {
+	(0..4).map(MemberCount::<Test>::get).collect()
+}
+
+fn tally(bare_ayes: MemberIndex, ayes: Votes, nays: Votes) -> TallyOf<Test> {
+	Tally::from_parts(bare_ayes, ayes, nays)
+}
+
+#[test]
+fn membership_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(Origin::root(), 1));
+		assert_noop!(Club::add_member(Origin::root(), 1), Error::<Test>::AlreadyMember);
+		assert_ok!(Club::promote_member(Origin::root(), 1));
+		assert_ok!(Club::promote_member(Origin::root(), 1));
+		assert_ok!(Club::add_member(Origin::root(), 2));
+		assert_eq!(Club::rank_of(&1), Some(2));
+		assert_eq!(Club::rank_of(&2), Some(0));
+		assert_eq!(member_counts(), vec![2, 1, 1, 0]);
+
+		assert_ok!(Club::demote_member(Origin::root(), 1));
+		assert_eq!(member_counts(), vec![2, 1, 0, 0]);
+		// demoting a member of rank zero removes it.
+		assert_ok!(Club::demote_member(Origin::root(), 2));
+		assert_eq!(Club::rank_of(&2), None);
+		assert_eq!(member_counts(), vec![1, 1, 0, 0]);
+
+		assert_noop!(Club::remove_member(Origin::root(), 1, 0), Error::<Test>::InvalidWitness);
+		assert_ok!(Club::remove_member(Origin::root(), 1, 1));
+		assert_eq!(member_counts(), vec![0, 0, 0, 0]);
+
+		assert_noop!(Club::promote_member(Origin::root(), 1), Error::<Test>::NotMember);
+		assert_noop!(Club::demote_member(Origin::root(), 1), Error::<Test>::NotMember);
+		assert_eq!(
+			System::events().into_iter().map(|r| r.event).filter_map(|e| match e {
+				Event::Club(inner) => Some(inner),
+				_ => None,
+			}).collect::<Vec<_>>(),
+			vec![
+				crate::Event::MemberAdded(1),
+				crate::Event::RankChanged(1, 1),
+				crate::Event::RankChanged(1, 2),
+				crate::Event::MemberAdded(2),
+				crate::Event::RankChanged(1, 1),
+				crate::Event::MemberRemoved(2, 0),
+				crate::Event::MemberRemoved(1, 1),
+			],
+		);
+	});
+}
+
+#[test]
+fn members_promote_and_demote_lower_ranks_only() {
+	new_test_ext().execute_with(|| {
+		add_member_at(1, 2);
+		add_member_at(2, 0);
+		add_member_at(3, 2);
+		add_member_at(4, 1);
+
+		assert_ok!(Club::add_member(Origin::signed(1), 5));
+		assert_ok!(Club::promote_member(Origin::signed(1), 2));
+		assert_noop!(Club::promote_member(Origin::signed(1), 2), Error::<Test>::NoPermission);
+		assert_noop!(Club::demote_member(Origin::signed(1), 3), Error::<Test>::NoPermission);
+		assert_ok!(Club::demote_member(Origin::signed(1), 4));
+		assert_eq!(Club::rank_of(&4), Some(0));
+
+		// members of rank zero and non-members have no permission at all.
+		assert_noop!(Club::promote_member(Origin::signed(4), 5), BadOrigin);
+		assert_noop!(Club::demote_member(Origin::signed(6), 5), BadOrigin);
+		assert_noop!(Club::remove_member(Origin::signed(1), 3, 2), Error::<Test>::NoPermission);
+		assert_ok!(Club::remove_member(Origin::signed(1), 5, 0));
+	});
+}
+
+#[test]
+fn ensure_ranked_works() {
+	new_test_ext().execute_with(|| {
+		add_member_at(1, 1);
+		add_member_at(2, 3);
+
+		type Rank2 = EnsureRanked<Test, (), 2>;
+		assert!(Rank2::try_origin(Origin::signed(1)).is_err());
+		assert_eq!(Rank2::try_origin(Origin::signed(2)).ok(), Some(3));
+		assert!(Rank2::try_origin(Origin::signed(3)).is_err());
+		assert!(Rank2::try_origin(Origin::root()).is_err());
+
+		type Lower = EnsureLowerRanks<Test, (), 0>;
+		assert_eq!(Lower::try_origin(Origin::signed(1)).ok(), Some(0));
+		assert_eq!(Lower::try_origin(Origin::signed(2)).ok(), Some(2));
+	});
+}
+
+#[test]
+fn voting_works() {
+	new_test_ext().execute_with(|| {
+		add_member_at(0, 0);
+		add_member_at(1, 1);
+		add_member_at(2, 2);
+		add_member_at(3, 3);
+
+		assert_noop!(Club::vote(Origin::signed(0), 1, true), Error::<Test>::RankTooLow);
+		assert_noop!(Club::vote(Origin::signed(4), 1, true), Error::<Test>::NotMember);
+
+		assert_ok!(Club::vote(Origin::signed(1), 1, true));
+		assert_eq!(TestPolls::tally(1), tally(1, 1, 0));
+		assert_ok!(Club::vote(Origin::signed(3), 1, false));
+		assert_eq!(TestPolls::tally(1), tally(1, 1, 3));
+		assert_ok!(Club::vote(Origin::signed(2), 1, true));
+		assert_eq!(TestPolls::tally(1), tally(2, 3, 3));
+		assert_eq!(Voting::<Test>::get(1, 3), Some(VoteRecord::Nay(3)));
+
+		// changing a vote.
+		assert_ok!(Club::vote(Origin::signed(3), 1, true));
+		assert_eq!(TestPolls::tally(1), tally(3, 6, 0));
+		assert_ok!(Club::vote(Origin::signed(1), 1, false));
+		assert_eq!(TestPolls::tally(1), tally(2, 5, 1));
+		assert_eq!(
+			System::events().last().map(|r| r.event.clone()),
+			Some(Event::Club(crate::Event::Voted(1, 1, VoteRecord::Nay(1), tally(2, 5, 1)))),
+		);
+
+		// only the members of rank three vote in poll 2.
+		assert_noop!(Club::vote(Origin::signed(2), 2, true), Error::<Test>::RankTooLow);
+		assert_ok!(Club::vote(Origin::signed(3), 2, true));
+		assert_eq!(TestPolls::tally(2), tally(1, 1, 0));
+	});
+}
+
+#[test]
+fn tally_support_and_approval_work() {
+	new_test_ext().execute_with(|| {
+		add_member_at(1, 1);
+		add_member_at(2, 2);
+		add_member_at(3, 3);
+		add_member_at(4, 3);
+
+		let tally = tally(1, 3, 1);
+		assert_eq!(tally.support(1), Perbill::from_percent(25));
+		assert_eq!(tally.support(3), Perbill::from_percent(50));
+		assert_eq!(tally.approval(1), Perbill::from_percent(75));
+		assert_eq!(tally.ayes(1), 1);
+	});
+}
+
+#[test]
+fn voting_on_concluded_polls_fails() {
+	new_test_ext().execute_with(|| {
+		add_member_at(1, 1);
+		TestPolls::set(1, Completed(1, true));
+		assert_noop!(Club::vote(Origin::signed(1), 1, true), Error::<Test>::Completed);
+		assert_noop!(Club::vote(Origin::signed(1), 3, true), Error::<Test>::NotPolling);
+	});
+}
+
+#[test]
+fn cleanup_poll_works() {
+	new_test_ext().execute_with(|| {
+		add_member_at(1, 1);
+		add_member_at(2, 1);
+		add_member_at(3, 1);
+		for who in 1..=3 {
+			assert_ok!(Club::vote(Origin::signed(who), 1, true));
+		}
+		assert_noop!(Club::cleanup_poll(Origin::signed(4), 1, 10), Error::<Test>::Ongoing);
+
+		TestPolls::set(1, Completed(1, true));
+		assert_ok!(Club::cleanup_poll(Origin::signed(4), 1, 2));
+		assert_eq!(Voting::<Test>::iter_prefix(1).count(), 1);
+		assert_ok!(Club::cleanup_poll(Origin::signed(4), 1, 2));
+		assert_eq!(Voting::<Test>::iter_prefix(1).count(), 0);
+		assert_noop!(Club::cleanup_poll(Origin::signed(4), 1, 2), Error::<Test>::NoneRemaining);
+	});
+}
@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Referenda pallet benchmarking.
+//!
+//! The referenda are submitted with the root origin, for which the runtime must have a track.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{
+	account, benchmarks, impl_benchmark_test_suite, whitelist_account, whitelisted_caller,
+};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+
+use crate::Pallet as Referenda;
+
+/// An account with enough funds for any deposit.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value());
+	who
+}
+
+/// Submit a referendum with the root origin, returning its index.
+fn create_referendum<T: Config>() -> ReferendumIndex {
+	let origin = T::SubmitOrigin::successful_origin();
+	let who = T::SubmitOrigin::ensure_origin(origin.clone()).expect("the origin is successful");
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value());
+	let index = ReferendumCount::<T>::get();
+	assert!(Referenda::<T>::submit(
+		origin,
+		Box::new(RawOrigin::Root.into()),
+		T::Hashing::hash_of(&index),
+		DispatchTime::After(Zero::zero()),
+	).is_ok());
+	index
+}
+
+/// Place the decision deposit of the referendum `index`.
+fn place_deposit<T: Config>(index: ReferendumIndex) {
+	let who = funded_account::<T>("depositor", index);
+	assert!(Referenda::<T>::place_decision_deposit(RawOrigin::Signed(who).into(), index).is_ok());
+}
+
+benchmarks! {
+	submit {
+		let origin = T::SubmitOrigin::successful_origin();
+		let who = T::SubmitOrigin::ensure_origin(origin.clone()).expect("the origin is successful");
+		T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value());
+		let call = Call::<T>::submit(
+			Box::new(RawOrigin::Root.into()),
+			T::Hashing::hash_of(&0),
+			DispatchTime::After(Zero::zero()),
+		);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(ReferendumInfoFor::<T>::contains_key(0));
+	}
+
+	place_decision_deposit {
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let index = create_referendum::<T>();
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		let status = Referenda::<T>::ensure_ongoing(index).expect("the referendum is ongoing");
+		assert!(status.decision_deposit.is_some());
+	}
+
+	refund_decision_deposit {
+		let index = create_referendum::<T>();
+		place_deposit::<T>(index);
+		assert!(Referenda::<T>::cancel(T::CancelOrigin::successful_origin(), index).is_ok());
+	}: _(RawOrigin::Signed(whitelisted_caller()), index)
+	verify {
+		let mut info = ReferendumInfoFor::<T>::get(index).expect("the referendum exists");
+		assert_eq!(info.take_decision_deposit(), Ok(None));
+	}
+
+	cancel {
+		let index = create_referendum::<T>();
+		place_deposit::<T>(index);
+		let origin = T::CancelOrigin::successful_origin();
+		let call = Call::<T>::cancel(index);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T>::get(index), Some(ReferendumInfo::Cancelled(..))));
+	}
+
+	kill {
+		let index = create_referendum::<T>();
+		place_deposit::<T>(index);
+		let origin = T::KillOrigin::successful_origin();
+		let call = Call::<T>::kill(index);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(matches!(ReferendumInfoFor::<T>::get(index), Some(ReferendumInfo::Killed(..))));
+	}
+
+	// The referendum starts being decided, which sets its alarm.
+	nudge_referendum {
+		let index = create_referendum::<T>();
+		place_deposit::<T>(index);
+		let track = Referenda::<T>::ensure_ongoing(index).expect("the referendum is ongoing").track;
+		let prepare_period = T::Tracks::info(track).expect("the track exists").prepare_period;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(prepare_period));
+	}: _(RawOrigin::Root, index)
+	verify {
+		let status = Referenda::<T>::ensure_ongoing(index).expect("the referendum is ongoing");
+		assert!(status.deciding.is_some() || status.in_queue);
+	}
+}
+
+impl_benchmark_test_suite!(
+	Referenda,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
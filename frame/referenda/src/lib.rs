@@ -0,0 +1,839 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Referenda Pallet
+//!
+//! A pallet deciding referenda on proposals, each dispatched with its own origin, along tracks
+//! with their own parameters.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! A referendum is submitted with [`Pallet::submit`], for a proposal given by the hash of its
+//! preimage and the origin with which it is dispatched once approved. The origin determines the
+//! track of the referendum, see [`TracksInfo`], and a [`Config::SubmissionDeposit`] is reserved.
+//!
+//! A referendum is decided once the decision deposit of its track is placed with
+//! [`Pallet::place_decision_deposit`], its prepare period elapsed, and fewer than `max_deciding`
+//! referenda of its track are being decided. The other referenda wait in the queue of their track,
+//! ordered by their aye votes. A referendum which is not decided within
+//! [`Config::UndecidingTimeout`] times out.
+//!
+//! While it is decided, a referendum passes if its approval and its support meet the thresholds
+//! of its track, which decrease along the decision period. A referendum which keeps passing for
+//! the confirm period of its track is approved, and its proposal scheduled for enactment. A
+//! referendum which does not pass by the end of its decision period is rejected.
+//!
+//! The votes are counted by another pallet, e.g. the conviction voting pallet, through the
+//! [`Polling`] implementation of this pallet. The referenda are serviced by alarms set with
+//! [`Config::Scheduler`], which dispatch [`Pallet::nudge_referendum`].
+//!
+//! The submission deposit is returned once the referendum is concluded, unless it timed out or was
+//! killed. The decision deposit can be refunded with [`Pallet::refund_decision_deposit`] once the
+//! referendum is concluded, unless it was killed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod types;
+pub mod weights;
+
+use codec::HasCompact;
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	storage::bounded_vec::BoundedVec,
+	traits::{
+		schedule::{Anon as ScheduleAnon, DispatchTime, MaybeHashed},
+		Currency, Get, OnUnbalanced, PollStatus, Polling, QueryPreimage, ReservableCurrency,
+		VoteTally,
+	},
+};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, One, Saturating, Zero},
+	PerThing, Perbill,
+};
+use sp_std::prelude::*;
+pub use pallet::*;
+pub use types::{
+	dispatch_time_at, Curve, DecidingStatus, Deposit, ReferendumIndex, ReferendumInfo,
+	ReferendumStatus, TrackInfo, TracksInfo,
+};
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub type CallOf<T> = <T as Config>::Call;
+pub type VotesOf<T> = <T as Config>::Votes;
+pub type TallyOf<T> = <T as Config>::Tally;
+pub type PalletsOriginOf<T> = <T as Config>::PalletsOrigin;
+pub type TrackIdOf<T> = <<T as Config>::Tracks as TracksInfo<
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>>::Id;
+pub type TrackInfoOf<T> = TrackInfo<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type CallOrHashOf<T> = MaybeHashed<CallOf<T>, <T as frame_system::Config>::Hash>;
+pub type ScheduleAddressOf<T> = <<T as Config>::Scheduler as ScheduleAnon<
+	<T as frame_system::Config>::BlockNumber,
+	CallOrHashOf<T>,
+	PalletsOriginOf<T>,
+>>::Address;
+pub type ReferendumInfoOf<T> = ReferendumInfo<
+	TrackIdOf<T>,
+	PalletsOriginOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+	BalanceOf<T>,
+	TallyOf<T>,
+	<T as frame_system::Config>::AccountId,
+	ScheduleAddressOf<T>,
+>;
+pub type ReferendumStatusOf<T> = ReferendumStatus<
+	TrackIdOf<T>,
+	PalletsOriginOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+	BalanceOf<T>,
+	TallyOf<T>,
+	<T as frame_system::Config>::AccountId,
+	ScheduleAddressOf<T>,
+>;
+pub type DepositOf<T> = Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Dispatchable;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + Sized {
+		/// The overarching call type, of the proposals and of the alarms.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin> + From<Call<Self>>;
+
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The scheduler of the alarms and of the enactment of the approved proposals.
+		type Scheduler: ScheduleAnon<Self::BlockNumber, CallOrHashOf<Self>, PalletsOriginOf<Self>>;
+
+		/// The currency of the deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The origins with which the proposals are dispatched.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>> + Parameter;
+
+		/// The origin which can submit referenda.
+		type SubmitOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+		/// The origin which can cancel an ongoing referendum, returning its deposits.
+		type CancelOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which can kill an ongoing referendum, slashing its deposits.
+		type KillOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The handler of the slashed deposits.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The type in which the votes are counted.
+		type Votes: AtLeast32BitUnsigned + Copy + Parameter + Member + HasCompact;
+
+		/// The tally of the votes of a referendum.
+		type Tally: VoteTally<Self::Votes, TrackIdOf<Self>> + Parameter + Member;
+
+		/// The deposit reserved when submitting a referendum.
+		#[pallet::constant]
+		type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximal number of referenda in the queue of a track.
+		#[pallet::constant]
+		type MaxQueued: Get<u32>;
+
+		/// The time after its submission after which a referendum which is not being decided
+		/// times out.
+		#[pallet::constant]
+		type UndecidingTimeout: Get<Self::BlockNumber>;
+
+		/// The alarms are rounded up to a multiple of this interval, so that they are batched.
+		#[pallet::constant]
+		type AlarmInterval: Get<Self::BlockNumber>;
+
+		/// The tracks of the referenda.
+		type Tracks: TracksInfo<BalanceOf<Self>, Self::BlockNumber, Origin = Self::PalletsOrigin>;
+
+		/// The provider of the preimages of the proposals.
+		type Preimages: QueryPreimage<Self::Hash>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		BalanceOf<T> = "Balance",
+		T::Hash = "Hash",
+		TrackIdOf<T> = "TrackId",
+		T::Tally = "Tally",
+	)]
+	pub enum Event<T: Config> {
+		/// A referendum was submitted. \[index, track, proposal_hash\]
+		Submitted(ReferendumIndex, TrackIdOf<T>, T::Hash),
+		/// The decision deposit of a referendum was placed. \[index, who, amount\]
+		DecisionDepositPlaced(ReferendumIndex, T::AccountId, BalanceOf<T>),
+		/// The decision deposit of a referendum was refunded. \[index, who, amount\]
+		DecisionDepositRefunded(ReferendumIndex, T::AccountId, BalanceOf<T>),
+		/// A deposit was slashed. \[who, amount\]
+		DepositSlashed(T::AccountId, BalanceOf<T>),
+		/// A referendum started being decided. \[index, track, proposal_hash, tally\]
+		DecisionStarted(ReferendumIndex, TrackIdOf<T>, T::Hash, T::Tally),
+		/// A referendum is passing, and started its confirmation period. \[index\]
+		ConfirmStarted(ReferendumIndex),
+		/// A referendum stopped passing during its confirmation period. \[index\]
+		ConfirmAborted(ReferendumIndex),
+		/// A referendum kept passing for its whole confirmation period. \[index, tally\]
+		Confirmed(ReferendumIndex, T::Tally),
+		/// A referendum was approved, and its proposal scheduled for enactment. \[index\]
+		Approved(ReferendumIndex),
+		/// A referendum was approved, but its proposal could not be scheduled for enactment.
+		/// \[index\]
+		EnactmentFailed(ReferendumIndex),
+		/// A referendum was rejected. \[index, tally\]
+		Rejected(ReferendumIndex, T::Tally),
+		/// A referendum was not decided in time. \[index, tally\]
+		TimedOut(ReferendumIndex, T::Tally),
+		/// A referendum was cancelled. \[index, tally\]
+		Cancelled(ReferendumIndex, T::Tally),
+		/// A referendum was killed. \[index, tally\]
+		Killed(ReferendumIndex, T::Tally),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The referendum is not ongoing.
+		NotOngoing,
+		/// The referendum has a decision deposit already.
+		HasDeposit,
+		/// There is no track for the origin of the proposal.
+		NoTrack,
+		/// The referendum does not exist.
+		BadReferendum,
+		/// The referendum is still ongoing.
+		Unfinished,
+		/// There is no decision deposit to refund.
+		NoDeposit,
+	}
+
+	/// The number of referenda submitted so far.
+	#[pallet::storage]
+	pub type ReferendumCount<T> = StorageValue<_, ReferendumIndex, ValueQuery>;
+
+	/// The information about the referenda.
+	#[pallet::storage]
+	pub type ReferendumInfoFor<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, ReferendumInfoOf<T>>;
+
+	/// The referenda of each track waiting to be decided, with their aye votes, sorted by
+	/// increasing aye votes.
+	#[pallet::storage]
+	pub type TrackQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat, TrackIdOf<T>,
+		BoundedVec<(ReferendumIndex, T::Votes), T::MaxQueued>,
+		ValueQuery,
+	>;
+
+	/// The number of referenda of each track being decided.
+	#[pallet::storage]
+	pub type DecidingCount<T: Config> = StorageMap<_, Twox64Concat, TrackIdOf<T>, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			let tracks = T::Tracks::tracks();
+			assert!(!tracks.is_empty(), "there must be at least one track");
+			for (i, (id, _)) in tracks.iter().enumerate() {
+				assert!(
+					tracks[..i].iter().all(|(other, _)| other != id),
+					"the identifiers of the tracks must be unique",
+				);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Submit a referendum on a proposal, reserving [`Config::SubmissionDeposit`].
+		///
+		/// The dispatch origin must be [`Config::SubmitOrigin`].
+		///
+		/// - `proposal_origin`: The origin with which the proposal is dispatched, which determines
+		///   the track of the referendum.
+		/// - `proposal_hash`: The hash of the preimage of the proposal.
+		/// - `enactment_moment`: When the proposal is enacted, once approved. It is never enacted
+		///   before the minimal enactment period of its track.
+		#[pallet::weight(T::WeightInfo::submit())]
+		pub fn submit(
+			origin: OriginFor<T>,
+			proposal_origin: Box<PalletsOriginOf<T>>,
+			proposal_hash: T::Hash,
+			enactment_moment: DispatchTime<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = T::SubmitOrigin::ensure_origin(origin)?;
+			let track = T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T>::NoTrack)?;
+			let submission_deposit = Self::take_deposit(who, T::SubmissionDeposit::get())?;
+
+			let index = ReferendumCount::<T>::mutate(|count| {
+				let index = *count;
+				*count = count.saturating_add(1);
+				index
+			});
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut status = ReferendumStatus {
+				track,
+				origin: *proposal_origin,
+				proposal_hash,
+				enactment: enactment_moment,
+				submitted: now,
+				submission_deposit,
+				decision_deposit: None,
+				deciding: None,
+				tally: T::Tally::new(track),
+				in_queue: false,
+				alarm: None,
+			};
+			let timeout = now.saturating_add(T::UndecidingTimeout::get());
+			Self::ensure_alarm_at(&mut status, index, timeout);
+			T::Preimages::request(&proposal_hash);
+			ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Ongoing(status));
+
+			Self::deposit_event(Event::<T>::Submitted(index, track, proposal_hash));
+			Ok(())
+		}
+
+		/// Place the decision deposit of a referendum, so that it can be decided.
+		///
+		/// The dispatch origin must be signed, and is the account from which the decision deposit
+		/// of the track of the referendum is reserved.
+		#[pallet::weight(T::WeightInfo::place_decision_deposit())]
+		pub fn place_decision_deposit(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut status = Self::ensure_ongoing(index)?;
+			ensure!(status.decision_deposit.is_none(), Error::<T>::HasDeposit);
+			let track = T::Tracks::info(status.track).ok_or(Error::<T>::NoTrack)?;
+			let deposit = Self::take_deposit(who.clone(), track.decision_deposit)?;
+			status.decision_deposit = Some(deposit);
+			Self::deposit_event(
+				Event::<T>::DecisionDepositPlaced(index, who, track.decision_deposit),
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let (info, _) = Self::service_referendum(now, index, status);
+			ReferendumInfoFor::<T>::insert(index, info);
+			Ok(())
+		}
+
+		/// Refund the decision deposit of a concluded referendum, unless it was killed.
+		///
+		/// The dispatch origin must be signed.
+		#[pallet::weight(T::WeightInfo::refund_decision_deposit())]
+		pub fn refund_decision_deposit(
+			origin: OriginFor<T>,
+			index: ReferendumIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut info = ReferendumInfoFor::<T>::get(index).ok_or(Error::<T>::BadReferendum)?;
+			let deposit = info.take_decision_deposit()
+				.map_err(|_| Error::<T>::Unfinished)?
+				.ok_or(Error::<T>::NoDeposit)?;
+			Self::refund_deposit(Some(deposit.clone()));
+			ReferendumInfoFor::<T>::insert(index, info);
+
+			Self::deposit_event(
+				Event::<T>::DecisionDepositRefunded(index, deposit.who, deposit.amount),
+			);
+			Ok(())
+		}
+
+		/// Cancel an ongoing referendum, returning its submission deposit. Its decision deposit
+		/// is left to refund.
+		///
+		/// The dispatch origin must be [`Config::CancelOrigin`].
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			T::CancelOrigin::ensure_origin(origin)?;
+			let status = Self::ensure_ongoing(index)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			Self::deposit_event(Event::<T>::Cancelled(index, status.tally.clone()));
+			Self::leave_ongoing(now, index, &status);
+			Self::refund_deposit(Some(status.submission_deposit));
+			ReferendumInfoFor::<T>::insert(
+				index,
+				ReferendumInfo::Cancelled(now, status.decision_deposit),
+			);
+			Ok(())
+		}
+
+		/// Kill an ongoing referendum, slashing its deposits.
+		///
+		/// The dispatch origin must be [`Config::KillOrigin`].
+		#[pallet::weight(T::WeightInfo::kill())]
+		pub fn kill(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			T::KillOrigin::ensure_origin(origin)?;
+			let status = Self::ensure_ongoing(index)?;
+			let now = frame_system::Pallet::<T>::block_number();
+
+			Self::deposit_event(Event::<T>::Killed(index, status.tally.clone()));
+			Self::leave_ongoing(now, index, &status);
+			Self::slash_deposit(Some(status.submission_deposit));
+			Self::slash_deposit(status.decision_deposit);
+			ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Killed(now));
+			Ok(())
+		}
+
+		/// Advance a referendum to its next state, e.g. start deciding it, or conclude it.
+		///
+		/// The dispatch origin must be root. This is dispatched by the alarms of the referenda.
+		#[pallet::weight(T::WeightInfo::nudge_referendum())]
+		pub fn nudge_referendum(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
+			ensure_root(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut status = Self::ensure_ongoing(index)?;
+			// the alarm which is due is the one dispatching this call.
+			let alarm_due = status.alarm.as_ref().map_or(false, |(when, _)| *when <= now);
+			if alarm_due {
+				status.alarm = None;
+			}
+
+			let (info, dirty) = Self::service_referendum(now, index, status);
+			if dirty || alarm_due {
+				ReferendumInfoFor::<T>::insert(index, info);
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Polling<T::Tally> for Pallet<T> {
+	type Index = ReferendumIndex;
+	type Votes = VotesOf<T>;
+	type Class = TrackIdOf<T>;
+	type Moment = T::BlockNumber;
+
+	fn classes() -> Vec<Self::Class> {
+		T::Tracks::tracks().iter().map(|(id, _)| *id).collect()
+	}
+
+	fn as_ongoing(index: Self::Index) -> Option<(T::Tally, Self::Class)> {
+		Self::ensure_ongoing(index).ok().map(|status| (status.tally, status.track))
+	}
+
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut T::Tally, T::BlockNumber, TrackIdOf<T>>) -> R,
+	) -> R {
+		match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(mut status)) => {
+				let result = f(PollStatus::Ongoing(&mut status.tally, status.track));
+				Self::note_tally_changed(index, status);
+				result
+			},
+			Some(ReferendumInfo::Approved(end, ..)) => f(PollStatus::Completed(end, true)),
+			Some(ReferendumInfo::Rejected(end, ..)) => f(PollStatus::Completed(end, false)),
+			_ => f(PollStatus::None),
+		}
+	}
+
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(
+			PollStatus<&mut T::Tally, T::BlockNumber, TrackIdOf<T>>,
+		) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(mut status)) => {
+				let result = f(PollStatus::Ongoing(&mut status.tally, status.track))?;
+				Self::note_tally_changed(index, status);
+				Ok(result)
+			},
+			Some(ReferendumInfo::Approved(end, ..)) => f(PollStatus::Completed(end, true)),
+			Some(ReferendumInfo::Rejected(end, ..)) => f(PollStatus::Completed(end, false)),
+			_ => f(PollStatus::None),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
+		let index = ReferendumCount::<T>::mutate(|count| {
+			let index = *count;
+			*count = count.saturating_add(1);
+			index
+		});
+		let now = frame_system::Pallet::<T>::block_number();
+		let who: T::AccountId = frame_benchmarking::whitelisted_caller();
+		let status = ReferendumStatus {
+			track: class,
+			origin: frame_system::RawOrigin::Root.into(),
+			proposal_hash: Default::default(),
+			enactment: DispatchTime::After(Zero::zero()),
+			submitted: now,
+			submission_deposit: Deposit { who, amount: Zero::zero() },
+			decision_deposit: None,
+			deciding: Some(DecidingStatus { since: now, confirming: None }),
+			tally: T::Tally::new(class),
+			in_queue: false,
+			alarm: None,
+		};
+		ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Ongoing(status));
+		Ok(index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()> {
+		let status = Self::ensure_ongoing(index).map_err(|_| ())?;
+		if let Some((_, alarm)) = status.alarm {
+			let _ = T::Scheduler::cancel(alarm);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let info = match approved {
+			true => ReferendumInfo::Approved(now, None),
+			false => ReferendumInfo::Rejected(now, None),
+		};
+		ReferendumInfoFor::<T>::insert(index, info);
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The status of the referendum `index`, if it is ongoing.
+	pub fn ensure_ongoing(index: ReferendumIndex) -> Result<ReferendumStatusOf<T>, DispatchError> {
+		match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => Ok(status),
+			_ => Err(Error::<T>::NotOngoing.into()),
+		}
+	}
+
+	/// Store the status of the ongoing referendum `index` after its tally changed, and nudge it in
+	/// the next block.
+	fn note_tally_changed(index: ReferendumIndex, mut status: ReferendumStatusOf<T>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::ensure_alarm_at(&mut status, index, now.saturating_add(One::one()));
+		ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Ongoing(status));
+	}
+
+	/// Advance the ongoing referendum `index`, whose status is `status`, at block `now`.
+	///
+	/// Returns the new information about the referendum, and whether it changed.
+	fn service_referendum(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		mut status: ReferendumStatusOf<T>,
+	) -> (ReferendumInfoOf<T>, bool) {
+		let track = match T::Tracks::info(status.track) {
+			Some(track) => track,
+			None => return (ReferendumInfo::Ongoing(status), false),
+		};
+		let mut dirty = false;
+		let timeout = status.submitted.saturating_add(T::UndecidingTimeout::get());
+		let mut alarm = timeout;
+
+		if status.deciding.is_none() && status.decision_deposit.is_some() {
+			let prepare_end = status.submitted.saturating_add(track.prepare_period);
+			if now < prepare_end {
+				alarm = alarm.min(prepare_end);
+			} else if status.in_queue {
+				// the tally may have changed, and with it the position in the queue.
+				Self::requeue(index, &status);
+			} else {
+				let deciding_count = DecidingCount::<T>::get(status.track);
+				if deciding_count < track.max_deciding {
+					DecidingCount::<T>::insert(status.track, deciding_count.saturating_add(1));
+					Self::begin_deciding(now, index, &mut status);
+				} else {
+					status.in_queue = Self::queue(index, &status);
+				}
+				dirty = true;
+			}
+		}
+
+		let (since, confirming) = match status.deciding {
+			Some(DecidingStatus { since, confirming }) => (since, confirming),
+			None => {
+				if now >= timeout {
+					Self::deposit_event(Event::<T>::TimedOut(index, status.tally.clone()));
+					Self::leave_ongoing(now, index, &status);
+					Self::slash_deposit(Some(status.submission_deposit));
+					return (ReferendumInfo::TimedOut(now, status.decision_deposit), true)
+				}
+				dirty |= Self::ensure_alarm_at(&mut status, index, alarm);
+				return (ReferendumInfo::Ongoing(status), dirty)
+			},
+		};
+
+		let approval = status.tally.approval(status.track);
+		let support = status.tally.support(status.track);
+		let elapsed =
+			Perbill::from_rational_approximation(now.saturating_sub(since), track.decision_period);
+		let is_passing = track.min_approval.passing(elapsed, approval) &&
+			track.min_support.passing(elapsed, support);
+		let confirming = match confirming {
+			Some(end) if is_passing && end <= now =>
+				return (Self::approve(now, index, status, track), true),
+			Some(_) if !is_passing => {
+				Self::deposit_event(Event::<T>::ConfirmAborted(index));
+				None
+			},
+			None if is_passing => {
+				Self::deposit_event(Event::<T>::ConfirmStarted(index));
+				Some(now.saturating_add(track.confirm_period))
+			},
+			unchanged => unchanged,
+		};
+		if status.deciding.as_ref().map_or(true, |deciding| deciding.confirming != confirming) {
+			status.deciding = Some(DecidingStatus { since, confirming });
+			dirty = true;
+		}
+
+		match confirming {
+			Some(end) => alarm = end,
+			None => {
+				let decision_end = since.saturating_add(track.decision_period);
+				if now >= decision_end {
+					Self::deposit_event(Event::<T>::Rejected(index, status.tally.clone()));
+					Self::leave_ongoing(now, index, &status);
+					Self::refund_deposit(Some(status.submission_deposit));
+					return (ReferendumInfo::Rejected(now, status.decision_deposit), true)
+				}
+				// the earliest block at which the current tally would pass.
+				let delay =
+					track.min_approval.delay(approval).max(track.min_support.delay(support));
+				alarm =
+					since.saturating_add(delay.mul_ceil(track.decision_period)).min(decision_end);
+			},
+		}
+		let alarm = alarm.max(now.saturating_add(One::one()));
+		dirty |= Self::ensure_alarm_at(&mut status, index, alarm);
+		(ReferendumInfo::Ongoing(status), dirty)
+	}
+
+	/// Start deciding the referendum `index` at block `now`.
+	fn begin_deciding(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		status: &mut ReferendumStatusOf<T>,
+	) {
+		status.in_queue = false;
+		status.deciding = Some(DecidingStatus { since: now, confirming: None });
+		Self::deposit_event(Event::<T>::DecisionStarted(
+			index,
+			status.track,
+			status.proposal_hash,
+			status.tally.clone(),
+		));
+	}
+
+	/// Approve the referendum `index`, and schedule the enactment of its proposal.
+	fn approve(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		status: ReferendumStatusOf<T>,
+		track: &TrackInfoOf<T>,
+	) -> ReferendumInfoOf<T> {
+		Self::deposit_event(Event::<T>::Confirmed(index, status.tally.clone()));
+
+		let when = dispatch_time_at(&status.enactment, now)
+			.max(now.saturating_add(track.min_enactment_period))
+			.max(now.saturating_add(One::one()));
+		// The proposal is scheduled by hash, the scheduler requests its preimage until it is
+		// dispatched.
+		let scheduled = T::Scheduler::schedule(
+			DispatchTime::At(when),
+			None,
+			63,
+			status.origin.clone(),
+			MaybeHashed::Hash(status.proposal_hash),
+		);
+		match scheduled {
+			Ok(_) => Self::deposit_event(Event::<T>::Approved(index)),
+			Err(_) => Self::deposit_event(Event::<T>::EnactmentFailed(index)),
+		}
+
+		Self::leave_ongoing(now, index, &status);
+		Self::refund_deposit(Some(status.submission_deposit));
+		ReferendumInfo::Approved(now, status.decision_deposit)
+	}
+
+	/// Clean up after the ongoing referendum `index`, which is concluded at block `now`.
+	///
+	/// Its alarm is cancelled, it leaves the queue of its track, and its place among the
+	/// referenda being decided is given to the best queued referendum of its track.
+	fn leave_ongoing(now: T::BlockNumber, index: ReferendumIndex, status: &ReferendumStatusOf<T>) {
+		if let Some((_, alarm)) = status.alarm.clone() {
+			let _ = T::Scheduler::cancel(alarm);
+		}
+		if status.in_queue {
+			TrackQueue::<T>::mutate(status.track, |queue| queue.retain(|(i, _)| *i != index));
+		}
+		if status.deciding.is_some() {
+			Self::note_one_fewer_deciding(now, status.track);
+		}
+		T::Preimages::unrequest(&status.proposal_hash);
+	}
+
+	/// Note that one fewer referendum of `track` is being decided, and start deciding the best
+	/// queued referendum of the track in its place, if any.
+	fn note_one_fewer_deciding(now: T::BlockNumber, track: TrackIdOf<T>) {
+		let next = TrackQueue::<T>::mutate(track, |queue| match queue.len() {
+			0 => None,
+			len => Some(queue.remove(len - 1)),
+		});
+		let next_status = next.and_then(|(index, _)| match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => Some((index, status)),
+			_ => None,
+		});
+		match next_status {
+			Some((index, mut status)) => {
+				Self::begin_deciding(now, index, &mut status);
+				let (info, _) = Self::service_referendum(now, index, status);
+				ReferendumInfoFor::<T>::insert(index, info);
+			},
+			None => DecidingCount::<T>::mutate(track, |count| *count = count.saturating_sub(1)),
+		}
+	}
+
+	/// Insert the referendum `index` in the queue of its track, according to its aye votes.
+	///
+	/// A full queue makes room by evicting its worst referendum, unless the referendum is worse.
+	/// Returns whether the referendum was queued.
+	fn queue(index: ReferendumIndex, status: &ReferendumStatusOf<T>) -> bool {
+		let ayes = status.tally.ayes(status.track);
+		let (queued, evicted) = TrackQueue::<T>::mutate(status.track, |queue| {
+			let mut pos =
+				queue.binary_search_by_key(&ayes, |(_, ayes)| *ayes).unwrap_or_else(|x| x);
+			let mut evicted = None;
+			if queue.len() >= T::MaxQueued::get() as usize {
+				if pos == 0 {
+					return (false, None)
+				}
+				evicted = Some(queue.remove(0).0);
+				pos -= 1;
+			}
+			(queue.try_insert(pos, (index, ayes)).is_ok(), evicted)
+		});
+		if let Some(evicted) = evicted {
+			ReferendumInfoFor::<T>::mutate(evicted, |info| {
+				if let Some(ReferendumInfo::Ongoing(status)) = info {
+					status.in_queue = false;
+				}
+			});
+		}
+		queued
+	}
+
+	/// Move the queued referendum `index` to its position for its current aye votes.
+	fn requeue(index: ReferendumIndex, status: &ReferendumStatusOf<T>) {
+		let ayes = status.tally.ayes(status.track);
+		TrackQueue::<T>::mutate(status.track, |queue| {
+			queue.retain(|(i, _)| *i != index);
+			let pos = queue.binary_search_by_key(&ayes, |(_, ayes)| *ayes).unwrap_or_else(|x| x);
+			let _queued = queue.try_insert(pos, (index, ayes));
+			debug_assert!(_queued.is_ok(), "the referendum was removed from the queue; qed");
+		});
+	}
+
+	/// Make sure that the next alarm of the referendum `index` is at block `when`, rounded up to
+	/// a multiple of [`Config::AlarmInterval`].
+	///
+	/// Returns whether the alarm changed.
+	fn ensure_alarm_at(
+		status: &mut ReferendumStatusOf<T>,
+		index: ReferendumIndex,
+		when: T::BlockNumber,
+	) -> bool {
+		let interval = T::AlarmInterval::get().max(One::one());
+		let remainder = when % interval;
+		let when = match remainder.is_zero() {
+			true => when,
+			false => when.saturating_add(interval - remainder),
+		};
+		if status.alarm.as_ref().map_or(false, |(at, _)| *at == when) {
+			return false
+		}
+
+		if let Some((_, alarm)) = status.alarm.take() {
+			let _ = T::Scheduler::cancel(alarm);
+		}
+		let call = MaybeHashed::Value(Call::<T>::nudge_referendum(index).into());
+		let result = T::Scheduler::schedule(
+			DispatchTime::At(when),
+			None,
+			128,
+			frame_system::RawOrigin::Root.into(),
+			call,
+		);
+		debug_assert!(result.is_ok(), "the alarm of a referendum can always be scheduled; qed");
+		status.alarm = result.ok().map(|address| (when, address));
+		true
+	}
+
+	/// Reserve `amount` from `who`.
+	fn take_deposit(
+		who: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<DepositOf<T>, DispatchError> {
+		T::Currency::reserve(&who, amount)?;
+		Ok(Deposit { who, amount })
+	}
+
+	/// Return `deposit`, if any.
+	fn refund_deposit(deposit: Option<DepositOf<T>>) {
+		if let Some(Deposit { who, amount }) = deposit {
+			let _remaining = T::Currency::unreserve(&who, amount);
+			debug_assert!(_remaining.is_zero());
+		}
+	}
+
+	/// Slash `deposit`, if any.
+	fn slash_deposit(deposit: Option<DepositOf<T>>) {
+		if let Some(Deposit { who, amount }) = deposit {
+			T::Slash::on_unbalanced(T::Currency::slash_reserved(&who, amount).0);
+			Self::deposit_event(Event::<T>::DepositSlashed(who, amount));
+		}
+	}
+}
@@ -0,0 +1,345 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+use crate as pallet_referenda;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_ok, parameter_types, ord_parameter_types,
+	traits::{GenesisBuild, OnInitialize},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	testing::Header,
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Config, Event<T>},
+		Referenda: pallet_referenda::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+parameter_types! {
+	pub const MaximumSchedulerWeight: Weight = Weight::max_value();
+}
+impl pallet_scheduler::Config for Test {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ();
+	type WeightInfo = ();
+	type Preimages = TestPreimages;
+	type NoPreimagePostponement = ();
+	type MaxPostponements = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+	pub const MaxLocks: u32 = 10;
+}
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
+	type MaxLocks = MaxLocks;
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+/// The tally of the referenda of the tests, whose support is counted out of 100 votes.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct Tally {
+	pub ayes: u32,
+	pub nays: u32,
+}
+
+impl VoteTally<u32, u8> for Tally {
+	fn new(_: u8) -> Self {
+		Self::default()
+	}
+
+	fn ayes(&self, _: u8) -> u32 {
+		self.ayes
+	}
+
+	fn support(&self, _: u8) -> Perbill {
+		Perbill::from_percent(self.ayes)
+	}
+
+	fn approval(&self, _: u8) -> Perbill {
+		Perbill::from_rational_approximation(self.ayes, self.ayes + self.nays)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(_: u8) -> Self {
+		Self { ayes: 100, nays: 0 }
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(_: u8) -> Self {
+		Self { ayes: 0, nays: 100 }
+	}
+}
+
+/// Two tracks: `0` for the root origin, deciding one referendum at a time, and `1` for the none
+/// origin, deciding two referenda at a time.
+pub struct TestTracksInfo;
+impl TracksInfo<u64, u64> for TestTracksInfo {
+	type Id = u8;
+	type Origin = OriginCaller;
+
+	fn tracks() -> &'static [(u8, TrackInfo<u64, u64>)] {
+		static DATA: [(u8, TrackInfo<u64, u64>); 2] = [
+			(
+				0u8,
+				TrackInfo {
+					name: "root",
+					max_deciding: 1,
+					decision_deposit: 10,
+					prepare_period: 4,
+					decision_period: 4,
+					confirm_period: 2,
+					min_enactment_period: 4,
+					min_approval: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(50),
+						ceil: Perbill::from_percent(100),
+					},
+					min_support: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(0),
+						ceil: Perbill::from_percent(100),
+					},
+				},
+			),
+			(
+				1u8,
+				TrackInfo {
+					name: "none",
+					max_deciding: 2,
+					decision_deposit: 1,
+					prepare_period: 2,
+					decision_period: 2,
+					confirm_period: 1,
+					min_enactment_period: 2,
+					min_approval: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(95),
+						ceil: Perbill::from_percent(100),
+					},
+					min_support: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(90),
+						ceil: Perbill::from_percent(100),
+					},
+				},
+			),
+		];
+		&DATA[..]
+	}
+
+	fn track_for(origin: &OriginCaller) -> Result<u8, ()> {
+		match origin {
+			OriginCaller::system(frame_system::RawOrigin::Root) => Ok(0),
+			OriginCaller::system(frame_system::RawOrigin::None) => Ok(1),
+			_ => Err(()),
+		}
+	}
+}
+
+thread_local! {
+	/// The preimages known to the tests.
+	pub static PREIMAGES: RefCell<BTreeMap<H256, (Vec<u8>, u32)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Keeps the preimages noted by the tests, with the number of requests of each of them.
+pub struct TestPreimages;
+impl QueryPreimage<H256> for TestPreimages {
+	fn len(hash: &H256) -> Option<u32> {
+		Self::fetch(hash).map(|data| data.len() as u32)
+	}
+
+	fn fetch(hash: &H256) -> Option<Vec<u8>> {
+		PREIMAGES.with(|p| p.borrow().get(hash).map(|(data, _)| data.clone()))
+			.filter(|data| !data.is_empty())
+	}
+
+	fn is_requested(hash: &H256) -> bool {
+		PREIMAGES.with(|p| p.borrow().get(hash).map_or(false, |(_, count)| *count > 0))
+	}
+
+	fn request(hash: &H256) {
+		PREIMAGES.with(|p| p.borrow_mut().entry(*hash).or_default().1 += 1);
+	}
+
+	fn unrequest(hash: &H256) {
+		PREIMAGES.with(|p| {
+			if let Some((_, count)) = p.borrow_mut().get_mut(hash) {
+				*count = count.saturating_sub(1);
+			}
+		});
+	}
+}
+
+parameter_types! {
+	pub const SubmissionDeposit: u64 = 2;
+	pub const MaxQueued: u32 = 3;
+	pub const UndecidingTimeout: u64 = 20;
+	pub static AlarmInterval: u64 = 1;
+}
+ord_parameter_types! {
+	pub const Four: u64 = 4;
+}
+impl pallet_referenda::Config for Test {
+	type Call = Call;
+	type Event = Event;
+	type WeightInfo = ();
+	type Scheduler = Scheduler;
+	type Currency = pallet_balances::Pallet<Self>;
+	type PalletsOrigin = OriginCaller;
+	type SubmitOrigin = EnsureSigned<u64>;
+	type CancelOrigin = EnsureSignedBy<Four, u64>;
+	type KillOrigin = EnsureRoot<u64>;
+	type Slash = ();
+	type Votes = u32;
+	type Tally = Tally;
+	type SubmissionDeposit = SubmissionDeposit;
+	type MaxQueued = MaxQueued;
+	type UndecidingTimeout = UndecidingTimeout;
+	type AlarmInterval = AlarmInterval;
+	type Tracks = TestTracksInfo;
+	type Preimages = TestPreimages;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let balances = vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)];
+	pallet_balances::GenesisConfig::<Test> { balances }.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// The proposal setting the free balance of account 42 to `value`, whose preimage is noted.
+pub fn set_balance_proposal_hash(value: u64) -> H256 {
+	let call = Call::Balances(pallet_balances::Call::set_balance(42, value, 0));
+	let data = call.encode();
+	let hash = BlakeTwo256::hash(&data[..]);
+	PREIMAGES.with(|p| p.borrow_mut().entry(hash).or_default().0 = data);
+	hash
+}
+
+/// Run the blocks up to `n`, dispatching the scheduled calls.
+pub fn run_to(n: u64) {
+	while System::block_number() < n {
+		let now = System::block_number() + 1;
+		System::set_block_number(now);
+		Scheduler::on_initialize(now);
+	}
+}
+
+/// Submit a referendum from account 1 on the proposal `value`, dispatched with the origin
+/// `origin`, and place its decision deposit from account 2.
+pub fn submit_and_place_deposit(
+	origin: frame_system::RawOrigin<u64>,
+	value: u64,
+) -> ReferendumIndex {
+	let index = ReferendumCount::<Test>::get();
+	assert_ok!(Referenda::submit(
+		Origin::signed(1),
+		Box::new(origin.into()),
+		set_balance_proposal_hash(value),
+		DispatchTime::After(0),
+	));
+	assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), index));
+	index
+}
+
+/// Set the tally of the ongoing referendum `index`.
+pub fn set_tally(index: ReferendumIndex, ayes: u32, nays: u32) {
+	Referenda::access_poll(index, |status| match status {
+		PollStatus::Ongoing(tally, _) => *tally = Tally { ayes, nays },
+		_ => panic!("the referendum is ongoing"),
+	});
+}
+
+/// Whether the referendum `index` is being decided, and whether it is confirming.
+pub fn deciding_and_confirming(index: ReferendumIndex) -> (bool, bool) {
+	match ReferendumInfoFor::<Test>::get(index) {
+		Some(ReferendumInfo::Ongoing(status)) =>
+			(status.deciding.is_some(), status.deciding.map_or(false, |d| d.confirming.is_some())),
+		_ => (false, false),
+	}
+}
+
+/// The events of the pallet since the last call.
+pub fn referenda_events_since_last_call() -> Vec<pallet_referenda::Event<Test>> {
+	let events = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| if let Event::Referenda(inner) = e { Some(inner) } else { None })
+		.collect::<Vec<_>>();
+	System::reset_events();
+	events
+}
@@ -0,0 +1,378 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the referenda pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn params_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ReferendumCount::<Test>::get(), 0);
+		assert_eq!(Referenda::classes(), vec![0, 1]);
+		assert_eq!(TestTracksInfo::info(1).map(|track| track.name), Some("none"));
+	});
+}
+
+#[test]
+fn basic_happy_path_works() {
+	new_test_ext().execute_with(|| {
+		let hash = set_balance_proposal_hash(100);
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		assert!(TestPreimages::is_requested(&hash));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		set_tally(index, 100, 0);
+
+		// the referendum is prepared until block 5.
+		run_to(4);
+		assert_eq!(deciding_and_confirming(index), (false, false));
+		run_to(5);
+		assert_eq!(deciding_and_confirming(index), (true, true));
+		run_to(6);
+		assert_eq!(deciding_and_confirming(index), (true, true));
+
+		// it is approved once confirmed, and enacted after the minimal enactment period.
+		run_to(7);
+		assert_eq!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Approved(7, Some(Deposit { who: 2, amount: 10 }))),
+		);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		assert!(!TestPreimages::is_requested(&hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		run_to(10);
+		assert_eq!(Balances::free_balance(42), 0);
+		run_to(11);
+		assert_eq!(Balances::free_balance(42), 100);
+
+		assert_eq!(
+			referenda_events_since_last_call(),
+			vec![
+				crate::Event::Submitted(index, 0, hash),
+				crate::Event::DecisionDepositPlaced(index, 2, 10),
+				crate::Event::DecisionStarted(index, 0, hash, Tally { ayes: 100, nays: 0 }),
+				crate::Event::ConfirmStarted(index),
+				crate::Event::Confirmed(index, Tally { ayes: 100, nays: 0 }),
+				crate::Event::Approved(index),
+			],
+		);
+		Referenda::access_poll(index, |status| {
+			assert_eq!(status.ensure_ongoing(), None);
+		});
+		assert_eq!(Referenda::as_ongoing(index), None);
+
+		// the decision deposit is refunded once.
+		assert_ok!(Referenda::refund_decision_deposit(Origin::signed(3), index));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			Referenda::refund_decision_deposit(Origin::signed(3), index),
+			Error::<Test>::NoDeposit,
+		);
+	});
+}
+
+#[test]
+fn failing_referendum_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		set_tally(index, 0, 10);
+		run_to(8);
+		assert_eq!(deciding_and_confirming(index), (true, false));
+		run_to(9);
+		assert_eq!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Rejected(9, Some(Deposit { who: 2, amount: 10 }))),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(
+			referenda_events_since_last_call().last(),
+			Some(&crate::Event::Rejected(index, Tally { ayes: 0, nays: 10 })),
+		);
+	});
+}
+
+#[test]
+fn confirmation_is_aborted_when_no_longer_passing() {
+	new_test_ext().execute_with(|| {
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		set_tally(index, 100, 0);
+		run_to(6);
+		assert_eq!(deciding_and_confirming(index), (true, true));
+
+		set_tally(index, 0, 100);
+		run_to(7);
+		assert_eq!(deciding_and_confirming(index), (true, false));
+		assert!(referenda_events_since_last_call().contains(&crate::Event::ConfirmAborted(index)));
+
+		run_to(9);
+		assert!(matches!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Rejected(9, _)),
+		));
+	});
+}
+
+#[test]
+fn tally_passing_later_in_the_decision_period_works() {
+	new_test_ext().execute_with(|| {
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		// the approval threshold reaches 75% halfway through the decision period.
+		set_tally(index, 75, 25);
+		run_to(6);
+		assert_eq!(deciding_and_confirming(index), (true, false));
+		run_to(7);
+		assert_eq!(deciding_and_confirming(index), (true, true));
+		run_to(9);
+		assert!(matches!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Approved(9, _)),
+		));
+	});
+}
+
+#[test]
+fn queueing_works() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			submit_and_place_deposit(RawOrigin::Root, 100);
+		}
+		run_to(5);
+		assert_eq!(deciding_and_confirming(0), (true, false));
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert_eq!(TrackQueue::<Test>::get(0).into_inner(), vec![(2, 0), (1, 0)]);
+
+		// the queue follows the aye votes.
+		set_tally(1, 5, 95);
+		set_tally(2, 10, 90);
+		run_to(6);
+		assert_eq!(TrackQueue::<Test>::get(0).into_inner(), vec![(1, 5), (2, 10)]);
+
+		// the best queued referendum is decided in place of a concluded one.
+		assert_ok!(Referenda::kill(Origin::root(), 0));
+		assert_eq!(deciding_and_confirming(2), (true, false));
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert_eq!(TrackQueue::<Test>::get(0).into_inner(), vec![(1, 5)]);
+
+		// once the queue is empty, fewer referenda are decided.
+		assert_ok!(Referenda::cancel(Origin::signed(4), 2));
+		assert_eq!(deciding_and_confirming(1), (true, false));
+		assert_ok!(Referenda::cancel(Origin::signed(4), 1));
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		assert!(TrackQueue::<Test>::get(0).is_empty());
+	});
+}
+
+#[test]
+fn full_queue_evicts_the_referendum_with_the_fewest_ayes() {
+	new_test_ext().execute_with(|| {
+		for ayes in 0..5 {
+			let index = submit_and_place_deposit(RawOrigin::Root, 100);
+			set_tally(index, ayes, 100);
+		}
+		run_to(5);
+		assert_eq!(TrackQueue::<Test>::get(0).into_inner(), vec![(2, 2), (3, 3), (4, 4)]);
+		match ReferendumInfoFor::<Test>::get(1) {
+			Some(ReferendumInfo::Ongoing(status)) => assert!(!status.in_queue),
+			_ => panic!("the referendum is ongoing"),
+		}
+
+		// the evicted referendum times out.
+		run_to(21);
+		assert!(matches!(ReferendumInfoFor::<Test>::get(1), Some(ReferendumInfo::TimedOut(21, _))));
+	});
+}
+
+#[test]
+fn tracks_are_independent() {
+	new_test_ext().execute_with(|| {
+		let root = submit_and_place_deposit(RawOrigin::Root, 100);
+		let none =
+			(0..2).map(|_| submit_and_place_deposit(RawOrigin::None, 100)).collect::<Vec<_>>();
+		run_to(3);
+		assert_eq!(deciding_and_confirming(root), (false, false));
+		for index in none {
+			assert_eq!(deciding_and_confirming(index), (true, false));
+		}
+		assert_eq!(DecidingCount::<Test>::get(1), 2);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn referendum_without_decision_deposit_times_out() {
+	new_test_ext().execute_with(|| {
+		let hash = set_balance_proposal_hash(100);
+		assert_ok!(Referenda::submit(
+			Origin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			hash,
+			DispatchTime::At(10),
+		));
+		run_to(20);
+		assert!(matches!(ReferendumInfoFor::<Test>::get(0), Some(ReferendumInfo::Ongoing(_))));
+		run_to(21);
+		assert_eq!(ReferendumInfoFor::<Test>::get(0), Some(ReferendumInfo::TimedOut(21, None)));
+		assert_eq!(Balances::total_balance(&1), 98);
+		assert!(!TestPreimages::is_requested(&hash));
+		let events = referenda_events_since_last_call();
+		assert!(events.contains(&crate::Event::DepositSlashed(1, 2)));
+		assert_noop!(
+			Referenda::refund_decision_deposit(Origin::signed(1), 0),
+			Error::<Test>::NoDeposit,
+		);
+	});
+}
+
+#[test]
+fn cancel_works() {
+	new_test_ext().execute_with(|| {
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		run_to(5);
+		assert_noop!(Referenda::cancel(Origin::signed(1), index), BadOrigin);
+		assert_noop!(
+			Referenda::refund_decision_deposit(Origin::signed(2), index),
+			Error::<Test>::Unfinished,
+		);
+
+		assert_ok!(Referenda::cancel(Origin::signed(4), index));
+		assert_eq!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Cancelled(5, Some(Deposit { who: 2, amount: 10 }))),
+		);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(Referenda::refund_decision_deposit(Origin::signed(2), index));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(Referenda::cancel(Origin::signed(4), index), Error::<Test>::NotOngoing);
+
+		// the cancelled referendum is not nudged anymore.
+		run_to(25);
+		assert!(matches!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Cancelled(..)),
+		));
+	});
+}
+
+#[test]
+fn kill_works() {
+	new_test_ext().execute_with(|| {
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		assert_noop!(Referenda::kill(Origin::signed(4), index), BadOrigin);
+		assert_ok!(Referenda::kill(Origin::root(), index));
+		assert_eq!(ReferendumInfoFor::<Test>::get(index), Some(ReferendumInfo::Killed(1)));
+		assert_eq!(Balances::total_balance(&1), 98);
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_noop!(
+			Referenda::refund_decision_deposit(Origin::signed(2), index),
+			Error::<Test>::NoDeposit,
+		);
+	});
+}
+
+#[test]
+fn submit_and_deposit_errors_work() {
+	new_test_ext().execute_with(|| {
+		let hash = set_balance_proposal_hash(100);
+		assert_noop!(
+			Referenda::submit(
+				Origin::signed(1),
+				Box::new(RawOrigin::Signed(1).into()),
+				hash,
+				DispatchTime::After(0),
+			),
+			Error::<Test>::NoTrack,
+		);
+		assert_noop!(
+			Referenda::submit(
+				Origin::root(),
+				Box::new(RawOrigin::Root.into()),
+				hash,
+				DispatchTime::After(0),
+			),
+			BadOrigin,
+		);
+		assert_noop!(
+			Referenda::submit(
+				Origin::signed(7),
+				Box::new(RawOrigin::Root.into()),
+				hash,
+				DispatchTime::After(0),
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance,
+		);
+
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		assert_noop!(
+			Referenda::place_decision_deposit(Origin::signed(3), index),
+			Error::<Test>::HasDeposit,
+		);
+		assert_noop!(
+			Referenda::place_decision_deposit(Origin::signed(3), index + 1),
+			Error::<Test>::NotOngoing,
+		);
+		assert_noop!(
+			Referenda::refund_decision_deposit(Origin::signed(3), index + 1),
+			Error::<Test>::BadReferendum,
+		);
+		assert_noop!(Referenda::nudge_referendum(Origin::signed(1), index), BadOrigin);
+	});
+}
+
+#[test]
+fn proposals_are_scheduled_by_hash() {
+	new_test_ext().execute_with(|| {
+		let hash = H256::repeat_byte(1);
+		assert_ok!(Referenda::submit(
+			Origin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			hash,
+			DispatchTime::After(0),
+		));
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), 0));
+		set_tally(0, 100, 0);
+		run_to(7);
+		assert!(matches!(ReferendumInfoFor::<Test>::get(0), Some(ReferendumInfo::Approved(7, _))));
+		// The preimage is not known yet, but the proposal is scheduled by hash and its preimage
+		// stays requested until it is dispatched.
+		assert!(referenda_events_since_last_call().contains(&crate::Event::Approved(0)));
+		assert!(TestPreimages::is_requested(&hash));
+	});
+}
+
+#[test]
+fn alarms_are_rounded_up_to_the_alarm_interval() {
+	new_test_ext().execute_with(|| {
+		AlarmInterval::set(3);
+		let index = submit_and_place_deposit(RawOrigin::Root, 100);
+		let alarm = match ReferendumInfoFor::<Test>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => status.alarm.map(|(when, _)| when),
+			_ => None,
+		};
+		assert_eq!(alarm, Some(6));
+		run_to(5);
+		assert_eq!(deciding_and_confirming(index), (false, false));
+		run_to(6);
+		assert_eq!(deciding_and_confirming(index), (true, false));
+	});
+}
@@ -0,0 +1,319 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Miscellaneous additional datatypes.
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::Parameter, traits::schedule::DispatchTime};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Member},
+	PerThing, Perbill, RuntimeDebug,
+};
+use sp_std::fmt::Debug;
+
+/// The index of a referendum.
+pub type ReferendumIndex = u32;
+
+/// A deposit, with the account from which it was reserved.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Deposit<AccountId, Balance> {
+	/// The account from which the deposit was reserved.
+	pub who: AccountId,
+	/// The amount of the deposit.
+	pub amount: Balance,
+}
+
+/// The decision status of a referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct DecidingStatus<BlockNumber> {
+	/// The block at which the decision period started.
+	pub since: BlockNumber,
+	/// The end of the confirmation period, if the referendum is passing and being confirmed.
+	pub confirming: Option<BlockNumber>,
+}
+
+/// The threshold of approval or support which a referendum must meet to pass, as a function of
+/// the fraction of its decision period which elapsed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Curve {
+	/// Decreasing linearly from `ceil` at the beginning of the decision period down to `floor`
+	/// once `length` of the decision period elapsed, and staying at `floor` afterwards.
+	LinearDecreasing { length: Perbill, floor: Perbill, ceil: Perbill },
+	/// Starting at `begin`, and decreasing by `step` every `period` of the decision period, never
+	/// below `end`.
+	SteppedDecreasing { begin: Perbill, end: Perbill, step: Perbill, period: Perbill },
+}
+
+/// Integer division of `a` by `b`, rounded up.
+fn div_ceil(a: u64, b: u64) -> u64 {
+	(a + b - 1) / b
+}
+
+impl Curve {
+	/// The threshold once `x` of the decision period elapsed.
+	pub fn threshold(&self, x: Perbill) -> Perbill {
+		match *self {
+			Self::LinearDecreasing { length, floor, ceil } => {
+				let (x, length) = (x.deconstruct() as u64, length.deconstruct() as u64);
+				let (floor, ceil) = (floor.deconstruct() as u64, ceil.deconstruct() as u64);
+				if x >= length || ceil <= floor {
+					return Perbill::from_parts(floor.min(ceil) as u32)
+				}
+				Perbill::from_parts((ceil - (ceil - floor) * x / length) as u32)
+			},
+			Self::SteppedDecreasing { begin, end, step, period } => {
+				let steps = match period.deconstruct() {
+					0 => 0,
+					period => x.deconstruct() / period,
+				};
+				let decrease = (step.deconstruct() as u64).saturating_mul(steps as u64);
+				let threshold = (begin.deconstruct() as u64).saturating_sub(decrease);
+				Perbill::from_parts(threshold.max(end.deconstruct() as u64) as u32)
+			},
+		}
+	}
+
+	/// The fraction of the decision period after which `y` meets the threshold.
+	///
+	/// The whole decision period if `y` never meets the threshold.
+	pub fn delay(&self, y: Perbill) -> Perbill {
+		match *self {
+			Self::LinearDecreasing { length, floor, ceil } => {
+				if y >= ceil {
+					return Perbill::zero()
+				}
+				if y < floor || ceil <= floor {
+					return Perbill::one()
+				}
+				let (y, length) = (y.deconstruct() as u64, length.deconstruct() as u64);
+				let (floor, ceil) = (floor.deconstruct() as u64, ceil.deconstruct() as u64);
+				let delay = div_ceil((ceil - y) * length, ceil - floor);
+				Perbill::from_parts(delay.min(Perbill::ACCURACY as u64) as u32)
+			},
+			Self::SteppedDecreasing { begin, end, step, period } => {
+				if y >= begin {
+					return Perbill::zero()
+				}
+				if y < end || step.is_zero() {
+					return Perbill::one()
+				}
+				let (y, begin) = (y.deconstruct() as u64, begin.deconstruct() as u64);
+				let steps = div_ceil(begin - y, step.deconstruct() as u64);
+				let delay = steps.saturating_mul(period.deconstruct() as u64);
+				Perbill::from_parts(delay.min(Perbill::ACCURACY as u64) as u32)
+			},
+		}
+	}
+
+	/// Whether `y` meets the threshold once `x` of the decision period elapsed.
+	pub fn passing(&self, x: Perbill, y: Perbill) -> bool {
+		y >= self.threshold(x)
+	}
+}
+
+/// The parameters of a track, i.e. of the referenda submitted by some class of origins.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TrackInfo<Balance, Moment> {
+	/// The name of the track.
+	pub name: &'static str,
+	/// The maximal number of referenda of the track which are decided at the same time.
+	pub max_deciding: u32,
+	/// The deposit which must be placed for a referendum of the track to be decided.
+	pub decision_deposit: Balance,
+	/// The minimal time between the submission of a referendum and the start of its decision.
+	pub prepare_period: Moment,
+	/// The time during which a referendum is decided, unless it is confirmed before.
+	pub decision_period: Moment,
+	/// The time during which a referendum must keep passing to be approved.
+	pub confirm_period: Moment,
+	/// The minimal time between the approval of a referendum and the enactment of its proposal.
+	pub min_enactment_period: Moment,
+	/// The minimal approval of a referendum to pass, i.e. the fraction of aye votes.
+	pub min_approval: Curve,
+	/// The minimal support of a referendum to pass, i.e. the fraction of the possible turnout
+	/// which supports it.
+	pub min_support: Curve,
+}
+
+/// The tracks of the referenda.
+pub trait TracksInfo<Balance, Moment> {
+	/// The identifier of a track.
+	type Id: Parameter + Member + Ord + PartialOrd + Copy + 'static;
+
+	/// The origins of the proposals, from which their track is derived.
+	type Origin;
+
+	/// All the tracks, with their identifiers.
+	fn tracks() -> &'static [(Self::Id, TrackInfo<Balance, Moment>)];
+
+	/// The track of the proposals which are dispatched with `origin`.
+	fn track_for(origin: &Self::Origin) -> Result<Self::Id, ()>;
+
+	/// The parameters of the track `id`.
+	fn info(id: Self::Id) -> Option<&'static TrackInfo<Balance, Moment>> {
+		Self::tracks().iter().find(|x| x.0 == id).map(|x| &x.1)
+	}
+}
+
+/// The status of an ongoing referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ReferendumStatus<
+	TrackId,
+	Origin,
+	Moment,
+	Hash,
+	Balance,
+	Tally,
+	AccountId,
+	ScheduleAddress,
+> {
+	/// The track of the referendum.
+	pub track: TrackId,
+	/// The origin with which the proposal is dispatched, once approved.
+	pub origin: Origin,
+	/// The hash of the preimage of the proposal.
+	pub proposal_hash: Hash,
+	/// When the proposal is enacted, once approved.
+	pub enactment: DispatchTime<Moment>,
+	/// The block at which the referendum was submitted.
+	pub submitted: Moment,
+	/// The deposit reserved at the submission.
+	pub submission_deposit: Deposit<AccountId, Balance>,
+	/// The deposit placed for the referendum to be decided, if any.
+	pub decision_deposit: Option<Deposit<AccountId, Balance>>,
+	/// The decision status, if the referendum is being decided.
+	pub deciding: Option<DecidingStatus<Moment>>,
+	/// The tally of the votes.
+	pub tally: Tally,
+	/// Whether the referendum is in the queue of its track, waiting to be decided.
+	pub in_queue: bool,
+	/// The next scheduled nudge of the referendum, if any.
+	pub alarm: Option<(Moment, ScheduleAddress)>,
+}
+
+/// The information about a referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ReferendumInfo<
+	TrackId,
+	Origin,
+	Moment,
+	Hash,
+	Balance,
+	Tally,
+	AccountId,
+	ScheduleAddress,
+> {
+	/// The referendum is ongoing.
+	Ongoing(ReferendumStatus<
+		TrackId,
+		Origin,
+		Moment,
+		Hash,
+		Balance,
+		Tally,
+		AccountId,
+		ScheduleAddress,
+	>),
+	/// The referendum was approved at the given block. The decision deposit is left to refund.
+	Approved(Moment, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was rejected at the given block. The decision deposit is left to refund.
+	Rejected(Moment, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was cancelled at the given block. The decision deposit is left to refund.
+	Cancelled(Moment, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was not decided in time at the given block. The decision deposit is left to
+	/// refund.
+	TimedOut(Moment, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was killed at the given block, and its deposits slashed.
+	Killed(Moment),
+}
+
+impl<TrackId, Origin, Moment, Hash, Balance, Tally, AccountId, ScheduleAddress>
+	ReferendumInfo<TrackId, Origin, Moment, Hash, Balance, Tally, AccountId, ScheduleAddress>
+{
+	/// Take the decision deposit which is left to refund, if any.
+	///
+	/// Fails if the referendum is still ongoing.
+	pub fn take_decision_deposit(&mut self) -> Result<Option<Deposit<AccountId, Balance>>, ()> {
+		match self {
+			Self::Ongoing(_) => Err(()),
+			Self::Approved(_, deposit) |
+			Self::Rejected(_, deposit) |
+			Self::Cancelled(_, deposit) |
+			Self::TimedOut(_, deposit) => Ok(deposit.take()),
+			Self::Killed(_) => Ok(None),
+		}
+	}
+}
+
+/// The block number at which `time` is due, for a time given relative to `since`.
+pub fn dispatch_time_at<Moment: AtLeast32BitUnsigned + Copy + Debug>(
+	time: &DispatchTime<Moment>,
+	since: Moment,
+) -> Moment {
+	match *time {
+		DispatchTime::At(at) => at,
+		DispatchTime::After(after) => since.saturating_add(after),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn percent(x: u32) -> Perbill {
+		Perbill::from_percent(x)
+	}
+
+	#[test]
+	fn linear_curve_works() {
+		let curve =
+			Curve::LinearDecreasing { length: percent(50), floor: percent(10), ceil: percent(90) };
+		assert_eq!(curve.threshold(percent(0)), percent(90));
+		assert_eq!(curve.threshold(percent(25)), percent(50));
+		assert_eq!(curve.threshold(percent(50)), percent(10));
+		assert_eq!(curve.threshold(percent(100)), percent(10));
+
+		assert_eq!(curve.delay(percent(95)), percent(0));
+		assert_eq!(curve.delay(percent(50)), percent(25));
+		assert_eq!(curve.delay(percent(10)), percent(50));
+		assert_eq!(curve.delay(percent(5)), percent(100));
+		for y in 10..=90 {
+			assert!(curve.passing(curve.delay(percent(y)), percent(y)));
+		}
+	}
+
+	#[test]
+	fn stepped_curve_works() {
+		let curve = Curve::SteppedDecreasing {
+			begin: percent(80),
+			end: percent(30),
+			step: percent(10),
+			period: percent(10),
+		};
+		assert_eq!(curve.threshold(percent(0)), percent(80));
+		assert_eq!(curve.threshold(percent(15)), percent(70));
+		assert_eq!(curve.threshold(percent(100)), percent(30));
+
+		assert_eq!(curve.delay(percent(80)), percent(0));
+		assert_eq!(curve.delay(percent(75)), percent(10));
+		assert_eq!(curve.delay(percent(30)), percent(50));
+		assert_eq!(curve.delay(percent(20)), percent(100));
+		for y in 30..=80 {
+			assert!(curve.passing(curve.delay(percent(y)), percent(y)));
+		}
+	}
+}
@@ -0,0 +1,143 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Safe mode pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Hooks, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+
+use crate::Pallet as SafeMode;
+
+/// A funded account which can place a few deposits of `amount`.
+fn funded_caller<T: Config>(amount: BalanceOf<T>) -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let balance = T::Currency::minimum_balance().saturating_add(amount.saturating_mul(10u32.into()));
+	T::Currency::make_free_balance_be(&caller, balance);
+	caller
+}
+
+/// Enter the safe mode until the next block.
+fn enter_until_next_block<T: Config>() -> T::BlockNumber {
+	let until = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+	EnteredUntil::<T>::put(until);
+	until
+}
+
+/// Place a deposit from a funded caller, and advance the block number past the release delay.
+fn deposit_to_release<T: Config>() -> Result<(T::AccountId, T::BlockNumber), &'static str> {
+	let delay = T::ReleaseDelay::get().ok_or("the release delay is not configured")?;
+	let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+	let caller = funded_caller::<T>(amount);
+	let block = frame_system::Pallet::<T>::block_number();
+	SafeMode::<T>::place_deposit(caller.clone(), amount)?;
+	frame_system::Pallet::<T>::set_block_number(block.saturating_add(delay));
+	Ok((caller, block))
+}
+
+benchmarks! {
+	on_initialize_noop {
+		let now = frame_system::Pallet::<T>::block_number();
+	}: { SafeMode::<T>::on_initialize(now); }
+
+	on_initialize_exit {
+		let now = frame_system::Pallet::<T>::block_number();
+		EnteredUntil::<T>::put(now);
+	}: { SafeMode::<T>::on_initialize(now); }
+	verify {
+		assert!(!SafeMode::<T>::is_entered());
+	}
+
+	enter {
+		let deposit = T::ActivationDeposit::get().ok_or("the activation deposit is not configured")?;
+		let caller = funded_caller::<T>(deposit);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert!(SafeMode::<T>::is_entered());
+	}
+
+	force_enter {
+		let origin = T::ForceEnterOrigin::successful_origin();
+		let call = Call::<T>::force_enter();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(SafeMode::<T>::is_entered());
+	}
+
+	extend {
+		let deposit = T::ExtensionDeposit::get().ok_or("the extension deposit is not configured")?;
+		let caller = funded_caller::<T>(deposit);
+		let until = enter_until_next_block::<T>();
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert_eq!(SafeMode::<T>::entered_until(), Some(until + T::ExtensionDuration::get()));
+	}
+
+	force_extend {
+		let until = enter_until_next_block::<T>();
+		let origin = T::ForceExtendOrigin::successful_origin();
+		let call = Call::<T>::force_extend();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(SafeMode::<T>::entered_until(), Some(until + T::ExtensionDuration::get()));
+	}
+
+	force_exit {
+		enter_until_next_block::<T>();
+		let origin = T::ForceExitOrigin::successful_origin();
+		let call = Call::<T>::force_exit();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!SafeMode::<T>::is_entered());
+	}
+
+	release_deposit {
+		let (account, block) = deposit_to_release::<T>()?;
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), account.clone(), block)
+	verify {
+		assert!(!Deposits::<T>::contains_key(&account, block));
+	}
+
+	force_release_deposit {
+		let (account, block) = deposit_to_release::<T>()?;
+		let origin = T::ForceDepositOrigin::successful_origin();
+		let call = Call::<T>::force_release_deposit(account.clone(), block);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Deposits::<T>::contains_key(&account, block));
+	}
+
+	force_slash_deposit {
+		let (account, block) = deposit_to_release::<T>()?;
+		let origin = T::ForceDepositOrigin::successful_origin();
+		let call = Call::<T>::force_slash_deposit(account.clone(), block);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Deposits::<T>::contains_key(&account, block));
+	}
+}
+
+impl_benchmark_test_suite!(
+	SafeMode,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
@@ -0,0 +1,400 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Safe Mode Pallet
+//!
+//! Puts the chain in a safe mode for a limited number of blocks, during which only a whitelist of
+//! calls can be dispatched. Meant as a circuit breaker while an incident is investigated.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! The safe mode is entered either permissionlessly, by reserving [`Config::ActivationDeposit`],
+//! or by [`Config::ForceEnterOrigin`]. It lasts [`Config::ActivationDuration`] blocks and can be
+//! extended in the same way by [`Config::ExtensionDuration`] blocks. [`Config::ForceExitOrigin`]
+//! can exit the safe mode early, otherwise it is exited at the beginning of the block at which its
+//! time is up.
+//!
+//! The pallet implements [`Filter`] for the calls of the runtime and is meant to be used as, or
+//! be part of, its `BaseCallFilter`. While the safe mode is entered, only the mandatory calls and
+//! the calls contained in [`Config::WhitelistedCalls`] pass the filter. The mandatory calls are
+//! always allowed since a block without them is invalid, they include the inherents of the
+//! pallets of FRAME, e.g. setting the timestamp. The inherents which are not mandatory must be
+//! whitelisted, e.g. with [`frame_support::inherent::InherentsOf`].
+//!
+//! The whitelist should contain the calls of this pallet, and the calls needed to dispatch them
+//! with the privileged origins, for example those of the sudo or collective pallets.
+//!
+//! Deposits can be released by anyone [`Config::ReleaseDelay`] blocks after they were placed, as
+//! long as the safe mode is not entered. [`Config::ForceDepositOrigin`] can release or slash them
+//! at any time, e.g. to punish an account which entered the safe mode without a good reason.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Encode, Decode};
+use sp_runtime::{RuntimeDebug, traits::{Saturating, Zero}};
+use frame_support::{
+	traits::{Contains, Currency, Filter, ReservableCurrency},
+	weights::{DispatchClass, GetDispatchInfo},
+};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The reason why the safe mode was exited.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ExitReason {
+	/// The safe mode ran out of time.
+	Timeout,
+	/// The safe mode was exited by [`Config::ForceExitOrigin`].
+	Force,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency in which the deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The calls which can still be dispatched while the safe mode is entered.
+		type WhitelistedCalls: Contains<<Self as frame_system::Config>::Call>;
+
+		/// The number of blocks for which the safe mode is entered by [`Pallet::enter`] and
+		/// [`Pallet::force_enter`].
+		#[pallet::constant]
+		type ActivationDuration: Get<Self::BlockNumber>;
+
+		/// The number of blocks by which [`Pallet::extend`] and [`Pallet::force_extend`] extend
+		/// the safe mode.
+		#[pallet::constant]
+		type ExtensionDuration: Get<Self::BlockNumber>;
+
+		/// The deposit reserved to enter the safe mode, `None` to disable [`Pallet::enter`].
+		#[pallet::constant]
+		type ActivationDeposit: Get<Option<BalanceOf<Self>>>;
+
+		/// The deposit reserved to extend the safe mode, `None` to disable [`Pallet::extend`].
+		#[pallet::constant]
+		type ExtensionDeposit: Get<Option<BalanceOf<Self>>>;
+
+		/// The number of blocks after which a deposit can be released with
+		/// [`Pallet::release_deposit`], `None` if only [`Config::ForceDepositOrigin`] can release
+		/// the deposits.
+		#[pallet::constant]
+		type ReleaseDelay: Get<Option<Self::BlockNumber>>;
+
+		/// The origin which can enter the safe mode without a deposit.
+		type ForceEnterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which can extend the safe mode without a deposit.
+		type ForceExtendOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which can exit the safe mode.
+		type ForceExitOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which can release or slash the deposits at any time.
+		type ForceDepositOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(
+		T::BlockNumber = "BlockNumber",
+		T::AccountId = "AccountId",
+		BalanceOf<T> = "Balance",
+	)]
+	pub enum Event<T: Config> {
+		/// The safe mode was entered until the given block. \[until\]
+		Entered(T::BlockNumber),
+		/// The safe mode was extended until the given block. \[until\]
+		Extended(T::BlockNumber),
+		/// The safe mode was exited. \[reason\]
+		Exited(ExitReason),
+		/// A deposit was reserved to enter or extend the safe mode. \[account, amount\]
+		DepositPlaced(T::AccountId, BalanceOf<T>),
+		/// A deposit was released. \[account, amount\]
+		DepositReleased(T::AccountId, BalanceOf<T>),
+		/// A deposit was slashed. \[account, amount\]
+		DepositSlashed(T::AccountId, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The safe mode is entered.
+		Entered,
+		/// The safe mode is not entered.
+		Exited,
+		/// The call is disabled by the configuration of the pallet.
+		NotConfigured,
+		/// No deposit was placed by the account at the given block.
+		NoDeposit,
+		/// The deposit cannot be released yet.
+		CannotReleaseYet,
+	}
+
+	/// The block number until which the safe mode is entered, `None` if it is not entered.
+	#[pallet::storage]
+	#[pallet::getter(fn entered_until)]
+	pub type EnteredUntil<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// The deposits reserved to enter or extend the safe mode, by account and by the block at
+	/// which they were placed.
+	#[pallet::storage]
+	pub type Deposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat, T::AccountId,
+		Twox64Concat, T::BlockNumber,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			match EnteredUntil::<T>::get() {
+				Some(until) if until <= now => {
+					Self::do_exit(ExitReason::Timeout);
+					T::WeightInfo::on_initialize_exit()
+				},
+				_ => T::WeightInfo::on_initialize_noop(),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enter the safe mode for [`Config::ActivationDuration`] blocks, reserving
+		/// [`Config::ActivationDeposit`] from the caller.
+		///
+		/// Fails if the safe mode is already entered or if no activation deposit is configured.
+		#[pallet::weight(T::WeightInfo::enter())]
+		pub fn enter(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit = T::ActivationDeposit::get().ok_or(Error::<T>::NotConfigured)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+
+			Self::place_deposit(who, deposit)?;
+			Self::do_enter();
+			Ok(())
+		}
+
+		/// Enter the safe mode for [`Config::ActivationDuration`] blocks without a deposit.
+		///
+		/// The dispatch origin must be [`Config::ForceEnterOrigin`].
+		#[pallet::weight(T::WeightInfo::force_enter())]
+		pub fn force_enter(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceEnterOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+
+			Self::do_enter();
+			Ok(())
+		}
+
+		/// Extend the safe mode by [`Config::ExtensionDuration`] blocks, reserving
+		/// [`Config::ExtensionDeposit`] from the caller.
+		///
+		/// Fails if the safe mode is not entered or if no extension deposit is configured.
+		#[pallet::weight(T::WeightInfo::extend())]
+		pub fn extend(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit = T::ExtensionDeposit::get().ok_or(Error::<T>::NotConfigured)?;
+			ensure!(Self::is_entered(), Error::<T>::Exited);
+
+			Self::place_deposit(who, deposit)?;
+			Self::do_extend()
+		}
+
+		/// Extend the safe mode by [`Config::ExtensionDuration`] blocks without a deposit.
+		///
+		/// The dispatch origin must be [`Config::ForceExtendOrigin`].
+		#[pallet::weight(T::WeightInfo::force_extend())]
+		pub fn force_extend(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceExtendOrigin::ensure_origin(origin)?;
+
+			Self::do_extend()
+		}
+
+		/// Exit the safe mode before its time is up.
+		///
+		/// The dispatch origin must be [`Config::ForceExitOrigin`].
+		#[pallet::weight(T::WeightInfo::force_exit())]
+		pub fn force_exit(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceExitOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_entered(), Error::<T>::Exited);
+
+			Self::do_exit(ExitReason::Force);
+			Ok(())
+		}
+
+		/// Release the deposit placed by `account` at `block`.
+		///
+		/// Can be called by any signed origin once [`Config::ReleaseDelay`] blocks have passed
+		/// since `block`, as long as the safe mode is not entered.
+		#[pallet::weight(T::WeightInfo::release_deposit())]
+		pub fn release_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delay = T::ReleaseDelay::get().ok_or(Error::<T>::NotConfigured)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(block.saturating_add(delay) <= now, Error::<T>::CannotReleaseYet);
+
+			Self::do_release_deposit(account, block)
+		}
+
+		/// Release the deposit placed by `account` at `block`, regardless of the safe mode and
+		/// of [`Config::ReleaseDelay`].
+		///
+		/// The dispatch origin must be [`Config::ForceDepositOrigin`].
+		#[pallet::weight(T::WeightInfo::force_release_deposit())]
+		pub fn force_release_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			T::ForceDepositOrigin::ensure_origin(origin)?;
+
+			Self::do_release_deposit(account, block)
+		}
+
+		/// Slash the deposit placed by `account` at `block`.
+		///
+		/// The dispatch origin must be [`Config::ForceDepositOrigin`].
+		#[pallet::weight(T::WeightInfo::force_slash_deposit())]
+		pub fn force_slash_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			T::ForceDepositOrigin::ensure_origin(origin)?;
+
+			let amount = Deposits::<T>::take(&account, block).ok_or(Error::<T>::NoDeposit)?;
+			// The slashed imbalance is dropped, i.e. the deposit is burned.
+			let _ = T::Currency::slash_reserved(&account, amount);
+			Self::deposit_event(Event::DepositSlashed(account, amount));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the safe mode is entered.
+	pub fn is_entered() -> bool {
+		EnteredUntil::<T>::exists()
+	}
+
+	/// Whether `call` can be dispatched, i.e. the safe mode is not entered, or `call` is
+	/// mandatory or whitelisted.
+	pub fn is_allowed(call: &<T as frame_system::Config>::Call) -> bool where
+		<T as frame_system::Config>::Call: GetDispatchInfo,
+	{
+		!Self::is_entered() ||
+			call.get_dispatch_info().class == DispatchClass::Mandatory ||
+			T::WhitelistedCalls::contains(call)
+	}
+
+	fn do_enter() {
+		let now = frame_system::Pallet::<T>::block_number();
+		let until = now.saturating_add(T::ActivationDuration::get());
+		EnteredUntil::<T>::put(until);
+		Self::deposit_event(Event::Entered(until));
+	}
+
+	fn do_extend() -> frame_support::dispatch::DispatchResult {
+		let until = EnteredUntil::<T>::get()
+			.ok_or(Error::<T>::Exited)?
+			.saturating_add(T::ExtensionDuration::get());
+		EnteredUntil::<T>::put(until);
+		Self::deposit_event(Event::Extended(until));
+		Ok(())
+	}
+
+	fn do_exit(reason: ExitReason) {
+		EnteredUntil::<T>::kill();
+		Self::deposit_event(Event::Exited(reason));
+	}
+
+	fn place_deposit(
+		who: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> frame_support::dispatch::DispatchResult {
+		T::Currency::reserve(&who, amount)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		Deposits::<T>::mutate(&who, now, |deposit| {
+			*deposit = Some(deposit.unwrap_or_else(Zero::zero).saturating_add(amount));
+		});
+		Self::deposit_event(Event::DepositPlaced(who, amount));
+		Ok(())
+	}
+
+	fn do_release_deposit(
+		account: T::AccountId,
+		block: T::BlockNumber,
+	) -> frame_support::dispatch::DispatchResult {
+		let amount = Deposits::<T>::take(&account, block).ok_or(Error::<T>::NoDeposit)?;
+		T::Currency::unreserve(&account, amount);
+		Self::deposit_event(Event::DepositReleased(account, amount));
+		Ok(())
+	}
+}
+
+impl<T: Config> Contains<<T as frame_system::Config>::Call> for Pallet<T> where
+	<T as frame_system::Config>::Call: GetDispatchInfo,
+{
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		Self::is_allowed(call)
+	}
+}
+
+impl<T: Config> Filter<<T as frame_system::Config>::Call> for Pallet<T> where
+	<T as frame_system::Config>::Call: GetDispatchInfo,
+{
+	fn filter(call: &<T as frame_system::Config>::Call) -> bool {
+		Self::is_allowed(call)
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the safe mode pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+
+fn transfer_call() -> Call {
+	Call::Balances(pallet_balances::Call::transfer(2, 1))
+}
+
+#[test]
+fn calls_are_filtered_while_entered() {
+	new_test_ext().execute_with(|| {
+		let force_exit = Call::SafeMode(crate::Call::force_exit());
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&transfer_call()));
+
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert!(SafeMode::is_entered());
+		assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&transfer_call()));
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&force_exit));
+
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&transfer_call()));
+	});
+}
+
+#[test]
+fn mandatory_calls_are_allowed_while_entered() {
+	new_test_ext().execute_with(|| {
+		let set_timestamp = Call::Timestamp(pallet_timestamp::Call::set(42));
+		assert_eq!(set_timestamp.get_dispatch_info().class, DispatchClass::Mandatory);
+
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&set_timestamp));
+		assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&transfer_call()));
+	});
+}
+
+#[test]
+fn enter_reserves_deposit_and_times_out() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_eq!(SafeMode::entered_until(), Some(11));
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_eq!(Deposits::<Test>::get(1, 1), Some(20));
+		assert_noop!(SafeMode::enter(Origin::signed(2)), Error::<Test>::Entered);
+		assert_noop!(SafeMode::force_enter(Origin::root()), Error::<Test>::Entered);
+
+		run_to_block(10);
+		assert!(SafeMode::is_entered());
+		run_to_block(11);
+		assert!(!SafeMode::is_entered());
+		System::assert_last_event(crate::Event::Exited(ExitReason::Timeout).into());
+	});
+}
+
+#[test]
+fn enter_and_extend_can_be_disabled() {
+	new_test_ext().execute_with(|| {
+		ActivationDeposit::set(None);
+		ExtensionDeposit::set(None);
+		assert_noop!(SafeMode::enter(Origin::signed(1)), Error::<Test>::NotConfigured);
+
+		assert_ok!(SafeMode::force_enter(Origin::root()));
+		assert_noop!(SafeMode::extend(Origin::signed(1)), Error::<Test>::NotConfigured);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn extend_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::extend(Origin::signed(1)), Error::<Test>::Exited);
+		assert_noop!(SafeMode::force_extend(Origin::root()), Error::<Test>::Exited);
+
+		assert_ok!(SafeMode::force_enter(Origin::root()));
+		assert_ok!(SafeMode::extend(Origin::signed(1)));
+		assert_ok!(SafeMode::extend(Origin::signed(1)));
+		assert_eq!(Deposits::<Test>::get(1, 1), Some(20));
+		assert_ok!(SafeMode::force_extend(Origin::root()));
+		assert_eq!(SafeMode::entered_until(), Some(26));
+		System::assert_last_event(crate::Event::Extended(26).into());
+
+		run_to_block(26);
+		assert!(!SafeMode::is_entered());
+	});
+}
+
+#[test]
+fn force_calls_require_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::force_enter(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_noop!(SafeMode::force_extend(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_noop!(SafeMode::force_exit(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_noop!(
+			SafeMode::force_release_deposit(Origin::signed(1), 1, 1),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			SafeMode::force_slash_deposit(Origin::signed(1), 1, 1),
+			DispatchError::BadOrigin,
+		);
+
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		System::assert_last_event(crate::Event::Exited(ExitReason::Force).into());
+		assert_noop!(SafeMode::force_exit(Origin::root()), Error::<Test>::Exited);
+	});
+}
+
+#[test]
+fn release_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		run_to_block(20);
+		assert_noop!(
+			SafeMode::release_deposit(Origin::signed(2), 1, 1),
+			Error::<Test>::CannotReleaseYet,
+		);
+
+		// Cannot be released while the safe mode is entered.
+		assert_ok!(SafeMode::force_enter(Origin::root()));
+		run_to_block(22);
+		assert_noop!(SafeMode::release_deposit(Origin::signed(2), 1, 1), Error::<Test>::Entered);
+
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		assert_ok!(SafeMode::release_deposit(Origin::signed(2), 1, 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_noop!(SafeMode::release_deposit(Origin::signed(2), 1, 1), Error::<Test>::NoDeposit);
+	});
+}
+
+#[test]
+fn release_deposit_can_be_disabled() {
+	new_test_ext().execute_with(|| {
+		ReleaseDelay::set(None);
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		run_to_block(100);
+		assert_noop!(
+			SafeMode::release_deposit(Origin::signed(1), 1, 1),
+			Error::<Test>::NotConfigured,
+		);
+
+		assert_ok!(SafeMode::force_release_deposit(Origin::root(), 1, 1));
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn force_slash_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_ok!(SafeMode::force_slash_deposit(Origin::root(), 1, 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 80);
+		assert_eq!(Balances::total_issuance(), 280);
+		System::assert_last_event(crate::Event::DepositSlashed(1, 20).into());
+		assert_noop!(
+			SafeMode::force_slash_deposit(Origin::root(), 1, 1),
+			Error::<Test>::NoDeposit,
+		);
+	});
+}
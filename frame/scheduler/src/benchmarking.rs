@@ -33,7 +33,7 @@ const BLOCK_NUMBER: u32 = 2;
 // Add `n` named items to the schedule
 fn fill_schedule<T: Config> (when: T::BlockNumber, n: u32) -> Result<(), &'static str> {
 	// Essentially a no-op call.
-	let call = frame_system::Call::set_storage(vec![]);
+	let call: <T as Config>::Call = frame_system::Call::set_storage(vec![]).into();
 	for i in 0..n {
 		// Named schedule is strictly heavier than anonymous
 		Scheduler::<T>::do_schedule_named(
@@ -58,10 +58,10 @@ benchmarks! {
 		let periodic = Some((T::BlockNumber::one(), 100));
 		let priority = 0;
 		// Essentially a no-op call.
-		let call = Box::new(frame_system::Call::set_storage(vec![]).into());
+		let call: <T as Config>::Call = frame_system::Call::set_storage(vec![]).into();
 
 		fill_schedule::<T>(when, s)?;
-	}: _(RawOrigin::Root, when, periodic, priority, call)
+	}: _(RawOrigin::Root, when, periodic, priority, Box::new(call.into()))
 	verify {
 		ensure!(
 			Agenda::<T>::get(when).len() == (s + 1) as usize,
@@ -95,10 +95,10 @@ benchmarks! {
 		let periodic = Some((T::BlockNumber::one(), 100));
 		let priority = 0;
 		// Essentially a no-op call.
-		let call = Box::new(frame_system::Call::set_storage(vec![]).into());
+		let call: <T as Config>::Call = frame_system::Call::set_storage(vec![]).into();
 
 		fill_schedule::<T>(when, s)?;
-	}: _(RawOrigin::Root, id, when, periodic, priority, call)
+	}: _(RawOrigin::Root, id, when, periodic, priority, Box::new(call.into()))
 	verify {
 		ensure!(
 			Agenda::<T>::get(when).len() == (s + 1) as usize,
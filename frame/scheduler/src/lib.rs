@@ -36,6 +36,14 @@
 //! If a call is scheduled using proxy or whatever mecanism which adds filter,
 //! then those filter will not be used when dispatching the schedule call.
 //!
+//! Calls may be scheduled by value or by the hash of their encoding. For the latter, the
+//! preimage is requested from the configured `Preimages` and looked up once the task is
+//! due. If it is not available by then, the task is either postponed by
+//! `NoPreimagePostponement` blocks or dropped.
+//!
+//! Tasks which do not fit into the `MaximumWeight` of their block are postponed to the next
+//! block, at most `MaxPostponements` times.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -59,7 +67,10 @@ use codec::{Encode, Decode, Codec};
 use sp_runtime::{RuntimeDebug, traits::{Zero, One, BadOrigin, Saturating}};
 use frame_support::{
 	dispatch::{Dispatchable, DispatchError, DispatchResult, Parameter},
-	traits::{Get, schedule::{self, DispatchTime}, OriginTrait, EnsureOrigin, IsType},
+	traits::{
+		Get, schedule::{self, DispatchTime, MaybeHashed, LookupError}, OriginTrait, EnsureOrigin,
+		IsType, QueryPreimage,
+	},
 	weights::{GetDispatchInfo, Weight},
 };
 use frame_system::{self as system, ensure_signed};
@@ -71,6 +82,10 @@ pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
 
+/// A scheduled call, either given by value or by the hash of its encoding.
+pub type CallOrHashOf<T> =
+	MaybeHashed<<T as Config>::Call, <T as frame_system::Config>::Hash>;
+
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 struct ScheduledV1<Call, BlockNumber> {
@@ -80,7 +95,7 @@ struct ScheduledV1<Call, BlockNumber> {
 	maybe_periodic: Option<schedule::Period<BlockNumber>>,
 }
 
-/// Information regarding an item to be executed in the future.
+/// Information regarding an item to be executed in the future, as stored in version 2.
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 pub struct ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId> {
@@ -97,9 +112,36 @@ pub struct ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId> {
 	_phantom: PhantomData<AccountId>,
 }
 
+/// Information regarding an item to be executed in the future.
+#[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
+#[derive(Clone, RuntimeDebug, Encode, Decode)]
+pub struct ScheduledV3<Call, BlockNumber, PalletsOrigin, AccountId> {
+	/// The unique identity for this task, if there is one.
+	maybe_id: Option<Vec<u8>>,
+	/// This task's priority.
+	priority: schedule::Priority,
+	/// The call to be dispatched, or its hash.
+	call: Call,
+	/// If the call is periodic, then this points to the information concerning that.
+	maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The origin to dispatch the call.
+	origin: PalletsOrigin,
+	/// The number of times this task was postponed because it did not fit into its block.
+	postponed: u32,
+	_phantom: PhantomData<AccountId>,
+}
+
 /// The current version of Scheduled struct.
 pub type Scheduled<Call, BlockNumber, PalletsOrigin, AccountId> =
-	ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId>;
+	ScheduledV3<Call, BlockNumber, PalletsOrigin, AccountId>;
+
+/// The `Scheduled` struct of a pallet's configuration.
+pub type ScheduledOf<T> = Scheduled<
+	CallOrHashOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::PalletsOrigin,
+	<T as frame_system::Config>::AccountId,
+>;
 
 // A value placed in storage that represents the current version of the Scheduler storage.
 // This value is used by the `on_runtime_upgrade` logic to determine whether we run
@@ -108,6 +150,7 @@ pub type Scheduled<Call, BlockNumber, PalletsOrigin, AccountId> =
 enum Releases {
 	V1,
 	V2,
+	V3,
 }
 
 impl Default for Releases {
@@ -159,6 +202,18 @@ pub mod pallet {
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The preimages with which we look up call hashes to get the call.
+		type Preimages: QueryPreimage<Self::Hash>;
+
+		/// If `Some` then the number of blocks to postpone execution for when the preimage of a
+		/// call hash is not available. If `None`, such tasks are dropped.
+		type NoPreimagePostponement: Get<Option<Self::BlockNumber>>;
+
+		/// The maximum number of times a task which does not fit into the weight of its block is
+		/// postponed to the next block before it is dropped. If `None`, such tasks are postponed
+		/// for as long as it takes.
+		type MaxPostponements: Get<Option<u32>>;
 	}
 
 	/// Items to be executed, indexed by the block number that they should be executed on.
@@ -167,7 +222,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::BlockNumber,
-		Vec<Option<Scheduled<<T as Config>::Call, T::BlockNumber, T::PalletsOrigin, T::AccountId>>>,
+		Vec<Option<ScheduledOf<T>>>,
 		ValueQuery,
 	>;
 
@@ -193,6 +248,12 @@ pub mod pallet {
 		Canceled(T::BlockNumber, u32),
 		/// Dispatched some task. \[task, id, result\]
 		Dispatched(TaskAddress<T::BlockNumber>, Option<Vec<u8>>, DispatchResult),
+		/// The call for the provided hash was not found so the task has been aborted.
+		/// \[task, id, error\]
+		CallLookupFailed(TaskAddress<T::BlockNumber>, Option<Vec<u8>>, LookupError),
+		/// The task was postponed too many times for lack of weight and has been dropped.
+		/// \[task, id\]
+		PermanentlyOverweight(TaskAddress<T::BlockNumber>, Option<Vec<u8>>),
 	}
 
 	#[pallet::error]
@@ -220,22 +281,33 @@ pub mod pallet {
  	#[pallet::genesis_build]
  	impl<T: Config> GenesisBuild<T> for GenesisConfig {
  		fn build(&self) {
-			StorageVersion::<T>::put(Releases::V2);
+			StorageVersion::<T>::put(Releases::V3);
  		}
  	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Migrate the agenda to the latest storage version, according to `StorageVersion`.
+		fn on_runtime_upgrade() -> Weight {
+			if Self::migrate_v1_to_t2() || Self::migrate_v2_to_v3() {
+				log::info!(target: "runtime::scheduler", "migrated the agenda to V3");
+				T::BlockWeights::get().max_block
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
 		/// Execute the scheduled calls
 		///
 		/// # <weight>
 		/// - S = Number of already scheduled calls
 		/// - N = Named scheduled calls
 		/// - P = Periodic Calls
+		/// - H = Calls scheduled by hash
 		/// - Base Weight: 9.243 + 23.45 * S µs
 		/// - DB Weight:
-		///     - Read: Agenda + Lookup * N + Agenda(Future) * P
-		///     - Write: Agenda + Lookup * N  + Agenda(future) * P
+		///     - Read: Agenda + Lookup * N + Agenda(Future) * P + Preimage * H
+		///     - Write: Agenda + Lookup * N  + Agenda(future) * P + Preimage * H
 		/// # </weight>
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			let limit = T::MaximumWeight::get();
@@ -252,82 +324,90 @@ pub mod pallet {
 				);
 			}
 			queued.sort_by_key(|(_, s)| s.priority);
-			let base_weight: Weight = T::DbWeight::get().reads_writes(1, 2); // Agenda + Agenda(next)
-			let mut total_weight: Weight = 0;
-			queued
-				.into_iter()
-				.enumerate()
-				.scan(base_weight, |cumulative_weight, (order, (index, s))| {
-					*cumulative_weight =
-						cumulative_weight.saturating_add(s.call.get_dispatch_info().weight);
+			let next = now + One::one();
+			// Agenda + Agenda(next)
+			let mut cumulative_weight: Weight = T::DbWeight::get().reads_writes(1, 2);
+			let mut total_weight = Weight::zero();
+			for (order, (index, mut s)) in queued.into_iter().enumerate() {
+				let call = match &s.call {
+					MaybeHashed::Value(call) => call.clone(),
+					MaybeHashed::Hash(hash) => {
+						let hash = *hash;
+						// Read the preimage and update its request.
+						cumulative_weight =
+							cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+						// The preimage is only unrequested once the call is dispatched, an
+						// overweight task keeps referring to it.
+						match CallOrHashOf::<T>::lookup::<T::Preimages>(&hash) {
+							Ok(call) => call,
+							Err(error) => {
+								Self::handle_lookup_failure((now, index), s, error);
+								continue
+							},
+						}
+					},
+				};
 
-					let origin =
-						<<T as Config>::Origin as From<T::PalletsOrigin>>::from(s.origin.clone())
-							.into();
+				cumulative_weight =
+					cumulative_weight.saturating_add(call.get_dispatch_info().weight);
 
-					if ensure_signed(origin).is_ok() {
-						// AccountData for inner call origin accountdata.
-						*cumulative_weight =
-							cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
-					}
+				let origin =
+					<<T as Config>::Origin as From<T::PalletsOrigin>>::from(s.origin.clone())
+						.into();
 
-					if s.maybe_id.is_some() {
-						// Remove/Modify Lookup
-						*cumulative_weight =
-							cumulative_weight.saturating_add(T::DbWeight::get().writes(1));
-					}
-					if s.maybe_periodic.is_some() {
-						// Read/Write Agenda for future block
-						*cumulative_weight =
-							cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
-					}
+				if ensure_signed(origin).is_ok() {
+					// AccountData for inner call origin accountdata.
+					cumulative_weight =
+						cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				}
 
-					Some((order, index, *cumulative_weight, s))
-				})
-				.filter_map(|(order, index, cumulative_weight, mut s)| {
-					// We allow a scheduled call if any is true:
-					// - It's priority is `HARD_DEADLINE`
-					// - It does not push the weight past the limit.
-					// - It is the first item in the schedule
-					if s.priority <= schedule::HARD_DEADLINE
-						|| cumulative_weight <= limit
-						|| order == 0
-					{
-						let r = s.call.clone().dispatch(s.origin.clone().into());
-						let maybe_id = s.maybe_id.clone();
-						if let &Some((period, count)) = &s.maybe_periodic {
-							if count > 1 {
-								s.maybe_periodic = Some((period, count - 1));
-							} else {
-								s.maybe_periodic = None;
-							}
-							let next = now + period;
-							// If scheduled is named, place it's information in `Lookup`
-							if let Some(ref id) = s.maybe_id {
-								let next_index = Agenda::<T>::decode_len(now + period).unwrap_or(0);
-								Lookup::<T>::insert(id, (next, next_index as u32));
-							}
-							Agenda::<T>::append(next, Some(s));
-						} else {
-							if let Some(ref id) = s.maybe_id {
-								Lookup::<T>::remove(id);
-							}
-						}
-						Self::deposit_event(Event::Dispatched(
-							(now, index),
-							maybe_id,
-							r.map(|_| ()).map_err(|e| e.error),
-						));
-						total_weight = cumulative_weight;
-						None
+				if s.maybe_id.is_some() {
+					// Remove/Modify Lookup
+					cumulative_weight =
+						cumulative_weight.saturating_add(T::DbWeight::get().writes(1));
+				}
+				if s.maybe_periodic.is_some() {
+					// Read/Write Agenda for future block
+					cumulative_weight =
+						cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+				}
+
+				// We allow a scheduled call if any is true:
+				// - It's priority is `HARD_DEADLINE`
+				// - It does not push the weight past the limit.
+				// - It is the first item in the schedule
+				if s.priority > schedule::HARD_DEADLINE &&
+					cumulative_weight.any_gt(&limit) &&
+					order > 0
+				{
+					Self::handle_overweight((now, index), next, s);
+					continue
+				}
+
+				// The call is dispatched, the task keeps its value if it is periodic.
+				s.call.ensure_unrequested::<T::Preimages>();
+				s.call = call.clone().into();
+
+				let r = call.dispatch(s.origin.clone().into());
+				let maybe_id = s.maybe_id.clone();
+				if let &Some((period, count)) = &s.maybe_periodic {
+					if count > 1 {
+						s.maybe_periodic = Some((period, count - 1));
 					} else {
-						Some(Some(s))
+						s.maybe_periodic = None;
 					}
-				})
-				.for_each(|unused| {
-					let next = now + One::one();
-					Agenda::<T>::append(next, unused);
-				});
+					s.postponed = 0;
+					Self::place_task(now + period, s);
+				} else if let Some(ref id) = s.maybe_id {
+					Lookup::<T>::remove(id);
+				}
+				Self::deposit_event(Event::Dispatched(
+					(now, index),
+					maybe_id,
+					r.map(|_| ()).map_err(|e| e.error),
+				));
+				total_weight = cumulative_weight;
+			}
 
 			total_weight
 		}
@@ -351,7 +431,7 @@ pub mod pallet {
 			when: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Config>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::Origin::from(origin);
@@ -400,7 +480,7 @@ pub mod pallet {
 			when: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Config>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::Origin::from(origin);
@@ -444,7 +524,7 @@ pub mod pallet {
 			after: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Config>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::Origin::from(origin);
@@ -470,7 +550,7 @@ pub mod pallet {
 			after: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Config>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::Origin::from(origin);
@@ -488,23 +568,55 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-	/// Migrate storage format from V1 to V2.
+	/// Migrate storage format from V1 to the latest version, V3.
 	/// Return true if migration is performed.
 	pub fn migrate_v1_to_t2() -> bool {
 		if StorageVersion::<T>::get() == Releases::V1 {
-			StorageVersion::<T>::put(Releases::V2);
+			StorageVersion::<T>::put(Releases::V3);
 
 			Agenda::<T>::translate::<
 				Vec<Option<ScheduledV1<<T as Config>::Call, T::BlockNumber>>>, _
 			>(|_, agenda| Some(
 				agenda
 					.into_iter()
-					.map(|schedule| schedule.map(|schedule| ScheduledV2 {
+					.map(|schedule| schedule.map(|schedule| ScheduledV3 {
 						maybe_id: schedule.maybe_id,
 						priority: schedule.priority,
-						call: schedule.call,
+						call: schedule.call.into(),
 						maybe_periodic: schedule.maybe_periodic,
 						origin: system::RawOrigin::Root.into(),
+						postponed: 0,
+						_phantom: Default::default(),
+					}))
+					.collect::<Vec<_>>()
+			));
+
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Migrate storage format from V2 to V3.
+	/// Return true if migration is performed.
+	pub fn migrate_v2_to_v3() -> bool {
+		if StorageVersion::<T>::get() == Releases::V2 {
+			StorageVersion::<T>::put(Releases::V3);
+
+			Agenda::<T>::translate::<
+				Vec<Option<ScheduledV2<
+					<T as Config>::Call, T::BlockNumber, T::PalletsOrigin, T::AccountId
+				>>>, _
+			>(|_, agenda| Some(
+				agenda
+					.into_iter()
+					.map(|schedule| schedule.map(|schedule| ScheduledV3 {
+						maybe_id: schedule.maybe_id,
+						priority: schedule.priority,
+						call: schedule.call.into(),
+						maybe_periodic: schedule.maybe_periodic,
+						origin: schedule.origin,
+						postponed: 0,
 						_phantom: Default::default(),
 					}))
 					.collect::<Vec<_>>()
@@ -519,7 +631,7 @@ impl<T: Config> Pallet<T> {
 	/// Helper to migrate scheduler when the pallet origin type has changed.
 	pub fn migrate_origin<OldOrigin: Into<T::PalletsOrigin> + codec::Decode>() {
 		Agenda::<T>::translate::<
-			Vec<Option<Scheduled<<T as Config>::Call, T::BlockNumber, OldOrigin, T::AccountId>>>, _
+			Vec<Option<Scheduled<CallOrHashOf<T>, T::BlockNumber, OldOrigin, T::AccountId>>>, _
 		>(|_, agenda| Some(
 			agenda
 				.into_iter()
@@ -529,12 +641,66 @@ impl<T: Config> Pallet<T> {
 					call: schedule.call,
 					maybe_periodic: schedule.maybe_periodic,
 					origin: schedule.origin.into(),
+					postponed: schedule.postponed,
 					_phantom: Default::default(),
 				}))
 				.collect::<Vec<_>>()
 		));
 	}
 
+	/// Place a task into the agenda of block `when`, updating its `Lookup` entry if it is named.
+	fn place_task(when: T::BlockNumber, task: ScheduledOf<T>) {
+		if let Some(ref id) = task.maybe_id {
+			let index = Agenda::<T>::decode_len(when).unwrap_or(0);
+			Lookup::<T>::insert(id, (when, index as u32));
+		}
+		Agenda::<T>::append(when, Some(task));
+	}
+
+	/// Handle a due task whose call preimage could not be looked up.
+	///
+	/// The task is postponed if the preimage is unknown and `NoPreimagePostponement` is set,
+	/// otherwise it is dropped.
+	fn handle_lookup_failure(
+		address: TaskAddress<T::BlockNumber>,
+		task: ScheduledOf<T>,
+		error: LookupError,
+	) {
+		match (error, T::NoPreimagePostponement::get()) {
+			(LookupError::Unknown, Some(delay)) => {
+				Self::place_task(address.0.saturating_add(delay), task);
+			},
+			_ => {
+				if let Some(ref id) = task.maybe_id {
+					Lookup::<T>::remove(id);
+				}
+				task.call.ensure_unrequested::<T::Preimages>();
+				Self::deposit_event(Event::CallLookupFailed(address, task.maybe_id, error));
+			},
+		}
+	}
+
+	/// Handle a due task which does not fit into the remaining weight of the block.
+	///
+	/// The task is postponed to block `next`, unless it was already postponed `MaxPostponements`
+	/// times, in which case it is dropped.
+	fn handle_overweight(
+		address: TaskAddress<T::BlockNumber>,
+		next: T::BlockNumber,
+		mut task: ScheduledOf<T>,
+	) {
+		if T::MaxPostponements::get().map_or(false, |max| task.postponed >= max) {
+			if let Some(ref id) = task.maybe_id {
+				Lookup::<T>::remove(id);
+			}
+			task.call.ensure_unrequested::<T::Preimages>();
+			Self::deposit_event(Event::PermanentlyOverweight(address, task.maybe_id));
+		} else {
+			task.postponed = task.postponed.saturating_add(1);
+			Self::place_task(next, task);
+		}
+	}
+
 	fn resolve_time(when: DispatchTime<T::BlockNumber>) -> Result<T::BlockNumber, DispatchError> {
 		let now = frame_system::Pallet::<T>::block_number();
 
@@ -557,7 +723,7 @@ impl<T: Config> Pallet<T> {
 		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
-		call: <T as Config>::Call,
+		call: CallOrHashOf<T>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
 		let when = Self::resolve_time(when)?;
 
@@ -566,12 +732,14 @@ impl<T: Config> Pallet<T> {
 			.filter(|p| p.1 > 1 && !p.0.is_zero())
 			// Remove one from the number of repetitions since we will schedule one now.
 			.map(|(p, c)| (p, c - 1));
+		call.ensure_requested::<T::Preimages>();
 		let s = Some(Scheduled {
 			maybe_id: None,
 			priority,
 			call,
 			maybe_periodic,
 			origin,
+			postponed: 0,
 			_phantom: PhantomData::<T::AccountId>::default(),
 		});
 		Agenda::<T>::append(when, s);
@@ -606,6 +774,7 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 		if let Some(s) = scheduled {
+			s.call.ensure_unrequested::<T::Preimages>();
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
 			}
@@ -646,7 +815,7 @@ impl<T: Config> Pallet<T> {
 		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
-		call: <T as Config>::Call,
+		call: CallOrHashOf<T>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
 		// ensure id it is unique
 		if Lookup::<T>::contains_key(&id) {
@@ -661,12 +830,14 @@ impl<T: Config> Pallet<T> {
 			// Remove one from the number of repetitions since we will schedule one now.
 			.map(|(p, c)| (p, c - 1));
 
+		call.ensure_requested::<T::Preimages>();
 		let s = Scheduled {
 			maybe_id: Some(id.clone()),
 			priority,
 			call,
 			maybe_periodic,
 			origin,
+			postponed: 0,
 			_phantom: Default::default(),
 		};
 		Agenda::<T>::append(when, Some(s));
@@ -696,7 +867,9 @@ impl<T: Config> Pallet<T> {
 								return Err(BadOrigin.into());
 							}
 						}
-						*s = None;
+						if let Some(s) = s.take() {
+							s.call.ensure_unrequested::<T::Preimages>();
+						}
 					}
 					Ok(())
 				})?;
@@ -743,8 +916,10 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
-impl<T: Config> schedule::Anon<T::BlockNumber, <T as Config>::Call, T::PalletsOrigin>
-	for Pallet<T>
+/// Anonymous tasks can be scheduled with their call, or with the hash of its preimage, which is
+/// then requested until the task is dispatched.
+impl<T: Config, C> schedule::Anon<T::BlockNumber, C, T::PalletsOrigin> for Pallet<T> where
+	C: Into<CallOrHashOf<T>>,
 {
 	type Address = TaskAddress<T::BlockNumber>;
 
@@ -753,9 +928,9 @@ impl<T: Config> schedule::Anon<T::BlockNumber, <T as Config>::Call, T::PalletsOr
 		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
-		call: <T as Config>::Call,
+		call: C,
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_schedule(when, maybe_periodic, priority, origin, call)
+		Self::do_schedule(when, maybe_periodic, priority, origin, call.into())
 	}
 
 	fn cancel((when, index): Self::Address) -> Result<(), ()> {
@@ -790,7 +965,8 @@ impl<T: Config> schedule::Named<T::BlockNumber, <T as Config>::Call, T::PalletsO
 		origin: T::PalletsOrigin,
 		call: <T as Config>::Call,
 	) -> Result<Self::Address, ()> {
-		Self::do_schedule_named(id, when, maybe_periodic, priority, origin, call).map_err(|_| ())
+		Self::do_schedule_named(id, when, maybe_periodic, priority, origin, call.into())
+			.map_err(|_| ())
 	}
 
 	fn cancel_named(id: Vec<u8>) -> Result<(), ()> {
@@ -818,7 +994,7 @@ mod tests {
 	use crate as scheduler;
 	use frame_support::{
 		assert_err, assert_noop, assert_ok, ord_parameter_types, parameter_types,
-		traits::{Filter, OnFinalize, OnInitialize},
+		traits::{Filter, Get, OnFinalize, OnInitialize, OnRuntimeUpgrade},
 		weights::constants::RocksDbWeight,
 		Hashable,
 	};
@@ -826,10 +1002,11 @@ mod tests {
 	use sp_core::H256;
 	use sp_runtime::{
 		testing::Header,
-		traits::{BlakeTwo256, IdentityLookup},
+		traits::{BlakeTwo256, Hash, IdentityLookup},
 		Perbill,
 	};
 	use substrate_test_utils::assert_eq_uvec;
+	use std::{cell::RefCell, collections::BTreeMap};
 
 	// Logger module to track execution.
 	#[frame_support::pallet]
@@ -912,7 +1089,9 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(2_000_000_000_000);
+			frame_system::limits::BlockWeights::simple_max(
+				Weight::from_ref_time(2_000_000_000_000),
+			);
 	}
 	impl system::Config for Test {
 		type BaseCallFilter = BaseFilter;
@@ -945,6 +1124,47 @@ mod tests {
 	parameter_types! {
 		pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
 		pub const MaxScheduledPerBlock: u32 = 10;
+		pub static NoPreimagePostponement: Option<u64> = Some(2);
+		pub static MaxPostponements: Option<u32> = None;
+	}
+
+	thread_local! {
+		static PREIMAGES: RefCell<BTreeMap<H256, Vec<u8>>> = RefCell::new(BTreeMap::new());
+		static REQUESTS: RefCell<BTreeMap<H256, u32>> = RefCell::new(BTreeMap::new());
+	}
+
+	/// A preimage provider keeping the preimages and the number of requests for them in memory.
+	pub struct TestPreimages;
+	impl TestPreimages {
+		fn note(call: &Call) -> H256 {
+			let hash = BlakeTwo256::hash_of(call);
+			PREIMAGES.with(|p| p.borrow_mut().insert(hash, call.encode()));
+			hash
+		}
+	}
+	impl QueryPreimage<H256> for TestPreimages {
+		fn len(hash: &H256) -> Option<u32> {
+			PREIMAGES.with(|p| p.borrow().get(hash).map(|p| p.len() as u32))
+		}
+		fn fetch(hash: &H256) -> Option<Vec<u8>> {
+			PREIMAGES.with(|p| p.borrow().get(hash).cloned())
+		}
+		fn is_requested(hash: &H256) -> bool {
+			REQUESTS.with(|r| r.borrow().contains_key(hash))
+		}
+		fn request(hash: &H256) {
+			REQUESTS.with(|r| *r.borrow_mut().entry(*hash).or_default() += 1);
+		}
+		fn unrequest(hash: &H256) {
+			REQUESTS.with(|r| {
+				let mut requests = r.borrow_mut();
+				let count = requests.get_mut(hash).expect("unrequested a preimage twice");
+				*count -= 1;
+				if *count == 0 {
+					requests.remove(hash);
+				}
+			});
+		}
 	}
 	ord_parameter_types! {
 		pub const One: u64 = 1;
@@ -959,6 +1179,9 @@ mod tests {
 		type ScheduleOrigin = EnsureOneOf<u64, EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 		type MaxScheduledPerBlock = MaxScheduledPerBlock;
 		type WeightInfo = ();
+		type Preimages = TestPreimages;
+		type NoPreimagePostponement = NoPreimagePostponement;
+		type MaxPostponements = MaxPostponements;
 	}
 
 	pub type LoggerCall = logger::Call<Test>;
@@ -983,11 +1206,11 @@ mod tests {
 	#[test]
 	fn basic_scheduling_works() {
 		new_test_ext().execute_with(|| {
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(
 				&call
 			));
-			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()));
 			run_to_block(3);
 			assert!(logger::log().is_empty());
 			run_to_block(4);
@@ -1001,12 +1224,12 @@ mod tests {
 	fn schedule_after_works() {
 		new_test_ext().execute_with(|| {
 			run_to_block(2);
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(
 				&call
 			));
 			// This will schedule the call 3 blocks after the next block... so block 3 + 3 = 6
-			assert_ok!(Scheduler::do_schedule(DispatchTime::After(3), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::After(3), None, 127, root(), call.into()));
 			run_to_block(5);
 			assert!(logger::log().is_empty());
 			run_to_block(6);
@@ -1020,9 +1243,9 @@ mod tests {
 	fn schedule_after_zero_works() {
 		new_test_ext().execute_with(|| {
 			run_to_block(2);
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&call));
-			assert_ok!(Scheduler::do_schedule(DispatchTime::After(0), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::After(0), None, 127, root(), call.into()));
 			// Will trigger on the next block.
 			run_to_block(3);
 			assert_eq!(logger::log(), vec![(root(), 42u32)]);
@@ -1036,7 +1259,11 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			// at #4, every 3 blocks, 3 times.
 			assert_ok!(Scheduler::do_schedule(
-				DispatchTime::At(4), Some((3, 3)), 127, root(), Call::Logger(logger::Call::log(42, 1000))
+				DispatchTime::At(4),
+				Some((3, 3)),
+				127,
+				root(),
+				Call::Logger(logger::Call::log(42, Weight::from_ref_time(1000))).into(),
 			));
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -1064,9 +1291,9 @@ mod tests {
 	#[test]
 	fn reschedule_works() {
 		new_test_ext().execute_with(|| {
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&call));
-			assert_eq!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call).unwrap(), (4, 0));
+			assert_eq!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()).unwrap(), (4, 0));
 
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -1089,10 +1316,10 @@ mod tests {
 	#[test]
 	fn reschedule_named_works() {
 		new_test_ext().execute_with(|| {
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&call));
 			assert_eq!(Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), None, 127, root(), call
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), call.into()
 			).unwrap(), (4, 0));
 
 			run_to_block(3);
@@ -1116,10 +1343,10 @@ mod tests {
 	#[test]
 	fn reschedule_named_perodic_works() {
 		new_test_ext().execute_with(|| {
-			let call = Call::Logger(LoggerCall::log(42, 1000));
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
 			assert!(!<Test as frame_system::Config>::BaseCallFilter::filter(&call));
 			assert_eq!(Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), Some((3, 3)), 127, root(), call
+				1u32.encode(), DispatchTime::At(4), Some((3, 3)), 127, root(), call.into()
 			).unwrap(), (4, 0));
 
 			run_to_block(3);
@@ -1155,10 +1382,19 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			// at #4.
 			Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), None, 127, root(), Call::Logger(LoggerCall::log(69, 1000))
+				1u32.encode(),
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into(),
 			).unwrap();
 			let i = Scheduler::do_schedule(
-				DispatchTime::At(4), None, 127, root(), Call::Logger(LoggerCall::log(42, 1000))
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into(),
 			).unwrap();
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -1179,7 +1415,7 @@ mod tests {
 				Some((3, 3)),
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(42, 1000)),
+				Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into(),
 			)
 			.unwrap();
 			// same id results in error.
@@ -1189,7 +1425,7 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(69, 1000))
+				Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into()
 			)
 			.is_err());
 			// different id is ok.
@@ -1199,7 +1435,7 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(69, 1000)),
+				Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into(),
 			)
 			.unwrap();
 			run_to_block(3);
@@ -1221,14 +1457,14 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// 69 and 42 do not fit together
 			run_to_block(4);
@@ -1246,14 +1482,14 @@ mod tests {
 				None,
 				0,
 				root(),
-				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				0,
 				root(),
-				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// With base weights, 69 and 42 should not fit together, but do because of hard deadlines
 			run_to_block(4);
@@ -1269,14 +1505,14 @@ mod tests {
 				None,
 				1,
 				root(),
-				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				0,
 				root(),
-				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			run_to_block(4);
 			assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
@@ -1291,21 +1527,21 @@ mod tests {
 				None,
 				255,
 				root(),
-				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 3))
+				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 3)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				126,
 				root(),
-				Call::Logger(LoggerCall::log(2600, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(2600, MaximumSchedulerWeight::get() / 2)).into()
 			));
 
 			// 2600 does not fit with 69 or 42, but has higher priority, so will go through
@@ -1325,7 +1561,7 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			let base_weight: Weight =
 				<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 2);
-			let base_multiplier = 0;
+			let base_multiplier = Weight::zero();
 			let named_multiplier = <Test as frame_system::Config>::DbWeight::get().writes(1);
 			let periodic_multiplier =
 				<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
@@ -1337,7 +1573,7 @@ mod tests {
 				None,
 				255,
 				root(),
-				Call::Logger(LoggerCall::log(3, MaximumSchedulerWeight::get() / 3))
+				Call::Logger(LoggerCall::log(3, MaximumSchedulerWeight::get() / 3)).into()
 			));
 			// Anon Periodic
 			assert_ok!(Scheduler::do_schedule(
@@ -1345,7 +1581,7 @@ mod tests {
 				Some((1000, 3)),
 				128,
 				root(),
-				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 3))
+				Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 3)).into()
 			));
 			// Anon
 			assert_ok!(Scheduler::do_schedule(
@@ -1353,7 +1589,7 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// Named Periodic
 			assert_ok!(Scheduler::do_schedule_named(
@@ -1362,7 +1598,7 @@ mod tests {
 				Some((1000, 3)),
 				126,
 				root(),
-				Call::Logger(LoggerCall::log(2600, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(LoggerCall::log(2600, MaximumSchedulerWeight::get() / 2)).into()
 			));
 
 			// Will include the named periodic only
@@ -1407,15 +1643,165 @@ mod tests {
 
 			// Will contain none
 			let actual_weight = Scheduler::on_initialize(4);
-			assert_eq!(actual_weight, 0);
+			assert_eq!(actual_weight, Weight::zero());
+		});
+	}
+
+	#[test]
+	fn scheduling_with_preimages_works() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
+			let hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+			assert!(TestPreimages::is_requested(&hash));
+			TestPreimages::note(&call);
+
+			run_to_block(3);
+			assert!(logger::log().is_empty());
+			run_to_block(4);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			assert!(!TestPreimages::is_requested(&hash));
+		});
+	}
+
+	#[test]
+	fn scheduling_with_preimages_postpones_correctly() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
+			let hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+
+			// The preimage is missing, so the task is postponed by `NoPreimagePostponement`.
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((6, 0)));
+			assert!(TestPreimages::is_requested(&hash));
+
+			TestPreimages::note(&call);
+			run_to_block(5);
+			assert!(logger::log().is_empty());
+			run_to_block(6);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			assert!(!TestPreimages::is_requested(&hash));
+			assert!(Lookup::<Test>::get(1u32.encode()).is_none());
+		});
+	}
+
+	#[test]
+	fn missing_preimages_drop_the_task_without_postponement() {
+		new_test_ext().execute_with(|| {
+			NoPreimagePostponement::set(None);
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
+			let hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+
+			run_to_block(4);
+			System::assert_last_event(
+				Event::Scheduler(crate::Event::CallLookupFailed(
+					(4, 0),
+					Some(1u32.encode()),
+					LookupError::Unknown,
+				))
+			);
+			assert!(!TestPreimages::is_requested(&hash));
+			assert!(Lookup::<Test>::get(1u32.encode()).is_none());
+
+			TestPreimages::note(&call);
+			run_to_block(100);
+			assert!(logger::log().is_empty());
+		});
+	}
+
+	#[test]
+	fn overweight_hashed_tasks_keep_their_preimage_requested() {
+		new_test_ext().execute_with(|| {
+			let heavy = Call::Logger(LoggerCall::log(1, MaximumSchedulerWeight::get() / 2));
+			let call = Call::Logger(LoggerCall::log(42, MaximumSchedulerWeight::get() / 2));
+			let hash = TestPreimages::note(&call);
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 0, root(), heavy.into()));
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+
+			// The task is postponed, its call is still looked up by hash in the next block.
+			run_to_block(4);
+			assert_eq!(logger::log(), vec![(root(), 1u32)]);
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((5, 0)));
+			assert!(TestPreimages::is_requested(&hash));
+
+			run_to_block(5);
+			assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 42u32)]);
+			assert!(!TestPreimages::is_requested(&hash));
+		});
+	}
+
+	#[test]
+	fn cancel_unrequests_preimages() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000)));
+			let hash = BlakeTwo256::hash_of(&call);
+			let address = Scheduler::do_schedule(
+				DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			).unwrap();
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+			assert!(TestPreimages::is_requested(&hash));
+
+			assert_ok!(Scheduler::do_cancel(None, address));
+			assert!(TestPreimages::is_requested(&hash));
+			assert_ok!(Scheduler::do_cancel_named(None, 1u32.encode()));
+			assert!(!TestPreimages::is_requested(&hash));
+		});
+	}
+
+	#[test]
+	fn overweight_tasks_are_dropped_after_max_postponements() {
+		new_test_ext().execute_with(|| {
+			MaxPostponements::set(Some(1));
+			for i in 0..3u32 {
+				assert_ok!(Scheduler::do_schedule_named(
+					i.encode(),
+					DispatchTime::At(4),
+					None,
+					127,
+					root(),
+					Call::Logger(LoggerCall::log(i, MaximumSchedulerWeight::get() / 2)).into(),
+				));
+			}
+
+			// Only one task fits into each block.
+			run_to_block(4);
+			assert_eq!(logger::log(), vec![(root(), 0u32)]);
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((5, 0)));
+			assert_eq!(Lookup::<Test>::get(2u32.encode()), Some((5, 1)));
+
+			// The last task was already postponed once, so it is dropped.
+			run_to_block(5);
+			assert_eq!(logger::log(), vec![(root(), 0u32), (root(), 1u32)]);
+			System::assert_last_event(
+				Event::Scheduler(crate::Event::PermanentlyOverweight((5, 1), Some(2u32.encode())))
+			);
+			assert!(Lookup::<Test>::get(2u32.encode()).is_none());
+
+			run_to_block(100);
+			assert_eq!(logger::log(), vec![(root(), 0u32), (root(), 1u32)]);
 		});
 	}
 
 	#[test]
 	fn root_calls_works() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(LoggerCall::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(LoggerCall::log(42, 1000)));
+			let call =
+				Box::new(Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into());
+			let call2 =
+				Box::new(Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into());
 			assert_ok!(Scheduler::schedule_named(
 				Origin::root(),
 				1u32.encode(),
@@ -1442,8 +1828,10 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			run_to_block(3);
 
-			let call = Box::new(Call::Logger(LoggerCall::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(LoggerCall::log(42, 1000)));
+			let call =
+				Box::new(Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into());
+			let call2: Box<CallOrHashOf<Test>> =
+				Box::new(Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into());
 
 			assert_err!(
 				Scheduler::schedule_named(Origin::root(), 1u32.encode(), 2, None, 127, call),
@@ -1465,8 +1853,10 @@ mod tests {
 	#[test]
 	fn should_use_orign() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(LoggerCall::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(LoggerCall::log(42, 1000)));
+			let call =
+				Box::new(Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into());
+			let call2 =
+				Box::new(Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into());
 			assert_ok!(Scheduler::schedule_named(
 				system::RawOrigin::Signed(1).into(),
 				1u32.encode(),
@@ -1500,8 +1890,10 @@ mod tests {
 	#[test]
 	fn should_check_orign() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(LoggerCall::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(LoggerCall::log(42, 1000)));
+			let call =
+				Box::new(Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into());
+			let call2 =
+				Box::new(Call::Logger(LoggerCall::log(42, Weight::from_ref_time(1000))).into());
 			assert_noop!(
 				Scheduler::schedule_named(
 					system::RawOrigin::Signed(2).into(),
@@ -1523,8 +1915,14 @@ mod tests {
 	#[test]
 	fn should_check_orign_for_cancel() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(LoggerCall::log_without_filter(69, 1000)));
-			let call2 = Box::new(Call::Logger(LoggerCall::log_without_filter(42, 1000)));
+			let call = Box::new(
+				Call::Logger(LoggerCall::log_without_filter(69, Weight::from_ref_time(1000)))
+					.into(),
+			);
+			let call2 = Box::new(
+				Call::Logger(LoggerCall::log_without_filter(42, Weight::from_ref_time(1000)))
+					.into(),
+			);
 			assert_ok!(Scheduler::schedule_named(
 				system::RawOrigin::Signed(1).into(),
 				1u32.encode(),
@@ -1572,7 +1970,7 @@ mod tests {
 	}
 
 	#[test]
-	fn migration_to_v2_works() {
+	fn migration_to_v3_works() {
 		new_test_ext().execute_with(|| {
 			for i in 0..3u64 {
 				let k = i.twox_64_concat();
@@ -1580,14 +1978,14 @@ mod tests {
 					Some(ScheduledV1 {
 						maybe_id: None,
 						priority: i as u8 + 10,
-						call: Call::Logger(LoggerCall::log(96, 100)),
+						call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100))),
 						maybe_periodic: None,
 					}),
 					None,
 					Some(ScheduledV1 {
 						maybe_id: Some(b"test".to_vec()),
 						priority: 123,
-						call: Call::Logger(LoggerCall::log(69, 1000)),
+						call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))),
 						maybe_periodic: Some((456u64, 10)),
 					}),
 				];
@@ -1604,21 +2002,25 @@ mod tests {
 					(
 						0,
 						vec![
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: None,
 								priority: 10,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
@@ -1626,21 +2028,25 @@ mod tests {
 					(
 						1,
 						vec![
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: None,
 								priority: 11,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
@@ -1648,21 +2054,25 @@ mod tests {
 					(
 						2,
 						vec![
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: None,
 								priority: 12,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: root(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
@@ -1670,7 +2080,58 @@ mod tests {
 				]
 			);
 
-			assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V3);
+		});
+	}
+
+	#[test]
+	fn migration_from_v2_is_executed_on_runtime_upgrade() {
+		new_test_ext().execute_with(|| {
+			let old = vec![
+				Some(ScheduledV2 {
+					maybe_id: Some(b"test".to_vec()),
+					priority: 123,
+					call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))),
+					maybe_periodic: Some((456u64, 10)),
+					origin: root(),
+					_phantom: PhantomData::<u64>::default(),
+				}),
+				None,
+			];
+			frame_support::migration::put_storage_value(
+				b"Scheduler",
+				b"Agenda",
+				&4u64.twox_64_concat(),
+				old,
+			);
+			StorageVersion::<Test>::put(Releases::V2);
+
+			assert_eq!(
+				Scheduler::on_runtime_upgrade(),
+				<Test as frame_system::Config>::BlockWeights::get().max_block,
+			);
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V3);
+			assert_eq!(
+				Agenda::<Test>::get(4),
+				vec![
+					Some(ScheduledV3 {
+						maybe_id: Some(b"test".to_vec()),
+						priority: 123,
+						call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into(),
+						maybe_periodic: Some((456u64, 10)),
+						origin: root(),
+						postponed: 0,
+						_phantom: PhantomData::<u64>::default(),
+					}),
+					None,
+				],
+			);
+
+			// The agenda is only migrated once.
+			assert_eq!(
+				Scheduler::on_runtime_upgrade(),
+				<Test as frame_system::Config>::DbWeight::get().reads(1),
+			);
 		});
 	}
 
@@ -1679,13 +2140,14 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			for i in 0..3u64 {
 				let k = i.twox_64_concat();
-				let old: Vec<Option<Scheduled<_, _, u32, u64>>> = vec![
+				let old: Vec<Option<Scheduled<CallOrHashOf<Test>, u64, u32, u64>>> = vec![
 					Some(Scheduled {
 						maybe_id: None,
 						priority: i as u8 + 10,
-						call: Call::Logger(LoggerCall::log(96, 100)),
+						call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100))).into(),
 						origin: 3u32,
 						maybe_periodic: None,
+						postponed: 0,
 						_phantom: Default::default(),
 					}),
 					None,
@@ -1693,8 +2155,9 @@ mod tests {
 						maybe_id: Some(b"test".to_vec()),
 						priority: 123,
 						origin: 2u32,
-						call: Call::Logger(LoggerCall::log(69, 1000)),
+						call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000))).into(),
 						maybe_periodic: Some((456u64, 10)),
+						postponed: 0,
 						_phantom: Default::default(),
 					}),
 				];
@@ -1719,21 +2182,25 @@ mod tests {
 					(
 						0,
 						vec![
-							Some(ScheduledV2::<_, _, OriginCaller, u64> {
+							Some(ScheduledV3::<_, _, OriginCaller, u64> {
 								maybe_id: None,
 								priority: 10,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: system::RawOrigin::Root.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: system::RawOrigin::None.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
@@ -1741,21 +2208,25 @@ mod tests {
 					(
 						1,
 						vec![
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: None,
 								priority: 11,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: system::RawOrigin::Root.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: system::RawOrigin::None.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
@@ -1763,21 +2234,25 @@ mod tests {
 					(
 						2,
 						vec![
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: None,
 								priority: 12,
-								call: Call::Logger(LoggerCall::log(96, 100)),
+								call: Call::Logger(LoggerCall::log(96, Weight::from_ref_time(100)))
+									.into(),
 								maybe_periodic: None,
 								origin: system::RawOrigin::Root.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 							None,
-							Some(ScheduledV2 {
+							Some(ScheduledV3 {
 								maybe_id: Some(b"test".to_vec()),
 								priority: 123,
-								call: Call::Logger(LoggerCall::log(69, 1000)),
+								call: Call::Logger(LoggerCall::log(69, Weight::from_ref_time(1000)))
+									.into(),
 								maybe_periodic: Some((456u64, 10)),
 								origin: system::RawOrigin::None.into(),
+								postponed: 0,
 								_phantom: PhantomData::<u64>::default(),
 							}),
 						]
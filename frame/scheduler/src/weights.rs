@@ -54,31 +54,31 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn schedule(s: u32, ) -> Weight {
-		(35_029_000 as Weight)
-			.saturating_add((77_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(35_029_000 as u64)
+			.saturating_add(Weight::from_ref_time(77_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel(s: u32, ) -> Weight {
-		(31_419_000 as Weight)
-			.saturating_add((4_015_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(31_419_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_015_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn schedule_named(s: u32, ) -> Weight {
-		(44_752_000 as Weight)
-			.saturating_add((123_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(44_752_000 as u64)
+			.saturating_add(Weight::from_ref_time(123_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	fn cancel_named(s: u32, ) -> Weight {
-		(35_712_000 as Weight)
-			.saturating_add((4_008_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(35_712_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_008_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -87,31 +87,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn schedule(s: u32, ) -> Weight {
-		(35_029_000 as Weight)
-			.saturating_add((77_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		Weight::from_ref_time(35_029_000 as u64)
+			.saturating_add(Weight::from_ref_time(77_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 			
 	}
 	fn cancel(s: u32, ) -> Weight {
-		(31_419_000 as Weight)
-			.saturating_add((4_015_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(31_419_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_015_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn schedule_named(s: u32, ) -> Weight {
-		(44_752_000 as Weight)
-			.saturating_add((123_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(44_752_000 as u64)
+			.saturating_add(Weight::from_ref_time(123_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	fn cancel_named(s: u32, ) -> Weight {
-		(35_712_000 as Weight)
-			.saturating_add((4_008_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+		Weight::from_ref_time(35_712_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_008_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			
 	}
 	
@@ -73,6 +73,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = Balance;
 	type Event = Event;
 	type DustRemoval = ();
@@ -199,4 +200,5 @@ impl pallet_staking::Config for Test {
 	type OffchainSolutionWeightLimit = ();
 	type WeightInfo = ();
 	type ElectionProvider = MockElectionProvider;
+	type SortedListProvider = pallet_staking::UseNominatorsMap<Self>;
 }
@@ -317,7 +317,10 @@ use frame_system::{
 	self as system, ensure_signed, ensure_root,
 	offchain::SendTransactionTypes,
 };
-use frame_election_provider_support::{ElectionProvider, VoteWeight, Supports, data_provider};
+use frame_election_provider_support::{
+	ElectionProvider, VoteWeight, Supports, data_provider, DataProviderBounds, SortedListProvider,
+	VoteWeightProvider,
+};
 pub use weights::WeightInfo;
 
 const STAKING_ID: LockIdentifier = *b"staking ";
@@ -741,6 +744,12 @@ pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 	/// Maximum number of nominations per nominator.
 	const MAX_NOMINATIONS: u32;
 
+	/// Something that keeps the nominators sorted by their vote weight, from which the voters of
+	/// the elections are taken.
+	///
+	/// [`UseNominatorsMap`] can be used when the nominators need not be sorted.
+	type SortedListProvider: SortedListProvider<Self::AccountId>;
+
 	/// Tokens have been minted and are unused for validator-reward.
 	/// See [Era payout](./index.html#era-payout).
 	type RewardRemainder: OnUnbalanced<NegativeImbalanceOf<Self>>;
@@ -1073,6 +1082,29 @@ pub mod migrations {
 			T::DbWeight::get().writes(6 + 1)
 		}
 	}
+
+	/// Populate [`Config::SortedListProvider`] with all the current nominators.
+	///
+	/// Meant to be used in the runtime upgrade which starts using a new sorted list provider. It
+	/// does nothing if the list is already populated, e.g. at genesis.
+	pub struct InjectNominatorsIntoSortedListProvider<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> frame_support::traits::OnRuntimeUpgrade
+		for InjectNominatorsIntoSortedListProvider<T>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			if T::SortedListProvider::count() > 0 {
+				log!(info, "the sorted list provider is already populated, skipping");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight_of = Module::<T>::slashable_balance_of_fn();
+			let nominators = <Nominators<T>>::iter().map(|(nominator, _)| nominator);
+			let inserted = T::SortedListProvider::regenerate(nominators, weight_of);
+			log!(info, "injected {} nominators into the sorted list provider", inserted);
+			T::BlockWeights::get().max_block
+		}
+	}
 }
 
 decl_event!(
@@ -1477,7 +1509,7 @@ decl_module! {
 			let controller = ensure_signed(origin)?;
 			let ledger = Self::ledger(&controller).ok_or(Error::<T>::NotController)?;
 			let stash = &ledger.stash;
-			<Nominators<T>>::remove(stash);
+			Self::do_remove_nominator(stash);
 			<Validators<T>>::insert(stash, prefs);
 		}
 
@@ -1527,7 +1559,7 @@ decl_module! {
 			};
 
 			<Validators<T>>::remove(stash);
-			<Nominators<T>>::insert(stash, &nominations);
+			Self::do_add_nominator(stash, nominations);
 		}
 
 		/// Declare no desire to either validate or nominate.
@@ -1824,7 +1856,7 @@ decl_module! {
 			Self::update_ledger(&controller, &ledger);
 			Ok(Some(
 				35 * WEIGHT_PER_MICROS
-				+ 50 * WEIGHT_PER_NANOS * (ledger.unlocking.len() as Weight)
+				+ 50 * WEIGHT_PER_NANOS * (ledger.unlocking.len() as u64)
 				+ T::DbWeight::get().reads_writes(3, 2)
 			).into())
 		}
@@ -2082,12 +2114,45 @@ impl<T: Config> Module<T> {
 			WithdrawReasons::all(),
 		);
 		<Ledger<T>>::insert(controller, ledger);
+
+		if T::SortedListProvider::contains(&ledger.stash) {
+			T::SortedListProvider::on_update(&ledger.stash, Self::weight_of(&ledger.stash));
+		}
 	}
 
 	/// Chill a stash account.
 	fn chill_stash(stash: &T::AccountId) {
 		<Validators<T>>::remove(stash);
-		<Nominators<T>>::remove(stash);
+		Self::do_remove_nominator(stash);
+	}
+
+	/// The vote weight of `who`, based on the current total issuance.
+	fn weight_of(who: &T::AccountId) -> VoteWeight {
+		Self::slashable_balance_of_vote_weight(who, T::Currency::total_issuance())
+	}
+
+	/// Make `who` a nominator, inserting it into [`Config::SortedListProvider`] if it was not a
+	/// nominator already.
+	pub fn do_add_nominator(who: &T::AccountId, nominations: Nominations<T::AccountId>) {
+		if !T::SortedListProvider::contains(who) {
+			if let Err(e) = T::SortedListProvider::on_insert(who.clone(), Self::weight_of(who)) {
+				log!(warn, "failed to insert nominator {:?} into the sorted list: {:?}", who, e);
+			}
+		}
+		<Nominators<T>>::insert(who, nominations);
+	}
+
+	/// Remove `who` from the nominators and from [`Config::SortedListProvider`].
+	///
+	/// Returns true if `who` was a nominator.
+	pub fn do_remove_nominator(who: &T::AccountId) -> bool {
+		if <Nominators<T>>::contains_key(who) {
+			<Nominators<T>>::remove(who);
+			T::SortedListProvider::on_remove(who);
+			true
+		} else {
+			false
+		}
 	}
 
 	/// Actually make a payment to a staker. This uses the currency's reward function
@@ -2405,7 +2470,7 @@ impl<T: Config> Module<T> {
 
 		<Payee<T>>::remove(stash);
 		<Validators<T>>::remove(stash);
-		<Nominators<T>>::remove(stash);
+		Self::do_remove_nominator(stash);
 
 		system::Pallet::<T>::dec_consumers(stash);
 
@@ -2485,9 +2550,11 @@ impl<T: Config> Module<T> {
 		SlashRewardFraction::put(fraction);
 	}
 
-	/// Get all of the voters that are eligible for the npos election.
+	/// Get the voters that are eligible for the npos election, as many as `bounds` allow.
 	///
-	/// This will use all on-chain nominators, and all the validators will inject a self vote.
+	/// All the validators inject a self vote first, followed by the nominators in the order of
+	/// [`Config::SortedListProvider`]. The voters are taken in this order until the next one would
+	/// exhaust the count or the encoded size of `bounds`, so that the best nominators are kept.
 	///
 	/// ### Slashing
 	///
@@ -2495,20 +2562,49 @@ impl<T: Config> Module<T> {
 	/// auto-chilled.
 	///
 	/// Note that this is VERY expensive. Use with care.
-	pub fn get_npos_voters() -> Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)> {
+	pub fn get_npos_voters(
+		bounds: DataProviderBounds,
+	) -> Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)> {
 		let weight_of = Self::slashable_balance_of_fn();
 		let mut all_voters = Vec::new();
+		// The encoded size of the voters, without the length prefix of the vector.
+		let mut voters_size = 0usize;
+		let mut try_push = |voter: (T::AccountId, VoteWeight, Vec<T::AccountId>)| -> bool {
+			let count = all_voters.len().saturating_add(1);
+			let voter_size = voter.encoded_size();
+			let size = voters_size
+				.saturating_add(voter_size)
+				.saturating_add(codec::Compact(count as u32).encoded_size());
+			if bounds.exhausted(
+				Some(count.saturated_into::<u32>().into()),
+				Some(size.saturated_into::<u32>().into()),
+			) {
+				return false
+			}
+			voters_size = voters_size.saturating_add(voter_size);
+			all_voters.push(voter);
+			true
+		};
 
 		for (validator, _) in <Validators<T>>::iter() {
 			// append self vote
 			let self_vote = (validator.clone(), weight_of(&validator), vec![validator.clone()]);
-			all_voters.push(self_vote);
+			if !try_push(self_vote) {
+				return all_voters
+			}
 		}
 
 		// collect all slashing spans into a BTreeMap for further queries.
 		let slashing_spans = <SlashingSpans<T>>::iter().collect::<BTreeMap<_, _>>();
 
-		for (nominator, nominations) in <Nominators<T>>::iter() {
+		for nominator in T::SortedListProvider::iter() {
+			let nominations = match <Nominators<T>>::get(&nominator) {
+				Some(nominations) => nominations,
+				None => {
+					log!(warn, "{:?} is in the sorted list but is not a nominator", nominator);
+					continue
+				},
+			};
 			let Nominations { submitted_in, mut targets, suppressed: _ } = nominations;
 
 			// Filter out nomination targets which were nominated before the most recent
@@ -2521,7 +2617,9 @@ impl<T: Config> Module<T> {
 
 			if !targets.is_empty() {
 				let vote_weight = weight_of(&nominator);
-				all_voters.push((nominator, vote_weight, targets))
+				if !try_push((nominator, vote_weight, targets)) {
+					break
+				}
 			}
 		}
 
@@ -2542,37 +2640,40 @@ impl<T: Config> frame_election_provider_support::ElectionDataProvider<T::Account
 	}
 
 	fn voters(
-		maybe_max_len: Option<usize>,
+		bounds: DataProviderBounds,
 	) -> data_provider::Result<(Vec<(T::AccountId, VoteWeight, Vec<T::AccountId>)>, Weight)> {
 		// NOTE: reading these counts already needs to iterate a lot of storage keys, but they get
-		// cached. This is okay for the case of `Ok(_)`, but bad for `Err(_)`, as the trait does not
-		// report weight in failures.
-		let nominator_count = <Nominators<T>>::iter().count();
+		// cached.
 		let validator_count = <Validators<T>>::iter().count();
-		let voter_count = nominator_count.saturating_add(validator_count);
-
-		if maybe_max_len.map_or(false, |max_len| voter_count > max_len) {
-			return Err("Voter snapshot too big");
-		}
-
 		let slashing_span_count = <SlashingSpans<T>>::iter().count();
+
+		// Only the voters within `bounds` are taken, the worst nominators are left out.
+		let voters = Self::get_npos_voters(bounds);
+		let validators_taken = voters.len().min(validator_count);
+		let nominators_taken = voters.len().saturating_sub(validators_taken);
 		let weight = T::WeightInfo::get_npos_voters(
-			nominator_count as u32,
-			validator_count as u32,
+			validators_taken as u32,
+			nominators_taken as u32,
 			slashing_span_count as u32,
 		);
-		Ok((Self::get_npos_voters(), weight))
+
+		Ok((voters, weight))
 	}
 
-	fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<(Vec<T::AccountId>, Weight)> {
+	fn targets(bounds: DataProviderBounds) -> data_provider::Result<(Vec<T::AccountId>, Weight)> {
 		let target_count = <Validators<T>>::iter().count();
 
-		if maybe_max_len.map_or(false, |max_len| target_count > max_len) {
+		if bounds.count_exhausted(target_count.saturated_into::<u32>().into()) {
+			return Err("Target snapshot too big");
+		}
+
+		let targets = Self::get_npos_targets();
+		if bounds.size_exhausted(targets.encoded_size().saturated_into::<u32>().into()) {
 			return Err("Target snapshot too big");
 		}
 
 		let weight = <T as frame_system::Config>::DbWeight::get().reads(target_count as u64);
-		Ok((Self::get_npos_targets(), weight))
+		Ok((targets, weight))
 	}
 
 	fn next_election_prediction(now: T::BlockNumber) -> T::BlockNumber {
@@ -2645,14 +2746,85 @@ impl<T: Config> frame_election_provider_support::ElectionDataProvider<T::Account
 					claimed_rewards: vec![],
 				},
 			);
-			<Nominators<T>>::insert(
-				v,
+			Self::do_add_nominator(
+				&v,
 				Nominations { targets: t, submitted_in: 0, suppressed: false },
 			);
 		});
 	}
 }
 
+impl<T: Config> VoteWeightProvider<T::AccountId> for Module<T> {
+	fn vote_weight(who: &T::AccountId) -> VoteWeight {
+		Self::weight_of(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_vote_weight_of(who: &T::AccountId, weight: VoteWeight) {
+		use sp_std::convert::TryFrom;
+		// this leaves the total of the ledger and the locks out of sync, which does not matter for
+		// the benchmarks of the sorted list providers.
+		let active = <BalanceOf<T>>::try_from(weight).unwrap_or_else(|_| {
+			panic!("cannot convert a VoteWeight into BalanceOf, benchmark needs reconfiguring.")
+		});
+		let mut ledger = Self::ledger(who).unwrap_or_else(|| StakingLedger {
+			stash: who.clone(),
+			active: Zero::zero(),
+			total: Zero::zero(),
+			unlocking: vec![],
+			claimed_rewards: vec![],
+		});
+		ledger.active = active;
+		<Bonded<T>>::insert(who, who);
+		<Ledger<T>>::insert(who, ledger);
+	}
+}
+
+/// A [`SortedListProvider`] which is simply the map of the nominators, in no particular order.
+///
+/// This does not need any other pallet, but iterating it requires reading all the nominators.
+pub struct UseNominatorsMap<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> SortedListProvider<T::AccountId> for UseNominatorsMap<T> {
+	type Error = ();
+
+	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
+		Box::new(<Nominators<T>>::iter().map(|(nominator, _)| nominator))
+	}
+
+	fn count() -> u32 {
+		<Nominators<T>>::iter().count() as u32
+	}
+
+	fn contains(id: &T::AccountId) -> bool {
+		<Nominators<T>>::contains_key(id)
+	}
+
+	fn on_insert(_: T::AccountId, _: VoteWeight) -> Result<(), ()> {
+		// nothing to do, the nominators map is updated by the caller.
+		Ok(())
+	}
+
+	fn on_update(_: &T::AccountId, _: VoteWeight) {}
+
+	fn on_remove(_: &T::AccountId) {}
+
+	fn regenerate(
+		_: impl IntoIterator<Item = T::AccountId>,
+		_: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
+	) -> u32 {
+		0
+	}
+
+	fn clear() {
+		<Nominators<T>>::remove_all();
+	}
+
+	fn sanity_check() -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
 /// In this implementation `new_session(session)` must be called before `end_session(session-1)`
 /// i.e. the new session must be planned before the ending of the previous session.
 ///
@@ -2770,7 +2942,7 @@ where
 		slash_session: SessionIndex,
 	) -> Weight {
 		let reward_proportion = SlashRewardFraction::get();
-		let mut consumed_weight: Weight = 0;
+		let mut consumed_weight = Weight::zero();
 		let mut add_db_reads_writes = |reads, writes| {
 			consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
 		};
@@ -157,6 +157,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = MaxLocks;
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = Balance;
 	type Event = Event;
 	type DustRemoval = ();
@@ -261,6 +262,7 @@ impl Config for Test {
 	type NextNewSession = Session;
 	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
 	type ElectionProvider = onchain::OnChainSequentialPhragmen<Self>;
+	type SortedListProvider = UseNominatorsMap<Self>;
 	type WeightInfo = ();
 }
 
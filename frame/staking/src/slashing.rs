@@ -143,6 +143,13 @@ impl SlashingSpans {
 		sp_std::iter::once(last).chain(prior)
 	}
 
+	/// The number of slashing spans, including the ongoing one.
+	///
+	/// This is the `num_slashing_spans` to pass to the calls removing the stash.
+	pub fn num_spans(&self) -> u32 {
+		(self.prior.len() as u32).saturating_add(1)
+	}
+
 	/// Yields the era index where the most recent non-zero slash occurred.
 	pub fn last_nonzero_slash(&self) -> EraIndex {
 		self.last_nonzero_slash
@@ -3373,7 +3373,7 @@ fn payout_stakers_handles_weight_refund() {
 		let half_max_nom_rewarded_weight
 			= <Test as Config>::WeightInfo::payout_stakers_alive_staked(half_max_nom_rewarded);
 		let zero_nom_payouts_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(0);
-		assert!(zero_nom_payouts_weight > 0);
+		assert!(!zero_nom_payouts_weight.is_zero());
 		assert!(half_max_nom_rewarded_weight > zero_nom_payouts_weight);
 		assert!(max_nom_rewarded_weight > half_max_nom_rewarded_weight);
 
@@ -3838,7 +3838,7 @@ fn on_finalize_weight_is_nonzero() {
 
 mod election_data_provider {
 	use super::*;
-	use frame_election_provider_support::ElectionDataProvider;
+	use frame_election_provider_support::{DataProviderBounds, ElectionDataProvider};
 
 	#[test]
 	fn targets_2sec_block() {
@@ -3876,10 +3876,9 @@ mod election_data_provider {
 	#[test]
 	fn voters_include_self_vote() {
 		ExtBuilder::default().nominate(false).build().execute_with(|| {
-			assert!(<Validators<Test>>::iter().map(|(x, _)| x).all(|v| Staking::voters(None)
-				.unwrap()
-				.0
-				.into_iter()
+			let (voters, _) = Staking::voters(DataProviderBounds::unbounded()).unwrap();
+			assert!(<Validators<Test>>::iter().map(|(x, _)| x).all(|v| voters
+				.iter()
 				.find(|(w, _, t)| { v == *w && t[0] == *w })
 				.is_some()))
 		})
@@ -3890,13 +3889,15 @@ mod election_data_provider {
 		ExtBuilder::default().build().execute_with(|| {
 			assert_eq!(Staking::nominators(101).unwrap().targets, vec![11, 21]);
 			assert_eq!(
-				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(None)
-					.unwrap()
-					.0
-					.iter()
-					.find(|x| x.0 == 101)
-					.unwrap()
-					.2,
+				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(
+					DataProviderBounds::unbounded()
+				)
+				.unwrap()
+				.0
+				.iter()
+				.find(|x| x.0 == 101)
+				.unwrap()
+				.2,
 				vec![11, 21]
 			);
 
@@ -3906,26 +3907,30 @@ mod election_data_provider {
 			// 11 is gone.
 			start_active_era(2);
 			assert_eq!(
-				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(None)
-					.unwrap()
-					.0
-					.iter()
-					.find(|x| x.0 == 101)
-					.unwrap()
-					.2,
+				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(
+					DataProviderBounds::unbounded()
+				)
+				.unwrap()
+				.0
+				.iter()
+				.find(|x| x.0 == 101)
+				.unwrap()
+				.2,
 				vec![21]
 			);
 
 			// resubmit and it is back
 			assert_ok!(Staking::nominate(Origin::signed(100), vec![11, 21]));
 			assert_eq!(
-				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(None)
-					.unwrap()
-					.0
-					.iter()
-					.find(|x| x.0 == 101)
-					.unwrap()
-					.2,
+				<Staking as ElectionDataProvider<AccountId, BlockNumber>>::voters(
+					DataProviderBounds::unbounded()
+				)
+				.unwrap()
+				.0
+				.iter()
+				.find(|x| x.0 == 101)
+				.unwrap()
+				.2,
 				vec![11, 21]
 			);
 		})
@@ -3934,8 +3939,56 @@ mod election_data_provider {
 	#[test]
 	fn respects_len_limits() {
 		ExtBuilder::default().build().execute_with(|| {
-			assert_eq!(Staking::voters(Some(1)).unwrap_err(), "Voter snapshot too big");
-			assert_eq!(Staking::targets(Some(1)).unwrap_err(), "Target snapshot too big");
+			let (all_voters, _) = Staking::voters(DataProviderBounds::unbounded()).unwrap();
+			assert!(all_voters.len() > 1);
+
+			// the first voters are taken.
+			let by_count = DataProviderBounds { count: Some(1.into()), size: None };
+			let (voters, _) = Staking::voters(by_count).unwrap();
+			assert_eq!(voters, all_voters[..1].to_vec());
+			assert_eq!(Staking::targets(by_count).unwrap_err(), "Target snapshot too big");
+		});
+	}
+
+	#[test]
+	fn voters_are_taken_in_sorted_list_order() {
+		ExtBuilder::default().build().execute_with(|| {
+			let validator_count = <Validators<Test>>::iter().count();
+			let nominators = <Test as Config>::SortedListProvider::iter().collect::<Vec<_>>();
+			assert!(!nominators.is_empty());
+
+			// all the validators self vote and the best nominator fit.
+			let bounds = DataProviderBounds {
+				count: Some((validator_count as u32 + 1).into()),
+				size: None,
+			};
+			let (voters, _) = Staking::voters(bounds).unwrap();
+			assert_eq!(voters.len(), validator_count + 1);
+			assert_eq!(voters.last().unwrap().0, nominators[0]);
+		});
+	}
+
+	#[test]
+	fn respects_size_limits() {
+		ExtBuilder::default().build().execute_with(|| {
+			let (voters, _) = Staking::voters(DataProviderBounds::unbounded()).unwrap();
+			let (targets, _) = Staking::targets(DataProviderBounds::unbounded()).unwrap();
+
+			// exactly fitting bounds are fine.
+			let exact = |size: usize| DataProviderBounds {
+				count: None,
+				size: Some((size as u32).into()),
+			};
+			assert!(Staking::voters(exact(voters.encoded_size())).is_ok());
+			assert!(Staking::targets(exact(targets.encoded_size())).is_ok());
+
+			// one byte less leaves the last voter out.
+			let (fewer_voters, _) = Staking::voters(exact(voters.encoded_size() - 1)).unwrap();
+			assert_eq!(fewer_voters, voters[..voters.len() - 1].to_vec());
+			assert_eq!(
+				Staking::targets(exact(targets.encoded_size() - 1)).unwrap_err(),
+				"Target snapshot too big",
+			);
 		});
 	}
 
@@ -98,7 +98,7 @@ use sp_runtime::{DispatchResult, traits::StaticLookup};
 
 use frame_support::{
 	weights::GetDispatchInfo,
-	traits::UnfilteredDispatchable,
+	traits::{Contains, IsSubType, UnfilteredDispatchable},
 };
 
 #[cfg(test)]
@@ -142,7 +142,10 @@ pub mod pallet {
 		/// # </weight>
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(dispatch_info.weight.saturating_add(10_000), dispatch_info.class)
+			(
+				dispatch_info.weight.saturating_add(Weight::from_ref_time(10_000)),
+				dispatch_info.class,
+			)
 		})]
 		pub(crate) fn sudo(
 			origin: OriginFor<T>,
@@ -224,7 +227,7 @@ pub mod pallet {
 			let dispatch_info = call.get_dispatch_info();
 			(
 				dispatch_info.weight
-					.saturating_add(10_000)
+					.saturating_add(Weight::from_ref_time(10_000))
 					// AccountData for inner call origin accountdata.
 					.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
 				dispatch_info.class,
@@ -295,3 +298,24 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Contains the calls of `Inner` dispatched by the sudo key with `sudo` or
+/// `sudo_unchecked_weight`.
+///
+/// This can be used to let the sudo key make calls exempt from some filter, e.g. to upgrade the
+/// runtime while multi-block migrations are ongoing.
+pub struct SudoCalls<T, Inner>(sp_std::marker::PhantomData<(T, Inner)>);
+
+impl<T, Inner> Contains<<T as Config>::Call> for SudoCalls<T, Inner> where
+	T: Config,
+	<T as Config>::Call: IsSubType<Call<T>>,
+	Inner: Contains<<T as Config>::Call>,
+{
+	fn contains(call: &<T as Config>::Call) -> bool {
+		match call.is_sub_type() {
+			Some(Call::sudo(inner)) | Some(Call::sudo_unchecked_weight(inner, _)) =>
+				Inner::contains(inner),
+			_ => false,
+		}
+	}
+}
@@ -44,11 +44,11 @@ pub mod logger {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(*weight)]
+		#[pallet::weight(Weight::from_ref_time(*weight))]
 		pub(crate) fn privileged_i32_log(
 			origin: OriginFor<T>,
 			i: i32,
-			weight: Weight
+			weight: u64
 		) -> DispatchResultWithPostInfo {
 			// Ensure that the `origin` is `Root`.
 			ensure_root(origin)?;
@@ -57,11 +57,11 @@ pub mod logger {
 			Ok(().into())
 		}
 
-		#[pallet::weight(*weight)]
+		#[pallet::weight(Weight::from_ref_time(*weight))]
 		pub(crate) fn non_privileged_log(
 			origin: OriginFor<T>,
 			i: i32,
-			weight: Weight
+			weight: u64
 		) -> DispatchResultWithPostInfo {
 			// Ensure that the `origin` is some signed account.
 			let sender = ensure_signed(origin)?;
@@ -76,8 +76,8 @@ pub mod logger {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	#[pallet::metadata(T::AccountId = "AccountId")]
 	pub enum Event<T: Config> {
-		AppendI32(i32, Weight),
-		AppendI32AndAccount(T::AccountId, i32, Weight),
+		AppendI32(i32, u64),
+		AppendI32AndAccount(T::AccountId, i32, u64),
 	}
 
 	#[pallet::storage]
@@ -115,7 +115,9 @@ frame_support::construct_runtime!(
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
-	pub BlockWeights: limits::BlockWeights = limits::BlockWeights::simple_max(1024);
+	pub BlockWeights: limits::BlockWeights = limits::BlockWeights::simple_max(
+		frame_support::weights::Weight::from_ref_time(1024),
+	);
 }
 
 pub struct BlockEverything;
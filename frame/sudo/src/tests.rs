@@ -22,7 +22,7 @@ use mock::{
 	Sudo, SudoCall, Origin, Call, Test, new_test_ext, LoggerCall, Logger, System,
 	Event as TestEvent,
 };
-use frame_support::{assert_ok, assert_noop};
+use frame_support::{assert_ok, assert_noop, weights::Weight};
 
 #[test]
 fn test_setup_works() {
@@ -67,13 +67,17 @@ fn sudo_unchecked_weight_basics() {
 	new_test_ext(1).execute_with(|| {
 		// A privileged function should work when `sudo` is passed the root `key` as origin.
 		let call = Box::new(Call::Logger(LoggerCall::privileged_i32_log(42, 1_000)));
-		assert_ok!(Sudo::sudo_unchecked_weight(Origin::signed(1), call, 1_000));
+		assert_ok!(Sudo::sudo_unchecked_weight(
+			Origin::signed(1),
+			call,
+			Weight::from_ref_time(1_000),
+		));
 		assert_eq!(Logger::i32_log(), vec![42i32]);
 
 		// A privileged function should not work when called with a non-root `key`.
 		let call = Box::new(Call::Logger(LoggerCall::privileged_i32_log(42, 1_000)));
 		assert_noop!(
-			Sudo::sudo_unchecked_weight(Origin::signed(2), call, 1_000),
+			Sudo::sudo_unchecked_weight(Origin::signed(2), call, Weight::from_ref_time(1_000)),
 			Error::<Test>::RequireSudo,
 		);
 		// `I32Log` is unchanged after unsuccessful call.
@@ -81,9 +85,10 @@ fn sudo_unchecked_weight_basics() {
 
 		// Controls the dispatched weight.
 		let call = Box::new(Call::Logger(LoggerCall::privileged_i32_log(42, 1)));
-		let sudo_unchecked_weight_call = SudoCall::sudo_unchecked_weight(call, 1_000);
+		let sudo_unchecked_weight_call =
+			SudoCall::sudo_unchecked_weight(call, Weight::from_ref_time(1_000));
 		let info = sudo_unchecked_weight_call.get_dispatch_info();
-		assert_eq!(info.weight, 1_000);
+		assert_eq!(info.weight, Weight::from_ref_time(1_000));
 	});
 }
 
@@ -95,7 +100,11 @@ fn sudo_unchecked_weight_emits_events_correctly() {
 
 		// Should emit event to indicate success when called with the root `key` and `call` is `Ok`.
 		let call = Box::new(Call::Logger(LoggerCall::privileged_i32_log(42, 1)));
-		assert_ok!(Sudo::sudo_unchecked_weight(Origin::signed(1), call, 1_000));
+		assert_ok!(Sudo::sudo_unchecked_weight(
+			Origin::signed(1),
+			call,
+			Weight::from_ref_time(1_000),
+		));
 		System::assert_has_event(TestEvent::Sudo(Event::Sudid(Ok(()))));
 	})
 }
@@ -163,3 +172,27 @@ fn sudo_as_emits_events_correctly() {
 		System::assert_has_event(TestEvent::Sudo(Event::SudoAsDone(Ok(()))));
 	});
 }
+
+#[test]
+fn sudo_calls_contains_the_inner_calls_dispatched_by_sudo() {
+	frame_support::match_type! {
+		pub type PrivilegedLogs: impl Contains<Call> = {
+			Call::Logger(LoggerCall::privileged_i32_log(..))
+		};
+	}
+	type SudoPrivilegedLogs = SudoCalls<Test, PrivilegedLogs>;
+
+	let privileged = Box::new(Call::Logger(LoggerCall::privileged_i32_log(42, 1_000)));
+	let unprivileged = Box::new(Call::Logger(LoggerCall::non_privileged_log(42, 1_000)));
+
+	assert!(SudoPrivilegedLogs::contains(&Call::Sudo(SudoCall::sudo(privileged.clone()))));
+	assert!(SudoPrivilegedLogs::contains(
+		&Call::Sudo(SudoCall::sudo_unchecked_weight(
+			privileged.clone(),
+			Weight::from_ref_time(1_000),
+		)),
+	));
+	assert!(!SudoPrivilegedLogs::contains(&Call::Sudo(SudoCall::sudo(unprivileged))));
+	assert!(!SudoPrivilegedLogs::contains(&Call::Sudo(SudoCall::sudo_as(2, privileged.clone()))));
+	assert!(!SudoPrivilegedLogs::contains(&privileged));
+}
@@ -25,7 +25,7 @@ pub fn expand_outer_config(
 	runtime: &Ident,
 	pallet_decls: &[Pallet],
 	scrate: &TokenStream,
-) -> TokenStream {
+) -> syn::Result<TokenStream> {
 	let mut types = TokenStream::new();
 	let mut fields = TokenStream::new();
 	let mut build_storage_calls = TokenStream::new();
@@ -33,17 +33,42 @@ pub fn expand_outer_config(
 	for decl in pallet_decls {
 		if let Some(pallet_entry) = decl.find_part("Config") {
 			let config = format_ident!("{}Config", decl.name);
-			let pallet_name = &decl.name.to_string().to_snake_case();
-			let field_name = &Ident::new(pallet_name, decl.name.span());
+			let field_name = &decl.genesis_field();
 			let part_is_generic = !pallet_entry.generics.params.is_empty();
 
+			if decl.instance.is_some() && !part_is_generic {
+				let msg = format!(
+					"Instantiable pallet with no generic `Config` cannot \
+					 be constructed: pallet `{}` must have generic `Config`",
+					decl.name,
+				);
+				return Err(syn::Error::new(decl.name.span(), msg));
+			}
+
 			types.extend(expand_config_types(runtime, decl, &config, part_is_generic));
-			fields.extend(quote!(pub #field_name: #config,));
+			let deserialize_fn = format_ident!("__genesis_config_deserialize_{}", field_name);
+			let deserialize_fn_name = deserialize_fn.to_string();
+			let json_name = field_name.to_string().to_camel_case();
+			types.extend(quote!{
+				#[cfg(any(feature = "std", test))]
+				fn #deserialize_fn<'de, D: #scrate::serde::Deserializer<'de>>(
+					deserializer: D,
+				) -> std::result::Result<#config, D::Error> {
+					#scrate::genesis_config::deserialize_pallet_genesis_config(
+						deserializer,
+						#json_name,
+					)
+				}
+			});
+			fields.extend(quote!(
+				#[serde(deserialize_with = #deserialize_fn_name)]
+				pub #field_name: #config,
+			));
 			build_storage_calls.extend(expand_config_build_storage_call(scrate, runtime, decl, &field_name));
 		}
 	}
 
-	quote!{
+	Ok(quote!{
 		#types
 
 		#[cfg(any(feature = "std", test))]
@@ -53,12 +78,16 @@ pub fn expand_outer_config(
 		#[serde(rename_all = "camelCase")]
 		#[serde(deny_unknown_fields)]
 		#[serde(crate = "__genesis_config_serde_import__")]
-		pub struct GenesisConfig {
+		pub struct RuntimeGenesisConfig {
 			#fields
 		}
 
+		/// The genesis config of the runtime, under its former name.
 		#[cfg(any(feature = "std", test))]
-		impl #scrate::sp_runtime::BuildStorage for GenesisConfig {
+		pub type GenesisConfig = RuntimeGenesisConfig;
+
+		#[cfg(any(feature = "std", test))]
+		impl #scrate::sp_runtime::BuildStorage for RuntimeGenesisConfig {
 			fn assimilate_storage(
 				&self,
 				storage: &mut #scrate::sp_runtime::Storage,
@@ -72,7 +101,7 @@ pub fn expand_outer_config(
 				Ok(())
 			}
 		}
-	}
+	})
 }
 
 fn expand_config_types(
@@ -84,7 +113,7 @@ fn expand_config_types(
 	let path = &decl.path;
 
 	match (decl.instance.as_ref(), part_is_generic) {
-		(Some(inst), true) => quote!{
+		(Some(inst), _) => quote!{
 			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig<#runtime, #path::#inst>;
 		},
@@ -92,7 +121,7 @@ fn expand_config_types(
 			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig<#runtime>;
 		},
-		(_, false) => quote!{
+		(None, false) => quote!{
 			#[cfg(any(feature = "std", test))]
 			pub type #config = #path::GenesisConfig;
 		},
@@ -112,8 +141,11 @@ fn expand_config_build_storage_call(
 		quote!(#path::__InherentHiddenInstance)
 	};
 
+	let error_prefix = format!("Failed to build the genesis storage of `{}`: ", decl.name);
+
 	quote!{
 		#scrate::sp_runtime::BuildModuleGenesisStorage::
-			<#runtime, #instance>::build_module_genesis_storage(&self.#field_name, storage)?;
+			<#runtime, #instance>::build_module_genesis_storage(&self.#field_name, storage)
+			.map_err(|e| format!("{}{}", #error_prefix, e))?;
 	}
 }
@@ -20,6 +20,7 @@ mod parse;
 
 use frame_support_procedural_tools::syn_ext as ext;
 use frame_support_procedural_tools::{generate_crate_access, generate_hidden_includes};
+use inflector::Inflector;
 use parse::{PalletDeclaration, PalletPart, PalletPath, RuntimeDefinition, WhereSection};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
@@ -34,6 +35,8 @@ const SYSTEM_PALLET_NAME: &str = "System";
 #[derive(Debug, Clone)]
 pub struct Pallet {
 	pub name: Ident,
+	pub renamed_from: Vec<Ident>,
+	pub genesis_field: Option<Ident>,
 	pub index: u8,
 	pub path: PalletPath,
 	pub instance: Option<Ident>,
@@ -55,6 +58,14 @@ impl Pallet {
 	fn exists_part(&self, name: &str) -> bool {
 		self.find_part(name).is_some()
 	}
+
+	/// The name of the field of the pallet in the genesis config of the runtime, the snake case
+	/// name of the pallet unless overridden with `#[genesis_field(..)]`.
+	fn genesis_field(&self) -> Ident {
+		self.genesis_field.clone().unwrap_or_else(|| {
+			Ident::new(&self.name.to_string().to_snake_case(), self.name.span())
+		})
+	}
 }
 
 /// Convert from the parsed pallet to their final information.
@@ -65,7 +76,7 @@ fn complete_pallets(decl: impl Iterator<Item = PalletDeclaration>) -> syn::Resul
 	let mut last_index: Option<u8> = None;
 	let mut names = HashMap::new();
 
-	decl
+	let pallets = decl
 		.map(|pallet| {
 			let final_index = match pallet.index {
 				Some(i) => i,
@@ -100,13 +111,61 @@ fn complete_pallets(decl: impl Iterator<Item = PalletDeclaration>) -> syn::Resul
 
 			Ok(Pallet {
 				name: pallet.name,
+				renamed_from: pallet.renamed_from,
+				genesis_field: pallet.genesis_field,
 				index: final_index,
 				path: pallet.path,
 				instance: pallet.instance,
 				pallet_parts: pallet.pallet_parts,
 			})
 		})
-		.collect()
+		.collect::<syn::Result<Vec<_>>>()?;
+
+	// The storage of a renamed pallet is moved from its previous names, they must not be the name
+	// of another pallet.
+	for pallet in &pallets {
+		for old_name in &pallet.renamed_from {
+			if names.contains_key(old_name) {
+				let msg = format!(
+					"Pallet {} is renamed from {} which is the name of a pallet of the runtime",
+					pallet.name,
+					old_name,
+				);
+				return Err(syn::Error::new(old_name.span(), msg));
+			}
+		}
+	}
+
+	// Each pallet with a genesis config is a field of the genesis config of the runtime.
+	let mut genesis_fields = HashMap::new();
+	for pallet in &pallets {
+		if !pallet.exists_part("Config") {
+			if let Some(field) = &pallet.genesis_field {
+				let msg = format!(
+					"Pallet {} has a genesis field but no `Config` part",
+					pallet.name,
+				);
+				return Err(syn::Error::new(field.span(), msg));
+			}
+			continue
+		}
+
+		let field = pallet.genesis_field();
+		if let Some(used_pallet) = genesis_fields.insert(field.to_string(), pallet.name.clone()) {
+			let msg = format!(
+				"Pallets {} and {} have the same genesis field {}, \
+				 one of them must be renamed with `#[genesis_field(..)]`",
+				used_pallet,
+				pallet.name,
+				field,
+			);
+			let mut err = syn::Error::new(used_pallet.span(), &msg);
+			err.combine(syn::Error::new(pallet.name.span(), msg));
+			return Err(err);
+		}
+	}
+
+	Ok(pallets)
 }
 
 pub fn construct_runtime(input: TokenStream) -> TokenStream {
@@ -147,7 +206,7 @@ fn construct_runtime_parsed(definition: RuntimeDefinition) -> Result<TokenStream
 
 	let dispatch = decl_outer_dispatch(&name, pallets.iter(), &scrate);
 	let metadata = expand::expand_runtime_metadata(&name, &pallets, &scrate, &unchecked_extrinsic);
-	let outer_config = expand::expand_outer_config(&name, &pallets, &scrate);
+	let outer_config = expand::expand_outer_config(&name, &pallets, &scrate)?;
 	let inherent = decl_outer_inherent(
 		&name,
 		&block,
@@ -325,6 +384,12 @@ fn decl_pallet_runtime_setup(
 	let names = pallet_declarations.iter().map(|d| &d.name);
 	let names2 = pallet_declarations.iter().map(|d| &d.name);
 	let name_strings = pallet_declarations.iter().map(|d| d.name.to_string());
+	let renamed_pallets = pallet_declarations.iter().filter(|d| !d.renamed_from.is_empty());
+	let names3 = renamed_pallets.clone().map(|d| &d.name);
+	let old_name_strings = renamed_pallets.map(|d| {
+		let old_names = d.renamed_from.iter().map(|old_name| old_name.to_string());
+		quote!( &[ #( #old_names ),* ] )
+	});
 	let indices = pallet_declarations.iter()
 		.map(|pallet| pallet.index as usize);
 
@@ -355,6 +420,17 @@ fn decl_pallet_runtime_setup(
 
 				None
 			}
+
+			fn renamed_from<P: 'static>() -> &'static [&'static str] {
+				let type_id = #scrate::sp_std::any::TypeId::of::<P>();
+				#(
+					if type_id == #scrate::sp_std::any::TypeId::of::<#names3>() {
+						return #old_name_strings
+					}
+				)*
+
+				&[]
+			}
 		}
 	)
 }
@@ -367,7 +443,25 @@ fn decl_integrity_test(scrate: &TokenStream2) -> TokenStream2 {
 
 			#[test]
 			pub fn runtime_integrity_tests() {
-				<AllPallets as #scrate::traits::IntegrityTest>::integrity_test();
+				<AllPalletsWithSystem as #scrate::traits::IntegrityTest>::integrity_test();
+
+				// The migrations executed by a runtime upgrade must be no-ops on a chain which is
+				// already at the current storage versions, i.e. they must check the on-chain
+				// storage version before migrating and writing a new one.
+				#scrate::sp_io::TestExternalities::new_empty().execute_with(|| {
+					<AllPalletsWithSystem as #scrate::traits::OnGenesis>::on_genesis();
+					<
+						AllPalletsWithSystem as #scrate::traits::CheckStorageVersion
+					>::check_storage_version()
+						.expect("The genesis must set the storage version of every pallet");
+					<
+						AllPalletsWithSystem as #scrate::traits::OnRuntimeUpgrade
+					>::on_runtime_upgrade();
+					<
+						AllPalletsWithSystem as #scrate::traits::CheckStorageVersion
+					>::check_storage_version()
+						.expect("A migration changed the storage version of an up-to-date pallet");
+				});
 			}
 		}
 	)
@@ -38,6 +38,8 @@ mod keyword {
 	syn::custom_keyword!(Origin);
 	syn::custom_keyword!(Inherent);
 	syn::custom_keyword!(ValidateUnsigned);
+	syn::custom_keyword!(renamed_from);
+	syn::custom_keyword!(genesis_field);
 }
 
 #[derive(Debug)]
@@ -154,6 +156,11 @@ impl Parse for WhereDefinition {
 #[derive(Debug, Clone)]
 pub struct PalletDeclaration {
 	pub name: Ident,
+	/// The previous names of the pallet (e.g. `#[renamed_from(OldName)] MyPallet ...`)
+	pub renamed_from: Vec<Ident>,
+	/// The optional name of the field of the pallet in the genesis config of the runtime (e.g.
+	/// `#[genesis_field(my_field)] MyPallet ...`)
+	pub genesis_field: Option<Ident>,
 	/// Optional fixed index (e.g. `MyPallet ...  = 3,`)
 	pub index: Option<u8>,
 	pub path: PalletPath,
@@ -163,6 +170,36 @@ pub struct PalletDeclaration {
 
 impl Parse for PalletDeclaration {
 	fn parse(input: ParseStream) -> Result<Self> {
+		let mut renamed_from = None;
+		let mut genesis_field = None;
+		while input.peek(Token![#]) {
+			input.parse::<Token![#]>()?;
+			let attr;
+			syn::bracketed!(attr in input);
+			let lookahead = attr.lookahead1();
+			if lookahead.peek(keyword::renamed_from) {
+				let keyword = attr.parse::<keyword::renamed_from>()?;
+				let names;
+				syn::parenthesized!(names in attr);
+				let names = names.parse_terminated::<Ident, Token![,]>(Ident::parse)?;
+				if renamed_from.replace(names.into_iter().collect::<Vec<_>>()).is_some() {
+					let msg = "`renamed_from` was already declared before";
+					return Err(Error::new(keyword.span, msg));
+				}
+			} else if lookahead.peek(keyword::genesis_field) {
+				let keyword = attr.parse::<keyword::genesis_field>()?;
+				let field;
+				syn::parenthesized!(field in attr);
+				if genesis_field.replace(field.parse::<Ident>()?).is_some() {
+					let msg = "`genesis_field` was already declared before";
+					return Err(Error::new(keyword.span, msg));
+				}
+			} else {
+				return Err(lookahead.error());
+			}
+		}
+		let renamed_from = renamed_from.unwrap_or_default();
+
 		let name = input.parse()?;
 		let _: Token![:] = input.parse()?;
 		let path = input.parse()?;
@@ -189,6 +226,8 @@ impl Parse for PalletDeclaration {
 
 		let parsed = Self {
 			name,
+			renamed_from,
+			genesis_field,
 			path,
 			instance,
 			pallet_parts,
@@ -28,6 +28,7 @@ mod debug_no_bound;
 mod clone_no_bound;
 mod partial_eq_no_bound;
 mod default_no_bound;
+mod pallet_error;
 mod key_prefix;
 
 pub(crate) use storage::INHERENT_INSTANCE_NAME;
@@ -203,6 +204,10 @@ use proc_macro::TokenStream;
 /// );
 /// ```
 ///
+/// When deserializing the struct, e.g. from a chain spec, unknown fields are rejected, and the
+/// fields which have a declared default value (`config(foo): u32 = 5` or `Foo config(): u32 = 5`)
+/// can be omitted.
+///
 /// ### Module with Instances
 ///
 /// The `decl_storage!` macro supports building modules with instances with the following syntax
@@ -311,6 +316,28 @@ pub fn decl_storage(input: TokenStream) -> TokenStream {
 /// module4 .., // Here module4 is given index 1
 /// ```
 ///
+/// The optional attribute `#[renamed_from(OldName1, OldName2)]` before a module records its
+/// previous names, they are given by `PalletInfo::renamed_from`. It allows to rename a module
+/// without orphaning its storage: the migration `frame_support::migrations::MoveRenamedPallet`
+/// moves the storage from the prefixes of the previous names to the prefix of the new name.
+/// ```nocompile
+/// #[renamed_from(OldName)]
+/// NewName: pallet_example::{Pallet, Call, Storage},
+/// ```
+///
+/// # Genesis config
+///
+/// The macro generates `RuntimeGenesisConfig`, also available as `GenesisConfig`, with a field
+/// for each module with a `Config` part, named after the module in snake case, e.g. `system` for
+/// `System`. The optional attribute `#[genesis_field(name)]` before a module overrides the name
+/// of its field, which must be unique. Its `BuildStorage` implementation builds the genesis
+/// storage of each module in order, and returns the error of the first module failing to, along
+/// with the name of the module.
+/// ```nocompile
+/// #[genesis_field(council)]
+/// CouncilCollective: pallet_collective::<Instance1>::{Pallet, Call, Storage, Config<T>},
+/// ```
+///
 /// # Note
 ///
 /// The population of the genesis storage depends on the order of modules. So, if one of your
@@ -432,6 +459,12 @@ pub fn derive_default_no_bound(input: TokenStream) -> TokenStream {
 	default_no_bound::derive_default_no_bound(input)
 }
 
+/// Derive `PalletError`. Docs are at `frame_support::PalletError`.
+#[proc_macro_derive(PalletError)]
+pub fn derive_pallet_error(input: TokenStream) -> TokenStream {
+	pallet_error::derive_pallet_error(input)
+}
+
 #[proc_macro_attribute]
 pub fn require_transactional(attr: TokenStream, input: TokenStream) -> TokenStream {
 	transactional::require_transactional(attr, input).unwrap_or_else(|e| e.to_compile_error().into())
@@ -0,0 +1,144 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export of the dispatchable ABI of a pallet as JSON, behind the `abi-json` feature.
+//!
+//! The JSON is given by the constant `CALL_ABI_JSON` of the pallet module, e.g. a build script or
+//! a small binary of the runtime can write it to a file. It contains:
+//!
+//! ```json
+//! {
+//!   "crate": "pallet-example",
+//!   "pallet": "pallet",
+//!   "call_type": "Call",
+//!   "calls": [
+//!     {
+//!       "name": "transfer",
+//!       "index": 0,
+//!       "args": [{ "name": "dest", "type": "T::AccountId", "compact": false }],
+//!       "docs": [" Transfer some balance."]
+//!     }
+//!   ]
+//! }
+//! ```
+
+use crate::pallet::Def;
+use frame_support_procedural_tools::clean_type_string;
+use quote::ToTokens;
+
+/// Escape `s` and quote it as a JSON string.
+fn json_string(s: &str) -> String {
+	let mut out = String::with_capacity(s.len() + 2);
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+/// Render the docs literals as a JSON array of strings.
+fn json_docs(docs: &[syn::Lit]) -> String {
+	let docs = docs.iter()
+		.filter_map(|lit| match lit {
+			syn::Lit::Str(lit) => Some(json_string(&lit.value())),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+
+	format!("[{}]", docs.join(", "))
+}
+
+/// Render the JSON description of the dispatchables of `def`.
+fn call_abi_json(def: &Def, crate_name: &str) -> String {
+	let calls = def.call.as_ref()
+		.map(|call| call.methods.iter()
+			.enumerate()
+			.map(|(index, method)| {
+				let args = method.args.iter()
+					.map(|(is_compact, name, type_)| format!(
+						"{{ \"name\": {}, \"type\": {}, \"compact\": {} }}",
+						json_string(&name.to_string()),
+						json_string(&clean_type_string(&type_.to_token_stream().to_string())),
+						is_compact,
+					))
+					.collect::<Vec<_>>();
+
+				format!(
+					"    {{\n      \"name\": {},\n      \"index\": {},\n      \"args\": [{}],\n      \
+					\"docs\": {}\n    }}",
+					json_string(&method.name.to_string()),
+					index,
+					args.join(", "),
+					json_docs(&method.docs),
+				)
+			})
+			.collect::<Vec<_>>()
+		)
+		.unwrap_or_default();
+
+	format!(
+		"{{\n  \"crate\": {},\n  \"pallet\": {},\n  \"call_type\": {},\n  \"calls\": [\n{}\n  \
+		]\n}}\n",
+		json_string(crate_name),
+		json_string(&def.item.ident.to_string()),
+		json_string(&def.call_ident(def.item.ident.span()).to_string()),
+		calls.join(",\n"),
+	)
+}
+
+/// * Add the constant `CALL_ABI_JSON` containing the JSON description of the dispatchables.
+pub fn expand_call_abi(def: &Def) -> proc_macro2::TokenStream {
+	let crate_name = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "unknown".into());
+	let json = call_abi_json(def, &crate_name);
+
+	quote::quote_spanned!(def.item.ident.span() =>
+		/// The JSON description of the dispatchables of this pallet: their name, index, arguments
+		/// with their types, and docs.
+		pub const CALL_ABI_JSON: &str = #json;
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn json_strings_are_escaped() {
+		assert_eq!(json_string("transfer"), r#""transfer""#);
+		assert_eq!(json_string("a \"b\"\\c"), r#""a \"b\"\\c""#);
+		assert_eq!(json_string("line\n\ttab\u{1}"), r#""line\n\ttab\u0001""#);
+	}
+
+	#[test]
+	fn docs_are_rendered_as_an_array() {
+		let docs: Vec<syn::Lit> = vec![
+			syn::parse_quote!(" Transfer some balance."),
+			syn::parse_quote!(" # <weight>"),
+		];
+		assert_eq!(json_docs(&docs), r#"[" Transfer some balance.", " # <weight>"]"#);
+		assert_eq!(json_docs(&[]), "[]");
+	}
+}
@@ -20,6 +20,10 @@ use frame_support_procedural_tools::clean_type_string;
 use syn::spanned::Spanned;
 
 /// * Generate enum call and implement various trait on it.
+/// * Generate a constructor `{fn_name}_call` on the enum call for each dispatchable.
+/// * Generate a predicate `is_{fn_name}` on the enum call for each dispatchable.
+/// * Generate a compile error instead of the helpers above if their names collide.
+/// * Generate the type alias `CallOf`.
 /// * Implement Callable and call_function on `Pallet`
 pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	let (span, where_clause, methods, docs) = match def.call.as_ref() {
@@ -38,11 +42,45 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	let type_impl_gen = &def.type_impl_generics(span);
 	let type_decl_bounded_gen = &def.type_decl_bounded_generics(span);
 	let type_use_gen = &def.type_use_generics(span);
-	let call_ident = syn::Ident::new("Call", span);
+	let type_decl_gen = &def.type_decl_generics(span);
+	let call_ident = def.call_ident(span);
 	let pallet_ident = &def.pallet_struct.pallet;
 
 	let fn_name = methods.iter().map(|method| &method.name).collect::<Vec<_>>();
 
+	let fn_constructor_name = methods.iter()
+		.map(|method| syn::Ident::new(&format!("{}_call", method.name), method.name.span()))
+		.collect::<Vec<_>>();
+
+	let fn_constructor_doc = methods.iter()
+		.map(|method| format!("Create a call with the variant `{}`.", method.name))
+		.collect::<Vec<_>>();
+
+	let fn_is_name = methods.iter()
+		.map(|method| syn::Ident::new(&format!("is_{}", method.name), method.name.span()))
+		.collect::<Vec<_>>();
+
+	let fn_is_doc = methods.iter()
+		.map(|method| format!("Return `true` if this is a call with the variant `{}`.", method.name))
+		.collect::<Vec<_>>();
+
+	// The helpers share the namespace of the variants of the enum call.
+	let encoded_ident = syn::Ident::new("encoded", span);
+	let mut helper_names = fn_name.iter().map(|name| name.to_string())
+		.collect::<std::collections::HashSet<_>>();
+	let helpers_error = fn_constructor_name.iter()
+		.chain(fn_is_name.iter())
+		.chain(std::iter::once(&encoded_ident))
+		.find(|name| !helper_names.insert(name.to_string()))
+		.map(|name| {
+			let msg = format!(
+				"Invalid pallet::call, the generated call helper `{}` collides with a dispatchable \
+				or another generated helper of the same name, consider renaming the dispatchable",
+				name,
+			);
+			syn::Error::new(name.span(), msg)
+		});
+
 	let fn_weight = methods.iter().map(|method| &method.weight);
 
 	let fn_doc = methods.iter().map(|method| &method.docs).collect::<Vec<_>>();
@@ -78,6 +116,25 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				clean_type_string(&final_type.to_string())
 			})
 			.collect::<Vec<_>>()
+	}).collect::<Vec<_>>();
+
+	let args_value = methods.iter().map(|method| {
+		method.args.iter()
+			.map(|(is_compact, name, type_)| {
+				if *is_compact {
+					quote::quote_spanned!(type_.span() =>
+						#frame_support::codec::Encode::encode(
+							&<
+								<#type_ as #frame_support::codec::HasCompact>::Type
+									as #frame_support::codec::EncodeAsRef<'_, #type_>
+							>::RefType::from(#name)
+						)
+					)
+				} else {
+					quote::quote!(#frame_support::codec::Encode::encode(#name))
+				}
+			})
+			.collect::<Vec<_>>()
 	});
 
 	let default_docs = [syn::parse_quote!(
@@ -89,6 +146,37 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		&docs[..]
 	};
 
+	let call_helpers = match helpers_error {
+		Some(error) => error.to_compile_error(),
+		None => quote::quote_spanned!(span =>
+			impl<#type_impl_gen> #call_ident<#type_use_gen> #where_clause {
+				#(
+					#[doc = #fn_constructor_doc]
+					#[allow(dead_code)]
+					pub fn #fn_constructor_name( #( #args_name: #args_type ),* ) -> Self {
+						Self::#fn_name( #( #args_name ),* )
+					}
+				)*
+
+				#(
+					#[doc = #fn_is_doc]
+					#[allow(dead_code)]
+					pub fn #fn_is_name(&self) -> bool {
+						matches!(self, Self::#fn_name(..))
+					}
+				)*
+
+				/// Return the SCALE encoded bytes of this call.
+				///
+				/// Note that these are the bytes of the pallet call, not of the outer runtime call.
+				#[allow(dead_code)]
+				pub fn #encoded_ident(&self) -> #frame_support::sp_std::vec::Vec<u8> {
+					#frame_support::codec::Encode::encode(self)
+				}
+			}
+		),
+	};
+
 	quote::quote_spanned!(span =>
 		#( #[doc = #docs] )*
 		#[derive(
@@ -112,6 +200,14 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			#( #( #[doc = #fn_doc] )* #fn_name( #( #args_compact_attr #args_type ),* ), )*
 		}
 
+		/// Type alias to the call enum, to construct calls of this pallet without naming it.
+		///
+		/// Generated by `pallet` attribute macro.
+		#[allow(dead_code)]
+		pub type CallOf<#type_decl_gen> = #call_ident<#type_use_gen>;
+
+		#call_helpers
+
 		impl<#type_impl_gen> #frame_support::dispatch::GetDispatchInfo
 			for #call_ident<#type_use_gen>
 			#where_clause
@@ -163,6 +259,28 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		impl<#type_impl_gen> #frame_support::dispatch::GetCallArguments
+			for #call_ident<#type_use_gen>
+			#where_clause
+		{
+			fn get_call_arguments(
+				&self,
+			) -> #frame_support::sp_std::vec::Vec<#frame_support::dispatch::CallArgument> {
+				match *self {
+					#(
+						Self::#fn_name( #( ref #args_name, )* ) => #frame_support::sp_std::vec![ #(
+							#frame_support::dispatch::CallArgument {
+								name: stringify!(#args_name),
+								ty: #args_metadata_type,
+								value: #args_value,
+							},
+						)* ],
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+		}
+
 		impl<#type_impl_gen> #frame_support::traits::UnfilteredDispatchable
 			for #call_ident<#type_use_gen>
 			#where_clause
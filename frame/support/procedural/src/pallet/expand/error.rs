@@ -19,6 +19,7 @@ use crate::pallet::{Def, parse::helper::get_doc_literals};
 
 /// * impl various trait on Error
 /// * impl ModuleErrorMetadata for Error
+/// * compute the maximum encoded size of Error
 pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 	let error = if let Some(error) = &def.error {
 		error
@@ -42,18 +43,49 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 	);
 
 	let as_u8_matches = error.variants.iter().enumerate()
-		.map(|(i, (variant, _))| {
-			quote::quote_spanned!(error.attr_span => Self::#variant => #i as u8,)
+		.map(|(i, (variant, _, _))| {
+			quote::quote_spanned!(error.attr_span => Self::#variant { .. } => #i as u8,)
 		});
 
 	let as_str_matches = error.variants.iter()
-		.map(|(variant, _)| {
+		.map(|(variant, _, _)| {
 			let variant_str = format!("{}", variant);
-			quote::quote_spanned!(error.attr_span => Self::#variant => #variant_str,)
+			quote::quote_spanned!(error.attr_span => Self::#variant { .. } => #variant_str,)
+		});
+
+	let encode_fields_matches = error.variants.iter()
+		.map(|(variant, fields, _)| {
+			let bindings = fields.iter().enumerate()
+				.map(|(i, field)| field.ident.clone().unwrap_or_else(|| {
+					syn::Ident::new(&format!("__field_{}", i), proc_macro2::Span::call_site())
+				}))
+				.collect::<Vec<_>>();
+			let pattern = match fields {
+				syn::Fields::Named(_) => quote::quote!(Self::#variant { #( #bindings, )* }),
+				syn::Fields::Unnamed(_) => quote::quote!(Self::#variant( #( #bindings, )* )),
+				syn::Fields::Unit => quote::quote!(Self::#variant),
+			};
+			quote::quote_spanned!(error.attr_span =>
+				#pattern => {
+					#( #frame_support::codec::Encode::encode_to(#bindings, &mut encoded); )*
+				},
+			)
+		});
+
+	let variant_sizes = error.variants.iter()
+		.map(|(_, fields, _)| {
+			let field_types = fields.iter().map(|field| &field.ty);
+			quote::quote_spanned!(error.attr_span =>
+				0_usize #(
+					.saturating_add(
+						<#field_types as #frame_support::traits::PalletError>::MAX_ENCODED_SIZE
+					)
+				)*
+			)
 		});
 
 	let metadata = error.variants.iter()
-		.map(|(variant, doc)| {
+		.map(|(variant, _, doc)| {
 			let variant_str = format!("{}", variant);
 			quote::quote_spanned!(error.attr_span =>
 				#frame_support::error::ErrorMetadata {
@@ -95,6 +127,20 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 
 		impl<#type_impl_gen> #error_ident<#type_use_gen> #config_where_clause {
+			/// The maximum size of the encoded error, i.e. the index of its variant followed by
+			/// the fields of the variant.
+			pub const MAX_ENCODED_SIZE: usize = {
+				#[allow(unused_mut)]
+				let mut size = 0_usize;
+				#(
+					let variant_size = #variant_sizes;
+					if variant_size > size {
+						size = variant_size;
+					}
+				)*
+				size.saturating_add(1)
+			};
+
 			pub fn as_u8(&self) -> u8 {
 				match &self {
 					Self::__Ignore(_, _) => unreachable!("`__Ignore` can never be constructed"),
@@ -108,6 +154,24 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 					#( #as_str_matches )*
 				}
 			}
+
+			/// The encoded error, as given by `DispatchError::Module`.
+			pub fn as_module_error(
+				&self,
+			) -> [u8; #frame_support::sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE] {
+				#[allow(unused_mut)]
+				let mut encoded = #frame_support::sp_std::vec![self.as_u8()];
+				match &self {
+					Self::__Ignore(_, _) => unreachable!("`__Ignore` can never be constructed"),
+					#( #encode_fields_matches )*
+				}
+
+				// The size is checked by the integrity test of the pallet.
+				let mut error = [0u8; #frame_support::sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE];
+				let len = encoded.len().min(error.len());
+				error[..len].copy_from_slice(&encoded[..len]);
+				error
+			}
 		}
 
 		impl<#type_impl_gen> From<#error_ident<#type_use_gen>> for &'static str
@@ -131,7 +195,7 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 
 				#frame_support::sp_runtime::DispatchError::Module {
 					index,
-					error: err.as_u8(),
+					error: err.as_module_error(),
 					message: Some(err.as_str()),
 				}
 			}
@@ -16,8 +16,10 @@
 // limitations under the License.
 
 use crate::pallet::{Def, parse::helper::get_doc_literals};
+use syn::spanned::Spanned;
 
 /// * add various derive trait on GenesisConfig struct.
+/// * for fields with `#[pallet::default($expr)]`, add a serde default and implement `Default`.
 pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 	let genesis_config = if let Some(genesis_config) = &def.genesis_config {
 		genesis_config
@@ -57,5 +59,86 @@ pub fn expand_genesis_config(def: &mut Def) -> proc_macro2::TokenStream {
 		_ => unreachable!("Checked by genesis_config parser"),
 	}
 
-	Default::default()
+	if genesis_config.field_defaults.is_empty() {
+		return Default::default()
+	}
+
+	let item = if let syn::Item::Struct(item) = genesis_config_item {
+		item
+	} else {
+		unreachable!("Field defaults are only parsed on struct")
+	};
+
+	let span = genesis_config.genesis_config.span();
+	let type_impl_gen = &genesis_config.gen_kind.type_impl_gen(span);
+	let type_use_gen = &genesis_config.gen_kind.type_use_gen(span);
+	let where_clause = item.generics.where_clause.clone();
+
+	let mut default_fns = vec![];
+	for field_default in &genesis_config.field_defaults {
+		let fn_name = match &field_default.member {
+			syn::Member::Named(ident) => ident.to_string(),
+			syn::Member::Unnamed(index) => index.index.to_string(),
+		};
+		let fn_ident = syn::Ident::new(
+			&format!("__genesis_config_default_{}", fn_name),
+			field_default.default.span(),
+		);
+		let ty = &field_default.ty;
+		let default = &field_default.default;
+
+		default_fns.push(quote::quote_spanned!(field_default.default.span() =>
+			#[doc(hidden)]
+			#[cfg(feature = "std")]
+			fn #fn_ident<#type_impl_gen>() -> #ty #where_clause {
+				#default
+			}
+		));
+
+		let serde_default = if genesis_config.gen_kind.is_generic() {
+			format!("{}::<{}>", fn_ident, type_use_gen)
+		} else {
+			fn_ident.to_string()
+		};
+		let field = item.fields.iter_mut()
+			.enumerate()
+			.find(|(index, field)| match &field_default.member {
+				syn::Member::Named(ident) => field.ident.as_ref() == Some(ident),
+				syn::Member::Unnamed(i) => i.index as usize == *index,
+			})
+			.map(|(_, field)| field)
+			.expect("Member is taken from the struct fields");
+		field.attrs.push(syn::parse_quote!( #[serde(default = #serde_default)] ));
+	}
+
+	let fields_default = item.fields.iter()
+		.enumerate()
+		.map(|(index, field)| {
+			let member = match &field.ident {
+				Some(ident) => syn::Member::Named(ident.clone()),
+				None => syn::Member::Unnamed(syn::Index::from(index)),
+			};
+			let default = genesis_config.field_defaults.iter()
+				.find(|field_default| field_default.member == member)
+				.map(|field_default| {
+					let default = &field_default.default;
+					quote::quote_spanned!(default.span() => #default)
+				})
+				.unwrap_or_else(|| quote::quote_spanned!(field.span() => Default::default()));
+
+			quote::quote_spanned!(field.span() => #member: #default)
+		});
+
+	quote::quote_spanned!(span =>
+		#( #default_fns )*
+
+		#[cfg(feature = "std")]
+		impl<#type_impl_gen> Default for GenesisConfig<#type_use_gen> #where_clause {
+			fn default() -> Self {
+				Self {
+					#( #fields_default, )*
+				}
+			}
+		}
+	)
 }
@@ -35,6 +35,18 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 	let pallet_ident = &def.pallet_struct.pallet;
 	let frame_system = &def.frame_system;
 
+	let check_error_size = def.error.as_ref().map(|error| {
+		let error_ident = &error.error;
+		quote::quote! {
+			assert!(
+				#error_ident::<#type_use_gen>::MAX_ENCODED_SIZE
+					<= #frame_support::sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE,
+				"The maximum encoded size of `{}` exceeds `MAX_MODULE_ERROR_ENCODED_SIZE`",
+				stringify!(#error_ident),
+			);
+		}
+	});
+
 	let log_runtime_upgrade = if has_runtime_upgrade {
 		// a migration is defined here.
 		quote::quote! {
@@ -95,6 +107,9 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 				n: <T as #frame_system::Config>::BlockNumber,
 				remaining_weight: #frame_support::weights::Weight
 			) -> #frame_support::weights::Weight {
+				#frame_support::sp_tracing::enter_span!(
+					#frame_support::sp_tracing::trace_span!("on_idle")
+				);
 				<
 					Self as #frame_support::traits::Hooks<
 						<T as #frame_system::Config>::BlockNumber
@@ -121,6 +136,24 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		impl<#type_impl_gen>
+			#frame_support::traits::OnInitializeMetered<<T as #frame_system::Config>::BlockNumber>
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			fn on_initialize_metered(
+				n: <T as #frame_system::Config>::BlockNumber,
+				report: &mut dyn FnMut(&'static str, #frame_support::weights::Weight),
+			) -> #frame_support::weights::Weight {
+				let weight = <
+					Self as #frame_support::traits::OnInitialize<
+						<T as #frame_system::Config>::BlockNumber
+					>
+				>::on_initialize(n);
+				report(<Self as #frame_support::traits::PalletInfoAccess>::name(), weight);
+				weight
+			}
+		}
+
 		impl<#type_impl_gen>
 			#frame_support::traits::OnRuntimeUpgrade
 			for #pallet_ident<#type_use_gen> #where_clause
@@ -169,7 +202,29 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 					Self
 					as
 					#frame_support::traits::Hooks<<T as #frame_system::Config>::BlockNumber>
-				>::post_upgrade()
+				>::post_upgrade()?;
+
+				// The migrations must have set the storage version of the pallet.
+				<Self as #frame_support::traits::CheckStorageVersion>::check_storage_version()
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		impl<#type_impl_gen>
+			#frame_support::traits::TryState<<T as #frame_system::Config>::BlockNumber>
+			for #pallet_ident<#type_use_gen> #where_clause
+		{
+			fn try_state(
+				n: <T as #frame_system::Config>::BlockNumber,
+			) -> Result<(), &'static str> {
+				#frame_support::sp_tracing::enter_span!(
+					#frame_support::sp_tracing::trace_span!("try_state")
+				);
+				<
+					Self as #frame_support::traits::Hooks<
+						<T as #frame_system::Config>::BlockNumber
+					>
+				>::try_state(n)
 			}
 		}
 
@@ -191,6 +246,7 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 			for #pallet_ident<#type_use_gen> #where_clause
 		{
 			fn integrity_test() {
+				#check_error_size
 				<
 					Self as #frame_support::traits::Hooks<
 						<T as #frame_system::Config>::BlockNumber
@@ -0,0 +1,129 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::Def;
+
+/// * Add the items of `ProvideInherent` which are not declared:
+///   * `type Call` as the call enum of the pallet,
+///   * `type Error = MakeFatalError<()>`, or `MakeFatalError<MissingInherent>` if the inherent
+///     is declared as `required`,
+///   * `const INHERENT_IDENTIFIER` from `#[pallet::inherent(identifier = $expr)]`,
+///   * `fn is_inherent` matching the dispatchables annotated with `#[pallet::inherent]`, if any,
+///   * `const INHERENT_REQUIRED` and `fn is_inherent_required` always requiring the inherent,
+///     if declared as `required`.
+/// * Impl `decode_inherent_error` on Pallet.
+pub fn expand_inherent(def: &mut Def) -> proc_macro2::TokenStream {
+	let inherent = if let Some(inherent) = &def.inherent {
+		inherent
+	} else {
+		return Default::default()
+	};
+
+	let frame_support = &def.frame_support;
+	let span = inherent.attr_span;
+	let type_impl_gen = &def.type_impl_generics(span);
+	let type_use_gen = &def.type_use_generics(span);
+	let where_clause = &inherent.where_clause;
+	let call_ident = def.call_ident(span);
+
+	let mut new_items: Vec<syn::ImplItem> = vec![];
+	if !inherent.has_call_type {
+		new_items.push(syn::parse_quote_spanned!(span => type Call = #call_ident<#type_use_gen>;));
+	}
+	if !inherent.has_error_type && inherent.required {
+		new_items.push(syn::parse_quote_spanned!(span =>
+			type Error = #frame_support::inherent::MakeFatalError<
+				#frame_support::inherent::MissingInherent
+			>;
+		));
+	} else if !inherent.has_error_type {
+		new_items.push(syn::parse_quote_spanned!(span =>
+			type Error = #frame_support::inherent::MakeFatalError<()>;
+		));
+	}
+	if let Some(identifier) = &inherent.identifier {
+		new_items.push(syn::parse_quote_spanned!(span =>
+			const INHERENT_IDENTIFIER: #frame_support::inherent::InherentIdentifier = #identifier;
+		));
+	}
+	let inherent_calls = def.call.as_ref()
+		.map(|call| call.methods.iter()
+			.filter(|method| method.inherent)
+			.map(|method| &method.name)
+			.collect::<Vec<_>>()
+		)
+		.unwrap_or_default();
+	if !inherent_calls.is_empty() {
+		new_items.push(syn::parse_quote_spanned!(span =>
+			fn is_inherent(call: &Self::Call) -> bool {
+				match call {
+					#( #call_ident::#inherent_calls { .. } )|* => true,
+					_ => false,
+				}
+			}
+		));
+	}
+
+	if inherent.required {
+		new_items.push(syn::parse_quote_spanned!(span =>
+			const INHERENT_REQUIRED: bool = true;
+		));
+		new_items.push(syn::parse_quote_spanned!(span =>
+			fn is_inherent_required(
+				_: &#frame_support::inherent::InherentData,
+			) -> Result<Option<Self::Error>, Self::Error> {
+				let pallet = <Self as #frame_support::traits::PalletInfoAccess>::name();
+				Ok(Some(<Self::Error as #frame_support::inherent::MissingInherentError>
+					::missing_inherent(pallet)))
+			}
+		));
+	}
+
+	let provide_inherent = quote::quote_spanned!(span =>
+		<Self as #frame_support::inherent::ProvideInherent>
+	);
+	let decode_inherent_error = quote::quote_spanned!(span =>
+		#[cfg(feature = "std")]
+		impl<#type_impl_gen> Pallet<#type_use_gen> #where_clause {
+			/// Decode the error reported by the check of the inherents if it belongs to the
+			/// inherent of this pallet.
+			pub fn decode_inherent_error(
+				identifier: &#frame_support::inherent::InherentIdentifier,
+				mut error: &[u8],
+			) -> Option<#provide_inherent::Error>
+			where
+				#provide_inherent::Error: #frame_support::codec::Decode
+			{
+				if identifier != &#provide_inherent::INHERENT_IDENTIFIER {
+					return None
+				}
+
+				#frame_support::codec::Decode::decode(&mut error).ok()
+			}
+		}
+	);
+
+	let index = inherent.index;
+	let item = &mut def.item.content.as_mut().expect("Checked by def parser").1[index];
+	if let syn::Item::Impl(item) = item {
+		item.items.extend(new_items);
+	} else {
+		unreachable!("Checked by inherent parser");
+	}
+
+	decode_inherent_error
+}
@@ -21,6 +21,9 @@ mod call;
 mod config;
 mod error;
 mod event;
+mod origin;
+mod inherent;
+mod validate_unsigned;
 mod storage;
 mod hooks;
 mod store_trait;
@@ -28,6 +31,8 @@ mod instances;
 mod genesis_build;
 mod genesis_config;
 mod type_value;
+#[cfg(feature = "abi-json")]
+mod abi;
 
 use crate::pallet::{Def, parse::helper::get_doc_literals};
 use quote::ToTokens;
@@ -53,6 +58,9 @@ pub fn expand(mut def: Def) -> proc_macro2::TokenStream {
 	let call = call::expand_call(&mut def);
 	let error = error::expand_error(&mut def);
 	let event = event::expand_event(&mut def);
+	let origin = origin::expand_origin(&mut def);
+	let inherent = inherent::expand_inherent(&mut def);
+	let validate_unsigned = validate_unsigned::expand_validate_unsigned(&mut def);
 	let storages = storage::expand_storages(&mut def);
 	let instances = instances::expand_instances(&mut def);
 	let store_trait = store_trait::expand_store_trait(&mut def);
@@ -60,6 +68,10 @@ pub fn expand(mut def: Def) -> proc_macro2::TokenStream {
 	let genesis_build = genesis_build::expand_genesis_build(&mut def);
 	let genesis_config = genesis_config::expand_genesis_config(&mut def);
 	let type_values = type_value::expand_type_values(&mut def);
+	#[cfg(feature = "abi-json")]
+	let call_abi = abi::expand_call_abi(&def);
+	#[cfg(not(feature = "abi-json"))]
+	let call_abi = proc_macro2::TokenStream::new();
 
 	if get_doc_literals(&def.item.attrs).is_empty() {
 		def.item.attrs.push(syn::parse_quote!(
@@ -79,6 +91,9 @@ pub fn expand(mut def: Def) -> proc_macro2::TokenStream {
 		#call
 		#error
 		#event
+		#origin
+		#inherent
+		#validate_unsigned
 		#storages
 		#instances
 		#store_trait
@@ -86,6 +101,7 @@ pub fn expand(mut def: Def) -> proc_macro2::TokenStream {
 		#genesis_build
 		#genesis_config
 		#type_values
+		#call_abi
 	);
 
 	def.item.content.as_mut().expect("This is checked by parsing").1
@@ -0,0 +1,191 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::{Def, parse::GenericKind, parse::origin::EnsureVariantDef};
+
+/// * Impl `try_from_outer` and `into_outer` on Origin if it is not a type alias.
+/// * For each variant annotated with `#[pallet::ensure($Ident)]`, generate the struct `$Ident` and
+///   impl `EnsureOrigin` and `EnsureOriginWithArg` on it.
+pub fn expand_origin(def: &mut Def) -> proc_macro2::TokenStream {
+	let origin = if let Some(origin) = &def.origin {
+		origin
+	} else {
+		return Default::default()
+	};
+
+	let frame_support = &def.frame_support;
+	let span = origin.origin_span;
+	let gen_kind = GenericKind::from_gens(origin.is_generic, origin.has_instance)
+		.expect("Checked by origin parser");
+	let type_use_gen = gen_kind.type_use_gen(span);
+	let type_impl_gen = gen_kind.type_impl_gen(span);
+
+	let conversions = if origin.is_type_alias {
+		Default::default()
+	} else {
+		quote::quote_spanned!(span =>
+			impl<#type_impl_gen> Origin<#type_use_gen> {
+				/// Try to convert the outer origin into this pallet origin, returns the outer
+				/// origin if it is not this pallet origin.
+				pub fn try_from_outer<O: Into<Result<Self, O>>>(o: O) -> Result<Self, O> {
+					o.into()
+				}
+
+				/// Convert this pallet origin into the outer origin.
+				pub fn into_outer<O: From<Self>>(self) -> O {
+					O::from(self)
+				}
+			}
+		)
+	};
+
+	let ensures = origin.ensures.iter()
+		.map(|ensure| expand_ensure(ensure, frame_support, &gen_kind, span));
+
+	quote::quote_spanned!(span =>
+		#conversions
+		#( #ensures )*
+	)
+}
+
+/// Generate the struct of `ensure` and its `EnsureOrigin` and `EnsureOriginWithArg` impls.
+fn expand_ensure(
+	ensure: &EnsureVariantDef,
+	frame_support: &syn::Ident,
+	gen_kind: &GenericKind,
+	span: proc_macro2::Span,
+) -> proc_macro2::TokenStream {
+	let ensure_ident = &ensure.ensure_ident;
+	let variant = &ensure.variant;
+	let type_use_gen = gen_kind.type_use_gen(span);
+	let type_impl_gen = gen_kind.type_impl_gen(span);
+
+	let (ensure_struct, impl_gen) = match gen_kind {
+		GenericKind::None => (
+			quote::quote_spanned!(span => pub struct #ensure_ident;),
+			quote::quote_spanned!(span => O),
+		),
+		GenericKind::Config => (
+			quote::quote_spanned!(span =>
+				pub struct #ensure_ident<T>(#frame_support::sp_std::marker::PhantomData<T>);
+			),
+			quote::quote_spanned!(span => #type_impl_gen, O),
+		),
+		GenericKind::ConfigAndInstance => (
+			quote::quote_spanned!(span =>
+				pub struct #ensure_ident<T, I = ()>(
+					#frame_support::sp_std::marker::PhantomData<(T, I)>
+				);
+			),
+			quote::quote_spanned!(span => #type_impl_gen, O),
+		),
+	};
+
+	let field_members = ensure.fields.iter()
+		.enumerate()
+		.map(|(i, field)| match &field.ident {
+			Some(ident) => quote::quote_spanned!(span => #ident),
+			None => {
+				let index = syn::Index::from(i);
+				quote::quote_spanned!(span => #index)
+			},
+		})
+		.collect::<Vec<_>>();
+	let field_bindings = ensure.fields.iter()
+		.enumerate()
+		.map(|(i, field)| match &field.ident {
+			Some(ident) => quote::format_ident!("__field_{}", ident),
+			None => quote::format_ident!("__field_{}", i),
+		})
+		.collect::<Vec<_>>();
+	let field_types = ensure.fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+	// E.g. `Origin::Variant { 0: __field_0, }` or `Origin::Variant { who: __field_who, }`.
+	let variant_pat = quote::quote_spanned!(span =>
+		Origin::#variant { #( #field_members: #field_bindings, )* }
+	);
+	let variant_default = quote::quote_spanned!(span =>
+		Origin::#variant { #( #field_members: Default::default(), )* }
+	);
+
+	// The success type and how to destructure it, depending on the number of fields.
+	let (success, success_pat) = match field_types.len() {
+		1 => (
+			quote::quote_spanned!(span => #( #field_types )*),
+			quote::quote_spanned!(span => #( #field_bindings )*),
+		),
+		_ => (
+			quote::quote_spanned!(span => ( #( #field_types, )* )),
+			quote::quote_spanned!(span => ( #( #field_bindings, )* )),
+		),
+	};
+
+	let doc = format!(
+		"Ensure the origin is `Origin::{}`, succeeds with the data of the variant.",
+		variant,
+	);
+
+	quote::quote_spanned!(span =>
+		#[doc = #doc]
+		#ensure_struct
+
+		impl<#impl_gen> #frame_support::traits::EnsureOrigin<O> for #ensure_ident<#type_use_gen>
+		where
+			O: Into<Result<Origin<#type_use_gen>, O>> + From<Origin<#type_use_gen>>,
+		{
+			type Success = #success;
+
+			#[allow(unreachable_patterns)]
+			fn try_origin(o: O) -> Result<Self::Success, O> {
+				o.into().and_then(|o| match o {
+					#variant_pat => Ok(#success_pat),
+					r => Err(O::from(r)),
+				})
+			}
+
+			#[cfg(feature = "runtime-benchmarks")]
+			fn successful_origin() -> O {
+				O::from(#variant_default)
+			}
+		}
+
+		impl<#impl_gen> #frame_support::traits::EnsureOriginWithArg<O, #success>
+			for #ensure_ident<#type_use_gen>
+		where
+			O: Into<Result<Origin<#type_use_gen>, O>> + From<Origin<#type_use_gen>>,
+			#success: PartialEq + Clone,
+		{
+			type Success = #success;
+
+			fn try_origin(o: O, a: &#success) -> Result<Self::Success, O> {
+				<Self as #frame_support::traits::EnsureOrigin<O>>::try_origin(o)
+					.and_then(|success| if &success == a {
+						Ok(success)
+					} else {
+						let #success_pat = success;
+						Err(O::from(#variant_pat))
+					})
+			}
+
+			#[cfg(feature = "runtime-benchmarks")]
+			fn successful_origin(a: &#success) -> O {
+				let #success_pat = a.clone();
+				O::from(#variant_pat)
+			}
+		}
+	)
+}
@@ -16,9 +16,11 @@
 // limitations under the License.
 
 use crate::pallet::{Def, expand::merge_where_clauses, parse::helper::get_doc_literals};
+use syn::spanned::Spanned;
 
 /// * Add derive trait on Pallet
 /// * Implement GetPalletVersion on Pallet
+/// * Implement GetStorageVersion and CheckStorageVersion on Pallet
 /// * Implement OnGenesis on Pallet
 /// * Implement ModuleErrorMetadata on Pallet
 /// * declare Module type alias for construct_runtime
@@ -137,6 +139,13 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 		Default::default()
 	};
 
+	let current_storage_version = match &def.pallet_struct.storage_version {
+		Some(storage_version) => quote::quote_spanned!(storage_version.span() => #storage_version),
+		None => quote::quote_spanned!(def.pallet_struct.attr_span =>
+			#frame_support::traits::StorageVersion::default()
+		),
+	};
+
 	quote::quote_spanned!(def.pallet_struct.attr_span =>
 		#module_error_metadata
 
@@ -165,6 +174,49 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		// Implement `GetStorageVersion` for `Pallet`
+		impl<#type_impl_gen> #frame_support::traits::GetStorageVersion
+			for #pallet_ident<#type_use_gen>
+			#config_where_clause
+		{
+			fn current_storage_version() -> #frame_support::traits::StorageVersion {
+				#current_storage_version
+			}
+
+			fn on_chain_storage_version() -> #frame_support::traits::StorageVersion {
+				#frame_support::traits::StorageVersion::get::<Self>()
+			}
+		}
+
+		// Implement `CheckStorageVersion` for `Pallet`
+		impl<#type_impl_gen> #frame_support::traits::CheckStorageVersion
+			for #pallet_ident<#type_use_gen>
+			#config_where_clause
+		{
+			fn check_storage_version() -> Result<(), &'static str> {
+				let on_chain = <
+					Self as #frame_support::traits::GetStorageVersion
+				>::on_chain_storage_version();
+				let current = <
+					Self as #frame_support::traits::GetStorageVersion
+				>::current_storage_version();
+
+				if on_chain == current {
+					Ok(())
+				} else {
+					#frame_support::log::error!(
+						target: #frame_support::LOG_TARGET,
+						"{}: on-chain storage version {:?} doesn't match the current storage \
+							version {:?}",
+						<Self as #frame_support::traits::PalletInfoAccess>::name(),
+						on_chain,
+						current,
+					);
+					Err("On-chain storage version doesn't match the current storage version")
+				}
+			}
+		}
+
 		// Implement `OnGenesis` for `Pallet`
 		impl<#type_impl_gen> #frame_support::traits::OnGenesis
 			for #pallet_ident<#type_use_gen>
@@ -173,6 +225,8 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 			fn on_genesis() {
 				#frame_support::crate_to_pallet_version!()
 					.put_into_storage::<<T as #frame_system::Config>::PalletInfo, Self>();
+				<Self as #frame_support::traits::GetStorageVersion>::current_storage_version()
+					.put::<Self>();
 			}
 		}
 
@@ -196,6 +250,12 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 					.expect("Pallet is part of the runtime because pallet `Config` trait is \
 						implemented by the runtime")
 			}
+
+			fn renamed_from() -> &'static [&'static str] {
+				<
+					<T as #frame_system::Config>::PalletInfo as #frame_support::traits::PalletInfo
+				>::renamed_from::<Self>()
+			}
 		}
 
 		#storage_info
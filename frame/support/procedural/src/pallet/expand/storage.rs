@@ -346,7 +346,7 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		let type_use_gen = &def.type_use_generics(storage_def.attr_span);
 		let prefix_struct_ident = prefix_ident(&storage_def.ident);
 		let prefix_struct_vis = &storage_def.vis;
-		let prefix_struct_const = storage_def.ident.to_string();
+		let prefix_struct_const = storage_def.prefix();
 		let config_where_clause = &def.config.where_clause;
 
 		let cfg_attrs = &storage_def.cfg_attrs;
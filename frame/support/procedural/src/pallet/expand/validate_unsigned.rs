@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::Def;
+
+/// If some dispatchables are annotated with `#[pallet::validate_unsigned(..)]`, impl
+/// `ValidateUnsigned` on Pallet, accepting those dispatchables as unsigned transactions:
+/// * the signature of the signed payload is verified, if any,
+/// * the `check` expression must succeed, if any,
+/// * the transaction provides the tag built from the `provides` expression, or from the
+///   arguments of the call, prefixed with the path of the dispatchable.
+pub fn expand_validate_unsigned(def: &mut Def) -> proc_macro2::TokenStream {
+	let call = match &def.call {
+		Some(call) if call.methods.iter().any(|method| method.validate_unsigned.is_some()) => call,
+		_ => return Default::default(),
+	};
+
+	let frame_support = &def.frame_support;
+	let frame_system = &def.frame_system;
+	let span = call.attr_span;
+	let type_impl_gen = &def.type_impl_generics(span);
+	let type_use_gen = &def.type_use_generics(span);
+	let where_clause = &call.where_clause;
+	let call_ident = def.call_ident(span);
+
+	let arms = call.methods.iter()
+		.filter_map(|method| method.validate_unsigned.as_ref().map(|v| (method, v)))
+		.map(|(method, validate)| {
+			let span = validate.attr_span;
+			let name = &method.name;
+			let args = method.args.iter().map(|(_, name, _)| name).collect::<Vec<_>>();
+			let tag_prefix = format!("::{}", name);
+
+			let verify_signature = validate.signed_payload.as_ref()
+				.map(|(payload, signature, authority)| quote::quote_spanned!(span =>
+					if !#frame_system::offchain::SignedPayload::<T>::verify::<#authority>(
+						#payload,
+						#signature.clone(),
+					) {
+						return #frame_support::unsigned::InvalidTransaction::BadProof.into();
+					}
+				));
+			let check = validate.check.as_ref().map(|check| quote::quote_spanned!(span =>
+				let _: () = #check?;
+			));
+			let priority = validate.priority.as_ref()
+				.map(|priority| quote::quote_spanned!(span => .priority(#priority)));
+			let longevity = validate.longevity.as_ref()
+				.map(|longevity| quote::quote_spanned!(span => .longevity(#longevity)));
+			let provides = match &validate.provides {
+				Some(provides) => quote::quote_spanned!(span => #provides),
+				None => quote::quote_spanned!(span => ( #( #args, )* )),
+			};
+
+			quote::quote_spanned!(span =>
+				#call_ident::#name( #( ref #args, )* ) => {
+					#verify_signature
+					#check
+					#frame_support::unsigned::ValidTransaction::with_tag_prefix(
+						concat!(module_path!(), #tag_prefix)
+					)
+						#priority
+						.and_provides(#provides)
+						#longevity
+						.propagate(true)
+						.build()
+				},
+			)
+		});
+
+	quote::quote_spanned!(span =>
+		impl<#type_impl_gen> #frame_support::unsigned::ValidateUnsigned for Pallet<#type_use_gen>
+			#where_clause
+		{
+			type Call = #call_ident<#type_use_gen>;
+
+			#[allow(unused_variables)]
+			fn validate_unsigned(
+				source: #frame_support::unsigned::TransactionSource,
+				call: &Self::Call,
+			) -> #frame_support::unsigned::TransactionValidity {
+				match call {
+					#( #arms )*
+					_ => #frame_support::unsigned::InvalidTransaction::Call.into(),
+				}
+			}
+		}
+	)
+}
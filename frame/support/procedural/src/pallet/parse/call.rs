@@ -24,6 +24,13 @@ mod keyword {
 	syn::custom_keyword!(Call);
 	syn::custom_keyword!(OriginFor);
 	syn::custom_keyword!(weight);
+	syn::custom_keyword!(inherent);
+	syn::custom_keyword!(validate_unsigned);
+	syn::custom_keyword!(priority);
+	syn::custom_keyword!(longevity);
+	syn::custom_keyword!(provides);
+	syn::custom_keyword!(check);
+	syn::custom_keyword!(signed_payload);
 	syn::custom_keyword!(compact);
 	syn::custom_keyword!(T);
 	syn::custom_keyword!(pallet);
@@ -43,6 +50,8 @@ pub struct CallDef {
 	pub attr_span: proc_macro2::Span,
 	/// Docs, specified on the impl Block.
 	pub docs: Vec<syn::Lit>,
+	/// The name of the call enum if renamed with `#[pallet::call(rename = "$Ident")]`.
+	pub rename: Option<syn::Ident>,
 }
 
 #[derive(Clone)]
@@ -56,12 +65,106 @@ pub struct CallVariantDef {
 	pub weight: syn::Expr,
 	/// Docs, used for metadata.
 	pub docs: Vec<syn::Lit>,
+	/// Whether the dispatchable is annotated with `#[pallet::inherent]`, i.e. it is an inherent
+	/// of the pallet.
+	pub inherent: bool,
+	/// The validation of the dispatchable when submitted as an unsigned transaction, given by
+	/// `#[pallet::validate_unsigned(..)]`.
+	pub validate_unsigned: Option<CallValidateUnsignedDef>,
+}
+
+/// Definition of the validation of an unsigned dispatchable:
+/// `#[pallet::validate_unsigned(priority = $expr, longevity = $expr, provides = $expr,
+/// check = $expr, signed_payload($payload_arg, $signature_arg, $AuthorityId))]`, all optional.
+#[derive(Clone)]
+pub struct CallValidateUnsignedDef {
+	/// The span of the attribute.
+	pub attr_span: proc_macro2::Span,
+	/// The priority of the transaction.
+	pub priority: Option<syn::Expr>,
+	/// The longevity of the transaction.
+	pub longevity: Option<syn::Expr>,
+	/// The tag provided by the transaction, the arguments of the call if not given.
+	pub provides: Option<syn::Expr>,
+	/// An expression returning `Result<(), TransactionValidityError>`, evaluated before the
+	/// transaction is considered valid.
+	pub check: Option<syn::Expr>,
+	/// The argument holding a signed payload, the argument holding its signature and the
+	/// authority type used to verify the signature.
+	pub signed_payload: Option<(syn::Ident, syn::Ident, syn::Type)>,
+}
+
+impl CallValidateUnsignedDef {
+	fn parse_content(
+		attr_span: proc_macro2::Span,
+		input: syn::parse::ParseStream,
+	) -> syn::Result<Self> {
+		let mut def = CallValidateUnsignedDef {
+			attr_span,
+			priority: None,
+			longevity: None,
+			provides: None,
+			check: None,
+			signed_payload: None,
+		};
+
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			let span = input.span();
+			let duplicated = if lookahead.peek(keyword::priority) {
+				input.parse::<keyword::priority>()?;
+				input.parse::<syn::Token![=]>()?;
+				def.priority.replace(input.parse()?).is_some()
+			} else if lookahead.peek(keyword::longevity) {
+				input.parse::<keyword::longevity>()?;
+				input.parse::<syn::Token![=]>()?;
+				def.longevity.replace(input.parse()?).is_some()
+			} else if lookahead.peek(keyword::provides) {
+				input.parse::<keyword::provides>()?;
+				input.parse::<syn::Token![=]>()?;
+				def.provides.replace(input.parse()?).is_some()
+			} else if lookahead.peek(keyword::check) {
+				input.parse::<keyword::check>()?;
+				input.parse::<syn::Token![=]>()?;
+				def.check.replace(input.parse()?).is_some()
+			} else if lookahead.peek(keyword::signed_payload) {
+				input.parse::<keyword::signed_payload>()?;
+				let content;
+				syn::parenthesized!(content in input);
+				let payload = content.parse::<syn::Ident>()?;
+				content.parse::<syn::Token![,]>()?;
+				let signature = content.parse::<syn::Ident>()?;
+				content.parse::<syn::Token![,]>()?;
+				let authority = content.parse::<syn::Type>()?;
+				def.signed_payload.replace((payload, signature, authority)).is_some()
+			} else {
+				return Err(lookahead.error());
+			};
+
+			if duplicated {
+				let msg = "Invalid pallet::validate_unsigned, duplicated argument";
+				return Err(syn::Error::new(span, msg));
+			}
+
+			if !input.is_empty() {
+				input.parse::<syn::Token![,]>()?;
+			}
+		}
+
+		Ok(def)
+	}
 }
 
 /// Attributes for functions in call impl block.
-/// Parse for `#[pallet::weight(expr)]`
-pub struct FunctionAttr {
-	weight: syn::Expr,
+/// Parse for `#[pallet::weight(expr)]`, `#[pallet::inherent]` or
+/// `#[pallet::validate_unsigned(..)]`
+pub enum FunctionAttr {
+	/// Parse for `#[pallet::weight(expr)]`
+	Weight(syn::Expr),
+	/// Parse for `#[pallet::inherent]`
+	Inherent(proc_macro2::Span),
+	/// Parse for `#[pallet::validate_unsigned(..)]`
+	ValidateUnsigned(CallValidateUnsignedDef),
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -71,13 +174,24 @@ impl syn::parse::Parse for FunctionAttr {
 		syn::bracketed!(content in input);
 		content.parse::<keyword::pallet>()?;
 		content.parse::<syn::Token![::]>()?;
-		content.parse::<keyword::weight>()?;
 
-		let weight_content;
-		syn::parenthesized!(weight_content in content);
-		Ok(FunctionAttr {
-			weight: weight_content.parse::<syn::Expr>()?,
-		})
+		let lookahead = content.lookahead1();
+		if lookahead.peek(keyword::weight) {
+			content.parse::<keyword::weight>()?;
+			let weight_content;
+			syn::parenthesized!(weight_content in content);
+			Ok(FunctionAttr::Weight(weight_content.parse::<syn::Expr>()?))
+		} else if lookahead.peek(keyword::inherent) {
+			Ok(FunctionAttr::Inherent(content.parse::<keyword::inherent>()?.span()))
+		} else if lookahead.peek(keyword::validate_unsigned) {
+			let span = content.parse::<keyword::validate_unsigned>()?.span();
+			let validate_content;
+			syn::parenthesized!(validate_content in content);
+			let def = CallValidateUnsignedDef::parse_content(span, &validate_content)?;
+			Ok(FunctionAttr::ValidateUnsigned(def))
+		} else {
+			Err(lookahead.error())
+		}
 	}
 }
 
@@ -127,6 +241,7 @@ pub fn check_dispatchable_first_arg_type(ty: &syn::Type) -> syn::Result<()> {
 impl CallDef {
 	pub fn try_from(
 		attr_span: proc_macro2::Span,
+		rename: Option<syn::Ident>,
 		index: usize,
 		item: &mut syn::Item
 	) -> syn::Result<Self> {
@@ -172,18 +287,41 @@ impl CallDef {
 					return Err(syn::Error::new(method.sig.span(), msg));
 				}
 
-				let mut call_var_attrs: Vec<FunctionAttr> =
+				let call_var_attrs: Vec<FunctionAttr> =
 					helper::take_item_pallet_attrs(&mut method.attrs)?;
 
-				if call_var_attrs.len() != 1 {
-					let msg = if call_var_attrs.is_empty() {
+				let mut weights = vec![];
+				let mut inherent_attrs = vec![];
+				let mut validate_unsigned_attrs = vec![];
+				for attr in call_var_attrs {
+					match attr {
+						FunctionAttr::Weight(weight) => weights.push(weight),
+						FunctionAttr::Inherent(span) => inherent_attrs.push(span),
+						FunctionAttr::ValidateUnsigned(def) => validate_unsigned_attrs.push(def),
+					}
+				}
+
+				if weights.len() != 1 {
+					let msg = if weights.is_empty() {
 						"Invalid pallet::call, requires weight attribute i.e. `#[pallet::weight($expr)]`"
 					} else {
 						"Invalid pallet::call, too many weight attributes given"
 					};
 					return Err(syn::Error::new(method.sig.span(), msg));
 				}
-				let weight = call_var_attrs.pop().unwrap().weight;
+				let weight = weights.pop().unwrap();
+
+				if inherent_attrs.len() > 1 {
+					let msg = "Invalid pallet::call, too many inherent attributes given";
+					return Err(syn::Error::new(inherent_attrs[1], msg));
+				}
+				let inherent = !inherent_attrs.is_empty();
+
+				if validate_unsigned_attrs.len() > 1 {
+					let msg = "Invalid pallet::call, too many validate_unsigned attributes given";
+					return Err(syn::Error::new(validate_unsigned_attrs[1].attr_span, msg));
+				}
+				let validate_unsigned = validate_unsigned_attrs.pop();
 
 				let mut args = vec![];
 				for arg in method.sig.inputs.iter_mut().skip(1) {
@@ -211,6 +349,18 @@ impl CallDef {
 					args.push((!arg_attrs.is_empty(), arg_ident, arg.ty.clone()));
 				}
 
+				if let Some((payload, signature, _)) = validate_unsigned.as_ref()
+					.and_then(|def| def.signed_payload.as_ref())
+				{
+					for ident in &[payload, signature] {
+						if !args.iter().any(|(_, arg, _)| arg == *ident) {
+							let msg = "Invalid pallet::validate_unsigned, expected an argument of \
+								the call";
+							return Err(syn::Error::new(ident.span(), msg));
+						}
+					}
+				}
+
 				let docs = helper::get_doc_literals(&method.attrs);
 
 				methods.push(CallVariantDef {
@@ -218,6 +368,8 @@ impl CallDef {
 					weight,
 					args,
 					docs,
+					inherent,
+					validate_unsigned,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
@@ -232,6 +384,7 @@ impl CallDef {
 			methods,
 			where_clause: item.generics.where_clause.clone(),
 			docs: helper::get_doc_literals(&item.attrs),
+			rename,
 		})
 	}
 }
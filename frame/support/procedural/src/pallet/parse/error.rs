@@ -24,17 +24,18 @@ mod keyword {
 	syn::custom_keyword!(Error);
 }
 
-/// This checks error declaration as a enum declaration with only variants without fields nor
-/// discriminant.
+/// This checks error declaration as a enum declaration with variants without discriminant. The
+/// fields of the variants must implement `PalletError`.
 pub struct ErrorDef {
 	/// The index of error item in pallet module.
 	pub index: usize,
-	/// Variants ident and doc literals (ordered as declaration order)
-	pub variants: Vec<(syn::Ident, Vec<syn::Lit>)>,
+	/// Variants ident, fields and doc literals (ordered as declaration order)
+	pub variants: Vec<(syn::Ident, syn::Fields, Vec<syn::Lit>)>,
 	/// A set of usage of instance, must be check for consistency with trait.
 	pub instances: Vec<helper::InstanceUsage>,
-	/// The keyword error used (contains span).
-	pub error: keyword::Error,
+	/// The ident of the error, `Error` unless renamed with
+	/// `#[pallet::error(rename = "$Ident")]`.
+	pub error: syn::Ident,
 	/// The span of the pallet::error attribute.
 	pub attr_span: proc_macro2::Span,
 }
@@ -42,6 +43,7 @@ pub struct ErrorDef {
 impl ErrorDef {
 	pub fn try_from(
 		attr_span: proc_macro2::Span,
+		rename: Option<syn::Ident>,
 		index: usize,
 		item: &mut syn::Item,
 	) -> syn::Result<Self> {
@@ -63,14 +65,26 @@ impl ErrorDef {
 			return Err(syn::Error::new(item.generics.where_clause.as_ref().unwrap().span(), msg));
 		}
 
-		let error = syn::parse2::<keyword::Error>(item.ident.to_token_stream())?;
+		let error = match rename {
+			Some(rename) if item.ident == rename => item.ident.clone(),
+			Some(rename) => {
+				let msg = format!("Invalid pallet::error, expected enum named `{}`", rename);
+				return Err(syn::Error::new(item.ident.span(), msg));
+			},
+			None => {
+				syn::parse2::<keyword::Error>(item.ident.to_token_stream())?;
+				item.ident.clone()
+			},
+		};
+
+		if item.variants.len() > 256 {
+			let msg = "Invalid pallet::error, too many variants, the index of the variant must fit \
+				in a `u8`";
+			return Err(syn::Error::new(item.variants.span(), msg));
+		}
 
 		let variants = item.variants.iter()
 			.map(|variant| {
-				if !matches!(variant.fields, syn::Fields::Unit) {
-					let msg = "Invalid pallet::error, unexpected fields, must be `Unit`";
-					return Err(syn::Error::new(variant.fields.span(), msg));
-				}
 				if variant.discriminant.is_some() {
 					let msg = "Invalid pallet::error, unexpected discriminant, discriminant \
 						are not supported";
@@ -78,7 +92,11 @@ impl ErrorDef {
 					return Err(syn::Error::new(span, msg));
 				}
 
-				Ok((variant.ident.clone(), helper::get_doc_literals(&variant.attrs)))
+				Ok((
+					variant.ident.clone(),
+					variant.fields.clone(),
+					helper::get_doc_literals(&variant.attrs),
+				))
 			})
 			.collect::<Result<_, _>>()?;
 
@@ -18,6 +18,44 @@
 use syn::spanned::Spanned;
 use super::helper;
 
+/// List of additional token to be used for parsing.
+mod keyword {
+	syn::custom_keyword!(pallet);
+	syn::custom_keyword!(default);
+}
+
+/// Attribute on a genesis config field: `#[pallet::default($expr)]`.
+struct PalletGenesisConfigFieldAttr {
+	default: syn::Expr,
+}
+
+impl syn::parse::Parse for PalletGenesisConfigFieldAttr {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		input.parse::<syn::Token![#]>()?;
+		let content;
+		syn::bracketed!(content in input);
+		content.parse::<keyword::pallet>()?;
+		content.parse::<syn::Token![::]>()?;
+		content.parse::<keyword::default>()?;
+
+		let default_content;
+		syn::parenthesized!(default_content in content);
+		let default = default_content.parse::<syn::Expr>()?;
+
+		Ok(Self { default })
+	}
+}
+
+/// A genesis config field annotated with `#[pallet::default($expr)]`.
+pub struct GenesisConfigFieldDefault {
+	/// The field, i.e. its ident or its index.
+	pub member: syn::Member,
+	/// The type of the field.
+	pub ty: syn::Type,
+	/// The expression of the default value of the field.
+	pub default: syn::Expr,
+}
+
 /// Definition for pallet genesis config type.
 ///
 /// Either:
@@ -32,6 +70,8 @@ pub struct GenesisConfigDef {
 	pub instances: Vec<helper::InstanceUsage>,
 	/// The ident of genesis_config, can be used for span.
 	pub genesis_config: syn::Ident,
+	/// The fields annotated with `#[pallet::default($expr)]`, only for structs.
+	pub field_defaults: Vec<GenesisConfigFieldDefault>,
 }
 
 impl GenesisConfigDef {
@@ -68,11 +108,40 @@ impl GenesisConfigDef {
 			return Err(syn::Error::new(ident.span(), msg));
 		}
 
+		let genesis_config = ident.clone();
+
+		let mut field_defaults = vec![];
+		if let syn::Item::Struct(item) = item {
+			for (index, field) in item.fields.iter_mut().enumerate() {
+				let mut attrs: Vec<PalletGenesisConfigFieldAttr> =
+					helper::take_item_pallet_attrs(&mut field.attrs)?;
+
+				if attrs.len() > 1 {
+					let msg = "Invalid pallet::genesis_config, unexpected multiple \
+						`pallet::default` on a field";
+					return Err(syn::Error::new(field.span(), msg));
+				}
+
+				if let Some(attr) = attrs.pop() {
+					let member = match &field.ident {
+						Some(ident) => syn::Member::Named(ident.clone()),
+						None => syn::Member::Unnamed(syn::Index::from(index)),
+					};
+					field_defaults.push(GenesisConfigFieldDefault {
+						member,
+						ty: field.ty.clone(),
+						default: attr.default,
+					});
+				}
+			}
+		}
+
 		Ok(GenesisConfigDef {
 			index,
-			genesis_config: ident.clone(),
+			genesis_config,
 			instances,
 			gen_kind,
+			field_defaults,
 		})
 	}
 }
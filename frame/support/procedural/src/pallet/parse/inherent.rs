@@ -24,10 +24,33 @@ pub struct InherentDef {
 	pub index: usize,
 	/// A set of usage of instance, must be check for consistency with trait.
 	pub instances: Vec<helper::InstanceUsage>,
+	/// The where_clause used.
+	pub where_clause: Option<syn::WhereClause>,
+	/// The span of the pallet::inherent attribute.
+	pub attr_span: proc_macro2::Span,
+	/// The identifier given as `#[pallet::inherent(identifier = $expr)]`, if any.
+	pub identifier: Option<syn::Expr>,
+	/// Whether the inherent is declared as required in every block with
+	/// `#[pallet::inherent(required)]`.
+	pub required: bool,
+	/// Whether the impl declares the associated type `Call`.
+	pub has_call_type: bool,
+	/// Whether the impl declares the associated type `Error`.
+	pub has_error_type: bool,
+	/// Whether the impl declares the function `is_inherent`.
+	pub has_is_inherent: bool,
+	/// Whether the impl declares the function `is_inherent_required`.
+	pub has_is_inherent_required: bool,
 }
 
 impl InherentDef {
-	pub fn try_from(index: usize, item: &mut syn::Item) -> syn::Result<Self> {
+	pub fn try_from(
+		attr_span: proc_macro2::Span,
+		identifier: Option<syn::Expr>,
+		required: bool,
+		index: usize,
+		item: &mut syn::Item,
+	) -> syn::Result<Self> {
 		let item = if let syn::Item::Impl(item) = item {
 			item
 		} else {
@@ -54,6 +77,48 @@ impl InherentDef {
 		instances.push(helper::check_pallet_struct_usage(&item.self_ty)?);
 		instances.push(helper::check_impl_gen(&item.generics, item.impl_token.span())?);
 
-		Ok(InherentDef { index, instances })
+		let mut has_call_type = false;
+		let mut has_error_type = false;
+		let mut has_identifier = false;
+		let mut has_is_inherent = false;
+		let mut has_is_inherent_required = false;
+		for impl_item in &item.items {
+			match impl_item {
+				syn::ImplItem::Type(type_) if type_.ident == "Call" => has_call_type = true,
+				syn::ImplItem::Type(type_) if type_.ident == "Error" => has_error_type = true,
+				syn::ImplItem::Const(const_) if const_.ident == "INHERENT_IDENTIFIER" =>
+					has_identifier = true,
+				syn::ImplItem::Method(method) if method.sig.ident == "is_inherent" =>
+					has_is_inherent = true,
+				syn::ImplItem::Method(method) if method.sig.ident == "is_inherent_required" =>
+					has_is_inherent_required = true,
+				_ => (),
+			}
+		}
+
+		if let (Some(identifier), true) = (&identifier, has_identifier) {
+			let msg = "Invalid pallet::inherent, the identifier is given both in the attribute \
+				and as `INHERENT_IDENTIFIER`";
+			return Err(syn::Error::new(identifier.span(), msg));
+		}
+
+		if required && has_is_inherent_required {
+			let msg = "Invalid pallet::inherent, the inherent is declared as `required` in the \
+				attribute and `is_inherent_required` is implemented";
+			return Err(syn::Error::new(attr_span, msg));
+		}
+
+		Ok(InherentDef {
+			index,
+			instances,
+			where_clause: item.generics.where_clause.clone(),
+			attr_span,
+			identifier,
+			required,
+			has_call_type,
+			has_error_type,
+			has_is_inherent,
+			has_is_inherent_required,
+		})
 	}
 }
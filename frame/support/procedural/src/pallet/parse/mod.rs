@@ -102,10 +102,10 @@ impl Def {
 					let m = hooks::HooksDef::try_from(span, index, item)?;
 					hooks = Some(m);
 				},
-				Some(PalletAttr::Call(span)) if call.is_none() =>
-					call = Some(call::CallDef::try_from(span, index, item)?),
-				Some(PalletAttr::Error(span)) if error.is_none() =>
-					error = Some(error::ErrorDef::try_from(span, index, item)?),
+				Some(PalletAttr::Call(span, rename)) if call.is_none() =>
+					call = Some(call::CallDef::try_from(span, rename, index, item)?),
+				Some(PalletAttr::Error(span, rename)) if error.is_none() =>
+					error = Some(error::ErrorDef::try_from(span, rename, index, item)?),
 				Some(PalletAttr::Event(span)) if event.is_none() =>
 					event = Some(event::EventDef::try_from(span, index, item)?),
 				Some(PalletAttr::GenesisConfig(_)) if genesis_config.is_none() => {
@@ -118,8 +118,16 @@ impl Def {
 				},
 				Some(PalletAttr::Origin(_)) if origin.is_none() =>
 					origin = Some(origin::OriginDef::try_from(index, item)?),
-				Some(PalletAttr::Inherent(_)) if inherent.is_none() =>
-					inherent = Some(inherent::InherentDef::try_from(index, item)?),
+				Some(PalletAttr::Inherent(span, identifier, required)) if inherent.is_none() => {
+					let i = inherent::InherentDef::try_from(
+						span,
+						identifier,
+						required,
+						index,
+						item,
+					)?;
+					inherent = Some(i);
+				},
 				Some(PalletAttr::Storage(span)) =>
 					storages.push(storage::StorageDef::try_from(span, index, item)?),
 				Some(PalletAttr::ValidateUnsigned(_)) if validate_unsigned.is_none() => {
@@ -151,6 +159,18 @@ impl Def {
 			return Err(syn::Error::new(item_span, msg));
 		}
 
+		let mut prefixes = std::collections::HashSet::new();
+		for storage in &storages {
+			if !prefixes.insert(storage.prefix()) {
+				let msg = format!(
+					"Duplicate storage prefix found for `{}`, storage prefixes must be unique, \
+					consider using `#[pallet::storage_prefix]` to rename one of them",
+					storage.prefix(),
+				);
+				return Err(syn::Error::new(storage.prefix_span(), msg));
+			}
+		}
+
 		let def = Def {
 			item,
 			config: config.ok_or_else(|| syn::Error::new(item_span, "Missing `#[pallet::config]`"))?,
@@ -174,6 +194,8 @@ impl Def {
 
 		def.check_instance_usage()?;
 		def.check_event_usage()?;
+		def.check_inherent_usage()?;
+		def.check_validate_unsigned_usage()?;
 
 		Ok(def)
 	}
@@ -201,6 +223,48 @@ impl Def {
 		}
 	}
 
+	/// Check that the dispatchables annotated with `#[pallet::inherent]` are consistent with the
+	/// inherent implementation, i.e. it is implemented and doesn't implement `is_inherent`.
+	fn check_inherent_usage(&self) -> syn::Result<()> {
+		let inherent_call_span = self.call.as_ref()
+			.and_then(|call| call.methods.iter().find(|method| method.inherent))
+			.map(|method| method.name.span());
+
+		match (&self.inherent, inherent_call_span) {
+			(None, Some(span)) => {
+				let msg = "Invalid usage of inherent, a dispatchable is annotated with \
+					`#[pallet::inherent]` but `ProvideInherent` is not implemented (i.e. no use \
+					of `#[pallet::inherent]` on an impl).";
+				Err(syn::Error::new(span, msg))
+			},
+			(Some(inherent), Some(span)) if inherent.has_is_inherent => {
+				let msg = "Invalid usage of inherent, `is_inherent` is generated from the \
+					dispatchables annotated with `#[pallet::inherent]` and must not be \
+					implemented.";
+				Err(syn::Error::new(span, msg))
+			},
+			_ => Ok(()),
+		}
+	}
+
+	/// Check that `ValidateUnsigned` is not implemented if it is generated from the dispatchables
+	/// annotated with `#[pallet::validate_unsigned(..)]`.
+	fn check_validate_unsigned_usage(&self) -> syn::Result<()> {
+		let annotated_call = self.call.as_ref().and_then(|call| {
+			call.methods.iter().find_map(|method| method.validate_unsigned.as_ref())
+		});
+
+		match (&self.validate_unsigned, annotated_call) {
+			(Some(_), Some(annotated_call)) => {
+				let msg = "Invalid usage of validate_unsigned, `ValidateUnsigned` is generated \
+					from the dispatchables annotated with `#[pallet::validate_unsigned(..)]` and \
+					must not be implemented.";
+				Err(syn::Error::new(annotated_call.attr_span, msg))
+			},
+			_ => Ok(()),
+		}
+	}
+
 	/// Check that usage of trait `Config` is consistent with the definition, i.e. it is used with
 	/// instance iff it is defined with instance.
 	fn check_instance_usage(&self) -> syn::Result<()> {
@@ -259,6 +323,14 @@ impl Def {
 		}
 	}
 
+	/// The ident of the call enum, `Call` unless renamed with
+	/// `#[pallet::call(rename = "$Ident")]`.
+	pub fn call_ident(&self, span: proc_macro2::Span) -> syn::Ident {
+		self.call.as_ref()
+			.and_then(|call| call.rename.clone())
+			.unwrap_or_else(|| syn::Ident::new("Call", span))
+	}
+
 	/// Depending on if pallet is instantiable:
 	/// * either `T: Config`
 	/// * or `T: Config<I>, I: 'static`
@@ -382,6 +454,26 @@ mod keyword {
 	syn::custom_keyword!(generate_store);
 	syn::custom_keyword!(Store);
 	syn::custom_keyword!(extra_constants);
+	syn::custom_keyword!(identifier);
+	syn::custom_keyword!(required);
+	syn::custom_keyword!(rename);
+}
+
+/// Parse the optional `(rename = "$Ident")` following some attributes, e.g.
+/// `#[pallet::call(rename = "Dispatch")]`.
+fn parse_rename(input: syn::parse::ParseStream) -> syn::Result<Option<syn::Ident>> {
+	if !input.peek(syn::token::Paren) {
+		return Ok(None)
+	}
+
+	let content;
+	syn::parenthesized!(content in input);
+	content.parse::<keyword::rename>()?;
+	content.parse::<syn::Token![=]>()?;
+	let name = content.parse::<syn::LitStr>()?;
+	name.parse::<syn::Ident>()
+		.map(Some)
+		.map_err(|_| syn::Error::new(name.span(), "Invalid rename, expected an identifier"))
 }
 
 /// Parse attributes for item in pallet module
@@ -390,11 +482,11 @@ enum PalletAttr {
 	Config(proc_macro2::Span),
 	Pallet(proc_macro2::Span),
 	Hooks(proc_macro2::Span),
-	Call(proc_macro2::Span),
-	Error(proc_macro2::Span),
+	Call(proc_macro2::Span, Option<syn::Ident>),
+	Error(proc_macro2::Span, Option<syn::Ident>),
 	Event(proc_macro2::Span),
 	Origin(proc_macro2::Span),
-	Inherent(proc_macro2::Span),
+	Inherent(proc_macro2::Span, Option<syn::Expr>, bool),
 	Storage(proc_macro2::Span),
 	GenesisConfig(proc_macro2::Span),
 	GenesisBuild(proc_macro2::Span),
@@ -409,11 +501,11 @@ impl PalletAttr {
 			Self::Config(span) => *span,
 			Self::Pallet(span) => *span,
 			Self::Hooks(span) => *span,
-			Self::Call(span) => *span,
-			Self::Error(span) => *span,
+			Self::Call(span, _) => *span,
+			Self::Error(span, _) => *span,
 			Self::Event(span) => *span,
 			Self::Origin(span) => *span,
-			Self::Inherent(span) => *span,
+			Self::Inherent(span, _, _) => *span,
 			Self::Storage(span) => *span,
 			Self::GenesisConfig(span) => *span,
 			Self::GenesisBuild(span) => *span,
@@ -440,15 +532,42 @@ impl syn::parse::Parse for PalletAttr {
 		} else if lookahead.peek(keyword::hooks) {
 			Ok(PalletAttr::Hooks(content.parse::<keyword::hooks>()?.span()))
 		} else if lookahead.peek(keyword::call) {
-			Ok(PalletAttr::Call(content.parse::<keyword::call>()?.span()))
+			let span = content.parse::<keyword::call>()?.span();
+			Ok(PalletAttr::Call(span, parse_rename(&content)?))
 		} else if lookahead.peek(keyword::error) {
-			Ok(PalletAttr::Error(content.parse::<keyword::error>()?.span()))
+			let span = content.parse::<keyword::error>()?.span();
+			Ok(PalletAttr::Error(span, parse_rename(&content)?))
 		} else if lookahead.peek(keyword::event) {
 			Ok(PalletAttr::Event(content.parse::<keyword::event>()?.span()))
 		} else if lookahead.peek(keyword::origin) {
 			Ok(PalletAttr::Origin(content.parse::<keyword::origin>()?.span()))
 		} else if lookahead.peek(keyword::inherent) {
-			Ok(PalletAttr::Inherent(content.parse::<keyword::inherent>()?.span()))
+			let span = content.parse::<keyword::inherent>()?.span();
+			let mut identifier = None;
+			let mut required = false;
+			if content.peek(syn::token::Paren) {
+				let args_content;
+				syn::parenthesized!(args_content in content);
+				loop {
+					let lookahead = args_content.lookahead1();
+					if lookahead.peek(keyword::identifier) && identifier.is_none() {
+						args_content.parse::<keyword::identifier>()?;
+						args_content.parse::<syn::Token![=]>()?;
+						identifier = Some(args_content.parse::<syn::Expr>()?);
+					} else if lookahead.peek(keyword::required) && !required {
+						args_content.parse::<keyword::required>()?;
+						required = true;
+					} else {
+						return Err(lookahead.error())
+					}
+
+					if args_content.is_empty() {
+						break
+					}
+					args_content.parse::<syn::Token![,]>()?;
+				}
+			}
+			Ok(PalletAttr::Inherent(span, identifier, required))
 		} else if lookahead.peek(keyword::storage) {
 			Ok(PalletAttr::Storage(content.parse::<keyword::storage>()?.span()))
 		} else if lookahead.peek(keyword::genesis_config) {
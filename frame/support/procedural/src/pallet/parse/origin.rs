@@ -18,6 +18,12 @@
 use syn::spanned::Spanned;
 use super::helper;
 
+/// List of additional token to be used for parsing.
+mod keyword {
+	syn::custom_keyword!(pallet);
+	syn::custom_keyword!(ensure);
+}
+
 /// Definition of the pallet origin type.
 ///
 /// Either:
@@ -29,8 +35,46 @@ pub struct OriginDef {
 	pub index: usize,
 	pub has_instance: bool,
 	pub is_generic: bool,
+	/// Whether the origin is a type alias, i.e. a type on which nothing can be implemented.
+	pub is_type_alias: bool,
 	/// A set of usage of instance, must be check for consistency with trait.
 	pub instances: Vec<helper::InstanceUsage>,
+	/// The variants annotated with `#[pallet::ensure($Ident)]`, only for enums.
+	pub ensures: Vec<EnsureVariantDef>,
+	/// The span of the origin ident.
+	pub origin_span: proc_macro2::Span,
+}
+
+/// A variant of the origin enum for which an `EnsureOrigin` implementation must be generated.
+pub struct EnsureVariantDef {
+	/// The ident of the generated struct implementing `EnsureOrigin`.
+	pub ensure_ident: syn::Ident,
+	/// The ident of the variant.
+	pub variant: syn::Ident,
+	/// The fields of the variant.
+	pub fields: syn::Fields,
+}
+
+/// Attribute on origin variant: `#[pallet::ensure($Ident)]`.
+struct PalletOriginVariantAttr {
+	ensure_ident: syn::Ident,
+}
+
+impl syn::parse::Parse for PalletOriginVariantAttr {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		input.parse::<syn::Token![#]>()?;
+		let content;
+		syn::bracketed!(content in input);
+		content.parse::<keyword::pallet>()?;
+		content.parse::<syn::Token![::]>()?;
+		content.parse::<keyword::ensure>()?;
+
+		let ensure_content;
+		syn::parenthesized!(ensure_content in content);
+		let ensure_ident = ensure_content.parse::<syn::Ident>()?;
+
+		Ok(Self { ensure_ident })
+	}
 }
 
 impl OriginDef {
@@ -48,6 +92,7 @@ impl OriginDef {
 
 		let has_instance = generics.params.len() == 2;
 		let is_generic = !generics.params.is_empty();
+		let origin_span = ident.span();
 
 		let mut instances = vec![];
 		if let Some(u) = helper::check_type_def_optional_gen(&generics, item.span())? {
@@ -70,11 +115,38 @@ impl OriginDef {
 			return Err(syn::Error::new(ident.span(), msg));
 		}
 
+		let is_type_alias = matches!(item, syn::Item::Type(_));
+
+		let mut ensures = vec![];
+		if let syn::Item::Enum(item) = item {
+			for variant in item.variants.iter_mut() {
+				let mut attrs: Vec<PalletOriginVariantAttr> =
+					helper::take_item_pallet_attrs(&mut variant.attrs)?;
+
+				if attrs.len() > 1 {
+					let msg = "Invalid pallet::origin, unexpected multiple `pallet::ensure` on a \
+						variant";
+					return Err(syn::Error::new(variant.span(), msg));
+				}
+
+				if let Some(attr) = attrs.pop() {
+					ensures.push(EnsureVariantDef {
+						ensure_ident: attr.ensure_ident,
+						variant: variant.ident.clone(),
+						fields: variant.fields.clone(),
+					});
+				}
+			}
+		}
+
 		Ok(OriginDef {
 			index,
 			has_instance,
 			is_generic,
+			is_type_alias,
 			instances,
+			ensures,
+			origin_span,
 		})
 	}
 }
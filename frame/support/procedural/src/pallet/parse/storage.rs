@@ -25,13 +25,26 @@ mod keyword {
 	syn::custom_keyword!(Error);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(getter);
+	syn::custom_keyword!(storage_prefix);
 	syn::custom_keyword!(OptionQuery);
 	syn::custom_keyword!(ValueQuery);
 }
 
-/// Parse for `#[pallet::getter(fn dummy)]`
-pub struct PalletStorageAttr {
-	getter: syn::Ident,
+/// Parse for one of:
+/// * `#[pallet::getter(fn dummy)]`
+/// * `#[pallet::storage_prefix = "CustomName"]`
+pub enum PalletStorageAttr {
+	Getter(syn::Ident),
+	StoragePrefix(syn::LitStr),
+}
+
+impl PalletStorageAttr {
+	fn span(&self) -> proc_macro2::Span {
+		match self {
+			Self::Getter(ident) => ident.span(),
+			Self::StoragePrefix(prefix) => prefix.span(),
+		}
+	}
 }
 
 impl syn::parse::Parse for PalletStorageAttr {
@@ -41,12 +54,28 @@ impl syn::parse::Parse for PalletStorageAttr {
 		syn::bracketed!(content in input);
 		content.parse::<keyword::pallet>()?;
 		content.parse::<syn::Token![::]>()?;
-		content.parse::<keyword::getter>()?;
 
-		let generate_content;
-		syn::parenthesized!(generate_content in content);
-		generate_content.parse::<syn::Token![fn]>()?;
-		Ok(Self { getter: generate_content.parse::<syn::Ident>()? })
+		let lookahead = content.lookahead1();
+		if lookahead.peek(keyword::getter) {
+			content.parse::<keyword::getter>()?;
+
+			let generate_content;
+			syn::parenthesized!(generate_content in content);
+			generate_content.parse::<syn::Token![fn]>()?;
+			Ok(Self::Getter(generate_content.parse::<syn::Ident>()?))
+		} else if lookahead.peek(keyword::storage_prefix) {
+			content.parse::<keyword::storage_prefix>()?;
+			content.parse::<syn::Token![=]>()?;
+
+			let prefix = content.parse::<syn::LitStr>()?;
+			// Ensure the prefix is a valid ident, it is used as the name of the storage.
+			syn::parse_str::<syn::Ident>(&prefix.value()).map_err(|_| {
+				syn::Error::new(prefix.span(), "Invalid storage prefix, expected an ident")
+			})?;
+			Ok(Self::StoragePrefix(prefix))
+		} else {
+			Err(lookahead.error())
+		}
 	}
 }
 
@@ -81,6 +110,8 @@ pub struct StorageDef {
 	pub vis: syn::Visibility,
 	/// The type ident, to generate the StoragePrefix for.
 	pub ident: syn::Ident,
+	/// The storage prefix given by `#[pallet::storage_prefix]`, overriding the type ident.
+	pub rename_as: Option<syn::LitStr>,
 	/// The keys and value metadata of the storage.
 	pub metadata: Metadata,
 	/// The doc associated to the storage.
@@ -541,6 +572,16 @@ fn extract_key(ty: &syn::Type) -> syn::Result<syn::Type> {
 }
 
 impl StorageDef {
+	/// The storage prefix, i.e. the name of the storage in the storage keys and in the metadata.
+	pub fn prefix(&self) -> String {
+		self.rename_as.as_ref().map_or_else(|| self.ident.to_string(), |prefix| prefix.value())
+	}
+
+	/// The span of the storage prefix.
+	pub fn prefix_span(&self) -> proc_macro2::Span {
+		self.rename_as.as_ref().map_or_else(|| self.ident.span(), |prefix| prefix.span())
+	}
+
 	pub fn try_from(
 		attr_span: proc_macro2::Span,
 		index: usize,
@@ -552,12 +593,21 @@ impl StorageDef {
 			return Err(syn::Error::new(item.span(), "Invalid pallet::storage, expect item type."));
 		};
 
-		let mut attrs: Vec<PalletStorageAttr> = helper::take_item_pallet_attrs(&mut item.attrs)?;
-		if attrs.len() > 1 {
-			let msg = "Invalid pallet::storage, multiple argument pallet::getter found";
-			return Err(syn::Error::new(attrs[1].getter.span(), msg));
+		let attrs: Vec<PalletStorageAttr> = helper::take_item_pallet_attrs(&mut item.attrs)?;
+		let mut getter = None;
+		let mut rename_as = None;
+		for attr in attrs {
+			let span = attr.span();
+			let duplicate = match attr {
+				PalletStorageAttr::Getter(ident) => getter.replace(ident).is_some(),
+				PalletStorageAttr::StoragePrefix(prefix) => rename_as.replace(prefix).is_some(),
+			};
+			if duplicate {
+				let msg = "Invalid pallet::storage, multiple argument pallet::getter or \
+					pallet::storage_prefix found";
+				return Err(syn::Error::new(span, msg));
+			}
 		}
-		let getter = attrs.pop().map(|attr| attr.getter);
 
 		let cfg_attrs = helper::get_item_cfg_attrs(&item.attrs);
 
@@ -605,6 +655,7 @@ impl StorageDef {
 			index,
 			vis: item.vis.clone(),
 			ident: item.ident.clone(),
+			rename_as,
 			instances,
 			metadata,
 			docs,
@@ -0,0 +1,84 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support_procedural_tools::generate_crate_access_2018;
+use syn::spanned::Spanned;
+
+/// Derive `PalletError`, the maximum encoded size is computed from the fields.
+pub fn derive_pallet_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input: syn::DeriveInput = match syn::parse(input) {
+		Ok(input) => input,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	let frame_support = match generate_crate_access_2018("frame-support") {
+		Ok(c) => c,
+		Err(e) => return e.into_compile_error().into(),
+	};
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let fields_size = |fields: &syn::Fields| {
+		let field_types = fields.iter().map(|field| &field.ty);
+		quote::quote!(
+			0_usize #(
+				.saturating_add(
+					<#field_types as #frame_support::traits::PalletError>::MAX_ENCODED_SIZE
+				)
+			)*
+		)
+	};
+
+	let max_encoded_size = match input.data {
+		syn::Data::Struct(struct_) => fields_size(&struct_.fields),
+		syn::Data::Enum(enum_) => {
+			if enum_.variants.len() > 256 {
+				let msg = "Enum with more than 256 variants not supported by `derive(PalletError)`";
+				return syn::Error::new(input.span(), msg).to_compile_error().into()
+			}
+
+			let variant_sizes = enum_.variants.iter().map(|variant| fields_size(&variant.fields));
+
+			quote::quote!({
+				#[allow(unused_mut)]
+				let mut size = 0_usize;
+				#(
+					let variant_size = #variant_sizes;
+					if variant_size > size {
+						size = variant_size;
+					}
+				)*
+				size.saturating_add(1)
+			})
+		},
+		syn::Data::Union(_) => {
+			let msg = "Union type not supported by `derive(PalletError)`";
+			return syn::Error::new(input.span(), msg).to_compile_error().into()
+		},
+	};
+
+	quote::quote!(
+		const _: () = {
+			impl #impl_generics #frame_support::traits::PalletError
+				for #name #ty_generics #where_clause
+			{
+				const MAX_ENCODED_SIZE: usize = #max_encoded_size;
+			}
+		};
+	).into()
+}
@@ -28,6 +28,9 @@ pub struct GenesisConfigFieldDef {
 	pub typ: syn::Type,
 	pub attrs: Vec<syn::Meta>,
 	pub default: TokenStream,
+	/// Whether `default` was explicitly declared, in which case the field can be omitted when
+	/// deserializing the genesis config.
+	pub has_declared_default: bool,
 }
 
 pub struct GenesisConfigDef {
@@ -125,6 +128,7 @@ impl GenesisConfigDef {
 				typ,
 				attrs: line.doc_attrs.clone(),
 				default,
+				has_declared_default: line.default_value.is_some(),
 			});
 		}
 
@@ -151,6 +155,7 @@ impl GenesisConfigDef {
 				typ: line.typ.clone(),
 				attrs,
 				default,
+				has_declared_default: line.default.is_some(),
 			});
 		}
 
@@ -33,10 +33,36 @@ fn decl_genesis_config_and_impl_default(
 	scrate: &TokenStream,
 	genesis_config: &GenesisConfigDef,
 ) -> TokenStream {
+	let genesis_struct = &genesis_config.genesis_struct;
+	let genesis_impl = &genesis_config.genesis_impl;
+	let genesis_where_clause = &genesis_config.genesis_where_clause;
+
+	// Fields with a declared default can be omitted from the serialized genesis config.
+	let mut default_fns = Vec::new();
 	let config_fields = genesis_config.fields.iter().map(|field| {
 		let (name, typ, attrs) = (&field.name, &field.typ, &field.attrs);
-		quote!( #( #[ #attrs] )* pub #name: #typ, )
-	});
+		let serde_default = if field.has_declared_default {
+			let fn_name = format!("__genesis_config_default_{}", name);
+			let fn_ident = syn::Ident::new(&fn_name, name.span());
+			let default = &field.default;
+			default_fns.push(quote!(
+				#[doc(hidden)]
+				#[cfg(feature = "std")]
+				fn #fn_ident#genesis_impl() -> #typ #genesis_where_clause {
+					#default
+				}
+			));
+			let path = if genesis_config.is_generic {
+				format!("{}::{}", fn_ident, genesis_struct)
+			} else {
+				fn_ident.to_string()
+			};
+			quote!( #[serde(default = #path)] )
+		} else {
+			quote!()
+		};
+		quote!( #( #[ #attrs] )* #serde_default pub #name: #typ, )
+	}).collect::<Vec<_>>();
 
 	let config_field_defaults = genesis_config.fields.iter().map(|field| {
 		let (name, default) = (&field.name, &field.default);
@@ -62,9 +88,6 @@ fn decl_genesis_config_and_impl_default(
 	};
 
 	let genesis_struct_decl = &genesis_config.genesis_struct_decl;
-	let genesis_struct = &genesis_config.genesis_struct;
-	let genesis_impl = &genesis_config.genesis_impl;
-	let genesis_where_clause = &genesis_config.genesis_where_clause;
 	let serde_crate = format!("{}::serde", scrate);
 
 	quote!(
@@ -79,6 +102,8 @@ fn decl_genesis_config_and_impl_default(
 			#( #config_fields )*
 		}
 
+		#( #default_fns )*
+
 		#[cfg(feature = "std")]
 		impl#genesis_impl Default for GenesisConfig#genesis_struct #genesis_where_clause {
 			fn default() -> Self {
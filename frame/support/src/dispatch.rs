@@ -31,6 +31,7 @@ pub use crate::weights::{
 pub use sp_runtime::{traits::Dispatchable, DispatchError};
 pub use crate::traits::{
 	CallMetadata, GetCallMetadata, GetCallName, UnfilteredDispatchable, GetPalletVersion,
+	CallArgument, GetCallArguments,
 };
 
 /// The return typ of a `Dispatchable` in frame. When returned explicitly from
@@ -151,12 +152,13 @@ impl<T> Parameter for T where T: Codec + EncodeLike + Clone + Eq + fmt::Debug {}
 /// # #[macro_use]
 /// # extern crate frame_support;
 /// # use frame_support::dispatch::{DispatchResultWithPostInfo, WithPostDispatchInfo};
+/// # use frame_support::weights::Weight;
 /// # use frame_system::{Config, ensure_signed};
 /// decl_module! {
 /// 	pub struct Module<T: Config> for enum Call where origin: T::Origin {
 /// 		#[weight = 1_000_000]
 /// 		fn my_long_function(origin, do_expensive_calc: bool) -> DispatchResultWithPostInfo {
-/// 			ensure_signed(origin).map_err(|e| e.with_weight(100_000))?;
+/// 			ensure_signed(origin).map_err(|e| e.with_weight(Weight::from_ref_time(100_000)))?;
 /// 			if do_expensive_calc {
 /// 				// do the expensive calculation
 /// 				// ...
@@ -164,7 +166,7 @@ impl<T> Parameter for T where T: Codec + EncodeLike + Clone + Eq + fmt::Debug {}
 /// 				return Ok(None.into());
 /// 			}
 /// 			// expensive calculation not executed: use only a portion of the weight
-/// 			Ok(Some(100_000).into())
+/// 			Ok(Some(Weight::from_ref_time(100_000)).into())
 /// 		}
 /// 	}
 /// }
@@ -1429,6 +1431,31 @@ macro_rules! decl_module {
 		{}
 	};
 
+	(@impl_on_initialize_metered
+		{ $system:ident }
+		$module:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>;
+		{ $( $other_where_bounds:tt )* }
+	) => {
+		impl<$trait_instance: $system::Config + $trait_name$(<I>, $instance: $instantiable)?>
+			$crate::traits::OnInitializeMetered<<$trait_instance as $system::Config>::BlockNumber>
+			for $module<$trait_instance$(, $instance)?> where $( $other_where_bounds )*
+		{
+			fn on_initialize_metered(
+				n: <$trait_instance as $system::Config>::BlockNumber,
+				report: &mut dyn FnMut(&'static str, $crate::weights::Weight),
+			) -> $crate::weights::Weight {
+				let weight = <Self as $crate::traits::OnInitialize<
+					<$trait_instance as $system::Config>::BlockNumber
+				>>::on_initialize(n);
+				let name = <
+					<$trait_instance as $system::Config>::PalletInfo as $crate::traits::PalletInfo
+				>::name::<Self>().unwrap_or("<unknown pallet name>");
+				report(name, weight);
+				weight
+			}
+		}
+	};
+
 	(@impl_on_runtime_upgrade
 		{ $system:ident }
 		$module:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>;
@@ -1872,6 +1899,13 @@ macro_rules! decl_module {
 			$( $on_initialize )*
 		}
 
+		$crate::decl_module! {
+			@impl_on_initialize_metered
+			{ $system }
+			$mod_type<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>;
+			{ $( $other_where_bounds )* }
+		}
+
 		$crate::decl_module! {
 			@impl_on_runtime_upgrade
 			{ $system }
@@ -1918,6 +1952,12 @@ macro_rules! decl_module {
 			$( $integrity_test )*
 		}
 
+		#[cfg(feature = "try-runtime")]
+		impl<$trait_instance: $system::Config + $trait_name $(<I>, $instance: $instantiable)?>
+			$crate::traits::TryState<<$trait_instance as $system::Config>::BlockNumber>
+			for $mod_type<$trait_instance $(, $instance)?> where $( $other_where_bounds )*
+		{}
+
 		/// Can also be called using [`Call`].
 		///
 		/// [`Call`]: enum.Call.html
@@ -2018,6 +2058,28 @@ macro_rules! decl_module {
 			}
 		}
 
+		// Implement GetCallArguments for the Call.
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $crate::dispatch::GetCallArguments
+			for $call_type<$trait_instance $(, $instance)?> where $( $other_where_bounds )*
+		{
+			fn get_call_arguments(
+				&self,
+			) -> $crate::sp_std::vec::Vec<$crate::dispatch::CallArgument> {
+				match *self {
+					$(
+						$call_type::$fn_name( $( ref $param_name ),* ) => $crate::sp_std::vec![
+							$(
+								$crate::__call_argument!(
+									$(#[$codec_attr])* $param_name: $param
+								),
+							)*
+						],
+					)*
+					$call_type::__PhantomItem(_, _) => unreachable!("__PhantomItem should never be used."),
+				}
+			}
+		}
+
 		// Bring `GetPalletVersion` into scope to make it easily usable.
 		pub use $crate::traits::GetPalletVersion as _;
 		// Implement `GetPalletVersion` for `Module`
@@ -2047,6 +2109,11 @@ macro_rules! decl_module {
 			}
 		}
 
+		// Implement `CheckStorageVersion` for `Module`, storage versions are not tracked.
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>
+			$crate::traits::CheckStorageVersion
+			for $mod_type<$trait_instance $(, $instance)?> where $( $other_where_bounds )* {}
+
 		// manual implementation of clone/eq/partialeq because using derive erroneously requires
 		// clone/eq/partialeq from T.
 		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $crate::dispatch::Clone
@@ -2193,6 +2260,16 @@ macro_rules! impl_outer_dispatch {
 				}
 			}
 		}
+		impl $crate::dispatch::GetCallArguments for $call_type {
+			fn get_call_arguments(
+				&self,
+			) -> $crate::sp_std::vec::Vec<$crate::dispatch::CallArgument> {
+				use $crate::dispatch::GetCallArguments;
+				match self {
+					$( $call_type::$camelcase(call) => call.get_call_arguments(), )*
+				}
+			}
+		}
 		impl $crate::dispatch::GetCallMetadata for $call_type {
 			fn get_call_metadata(&self) -> $crate::dispatch::CallMetadata {
 				use $crate::dispatch::GetCallName;
@@ -2558,6 +2635,32 @@ macro_rules! __function_to_metadata {
 	}
 }
 
+/// Convert an argument of a call into a `CallArgument`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __call_argument {
+	(#[compact] $param_name:ident : $param:ty) => {
+		$crate::dispatch::CallArgument {
+			name: stringify!($param_name),
+			ty: concat!("Compact<", stringify!($param), ">"),
+			value: $crate::codec::Encode::encode(
+				&<
+					<$param as $crate::codec::HasCompact>::Type
+						as $crate::codec::EncodeAsRef<'_, $param>
+				>::RefType::from($param_name)
+			),
+		}
+	};
+
+	($param_name:ident : $param:ty) => {
+		$crate::dispatch::CallArgument {
+			name: stringify!($param_name),
+			ty: stringify!($param),
+			value: $crate::codec::Encode::encode($param_name),
+		}
+	};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __check_reserved_fn_name {
@@ -2618,7 +2721,7 @@ mod tests {
 	use crate::weights::{DispatchInfo, DispatchClass, Pays, RuntimeDbWeight};
 	use crate::traits::{
 		CallMetadata, GetCallMetadata, GetCallName, OnInitialize, OnFinalize, OnIdle, OnRuntimeUpgrade,
-		IntegrityTest, Get, PalletInfo,
+		IntegrityTest, Get, PalletInfo, OnInitializeMetered,
 	};
 
 	pub trait Config: system::Config + Sized where Self::AccountId: From<u32> { }
@@ -2679,13 +2782,18 @@ mod tests {
 			#[weight = (5, DispatchClass::Operational)]
 			fn operational(_origin) { unreachable!() }
 
-			fn on_initialize(n: T::BlockNumber,) -> Weight { if n.into() == 42 { panic!("on_initialize") } 7 }
+			fn on_initialize(n: T::BlockNumber,) -> Weight {
+				if n.into() == 42 { panic!("on_initialize") }
+				Weight::from_ref_time(7)
+			}
 			fn on_idle(n: T::BlockNumber, remaining_weight: Weight,) -> Weight {
-				if n.into() == 42 || remaining_weight == 42  { panic!("on_idle") }
-				7
+				if n.into() == 42 || remaining_weight == Weight::from_ref_time(42) {
+					panic!("on_idle")
+				}
+				Weight::from_ref_time(7)
 			}
 			fn on_finalize(n: T::BlockNumber,) { if n.into() == 42 { panic!("on_finalize") } }
-			fn on_runtime_upgrade() -> Weight { 10 }
+			fn on_runtime_upgrade() -> Weight { Weight::from_ref_time(10) }
 			fn offchain_worker() {}
 			/// Some doc
 			fn integrity_test() { panic!("integrity_test") }
@@ -2846,24 +2954,41 @@ mod tests {
 
 	#[test]
 	fn on_initialize_should_work_2() {
-		assert_eq!(<Module<TraitImpl> as OnInitialize<u32>>::on_initialize(10), 7);
+		assert_eq!(
+			<Module<TraitImpl> as OnInitialize<u32>>::on_initialize(10),
+			Weight::from_ref_time(7),
+		);
+	}
+
+	#[test]
+	fn on_initialize_metered_reports_the_weight_of_the_pallet() {
+		let mut reported = Vec::new();
+		let weight = <(Module<TraitImpl>,) as OnInitializeMetered<u32>>::on_initialize_metered(
+			10,
+			&mut |name, weight| reported.push((name, weight)),
+		);
+		assert_eq!(weight, Weight::from_ref_time(7));
+		assert_eq!(reported, vec![("Test", Weight::from_ref_time(7))]);
 	}
 
 	#[test]
 	#[should_panic(expected = "on_idle")]
 	fn on_idle_should_work_1() {
-		<Module<TraitImpl> as OnIdle<u32>>::on_idle(42, 9);
+		<Module<TraitImpl> as OnIdle<u32>>::on_idle(42, Weight::from_ref_time(9));
 	}
 
 	#[test]
 	#[should_panic(expected = "on_idle")]
 	fn on_idle_should_work_2() {
-		<Module<TraitImpl> as OnIdle<u32>>::on_idle(9, 42);
+		<Module<TraitImpl> as OnIdle<u32>>::on_idle(9, Weight::from_ref_time(42));
 	}
 
 	#[test]
 	fn on_idle_should_work_3() {
-		assert_eq!(<Module<TraitImpl> as OnIdle<u32>>::on_idle(10, 11), 7);
+		assert_eq!(
+			<Module<TraitImpl> as OnIdle<u32>>::on_idle(10, Weight::from_ref_time(11)),
+			Weight::from_ref_time(7),
+		);
 	}
 
 	#[test]
@@ -2875,7 +3000,10 @@ mod tests {
 	#[test]
 	fn on_runtime_upgrade_should_work() {
 		sp_io::TestExternalities::default().execute_with(||
-			assert_eq!(<Module<TraitImpl> as OnRuntimeUpgrade>::on_runtime_upgrade(), 10)
+			assert_eq!(
+				<Module<TraitImpl> as OnRuntimeUpgrade>::on_runtime_upgrade(),
+				Weight::from_ref_time(10),
+			)
 		);
 	}
 
@@ -2884,12 +3012,20 @@ mod tests {
 		// operational.
 		assert_eq!(
 			Call::<TraitImpl>::operational().get_dispatch_info(),
-			DispatchInfo { weight: 5, class: DispatchClass::Operational, pays_fee: Pays::Yes },
+			DispatchInfo {
+				weight: Weight::from_ref_time(5),
+				class: DispatchClass::Operational,
+				pays_fee: Pays::Yes,
+			},
 		);
 		// custom basic
 		assert_eq!(
 			Call::<TraitImpl>::aux_3().get_dispatch_info(),
-			DispatchInfo { weight: 3, class: DispatchClass::Normal, pays_fee: Pays::Yes },
+			DispatchInfo {
+				weight: Weight::from_ref_time(3),
+				class: DispatchClass::Normal,
+				pays_fee: Pays::Yes,
+			},
 		);
 	}
 
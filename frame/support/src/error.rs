@@ -154,7 +154,7 @@ macro_rules! decl_error {
 
 				$crate::sp_runtime::DispatchError::Module {
 					index,
-					error: err.as_u8(),
+					error: [err.as_u8(), 0, 0, 0],
 					message: Some(err.as_str()),
 				}
 			}
@@ -17,6 +17,25 @@
 
 //! Macros for generating the runtime genesis config.
 
+/// Deserialize the genesis config of the pallet named `pallet` in the runtime genesis config.
+///
+/// Any error is prefixed with the name of the pallet, so that it can be told which of the pallet
+/// configs is invalid.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn deserialize_pallet_genesis_config<'de, D, T>(
+	deserializer: D,
+	pallet: &str,
+) -> Result<T, D::Error>
+where
+	D: serde::Deserializer<'de>,
+	T: serde::Deserialize<'de>,
+{
+	T::deserialize(deserializer).map_err(|e| serde::de::Error::custom(
+		format_args!("Invalid genesis config of `{}`: {}", pallet, e)
+	))
+}
+
 /// Helper macro for `impl_outer_config`
 #[macro_export]
 macro_rules! __impl_outer_config_types {
@@ -34,6 +34,12 @@ pub trait ProvideInherent {
 	type Error: codec::Encode + IsFatalError;
 	/// The inherent identifier used by this inherent.
 	const INHERENT_IDENTIFIER: self::InherentIdentifier;
+	/// Whether this inherent is declared as required in every block, e.g. with
+	/// `#[pallet::inherent(required)]`.
+	///
+	/// This is only reported in the [`InherentsMetadata`] of the runtime,
+	/// [`Self::is_inherent_required`] decides whether the inherent is required in a block.
+	const INHERENT_REQUIRED: bool = false;
 
 	/// Create an inherent out of the given `InherentData`.
 	fn create_inherent(data: &InherentData) -> Option<Self::Call>;
@@ -81,6 +87,61 @@ pub trait ProvideInherent {
 	fn is_inherent(call: &Self::Call) -> bool;
 }
 
+/// Contains the inherents of the pallet `P`, according to its [`ProvideInherent::is_inherent`].
+///
+/// `Call` is usually the outer call of the runtime, e.g. `InherentsOf<Timestamp>` contains the
+/// calls setting the timestamp.
+pub struct InherentsOf<P>(crate::sp_std::marker::PhantomData<P>);
+
+impl<Call, P> crate::traits::Contains<Call> for InherentsOf<P> where
+	P: ProvideInherent,
+	Call: crate::traits::IsSubType<P::Call>,
+{
+	fn contains(call: &Call) -> bool {
+		call.is_sub_type().map_or(false, P::is_inherent)
+	}
+}
+
+/// The inherent of a pallet is required in a block but is missing from it.
+#[derive(codec::Encode, codec::Decode, Clone, PartialEq, Eq, crate::RuntimeDebug)]
+pub struct MissingInherent {
+	/// The name of the pallet in the runtime.
+	pub pallet: Vec<u8>,
+}
+
+/// The error of an inherent which is required in a block but is missing from it.
+///
+/// The pallets declaring their inherent with `#[pallet::inherent(required)]` report this error
+/// from [`ProvideInherent::is_inherent_required`], so their `Error` must implement this trait.
+pub trait MissingInherentError {
+	/// The error reported when the inherent of `pallet` is missing from the block.
+	fn missing_inherent(pallet: &'static str) -> Self;
+}
+
+impl<E: codec::Encode + From<MissingInherent>> MissingInherentError for MakeFatalError<E> {
+	fn missing_inherent(pallet: &'static str) -> Self {
+		E::from(MissingInherent { pallet: pallet.as_bytes().to_vec() }).into()
+	}
+}
+
+/// The inherent of a pallet of the runtime.
+#[derive(Clone, PartialEq, Eq, crate::RuntimeDebug)]
+pub struct InherentMetadata {
+	/// The name of the pallet in the runtime.
+	pub pallet: &'static str,
+	/// The identifier of the inherent.
+	pub identifier: InherentIdentifier,
+	/// Whether the inherent is declared as required in every block, see
+	/// [`ProvideInherent::INHERENT_REQUIRED`].
+	pub required: bool,
+}
+
+/// The inherents of a runtime, implemented by [`impl_outer_inherent!`].
+pub trait InherentsMetadata {
+	/// The inherents of the pallets, in the order in which they are created and checked.
+	fn inherents() -> Vec<InherentMetadata>;
+}
+
 /// Implement the outer inherent.
 /// All given modules need to implement [`ProvideInherent`].
 ///
@@ -201,6 +262,11 @@ macro_rules! impl_outer_inherent {
 							});
 
 							if !found {
+								$crate::log::error!(
+									target: $crate::LOG_TARGET,
+									"Block is missing the mandatory inherent of {}",
+									stringify!($module),
+								);
 								result.put_error(
 									$module::INHERENT_IDENTIFIER, &e
 								).expect("There is only one fatal error; qed");
@@ -225,13 +291,30 @@ macro_rules! impl_outer_inherent {
 			}
 		}
 
+		impl $crate::inherent::InherentsMetadata for $runtime {
+			fn inherents() -> $crate::inherent::Vec<$crate::inherent::InherentMetadata> {
+				use $crate::inherent::ProvideInherent;
+
+				let mut inherents = $crate::inherent::Vec::new();
+				$(
+					inherents.push($crate::inherent::InherentMetadata {
+						pallet: stringify!($module),
+						identifier: $module::INHERENT_IDENTIFIER,
+						required: $module::INHERENT_REQUIRED,
+					});
+				)*
+				inherents
+			}
+		}
+
 		impl $crate::traits::EnsureInherentsAreFirst<$block> for $runtime {
-			fn ensure_inherents_are_first(block: &$block) -> Result<(), u32> {
+			fn ensure_inherents_are_first(block: &$block) -> Result<u32, u32> {
 				use $crate::inherent::ProvideInherent;
 				use $crate::traits::{IsSubType, ExtrinsicCall};
 				use $crate::sp_runtime::traits::Block as _;
 
 				let mut first_signed_observed = false;
+				let mut num_inherents = 0u32;
 
 				for (i, xt) in block.extrinsics().iter().enumerate() {
 					let is_signed = $crate::inherent::Extrinsic::is_signed(xt).unwrap_or(false);
@@ -259,9 +342,13 @@ macro_rules! impl_outer_inherent {
 					if first_signed_observed && is_inherent {
 						return Err(i as u32)
 					}
+
+					if is_inherent {
+						num_inherents += 1;
+					}
 				}
 
-				Ok(())
+				Ok(num_inherents)
 			}
 		}
 	};
@@ -347,6 +434,7 @@ mod tests {
 		type Call = CallTest2;
 		type Error = sp_inherents::MakeFatalError<()>;
 		const INHERENT_IDENTIFIER: sp_inherents::InherentIdentifier = *b"test1234";
+		const INHERENT_REQUIRED: bool = true;
 
 		fn create_inherent(_: &InherentData) -> Option<Self::Call> {
 			Some(CallTest2::RequiredInherent)
@@ -417,6 +505,25 @@ mod tests {
 		assert_eq!(expected, inherents);
 	}
 
+	#[test]
+	fn inherents_metadata_works() {
+		assert_eq!(
+			<Runtime as InherentsMetadata>::inherents(),
+			vec![
+				InherentMetadata {
+					pallet: "ModuleTest",
+					identifier: *b"test1235",
+					required: false,
+				},
+				InherentMetadata {
+					pallet: "ModuleTest2",
+					identifier: *b"test1234",
+					required: true,
+				},
+			],
+		);
+	}
+
 	#[test]
 	fn check_inherents_works() {
 		let block = Block::new(
@@ -494,7 +601,7 @@ mod tests {
 			],
 		);
 
-		assert!(Runtime::ensure_inherents_are_first(&block).is_ok());
+		assert_eq!(Runtime::ensure_inherents_are_first(&block), Ok(2));
 	}
 
 	#[test]
@@ -68,6 +68,7 @@ pub mod error;
 pub mod traits;
 pub mod weights;
 pub mod instances;
+pub mod migrations;
 
 pub use self::hash::{
 	Twox256, Twox128, Blake2_256, Blake2_128, Identity, Twox64Concat, Blake2_128Concat, Hashable,
@@ -77,6 +78,7 @@ pub use self::storage::{
 	StorageValue, StorageMap, StorageDoubleMap, StorageNMap, StoragePrefixedMap,
 	IterableStorageMap, IterableStorageDoubleMap, IterableStorageNMap, migration,
 	bounded_vec::{BoundedVec, BoundedSlice}, weak_bounded_vec::WeakBoundedVec,
+	bounded_btree_map::BoundedBTreeMap, bounded_btree_set::BoundedBTreeSet,
 };
 pub use self::dispatch::{Parameter, Callable};
 pub use sp_runtime::{self, ConsensusEngineId, print, traits::Printable};
@@ -596,6 +598,22 @@ pub use frame_support_procedural::DebugNoBound;
 /// ```
 pub use frame_support_procedural::DefaultNoBound;
 
+/// Derive [`traits::PalletError`], the maximum encoded size is computed from the fields.
+///
+/// This is useful to nest error types defined in sub-modules in the `#[pallet::error]` enum:
+/// ```
+/// # use frame_support::{PalletError, traits::PalletError as _};
+/// # use codec::{Encode, Decode};
+/// #[derive(Encode, Decode, PalletError)]
+/// enum ArithmeticError {
+///		Overflow,
+///		DivisionByZero { numerator: u16 },
+/// }
+///
+/// assert_eq!(ArithmeticError::MAX_ENCODED_SIZE, 3);
+/// ```
+pub use frame_support_procedural::PalletError;
+
 /// Assert the annotated function is executed within a storage transaction.
 ///
 /// The assertion is enabled for native execution and when `debug_assertions` are enabled.
@@ -664,6 +682,22 @@ macro_rules! ensure {
 	}}
 }
 
+/// Mark an execution path as one that should never be executed.
+///
+/// Logs an error and panics when `debug_assertions` are enabled, e.g. in tests, but does not
+/// panic in production. An optional proof is logged along with the error.
+///
+/// Used as `defensive!()` or `defensive!("the index is checked above")`.
+#[macro_export]
+macro_rules! defensive {
+	() => {
+		$crate::traits::defensive_path("defensive!")
+	};
+	( $proof:expr $(,)? ) => {
+		$crate::traits::defensive_path($proof)
+	};
+}
+
 /// Evaluate an expression, assert it returns an expected `Err` value and that
 /// runtime storage has not been mutated (i.e. expression is a no-operation).
 ///
@@ -1236,10 +1270,11 @@ pub mod pallet_prelude {
 	pub use crate::{
 		EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, DebugNoBound, CloneNoBound, Twox256,
 		Twox128, Blake2_256, Blake2_128, Identity, Twox64Concat, Blake2_128Concat, ensure,
-		RuntimeDebug, storage,
+		defensive, RuntimeDebug, storage, PalletError,
 		traits::{
-			Get, Hooks, IsType, GetPalletVersion, EnsureOrigin, PalletInfoAccess, StorageInfoTrait,
-			ConstU32, GetDefault, MaxEncodedLen,
+			Get, Hooks, IsType, GetPalletVersion, EnsureOrigin, EnsureOriginWithArg, PalletInfoAccess,
+			StorageInfoTrait, ConstU32, GetDefault, MaxEncodedLen, StorageVersion,
+			GetStorageVersion, Defensive, DefensiveOption, DefensiveSaturating, PalletError,
 		},
 		dispatch::{DispatchResultWithPostInfo, Parameter, DispatchError, DispatchResult},
 		weights::{DispatchClass, Pays, Weight},
@@ -1247,7 +1282,10 @@ pub mod pallet_prelude {
 			Key as NMapKey, StorageDoubleMap, StorageMap, StorageNMap, StorageValue, ValueQuery,
 			OptionQuery,
 		},
-		storage::bounded_vec::BoundedVec,
+		storage::{
+			bounded_vec::BoundedVec, bounded_btree_map::BoundedBTreeMap,
+			bounded_btree_set::BoundedBTreeSet,
+		},
 	};
 	pub use codec::{Encode, Decode};
 	pub use crate::inherent::{InherentData, InherentIdentifier, ProvideInherent};
@@ -1363,6 +1401,20 @@ pub mod pallet_prelude {
 /// This require all storage to implement the trait [`traits::StorageInfoTrait`], thus all keys
 /// and value types must bound [`traits::MaxEncodedLen`].
 ///
+/// To declare the current version of the storage layout of the pallet, use the attribute
+/// `#[pallet::storage_version($expr)]`, e.g.:
+/// ```ignore
+/// const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+///
+/// #[pallet::pallet]
+/// #[pallet::storage_version(STORAGE_VERSION)]
+/// pub struct Pallet<T>(_);
+/// ```
+/// The version is written to the state at genesis, then the migrations of the pallet are expected
+/// to write it with `STORAGE_VERSION.put::<Pallet<T>>()` once they migrated the storage. Comparing
+/// `Pallet::<T>::on_chain_storage_version()` against the version a migration targets makes the
+/// migration idempotent. If not declared, the storage version is the default version `0`.
+///
 /// ### Macro expansion:
 ///
 /// The macro add this attribute to the struct definition:
@@ -1378,7 +1430,11 @@ pub mod pallet_prelude {
 ///
 /// It implements on pallet:
 /// * [`traits::GetPalletVersion`]
-/// * [`traits::OnGenesis`]: contains some logic to write pallet version into storage.
+/// * [`traits::GetStorageVersion`] and [`traits::CheckStorageVersion`]: the storage version is
+///   checked by the integrity test of `construct_runtime`, and after the runtime upgrade with the
+///   feature `try-runtime`.
+/// * [`traits::OnGenesis`]: contains some logic to write pallet version and storage version into
+///   storage.
 /// * `ModuleErrorMetadata`: using error declared or no metadata.
 ///
 /// It declare `type Module` type alias for `Pallet`, used by [`construct_runtime`].
@@ -1426,7 +1482,12 @@ pub mod pallet_prelude {
 /// logic. E.g. logic to write pallet version into storage.
 ///
 /// NOTE: The macro also adds some tracing logic when implementing the above traits. The following
-///  hooks emit traces: `on_initialize`, `on_finalize` and `on_runtime_upgrade`.
+///  hooks emit traces: `on_initialize`, `on_idle`, `on_finalize` and `on_runtime_upgrade`.
+///
+/// NOTE: With the `try-runtime` feature, the macro also implements `TryState` using
+/// `Hooks::try_state`. It is meant to check the invariants of the pallet storage against the
+/// live state, e.g. with the `try-runtime` cli, and is aggregated for all the pallets by
+/// `construct_runtime` through `AllPallets`.
 ///
 /// # Call: `#[pallet::call]` optional
 ///
@@ -1467,6 +1528,12 @@ pub mod pallet_prelude {
 /// impl<T: Config> Pallet<T> {}
 /// ```
 ///
+/// The generated enum is named `Call`, it can be renamed with
+/// `#[pallet::call(rename = "$Ident")]`, e.g. if the pallet already defines or imports a type named
+/// `Call`. The renamed enum is then used by the `Callable` implementation, the generated
+/// `ProvideInherent` and `ValidateUnsigned` items and the JSON ABI; the outer runtime call still
+/// refers to it through `Callable`, so `construct_runtime` is unaffected.
+///
 /// **WARNING**: modifying dispatchables, changing their order, removing some must be done with
 /// care. Indeed this will change the outer runtime call type (which is an enum with one variant
 /// per pallet), this outer runtime call can be stored on-chain (e.g. in pallet-scheduler).
@@ -1478,9 +1545,20 @@ pub mod pallet_prelude {
 /// `Clone`, `Eq`, `PartialEq`, `Debug` (with stripped implementation in `not("std")`), `Encode`,
 /// `Decode`, `GetDispatchInfo`, `GetCallName`, `UnfilteredDispatchable`.
 ///
+/// For each dispatchable `$fn_name`, the macro also implements on `Call` a constructor
+/// `$fn_name_call($some_arg: $some_type, ...) -> Self`, so that calls can be built without
+/// spelling out the variant, e.g. `Call::<T>::transfer_call(dest, value)`. The function
+/// `encoded(&self) -> Vec<u8>` returns the encoded bytes of the call. A type alias `CallOf<T>`
+/// (or `CallOf<T, I = ()>` for instantiable pallets) to `Call` is generated as well.
+///
 /// The macro implement on `Pallet`, the `Callable` trait and a function `call_functions` which
 /// returns the dispatchable metadatas.
 ///
+/// With the feature `abi-json` enabled, the macro additionally adds the constant `CALL_ABI_JSON`
+/// to the pallet module: a JSON description of the dispatchables (name, index, arguments with
+/// their types, and docs). A small binary or build step can write it to a file, which allows
+/// generating bindings for the calls without running a node to fetch the metadata.
+///
 /// # Extra constants: `#[pallet::extra_constants]` optional
 ///
 /// Allow to define some extra constants to put into constant metadata.
@@ -1514,17 +1592,31 @@ pub mod pallet_prelude {
 /// pub enum Error<T> {
 /// 	/// $some_optional_doc
 /// 	$SomeFieldLessVariant,
+/// 	/// $some_variant_doc
+/// 	$SomeVariantWithFields($SomePalletErrorType, ..),
 /// 	...
 /// }
 /// ```
-/// I.e. a regular rust enum named `Error`, with generic `T` and fieldless variants.
+/// I.e. a regular rust enum named `Error`, with generic `T` and variants without discriminant.
+/// The fields of the variants must implement `traits::PalletError`, which can be derived with
+/// `#[derive(PalletError)]`, this allows to nest error types defined in other modules.
+/// The encoded error, i.e. the index of the variant followed by its fields, must fit in
+/// `sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE` bytes, this is checked by the integrity test of
+/// the pallet.
 /// The generic `T` mustn't bound anything and where clause is not allowed. But bounds and where
 /// clause shouldn't be needed for any usecase.
 ///
+/// The enum can be given another name with `#[pallet::error(rename = "$Ident")]`, the enum must
+/// then be named `$Ident`, e.g. `#[pallet::error(rename = "StakingError")] pub enum
+/// StakingError<T> { .. }`.
+///
+/// Events can't be renamed, `construct_runtime` refers to the event of a pallet as `Event`.
+///
 /// ### Macro expansion
 ///
 /// The macro implements `Debug` trait and functions `as_u8` using variant position, and `as_str`
-/// using variant doc.
+/// using variant doc. It also defines the constant `MAX_ENCODED_SIZE` and the function
+/// `as_module_error` returning the encoded error.
 ///
 /// The macro implements `From<Error<T>>` for `&'static str`.
 /// The macro implements `From<Error<T>>` for `DispatchError`.
@@ -1647,6 +1739,22 @@ pub mod pallet_prelude {
 /// pub(super) type MyStorage<T> = StorageMap<_, Blake2_128Concat, u32, u32>;
 /// ```
 ///
+/// The optional attribute `#[pallet::storage_prefix = "SomeName"]` allow to define the storage
+/// prefix to use instead of the name of the storage type, the prefix is also the name of the
+/// storage in the metadata. This allows to keep the storage layout of a pallet ported from
+/// `decl_storage!`, e.g. when a storage was declared with a name which is not a valid type name.
+///
+/// E.g:
+/// ```ignore
+/// #[pallet::storage]
+/// #[pallet::storage_prefix = "foo"]
+/// pub(super) type Foo<T> = StorageValue<_, u32>;
+/// ```
+///
+/// The function [`storage::migration::storage_layout_diff`] allows to check in a test that the
+/// storage layout is unchanged by the port, and the `storage-layout-check` binary of
+/// `frame-storage-layout-check` does the same check between the metadata of two runtimes.
+///
 /// The optional attributes `#[cfg(..)]` allow conditional compilation for the storage.
 ///
 /// E.g:
@@ -1726,6 +1834,20 @@ pub mod pallet_prelude {
 /// }
 /// ```
 ///
+/// Serde attributes (e.g. `#[serde(skip)]`, `#[serde(default)]` or `#[serde(rename = "..")]`) can
+/// be used on the fields.
+///
+/// Fields of a struct can be annotated with `#[pallet::default($expr)]`, the expression can use
+/// the generics of the struct, e.g. to fall back to a runtime constant:
+/// ```ignore
+/// #[pallet::genesis_config]
+/// pub struct GenesisConfig<T: Config> {
+/// 	#[pallet::default(T::MinimumBalance::get())]
+/// 	pub minimum_balance: BalanceOf<T>,
+/// 	pub balances: Vec<(T::AccountId, BalanceOf<T>)>,
+/// }
+/// ```
+///
 /// ### Macro expansion
 ///
 /// Macro will add the following attribute on it:
@@ -1736,6 +1858,11 @@ pub mod pallet_prelude {
 /// * `#[serde(bound(serialize = ""))]`
 /// * `#[serde(bound(deserialize = ""))]`
 ///
+/// If some fields are annotated with `#[pallet::default($expr)]`:
+/// * those fields are optional when deserializing and default to `$expr`,
+/// * the macro implements `Default` on the struct, using `$expr` for those fields and
+///   `Default::default()` for the others. Thus `Default` must not be implemented manually.
+///
 /// # Genesis build: `#[pallet::genesis_build]` optional
 ///
 /// Allow to define how genesis_configuration is built.
@@ -1780,10 +1907,50 @@ pub mod pallet_prelude {
 /// I.e. a trait implementation with bound `T: Config`, of trait `ProvideInherent` for type
 /// `Pallet<T>`, and some optional where clause.
 ///
+/// The repetitive parts of the implementation can be left to the macro, e.g.:
+/// ```ignore
+/// #[pallet::call]
+/// impl<T: Config> Pallet<T> {
+/// 	#[pallet::weight(0)]
+/// 	#[pallet::inherent]
+/// 	fn set(origin: OriginFor<T>, now: T::Moment) -> DispatchResultWithPostInfo {
+/// 		// ...
+/// 	}
+/// }
+///
+/// #[pallet::inherent(identifier = *b"example0")]
+/// impl<T: Config> ProvideInherent for Pallet<T> {
+/// 	fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+/// 		// ...
+/// 	}
+/// }
+/// ```
+/// * the identifier of the inherent can be given as `#[pallet::inherent(identifier = $expr)]`
+///   instead of `const INHERENT_IDENTIFIER`.
+/// * the dispatchables which are inherents can be annotated with `#[pallet::inherent]` in
+///   `#[pallet::call]` instead of implementing `is_inherent`.
+/// * the inherent can be declared as required in every block with
+///   `#[pallet::inherent(required)]` instead of implementing `is_inherent_required`, e.g.
+///   `#[pallet::inherent(identifier = *b"example0", required)]`. The block import then fails
+///   with the error given by [`inherent::MissingInherentError`], naming the pallet, if the block
+///   doesn't contain the inherent. The default error is then
+///   `MakeFatalError<inherent::MissingInherent>`; a declared `Error` must implement
+///   `MissingInherentError`.
+///
 /// ### Macro expansion
 ///
-/// Macro make currently no use of this information, but it might use this information in the
-/// future to give information directly to construct_runtime.
+/// The macro adds the items of `ProvideInherent` which are not implemented:
+/// * `type Call = Call<T>`,
+/// * `type Error = MakeFatalError<()>`, or `MakeFatalError<MissingInherent>` if the inherent is
+///   declared as `required`,
+/// * `const INHERENT_IDENTIFIER` set to the identifier given in the attribute, if any,
+/// * `fn is_inherent` returning true for the dispatchables annotated with `#[pallet::inherent]`,
+///   if any,
+/// * `const INHERENT_REQUIRED` set to true and `fn is_inherent_required` always returning the
+///   `MissingInherentError` of `Error` for the pallet, if the inherent is declared as `required`.
+///
+/// On std the macro also implements `Pallet::decode_inherent_error` which decodes the error
+/// reported by `check_inherents` for the identifier of the pallet, if `Error` implements `Decode`.
 ///
 /// # Validate unsigned: `#[pallet::validate_unsigned]` optional
 ///
@@ -1802,10 +1969,44 @@ pub mod pallet_prelude {
 /// NOTE: There is also `sp_runtime::traits::SignedExtension` that can be used to add some specific
 /// logic for transaction validation.
 ///
+/// Instead of implementing the trait, the dispatchables which can be submitted as unsigned
+/// transactions can be annotated in `#[pallet::call]`:
+/// ```ignore
+/// #[pallet::weight(0)]
+/// #[pallet::validate_unsigned(
+/// 	priority = T::UnsignedPriority::get(),
+/// 	longevity = 5,
+/// 	provides = payload.block_number,
+/// 	check = Self::check_block_number(&payload.block_number),
+/// 	signed_payload(payload, signature, T::AuthorityId),
+/// )]
+/// fn submit_price(
+/// 	origin: OriginFor<T>,
+/// 	payload: PricePayload<T::Public, T::BlockNumber>,
+/// 	signature: T::Signature,
+/// ) -> DispatchResultWithPostInfo {
+/// 	// ...
+/// }
+/// ```
+/// All the arguments of the attribute are optional, the arguments of the call are available by
+/// reference in their expressions:
+/// * `priority` and `longevity`: the priority and longevity of the transaction.
+/// * `provides`: the tag provided by the transaction, the tuple of all the arguments of the call
+///   if not given. It is prefixed with the path of the dispatchable, so that a transaction can't
+///   be replayed in the pool.
+/// * `check`: an expression of type `Result<(), TransactionValidityError>` which must succeed for
+///   the transaction to be valid, e.g. to reject transactions already applied on chain.
+/// * `signed_payload($payload, $signature, $AuthorityId)`: the transaction is only valid if
+///   `$signature` is a valid signature of the `SignedPayload` `$payload` for `$AuthorityId`.
+///
 /// ### Macro expansion
 ///
-/// Macro make currently no use of this information, but it might use this information in the
-/// future to give information directly to construct_runtime.
+/// If some dispatchables are annotated with `#[pallet::validate_unsigned(..)]`, the macro
+/// implements `ValidateUnsigned` for `Pallet`, which validates those dispatchables and rejects
+/// the other ones. In this case the trait must not be implemented by the pallet.
+///
+/// Otherwise the macro makes currently no use of this information, but it might use this
+/// information in the future to give information directly to construct_runtime.
 ///
 /// # Origin: `#[pallet::origin]` optional
 ///
@@ -1825,6 +2026,37 @@ pub mod pallet_prelude {
 ///
 /// NOTE: for instantiable pallet, origin must be generic over T and I.
 ///
+/// Variants of an enum origin can be annotated with `#[pallet::ensure($EnsureName)]`, variants
+/// can carry data:
+/// ```ignore
+/// #[pallet::origin]
+/// pub enum Origin<T: Config> {
+/// 	#[pallet::ensure(EnsureMembers)]
+/// 	Members(u32, u32),
+/// 	#[pallet::ensure(EnsureMember)]
+/// 	Member(T::AccountId),
+/// 	_Phantom(PhantomData<T>),
+/// }
+/// ```
+///
+/// ### Macro expansion
+///
+/// The macro implements on enum and struct origins the function `try_from_outer`, converting an
+/// outer origin to the pallet origin, returning the outer origin on failure; and the function
+/// `into_outer`, converting the pallet origin to an outer origin.
+///
+/// For each variant annotated with `#[pallet::ensure($EnsureName)]`, the macro generates the
+/// struct `$EnsureName` generic over the same generics as the origin, and implements on it:
+/// * `EnsureOrigin<O>` for any outer origin `O` convertible from and into the pallet origin. The
+///   `Success` type is the data of the variant: `()` if it has no fields, the field type if it has
+///   one field, and the tuple of the field types otherwise.
+/// * `EnsureOriginWithArg<O, Success>`, which also checks that the data of the variant is equal to
+///   the argument, it is only implemented if the fields implement `PartialEq` and `Clone`.
+///
+/// `successful_origin` builds the variant using `Default::default()` for each field (or the
+/// argument for `EnsureOriginWithArg`), thus fields must implement `Default` when the
+/// `runtime-benchmarks` feature is enabled.
+///
 /// # General notes on instantiable pallet
 ///
 /// An instantiable pallet is one where Config is generic, i.e. `Config<I>`. This allow runtime to
@@ -0,0 +1,273 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to write the migrations of the storage of pallets.
+
+use sp_std::marker::PhantomData;
+use crate::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::{RuntimeDbWeight, Weight},
+};
+
+/// A migration which is only executed when the on-chain storage version of `Pallet` is `FROM`,
+/// and which then sets the on-chain storage version to `TO`.
+///
+/// `Inner` is the migration itself, it doesn't need to check or update the storage version. The
+/// read and the write of the storage version are charged with `DbWeight`. When the storage version
+/// is not `FROM` the migration is skipped with a warning, so it is safe to leave it in the
+/// migrations of the runtime for longer than one runtime upgrade.
+///
+/// # Example
+///
+/// ```ignore
+/// pub type MigrateV1ToV2<T> = frame_support::migrations::VersionedMigration<
+/// 	1,
+/// 	2,
+/// 	InnerMigrateV1ToV2<T>,
+/// 	crate::pallet::Pallet<T>,
+/// 	<T as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub struct VersionedMigration<const FROM: u16, const TO: u16, Inner, Pallet, DbWeight>(
+	PhantomData<(Inner, Pallet, DbWeight)>,
+);
+
+impl<
+	const FROM: u16,
+	const TO: u16,
+	Inner: OnRuntimeUpgrade,
+	Pallet: GetStorageVersion + PalletInfoAccess,
+	DbWeight: Get<RuntimeDbWeight>,
+> VersionedMigration<FROM, TO, Inner, Pallet, DbWeight> {
+	/// The key under which `pre_upgrade` notes whether the migration is going to be executed.
+	#[cfg(feature = "try-runtime")]
+	fn will_execute_key() -> [u8; 32] {
+		use codec::Encode;
+
+		let prefix = sp_io::hashing::twox_128(crate::traits::ON_RUNTIME_UPGRADE_PREFIX);
+		let ident = sp_io::hashing::twox_128(&(Pallet::name(), FROM, TO).encode());
+
+		let mut final_key = [0u8; 32];
+		final_key[..16].copy_from_slice(&prefix);
+		final_key[16..].copy_from_slice(&ident);
+
+		final_key
+	}
+}
+
+impl<
+	const FROM: u16,
+	const TO: u16,
+	Inner: OnRuntimeUpgrade,
+	Pallet: GetStorageVersion + PalletInfoAccess,
+	DbWeight: Get<RuntimeDbWeight>,
+> OnRuntimeUpgrade for VersionedMigration<FROM, TO, Inner, Pallet, DbWeight> {
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::on_chain_storage_version();
+		if on_chain_version != StorageVersion::new(FROM) {
+			log::warn!(
+				target: crate::LOG_TARGET,
+				"{}: skipping the migration from storage version {} to {}, the on-chain storage \
+				version is {:?}",
+				Pallet::name(),
+				FROM,
+				TO,
+				on_chain_version,
+			);
+			return DbWeight::get().reads(1)
+		}
+
+		log::info!(
+			target: crate::LOG_TARGET,
+			"{}: migrating from storage version {} to {}",
+			Pallet::name(),
+			FROM,
+			TO,
+		);
+		let weight = Inner::on_runtime_upgrade();
+		StorageVersion::new(TO).put::<Pallet>();
+
+		weight.saturating_add(DbWeight::get().reads_writes(1, 1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		let will_execute = Pallet::on_chain_storage_version() == StorageVersion::new(FROM);
+		crate::storage::unhashed::put(&Self::will_execute_key(), &will_execute);
+
+		if will_execute {
+			Inner::pre_upgrade()
+		} else {
+			Ok(())
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		let executed = crate::storage::unhashed::take(&Self::will_execute_key()).unwrap_or(false);
+		if !executed {
+			return Ok(())
+		}
+
+		if Pallet::on_chain_storage_version() != StorageVersion::new(TO) {
+			return Err("The storage version was not updated by the migration")
+		}
+		Inner::post_upgrade()
+	}
+}
+
+/// A migration which moves the storage of `Pallet` from the prefixes of its previous names, as
+/// declared with `#[renamed_from(..)]` in `construct_runtime!`, to the prefix of its current name.
+///
+/// The move of each key is charged with `DbWeight`. Once the storage is moved the migration is a
+/// no-op, it can be removed together with the `#[renamed_from(..)]` attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// construct_runtime!(
+/// 	pub enum Runtime where ... {
+/// 		#[renamed_from(OldName)]
+/// 		NewName: pallet_example::{Pallet, Call, Storage},
+/// 	}
+/// );
+///
+/// pub type Migrations = MoveRenamedPallet<NewName, <Runtime as frame_system::Config>::DbWeight>;
+/// ```
+pub struct MoveRenamedPallet<Pallet, DbWeight>(PhantomData<(Pallet, DbWeight)>);
+
+impl<
+	Pallet: PalletInfoAccess,
+	DbWeight: Get<RuntimeDbWeight>,
+> OnRuntimeUpgrade for MoveRenamedPallet<Pallet, DbWeight> {
+	fn on_runtime_upgrade() -> Weight {
+		let moved = crate::storage::migration::move_renamed_pallet::<Pallet>();
+		if moved > 0 {
+			log::info!(
+				target: crate::LOG_TARGET,
+				"{}: moved {} keys from the previous names {:?}",
+				Pallet::name(),
+				moved,
+				Pallet::renamed_from(),
+			);
+		}
+
+		let moved = moved as u64;
+		DbWeight::get().reads_writes(
+			moved.saturating_add(Pallet::renamed_from().len() as u64),
+			moved.saturating_mul(2),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::parameter_types;
+	use sp_io::TestExternalities;
+
+	struct MockedPallet;
+
+	impl PalletInfoAccess for MockedPallet {
+		fn index() -> usize {
+			0
+		}
+
+		fn name() -> &'static str {
+			"MockedPallet"
+		}
+
+		fn renamed_from() -> &'static [&'static str] {
+			&["OldMockedPallet"]
+		}
+	}
+
+	impl GetStorageVersion for MockedPallet {
+		fn current_storage_version() -> StorageVersion {
+			StorageVersion::new(2)
+		}
+
+		fn on_chain_storage_version() -> StorageVersion {
+			StorageVersion::get::<Self>()
+		}
+	}
+
+	const MIGRATED_KEY: &[u8] = b":migrated:";
+
+	struct InnerMigration;
+
+	impl OnRuntimeUpgrade for InnerMigration {
+		fn on_runtime_upgrade() -> Weight {
+			crate::storage::unhashed::put(MIGRATED_KEY, &true);
+			Weight::from_ref_time(100)
+		}
+	}
+
+	parameter_types! {
+		pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 10, proof_size: 0 };
+	}
+
+	type Migration = VersionedMigration<1, 2, InnerMigration, MockedPallet, DbWeight>;
+
+	#[test]
+	fn migration_is_executed_from_the_expected_version() {
+		TestExternalities::default().execute_with(|| {
+			StorageVersion::new(1).put::<MockedPallet>();
+
+			assert_eq!(Migration::on_runtime_upgrade(), Weight::from_ref_time(111));
+			assert_eq!(crate::storage::unhashed::get(MIGRATED_KEY), Some(true));
+			assert_eq!(MockedPallet::on_chain_storage_version(), StorageVersion::new(2));
+		});
+	}
+
+	#[test]
+	fn migration_is_skipped_from_other_versions() {
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(Migration::on_runtime_upgrade(), Weight::from_ref_time(1));
+			assert_eq!(crate::storage::unhashed::get::<bool>(MIGRATED_KEY), None);
+			assert_eq!(MockedPallet::on_chain_storage_version(), StorageVersion::new(0));
+
+			StorageVersion::new(2).put::<MockedPallet>();
+			assert_eq!(Migration::on_runtime_upgrade(), Weight::from_ref_time(1));
+			assert_eq!(crate::storage::unhashed::get::<bool>(MIGRATED_KEY), None);
+			assert_eq!(MockedPallet::on_chain_storage_version(), StorageVersion::new(2));
+		});
+	}
+
+	#[test]
+	fn renamed_pallet_is_moved() {
+		use crate::{hash::{StorageHasher, Twox128}, storage::unhashed};
+
+		let key = |pallet: &str| [Twox128::hash(pallet.as_bytes()), Twox128::hash(b"Foo")].concat();
+
+		TestExternalities::default().execute_with(|| {
+			unhashed::put(&key("OldMockedPallet"), &42u32);
+
+			assert_eq!(
+				MoveRenamedPallet::<MockedPallet, DbWeight>::on_runtime_upgrade(),
+				Weight::from_ref_time(22),
+			);
+			assert_eq!(unhashed::get::<u32>(&key("OldMockedPallet")), None);
+			assert_eq!(unhashed::get::<u32>(&key("MockedPallet")), Some(42));
+
+			assert_eq!(
+				MoveRenamedPallet::<MockedPallet, DbWeight>::on_runtime_upgrade(),
+				Weight::from_ref_time(1),
+			);
+		});
+	}
+}
@@ -105,8 +105,10 @@ where
 
 	/// Exactly the same semantics as [`BTreeSet::insert`], but returns an `Err` (and is a noop) if the
 	/// new length of the set exceeds `S`.
+	///
+	/// Inserting an item which is already in a full set succeeds, as it does not grow the set.
 	pub fn try_insert(&mut self, item: T) -> Result<(), ()> {
-		if self.len() < Self::bound() {
+		if self.len() < Self::bound() || self.0.contains(&item) {
 			self.0.insert(item);
 			Ok(())
 		} else {
@@ -216,6 +218,15 @@ impl<T, S> IntoIterator for BoundedBTreeSet<T, S> {
 	}
 }
 
+impl<'a, T, S> IntoIterator for &'a BoundedBTreeSet<T, S> {
+	type Item = &'a T;
+	type IntoIter = sp_std::collections::btree_set::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
 impl<T, S> MaxEncodedLen for BoundedBTreeSet<T, S>
 where
 	T: MaxEncodedLen,
@@ -352,6 +363,40 @@ pub mod test {
 
 		assert!(bounded.try_insert(9).is_err());
 		assert_eq!(*bounded, map_from_keys(&[1, 0, 2, 3]));
+
+		// the items already in the full set may be inserted again.
+		assert!(bounded.try_insert(2).is_ok());
+		assert_eq!(*bounded, map_from_keys(&[1, 0, 2, 3]));
+	}
+
+	#[test]
+	fn storage_keeps_items_unique() {
+		TestExternalities::default().execute_with(|| {
+			FooMap::insert(1, boundedmap_from_keys::<u32, Seven>(&[1, 2, 3]));
+			FooMap::mutate(1, |set| {
+				let set = set.as_mut().unwrap();
+				assert!(set.try_insert(2).is_ok());
+				assert!(set.try_insert(4).is_ok());
+			});
+			assert_eq!(FooMap::get(1).unwrap(), map_from_keys(&[1, 2, 3, 4]));
+			assert_eq!(FooMap::decode_len(1).unwrap(), 4);
+		});
+	}
+
+	#[test]
+	fn iterating_by_reference_works() {
+		let bounded = boundedmap_from_keys::<u32, Seven>(&[3, 1, 2]);
+		let mut items = Vec::new();
+		for item in &bounded {
+			items.push(*item);
+		}
+		assert_eq!(items, vec![1, 2, 3]);
+	}
+
+	#[test]
+	fn max_encoded_len_works() {
+		let full = boundedmap_from_keys::<u32, Four>(&[1, 2, 3, 4]);
+		assert_eq!(full.encode().len(), BoundedBTreeSet::<u32, Four>::max_encoded_len());
 	}
 
 	#[test]
@@ -53,7 +53,7 @@ impl<'a, T: Encode + Decode, S: Get<u32>> EncodeLike<Vec<T>> for BoundedSlice<'a
 impl<'a, T, S: Get<u32>> TryFrom<&'a [T]> for BoundedSlice<'a, T, S> {
 	type Error = ();
 	fn try_from(t: &'a [T]) -> Result<Self, Self::Error> {
-		if t.len() < S::get() as usize {
+		if t.len() <= S::get() as usize {
 			Ok(BoundedSlice(t, PhantomData))
 		} else {
 			Err(())
@@ -67,6 +67,67 @@ impl<'a, T, S> From<BoundedSlice<'a, T, S>> for &'a [T] {
 	}
 }
 
+impl<'a, T, S> Clone for BoundedSlice<'a, T, S> {
+	fn clone(&self) -> Self {
+		BoundedSlice(self.0, PhantomData)
+	}
+}
+
+impl<'a, T, S> Copy for BoundedSlice<'a, T, S> {}
+
+impl<'a, T, S> fmt::Debug for BoundedSlice<'a, T, S>
+where
+	T: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("BoundedSlice").field(&self.0).finish()
+	}
+}
+
+impl<'a, T: PartialEq, S> PartialEq for BoundedSlice<'a, T, S> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<'a, T: Eq, S> Eq for BoundedSlice<'a, T, S> {}
+
+// Allows for all immutable operations of `[T]` on `BoundedSlice<T>`, including iteration.
+impl<'a, T, S> Deref for BoundedSlice<'a, T, S> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		self.0
+	}
+}
+
+impl<'a, T, S> sp_std::iter::IntoIterator for BoundedSlice<'a, T, S> {
+	type Item = &'a T;
+	type IntoIter = sp_std::slice::Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<'a, T, S> MaxEncodedLen for BoundedSlice<'a, T, S>
+where
+	T: MaxEncodedLen,
+	S: Get<u32>,
+	BoundedSlice<'a, T, S>: Encode,
+{
+	fn max_encoded_len() -> usize {
+		// `BoundedSlice` encodes exactly like the `BoundedVec` it may be decoded as.
+		BoundedVec::<T, S>::max_encoded_len()
+	}
+}
+
+impl<'a, T: Clone, S> From<BoundedSlice<'a, T, S>> for BoundedVec<T, S> {
+	fn from(slice: BoundedSlice<'a, T, S>) -> Self {
+		// The slice respects the same bound.
+		BoundedVec::unchecked_from(slice.0.to_vec())
+	}
+}
+
 impl<T: Decode, S: Get<u32>> Decode for BoundedVec<T, S> {
 	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
 		let inner = Vec::<T>::decode(input)?;
@@ -100,6 +161,11 @@ impl<T, S> BoundedVec<T, S> {
 		self.0
 	}
 
+	/// Borrow the inner items as a `BoundedSlice` of the same bound.
+	pub fn as_bounded_slice(&self) -> BoundedSlice<'_, T, S> {
+		BoundedSlice(&self.0[..], PhantomData)
+	}
+
 	/// Exactly the same semantics as [`Vec::remove`].
 	///
 	/// # Panics
@@ -278,6 +344,14 @@ impl<T, S> sp_std::iter::IntoIterator for BoundedVec<T, S> {
 	}
 }
 
+impl<'a, T, S> sp_std::iter::IntoIterator for &'a BoundedVec<T, S> {
+	type Item = &'a T;
+	type IntoIter = sp_std::slice::Iter<'a, T>;
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
 impl<T, S> codec::DecodeLength for BoundedVec<T, S> {
 	fn len(self_encoded: &[u8]) -> Result<usize, codec::Error> {
 		// `BoundedVec<T, _>` stored just a `Vec<T>`, thus the length is at the beginning in
@@ -435,6 +509,35 @@ pub mod test {
 		assert_eq!(bounded, vec![1, 2, 3, 4, 5, 6]);
 	}
 
+	#[test]
+	fn iterating_by_reference_works() {
+		let bounded: BoundedVec<u32, Seven> = vec![1, 2, 3].try_into().unwrap();
+		let mut sum = 0;
+		for item in &bounded {
+			sum += item;
+		}
+		assert_eq!(sum, 6);
+	}
+
+	#[test]
+	fn bounded_slice_works() {
+		let v = vec![1, 2, 3];
+		let slice = BoundedSlice::<u32, Four>::try_from(&v[..]).unwrap();
+		assert_eq!(slice.len(), 3);
+		assert_eq!(slice.into_iter().copied().collect::<Vec<_>>(), v);
+		assert_eq!(BoundedVec::from(slice), v);
+		assert!(BoundedSlice::<u32, Four>::try_from(&[1, 2, 3, 4, 5][..]).is_err());
+
+		// a bounded slice encodes like the bounded vector it borrows from.
+		let bounded: BoundedVec<u32, Four> = v.clone().try_into().unwrap();
+		assert_eq!(bounded.as_bounded_slice(), slice);
+		assert_eq!(bounded.as_bounded_slice().encode(), bounded.encode());
+		assert_eq!(
+			BoundedSlice::<u32, Four>::max_encoded_len(),
+			BoundedVec::<u32, Four>::max_encoded_len(),
+		);
+	}
+
 	#[test]
 	fn too_big_vec_fail_to_decode() {
 		let v: Vec<u32> = vec![1, 2, 3, 4, 5];
@@ -17,12 +17,18 @@
 
 //! Operation on runtime child storages.
 //!
-//! This module is a currently only a variant of unhashed with additional `child_info`.
+//! This module is mostly a variant of unhashed with additional `child_info`, it also provides the
+//! typed [`StorageValue`] and [`ChildTriePrefixedMap`] wrappers.
 // NOTE: could replace unhashed by having only one kind of storage (top trie being the child info
 // of null length parent storage key).
 
-use crate::sp_std::prelude::*;
-use codec::{Codec, Encode, Decode};
+use crate::sp_std::{prelude::*, marker::PhantomData};
+use crate::{
+	hash::{StorageHasher, ReversibleStorageHasher},
+	storage::ChildTriePrefixIterator,
+	traits::Get,
+};
+use codec::{Codec, Encode, EncodeLike, Decode, FullCodec};
 pub use sp_core::storage::{ChildInfo, ChildType};
 pub use crate::sp_io::KillChildStorageResult;
 
@@ -254,3 +260,113 @@ pub fn len(
 		}
 	}
 }
+
+/// A typed value stored under the key `Key` of the child trie `ChildTrie`.
+///
+/// This is the child trie counterpart of [`crate::storage::types::StorageValue`].
+pub struct StorageValue<ChildTrie, Key, Value>(PhantomData<(ChildTrie, Key, Value)>);
+
+impl<ChildTrie, Key, Value> StorageValue<ChildTrie, Key, Value> where
+	ChildTrie: Get<ChildInfo>,
+	Key: Get<&'static [u8]>,
+	Value: FullCodec,
+{
+	/// Does the value (explicitly) exist in storage?
+	pub fn exists() -> bool {
+		exists(&ChildTrie::get(), Key::get())
+	}
+
+	/// Load the value from the storage, `None` if there is no explicit entry.
+	pub fn get() -> Option<Value> {
+		get(&ChildTrie::get(), Key::get())
+	}
+
+	/// Store a value in the storage.
+	pub fn put(value: &Value) {
+		put(&ChildTrie::get(), Key::get(), value)
+	}
+
+	/// Take the value from the storage, leaving no entry.
+	pub fn take() -> Option<Value> {
+		take(&ChildTrie::get(), Key::get())
+	}
+
+	/// Remove the value from the storage.
+	pub fn kill() {
+		kill(&ChildTrie::get(), Key::get())
+	}
+}
+
+/// A typed map stored in the child trie `ChildTrie`, whose keys are hashed with `Hasher`.
+///
+/// This is the child trie counterpart of [`crate::storage::types::StorageMap`]. The map stores its
+/// entries at the root of the child trie, which should be dedicated to the map: iterating or
+/// clearing the map includes every entry of the child trie.
+pub struct ChildTriePrefixedMap<ChildTrie, Hasher, Key, Value>(
+	PhantomData<(ChildTrie, Hasher, Key, Value)>,
+);
+
+impl<ChildTrie, Hasher, Key, Value> ChildTriePrefixedMap<ChildTrie, Hasher, Key, Value> where
+	ChildTrie: Get<ChildInfo>,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+{
+	/// The key of the child trie under which `key` is stored.
+	pub fn hashed_key_for<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Vec<u8> {
+		key.using_encoded(Hasher::hash).as_ref().to_vec()
+	}
+
+	/// Does the value (explicitly) exist in storage?
+	pub fn contains_key<KeyArg: EncodeLike<Key>>(key: KeyArg) -> bool {
+		exists(&ChildTrie::get(), &Self::hashed_key_for(key))
+	}
+
+	/// Load the value associated with the given key from the map.
+	pub fn get<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Option<Value> {
+		get(&ChildTrie::get(), &Self::hashed_key_for(key))
+	}
+
+	/// Store a value to be associated with the given key from the map.
+	pub fn insert<KeyArg: EncodeLike<Key>, ValArg: EncodeLike<Value>>(key: KeyArg, val: ValArg) {
+		put(&ChildTrie::get(), &Self::hashed_key_for(key), &val)
+	}
+
+	/// Remove the value under a key.
+	pub fn remove<KeyArg: EncodeLike<Key>>(key: KeyArg) {
+		kill(&ChildTrie::get(), &Self::hashed_key_for(key))
+	}
+
+	/// Take the value under a key.
+	pub fn take<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Option<Value> {
+		take(&ChildTrie::get(), &Self::hashed_key_for(key))
+	}
+
+	/// Remove all the values of the map, removing at most `limit` values from the backend.
+	///
+	/// See [`kill_storage`] for the meaning of the limit.
+	pub fn clear(limit: Option<u32>) -> KillChildStorageResult {
+		kill_storage(&ChildTrie::get(), limit)
+	}
+}
+
+impl<ChildTrie, Hasher, Key, Value> ChildTriePrefixedMap<ChildTrie, Hasher, Key, Value> where
+	ChildTrie: Get<ChildInfo>,
+	Hasher: ReversibleStorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+{
+	/// Enumerate all elements in the map in no particular order.
+	///
+	/// If you alter the map while doing this, you'll get undefined results.
+	pub fn iter() -> ChildTriePrefixIterator<(Key, Value)> {
+		ChildTriePrefixIterator::with_prefix_over_key::<Hasher>(&ChildTrie::get(), &[])
+	}
+
+	/// Remove all elements from the map and iterate through them in no particular order.
+	///
+	/// If you add elements to the map while doing this, you'll get undefined results.
+	pub fn drain() -> ChildTriePrefixIterator<(Key, Value)> {
+		Self::iter().drain()
+	}
+}
@@ -361,6 +361,24 @@ impl<
 		iterator
 	}
 
+	fn iter_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator {
+		let mut iterator = Self::iter_prefix(k1);
+		iterator.previous_key = starting_raw_key;
+		iterator
+	}
+
+	fn drain_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator {
+		let mut iterator = Self::iter_prefix_from(k1, starting_raw_key);
+		iterator.drain = true;
+		iterator
+	}
+
 	fn iter() -> Self::Iterator {
 		let prefix = G::prefix_hash();
 		Self::Iterator {
@@ -383,13 +401,33 @@ impl<
 		iterator
 	}
 
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator {
+		let mut iterator = Self::iter();
+		iterator.previous_key = starting_raw_key;
+		iterator
+	}
+
 	fn translate<O: Decode, F: FnMut(K1, K2, O) -> Option<V>>(mut f: F) {
+		let mut cursor = None;
+		while let Some(next) = Self::translate_next(cursor, u32::max_value(), &mut f) {
+			cursor = Some(next);
+		}
+	}
+
+	fn translate_next<O: Decode, F: FnMut(K1, K2, O) -> Option<V>>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		mut f: F,
+	) -> Option<Vec<u8>> {
+		if limit == 0 {
+			return None
+		}
+
 		let prefix = G::prefix_hash();
-		let mut previous_key = prefix.clone();
-		while let Some(next) = sp_io::storage::next_key(&previous_key)
-			.filter(|n| n.starts_with(&prefix))
-		{
-			previous_key = next;
+		let mut previous_key = cursor.unwrap_or_else(|| prefix.clone());
+		for _ in 0..limit {
+			previous_key = sp_io::storage::next_key(&previous_key)
+				.filter(|n| n.starts_with(&prefix))?;
 			let value = match unhashed::get::<O>(&previous_key) {
 				Some(value) => value,
 				None => {
@@ -420,6 +458,8 @@ impl<
 				None => unhashed::kill(&previous_key),
 			}
 		}
+
+		Some(previous_key)
 	}
 }
 
@@ -564,6 +604,37 @@ mod test_iterators {
 				DoubleMap::iter().collect::<Vec<_>>(),
 				vec![(3, 3, 6), (0, 0, 0), (2, 2, 4), (1, 1, 2)],
 			);
+
+			// Translate with a cursor
+			let mut translated = vec![];
+			let mut cursor = None;
+			let mut calls = 0;
+			loop {
+				cursor = DoubleMap::translate_next(cursor, 3, |k1, k2, v: u64| {
+					translated.push((k1, k2));
+					(k1 != 0).then(|| v + 1)
+				});
+				calls += 1;
+				if cursor.is_none() {
+					break
+				}
+			}
+			// The wrong keys and the wrong value count in the limit.
+			assert_eq!(calls, 3);
+			assert_eq!(translated, vec![(3, 3), (0, 0), (2, 2), (1, 1)]);
+			assert_eq!(
+				DoubleMap::iter().collect::<Vec<_>>(),
+				vec![(3, 3, 7), (2, 2, 5), (1, 1, 3)],
+			);
+			assert_eq!(unhashed::get(&key_before_prefix(prefix.clone())), Some(1u64));
+			assert_eq!(unhashed::get(&key_after_prefix(prefix.clone())), Some(1u64));
+
+			// Nothing is translated with a zero limit.
+			assert_eq!(DoubleMap::translate_next(None, 0, |_k1, _k2, v: u64| Some(v + 1)), None);
+			assert_eq!(
+				DoubleMap::iter().collect::<Vec<_>>(),
+				vec![(3, 3, 7), (2, 2, 5), (1, 1, 3)],
+			);
 		})
 	}
 }
@@ -19,7 +19,7 @@
 
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
-use crate::{StorageHasher, Twox128, storage::unhashed};
+use crate::{StorageHasher, Twox128, storage::unhashed, traits::PalletInfoAccess};
 use crate::hash::ReversibleStorageHasher;
 
 use super::PrefixIterator;
@@ -321,6 +321,20 @@ pub fn move_pallet(old_pallet_name: &[u8], new_pallet_name: &[u8]) {
 	move_prefix(&Twox128::hash(old_pallet_name), &Twox128::hash(new_pallet_name))
 }
 
+/// Move all storages of the pallet `P` from the prefixes of its previous names, as declared with
+/// `#[renamed_from(..)]` in `construct_runtime!`, to the prefix of its current name.
+///
+/// Returns the number of keys moved.
+///
+/// NOTE: The values at the keys `twox_128(old_pallet_name)` are not moved.
+pub fn move_renamed_pallet<P: PalletInfoAccess>() -> u32 {
+	let new_prefix = Twox128::hash(P::name().as_bytes());
+	P::renamed_from()
+		.iter()
+		.map(|old_name| move_prefix_counted(&Twox128::hash(old_name.as_bytes()), &new_prefix))
+		.fold(0, u32::saturating_add)
+}
+
 /// Move all `(key, value)` after some prefix to the another prefix
 ///
 /// This function will remove all value for which the key start with `from_prefix`
@@ -328,8 +342,13 @@ pub fn move_pallet(old_pallet_name: &[u8], new_pallet_name: &[u8]) {
 ///
 /// NOTE: The value at the key `from_prefix` is not moved.
 pub fn move_prefix(from_prefix: &[u8], to_prefix: &[u8]) {
+	move_prefix_counted(from_prefix, to_prefix);
+}
+
+/// Same as [`move_prefix`], returns the number of keys moved.
+fn move_prefix_counted(from_prefix: &[u8], to_prefix: &[u8]) -> u32 {
 	if from_prefix == to_prefix {
-		return
+		return 0
 	}
 
 	let iter = PrefixIterator {
@@ -339,10 +358,87 @@ pub fn move_prefix(from_prefix: &[u8], to_prefix: &[u8]) {
 		closure: |key, value| Ok((key.to_vec(), value.to_vec())),
 	};
 
+	let mut moved = 0u32;
 	for (key, value) in iter {
 		let full_key = [to_prefix, &key].concat();
 		unhashed::put_raw(&full_key, &value);
+		moved = moved.saturating_add(1);
 	}
+	moved
+}
+
+/// A difference between two storage layouts of a pallet, see [`storage_layout_diff`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageLayoutDiff {
+	/// The prefix of the pallet changed from the first to the second.
+	Prefix(String, String),
+	/// The storage with this name is only in the old layout.
+	Removed(String),
+	/// The storage with this name is only in the new layout.
+	Added(String),
+	/// The storage with this name changed its modifier, its type or its default value.
+	Changed(String),
+}
+
+/// Compare the storage layouts of two versions of a pallet, e.g. before and after porting it from
+/// `decl_storage!` to `#[pallet::storage]`.
+///
+/// Returns all the differences, i.e. an empty vector if the storage layout is unchanged. The
+/// documentation of the storages is ignored. It is typically used in a test of the pallet:
+/// ```ignore
+/// let old = OldModule::<Runtime>::storage_metadata();
+/// let new = Pallet::<Runtime>::storage_metadata();
+/// assert_eq!(storage_layout_diff(&old, &new), vec![]);
+/// ```
+#[cfg(feature = "std")]
+pub fn storage_layout_diff(
+	old: &crate::metadata::StorageMetadata,
+	new: &crate::metadata::StorageMetadata,
+) -> Vec<StorageLayoutDiff> {
+	use crate::metadata::{DecodeDifferent, StorageEntryMetadata};
+
+	fn name(name: &DecodeDifferent<&'static str, String>) -> String {
+		match name {
+			DecodeDifferent::Encode(name) => name.to_string(),
+			DecodeDifferent::Decoded(name) => name.clone(),
+		}
+	}
+	fn entries(
+		entries: &DecodeDifferent<&'static [StorageEntryMetadata], Vec<StorageEntryMetadata>>,
+	) -> &[StorageEntryMetadata] {
+		match entries {
+			DecodeDifferent::Encode(entries) => entries,
+			DecodeDifferent::Decoded(entries) => &entries[..],
+		}
+	}
+
+	let mut diff = Vec::new();
+	if name(&old.prefix) != name(&new.prefix) {
+		diff.push(StorageLayoutDiff::Prefix(name(&old.prefix), name(&new.prefix)));
+	}
+
+	let (old_entries, new_entries) = (entries(&old.entries), entries(&new.entries));
+	for old_entry in old_entries {
+		let entry_name = name(&old_entry.name);
+		match new_entries.iter().find(|new_entry| name(&new_entry.name) == entry_name) {
+			None => diff.push(StorageLayoutDiff::Removed(entry_name)),
+			Some(new_entry) => if old_entry.modifier != new_entry.modifier
+				|| old_entry.ty != new_entry.ty
+				|| old_entry.default != new_entry.default
+			{
+				diff.push(StorageLayoutDiff::Changed(entry_name))
+			},
+		}
+	}
+	for new_entry in new_entries {
+		let entry_name = name(&new_entry.name);
+		if !old_entries.iter().any(|old_entry| name(&old_entry.name) == entry_name) {
+			diff.push(StorageLayoutDiff::Added(entry_name));
+		}
+	}
+
+	diff
 }
 
 #[cfg(test)]
@@ -358,6 +454,8 @@ mod tests {
 		move_storage_from_pallet,
 		storage_iter,
 		storage_key_iter,
+		storage_layout_diff,
+		StorageLayoutDiff,
 	};
 
 	struct OldPalletStorageValuePrefix;
@@ -477,4 +575,43 @@ mod tests {
 			assert_eq!(OldStorageValue::get(), Some(3));
 		});
 	}
+
+	#[test]
+	fn test_storage_layout_diff() {
+		use crate::metadata::{
+			DecodeDifferent, StorageEntryMetadata, StorageEntryModifier, StorageEntryType,
+			StorageMetadata,
+		};
+
+		let entry = |name: &str, ty: &str| StorageEntryMetadata {
+			name: DecodeDifferent::Decoded(name.into()),
+			modifier: StorageEntryModifier::Optional,
+			ty: StorageEntryType::Plain(DecodeDifferent::Decoded(ty.into())),
+			default: DecodeDifferent::Decoded(vec![0]),
+			documentation: DecodeDifferent::Decoded(vec![]),
+		};
+		let metadata = |prefix: &str, entries| StorageMetadata {
+			prefix: DecodeDifferent::Decoded(prefix.into()),
+			entries: DecodeDifferent::Decoded(entries),
+		};
+
+		let old = metadata("Example", vec![entry("Foo", "u32"), entry("Bar", "u32")]);
+		assert_eq!(storage_layout_diff(&old, &old), vec![]);
+
+		let new = metadata("Example", vec![entry("Foo", "u64"), entry("Baz", "u32")]);
+		assert_eq!(
+			storage_layout_diff(&old, &new),
+			vec![
+				StorageLayoutDiff::Changed("Foo".into()),
+				StorageLayoutDiff::Removed("Bar".into()),
+				StorageLayoutDiff::Added("Baz".into()),
+			],
+		);
+
+		let new = metadata("NewExample", vec![entry("Foo", "u32"), entry("Bar", "u32")]);
+		assert_eq!(
+			storage_layout_diff(&old, &new),
+			vec![StorageLayoutDiff::Prefix("Example".into(), "NewExample".into())],
+		);
+	}
 }
@@ -27,7 +27,7 @@ use crate::{
 		ReversibleKeyGenerator, TupleToEncodedIter,
 	},
 };
-use sp_runtime::generic::{Digest, DigestItem};
+use sp_runtime::{DispatchError, generic::{Digest, DigestItem}};
 pub use sp_runtime::TransactionOutcome;
 pub use types::Key;
 
@@ -42,48 +42,7 @@ pub mod child;
 pub mod generator;
 pub mod migration;
 pub mod types;
-
-#[cfg(all(feature = "std", any(test, debug_assertions)))]
-mod debug_helper {
-	use std::cell::RefCell;
-
-	thread_local! {
-		static TRANSACTION_LEVEL: RefCell<u32> = RefCell::new(0);
-	}
-
-	pub fn require_transaction() {
-		let level = TRANSACTION_LEVEL.with(|v| *v.borrow());
-		if level == 0 {
-			panic!("Require transaction not called within with_transaction");
-		}
-	}
-
-	pub struct TransactionLevelGuard;
-
-	impl Drop for TransactionLevelGuard {
-		fn drop(&mut self) {
-			TRANSACTION_LEVEL.with(|v| *v.borrow_mut() -= 1);
-		}
-	}
-
-	/// Increments the transaction level.
-	///
-	/// Returns a guard that when dropped decrements the transaction level automatically.
-	pub fn inc_transaction_level() -> TransactionLevelGuard {
-		TRANSACTION_LEVEL.with(|v| {
-			let mut val = v.borrow_mut();
-			*val += 1;
-			if *val > 10 {
-				log::warn!(
-					"Detected with_transaction with nest level {}. Nested usage of with_transaction is not recommended.",
-					*val
-				);
-			}
-		});
-
-		TransactionLevelGuard
-	}
-}
+pub mod transactional;
 
 /// Assert this method is called within a storage transaction.
 /// This will **panic** if is not called within a storage transaction.
@@ -91,16 +50,68 @@ mod debug_helper {
 /// This assertion is enabled for native execution and when `debug_assertions` are enabled.
 pub fn require_transaction() {
 	#[cfg(all(feature = "std", any(test, debug_assertions)))]
-	debug_helper::require_transaction();
+	assert!(
+		transactional::is_transactional(),
+		"Require transaction not called within with_transaction",
+	);
 }
 
 /// Execute the supplied function in a new storage transaction.
 ///
 /// All changes to storage performed by the supplied function are discarded if the returned
-/// outcome is `TransactionOutcome::Rollback`.
+/// outcome is `TransactionOutcome::Rollback`. This includes the events deposited by the supplied
+/// function, as they are kept in the storage until the end of the block.
 ///
-/// Transactions can be nested to any depth. Commits happen to the parent transaction.
+/// Transactions can be nested to any depth. Commits happen to the parent transaction. Use
+/// [`try_with_transaction`] to bound the nesting depth.
 pub fn with_transaction<R>(f: impl FnOnce() -> TransactionOutcome<R>) -> R {
+	let _guard = transactional::inc_transaction_level_unbounded();
+	execute_in_layer(f)
+}
+
+/// Execute the supplied function in a new storage transaction, unless
+/// [`transactional::TRANSACTIONAL_LIMIT`] transactions are already open.
+///
+/// See [`try_with_transaction_limit`].
+pub fn try_with_transaction<R, E: From<DispatchError>>(
+	f: impl FnOnce() -> TransactionOutcome<Result<R, E>>,
+) -> Result<R, E> {
+	try_with_transaction_limit(transactional::TRANSACTIONAL_LIMIT, f)
+}
+
+/// Execute the supplied function in a new storage transaction, unless `limit` transactions are
+/// already open.
+///
+/// All changes to storage performed by the supplied function, events included, are discarded if
+/// the returned outcome is `TransactionOutcome::Rollback`. When the limit is reached the function
+/// is not executed and an error is returned.
+pub fn try_with_transaction_limit<R, E: From<DispatchError>>(
+	limit: transactional::Layer,
+	f: impl FnOnce() -> TransactionOutcome<Result<R, E>>,
+) -> Result<R, E> {
+	let _guard = transactional::inc_transaction_level(limit)
+		.map_err(|()| DispatchError::Other("Transactional limit reached"))?;
+	execute_in_layer(f)
+}
+
+/// Execute the supplied function in a new storage transaction, committing its changes if it
+/// returns `Ok` and discarding them if it returns `Err`.
+///
+/// The nesting depth is bounded by [`transactional::TRANSACTIONAL_LIMIT`].
+pub fn with_storage_layer<R, E: From<DispatchError>>(
+	f: impl FnOnce() -> Result<R, E>,
+) -> Result<R, E> {
+	try_with_transaction(|| {
+		let r = f();
+		if r.is_ok() {
+			TransactionOutcome::Commit(r)
+		} else {
+			TransactionOutcome::Rollback(r)
+		}
+	})
+}
+
+fn execute_in_layer<R>(f: impl FnOnce() -> TransactionOutcome<R>) -> R {
 	use sp_io::storage::{
 		start_transaction, commit_transaction, rollback_transaction,
 	};
@@ -108,9 +119,6 @@ pub fn with_transaction<R>(f: impl FnOnce() -> TransactionOutcome<R>) -> R {
 
 	start_transaction();
 
-	#[cfg(all(feature = "std", any(test, debug_assertions)))]
-	let _guard = debug_helper::inc_transaction_level();
-
 	match f() {
 		Commit(res) => { commit_transaction(); res },
 		Rollback(res) => { rollback_transaction(); res },
@@ -328,6 +336,30 @@ pub trait IterableStorageMap<K: FullEncode, V: FullCodec>: StorageMap<K, V> {
 	///
 	/// NOTE: If a value fail to decode because storage is corrupted then it is skipped.
 	fn translate<O: Decode, F: FnMut(K, O) -> Option<V>>(f: F);
+
+	/// Enumerate all elements in the map after the raw storage key `starting_raw_key`, in no
+	/// particular order.
+	///
+	/// `starting_raw_key` is typically the [`PrefixIterator::last_raw_key`] of a previous
+	/// iteration, it allows to paginate over a big map. If you alter the map while doing this,
+	/// you'll get undefined results.
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator;
+
+	/// Translate the values of at most `limit` elements by a function `f`, starting after the
+	/// element at `cursor`, or at the first element of the map if `cursor` is `None`.
+	///
+	/// Returns the cursor to give to the next call to continue the translation, or `None` if all
+	/// the elements of the map were translated. It allows to spread the translation of a big map
+	/// over multiple blocks. The map must not be altered by something else in between.
+	///
+	/// `limit` must not be zero: nothing is translated and `None` is returned otherwise.
+	///
+	/// NOTE: If a value fail to decode because storage is corrupted then it is skipped.
+	fn translate_next<O: Decode, F: FnMut(K, O) -> Option<V>>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		f: F,
+	) -> Option<Vec<u8>>;
 }
 
 /// A strongly-typed double map in storage whose secondary keys and values can be iterated over.
@@ -352,10 +384,34 @@ pub trait IterableStorageDoubleMap<
 	/// you'll get undefined results.
 	fn drain_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator;
 
+	/// Enumerate all elements in the map with first key `k1` after the raw storage key
+	/// `starting_raw_key`, in no particular order. If you add or remove values whose first key is
+	/// `k1` to the map while doing this, you'll get undefined results.
+	fn iter_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator;
+
+	/// Remove all elements from the map with first key `k1` after the raw storage key
+	/// `starting_raw_key` and iterate through them in no particular order. If you add elements
+	/// with first key `k1` to the map while doing this, you'll get undefined results.
+	fn drain_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator;
+
 	/// Enumerate all elements in the map in no particular order. If you add or remove values to
 	/// the map while doing this, you'll get undefined results.
 	fn iter() -> Self::Iterator;
 
+	/// Enumerate all elements in the map after the raw storage key `starting_raw_key`, in no
+	/// particular order.
+	///
+	/// `starting_raw_key` is typically the [`PrefixIterator::last_raw_key`] of a previous
+	/// iteration, it allows to paginate over a big map. If you add or remove values to the map
+	/// while doing this, you'll get undefined results.
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator;
+
 	/// Remove all elements from the map and iterate through them in no particular order. If you
 	/// add elements to the map while doing this, you'll get undefined results.
 	fn drain() -> Self::Iterator;
@@ -365,6 +421,22 @@ pub trait IterableStorageDoubleMap<
 	///
 	/// NOTE: If a value fail to decode because storage is corrupted then it is skipped.
 	fn translate<O: Decode, F: FnMut(K1, K2, O) -> Option<V>>(f: F);
+
+	/// Translate the values of at most `limit` elements by a function `f`, starting after the
+	/// element at `cursor`, or at the first element of the map if `cursor` is `None`.
+	///
+	/// Returns the cursor to give to the next call to continue the translation, or `None` if all
+	/// the elements of the map were translated. It allows to spread the translation of a big map
+	/// over multiple blocks. The map must not be altered by something else in between.
+	///
+	/// `limit` must not be zero: nothing is translated and `None` is returned otherwise.
+	///
+	/// NOTE: If a value fail to decode because storage is corrupted then it is skipped.
+	fn translate_next<O: Decode, F: FnMut(K1, K2, O) -> Option<V>>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		f: F,
+	) -> Option<Vec<u8>>;
 }
 
 /// A strongly-typed map with arbitrary number of keys in storage whose keys and values can be
@@ -685,6 +757,25 @@ impl<T> PrefixIterator<T> {
 		self.drain = true;
 		self
 	}
+
+	/// The raw storage key of the last item iterated, or the prefix if no item was iterated yet.
+	///
+	/// It can be given to the `iter_from` functions to resume the iteration later on.
+	pub fn last_raw_key(&self) -> &[u8] {
+		&self.previous_key
+	}
+}
+
+/// The result of a bounded removal of the values of a map, see [`StoragePrefixedMap::clear`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiRemovalResults {
+	/// The cursor to give to the next call to continue the removal, `None` if all the values were
+	/// removed.
+	pub maybe_cursor: Option<Vec<u8>>,
+	/// The number of values removed.
+	pub unique: u32,
+	/// The number of keys read from the storage, to account for the weight of the removal.
+	pub loops: u32,
 }
 
 impl<T> Iterator for PrefixIterator<T> {
@@ -884,6 +975,35 @@ pub trait StoragePrefixedMap<Value: FullCodec> {
 		sp_io::storage::clear_prefix(&Self::final_prefix())
 	}
 
+	/// Remove at most `limit` values of the storage, starting after `maybe_cursor`, or at the first
+	/// value if `maybe_cursor` is `None`.
+	///
+	/// The returned [`MultiRemovalResults::maybe_cursor`] must be given to the next call to
+	/// continue the removal, it is `None` once all the values are removed. It allows to clear a
+	/// big map over multiple blocks without exceeding the weight of a block.
+	fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> MultiRemovalResults {
+		let prefix = Self::final_prefix();
+		let mut previous_key = maybe_cursor.map_or_else(|| prefix.to_vec(), |c| c.to_vec());
+		let mut results = MultiRemovalResults::default();
+		loop {
+			results.loops = results.loops.saturating_add(1);
+			let next = match sp_io::storage::next_key(&previous_key)
+				.filter(|n| n.starts_with(&prefix))
+			{
+				Some(next) => next,
+				None => break,
+			};
+			if results.unique >= limit {
+				results.maybe_cursor = Some(previous_key);
+				break
+			}
+			unhashed::kill(&next);
+			results.unique += 1;
+			previous_key = next;
+		}
+		results
+	}
+
 	/// Iter over all value of the storage.
 	///
 	/// NOTE: If a value failed to decode becaues storage is corrupted then it is skipped.
@@ -1133,7 +1253,7 @@ where
 mod test {
 	use super::*;
 	use sp_core::hashing::twox_128;
-	use crate::{hash::Identity, assert_ok};
+	use crate::{hash::{Identity, Twox64Concat}, assert_ok};
 	use sp_io::TestExternalities;
 	use generator::StorageValue as _;
 	use bounded_vec::BoundedVec;
@@ -1274,6 +1394,46 @@ mod test {
 		});
 	}
 
+	#[test]
+	fn try_with_transaction_limits_the_nesting_depth() {
+		fn recurse(depth: u32) -> Result<u32, DispatchError> {
+			try_with_transaction_limit(3, || {
+				unhashed::put(&depth.to_le_bytes(), &depth);
+				let result = if depth < 5 { recurse(depth + 1) } else { Ok(depth) };
+				TransactionOutcome::Commit(result)
+			})
+		}
+
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(recurse(1), Err(DispatchError::Other("Transactional limit reached")));
+			// The layers below the limit were committed, the failed one was never opened.
+			assert_eq!(unhashed::get::<u32>(&3u32.to_le_bytes()), Some(3));
+			assert_eq!(unhashed::get::<u32>(&4u32.to_le_bytes()), None);
+			assert_eq!(transactional::get_transaction_level(), 0);
+			assert_eq!(unhashed::get_raw(transactional::TRANSACTION_LEVEL_KEY), None);
+		});
+	}
+
+	#[test]
+	fn with_storage_layer_rolls_back_on_error() {
+		TestExternalities::default().execute_with(|| {
+			let result: Result<(), DispatchError> = with_storage_layer(|| {
+				unhashed::put(b"key", &1u32);
+				assert_eq!(transactional::get_transaction_level(), 1);
+				Err(DispatchError::BadOrigin)
+			});
+			assert_eq!(result, Err(DispatchError::BadOrigin));
+			assert_eq!(unhashed::get::<u32>(b"key"), None);
+
+			assert_ok!(with_storage_layer(|| {
+				unhashed::put(b"key", &2u32);
+				Ok::<_, DispatchError>(())
+			}));
+			assert_eq!(unhashed::get::<u32>(b"key"), Some(2));
+			assert_eq!(transactional::get_transaction_level(), 0);
+		});
+	}
+
 	#[test]
 	fn child_trie_prefixed_map_works() {
 		TestExternalities::default().execute_with(|| {
@@ -1348,6 +1508,43 @@ mod test {
 		});
 	}
 
+	crate::parameter_types! {
+		pub ContributionsTrie: child::ChildInfo = child::ChildInfo::new_default(b"contributions");
+		pub ConfigTrie: child::ChildInfo = child::ChildInfo::new_default(b"config");
+		pub const RaisedKey: &'static [u8] = b"raised";
+	}
+
+	type Contributions = child::ChildTriePrefixedMap<ContributionsTrie, Twox64Concat, u32, u64>;
+	type Raised = child::StorageValue<ConfigTrie, RaisedKey, u64>;
+
+	#[test]
+	fn typed_child_storage_works() {
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(Raised::get(), None);
+			Raised::put(&30);
+			assert!(Raised::exists());
+			assert_eq!(Raised::take(), Some(30));
+			assert!(!Raised::exists());
+
+			Contributions::insert(1, 10);
+			Contributions::insert(2, 20);
+			assert!(Contributions::contains_key(1));
+			assert_eq!(Contributions::get(2), Some(20));
+			assert_eq!(Contributions::get(3), None);
+
+			let mut contributions = Contributions::iter().collect::<Vec<_>>();
+			contributions.sort();
+			assert_eq!(contributions, vec![(1, 10), (2, 20)]);
+
+			assert_eq!(Contributions::take(1), Some(10));
+			assert!(!Contributions::contains_key(1));
+
+			Contributions::insert(3, 30);
+			Contributions::clear(None);
+			assert_eq!(Contributions::iter().count(), 0);
+		});
+	}
+
 	crate::parameter_types! {
 		pub const Seven: u32 = 7;
 		pub const Four: u32 = 4;
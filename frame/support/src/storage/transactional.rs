@@ -0,0 +1,82 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracking of the storage layers opened by [`super::with_transaction`].
+//!
+//! Each storage transaction is a layer of the overlay of `sp-state-machine`. The number of layers
+//! currently open is kept in the storage under [`TRANSACTION_LEVEL_KEY`], so that it is known to
+//! both the native and the wasm runtime. The key is written outside of the layers it counts and is
+//! removed once the last layer is closed, it is never part of the committed storage.
+
+use crate::storage::unhashed;
+
+/// The type used to count the storage layers.
+pub type Layer = u32;
+
+/// The storage key under which the number of open storage layers is kept.
+pub const TRANSACTION_LEVEL_KEY: &[u8] = b":transaction_level:";
+
+/// The default maximum number of nested storage layers.
+pub const TRANSACTIONAL_LIMIT: Layer = 255;
+
+/// The number of storage layers currently open.
+pub fn get_transaction_level() -> Layer {
+	unhashed::get_or_default::<Layer>(TRANSACTION_LEVEL_KEY)
+}
+
+fn set_transaction_level(level: Layer) {
+	if level == 0 {
+		unhashed::kill(TRANSACTION_LEVEL_KEY);
+	} else {
+		unhashed::put::<Layer>(TRANSACTION_LEVEL_KEY, &level);
+	}
+}
+
+/// Whether the caller is executed within a storage layer.
+pub fn is_transactional() -> bool {
+	get_transaction_level() > 0
+}
+
+/// Decrements the transaction level when dropped.
+pub struct StorageLayerGuard;
+
+impl Drop for StorageLayerGuard {
+	fn drop(&mut self) {
+		set_transaction_level(get_transaction_level().saturating_sub(1))
+	}
+}
+
+/// Increments the transaction level, unless `limit` storage layers are already open.
+///
+/// Returns a guard that decrements the transaction level when dropped.
+pub fn inc_transaction_level(limit: Layer) -> Result<StorageLayerGuard, ()> {
+	let level = get_transaction_level();
+	if level >= limit {
+		return Err(())
+	}
+
+	set_transaction_level(level + 1);
+	Ok(StorageLayerGuard)
+}
+
+/// Increments the transaction level without any limit.
+///
+/// Returns a guard that decrements the transaction level when dropped.
+pub(crate) fn inc_transaction_level_unbounded() -> StorageLayerGuard {
+	set_transaction_level(get_transaction_level().saturating_add(1));
+	StorageLayerGuard
+}
@@ -320,6 +320,13 @@ where
 		<Self as crate::storage::StoragePrefixedMap<Value>>::remove_all()
 	}
 
+	/// Remove at most `limit` values of the storage, starting after `maybe_cursor`.
+	///
+	/// See [`StoragePrefixedMap::clear`](crate::storage::StoragePrefixedMap::clear).
+	pub fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> crate::storage::MultiRemovalResults {
+		<Self as crate::storage::StoragePrefixedMap<Value>>::clear(limit, maybe_cursor)
+	}
+
 	/// Iter over all value of the storage.
 	///
 	/// NOTE: If a value failed to decode becaues storage is corrupted then it is skipped.
@@ -395,6 +402,34 @@ where
 		<Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::drain_prefix(k1)
 	}
 
+	/// Enumerate all elements in the map with first key `k1` after the raw storage key
+	/// `starting_raw_key`, in no particular order.
+	///
+	/// If you add or remove values whose first key is `k1` to the map while doing this, you'll get
+	/// undefined results.
+	pub fn iter_prefix_from(
+		k1: impl EncodeLike<Key1>,
+		starting_raw_key: Vec<u8>,
+	) -> crate::storage::PrefixIterator<(Key2, Value)> {
+		<
+			Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>
+		>::iter_prefix_from(k1, starting_raw_key)
+	}
+
+	/// Remove all elements from the map with first key `k1` after the raw storage key
+	/// `starting_raw_key` and iterate through them in no particular order.
+	///
+	/// If you add elements with first key `k1` to the map while doing this, you'll get undefined
+	/// results.
+	pub fn drain_prefix_from(
+		k1: impl EncodeLike<Key1>,
+		starting_raw_key: Vec<u8>,
+	) -> crate::storage::PrefixIterator<(Key2, Value)> {
+		<
+			Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>
+		>::drain_prefix_from(k1, starting_raw_key)
+	}
+
 	/// Enumerate all elements in the map in no particular order.
 	///
 	/// If you add or remove values to the map while doing this, you'll get undefined results.
@@ -409,6 +444,18 @@ where
 		<Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::drain()
 	}
 
+	/// Enumerate all elements in the map after the raw storage key `starting_raw_key`, in no
+	/// particular order.
+	///
+	/// If you add or remove values to the map while doing this, you'll get undefined results.
+	pub fn iter_from(
+		starting_raw_key: Vec<u8>,
+	) -> crate::storage::PrefixIterator<(Key1, Key2, Value)> {
+		<
+			Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>
+		>::iter_from(starting_raw_key)
+	}
+
 	/// Translate the values of all elements by a function `f`, in the map in no particular order.
 	///
 	/// By returning `None` from `f` for an element, you'll remove it from the map.
@@ -417,6 +464,23 @@ where
 	pub fn translate<O: Decode, F: FnMut(Key1, Key2, O) -> Option<Value>>(f: F) {
 		<Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::translate(f)
 	}
+
+	/// Translate the values of at most `limit` elements by a function `f`, starting after the
+	/// element at `cursor`, or at the first element of the map if `cursor` is `None`.
+	///
+	/// Returns the cursor to continue the translation, or `None` if all the elements of the map
+	/// were translated. `limit` must not be zero, nothing is translated otherwise.
+	///
+	/// See [`crate::storage::IterableStorageDoubleMap::translate_next`].
+	pub fn translate_next<O: Decode, F: FnMut(Key1, Key2, O) -> Option<Value>>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		f: F,
+	) -> Option<Vec<u8>> {
+		<
+			Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>
+		>::translate_next(cursor, limit, f)
+	}
 }
 
 /// Part of storage metadata for a storage double map.
@@ -676,6 +740,15 @@ mod test {
 			assert_eq!(A::drain_prefix(4).collect::<Vec<_>>(), vec![(40, 13), (41, 14)]);
 			assert_eq!(A::iter_prefix(4).collect::<Vec<_>>(), vec![]);
 			assert_eq!(A::drain_prefix(4).collect::<Vec<_>>(), vec![]);
+
+			A::insert(4, 40, 13);
+			A::insert(4, 41, 14);
+			let mut iter = A::iter_prefix(4);
+			let first = iter.next().unwrap();
+			let starting_raw_key = iter.last_raw_key().to_vec();
+			assert_eq!(A::drain_prefix_from(4, starting_raw_key).count(), 1);
+			assert_eq!(A::iter_prefix(4).collect::<Vec<_>>(), vec![first]);
+			A::remove_all();
 		})
 	}
 }
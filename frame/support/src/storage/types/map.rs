@@ -235,6 +235,13 @@ where
 		<Self as crate::storage::StoragePrefixedMap<Value>>::remove_all()
 	}
 
+	/// Remove at most `limit` values of the storage, starting after `maybe_cursor`.
+	///
+	/// See [`StoragePrefixedMap::clear`](crate::storage::StoragePrefixedMap::clear).
+	pub fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> crate::storage::MultiRemovalResults {
+		<Self as crate::storage::StoragePrefixedMap<Value>>::clear(limit, maybe_cursor)
+	}
+
 	/// Iter over all value of the storage.
 	///
 	/// NOTE: If a value failed to decode becaues storage is corrupted then it is skipped.
@@ -304,6 +311,14 @@ where
 		<Self as crate::storage::IterableStorageMap<Key, Value>>::drain()
 	}
 
+	/// Enumerate all elements in the map after the raw storage key `starting_raw_key`, in no
+	/// particular order.
+	///
+	/// If you alter the map while doing this, you'll get undefined results.
+	pub fn iter_from(starting_raw_key: Vec<u8>) -> crate::storage::PrefixIterator<(Key, Value)> {
+		<Self as crate::storage::IterableStorageMap<Key, Value>>::iter_from(starting_raw_key)
+	}
+
 	/// Translate the values of all elements by a function `f`, in the map in no particular order.
 	///
 	/// By returning `None` from `f` for an element, you'll remove it from the map.
@@ -312,6 +327,21 @@ where
 	pub fn translate<O: Decode, F: FnMut(Key, O) -> Option<Value>>(f: F) {
 		<Self as crate::storage::IterableStorageMap<Key, Value>>::translate(f)
 	}
+
+	/// Translate the values of at most `limit` elements by a function `f`, starting after the
+	/// element at `cursor`, or at the first element of the map if `cursor` is `None`.
+	///
+	/// Returns the cursor to continue the translation, or `None` if all the elements of the map
+	/// were translated. `limit` must not be zero, nothing is translated otherwise.
+	///
+	/// See [`crate::storage::IterableStorageMap::translate_next`].
+	pub fn translate_next<O: Decode, F: FnMut(Key, O) -> Option<Value>>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+		f: F,
+	) -> Option<Vec<u8>> {
+		<Self as crate::storage::IterableStorageMap<Key, Value>>::translate_next(cursor, limit, f)
+	}
 }
 
 /// Part of storage metadata for a storage map.
@@ -539,4 +569,33 @@ mod test {
 			assert_eq!(WithLen::decode_len(0), Some(1));
 		})
 	}
+
+	#[test]
+	fn iter_from_and_clear_work() {
+		type A = StorageMap<Prefix, Blake2_128Concat, u16, u32, OptionQuery>;
+
+		TestExternalities::default().execute_with(|| {
+			for i in 0..5u16 {
+				A::insert(i, i as u32);
+			}
+			let all = A::iter().collect::<Vec<_>>();
+
+			let mut iter = A::iter();
+			let first = iter.by_ref().take(2).collect::<Vec<_>>();
+			let rest = A::iter_from(iter.last_raw_key().to_vec()).collect::<Vec<_>>();
+			assert_eq!([first, rest].concat(), all);
+
+			let results = A::clear(3, None);
+			assert_eq!(results.unique, 3);
+			assert_eq!(results.loops, 4);
+			assert!(results.maybe_cursor.is_some());
+			assert_eq!(A::iter().count(), 2);
+
+			let results = A::clear(3, results.maybe_cursor.as_deref());
+			assert_eq!(results.unique, 2);
+			assert_eq!(results.loops, 3);
+			assert_eq!(results.maybe_cursor, None);
+			assert_eq!(A::iter().count(), 0);
+		})
+	}
 }
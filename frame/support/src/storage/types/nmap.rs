@@ -270,6 +270,13 @@ where
 		<Self as crate::storage::StoragePrefixedMap<Value>>::remove_all()
 	}
 
+	/// Remove at most `limit` values of the storage, starting after `maybe_cursor`.
+	///
+	/// See [`StoragePrefixedMap::clear`](crate::storage::StoragePrefixedMap::clear).
+	pub fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> crate::storage::MultiRemovalResults {
+		<Self as crate::storage::StoragePrefixedMap<Value>>::clear(limit, maybe_cursor)
+	}
+
 	/// Iter over all value of the storage.
 	///
 	/// NOTE: If a value failed to decode because storage is corrupted then it is skipped.
@@ -26,13 +26,16 @@ pub use tokens::currency::{
 	Currency, LockIdentifier, LockableCurrency, ReservableCurrency, NamedReservableCurrency,
 	VestingSchedule,
 };
-pub use tokens::imbalance::{Imbalance, OnUnbalanced, SignedImbalance};
+pub use tokens::imbalance::{
+	Imbalance, OnUnbalanced, SignedImbalance, SplitTwoWays, DealWithFees,
+};
 pub use tokens::{ExistenceRequirement, WithdrawReasons, BalanceStatus};
 
 mod members;
 pub use members::{
 	Contains, ContainsLengthBound, SortedMembers, InitializeMembers, ChangeMembers, All, IsInVec,
-	AsContains,
+	AsContains, Everything, Nothing, EverythingBut, TheseExcept, InsideBoth, Equals, InPallet,
+	IsCall, IsSubCall,
 };
 
 mod validation;
@@ -51,7 +54,8 @@ mod misc;
 pub use misc::{
 	Len, Get, GetDefault, HandleLifetime, TryDrop, Time, UnixTime, IsType, IsSubType, ExecuteBlock,
 	SameOrOther, OnNewAccount, OnKilledAccount, OffchainWorker, GetBacking, Backing, ExtrinsicCall,
-	EnsureInherentsAreFirst, ConstU32,
+	EnsureInherentsAreFirst, ConstU32, Replace, Defensive, DefensiveOption, DefensiveSaturating,
+	defensive_path, DEFENSIVE_OP_PUBLIC_ERROR, DEFENSIVE_OP_INTERNAL_ERROR,
 };
 
 mod stored_map;
@@ -62,13 +66,24 @@ pub use randomness::Randomness;
 mod metadata;
 pub use metadata::{
 	CallMetadata, GetCallMetadata, GetCallName, PalletInfo, PalletVersion, GetPalletVersion,
-	PALLET_VERSION_STORAGE_KEY_POSTFIX, PalletInfoAccess,
+	CallArgument, GetCallArguments,
+	PALLET_VERSION_STORAGE_KEY_POSTFIX, PalletInfoAccess, StorageVersion, GetStorageVersion,
+	CheckStorageVersion, STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+};
+
+mod migrations;
+pub use migrations::{
+	SteppedMigration, SteppedMigrations, SteppedMigrationError, SteppedMigrationResult,
+	MultiStepMigrator,
 };
 
 mod hooks;
-pub use hooks::{Hooks, OnGenesis, OnInitialize, OnFinalize, OnIdle, OnRuntimeUpgrade, OnTimestampSet};
+pub use hooks::{
+	Hooks, OnGenesis, OnInitialize, OnInitializeMetered, OnFinalize, OnIdle, OnRuntimeUpgrade,
+	OnTimestampSet,
+};
 #[cfg(feature = "try-runtime")]
-pub use hooks::{OnRuntimeUpgradeHelpersExt, ON_RUNTIME_UPGRADE_PREFIX};
+pub use hooks::{OnRuntimeUpgradeHelpersExt, ON_RUNTIME_UPGRADE_PREFIX, TryState};
 #[cfg(feature = "std")]
 pub use hooks::GenesisBuild;
 
@@ -77,10 +92,26 @@ mod storage;
 pub use storage::{Instance, StorageInstance, StorageInfo, StorageInfoTrait};
 
 mod dispatch;
-pub use dispatch::{EnsureOrigin, OriginTrait, UnfilteredDispatchable};
+pub use dispatch::{
+	EnsureOrigin, EnsureOriginWithArg, OriginTrait, UnfilteredDispatchable, NeverEnsureOrigin,
+	AsEnsureOriginWithArg, MapSuccess, TryMapSuccess, EitherOf,
+};
+
+mod error;
+pub use error::PalletError;
+
+mod preimages;
+pub use preimages::{QueryPreimage, StorePreimage};
+
+mod messages;
+pub use messages::{
+	ProcessMessage, ProcessMessageError, EnqueueMessage, Footprint,
+};
 
 mod voting;
-pub use voting::{CurrencyToVote, SaturatingCurrencyToVote, U128CurrencyToVote};
+pub use voting::{
+	CurrencyToVote, SaturatingCurrencyToVote, U128CurrencyToVote, VoteTally, PollStatus, Polling,
+};
 
 // for backwards-compatibility with existing imports
 pub use max_encoded_len::MaxEncodedLen;
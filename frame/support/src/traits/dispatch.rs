@@ -17,8 +17,9 @@
 
 //! Traits for dealing with dispatching calls and the origin from which they are dispatched.
 
+use sp_std::marker::PhantomData;
 use crate::dispatch::DispatchResultWithPostInfo;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, Morph, TryMorph};
 
 /// Some sort of check on the origin is performed by this object.
 pub trait EnsureOrigin<OuterOrigin> {
@@ -33,9 +34,147 @@ pub trait EnsureOrigin<OuterOrigin> {
 
 	/// Returns an outer origin capable of passing `try_origin` check.
 	///
+	/// Implementations for which no such origin may exist implement
+	/// [`EnsureOrigin::try_successful_origin`] instead.
+	///
+	/// ** Should be used for benchmarking only!!! **
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> OuterOrigin {
+		Self::try_successful_origin().expect("No origin passes the check")
+	}
+
+	/// Returns an outer origin capable of passing `try_origin` check, or `Err` if there is none.
+	///
 	/// ** Should be used for benchmarking only!!! **
 	#[cfg(feature = "runtime-benchmarks")]
-	fn successful_origin() -> OuterOrigin;
+	fn try_successful_origin() -> Result<OuterOrigin, ()> {
+		Ok(Self::successful_origin())
+	}
+}
+
+/// Some sort of check on the origin is performed by this object, depending on an argument.
+///
+/// This is the same as [`EnsureOrigin`] except that the check may depend on `Argument`, e.g. the
+/// origin must be a specific member of a collective.
+pub trait EnsureOriginWithArg<OuterOrigin, Argument> {
+	/// A return type.
+	type Success;
+	/// Perform the origin check.
+	fn ensure_origin(o: OuterOrigin, a: &Argument) -> Result<Self::Success, BadOrigin> {
+		Self::try_origin(o, a).map_err(|_| BadOrigin)
+	}
+	/// Perform the origin check.
+	fn try_origin(o: OuterOrigin, a: &Argument) -> Result<Self::Success, OuterOrigin>;
+
+	/// Returns an outer origin capable of passing `try_origin` check with the argument `a`.
+	///
+	/// Implementations for which no such origin may exist implement
+	/// [`EnsureOriginWithArg::try_successful_origin`] instead.
+	///
+	/// ** Should be used for benchmarking only!!! **
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin(a: &Argument) -> OuterOrigin {
+		Self::try_successful_origin(a).expect("No origin passes the check")
+	}
+
+	/// Returns an outer origin capable of passing `try_origin` check with the argument `a`, or
+	/// `Err` if there is none.
+	///
+	/// ** Should be used for benchmarking only!!! **
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(a: &Argument) -> Result<OuterOrigin, ()> {
+		Ok(Self::successful_origin(a))
+	}
+}
+
+/// An [`EnsureOrigin`] which never accepts any origin.
+pub struct NeverEnsureOrigin<Success>(PhantomData<Success>);
+impl<OO, Success> EnsureOrigin<OO> for NeverEnsureOrigin<Success> {
+	type Success = Success;
+	fn try_origin(o: OO) -> Result<Success, OO> {
+		Err(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<OO, ()> {
+		Err(())
+	}
+}
+
+/// An [`EnsureOriginWithArg`] which ignores the argument and checks the origin with
+/// [`EnsureOrigin`] `EO`.
+pub struct AsEnsureOriginWithArg<EO>(PhantomData<EO>);
+impl<OuterOrigin, Argument, EO: EnsureOrigin<OuterOrigin>>
+	EnsureOriginWithArg<OuterOrigin, Argument> for AsEnsureOriginWithArg<EO>
+{
+	type Success = EO::Success;
+	fn try_origin(o: OuterOrigin, _: &Argument) -> Result<Self::Success, OuterOrigin> {
+		EO::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &Argument) -> Result<OuterOrigin, ()> {
+		EO::try_successful_origin()
+	}
+}
+
+/// An [`EnsureOrigin`] which checks the origin with `Original` and then maps its success with
+/// `Mutator`.
+///
+/// E.g. `MapSuccess<EnsureRoot<AccountId>, Replace<Balance, MaxSpend>>` accepts the root origin
+/// and returns `MaxSpend` as the success.
+pub struct MapSuccess<Original, Mutator>(PhantomData<(Original, Mutator)>);
+impl<O, Original: EnsureOrigin<O>, Mutator: Morph<Original::Success>> EnsureOrigin<O>
+	for MapSuccess<Original, Mutator>
+{
+	type Success = Mutator::Outcome;
+	fn try_origin(o: O) -> Result<Mutator::Outcome, O> {
+		Original::try_origin(o).map(Mutator::morph)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Original::try_successful_origin()
+	}
+}
+
+/// An [`EnsureOrigin`] which checks the origin with `Original` and then tries to map its success
+/// with `Mutator`.
+///
+/// The origin is rejected if the mapping fails.
+pub struct TryMapSuccess<Original, Mutator>(PhantomData<(Original, Mutator)>);
+impl<O: Clone, Original: EnsureOrigin<O>, Mutator: TryMorph<Original::Success>> EnsureOrigin<O>
+	for TryMapSuccess<Original, Mutator>
+{
+	type Success = Mutator::Outcome;
+	fn try_origin(o: O) -> Result<Mutator::Outcome, O> {
+		let orig = o.clone();
+		Original::try_origin(o).and_then(|success| Mutator::try_morph(success).map_err(|()| orig))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Original::try_successful_origin()
+	}
+}
+
+/// The "OR gate" of two [`EnsureOrigin`]s with the same success type.
+///
+/// The origin check passes if `L` or `R` passes, `L` is tested first. Use
+/// `frame_system::EnsureOneOf` if the success types are different.
+pub struct EitherOf<L, R>(PhantomData<(L, R)>);
+impl<O, L: EnsureOrigin<O>, R: EnsureOrigin<O, Success = L::Success>> EnsureOrigin<O>
+	for EitherOf<L, R>
+{
+	type Success = L::Success;
+	fn try_origin(o: O) -> Result<L::Success, O> {
+		L::try_origin(o).or_else(R::try_origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		L::try_successful_origin().or_else(|()| R::try_successful_origin())
+	}
 }
 
 /// Type that can be dispatched with an origin but without checking the origin filter.
@@ -91,3 +230,72 @@ pub trait OriginTrait: Sized {
 	/// Create with system signed origin and `frame-system::Config::BaseCallFilter`.
 	fn signed(by: Self::AccountId) -> Self;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{parameter_types, traits::Replace};
+
+	/// Accepts the even origins and returns them.
+	struct EnsureEven;
+	impl EnsureOrigin<u32> for EnsureEven {
+		type Success = u32;
+		fn try_origin(o: u32) -> Result<u32, u32> {
+			if o % 2 == 0 { Ok(o) } else { Err(o) }
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn successful_origin() -> u32 {
+			0
+		}
+	}
+
+	/// Accepts the multiples of three and returns them.
+	struct EnsureMultipleOfThree;
+	impl EnsureOrigin<u32> for EnsureMultipleOfThree {
+		type Success = u32;
+		fn try_origin(o: u32) -> Result<u32, u32> {
+			if o % 3 == 0 { Ok(o) } else { Err(o) }
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn successful_origin() -> u32 {
+			0
+		}
+	}
+
+	/// Only accepts values below ten.
+	struct BelowTen;
+	impl TryMorph<u32> for BelowTen {
+		type Outcome = u32;
+		fn try_morph(a: u32) -> Result<u32, ()> {
+			if a < 10 { Ok(a) } else { Err(()) }
+		}
+	}
+
+	parameter_types! {
+		pub const SpendLimit: u64 = 100;
+	}
+
+	#[test]
+	fn ensure_origin_combinators_work() {
+		assert_eq!(NeverEnsureOrigin::<()>::try_origin(2), Err(2));
+
+		assert_eq!(EitherOf::<EnsureEven, EnsureMultipleOfThree>::try_origin(4), Ok(4));
+		assert_eq!(EitherOf::<EnsureEven, EnsureMultipleOfThree>::try_origin(9), Ok(9));
+		assert_eq!(EitherOf::<EnsureEven, EnsureMultipleOfThree>::try_origin(5), Err(5));
+
+		type EvenSpend = MapSuccess<EnsureEven, Replace<u64, SpendLimit>>;
+		assert_eq!(EvenSpend::try_origin(4), Ok(100));
+		assert_eq!(EvenSpend::try_origin(5), Err(5));
+
+		assert_eq!(TryMapSuccess::<EnsureEven, BelowTen>::try_origin(4), Ok(4));
+		assert_eq!(TryMapSuccess::<EnsureEven, BelowTen>::try_origin(12), Err(12));
+		assert_eq!(TryMapSuccess::<EnsureEven, BelowTen>::try_origin(5), Err(5));
+
+		assert_eq!(
+			<AsEnsureOriginWithArg<EnsureEven> as EnsureOriginWithArg<u32, ()>>::try_origin(4, &()),
+			Ok(4),
+		);
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for describing and constraining pallet error types.
+
+use codec::{Decode, Encode};
+use sp_std::marker::PhantomData;
+
+/// Trait for types which can be used as a field in a variant of the `#[pallet::error]` enum.
+///
+/// The encoded pallet error, i.e. the index of the variant followed by its fields, is stored in
+/// `DispatchError::Module` and must fit in `sp_runtime::MAX_MODULE_ERROR_ENCODED_SIZE` bytes.
+/// This is checked by the integrity test of the pallet.
+///
+/// It can be derived with `#[derive(PalletError)]`.
+pub trait PalletError: Encode + Decode {
+	/// The maximum encoded size of the type.
+	const MAX_ENCODED_SIZE: usize;
+}
+
+macro_rules! impl_for_types {
+	(size: $size:expr, $($typ:ty),+) => {
+		$(
+			impl PalletError for $typ {
+				const MAX_ENCODED_SIZE: usize = $size;
+			}
+		)+
+	};
+}
+
+impl_for_types!(size: 0, ());
+impl_for_types!(size: 1, u8, i8, bool);
+impl_for_types!(size: 2, u16, i16);
+impl_for_types!(size: 4, u32, i32);
+
+impl<T> PalletError for PhantomData<T> {
+	const MAX_ENCODED_SIZE: usize = 0;
+}
+
+impl<T: PalletError> PalletError for Option<T> {
+	const MAX_ENCODED_SIZE: usize = T::MAX_ENCODED_SIZE.saturating_add(1);
+}
+
+impl<T: PalletError, E: PalletError> PalletError for Result<T, E> {
+	const MAX_ENCODED_SIZE: usize = {
+		let size = if T::MAX_ENCODED_SIZE > E::MAX_ENCODED_SIZE {
+			T::MAX_ENCODED_SIZE
+		} else {
+			E::MAX_ENCODED_SIZE
+		};
+		size.saturating_add(1)
+	};
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 18)]
+impl PalletError for Tuple {
+	const MAX_ENCODED_SIZE: usize = {
+		let mut size = 0_usize;
+		for_tuples!( #( size = size.saturating_add(Tuple::MAX_ENCODED_SIZE); )* );
+		size
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn max_encoded_size_of_nested_types() {
+		assert_eq!(<(u8, Option<u16>)>::MAX_ENCODED_SIZE, 4);
+		assert_eq!(<Result<u32, ()>>::MAX_ENCODED_SIZE, 5);
+		assert_eq!(<PhantomData<u64>>::MAX_ENCODED_SIZE, 0);
+	}
+}
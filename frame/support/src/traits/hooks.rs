@@ -39,12 +39,42 @@ pub trait OnInitialize<BlockNumber> {
 #[impl_for_tuples(30)]
 impl<BlockNumber: Clone> OnInitialize<BlockNumber> for Tuple {
 	fn on_initialize(n: BlockNumber) -> crate::weights::Weight {
-		let mut weight = 0;
+		let mut weight = Weight::zero();
 		for_tuples!( #( weight = weight.saturating_add(Tuple::on_initialize(n.clone())); )* );
 		weight
 	}
 }
 
+/// The `on_initialize` hook of a pallet, or of a tuple of pallets, reporting the weight consumed
+/// by each pallet.
+///
+/// This is implemented by `decl_module` and the pallet macro for every pallet, there is no need to
+/// implement it manually.
+pub trait OnInitializeMetered<BlockNumber> {
+	/// Execute [`OnInitialize::on_initialize`], reporting the name and the weight of each pallet to
+	/// `report`.
+	///
+	/// Return the total weight consumed.
+	fn on_initialize_metered(
+		n: BlockNumber,
+		report: &mut dyn FnMut(&'static str, crate::weights::Weight),
+	) -> crate::weights::Weight;
+}
+
+#[impl_for_tuples(30)]
+impl<BlockNumber: Clone> OnInitializeMetered<BlockNumber> for Tuple {
+	fn on_initialize_metered(
+		n: BlockNumber,
+		report: &mut dyn FnMut(&'static str, crate::weights::Weight),
+	) -> crate::weights::Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight = weight.saturating_add(Tuple::on_initialize_metered(n.clone(), report));
+		)* );
+		weight
+	}
+}
+
 /// The block finalization trait.
 ///
 /// Implementing this lets you express what should happen for your pallet when the block is ending.
@@ -80,7 +110,7 @@ pub trait OnIdle<BlockNumber> {
 #[impl_for_tuples(30)]
 impl<BlockNumber: Clone> OnIdle<BlockNumber> for Tuple {
 	fn on_idle(n: BlockNumber,  remaining_weight: crate::weights::Weight) -> crate::weights::Weight {
-		let mut weight = 0;
+		let mut weight = Weight::zero();
 		for_tuples!( #(
 			let adjusted_remaining_weight = remaining_weight.saturating_sub(weight);
 			weight = weight.saturating_add(Tuple::on_idle(n.clone(),  adjusted_remaining_weight));
@@ -89,6 +119,26 @@ impl<BlockNumber: Clone> OnIdle<BlockNumber> for Tuple {
 	}
 }
 
+/// Check the invariants of the storage of a pallet against the current state.
+///
+/// This is never meant to be executed on-chain, only by testing tools like `try-runtime`.
+#[cfg(feature = "try-runtime")]
+pub trait TryState<BlockNumber> {
+	/// Execute the state checks, returns an error describing the first broken invariant.
+	fn try_state(_n: BlockNumber) -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+#[impl_for_tuples(30)]
+impl<BlockNumber: Clone> TryState<BlockNumber> for Tuple {
+	fn try_state(n: BlockNumber) -> Result<(), &'static str> {
+		for_tuples!( #( Tuple::try_state(n.clone())?; )* );
+		Ok(())
+	}
+}
+
 /// A trait that will be called at genesis.
 ///
 /// Implementing this trait for a pallet let's you express operations that should
@@ -182,7 +232,7 @@ pub trait OnRuntimeUpgrade {
 #[impl_for_tuples(30)]
 impl OnRuntimeUpgrade for Tuple {
 	fn on_runtime_upgrade() -> crate::weights::Weight {
-		let mut weight = 0;
+		let mut weight = Weight::zero();
 		for_tuples!( #( weight = weight.saturating_add(Tuple::on_runtime_upgrade()); )* );
 		weight
 	}
@@ -256,6 +306,16 @@ pub trait Hooks<BlockNumber> {
 		Ok(())
 	}
 
+	/// Check the invariants of the storage of this pallet against the state at block `_n`, e.g.
+	/// that the sum of the balances equals the total issuance.
+	///
+	/// This hook is never meant to be executed on-chain but is meant to be used by testing tools.
+	/// It must not modify the state.
+	#[cfg(feature = "try-runtime")]
+	fn try_state(_n: BlockNumber) -> Result<(), &'static str> {
+		Ok(())
+	}
+
 	/// Implementing this function on a module allows you to perform long-running tasks
 	/// that make (by default) validators generate transactions that feed results
 	/// of those long-running computations back on chain.
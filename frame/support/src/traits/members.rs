@@ -18,6 +18,7 @@
 //! Traits for dealing with the idea of membership.
 
 use sp_std::{prelude::*, marker::PhantomData};
+use super::{Filter, GetCallMetadata, IsSubType};
 
 /// A trait for querying whether a type can be said to "contain" a value.
 pub trait Contains<T> {
@@ -31,6 +32,128 @@ impl<T> Contains<T> for All<T> {
 	fn contains(_: &T) -> bool { true }
 }
 
+/// A `Contains` implementation which always returns `true`.
+///
+/// Like the other combinators of this module, it also implements [`Filter`], so that it can be
+/// used as the `BaseCallFilter` of a runtime.
+pub struct Everything;
+impl<T> Contains<T> for Everything {
+	fn contains(_: &T) -> bool { true }
+}
+impl<T> Filter<T> for Everything {
+	fn filter(_: &T) -> bool { true }
+}
+
+/// A `Contains` implementation which always returns `false`.
+pub struct Nothing;
+impl<T> Contains<T> for Nothing {
+	fn contains(_: &T) -> bool { false }
+}
+impl<T> Filter<T> for Nothing {
+	fn filter(_: &T) -> bool { false }
+}
+impl<T: Ord> SortedMembers<T> for Nothing {
+	fn sorted_members() -> Vec<T> { Vec::new() }
+}
+impl ContainsLengthBound for Nothing {
+	fn min_len() -> usize { 0 }
+	fn max_len() -> usize { 0 }
+}
+
+/// A `Contains` implementation that contains everything except the values in `Exclude`.
+pub struct EverythingBut<Exclude>(PhantomData<Exclude>);
+impl<T, Exclude: Contains<T>> Contains<T> for EverythingBut<Exclude> {
+	fn contains(t: &T) -> bool { !Exclude::contains(t) }
+}
+impl<T, Exclude: Contains<T>> Filter<T> for EverythingBut<Exclude> {
+	fn filter(t: &T) -> bool { Self::contains(t) }
+}
+
+/// A `Contains` implementation that contains all the values in `These` except the values in
+/// `Except`.
+pub struct TheseExcept<These, Except>(PhantomData<(These, Except)>);
+impl<T, These: Contains<T>, Except: Contains<T>> Contains<T> for TheseExcept<These, Except> {
+	fn contains(t: &T) -> bool { These::contains(t) && !Except::contains(t) }
+}
+impl<T, These: Contains<T>, Except: Contains<T>> Filter<T> for TheseExcept<These, Except> {
+	fn filter(t: &T) -> bool { Self::contains(t) }
+}
+
+/// A `Contains` implementation that contains the values which are both in `These` and in `Those`.
+pub struct InsideBoth<These, Those>(PhantomData<(These, Those)>);
+impl<T, These: Contains<T>, Those: Contains<T>> Contains<T> for InsideBoth<These, Those> {
+	fn contains(t: &T) -> bool { These::contains(t) && Those::contains(t) }
+}
+impl<T, These: Contains<T>, Those: Contains<T>> Filter<T> for InsideBoth<These, Those> {
+	fn filter(t: &T) -> bool { Self::contains(t) }
+}
+
+/// A `Contains` and `SortedMembers` implementation that contains only the value given by
+/// `Value`.
+///
+/// Like the types created by `ord_parameter_types!`, it can be used as the set of accounts of an
+/// origin such as `EnsureSignedBy`, without declaring a type for the account.
+pub struct Equals<Value>(PhantomData<Value>);
+impl<T: PartialEq, Value: super::Get<T>> Contains<T> for Equals<Value> {
+	fn contains(t: &T) -> bool { &Value::get() == t }
+}
+impl<T: PartialEq, Value: super::Get<T>> Filter<T> for Equals<Value> {
+	fn filter(t: &T) -> bool { <Self as Contains<T>>::contains(t) }
+}
+impl<T: Ord, Value: super::Get<T>> SortedMembers<T> for Equals<Value> {
+	fn sorted_members() -> Vec<T> { vec![Value::get()] }
+	fn contains(t: &T) -> bool { &Value::get() == t }
+	fn count() -> usize { 1 }
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(_: &T) {}
+}
+impl<Value> ContainsLengthBound for Equals<Value> {
+	fn min_len() -> usize { 1 }
+	fn max_len() -> usize { 1 }
+}
+
+/// A `Contains` implementation that contains the calls of the pallet named `Pallet` in the
+/// runtime, as given by the [`GetCallMetadata`] of the calls.
+pub struct InPallet<Pallet>(PhantomData<Pallet>);
+impl<Call: GetCallMetadata, Pallet: super::Get<&'static str>> Contains<Call> for InPallet<Pallet> {
+	fn contains(call: &Call) -> bool { call.get_call_metadata().pallet_name == Pallet::get() }
+}
+impl<Call: GetCallMetadata, Pallet: super::Get<&'static str>> Filter<Call> for InPallet<Pallet> {
+	fn filter(call: &Call) -> bool { Self::contains(call) }
+}
+
+/// A `Contains` implementation that contains the calls of the function named `Function` of the
+/// pallet named `Pallet` in the runtime, as given by the [`GetCallMetadata`] of the calls.
+pub struct IsCall<Pallet, Function>(PhantomData<(Pallet, Function)>);
+impl<Call, Pallet, Function> Contains<Call> for IsCall<Pallet, Function> where
+	Call: GetCallMetadata,
+	Pallet: super::Get<&'static str>,
+	Function: super::Get<&'static str>,
+{
+	fn contains(call: &Call) -> bool {
+		let metadata = call.get_call_metadata();
+		metadata.pallet_name == Pallet::get() && metadata.function_name == Function::get()
+	}
+}
+impl<Call, Pallet, Function> Filter<Call> for IsCall<Pallet, Function> where
+	Call: GetCallMetadata,
+	Pallet: super::Get<&'static str>,
+	Function: super::Get<&'static str>,
+{
+	fn filter(call: &Call) -> bool { Self::contains(call) }
+}
+
+/// A `Contains` implementation that contains the calls which are a `SubCall`, usually the calls
+/// of the pallet whose call enum is `SubCall`.
+pub struct IsSubCall<SubCall>(PhantomData<SubCall>);
+impl<Call: IsSubType<SubCall>, SubCall> Contains<Call> for IsSubCall<SubCall> {
+	fn contains(call: &Call) -> bool { call.is_sub_type().is_some() }
+}
+impl<Call: IsSubType<SubCall>, SubCall> Filter<Call> for IsSubCall<SubCall> {
+	fn filter(call: &Call) -> bool { Self::contains(call) }
+}
+
+/// A tuple contains the values which are contained by any of its elements.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 impl<T> Contains<T> for Tuple {
 	fn contains(t: &T) -> bool {
@@ -41,8 +164,40 @@ impl<T> Contains<T> for Tuple {
 	}
 }
 
+/// The members of a tuple are the members of all its elements, sorted and without duplicates.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<T: Ord> SortedMembers<T> for Tuple {
+	fn sorted_members() -> Vec<T> {
+		let mut members = Vec::new();
+		for_tuples!( #( members.extend(Tuple::sorted_members()); )* );
+		members.sort();
+		members.dedup();
+		members
+	}
+
+	fn contains(t: &T) -> bool {
+		for_tuples!( #(
+			if Tuple::contains(t) { return true }
+		)* );
+		false
+	}
+}
+
 /// Create a type which implements the `Contains` trait for a particular type with syntax similar
 /// to `matches!`.
+///
+/// The type also implements `Filter`, so that it can be used as the `BaseCallFilter` of a runtime,
+/// possibly combined with the other combinators of this module:
+/// ```
+/// # use frame_support::{match_type, traits::{Contains, EverythingBut}};
+/// match_type! {
+/// 	pub type TenToTwenty: impl Contains<u8> = { 10..=20 };
+/// }
+/// type OutsideTenToTwenty = EverythingBut<TenToTwenty>;
+///
+/// assert!(TenToTwenty::contains(&10));
+/// assert!(OutsideTenToTwenty::contains(&21));
+/// ```
 #[macro_export]
 macro_rules! match_type {
 	( pub type $n:ident: impl Contains<$t:ty> = { $phead:pat $( | $ptail:pat )* } ; ) => {
@@ -52,6 +207,11 @@ macro_rules! match_type {
 				matches!(l, $phead $( | $ptail )* )
 			}
 		}
+		impl $crate::traits::Filter<$t> for $n {
+			fn filter(l: &$t) -> bool {
+				<Self as $crate::traits::Contains<$t>>::contains(l)
+			}
+		}
 	}
 }
 
@@ -69,6 +229,65 @@ mod tests {
 			assert_eq!(OneOrTenToTwenty::contains(&i), i == 1 || i >= 10 && i <= 20);
 		}
 	}
+
+	match_type! {
+		pub type TenToFifteen: impl Contains<u8> = { 10..=15 };
+	}
+
+	crate::parameter_types! {
+		pub const Ten: u8 = 10;
+		pub const Twenty: u8 = 20;
+	}
+
+	#[test]
+	fn contains_combinators_work() {
+		for i in 0..=255 {
+			assert!(<Everything as Contains<u8>>::contains(&i));
+			assert!(!<Nothing as Contains<u8>>::contains(&i));
+			assert_eq!(
+				EverythingBut::<OneOrTenToTwenty>::contains(&i),
+				!OneOrTenToTwenty::contains(&i),
+			);
+			assert_eq!(
+				TheseExcept::<OneOrTenToTwenty, TenToFifteen>::contains(&i),
+				i == 1 || i >= 16 && i <= 20,
+			);
+			assert_eq!(
+				InsideBoth::<OneOrTenToTwenty, TenToFifteen>::filter(&i),
+				i >= 10 && i <= 15,
+			);
+			assert_eq!(
+				<(TenToFifteen, OneOrTenToTwenty)>::contains(&i),
+				OneOrTenToTwenty::contains(&i),
+			);
+			assert_eq!(TenToFifteen::filter(&i), TenToFifteen::contains(&i));
+			assert_eq!(<Equals<Ten> as Contains<u8>>::contains(&i), i == 10);
+			assert_eq!(
+				<(Equals<Ten>, TenToFifteen) as Contains<u8>>::contains(&i),
+				i >= 10 && i <= 15,
+			);
+		}
+	}
+
+	#[test]
+	fn sorted_members_adapters_work() {
+		type TenAndTwenty = (Equals<Twenty>, Equals<Ten>, Equals<Ten>);
+
+		assert_eq!(<Equals<Ten> as SortedMembers<u8>>::sorted_members(), vec![10]);
+		assert_eq!(<Equals<Ten> as ContainsLengthBound>::max_len(), 1);
+		assert_eq!(<Nothing as SortedMembers<u8>>::sorted_members(), Vec::<u8>::new());
+		assert_eq!(<TenAndTwenty as SortedMembers<u8>>::sorted_members(), vec![10, 20]);
+		assert_eq!(<TenAndTwenty as SortedMembers<u8>>::count(), 2);
+		for i in 0..=255u8 {
+			assert_eq!(<Equals<Ten> as SortedMembers<u8>>::contains(&i), i == 10);
+			assert!(!<Nothing as SortedMembers<u8>>::contains(&i));
+			assert_eq!(
+				<TenAndTwenty as SortedMembers<u8>>::contains(&i),
+				i == 10 || i == 20,
+			);
+			assert_eq!(AsContains::<TenAndTwenty>::contains(&i), i == 10 || i == 20);
+		}
+	}
 }
 
 /// A trait for a set which can enumerate its members in order.
@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for enqueueing opaque messages and for processing them later on.
+
+use codec::{Decode, Encode, FullCodec};
+use sp_runtime::{traits::Member, RuntimeDebug};
+use crate::{storage::bounded_vec::BoundedSlice, traits::Get, weights::Weight};
+
+/// The errors with which processing a message with [`ProcessMessage::process_message`] can fail.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub enum ProcessMessageError {
+	/// The message data format is unknown, e.g. its header is unrecognised. This is permanent.
+	BadFormat,
+	/// The message data is bad, e.g. it fails to decode. This is permanent.
+	Corrupt,
+	/// The message format is unsupported, e.g. it is of an old version. This is permanent.
+	Unsupported,
+	/// The message was not processed because it could not be processed within the weight limit.
+	/// The parameter is the maximum weight which processing the message could take.
+	///
+	/// This is temporary, the message is processed again once enough weight is available.
+	Overweight(Weight),
+	/// The message processor wants to give up its current processing slot. This is temporary,
+	/// the message is processed again later on.
+	Yield,
+}
+
+impl ProcessMessageError {
+	/// Returns whether the message can never be processed successfully, and should be dropped.
+	pub fn is_permanent(&self) -> bool {
+		matches!(self, Self::BadFormat | Self::Corrupt | Self::Unsupported)
+	}
+}
+
+/// Processes opaque messages of some origin.
+pub trait ProcessMessage {
+	/// The origin of the messages, e.g. the transport from which they come.
+	type Origin: Member + FullCodec;
+
+	/// Process the given message, using no more than `weight_limit`.
+	///
+	/// Returns the weight used to process the message. A message which is processed but whose
+	/// execution fails must still return `Ok`, only the errors in [`ProcessMessageError`] prevent
+	/// the message from being consumed.
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<Weight, ProcessMessageError>;
+}
+
+/// The number of messages and their total size in bytes.
+#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Footprint {
+	/// The number of messages.
+	pub count: u64,
+	/// The total size of the messages in bytes.
+	pub size: u64,
+}
+
+/// Enqueues opaque messages of some origin, to be processed later on.
+pub trait EnqueueMessage<Origin> {
+	/// The maximal length of a single message in bytes.
+	type MaxMessageLen: Get<u32>;
+
+	/// Enqueue a single message of `origin`.
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: Origin);
+
+	/// Enqueue several messages of `origin`, in order.
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: Origin,
+	);
+
+	/// Remove all the messages of `origin` which are not yet processed.
+	fn sweep_queue(origin: Origin);
+
+	/// Returns the footprint of the messages of `origin` which are not yet processed.
+	fn footprint(origin: Origin) -> Footprint;
+}
@@ -19,6 +19,7 @@
 
 use codec::{Encode, Decode};
 use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
 
 /// Provides information about the pallet setup in the runtime.
 ///
@@ -29,6 +30,10 @@ pub trait PalletInfo {
 	fn index<P: 'static>() -> Option<usize>;
 	/// Convert the given pallet `P` into its name as configured in the runtime.
 	fn name<P: 'static>() -> Option<&'static str>;
+	/// Convert the given pallet `P` into the names it had before being renamed in the runtime.
+	fn renamed_from<P: 'static>() -> &'static [&'static str] {
+		&[]
+	}
 }
 
 /// Provides information about the pallet setup in the runtime.
@@ -39,6 +44,10 @@ pub trait PalletInfoAccess {
 	fn index() -> usize;
 	/// Name of the pallet as configured in the runtime.
 	fn name() -> &'static str;
+	/// Names of the pallet before being renamed in the runtime.
+	fn renamed_from() -> &'static [&'static str] {
+		&[]
+	}
 }
 
 /// The function and pallet name of the Call.
@@ -58,6 +67,23 @@ pub trait GetCallName {
 	fn get_call_name(&self) -> &'static str;
 }
 
+/// An argument of a Call.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct CallArgument {
+	/// Name of the argument.
+	pub name: &'static str,
+	/// Name of the type of the argument, as found in the metadata.
+	pub ty: &'static str,
+	/// The SCALE encoded value of the argument, as found in the encoded Call.
+	pub value: Vec<u8>,
+}
+
+/// Gets the arguments of the Call.
+pub trait GetCallArguments {
+	/// Return the arguments of the Call, in the order in which they are encoded.
+	fn get_call_arguments(&self) -> Vec<CallArgument>;
+}
+
 /// Gets the metadata for the Call - function name and pallet name.
 pub trait GetCallMetadata {
 	/// Return all module names.
@@ -176,3 +202,107 @@ pub trait GetPalletVersion {
 	/// this function returns `None`.
 	fn storage_version() -> Option<PalletVersion>;
 }
+
+/// The storage key postfix that is used to store the [`StorageVersion`] per pallet.
+///
+/// The full storage key is built by using:
+/// Twox128([`PalletInfo::name`]) ++ Twox128([`STORAGE_VERSION_STORAGE_KEY_POSTFIX`])
+pub const STORAGE_VERSION_STORAGE_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
+
+/// The version of the storage layout of a pallet.
+///
+/// Unlike [`PalletVersion`], which follows the crate version, the storage version is only bumped
+/// when the storage layout of the pallet changes, and it is only written to the state by the
+/// genesis of the pallet and by its migrations. Migrations can thus check the version found in
+/// the state to know whether they were already applied.
+///
+/// Each storage version is stored in the state under a fixed key. See
+/// [`STORAGE_VERSION_STORAGE_KEY_POSTFIX`] for how this key is built.
+#[derive(RuntimeDebug, Eq, PartialEq, Encode, Decode, Ord, PartialOrd, Clone, Copy, Default)]
+pub struct StorageVersion(u16);
+
+impl StorageVersion {
+	/// Creates a new instance of `Self`.
+	pub const fn new(version: u16) -> Self {
+		Self(version)
+	}
+
+	/// Returns the storage key for the storage version of the pallet `P`.
+	///
+	/// See [`STORAGE_VERSION_STORAGE_KEY_POSTFIX`] on how this key is built.
+	pub fn storage_key<P: PalletInfoAccess>() -> [u8; 32] {
+		let pallet_name = sp_io::hashing::twox_128(P::name().as_bytes());
+		let postfix = sp_io::hashing::twox_128(STORAGE_VERSION_STORAGE_KEY_POSTFIX);
+
+		let mut final_key = [0u8; 32];
+		final_key[..16].copy_from_slice(&pallet_name);
+		final_key[16..].copy_from_slice(&postfix);
+
+		final_key
+	}
+
+	/// Put this storage version for the pallet `P` into the storage.
+	///
+	/// # Panics
+	///
+	/// This function will panic if it isn't executed in an externalities provided environment.
+	pub fn put<P: PalletInfoAccess>(&self) {
+		crate::storage::unhashed::put(&Self::storage_key::<P>(), self);
+	}
+
+	/// Get the storage version of the pallet `P` from the storage.
+	///
+	/// Returns the default version, i.e. `0`, if no version is stored.
+	///
+	/// # Panics
+	///
+	/// This function will panic if it isn't executed in an externalities provided environment.
+	pub fn get<P: PalletInfoAccess>() -> Self {
+		crate::storage::unhashed::get_or_default(&Self::storage_key::<P>())
+	}
+}
+
+impl PartialEq<u16> for StorageVersion {
+	fn eq(&self, other: &u16) -> bool {
+		self.0 == *other
+	}
+}
+
+impl PartialOrd<u16> for StorageVersion {
+	fn partial_cmp(&self, other: &u16) -> Option<sp_std::cmp::Ordering> {
+		Some(self.0.cmp(other))
+	}
+}
+
+/// Provides the storage versions of a pallet, see [`StorageVersion`].
+pub trait GetStorageVersion {
+	/// Returns the storage version declared by the pallet with `#[pallet::storage_version]`, or the
+	/// default version if none is declared.
+	fn current_storage_version() -> StorageVersion;
+
+	/// Returns the storage version found in the state.
+	///
+	/// It differs from [`GetStorageVersion::current_storage_version`] only after a runtime upgrade
+	/// which bumped the storage version of the pallet, until the migration of the pallet is
+	/// executed.
+	fn on_chain_storage_version() -> StorageVersion;
+}
+
+/// Checks that the on-chain storage version of the pallets match their current storage version.
+///
+/// This is checked by the integrity test generated by `construct_runtime!`, after the genesis of
+/// the pallets, and after the runtime upgrade with the feature `try-runtime`.
+pub trait CheckStorageVersion {
+	/// Returns an error if the on-chain storage version doesn't match the current storage version.
+	fn check_storage_version() -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl CheckStorageVersion for Tuple {
+	fn check_storage_version() -> Result<(), &'static str> {
+		for_tuples!( #( Tuple::check_storage_version()?; )* );
+		Ok(())
+	}
+}
@@ -0,0 +1,141 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for migrations which are executed over multiple blocks.
+
+use codec::{Decode, Encode, FullCodec};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+use crate::weights::Weight;
+
+/// An error which can occur when stepping a [`SteppedMigration`].
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum SteppedMigrationError {
+	/// The weight limit is not enough to make any progress, at least `required` is needed.
+	///
+	/// The step is retried in the next block with a full weight limit, unless it already had one.
+	InsufficientWeight {
+		/// The weight needed to make progress.
+		required: Weight,
+	},
+	/// The stored cursor could not be decoded into the cursor of the migration.
+	InvalidCursor,
+	/// The migration failed and cannot make any progress.
+	Failed,
+}
+
+/// The result of a step of a migration with an encoded cursor.
+pub type SteppedMigrationResult = Result<(Option<Vec<u8>>, Weight), SteppedMigrationError>;
+
+/// A migration which is executed in steps, each of them bounded by a weight limit.
+///
+/// Between two steps the migration keeps its progress in a `Cursor`, which is stored in the state
+/// by whoever drives the migration (e.g. `pallet-migrations`). A step is only ever given the
+/// cursor returned by the previous step, `None` for the first step.
+pub trait SteppedMigration {
+	/// The progress of the migration between two steps.
+	type Cursor: FullCodec;
+
+	/// Execute the next step of the migration, consuming at most `weight_limit`.
+	///
+	/// Returns the cursor to give to the next step, `None` if the migration is complete, and the
+	/// consumed weight.
+	///
+	/// A step must not have any side effect when it returns an error.
+	fn step(
+		cursor: Option<Self::Cursor>,
+		weight_limit: Weight,
+	) -> Result<(Option<Self::Cursor>, Weight), SteppedMigrationError>;
+
+	/// Same as [`Self::step`], but with the cursor encoded.
+	fn transmuted_step(cursor: Option<Vec<u8>>, weight_limit: Weight) -> SteppedMigrationResult {
+		let cursor = cursor
+			.map(|cursor| Self::Cursor::decode(&mut &cursor[..]))
+			.transpose()
+			.map_err(|_| SteppedMigrationError::InvalidCursor)?;
+
+		Self::step(cursor, weight_limit)
+			.map(|(cursor, weight)| (cursor.map(|cursor| cursor.encode()), weight))
+	}
+}
+
+/// An ordered list of [`SteppedMigration`]s, implemented for tuples of them.
+pub trait SteppedMigrations {
+	/// The number of migrations.
+	fn len() -> u32;
+
+	/// Execute the next step of the `n`th migration, see [`SteppedMigration::transmuted_step`].
+	///
+	/// Returns `None` if there is no `n`th migration.
+	fn nth_step(
+		n: u32,
+		cursor: Option<Vec<u8>>,
+		weight_limit: Weight,
+	) -> Option<SteppedMigrationResult>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+#[tuple_types_custom_trait_bound(SteppedMigration)]
+impl SteppedMigrations for Tuple {
+	fn len() -> u32 {
+		let steps: &[fn(Option<Vec<u8>>, Weight) -> SteppedMigrationResult] =
+			&[ for_tuples!( #( Tuple::transmuted_step ),* ) ];
+		steps.len() as u32
+	}
+
+	fn nth_step(
+		n: u32,
+		cursor: Option<Vec<u8>>,
+		weight_limit: Weight,
+	) -> Option<SteppedMigrationResult> {
+		let steps: &[fn(Option<Vec<u8>>, Weight) -> SteppedMigrationResult] =
+			&[ for_tuples!( #( Tuple::transmuted_step ),* ) ];
+		steps.get(n as usize).map(|step| step(cursor, weight_limit))
+	}
+}
+
+/// Something which drives multi-block migrations, used by the executive.
+///
+/// While migrations are ongoing the executive steps them at the beginning of every block and
+/// rejects all the extrinsics which are not mandatory, except the exempt calls of type `Call`.
+pub trait MultiStepMigrator<Call> {
+	/// Whether some migrations are ongoing.
+	fn ongoing() -> bool;
+
+	/// Whether `call` is applied even while migrations are ongoing.
+	///
+	/// This must at least include the calls upgrading the runtime, which are the only way out of
+	/// a failed migration keeping the chain stuck.
+	fn is_exempt(call: &Call) -> bool;
+
+	/// Execute the next steps of the ongoing migrations, returns the consumed weight.
+	fn step() -> Weight;
+}
+
+impl<Call> MultiStepMigrator<Call> for () {
+	fn ongoing() -> bool {
+		false
+	}
+
+	fn is_exempt(_call: &Call) -> bool {
+		false
+	}
+
+	fn step() -> Weight {
+		Weight::zero()
+	}
+}
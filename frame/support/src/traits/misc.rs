@@ -17,8 +17,8 @@
 
 //! Smaller traits used in FRAME which don't need their own file.
 
-use sp_runtime::traits::{StoredMapError, Block as BlockT};
-use sp_arithmetic::traits::AtLeast32Bit;
+use sp_runtime::traits::{StoredMapError, Block as BlockT, Morph, TryMorph};
+use sp_arithmetic::traits::{AtLeast32Bit, CheckedAdd, CheckedMul, CheckedSub, Saturating};
 use crate::dispatch::Parameter;
 
 /// Anything that can have a `::len()` method.
@@ -68,6 +68,24 @@ impl<const T: u32> Get<Option<u32>> for ConstU32<T> {
 	}
 }
 
+/// Morph any value into the value of type `T` returned by `V`, e.g. to replace the success of an
+/// origin check with a constant.
+pub struct Replace<T, V>(sp_std::marker::PhantomData<(T, V)>);
+
+impl<A, T, V: Get<T>> Morph<A> for Replace<T, V> {
+	type Outcome = T;
+	fn morph(_: A) -> T {
+		V::get()
+	}
+}
+
+impl<A, T, V: Get<T>> TryMorph<A> for Replace<T, V> {
+	type Outcome = T;
+	fn try_morph(_: A) -> Result<T, ()> {
+		Ok(V::get())
+	}
+}
+
 /// A type for which some values make sense to be able to drop without further consideration.
 pub trait TryDrop: Sized {
 	/// Drop an instance cleanly. Only works if its value represents "no-operation".
@@ -308,8 +326,9 @@ pub trait GetBacking {
 pub trait EnsureInherentsAreFirst<Block> {
 	/// Ensure the position of inherent is correct, i.e. they are before non-inherents.
 	///
+	/// On success return the number of inherents, i.e. the index of the first non-inherent.
 	/// On error return the index of the inherent with invalid position (counting from 0).
-	fn ensure_inherents_are_first(block: &Block) -> Result<(), u32>;
+	fn ensure_inherents_are_first(block: &Block) -> Result<u32, u32>;
 }
 
 /// An extrinsic on which we can get access to call.
@@ -336,3 +355,166 @@ where
 		&self.function
 	}
 }
+
+/// Generic function to mark an execution path as one that should never be executed.
+///
+/// It logs an error and panics in debug builds, i.e. when `debug_assertions` are enabled as they
+/// are in tests, but does nothing else in production.
+pub fn defensive_path(proof: impl sp_std::fmt::Debug) {
+	log::error!(
+		target: crate::LOG_TARGET,
+		"{}: {:?}",
+		DEFENSIVE_OP_PUBLIC_ERROR,
+		proof,
+	);
+	debug_assert!(false, "{}: {:?}", DEFENSIVE_OP_INTERNAL_ERROR, proof);
+}
+
+/// The error logged in production when a defensive operation fails.
+pub const DEFENSIVE_OP_PUBLIC_ERROR: &'static str =
+	"a defensive failure has been triggered; please report the block number";
+
+/// The panic message of a failed defensive operation in debug builds.
+pub const DEFENSIVE_OP_INTERNAL_ERROR: &'static str = "defensive failure has been triggered!";
+
+/// Unwrapping which should never fail.
+///
+/// When the value is missing the alternative is used, after logging an error and panicking in
+/// debug builds, see [`defensive_path`].
+pub trait Defensive<T> {
+	/// Unwrap or use `or`, defensively.
+	fn defensive_unwrap_or(self, or: T) -> T;
+
+	/// Unwrap or compute the value with `f`, defensively.
+	fn defensive_unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T;
+
+	/// Unwrap or use the default value, defensively.
+	fn defensive_unwrap_or_default(self) -> T where T: Default;
+
+	/// Return `self` as is, defensively logging if it is missing a value.
+	fn defensive(self) -> Self;
+}
+
+impl<T> Defensive<T> for Option<T> {
+	fn defensive_unwrap_or(self, or: T) -> T {
+		self.defensive_unwrap_or_else(|| or)
+	}
+
+	fn defensive_unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+		self.unwrap_or_else(|| {
+			defensive_path("Option::None");
+			f()
+		})
+	}
+
+	fn defensive_unwrap_or_default(self) -> T where T: Default {
+		self.defensive_unwrap_or_else(Default::default)
+	}
+
+	fn defensive(self) -> Self {
+		if self.is_none() {
+			defensive_path("Option::None");
+		}
+		self
+	}
+}
+
+impl<T, E: sp_std::fmt::Debug> Defensive<T> for Result<T, E> {
+	fn defensive_unwrap_or(self, or: T) -> T {
+		self.defensive_unwrap_or_else(|| or)
+	}
+
+	fn defensive_unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+		self.unwrap_or_else(|e| {
+			defensive_path(e);
+			f()
+		})
+	}
+
+	fn defensive_unwrap_or_default(self) -> T where T: Default {
+		self.defensive_unwrap_or_else(Default::default)
+	}
+
+	fn defensive(self) -> Self {
+		if let Err(e) = &self {
+			defensive_path(e);
+		}
+		self
+	}
+}
+
+/// Operations on an `Option` which is expected to always be `Some`.
+pub trait DefensiveOption<T> {
+	/// Map the value with `f`, or use `default` defensively.
+	fn defensive_map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U;
+
+	/// Turn the option into a result with the error `err`, defensively logging if it is `None`.
+	fn defensive_ok_or<E>(self, err: E) -> Result<T, E>;
+}
+
+impl<T> DefensiveOption<T> for Option<T> {
+	fn defensive_map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+		self.map(f).defensive_unwrap_or(default)
+	}
+
+	fn defensive_ok_or<E>(self, err: E) -> Result<T, E> {
+		self.defensive().ok_or(err)
+	}
+}
+
+/// Saturating arithmetic which should never saturate.
+///
+/// The result saturates as with [`Saturating`], after logging an error and panicking in debug
+/// builds, see [`defensive_path`].
+pub trait DefensiveSaturating {
+	/// Add `other`, defensively saturating.
+	fn defensive_saturating_add(self, other: Self) -> Self;
+
+	/// Subtract `other`, defensively saturating.
+	fn defensive_saturating_sub(self, other: Self) -> Self;
+
+	/// Multiply by `other`, defensively saturating.
+	fn defensive_saturating_mul(self, other: Self) -> Self;
+}
+
+impl<T: Saturating + CheckedAdd + CheckedSub + CheckedMul + Copy> DefensiveSaturating for T {
+	fn defensive_saturating_add(self, other: Self) -> Self {
+		self.checked_add(&other).defensive_unwrap_or_else(|| self.saturating_add(other))
+	}
+
+	fn defensive_saturating_sub(self, other: Self) -> Self {
+		self.checked_sub(&other).defensive_unwrap_or_else(|| self.saturating_sub(other))
+	}
+
+	fn defensive_saturating_mul(self, other: Self) -> Self {
+		self.checked_mul(&other).defensive_unwrap_or_else(|| self.saturating_mul(other))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn defensive_operations_pass_through_expected_values() {
+		assert_eq!(Some(1u32).defensive_unwrap_or(2), 1);
+		assert_eq!(Ok::<_, ()>(1u32).defensive_unwrap_or_default(), 1);
+		assert_eq!(Some(1u32).defensive_map_or(0, |v| v + 1), 2);
+		assert_eq!(Some(1u32).defensive_ok_or(()), Ok(1));
+		assert_eq!(1u32.defensive_saturating_add(2), 3);
+		assert_eq!(3u32.defensive_saturating_sub(2), 1);
+		assert_eq!(3u32.defensive_saturating_mul(2), 6);
+	}
+
+	#[test]
+	#[should_panic(expected = "defensive failure has been triggered!")]
+	fn defensive_unwrap_panics_in_debug_builds() {
+		None::<u32>.defensive_unwrap_or(1);
+	}
+
+	#[test]
+	#[should_panic(expected = "defensive failure has been triggered!")]
+	fn defensive_saturating_panics_in_debug_builds() {
+		0u32.defensive_saturating_sub(1);
+	}
+}
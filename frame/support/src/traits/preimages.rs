@@ -0,0 +1,83 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for dealing with preimages, i.e. data stored on chain and referenced by its hash.
+
+use sp_std::vec::Vec;
+use codec::Encode;
+use sp_runtime::DispatchError;
+
+/// An interface for querying preimages by their hash, and for requesting that missing ones are
+/// noted.
+pub trait QueryPreimage<Hash> {
+	/// Returns the length of the preimage of `hash`, if it is noted.
+	fn len(hash: &Hash) -> Option<u32>;
+
+	/// Returns the preimage of `hash`, if it is noted.
+	fn fetch(hash: &Hash) -> Option<Vec<u8>>;
+
+	/// Returns whether the preimage of `hash` is requested.
+	fn is_requested(hash: &Hash) -> bool;
+
+	/// Request that the preimage of `hash` is noted, and kept until the request is withdrawn.
+	///
+	/// Requests are counted: every call to `request` must be matched by a call to `unrequest`.
+	fn request(hash: &Hash);
+
+	/// Withdraw a previous request for the preimage of `hash`.
+	///
+	/// Once no requests are left, the preimage may be removed.
+	fn unrequest(hash: &Hash);
+
+	/// Returns whether the preimage of `hash` is noted.
+	fn have(hash: &Hash) -> bool {
+		Self::len(hash).is_some()
+	}
+}
+
+impl<Hash> QueryPreimage<Hash> for () {
+	fn len(_: &Hash) -> Option<u32> {
+		None
+	}
+	fn fetch(_: &Hash) -> Option<Vec<u8>> {
+		None
+	}
+	fn is_requested(_: &Hash) -> bool {
+		false
+	}
+	fn request(_: &Hash) {}
+	fn unrequest(_: &Hash) {}
+}
+
+/// An interface for storing preimages on chain.
+pub trait StorePreimage<Hash>: QueryPreimage<Hash> {
+	/// Note the preimage `bytes` and return its hash.
+	///
+	/// Noting a preimage which is already noted does nothing and also returns its hash.
+	///
+	/// No deposit is taken for preimages noted through this interface, it is up to the caller to
+	/// `unnote` them once they are no longer needed.
+	fn note(bytes: Vec<u8>) -> Result<Hash, DispatchError>;
+
+	/// Remove the preimage of `hash`, unless it is requested.
+	fn unnote(hash: &Hash);
+
+	/// Note the encoding of `value` and return its hash.
+	fn note_encoded<T: Encode>(value: &T) -> Result<Hash, DispatchError> {
+		Self::note(value.encode())
+	}
+}
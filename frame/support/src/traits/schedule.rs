@@ -20,6 +20,7 @@
 use sp_std::{prelude::*, fmt::Debug};
 use codec::{Encode, Decode, Codec, EncodeLike};
 use sp_runtime::{RuntimeDebug, DispatchError};
+use super::QueryPreimage;
 
 /// Information relating to the period of a scheduled task. First item is the length of the
 /// period and the second is the number of times it should be executed in total before the task
@@ -48,6 +49,68 @@ pub const HARD_DEADLINE: Priority = 63;
 /// The lowest priority. Most stuff should be around here.
 pub const LOWEST_PRIORITY: Priority = 255;
 
+/// Type representing an encodable value or the hash of the encoding of such a value.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum MaybeHashed<T, Hash> {
+	/// The value itself.
+	Value(T),
+	/// The hash of the encoded value which this value represents.
+	Hash(Hash),
+}
+
+impl<T, H> From<T> for MaybeHashed<T, H> {
+	fn from(t: T) -> Self {
+		MaybeHashed::Value(t)
+	}
+}
+
+/// Error type for `MaybeHashed::lookup`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum LookupError {
+	/// A call of this hash was not known.
+	Unknown,
+	/// The preimage for this hash was known but could not be decoded into a `Call`.
+	BadFormat,
+}
+
+impl<T: Decode, H> MaybeHashed<T, H> {
+	/// The value, if it is known.
+	pub fn as_value(&self) -> Option<&T> {
+		match &self {
+			Self::Value(c) => Some(c),
+			Self::Hash(_) => None,
+		}
+	}
+
+	/// The hash, if the value is not known.
+	pub fn as_hash(&self) -> Option<&H> {
+		match &self {
+			Self::Value(_) => None,
+			Self::Hash(h) => Some(h),
+		}
+	}
+
+	/// Request the preimage of the hash, if the value is not known.
+	pub fn ensure_requested<P: QueryPreimage<H>>(&self) {
+		if let Self::Hash(hash) = self {
+			P::request(hash)
+		}
+	}
+
+	/// Cancel the request for the preimage of the hash, if the value is not known.
+	pub fn ensure_unrequested<P: QueryPreimage<H>>(&self) {
+		if let Self::Hash(hash) = self {
+			P::unrequest(hash)
+		}
+	}
+
+	/// Look up the value of the preimage of `hash`.
+	pub fn lookup<P: QueryPreimage<H>>(hash: &H) -> Result<T, LookupError> {
+		let data = P::fetch(hash).ok_or(LookupError::Unknown)?;
+		T::decode(&mut &data[..]).map_err(|_| LookupError::BadFormat)
+	}
+}
+
 /// A type that can be used as a scheduler.
 pub trait Anon<BlockNumber, Call, Origin> {
 	/// An address which can be used for removing a scheduled task.
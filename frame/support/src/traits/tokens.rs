@@ -26,5 +26,6 @@ pub mod nonfungibles;
 mod misc;
 pub use misc::{
 	WithdrawConsequence, DepositConsequence, ExistenceRequirement, BalanceStatus, WithdrawReasons,
+	ReasonId,
 };
 pub use imbalance::Imbalance;
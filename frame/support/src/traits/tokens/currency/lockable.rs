@@ -32,6 +32,9 @@ pub trait LockableCurrency<AccountId>: Currency<AccountId> {
 	type Moment;
 
 	/// The maximum number of locks a user should have on their account.
+	///
+	/// It is enforced by `try_set_lock` and `try_extend_lock`, while `set_lock` and `extend_lock`
+	/// cannot fail and only use it as a hint.
 	type MaxLocks: Get<u32>;
 
 	/// Create a new balance lock on account `who`.
@@ -62,6 +65,34 @@ pub trait LockableCurrency<AccountId>: Currency<AccountId> {
 		reasons: WithdrawReasons,
 	);
 
+	/// Like [`Self::set_lock`], but fails instead of creating a lock beyond `MaxLocks` on the
+	/// account `who`. Updating an existing lock never fails.
+	///
+	/// The default implementation does not enforce `MaxLocks` and never fails.
+	fn try_set_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		Self::set_lock(id, who, amount, reasons);
+		Ok(())
+	}
+
+	/// Like [`Self::extend_lock`], but fails instead of creating a lock beyond `MaxLocks` on the
+	/// account `who`. Extending an existing lock never fails.
+	///
+	/// The default implementation does not enforce `MaxLocks` and never fails.
+	fn try_extend_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) -> DispatchResult {
+		Self::extend_lock(id, who, amount, reasons);
+		Ok(())
+	}
+
 	/// Remove an existing lock.
 	fn remove_lock(
 		id: LockIdentifier,
@@ -84,8 +115,8 @@ pub trait VestingSchedule<AccountId> {
 
 	/// Adds a vesting schedule to a given account.
 	///
-	/// If there already exists a vesting schedule for the given account, an `Err` is returned
-	/// and nothing is updated.
+	/// If the account can't have another vesting schedule, or the schedule is invalid, an `Err`
+	/// is returned and nothing is updated.
 	///
 	/// Is a no-op if the amount to be vested is zero.
 	///
@@ -97,8 +128,16 @@ pub trait VestingSchedule<AccountId> {
 		starting_block: Self::Moment,
 	) -> DispatchResult;
 
-	/// Remove a vesting schedule for a given account.
+	/// Checks if `add_vesting_schedule` would work against `who`.
+	fn can_add_vesting_schedule(
+		who: &AccountId,
+		locked: <Self::Currency as Currency<AccountId>>::Balance,
+		per_block: <Self::Currency as Currency<AccountId>>::Balance,
+		starting_block: Self::Moment,
+	) -> DispatchResult;
+
+	/// Remove the vesting schedule at `schedule_index` for a given account.
 	///
 	/// NOTE: This doesn't alter the free balance of the account.
-	fn remove_vesting_schedule(who: &AccountId);
+	fn remove_vesting_schedule(who: &AccountId, schedule_index: u32) -> DispatchResult;
 }
@@ -16,12 +16,17 @@
 // limitations under the License.
 
 //! The traits for dealing with a single fungible token class and any associated types.
+//!
+//! The capabilities are split into narrow traits, so that a pallet only depends on what it needs:
+//! [`Inspect`] and [`Mutate`] for the balances, [`Transfer`] for transfers which don't create any
+//! imbalance, [`InspectHold`] and [`MutateHold`] for funds held for a reason and
+//! [`InspectFreeze`] and [`MutateFreeze`] for frozen funds.
 
 use super::*;
 use sp_runtime::traits::Saturating;
 use crate::traits::misc::Get;
 use crate::dispatch::{DispatchResult, DispatchError};
-use super::misc::{DepositConsequence, WithdrawConsequence, Balance};
+use super::misc::{DepositConsequence, WithdrawConsequence, Balance, ReasonId};
 
 mod balanced;
 mod imbalance;
@@ -109,34 +114,48 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 	) -> Result<Self::Balance, DispatchError>;
 }
 
-/// Trait for inspecting a fungible asset which can be reserved.
+/// Trait for inspecting a fungible asset whose funds can be placed on hold.
+///
+/// Funds are held for a `Reason`, e.g. the deposit of some pallet, and a held amount can only be
+/// released or slashed for the same reason. Held funds are still part of the balance of the
+/// account but cannot be transferred.
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
-	/// Amount of funds held in reserve by `who`.
-	fn balance_on_hold(who: &AccountId) -> Self::Balance;
+	/// An identifier for the reason of a hold.
+	type Reason: ReasonId;
 
-	/// Check to see if some `amount` of funds of `who` may be placed on hold.
-	fn can_hold(who: &AccountId, amount: Self::Balance) -> bool;
+	/// Amount of funds held by `who` for all the reasons.
+	fn total_balance_on_hold(who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds held by `who` for `reason`.
+	fn balance_on_hold(reason: &Self::Reason, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if some `amount` of funds of `who` may be placed on hold for `reason`.
+	fn can_hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> bool;
 }
 
-/// Trait for mutating a fungible asset which can be reserved.
+/// Trait for mutating a fungible asset whose funds can be placed on hold.
 pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
-	/// Hold some funds in an account.
-	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult;
+	/// Hold some funds in an account for `reason`.
+	fn hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> DispatchResult;
 
-	/// Release up to `amount` held funds in an account.
+	/// Release up to `amount` funds held in an account for `reason`.
 	///
 	/// The actual amount released is returned with `Ok`.
 	///
 	/// If `best_effort` is `true`, then the amount actually unreserved and returned as the inner
 	/// value of `Ok` may be smaller than the `amount` passed.
-	fn release(who: &AccountId, amount: Self::Balance, best_effort: bool)
-		-> Result<Self::Balance, DispatchError>;
+	fn release(
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<Self::Balance, DispatchError>;
 
-	/// Transfer held funds into a destination account.
+	/// Transfer funds held for `reason` into a destination account.
 	///
 	/// If `on_hold` is `true`, then the destination account must already exist and the assets
-	/// transferred will still be on hold in the destination account. If not, then the destination
-	/// account need not already exist, but must be creatable.
+	/// transferred will still be on hold for `reason` in the destination account. If not, then the
+	/// destination account need not already exist, but must be creatable.
 	///
 	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
 	/// error.
@@ -144,23 +163,24 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	/// The actual amount transferred is returned, or `Err` in the case of error and nothing is
 	/// changed.
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
-		on_held: bool,
+		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError>;
 }
 
-/// Trait for slashing a fungible asset which can be reserved.
+/// Trait for slashing a fungible asset whose funds can be placed on hold.
 pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
-	/// Reduce the balance of some funds on hold in an account.
+	/// Reduce the balance of some funds held for `reason` in an account.
 	///
 	/// The resulting imbalance is the first item of the tuple returned.
 	///
 	/// As much funds that are on hold up to `amount` will be deducted as possible. If this is less
 	/// than `amount`, then a non-zero second item will be returned.
-	fn slash_held(who: &AccountId, amount: Self::Balance)
+	fn slash_held(reason: &Self::Reason, who: &AccountId, amount: Self::Balance)
 		-> (CreditOf<AccountId, Self>, Self::Balance);
 }
 
@@ -168,10 +188,10 @@ impl<
 	AccountId,
 	T: Balanced<AccountId> + MutateHold<AccountId>,
 > BalancedHold<AccountId> for T {
-	fn slash_held(who: &AccountId, amount: Self::Balance)
+	fn slash_held(reason: &Self::Reason, who: &AccountId, amount: Self::Balance)
 		-> (CreditOf<AccountId, Self>, Self::Balance)
 	{
-		let actual = match Self::release(who, amount, true) {
+		let actual = match Self::release(reason, who, amount, true) {
 			Ok(x) => x,
 			Err(_) => return (Imbalance::default(), amount),
 		};
@@ -179,6 +199,37 @@ impl<
 	}
 }
 
+/// Trait for inspecting a fungible asset whose funds can be frozen.
+///
+/// A freeze for an `Id` prevents the balance of the account from being reduced below the frozen
+/// amount. Whether the frozen funds may still be placed on hold depends on the implementation.
+/// The freezes of an account overlap, the largest one is the one in effect.
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// An identifier for a freeze.
+	type Id: ReasonId;
+
+	/// Amount of funds of `who` frozen for `id`.
+	fn balance_frozen(id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if a new freeze for `id` may be placed on the account of `who`.
+	fn can_freeze(id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Trait for mutating a fungible asset whose funds can be frozen.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Freeze `amount` of the funds of `who` for `id`, replacing any previous freeze for `id`.
+	///
+	/// The amount may be larger than the balance of the account.
+	fn set_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Freeze at least `amount` of the funds of `who` for `id`, i.e. the freeze for `id` is only
+	/// increased.
+	fn extend_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Remove the freeze of `who` for `id`.
+	fn thaw(id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
+
 /// Convert a `fungibles` trait implementation into a `fungible` trait implementation by identifying
 /// a single item.
 pub struct ItemOf<
@@ -245,11 +296,15 @@ impl<
 	A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
 	AccountId,
 > InspectHold<AccountId> for ItemOf<F, A, AccountId> {
-	fn balance_on_hold(who: &AccountId) -> Self::Balance {
-		<F as fungibles::InspectHold<AccountId>>::balance_on_hold(A::get(), who)
+	type Reason = <F as fungibles::InspectHold<AccountId>>::Reason;
+	fn total_balance_on_hold(who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectHold<AccountId>>::total_balance_on_hold(A::get(), who)
+	}
+	fn balance_on_hold(reason: &Self::Reason, who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectHold<AccountId>>::balance_on_hold(A::get(), reason, who)
 	}
-	fn can_hold(who: &AccountId, amount: Self::Balance) -> bool {
-		<F as fungibles::InspectHold<AccountId>>::can_hold(A::get(), who, amount)
+	fn can_hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> bool {
+		<F as fungibles::InspectHold<AccountId>>::can_hold(A::get(), reason, who, amount)
 	}
 }
 
@@ -258,15 +313,19 @@ impl<
 	A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
 	AccountId,
 > MutateHold<AccountId> for ItemOf<F, A, AccountId> {
-	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult {
-		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), who, amount)
+	fn hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), reason, who, amount)
 	}
-	fn release(who: &AccountId, amount: Self::Balance, best_effort: bool)
-		-> Result<Self::Balance, DispatchError>
-	{
-		<F as fungibles::MutateHold<AccountId>>::release(A::get(), who, amount, best_effort)
+	fn release(
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		<F as fungibles::MutateHold<AccountId>>::release(A::get(), reason, who, amount, best_effort)
 	}
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
@@ -275,6 +334,7 @@ impl<
 	) -> Result<Self::Balance, DispatchError> {
 		<F as fungibles::MutateHold<AccountId>>::transfer_held(
 			A::get(),
+			reason,
 			source,
 			dest,
 			amount,
@@ -284,6 +344,36 @@ impl<
 	}
 }
 
+impl<
+	F: fungibles::InspectFreeze<AccountId>,
+	A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
+	AccountId,
+> InspectFreeze<AccountId> for ItemOf<F, A, AccountId> {
+	type Id = <F as fungibles::InspectFreeze<AccountId>>::Id;
+	fn balance_frozen(id: &Self::Id, who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectFreeze<AccountId>>::balance_frozen(A::get(), id, who)
+	}
+	fn can_freeze(id: &Self::Id, who: &AccountId) -> bool {
+		<F as fungibles::InspectFreeze<AccountId>>::can_freeze(A::get(), id, who)
+	}
+}
+
+impl<
+	F: fungibles::MutateFreeze<AccountId>,
+	A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
+	AccountId,
+> MutateFreeze<AccountId> for ItemOf<F, A, AccountId> {
+	fn set_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::set_freeze(A::get(), id, who, amount)
+	}
+	fn extend_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::extend_freeze(A::get(), id, who, amount)
+	}
+	fn thaw(id: &Self::Id, who: &AccountId) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::thaw(A::get(), id, who)
+	}
+}
+
 impl<
 	F: fungibles::Unbalanced<AccountId>,
 	A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
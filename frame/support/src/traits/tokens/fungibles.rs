@@ -19,7 +19,7 @@
 
 use super::*;
 use crate::dispatch::{DispatchError, DispatchResult};
-use super::misc::{AssetId, Balance};
+use super::misc::{AssetId, Balance, ReasonId};
 use sp_runtime::traits::Saturating;
 
 mod balanced;
@@ -140,32 +140,56 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 	) -> Result<Self::Balance, DispatchError>;
 }
 
-/// Trait for inspecting a set of named fungible assets which can be placed on hold.
+/// Trait for inspecting a set of named fungible assets whose funds can be placed on hold.
+///
+/// Funds are held for a `Reason`, see [`fungible::InspectHold`].
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
-	/// Amount of funds held in hold.
-	fn balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+	/// An identifier for the reason of a hold.
+	type Reason: ReasonId;
 
-	/// Check to see if some `amount` of `asset` may be held on the account of `who`.
-	fn can_hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> bool;
+	/// Amount of funds of `asset` held by `who` for all the reasons.
+	fn total_balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds of `asset` held by `who` for `reason`.
+	fn balance_on_hold(asset: Self::AssetId, reason: &Self::Reason, who: &AccountId)
+		-> Self::Balance;
+
+	/// Check to see if some `amount` of `asset` may be held on the account of `who` for `reason`.
+	fn can_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> bool;
 }
 
-/// Trait for mutating a set of named fungible assets which can be placed on hold.
+/// Trait for mutating a set of named fungible assets whose funds can be placed on hold.
 pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
-	/// Hold some funds in an account.
-	fn hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+	/// Hold some funds in an account for `reason`.
+	fn hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
 
-	/// Release some funds in an account from being on hold.
+	/// Release some funds held in an account for `reason`.
 	///
 	/// If `best_effort` is `true`, then the amount actually released and returned as the inner
 	/// value of `Ok` may be smaller than the `amount` passed.
-	fn release(asset: Self::AssetId, who: &AccountId, amount: Self::Balance, best_effort: bool)
-		-> Result<Self::Balance, DispatchError>;
+	fn release(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<Self::Balance, DispatchError>;
 
-	/// Transfer held funds into a destination account.
+	/// Transfer funds held for `reason` into a destination account.
 	///
 	/// If `on_hold` is `true`, then the destination account must already exist and the assets
-	/// transferred will still be on hold in the destination account. If not, then the destination
-	/// account need not already exist, but must be creatable.
+	/// transferred will still be on hold for `reason` in the destination account. If not, then the
+	/// destination account need not already exist, but must be creatable.
 	///
 	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
 	/// error.
@@ -174,6 +198,7 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	/// changed.
 	fn transfer_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
@@ -184,27 +209,72 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 
 /// Trait for mutating one of several types of fungible assets which can be held.
 pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
-	/// Release and slash some funds in an account.
+	/// Release and slash some funds held for `reason` in an account.
 	///
 	/// The resulting imbalance is the first item of the tuple returned.
 	///
 	/// As much funds up to `amount` will be deducted as possible. If this is less than `amount`,
 	/// then a non-zero second item will be returned.
-	fn slash_held(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
-		-> (CreditOf<AccountId, Self>, Self::Balance);
+	fn slash_held(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> (CreditOf<AccountId, Self>, Self::Balance);
 }
 
 impl<
 	AccountId,
 	T: Balanced<AccountId> + MutateHold<AccountId>,
 > BalancedHold<AccountId> for T {
-	fn slash_held(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
-		-> (CreditOf<AccountId, Self>, Self::Balance)
-	{
-		let actual = match Self::release(asset, who, amount, true) {
+	fn slash_held(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> (CreditOf<AccountId, Self>, Self::Balance) {
+		let actual = match Self::release(asset, reason, who, amount, true) {
 			Ok(x) => x,
 			Err(_) => return (Imbalance::zero(asset), amount),
 		};
 		<Self as fungibles::Balanced<AccountId>>::slash(asset, who, actual)
 	}
 }
+
+/// Trait for inspecting a set of named fungible assets whose funds can be frozen.
+///
+/// See [`fungible::InspectFreeze`].
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// An identifier for a freeze.
+	type Id: ReasonId;
+
+	/// Amount of funds of `asset` of `who` frozen for `id`.
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if a new freeze of `asset` for `id` may be placed on the account of `who`.
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Trait for mutating a set of named fungible assets whose funds can be frozen.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Freeze `amount` of the funds of `asset` of `who` for `id`, replacing any previous freeze
+	/// for `id`.
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Freeze at least `amount` of the funds of `asset` of `who` for `id`, i.e. the freeze for
+	/// `id` is only increased.
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Remove the freeze of `asset` of `who` for `id`.
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
@@ -23,9 +23,11 @@ use sp_runtime::traits::Saturating;
 use crate::traits::misc::{TryDrop, SameOrOther};
 
 mod split_two_ways;
+mod deal_with_fees;
 mod signed_imbalance;
 mod on_unbalanced;
 pub use split_two_ways::SplitTwoWays;
+pub use deal_with_fees::DealWithFees;
 pub use signed_imbalance::SignedImbalance;
 pub use on_unbalanced::OnUnbalanced;
 
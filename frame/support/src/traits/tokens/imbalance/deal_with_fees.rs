@@ -0,0 +1,50 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Means for handling transaction fees and tips differently.
+
+use sp_std::marker::PhantomData;
+use crate::traits::misc::TryDrop;
+use super::super::imbalance::{Imbalance, OnUnbalanced};
+
+/// Handle the imbalances of a transaction payment, given as fees then tips, with different
+/// handlers.
+///
+/// The first imbalance given to `on_unbalanceds` is passed to `Fees` and all of the others are
+/// merged and passed to `Tips`. A single imbalance given to `on_unbalanced` is treated as a fee.
+///
+/// Combined with [`SplitTwoWays`](super::SplitTwoWays), this covers the usual runtime fee
+/// handlers, e.g. sending 80% of the fees to the treasury and the rest, with all of the tips, to
+/// the block author.
+pub struct DealWithFees<Fees, Tips>(PhantomData<(Fees, Tips)>);
+
+impl<I, Fees, Tips> OnUnbalanced<I> for DealWithFees<Fees, Tips> where
+	I: TryDrop,
+	Fees: OnUnbalanced<I>,
+	Tips: OnUnbalanced<I>,
+{
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item=I>) where I: Imbalance<B> {
+		if let Some(fees) = fees_then_tips.next() {
+			Fees::on_unbalanced(fees);
+			Tips::on_unbalanced(fees_then_tips.fold(I::zero(), |tips, x| x.merge(tips)));
+		}
+	}
+
+	fn on_nonzero_unbalanced(amount: I) {
+		Fees::on_nonzero_unbalanced(amount);
+	}
+}
@@ -17,12 +17,16 @@
 
 //! Means for splitting an imbalance into two and hanlding them differently.
 
-use sp_std::{ops::Div, marker::PhantomData};
+use sp_std::{ops::{Div, Rem}, marker::PhantomData};
 use sp_core::u32_trait::Value as U32;
 use sp_runtime::traits::Saturating;
 use super::super::imbalance::{Imbalance, OnUnbalanced};
 
 /// Split an unbalanced amount two ways between a common divisor.
+///
+/// `Target1` receives `Part1 / (Part1 + Part2)` of the amount, rounded down, and `Target2` receives
+/// the remainder, so nothing is lost to rounding, even for amounts close to the maximum balance.
+/// If both parts are zero, the whole amount goes to `Target2`.
 pub struct SplitTwoWays<
 	Balance,
 	Imbalance,
@@ -33,7 +37,7 @@ pub struct SplitTwoWays<
 >(PhantomData<(Balance, Imbalance, Part1, Target1, Part2, Target2)>);
 
 impl<
-	Balance: From<u32> + Saturating + Div<Output=Balance>,
+	Balance: From<u32> + Saturating + Div<Output=Balance> + Rem<Output=Balance>,
 	I: Imbalance<Balance>,
 	Part1: U32,
 	Target1: OnUnbalanced<I>,
@@ -42,9 +46,17 @@ impl<
 > OnUnbalanced<I> for SplitTwoWays<Balance, I, Part1, Target1, Part2, Target2>
 {
 	fn on_nonzero_unbalanced(amount: I) {
-		let total: u32 = Part1::VALUE + Part2::VALUE;
-		let amount1 = amount.peek().saturating_mul(Part1::VALUE.into()) / total.into();
-		let (imb1, imb2) = amount.split(amount1);
+		let total = Part1::VALUE.saturating_add(Part2::VALUE);
+		let (imb1, imb2) = if total == 0 {
+			(I::zero(), amount)
+		} else {
+			// `amount * Part1 / total`, computed without saturating the product for large amounts.
+			let quotient = amount.peek() / total.into();
+			let remainder = amount.peek() % total.into();
+			let amount1 = quotient.saturating_mul(Part1::VALUE.into())
+				.saturating_add(remainder.saturating_mul(Part1::VALUE.into()) / total.into());
+			amount.split(amount1)
+		};
 		Target1::on_unbalanced(imb1);
 		Target2::on_unbalanced(imb2);
 	}
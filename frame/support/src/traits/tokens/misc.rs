@@ -164,6 +164,13 @@ impl WithdrawReasons {
 pub trait AssetId: FullCodec + Copy + Default + Eq + PartialEq + Debug {}
 impl<T: FullCodec + Copy + Default + Eq + PartialEq + Debug> AssetId for T {}
 
+/// Simple amalgamation trait to collect together properties for the reason of a hold or a
+/// freeze under one roof.
+///
+/// It is usually an enum aggregating the reasons of all the pallets of the runtime.
+pub trait ReasonId: FullCodec + Clone + Eq + PartialEq + Debug {}
+impl<T: FullCodec + Clone + Eq + PartialEq + Debug> ReasonId for T {}
+
 /// Simple amalgamation trait to collect together properties for a Balance under one roof.
 pub trait Balance: AtLeast32BitUnsigned + FullCodec + Copy + Default + Debug {}
 impl<T: AtLeast32BitUnsigned + FullCodec + Copy + Default + Debug> Balance for T {}
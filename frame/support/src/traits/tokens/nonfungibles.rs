@@ -183,6 +183,12 @@ pub trait Mutate<AccountId>: Inspect<AccountId> {
 	}
 }
 
+/// Trait for providing the ability to create classes of nonfungible assets.
+pub trait Create<AccountId>: Inspect<AccountId> {
+	/// Create a `class` of nonfungible assets to be owned by `who` and managed by `admin`.
+	fn create_class(class: &Self::ClassId, who: &AccountId, admin: &AccountId) -> DispatchResult;
+}
+
 /// Trait for providing a non-fungible sets of assets which can only be transferred.
 pub trait Transfer<AccountId>: Inspect<AccountId> {
 	/// Transfer asset `instance` of `class` into `destination` account.
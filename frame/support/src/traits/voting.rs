@@ -18,7 +18,14 @@
 //! Traits and associated data structures concerned with voting, and moving between tokens and
 //! votes.
 
-use sp_arithmetic::traits::{UniqueSaturatedInto, UniqueSaturatedFrom, SaturatedConversion};
+use sp_std::prelude::*;
+use sp_arithmetic::{
+	traits::{UniqueSaturatedInto, UniqueSaturatedFrom, SaturatedConversion},
+	Perbill,
+};
+use sp_runtime::{traits::Member, DispatchError};
+use codec::HasCompact;
+use crate::dispatch::Parameter;
 
 /// A trait similar to `Convert` to convert values from `B` an abstract balance type
 /// into u64 and back from u128. (This conversion is used in election and other places where complex
@@ -86,3 +93,89 @@ impl<B: UniqueSaturatedInto<u64> + UniqueSaturatedFrom<u128>> CurrencyToVote<B>
 		B::unique_saturated_from(value)
 	}
 }
+
+/// The tally of the votes of a poll, from which its approval and support are derived.
+pub trait VoteTally<Votes, Class> {
+	/// A tally without any vote, for a poll of `class`.
+	fn new(class: Class) -> Self;
+
+	/// The number of aye votes.
+	fn ayes(&self, class: Class) -> Votes;
+
+	/// The fraction of the possible turnout which supports the poll.
+	fn support(&self, class: Class) -> Perbill;
+
+	/// The fraction of the votes which are aye.
+	fn approval(&self, class: Class) -> Perbill;
+
+	/// A tally of a poll which everyone supports and approves.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn unanimity(class: Class) -> Self;
+
+	/// A tally of a poll which nobody supports nor approves.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn rejection(class: Class) -> Self;
+}
+
+/// The status of a poll.
+#[derive(Clone, Copy, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub enum PollStatus<Tally, Moment, Class> {
+	/// There is no such poll, or it was cancelled.
+	None,
+	/// The poll is ongoing, with its tally and class.
+	Ongoing(Tally, Class),
+	/// The poll completed at the given moment, approved if `true`.
+	Completed(Moment, bool),
+}
+
+impl<Tally, Moment, Class> PollStatus<Tally, Moment, Class> {
+	/// The tally and the class of the poll, if it is ongoing.
+	pub fn ensure_ongoing(self) -> Option<(Tally, Class)> {
+		match self {
+			Self::Ongoing(tally, class) => Some((tally, class)),
+			_ => None,
+		}
+	}
+}
+
+/// A source of polls, whose tallies can be accessed by the voting systems.
+pub trait Polling<Tally> {
+	/// The index of a poll.
+	type Index: Parameter + Member + Ord + PartialOrd + Copy + HasCompact;
+	/// The type in which the votes are counted.
+	type Votes: Parameter + Member + Ord + PartialOrd + Copy + HasCompact;
+	/// The class of a poll, e.g. its track.
+	type Class: Parameter + Member + Ord + PartialOrd;
+	/// The type of the moments at which the polls complete.
+	type Moment;
+
+	/// All the classes of polls.
+	fn classes() -> Vec<Self::Class>;
+
+	/// The tally and the class of the poll `index`, if it is ongoing.
+	fn as_ongoing(index: Self::Index) -> Option<(Tally, Self::Class)>;
+
+	/// Access the status of the poll `index`, with a mutable tally if it is ongoing.
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut Tally, Self::Moment, Self::Class>) -> R,
+	) -> R;
+
+	/// Access the status of the poll `index`, with a mutable tally if it is ongoing.
+	///
+	/// Nothing is changed if `f` fails.
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(
+			PollStatus<&mut Tally, Self::Moment, Self::Class>,
+		) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError>;
+
+	/// Create an ongoing poll of `class`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()>;
+
+	/// End the ongoing poll `index`, approved if `approved`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()>;
+}
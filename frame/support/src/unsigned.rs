@@ -20,6 +20,7 @@ pub use crate::sp_runtime::traits::ValidateUnsigned;
 #[doc(hidden)]
 pub use crate::sp_runtime::transaction_validity::{
 	TransactionValidity, UnknownTransaction, TransactionValidityError, TransactionSource,
+	ValidTransaction, InvalidTransaction,
 };
 
 
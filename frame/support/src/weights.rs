@@ -118,7 +118,12 @@
 //! }
 //! # fn main() {}
 //! ```
-//! FRAME assumes a weight of `1_000_000_000_000` equals 1 second of compute on a standard machine.
+//! FRAME assumes a computation time of `1_000_000_000_000` equals 1 second of compute on a
+//! standard machine.
+//!
+//! A [`Weight`] also accounts for the size of the storage proof needed to validate the
+//! computation. The scalar weights above, e.g. `#[weight = 1000]`, are computation times with no
+//! proof size, see [`Weight::from_ref_time`].
 //!
 //! Latest machine specification used to benchmark are:
 //! - Digital Ocean: ubuntu-s-2vcpu-4gb-ams3-01
@@ -133,6 +138,7 @@ use codec::{Encode, Decode};
 use sp_runtime::{RuntimeDebug, traits::SignedExtension};
 use sp_runtime::generic::{CheckedExtrinsic, UncheckedExtrinsic};
 use crate::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, DispatchError};
+use crate::traits::Get;
 use sp_runtime::traits::SaturatedConversion;
 use sp_arithmetic::{Perbill, traits::{BaseArithmetic, Saturating, Unsigned}};
 use smallvec::{smallvec, SmallVec};
@@ -140,8 +146,8 @@ use smallvec::{smallvec, SmallVec};
 /// Re-export priority as type
 pub use sp_runtime::transaction_validity::TransactionPriority;
 
-/// Numeric range of a transaction weight.
-pub type Weight = u64;
+mod weight_v2;
+pub use weight_v2::{Weight, OldWeight};
 
 /// These constants are specific to FRAME, and the current implementation of its various components.
 /// For example: FRAME System, FRAME Executive, our FRAME support libraries, etc...
@@ -149,10 +155,14 @@ pub mod constants {
 	use super::{RuntimeDbWeight, Weight};
 	use crate::parameter_types;
 
-	pub const WEIGHT_PER_SECOND: Weight = 1_000_000_000_000;
-	pub const WEIGHT_PER_MILLIS: Weight = WEIGHT_PER_SECOND / 1000; // 1_000_000_000
-	pub const WEIGHT_PER_MICROS: Weight = WEIGHT_PER_MILLIS / 1000; // 1_000_000
-	pub const WEIGHT_PER_NANOS:  Weight = WEIGHT_PER_MICROS / 1000; // 1_000
+	pub const WEIGHT_PER_SECOND: Weight = Weight::from_ref_time(1_000_000_000_000);
+	pub const WEIGHT_PER_MILLIS: Weight = Weight::from_ref_time(1_000_000_000);
+	pub const WEIGHT_PER_MICROS: Weight = Weight::from_ref_time(1_000_000);
+	pub const WEIGHT_PER_NANOS:  Weight = Weight::from_ref_time(1_000);
+
+	/// The size of the trie nodes a storage access adds to the storage proof: ~5 branch nodes of
+	/// ~512 bytes @ 200,000 items.
+	pub const STORAGE_ACCESS_PROOF_SIZE: u64 = 2_600;
 
 	parameter_types! {
 		/// Importing a block with 0 txs takes ~5 ms
@@ -162,14 +172,16 @@ pub mod constants {
 		/// By default, Substrate uses RocksDB, so this will be the weight used throughout
 		/// the runtime.
 		pub const RocksDbWeight: RuntimeDbWeight = RuntimeDbWeight {
-			read: 25 * WEIGHT_PER_MICROS,   // ~25 µs @ 200,000 items
-			write: 100 * WEIGHT_PER_MICROS, // ~100 µs @ 200,000 items
+			read: 25 * WEIGHT_PER_MICROS.ref_time(),   // ~25 µs @ 200,000 items
+			write: 100 * WEIGHT_PER_MICROS.ref_time(), // ~100 µs @ 200,000 items
+			proof_size: STORAGE_ACCESS_PROOF_SIZE,
 		};
 		/// ParityDB can be enabled with a feature flag, but is still experimental. These weights
 		/// are available for brave runtime engineers who may want to try this out as default.
 		pub const ParityDbWeight: RuntimeDbWeight = RuntimeDbWeight {
-			read: 8 * WEIGHT_PER_MICROS,   // ~8 µs @ 200,000 items
-			write: 50 * WEIGHT_PER_MICROS, // ~50 µs @ 200,000 items
+			read: 8 * WEIGHT_PER_MICROS.ref_time(),   // ~8 µs @ 200,000 items
+			write: 50 * WEIGHT_PER_MICROS.ref_time(), // ~50 µs @ 200,000 items
+			proof_size: STORAGE_ACCESS_PROOF_SIZE,
 		};
 	}
 }
@@ -333,7 +345,7 @@ pub struct PostDispatchInfo {
 impl PostDispatchInfo {
 	/// Calculate how much (if any) weight was not used by the `Dispatchable`.
 	pub fn calc_unspent(&self, info: &DispatchInfo) -> Weight {
-		info.weight - self.calc_actual_weight(info)
+		info.weight.saturating_sub(self.calc_actual_weight(info))
 	}
 
 	/// Calculate how much weight was actually spent by the `Dispatchable`.
@@ -409,7 +421,10 @@ impl sp_runtime::traits::Printable for PostDispatchInfo {
 	fn print(&self) {
 		"actual_weight=".print();
 		match self.actual_weight {
-			Some(weight) => weight.print(),
+			Some(weight) => {
+				weight.ref_time().print();
+				weight.proof_size().print();
+			},
 			None => "max-weight".print(),
 		};
 		"pays_fee=".print();
@@ -428,8 +443,8 @@ pub trait WithPostDispatchInfo {
 	/// # Example
 	///
 	/// ```ignore
-	/// let who = ensure_signed(origin).map_err(|e| e.with_weight(100))?;
-	/// ensure!(who == me, Error::<T>::NotMe.with_weight(200_000));
+	/// let who = ensure_signed(origin).map_err(|e| e.with_weight(Weight::from_ref_time(100)))?;
+	/// ensure!(who == me, Error::<T>::NotMe.with_weight(Weight::from_ref_time(200_000)));
 	/// ```
 	fn with_weight(self, actual_weight: Weight) -> DispatchErrorWithPostInfo;
 }
@@ -520,6 +535,80 @@ impl<T> PaysFee<T> for (Weight, Pays) {
 	}
 }
 
+// The scalar weights, e.g. `#[weight = 1000]`, are computation times. Prefer a `Weight`, which
+// also accounts for the proof size.
+impl<T> WeighData<T> for u64 {
+	fn weigh_data(&self, _: T) -> Weight {
+		Weight::from_ref_time(*self)
+	}
+}
+
+impl<T> ClassifyDispatch<T> for u64 {
+	fn classify_dispatch(&self, _: T) -> DispatchClass {
+		DispatchClass::Normal
+	}
+}
+
+impl<T> PaysFee<T> for u64 {
+	fn pays_fee(&self, _: T) -> Pays {
+		Pays::Yes
+	}
+}
+
+impl<T> WeighData<T> for (u64, DispatchClass, Pays) {
+	fn weigh_data(&self, _: T) -> Weight {
+		Weight::from_ref_time(self.0)
+	}
+}
+
+impl<T> ClassifyDispatch<T> for (u64, DispatchClass, Pays) {
+	fn classify_dispatch(&self, _: T) -> DispatchClass {
+		self.1
+	}
+}
+
+impl<T> PaysFee<T> for (u64, DispatchClass, Pays) {
+	fn pays_fee(&self, _: T) -> Pays {
+		self.2
+	}
+}
+
+impl<T> WeighData<T> for (u64, DispatchClass) {
+	fn weigh_data(&self, _: T) -> Weight {
+		Weight::from_ref_time(self.0)
+	}
+}
+
+impl<T> ClassifyDispatch<T> for (u64, DispatchClass) {
+	fn classify_dispatch(&self, _: T) -> DispatchClass {
+		self.1
+	}
+}
+
+impl<T> PaysFee<T> for (u64, DispatchClass) {
+	fn pays_fee(&self, _: T) -> Pays {
+		Pays::Yes
+	}
+}
+
+impl<T> WeighData<T> for (u64, Pays) {
+	fn weigh_data(&self, _: T) -> Weight {
+		Weight::from_ref_time(self.0)
+	}
+}
+
+impl<T> ClassifyDispatch<T> for (u64, Pays) {
+	fn classify_dispatch(&self, _: T) -> DispatchClass {
+		DispatchClass::Normal
+	}
+}
+
+impl<T> PaysFee<T> for (u64, Pays) {
+	fn pays_fee(&self, _: T) -> Pays {
+		self.1
+	}
+}
+
 /// A struct to represent a weight which is a function of the input arguments. The given items have
 /// the following types:
 ///
@@ -615,7 +704,7 @@ impl<Call: Encode, Extra: Encode> GetDispatchInfo for sp_runtime::testing::TestX
 	fn get_dispatch_info(&self) -> DispatchInfo {
 		// for testing: weight == size.
 		DispatchInfo {
-			weight: self.encode().len() as _,
+			weight: Weight::from_ref_time(self.encode().len() as _),
 			pays_fee: Pays::Yes,
 			..Default::default()
 		}
@@ -623,25 +712,35 @@ impl<Call: Encode, Extra: Encode> GetDispatchInfo for sp_runtime::testing::TestX
 }
 
 /// The weight of database operations that the runtime can invoke.
+///
+/// A runtime selects the weights of its database backend through `frame_system::Config::DbWeight`,
+/// e.g. with [`constants::RocksDbWeight`] or [`constants::ParityDbWeight`], which all the weight
+/// functions of the pallets read their database weights from.
+///
+/// Every read and write also accounts `proof_size` bytes of storage proof, for the trie nodes on
+/// the path of the accessed item. The benchmarked weights add the proof size measured on the
+/// benchmark state on top of it, which covers the values themselves.
 #[derive(Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode)]
 pub struct RuntimeDbWeight {
-	pub read: Weight,
-	pub write: Weight,
+	/// The reference time of a single read.
+	pub read: u64,
+	/// The reference time of a single write.
+	pub write: u64,
+	/// The size a single read or write adds to the storage proof, in bytes.
+	pub proof_size: u64,
 }
 
 impl RuntimeDbWeight {
-	pub fn reads(self, r: Weight) -> Weight {
-		self.read.saturating_mul(r)
+	pub fn reads(self, r: u64) -> Weight {
+		Weight::from_parts(self.read.saturating_mul(r), self.proof_size.saturating_mul(r))
 	}
 
-	pub fn writes(self, w: Weight) -> Weight {
-		self.write.saturating_mul(w)
+	pub fn writes(self, w: u64) -> Weight {
+		Weight::from_parts(self.write.saturating_mul(w), self.proof_size.saturating_mul(w))
 	}
 
-	pub fn reads_writes(self, r: Weight, w: Weight) -> Weight {
-		let read_weight = self.read.saturating_mul(r);
-		let write_weight = self.write.saturating_mul(w);
-		read_weight.saturating_add(write_weight)
+	pub fn reads_writes(self, r: u64, w: u64) -> Weight {
+		self.reads(r).saturating_add(self.writes(w))
 	}
 }
 
@@ -685,13 +784,15 @@ pub trait WeightToFeePolynomial {
 	/// first will most likely saturate the result to zero mid evaluation.
 	fn polynomial() -> WeightToFeeCoefficients<Self::Balance>;
 
-	/// Calculates the fee from the passed `weight` according to the `polynomial`.
+	/// Calculates the fee from the computation time of the passed `weight` according to the
+	/// `polynomial`.
 	///
 	/// This should not be overriden in most circumstances. Calculation is done in the
 	/// `Balance` type and never overflows. All evaluation is saturating.
 	fn calc(weight: &Weight) -> Self::Balance {
 		Self::polynomial().iter().fold(Self::Balance::saturated_from(0u32), |mut acc, args| {
-			let w = Self::Balance::saturated_from(*weight).saturating_pow(args.degree.into());
+			let w = Self::Balance::saturated_from(weight.ref_time())
+				.saturating_pow(args.degree.into());
 
 			// The sum could get negative. Therefore we only sum with the accumulator.
 			// The Perbill Mul implementation is non overflowing.
@@ -729,6 +830,25 @@ impl<T> WeightToFeePolynomial for IdentityFee<T> where
 	}
 }
 
+/// Implementor of `WeightToFeePolynomial` that maps one unit of weight to `M` units of fee.
+pub struct ConstantMultiplier<T, M>(sp_std::marker::PhantomData<(T, M)>);
+
+impl<T, M> WeightToFeePolynomial for ConstantMultiplier<T, M> where
+	T: BaseArithmetic + From<u32> + Copy + Unsigned,
+	M: Get<T>,
+{
+	type Balance = T;
+
+	fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+		smallvec!(WeightToFeeCoefficient {
+			coeff_integer: M::get(),
+			coeff_frac: Perbill::zero(),
+			negative: false,
+			degree: 1,
+		})
+	}
+}
+
 /// A struct holding value for each `DispatchClass`.
 #[derive(Clone, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode)]
 pub struct PerDispatchClass<T> {
@@ -781,7 +901,7 @@ impl<T: Clone> PerDispatchClass<T> {
 impl PerDispatchClass<Weight> {
 	/// Returns the total weight consumed by all extrinsics in the block.
 	pub fn total(&self) -> Weight {
-		let mut sum = 0;
+		let mut sum = Weight::zero();
 		for class in DispatchClass::all() {
 			sum = sum.saturating_add(*self.get(*class));
 		}
@@ -798,7 +918,7 @@ impl PerDispatchClass<Weight> {
 	/// occur.
 	pub fn checked_add(&mut self, weight: Weight, class: DispatchClass) -> Result<(), ()> {
 		let value = self.get_mut(class);
-		*value = value.checked_add(weight).ok_or(())?;
+		*value = value.checked_add(&weight).ok_or(())?;
 		Ok(())
 	}
 
@@ -830,6 +950,7 @@ mod tests {
 		pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight {
 			read: 100,
 			write: 1000,
+			proof_size: 10,
 		};
 	}
 
@@ -857,16 +978,17 @@ mod tests {
 			fn f03(_origin) { unimplemented!(); }
 
 			// weight = a x 10 + b
-			#[weight = ((_a * 10 + _eb * 1) as Weight, DispatchClass::Normal, Pays::Yes)]
+			#[weight = ((_a * 10 + _eb * 1) as u64, DispatchClass::Normal, Pays::Yes)]
 			fn f11(_origin, _a: u32, _eb: u32) { unimplemented!(); }
 
 			#[weight = (0, DispatchClass::Operational, Pays::Yes)]
 			fn f12(_origin, _a: u32, _eb: u32) { unimplemented!(); }
 
-			#[weight = T::DbWeight::get().reads(3) + T::DbWeight::get().writes(2) + 10_000]
+			#[weight = T::DbWeight::get().reads(3) + T::DbWeight::get().writes(2) +
+				Weight::from_ref_time(10_000)]
 			fn f20(_origin) { unimplemented!(); }
 
-			#[weight = T::DbWeight::get().reads_writes(6, 5) + 40_000]
+			#[weight = T::DbWeight::get().reads_writes(6, 5) + Weight::from_ref_time(40_000)]
 			fn f21(_origin) { unimplemented!(); }
 
 		}
@@ -876,49 +998,51 @@ mod tests {
 	fn weights_are_correct() {
 		// #[weight = 1000]
 		let info = Call::<TraitImpl>::f00().get_dispatch_info();
-		assert_eq!(info.weight, 1000);
+		assert_eq!(info.weight, Weight::from_ref_time(1000));
 		assert_eq!(info.class, DispatchClass::Normal);
 		assert_eq!(info.pays_fee, Pays::Yes);
 
 		// #[weight = (1000, DispatchClass::Mandatory)]
 		let info = Call::<TraitImpl>::f01().get_dispatch_info();
-		assert_eq!(info.weight, 1000);
+		assert_eq!(info.weight, Weight::from_ref_time(1000));
 		assert_eq!(info.class, DispatchClass::Mandatory);
 		assert_eq!(info.pays_fee, Pays::Yes);
 
 		// #[weight = (1000, Pays::No)]
 		let info = Call::<TraitImpl>::f02().get_dispatch_info();
-		assert_eq!(info.weight, 1000);
+		assert_eq!(info.weight, Weight::from_ref_time(1000));
 		assert_eq!(info.class, DispatchClass::Normal);
 		assert_eq!(info.pays_fee, Pays::No);
 
 		// #[weight = (1000, DispatchClass::Operational, Pays::No)]
 		let info = Call::<TraitImpl>::f03().get_dispatch_info();
-		assert_eq!(info.weight, 1000);
+		assert_eq!(info.weight, Weight::from_ref_time(1000));
 		assert_eq!(info.class, DispatchClass::Operational);
 		assert_eq!(info.pays_fee, Pays::No);
 
-		// #[weight = ((_a * 10 + _eb * 1) as Weight, DispatchClass::Normal, Pays::Yes)]
+		// #[weight = ((_a * 10 + _eb * 1) as u64, DispatchClass::Normal, Pays::Yes)]
 		let info = Call::<TraitImpl>::f11(13, 20).get_dispatch_info();
-		assert_eq!(info.weight, 150); // 13*10 + 20
+		assert_eq!(info.weight, Weight::from_ref_time(150)); // 13*10 + 20
 		assert_eq!(info.class, DispatchClass::Normal);
 		assert_eq!(info.pays_fee, Pays::Yes);
 
 		// #[weight = (0, DispatchClass::Operational, Pays::Yes)]
 		let info = Call::<TraitImpl>::f12(10, 20).get_dispatch_info();
-		assert_eq!(info.weight, 0);
+		assert_eq!(info.weight, Weight::from_ref_time(0));
 		assert_eq!(info.class, DispatchClass::Operational);
 		assert_eq!(info.pays_fee, Pays::Yes);
 
 		// #[weight = T::DbWeight::get().reads(3) + T::DbWeight::get().writes(2) + 10_000]
 		let info = Call::<TraitImpl>::f20().get_dispatch_info();
-		assert_eq!(info.weight, 12300); // 100*3 + 1000*2 + 10_1000
+		// 100*3 + 1000*2 + 10_1000, 10*(3 + 2)
+		assert_eq!(info.weight, Weight::from_parts(12300, 50));
 		assert_eq!(info.class, DispatchClass::Normal);
 		assert_eq!(info.pays_fee, Pays::Yes);
 
 		// #[weight = T::DbWeight::get().reads_writes(6, 5) + 40_000]
 		let info = Call::<TraitImpl>::f21().get_dispatch_info();
-		assert_eq!(info.weight, 45600); // 100*6 + 1000*5 + 40_1000
+		// 100*6 + 1000*5 + 40_1000, 10*(6 + 5)
+		assert_eq!(info.weight, Weight::from_parts(45600, 110));
 		assert_eq!(info.class, DispatchClass::Normal);
 		assert_eq!(info.pays_fee, Pays::Yes);
 	}
@@ -926,27 +1050,29 @@ mod tests {
 	#[test]
 	fn extract_actual_weight_works() {
 		let pre = DispatchInfo {
-			weight: 1000,
+			weight: Weight::from_ref_time(1000),
 			.. Default::default()
 		};
-		assert_eq!(extract_actual_weight(&Ok(Some(7).into()), &pre), 7);
-		assert_eq!(extract_actual_weight(&Ok(Some(1000).into()), &pre), 1000);
+		let weight = Weight::from_ref_time;
+		assert_eq!(extract_actual_weight(&Ok(Some(weight(7)).into()), &pre), weight(7));
+		assert_eq!(extract_actual_weight(&Ok(Some(weight(1000)).into()), &pre), weight(1000));
 		assert_eq!(
-			extract_actual_weight(&Err(DispatchError::BadOrigin.with_weight(9)), &pre),
-			9
+			extract_actual_weight(&Err(DispatchError::BadOrigin.with_weight(weight(9))), &pre),
+			weight(9)
 		);
 	}
 
 	#[test]
 	fn extract_actual_weight_caps_at_pre_weight() {
 		let pre = DispatchInfo {
-			weight: 1000,
+			weight: Weight::from_ref_time(1000),
 			.. Default::default()
 		};
-		assert_eq!(extract_actual_weight(&Ok(Some(1250).into()), &pre), 1000);
+		let weight = Weight::from_ref_time;
+		assert_eq!(extract_actual_weight(&Ok(Some(weight(1250)).into()), &pre), weight(1000));
 		assert_eq!(
-			extract_actual_weight(&Err(DispatchError::BadOrigin.with_weight(1300)), &pre),
-			1000
+			extract_actual_weight(&Err(DispatchError::BadOrigin.with_weight(weight(1300))), &pre),
+			weight(1000)
 		);
 	}
 
@@ -990,15 +1116,15 @@ mod tests {
 	#[test]
 	fn polynomial_works() {
 		// 100^3/2=500000 100^2*(2+1/3)=23333 700 -10000
-		assert_eq!(Poly::calc(&100), 514033);
+		assert_eq!(Poly::calc(&Weight::from_ref_time(100)), 514033);
 		// 10123^3/2=518677865433 10123^2*(2+1/3)=239108634 70861 -10000
-		assert_eq!(Poly::calc(&10_123), 518917034928);
+		assert_eq!(Poly::calc(&Weight::from_ref_time(10_123)), 518917034928);
 	}
 
 	#[test]
 	fn polynomial_does_not_underflow() {
-		assert_eq!(Poly::calc(&0), 0);
-		assert_eq!(Poly::calc(&10), 0);
+		assert_eq!(Poly::calc(&Weight::from_ref_time(0)), 0);
+		assert_eq!(Poly::calc(&Weight::from_ref_time(10)), 0);
 	}
 
 	#[test]
@@ -1008,8 +1134,22 @@ mod tests {
 
 	#[test]
 	fn identity_fee_works() {
-		assert_eq!(IdentityFee::<Balance>::calc(&0), 0);
-		assert_eq!(IdentityFee::<Balance>::calc(&50), 50);
+		assert_eq!(IdentityFee::<Balance>::calc(&Weight::from_ref_time(0)), 0);
+		assert_eq!(IdentityFee::<Balance>::calc(&Weight::from_ref_time(50)), 50);
 		assert_eq!(IdentityFee::<Balance>::calc(&Weight::max_value()), Balance::max_value());
 	}
+
+	#[test]
+	fn constant_multiplier_works() {
+		parameter_types! {
+			pub const Ten: Balance = 10;
+		}
+
+		assert_eq!(ConstantMultiplier::<Balance, Ten>::calc(&Weight::from_ref_time(0)), 0);
+		assert_eq!(ConstantMultiplier::<Balance, Ten>::calc(&Weight::from_ref_time(50)), 500);
+		assert_eq!(
+			ConstantMultiplier::<Balance, Ten>::calc(&Weight::max_value()),
+			Balance::max_value(),
+		);
+	}
 }
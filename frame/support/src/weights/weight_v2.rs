@@ -0,0 +1,386 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The weight of some computation, in two dimensions: the computation time and the proof size.
+
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use codec::{Encode, Decode, Compact, CompactAs};
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, Div};
+use sp_runtime::RuntimeDebug;
+use sp_arithmetic::traits::{Bounded, Zero};
+use crate::traits::MaxEncodedLen;
+
+/// The weight of some computation, in two dimensions.
+///
+/// `ref_time` is the computation time, `1_000_000_000_000` for one second on the reference
+/// hardware, see [`super::constants::WEIGHT_PER_SECOND`]. `proof_size` is the size in bytes of the
+/// storage proof needed to validate the computation, which is what limits the blocks of a
+/// parachain.
+///
+/// All the arithmetic saturates, the operators included: a weight is an upper bound of some
+/// resource usage, it never wraps around nor panics.
+///
+/// Weights are ordered by their computation time first, the limits must be checked in both
+/// dimensions with [`Weight::all_lte`] or [`Weight::any_gt`].
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, RuntimeDebug,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Weight {
+	/// The computation time.
+	#[codec(compact)]
+	ref_time: u64,
+	/// The size of the storage proof, in bytes.
+	#[codec(compact)]
+	proof_size: u64,
+}
+
+impl Weight {
+	/// The weight with both dimensions set to their maximum.
+	pub const MAX: Self = Self { ref_time: u64::max_value(), proof_size: u64::max_value() };
+
+	/// The weight with both dimensions set to zero.
+	pub const fn zero() -> Self {
+		Self { ref_time: 0, proof_size: 0 }
+	}
+
+	/// The weight with both dimensions set to their maximum.
+	pub const fn max_value() -> Self {
+		Self::MAX
+	}
+
+	/// Construct a weight from its two dimensions.
+	pub const fn from_parts(ref_time: u64, proof_size: u64) -> Self {
+		Self { ref_time, proof_size }
+	}
+
+	/// Construct a weight with only a computation time.
+	///
+	/// This is the conversion of the scalar weights, which only accounted for the computation
+	/// time.
+	pub const fn from_ref_time(ref_time: u64) -> Self {
+		Self { ref_time, proof_size: 0 }
+	}
+
+	/// Construct a weight with only a proof size.
+	pub const fn from_proof_size(proof_size: u64) -> Self {
+		Self { ref_time: 0, proof_size }
+	}
+
+	/// The computation time.
+	pub const fn ref_time(&self) -> u64 {
+		self.ref_time
+	}
+
+	/// The size of the storage proof, in bytes.
+	pub const fn proof_size(&self) -> u64 {
+		self.proof_size
+	}
+
+	/// Set the computation time.
+	pub const fn set_ref_time(mut self, ref_time: u64) -> Self {
+		self.ref_time = ref_time;
+		self
+	}
+
+	/// Set the size of the storage proof.
+	pub const fn set_proof_size(mut self, proof_size: u64) -> Self {
+		self.proof_size = proof_size;
+		self
+	}
+
+	/// Whether both dimensions are zero.
+	pub const fn is_zero(&self) -> bool {
+		self.ref_time == 0 && self.proof_size == 0
+	}
+
+	/// Add two weights, saturating each dimension at its maximum.
+	pub const fn saturating_add(self, rhs: Self) -> Self {
+		Self {
+			ref_time: self.ref_time.saturating_add(rhs.ref_time),
+			proof_size: self.proof_size.saturating_add(rhs.proof_size),
+		}
+	}
+
+	/// Subtract two weights, saturating each dimension at zero.
+	pub const fn saturating_sub(self, rhs: Self) -> Self {
+		Self {
+			ref_time: self.ref_time.saturating_sub(rhs.ref_time),
+			proof_size: self.proof_size.saturating_sub(rhs.proof_size),
+		}
+	}
+
+	/// Multiply both dimensions by `scalar`, saturating at their maximum.
+	pub const fn saturating_mul(self, scalar: u64) -> Self {
+		Self {
+			ref_time: self.ref_time.saturating_mul(scalar),
+			proof_size: self.proof_size.saturating_mul(scalar),
+		}
+	}
+
+	/// Add `rhs` in place, saturating each dimension at its maximum.
+	pub fn saturating_accrue(&mut self, rhs: Self) {
+		*self = self.saturating_add(rhs);
+	}
+
+	/// Subtract `rhs` in place, saturating each dimension at zero.
+	pub fn saturating_reduce(&mut self, rhs: Self) {
+		*self = self.saturating_sub(rhs);
+	}
+
+	/// Add two weights, `None` if any dimension overflows.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_add(rhs.ref_time)?,
+			proof_size: self.proof_size.checked_add(rhs.proof_size)?,
+		})
+	}
+
+	/// Subtract two weights, `None` if any dimension underflows.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_sub(rhs.ref_time)?,
+			proof_size: self.proof_size.checked_sub(rhs.proof_size)?,
+		})
+	}
+
+	/// Multiply both dimensions by `scalar`, `None` if any dimension overflows.
+	pub fn checked_mul(&self, scalar: u64) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_mul(scalar)?,
+			proof_size: self.proof_size.checked_mul(scalar)?,
+		})
+	}
+
+	/// Divide both dimensions by `scalar`, `None` if `scalar` is zero.
+	pub fn checked_div(&self, scalar: u64) -> Option<Self> {
+		Some(Self {
+			ref_time: self.ref_time.checked_div(scalar)?,
+			proof_size: self.proof_size.checked_div(scalar)?,
+		})
+	}
+
+	/// The maximum of each dimension of the two weights.
+	pub fn max(self, other: Self) -> Self {
+		Self {
+			ref_time: self.ref_time.max(other.ref_time),
+			proof_size: self.proof_size.max(other.proof_size),
+		}
+	}
+
+	/// The minimum of each dimension of the two weights.
+	pub fn min(self, other: Self) -> Self {
+		Self {
+			ref_time: self.ref_time.min(other.ref_time),
+			proof_size: self.proof_size.min(other.proof_size),
+		}
+	}
+
+	/// Whether both dimensions of `self` are lower than or equal to the ones of `other`.
+	///
+	/// This is the check to use against a limit: a weight fits in a limit only if it fits in both
+	/// dimensions.
+	pub const fn all_lte(&self, other: &Self) -> bool {
+		self.ref_time <= other.ref_time && self.proof_size <= other.proof_size
+	}
+
+	/// Whether any dimension of `self` is greater than the one of `other`.
+	pub const fn any_gt(&self, other: &Self) -> bool {
+		!self.all_lte(other)
+	}
+}
+
+impl MaxEncodedLen for Weight {
+	fn max_encoded_len() -> usize {
+		Compact::<u64>::max_encoded_len().saturating_mul(2)
+	}
+}
+
+impl Zero for Weight {
+	fn zero() -> Self {
+		Self::zero()
+	}
+
+	fn is_zero(&self) -> bool {
+		Weight::is_zero(self)
+	}
+}
+
+impl Bounded for Weight {
+	fn min_value() -> Self {
+		Self::zero()
+	}
+
+	fn max_value() -> Self {
+		Self::MAX
+	}
+}
+
+impl Add for Weight {
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self {
+		self.saturating_add(rhs)
+	}
+}
+
+impl Sub for Weight {
+	type Output = Self;
+	fn sub(self, rhs: Self) -> Self {
+		self.saturating_sub(rhs)
+	}
+}
+
+impl Mul<u64> for Weight {
+	type Output = Self;
+	fn mul(self, scalar: u64) -> Self {
+		self.saturating_mul(scalar)
+	}
+}
+
+impl Mul<Weight> for u64 {
+	type Output = Weight;
+	fn mul(self, weight: Weight) -> Weight {
+		weight.saturating_mul(self)
+	}
+}
+
+impl Div<u64> for Weight {
+	type Output = Self;
+	fn div(self, scalar: u64) -> Self {
+		Self {
+			ref_time: self.ref_time / scalar,
+			proof_size: self.proof_size / scalar,
+		}
+	}
+}
+
+impl AddAssign for Weight {
+	fn add_assign(&mut self, rhs: Self) {
+		self.saturating_accrue(rhs);
+	}
+}
+
+impl SubAssign for Weight {
+	fn sub_assign(&mut self, rhs: Self) {
+		self.saturating_reduce(rhs);
+	}
+}
+
+impl core::iter::Sum for Weight {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::zero(), Self::saturating_add)
+	}
+}
+
+macro_rules! impl_per_thing_mul {
+	($($per_thing:ty),* $(,)?) => {
+		$(
+			impl Mul<Weight> for $per_thing {
+				type Output = Weight;
+				fn mul(self, weight: Weight) -> Weight {
+					Weight {
+						ref_time: self * weight.ref_time,
+						proof_size: self * weight.proof_size,
+					}
+				}
+			}
+		)*
+	}
+}
+
+impl_per_thing_mul!(
+	sp_arithmetic::Percent,
+	sp_arithmetic::PerU16,
+	sp_arithmetic::Permill,
+	sp_arithmetic::Perbill,
+	sp_arithmetic::Perquintill,
+);
+
+/// A weight made only of the computation time, encoded with the compact encoding.
+///
+/// This is how the weights were stored and transmitted before the proof size was accounted for,
+/// e.g. by the previous versions of the runtime APIs.
+#[derive(Encode, Decode, CompactAs, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OldWeight(pub u64);
+
+impl From<OldWeight> for Weight {
+	fn from(old: OldWeight) -> Self {
+		Self::from_ref_time(old.0)
+	}
+}
+
+impl From<Weight> for OldWeight {
+	fn from(weight: Weight) -> Self {
+		Self(weight.ref_time)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_arithmetic::Perbill;
+
+	#[test]
+	fn old_weight_converts_into_ref_time() {
+		assert_eq!(Weight::from(OldWeight(7)), Weight::from_parts(7, 0));
+		assert_eq!(OldWeight::from(Weight::from_parts(7, 100)), OldWeight(7));
+	}
+
+	#[test]
+	fn arithmetic_works_on_both_dimensions() {
+		let a = Weight::from_parts(10, 100);
+		let b = Weight::from_parts(20, 50);
+
+		assert_eq!(a + b, Weight::from_parts(30, 150));
+		assert_eq!(a.saturating_sub(b), Weight::from_parts(0, 50));
+		assert_eq!(a.checked_sub(&b), None);
+		assert_eq!(a * 2, Weight::from_parts(20, 200));
+		assert_eq!(2 * a, Weight::from_parts(20, 200));
+		assert_eq!(a / 2, Weight::from_parts(5, 50));
+		assert_eq!(Perbill::from_percent(50) * a, Weight::from_parts(5, 50));
+		assert_eq!(a.max(b), Weight::from_parts(20, 100));
+		assert_eq!(a.min(b), Weight::from_parts(10, 50));
+		assert_eq!(vec![a, b].into_iter().sum::<Weight>(), Weight::from_parts(30, 150));
+	}
+
+	#[test]
+	fn operators_saturate() {
+		let a = Weight::from_parts(10, 100);
+
+		assert_eq!(Weight::MAX + a, Weight::MAX);
+		assert_eq!(a - Weight::MAX, Weight::zero());
+		assert_eq!(Weight::MAX * 2, Weight::MAX);
+		let mut b = Weight::MAX;
+		b += a;
+		assert_eq!(b, Weight::MAX);
+		b = Weight::zero();
+		b -= a;
+		assert_eq!(b, Weight::zero());
+	}
+
+	#[test]
+	fn limits_are_checked_in_both_dimensions() {
+		let limit = Weight::from_parts(100, 100);
+
+		assert!(Weight::from_parts(100, 100).all_lte(&limit));
+		assert!(Weight::from_parts(101, 0).any_gt(&limit));
+		assert!(Weight::from_parts(0, 101).any_gt(&limit));
+	}
+}
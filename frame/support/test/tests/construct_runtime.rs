@@ -228,8 +228,10 @@ frame_support::construct_runtime!(
 	{
 		System: system::{Pallet, Call, Event<T>, Origin<T>} = 30,
 		Module1_1: module1::<Instance1>::{Pallet, Call, Storage, Event<T>, Origin<T>},
+		#[renamed_from(OldModule2, OlderModule2)]
 		Module2: module2::{Pallet, Call, Storage, Event, Origin},
 		Module1_2: module1::<Instance2>::{Pallet, Call, Storage, Event<T>, Origin<T>},
+		#[genesis_field(nested)]
 		NestedModule3: nested::module3::{Pallet, Call, Config, Storage, Event, Origin},
 		Module3: self::module3::{Pallet, Call, Config, Storage, Event, Origin<T>},
 		Module1_3: module1::<Instance3>::{Pallet, Storage} = 6,
@@ -246,6 +248,34 @@ pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<u32, Call, Signature, ()>;
 
+#[test]
+fn genesis_config_errors_name_the_pallet() {
+	let config = r#"{ "nested": {}, "module3": {} }"#;
+	assert!(serde_json::from_str::<GenesisConfig>(config).is_ok());
+
+	let config = r#"{ "nested": {}, "module3": { "foo": 1 } }"#;
+	let error = serde_json::from_str::<GenesisConfig>(config).unwrap_err().to_string();
+	assert!(error.contains("Invalid genesis config of `module3`"), "{}", error);
+	assert!(error.contains("unknown field `foo`"), "{}", error);
+
+	let config = r#"{ "nested": {}, "modul3": {} }"#;
+	let error = serde_json::from_str::<GenesisConfig>(config).unwrap_err().to_string();
+	assert!(error.contains("unknown field `modul3`"), "{}", error);
+}
+
+#[test]
+fn genesis_config_fields_are_named_after_the_pallets() {
+	use sp_runtime::BuildStorage;
+
+	// `NestedModule3` is renamed, `Module3` is not.
+	let config = RuntimeGenesisConfig { nested: Default::default(), module3: Default::default() };
+	assert!(config.build_storage().is_ok());
+
+	let config = r#"{ "nestedModule3": {}, "module3": {} }"#;
+	let error = serde_json::from_str::<RuntimeGenesisConfig>(config).unwrap_err().to_string();
+	assert!(error.contains("unknown field `nestedModule3`"), "{}", error);
+}
+
 mod origin_test {
 	use frame_support::traits::{Filter, OriginTrait};
 	use super::{module3, nested, system, Block, UncheckedExtrinsic};
@@ -326,47 +356,47 @@ mod origin_test {
 fn check_modules_error_type() {
 	assert_eq!(
 		Module1_1::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 31, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 31, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module2::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 32, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 32, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_2::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 33, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 33, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		NestedModule3::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 34, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 34, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_3::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 6, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 6, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_4::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 3, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 3, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_5::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 4, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 4, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_6::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 1, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 1, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_7::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 2, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 2, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_8::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 12, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 12, error: [0; 4], message: Some("Something") }),
 	);
 	assert_eq!(
 		Module1_9::fail(system::Origin::<Runtime>::Root.into()),
-		Err(DispatchError::Module { index: 13, error: 0, message: Some("Something") }),
+		Err(DispatchError::Module { index: 13, error: [0; 4], message: Some("Something") }),
 	);
 }
 
@@ -733,7 +763,7 @@ fn test_metadata() {
 			},
 		]),
 		extrinsic: ExtrinsicMetadata {
-			version: 4,
+			version: 5,
 			signed_extensions: vec![DecodeDifferent::Encode("UnitSignedExtension")],
 		},
 	};
@@ -750,6 +780,8 @@ fn pallet_in_runtime_is_correct() {
 
 	assert_eq!(PalletInfo::index::<Module2>().unwrap(), 32);
 	assert_eq!(PalletInfo::name::<Module2>().unwrap(), "Module2");
+	assert_eq!(PalletInfo::renamed_from::<Module2>(), &["OldModule2", "OlderModule2"]);
+	assert!(PalletInfo::renamed_from::<Module1_2>().is_empty());
 
 	assert_eq!(PalletInfo::index::<Module1_2>().unwrap(), 33);
 	assert_eq!(PalletInfo::name::<Module1_2>().unwrap(), "Module1_2");
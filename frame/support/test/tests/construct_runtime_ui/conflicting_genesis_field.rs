@@ -0,0 +1,16 @@
+use frame_support::construct_runtime;
+
+construct_runtime! {
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: system::{Pallet},
+		Balances: balances::{Config},
+		#[genesis_field(balances)]
+		OtherBalances: balances::{Config},
+	}
+}
+
+fn main() {}
@@ -24,6 +24,7 @@ frame_support::decl_module! {
 frame_support::decl_storage! {
 	trait Store for Module<T: Config> as Test {
 		pub AppendableDM config(t): double_map hasher(identity) u32, hasher(identity) T::BlockNumber => Vec<u32>;
+		pub Value config(value): u32 = 42;
 	}
 }
 
@@ -42,5 +43,23 @@ impl Config for Test {}
 fn init_genesis_config() {
 	GenesisConfig::<Test> {
 		t: Default::default(),
+		value: Default::default(),
 	};
 }
+
+#[test]
+fn genesis_config_fields_with_declared_default_can_be_omitted() {
+	let config: GenesisConfig<Test> = serde_json::from_str(r#"{ "t": [[1, 2, [3]]] }"#).unwrap();
+	assert_eq!(config.t, vec![(1, 2, vec![3])]);
+	assert_eq!(config.value, 42);
+
+	let error = serde_json::from_str::<GenesisConfig<Test>>(r#"{ "value": 1 }"#).unwrap_err();
+	assert!(error.to_string().contains("missing field `t`"));
+}
+
+#[test]
+fn genesis_config_unknown_fields_are_rejected() {
+	let error = serde_json::from_str::<GenesisConfig<Test>>(r#"{ "t": [], "vaule": 1 }"#)
+		.unwrap_err();
+	assert!(error.to_string().contains("unknown field `vaule`"));
+}
@@ -16,10 +16,10 @@
 // limitations under the License.
 
 use frame_support::{
-	weights::{DispatchInfo, DispatchClass, Pays, GetDispatchInfo},
+	weights::{DispatchInfo, DispatchClass, Pays, GetDispatchInfo, Weight},
 	traits::{
-		GetCallName, OnInitialize, OnFinalize, OnRuntimeUpgrade, GetPalletVersion, OnGenesis,
-		MaxEncodedLen,
+		GetCallName, OnInitialize, OnIdle, OnFinalize, OnRuntimeUpgrade, GetPalletVersion,
+		OnGenesis, MaxEncodedLen, GetCallArguments, CallArgument,
 	},
 	dispatch::{UnfilteredDispatchable, Parameter},
 	storage::unhashed,
@@ -54,17 +54,25 @@ impl SomeAssociation1 for u64 { type _1 = u64; }
 pub trait SomeAssociation2 { type _2: Parameter + MaxEncodedLen; }
 impl SomeAssociation2 for u64 { type _2 = u64; }
 
+#[derive(codec::Encode, codec::Decode, frame_support::PalletError)]
+pub enum SomeSubError {
+	Foo,
+	Bar(u16),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{
 		SomeType1, SomeType2, SomeType3, SomeType4, SomeType5, SomeType6, SomeType7,
-		SomeAssociation1, SomeAssociation2,
+		SomeAssociation1, SomeAssociation2, SomeSubError,
 	};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
 	type BalanceOf<T> = <T as Config>::Balance;
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config
 	where <Self as frame_system::Config>::AccountId: From<SomeType1> + SomeAssociation1,
@@ -102,6 +110,7 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
 	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -112,7 +121,14 @@ pub mod pallet {
 			T::AccountId::from(SomeType1); // Test for where clause
 			T::AccountId::from(SomeType2); // Test for where clause
 			Self::deposit_event(Event::Something(10));
-			10
+			Weight::from_ref_time(10)
+		}
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			T::AccountId::from(SomeType1); // Test for where clause
+			T::AccountId::from(SomeType2); // Test for where clause
+			let used_weight = remaining_weight.min(Weight::from_ref_time(15));
+			Self::deposit_event(Event::Something(used_weight.ref_time() as u32));
+			used_weight
 		}
 		fn on_finalize(_: BlockNumberFor<T>) {
 			T::AccountId::from(SomeType1); // Test for where clause
@@ -123,12 +139,19 @@ pub mod pallet {
 			T::AccountId::from(SomeType1); // Test for where clause
 			T::AccountId::from(SomeType2); // Test for where clause
 			Self::deposit_event(Event::Something(30));
-			30
+			Weight::from_ref_time(30)
 		}
 		fn integrity_test() {
 			T::AccountId::from(SomeType1); // Test for where clause
 			T::AccountId::from(SomeType2); // Test for where clause
 		}
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), &'static str> {
+			T::AccountId::from(SomeType1); // Test for where clause
+			T::AccountId::from(SomeType2); // Test for where clause
+			frame_support::ensure!(Value::<T>::get() != Some(u32::max_value()), "Value is too big");
+			Ok(())
+		}
 	}
 
 	#[pallet::call]
@@ -136,7 +159,7 @@ pub mod pallet {
 	where T::AccountId: From<SomeType1> + From<SomeType3> + SomeAssociation1
 	{
 		/// Doc comment put in metadata
-		#[pallet::weight(Weight::from(*_foo))]
+		#[pallet::weight(Weight::from_ref_time(*_foo as u64))]
 		fn foo(
 			origin: OriginFor<T>,
 			#[pallet::compact] _foo: u32,
@@ -177,6 +200,8 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// doc comment put into metadata
 		InsufficientProposersBalance,
+		/// error nesting an error type
+		SubError(SomeSubError),
 	}
 
 	#[pallet::event]
@@ -370,6 +395,49 @@ pub mod pallet2 {
 	impl<T: Config> Pallet<T>
 	where T::AccountId: From<SomeType1> + SomeAssociation1,
 	{
+		#[pallet::weight(0)]
+		#[pallet::inherent]
+		fn set(origin: OriginFor<T>, _now: u32) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Ok(().into())
+		}
+
+		#[pallet::weight(0)]
+		fn other(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Ok(().into())
+		}
+
+		#[pallet::weight(0)]
+		#[pallet::validate_unsigned(
+			priority = 10,
+			longevity = 5,
+			check = Self::check_value(*_value),
+		)]
+		fn submit(origin: OriginFor<T>, _value: u32) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where T::AccountId: From<SomeType1> + SomeAssociation1,
+	{
+		fn check_value(value: u32) -> Result<(), TransactionValidityError> {
+			if value == 0 {
+				Err(InvalidTransaction::Stale.into())
+			} else {
+				Ok(())
+			}
+		}
+	}
+
+	#[pallet::inherent(identifier = *b"testpal2", required)]
+	impl<T: Config> ProvideInherent for Pallet<T>
+	where T::AccountId: From<SomeType1> + SomeAssociation1,
+	{
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			data.get_data::<u32>(&Self::INHERENT_IDENTIFIER).ok().flatten().map(Call::set)
+		}
 	}
 
 	#[pallet::event]
@@ -378,6 +446,17 @@ pub mod pallet2 {
 		Something(u32),
 	}
 
+	#[pallet::origin]
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode)]
+	pub enum Origin {
+		#[pallet::ensure(EnsureMembers)]
+		Members(u32, u32),
+		#[pallet::ensure(EnsureMember)]
+		Member { who: u64 },
+		#[pallet::ensure(EnsureUnanimous)]
+		Unanimous,
+	}
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config>
 	where T::AccountId: From<SomeType1> + SomeAssociation1,
@@ -413,6 +492,35 @@ pub mod pallet3 {
 	pub struct Pallet<T>(_);
 }
 
+/// Test the field defaults and the serde attributes of the genesis config.
+#[frame_support::pallet]
+pub mod pallet4 {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		phantom: PhantomData<T>,
+		#[pallet::default(T::BlockHashCount::get())]
+		pub block_hash_count: T::BlockNumber,
+		#[pallet::default(vec![3, 4])]
+		#[serde(rename = "someValues")]
+		pub values: Vec<u32>,
+		pub other: u32,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {}
+	}
+}
+
 frame_support::parameter_types!(
 	pub const MyGetParam: u32= 10;
 	pub const MyGetParam2: u32= 11;
@@ -457,6 +565,8 @@ impl pallet2::Config for Runtime {
 	type Event = Event;
 }
 
+impl pallet4::Config for Runtime {}
+
 pub type Header = sp_runtime::generic::Header<u32, sp_runtime::traits::BlakeTwo256>;
 pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
 pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, Call, (), ()>;
@@ -469,7 +579,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Event<T>},
 		Example: pallet::{Pallet, Call, Event<T>, Config, Storage, Inherent, Origin<T>, ValidateUnsigned},
-		Example2: pallet2::{Pallet, Call, Event, Config<T>, Storage},
+		Example2: pallet2::{Pallet, Call, Event, Config<T>, Storage, Origin},
 	}
 );
 
@@ -490,13 +600,59 @@ fn transactional_works() {
 	})
 }
 
+#[test]
+fn origin_expand() {
+	use frame_support::traits::EnsureOrigin;
+
+	fn try_origin_with_arg<E, A>(o: Origin, a: &A) -> Result<E::Success, Origin>
+	where E: frame_support::traits::EnsureOriginWithArg<Origin, A>
+	{
+		E::try_origin(o, a)
+	}
+
+	let members: Origin = pallet2::Origin::Members(1, 3).into_outer();
+	assert_eq!(pallet2::EnsureMembers::try_origin(members.clone()).ok(), Some((1, 3)));
+	assert!(pallet2::EnsureMember::try_origin(members.clone()).is_err());
+	assert!(pallet2::EnsureUnanimous::try_origin(members.clone()).is_err());
+	assert_eq!(
+		try_origin_with_arg::<pallet2::EnsureMembers, _>(members.clone(), &(1, 3)).ok(),
+		Some((1, 3)),
+	);
+	assert!(try_origin_with_arg::<pallet2::EnsureMembers, _>(members, &(2, 3)).is_err());
+
+	let member: Origin = pallet2::Origin::Member { who: 7 }.into();
+	assert_eq!(pallet2::EnsureMember::try_origin(member.clone()).ok(), Some(7));
+	assert!(try_origin_with_arg::<pallet2::EnsureMember, _>(member, &8).is_err());
+
+	let unanimous: Origin = pallet2::Origin::Unanimous.into();
+	assert_eq!(pallet2::EnsureUnanimous::try_origin(unanimous.clone()).ok(), Some(()));
+	assert_eq!(pallet2::Origin::try_from_outer(unanimous).ok(), Some(pallet2::Origin::Unanimous));
+
+	let root: Origin = frame_system::RawOrigin::Root.into();
+	assert!(pallet2::EnsureUnanimous::ensure_origin(root.clone()).is_err());
+	assert!(pallet2::Origin::try_from_outer(root).is_err());
+}
+
+#[test]
+fn genesis_config_default_expand() {
+	let config = pallet4::GenesisConfig::<Runtime>::default();
+	assert_eq!(config.block_hash_count, BlockHashCount::get());
+	assert_eq!(config.values, vec![3, 4]);
+	assert_eq!(config.other, 0);
+
+	let json = serde_json::to_string(&config).unwrap();
+	assert_eq!(json, r#"{"blockHashCount":250,"someValues":[3,4],"other":0}"#);
+	let config: pallet4::GenesisConfig<Runtime> = serde_json::from_str(&json).unwrap();
+	assert_eq!(config.values, vec![3, 4]);
+}
+
 #[test]
 fn call_expand() {
 	let call_foo = pallet::Call::<Runtime>::foo(3, 0);
 	assert_eq!(
 		call_foo.get_dispatch_info(),
 		DispatchInfo {
-			weight: 3,
+			weight: Weight::from_ref_time(3),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::Yes,
 		}
@@ -508,6 +664,64 @@ fn call_expand() {
 	);
 }
 
+#[test]
+fn call_arguments_expand() {
+	use codec::{Compact, Encode};
+
+	assert_eq!(
+		pallet::Call::<Runtime>::foo(300, 7).get_call_arguments(),
+		vec![
+			CallArgument { name: "_foo", ty: "Compact<u32>", value: Compact(300u32).encode() },
+			CallArgument { name: "_bar", ty: "u32", value: 7u32.encode() },
+		],
+	);
+	assert_eq!(pallet::Call::<Runtime>::foo_no_post_info().get_call_arguments(), vec![]);
+}
+
+#[test]
+fn call_constructors_expand() {
+	use codec::{Decode, Encode};
+
+	assert_eq!(pallet::Call::<Runtime>::foo_call(3, 0), pallet::Call::<Runtime>::foo(3, 0));
+	assert_eq!(
+		pallet::CallOf::<Runtime>::foo_transactional_call(1),
+		pallet::Call::<Runtime>::foo_transactional(1),
+	);
+	assert_eq!(
+		pallet::Call::<Runtime>::foo_no_post_info_call(),
+		pallet::Call::<Runtime>::foo_no_post_info(),
+	);
+
+	let call_foo = pallet::Call::<Runtime>::foo_call(3, 0);
+	assert_eq!(call_foo.encoded(), call_foo.encode());
+	assert_eq!(pallet::Call::<Runtime>::decode(&mut &call_foo.encoded()[..]).unwrap(), call_foo);
+}
+
+#[test]
+fn call_match_helpers_expand() {
+	use frame_support::traits::{Contains, InPallet, IsCall, IsSubCall};
+
+	frame_support::parameter_types! {
+		pub const ExamplePallet: &'static str = "Example";
+		pub const FooFunction: &'static str = "foo";
+	}
+
+	assert!(pallet::Call::<Runtime>::foo(3, 0).is_foo());
+	assert!(!pallet::Call::<Runtime>::foo(3, 0).is_foo_transactional());
+	assert!(pallet::Call::<Runtime>::foo_no_post_info().is_foo_no_post_info());
+
+	let call_foo = Call::Example(pallet::Call::<Runtime>::foo(3, 0));
+	let call_foo_transactional = Call::Example(pallet::Call::<Runtime>::foo_transactional(1));
+	let call_remark = Call::System(frame_system::Call::remark(vec![]));
+
+	assert!(InPallet::<ExamplePallet>::contains(&call_foo_transactional));
+	assert!(!InPallet::<ExamplePallet>::contains(&call_remark));
+	assert!(IsCall::<ExamplePallet, FooFunction>::contains(&call_foo));
+	assert!(!IsCall::<ExamplePallet, FooFunction>::contains(&call_foo_transactional));
+	assert!(IsSubCall::<pallet::Call<Runtime>>::contains(&call_foo_transactional));
+	assert!(!IsSubCall::<pallet::Call<Runtime>>::contains(&call_remark));
+}
+
 #[test]
 fn error_expand() {
 	assert_eq!(
@@ -522,10 +736,31 @@ fn error_expand() {
 		DispatchError::from(pallet::Error::<Runtime>::InsufficientProposersBalance),
 		DispatchError::Module {
 			index: 1,
-			error: 0,
+			error: [0; 4],
 			message: Some("InsufficientProposersBalance"),
 		},
 	);
+
+	assert_eq!(pallet::Error::<Runtime>::MAX_ENCODED_SIZE, 4);
+	assert_eq!(
+		DispatchError::from(pallet::Error::<Runtime>::SubError(SomeSubError::Bar(0x0203))),
+		DispatchError::Module {
+			index: 1,
+			error: [1, 1, 3, 2],
+			message: Some("SubError"),
+		},
+	);
+	assert_eq!(
+		DispatchError::from(pallet::Error::<Runtime>::SubError(SomeSubError::Foo)),
+		DispatchError::Module {
+			index: 1,
+			error: [1, 0, 0, 0],
+			message: Some("SubError"),
+		},
+	);
+
+	// Checks the maximum encoded size of the errors.
+	<pallet::Pallet<Runtime> as frame_support::traits::IntegrityTest>::integrity_test();
 }
 
 #[test]
@@ -631,11 +866,13 @@ fn pallet_hooks_expand() {
 	TestExternalities::default().execute_with(|| {
 		frame_system::Pallet::<Runtime>::set_block_number(1);
 
-		assert_eq!(AllPallets::on_initialize(1), 10);
+		assert_eq!(AllPallets::on_initialize(1), Weight::from_ref_time(10));
+		assert_eq!(AllPallets::on_idle(1, Weight::from_ref_time(100)), Weight::from_ref_time(15));
+		assert_eq!(AllPallets::on_idle(1, Weight::from_ref_time(5)), Weight::from_ref_time(5));
 		AllPallets::on_finalize(1);
 
 		assert_eq!(pallet::Pallet::<Runtime>::storage_version(), None);
-		assert_eq!(AllPallets::on_runtime_upgrade(), 30);
+		assert_eq!(AllPallets::on_runtime_upgrade(), Weight::from_ref_time(30));
 		assert_eq!(
 			pallet::Pallet::<Runtime>::storage_version(),
 			Some(pallet::Pallet::<Runtime>::current_version()),
@@ -647,15 +884,88 @@ fn pallet_hooks_expand() {
 		);
 		assert_eq!(
 			frame_system::Pallet::<Runtime>::events()[1].event,
-			Event::Example(pallet::Event::Something(20)),
+			Event::Example(pallet::Event::Something(15)),
 		);
 		assert_eq!(
 			frame_system::Pallet::<Runtime>::events()[2].event,
+			Event::Example(pallet::Event::Something(5)),
+		);
+		assert_eq!(
+			frame_system::Pallet::<Runtime>::events()[3].event,
+			Event::Example(pallet::Event::Something(20)),
+		);
+		assert_eq!(
+			frame_system::Pallet::<Runtime>::events()[4].event,
 			Event::Example(pallet::Event::Something(30)),
 		);
 	})
 }
 
+#[cfg(feature = "try-runtime")]
+#[test]
+fn pallet_try_state_expand() {
+	use frame_support::traits::TryState;
+
+	TestExternalities::default().execute_with(|| {
+		assert_eq!(AllPallets::try_state(1), Ok(()));
+
+		pallet::Value::<Runtime>::put(u32::max_value());
+		assert_eq!(AllPallets::try_state(1), Err("Value is too big"));
+	})
+}
+
+#[test]
+fn pallet_inherent_expand() {
+	use codec::Encode;
+	use frame_support::inherent::{InherentData, MakeFatalError, MissingInherent, ProvideInherent};
+
+	assert_eq!(<Example2 as ProvideInherent>::INHERENT_IDENTIFIER, *b"testpal2");
+	assert!(<Example2 as ProvideInherent>::is_inherent(&pallet2::Call::<Runtime>::set(1)));
+	assert!(!<Example2 as ProvideInherent>::is_inherent(&pallet2::Call::<Runtime>::other()));
+
+	let mut data = InherentData::new();
+	data.put_data(*b"testpal2", &3u32).unwrap();
+	assert_eq!(
+		<Example2 as ProvideInherent>::create_inherent(&data),
+		Some(pallet2::Call::<Runtime>::set(3)),
+	);
+
+	// The default error type of a required inherent names the pallet missing its inherent.
+	let _: Option<<Example2 as ProvideInherent>::Error> = None::<MakeFatalError<MissingInherent>>;
+
+	// The inherent declared as required is required in every block, other are not by default.
+	assert!(<Example2 as ProvideInherent>::INHERENT_REQUIRED);
+	assert!(!<Example as ProvideInherent>::INHERENT_REQUIRED);
+	match <Example2 as ProvideInherent>::is_inherent_required(&data) {
+		Ok(Some(e)) =>
+			assert_eq!(e.encode(), MissingInherent { pallet: b"Example2".to_vec() }.encode()),
+		_ => panic!("The inherent of Example2 is required"),
+	}
+	assert!(matches!(<Example as ProvideInherent>::is_inherent_required(&data), Ok(None)));
+
+	assert!(Example::decode_inherent_error(&pallet::INHERENT_IDENTIFIER, &[]).is_none());
+	assert!(Example::decode_inherent_error(b"testpal2", &[]).is_none());
+}
+
+#[test]
+fn pallet_validate_unsigned_expand() {
+	use codec::Encode;
+	use frame_support::unsigned::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+
+	let validate = |call: pallet2::Call<Runtime>| {
+		Example2::validate_unsigned(TransactionSource::External, &call)
+	};
+
+	let valid = validate(pallet2::Call::submit(3)).unwrap();
+	assert_eq!(valid.priority, 10);
+	assert_eq!(valid.longevity, 5);
+	assert_eq!(valid.provides, vec![(concat!(module_path!(), "::pallet2::submit"), 3u32).encode()]);
+	assert!(valid.propagate);
+
+	assert_eq!(validate(pallet2::Call::submit(0)), Err(InvalidTransaction::Stale.into()));
+	assert_eq!(validate(pallet2::Call::other()), Err(InvalidTransaction::Call.into()));
+}
+
 #[test]
 fn pallet_on_genesis() {
 	TestExternalities::default().execute_with(|| {
@@ -668,6 +978,32 @@ fn pallet_on_genesis() {
 	})
 }
 
+#[test]
+fn pallet_storage_version_expand() {
+	use frame_support::traits::{CheckStorageVersion, GetStorageVersion, StorageVersion};
+
+	TestExternalities::default().execute_with(|| {
+		assert_eq!(pallet::Pallet::<Runtime>::current_storage_version(), StorageVersion::new(4));
+		assert_eq!(pallet2::Pallet::<Runtime>::current_storage_version(), StorageVersion::new(0));
+		assert_eq!(pallet::Pallet::<Runtime>::on_chain_storage_version(), StorageVersion::new(0));
+		assert!(pallet::Pallet::<Runtime>::check_storage_version().is_err());
+		assert!(AllPallets::check_storage_version().is_err());
+
+		AllPallets::on_genesis();
+		assert_eq!(pallet::Pallet::<Runtime>::on_chain_storage_version(), StorageVersion::new(4));
+		assert!(pallet2::Pallet::<Runtime>::on_chain_storage_version() == 0u16);
+		assert_eq!(AllPallets::check_storage_version(), Ok(()));
+
+		// The version is stored under the name of the pallet in the runtime.
+		assert_eq!(
+			unhashed::get::<StorageVersion>(
+				&[twox_128(b"Example"), twox_128(b":__STORAGE_VERSION__:")].concat(),
+			),
+			Some(StorageVersion::new(4)),
+		);
+	})
+}
+
 #[test]
 fn metadata() {
 	use frame_metadata::*;
@@ -936,6 +1272,12 @@ fn metadata() {
 					" doc comment put into metadata".to_string(),
 				]),
 			},
+			ErrorMetadata {
+				name: DecodeDifferent::Decoded("SubError".to_string()),
+				documentation: DecodeDifferent::Decoded(vec![
+					" error nesting an error type".to_string(),
+				]),
+			},
 		]),
 	};
 
@@ -0,0 +1,78 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "abi-json")]
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Doc comment put in the ABI.
+		#[pallet::weight(0)]
+		pub fn foo(
+			_origin: OriginFor<T>,
+			#[pallet::compact] amount: u64,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let _ = (amount, who);
+			Ok(())
+		}
+
+		#[pallet::weight(0)]
+		pub fn bar(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
+	}
+}
+
+#[test]
+fn call_abi_json_describes_the_calls() {
+	let abi: serde_json::Value = serde_json::from_str(pallet::CALL_ABI_JSON).unwrap();
+
+	assert_eq!(abi["crate"], "frame-support-test");
+	assert_eq!(abi["pallet"], "pallet");
+	assert_eq!(abi["call_type"], "Call");
+	assert_eq!(abi["calls"], serde_json::json!([
+		{
+			"name": "foo",
+			"index": 0,
+			"args": [
+				{ "name": "amount", "type": "u64", "compact": true },
+				{ "name": "who", "type": "T::AccountId", "compact": false },
+			],
+			"docs": [" Doc comment put in the ABI."],
+		},
+		{
+			"name": "bar",
+			"index": 1,
+			"args": [],
+			"docs": [],
+		},
+	]));
+}
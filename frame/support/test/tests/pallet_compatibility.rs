@@ -31,7 +31,7 @@ mod pallet_old {
 
 	pub trait Config: frame_system::Config {
 		type SomeConst: Get<Self::Balance>;
-		type Balance: Parameter + codec::HasCompact + From<u32> + Into<Weight> + Default
+		type Balance: Parameter + codec::HasCompact + From<u32> + Into<u64> + Default
 			+ SomeAssociation;
 		type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
 	}
@@ -46,6 +46,7 @@ mod pallet_old {
 				hasher(blake2_128_concat) u32,
 				hasher(twox_64_concat) u64
 				=> <T::Balance as SomeAssociation>::A;
+			Renamed: u32;
 		}
 	}
 
@@ -62,7 +63,7 @@ mod pallet_old {
 			fn deposit_event() = default;
 			const SomeConst: T::Balance = T::SomeConst::get();
 
-			#[weight = <T::Balance as Into<Weight>>::into(new_value.clone())]
+			#[weight = <T::Balance as Into<u64>>::into(new_value.clone())]
 			fn set_dummy(origin, #[compact] new_value: T::Balance) {
 				ensure_root(origin)?;
 
@@ -72,7 +73,7 @@ mod pallet_old {
 
 			fn on_initialize(_n: T::BlockNumber) -> Weight {
 				<Dummy<T>>::put(T::Balance::from(10));
-				10
+				Weight::from_ref_time(10)
 			}
 
 			fn on_finalize(_n: T::BlockNumber) {
@@ -98,7 +99,7 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		type Balance: Parameter + codec::HasCompact + From<u32> + Into<Weight> + Default
+		type Balance: Parameter + codec::HasCompact + From<u32> + Into<u64> + Default
 			+ MaybeSerializeDeserialize + SomeAssociation;
 		#[pallet::constant]
 		type SomeConst: Get<Self::Balance>;
@@ -112,7 +113,7 @@ pub mod pallet {
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
 			<Dummy<T>>::put(T::Balance::from(10));
-			10
+			Weight::from_ref_time(10)
 		}
 
 		fn on_finalize(_n: T::BlockNumber) {
@@ -122,7 +123,7 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(<T::Balance as Into<Weight>>::into(new_value.clone()))]
+		#[pallet::weight(<T::Balance as Into<u64>>::into(new_value.clone()))]
 		fn set_dummy(
 			origin: OriginFor<T>,
 			#[pallet::compact] new_value: T::Balance
@@ -166,6 +167,10 @@ pub mod pallet {
 		_, Blake2_128Concat, u32, Twox64Concat, u64, <T::Balance as SomeAssociation>::A, ValueQuery
 	>;
 
+	#[pallet::storage]
+	#[pallet::storage_prefix = "Renamed"]
+	type NewName<T> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		dummy: Option<T::Balance>,
@@ -279,6 +284,17 @@ mod test {
 		pretty_assertions::assert_eq!(modules[1].errors, modules[2].errors);
 	}
 
+	#[test]
+	fn storage_layout() {
+		assert_eq!(
+			frame_support::storage::migration::storage_layout_diff(
+				&pallet_old::Module::<Runtime>::storage_metadata(),
+				&pallet::Pallet::<Runtime>::storage_metadata(),
+			),
+			vec![],
+		);
+	}
+
 	#[test]
 	fn types() {
 		assert_eq!(
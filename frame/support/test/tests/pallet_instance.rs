@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use frame_support::{
-	weights::{DispatchInfo, DispatchClass, Pays, GetDispatchInfo},
+	weights::{DispatchInfo, DispatchClass, Pays, GetDispatchInfo, Weight},
 	traits::{
 		GetCallName, GetPalletVersion, OnInitialize, OnFinalize, OnRuntimeUpgrade, OnGenesis,
 	},
@@ -51,10 +51,10 @@ pub mod pallet {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
 			if TypeId::of::<I>() == TypeId::of::<()>() {
 				Self::deposit_event(Event::Something(10));
-				10
+				Weight::from_ref_time(10)
 			} else {
 				Self::deposit_event(Event::Something(11));
-				11
+				Weight::from_ref_time(11)
 			}
 		}
 		fn on_finalize(_: BlockNumberFor<T>) {
@@ -67,10 +67,10 @@ pub mod pallet {
 		fn on_runtime_upgrade() -> Weight {
 			if TypeId::of::<I>() == TypeId::of::<()>() {
 				Self::deposit_event(Event::Something(30));
-				30
+				Weight::from_ref_time(30)
 			} else {
 				Self::deposit_event(Event::Something(31));
-				31
+				Weight::from_ref_time(31)
 			}
 		}
 		fn integrity_test() {
@@ -80,7 +80,7 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Doc comment put in metadata
-		#[pallet::weight(Weight::from(*_foo))]
+		#[pallet::weight(Weight::from_ref_time(*_foo as u64))]
 		fn foo(origin: OriginFor<T>, #[pallet::compact] _foo: u32) -> DispatchResultWithPostInfo {
 			let _ = origin;
 			Self::deposit_event(Event::Something(3));
@@ -317,7 +317,7 @@ fn call_expand() {
 	assert_eq!(
 		call_foo.get_dispatch_info(),
 		DispatchInfo {
-			weight: 3,
+			weight: Weight::from_ref_time(3),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::Yes,
 		}
@@ -332,7 +332,7 @@ fn call_expand() {
 	assert_eq!(
 		call_foo.get_dispatch_info(),
 		DispatchInfo {
-			weight: 3,
+			weight: Weight::from_ref_time(3),
 			class: DispatchClass::Normal,
 			pays_fee: Pays::Yes,
 		}
@@ -344,6 +344,20 @@ fn call_expand() {
 	);
 }
 
+#[test]
+fn call_constructors_expand() {
+	use codec::Encode;
+
+	assert_eq!(pallet::Call::<Runtime>::foo_call(3), pallet::Call::<Runtime>::foo(3));
+	assert_eq!(
+		pallet::CallOf::<Runtime, pallet::Instance1>::foo_call(3),
+		pallet::Call::<Runtime, pallet::Instance1>::foo(3),
+	);
+
+	let call_foo = pallet::Call::<Runtime, pallet::Instance1>::foo_call(3);
+	assert_eq!(call_foo.encoded(), call_foo.encode());
+}
+
 #[test]
 fn error_expand() {
 	assert_eq!(
@@ -358,7 +372,7 @@ fn error_expand() {
 		DispatchError::from(pallet::Error::<Runtime>::InsufficientProposersBalance),
 		DispatchError::Module {
 			index: 1,
-			error: 0,
+			error: [0; 4],
 			message: Some("InsufficientProposersBalance"),
 		},
 	);
@@ -375,7 +389,7 @@ fn error_expand() {
 		DispatchError::from(pallet::Error::<Runtime, pallet::Instance1>::InsufficientProposersBalance),
 		DispatchError::Module {
 			index: 2,
-			error: 0,
+			error: [0; 4],
 			message: Some("InsufficientProposersBalance"),
 		},
 	);
@@ -521,12 +535,12 @@ fn pallet_hooks_expand() {
 	TestExternalities::default().execute_with(|| {
 		frame_system::Pallet::<Runtime>::set_block_number(1);
 
-		assert_eq!(AllPallets::on_initialize(1), 21);
+		assert_eq!(AllPallets::on_initialize(1), Weight::from_ref_time(21));
 		AllPallets::on_finalize(1);
 
 		assert_eq!(pallet::Pallet::<Runtime>::storage_version(), None);
 		assert_eq!(pallet::Pallet::<Runtime, pallet::Instance1>::storage_version(), None);
-		assert_eq!(AllPallets::on_runtime_upgrade(), 61);
+		assert_eq!(AllPallets::on_runtime_upgrade(), Weight::from_ref_time(61));
 		assert_eq!(
 			pallet::Pallet::<Runtime>::storage_version(),
 			Some(pallet::Pallet::<Runtime>::current_version()),
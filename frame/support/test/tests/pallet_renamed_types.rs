@@ -0,0 +1,123 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	dispatch::{GetCallName, UnfilteredDispatchable},
+	unsigned::{TransactionSource, ValidateUnsigned},
+};
+use sp_runtime::DispatchError;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// A type of the pallet which would collide with the generated call enum.
+	#[derive(Clone, PartialEq, Eq, Debug)]
+	pub struct Call(pub u32);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call(rename = "Dispatch")]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		#[pallet::validate_unsigned(priority = 1)]
+		pub fn ping(_origin: OriginFor<T>, value: u32) -> DispatchResult {
+			ensure!(value != 0, PalletError::<T>::Zero);
+			Ok(())
+		}
+	}
+
+	#[pallet::error(rename = "PalletError")]
+	pub enum PalletError<T> {
+		/// The value is zero.
+		Zero,
+	}
+}
+
+pub type Header = sp_runtime::generic::Header<u32, sp_runtime::traits::BlakeTwo256>;
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, Call, (), ()>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Event<T>},
+		Example: pallet::{Pallet, Call, ValidateUnsigned},
+	}
+);
+
+frame_support::parameter_types!(
+	pub const BlockHashCount: u32 = 250;
+);
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u32;
+	type Call = Call;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+impl pallet::Config for Runtime {}
+
+#[test]
+fn renamed_call_expand() {
+	let call = pallet::Dispatch::<Runtime>::ping(1);
+	assert_eq!(call.get_call_name(), "ping");
+	assert_eq!(pallet::CallOf::<Runtime>::ping_call(1), call);
+	assert_eq!(pallet::Call(1), pallet::Call(1));
+
+	// The outer call wraps the renamed enum.
+	let _: Call = Call::Example(call.clone());
+	assert!(Example::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+	assert_eq!(
+		pallet::Dispatch::<Runtime>::ping(0)
+			.dispatch_bypass_filter(Origin::root())
+			.map_err(|e| e.error),
+		Err(DispatchError::Module { index: 1, error: [0; 4], message: Some("Zero") }),
+	);
+}
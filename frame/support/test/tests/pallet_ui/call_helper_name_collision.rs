@@ -0,0 +1,30 @@
+#[frame_support::pallet]
+mod pallet {
+	use frame_support::pallet_prelude::{Hooks, DispatchResultWithPostInfo};
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(core::marker::PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		fn foo(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Ok(().into())
+		}
+
+		#[pallet::weight(0)]
+		fn foo_call(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Ok(().into())
+		}
+	}
+}
+
+fn main() {
+}
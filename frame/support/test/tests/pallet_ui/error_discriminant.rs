@@ -17,7 +17,7 @@ mod pallet {
 
 	#[pallet::error]
 	pub enum Error<T> {
-		U8(u8),
+		A = 1,
 	}
 }
 
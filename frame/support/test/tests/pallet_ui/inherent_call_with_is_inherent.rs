@@ -0,0 +1,39 @@
+#[frame_support::pallet]
+mod pallet {
+	use frame_support::pallet_prelude::{
+		DispatchResultWithPostInfo, Hooks, InherentData, ProvideInherent,
+	};
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(core::marker::PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		#[pallet::inherent]
+		fn set(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Ok(().into())
+		}
+	}
+
+	#[pallet::inherent(identifier = *b"testpall")]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		fn create_inherent(_data: &InherentData) -> Option<Self::Call> {
+			None
+		}
+
+		fn is_inherent(_call: &Self::Call) -> bool {
+			true
+		}
+	}
+}
+
+fn main() {
+}
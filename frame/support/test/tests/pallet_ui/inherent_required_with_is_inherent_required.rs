@@ -0,0 +1,35 @@
+#[frame_support::pallet]
+mod pallet {
+	use frame_support::pallet_prelude::{Hooks, InherentData, ProvideInherent};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(core::marker::PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+
+	#[pallet::inherent(identifier = *b"testpall", required)]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		fn create_inherent(_data: &InherentData) -> Option<Self::Call> {
+			None
+		}
+
+		fn is_inherent_required(_data: &InherentData) -> Result<Option<Self::Error>, Self::Error> {
+			Ok(None)
+		}
+
+		fn is_inherent(_call: &Self::Call) -> bool {
+			false
+		}
+	}
+}
+
+fn main() {
+}
@@ -0,0 +1,37 @@
+#[frame_support::pallet]
+mod pallet {
+	use frame_support::pallet_prelude::{
+		DispatchResultWithPostInfo, Hooks, TransactionSource, TransactionValidity, ValidateUnsigned,
+	};
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(core::marker::PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		#[pallet::validate_unsigned(priority = 1)]
+		fn submit(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			Ok(().into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, _call: &Self::Call) -> TransactionValidity {
+			unimplemented!();
+		}
+	}
+}
+
+fn main() {
+}
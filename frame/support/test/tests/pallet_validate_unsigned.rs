@@ -0,0 +1,161 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::unsigned::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+use frame_system::offchain::{AppCrypto, SigningTypes};
+use sp_runtime::testing::{TestSignature, UintAuthorityId};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::{
+		offchain::{AppCrypto, SignedPayload, SigningTypes},
+		pallet_prelude::*,
+	};
+
+	/// A value submitted by an authority.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct Payload<Public> {
+		pub public: Public,
+		pub value: u32,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for Payload<T::Public> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: SigningTypes {
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(0)]
+		#[pallet::validate_unsigned(
+			provides = payload.value,
+			signed_payload(payload, _signature, T::AuthorityId),
+		)]
+		pub fn submit(
+			origin: OriginFor<T>,
+			payload: Payload<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			ensure!(payload.value != 0, DispatchError::Other("zero value"));
+			Ok(())
+		}
+	}
+}
+
+pub type Header = sp_runtime::generic::Header<u32, sp_runtime::traits::BlakeTwo256>;
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, Call, (), ()>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Event<T>},
+		Example: pallet::{Pallet, Call, ValidateUnsigned},
+	}
+);
+
+frame_support::parameter_types!(
+	pub const BlockHashCount: u32 = 250;
+);
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u32;
+	type Call = Call;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+impl SigningTypes for Runtime {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+pub struct TestAuthorityId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+impl pallet::Config for Runtime {
+	type AuthorityId = TestAuthorityId;
+}
+
+#[test]
+fn signed_payload_is_verified() {
+	use codec::Encode;
+
+	let payload = pallet::Payload { public: UintAuthorityId(1), value: 42 };
+	let validate = |signature| {
+		let call = pallet::Call::<Runtime>::submit(payload.clone(), signature);
+		Example::validate_unsigned(TransactionSource::External, &call)
+	};
+
+	let valid = validate(TestSignature(1, payload.encode())).unwrap();
+	assert_eq!(
+		valid.provides,
+		vec![(concat!(module_path!(), "::pallet::submit"), 42u32).encode()],
+	);
+
+	// Signed by another authority.
+	assert_eq!(
+		validate(TestSignature(2, payload.encode())),
+		Err(InvalidTransaction::BadProof.into()),
+	);
+	// Signature of another payload.
+	let other = pallet::Payload { public: UintAuthorityId(1), value: 43 };
+	assert_eq!(
+		validate(TestSignature(1, other.encode())),
+		Err(InvalidTransaction::BadProof.into()),
+	);
+}
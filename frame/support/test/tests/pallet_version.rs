@@ -66,7 +66,7 @@ mod module2 {
 					assert!(Self::storage_version().is_none());
 				}
 
-				0
+				Weight::zero()
 			}
 		}
 	}
@@ -91,7 +91,7 @@ mod pallet3 {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_runtime_upgrade() -> Weight {
-			return 3;
+			return Weight::from_ref_time(3);
 		}
 	}
 
@@ -115,7 +115,7 @@ mod pallet4 {
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		fn on_runtime_upgrade() -> Weight {
-			return 3;
+			return Weight::from_ref_time(3);
 		}
 	}
 
@@ -51,7 +51,7 @@ frame_support::decl_module! {
 		}
 
 		fn on_initialize(_n: T::BlockNumber) -> frame_support::weights::Weight {
-			0
+			frame_support::weights::Weight::zero()
 		}
 	}
 }
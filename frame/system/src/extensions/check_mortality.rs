@@ -85,7 +85,7 @@ impl<T: Config + Send + Sync> SignedExtension for CheckMortality<T> {
 mod tests {
 	use super::*;
 	use crate::mock::{Test, new_test_ext, System, CALL};
-	use frame_support::weights::{DispatchClass, DispatchInfo, Pays};
+	use frame_support::weights::{DispatchClass, DispatchInfo, Pays, Weight};
 	use sp_core::H256;
 
 	#[test]
@@ -107,7 +107,11 @@ mod tests {
 	#[test]
 	fn signed_ext_check_era_should_change_longevity() {
 		new_test_ext().execute_with(|| {
-			let normal = DispatchInfo { weight: 100, class: DispatchClass::Normal, pays_fee: Pays::Yes };
+			let normal = DispatchInfo {
+				weight: Weight::from_ref_time(100),
+				class: DispatchClass::Normal,
+				pays_fee: Pays::Yes,
+			};
 			let len = 0_usize;
 			let ext = (
 				crate::CheckWeight::<Test>::new(),
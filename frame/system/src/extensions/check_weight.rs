@@ -44,7 +44,7 @@ impl<T: Config + Send + Sync> CheckWeight<T> where
 	) -> Result<(), TransactionValidityError> {
 		let max = T::BlockWeights::get().get(info.class).max_extrinsic;
 		match max {
-			Some(max) if info.weight > max => {
+			Some(max) if info.weight.any_gt(&max) => {
 				Err(InvalidTransaction::ExhaustsResources.into())
 			},
 			_ => Ok(()),
@@ -88,11 +88,11 @@ impl<T: Config + Send + Sync> CheckWeight<T> where
 		match info.class {
 			// Normal transaction.
 			DispatchClass::Normal =>
-				FrameTransactionPriority::Normal(info.weight.into()).into(),
+				FrameTransactionPriority::Normal(info.weight.ref_time()).into(),
 			// Don't use up the whole priority space, to allow things like `tip` to be taken into
 			// account as well.
 			DispatchClass::Operational =>
-				FrameTransactionPriority::Operational(info.weight.into()).into(),
+				FrameTransactionPriority::Operational(info.weight.ref_time()).into(),
 			// Mandatory extrinsics are only for inherents; never transactions.
 			DispatchClass::Mandatory => TransactionPriority::min_value(),
 		}
@@ -159,7 +159,7 @@ pub fn calculate_consumed_weight<Call>(
 
 	// Check if we don't exceed per-class allowance
 	match limit_per_class.max_total {
-		Some(max) if per_class > max => {
+		Some(max) if per_class.any_gt(&max) => {
 			return Err(InvalidTransaction::ExhaustsResources.into());
 		},
 		// There is no `max_total` limit (`None`),
@@ -169,10 +169,10 @@ pub fn calculate_consumed_weight<Call>(
 
 	// In cases total block weight is exceeded, we need to fall back
 	// to `reserved` pool if there is any.
-	if all_weight.total() > maximum_weight.max_block {
+	if all_weight.total().any_gt(&maximum_weight.max_block) {
 		match limit_per_class.reserved {
 			// We are over the limit in reserved pool.
-			Some(reserved) if per_class > reserved => {
+			Some(reserved) if per_class.any_gt(&reserved) => {
 				return Err(InvalidTransaction::ExhaustsResources.into());
 			}
 			// There is either no limit in reserved pool (`None`),
@@ -253,7 +253,7 @@ impl<T: Config + Send + Sync> SignedExtension for CheckWeight<T> where
 		}
 
 		let unspent = post_info.calc_unspent(info);
-		if unspent > 0 {
+		if !unspent.is_zero() {
 			crate::BlockWeight::<T>::mutate(|current_weight| {
 				current_weight.sub(unspent, info.class);
 			})
@@ -318,7 +318,7 @@ mod tests {
 		check(|max, len| {
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(max, len));
 			assert_eq!(System::block_weight().total(), Weight::max_value());
-			assert!(System::block_weight().total() > block_weight_limit());
+			assert!(System::block_weight().total().any_gt(&block_weight_limit()));
 		});
 		check(|max, len| {
 			assert_ok!(CheckWeight::<Test>::do_validate(max, len));
@@ -353,7 +353,7 @@ mod tests {
 				..Default::default()
 			};
 			let max = DispatchInfo {
-				weight: weight + 1,
+				weight: weight + Weight::from_ref_time(1),
 				class: DispatchClass::Operational,
 				..Default::default()
 			};
@@ -375,7 +375,7 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			System::register_extra_weight_unchecked(Weight::max_value(), DispatchClass::Normal);
 			assert_eq!(System::block_weight().total(), Weight::max_value());
-			assert!(System::block_weight().total() > block_weight_limit());
+			assert!(System::block_weight().total().any_gt(&block_weight_limit()));
 		});
 	}
 
@@ -387,15 +387,22 @@ mod tests {
 			// 10 is taken for block execution weight
 			// So normal extrinsic can be 758 weight (-5 for base extrinsic weight)
 			// And Operational can be 256 to produce a full block (-5 for base)
-			let max_normal = DispatchInfo { weight: 753, ..Default::default() };
-			let rest_operational = DispatchInfo { weight: 251, class: DispatchClass::Operational, ..Default::default() };
+			let max_normal = DispatchInfo {
+				weight: Weight::from_ref_time(753),
+				..Default::default()
+			};
+			let rest_operational = DispatchInfo {
+				weight: Weight::from_ref_time(251),
+				class: DispatchClass::Operational,
+				..Default::default()
+			};
 
 			let len = 0_usize;
 
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&max_normal, len));
-			assert_eq!(System::block_weight().total(), 768);
+			assert_eq!(System::block_weight().total(), Weight::from_ref_time(768));
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&rest_operational, len));
-			assert_eq!(block_weight_limit(), 1024);
+			assert_eq!(block_weight_limit(), Weight::from_ref_time(1024));
 			assert_eq!(System::block_weight().total(), block_weight_limit());
 			// Checking single extrinsic should not take current block weight into account.
 			assert_eq!(CheckWeight::<Test>::check_extrinsic_weight(&rest_operational), Ok(()));
@@ -406,16 +413,23 @@ mod tests {
 	fn dispatch_order_does_not_effect_weight_logic() {
 		new_test_ext().execute_with(|| {
 			// We switch the order of `full_block_with_normal_and_operational`
-			let max_normal = DispatchInfo { weight: 753, ..Default::default() };
-			let rest_operational = DispatchInfo { weight: 251, class: DispatchClass::Operational, ..Default::default() };
+			let max_normal = DispatchInfo {
+				weight: Weight::from_ref_time(753),
+				..Default::default()
+			};
+			let rest_operational = DispatchInfo {
+				weight: Weight::from_ref_time(251),
+				class: DispatchClass::Operational,
+				..Default::default()
+			};
 
 			let len = 0_usize;
 
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&rest_operational, len));
 			// Extra 15 here from block execution + base extrinsic weight
-			assert_eq!(System::block_weight().total(), 266);
+			assert_eq!(System::block_weight().total(), Weight::from_ref_time(266));
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&max_normal, len));
-			assert_eq!(block_weight_limit(), 1024);
+			assert_eq!(block_weight_limit(), Weight::from_ref_time(1024));
 			assert_eq!(System::block_weight().total(), block_weight_limit());
 		});
 	}
@@ -425,8 +439,16 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			// An on_initialize takes up the whole block! (Every time!)
 			System::register_extra_weight_unchecked(Weight::max_value(), DispatchClass::Mandatory);
-			let dispatch_normal = DispatchInfo { weight: 251, class: DispatchClass::Normal, ..Default::default() };
-			let dispatch_operational = DispatchInfo { weight: 251, class: DispatchClass::Operational, ..Default::default() };
+			let dispatch_normal = DispatchInfo {
+				weight: Weight::from_ref_time(251),
+				class: DispatchClass::Normal,
+				..Default::default()
+			};
+			let dispatch_operational = DispatchInfo {
+				weight: Weight::from_ref_time(251),
+				class: DispatchClass::Operational,
+				..Default::default()
+			};
 			let len = 0_usize;
 
 			assert_err!( CheckWeight::<Test>::do_pre_dispatch(&dispatch_normal, len),
@@ -446,8 +468,12 @@ mod tests {
 	#[test]
 	fn signed_ext_check_weight_works_operational_tx() {
 		new_test_ext().execute_with(|| {
-			let normal = DispatchInfo { weight: 100, ..Default::default() };
-			let op = DispatchInfo { weight: 100, class: DispatchClass::Operational, pays_fee: Pays::Yes };
+			let normal = DispatchInfo { weight: Weight::from_ref_time(100), ..Default::default() };
+			let op = DispatchInfo {
+				weight: Weight::from_ref_time(100),
+				class: DispatchClass::Operational,
+				pays_fee: Pays::Yes,
+			};
 			let len = 0_usize;
 			let normal_limit = normal_weight_limit();
 
@@ -475,8 +501,16 @@ mod tests {
 	#[test]
 	fn signed_ext_check_weight_works() {
 		new_test_ext().execute_with(|| {
-			let normal = DispatchInfo { weight: 100, class: DispatchClass::Normal, pays_fee: Pays::Yes };
-			let op = DispatchInfo { weight: 100, class: DispatchClass::Operational, pays_fee: Pays::Yes };
+			let normal = DispatchInfo {
+				weight: Weight::from_ref_time(100),
+				class: DispatchClass::Normal,
+				pays_fee: Pays::Yes,
+			};
+			let op = DispatchInfo {
+				weight: Weight::from_ref_time(100),
+				class: DispatchClass::Operational,
+				pays_fee: Pays::Yes,
+			};
 			let len = 0_usize;
 
 			let priority = CheckWeight::<Test>(PhantomData)
@@ -497,7 +531,7 @@ mod tests {
 	fn signed_ext_check_weight_block_size_works() {
 		new_test_ext().execute_with(|| {
 			let normal = DispatchInfo::default();
-			let normal_limit = normal_weight_limit() as usize;
+			let normal_limit = normal_weight_limit().ref_time() as usize;
 			let reset_check_weight = |tx, s, f| {
 				AllExtrinsicsLen::<Test>::put(0);
 				let r = CheckWeight::<Test>(PhantomData).pre_dispatch(&1, CALL, tx, s);
@@ -509,7 +543,11 @@ mod tests {
 			reset_check_weight(&normal, normal_limit + 1, true);
 
 			// Operational ones don't have this limit.
-			let op = DispatchInfo { weight: 0, class: DispatchClass::Operational, pays_fee: Pays::Yes };
+			let op = DispatchInfo {
+				weight: Weight::zero(),
+				class: DispatchClass::Operational,
+				pays_fee: Pays::Yes,
+			};
 			reset_check_weight(&op, normal_limit, false);
 			reset_check_weight(&op, normal_limit + 100, false);
 			reset_check_weight(&op, 1024, false);
@@ -522,14 +560,14 @@ mod tests {
 	fn signed_ext_check_weight_works_normal_tx() {
 		new_test_ext().execute_with(|| {
 			let normal_limit = normal_weight_limit();
-			let small = DispatchInfo { weight: 100, ..Default::default() };
+			let small = DispatchInfo { weight: Weight::from_ref_time(100), ..Default::default() };
 			let base_extrinsic = block_weights().get(DispatchClass::Normal).base_extrinsic;
 			let medium = DispatchInfo {
 				weight: normal_limit - base_extrinsic,
 				..Default::default()
 			};
 			let big = DispatchInfo {
-				weight: normal_limit - base_extrinsic + 1,
+				weight: normal_limit - base_extrinsic + Weight::from_ref_time(1),
 				..Default::default()
 			};
 			let len = 0_usize;
@@ -542,9 +580,9 @@ mod tests {
 				if f { assert!(r.is_err()) } else { assert!(r.is_ok()) }
 			};
 
-			reset_check_weight(&small, false, 0);
-			reset_check_weight(&medium, false, 0);
-			reset_check_weight(&big, true, 1);
+			reset_check_weight(&small, false, Weight::zero());
+			reset_check_weight(&medium, false, Weight::zero());
+			reset_check_weight(&big, true, Weight::from_ref_time(1));
 		})
 	}
 
@@ -552,9 +590,9 @@ mod tests {
 	fn signed_ext_check_weight_refund_works() {
 		new_test_ext().execute_with(|| {
 			// This is half of the max block weight
-			let info = DispatchInfo { weight: 512, ..Default::default() };
+			let info = DispatchInfo { weight: Weight::from_ref_time(512), ..Default::default() };
 			let post_info = PostDispatchInfo {
-				actual_weight: Some(128),
+				actual_weight: Some(Weight::from_ref_time(128)),
 				pays_fee: Default::default(),
 			};
 			let len = 0_usize;
@@ -562,12 +600,13 @@ mod tests {
 
 			// We allow 75% for normal transaction, so we put 25% - extrinsic base weight
 			BlockWeight::<Test>::mutate(|current_weight| {
-				current_weight.set(0, DispatchClass::Mandatory);
-				current_weight.set(256 - base_extrinsic, DispatchClass::Normal);
+				current_weight.set(Weight::zero(), DispatchClass::Mandatory);
+				current_weight
+					.set(Weight::from_ref_time(256) - base_extrinsic, DispatchClass::Normal);
 			});
 
 			let pre = CheckWeight::<Test>(PhantomData).pre_dispatch(&1, CALL, &info, len).unwrap();
-			assert_eq!(BlockWeight::<Test>::get().total(), info.weight + 256);
+			assert_eq!(BlockWeight::<Test>::get().total(), info.weight + Weight::from_ref_time(256));
 
 			assert_ok!( CheckWeight::<Test>::post_dispatch(pre, &info, &post_info, len, &Ok(())));
 			assert_eq!(
@@ -580,28 +619,30 @@ mod tests {
 	#[test]
 	fn signed_ext_check_weight_actual_weight_higher_than_max_is_capped() {
 		new_test_ext().execute_with(|| {
-			let info = DispatchInfo { weight: 512, ..Default::default() };
+			let info = DispatchInfo { weight: Weight::from_ref_time(512), ..Default::default() };
 			let post_info = PostDispatchInfo {
-				actual_weight: Some(700),
+				actual_weight: Some(Weight::from_ref_time(700)),
 				pays_fee: Default::default(),
 			};
 			let len = 0_usize;
 
 			BlockWeight::<Test>::mutate(|current_weight| {
-				current_weight.set(0, DispatchClass::Mandatory);
-				current_weight.set(128, DispatchClass::Normal);
+				current_weight.set(Weight::zero(), DispatchClass::Mandatory);
+				current_weight.set(Weight::from_ref_time(128), DispatchClass::Normal);
 			});
 
 			let pre = CheckWeight::<Test>(PhantomData).pre_dispatch(&1, CALL, &info, len).unwrap();
 			assert_eq!(
 				BlockWeight::<Test>::get().total(),
-				info.weight + 128 + block_weights().get(DispatchClass::Normal).base_extrinsic,
+				info.weight + Weight::from_ref_time(128) +
+					block_weights().get(DispatchClass::Normal).base_extrinsic,
 			);
 
 			assert_ok!(CheckWeight::<Test>::post_dispatch(pre, &info, &post_info, len, &Ok(())));
 			assert_eq!(
 				BlockWeight::<Test>::get().total(),
-				info.weight + 128 + block_weights().get(DispatchClass::Normal).base_extrinsic,
+				info.weight + Weight::from_ref_time(128) +
+					block_weights().get(DispatchClass::Normal).base_extrinsic,
 			);
 		})
 	}
@@ -610,7 +651,7 @@ mod tests {
 	fn zero_weight_extrinsic_still_has_base_weight() {
 		new_test_ext().execute_with(|| {
 			let weights = block_weights();
-			let free = DispatchInfo { weight: 0, ..Default::default() };
+			let free = DispatchInfo { weight: Weight::zero(), ..Default::default() };
 			let len = 0_usize;
 
 			// Initial weight from `weights.base_block`
@@ -632,16 +673,23 @@ mod tests {
 			// Max block is 1024
 			// Max normal is 768 (75%)
 			// Max mandatory is unlimited
-			let max_normal = DispatchInfo { weight: 753, ..Default::default() };
-			let mandatory = DispatchInfo { weight: 1019, class: DispatchClass::Mandatory, ..Default::default() };
+			let max_normal = DispatchInfo {
+				weight: Weight::from_ref_time(753),
+				..Default::default()
+			};
+			let mandatory = DispatchInfo {
+				weight: Weight::from_ref_time(1019),
+				class: DispatchClass::Mandatory,
+				..Default::default()
+			};
 
 			let len = 0_usize;
 
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&max_normal, len));
-			assert_eq!(System::block_weight().total(), 768);
+			assert_eq!(System::block_weight().total(), Weight::from_ref_time(768));
 			assert_ok!(CheckWeight::<Test>::do_pre_dispatch(&mandatory, len));
-			assert_eq!(block_weight_limit(), 1024);
-			assert_eq!(System::block_weight().total(), 1024 + 768);
+			assert_eq!(block_weight_limit(), Weight::from_ref_time(1024));
+			assert_eq!(System::block_weight().total(), Weight::from_ref_time(1024 + 768));
 			assert_eq!(CheckWeight::<Test>::check_extrinsic_weight(&mandatory), Ok(()));
 		});
 	}
@@ -650,28 +698,36 @@ mod tests {
 	fn no_max_total_should_still_be_limited_by_max_block() {
 		// given
 		let maximum_weight = BlockWeights::builder()
-			.base_block(0)
+			.base_block(Weight::zero())
 			.for_class(DispatchClass::non_mandatory(), |w| {
-				w.base_extrinsic = 0;
-				w.max_total = Some(20);
+				w.base_extrinsic = Weight::zero();
+				w.max_total = Some(Weight::from_ref_time(20));
 			})
 			.for_class(DispatchClass::Mandatory, |w| {
-				w.base_extrinsic = 0;
-				w.reserved = Some(5);
+				w.base_extrinsic = Weight::zero();
+				w.reserved = Some(Weight::from_ref_time(5));
 				w.max_total = None;
 			})
 			.build_or_panic();
 		let all_weight = crate::ConsumedWeight::new(|class| match class {
-			DispatchClass::Normal => 10,
-			DispatchClass::Operational => 10,
-			DispatchClass::Mandatory => 0,
+			DispatchClass::Normal => Weight::from_ref_time(10),
+			DispatchClass::Operational => Weight::from_ref_time(10),
+			DispatchClass::Mandatory => Weight::zero(),
 		});
 		assert_eq!(maximum_weight.max_block, all_weight.total());
 
 		// fits into reserved
-		let mandatory1 = DispatchInfo { weight: 5, class: DispatchClass::Mandatory, ..Default::default() };
+		let mandatory1 = DispatchInfo {
+			weight: Weight::from_ref_time(5),
+			class: DispatchClass::Mandatory,
+			..Default::default()
+		};
 		// does not fit into reserved and the block is full.
-		let mandatory2 = DispatchInfo { weight: 6, class: DispatchClass::Mandatory, ..Default::default() };
+		let mandatory2 = DispatchInfo {
+			weight: Weight::from_ref_time(6),
+			class: DispatchClass::Mandatory,
+			..Default::default()
+		};
 
 		// when
 		assert_ok!(
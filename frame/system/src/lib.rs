@@ -284,7 +284,7 @@ pub mod pallet {
 				UpgradedToTripleRefCount::<T>::put(true);
 				migrations::migrate_to_triple_ref_count::<T>()
 			} else {
-				0
+				Weight::zero()
 			}
 		}
 
@@ -194,6 +194,9 @@ pub struct WeightsPerClass {
 ///
 /// As a consequence of `reserved` space, total consumed block weight might exceed `max_block`
 /// value, so this parameter should rather be thought of as "target block weight" than a hard limit.
+///
+/// All the limits apply to both dimensions of the weight: a weight fits in a limit only if its
+/// reference time and its proof size both fit.
 #[derive(RuntimeDebug, Clone, codec::Encode, codec::Decode)]
 pub struct BlockWeights {
 	/// Base weight of block execution.
@@ -207,7 +210,7 @@ pub struct BlockWeights {
 impl Default for BlockWeights {
 	fn default() -> Self {
 		Self::with_sensible_defaults(
-			1 * constants::WEIGHT_PER_SECOND,
+			Weight::from_parts(constants::WEIGHT_PER_SECOND.ref_time(), u64::max_value()),
 			DEFAULT_NORMAL_RATIO,
 		)
 	}
@@ -224,6 +227,10 @@ impl BlockWeights {
 		fn or_max(w: Option<Weight>) -> Weight {
 			w.unwrap_or_else(|| Weight::max_value())
 		}
+		// `a` is at least `b` in both dimensions, and greater in one of them.
+		fn exceeds(a: Weight, b: Weight) -> bool {
+			b.all_lte(&a) && a != b
+		}
 		let mut error = ValidationErrors::default();
 
 		for class in DispatchClass::all() {
@@ -234,15 +241,16 @@ impl BlockWeights {
 			// Make sure that if total is set it's greater than base_block &&
 			// base_for_class
 			error_assert!(
-				(max_for_class > self.base_block && max_for_class > base_for_class)
-				|| max_for_class == 0,
+				(exceeds(max_for_class, self.base_block) && exceeds(max_for_class, base_for_class))
+				|| max_for_class.is_zero(),
 				&mut error,
 				"[{:?}] {:?} (total) has to be greater than {:?} (base block) & {:?} (base extrinsic)",
 				class, max_for_class, self.base_block, base_for_class,
 			);
 			// Max extrinsic can't be greater than max_for_class.
 			error_assert!(
-				weights.max_extrinsic.unwrap_or(0) <= max_for_class.saturating_sub(base_for_class),
+				weights.max_extrinsic.unwrap_or_else(Weight::zero)
+					.all_lte(&max_for_class.saturating_sub(base_for_class)),
 				&mut error,
 				"[{:?}] {:?} (max_extrinsic) can't be greater than {:?} (max for class)",
 				class, weights.max_extrinsic,
@@ -250,28 +258,28 @@ impl BlockWeights {
 			);
 			// Max extrinsic should not be 0
 			error_assert!(
-				weights.max_extrinsic.unwrap_or_else(|| Weight::max_value()) > 0,
+				!weights.max_extrinsic.unwrap_or_else(|| Weight::max_value()).is_zero(),
 				&mut error,
 				"[{:?}] {:?} (max_extrinsic) must not be 0. Check base cost and average initialization cost.",
 				class, weights.max_extrinsic,
 			);
 			// Make sure that if reserved is set it's greater than base_for_class.
 			error_assert!(
-				reserved > base_for_class || reserved == 0,
+				exceeds(reserved, base_for_class) || reserved.is_zero(),
 				&mut error,
 				"[{:?}] {:?} (reserved) has to be greater than {:?} (base extrinsic) if set",
 				class, reserved, base_for_class,
 			);
 			// Make sure max block is greater than max_total if it's set.
 			error_assert!(
-				self.max_block >= weights.max_total.unwrap_or(0),
+				weights.max_total.unwrap_or_else(Weight::zero).all_lte(&self.max_block),
 				&mut error,
 				"[{:?}] {:?} (max block) has to be greater than {:?} (max for class)",
 				class, self.max_block, weights.max_total,
 			);
 			// Make sure we can fit at least one extrinsic.
 			error_assert!(
-				self.max_block > base_for_class + self.base_block,
+				exceeds(self.max_block, base_for_class + self.base_block),
 				&mut error,
 				"[{:?}] {:?} (max block) must fit at least one extrinsic {:?} (base weight)",
 				class, self.max_block, base_for_class + self.base_block,
@@ -290,11 +298,15 @@ impl BlockWeights {
 	///
 	/// Note there is no reservation for `Operational` class, so this constructor
 	/// is not suitable for production deployments.
+	///
+	/// A `block_weight` without proof size, e.g. a converted scalar weight, doesn't limit the
+	/// proof size.
 	pub fn simple_max(block_weight: Weight) -> Self {
+		let block_weight = unlimited_proof_size_if_zero(block_weight);
 		Self::builder()
-			.base_block(0)
+			.base_block(Weight::zero())
 			.for_class(DispatchClass::all(), |weights| {
-				weights.base_extrinsic = 0;
+				weights.base_extrinsic = Weight::zero();
 			})
 			.for_class(DispatchClass::non_mandatory(), |weights| {
 				weights.max_total = block_weight.into();
@@ -309,10 +321,14 @@ impl BlockWeights {
 	/// Assumptions:
 	///  - Average block initialization is assumed to be `10%`.
 	///  - `Operational` transactions have reserved allowance (`1.0 - normal_ratio`)
+	///
+	/// An `expected_block_weight` without proof size, e.g. a converted scalar weight, doesn't
+	/// limit the proof size.
 	pub fn with_sensible_defaults(
 		expected_block_weight: Weight,
 		normal_ratio: Perbill,
 	) -> Self {
+		let expected_block_weight = unlimited_proof_size_if_zero(expected_block_weight);
 		let normal_weight = normal_ratio * expected_block_weight;
 		Self::builder()
 			.for_class(DispatchClass::Normal, |weights| {
@@ -334,9 +350,10 @@ impl BlockWeights {
 		BlockWeightsBuilder {
 			weights: BlockWeights {
 				base_block: constants::BlockExecutionWeight::get(),
-				max_block: 0,
+				max_block: Weight::zero(),
 				per_class: PerDispatchClass::new(|class| {
-					let initial = if class == DispatchClass::Mandatory { None } else { Some(0) };
+					let initial =
+						if class == DispatchClass::Mandatory { None } else { Some(Weight::zero()) };
 					WeightsPerClass {
 						base_extrinsic: constants::ExtrinsicBaseWeight::get(),
 						max_extrinsic: None,
@@ -350,6 +367,17 @@ impl BlockWeights {
 	}
 }
 
+/// `weight`, with an unlimited proof size if it has none.
+///
+/// Otherwise a block limit given as a scalar weight would reject any weight with a proof size.
+fn unlimited_proof_size_if_zero(weight: Weight) -> Weight {
+	if weight.proof_size() == 0 {
+		weight.set_proof_size(u64::max_value())
+	} else {
+		weight
+	}
+}
+
 /// An opinionated builder for `Weights` object.
 pub struct BlockWeightsBuilder {
 	weights: BlockWeights,
@@ -398,10 +426,9 @@ impl BlockWeightsBuilder {
 
 		// compute max block size.
 		for class in DispatchClass::all() {
-			weights.max_block = match weights.per_class.get(*class).max_total {
-				Some(max) if max > weights.max_block => max,
-				_ => weights.max_block,
-			};
+			if let Some(max) = weights.per_class.get(*class).max_total {
+				weights.max_block = weights.max_block.max(max);
+			}
 		}
 		// compute max size of single extrinsic
 		if let Some(init_weight) = init_cost.map(|rate| rate * weights.max_block) {
@@ -439,4 +466,34 @@ mod tests {
 			.validate()
 			.unwrap();
 	}
+
+	#[test]
+	fn default_weights_do_not_limit_the_proof_size() {
+		let weights = BlockWeights::default();
+		let proof_heavy = Weight::from_parts(1, 10 * 1024 * 1024);
+
+		assert_eq!(weights.max_block.proof_size(), u64::max_value());
+		assert!(!proof_heavy.any_gt(&weights.max_block));
+		let normal = weights.get(DispatchClass::Normal);
+		assert!(!proof_heavy.any_gt(&normal.max_extrinsic.unwrap()));
+	}
+
+	#[test]
+	fn scalar_block_weights_do_not_limit_the_proof_size() {
+		let weights = BlockWeights::simple_max(Weight::from_ref_time(1024));
+		assert_eq!(weights.max_block, Weight::from_parts(1024, u64::max_value()));
+
+		let weights = BlockWeights::with_sensible_defaults(
+			Weight::from_ref_time(1024),
+			Perbill::from_percent(75),
+		);
+		assert_eq!(weights.max_block.proof_size(), u64::max_value());
+	}
+
+	#[test]
+	fn proof_size_limits_are_kept() {
+		let weights = BlockWeights::simple_max(Weight::from_parts(1024, 5 * 1024 * 1024));
+		assert_eq!(weights.max_block, Weight::from_parts(1024, 5 * 1024 * 1024));
+		assert!(Weight::from_parts(1, 6 * 1024 * 1024).any_gt(&weights.max_block));
+	}
 }
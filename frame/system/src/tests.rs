@@ -20,7 +20,8 @@ use mock::{*, Origin};
 use sp_core::H256;
 use sp_runtime::{DispatchError, DispatchErrorWithPostInfo, traits::{Header, BlakeTwo256}};
 use frame_support::{
-	assert_noop, assert_ok, weights::WithPostDispatchInfo, dispatch::PostDispatchInfo
+	assert_noop, assert_ok, weights::WithPostDispatchInfo, dispatch::PostDispatchInfo,
+	storage::with_storage_layer,
 };
 
 #[test]
@@ -239,24 +240,24 @@ fn deposit_event_uses_actual_weight() {
 		System::note_finished_initialize();
 
 		let pre_info = DispatchInfo {
-			weight: 1000,
+			weight: Weight::from_ref_time(1000),
 			.. Default::default()
 		};
 		System::note_applied_extrinsic(
-			&Ok(Some(300).into()),
+			&Ok(Some(Weight::from_ref_time(300)).into()),
 			pre_info,
 		);
 		System::note_applied_extrinsic(
-			&Ok(Some(1000).into()),
+			&Ok(Some(Weight::from_ref_time(1000)).into()),
 			pre_info,
 		);
 		System::note_applied_extrinsic(
 			// values over the pre info should be capped at pre dispatch value
-			&Ok(Some(1200).into()),
+			&Ok(Some(Weight::from_ref_time(1200)).into()),
 			pre_info,
 		);
 		System::note_applied_extrinsic(
-			&Err(DispatchError::BadOrigin.with_weight(999)),
+			&Err(DispatchError::BadOrigin.with_weight(Weight::from_ref_time(999))),
 			pre_info,
 		);
 
@@ -267,7 +268,7 @@ fn deposit_event_uses_actual_weight() {
 					phase: Phase::ApplyExtrinsic(0),
 					event: SysEvent::ExtrinsicSuccess(
 						DispatchInfo {
-							weight: 300,
+							weight: Weight::from_ref_time(300),
 							.. Default::default()
 						},
 					).into(),
@@ -277,7 +278,7 @@ fn deposit_event_uses_actual_weight() {
 					phase: Phase::ApplyExtrinsic(1),
 					event: SysEvent::ExtrinsicSuccess(
 						DispatchInfo {
-							weight: 1000,
+							weight: Weight::from_ref_time(1000),
 							.. Default::default()
 						},
 					).into(),
@@ -287,7 +288,7 @@ fn deposit_event_uses_actual_weight() {
 					phase: Phase::ApplyExtrinsic(2),
 					event: SysEvent::ExtrinsicSuccess(
 						DispatchInfo {
-							weight: 1000,
+							weight: Weight::from_ref_time(1000),
 							.. Default::default()
 						},
 					).into(),
@@ -298,7 +299,7 @@ fn deposit_event_uses_actual_weight() {
 					event: SysEvent::ExtrinsicFailed(
 						DispatchError::BadOrigin.into(),
 						DispatchInfo {
-							weight: 999,
+							weight: Weight::from_ref_time(999),
 							.. Default::default()
 						},
 					).into(),
@@ -374,6 +375,40 @@ fn deposit_event_topics() {
 	});
 }
 
+#[test]
+fn events_of_rolled_back_storage_layers_are_discarded() {
+	new_test_ext().execute_with(|| {
+		System::initialize(
+			&1,
+			&[0u8; 32].into(),
+			&Default::default(),
+			InitKind::Full,
+		);
+		System::note_finished_initialize();
+
+		let _ = with_storage_layer(|| {
+			System::deposit_event(SysEvent::NewAccount(1));
+			Err::<(), _>(DispatchError::BadOrigin)
+		});
+		assert_ok!(with_storage_layer(|| {
+			System::deposit_event(SysEvent::NewAccount(2));
+			Ok::<_, DispatchError>(())
+		}));
+
+		assert_eq!(
+			System::events(),
+			vec![
+				EventRecord {
+					phase: Phase::ApplyExtrinsic(0),
+					event: SysEvent::NewAccount(2).into(),
+					topics: vec![],
+				},
+			]
+		);
+		assert_eq!(System::event_count(), 1);
+	});
+}
+
 #[test]
 fn event_util_functions_should_work() {
 	new_test_ext().execute_with(|| {
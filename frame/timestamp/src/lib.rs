@@ -184,6 +184,7 @@ pub mod pallet {
 			T::WeightInfo::set(),
 			DispatchClass::Mandatory
 		))]
+		#[pallet::inherent]
 		pub(super) fn set(origin: OriginFor<T>, #[pallet::compact] now: T::Moment) -> DispatchResult {
 			ensure_none(origin)?;
 			assert!(!DidUpdate::<T>::exists(), "Timestamp must be updated only once in the block");
@@ -203,7 +204,6 @@ pub mod pallet {
 
 	#[pallet::inherent]
 	impl<T: Config> ProvideInherent for Pallet<T> {
-		type Call = Call<T>;
 		type Error = InherentError;
 		const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
 
@@ -239,10 +239,6 @@ pub mod pallet {
 				Ok(())
 			}
 		}
-
-		fn is_inherent(call: &Self::Call) -> bool {
-			matches!(call, Call::set(_))
-		}
 	}
 }
 
@@ -323,7 +319,9 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(
+				frame_support::weights::Weight::from_ref_time(1024),
+			);
 	}
 	impl frame_system::Config for Test {
 		type BaseCallFilter = ();
@@ -21,14 +21,33 @@
 
 use codec::Codec;
 use sp_runtime::traits::MaybeDisplay;
+use frame_support::{sp_std::vec::Vec, weights::{OldWeight, Weight}};
 
-pub use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use pallet_transaction_payment::{
+	CallArgumentDescription, CallDescription, FeeDetails, InclusionFee, RuntimeDispatchInfo,
+};
 
 sp_api::decl_runtime_apis! {
+	#[api_version(4)]
 	pub trait TransactionPaymentApi<Balance> where
 		Balance: Codec + MaybeDisplay,
 	{
+		/// Returns the dispatch info of `uxt`, with the weight made only of the computation time.
+		#[changed_in(3)]
+		fn query_info(uxt: Block::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance, OldWeight>;
 		fn query_info(uxt: Block::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance>;
 		fn query_fee_details(uxt: Block::Extrinsic, len: u32) -> FeeDetails<Balance>;
+		/// Returns the fee details of `uxt` with `tip` added on top of the inclusion fee.
+		fn query_fee_details_with_tip(
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+		) -> FeeDetails<Balance>;
+		/// Returns the fee of `weight`, as computed by the `WeightToFee` of the runtime.
+		fn query_weight_to_fee(weight: Weight) -> Balance;
+		/// Decodes an encoded runtime call and describes its structure.
+		///
+		/// Returns `None` if `call` is not the encoding of a runtime call.
+		fn decode_call(call: Vec<u8>) -> Option<CallDescription>;
 	}
 }
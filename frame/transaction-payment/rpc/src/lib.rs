@@ -18,16 +18,20 @@
 //! RPC interface for the transaction payment module.
 
 use std::sync::Arc;
-use std::convert::TryInto;
-use codec::{Codec, Decode};
+use std::convert::{TryFrom, TryInto};
+use codec::{Codec, Compact, Decode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sp_blockchain::HeaderBackend;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 use sp_runtime::{generic::BlockId, traits::{Block as BlockT, MaybeDisplay}};
-use sp_api::ProvideRuntimeApi;
-use sp_core::Bytes;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_core::{Bytes, U256};
 use sp_rpc::number::NumberOrHex;
-use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+use pallet_transaction_payment_rpc_runtime_api::{
+	CallDescription, FeeDetails, InclusionFee, RuntimeDispatchInfo,
+};
 pub use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi as TransactionPaymentRuntimeApi;
 pub use self::gen_client::Client as TransactionPaymentClient;
 
@@ -39,12 +43,225 @@ pub trait TransactionPaymentApi<BlockHash, ResponseType> {
 		encoded_xt: Bytes,
 		at: Option<BlockHash>
 	) -> Result<ResponseType>;
+	/// Query the fee details of an extrinsic, with the `tip` added on top of the inclusion fee.
 	#[rpc(name = "payment_queryFeeDetails")]
 	fn query_fee_details(
 		&self,
 		encoded_xt: Bytes,
-		at: Option<BlockHash>
+		at: Option<BlockHash>,
+		tip: Option<NumberOrHex>,
 	) -> Result<FeeDetails<NumberOrHex>>;
+	#[rpc(name = "payment_decodeCall")]
+	fn decode_call(
+		&self,
+		encoded_call: Bytes,
+		at: Option<BlockHash>
+	) -> Result<DecodedCall>;
+}
+
+/// A runtime call, decoded for display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCall {
+	/// Name of the pallet to which the function belongs.
+	pub pallet: String,
+	/// Name of the function.
+	pub function: String,
+	/// The arguments of the function.
+	pub args: Vec<DecodedCallArgument>,
+}
+
+/// An argument of a runtime call, decoded for display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCallArgument {
+	/// Name of the argument.
+	pub name: String,
+	/// Name of the type of the argument, as found in the metadata.
+	#[serde(rename = "type")]
+	pub ty: String,
+	/// The value of the argument.
+	///
+	/// Booleans, integers, balances, byte vectors and the sequences, options and tuples of them
+	/// are decoded, the SCALE encoding of the other values is given as a hex string.
+	pub value: Value,
+}
+
+impl DecodedCall {
+	/// Describe `call` for display, the arguments of the balance type of the runtime are decoded
+	/// as `Balance`.
+	pub fn new<Balance: Decode + TryInto<NumberOrHex>>(call: CallDescription) -> Self {
+		let string = |bytes: Vec<u8>| String::from_utf8_lossy(&bytes).into_owned();
+		DecodedCall {
+			pallet: string(call.pallet),
+			function: string(call.function),
+			args: call.arguments.into_iter().map(|argument| {
+				let ty = string(argument.ty);
+				DecodedCallArgument {
+					value: decode_value::<Balance>(&ty, &argument.value),
+					name: string(argument.name),
+					ty,
+				}
+			}).collect(),
+		}
+	}
+}
+
+/// The names of the balance type of the runtime in the metadata.
+const BALANCE_TYPES: &[&str] = &["Balance", "T::Balance", "BalanceOf<T>", "BalanceOf<T,I>"];
+
+/// Decode the SCALE encoded `value` into JSON, as the type `ty` named in the metadata.
+///
+/// The primitive types and the balances are decoded, as well as the `Compact`, `Vec`, `Option`,
+/// tuples and arrays of them. The values of the other types, or which are not exactly an encoding
+/// of their type, are given as a hex string.
+fn decode_value<Balance: Decode + TryInto<NumberOrHex>>(ty: &str, value: &[u8]) -> Value {
+	let ty = ty.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+	let mut input = value;
+	match decode_type::<Balance>(&ty, &mut input) {
+		Some(decoded) if input.is_empty() => decoded,
+		_ => hex(value),
+	}
+}
+
+/// Decode a value of the type `ty`, without whitespaces, from the beginning of `input`.
+fn decode_type<Balance: Decode + TryInto<NumberOrHex>>(
+	ty: &str,
+	input: &mut &[u8],
+) -> Option<Value> {
+	fn decode<T: Decode>(input: &mut &[u8]) -> Option<T> {
+		T::decode(input).ok()
+	}
+	// Decode `len` values of the type `ty`, each value is at least a byte long.
+	fn decode_sequence<Balance: Decode + TryInto<NumberOrHex>>(
+		ty: &str,
+		len: usize,
+		input: &mut &[u8],
+	) -> Option<Value> {
+		if len > input.len() {
+			return None
+		}
+		(0..len)
+			.map(|_| decode_type::<Balance>(ty, input))
+			.collect::<Option<Vec<_>>>()
+			.map(Value::from)
+	}
+	// Take the `len` next bytes of `input`.
+	fn take<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+		if len > input.len() {
+			return None
+		}
+		let (bytes, rest) = (*input).split_at(len);
+		*input = rest;
+		Some(bytes)
+	}
+
+	if let Some(ty) = generic_argument(ty, "Compact") {
+		return match ty {
+			"u8" => decode::<Compact<u8>>(input).map(|n| number(n.0)),
+			"u16" => decode::<Compact<u16>>(input).map(|n| number(n.0)),
+			"u32" => decode::<Compact<u32>>(input).map(|n| number(n.0)),
+			"u64" => decode::<Compact<u64>>(input).map(|n| number(n.0)),
+			_ => decode::<Compact<u128>>(input).map(|n| number(n.0)),
+		}
+	}
+	if let Some(ty) = generic_argument(ty, "Vec") {
+		let len = decode::<Compact<u32>>(input)?.0 as usize;
+		return match ty {
+			"u8" => take(input, len).map(hex),
+			_ => decode_sequence::<Balance>(ty, len, input),
+		}
+	}
+	if let Some(ty) = generic_argument(ty, "Option") {
+		return match decode::<u8>(input)? {
+			0 => Some(Value::Null),
+			1 => decode_type::<Balance>(ty, input),
+			_ => None,
+		}
+	}
+	if let Some(ty) = generic_argument(ty, "Box") {
+		return decode_type::<Balance>(ty, input)
+	}
+	if let Some(types) = ty.strip_prefix('(').and_then(|ty| ty.strip_suffix(')')) {
+		if types.is_empty() {
+			return Some(Value::Null)
+		}
+		return split_top_level(types, ',')
+			.into_iter()
+			.filter(|ty| !ty.is_empty())
+			.map(|ty| decode_type::<Balance>(ty, input))
+			.collect::<Option<Vec<_>>>()
+			.map(Value::from)
+	}
+	if let Some(array) = ty.strip_prefix('[').and_then(|ty| ty.strip_suffix(']')) {
+		let (ty, len) = match split_top_level(array, ';')[..] {
+			[ty, len] => (ty, len.parse::<usize>().ok()?),
+			_ => return None,
+		};
+		return match ty {
+			"u8" => take(input, len).map(hex),
+			_ => decode_sequence::<Balance>(ty, len, input),
+		}
+	}
+
+	match ty {
+		"bool" => decode::<bool>(input).map(Value::from),
+		"u8" => decode::<u8>(input).map(number),
+		"u16" => decode::<u16>(input).map(number),
+		"u32" => decode::<u32>(input).map(number),
+		"u64" => decode::<u64>(input).map(number),
+		"u128" => decode::<u128>(input).map(number),
+		"i8" => decode::<i8>(input).map(Value::from),
+		"i16" => decode::<i16>(input).map(Value::from),
+		"i32" => decode::<i32>(input).map(Value::from),
+		"i64" => decode::<i64>(input).map(Value::from),
+		ty if BALANCE_TYPES.contains(&ty) => {
+			let balance = decode::<Balance>(input)?.try_into().ok()?.into_u256();
+			// Balances which would lose precision in JSON are given as strings.
+			match balance <= U256::from(u64::MAX) {
+				true => Some(Value::from(balance.low_u64())),
+				false => Some(Value::from(balance.to_string())),
+			}
+		},
+		_ => None,
+	}
+}
+
+/// The argument of the generic type `name` in `ty`, e.g. `u8` for `Vec` in `Vec<u8>`.
+fn generic_argument<'a>(ty: &'a str, name: &str) -> Option<&'a str> {
+	ty.strip_prefix(name)?.strip_prefix('<')?.strip_suffix('>')
+}
+
+/// Split `ty` at the `separator`s which are not nested in a generic type, a tuple or an array.
+fn split_top_level(ty: &str, separator: char) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let (mut depth, mut start) = (0i32, 0);
+	for (i, c) in ty.char_indices() {
+		match c {
+			'<' | '(' | '[' => depth += 1,
+			'>' | ')' | ']' => depth -= 1,
+			c if c == separator && depth == 0 => {
+				parts.push(&ty[start..i]);
+				start = i + 1;
+			},
+			_ => {},
+		}
+	}
+	parts.push(&ty[start..]);
+	parts
+}
+
+fn number<T: Into<u128>>(n: T) -> Value {
+	let n = n.into();
+	// Numbers which would lose precision in JSON are given as strings.
+	match n <= u64::MAX as u128 {
+		true => Value::from(n as u64),
+		false => Value::from(n.to_string()),
+	}
+}
+
+fn hex(bytes: &[u8]) -> Value {
+	Value::from(sp_core::bytes::to_hex(bytes, false))
 }
 
 /// A struct that implements the [`TransactionPaymentApi`].
@@ -85,7 +302,7 @@ where
 	Block: BlockT,
 	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
 	C::Api: TransactionPaymentRuntimeApi<Block, Balance>,
-	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex>,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + TryFrom<NumberOrHex>,
 {
 	fn query_info(
 		&self,
@@ -105,17 +322,33 @@ where
 			message: "Unable to query dispatch info.".into(),
 			data: Some(format!("{:?}", e).into()),
 		})?;
-		api.query_info(&at, uxt, encoded_len).map_err(|e| RpcError {
+		let runtime_error = |e: sp_api::ApiError| RpcError {
 			code: ErrorCode::ServerError(Error::RuntimeError.into()),
 			message: "Unable to query dispatch info.".into(),
 			data: Some(format!("{:?}", e).into()),
-		})
+		};
+
+		let has_v3 = api
+			.has_api_with::<dyn TransactionPaymentRuntimeApi<Block, Balance>, _>(&at, |v| v >= 3)
+			.map_err(runtime_error)?;
+		if has_v3 {
+			api.query_info(&at, uxt, encoded_len)
+		} else {
+			// The runtimes before the version 3 of the API give the weight as an `OldWeight`.
+			#[allow(deprecated)]
+			api.query_info_before_version_3(&at, uxt, encoded_len).map(|info| RuntimeDispatchInfo {
+				weight: info.weight.into(),
+				class: info.class,
+				partial_fee: info.partial_fee,
+			})
+		}.map_err(runtime_error)
 	}
 
 	fn query_fee_details(
 		&self,
 		encoded_xt: Bytes,
 		at: Option<<Block as BlockT>::Hash>,
+		tip: Option<NumberOrHex>,
 	) -> Result<FeeDetails<NumberOrHex>> {
 		let api = self.client.runtime_api();
 		let at = BlockId::hash(at.unwrap_or_else(||
@@ -130,11 +363,36 @@ where
 			message: "Unable to query fee details.".into(),
 			data: Some(format!("{:?}", e).into()),
 		})?;
-		let fee_details = api.query_fee_details(&at, uxt, encoded_len).map_err(|e| RpcError {
+		let runtime_error = |e: sp_api::ApiError| RpcError {
 			code: ErrorCode::ServerError(Error::RuntimeError.into()),
 			message: "Unable to query fee details.".into(),
 			data: Some(format!("{:?}", e).into()),
-		})?;
+		};
+		let fee_details = match tip {
+			Some(tip) => {
+				let tip = Balance::try_from(tip).map_err(|_| RpcError {
+					code: ErrorCode::InvalidParams,
+					message: format!("The tip {:?} doesn't fit in the balance type", tip),
+					data: None,
+				})?;
+				let supports_tip = api
+					.has_api_with::<dyn TransactionPaymentRuntimeApi<Block, Balance>, _>(
+						&at,
+						|version| version >= 4,
+					)
+					.map_err(runtime_error)?;
+				if !supports_tip {
+					return Err(RpcError {
+						code: ErrorCode::ServerError(Error::RuntimeError.into()),
+						message: "The runtime doesn't support querying the fee details with a tip."
+							.into(),
+						data: None,
+					})
+				}
+				api.query_fee_details_with_tip(&at, uxt, encoded_len, tip)
+			},
+			None => api.query_fee_details(&at, uxt, encoded_len),
+		}.map_err(runtime_error)?;
 
 		let try_into_rpc_balance = |value: Balance| value.try_into().map_err(|_| RpcError {
 			code: ErrorCode::InvalidParams,
@@ -152,7 +410,107 @@ where
 			} else {
 				None
 			},
-			tip: Default::default(),
+			tip: try_into_rpc_balance(fee_details.tip)?,
 		})
 	}
+
+	fn decode_call(
+		&self,
+		encoded_call: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<DecodedCall> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		let call = api.decode_call(&at, encoded_call.to_vec()).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to decode call.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		call.map(DecodedCall::new::<Balance>).ok_or_else(|| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to decode call.".into(),
+			data: Some("The bytes are not the encoding of a runtime call".into()),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use serde_json::json;
+
+	fn decode_value(ty: &str, value: &[u8]) -> Value {
+		super::decode_value::<u128>(ty, value)
+	}
+
+	#[test]
+	fn well_known_values_are_decoded() {
+		assert_eq!(decode_value("bool", &true.encode()), json!(true));
+		assert_eq!(decode_value("u32", &7u32.encode()), json!(7));
+		assert_eq!(decode_value("i32", &(-7i32).encode()), json!(-7));
+		assert_eq!(decode_value("u128", &u128::MAX.encode()), json!(u128::MAX.to_string()));
+		assert_eq!(decode_value("Compact<T::Balance>", &Compact(69u128).encode()), json!(69));
+		assert_eq!(decode_value("Vec<u8>", &vec![1u8, 2].encode()), json!("0x0102"));
+		assert_eq!(decode_value("T::AccountId", &[3u8, 4]), json!("0x0304"));
+		// Values which cannot be decoded as their type are given as hex.
+		assert_eq!(decode_value("u32", &[1u8, 2]), json!("0x0102"));
+		assert_eq!(decode_value("bool", &[1u8, 0]), json!("0x0100"));
+	}
+
+	#[test]
+	fn balances_are_decoded_as_the_runtime_balance() {
+		assert_eq!(decode_value("BalanceOf<T>", &69u128.encode()), json!(69));
+		assert_eq!(
+			decode_value("BalanceOf<T, I>", &u128::MAX.encode()),
+			json!(u128::MAX.to_string()),
+		);
+		assert_eq!(super::decode_value::<u64>("T::Balance", &69u64.encode()), json!(69));
+		// The balance is not a `u64` in this runtime.
+		assert_eq!(decode_value("T::Balance", &69u64.encode()), json!("0x4500000000000000"));
+	}
+
+	#[test]
+	fn composite_values_are_decoded() {
+		assert_eq!(decode_value("Vec<u32>", &vec![1u32, 2].encode()), json!([1, 2]));
+		assert_eq!(decode_value("Option<bool>", &Some(true).encode()), json!(true));
+		assert_eq!(decode_value("Option<bool>", &None::<bool>.encode()), json!(null));
+		assert_eq!(decode_value("Box<u16>", &7u16.encode()), json!(7));
+		assert_eq!(decode_value("(u8, Vec<u8>)", &(1u8, vec![2u8]).encode()), json!([1, "0x02"]));
+		assert_eq!(decode_value("[u8; 2]", &[1u8, 2].encode()), json!("0x0102"));
+		assert_eq!(decode_value("[u16; 2]", &[1u16, 2].encode()), json!([1, 2]));
+		assert_eq!(
+			decode_value("Vec<(T::AccountId, u32)>", &vec![([1u8; 2], 3u32)].encode()),
+			json!("0x04010103000000"),
+		);
+		// A sequence longer than its encoding is not decoded.
+		assert_eq!(decode_value("Vec<()>", &Compact(u32::MAX).encode()), json!("0x03ffffffff"));
+	}
+
+	#[test]
+	fn call_description_is_converted() {
+		let call = CallDescription {
+			pallet: b"Balances".to_vec(),
+			function: b"transfer".to_vec(),
+			arguments: vec![
+				pallet_transaction_payment_rpc_runtime_api::CallArgumentDescription {
+					name: b"value".to_vec(),
+					ty: b"Compact<T::Balance>".to_vec(),
+					value: Compact(69u64).encode(),
+				},
+			],
+		};
+		assert_eq!(
+			serde_json::to_value(DecodedCall::new::<u64>(call)).unwrap(),
+			json!({
+				"pallet": "Balances",
+				"function": "transfer",
+				"args": [{ "name": "value", "type": "Compact<T::Balance>", "value": 69 }],
+			}),
+		);
+	}
 }
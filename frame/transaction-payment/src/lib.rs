@@ -48,10 +48,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
-use codec::{Encode, Decode};
+use codec::{Encode, Decode, DecodeLimit};
 use frame_support::{
 	decl_storage, decl_module,
-	traits::Get,
+	traits::{Get, GetCallArguments, GetCallMetadata},
 	weights::{
 		Weight, DispatchInfo, PostDispatchInfo, GetDispatchInfo, Pays, WeightToFeePolynomial,
 		WeightToFeeCoefficient, DispatchClass,
@@ -73,11 +73,16 @@ mod payment;
 mod types;
 
 pub use payment::*;
-pub use types::{InclusionFee, FeeDetails, RuntimeDispatchInfo};
+pub use types::{
+	InclusionFee, FeeDetails, RuntimeDispatchInfo, CallDescription, CallArgumentDescription,
+};
 
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
 
+/// The maximum nesting depth of the calls decoded by [`Module::decode_call`].
+const MAX_CALL_DEPTH: u32 = 256;
+
 type BalanceOf<T> =
 	<<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
 
@@ -175,13 +180,15 @@ impl<T, S, V, M> Convert<Multiplier, Multiplier> for TargetedFeeAdjustment<T, S,
 		let previous = previous.max(min_multiplier);
 
 		let weights = T::BlockWeights::get();
-		// the computed ratio is only among the normal class.
+		// the computed ratio is only among the normal class, and only uses the reference time.
 		let normal_max_weight = weights.get(DispatchClass::Normal).max_total
-			.unwrap_or_else(|| weights.max_block);
+			.unwrap_or_else(|| weights.max_block)
+			.ref_time();
 		let current_block_weight = <frame_system::Pallet<T>>::block_weight();
-		let normal_block_weight = *current_block_weight
+		let normal_block_weight = current_block_weight
 			.get(DispatchClass::Normal)
-			.min(&normal_max_weight);
+			.ref_time()
+			.min(normal_max_weight);
 
 		let s = S::get();
 		let v = V::get();
@@ -272,14 +279,14 @@ decl_module! {
 		}
 
 		fn integrity_test() {
-			// given weight == u64, we build multipliers from `diff` of two weight values, which can
-			// at most be maximum block weight. Make sure that this can fit in a multiplier without
-			// loss.
+			// given ref time == u64, we build multipliers from `diff` of two ref time values, which
+			// can at most be the ref time of the maximum block weight. Make sure that this can fit in
+			// a multiplier without loss.
 			use sp_std::convert::TryInto;
 			assert!(
 				<Multiplier as sp_runtime::traits::Bounded>::max_value() >=
 				Multiplier::checked_from_integer(
-					T::BlockWeights::get().max_block.try_into().unwrap()
+					T::BlockWeights::get().max_block.ref_time().try_into().unwrap()
 				).unwrap(),
 			);
 
@@ -346,15 +353,29 @@ impl<T: Config> Module<T> where
 	}
 
 	/// Query the detailed fee of a given `call`.
+	///
+	/// Like [`Self::query_info`], this doesn't account for the tip of the extrinsic.
 	pub fn query_fee_details<Extrinsic: GetDispatchInfo>(
 		unchecked_extrinsic: Extrinsic,
 		len: u32,
 	) -> FeeDetails<BalanceOf<T>>
+	where
+		T::Call: Dispatchable<Info=DispatchInfo>,
+	{
+		Self::query_fee_details_with_tip(unchecked_extrinsic, len, 0u32.into())
+	}
+
+	/// Query the detailed fee of a given `call`, with `tip` added on top of the inclusion fee.
+	pub fn query_fee_details_with_tip<Extrinsic: GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		tip: BalanceOf<T>,
+	) -> FeeDetails<BalanceOf<T>>
 	where
 		T::Call: Dispatchable<Info=DispatchInfo>,
 	{
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
-		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
+		Self::compute_fee_details(len, &dispatch_info, tip)
 	}
 
 	/// Compute the final fee value for a particular transaction.
@@ -449,6 +470,38 @@ impl<T: Config> Module<T> where
 		}
 	}
 
+	/// Query the fee of `weight`, as computed by [`Config::WeightToFee`].
+	///
+	/// The weight is capped to the maximum weight of a block.
+	pub fn query_weight_to_fee(weight: Weight) -> BalanceOf<T> {
+		Self::weight_to_fee(weight)
+	}
+
+	/// Decode `encoded_call` and describe its structure.
+	///
+	/// Returns `None` if `encoded_call` is not exactly the encoding of a `Call`.
+	pub fn decode_call<Call>(encoded_call: &[u8]) -> Option<CallDescription>
+	where
+		Call: Decode + GetCallMetadata + GetCallArguments,
+	{
+		let call = Call::decode_all_with_depth_limit(MAX_CALL_DEPTH, encoded_call).ok()?;
+		let metadata = call.get_call_metadata();
+		let arguments = call.get_call_arguments()
+			.into_iter()
+			.map(|argument| CallArgumentDescription {
+				name: argument.name.as_bytes().to_vec(),
+				ty: argument.ty.as_bytes().to_vec(),
+				value: argument.value,
+			})
+			.collect();
+
+		Some(CallDescription {
+			pallet: metadata.pallet_name.as_bytes().to_vec(),
+			function: metadata.function_name.as_bytes().to_vec(),
+			arguments,
+		})
+	}
+
 	fn weight_to_fee(weight: Weight) -> BalanceOf<T> {
 		// cap the weight to the maximum defined in runtime, otherwise it will be the
 		// `Bounded` maximum of its data type, which is not desired.
@@ -516,7 +569,8 @@ impl<T: Config> ChargeTransactionPayment<T> where
 	///  that the transaction which consumes more resources (either length or weight) with the same
 	/// `fee` ends up having lower priority.
 	fn get_priority(len: usize, info: &DispatchInfoOf<T::Call>, final_fee: BalanceOf<T>) -> TransactionPriority {
-		let weight_saturation = T::BlockWeights::get().max_block / info.weight.max(1);
+		let weight_saturation =
+			T::BlockWeights::get().max_block.ref_time() / info.weight.ref_time().max(1);
 		let max_block_length = *T::BlockLength::get().max.get(DispatchClass::Normal);
 		let len_saturation = max_block_length as u64 / (len as u64).max(1);
 		let coefficient: BalanceOf<T> = weight_saturation.min(len_saturation).saturated_into::<BalanceOf<T>>();
@@ -648,12 +702,13 @@ mod tests {
 	impl Get<frame_system::limits::BlockWeights> for BlockWeights {
 		fn get() -> frame_system::limits::BlockWeights {
 			frame_system::limits::BlockWeights::builder()
-				.base_block(0)
+				.base_block(Weight::zero())
 				.for_class(DispatchClass::all(), |weights| {
-					weights.base_extrinsic = EXTRINSIC_BASE_WEIGHT.with(|v| *v.borrow()).into();
+					weights.base_extrinsic =
+						Weight::from_ref_time(EXTRINSIC_BASE_WEIGHT.with(|v| *v.borrow()));
 				})
 				.for_class(DispatchClass::non_mandatory(), |weights| {
-					weights.max_total = 1024.into();
+					weights.max_total = Some(Weight::from_ref_time(1024));
 				})
 				.build_or_panic()
 		}
@@ -704,6 +759,7 @@ mod tests {
 		type MaxLocks = ();
 		type MaxReserves = ();
 		type ReserveIdentifier = [u8; 8];
+		type HoldsAndFreezes = ();
 		type WeightInfo = ();
 	}
 
@@ -808,14 +864,14 @@ mod tests {
 	}
 
 	/// create a transaction info struct from weight. Handy to avoid building the whole struct.
-	pub fn info_from_weight(w: Weight) -> DispatchInfo {
+	pub fn info_from_weight(w: u64) -> DispatchInfo {
 		// pays_fee: Pays::Yes -- class: DispatchClass::Normal
-		DispatchInfo { weight: w, ..Default::default() }
+		DispatchInfo { weight: Weight::from_ref_time(w), ..Default::default() }
 	}
 
-	fn post_info_from_weight(w: Weight) -> PostDispatchInfo {
+	fn post_info_from_weight(w: u64) -> PostDispatchInfo {
 		PostDispatchInfo {
-			actual_weight: Some(w),
+			actual_weight: Some(Weight::from_ref_time(w)),
 			pays_fee: Default::default(),
 		}
 	}
@@ -910,12 +966,12 @@ mod tests {
 			// maximum weight possible
 			assert_ok!(
 				ChargeTransactionPayment::<Runtime>::from(0)
-					.pre_dispatch(&1, CALL, &info_from_weight(Weight::max_value()), 10)
+					.pre_dispatch(&1, CALL, &info_from_weight(u64::max_value()), 10)
 			);
 			// fee will be proportional to what is the actual maximum weight in the runtime.
 			assert_eq!(
 				Balances::free_balance(&1),
-				(10000 - <Runtime as frame_system::Config>::BlockWeights::get().max_block) as u64
+				10000 - <Runtime as frame_system::Config>::BlockWeights::get().max_block.ref_time()
 			);
 		});
 	}
@@ -935,7 +991,7 @@ mod tests {
 
 			// This is a completely free (and thus wholly insecure/DoS-ridden) transaction.
 			let operational_transaction = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::No,
 			};
@@ -946,7 +1002,7 @@ mod tests {
 
 			// like a InsecureFreeNormal
 			let free_transaction = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Normal,
 				pays_fee: Pays::Yes,
 			};
@@ -1011,13 +1067,93 @@ mod tests {
 					partial_fee:
 						5 * 2 /* base * weight_fee */
 						+ len as u64  /* len * 1 */
-						+ info.weight.min(BlockWeights::get().max_block) as u64 * 2 * 3 / 2 /* weight */
+						+ info.weight.min(BlockWeights::get().max_block).ref_time()
+							* 2 * 3 / 2 /* weight */
 				},
 			);
 
 		});
 	}
 
+	#[test]
+	fn query_fee_details_works() {
+		let call = Call::Balances(BalancesCall::transfer(2, 69));
+		let xt = TestXt::new(call, Some((111111, ())));
+		let info  = xt.get_dispatch_info();
+		let len = xt.encode().len() as u32;
+		ExtBuilder::default()
+			.base_weight(5)
+			.weight_fee(2)
+			.build()
+			.execute_with(||
+		{
+			// all fees should be x1.5
+			NextFeeMultiplier::put(Multiplier::saturating_from_rational(3, 2));
+
+			let inclusion_fee = InclusionFee {
+				base_fee: 5 * 2,
+				len_fee: len as u64,
+				adjusted_weight_fee:
+					info.weight.min(BlockWeights::get().max_block).ref_time() * 2 * 3 / 2,
+			};
+			assert_eq!(
+				TransactionPayment::query_fee_details(xt.clone(), len),
+				FeeDetails { inclusion_fee: Some(inclusion_fee.clone()), tip: 0 },
+			);
+
+			let details = TransactionPayment::query_fee_details_with_tip(xt.clone(), len, 7);
+			assert_eq!(details, FeeDetails { inclusion_fee: Some(inclusion_fee), tip: 7 });
+			assert_eq!(
+				details.final_fee(),
+				TransactionPayment::query_info(xt, len).partial_fee + 7,
+			);
+		});
+	}
+
+	#[test]
+	fn query_weight_to_fee_works() {
+		ExtBuilder::default()
+			.weight_fee(3)
+			.build()
+			.execute_with(||
+		{
+			assert_eq!(TransactionPayment::query_weight_to_fee(Weight::zero()), 0);
+			assert_eq!(TransactionPayment::query_weight_to_fee(Weight::from_ref_time(100)), 300);
+			// the weight is capped to the maximum weight of a block.
+			let max_block = BlockWeights::get().max_block;
+			assert_eq!(
+				TransactionPayment::query_weight_to_fee(max_block + Weight::from_ref_time(1)),
+				max_block.ref_time() * 3,
+			);
+		});
+	}
+
+	#[test]
+	fn decode_call_works() {
+		let encoded = CALL.encode();
+		let description = TransactionPayment::decode_call::<Call>(&encoded).unwrap();
+		assert_eq!(description.pallet, b"Balances".to_vec());
+		assert_eq!(description.function, b"transfer".to_vec());
+		assert_eq!(description.arguments, vec![
+			CallArgumentDescription {
+				name: b"dest".to_vec(),
+				ty: b"<T::Lookup as StaticLookup>::Source".to_vec(),
+				value: 2u64.encode(),
+			},
+			CallArgumentDescription {
+				name: b"value".to_vec(),
+				ty: b"Compact<T::Balance>".to_vec(),
+				value: codec::Compact(69u64).encode(),
+			},
+		]);
+
+		// Trailing bytes are not allowed.
+		let mut encoded = encoded;
+		encoded.push(0);
+		assert_eq!(TransactionPayment::decode_call::<Call>(&encoded), None);
+		assert_eq!(TransactionPayment::decode_call::<Call>(&[0xff]), None);
+	}
+
 	#[test]
 	fn compute_fee_works_without_multiplier() {
 		ExtBuilder::default()
@@ -1032,14 +1168,14 @@ mod tests {
 
 			// Tip only, no fees works
 			let dispatch_info = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::No,
 			};
 			assert_eq!(Module::<Runtime>::compute_fee(0, &dispatch_info, 10), 10);
 			// No tip, only base fee works
 			let dispatch_info = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1050,7 +1186,7 @@ mod tests {
 			assert_eq!(Module::<Runtime>::compute_fee(42, &dispatch_info, 0), 520);
 			// Weight fee + base fee works
 			let dispatch_info = DispatchInfo {
-				weight: 1000,
+				weight: Weight::from_ref_time(1000),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1071,7 +1207,7 @@ mod tests {
 			NextFeeMultiplier::put(Multiplier::saturating_from_rational(3, 2));
 			// Base fee is unaffected by multiplier
 			let dispatch_info = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1079,7 +1215,7 @@ mod tests {
 
 			// Everything works together :)
 			let dispatch_info = DispatchInfo {
-				weight: 123,
+				weight: Weight::from_ref_time(123),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1105,7 +1241,7 @@ mod tests {
 
 			// Base fee is unaffected by multiplier.
 			let dispatch_info = DispatchInfo {
-				weight: 0,
+				weight: Weight::zero(),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1113,7 +1249,7 @@ mod tests {
 
 			// Everything works together.
 			let dispatch_info = DispatchInfo {
-				weight: 123,
+				weight: Weight::from_ref_time(123),
 				class: DispatchClass::Operational,
 				pays_fee: Pays::Yes,
 			};
@@ -1217,7 +1353,7 @@ mod tests {
 			System::set_block_number(10);
 			let len = 10;
 			let dispatch_info = DispatchInfo {
-				weight: 100,
+				weight: Weight::from_ref_time(100),
 				pays_fee: Pays::No,
 				class: DispatchClass::Normal,
 			};
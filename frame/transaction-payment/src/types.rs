@@ -18,11 +18,11 @@
 //! Types for transaction-payment RPC.
 
 use sp_std::prelude::*;
-use frame_support::weights::{Weight, DispatchClass};
+use frame_support::weights::DispatchClass;
 use codec::{Encode, Decode};
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
-use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_runtime::{RuntimeDebug, traits::{AtLeast32BitUnsigned, Zero}};
 
 /// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
 #[derive(Encode, Decode, Clone, Eq, PartialEq)]
@@ -66,8 +66,7 @@ impl<Balance: AtLeast32BitUnsigned + Copy> InclusionFee<Balance> {
 pub struct FeeDetails<Balance> {
 	/// The minimum fee for a transaction to be included in a block.
 	pub inclusion_fee: Option<InclusionFee<Balance>>,
-	// Do not serialize and deserialize `tip` as we actually can not pass any tip to the RPC.
-	#[cfg_attr(feature = "std", serde(skip))]
+	/// The tip added on top of the inclusion fee.
 	pub tip: Balance,
 }
 
@@ -86,10 +85,20 @@ impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
-#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
-#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
-pub struct RuntimeDispatchInfo<Balance> {
+#[cfg_attr(
+	feature = "std",
+	serde(bound(serialize = "Balance: std::fmt::Display, Weight: Serialize")),
+)]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(deserialize = "Balance: std::str::FromStr, Weight: Deserialize<'de>")),
+)]
+pub struct RuntimeDispatchInfo<Balance, Weight = frame_support::weights::Weight> {
 	/// Weight of this dispatch.
+	///
+	/// The previous versions of the runtime API give an [`OldWeight`] instead.
+	///
+	/// [`OldWeight`]: frame_support::weights::OldWeight
 	pub weight: Weight,
 	/// Class of this dispatch.
 	pub class: DispatchClass,
@@ -101,6 +110,28 @@ pub struct RuntimeDispatchInfo<Balance> {
 	pub partial_fee: Balance,
 }
 
+/// An argument of a call decoded by the runtime.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct CallArgumentDescription {
+	/// Name of the argument.
+	pub name: Vec<u8>,
+	/// Name of the type of the argument, as found in the metadata.
+	pub ty: Vec<u8>,
+	/// SCALE encoded value of the argument.
+	pub value: Vec<u8>,
+}
+
+/// The structure of a call decoded by the runtime.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct CallDescription {
+	/// Name of the pallet to which the function belongs.
+	pub pallet: Vec<u8>,
+	/// Name of the function.
+	pub function: Vec<u8>,
+	/// The arguments of the function, in the order in which they are encoded.
+	pub arguments: Vec<CallArgumentDescription>,
+}
+
 #[cfg(feature = "std")]
 mod serde_balance {
 	use serde::{Deserialize, Serializer, Deserializer};
@@ -118,16 +149,18 @@ mod serde_balance {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use frame_support::weights::Weight;
 
 	#[test]
 	fn should_serialize_and_deserialize_properly_with_string() {
 		let info = RuntimeDispatchInfo {
-			weight: 5,
+			weight: Weight::from_ref_time(5),
 			class: DispatchClass::Normal,
 			partial_fee: 1_000_000_u64,
 		};
 
-		let json_str = r#"{"weight":5,"class":"normal","partialFee":"1000000"}"#;
+		let json_str =
+			r#"{"weight":{"refTime":5,"proofSize":0},"class":"normal","partialFee":"1000000"}"#;
 
 		assert_eq!(serde_json::to_string(&info).unwrap(), json_str);
 		assert_eq!(serde_json::from_str::<RuntimeDispatchInfo<u64>>(json_str).unwrap(), info);
@@ -139,12 +172,12 @@ mod tests {
 	#[test]
 	fn should_serialize_and_deserialize_properly_large_value() {
 		let info = RuntimeDispatchInfo {
-			weight: 5,
+			weight: Weight::from_ref_time(5),
 			class: DispatchClass::Normal,
 			partial_fee: u128::max_value(),
 		};
 
-		let json_str = r#"{"weight":5,"class":"normal","partialFee":"340282366920938463463374607431768211455"}"#;
+		let json_str = r#"{"weight":{"refTime":5,"proofSize":0},"class":"normal","partialFee":"340282366920938463463374607431768211455"}"#;
 
 		assert_eq!(serde_json::to_string(&info).unwrap(), json_str);
 		assert_eq!(serde_json::from_str::<RuntimeDispatchInfo<u128>>(json_str).unwrap(), info);
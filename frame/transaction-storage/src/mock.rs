@@ -90,6 +90,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = ();
+	type HoldsAndFreezes = ();
 }
 
 impl pallet_transaction_storage::Config for Test {
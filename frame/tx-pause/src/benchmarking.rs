@@ -0,0 +1,61 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction pause pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+
+use crate::Pallet as TxPause;
+
+/// The longest names of a call, which is assumed not to be whitelisted.
+fn longest_names<T: Config>() -> (Vec<u8>, Vec<u8>) {
+	let len = T::MaxNameLen::get() as usize;
+	(vec![b'p'; len], vec![b'c'; len])
+}
+
+benchmarks! {
+	pause {
+		let (pallet_name, call_name) = longest_names::<T>();
+		let origin = T::PauseOrigin::successful_origin();
+		let call = Call::<T>::pause(pallet_name.clone(), call_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(TxPause::<T>::is_paused(&pallet_name, &call_name));
+	}
+
+	unpause {
+		let (pallet_name, call_name) = longest_names::<T>();
+		let full_name = TxPause::<T>::full_name(pallet_name.clone(), call_name.clone())?;
+		PausedCalls::<T>::insert(&full_name, ());
+		let origin = T::UnpauseOrigin::successful_origin();
+		let call = Call::<T>::unpause(pallet_name.clone(), call_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!TxPause::<T>::is_paused(&pallet_name, &call_name));
+	}
+}
+
+impl_benchmark_test_suite!(
+	TxPause,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
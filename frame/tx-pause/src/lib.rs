@@ -0,0 +1,246 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Transaction Pause Pallet
+//!
+//! Allows a privileged origin to pause and unpause individual calls of the runtime, identified by
+//! the names of their pallet and of their function.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! [`Config::PauseOrigin`] pauses a call with [`Pallet::pause`] and [`Config::UnpauseOrigin`]
+//! unpauses it with [`Pallet::unpause`]. The calls are identified by the names of the
+//! [`CallMetadata`] of the runtime calls.
+//!
+//! Some calls are never paused:
+//! - the calls of this pallet, so that the paused calls can always be unpaused;
+//! - the calls contained in [`Config::WhitelistedCalls`], which cannot be paused either, e.g. the
+//!   calls needed to dispatch the unpausing with [`Config::UnpauseOrigin`];
+//! - the mandatory calls, e.g. the inherents of the pallets of FRAME, since a block without them
+//!   is invalid. They cannot be identified by their names, so pausing one is accepted but has no
+//!   effect: add the mandatory calls to the whitelist to refuse pausing them.
+//!
+//! The pallet implements [`Contains`] and [`Filter`] for the calls of the runtime, containing the
+//! calls which are not paused, and is meant to be used as, or be part of, the `BaseCallFilter` of
+//! the runtime:
+//!
+//! ```ignore
+//! impl frame_system::Config for Runtime {
+//! 	type BaseCallFilter = InsideBoth<SafeMode, TxPause>;
+//! 	// ...
+//! }
+//! ```
+//!
+//! [`CallMetadata`]: frame_support::traits::CallMetadata
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::{convert::TryInto, prelude::*};
+use frame_support::{
+	traits::{Contains, Filter, GetCallMetadata, PalletInfoAccess},
+	weights::{DispatchClass, GetDispatchInfo},
+	BoundedVec,
+};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+/// The name of a pallet, as stored by the pallet.
+pub type PalletNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The name of a function of a pallet, as stored by the pallet.
+pub type CallNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The names of a pallet and of one of its functions, identifying a call.
+pub type FullNameOf<T> = (PalletNameOf<T>, CallNameOf<T>);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, dispatch::Dispatchable};
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The overarching call type.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin>
+			+ GetCallMetadata
+			+ GetDispatchInfo;
+
+		/// The origin which can pause a call.
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which can unpause a call.
+		type UnpauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The calls which cannot be paused, in addition to the calls of this pallet.
+		type WhitelistedCalls: Contains<FullNameOf<Self>>;
+
+		/// The maximum length of the names of the pallets and of their functions.
+		///
+		/// The calls with longer names cannot be paused.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A call was paused. \[pallet_name, call_name\]
+		CallPaused(PalletNameOf<T>, CallNameOf<T>),
+		/// A call was unpaused. \[pallet_name, call_name\]
+		CallUnpaused(PalletNameOf<T>, CallNameOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call is paused.
+		IsPaused,
+		/// The call is not paused.
+		IsUnpaused,
+		/// The call is a call of this pallet or is whitelisted, and cannot be paused.
+		Unpausable,
+		/// The name of the pallet or of the call is too long.
+		NameTooLong,
+	}
+
+	/// The paused calls, by the names of their pallet and of their function.
+	#[pallet::storage]
+	pub type PausedCalls<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat, FullNameOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause the call with the given names, so that it cannot be dispatched anymore.
+		///
+		/// The dispatch origin must be [`Config::PauseOrigin`].
+		///
+		/// The calls of this pallet and the [`Config::WhitelistedCalls`] cannot be paused. The
+		/// mandatory calls are never paused, see the documentation of the pallet.
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			call_name: Vec<u8>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let full_name = Self::full_name(pallet_name, call_name)?;
+			ensure!(Self::is_pausable(&full_name), Error::<T>::Unpausable);
+			ensure!(!PausedCalls::<T>::contains_key(&full_name), Error::<T>::IsPaused);
+
+			PausedCalls::<T>::insert(&full_name, ());
+			Self::deposit_event(Event::CallPaused(full_name.0, full_name.1));
+			Ok(())
+		}
+
+		/// Unpause the call with the given names.
+		///
+		/// The dispatch origin must be [`Config::UnpauseOrigin`].
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			call_name: Vec<u8>,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			let full_name = Self::full_name(pallet_name, call_name)?;
+			ensure!(PausedCalls::<T>::contains_key(&full_name), Error::<T>::IsUnpaused);
+
+			PausedCalls::<T>::remove(&full_name);
+			Self::deposit_event(Event::CallUnpaused(full_name.0, full_name.1));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the call with the given names is paused.
+	pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+		match Self::full_name(pallet_name.to_vec(), call_name.to_vec()) {
+			Ok(full_name) => PausedCalls::<T>::contains_key(&full_name),
+			// Calls with names longer than `MaxNameLen` cannot be paused.
+			Err(_) => false,
+		}
+	}
+
+	/// Whether the call with the given names can be paused, i.e. it is neither a call of this
+	/// pallet nor whitelisted.
+	pub fn is_pausable(full_name: &FullNameOf<T>) -> bool {
+		&full_name.0[..] != <Self as PalletInfoAccess>::name().as_bytes() &&
+			!T::WhitelistedCalls::contains(full_name)
+	}
+
+	/// Whether `call` is paused. Mandatory calls are never paused.
+	pub fn is_call_paused(call: &<T as Config>::Call) -> bool {
+		if call.get_dispatch_info().class == DispatchClass::Mandatory {
+			return false
+		}
+		let metadata = call.get_call_metadata();
+		Self::is_paused(metadata.pallet_name.as_bytes(), metadata.function_name.as_bytes())
+	}
+
+	fn full_name(
+		pallet_name: Vec<u8>,
+		call_name: Vec<u8>,
+	) -> Result<FullNameOf<T>, Error<T>> {
+		let pallet_name: PalletNameOf<T> = pallet_name.try_into()
+			.map_err(|_| Error::<T>::NameTooLong)?;
+		let call_name: CallNameOf<T> = call_name.try_into()
+			.map_err(|_| Error::<T>::NameTooLong)?;
+		Ok((pallet_name, call_name))
+	}
+}
+
+impl<T: Config> Contains<<T as Config>::Call> for Pallet<T> {
+	fn contains(call: &<T as Config>::Call) -> bool {
+		!Self::is_call_paused(call)
+	}
+}
+
+impl<T: Config> Filter<<T as Config>::Call> for Pallet<T> {
+	fn filter(call: &<T as Config>::Call) -> bool {
+		!Self::is_call_paused(call)
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the transaction pause pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::{DispatchError, Dispatchable}};
+
+fn transfer_call() -> Call {
+	Call::Balances(pallet_balances::Call::transfer(2, 1))
+}
+
+#[test]
+fn paused_calls_are_filtered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(transfer_call().dispatch(Origin::signed(1)));
+
+		assert_ok!(TxPause::pause(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()));
+		System::assert_last_event(
+			crate::Event::CallPaused(
+				b"Balances".to_vec().try_into().unwrap(),
+				b"transfer".to_vec().try_into().unwrap(),
+			).into(),
+		);
+		assert!(TxPause::is_paused(b"Balances", b"transfer"));
+		assert_noop!(
+			transfer_call().dispatch(Origin::signed(1)),
+			DispatchError::BadOrigin,
+		);
+		// Other calls of the pallet are not paused.
+		let transfer_keep_alive = Call::Balances(pallet_balances::Call::transfer_keep_alive(2, 1));
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&transfer_keep_alive));
+
+		assert_ok!(TxPause::unpause(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert!(!TxPause::is_paused(b"Balances", b"transfer"));
+		assert_ok!(transfer_call().dispatch(Origin::signed(1)));
+	});
+}
+
+#[test]
+fn pause_and_unpause_fail_when_expected() {
+	new_test_ext().execute_with(|| {
+		let pause = |pallet: &[u8], call: &[u8]| {
+			TxPause::pause(Origin::root(), pallet.to_vec(), call.to_vec())
+		};
+		assert_noop!(
+			TxPause::pause(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec()),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(pause(b"TxPause", b"unpause"), Error::<Test>::Unpausable);
+		assert_noop!(pause(b"System", b"remark"), Error::<Test>::Unpausable);
+		assert_noop!(pause(&[b'a'; 33], b"transfer"), Error::<Test>::NameTooLong);
+
+		assert_ok!(pause(b"Balances", b"transfer"));
+		assert_noop!(pause(b"Balances", b"transfer"), Error::<Test>::IsPaused);
+
+		assert_noop!(
+			TxPause::unpause(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec()),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			TxPause::unpause(Origin::root(), b"Balances".to_vec(), b"set_balance".to_vec()),
+			Error::<Test>::IsUnpaused,
+		);
+	});
+}
+
+#[test]
+fn mandatory_calls_are_never_paused() {
+	new_test_ext().execute_with(|| {
+		let set_timestamp = Call::Timestamp(pallet_timestamp::Call::set(42));
+		assert_eq!(set_timestamp.get_dispatch_info().class, DispatchClass::Mandatory);
+
+		assert_ok!(TxPause::pause(Origin::root(), b"Timestamp".to_vec(), b"set".to_vec()));
+		assert!(TxPause::is_paused(b"Timestamp", b"set"));
+		assert!(!TxPause::is_call_paused(&set_timestamp));
+		assert!(<Test as frame_system::Config>::BaseCallFilter::filter(&set_timestamp));
+	});
+}
@@ -21,7 +21,7 @@
 
 use sp_std::{prelude::*, convert::TryInto};
 use super::*;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, One};
 use frame_system::RawOrigin as SystemOrigin;
 use frame_benchmarking::{
 	benchmarks_instance_pallet, account, whitelisted_caller, whitelist_account, impl_benchmark_test_suite
@@ -255,6 +255,8 @@ benchmarks_instance_pallet! {
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
 		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let origin = SystemOrigin::Signed(target.clone()).into();
+		Uniques::<T, I>::set_accept_ownership(origin, Some(class))?;
 	}: _(SystemOrigin::Signed(caller), class, target_lookup)
 	verify {
 		assert_last_event::<T, I>(Event::OwnerChanged(class, target).into());
@@ -371,6 +373,103 @@ benchmarks_instance_pallet! {
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalCancelled(class, instance, caller, delegate).into());
 	}
+
+	set_accept_ownership {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let class = T::ClassId::default();
+		create_class::<T, I>();
+	}: _(SystemOrigin::Signed(caller.clone()), Some(class))
+	verify {
+		assert_last_event::<T, I>(Event::OwnershipAcceptanceChanged(caller, Some(class)).into());
+	}
+
+	set_price {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let price = InstancePrice::<T, I>::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), class, instance, Some(price), Some(delegate_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::InstancePriceSet(class, instance, price, Some(delegate)).into());
+	}
+
+	buy_item {
+		let (class, seller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = InstancePrice::<T, I>::from(0u32);
+		let origin = SystemOrigin::Signed(seller.clone()).into();
+		Uniques::<T, I>::set_price(origin, class, instance, Some(price), Some(buyer_lookup))?;
+		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(buyer.clone()), class, instance, price)
+	verify {
+		assert_last_event::<T, I>(Event::InstanceBought(class, instance, price, seller, buyer).into());
+	}
+
+	create_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance1, ..) = mint_instance::<T, I>(0);
+		let (instance2, ..) = mint_instance::<T, I>(1);
+		let price = PriceWithDirection {
+			amount: InstancePrice::<T, I>::from(100u32),
+			direction: PriceDirection::Send,
+		};
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(caller.clone()), class, instance1, class, Some(instance2), Some(price), duration)
+	verify {
+		let deadline = duration.saturating_add(One::one());
+		assert_last_event::<T, I>(
+			Event::SwapCreated(class, instance1, class, Some(instance2), Some(price), deadline).into()
+		);
+	}
+
+	cancel_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance1, ..) = mint_instance::<T, I>(0);
+		let (instance2, ..) = mint_instance::<T, I>(1);
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::create_swap(origin, class, instance1, class, Some(instance2), None, duration)?;
+	}: _(SystemOrigin::Signed(caller), class, instance1)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCancelled(class, instance1).into());
+	}
+
+	claim_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance1, ..) = mint_instance::<T, I>(0);
+		let (instance2, ..) = mint_instance::<T, I>(1);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
+		let origin = SystemOrigin::Signed(caller.clone());
+		Uniques::<T, I>::transfer(origin.clone().into(), class, instance2, target_lookup)?;
+		let price = PriceWithDirection {
+			amount: InstancePrice::<T, I>::from(100u32),
+			direction: PriceDirection::Receive,
+		};
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		Uniques::<T, I>::create_swap(
+			origin.into(),
+			class,
+			instance1,
+			class,
+			Some(instance2),
+			Some(price),
+			duration,
+		)?;
+	}: _(SystemOrigin::Signed(target.clone()), class, instance2, class, instance1, Some(price))
+	verify {
+		assert_last_event::<T, I>(
+			Event::SwapClaimed(class, instance2, target, class, instance1, caller, Some(price)).into()
+		);
+	}
 }
 
 impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
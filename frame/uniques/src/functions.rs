@@ -42,12 +42,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Account::<T, I>::insert((&dest, &class, &instance), ());
 		let origin = details.owner;
 		details.owner = dest;
+		// The delegate, the price and the swap were all set by the previous owner.
+		details.approved = None;
 		Asset::<T, I>::insert(&class, &instance, &details);
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Transferred(class, instance, origin, details.owner));
 		Ok(())
 	}
 
+	pub(super) fn do_create_class(
+		class: T::ClassId,
+		owner: T::AccountId,
+		admin: T::AccountId,
+		deposit: DepositBalanceOf<T, I>,
+		free_holding: bool,
+		event: Event<T, I>,
+	) -> DispatchResult {
+		ensure!(!Class::<T, I>::contains_key(class), Error::<T, I>::InUse);
+
+		T::Currency::reserve(&owner, deposit)?;
+
+		Class::<T, I>::insert(
+			class,
+			ClassDetails {
+				owner,
+				issuer: admin.clone(),
+				admin: admin.clone(),
+				freezer: admin,
+				total_deposit: deposit,
+				free_holding,
+				instances: 0,
+				instance_metadatas: 0,
+				attributes: 0,
+				is_frozen: false,
+			},
+		);
+
+		Self::deposit_event(event);
+		Ok(())
+	}
+
 	pub(super) fn do_mint(
 		class: T::ClassId,
 		instance: T::InstanceId,
@@ -108,8 +144,167 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Asset::<T, I>::remove(&class, &instance);
 		Account::<T, I>::remove((&owner, &class, &instance));
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Burned(class, instance, owner));
 		Ok(())
 	}
+
+	pub(super) fn do_set_price(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+		price: Option<InstancePrice<T, I>>,
+		whitelisted_buyer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+
+		if let Some(price) = price {
+			InstancePriceOf::<T, I>::insert(&class, &instance, (price, whitelisted_buyer.clone()));
+			Self::deposit_event(Event::InstancePriceSet(class, instance, price, whitelisted_buyer));
+		} else {
+			InstancePriceOf::<T, I>::remove(&class, &instance);
+			Self::deposit_event(Event::InstancePriceRemoved(class, instance));
+		}
+		Ok(())
+	}
+
+	pub(super) fn do_buy_item(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		buyer: T::AccountId,
+		bid_price: InstancePrice<T, I>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner != buyer, Error::<T, I>::AlreadyOwned);
+
+		let (price, whitelisted_buyer) = InstancePriceOf::<T, I>::get(&class, &instance)
+			.ok_or(Error::<T, I>::NotForSale)?;
+		ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
+		if let Some(only_buyer) = whitelisted_buyer {
+			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
+		}
+
+		let seller = details.owner;
+		T::Currency::transfer(&buyer, &seller, price, ExistenceRequirement::KeepAlive)?;
+		Self::do_transfer(class, instance, buyer.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::InstanceBought(class, instance, price, seller, buyer));
+		Ok(())
+	}
+
+	pub(super) fn do_create_swap(
+		caller: T::AccountId,
+		offered_class: T::ClassId,
+		offered_instance: T::InstanceId,
+		desired_class: T::ClassId,
+		desired_instance: Option<T::InstanceId>,
+		price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+		duration: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+
+		let details = Asset::<T, I>::get(&offered_class, &offered_instance)
+			.ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+
+		match desired_instance {
+			Some(desired_instance) => ensure!(
+				Asset::<T, I>::contains_key(&desired_class, &desired_instance),
+				Error::<T, I>::Unknown,
+			),
+			None => ensure!(Class::<T, I>::contains_key(&desired_class), Error::<T, I>::Unknown),
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = now.saturating_add(duration);
+		PendingSwapOf::<T, I>::insert(
+			&offered_class,
+			&offered_instance,
+			PendingSwap { desired_class, desired_instance, price, deadline },
+		);
+
+		Self::deposit_event(Event::SwapCreated(
+			offered_class,
+			offered_instance,
+			desired_class,
+			desired_instance,
+			price,
+			deadline,
+		));
+		Ok(())
+	}
+
+	pub(super) fn do_cancel_swap(
+		caller: T::AccountId,
+		offered_class: T::ClassId,
+		offered_instance: T::InstanceId,
+	) -> DispatchResult {
+		let swap = PendingSwapOf::<T, I>::get(&offered_class, &offered_instance)
+			.ok_or(Error::<T, I>::UnknownSwap)?;
+
+		// Expired swaps can be cleaned up by anyone.
+		let now = frame_system::Pallet::<T>::block_number();
+		if swap.deadline >= now {
+			let details = Asset::<T, I>::get(&offered_class, &offered_instance)
+				.ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+		}
+
+		PendingSwapOf::<T, I>::remove(&offered_class, &offered_instance);
+		Self::deposit_event(Event::SwapCancelled(offered_class, offered_instance));
+		Ok(())
+	}
+
+	pub(super) fn do_claim_swap(
+		caller: T::AccountId,
+		send_class: T::ClassId,
+		send_instance: T::InstanceId,
+		receive_class: T::ClassId,
+		receive_instance: T::InstanceId,
+		witness_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+	) -> DispatchResult {
+		let send_details = Asset::<T, I>::get(&send_class, &send_instance)
+			.ok_or(Error::<T, I>::Unknown)?;
+		ensure!(send_details.owner == caller, Error::<T, I>::NoPermission);
+		let receive_details = Asset::<T, I>::get(&receive_class, &receive_instance)
+			.ok_or(Error::<T, I>::Unknown)?;
+		ensure!(receive_details.owner != caller, Error::<T, I>::AlreadyOwned);
+
+		let swap = PendingSwapOf::<T, I>::get(&receive_class, &receive_instance)
+			.ok_or(Error::<T, I>::UnknownSwap)?;
+		ensure!(swap.desired_class == send_class, Error::<T, I>::UnknownSwap);
+		if let Some(desired_instance) = swap.desired_instance {
+			ensure!(desired_instance == send_instance, Error::<T, I>::UnknownSwap);
+		}
+		ensure!(swap.price == witness_price, Error::<T, I>::UnknownSwap);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
+
+		let counterpart = receive_details.owner;
+		if let Some(ref price) = swap.price {
+			let (from, to) = match price.direction {
+				PriceDirection::Send => (&counterpart, &caller),
+				PriceDirection::Receive => (&caller, &counterpart),
+			};
+			T::Currency::transfer(from, to, price.amount, ExistenceRequirement::KeepAlive)?;
+		}
+
+		// Both transfers also remove the swap.
+		Self::do_transfer(send_class, send_instance, counterpart.clone(), |_, _| Ok(()))?;
+		Self::do_transfer(receive_class, receive_instance, caller.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::SwapClaimed(
+			send_class,
+			send_instance,
+			caller,
+			receive_class,
+			receive_instance,
+			counterpart,
+			swap.price,
+		));
+		Ok(())
+	}
 }
@@ -19,7 +19,9 @@
 
 use super::*;
 use sp_std::convert::TryFrom;
-use frame_support::traits::tokens::nonfungibles::{Inspect, Mutate, Transfer};
+use frame_support::traits::{
+	Get, tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
+};
 use frame_support::BoundedSlice;
 use sp_runtime::DispatchResult;
 
@@ -106,3 +108,53 @@ impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 		Self::do_transfer(class.clone(), instance.clone(), destination.clone(), |_, _| Ok(()))
 	}
 }
+
+impl<T: Config<I>, I: 'static> Create<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	/// Create a `class` of nonfungible assets to be owned by `who` and managed by `admin`.
+	///
+	/// `ClassDeposit` funds of `who` are reserved.
+	fn create_class(
+		class: &Self::ClassId,
+		who: &T::AccountId,
+		admin: &T::AccountId,
+	) -> DispatchResult {
+		Self::do_create_class(
+			class.clone(),
+			who.clone(),
+			admin.clone(),
+			T::ClassDeposit::get(),
+			false,
+			Event::Created(class.clone(), who.clone(), admin.clone()),
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I> {
+	/// Returns the asset classes in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn classes() -> Vec<Self::ClassId> {
+		Class::<T, I>::iter().map(|(class, _)| class).collect()
+	}
+
+	/// Returns the instances of an asset `class` in existence.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn instances(class: &Self::ClassId) -> Vec<Self::InstanceId> {
+		Asset::<T, I>::iter_prefix(class).map(|(instance, _)| instance).collect()
+	}
+
+	/// Returns the asset instances of all classes owned by `who`.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn owned(who: &T::AccountId) -> Vec<(Self::ClassId, Self::InstanceId)> {
+		Account::<T, I>::iter_prefix((who,)).map(|(key, _)| key).collect()
+	}
+
+	/// Returns the asset instances of `class` owned by `who`.
+	///
+	/// NOTE: iterating this list invokes a storage read per item.
+	fn owned_in_class(class: &Self::ClassId, who: &T::AccountId) -> Vec<Self::InstanceId> {
+		Account::<T, I>::iter_prefix((who, class)).map(|(instance, _)| instance).collect()
+	}
+}
@@ -43,7 +43,9 @@ pub use types::*;
 use sp_std::prelude::*;
 use sp_runtime::{RuntimeDebug, ArithmeticError, traits::{Zero, StaticLookup, Saturating}};
 use codec::{Encode, Decode, HasCompact};
-use frame_support::traits::{Currency, ReservableCurrency, BalanceStatus::Reserved};
+use frame_support::traits::{
+	Currency, ReservableCurrency, ExistenceRequirement, BalanceStatus::Reserved,
+};
 use frame_system::Config as SystemConfig;
 
 pub use weights::WeightInfo;
@@ -51,7 +53,7 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, transactional};
 	use frame_system::pallet_prelude::*;
 	use super::*;
 
@@ -103,6 +105,10 @@ pub mod pallet {
 		/// The maximum length of an attribute value.
 		type ValueLimit: Get<u32>;
 
+		/// The maximum number of blocks for which a swap may remain claimable.
+		#[pallet::constant]
+		type MaxDeadlineDuration: Get<<Self as SystemConfig>::BlockNumber>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -177,12 +183,47 @@ pub mod pallet {
 		OptionQuery
 	>;
 
+	#[pallet::storage]
+	/// The asset class, if any, of which an account is willing to take ownership.
+	pub(super) type OwnershipAcceptance<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		T::ClassId,
+	>;
+
+	#[pallet::storage]
+	/// The price of the asset instances which are for sale, and the only account allowed to buy
+	/// them, if any.
+	pub(super) type InstancePriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		(InstancePrice<T, I>, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The pending swaps of asset instances, keyed by the instance being offered.
+	pub(super) type PendingSwapOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		PendingSwapFor<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	#[pallet::metadata(
 		T::AccountId = "AccountId",
 		T::ClassId = "ClassId",
 		T::InstanceId = "InstanceId",
+		T::BlockNumber = "BlockNumber",
 	)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// An asset class was created. \[ class, creator, owner \]
@@ -242,6 +283,40 @@ pub mod pallet {
 		/// Attribute metadata has been cleared for an asset class or instance.
 		/// \[ class, maybe_instance, key, maybe_value \]
 		AttributeCleared(T::ClassId, Option<T::InstanceId>, BoundedVec<u8, T::KeyLimit>),
+		/// The class of which an account is willing to take ownership has changed.
+		/// \[ who, maybe_class \]
+		OwnershipAcceptanceChanged(T::AccountId, Option<T::ClassId>),
+		/// The price of an asset instance has been set.
+		/// \[ class, instance, price, whitelisted_buyer \]
+		InstancePriceSet(T::ClassId, T::InstanceId, InstancePrice<T, I>, Option<T::AccountId>),
+		/// The price of an asset instance has been removed. \[ class, instance \]
+		InstancePriceRemoved(T::ClassId, T::InstanceId),
+		/// An asset instance has been bought. \[ class, instance, price, seller, buyer \]
+		InstanceBought(T::ClassId, T::InstanceId, InstancePrice<T, I>, T::AccountId, T::AccountId),
+		/// A swap of an asset instance has been offered.
+		/// \[ class, instance, desired_class, desired_instance, price, deadline \]
+		SwapCreated(
+			T::ClassId,
+			T::InstanceId,
+			T::ClassId,
+			Option<T::InstanceId>,
+			Option<PriceWithDirection<InstancePrice<T, I>>>,
+			T::BlockNumber,
+		),
+		/// A swap of an asset instance has been cancelled. \[ class, instance \]
+		SwapCancelled(T::ClassId, T::InstanceId),
+		/// A swap has been claimed.
+		/// \[ sent_class, sent_instance, sent_owner, received_class, received_instance,
+		/// received_owner, price \]
+		SwapClaimed(
+			T::ClassId,
+			T::InstanceId,
+			T::AccountId,
+			T::ClassId,
+			T::InstanceId,
+			T::AccountId,
+			Option<PriceWithDirection<InstancePrice<T, I>>>,
+		),
 	}
 
 	#[pallet::error]
@@ -266,6 +341,20 @@ pub mod pallet {
 		NoDelegate,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
+		/// The new owner has not accepted the ownership of the asset class.
+		Unaccepted,
+		/// The asset instance is not for sale.
+		NotForSale,
+		/// The offered price is lower than the price of the asset instance.
+		BidTooLow,
+		/// The buyer of an asset instance is already its owner.
+		AlreadyOwned,
+		/// The deadline of the swap is too far in the future.
+		WrongDuration,
+		/// The swap does not exist or does not match the given parameters.
+		UnknownSwap,
+		/// The deadline of the swap has passed.
+		DeadlineExpired,
 	}
 
 	#[pallet::hooks]
@@ -305,28 +394,14 @@ pub mod pallet {
 			let owner = ensure_signed(origin)?;
 			let admin = T::Lookup::lookup(admin)?;
 
-			ensure!(!Class::<T, I>::contains_key(class), Error::<T, I>::InUse);
-
-			let deposit = T::ClassDeposit::get();
-			T::Currency::reserve(&owner, deposit)?;
-
-			Class::<T, I>::insert(
+			Self::do_create_class(
 				class,
-				ClassDetails {
-					owner: owner.clone(),
-					issuer: admin.clone(),
-					admin: admin.clone(),
-					freezer: admin.clone(),
-					total_deposit: deposit,
-					free_holding: false,
-					instances: 0,
-					instance_metadatas: 0,
-					attributes: 0,
-					is_frozen: false,
-				},
-			);
-			Self::deposit_event(Event::Created(class, owner, admin));
-			Ok(())
+				owner.clone(),
+				admin.clone(),
+				T::ClassDeposit::get(),
+				false,
+				Event::Created(class, owner, admin),
+			)
 		}
 
 		/// Issue a new class of non-fungible assets from a privileged origin.
@@ -355,25 +430,14 @@ pub mod pallet {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
 
-			ensure!(!Class::<T, I>::contains_key(class), Error::<T, I>::InUse);
-
-			Class::<T, I>::insert(
+			Self::do_create_class(
 				class,
-				ClassDetails {
-					owner: owner.clone(),
-					issuer: owner.clone(),
-					admin: owner.clone(),
-					freezer: owner.clone(),
-					total_deposit: Zero::zero(),
-					free_holding,
-					instances: 0,
-					instance_metadatas: 0,
-					attributes: 0,
-					is_frozen: false,
-				},
-			);
-			Self::deposit_event(Event::ForceCreated(class, owner));
-			Ok(())
+				owner.clone(),
+				owner.clone(),
+				Zero::zero(),
+				free_holding,
+				Event::ForceCreated(class, owner),
+			)
 		}
 
 		/// Destroy a class of fungible assets.
@@ -418,6 +482,8 @@ pub mod pallet {
 					Account::<T, I>::remove((&details.owner, &class, &instance));
 				}
 				InstanceMetadataOf::<T, I>::remove_prefix(&class);
+				InstancePriceOf::<T, I>::remove_prefix(&class);
+				PendingSwapOf::<T, I>::remove_prefix(&class);
 				ClassMetadataOf::<T, I>::remove(&class);
 				Attribute::<T, I>::remove_prefix((&class,));
 				T::Currency::unreserve(&class_details.owner, class_details.total_deposit);
@@ -703,7 +769,8 @@ pub mod pallet {
 		/// Origin must be Signed and the sender should be the Owner of the asset `class`.
 		///
 		/// - `class`: The asset class whose owner should be changed.
-		/// - `owner`: The new Owner of this asset class.
+		/// - `owner`: The new Owner of this asset class. They must have called
+		///   `set_accept_ownership` with `class` in order for this operation to succeed.
 		///
 		/// Emits `OwnerChanged`.
 		///
@@ -723,6 +790,9 @@ pub mod pallet {
 				if details.owner == owner {
 					return Ok(());
 				}
+				let acceptable_class = OwnershipAcceptance::<T, I>::get(&owner);
+				ensure!(acceptable_class.as_ref() == Some(&class), Error::<T, I>::Unaccepted);
+				OwnershipAcceptance::<T, I>::remove(&owner);
 
 				// Move the deposit to the new owner.
 				T::Currency::repatriate_reserved(
@@ -1239,5 +1309,185 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Set (or reset) the acceptance of ownership for a particular account.
+		///
+		/// Origin must be `Signed` and if `maybe_class` is `Some`, then the signer must have a
+		/// provider reference.
+		///
+		/// - `maybe_class`: The identifier of the asset class whose ownership the signer is willing
+		///   to accept, or if `None`, an indication that the signer is willing to accept no
+		///   ownership transferal.
+		///
+		/// Emits `OwnershipAcceptanceChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_accept_ownership())]
+		pub(super) fn set_accept_ownership(
+			origin: OriginFor<T>,
+			maybe_class: Option<T::ClassId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			match maybe_class {
+				Some(class) => {
+					ensure!(Class::<T, I>::contains_key(&class), Error::<T, I>::Unknown);
+					ensure!(
+						frame_system::Pallet::<T>::providers(&who) > 0,
+						Error::<T, I>::NoPermission,
+					);
+					OwnershipAcceptance::<T, I>::insert(&who, class);
+				}
+				None => OwnershipAcceptance::<T, I>::remove(&who),
+			}
+
+			Self::deposit_event(Event::OwnershipAcceptanceChanged(who, maybe_class));
+			Ok(())
+		}
+
+		/// Set (or reset) the price for an asset instance.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset instance.
+		/// - `instance`: The instance of the asset.
+		/// - `price`: The price for the instance. Pass `None` to take the instance off sale.
+		/// - `whitelisted_buyer`: Restricts the buy operation to a specific account.
+		///
+		/// Emits `InstancePriceSet` on success if the price is not `None`.
+		/// Emits `InstancePriceRemoved` on success if the price is `None`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub(super) fn set_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			price: Option<InstancePrice<T, I>>,
+			whitelisted_buyer: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_price(class, instance, origin, price, whitelisted_buyer)
+		}
+
+		/// Buy an asset instance if it is up for sale.
+		///
+		/// Origin must be Signed and must not be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset instance.
+		/// - `instance`: The instance the sender wants to buy.
+		/// - `bid_price`: The price the sender is willing to pay. Only the price set by the owner
+		///   is actually paid.
+		///
+		/// Emits `InstanceBought` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[transactional]
+		pub(super) fn buy_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			bid_price: InstancePrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item(class, instance, origin, bid_price)
+		}
+
+		/// Offer an asset instance in exchange for an instance of some class, optionally along
+		/// with a price difference.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `offered_class`: The class of the offered asset instance.
+		/// - `offered_instance`: The instance being offered.
+		/// - `desired_class`: The class from which an instance is desired in exchange.
+		/// - `maybe_desired_instance`: The instance desired in exchange, if any particular one.
+		/// - `maybe_price`: The price difference to be paid when the swap is claimed, and who
+		///   pays it.
+		/// - `duration`: The number of blocks during which the swap can be claimed. This must not
+		///   be greater than `MaxDeadlineDuration`.
+		///
+		/// Emits `SwapCreated` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		pub(super) fn create_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_class: T::ClassId,
+			#[pallet::compact] offered_instance: T::InstanceId,
+			#[pallet::compact] desired_class: T::ClassId,
+			maybe_desired_instance: Option<T::InstanceId>,
+			maybe_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_swap(
+				origin,
+				offered_class,
+				offered_instance,
+				desired_class,
+				maybe_desired_instance,
+				maybe_price,
+				duration,
+			)
+		}
+
+		/// Cancel the swap of an asset instance.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`, unless the
+		/// deadline of the swap has passed, in which case anyone may cancel it.
+		///
+		/// - `offered_class`: The class of the offered asset instance.
+		/// - `offered_instance`: The instance being offered.
+		///
+		/// Emits `SwapCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub(super) fn cancel_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offered_class: T::ClassId,
+			#[pallet::compact] offered_instance: T::InstanceId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_swap(origin, offered_class, offered_instance)
+		}
+
+		/// Claim the swap of an asset instance, exchanging it for an instance of the sender.
+		///
+		/// Origin must be Signed and must be the owner of the asset `send_instance`.
+		///
+		/// - `send_class`: The class of the asset instance to be sent.
+		/// - `send_instance`: The instance to be sent.
+		/// - `receive_class`: The class of the asset instance to be received.
+		/// - `receive_instance`: The instance to be received, whose owner created the swap.
+		/// - `witness_price`: The price difference of the swap, which must match the one of the
+		///   swap exactly.
+		///
+		/// Emits `SwapClaimed` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[transactional]
+		pub(super) fn claim_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] send_class: T::ClassId,
+			#[pallet::compact] send_instance: T::InstanceId,
+			#[pallet::compact] receive_class: T::ClassId,
+			#[pallet::compact] receive_instance: T::InstanceId,
+			witness_price: Option<PriceWithDirection<InstancePrice<T, I>>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_claim_swap(
+				origin,
+				send_class,
+				send_instance,
+				receive_class,
+				receive_instance,
+				witness_price,
+			)
+		}
 	}
 }
@@ -83,6 +83,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 }
 
 parameter_types! {
@@ -94,6 +95,7 @@ parameter_types! {
 	pub const MetadataDepositBase: u64 = 1;
 	pub const AttributeDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
+	pub const MaxDeadlineDuration: u64 = 100;
 }
 
 impl Config for Test {
@@ -110,6 +112,7 @@ impl Config for Test {
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type WeightInfo = ();
 }
 
@@ -199,7 +199,10 @@ fn transfer_owner_should_work() {
 		Balances::make_free_balance_be(&2, 100);
 		Balances::make_free_balance_be(&3, 100);
 		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+		assert_noop!(Uniques::transfer_ownership(Origin::signed(1), 0, 2), Error::<Test>::Unaccepted);
+		assert_ok!(Uniques::set_accept_ownership(Origin::signed(2), Some(0)));
 		assert_ok!(Uniques::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(OwnershipAcceptance::<Test>::get(2), None);
 		assert_eq!(Balances::total_balance(&1), 98);
 		assert_eq!(Balances::total_balance(&2), 102);
 		assert_eq!(Balances::reserved_balance(&1), 0);
@@ -211,6 +214,7 @@ fn transfer_owner_should_work() {
 		assert_ok!(Uniques::set_class_metadata(Origin::signed(2), 0, bvec![0u8; 20], false));
 		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
 		assert_ok!(Uniques::set_metadata(Origin::signed(2), 0, 42, bvec![0u8; 20], false));
+		assert_ok!(Uniques::set_accept_ownership(Origin::signed(3), Some(0)));
 		assert_ok!(Uniques::transfer_ownership(Origin::signed(2), 0, 3));
 		assert_eq!(Balances::total_balance(&2), 57);
 		assert_eq!(Balances::total_balance(&3), 145);
@@ -525,3 +529,169 @@ fn cancel_approval_works_with_force() {
 		assert_noop!(Uniques::cancel_approval(Origin::root(), 0, 42, None), Error::<Test>::NoDelegate);
 	});
 }
+
+#[test]
+fn nonfungibles_create_and_enumerate_work() {
+	use frame_support::traits::tokens::nonfungibles::{Create, InspectEnumerable};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(<Uniques as Create<_>>::create_class(&0, &1, &2));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert_noop!(<Uniques as Create<_>>::create_class(&0, &1, &2), Error::<Test>::InUse);
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+
+		assert_ok!(Uniques::mint(Origin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::mint(Origin::signed(2), 0, 43, 4));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 42, 3));
+
+		let mut classes = <Uniques as InspectEnumerable<_>>::classes();
+		classes.sort();
+		assert_eq!(classes, vec![0, 1]);
+		let mut instances = <Uniques as InspectEnumerable<_>>::instances(&0);
+		instances.sort();
+		assert_eq!(instances, vec![42, 43]);
+		let mut owned = <Uniques as InspectEnumerable<_>>::owned(&3);
+		owned.sort();
+		assert_eq!(owned, vec![(0, 42), (1, 42)]);
+		assert_eq!(<Uniques as InspectEnumerable<_>>::owned_in_class(&0, &3), vec![42]);
+		assert_eq!(<Uniques as InspectEnumerable<_>>::owned_in_class(&0, &5), vec![]);
+	});
+}
+
+#[test]
+fn set_accept_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+		assert_noop!(Uniques::set_accept_ownership(Origin::signed(2), Some(1)), Error::<Test>::Unknown);
+		assert_noop!(Uniques::set_accept_ownership(Origin::signed(3), Some(0)), Error::<Test>::NoPermission);
+
+		assert_ok!(Uniques::set_accept_ownership(Origin::signed(2), Some(0)));
+		assert_eq!(OwnershipAcceptance::<Test>::get(2), Some(0));
+		assert_ok!(Uniques::set_accept_ownership(Origin::signed(2), None));
+		assert_noop!(Uniques::transfer_ownership(Origin::signed(1), 0, 2), Error::<Test>::Unaccepted);
+	});
+}
+
+#[test]
+fn buying_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 1));
+
+		assert_noop!(Uniques::buy_item(Origin::signed(2), 0, 42, 10), Error::<Test>::NotForSale);
+		assert_noop!(Uniques::set_price(Origin::signed(2), 0, 42, Some(10), None), Error::<Test>::NoPermission);
+		assert_ok!(Uniques::set_price(Origin::signed(1), 0, 42, Some(10), None));
+		assert_ok!(Uniques::set_price(Origin::signed(1), 0, 43, Some(20), Some(3)));
+		assert_eq!(InstancePriceOf::<Test>::get(0, 42), Some((10, None)));
+
+		assert_noop!(Uniques::buy_item(Origin::signed(1), 0, 42, 10), Error::<Test>::AlreadyOwned);
+		assert_noop!(Uniques::buy_item(Origin::signed(2), 0, 42, 9), Error::<Test>::BidTooLow);
+		assert_noop!(Uniques::buy_item(Origin::signed(2), 0, 43, 20), Error::<Test>::NoPermission);
+
+		// Only the price set by the owner is paid.
+		assert_ok!(Uniques::buy_item(Origin::signed(2), 0, 42, 15));
+		assert_eq!(Balances::total_balance(&1), 110);
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_eq!(InstancePriceOf::<Test>::get(0, 42), None);
+		let event = crate::Event::InstanceBought(0, 42, 10, 1, 2);
+		System::assert_last_event(mock::Event::Uniques(event));
+
+		assert_ok!(Uniques::buy_item(Origin::signed(3), 0, 43, 20));
+		assert_eq!(assets(), vec![(2, 0, 42), (3, 0, 43)]);
+
+		// Frozen instances cannot be bought.
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(10), None));
+		assert_ok!(Uniques::freeze(Origin::signed(1), 0, 42));
+		assert_noop!(Uniques::buy_item(Origin::signed(3), 0, 42, 10), Error::<Test>::Frozen);
+
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, None, None));
+		assert_eq!(InstancePriceOf::<Test>::get(0, 42), None);
+	});
+}
+
+#[test]
+fn swapping_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 69, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 70, 2));
+
+		let price = Some(PriceWithDirection { amount: 10, direction: PriceDirection::Receive });
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(1), 0, 42, 1, Some(69), price, 101),
+			Error::<Test>::WrongDuration,
+		);
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(2), 0, 42, 1, Some(69), price, 10),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(1), 0, 42, 1, Some(71), price, 10),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 42, 1, Some(69), price, 10));
+
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 1, 70, 0, 42, price),
+			Error::<Test>::UnknownSwap,
+		);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 1, 69, 0, 42, None),
+			Error::<Test>::UnknownSwap,
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 1, 69, 0, 42, price),
+			Error::<Test>::DeadlineExpired,
+		);
+		System::set_block_number(11);
+
+		assert_ok!(Uniques::claim_swap(Origin::signed(2), 1, 69, 0, 42, price));
+		assert_eq!(assets(), vec![(1, 1, 69), (2, 0, 42), (2, 1, 70)]);
+		assert_eq!(Balances::total_balance(&1), 110);
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_eq!(PendingSwapOf::<Test>::get(0, 42), None);
+
+		// Any instance of the desired class will do.
+		assert_ok!(Uniques::create_swap(Origin::signed(2), 0, 42, 1, None, None, 10));
+		assert_noop!(Uniques::cancel_swap(Origin::signed(1), 0, 42), Error::<Test>::NoPermission);
+		assert_ok!(Uniques::claim_swap(Origin::signed(1), 1, 69, 0, 42, None));
+		assert_eq!(assets(), vec![(1, 0, 42), (2, 1, 69), (2, 1, 70)]);
+	});
+}
+
+#[test]
+fn cancelling_swaps_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 2));
+
+		assert_noop!(Uniques::cancel_swap(Origin::signed(1), 0, 42), Error::<Test>::UnknownSwap);
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 42, 0, Some(43), None, 10));
+		assert_ok!(Uniques::cancel_swap(Origin::signed(1), 0, 42));
+		assert_eq!(PendingSwapOf::<Test>::get(0, 42), None);
+
+		// Expired swaps can be cancelled by anyone.
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 42, 0, Some(43), None, 10));
+		assert_noop!(Uniques::cancel_swap(Origin::signed(2), 0, 42), Error::<Test>::NoPermission);
+		System::set_block_number(12);
+		assert_ok!(Uniques::cancel_swap(Origin::signed(2), 0, 42));
+
+		// Transferring the offered instance removes its swap.
+		assert_ok!(Uniques::create_swap(Origin::signed(1), 0, 42, 0, Some(43), None, 10));
+		assert_ok!(Uniques::transfer(Origin::signed(1), 0, 42, 3));
+		assert_eq!(PendingSwapOf::<Test>::get(0, 42), None);
+	});
+}
@@ -26,6 +26,13 @@ pub(super) type ClassDetailsFor<T, I> =
 	ClassDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
 pub(super) type InstanceDetailsFor<T, I> =
 	InstanceDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type InstancePrice<T, I = ()> = DepositBalanceOf<T, I>;
+pub(super) type PendingSwapFor<T, I> = PendingSwap<
+	<T as Config<I>>::ClassId,
+	<T as Config<I>>::InstanceId,
+	PriceWithDirection<InstancePrice<T, I>>,
+	<T as SystemConfig>::BlockNumber,
+>;
 
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
@@ -121,3 +128,34 @@ pub struct InstanceMetadata<DepositBalance, StringLimit: Get<u32>> {
 	/// Whether the asset metadata may be changed by a non Force origin.
 	pub(super) is_frozen: bool,
 }
+
+/// The direction in which the price difference of a swap is paid.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum PriceDirection {
+	/// The creator of the swap pays the price to the account which claims it.
+	Send,
+	/// The account which claims the swap pays the price to its creator.
+	Receive,
+}
+
+/// A price, together with the direction in which it is paid.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct PriceWithDirection<Amount> {
+	/// The amount to be paid.
+	pub amount: Amount,
+	/// Who pays the amount.
+	pub direction: PriceDirection,
+}
+
+/// An offer to swap an asset instance for an instance of another (or the same) class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct PendingSwap<ClassId, InstanceId, ItemPriceWithDirection, Deadline> {
+	/// The class from which an instance is desired in exchange.
+	pub(super) desired_class: ClassId,
+	/// The instance which is desired in exchange; any instance of `desired_class` if `None`.
+	pub(super) desired_instance: Option<InstanceId>,
+	/// The price difference to be paid on top of the exchange, if any.
+	pub(super) price: Option<ItemPriceWithDirection>,
+	/// The last block at which the swap can be claimed.
+	pub(super) deadline: Deadline,
+}
@@ -63,6 +63,28 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::BatchCompleted.into())
 	}
+
+	force_batch {
+		let c in 0 .. 1000;
+		let mut calls: Vec<<T as Config>::Call> = Vec::new();
+		for i in 0 .. c {
+			let call = frame_system::Call::remark(vec![]).into();
+			calls.push(call);
+		}
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), calls)
+	verify {
+		assert_last_event::<T>(Event::BatchCompleted.into())
+	}
+
+	dispatch_as {
+		let caller = account("caller", SEED, SEED);
+		let call = Box::new(frame_system::Call::remark(vec![]).into());
+		let origin: T::PalletsOrigin = frame_system::RawOrigin::Signed(caller).into();
+	}: _(RawOrigin::Root, Box::new(origin), call)
+	verify {
+		assert_last_event::<T>(Event::DispatchedAs(Ok(())).into())
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -23,7 +23,7 @@
 //!
 //! ## Overview
 //!
-//! This pallet contains two basic pieces of functionality:
+//! This pallet contains three basic pieces of functionality:
 //! - Batch dispatch: A stateless operation, allowing any origin to execute multiple calls in a
 //!   single dispatch. This can be useful to amalgamate proposals, combining `set_code` with
 //!   corresponding `set_storage`s, for efficient multiple payouts with just a single signature
@@ -35,6 +35,11 @@
 //!   it's perfectly fine to have each of them controlled by the same underlying keypair.
 //!   Derivative accounts are, for the purposes of proxy filtering considered exactly the same as
 //!   the origin and are thus hampered with the origin's filters.
+//! - Origin dispatch: A root-only operation, allowing to execute a call from any origin of the
+//!   runtime, e.g. from the origin of a collective.
+//!
+//! The nesting of the calls of this pallet in each other, or in the calls of other pallets, is
+//! bounded by the depth limit applied when decoding the extrinsics, `sp_api::MAX_EXTRINSIC_DEPTH`.
 //!
 //! Since proxy filters are respected in all dispatches of this pallet, it should never need to be
 //! filtered by any proxy.
@@ -44,10 +49,16 @@
 //! ### Dispatchable Functions
 //!
 //! #### For batch dispatch
-//! * `batch` - Dispatch multiple calls from the sender's origin.
+//! * `batch` - Dispatch multiple calls from the sender's origin, stopping at the first failure.
+//! * `batch_all` - Dispatch multiple calls from the sender's origin, reverting all of them if one
+//!   fails.
+//! * `force_batch` - Dispatch multiple calls from the sender's origin, continuing after failures.
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
+//!
+//! #### For origin dispatch
+//! * `dispatch_as` - Dispatch a call from the given origin.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -95,6 +106,11 @@ pub mod pallet {
 			+ IsSubType<Call<Self>>
 			+ IsType<<Self as frame_system::Config>::Call>;
 
+		/// The caller origin, overarching type of all pallets origins.
+		type PalletsOrigin: Parameter
+			+ Into<<Self as frame_system::Config>::Origin>
+			+ From<frame_system::RawOrigin<Self::AccountId>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -107,6 +123,14 @@ pub mod pallet {
 		BatchInterrupted(u32, DispatchError),
 		/// Batch of dispatches completed fully with no error.
 		BatchCompleted,
+		/// Batch of dispatches completed but has errors.
+		BatchCompletedWithErrors,
+		/// A single item within a batch of dispatches has completed with no error.
+		ItemCompleted,
+		/// A single item within a batch of dispatches has completed with error. \[error\]
+		ItemFailed(DispatchError),
+		/// A call was dispatched. \[result\]
+		DispatchedAs(DispatchResult),
 	}
 
 	#[pallet::call]
@@ -133,7 +157,7 @@ pub mod pallet {
 			let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info()).collect::<Vec<_>>();
 			let dispatch_weight = dispatch_infos.iter()
 				.map(|di| di.weight)
-				.fold(0, |total: Weight, weight: Weight| total.saturating_add(weight))
+				.fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
 				.saturating_add(T::WeightInfo::batch(calls.len() as u32));
 			let dispatch_class = {
 				let all_operational = dispatch_infos.iter()
@@ -154,7 +178,7 @@ pub mod pallet {
 			let is_root = ensure_root(origin.clone()).is_ok();
 			let calls_len = calls.len();
 			// Track the actual weight of each of the batch calls.
-			let mut weight: Weight = 0;
+			let mut weight = Weight::zero();
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
@@ -239,7 +263,7 @@ pub mod pallet {
 			let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info()).collect::<Vec<_>>();
 			let dispatch_weight = dispatch_infos.iter()
 				.map(|di| di.weight)
-				.fold(0, |total: Weight, weight: Weight| total.saturating_add(weight))
+				.fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
 				.saturating_add(T::WeightInfo::batch_all(calls.len() as u32));
 			let dispatch_class = {
 				let all_operational = dispatch_infos.iter()
@@ -261,7 +285,7 @@ pub mod pallet {
 			let is_root = ensure_root(origin.clone()).is_ok();
 			let calls_len = calls.len();
 			// Track the actual weight of each of the batch calls.
-			let mut weight: Weight = 0;
+			let mut weight = Weight::zero();
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, bypass any dispatch filter; root can call anything.
@@ -290,6 +314,110 @@ pub mod pallet {
 			let base_weight = T::WeightInfo::batch_all(calls_len as u32);
 			Ok(Some(base_weight + weight).into())
 		}
+
+		/// Send a batch of dispatch calls, continuing after the failed calls.
+		///
+		/// May be called from any origin.
+		///
+		/// - `calls`: The calls to be dispatched from the same origin.
+		///
+		/// If origin is root then call are dispatch without checking origin filter. (This includes
+		/// bypassing `frame_system::Config::BaseCallFilter`).
+		///
+		/// # <weight>
+		/// - Complexity: O(C) where C is the number of calls to be batched.
+		/// # </weight>
+		///
+		/// This will return `Ok` in all circumstances. The result of each call is given by an
+		/// `ItemCompleted` or an `ItemFailed` event, followed by a `BatchCompleted` event if all
+		/// the calls were successful, or a `BatchCompletedWithErrors` event otherwise.
+		#[pallet::weight({
+			let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info()).collect::<Vec<_>>();
+			let dispatch_weight = dispatch_infos.iter()
+				.map(|di| di.weight)
+				.fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
+				.saturating_add(T::WeightInfo::force_batch(calls.len() as u32));
+			let dispatch_class = {
+				let all_operational = dispatch_infos.iter()
+					.map(|di| di.class)
+					.all(|class| class == DispatchClass::Operational);
+				if all_operational {
+					DispatchClass::Operational
+				} else {
+					DispatchClass::Normal
+				}
+			};
+			(dispatch_weight, dispatch_class)
+		})]
+		pub fn force_batch(
+			origin: OriginFor<T>,
+			calls: Vec<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let calls_len = calls.len();
+			// Track the actual weight of each of the batch calls.
+			let mut weight = Weight::zero();
+			let mut has_error = false;
+			for call in calls.into_iter() {
+				let info = call.get_dispatch_info();
+				// If origin is root, don't apply any dispatch filters; root can call anything.
+				let result = if is_root {
+					call.dispatch_bypass_filter(origin.clone())
+				} else {
+					call.dispatch(origin.clone())
+				};
+				// Add the weight of this call.
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				match result {
+					Ok(_) => Self::deposit_event(Event::ItemCompleted),
+					Err(e) => {
+						has_error = true;
+						Self::deposit_event(Event::ItemFailed(e.error));
+					},
+				}
+			}
+			if has_error {
+				Self::deposit_event(Event::BatchCompletedWithErrors);
+			} else {
+				Self::deposit_event(Event::BatchCompleted);
+			}
+			let base_weight = T::WeightInfo::force_batch(calls_len as u32);
+			Ok(Some(base_weight + weight).into())
+		}
+
+		/// Dispatch a call with the given origin.
+		///
+		/// The dispatch origin for this call must be _Root_. The call is dispatched with the
+		/// filters of `as_origin`.
+		///
+		/// This will return `Ok` as long as the origin is _Root_, the result of the call is given
+		/// by the `DispatchedAs` event.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// # </weight>
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::dispatch_as().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn dispatch_as(
+			origin: OriginFor<T>,
+			as_origin: Box<T::PalletsOrigin>,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let info = call.get_dispatch_info();
+			let result = call.dispatch((*as_origin).into());
+			let weight = T::WeightInfo::dispatch_as()
+				.saturating_add(extract_actual_weight(&result, &info));
+
+			Self::deposit_event(Event::DispatchedAs(result.map(|_| ()).map_err(|e| e.error)));
+			Ok(Some(weight).into())
+		}
 	}
 
 }
@@ -308,4 +436,5 @@ impl<T: Config> Pallet<T> {
 		let entropy = (b"modlpy/utilisuba", who, index).using_encoded(blake2_256);
 		T::AccountId::decode(&mut &entropy[..]).unwrap_or_default()
 	}
+
 }
@@ -28,6 +28,8 @@ use frame_support::{
 	traits::Filter,
 	storage,
 };
+use codec::DecodeLimit;
+use sp_api::MAX_EXTRINSIC_DEPTH;
 use sp_core::H256;
 use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, testing::Header};
 use crate as utility;
@@ -120,6 +122,7 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldsAndFreezes = ();
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = Event;
@@ -153,6 +156,7 @@ impl Filter<Call> for TestBaseCallFilter {
 impl Config for Test {
 	type Event = Event;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 }
 
@@ -196,8 +200,8 @@ fn as_derivative_works() {
 #[test]
 fn as_derivative_handles_weight_refund() {
 	new_test_ext().execute_with(|| {
-		let start_weight = 100;
-		let end_weight = 75;
+		let start_weight = Weight::from_ref_time(100);
+		let end_weight = Weight::from_ref_time(75);
 		let diff = start_weight - end_weight;
 
 		// Full weight when ok
@@ -349,10 +353,10 @@ fn batch_weight_calculation_doesnt_overflow() {
 #[test]
 fn batch_handles_weight_refund() {
 	new_test_ext().execute_with(|| {
-		let start_weight = 100;
-		let end_weight = 75;
+		let start_weight = Weight::from_ref_time(100);
+		let end_weight = Weight::from_ref_time(75);
 		let diff = start_weight - end_weight;
-		let batch_len: Weight = 4;
+		let batch_len = 4;
 
 		// Full weight when ok
 		let inner_call = Call::Example(ExampleCall::foobar(false, start_weight, None));
@@ -389,7 +393,7 @@ fn batch_handles_weight_refund() {
 		let good_call = Call::Example(ExampleCall::foobar(false, start_weight, Some(end_weight)));
 		let bad_call = Call::Example(ExampleCall::foobar(true, start_weight, Some(end_weight)));
 		let batch_calls = vec![good_call, bad_call];
-		let batch_len = batch_calls.len() as Weight;
+		let batch_len = batch_calls.len() as u64;
 		let call = Call::Utility(UtilityCall::batch(batch_calls));
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(1));
@@ -460,10 +464,10 @@ fn batch_all_revert() {
 #[test]
 fn batch_all_handles_weight_refund() {
 	new_test_ext().execute_with(|| {
-		let start_weight = 100;
-		let end_weight = 75;
+		let start_weight = Weight::from_ref_time(100);
+		let end_weight = Weight::from_ref_time(75);
 		let diff = start_weight - end_weight;
-		let batch_len: Weight = 4;
+		let batch_len = 4;
 
 		// Full weight when ok
 		let inner_call = Call::Example(ExampleCall::foobar(false, start_weight, None));
@@ -499,7 +503,7 @@ fn batch_all_handles_weight_refund() {
 		let good_call = Call::Example(ExampleCall::foobar(false, start_weight, Some(end_weight)));
 		let bad_call = Call::Example(ExampleCall::foobar(true, start_weight, Some(end_weight)));
 		let batch_calls = vec![good_call, bad_call];
-		let batch_len = batch_calls.len() as Weight;
+		let batch_len = batch_calls.len() as u64;
 		let call = Call::Utility(UtilityCall::batch_all(batch_calls));
 		let info = call.get_dispatch_info();
 		let result = call.dispatch(Origin::signed(1));
@@ -562,3 +566,88 @@ fn batch_all_does_not_nest() {
 		assert_eq!(Balances::free_balance(2), 10);
 	});
 }
+
+#[test]
+fn force_batch_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_ok!(
+			Utility::force_batch(Origin::signed(1), vec![
+				Call::Balances(BalancesCall::transfer(2, 5)),
+				Call::Balances(BalancesCall::transfer(2, 10)),
+				Call::Balances(BalancesCall::transfer(2, 5)),
+			]),
+		);
+		System::assert_has_event(utility::Event::ItemCompleted.into());
+		System::assert_has_event(utility::Event::ItemFailed(
+			BalancesError::<Test, _>::InsufficientBalance.into(),
+		).into());
+		System::assert_last_event(utility::Event::BatchCompletedWithErrors.into());
+		assert_eq!(Balances::free_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 20);
+
+		assert_ok!(
+			Utility::force_batch(Origin::signed(2), vec![
+				Call::Balances(BalancesCall::transfer(1, 5)),
+			]),
+		);
+		System::assert_last_event(utility::Event::BatchCompleted.into());
+	});
+}
+
+#[test]
+fn dispatch_as_works() {
+	new_test_ext().execute_with(|| {
+		let as_origin = || Box::new(frame_system::RawOrigin::Signed(1).into());
+		assert_noop!(
+			Utility::dispatch_as(
+				Origin::signed(1),
+				as_origin(),
+				Box::new(Call::Balances(BalancesCall::transfer(2, 5))),
+			),
+			DispatchError::BadOrigin,
+		);
+
+		assert_ok!(Utility::dispatch_as(
+			Origin::root(),
+			as_origin(),
+			Box::new(Call::Balances(BalancesCall::transfer(2, 5))),
+		));
+		System::assert_last_event(utility::Event::DispatchedAs(Ok(())).into());
+		assert_eq!(Balances::free_balance(1), 5);
+		assert_eq!(Balances::free_balance(2), 15);
+
+		// The call is dispatched with the filters of the given origin.
+		assert_ok!(Utility::dispatch_as(
+			Origin::root(),
+			as_origin(),
+			Box::new(Call::Balances(BalancesCall::transfer_keep_alive(2, 1))),
+		));
+		System::assert_last_event(
+			utility::Event::DispatchedAs(Err(DispatchError::BadOrigin)).into(),
+		);
+		assert_eq!(Balances::free_balance(1), 5);
+	});
+}
+
+#[test]
+fn nesting_is_limited_by_the_extrinsic_depth_limit() {
+	let nest = |depth: u32| {
+		let mut call = Call::Balances(BalancesCall::transfer(2, 1));
+		for i in 0..depth {
+			call = if i % 2 == 0 {
+				Call::Utility(UtilityCall::batch(vec![call]))
+			} else {
+				Call::Utility(UtilityCall::as_derivative(0, Box::new(call)))
+			};
+		}
+		call.encode()
+	};
+	let decode = |encoded: Vec<u8>| {
+		<Call as DecodeLimit>::decode_all_with_depth_limit(MAX_EXTRINSIC_DEPTH, &encoded)
+	};
+
+	assert!(decode(nest(10)).is_ok());
+	assert!(decode(nest(MAX_EXTRINSIC_DEPTH)).is_err());
+}
@@ -47,39 +47,57 @@ pub trait WeightInfo {
 	fn batch(c: u32, ) -> Weight;
 	fn as_derivative() -> Weight;
 	fn batch_all(c: u32, ) -> Weight;
+	fn force_batch(c: u32, ) -> Weight;
+	fn dispatch_as() -> Weight;
 }
 
 /// Weights for pallet_utility using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn batch(c: u32, ) -> Weight {
-		(19_099_000 as Weight)
+		Weight::from_ref_time(19_099_000 as u64)
 			// Standard Error: 1_000
-			.saturating_add((640_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(c as u64))
 	}
 	fn as_derivative() -> Weight {
-		(3_701_000 as Weight)
+		Weight::from_ref_time(3_701_000 as u64)
 	}
 	fn batch_all(c: u32, ) -> Weight {
-		(19_199_000 as Weight)
+		Weight::from_ref_time(19_199_000 as u64)
 			// Standard Error: 0
-			.saturating_add((1_061_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(1_061_000 as u64).saturating_mul(c as u64))
+	}
+	fn force_batch(c: u32, ) -> Weight {
+		Weight::from_ref_time(19_099_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(c as u64))
+	}
+	fn dispatch_as() -> Weight {
+		Weight::from_ref_time(3_701_000 as u64)
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn batch(c: u32, ) -> Weight {
-		(19_099_000 as Weight)
+		Weight::from_ref_time(19_099_000 as u64)
 			// Standard Error: 1_000
-			.saturating_add((640_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(c as u64))
 	}
 	fn as_derivative() -> Weight {
-		(3_701_000 as Weight)
+		Weight::from_ref_time(3_701_000 as u64)
 	}
 	fn batch_all(c: u32, ) -> Weight {
-		(19_199_000 as Weight)
+		Weight::from_ref_time(19_199_000 as u64)
 			// Standard Error: 0
-			.saturating_add((1_061_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(Weight::from_ref_time(1_061_000 as u64).saturating_mul(c as u64))
+	}
+	fn force_batch(c: u32, ) -> Weight {
+		Weight::from_ref_time(19_099_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(640_000 as u64).saturating_mul(c as u64))
+	}
+	fn dispatch_as() -> Weight {
+		Weight::from_ref_time(3_701_000 as u64)
 	}
 }
@@ -40,86 +40,91 @@ fn add_locks<T: Config>(who: &T::AccountId, n: u8) {
 	}
 }
 
-fn add_vesting_schedule<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+fn add_vesting_schedules<T: Config>(who: &T::AccountId, n: u32) -> Result<(), &'static str> {
 	let locked = 100u32;
 	let per_block = 10u32;
 	let starting_block = 1u32;
 
 	System::<T>::set_block_number(0u32.into());
 
-	// Add schedule to avoid `NotVesting` error.
-	Vesting::<T>::add_vesting_schedule(
-		&who,
-		locked.into(),
-		per_block.into(),
-		starting_block.into(),
-	)?;
+	// Add schedules to avoid `NotVesting` error.
+	for _ in 0 .. n {
+		Vesting::<T>::add_vesting_schedule(
+			&who,
+			locked.into(),
+			per_block.into(),
+			starting_block.into(),
+		)?;
+	}
 	Ok(())
 }
 
 benchmarks! {
 	vest_locked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let caller = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		add_locks::<T>(&caller, l as u8);
-		add_vesting_schedule::<T>(&caller)?;
+		add_vesting_schedules::<T>(&caller, s)?;
 		// At block zero, everything is vested.
 		System::<T>::set_block_number(T::BlockNumber::zero());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
-			Some(100u32.into()),
-			"Vesting schedule not added",
+			Some((100 * s).into()),
+			"Vesting schedules not added",
 		);
 	}: vest(RawOrigin::Signed(caller.clone()))
 	verify {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
-			Some(100u32.into()),
-			"Vesting schedule was removed",
+			Some((100 * s).into()),
+			"Vesting schedules were removed",
 		);
 	}
 
 	vest_unlocked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let caller = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		add_locks::<T>(&caller, l as u8);
-		add_vesting_schedule::<T>(&caller)?;
+		add_vesting_schedules::<T>(&caller, s)?;
 		// At block 20, everything is unvested.
 		System::<T>::set_block_number(20u32.into());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
 			Some(BalanceOf::<T>::zero()),
-			"Vesting schedule still active",
+			"Vesting schedules still active",
 		);
 	}: vest(RawOrigin::Signed(caller.clone()))
 	verify {
-		// Vesting schedule is removed!
+		// Vesting schedules are removed!
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
 			None,
-			"Vesting schedule was not removed",
+			"Vesting schedules were not removed",
 		);
 	}
 
 	vest_other_locked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let other: T::AccountId = account("other", 0, SEED);
 		let other_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(other.clone());
 		T::Currency::make_free_balance_be(&other, BalanceOf::<T>::max_value());
 		add_locks::<T>(&other, l as u8);
-		add_vesting_schedule::<T>(&other)?;
+		add_vesting_schedules::<T>(&other, s)?;
 		// At block zero, everything is vested.
 		System::<T>::set_block_number(T::BlockNumber::zero());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
-			Some(100u32.into()),
-			"Vesting schedule not added",
+			Some((100 * s).into()),
+			"Vesting schedules not added",
 		);
 
 		let caller: T::AccountId = whitelisted_caller();
@@ -128,47 +133,53 @@ benchmarks! {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
-			Some(100u32.into()),
-			"Vesting schedule was removed",
+			Some((100 * s).into()),
+			"Vesting schedules were removed",
 		);
 	}
 
 	vest_other_unlocked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let other: T::AccountId = account("other", 0, SEED);
 		let other_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(other.clone());
 		T::Currency::make_free_balance_be(&other, BalanceOf::<T>::max_value());
 		add_locks::<T>(&other, l as u8);
-		add_vesting_schedule::<T>(&other)?;
+		add_vesting_schedules::<T>(&other, s)?;
 		// At block 20, everything is unvested.
 		System::<T>::set_block_number(20u32.into());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
 			Some(BalanceOf::<T>::zero()),
-			"Vesting schedule still active",
+			"Vesting schedules still active",
 		);
 
 		let caller: T::AccountId = whitelisted_caller();
 	}: vest_other(RawOrigin::Signed(caller.clone()), other_lookup)
 	verify {
-		// Vesting schedule is removed!
+		// Vesting schedules are removed!
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
 			None,
-			"Vesting schedule was not removed",
+			"Vesting schedules were not removed",
 		);
 	}
 
 	vested_transfer {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 0 .. T::MaxVestingSchedules::get() - 1;
 
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
-		// Give target existing locks
+		// Give target existing locks and vesting schedules
+		let existing_locked: BalanceOf<T> = (100 * s).into();
+		let target_balance = T::Currency::minimum_balance() + existing_locked;
+		T::Currency::make_free_balance_be(&target, target_balance);
 		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s)?;
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
@@ -180,27 +191,32 @@ benchmarks! {
 	}: _(RawOrigin::Signed(caller), target_lookup, vesting_schedule)
 	verify {
 		assert_eq!(
-			T::MinVestedTransfer::get(),
-			T::Currency::free_balance(&target),
-			"Transfer didn't happen",
+			Vesting::<T>::vesting(&target).map(|schedules| schedules.len()),
+			Some(s as usize + 1),
+			"Schedule not added",
 		);
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&target),
-			Some(T::MinVestedTransfer::get()),
+			Some(T::MinVestedTransfer::get() + existing_locked),
 			"Lock not created",
 		);
 	}
 
 	force_vested_transfer {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 0 .. T::MaxVestingSchedules::get() - 1;
 
 		let source: T::AccountId = account("source", 0, SEED);
 		let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
 		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
-		// Give target existing locks
+		// Give target existing locks and vesting schedules
+		let existing_locked: BalanceOf<T> = (100 * s).into();
+		let target_balance = T::Currency::minimum_balance() + existing_locked;
+		T::Currency::make_free_balance_be(&target, target_balance);
 		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s)?;
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
@@ -212,16 +228,41 @@ benchmarks! {
 	}: _(RawOrigin::Root, source_lookup, target_lookup, vesting_schedule)
 	verify {
 		assert_eq!(
-			T::MinVestedTransfer::get(),
-			T::Currency::free_balance(&target),
-			"Transfer didn't happen",
+			Vesting::<T>::vesting(&target).map(|schedules| schedules.len()),
+			Some(s as usize + 1),
+			"Schedule not added",
 		);
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&target),
-			Some(T::MinVestedTransfer::get()),
+			Some(T::MinVestedTransfer::get() + existing_locked),
 			"Lock not created",
 		);
 	}
+
+	merge_schedules {
+		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 2 .. T::MaxVestingSchedules::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		add_locks::<T>(&caller, l as u8);
+		add_vesting_schedules::<T>(&caller, s)?;
+		// At block zero, everything is vested.
+		System::<T>::set_block_number(T::BlockNumber::zero());
+	}: _(RawOrigin::Signed(caller.clone()), 0, s - 1)
+	verify {
+		// The two schedules are replaced by a single one, still locking everything.
+		assert_eq!(
+			Vesting::<T>::vesting(&caller).map(|schedules| schedules.len()),
+			Some(s as usize - 1),
+			"Schedules not merged",
+		);
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&caller),
+			Some((100 * s).into()),
+			"Lock changed",
+		);
+	}
 }
 
 impl_benchmark_test_suite!(
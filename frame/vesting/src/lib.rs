@@ -32,6 +32,9 @@
 //! either `vest` (in typical case where the sender is calling on their own behalf) or `vest_other`
 //! in case the sender is calling on another account's behalf.
 //!
+//! An account can have up to `MaxVestingSchedules` vesting schedules at once, the amount locked
+//! being the sum of the amounts still unvested by each of them.
+//!
 //! ## Interface
 //!
 //! This pallet implements the `VestingSchedule` trait.
@@ -41,22 +44,26 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `vested_transfer` - Transfer funds to an account and vest them with a new schedule.
+//! - `force_vested_transfer` - Same as `vested_transfer` but from any account, for root.
+//! - `merge_schedules` - Merge two vesting schedules of the sender into a single one.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 use sp_std::prelude::*;
-use sp_std::fmt::Debug;
+use sp_std::{convert::TryFrom, fmt::Debug};
 use codec::{Encode, Decode};
 use sp_runtime::{RuntimeDebug, traits::{
-	StaticLookup, Zero, AtLeast32BitUnsigned, MaybeSerializeDeserialize, Convert
+	StaticLookup, Zero, One, AtLeast32BitUnsigned, MaybeSerializeDeserialize, Convert, Saturating,
 }};
-use frame_support::{ensure, pallet_prelude::*};
+use frame_support::{ensure, pallet_prelude::*, storage::bounded_vec::BoundedVec};
 use frame_support::traits::{
 	Currency, LockableCurrency, VestingSchedule, WithdrawReasons, LockIdentifier,
-	ExistenceRequirement, Get,
+	ExistenceRequirement, Get, StorageVersion,
 };
 use frame_system::{ensure_signed, ensure_root, pallet_prelude::*};
 pub use weights::WeightInfo;
@@ -64,9 +71,13 @@ pub use pallet::*;
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type MaxLocksOf<T> = <<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
+type VestingInfoOf<T> = VestingInfo<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 /// Struct to encode the vesting schedule of an individual account.
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 pub struct VestingInfo<Balance, BlockNumber> {
@@ -82,6 +93,11 @@ impl<
 	Balance: AtLeast32BitUnsigned + Copy,
 	BlockNumber: AtLeast32BitUnsigned + Copy,
 > VestingInfo<Balance, BlockNumber> {
+	/// Whether the schedule locks something and eventually unlocks it.
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && !self.per_block.is_zero()
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<
 		BlockNumberToBalance: Convert<BlockNumber, Balance>
@@ -98,6 +114,28 @@ impl<
 			Zero::zero()
 		}
 	}
+
+	/// Block number at which the schedule is fully vested, as a balance.
+	pub fn ending_block_as_balance<
+		BlockNumberToBalance: Convert<BlockNumber, Balance>
+	>(&self) -> Balance {
+		let starting_block = BlockNumberToBalance::convert(self.starting_block);
+		let duration = if self.per_block >= self.locked {
+			One::one()
+		} else if self.per_block.is_zero() {
+			// A schedule which never vests.
+			return Balance::max_value()
+		} else {
+			let remainder_block = if (self.locked % self.per_block).is_zero() {
+				Zero::zero()
+			} else {
+				One::one()
+			};
+			self.locked / self.per_block + remainder_block
+		};
+
+		starting_block.saturating_add(duration)
+	}
 }
 
 #[frame_support::pallet]
@@ -119,6 +157,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinVestedTransfer: Get<BalanceOf<Self>>;
 
+		/// The maximum number of vesting schedules an account can have at once.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -130,13 +172,24 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		VestingInfo<BalanceOf<T>, T::BlockNumber>,
+		BoundedVec<VestingInfoOf<T>, T::MaxVestingSchedules>,
 	>;
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				T::MaxVestingSchedules::get() > 0,
+				"`MaxVestingSchedules` must be greater than 0",
+			);
+		}
+	}
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub vesting: Vec<(T::AccountId, T::BlockNumber, T::BlockNumber, BalanceOf<T>)>,
@@ -154,8 +207,6 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
-			use sp_runtime::traits::Saturating;
-
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
 			// * begin - Block when the account will start to vest
@@ -167,15 +218,24 @@ pub mod pallet {
 				// Total genesis `balance` minus `liquid` equals funds locked for vesting
 				let locked = balance.saturating_sub(liquid);
 				let length_as_balance = T::BlockNumberToBalance::convert(length);
-				let per_block = locked / length_as_balance.max(sp_runtime::traits::One::one());
+				let per_block = locked / length_as_balance.max(One::one());
 
-				Vesting::<T>::insert(who, VestingInfo {
+				let schedule = VestingInfo {
 					locked: locked,
 					per_block: per_block,
 					starting_block: begin
-				});
+				};
+				assert!(schedule.is_valid(), "Invalid vesting schedule at genesis");
+				Vesting::<T>::try_append(who, schedule)
+					.expect("Too many vesting schedules at genesis");
+
+				let locked_total = Vesting::<T>::get(who).into_iter()
+					.flatten()
+					.fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+						total.saturating_add(schedule.locked)
+					});
 				let reasons = WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE;
-				T::Currency::set_lock(VESTING_ID, who, locked, reasons);
+				T::Currency::set_lock(VESTING_ID, who, locked_total, reasons);
 			}
 		}
 	}
@@ -197,10 +257,14 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// The account given is not vesting.
 		NotVesting,
-		/// An existing vesting schedule already exists for this account that cannot be clobbered.
-		ExistingVestingSchedule,
+		/// The account already has `MaxVestingSchedules` vesting schedules, no more can be added.
+		AtMaxVestingSchedules,
 		/// Amount being transferred is too low to create a vesting schedule.
 		AmountLow,
+		/// An index was out of bounds of the vesting schedules.
+		ScheduleIndexOutOfBounds,
+		/// The vesting schedule locks nothing or never unlocks anything.
+		InvalidScheduleParams,
 	}
 
 	#[pallet::call]
@@ -213,14 +277,15 @@ pub mod pallet {
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of the sender.
 		/// - DbWeight: 2 Reads, 2 Writes
 		///     - Reads: Vesting Storage, Balances Locks, [Sender Account]
 		///     - Writes: Vesting Storage, Balances Locks, [Sender Account]
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::vest_locked(MaxLocksOf::<T>::get())
-			.max(T::WeightInfo::vest_unlocked(MaxLocksOf::<T>::get()))
-		)]
+		#[pallet::weight(T::WeightInfo::vest_locked(
+			MaxLocksOf::<T>::get(),
+			T::MaxVestingSchedules::get(),
+		).max(T::WeightInfo::vest_unlocked(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())))]
 		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::update_lock(who)
@@ -236,14 +301,18 @@ pub mod pallet {
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 3 Reads, 3 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account
 		///     - Writes: Vesting Storage, Balances Locks, Target Account
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::vest_other_locked(MaxLocksOf::<T>::get())
-			.max(T::WeightInfo::vest_other_unlocked(MaxLocksOf::<T>::get()))
-		)]
+		#[pallet::weight(T::WeightInfo::vest_other_locked(
+			MaxLocksOf::<T>::get(),
+			T::MaxVestingSchedules::get(),
+		).max(T::WeightInfo::vest_other_unlocked(
+			MaxLocksOf::<T>::get(),
+			T::MaxVestingSchedules::get(),
+		)))]
 		pub fn vest_other(origin: OriginFor<T>, target: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
 			ensure_signed(origin)?;
 			Self::update_lock(T::Lookup::lookup(target)?)
@@ -254,35 +323,27 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account that should be transferred the vested funds.
-		/// - `amount`: The amount of funds to transfer and will be vested.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer, which must lock at least
+		///   `MinVestedTransfer`.
 		///
-		/// Emits `VestingCreated`.
+		/// Emits `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 3 Reads, 3 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account, [Sender Account]
 		///     - Writes: Vesting Storage, Balances Locks, Target Account, [Sender Account]
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::vested_transfer(MaxLocksOf::<T>::get()))]
+		#[pallet::weight(
+			T::WeightInfo::vested_transfer(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())
+		)]
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			target: <T::Lookup as StaticLookup>::Source,
-			schedule: VestingInfo<BalanceOf<T>, T::BlockNumber>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
-			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
-
-			let who = T::Lookup::lookup(target)?;
-			ensure!(!Vesting::<T>::contains_key(&who), Error::<T>::ExistingVestingSchedule);
-
-			T::Currency::transfer(&transactor, &who, schedule.locked, ExistenceRequirement::AllowDeath)?;
-
-			Self::add_vesting_schedule(&who, schedule.locked, schedule.per_block, schedule.starting_block)
-				.expect("user does not have an existing vesting schedule; q.e.d.");
-
-			Ok(())
+			Self::do_vested_transfer(transactor, T::Lookup::lookup(target)?, schedule)
 		}
 
 		/// Force a vested transfer.
@@ -291,60 +352,198 @@ pub mod pallet {
 		///
 		/// - `source`: The account whose funds should be transferred.
 		/// - `target`: The account that should be transferred the vested funds.
-		/// - `amount`: The amount of funds to transfer and will be vested.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer, which must lock at least
+		///   `MinVestedTransfer`.
 		///
-		/// Emits `VestingCreated`.
+		/// Emits `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 4 Reads, 4 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account, Source Account
 		///     - Writes: Vesting Storage, Balances Locks, Target Account, Source Account
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::force_vested_transfer(MaxLocksOf::<T>::get()))]
+		#[pallet::weight(T::WeightInfo::force_vested_transfer(
+			MaxLocksOf::<T>::get(),
+			T::MaxVestingSchedules::get(),
+		))]
 		pub fn force_vested_transfer(
 			origin: OriginFor<T>,
 			source: <T::Lookup as StaticLookup>::Source,
 			target: <T::Lookup as StaticLookup>::Source,
-			schedule: VestingInfo<BalanceOf<T>, T::BlockNumber>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+			Self::do_vested_transfer(
+				T::Lookup::lookup(source)?,
+				T::Lookup::lookup(target)?,
+				schedule,
+			)
+		}
 
-			let target = T::Lookup::lookup(target)?;
-			let source = T::Lookup::lookup(source)?;
-			ensure!(!Vesting::<T>::contains_key(&target), Error::<T>::ExistingVestingSchedule);
+		/// Merge two vesting schedules of the sender together, creating a new vesting schedule
+		/// which unlocks the amounts still locked by both of them until the later of their ending
+		/// blocks.
+		///
+		/// The new schedule starts at the current block, or at the later starting block of the
+		/// merged schedules if they haven't started yet. If one of them has already fully vested,
+		/// it is simply removed, and so are the other fully vested schedules of the sender. The
+		/// merged schedule is put at the end of the schedules of the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `schedule1_index`: The index of the first schedule to merge.
+		/// - `schedule2_index`: The index of the second schedule to merge.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		///
+		/// # <weight>
+		/// - `O(S)` where `S` is the number of vesting schedules of the sender.
+		/// - DbWeight: 2 Reads, 2 Writes
+		///     - Reads: Vesting Storage, Balances Locks, [Sender Account]
+		///     - Writes: Vesting Storage, Balances Locks, [Sender Account]
+		/// # </weight>
+		#[pallet::weight(
+			T::WeightInfo::merge_schedules(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())
+		)]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if schedule1_index == schedule2_index {
+				return Ok(())
+			}
 
-			T::Currency::transfer(&source, &target, schedule.locked, ExistenceRequirement::AllowDeath)?;
+			let mut schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?.into_inner();
+			let (schedule1_index, schedule2_index) =
+				(schedule1_index as usize, schedule2_index as usize);
+			ensure!(
+				schedule1_index < schedules.len() && schedule2_index < schedules.len(),
+				Error::<T>::ScheduleIndexOutOfBounds,
+			);
+
+			// Remove the later index first so that the former one is still valid.
+			let (first_index, second_index) = if schedule1_index > schedule2_index {
+				(schedule1_index, schedule2_index)
+			} else {
+				(schedule2_index, schedule1_index)
+			};
+			let schedule1 = schedules.remove(first_index);
+			let schedule2 = schedules.remove(second_index);
 
-			Self::add_vesting_schedule(&target, schedule.locked, schedule.per_block, schedule.starting_block)
-				.expect("user does not have an existing vesting schedule; q.e.d.");
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(merged) = Self::merge_vesting_info(now, schedule1, schedule2) {
+				schedules.push(merged);
+			}
 
-			Ok(())
+			Self::write_vesting(who, schedules)
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// (Re)set or remove the pallet's currency lock on `who`'s account in accordance with their
-	/// current unvested amount.
-	fn update_lock(who: T::AccountId) -> DispatchResult {
-		let vesting = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
-		let now = <frame_system::Pallet<T>>::block_number();
-		let locked_now = vesting.locked_at::<T::BlockNumberToBalance>(now);
+	/// Merge two vesting schedules into one unlocking, from `now`, what both still lock until the
+	/// later of their ending blocks.
+	///
+	/// Returns `None` if both schedules are fully vested at `now`.
+	fn merge_vesting_info(
+		now: T::BlockNumber,
+		schedule1: VestingInfoOf<T>,
+		schedule2: VestingInfoOf<T>,
+	) -> Option<VestingInfoOf<T>> {
+		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+		// A fully vested schedule has nothing left to merge.
+		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
+			(true, true) => return None,
+			(true, false) => return Some(schedule2),
+			(false, true) => return Some(schedule1),
+			(false, false) => {},
+		}
+
+		let locked = schedule1.locked_at::<T::BlockNumberToBalance>(now)
+			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
+		let ending_block = schedule1_ending_block.max(schedule2_ending_block);
+		let starting_block = now.max(schedule1.starting_block).max(schedule2.starting_block);
+		let duration = ending_block
+			.saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+			.max(One::one());
+		let per_block = (locked / duration).max(One::one());
+
+		let schedule = VestingInfo { locked, per_block, starting_block };
+		if schedule.is_valid() {
+			Some(schedule)
+		} else {
+			None
+		}
+	}
+
+	/// Transfer the amount locked by `schedule` from `source` to `target` and vest it.
+	fn do_vested_transfer(
+		source: T::AccountId,
+		target: T::AccountId,
+		schedule: VestingInfoOf<T>,
+	) -> DispatchResult {
+		ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+		ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+		Self::can_add_vesting_schedule(
+			&target,
+			schedule.locked,
+			schedule.per_block,
+			schedule.starting_block,
+		)?;
+
+		T::Currency::transfer(&source, &target, schedule.locked, ExistenceRequirement::AllowDeath)?;
+
+		Self::add_vesting_schedule(
+			&target,
+			schedule.locked,
+			schedule.per_block,
+			schedule.starting_block,
+		).expect("schedule checked by `can_add_vesting_schedule`; q.e.d.");
 
-		if locked_now.is_zero() {
+		Ok(())
+	}
+
+	/// Write the vesting schedules of `who` without the ones which are fully vested, and (re)set
+	/// or remove the pallet's currency lock on `who`'s account in accordance with their current
+	/// unvested amount.
+	fn write_vesting(who: T::AccountId, schedules: Vec<VestingInfoOf<T>>) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut locked_now: BalanceOf<T> = Zero::zero();
+		let schedules = schedules.into_iter()
+			.filter(|schedule| {
+				let locked = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				locked_now = locked_now.saturating_add(locked);
+				!locked.is_zero()
+			})
+			.collect::<Vec<_>>();
+
+		if schedules.is_empty() {
 			T::Currency::remove_lock(VESTING_ID, &who);
 			Vesting::<T>::remove(&who);
 			Self::deposit_event(Event::<T>::VestingCompleted(who));
 		} else {
+			let schedules = BoundedVec::<_, T::MaxVestingSchedules>::try_from(schedules)
+				.map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+			Vesting::<T>::insert(&who, schedules);
 			let reasons = WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE;
 			T::Currency::set_lock(VESTING_ID, &who, locked_now, reasons);
 			Self::deposit_event(Event::<T>::VestingUpdated(who, locked_now));
 		}
 		Ok(())
 	}
+
+	/// (Re)set or remove the pallet's currency lock on `who`'s account in accordance with their
+	/// current unvested amount, removing their fully vested schedules.
+	fn update_lock(who: T::AccountId) -> DispatchResult {
+		let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+		Self::write_vesting(who, schedules.into_inner())
+	}
 }
 
 impl<T: Config> VestingSchedule<T::AccountId> for Pallet<T> where
@@ -355,9 +554,11 @@ impl<T: Config> VestingSchedule<T::AccountId> for Pallet<T> where
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Self::vesting(who) {
+		if let Some(schedules) = Self::vesting(who) {
 			let now = <frame_system::Pallet<T>>::block_number();
-			let locked_now = v.locked_at::<T::BlockNumberToBalance>(now);
+			let locked_now = schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+				total.saturating_add(schedule.locked_at::<T::BlockNumberToBalance>(now))
+			});
 			Some(T::Currency::free_balance(who).min(locked_now))
 		} else {
 			None
@@ -366,8 +567,8 @@ impl<T: Config> VestingSchedule<T::AccountId> for Pallet<T> where
 
 	/// Adds a vesting schedule to a given account.
 	///
-	/// If there already exists a vesting schedule for the given account, an `Err` is returned
-	/// and nothing is updated.
+	/// If the account already has `MaxVestingSchedules` vesting schedules, or the schedule is
+	/// invalid, an `Err` is returned and nothing is updated.
 	///
 	/// On success, a linearly reducing amount of funds will be locked. In order to realise any
 	/// reduction of the lock over time as it diminishes, the account owner must use `vest` or
@@ -381,27 +582,48 @@ impl<T: Config> VestingSchedule<T::AccountId> for Pallet<T> where
 		starting_block: T::BlockNumber
 	) -> DispatchResult {
 		if locked.is_zero() { return Ok(()) }
-		if Vesting::<T>::contains_key(who) {
-			Err(Error::<T>::ExistingVestingSchedule)?
-		}
+		Self::can_add_vesting_schedule(who, locked, per_block, starting_block)?;
+
 		let vesting_schedule = VestingInfo {
 			locked,
 			per_block,
 			starting_block
 		};
-		Vesting::<T>::insert(who, vesting_schedule);
+		let mut schedules = Self::vesting(who).map(|s| s.into_inner()).unwrap_or_default();
+		schedules.push(vesting_schedule);
 		// it can't fail, but even if somehow it did, we don't really care.
-		let res = Self::update_lock(who.clone());
+		let res = Self::write_vesting(who.clone(), schedules);
 		debug_assert!(res.is_ok());
 		Ok(())
 	}
 
-	/// Remove a vesting schedule for a given account.
-	fn remove_vesting_schedule(who: &T::AccountId) {
-		Vesting::<T>::remove(who);
-		// it can't fail, but even if somehow it did, we don't really care.
-		let res = Self::update_lock(who.clone());
-		debug_assert!(res.is_ok());
+	/// Ensure a vesting schedule can be added to the given account.
+	fn can_add_vesting_schedule(
+		who: &T::AccountId,
+		locked: BalanceOf<T>,
+		per_block: BalanceOf<T>,
+		starting_block: T::BlockNumber,
+	) -> DispatchResult {
+		let schedule = VestingInfo { locked, per_block, starting_block };
+		ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+
+		let schedule_count = Self::vesting(who).map_or(0, |schedules| schedules.len());
+		ensure!(
+			schedule_count < T::MaxVestingSchedules::get() as usize,
+			Error::<T>::AtMaxVestingSchedules,
+		);
+		Ok(())
+	}
+
+	/// Remove the vesting schedule at `schedule_index` of the given account.
+	fn remove_vesting_schedule(who: &T::AccountId, schedule_index: u32) -> DispatchResult {
+		let mut schedules = Self::vesting(who).ok_or(Error::<T>::NotVesting)?.into_inner();
+		ensure!(
+			(schedule_index as usize) < schedules.len(),
+			Error::<T>::ScheduleIndexOutOfBounds,
+		);
+		schedules.remove(schedule_index as usize);
+		Self::write_vesting(who.clone(), schedules)
 	}
 }
 
@@ -436,7 +658,9 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub BlockWeights: frame_system::limits::BlockWeights =
-			frame_system::limits::BlockWeights::simple_max(1024);
+			frame_system::limits::BlockWeights::simple_max(
+				frame_support::weights::Weight::from_ref_time(1024),
+			);
 	}
 	impl frame_system::Config for Test {
 		type BaseCallFilter = ();
@@ -475,10 +699,12 @@ mod tests {
 		type MaxLocks = MaxLocks;
 		type MaxReserves = ();
 		type ReserveIdentifier = [u8; 8];
+		type HoldsAndFreezes = ();
 		type WeightInfo = ();
 	}
 	parameter_types! {
 		pub const MinVestedTransfer: u64 = 256 * 2;
+		pub const MaxVestingSchedules: u32 = 3;
 		pub static ExistentialDeposit: u64 = 0;
 	}
 	impl Config for Test {
@@ -486,6 +712,7 @@ mod tests {
 		type Currency = Balances;
 		type BlockNumberToBalance = Identity;
 		type MinVestedTransfer = MinVestedTransfer;
+		type MaxVestingSchedules = MaxVestingSchedules;
 		type WeightInfo = ();
 	}
 
@@ -556,9 +783,9 @@ mod tests {
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&1), Some(user1_vesting_schedule)); // Account 1 has a vesting schedule
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule)); // Account 2 has a vesting schedule
-				assert_eq!(Vesting::vesting(&12), Some(user12_vesting_schedule)); // Account 12 has a vesting schedule
+				assert_eq!(Vesting::vesting(&1).unwrap(), vec![user1_vesting_schedule]); // Account 1 has a vesting schedule
+				assert_eq!(Vesting::vesting(&2).unwrap(), vec![user2_vesting_schedule]); // Account 2 has a vesting schedule
+				assert_eq!(Vesting::vesting(&12).unwrap(), vec![user12_vesting_schedule]); // Account 12 has a vesting schedule
 
 				// Account 1 has only 128 units vested from their illiquid 256 * 5 units at block 1
 				assert_eq!(Vesting::vesting_balance(&1), Some(128 * 9));
@@ -679,7 +906,7 @@ mod tests {
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&12), Some(user12_vesting_schedule));
+				assert_eq!(Vesting::vesting(&12).unwrap(), vec![user12_vesting_schedule]);
 
 				// Account 12 can still send liquid funds
 				assert_ok!(Balances::transfer(Some(12).into(), 3, 256 * 5));
@@ -706,7 +933,7 @@ mod tests {
 				};
 				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, new_vesting_schedule));
 				// Now account 4 should have vesting.
-				assert_eq!(Vesting::vesting(&4), Some(new_vesting_schedule));
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![new_vesting_schedule]);
 				// Ensure the transfer happened correctly.
 				let user3_free_balance_updated = Balances::free_balance(&3);
 				assert_eq!(user3_free_balance_updated, 256 * 25);
@@ -745,17 +972,32 @@ mod tests {
 					per_block: 256, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule));
+				assert_eq!(Vesting::vesting(&2).unwrap(), vec![user2_vesting_schedule]);
 
-				// The vesting schedule we will try to create, fails due to pre-existence of schedule.
+				// Account 2 can get schedules up to `MaxVestingSchedules`, then creating another
+				// one fails.
 				let new_vesting_schedule = VestingInfo {
 					locked: 256 * 5,
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
+				for _ in 1..MaxVestingSchedules::get() {
+					assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+				}
 				assert_noop!(
 					Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule),
-					Error::<Test>::ExistingVestingSchedule,
+					Error::<Test>::AtMaxVestingSchedules,
+				);
+
+				// Fails due to a schedule which never unlocks anything.
+				let new_vesting_schedule_invalid = VestingInfo {
+					locked: 256 * 5,
+					per_block: 0,
+					starting_block: 10,
+				};
+				assert_noop!(
+					Vesting::vested_transfer(Some(4).into(), 3, new_vesting_schedule_invalid),
+					Error::<Test>::InvalidScheduleParams,
 				);
 
 				// Fails due to too low transfer amount.
@@ -769,9 +1011,9 @@ mod tests {
 					Error::<Test>::AmountLow,
 				);
 
-				// Verify no currency transfer happened.
-				assert_eq!(user2_free_balance, 256 * 20);
-				assert_eq!(user4_free_balance, 256 * 40);
+				// Verify only the successful transfers happened.
+				assert_eq!(Balances::free_balance(&2), user2_free_balance + 2 * 256 * 5);
+				assert_eq!(Balances::free_balance(&4), user4_free_balance - 2 * 256 * 5);
 			});
 	}
 
@@ -796,7 +1038,7 @@ mod tests {
 				assert_noop!(Vesting::force_vested_transfer(Some(4).into(), 3, 4, new_vesting_schedule), BadOrigin);
 				assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, 4, new_vesting_schedule));
 				// Now account 4 should have vesting.
-				assert_eq!(Vesting::vesting(&4), Some(new_vesting_schedule));
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![new_vesting_schedule]);
 				// Ensure the transfer happened correctly.
 				let user3_free_balance_updated = Balances::free_balance(&3);
 				assert_eq!(user3_free_balance_updated, 256 * 25);
@@ -835,17 +1077,47 @@ mod tests {
 					per_block: 256, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule));
+				assert_eq!(Vesting::vesting(&2).unwrap(), vec![user2_vesting_schedule]);
 
-				// The vesting schedule we will try to create, fails due to pre-existence of schedule.
+				// Account 2 can get schedules up to `MaxVestingSchedules`, then creating another
+				// one fails.
 				let new_vesting_schedule = VestingInfo {
 					locked: 256 * 5,
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
+				for _ in 1..MaxVestingSchedules::get() {
+					assert_ok!(Vesting::force_vested_transfer(
+						RawOrigin::Root.into(),
+						4,
+						2,
+						new_vesting_schedule,
+					));
+				}
+				assert_noop!(
+					Vesting::force_vested_transfer(
+						RawOrigin::Root.into(),
+						4,
+						2,
+						new_vesting_schedule,
+					),
+					Error::<Test>::AtMaxVestingSchedules,
+				);
+
+				// Fails due to a schedule which never unlocks anything.
+				let new_vesting_schedule_invalid = VestingInfo {
+					locked: 256 * 5,
+					per_block: 0,
+					starting_block: 10,
+				};
 				assert_noop!(
-					Vesting::force_vested_transfer(RawOrigin::Root.into(), 4, 2, new_vesting_schedule),
-					Error::<Test>::ExistingVestingSchedule,
+					Vesting::force_vested_transfer(
+						RawOrigin::Root.into(),
+						4,
+						3,
+						new_vesting_schedule_invalid,
+					),
+					Error::<Test>::InvalidScheduleParams,
 				);
 
 				// Fails due to too low transfer amount.
@@ -859,9 +1131,150 @@ mod tests {
 					Error::<Test>::AmountLow,
 				);
 
-				// Verify no currency transfer happened.
-				assert_eq!(user2_free_balance, 256 * 20);
-				assert_eq!(user4_free_balance, 256 * 40);
+				// Verify only the successful transfers happened.
+				assert_eq!(Balances::free_balance(&2), user2_free_balance + 2 * 256 * 5);
+				assert_eq!(Balances::free_balance(&4), user4_free_balance - 2 * 256 * 5);
+			});
+	}
+
+	#[test]
+	fn multiple_schedules_lock_their_sum() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let schedule1 = VestingInfo {
+					locked: 256 * 5,
+					per_block: 256, // Vesting over 5 blocks
+					starting_block: 1,
+				};
+				let schedule2 = VestingInfo {
+					locked: 256 * 10,
+					per_block: 256, // Vesting over 10 blocks
+					starting_block: 10,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule1));
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule2));
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![schedule1, schedule2]);
+				assert_eq!(Vesting::vesting_balance(&4), Some(256 * 15));
+
+				// The first schedule has fully vested, vesting removes it.
+				System::set_block_number(6);
+				assert_eq!(Vesting::vesting_balance(&4), Some(256 * 10));
+				assert_ok!(Vesting::vest(Some(4).into()));
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![schedule2]);
+				System::assert_last_event(
+					pallet_vesting::Event::<Test>::VestingUpdated(4, 256 * 10).into(),
+				);
+
+				// Both have fully vested.
+				System::set_block_number(20);
+				assert_ok!(Vesting::vest(Some(4).into()));
+				assert_eq!(Vesting::vesting(&4), None);
+				System::assert_last_event(
+					pallet_vesting::Event::<Test>::VestingCompleted(4).into(),
+				);
+			});
+	}
+
+	#[test]
+	fn merge_schedules_works() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let schedule1 = VestingInfo {
+					locked: 256 * 10,
+					per_block: 256, // Vesting over 10 blocks
+					starting_block: 0,
+				};
+				let schedule2 = VestingInfo {
+					locked: 256 * 10,
+					per_block: 128, // Vesting over 20 blocks
+					starting_block: 10,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule1));
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule2));
+
+				assert_noop!(
+					Vesting::merge_schedules(Some(3).into(), 0, 1),
+					Error::<Test>::NotVesting,
+				);
+				assert_noop!(
+					Vesting::merge_schedules(Some(4).into(), 0, 2),
+					Error::<Test>::ScheduleIndexOutOfBounds,
+				);
+
+				// The merged schedule unlocks what remains of both, starting with the later one,
+				// until the end of the later one.
+				System::set_block_number(5);
+				assert_ok!(Vesting::merge_schedules(Some(4).into(), 0, 1));
+				let merged_schedule = VestingInfo {
+					locked: 256 * 5 + 256 * 10,
+					per_block: 256 * 15 / 20,
+					starting_block: 10,
+				};
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![merged_schedule]);
+				assert_eq!(Vesting::vesting_balance(&4), Some(256 * 15));
+				System::assert_last_event(
+					pallet_vesting::Event::<Test>::VestingUpdated(4, 256 * 15).into(),
+				);
+
+				System::set_block_number(30);
+				assert_eq!(Vesting::vesting_balance(&4), Some(0));
+			});
+	}
+
+	#[test]
+	fn merge_schedules_drops_fully_vested_schedule() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let schedule1 = VestingInfo {
+					locked: 256 * 10,
+					per_block: 256, // Vesting over 10 blocks
+					starting_block: 0,
+				};
+				let schedule2 = VestingInfo {
+					locked: 256 * 10,
+					per_block: 128, // Vesting over 20 blocks
+					starting_block: 10,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule1));
+				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule2));
+
+				// The first schedule has fully vested, only the second one is left.
+				System::set_block_number(12);
+				assert_ok!(Vesting::merge_schedules(Some(4).into(), 1, 0));
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![schedule2]);
+				assert_eq!(Vesting::vesting_balance(&4), Some(256 * 10 - 128 * 2));
+			});
+	}
+
+	#[test]
+	fn migrate_to_v1_works() {
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let schedule = VestingInfo {
+					locked: 256 * 5,
+					per_block: 64,
+					starting_block: 10,
+				};
+				// Only a single schedule stored as by the previous version of the pallet.
+				pallet_vesting::Vesting::<Test>::remove_all();
+				let key = pallet_vesting::Vesting::<Test>::hashed_key_for(&4);
+				sp_io::storage::set(&key, &schedule.encode());
+				StorageVersion::new(0).put::<Vesting>();
+
+				migrations::MigrateToV1::<Test>::on_runtime_upgrade();
+
+				assert_eq!(Vesting::vesting(&4).unwrap(), vec![schedule]);
+				assert_eq!(Vesting::on_chain_storage_version(), StorageVersion::new(1));
 			});
 	}
 }
@@ -0,0 +1,45 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations for the Vesting pallet.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+/// Turn the single vesting schedule stored for each account by the previous version of the pallet
+/// into a list of vesting schedules.
+pub struct MigrateToV1<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			log::info!(target: "runtime::vesting", "MigrateToV1 should be removed");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated = 0u64;
+		Vesting::<T>::translate::<VestingInfoOf<T>, _>(|_, schedule| {
+			translated += 1;
+			BoundedVec::try_from(vec![schedule]).ok()
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "runtime::vesting", "Migrated {} vesting schedules", translated);
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+}
@@ -0,0 +1,235 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # VRF Randomness Pallet
+//!
+//! Randomness accumulated from the VRF outputs revealed by the block authors, e.g. with BABE.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! The pallet hashes together the VRF outputs of all the blocks of an epoch, as provided by
+//! [`Config::VrfOutputSource`]. When the epoch ends, the accumulated value becomes the randomness
+//! of the epoch, and is kept in a ring buffer of the last [`Config::HistoryDepth`] epochs.
+//!
+//! Unlike `pallet-randomness-collective-flip`, whose randomness is derived from the hashes of the
+//! previous blocks and can be biased by any block author, the randomness of an epoch can only be
+//! biased by the authors of the last blocks of the epoch, by withholding their block.
+//!
+//! ## Known since
+//!
+//! The randomness of an epoch is determinable by chain observers from the last block of the
+//! epoch, which is the block number returned along with it by the [`Randomness`]
+//! implementation. Only the commitments made before that block may be resolved with it, later
+//! commitments must wait for the randomness of a later epoch.
+//!
+//! No randomness is available before the first epoch is completed, the [`Randomness`]
+//! implementation then returns `None`.
+//!
+//! ## Runtime integration
+//!
+//! The pallet reads the VRF output of each block in `on_finalize`, so it must be declared after
+//! the source of the VRF outputs, e.g. `Babe`, in `construct_runtime!`, for it to be finalized
+//! before the VRF output of the block is cleared. [`BabeVrfOutputs`] provides the VRF outputs of
+//! BABE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use frame_support::{traits::Randomness, RuntimeDebug};
+use sp_runtime::traits::{Hash, One, Saturating, Zero};
+use sp_std::marker::PhantomData;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The length of a VRF output.
+pub const VRF_OUTPUT_LENGTH: usize = 32;
+
+/// A source of VRF outputs, revealed by the block authors and grouped in epochs.
+pub trait VrfOutputSource {
+	/// The index of the current epoch.
+	fn epoch_index() -> u64;
+
+	/// The VRF output revealed by the author of the current block, if any.
+	///
+	/// This is expected to be available until the end of `on_finalize` of the pallet.
+	fn block_vrf_output() -> Option<[u8; VRF_OUTPUT_LENGTH]>;
+}
+
+/// The VRF outputs of the BABE block authors, grouped in BABE epochs.
+pub struct BabeVrfOutputs<T>(PhantomData<T>);
+
+impl<T: pallet_babe::Config> VrfOutputSource for BabeVrfOutputs<T> {
+	fn epoch_index() -> u64 {
+		pallet_babe::Pallet::<T>::epoch_index()
+	}
+
+	fn block_vrf_output() -> Option<[u8; VRF_OUTPUT_LENGTH]> {
+		pallet_babe::Pallet::<T>::author_vrf_randomness()
+	}
+}
+
+/// The randomness of a completed epoch.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct EpochRandomness<Hash, BlockNumber> {
+	/// The index of the epoch.
+	pub epoch: u64,
+	/// The accumulation of the VRF outputs of the epoch.
+	pub randomness: Hash,
+	/// The block since which the randomness is determinable, i.e. the last block of the epoch.
+	pub known_since: BlockNumber,
+}
+
+/// The randomness of a completed epoch, as stored by the pallet.
+pub type EpochRandomnessOf<T> =
+	EpochRandomness<<T as frame_system::Config>::Hash, <T as frame_system::Config>::BlockNumber>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The source of the VRF outputs and of the epochs.
+		type VrfOutputSource: VrfOutputSource;
+
+		/// The number of completed epochs whose randomness is kept.
+		#[pallet::constant]
+		type HistoryDepth: Get<u32>;
+
+		/// Weight information for the operations of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The accumulation of the VRF outputs of the current epoch so far.
+	#[pallet::storage]
+	pub type Accumulator<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+	/// The epoch whose VRF outputs are being accumulated, if any block was finalized yet.
+	#[pallet::storage]
+	pub type AccumulatingEpoch<T: Config> = StorageValue<_, u64>;
+
+	/// The randomness of the last [`Config::HistoryDepth`] completed epochs.
+	///
+	/// This is a ring buffer, the randomness of an epoch is at the index
+	/// `epoch % HistoryDepth`.
+	#[pallet::storage]
+	pub type History<T: Config> = StorageMap<
+		_,
+		Twox64Concat, u32,
+		EpochRandomnessOf<T>,
+	>;
+
+	/// The last completed epoch.
+	#[pallet::storage]
+	pub type LastCompletedEpoch<T: Config> = StorageValue<_, u64>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			// just return the weight of the on_finalize.
+			T::WeightInfo::on_finalize()
+		}
+
+		fn on_finalize(now: BlockNumberFor<T>) {
+			Self::accumulate(now);
+		}
+
+		fn integrity_test() {
+			assert!(T::HistoryDepth::get() > 0, "the history depth must not be zero");
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The randomness of `epoch`, if it is completed and still in the history.
+	pub fn epoch_randomness(epoch: u64) -> Option<EpochRandomnessOf<T>> {
+		History::<T>::get(Self::history_index(epoch)).filter(|r| r.epoch == epoch)
+	}
+
+	/// The randomness of the last completed epoch, if any.
+	pub fn last_epoch_randomness() -> Option<EpochRandomnessOf<T>> {
+		LastCompletedEpoch::<T>::get().and_then(Self::epoch_randomness)
+	}
+
+	/// The randomness of `epoch` for `subject`, with the block since which it is known, if the
+	/// epoch is completed and still in the history.
+	pub fn random_of_epoch(epoch: u64, subject: &[u8]) -> Option<(T::Hash, T::BlockNumber)> {
+		Self::epoch_randomness(epoch)
+			.map(|r| (Self::mix(subject, &r.randomness), r.known_since))
+	}
+
+	/// Accumulate the VRF output of the block `now`, completing the previous epoch first if `now`
+	/// is the first block of a new epoch.
+	fn accumulate(now: T::BlockNumber) {
+		let epoch = T::VrfOutputSource::epoch_index();
+		match AccumulatingEpoch::<T>::get() {
+			Some(accumulating) if accumulating != epoch => {
+				let completed = EpochRandomness {
+					epoch: accumulating,
+					randomness: Accumulator::<T>::take(),
+					known_since: now.saturating_sub(One::one()),
+				};
+				History::<T>::insert(Self::history_index(accumulating), completed);
+				LastCompletedEpoch::<T>::put(accumulating);
+			},
+			_ => {},
+		}
+		AccumulatingEpoch::<T>::put(epoch);
+
+		if let Some(output) = T::VrfOutputSource::block_vrf_output() {
+			Accumulator::<T>::mutate(|accumulator| {
+				*accumulator = T::Hashing::hash_of(&(&*accumulator, output));
+			});
+		}
+	}
+
+	fn history_index(epoch: u64) -> u32 {
+		(epoch % T::HistoryDepth::get().max(1) as u64) as u32
+	}
+
+	fn mix(subject: &[u8], randomness: &T::Hash) -> T::Hash {
+		T::Hashing::hash_of(&(subject, randomness))
+	}
+}
+
+impl<T: Config> Randomness<Option<T::Hash>, T::BlockNumber> for Pallet<T> {
+	/// The randomness of the last completed epoch for `subject`.
+	///
+	/// Before the first epoch is completed, no randomness is available: `None` is returned
+	/// along with the block number zero.
+	fn random(subject: &[u8]) -> (Option<T::Hash>, T::BlockNumber) {
+		match Self::last_epoch_randomness() {
+			Some(r) => (Some(Self::mix(subject, &r.randomness)), r.known_since),
+			None => (None, Zero::zero()),
+		}
+	}
+}
@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Test utilities
+
+use super::*;
+use crate as pallet_vrf_randomness;
+use frame_support::{parameter_types, traits::{OnFinalize, OnInitialize}};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::{BlakeTwo256, IdentityLookup}};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		VrfRandomness: pallet_vrf_randomness::{Pallet, Storage},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+thread_local! {
+	static EPOCH: RefCell<u64> = RefCell::new(0);
+	static VRF_OUTPUT: RefCell<Option<[u8; VRF_OUTPUT_LENGTH]>> = RefCell::new(None);
+}
+
+/// Epochs and VRF outputs set by the tests.
+pub struct MockVrfOutputs;
+impl VrfOutputSource for MockVrfOutputs {
+	fn epoch_index() -> u64 {
+		EPOCH.with(|epoch| *epoch.borrow())
+	}
+
+	fn block_vrf_output() -> Option<[u8; VRF_OUTPUT_LENGTH]> {
+		VRF_OUTPUT.with(|output| *output.borrow())
+	}
+}
+
+parameter_types! {
+	pub const HistoryDepth: u32 = 3;
+}
+
+impl Config for Test {
+	type VrfOutputSource = MockVrfOutputs;
+	type HistoryDepth = HistoryDepth;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		EPOCH.with(|epoch| *epoch.borrow_mut() = 0);
+		VRF_OUTPUT.with(|output| *output.borrow_mut() = None);
+	});
+	ext
+}
+
+/// Run the next block in `epoch`, its author revealing `vrf_output`.
+pub fn run_block(epoch: u64, vrf_output: Option<[u8; VRF_OUTPUT_LENGTH]>) {
+	let number = System::block_number() + 1;
+	System::set_block_number(number);
+	EPOCH.with(|e| *e.borrow_mut() = epoch);
+	VRF_OUTPUT.with(|output| *output.borrow_mut() = vrf_output);
+	VrfRandomness::on_initialize(number);
+	VrfRandomness::on_finalize(number);
+}
@@ -0,0 +1,112 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::mock::*;
+use sp_core::H256;
+
+fn accumulate(outputs: &[[u8; VRF_OUTPUT_LENGTH]]) -> H256 {
+	outputs.iter().fold(H256::default(), |accumulator, output| {
+		<Test as frame_system::Config>::Hashing::hash_of(&(accumulator, *output))
+	})
+}
+
+#[test]
+fn vrf_outputs_are_accumulated_over_an_epoch() {
+	new_test_ext().execute_with(|| {
+		run_block(0, Some([1; 32]));
+		run_block(0, None);
+		run_block(0, Some([2; 32]));
+		assert_eq!(Accumulator::<Test>::get(), accumulate(&[[1; 32], [2; 32]]));
+		assert_eq!(VrfRandomness::last_epoch_randomness(), None);
+
+		// the first block of epoch 1 completes epoch 0, and its output goes to epoch 1.
+		run_block(1, Some([3; 32]));
+		assert_eq!(
+			VrfRandomness::epoch_randomness(0),
+			Some(EpochRandomness {
+				epoch: 0,
+				randomness: accumulate(&[[1; 32], [2; 32]]),
+				known_since: 3,
+			}),
+		);
+		assert_eq!(LastCompletedEpoch::<Test>::get(), Some(0));
+		assert_eq!(AccumulatingEpoch::<Test>::get(), Some(1));
+		assert_eq!(Accumulator::<Test>::get(), accumulate(&[[3; 32]]));
+	});
+}
+
+#[test]
+fn random_returns_the_randomness_of_the_last_completed_epoch() {
+	new_test_ext().execute_with(|| {
+		// no randomness is available before the first epoch is completed.
+		run_block(0, Some([1; 32]));
+		assert_eq!(VrfRandomness::random(b"subject"), (None, 0));
+
+		run_block(1, Some([2; 32]));
+		run_block(1, Some([3; 32]));
+		let (random, known_since) = VrfRandomness::random(b"subject");
+		let random = random.unwrap();
+		assert_eq!(known_since, 1);
+		assert_eq!(Some((random, known_since)), VrfRandomness::random_of_epoch(0, b"subject"));
+		assert_ne!(Some(random), VrfRandomness::random(b"other subject").0);
+
+		// the randomness does not change within an epoch.
+		run_block(1, Some([4; 32]));
+		assert_eq!(VrfRandomness::random(b"subject"), (Some(random), known_since));
+
+		run_block(2, None);
+		let (new_random, known_since) = VrfRandomness::random(b"subject");
+		assert_eq!(known_since, 4);
+		assert_ne!(new_random, Some(random));
+	});
+}
+
+#[test]
+fn history_is_a_ring_buffer() {
+	new_test_ext().execute_with(|| {
+		for epoch in 0..5 {
+			run_block(epoch, Some([epoch as u8; 32]));
+		}
+		run_block(5, None);
+
+		// only the last `HistoryDepth` epochs are kept.
+		assert_eq!(History::<Test>::iter().count(), 3);
+		for epoch in 0..2 {
+			assert_eq!(VrfRandomness::epoch_randomness(epoch), None);
+			assert_eq!(VrfRandomness::random_of_epoch(epoch, b"subject"), None);
+		}
+		for epoch in 2..5 {
+			assert_eq!(
+				VrfRandomness::epoch_randomness(epoch).map(|r| r.randomness),
+				Some(accumulate(&[[epoch as u8; 32]])),
+			);
+		}
+		// the current epoch is not completed yet.
+		assert_eq!(VrfRandomness::epoch_randomness(5), None);
+	});
+}
+
+#[test]
+fn skipped_epochs_have_no_randomness() {
+	new_test_ext().execute_with(|| {
+		run_block(0, Some([1; 32]));
+		run_block(2, Some([2; 32]));
+		assert!(VrfRandomness::epoch_randomness(0).is_some());
+		assert_eq!(VrfRandomness::epoch_randomness(1), None);
+		assert_eq!(LastCompletedEpoch::<Test>::get(), Some(0));
+	});
+}
@@ -0,0 +1,57 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_vrf_randomness
+//!
+//! These weights are estimated from the storage accesses of the operations and are meant to be
+//! replaced by the results of benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_vrf_randomness.
+pub trait WeightInfo {
+	fn on_finalize() -> Weight;
+}
+
+/// Weights for pallet_vrf_randomness using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Babe EpochIndex (r:1 w:0)
+	// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	// Storage: VrfRandomness AccumulatingEpoch (r:1 w:1)
+	// Storage: VrfRandomness Accumulator (r:2 w:2)
+	// Storage: VrfRandomness History (r:0 w:1)
+	// Storage: VrfRandomness LastCompletedEpoch (r:0 w:1)
+	fn on_finalize() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn on_finalize() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+}
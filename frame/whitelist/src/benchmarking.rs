@@ -0,0 +1,93 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Whitelist pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+use crate::Pallet as Whitelist;
+
+/// A call which may be dispatched with the root origin, and whose weight is the same whatever
+/// its parameter.
+fn heap_pages_call<T: Config>(pages: u64) -> <T as Config>::Call {
+	frame_system::Call::<T>::set_heap_pages(pages).into()
+}
+
+/// Whitelist `call`, returning its hash.
+fn whitelist<T: Config>(call: &<T as Config>::Call) -> T::Hash {
+	let call_hash = T::Hashing::hash_of(call);
+	let origin = T::WhitelistOrigin::successful_origin();
+	assert!(Whitelist::<T>::whitelist_call(origin, call_hash).is_ok());
+	call_hash
+}
+
+benchmarks! {
+	whitelist_call {
+		let origin = T::WhitelistOrigin::successful_origin();
+		let call_hash = Default::default();
+		let call = Call::<T>::whitelist_call(call_hash);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(WhitelistedCall::<T>::contains_key(call_hash));
+		assert!(T::Preimages::is_requested(&call_hash));
+	}
+
+	remove_whitelisted_call {
+		let call_hash = whitelist::<T>(&heap_pages_call::<T>(1));
+		let origin = T::WhitelistOrigin::successful_origin();
+		let call = Call::<T>::remove_whitelisted_call(call_hash);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!WhitelistedCall::<T>::contains_key(call_hash));
+		assert!(!T::Preimages::is_requested(&call_hash));
+	}
+
+	// The weight of the dispatched call is excluded from the measure in the weights.
+	dispatch_whitelisted_call {
+		let whitelisted = heap_pages_call::<T>(1);
+		let call_hash = whitelist::<T>(&whitelisted);
+		assert!(T::Preimages::note_encoded(&whitelisted).is_ok());
+		let weight = whitelisted.get_dispatch_info().weight;
+		let origin = T::DispatchWhitelistedOrigin::successful_origin();
+		let call = Call::<T>::dispatch_whitelisted_call(call_hash, weight);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!WhitelistedCall::<T>::contains_key(call_hash));
+		assert!(!T::Preimages::have(&call_hash));
+	}
+
+	dispatch_whitelisted_call_with_preimage {
+		let whitelisted = heap_pages_call::<T>(1);
+		let call_hash = whitelist::<T>(&whitelisted);
+		let origin = T::DispatchWhitelistedOrigin::successful_origin();
+		let call = Call::<T>::dispatch_whitelisted_call_with_preimage(Box::new(whitelisted));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!WhitelistedCall::<T>::contains_key(call_hash));
+	}
+}
+
+impl_benchmark_test_suite!(
+	Whitelist,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
@@ -0,0 +1,242 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Whitelist Pallet
+//!
+//! Fast-tracks calls to be dispatched with the root origin, once whitelisted.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Overview
+//!
+//! [`Config::WhitelistOrigin`], e.g. a technical collective, whitelists the hash of a call with
+//! [`Pallet::whitelist_call`]. [`Config::DispatchWhitelistedOrigin`], e.g. a referendum track with
+//! shorter periods than the root track, may then dispatch the call with the root origin, exactly
+//! once: the call is removed from the whitelist when it is dispatched.
+//!
+//! The preimage of a whitelisted call is requested from [`Config::Preimages`], e.g. the preimage
+//! pallet, so that anyone may note it for free, and the call is dispatched from it with
+//! [`Pallet::dispatch_whitelisted_call`]. Alternatively, the call itself is given to
+//! [`Pallet::dispatch_whitelisted_call_with_preimage`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::prelude::*;
+use codec::Decode;
+use sp_runtime::traits::Hash;
+use frame_support::{
+	ensure,
+	dispatch::DispatchResult,
+	traits::{QueryPreimage, StorePreimage, UnfilteredDispatchable},
+	weights::{GetDispatchInfo, Weight},
+};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The calls which may be whitelisted.
+		type Call: Parameter
+			+ UnfilteredDispatchable<Origin = Self::Origin>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// The origin which may whitelist a call, or remove it from the whitelist.
+		type WhitelistOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may dispatch a whitelisted call.
+		type DispatchWhitelistedOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The provider of the preimages of the whitelisted calls, in which they may be noted.
+		type Preimages: StorePreimage<Self::Hash>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The hashes of the whitelisted calls.
+	#[pallet::storage]
+	pub type WhitelistedCall<T: Config> = StorageMap<_, Twox64Concat, T::Hash, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::Hash = "Hash")]
+	pub enum Event<T: Config> {
+		/// A call was whitelisted. \[call_hash\]
+		CallWhitelisted(T::Hash),
+		/// A call was removed from the whitelist. \[call_hash\]
+		WhitelistedCallRemoved(T::Hash),
+		/// A whitelisted call was dispatched. \[call_hash, result\]
+		WhitelistedCallDispatched(T::Hash, DispatchResult),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The preimage of the call is not available.
+		UnavailablePreImage,
+		/// The preimage of the call does not decode to a call.
+		UndecodableCall,
+		/// The weight of the call exceeds the given witness.
+		InvalidCallWeightWitness,
+		/// The call is not whitelisted.
+		CallIsNotWhitelisted,
+		/// The call is already whitelisted.
+		CallAlreadyWhitelisted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Whitelist the call of hash `call_hash`, and request its preimage.
+		///
+		/// The dispatch origin of this call must be the `WhitelistOrigin`.
+		///
+		/// Emits `CallWhitelisted`.
+		#[pallet::weight(T::WeightInfo::whitelist_call())]
+		pub fn whitelist_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			ensure!(
+				!WhitelistedCall::<T>::contains_key(call_hash),
+				Error::<T>::CallAlreadyWhitelisted,
+			);
+			WhitelistedCall::<T>::insert(call_hash, ());
+			T::Preimages::request(&call_hash);
+			Self::deposit_event(Event::CallWhitelisted(call_hash));
+			Ok(())
+		}
+
+		/// Remove the call of hash `call_hash` from the whitelist.
+		///
+		/// The dispatch origin of this call must be the `WhitelistOrigin`.
+		///
+		/// Emits `WhitelistedCallRemoved`.
+		#[pallet::weight(T::WeightInfo::remove_whitelisted_call())]
+		pub fn remove_whitelisted_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+			WhitelistedCall::<T>::take(call_hash).ok_or(Error::<T>::CallIsNotWhitelisted)?;
+			T::Preimages::unrequest(&call_hash);
+			Self::deposit_event(Event::WhitelistedCallRemoved(call_hash));
+			Ok(())
+		}
+
+		/// Dispatch the whitelisted call of hash `call_hash` with the root origin, from its
+		/// preimage.
+		///
+		/// The dispatch origin of this call must be the `DispatchWhitelistedOrigin`.
+		///
+		/// - `call_hash`: The hash of the whitelisted call, whose preimage must be available.
+		/// - `call_weight_witness`: An upper bound of the weight of the call.
+		///
+		/// Emits `WhitelistedCallDispatched`.
+		#[pallet::weight(
+			T::WeightInfo::dispatch_whitelisted_call().saturating_add(*call_weight_witness)
+		)]
+		pub fn dispatch_whitelisted_call(
+			origin: OriginFor<T>,
+			call_hash: T::Hash,
+			call_weight_witness: Weight,
+		) -> DispatchResultWithPostInfo {
+			T::DispatchWhitelistedOrigin::ensure_origin(origin)?;
+			ensure!(
+				WhitelistedCall::<T>::contains_key(call_hash),
+				Error::<T>::CallIsNotWhitelisted,
+			);
+
+			let call = T::Preimages::fetch(&call_hash).ok_or(Error::<T>::UnavailablePreImage)?;
+			let call = <T as Config>::Call::decode(&mut &call[..])
+				.map_err(|_| Error::<T>::UndecodableCall)?;
+			ensure!(
+				call.get_dispatch_info().weight.all_lte(&call_weight_witness),
+				Error::<T>::InvalidCallWeightWitness,
+			);
+
+			let actual_weight = Self::clean_and_dispatch(call_hash, call)
+				.map(|w| w.saturating_add(T::WeightInfo::dispatch_whitelisted_call()));
+			Ok(actual_weight.into())
+		}
+
+		/// Dispatch the whitelisted `call` with the root origin, without its preimage being noted.
+		///
+		/// The dispatch origin of this call must be the `DispatchWhitelistedOrigin`.
+		///
+		/// Emits `WhitelistedCallDispatched`.
+		#[pallet::weight({
+			let call_weight = call.get_dispatch_info().weight;
+			T::WeightInfo::dispatch_whitelisted_call_with_preimage().saturating_add(call_weight)
+		})]
+		pub fn dispatch_whitelisted_call_with_preimage(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			T::DispatchWhitelistedOrigin::ensure_origin(origin)?;
+			let call_hash = T::Hashing::hash_of(&call);
+			ensure!(
+				WhitelistedCall::<T>::contains_key(call_hash),
+				Error::<T>::CallIsNotWhitelisted,
+			);
+
+			let actual_weight = Self::clean_and_dispatch(call_hash, *call).map(|w| {
+				w.saturating_add(T::WeightInfo::dispatch_whitelisted_call_with_preimage())
+			});
+			Ok(actual_weight.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Remove the call of hash `call_hash` from the whitelist, withdraw the request for its
+	/// preimage, and dispatch it with the root origin.
+	///
+	/// Return the actual weight of the dispatched call, if known.
+	fn clean_and_dispatch(call_hash: T::Hash, call: <T as Config>::Call) -> Option<Weight> {
+		WhitelistedCall::<T>::remove(call_hash);
+		T::Preimages::unrequest(&call_hash);
+
+		let result = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
+		let call_actual_weight = match result {
+			Ok(call_post_info) => call_post_info.actual_weight,
+			Err(call_err) => call_err.post_info.actual_weight,
+		};
+		Self::deposit_event(Event::WhitelistedCallDispatched(
+			call_hash,
+			result.map(|_| ()).map_err(|e| e.error),
+		));
+		call_actual_weight
+	}
+}
@@ -0,0 +1,184 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the module.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchError, error::BadOrigin};
+use sp_core::storage::well_known_keys;
+use sp_runtime::traits::BlakeTwo256;
+
+fn heap_pages_call(pages: u64) -> Call {
+	Call::System(frame_system::Call::set_heap_pages(pages))
+}
+
+fn heap_pages() -> Option<Vec<u8>> {
+	sp_io::storage::get(well_known_keys::HEAP_PAGES)
+}
+
+fn whitelist_events() -> Vec<crate::Event<Test>> {
+	System::events().into_iter().filter_map(|r| match r.event {
+		Event::Whitelist(inner) => Some(inner),
+		_ => None,
+	}).collect()
+}
+
+#[test]
+fn whitelisting_and_removing_works() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash_of(&heap_pages_call(42));
+		assert_noop!(Whitelist::whitelist_call(Origin::signed(1), hash), BadOrigin);
+		assert_ok!(Whitelist::whitelist_call(Origin::root(), hash));
+		assert_noop!(
+			Whitelist::whitelist_call(Origin::root(), hash),
+			Error::<Test>::CallAlreadyWhitelisted,
+		);
+		assert!(WhitelistedCall::<Test>::contains_key(hash));
+		assert!(Preimage::is_requested(&hash));
+
+		assert_noop!(Whitelist::remove_whitelisted_call(Origin::signed(1), hash), BadOrigin);
+		assert_ok!(Whitelist::remove_whitelisted_call(Origin::root(), hash));
+		assert_noop!(
+			Whitelist::remove_whitelisted_call(Origin::root(), hash),
+			Error::<Test>::CallIsNotWhitelisted,
+		);
+		assert!(!WhitelistedCall::<Test>::contains_key(hash));
+		assert!(!Preimage::is_requested(&hash));
+		assert_eq!(
+			whitelist_events(),
+			vec![
+				crate::Event::CallWhitelisted(hash),
+				crate::Event::WhitelistedCallRemoved(hash),
+			],
+		);
+	});
+}
+
+#[test]
+fn dispatching_whitelisted_call_works() {
+	new_test_ext().execute_with(|| {
+		let call = heap_pages_call(42);
+		let encoded = call.encode();
+		let hash = BlakeTwo256::hash(&encoded[..]);
+		let weight = call.get_dispatch_info().weight;
+
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(Origin::signed(1), hash, weight),
+			Error::<Test>::CallIsNotWhitelisted,
+		);
+		assert_ok!(Whitelist::whitelist_call(Origin::root(), hash));
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(Origin::signed(1), hash, weight),
+			Error::<Test>::UnavailablePreImage,
+		);
+
+		// the preimage is requested, so anyone notes it for free.
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), encoded));
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(Origin::signed(2), hash, weight),
+			BadOrigin,
+		);
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(
+				Origin::signed(1),
+				hash,
+				weight - Weight::from_ref_time(1),
+			),
+			Error::<Test>::InvalidCallWeightWitness,
+		);
+		assert_ok!(Whitelist::dispatch_whitelisted_call(Origin::signed(1), hash, weight));
+		assert_eq!(heap_pages(), Some(42u64.encode()));
+		assert_eq!(
+			whitelist_events().last(),
+			Some(&crate::Event::WhitelistedCallDispatched(hash, Ok(()))),
+		);
+
+		// the call is dispatched only once, and its preimage is no longer kept.
+		assert!(!Preimage::have(&hash));
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(Origin::signed(1), hash, weight),
+			Error::<Test>::CallIsNotWhitelisted,
+		);
+	});
+}
+
+#[test]
+fn dispatching_whitelisted_call_with_preimage_works() {
+	new_test_ext().execute_with(|| {
+		let call = heap_pages_call(7);
+		let hash = BlakeTwo256::hash_of(&call);
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call_with_preimage(
+				Origin::signed(1),
+				Box::new(call.clone()),
+			),
+			Error::<Test>::CallIsNotWhitelisted,
+		);
+		assert_ok!(Whitelist::whitelist_call(Origin::root(), hash));
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call_with_preimage(
+				Origin::signed(2),
+				Box::new(call.clone()),
+			),
+			BadOrigin,
+		);
+		assert_ok!(Whitelist::dispatch_whitelisted_call_with_preimage(
+			Origin::signed(1),
+			Box::new(call.clone()),
+		));
+		assert_eq!(heap_pages(), Some(7u64.encode()));
+		assert!(!WhitelistedCall::<Test>::contains_key(hash));
+		assert!(!Preimage::is_requested(&hash));
+	});
+}
+
+#[test]
+fn failing_whitelisted_call_is_reported() {
+	new_test_ext().execute_with(|| {
+		// the call requires a signed origin, it fails with the root origin.
+		let call = Call::System(frame_system::Call::remark_with_event(vec![1, 2, 3]));
+		let hash = BlakeTwo256::hash_of(&call);
+		assert_ok!(Whitelist::whitelist_call(Origin::root(), hash));
+		assert_ok!(Whitelist::dispatch_whitelisted_call_with_preimage(
+			Origin::signed(1),
+			Box::new(call),
+		));
+		assert_eq!(
+			whitelist_events().last(),
+			Some(&crate::Event::WhitelistedCallDispatched(hash, Err(DispatchError::BadOrigin))),
+		);
+		assert!(!WhitelistedCall::<Test>::contains_key(hash));
+	});
+}
+
+#[test]
+fn undecodable_preimage_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let bytes = vec![255, 255];
+		let hash = BlakeTwo256::hash(&bytes[..]);
+		assert_ok!(Whitelist::whitelist_call(Origin::root(), hash));
+		assert_ok!(Preimage::note_preimage(Origin::signed(2), bytes));
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(Origin::signed(1), hash, 0),
+			Error::<Test>::UndecodableCall,
+		);
+	});
+}
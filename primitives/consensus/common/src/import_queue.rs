@@ -38,7 +38,7 @@ use crate::{
 	},
 	metrics::Metrics,
 };
-pub use basic_queue::BasicQueue;
+pub use basic_queue::{BasicQueue, BasicQueueHandle};
 
 /// A commonly-used Import Queue type.
 ///
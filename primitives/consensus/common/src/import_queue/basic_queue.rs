@@ -92,6 +92,43 @@ impl<B: BlockT, Transaction: Send + 'static> BasicQueue<B, Transaction> {
 			_phantom: PhantomData,
 		}
 	}
+
+	/// Returns a handle scheduling blocks for import in this queue, which remains usable when the
+	/// queue itself is moved to the network.
+	pub fn handle(&self) -> BasicQueueHandle<B> {
+		BasicQueueHandle { block_import_sender: self.block_import_sender.clone() }
+	}
+}
+
+/// Handle scheduling blocks for import in a [`BasicQueue`].
+pub struct BasicQueueHandle<B: BlockT> {
+	block_import_sender: TracingUnboundedSender<worker_messages::ImportBlocks<B>>,
+}
+
+impl<B: BlockT> Clone for BasicQueueHandle<B> {
+	fn clone(&self) -> Self {
+		BasicQueueHandle { block_import_sender: self.block_import_sender.clone() }
+	}
+}
+
+impl<B: BlockT> BasicQueueHandle<B> {
+	/// Schedule the given blocks for import.
+	///
+	/// Returns an error if the queue is no longer alive.
+	pub fn import_blocks(
+		&self,
+		origin: BlockOrigin,
+		blocks: Vec<IncomingBlock<B>>,
+	) -> Result<(), ()> {
+		if blocks.is_empty() {
+			return Ok(());
+		}
+
+		trace!(target: "sync", "Scheduling {} blocks for import", blocks.len());
+		self.block_import_sender
+			.unbounded_send(worker_messages::ImportBlocks(origin, blocks))
+			.map_err(|_| ())
+	}
 }
 
 impl<B: BlockT, Transaction: Send> ImportQueue<B> for BasicQueue<B, Transaction> {
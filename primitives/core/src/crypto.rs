@@ -1176,6 +1176,10 @@ pub mod key_types {
 	pub const STAKING: KeyTypeId = KeyTypeId(*b"stak");
 	/// Key type for equivocation reporting, built-in. Identified as `fish`.
 	pub const REPORTING: KeyTypeId = KeyTypeId(*b"fish");
+	/// Key type for the mixnet, built-in. Identified as `mixn`.
+	pub const MIXNET: KeyTypeId = KeyTypeId(*b"mixn");
+	/// Key type for the network identity of a node, built-in. Identified as `node`.
+	pub const NODE_KEY: KeyTypeId = KeyTypeId(*b"node");
 	/// A key type ID useful for tests.
 	pub const DUMMY: KeyTypeId = KeyTypeId(*b"dumy");
 }
@@ -109,6 +109,11 @@ pub enum ExecutionContext {
 	///
 	/// This allows passing offchain extension and customizing available capabilities.
 	OffchainCall(Option<(Box<dyn offchain::Externalities>, offchain::Capabilities)>),
+	/// Context used by the offchain workers of the node.
+	///
+	/// Only the offchain workers use this context, the node grants them their capabilities
+	/// whatever an offchain call would request.
+	OffchainWorker(Box<dyn offchain::Externalities>),
 }
 
 impl ExecutionContext {
@@ -119,13 +124,9 @@ impl ExecutionContext {
 		match self {
 			Importing | Syncing | BlockConstruction =>
 				offchain::Capabilities::none(),
-			// Enable keystore, transaction pool and Offchain DB reads by default for offchain calls.
-			OffchainCall(None) => [
-				offchain::Capability::Keystore,
-				offchain::Capability::OffchainDbRead,
-				offchain::Capability::TransactionPool,
-			][..].into(),
+			OffchainCall(None) => offchain::Capabilities::none(),
 			OffchainCall(Some((_, capabilities))) => *capabilities,
+			OffchainWorker(_) => offchain::Capabilities::all(),
 		}
 	}
 }
@@ -306,6 +306,11 @@ impl Capabilities {
 	pub fn has_all(&self) -> bool {
 		self == &Capabilities::all()
 	}
+
+	/// Return the capabilities enabled in both `self` and `other`.
+	pub fn intersection(&self, other: Capabilities) -> Self {
+		Self(self.0 & other.0)
+	}
 }
 
 impl<'a> From<&'a [Capability]> for Capabilities {
@@ -109,6 +109,13 @@ impl<D: KeyValueDB, H: Clone + AsRef<[u8]>> Database<H> for DbAdapter<D> {
 		handle_err(self.0.get(col, key))
 	}
 
+	fn iter_column(&self, col: ColumnId, f: &mut dyn FnMut(&[u8], &[u8])) -> error::Result<()> {
+		for (key, value) in self.0.iter(col) {
+			f(&key, &value);
+		}
+		Ok(())
+	}
+
 	fn contains(&self, col: ColumnId, key: &[u8]) -> bool {
 		handle_err(self.0.has_key(col, key))
 	}
@@ -103,6 +103,17 @@ pub trait Database<H: Clone + AsRef<[u8]>>: Send + Sync {
 	fn with_get(&self, col: ColumnId, key: &[u8], f: &mut dyn FnMut(&[u8])) {
 		self.get(col, key).map(|v| f(&v));
 	}
+
+	/// Call `f` with every key/value pair of the column `col`, as stored in the database.
+	///
+	/// The pairs are taken from a consistent view of the column. Returns an error if the database
+	/// does not support iteration.
+	fn iter_column(&self, _col: ColumnId, _f: &mut dyn FnMut(&[u8], &[u8])) -> error::Result<()> {
+		Err(error::DatabaseError(Box::new(std::io::Error::new(
+			std::io::ErrorKind::Other,
+			"Iteration is not supported by this database",
+		))))
+	}
 }
 
 impl<H> std::fmt::Debug for dyn Database<H> {
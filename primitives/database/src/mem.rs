@@ -62,6 +62,14 @@ impl<H> Database<H> for MemDb
 		let s = self.0.read();
 		s.get(&col).and_then(|c| c.get(key).map(|(_, v)| v.clone()))
 	}
+
+	fn iter_column(&self, col: ColumnId, f: &mut dyn FnMut(&[u8], &[u8])) -> error::Result<()> {
+		let s = self.0.read();
+		for (key, (_, value)) in s.get(&col).into_iter().flatten() {
+			f(key, value);
+		}
+		Ok(())
+	}
 }
 
 impl MemDb {
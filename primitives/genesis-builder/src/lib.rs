@@ -0,0 +1,43 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The genesis builder runtime api.
+//!
+//! With this api, a node can build the genesis state of a runtime from its Wasm blob alone,
+//! without knowing the `GenesisConfig` type of the runtime. The `GenesisConfig` goes through the
+//! api as JSON, so that it can be tweaked by the node, e.g. from a chain spec.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+/// The result of building the genesis state of the runtime.
+pub type Result = sp_std::result::Result<(), sp_runtime::RuntimeString>;
+
+sp_api::decl_runtime_apis! {
+	/// The `GenesisBuilder` api trait that builds the genesis state of the runtime.
+	pub trait GenesisBuilder {
+		/// Returns the default `GenesisConfig` of the runtime, serialized as JSON.
+		fn create_default_config() -> Vec<u8>;
+
+		/// Build the genesis state from the given `GenesisConfig`, serialized as JSON.
+		///
+		/// The genesis state is written to the storage of the externalities the runtime is called
+		/// with. The runtime code itself is not written, it is up to the caller to set it.
+		fn build_config(json: Vec<u8>) -> Result;
+	}
+}
@@ -585,6 +585,9 @@ pub trait Crypto {
 	/// Verify `sr25519` signature.
 	///
 	/// Returns `true` when the verification was successful.
+	///
+	/// Unlike the first version of this function, signatures in the deprecated format of
+	/// `schnorrkel` 0.1.1 are rejected.
 	#[version(2)]
 	fn sr25519_verify(
 		sig: &sr25519::Signature,
@@ -683,6 +686,11 @@ pub trait Crypto {
 	///
 	/// Returns `true` when the verification in successful regardless of
 	/// signature version.
+	///
+	/// This version is deprecated and not callable by new runtimes, which use the second version.
+	/// It stays registered as a host function, so that the blocks of the runtimes built against it
+	/// are still verified with the semantics they were authored with, e.g. when syncing an archive
+	/// node from genesis. It must not be removed or changed.
 	fn sr25519_verify(sig: &sr25519::Signature, msg: &[u8], pubkey: &sr25519::Public) -> bool {
 		sr25519::Pair::verify_deprecated(sig, msg, pubkey)
 	}
@@ -1540,6 +1548,17 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn all_versions_of_sr25519_verify_are_registered() {
+		use sp_wasm_interface::HostFunctions;
+
+		let host_functions = SubstrateHostFunctions::host_functions();
+		let is_registered = |name| host_functions.iter().any(|f| f.name() == name);
+
+		assert!(is_registered("ext_crypto_sr25519_verify_version_1"));
+		assert!(is_registered("ext_crypto_sr25519_verify_version_2"));
+	}
+
 	#[test]
 	fn batch_verify_start_finish_works() {
 		let mut ext = BasicExternalities::default();
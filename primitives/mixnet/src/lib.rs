@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Substrate mixnet types and runtime interface.
+//!
+//! The mixnet lets users submit extrinsics without revealing their IP address to the node which
+//! first receives them. The runtime keeps a registry of the mixnodes of each session, which is
+//! exposed to the nodes through the [`MixnetApi`] runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+mod app {
+	use sp_application_crypto::{
+		key_types::MIXNET,
+		app_crypto,
+		sr25519,
+	};
+	app_crypto!(sr25519, MIXNET);
+}
+
+sp_application_crypto::with_pair! {
+	/// A mixnet authority keypair.
+	pub type AuthorityPair = app::Pair;
+}
+
+/// A mixnet authority identifier, used to sign mixnode registrations.
+pub type AuthorityId = app::Public;
+
+/// A mixnet authority signature.
+pub type AuthoritySignature = app::Signature;
+
+/// The index of an authority in the authority set of a session.
+pub type AuthorityIndex = u32;
+
+/// The index of a session.
+pub type SessionIndex = u32;
+
+/// The public key of a mixnode, used for the key exchange with the senders of packets.
+///
+/// This is an X25519 public key.
+pub type KxPublic = [u8; 32];
+
+/// The peer ID of a mixnode, i.e. its libp2p ed25519 public key.
+pub type MixnodePeerId = [u8; 32];
+
+/// The phase of the current session, which determines how the mixnode sets of the current and
+/// of the previous sessions are used.
+///
+/// The phases follow each other in the order of the variants. Until [`SessionPhase::CoverToPrev`]
+/// the mixnode set of the previous session keeps forwarding the packets in flight.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum SessionPhase {
+	/// Generate cover traffic to the mixnode set of the current session, while requests are
+	/// still routed through the mixnode set of the previous session.
+	CoverToCurrent,
+	/// Route requests through the mixnode set of the current session.
+	RequestsToCurrent,
+	/// Only send cover and forwarded traffic to the mixnode set of the previous session.
+	CoverToPrev,
+	/// Disconnect from the mixnode set of the previous session.
+	DisconnectFromPrev,
+}
+
+/// The index and phase of the current session.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct SessionStatus {
+	/// The index of the current session.
+	pub current_index: SessionIndex,
+	/// The phase of the current session.
+	pub phase: SessionPhase,
+}
+
+/// A mixnode, as registered for a session.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Mixnode {
+	/// The key-exchange public key of the mixnode.
+	pub kx_public: KxPublic,
+	/// The peer ID of the mixnode.
+	pub peer_id: MixnodePeerId,
+	/// The addresses at which the mixnode can be reached, as UTF-8 encoded multiaddrs.
+	pub external_addresses: Vec<Vec<u8>>,
+}
+
+/// The error returned when the mixnode set of a session cannot be used.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum MixnodesErr {
+	/// Too few mixnodes registered for the session.
+	InsufficientRegistrations {
+		/// The number of mixnodes which registered.
+		num: u32,
+		/// The minimum number of mixnodes needed.
+		min: u32,
+	},
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for MixnodesErr {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			MixnodesErr::InsufficientRegistrations { num, min } =>
+				write!(fmt, "{} mixnode(s) registered; {} is the minimum", num, min),
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The mixnet api.
+	///
+	/// This api is used by the `client/mixnet` module to follow the sessions and their mixnode
+	/// sets, and to register the local node as a mixnode.
+	pub trait MixnetApi {
+		/// Get the index and phase of the current session.
+		fn session_status() -> SessionStatus;
+
+		/// Get the mixnode set of the previous session.
+		fn prev_mixnodes() -> Result<Vec<Mixnode>, MixnodesErr>;
+
+		/// Get the mixnode set of the current session.
+		fn current_mixnodes() -> Result<Vec<Mixnode>, MixnodesErr>;
+
+		/// Try to register `mixnode` for the session following `session_index`.
+		///
+		/// This is meant to be called from an offchain context. It signs and submits a
+		/// registration with a local mixnet authority key, if one of the authorities of the
+		/// following session has its key in the keystore. Returns `true` if a registration
+		/// was submitted.
+		fn maybe_register(session_index: SessionIndex, mixnode: Mixnode) -> bool;
+	}
+}
@@ -22,7 +22,7 @@ mod common;
 use common::*;
 use honggfuzz::fuzz;
 use sp_npos_elections::{
-	assignment_ratio_to_staked_normalized, is_score_better, phragmms, to_supports,
+	assignment_ratio_to_staked_normalized, phragmms, to_supports,
 	to_without_backing, EvaluateSupport, VoteWeight,
 };
 use sp_runtime::Perbill;
@@ -74,7 +74,7 @@ fn main() {
 				let winners = to_without_backing(unbalanced.winners.clone());
 				let score = to_supports(&winners, &staked).unwrap().evaluate();
 
-				if score[0] == 0 {
+				if score.minimal_stake == 0 {
 					// such cases cannot be improved by balancing.
 					return;
 				}
@@ -98,7 +98,7 @@ fn main() {
 					.evaluate()
 			};
 
-			let enhance = is_score_better(balanced_score, unbalanced_score, Perbill::zero());
+			let enhance = balanced_score.strict_threshold_better(unbalanced_score, Perbill::zero());
 
 			println!(
 				"iter = {} // {:?} -> {:?} [{}]",
@@ -111,9 +111,9 @@ fn main() {
 			// The only guarantee of balancing is such that the first and third element of the score
 			// cannot decrease.
 			assert!(
-				balanced_score[0] >= unbalanced_score[0] &&
-				balanced_score[1] == unbalanced_score[1] &&
-				balanced_score[2] <= unbalanced_score[2]
+				balanced_score.minimal_stake >= unbalanced_score.minimal_stake &&
+				balanced_score.sum_stake == unbalanced_score.sum_stake &&
+				balanced_score.sum_stake_squared <= unbalanced_score.sum_stake_squared
 			);
 		});
 	}
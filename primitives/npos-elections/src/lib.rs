@@ -274,9 +274,76 @@ pub type VoteWeight = u64;
 /// A type in which performing operations on vote weights are safe.
 pub type ExtendedBalance = u128;
 
-/// The score of an assignment. This can be computed from the support map via
-/// [`EvaluateSupport::evaluate`].
-pub type ElectionScore = [ExtendedBalance; 3];
+/// The score of an election. This is the main measure of an election's quality.
+///
+/// This can be computed from the support map via [`EvaluateSupport::evaluate`]. The SCALE encoding
+/// of this type is identical to that of `[ExtendedBalance; 3]`, which was previously used to
+/// represent the score.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ElectionScore {
+	/// The minimal winner, in terms of total backing stake.
+	///
+	/// This parameter should be maximized.
+	pub minimal_stake: ExtendedBalance,
+	/// The sum of the total backing of all winners.
+	///
+	/// This parameter should be maximized.
+	pub sum_stake: ExtendedBalance,
+	/// The sum squared of the total backing of all winners, aka. the variance.
+	///
+	/// This parameter should be minimized.
+	pub sum_stake_squared: ExtendedBalance,
+}
+
+impl ElectionScore {
+	/// Compares two sets of election scores based on desirability, returning true if `self` is
+	/// strictly better than `other` by at least `threshold`.
+	///
+	/// Evaluation is done in a lexicographic manner: a component only dictates the outcome if all
+	/// of the more significant components of `self` are equal to, or less than `threshold` better
+	/// than, those of `other`. Being better means being more than `other * threshold` greater in
+	/// the first two components, and more than `other * threshold` less in the third one.
+	///
+	/// Note that with a zero `threshold`, two equal scores are never better than one another.
+	pub fn strict_threshold_better(self, other: Self, threshold: impl PerThing) -> bool {
+		let compare = |this: ExtendedBalance, that: ExtendedBalance| {
+			(this.ge(&that), this.tcmp(&that, threshold.mul_ceil(that)))
+		};
+
+		match (
+			compare(self.minimal_stake, other.minimal_stake),
+			compare(self.sum_stake, other.sum_stake),
+			compare(self.sum_stake_squared, other.sum_stake_squared),
+		) {
+			// threshold better in the `minimal_stake`, accept.
+			((_, Ordering::Greater), _, _) => true,
+
+			// less than threshold better in `minimal_stake`, but more than threshold better in
+			// `sum_stake`.
+			((true, Ordering::Equal), (_, Ordering::Greater), _) => true,
+
+			// less than threshold better in `minimal_stake` and `sum_stake`, but more than
+			// threshold better in `sum_stake_squared`.
+			((true, Ordering::Equal), (true, Ordering::Equal), (_, Ordering::Less)) => true,
+
+			// anything else is not a good score.
+			_ => false,
+		}
+	}
+}
+
+impl From<[ExtendedBalance; 3]> for ElectionScore {
+	fn from(t: [ExtendedBalance; 3]) -> Self {
+		Self { minimal_stake: t[0], sum_stake: t[1], sum_stake_squared: t[2] }
+	}
+}
+
+impl From<ElectionScore> for [ExtendedBalance; 3] {
+	fn from(score: ElectionScore) -> Self {
+		[score.minimal_stake, score.sum_stake, score.sum_stake_squared]
+	}
+}
 
 /// A winner, with their respective approval stake.
 pub type WithApprovalOf<A> = (A, ExtendedBalance);
@@ -541,7 +608,7 @@ pub fn to_supports<A: IdentifierT>(
 
 /// Extension trait for evaluating a support map or vector.
 pub trait EvaluateSupport<K> {
-	/// Evaluate a support map. The returned tuple contains:
+	/// Evaluate a support map. The returned [`ElectionScore`] contains:
 	///
 	/// - Minimum support. This value must be **maximized**.
 	/// - Sum of all supports. This value must be **maximized**.
@@ -596,39 +663,7 @@ where
 				min_support = support.total;
 			}
 		}
-		[min_support, sum, sum_squared]
-	}
-}
-
-/// Compares two sets of election scores based on desirability and returns true if `this` is better
-/// than `that`.
-///
-/// Evaluation is done in a lexicographic manner, and if each element of `this` is `that * epsilon`
-/// greater or less than `that`.
-///
-/// Note that the third component should be minimized.
-pub fn is_score_better<P: PerThing>(this: ElectionScore, that: ElectionScore, epsilon: P) -> bool {
-	match this
-		.iter()
-		.zip(that.iter())
-		.map(|(thi, tha)| (
-			thi.ge(&tha),
-			thi.tcmp(&tha, epsilon.mul_ceil(*tha)),
-		))
-		.collect::<Vec<(bool, Ordering)>>()
-		.as_slice()
-	{
-		// epsilon better in the score[0], accept.
-		[(_, Ordering::Greater), _, _] => true,
-
-		// less than epsilon better in score[0], but more than epsilon better in the second.
-		[(true, Ordering::Equal), (_, Ordering::Greater), _] => true,
-
-		// less than epsilon better in score[0, 1], but more than epsilon better in the third
-		[(true, Ordering::Equal), (true, Ordering::Equal), (_, Ordering::Less)] => true,
-
-		// anything else is not a good score.
-		_ => false,
+		ElectionScore { minimal_stake: min_support, sum_stake: sum, sum_stake_squared: sum_squared }
 	}
 }
 
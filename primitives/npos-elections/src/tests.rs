@@ -18,8 +18,9 @@
 //! Tests for npos-elections.
 
 use crate::{
-	balancing, helpers::*, is_score_better, mock::*, seq_phragmen, seq_phragmen_core, setup_inputs,
-	to_support_map, to_supports, Assignment, CompactSolution, ElectionResult, ExtendedBalance,
+	balancing, helpers::*, mock::*, seq_phragmen, seq_phragmen_core, setup_inputs,
+	to_support_map, to_supports, Assignment, CompactSolution, ElectionResult, ElectionScore,
+	ExtendedBalance,
 	IndexAssignment, StakedAssignment, Support, Voter, EvaluateSupport,
 };
 use rand::{self, SeedableRng};
@@ -992,25 +993,29 @@ mod score {
 		let epsilon = Perbill::zero();
 		// only better in the fist parameter, worse in the other two ✅
 		assert_eq!(
-			is_score_better([12, 10, 35], [10, 20, 30], epsilon),
+			ElectionScore::from([12, 10, 35])
+				.strict_threshold_better([10, 20, 30].into(), epsilon),
 			true,
 		);
 
 		// worse in the first, better in the other two ❌
 		assert_eq!(
-			is_score_better([9, 30, 10], [10, 20, 30], epsilon),
+			ElectionScore::from([9, 30, 10])
+				.strict_threshold_better([10, 20, 30].into(), epsilon),
 			false,
 		);
 
 		// equal in the first, the second one dictates.
 		assert_eq!(
-			is_score_better([10, 25, 40], [10, 20, 30], epsilon),
+			ElectionScore::from([10, 25, 40])
+				.strict_threshold_better([10, 20, 30].into(), epsilon),
 			true,
 		);
 
 		// equal in the first two, the last one dictates.
 		assert_eq!(
-			is_score_better([10, 20, 40], [10, 20, 30], epsilon),
+			ElectionScore::from([10, 20, 40])
+				.strict_threshold_better([10, 20, 30].into(), epsilon),
 			false,
 		);
 	}
@@ -1022,19 +1027,22 @@ mod score {
 		{
 			// no more than 1 percent (10) better in the first param.
 			assert_eq!(
-				is_score_better([1009, 5000, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1009, 5000, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			// now equal, still not better.
 			assert_eq!(
-				is_score_better([1010, 5000, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1010, 5000, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			// now it is.
 			assert_eq!(
-				is_score_better([1011, 5000, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1011, 5000, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				true,
 			);
 		}
@@ -1043,7 +1051,8 @@ mod score {
 			// First score score is epsilon better, but first score is no longer `ge`. Then this is
 			// still not a good solution.
 			assert_eq!(
-				is_score_better([999, 6000, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([999, 6000, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 		}
@@ -1051,17 +1060,20 @@ mod score {
 		{
 			// first score is equal or better, but not epsilon. Then second one is the determinant.
 			assert_eq!(
-				is_score_better([1005, 5000, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5000, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			assert_eq!(
-				is_score_better([1005, 5050, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5050, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			assert_eq!(
-				is_score_better([1005, 5051, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5051, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				true,
 			);
 		}
@@ -1069,70 +1081,94 @@ mod score {
 		{
 			// first score and second are equal or less than epsilon more, third is determinant.
 			assert_eq!(
-				is_score_better([1005, 5025, 100000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5025, 100000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			assert_eq!(
-				is_score_better([1005, 5025, 99_000], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5025, 99_000])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				false,
 			);
 
 			assert_eq!(
-				is_score_better([1005, 5025, 98_999], [1000, 5000, 100000], epsilon),
+				ElectionScore::from([1005, 5025, 98_999])
+					.strict_threshold_better([1000, 5000, 100000].into(), epsilon),
 				true,
 			);
 		}
 	}
 
+	#[test]
+	fn score_comparison_is_strict() {
+		let score: ElectionScore = [10, 20, 30].into();
+
+		// an equal score is never better, regardless of the threshold.
+		assert!(!score.strict_threshold_better(score, Perbill::zero()));
+		assert!(!score.strict_threshold_better(score, Perbill::from_percent(1)));
+
+		// the slightest improvement is enough without a threshold.
+		assert!(ElectionScore::from([10, 20, 29]).strict_threshold_better(score, Perbill::zero()));
+	}
+
+	#[test]
+	fn score_encoding_is_backwards_compatible() {
+		use codec::{Decode, Encode};
+
+		let raw: [ExtendedBalance; 3] = [1, 2, 3];
+		let score = ElectionScore { minimal_stake: 1, sum_stake: 2, sum_stake_squared: 3 };
+
+		assert_eq!(raw.encode(), score.encode());
+		assert_eq!(ElectionScore::decode(&mut &raw.encode()[..]).unwrap(), score);
+		assert_eq!(<[ExtendedBalance; 3]>::from(score), raw);
+	}
+
 	#[test]
 	fn score_comparison_large_value() {
 		// some random value taken from eras in kusama.
-		let initial = [12488167277027543u128, 5559266368032409496, 118749283262079244270992278287436446];
+		let initial: ElectionScore =
+			[12488167277027543u128, 5559266368032409496, 118749283262079244270992278287436446].into();
 		// this claim is 0.04090% better in the third component. It should be accepted as better if
 		// epsilon is smaller than 5/10_0000
-		let claim = [12488167277027543u128, 5559266368032409496, 118700736389524721358337889258988054];
+		let claim: ElectionScore =
+			[12488167277027543u128, 5559266368032409496, 118700736389524721358337889258988054].into();
 
 		assert_eq!(
-			is_score_better(
-				claim.clone(),
-				initial.clone(),
+			claim.strict_threshold_better(
+				initial,
 				Perbill::from_rational(1u32, 10_000),
 			),
 			true,
 		);
 
 		assert_eq!(
-			is_score_better(
-				claim.clone(),
-				initial.clone(),
+			claim.strict_threshold_better(
+				initial,
 				Perbill::from_rational(2u32, 10_000),
 			),
 			true,
 		);
 
 		assert_eq!(
-			is_score_better(
-				claim.clone(),
-				initial.clone(),
+			claim.strict_threshold_better(
+				initial,
 				Perbill::from_rational(3u32, 10_000),
 			),
 			true,
 		);
 
 		assert_eq!(
-			is_score_better(
-				claim.clone(),
-				initial.clone(),
+			claim.strict_threshold_better(
+				initial,
 				Perbill::from_rational(4u32, 10_000),
 			),
 			true,
 		);
 
 		assert_eq!(
-			is_score_better(
-				claim.clone(),
-				initial.clone(),
+			claim.strict_threshold_better(
+				initial,
 				Perbill::from_rational(5u32, 10_000),
 			),
 			false,
@@ -34,27 +34,32 @@ use inflector::Inflector;
 
 /// Runtime interface function with all associated versions of this function.
 pub struct RuntimeInterfaceFunction<'a> {
-	latest_version: u32,
+	/// The latest version of this function that is called by the runtime, i.e. the latest one
+	/// that is not `register_only`. `None` if all versions are `register_only`.
+	latest_version_to_call: Option<u32>,
 	versions: BTreeMap<u32, &'a TraitItemMethod>,
 }
 
 impl<'a> RuntimeInterfaceFunction<'a> {
-	fn new(version: u32, trait_item: &'a TraitItemMethod) -> Self {
+	fn new(version: VersionAttribute, trait_item: &'a TraitItemMethod) -> Self {
 		Self {
-			latest_version: version,
+			latest_version_to_call: version.is_callable().then(|| version.version),
 			versions: {
 				let mut res = BTreeMap::new();
-				res.insert(version, trait_item);
+				res.insert(version.version, trait_item);
 				res
 			},
 		}
 	}
 
-	pub fn latest_version(&self) -> (u32, &TraitItemMethod) {
-		(
-			self.latest_version,
-			self.versions.get(&self.latest_version)
-				.expect("If latest_version has a value, the key with this value is in the versions; qed")
+	/// Returns the latest version of this function that should be called by the runtime, if any.
+	pub fn latest_version_to_call(&self) -> Option<(u32, &TraitItemMethod)> {
+		self.latest_version_to_call.map(|latest_version|
+			(
+				latest_version,
+				*self.versions.get(&latest_version)
+					.expect("If latest_version_to_call has a value, the key with this value is in the versions; qed")
+			)
 		)
 	}
 }
@@ -65,10 +70,14 @@ pub struct RuntimeInterface<'a> {
 }
 
 impl<'a> RuntimeInterface<'a> {
-	pub fn latest_versions(&self) -> impl Iterator<Item = (u32, &TraitItemMethod)> {
-		self.items.iter().map(|(_, item)| item.latest_version())
+	/// Returns the latest version of each function that should be called by the runtime.
+	///
+	/// Functions for which all versions are `register_only` are skipped.
+	pub fn latest_versions_to_call(&self) -> impl Iterator<Item = (u32, &TraitItemMethod)> {
+		self.items.iter().filter_map(|(_, item)| item.latest_version_to_call())
 	}
 
+	/// Returns all versions of all functions, including the `register_only` ones.
 	pub fn all_versions(&self) -> impl Iterator<Item = (u32, &TraitItemMethod)> {
 		self.items.iter().flat_map(|(_, item)| item.versions.iter()).map(|(v, i)| (*v, *i))
 	}
@@ -214,26 +223,59 @@ fn get_trait_methods<'a>(trait_def: &'a ItemTrait) -> impl Iterator<Item = &'a T
 		})
 }
 
+/// The parsed version attribute of a runtime interface function.
+#[derive(Clone, Copy)]
+struct VersionAttribute {
+	version: u32,
+	/// If set, the host function is only registered on the host, but the runtime keeps calling
+	/// the previous version.
+	is_register_only: bool,
+}
+
+impl VersionAttribute {
+	/// Is this function version callable from the runtime?
+	fn is_callable(&self) -> bool {
+		!self.is_register_only
+	}
+}
+
+impl Default for VersionAttribute {
+	fn default() -> Self {
+		Self { version: 1, is_register_only: false }
+	}
+}
+
 /// Parse version attribute.
 ///
-/// Returns error if it is in incorrent format. Correct format is only `#[version(X)]`.
-fn parse_version_attribute(version: &Attribute) -> Result<u32> {
+/// Returns error if it is in incorrent format. Correct formats are only `#[version(X)]` and
+/// `#[version(X, register_only)]`.
+fn parse_version_attribute(version: &Attribute) -> Result<VersionAttribute> {
 	let meta = version.parse_meta()?;
 
 	let err = Err(Error::new(
 			meta.span(),
-			"Unexpected `version` attribute. The supported format is `#[version(1)]`",
+			"Unexpected `version` attribute. The supported formats are `#[version(1)]` and \
+			`#[version(1, register_only)]`",
 		)
 	);
 
 	match meta {
 		Meta::List(list) => {
-			if list.nested.len() != 1 {
+			let mut nested = list.nested.iter();
+			let version = match nested.next() {
+				Some(NestedMeta::Lit(Lit::Int(i))) => i.base10_parse()?,
+				_ => return err,
+			};
+			let is_register_only = match nested.next() {
+				None => false,
+				Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident("register_only") => true,
+				_ => return err,
+			};
+
+			if nested.next().is_some() {
 				err
-			} else if let Some(NestedMeta::Lit(Lit::Int(i))) = list.nested.first() {
-				i.base10_parse()
 			} else {
-				err
+				Ok(VersionAttribute { version, is_register_only })
 			}
 		},
 		_ => err,
@@ -241,7 +283,7 @@ fn parse_version_attribute(version: &Attribute) -> Result<u32> {
 }
 
 /// Return item version (`#[version(X)]`) attribute, if present.
-fn get_item_version(item: &TraitItemMethod) -> Result<Option<u32>> {
+fn get_item_version(item: &TraitItemMethod) -> Result<Option<VersionAttribute>> {
 	item.attrs.iter().find(|attr| attr.path.is_ident("version"))
 		.map(|attr| parse_version_attribute(attr))
 		.transpose()
@@ -255,12 +297,12 @@ pub fn get_runtime_interface<'a>(trait_def: &'a ItemTrait)
 
 	for item in get_trait_methods(trait_def) {
 		let name = item.sig.ident.clone();
-		let version = get_item_version(item)?.unwrap_or(1);
+		let version = get_item_version(item)?.unwrap_or_default();
 
 		match functions.entry(name.clone()) {
 			Entry::Vacant(entry) => { entry.insert(RuntimeInterfaceFunction::new(version, item)); },
 			Entry::Occupied(mut entry) => {
-				if let Some(existing_item) = entry.get().versions.get(&version) {
+				if let Some(existing_item) = entry.get().versions.get(&version.version) {
 					let mut err = Error::new(
 						item.span(),
 						"Duplicated version attribute",
@@ -274,8 +316,12 @@ pub fn get_runtime_interface<'a>(trait_def: &'a ItemTrait)
 				}
 
 				let interface_item = entry.get_mut();
-				if interface_item.latest_version < version { interface_item.latest_version = version; }
-				interface_item.versions.insert(version, item);
+				if version.is_callable() &&
+					interface_item.latest_version_to_call.map_or(true, |v| v < version.version)
+				{
+					interface_item.latest_version_to_call = Some(version.version);
+				}
+				interface_item.versions.insert(version.version, item);
 			}
 		}
 	}
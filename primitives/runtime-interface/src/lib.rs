@@ -151,6 +151,16 @@ pub use sp_std;
 ///         [17].to_vec()
 ///     }
 ///
+///     /// Call function, but different version, which is only registered on the host.
+///     ///
+///     /// The host already provides this version, but runtimes will only start calling it once
+///     /// the `register_only` flag is removed. This allows to roll out the host function to the
+///     /// nodes of a network before the runtimes start to depend on it.
+///     #[version(3, register_only)]
+///     fn call(data: &[u8]) -> Vec<u8> {
+///         [18].to_vec()
+///     }
+///
 ///     /// A function can take a `&self` or `&mut self` argument to get access to the
 ///     /// `Externalities`. (The generated method does not require
 ///     /// this argument, so the function can be called just with the `optional` argument)
@@ -175,12 +185,14 @@ pub use sp_std;
 ///     trait Interface {
 ///         fn call_version_1(data: &[u8]) -> Vec<u8>;
 ///         fn call_version_2(data: &[u8]) -> Vec<u8>;
+///         fn call_version_3(data: &[u8]) -> Vec<u8>;
 ///         fn set_or_clear_version_1(&mut self, optional: Option<Vec<u8>>);
 ///     }
 ///
 ///     impl Interface for &mut dyn sp_externalities::Externalities {
 ///         fn call_version_1(data: &[u8]) -> Vec<u8> { Vec::new() }
 ///         fn call_version_2(data: &[u8]) -> Vec<u8> { [17].to_vec() }
+///         fn call_version_3(data: &[u8]) -> Vec<u8> { [18].to_vec() }
 ///         fn set_or_clear_version_1(&mut self, optional: Option<Vec<u8>>) {
 ///             match optional {
 ///                 Some(value) => self.set_storage([1, 2, 3, 4].to_vec(), value),
@@ -190,7 +202,7 @@ pub use sp_std;
 ///     }
 ///
 ///     pub fn call(data: &[u8]) -> Vec<u8> {
-///         // only latest version is exposed
+///         // only the latest version that is not `register_only` is exposed
 ///         call_version_2(data)
 ///     }
 ///
@@ -202,6 +214,10 @@ pub use sp_std;
 ///         <&mut dyn sp_externalities::Externalities as Interface>::call_version_2(data)
 ///     }
 ///
+///     fn call_version_3(data: &[u8]) -> Vec<u8> {
+///         <&mut dyn sp_externalities::Externalities as Interface>::call_version_3(data)
+///     }
+///
 ///     pub fn set_or_clear(optional: Option<Vec<u8>>) {
 ///         set_or_clear_version_1(optional)
 ///     }
@@ -236,7 +252,9 @@ pub use sp_std;
 ///             /// `<ARGUMENT_TYPE as RIType>::FFIType`.
 ///             ///
 ///             /// `data` holds the pointer and the length to the `[u8]` slice.
-///             pub fn ext_Interface_call_version_1(data: u64) -> u64;
+///             ///
+///             /// Only the latest version that is not `register_only` is imported.
+///             pub fn ext_Interface_call_version_2(data: u64) -> u64;
 ///             /// `optional` holds the pointer and the length of the encoded value.
 ///             pub fn ext_Interface_set_or_clear_version_1(optional: u64);
 ///         }
@@ -121,6 +121,16 @@ pub trait TestApi {
 		data == 42
 	}
 
+	fn test_versionning_register_only(&self, data: u32) -> bool {
+		data == 80
+	}
+
+	/// This version is only registered on the host, the runtime still calls version 1.
+	#[version(2, register_only)]
+	fn test_versionning_register_only(&self, data: u32) -> bool {
+		data == 42
+	}
+
 	/// Returns the input values as tuple.
 	fn return_input_as_tuple(
 		a: Vec<u8>,
@@ -269,6 +279,13 @@ wasm_export_functions! {
 		assert!(!test_api::test_versionning(102));
 	}
 
+	fn test_versionning_register_only_works() {
+		// Ensure that we will import the version of the runtime interface function that
+		// isn't tagged with `register_only`.
+		assert!(!test_api::test_versionning_register_only(42));
+		assert!(test_api::test_versionning_register_only(80));
+	}
+
 	fn test_return_input_as_tuple() {
 		let a = vec![1, 3, 4, 5];
 		let b = 10000;
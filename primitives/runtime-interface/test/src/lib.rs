@@ -155,6 +155,20 @@ fn test_versionining_with_new_host_works() {
 	);
 }
 
+#[test]
+fn test_versionining_register_only() {
+	// The host registers both versions, but the runtime only imports the callable one.
+	call_wasm_method::<HostFunctions>(
+		&wasm_binary_unwrap()[..],
+		"test_versionning_register_only_works",
+	);
+
+	let host_functions = HostFunctions::host_functions();
+	let registered = |name: &str| host_functions.iter().any(|f| f.name() == name);
+	assert!(registered("ext_test_api_test_versionning_register_only_version_1"));
+	assert!(registered("ext_test_api_test_versionning_register_only_version_2"));
+}
+
 #[test]
 fn test_tracing() {
 	use std::fmt;
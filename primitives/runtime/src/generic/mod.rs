@@ -28,7 +28,9 @@ mod digest;
 #[cfg(test)]
 mod tests;
 
-pub use self::unchecked_extrinsic::{UncheckedExtrinsic, SignedPayload};
+pub use self::unchecked_extrinsic::{
+	UncheckedExtrinsic, SignedPayload, ExtensionVersion, EXTENSION_VERSION,
+};
 pub use self::era::{Era, Phase};
 pub use self::checked_extrinsic::CheckedExtrinsic;
 pub use self::header::Header;
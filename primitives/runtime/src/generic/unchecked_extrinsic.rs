@@ -31,10 +31,38 @@ use crate::{
 };
 
 /// Current version of the [`UncheckedExtrinsic`] format.
-const EXTRINSIC_VERSION: u8 = 4;
+const EXTRINSIC_FORMAT_VERSION: u8 = 5;
+
+/// Previous version of the [`UncheckedExtrinsic`] format, without extension version, which is
+/// still decoded, e.g. for the extrinsics of historical blocks.
+const LEGACY_EXTRINSIC_FORMAT_VERSION: u8 = 4;
+
+/// The version of the signed extensions of an extrinsic.
+pub type ExtensionVersion = u8;
+
+/// The version of the signed extensions of the [`UncheckedExtrinsic`]s which are checked.
+///
+/// It is also the version of the signed extensions of the extrinsics of the legacy format.
+pub const EXTENSION_VERSION: ExtensionVersion = 0;
 
 /// A extrinsic right from the external world. This is unchecked and so
 /// can contain a signature.
+///
+/// # Format
+///
+/// The extrinsic is encoded as a `Vec<u8>` of:
+/// - a byte whose lower 7 bits are the version of the format, currently 5, and whose highest bit
+///   is set if the extrinsic is signed,
+/// - if the extrinsic is signed, the [`ExtensionVersion`] of its signed extensions, followed by
+///   its address, signature and signed extensions,
+/// - the call.
+///
+/// The signed extensions are versioned so that a runtime can change its set of signed extensions
+/// while still telling apart the extrinsics built for the previous set. The extrinsics of the
+/// version 4 of the format, which has no extension version, are decoded with the extension
+/// version [`EXTENSION_VERSION`], and are encoded back in the version 4 of the format, so that
+/// their encoding, and thus their hash and the extrinsics root of their block, do not change. The
+/// signed payload is the same in both versions of the format.
 #[derive(PartialEq, Eq, Clone)]
 pub struct UncheckedExtrinsic<Address, Call, Signature, Extra>
 where
@@ -44,8 +72,12 @@ where
 	/// the same signer and an era describing the longevity of this transaction,
 	/// if this is a signed extrinsic.
 	pub signature: Option<(Address, Signature, Extra)>,
+	/// The version of the signed extensions, only meaningful if this is a signed extrinsic.
+	pub extension_version: ExtensionVersion,
 	/// The function that should be called.
 	pub function: Call,
+	/// The version of the format the extrinsic is encoded in.
+	format_version: u8,
 }
 
 #[cfg(feature = "std")]
@@ -72,7 +104,9 @@ impl<Address, Call, Signature, Extra: SignedExtension>
 	) -> Self {
 		Self {
 			signature: Some((signed, signature, extra)),
+			extension_version: EXTENSION_VERSION,
 			function,
+			format_version: EXTRINSIC_FORMAT_VERSION,
 		}
 	}
 
@@ -80,9 +114,17 @@ impl<Address, Call, Signature, Extra: SignedExtension>
 	pub fn new_unsigned(function: Call) -> Self {
 		Self {
 			signature: None,
+			extension_version: EXTENSION_VERSION,
 			function,
+			format_version: EXTRINSIC_FORMAT_VERSION,
 		}
 	}
+
+	/// The version of the format the extrinsic is encoded in, which is the version it was decoded
+	/// from, or the current version for a new extrinsic.
+	pub fn format_version(&self) -> u8 {
+		self.format_version
+	}
 }
 
 impl<Address, Call, Signature, Extra: SignedExtension> Extrinsic
@@ -127,6 +169,9 @@ where
 	fn check(self, lookup: &Lookup) -> Result<Self::Checked, TransactionValidityError> {
 		Ok(match self.signature {
 			Some((signed, signature, extra)) => {
+				if self.extension_version != EXTENSION_VERSION {
+					return Err(InvalidTransaction::UnknownExtensionVersion.into())
+				}
 				let signed = lookup.lookup(signed)?;
 				let raw_payload = SignedPayload::new(self.function, extra)?;
 				if !raw_payload.using_encoded(|payload| signature.verify(payload, &signed)) {
@@ -152,7 +197,7 @@ impl<Address, Call, Signature, Extra> ExtrinsicMetadata
 		where
 			Extra: SignedExtension,
 {
-	const VERSION: u8 = EXTRINSIC_VERSION;
+	const VERSION: u8 = EXTRINSIC_FORMAT_VERSION;
 	type SignedExtensions = Extra;
 }
 
@@ -234,13 +279,17 @@ where
 
 		let is_signed = version & 0b1000_0000 != 0;
 		let version = version & 0b0111_1111;
-		if version != EXTRINSIC_VERSION {
-			return Err("Invalid transaction version".into());
-		}
+		let extension_version = match version {
+			EXTRINSIC_FORMAT_VERSION if is_signed => input.read_byte()?,
+			EXTRINSIC_FORMAT_VERSION | LEGACY_EXTRINSIC_FORMAT_VERSION => EXTENSION_VERSION,
+			_ => return Err("Invalid transaction version".into()),
+		};
 
 		Ok(Self {
 			signature: if is_signed { Some(Decode::decode(input)?) } else { None },
+			extension_version,
 			function: Decode::decode(input)?,
+			format_version: version,
 		})
 	}
 }
@@ -258,11 +307,15 @@ where
 			// 1 byte version id.
 			match self.signature.as_ref() {
 				Some(s) => {
-					v.push(EXTRINSIC_VERSION | 0b1000_0000);
+					v.push(self.format_version | 0b1000_0000);
+					// The legacy format has no extension version.
+					if self.format_version != LEGACY_EXTRINSIC_FORMAT_VERSION {
+						v.push(self.extension_version);
+					}
 					s.encode_to(v);
 				}
 				None => {
-					v.push(EXTRINSIC_VERSION & 0b0111_1111);
+					v.push(self.format_version & 0b0111_1111);
 				}
 			}
 			self.function.encode_to(v);
@@ -434,6 +487,73 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn signed_extrinsics_carry_the_extension_version() {
+		let mut ux = Ex::new_signed(
+			vec![0u8; 0],
+			TEST_ACCOUNT,
+			TestSig(TEST_ACCOUNT, (vec![0u8; 0], TestExtra).encode()),
+			TestExtra,
+		);
+		ux.extension_version = 1;
+		let encoded = ux.encode();
+		// the length prefix, the format version with the signed bit, and the extension version.
+		assert_eq!(encoded[1..3], [EXTRINSIC_FORMAT_VERSION | 0b1000_0000, 1]);
+		assert_eq!(Ex::decode(&mut &encoded[..]), Ok(ux.clone()));
+
+		assert_eq!(
+			<Ex as Checkable<TestContext>>::check(ux, &Default::default()),
+			Err(InvalidTransaction::UnknownExtensionVersion.into()),
+		);
+	}
+
+	#[test]
+	fn legacy_format_is_decoded() {
+		let signature = TestSig(TEST_ACCOUNT, (vec![0u8; 0], TestExtra).encode());
+		let signed = (TEST_ACCOUNT, signature.clone(), TestExtra);
+		let legacy = super::super::encode_with_vec_prefix::<Ex, _>(|v| {
+			v.push(LEGACY_EXTRINSIC_FORMAT_VERSION | 0b1000_0000);
+			signed.encode_to(v);
+			vec![0u8; 0].encode_to(v);
+		});
+
+		let ux = Ex::decode(&mut &legacy[..]).unwrap();
+		assert_eq!(ux.signature, Some(signed));
+		assert_eq!(ux.extension_version, EXTENSION_VERSION);
+		assert_eq!(ux.format_version(), LEGACY_EXTRINSIC_FORMAT_VERSION);
+		assert!(<Ex as Checkable<TestContext>>::check(ux, &Default::default()).is_ok());
+
+		let legacy = super::super::encode_with_vec_prefix::<Ex, _>(|v| {
+			v.push(LEGACY_EXTRINSIC_FORMAT_VERSION);
+			vec![0u8; 0].encode_to(v);
+		});
+		let ux = Ex::decode(&mut &legacy[..]).unwrap();
+		assert_eq!(ux.signature, None);
+		assert_eq!(ux.format_version(), LEGACY_EXTRINSIC_FORMAT_VERSION);
+	}
+
+	#[test]
+	fn legacy_format_is_encoded_back_unchanged() {
+		let signature = TestSig(TEST_ACCOUNT, (vec![0u8; 0], TestExtra).encode());
+		let signed = (TEST_ACCOUNT, signature, TestExtra);
+		let signed_legacy = super::super::encode_with_vec_prefix::<Ex, _>(|v| {
+			v.push(LEGACY_EXTRINSIC_FORMAT_VERSION | 0b1000_0000);
+			signed.encode_to(v);
+			vec![1u8; 3].encode_to(v);
+		});
+		let unsigned_legacy = super::super::encode_with_vec_prefix::<Ex, _>(|v| {
+			v.push(LEGACY_EXTRINSIC_FORMAT_VERSION);
+			vec![1u8; 3].encode_to(v);
+		});
+
+		for legacy in [signed_legacy, unsigned_legacy].iter() {
+			let ux = Ex::decode(&mut &legacy[..]).unwrap();
+			assert_eq!(&ux.encode(), legacy);
+			let opaque: OpaqueExtrinsic = ux.into();
+			assert_eq!(&opaque.encode(), legacy);
+		}
+	}
+
 	#[test]
 	fn encoding_matches_vec() {
 		let ex = Ex::new_unsigned(vec![0u8; 0]);
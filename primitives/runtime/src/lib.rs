@@ -441,6 +441,9 @@ pub type DispatchResult = sp_std::result::Result<(), DispatchError>;
 /// about the `Dispatchable` that is only known post dispatch.
 pub type DispatchResultWithInfo<T> = sp_std::result::Result<T, DispatchErrorWithPostInfo<T>>;
 
+/// The maximum size of the encoded error of a module, see [`DispatchError::Module`].
+pub const MAX_MODULE_ERROR_ENCODED_SIZE: usize = 4;
+
 /// Reason why a dispatch call failed.
 #[derive(Eq, Clone, Copy, Encode, Decode, Debug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -455,8 +458,11 @@ pub enum DispatchError {
 	Module {
 		/// Module index, matching the metadata module index.
 		index: u8,
-		/// Module specific error value.
-		error: u8,
+		/// Module specific error value, the encoded error of the module padded with zeros.
+		///
+		/// The first byte is the index of the error variant, the following bytes encode the
+		/// fields of the variant, if any.
+		error: [u8; MAX_MODULE_ERROR_ENCODED_SIZE],
 		/// Optional error message.
 		#[codec(skip)]
 		#[cfg_attr(feature = "std", serde(skip_deserializing))]
@@ -634,7 +640,7 @@ impl traits::Printable for DispatchError {
 			Self::BadOrigin => "Bad origin".print(),
 			Self::Module { index, error, message } => {
 				index.print();
-				error.print();
+				(&error[..]).print();
 				if let Some(msg) = message {
 					msg.print();
 				}
@@ -906,17 +912,17 @@ mod tests {
 	fn dispatch_error_encoding() {
 		let error = DispatchError::Module {
 			index: 1,
-			error: 2,
+			error: [2, 1, 0, 0],
 			message: Some("error message"),
 		};
 		let encoded = error.encode();
 		let decoded = DispatchError::decode(&mut &encoded[..]).unwrap();
-		assert_eq!(encoded, vec![3, 1, 2]);
+		assert_eq!(encoded, vec![3, 1, 2, 1, 0, 0]);
 		assert_eq!(
 			decoded,
 			DispatchError::Module {
 				index: 1,
-				error: 2,
+				error: [2, 1, 0, 0],
 				message: None,
 			},
 		);
@@ -931,9 +937,10 @@ mod tests {
 			Other("bar"),
 			CannotLookup,
 			BadOrigin,
-			Module { index: 1, error: 1, message: None },
-			Module { index: 1, error: 2, message: None },
-			Module { index: 2, error: 1, message: None },
+			Module { index: 1, error: [1, 0, 0, 0], message: None },
+			Module { index: 1, error: [2, 0, 0, 0], message: None },
+			Module { index: 1, error: [2, 1, 0, 0], message: None },
+			Module { index: 2, error: [1, 0, 0, 0], message: None },
 			ConsumerRemaining,
 			NoProviders,
 			Token(TokenError::NoFunds),
@@ -958,8 +965,8 @@ mod tests {
 
 		// Ignores `message` field in `Module` variant.
 		assert_eq!(
-			Module { index: 1, error: 1, message: Some("foo") },
-			Module { index: 1, error: 1, message: None},
+			Module { index: 1, error: [1, 0, 0, 0], message: Some("foo") },
+			Module { index: 1, error: [1, 0, 0, 0], message: None},
 		);
 	}
 
@@ -288,6 +288,37 @@ impl<A, B: From<A>> Convert<A, B> for ConvertInto {
 	fn convert(a: A) -> B { a.into() }
 }
 
+/// Infallible conversion of a value of type `A` into some other type, chosen by the implementer.
+///
+/// Unlike [`Convert`], the type of the outcome is determined by `Self`, so that it doesn't need to
+/// be named when the conversion is used.
+pub trait Morph<A> {
+	/// The type into which `A` is mutated.
+	type Outcome;
+
+	/// Make conversion.
+	fn morph(a: A) -> Self::Outcome;
+}
+
+impl<T> Morph<T> for Identity {
+	type Outcome = T;
+	fn morph(a: T) -> T { a }
+}
+
+/// Fallible conversion of a value of type `A` into some other type, chosen by the implementer.
+pub trait TryMorph<A> {
+	/// The type into which `A` is mutated.
+	type Outcome;
+
+	/// Make conversion, returning `Err(())` if it fails.
+	fn try_morph(a: A) -> Result<Self::Outcome, ()>;
+}
+
+impl<T> TryMorph<T> for Identity {
+	type Outcome = T;
+	fn try_morph(a: T) -> Result<T, ()> { Ok(a) }
+}
+
 /// Convenience type to work around the highly unergonomic syntax needed
 /// to invoke the functions of overloaded generic traits, in this case
 /// `TryFrom` and `TryInto`.
@@ -76,6 +76,11 @@ pub enum InvalidTransaction {
 	/// A transaction with a mandatory dispatch. This is invalid; only inherent extrinsics are
 	/// allowed to have mandatory dispatches.
 	MandatoryDispatch,
+	/// Multi-block migrations are ongoing, only mandatory extrinsics are allowed until they are
+	/// complete.
+	MigrationsOngoing,
+	/// The signed extensions of the transaction are of a version which is not supported.
+	UnknownExtensionVersion,
 }
 
 impl InvalidTransaction {
@@ -106,6 +111,10 @@ impl From<InvalidTransaction> for &'static str {
 				"A call was labelled as mandatory, but resulted in an Error.",
 			InvalidTransaction::MandatoryDispatch =>
 				"Transaction dispatch is mandatory; transactions may not have mandatory dispatches.",
+			InvalidTransaction::MigrationsOngoing =>
+				"Transactions are not allowed while multi-block migrations are ongoing",
+			InvalidTransaction::UnknownExtensionVersion =>
+				"Transaction has signed extensions of an unknown version",
 			InvalidTransaction::Custom(_) => "InvalidTransaction custom error",
 		}
 	}
@@ -39,6 +39,8 @@ mod trie_backend_essence;
 mod stats;
 #[cfg(feature = "std")]
 mod read_only;
+#[cfg(feature = "std")]
+mod tracking;
 
 #[cfg(feature = "std")]
 pub use std_reexport::*;
@@ -147,6 +149,7 @@ mod std_reexport {
 	pub use crate::testing::TestExternalities;
 	pub use crate::basic::BasicExternalities;
 	pub use crate::read_only::{ReadOnlyExternalities, InspectState};
+	pub use crate::tracking::{AccessTrackingExternalities, KeyAccess, StorageAccessReport};
 	pub use crate::changes_trie::{
 		AnchorBlockId as ChangesTrieAnchorBlockId,
 		State as ChangesTrieState,
@@ -215,6 +218,13 @@ mod execution {
 		NativeElseWasm,
 	}
 
+	/// Wasm execution is the default, native execution must be opted in explicitly.
+	impl Default for ExecutionStrategy {
+		fn default() -> Self {
+			ExecutionStrategy::AlwaysWasm
+		}
+	}
+
 	/// Storage backend trust level.
 	#[derive(Debug, Clone)]
 	pub enum BackendTrustLevel {
@@ -308,6 +318,13 @@ mod execution {
 		storage_transaction_cache: Option<&'a mut StorageTransactionCache<B::Transaction, H, N>>,
 		runtime_code: &'a RuntimeCode<'a>,
 		stats: StateMachineStats,
+		access_tracking: Option<AccessTracking>,
+	}
+
+	/// Access tracking state of the state machine.
+	struct AccessTracking {
+		read_only: bool,
+		report: StorageAccessReport,
 	}
 
 	impl<'a, B, H, N, Exec> Drop for StateMachine<'a, B, H, N, Exec> where
@@ -353,6 +370,7 @@ mod execution {
 				storage_transaction_cache: None,
 				runtime_code,
 				stats: StateMachineStats::default(),
+				access_tracking: None,
 			}
 		}
 
@@ -369,6 +387,20 @@ mod execution {
 			self
 		}
 
+		/// Record every storage access made by the runtime call.
+		///
+		/// If `read_only` is true, the call panics on any write. The accesses of the last call can
+		/// be retrieved with [`Self::storage_access_report`].
+		pub fn with_access_tracking(mut self, read_only: bool) -> Self {
+			self.access_tracking = Some(AccessTracking { read_only, report: Default::default() });
+			self
+		}
+
+		/// The storage accesses made by the last runtime call, if access tracking is enabled.
+		pub fn storage_access_report(&self) -> Option<&StorageAccessReport> {
+			self.access_tracking.as_ref().map(|tracking| &tracking.report)
+		}
+
 		/// Execute a call using the given state backend, overlayed changes, and call executor.
 		///
 		/// On an error, no prospective changes are written to the overlay.
@@ -376,10 +408,12 @@ mod execution {
 		/// Note: changes to code will be in place if this call is made again. For running partial
 		/// blocks (e.g. a transaction at a time), ensure a different method is used.
 		///
+		/// The native runtime is only used if `strategy` opts in for it, the default strategy is
+		/// [`ExecutionStrategy::AlwaysWasm`].
+		///
 		/// Returns the SCALE encoded result of the executed function.
 		pub fn execute(&mut self, strategy: ExecutionStrategy) -> Result<Vec<u8>, Box<dyn Error>> {
-			// We are not giving a native call and thus we are sure that the result can never be a native
-			// value.
+			// No native call is given, so the result is always SCALE encoded.
 			self.execute_using_consensus_failure_handler::<_, NeverNativeValue, fn() -> _>(
 				strategy.get_manager(),
 				None,
@@ -423,14 +457,32 @@ mod execution {
 				HexDisplay::from(&self.call_data),
 			);
 
-			let (result, was_native) = self.exec.call(
-				&mut ext,
-				self.runtime_code,
-				self.method,
-				self.call_data,
-				use_native,
-				native_call,
-			);
+			let (result, was_native) = match self.access_tracking.as_mut() {
+				Some(tracking) => {
+					let mut tracking_ext = AccessTrackingExternalities::new(
+						&mut ext,
+						tracking.read_only,
+					);
+					let res = self.exec.call(
+						&mut tracking_ext,
+						self.runtime_code,
+						self.method,
+						self.call_data,
+						use_native,
+						native_call,
+					);
+					tracking.report = tracking_ext.into_report();
+					res
+				},
+				None => self.exec.call(
+					&mut ext,
+					self.runtime_code,
+					self.method,
+					self.call_data,
+					use_native,
+					native_call,
+				),
+			};
 
 			self.overlay.exit_runtime()
 				.expect("Runtime is not able to call this function in the overlay; qed");
@@ -447,7 +499,7 @@ mod execution {
 
 		fn execute_call_with_both_strategy<Handler, R, NC>(
 			&mut self,
-			mut native_call: Option<NC>,
+			native_call: Option<NC>,
 			on_consensus_failure: Handler,
 		) -> CallResult<R, Exec::Error>
 			where
@@ -459,14 +511,12 @@ mod execution {
 				) -> CallResult<R, Exec::Error>
 		{
 			self.overlay.start_transaction();
-			let (result, was_native) = self.execute_aux(true, native_call.take());
+			// The native call is consumed by the native execution, the wasm one doesn't use it.
+			let (result, was_native) = self.execute_aux(true, native_call);
 
 			if was_native {
 				self.overlay.rollback_transaction().expect(PROOF_CLOSE_TRANSACTION);
-				let (wasm_result, _) = self.execute_aux(
-					false,
-					native_call,
-				);
+				let (wasm_result, _) = self.execute_aux(false, None::<NC>);
 
 				if (result.is_ok() && wasm_result.is_ok()
 					&& result.as_ref().ok() == wasm_result.as_ref().ok())
@@ -484,28 +534,21 @@ mod execution {
 
 		fn execute_call_with_native_else_wasm_strategy<R, NC>(
 			&mut self,
-			mut native_call: Option<NC>,
+			native_call: Option<NC>,
 		) -> CallResult<R, Exec::Error>
 			where
 				R: Decode + Encode + PartialEq,
 				NC: FnOnce() -> result::Result<R, Box<dyn std::error::Error + Send + Sync>> + UnwindSafe,
 		{
 			self.overlay.start_transaction();
-			let (result, was_native) = self.execute_aux(
-				true,
-				native_call.take(),
-			);
+			let (result, was_native) = self.execute_aux(true, native_call);
 
 			if !was_native || result.is_ok() {
 				self.overlay.commit_transaction().expect(PROOF_CLOSE_TRANSACTION);
 				result
 			} else {
 				self.overlay.rollback_transaction().expect(PROOF_CLOSE_TRANSACTION);
-				let (wasm_result, _) = self.execute_aux(
-					false,
-					native_call,
-				);
-				wasm_result
+				self.execute_aux(false, None::<NC>).0
 			}
 		}
 
@@ -521,7 +564,7 @@ mod execution {
 		pub fn execute_using_consensus_failure_handler<Handler, R, NC>(
 			&mut self,
 			manager: ExecutionManager<Handler>,
-			mut native_call: Option<NC>,
+			native_call: Option<NC>,
 		) -> Result<NativeOrEncoded<R>, Box<dyn Error>>
 			where
 				R: Decode + Encode + PartialEq,
@@ -534,30 +577,20 @@ mod execution {
 			let changes_tries_enabled = self.changes_trie_state.is_some();
 			self.overlay.set_collect_extrinsics(changes_tries_enabled);
 
-			let result = {
-				match manager {
-					ExecutionManager::Both(on_consensus_failure) => {
-						self.execute_call_with_both_strategy(
-							native_call.take(),
-							on_consensus_failure,
-						)
-					},
-					ExecutionManager::NativeElseWasm => {
-						self.execute_call_with_native_else_wasm_strategy(
-							native_call.take(),
-						)
-					},
-					ExecutionManager::AlwaysWasm(trust_level) => {
-						let _abort_guard = match trust_level {
-							BackendTrustLevel::Trusted => None,
-							BackendTrustLevel::Untrusted => Some(sp_panic_handler::AbortGuard::never_abort()),
-						};
-						self.execute_aux(false, native_call).0
-					},
-					ExecutionManager::NativeWhenPossible => {
-						self.execute_aux(true, native_call).0
-					},
-				}
+			let result = match manager {
+				ExecutionManager::AlwaysWasm(trust_level) => {
+					let _abort_guard = match trust_level {
+						BackendTrustLevel::Trusted => None,
+						BackendTrustLevel::Untrusted =>
+							Some(sp_panic_handler::AbortGuard::never_abort()),
+					};
+					self.execute_aux(false, None::<NC>).0
+				},
+				ExecutionManager::NativeWhenPossible => self.execute_aux(true, native_call).0,
+				ExecutionManager::NativeElseWasm =>
+					self.execute_call_with_native_else_wasm_strategy(native_call),
+				ExecutionManager::Both(on_consensus_failure) =>
+					self.execute_call_with_both_strategy(native_call, on_consensus_failure),
 			};
 
 			result.map_err(|e| Box::new(e) as _)
@@ -1009,6 +1042,72 @@ mod tests {
 		assert_eq!(state_machine.execute(ExecutionStrategy::NativeElseWasm).unwrap(), vec![66]);
 	}
 
+	fn execute_with(
+		executor: DummyCodeExecutor,
+		strategy: ExecutionStrategy,
+	) -> Result<Vec<u8>, Box<dyn Error>> {
+		let backend = trie_backend::tests::test_trie();
+		let mut overlayed_changes = Default::default();
+		let wasm_code = RuntimeCode::empty();
+
+		StateMachine::new(
+			&backend,
+			changes_trie::disabled_state::<_, u64>(),
+			&mut overlayed_changes,
+			&executor,
+			"test",
+			&[],
+			Default::default(),
+			&wasm_code,
+			TaskExecutor::new(),
+		).execute(strategy)
+	}
+
+	#[test]
+	fn execute_uses_wasm_by_default() {
+		assert_eq!(ExecutionStrategy::default(), ExecutionStrategy::AlwaysWasm);
+
+		// Only the wasm runtime succeeds.
+		let wasm_only = || DummyCodeExecutor {
+			change_changes_trie_config: false,
+			native_available: true,
+			native_succeeds: false,
+			fallback_succeeds: true,
+		};
+		assert_eq!(execute_with(wasm_only(), ExecutionStrategy::default()).unwrap(), vec![66]);
+		assert!(execute_with(wasm_only(), ExecutionStrategy::NativeWhenPossible).is_err());
+
+		// Only the native runtime succeeds.
+		let native_only = || DummyCodeExecutor {
+			change_changes_trie_config: false,
+			native_available: true,
+			native_succeeds: true,
+			fallback_succeeds: false,
+		};
+		assert!(execute_with(native_only(), ExecutionStrategy::default()).is_err());
+		assert_eq!(
+			execute_with(native_only(), ExecutionStrategy::NativeWhenPossible).unwrap(),
+			vec![66],
+		);
+	}
+
+	#[test]
+	fn native_else_wasm_falls_back_to_wasm() {
+		let executor = |native_available| DummyCodeExecutor {
+			change_changes_trie_config: false,
+			native_available,
+			native_succeeds: false,
+			fallback_succeeds: true,
+		};
+
+		for native_available in [true, false].iter().copied() {
+			assert_eq!(
+				execute_with(executor(native_available), ExecutionStrategy::NativeElseWasm).unwrap(),
+				vec![66],
+			);
+		}
+	}
+
 	#[test]
 	fn dual_execution_strategy_detects_consensus_failure() {
 		let mut consensus_failed = false;
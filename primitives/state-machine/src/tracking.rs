@@ -0,0 +1,468 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Externalities wrapper recording every storage access.
+//!
+//! [`AccessTrackingExternalities`] wraps any [`Externalities`] and records each key read or
+//! written in a [`StorageAccessReport`]. It can also enforce a read-only mode, in which any write
+//! panics.
+//!
+//! The report can be compared against the benchmarking whitelist to find the reads which are
+//! counted as database operations, see [`StorageAccessReport::db_ops`].
+
+use std::{
+	any::{TypeId, Any},
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+	fmt,
+};
+use crate::{StorageKey, StorageValue};
+use sp_core::{
+	hexdisplay::HexDisplay,
+	storage::{ChildInfo, TrackedStorageKey},
+	traits::Externalities,
+};
+
+/// How many times a single key was accessed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeyAccess {
+	/// Number of reads of the key.
+	pub reads: u32,
+	/// Number of writes of the key.
+	pub writes: u32,
+}
+
+/// All the storage accesses recorded by an [`AccessTrackingExternalities`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StorageAccessReport {
+	/// Accesses of the top trie, by key.
+	pub top: BTreeMap<StorageKey, KeyAccess>,
+	/// Accesses of the child tries, by child storage key and then by key.
+	pub children: BTreeMap<StorageKey, BTreeMap<StorageKey, KeyAccess>>,
+	/// Prefixes cleared, with the child storage key for prefixes cleared in a child trie.
+	///
+	/// A killed child trie is recorded as its empty prefix.
+	pub cleared_prefixes: BTreeSet<(Option<StorageKey>, StorageKey)>,
+}
+
+impl StorageAccessReport {
+	fn access(&mut self, child_info: Option<&ChildInfo>, key: &[u8]) -> &mut KeyAccess {
+		let keys = match child_info {
+			Some(child_info) => self.children
+				.entry(child_info.storage_key().to_vec())
+				.or_default(),
+			None => &mut self.top,
+		};
+
+		keys.entry(key.to_vec()).or_default()
+	}
+
+	fn record_read(&mut self, child_info: Option<&ChildInfo>, key: &[u8]) {
+		self.access(child_info, key).reads += 1;
+	}
+
+	fn record_write(&mut self, child_info: Option<&ChildInfo>, key: &[u8]) {
+		self.access(child_info, key).writes += 1;
+	}
+
+	fn record_clear_prefix(&mut self, child_info: Option<&ChildInfo>, prefix: &[u8]) {
+		let child = child_info.map(|child_info| child_info.storage_key().to_vec());
+		self.cleared_prefixes.insert((child, prefix.to_vec()));
+	}
+
+	fn all_accesses(&self) -> impl Iterator<Item = &KeyAccess> {
+		self.top.values().chain(self.children.values().flat_map(|keys| keys.values()))
+	}
+
+	/// The total number of reads, including repeated reads of the same key.
+	pub fn total_reads(&self) -> u32 {
+		self.all_accesses().map(|access| access.reads).sum()
+	}
+
+	/// The total number of writes, including repeated writes of the same key.
+	pub fn total_writes(&self) -> u32 {
+		self.all_accesses().map(|access| access.writes).sum()
+	}
+
+	/// The keys of the top trie which have been read but are not whitelisted for reads.
+	pub fn non_whitelisted_reads<'a>(
+		&'a self,
+		whitelist: &'a [TrackedStorageKey],
+	) -> impl Iterator<Item = &'a StorageKey> + 'a {
+		self.top.iter()
+			.filter(|(_, access)| access.reads > 0)
+			.map(|(key, _)| key)
+			.filter(move |key| {
+				!whitelist.iter().any(|tracked| tracked.has_been_read && &tracked.key == *key)
+			})
+	}
+
+	/// The number of database `(reads, writes)` the accesses amount to.
+	///
+	/// Like benchmarking, each key is counted at most once for reads and once for writes, and keys
+	/// of the top trie which are whitelisted are not counted.
+	pub fn db_ops(&self, whitelist: &[TrackedStorageKey]) -> (u32, u32) {
+		let is_whitelisted = |key: &StorageKey, for_write: bool| whitelist.iter().any(|tracked| {
+			&tracked.key == key &&
+				if for_write { tracked.has_been_written } else { tracked.has_been_read }
+		});
+		let count = |for_write: bool| {
+			let top = self.top.iter()
+				.filter(|(key, access)| {
+					let accessed = if for_write { access.writes > 0 } else { access.reads > 0 };
+					accessed && !is_whitelisted(key, for_write)
+				})
+				.count();
+			let children = self.children.values()
+				.flat_map(|keys| keys.values())
+				.filter(|access| if for_write { access.writes > 0 } else { access.reads > 0 })
+				.count();
+			(top + children) as u32
+		};
+
+		(count(false), count(true))
+	}
+}
+
+impl fmt::Display for StorageAccessReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		writeln!(
+			f,
+			"{} reads, {} writes, {} cleared prefixes",
+			self.total_reads(),
+			self.total_writes(),
+			self.cleared_prefixes.len(),
+		)?;
+
+		let write_keys = |f: &mut fmt::Formatter, keys: &BTreeMap<StorageKey, KeyAccess>| {
+			for (key, access) in keys {
+				writeln!(
+					f,
+					"  0x{}: {} reads, {} writes",
+					HexDisplay::from(key),
+					access.reads,
+					access.writes,
+				)?;
+			}
+			Ok(())
+		};
+
+		write_keys(f, &self.top)?;
+		for (child, keys) in &self.children {
+			writeln!(f, "child 0x{}:", HexDisplay::from(child))?;
+			write_keys(f, keys)?;
+		}
+		for (child, prefix) in &self.cleared_prefixes {
+			match child {
+				Some(child) => writeln!(
+					f,
+					"cleared prefix 0x{} of child 0x{}",
+					HexDisplay::from(prefix),
+					HexDisplay::from(child),
+				)?,
+				None => writeln!(f, "cleared prefix 0x{}", HexDisplay::from(prefix))?,
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Externalities recording every storage access made through them.
+///
+/// All the calls are forwarded to the wrapped externalities. In read-only mode any write panics
+/// before reaching the wrapped externalities.
+pub struct AccessTrackingExternalities<'a> {
+	inner: &'a mut dyn Externalities,
+	report: RefCell<StorageAccessReport>,
+	read_only: bool,
+}
+
+impl<'a> AccessTrackingExternalities<'a> {
+	/// Create new tracking externalities wrapping `inner`.
+	///
+	/// If `read_only` is true, any write panics.
+	pub fn new(inner: &'a mut dyn Externalities, read_only: bool) -> Self {
+		Self { inner, report: Default::default(), read_only }
+	}
+
+	/// Execute the given closure while `self` is set as externalities.
+	///
+	/// Returns the result of the given closure.
+	pub fn execute_with<R>(&mut self, f: impl FnOnce() -> R) -> R {
+		sp_externalities::set_and_run_with_externalities(self, f)
+	}
+
+	/// The accesses recorded so far.
+	pub fn report(&self) -> StorageAccessReport {
+		self.report.borrow().clone()
+	}
+
+	/// Consume `self` and return the accesses recorded.
+	pub fn into_report(self) -> StorageAccessReport {
+		self.report.into_inner()
+	}
+
+	fn ensure_writable(&self, operation: &str) {
+		if self.read_only {
+			panic!("`{}` is not allowed in read-only AccessTrackingExternalities", operation)
+		}
+	}
+}
+
+impl<'a> Externalities for AccessTrackingExternalities<'a> {
+	fn set_offchain_storage(&mut self, key: &[u8], value: Option<&[u8]>) {
+		self.ensure_writable("set_offchain_storage");
+		self.inner.set_offchain_storage(key, value)
+	}
+
+	fn storage(&self, key: &[u8]) -> Option<StorageValue> {
+		self.report.borrow_mut().record_read(None, key);
+		self.inner.storage(key)
+	}
+
+	fn storage_hash(&self, key: &[u8]) -> Option<Vec<u8>> {
+		self.report.borrow_mut().record_read(None, key);
+		self.inner.storage_hash(key)
+	}
+
+	fn child_storage_hash(&self, child_info: &ChildInfo, key: &[u8]) -> Option<Vec<u8>> {
+		self.report.borrow_mut().record_read(Some(child_info), key);
+		self.inner.child_storage_hash(child_info, key)
+	}
+
+	fn child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageValue> {
+		self.report.borrow_mut().record_read(Some(child_info), key);
+		self.inner.child_storage(child_info, key)
+	}
+
+	fn exists_storage(&self, key: &[u8]) -> bool {
+		self.report.borrow_mut().record_read(None, key);
+		self.inner.exists_storage(key)
+	}
+
+	fn exists_child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> bool {
+		self.report.borrow_mut().record_read(Some(child_info), key);
+		self.inner.exists_child_storage(child_info, key)
+	}
+
+	fn next_storage_key(&self, key: &[u8]) -> Option<StorageKey> {
+		let next_key = self.inner.next_storage_key(key);
+		if let Some(next_key) = &next_key {
+			self.report.borrow_mut().record_read(None, next_key);
+		}
+		next_key
+	}
+
+	fn next_child_storage_key(&self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageKey> {
+		let next_key = self.inner.next_child_storage_key(child_info, key);
+		if let Some(next_key) = &next_key {
+			self.report.borrow_mut().record_read(Some(child_info), next_key);
+		}
+		next_key
+	}
+
+	fn kill_child_storage(&mut self, child_info: &ChildInfo, limit: Option<u32>) -> (bool, u32) {
+		self.ensure_writable("kill_child_storage");
+		self.report.get_mut().record_clear_prefix(Some(child_info), &[]);
+		self.inner.kill_child_storage(child_info, limit)
+	}
+
+	fn clear_prefix(&mut self, prefix: &[u8]) {
+		self.ensure_writable("clear_prefix");
+		self.report.get_mut().record_clear_prefix(None, prefix);
+		self.inner.clear_prefix(prefix)
+	}
+
+	fn clear_child_prefix(&mut self, child_info: &ChildInfo, prefix: &[u8]) {
+		self.ensure_writable("clear_child_prefix");
+		self.report.get_mut().record_clear_prefix(Some(child_info), prefix);
+		self.inner.clear_child_prefix(child_info, prefix)
+	}
+
+	fn place_storage(&mut self, key: StorageKey, value: Option<StorageValue>) {
+		self.ensure_writable("place_storage");
+		self.report.get_mut().record_write(None, &key);
+		self.inner.place_storage(key, value)
+	}
+
+	fn place_child_storage(
+		&mut self,
+		child_info: &ChildInfo,
+		key: StorageKey,
+		value: Option<StorageValue>,
+	) {
+		self.ensure_writable("place_child_storage");
+		self.report.get_mut().record_write(Some(child_info), &key);
+		self.inner.place_child_storage(child_info, key, value)
+	}
+
+	fn storage_root(&mut self) -> Vec<u8> {
+		self.inner.storage_root()
+	}
+
+	fn child_storage_root(&mut self, child_info: &ChildInfo) -> Vec<u8> {
+		self.inner.child_storage_root(child_info)
+	}
+
+	fn storage_append(&mut self, key: Vec<u8>, value: Vec<u8>) {
+		self.ensure_writable("storage_append");
+		self.report.get_mut().record_write(None, &key);
+		self.inner.storage_append(key, value)
+	}
+
+	fn storage_changes_root(&mut self, parent: &[u8]) -> Result<Option<Vec<u8>>, ()> {
+		self.inner.storage_changes_root(parent)
+	}
+
+	fn storage_start_transaction(&mut self) {
+		self.inner.storage_start_transaction()
+	}
+
+	fn storage_rollback_transaction(&mut self) -> Result<(), ()> {
+		self.inner.storage_rollback_transaction()
+	}
+
+	fn storage_commit_transaction(&mut self) -> Result<(), ()> {
+		self.inner.storage_commit_transaction()
+	}
+
+	fn storage_index_transaction(&mut self, index: u32, hash: &[u8], size: u32) {
+		self.ensure_writable("storage_index_transaction");
+		self.inner.storage_index_transaction(index, hash, size)
+	}
+
+	fn storage_renew_transaction_index(&mut self, index: u32, hash: &[u8]) {
+		self.ensure_writable("storage_renew_transaction_index");
+		self.inner.storage_renew_transaction_index(index, hash)
+	}
+
+	fn wipe(&mut self) {
+		self.ensure_writable("wipe");
+		self.inner.wipe()
+	}
+
+	fn commit(&mut self) {
+		self.ensure_writable("commit");
+		self.inner.commit()
+	}
+
+	fn read_write_count(&self) -> (u32, u32, u32, u32) {
+		self.inner.read_write_count()
+	}
+
+	fn reset_read_write_count(&mut self) {
+		self.inner.reset_read_write_count()
+	}
+
+	fn get_whitelist(&self) -> Vec<TrackedStorageKey> {
+		self.inner.get_whitelist()
+	}
+
+	fn set_whitelist(&mut self, new: Vec<TrackedStorageKey>) {
+		self.inner.set_whitelist(new)
+	}
+
+	fn proof_size(&self) -> Option<u32> {
+		self.inner.proof_size()
+	}
+}
+
+impl<'a> sp_externalities::ExtensionStore for AccessTrackingExternalities<'a> {
+	fn extension_by_type_id(&mut self, type_id: TypeId) -> Option<&mut dyn Any> {
+		self.inner.extension_by_type_id(type_id)
+	}
+
+	fn register_extension_with_type_id(
+		&mut self,
+		type_id: TypeId,
+		extension: Box<dyn sp_externalities::Extension>,
+	) -> Result<(), sp_externalities::Error> {
+		self.inner.register_extension_with_type_id(type_id, extension)
+	}
+
+	fn deregister_extension_by_type_id(
+		&mut self,
+		type_id: TypeId,
+	) -> Result<(), sp_externalities::Error> {
+		self.inner.deregister_extension_by_type_id(type_id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::BasicExternalities;
+
+	#[test]
+	fn records_reads_and_writes() {
+		let mut basic = BasicExternalities::new_empty();
+		basic.insert(b"a".to_vec(), b"1".to_vec());
+		let child_info = ChildInfo::new_default(b"child");
+
+		let mut ext = AccessTrackingExternalities::new(&mut basic, false);
+		assert_eq!(ext.storage(b"a"), Some(b"1".to_vec()));
+		assert_eq!(ext.storage(b"a"), Some(b"1".to_vec()));
+		ext.set_storage(b"b".to_vec(), b"2".to_vec());
+		assert_eq!(ext.child_storage(&child_info, b"c"), None);
+		ext.clear_prefix(b"z");
+
+		let report = ext.into_report();
+		assert_eq!(report.top.get(&b"a".to_vec()), Some(&KeyAccess { reads: 2, writes: 0 }));
+		assert_eq!(report.top.get(&b"b".to_vec()), Some(&KeyAccess { reads: 0, writes: 1 }));
+		assert_eq!(
+			report.children.get(&b"child".to_vec()).and_then(|keys| keys.get(&b"c".to_vec())),
+			Some(&KeyAccess { reads: 1, writes: 0 }),
+		);
+		assert!(report.cleared_prefixes.contains(&(None, b"z".to_vec())));
+		assert_eq!((report.total_reads(), report.total_writes()), (3, 1));
+		assert_eq!(basic.storage(b"b"), Some(b"2".to_vec()));
+	}
+
+	#[test]
+	fn db_ops_respect_whitelist() {
+		let mut basic = BasicExternalities::new_empty();
+		let mut ext = AccessTrackingExternalities::new(&mut basic, false);
+		ext.storage(b"a");
+		ext.storage(b"a");
+		ext.storage(b"b");
+		ext.set_storage(b"b".to_vec(), vec![]);
+
+		let report = ext.into_report();
+		assert_eq!(report.db_ops(&[]), (2, 1));
+
+		let whitelist = vec![TrackedStorageKey {
+			key: b"b".to_vec(),
+			has_been_read: true,
+			has_been_written: false,
+		}];
+		assert_eq!(report.db_ops(&whitelist), (1, 1));
+		assert_eq!(
+			report.non_whitelisted_reads(&whitelist).collect::<Vec<_>>(),
+			vec![&b"a".to_vec()],
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "`place_storage` is not allowed in read-only")]
+	fn read_only_panics_on_write() {
+		let mut basic = BasicExternalities::new_empty();
+		let mut ext = AccessTrackingExternalities::new(&mut basic, true);
+		ext.storage(b"a");
+		ext.set_storage(b"a".to_vec(), vec![]);
+	}
+}
@@ -0,0 +1,261 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Substrate statement store types and runtime interface.
+//!
+//! A [`Statement`] is a piece of data signed by an account, which is gossiped between the nodes
+//! of the network without being put on-chain. Each node keeps the statements it knows about in
+//! its statement store, and asks the runtime whether a statement is allowed in, and how many
+//! statements its signer may keep in the store, through the [`ValidateStatement`] runtime API.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use sp_core::{ed25519, sr25519};
+use sp_runtime::{traits::Verify, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+mod store_api;
+
+#[cfg(feature = "std")]
+pub use store_api::{Error, Result, StatementStore, SubmitResult};
+
+/// The hash of a statement.
+pub type Hash = [u8; 32];
+
+/// A topic of a statement, which can be used to look up the statements.
+pub type Topic = [u8; 32];
+
+/// The key needed to decrypt the data of a statement.
+pub type DecryptionKey = [u8; 32];
+
+/// The account which signed a statement.
+pub type AccountId = [u8; 32];
+
+/// The maximum number of topics of a statement.
+pub const MAX_TOPICS: usize = 4;
+
+/// The proof of authenticity of a statement.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum Proof {
+	/// An sr25519 signature of the statement.
+	Sr25519 {
+		/// The signature.
+		signature: [u8; 64],
+		/// The public key of the signer.
+		signer: [u8; 32],
+	},
+	/// An ed25519 signature of the statement.
+	Ed25519 {
+		/// The signature.
+		signature: [u8; 64],
+		/// The public key of the signer.
+		signer: [u8; 32],
+	},
+}
+
+impl Proof {
+	/// The account which signed the statement.
+	pub fn account_id(&self) -> AccountId {
+		match self {
+			Proof::Sr25519 { signer, .. } | Proof::Ed25519 { signer, .. } => *signer,
+		}
+	}
+}
+
+/// The result of checking the signature of a statement.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum SignatureVerificationResult {
+	/// The signature is valid, and the statement was signed by the given account.
+	Valid(AccountId),
+	/// The signature is invalid.
+	Invalid,
+	/// The statement is not signed.
+	NoSignature,
+}
+
+/// A statement.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct Statement {
+	/// The proof of authenticity. Not covered by the signature.
+	pub proof: Option<Proof>,
+	/// The key to decrypt `data` with, if the data is encrypted.
+	pub decryption_key: Option<DecryptionKey>,
+	/// The priority of the statement. When the signer runs out of room in the store, statements
+	/// of lower priority are evicted first.
+	pub priority: u32,
+	/// The topics of the statement, at most [`MAX_TOPICS`].
+	pub topics: Vec<Topic>,
+	/// The data of the statement.
+	pub data: Vec<u8>,
+}
+
+impl Statement {
+	/// The hash of the statement, including its proof.
+	pub fn hash(&self) -> Hash {
+		self.using_encoded(sp_core::hashing::blake2_256)
+	}
+
+	/// The signer of the statement, if it has a proof.
+	pub fn account_id(&self) -> Option<AccountId> {
+		self.proof.as_ref().map(Proof::account_id)
+	}
+
+	/// The encoded statement without its proof, which is what gets signed.
+	pub fn signature_material(&self) -> Vec<u8> {
+		(&self.decryption_key, &self.priority, &self.topics, &self.data).encode()
+	}
+
+	/// Sign the statement with an sr25519 key, replacing its proof.
+	#[cfg(feature = "std")]
+	pub fn sign_sr25519_private(&mut self, key: &sr25519::Pair) {
+		use sp_core::Pair;
+		let signature = key.sign(&self.signature_material());
+		self.proof = Some(Proof::Sr25519 { signature: signature.0, signer: key.public().0 });
+	}
+
+	/// Sign the statement with an ed25519 key, replacing its proof.
+	#[cfg(feature = "std")]
+	pub fn sign_ed25519_private(&mut self, key: &ed25519::Pair) {
+		use sp_core::Pair;
+		let signature = key.sign(&self.signature_material());
+		self.proof = Some(Proof::Ed25519 { signature: signature.0, signer: key.public().0 });
+	}
+
+	/// Check the signature of the statement.
+	pub fn verify_signature(&self) -> SignatureVerificationResult {
+		let material = self.signature_material();
+		let valid = match &self.proof {
+			None => return SignatureVerificationResult::NoSignature,
+			Some(Proof::Sr25519 { signature, signer }) => sr25519::Signature::from_raw(*signature)
+				.verify(&material[..], &sr25519::Public::from_raw(*signer)),
+			Some(Proof::Ed25519 { signature, signer }) => ed25519::Signature::from_raw(*signature)
+				.verify(&material[..], &ed25519::Public::from_raw(*signer)),
+		};
+		match (valid, &self.proof) {
+			(true, Some(proof)) => SignatureVerificationResult::Valid(proof.account_id()),
+			_ => SignatureVerificationResult::Invalid,
+		}
+	}
+}
+
+/// Where a statement comes from.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum StatementSource {
+	/// The statement was submitted from the runtime, e.g. by an offchain worker.
+	Chain,
+	/// The statement was received from a peer.
+	Network,
+	/// The statement was submitted locally, e.g. over RPC.
+	Local,
+}
+
+/// The allowance of the signer of a valid statement.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct ValidStatement {
+	/// The maximum number of statements the signer may keep in the store.
+	pub max_count: u32,
+	/// The maximum total encoded size of the statements the signer may keep in the store.
+	pub max_size: u32,
+}
+
+/// The reason why a statement is invalid.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum InvalidStatement {
+	/// The proof of the statement is invalid.
+	BadProof,
+	/// The statement has no proof.
+	NoProof,
+	/// The signer of the statement is not allowed to submit statements.
+	NoAllowance,
+	/// The statement could not be validated because of an internal error.
+	InternalError,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for InvalidStatement {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			InvalidStatement::BadProof => write!(fmt, "Bad statement proof"),
+			InvalidStatement::NoProof => write!(fmt, "Missing statement proof"),
+			InvalidStatement::NoAllowance => write!(fmt, "The signer has no statement allowance"),
+			InvalidStatement::InternalError => write!(fmt, "Internal error"),
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The statement validation api.
+	///
+	/// This api is used by the statement store of the node to decide which statements to keep.
+	pub trait ValidateStatement {
+		/// Validate `statement`, returning the allowance of its signer.
+		fn validate_statement(
+			source: StatementSource,
+			statement: Statement,
+		) -> Result<ValidStatement, InvalidStatement>;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::Pair;
+
+	fn statement() -> Statement {
+		Statement {
+			proof: None,
+			decryption_key: Some([1; 32]),
+			priority: 7,
+			topics: vec![[2; 32], [3; 32]],
+			data: vec![4, 5, 6],
+		}
+	}
+
+	#[test]
+	fn sr25519_signatures_are_verified() {
+		let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let mut statement = statement();
+		assert_eq!(statement.verify_signature(), SignatureVerificationResult::NoSignature);
+
+		statement.sign_sr25519_private(&pair);
+		assert_eq!(
+			statement.verify_signature(),
+			SignatureVerificationResult::Valid(pair.public().0),
+		);
+		assert_eq!(Statement::decode(&mut &statement.encode()[..]).unwrap(), statement);
+
+		statement.priority = 8;
+		assert_eq!(statement.verify_signature(), SignatureVerificationResult::Invalid);
+	}
+
+	#[test]
+	fn ed25519_signatures_are_verified() {
+		let pair = ed25519::Pair::from_string("//Bob", None).unwrap();
+		let mut statement = statement();
+		statement.sign_ed25519_private(&pair);
+		assert_eq!(
+			statement.verify_signature(),
+			SignatureVerificationResult::Valid(pair.public().0),
+		);
+		assert_eq!(statement.account_id(), Some(pair.public().0));
+
+		statement.data.push(7);
+		assert_eq!(statement.verify_signature(), SignatureVerificationResult::Invalid);
+	}
+}
@@ -0,0 +1,85 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The interface of the statement store of the node.
+
+use crate::{Hash, InvalidStatement, Statement, StatementSource, Topic};
+
+/// A statement store error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+	/// The database could not be read or written.
+	Db(String),
+	/// A statement could not be decoded.
+	Decode(String),
+	/// The runtime could not be called.
+	Runtime(String),
+}
+
+impl std::fmt::Display for Error {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Error::Db(e) => write!(fmt, "Database error: {}", e),
+			Error::Decode(e) => write!(fmt, "Decoding error: {}", e),
+			Error::Runtime(e) => write!(fmt, "Runtime error: {}", e),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+/// Statement store result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The outcome of submitting a statement to the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitResult {
+	/// The statement was added to the store.
+	New,
+	/// The statement was already in the store.
+	Known,
+	/// The statement was in the store and left it, i.e. it was purged, evicted or removed. It
+	/// is not accepted again until its tombstone is purged.
+	KnownExpired,
+	/// The statement is valid, but its signer has no room left for it in the store, and none
+	/// of the statements of the signer has a lower priority.
+	Ignored,
+	/// The statement is malformed, e.g. it is not signed or has too many topics.
+	Bad(&'static str),
+	/// The statement was rejected by the runtime.
+	Invalid(InvalidStatement),
+	/// The statement could not be processed.
+	InternalError(Error),
+}
+
+/// The statement store of the node.
+pub trait StatementStore: Send + Sync {
+	/// All the statements in the store, with their hashes.
+	fn statements(&self) -> Result<Vec<(Hash, Statement)>>;
+
+	/// The statement with the given hash, if it is in the store.
+	fn statement(&self, hash: &Hash) -> Result<Option<Statement>>;
+
+	/// The data of all the statements which have all of the given topics.
+	fn broadcasts(&self, match_all_topics: &[Topic]) -> Result<Vec<Vec<u8>>>;
+
+	/// Submit a statement to the store.
+	fn submit(&self, statement: Statement, source: StatementSource) -> SubmitResult;
+
+	/// Remove the statement with the given hash from the store.
+	fn remove(&self, hash: &Hash) -> Result<()>;
+}
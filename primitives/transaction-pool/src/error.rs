@@ -69,6 +69,11 @@ pub enum Error {
 
 	#[error("The pool is not accepting future transactions")]
 	RejectedFutureTransaction,
+
+	/// The node is syncing and doesn't have the state of the recent blocks, so the
+	/// transaction can't be validated yet.
+	#[error("The node is not synced, transactions can't be validated yet")]
+	NotSynced,
 }
 
 /// Transaction pool error conversion.
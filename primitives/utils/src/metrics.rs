@@ -35,6 +35,14 @@ lazy_static! {
 	pub static ref TOKIO_THREADS_ALIVE: GenericGauge<AtomicU64> = GenericGauge::new(
 		"tokio_threads_alive", "Number of threads alive right now"
 	).expect("Creating of statics doesn't fail. qed");
+
+	pub static ref TOKIO_WORKER_THREADS: GenericGauge<AtomicU64> = GenericGauge::new(
+		"tokio_worker_threads", "Number of threads executing the non-blocking tasks"
+	).expect("Creating of statics doesn't fail. qed");
+
+	pub static ref TOKIO_MAX_BLOCKING_THREADS: GenericGauge<AtomicU64> = GenericGauge::new(
+		"tokio_max_blocking_threads", "Maximum number of threads executing the blocking tasks"
+	).expect("Creating of statics doesn't fail. qed");
 }
 
 #[cfg(feature = "metered")]
@@ -51,6 +59,8 @@ lazy_static! {
 pub fn register_globals(registry: &Registry) -> Result<(), PrometheusError> {
 	registry.register(Box::new(TOKIO_THREADS_ALIVE.clone()))?;
 	registry.register(Box::new(TOKIO_THREADS_TOTAL.clone()))?;
+	registry.register(Box::new(TOKIO_WORKER_THREADS.clone()))?;
+	registry.register(Box::new(TOKIO_MAX_BLOCKING_THREADS.clone()))?;
 
 	#[cfg(feature = "metered")]
 	registry.register(Box::new(UNBOUNDED_CHANNELS_COUNTER.clone()))?;
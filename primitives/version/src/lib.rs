@@ -189,6 +189,14 @@ impl RuntimeVersion {
 		self.authoring_version == other.authoring_version
 	}
 
+	/// Check if the native runtime with version `native` can be executed instead of the runtime
+	/// with this version: it can call into it and has the same implementation.
+	pub fn can_execute_natively_with(&self, native: &RuntimeVersion) -> bool {
+		self.can_call_with(native) &&
+			self.impl_version == native.impl_version &&
+			self.apis == native.apis
+	}
+
 	/// Check if the given api with `api_id` is implemented and the version passes the given
 	/// `predicate`.
 	pub fn has_api_with<P: Fn(u32) -> bool>(
@@ -87,6 +87,7 @@ pub struct TestApi {
 	valid_modifier: RwLock<Box<dyn Fn(&mut ValidTransaction) + Send + Sync>>,
 	chain: RwLock<ChainState>,
 	validation_requests: RwLock<Vec<Extrinsic>>,
+	not_synced: RwLock<bool>,
 }
 
 impl TestApi {
@@ -105,6 +106,7 @@ impl TestApi {
 			valid_modifier: RwLock::new(Box::new(|_| {})),
 			chain: Default::default(),
 			validation_requests: RwLock::new(Default::default()),
+			not_synced: RwLock::new(false),
 		};
 
 		// Push genesis block
@@ -200,6 +202,11 @@ impl TestApi {
 		);
 	}
 
+	/// Reject all the transactions with `NotSynced`, as if the node was syncing.
+	pub fn set_not_synced(&self, not_synced: bool) {
+		*self.not_synced.write() = not_synced;
+	}
+
 	/// Query validation requests received.
 	pub fn validation_requests(&self) -> Vec<Extrinsic> {
 		self.validation_requests.read().clone()
@@ -240,6 +247,10 @@ impl sc_transaction_graph::ChainApi for TestApi {
 	) -> Self::ValidationFuture {
 		self.validation_requests.write().push(uxt.clone());
 
+		if *self.not_synced.read() {
+			return ready(Err(sp_transaction_pool::error::Error::NotSynced.into()))
+		}
+
 		match self.block_id_to_number(at) {
 			Ok(Some(number)) => {
 				let found_best = self.chain
@@ -97,7 +97,7 @@ pub fn default_config(task_executor: TaskExecutor, mut chain_spec: Box<dyn Chain
 	Configuration {
 		impl_name: "test-node".to_string(),
 		impl_version: "0.1".to_string(),
-		role: Role::Authority,
+		role: Role::Authority { sentry_nodes: Vec::new() },
 		task_executor: task_executor.into(),
 		transaction_pool: Default::default(),
 		network: network_config,
@@ -111,6 +111,7 @@ pub fn default_config(task_executor: TaskExecutor, mut chain_spec: Box<dyn Chain
 		},
 		state_cache_size: 16777216,
 		state_cache_child_ratio: None,
+		trie_cache_maximum_size: None,
 		chain_spec,
 		wasm_method: WasmExecutionMethod::Interpreted,
 		execution_strategies: ExecutionStrategies {
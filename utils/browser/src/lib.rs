@@ -104,6 +104,7 @@ where
 		rpc_http_threads: Default::default(),
 		rpc_methods: Default::default(),
 		state_cache_child_ratio: Default::default(),
+		trie_cache_maximum_size: None,
 		state_cache_size: Default::default(),
 		tracing_receiver: Default::default(),
 		tracing_targets: Default::default(),
@@ -22,7 +22,7 @@ use frame_benchmarking::{Analysis, BenchmarkBatch, BenchmarkSelector};
 use sc_cli::{SharedParams, CliConfiguration, ExecutionStrategy, Result};
 use sc_client_db::BenchmarkingState;
 use sc_executor::NativeExecutor;
-use sp_state_machine::StateMachine;
+use sp_state_machine::{Backend as _, StateMachine};
 use sp_externalities::Extensions;
 use sc_service::{Configuration, NativeExecutionDispatch};
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
@@ -75,7 +75,7 @@ impl BenchmarkCmd {
 		let (offchain, _) = TestOffchainExt::new();
 		extensions.register(OffchainWorkerExt::new(offchain));
 
-		let result = StateMachine::<_, _, NumberFor<BB>, _>::new(
+		let mut state_machine = StateMachine::<_, _, NumberFor<BB>, _>::new(
 			&state,
 			None,
 			&mut changes,
@@ -94,9 +94,15 @@ impl BenchmarkCmd {
 			extensions,
 			&sp_state_machine::backend::BackendRuntimeCode::new(&state).runtime_code()?,
 			sp_core::testing::TaskExecutor::new(),
-		)
-		.execute(strategy.into())
-		.map_err(|e| format!("Error executing runtime benchmark: {:?}", e))?;
+		);
+		if self.storage_access_report {
+			state_machine = state_machine.with_access_tracking(false);
+		}
+
+		let result = state_machine
+			.execute(strategy.into())
+			.map_err(|e| format!("Error executing runtime benchmark: {:?}", e))?;
+		let storage_access_report = state_machine.storage_access_report().cloned();
 
 		let results = <std::result::Result<Vec<BenchmarkBatch>, String> as Decode>::decode(&mut &result[..])
 			.map_err(|e| format!("Failed to decode benchmark results: {:?}", e))?;
@@ -176,6 +182,14 @@ impl BenchmarkCmd {
 			Err(error) => eprintln!("Error: {}", error),
 		}
 
+		if let Some(report) = storage_access_report {
+			// Like the benchmarks, the whitelisted keys are not counted as database operations.
+			let (reads, writes) = report.db_ops(&state.get_whitelist());
+			println!("Storage Access Report\n========");
+			println!("Database operations: {} reads, {} writes", reads, writes);
+			print!("{}", report);
+		}
+
 		Ok(())
 	}
 }
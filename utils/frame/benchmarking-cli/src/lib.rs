@@ -101,6 +101,13 @@ pub struct BenchmarkCmd {
 	#[structopt(long)]
 	pub record_proof: bool,
 
+	/// Report the storage keys read and written by the benchmarks, and the database reads and
+	/// writes they amount to.
+	///
+	/// The tracking slows the storage accesses down, the measured times are not accurate with it.
+	#[structopt(long)]
+	pub storage_access_report: bool,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub shared_params: sc_cli::SharedParams,
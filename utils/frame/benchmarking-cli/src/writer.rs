@@ -56,9 +56,12 @@ struct BenchmarkData {
 	base_reads: u128,
 	#[serde(serialize_with = "string_serialize")]
 	base_writes: u128,
+	#[serde(serialize_with = "string_serialize")]
+	base_proof_size: u128,
 	component_weight: Vec<ComponentSlope>,
 	component_reads: Vec<ComponentSlope>,
 	component_writes: Vec<ComponentSlope>,
+	component_proof_size: Vec<ComponentSlope>,
 }
 
 // This forwards some specific metadata from the `BenchmarkCmd`
@@ -172,12 +175,15 @@ fn get_benchmark_data(
 		.expect("analysis function should return the number of reads for valid inputs");
 	let writes = analysis_function(&batch.results, BenchmarkSelector::Writes)
 		.expect("analysis function should return the number of writes for valid inputs");
+	let proof_size = analysis_function(&batch.results, BenchmarkSelector::ProofSize)
+		.expect("analysis function should return the proof size for valid inputs");
 
 	// Analysis data may include components that are not used, this filters out anything whose value is zero.
 	let mut used_components = Vec::new();
 	let mut used_extrinsic_time = Vec::new();
 	let mut used_reads = Vec::new();
 	let mut used_writes = Vec::new();
+	let mut used_proof_size = Vec::new();
 
 	extrinsic_time.slopes.into_iter()
 		.zip(extrinsic_time.names.iter())
@@ -218,6 +224,19 @@ fn get_benchmark_data(
 				});
 			}
 		});
+	proof_size.slopes.into_iter()
+		.zip(proof_size.names.iter())
+		.zip(extract_errors(&proof_size.model))
+		.for_each(|((slope, name), error)| {
+			if !slope.is_zero() {
+				if !used_components.contains(&name) { used_components.push(name); }
+				used_proof_size.push(ComponentSlope {
+					name: name.clone(),
+					slope,
+					error,
+				});
+			}
+		});
 
 	// This puts a marker on any component which is entirely unused in the weight formula.
 	let components = batch.results[0].components
@@ -235,9 +254,11 @@ fn get_benchmark_data(
 		base_weight: extrinsic_time.base.saturating_mul(1000),
 		base_reads: reads.base,
 		base_writes: writes.base,
+		base_proof_size: proof_size.base,
 		component_weight: used_extrinsic_time,
 		component_reads: used_reads,
 		component_writes: used_writes,
+		component_proof_size: used_proof_size,
 	}
 }
 
@@ -421,7 +442,7 @@ mod test {
 					repeat_reads: 0,
 					writes: (base + slope * i).into(),
 					repeat_writes: 0,
-					proof_size: 0,
+					proof_size: base + slope * i,
 				}
 			)
 		}
@@ -471,6 +492,16 @@ mod test {
 				error: 0,
 			}]
 		);
+		// Proof sizes are untouched
+		assert_eq!(benchmark.base_proof_size, base);
+		assert_eq!(
+			benchmark.component_proof_size,
+			vec![ComponentSlope {
+				name: component.to_string(),
+				slope,
+				error: 0,
+			}]
+		);
 	}
 
 	#[test]
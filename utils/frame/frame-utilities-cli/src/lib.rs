@@ -18,6 +18,11 @@
 //! frame-system CLI utilities
 
 mod pallet_id;
+mod storage_info;
 
 pub use pallet_id::PalletIdCmd;
+pub use storage_info::{
+	storage_usage, PalletStorageUsage, StorageInfoCmd, StorageLabels, StorageUsage,
+	StorageUsageReport,
+};
 
@@ -0,0 +1,412 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `storage-info` subcommand
+
+use codec::Decode;
+use frame_support::metadata::{
+	DecodeDifferent, RuntimeMetadata, RuntimeMetadataPrefixed, RuntimeMetadataV13,
+};
+use sc_cli::{
+	BlockNumberOrHash, CliConfiguration, DatabaseParams, Error, PruningParams, SharedParams,
+};
+use sc_client_api::{StorageProvider, UsageProvider};
+use sp_api::{Metadata, ProvideRuntimeApi};
+use sp_core::{hashing::twox_128, storage::{ChildInfo, StorageKey, well_known_keys}};
+use sp_runtime::{generic::BlockId, traits::{Block as BlockT, Header as HeaderT}};
+use std::{collections::{BTreeMap, HashMap}, fmt::Debug, str::FromStr, sync::Arc};
+use structopt::StructOpt;
+
+/// The group of the keys which start with `:`, e.g. `:code`.
+const WELL_KNOWN: &str = "<well known keys>";
+/// The group of the keys which are not under the prefix of any pallet.
+const UNKNOWN: &str = "<unknown>";
+/// The item of the keys which are under the prefix of a pallet but not of its storage items.
+const UNKNOWN_ITEM: &str = "<unknown item>";
+/// The item of the keys of all default child tries.
+const CHILD_TRIES: &str = "<child tries>";
+
+/// The `storage-info` command used to report the storage usage of each pallet at a given block.
+#[derive(Debug, StructOpt)]
+#[structopt(
+	name = "storage-info",
+	about = "Report the number of keys and bytes stored by each pallet at a given block."
+)]
+pub struct StorageInfoCmd {
+	/// Block hash or number, the best block is used if not given.
+	#[structopt(value_name = "HASH or NUMBER")]
+	pub input: Option<BlockNumberOrHash>,
+
+	/// Also report the usage of each storage item of the pallets.
+	#[structopt(long)]
+	pub detailed: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+/// The number of keys and bytes stored under some prefix.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StorageUsage {
+	/// The number of keys.
+	pub keys: u64,
+	/// The accumulated size of the keys, in bytes.
+	pub key_bytes: u64,
+	/// The accumulated size of the values, in bytes.
+	pub value_bytes: u64,
+}
+
+impl StorageUsage {
+	/// The accumulated size of the keys and the values, in bytes.
+	pub fn total_bytes(&self) -> u64 {
+		self.key_bytes.saturating_add(self.value_bytes)
+	}
+
+	fn note(&mut self, key: &[u8], value_len: usize) {
+		self.keys = self.keys.saturating_add(1);
+		self.key_bytes = self.key_bytes.saturating_add(key.len() as u64);
+		self.value_bytes = self.value_bytes.saturating_add(value_len as u64);
+	}
+}
+
+/// The storage usage of a pallet, in total and by storage item.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PalletStorageUsage {
+	/// The usage of all the keys under the prefix of the pallet.
+	pub total: StorageUsage,
+	/// The usage of each storage item of the pallet.
+	pub items: BTreeMap<String, StorageUsage>,
+}
+
+/// The storage usage of the whole state, by pallet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StorageUsageReport {
+	/// The usage of the whole state.
+	pub total: StorageUsage,
+	/// The usage of each pallet.
+	pub pallets: BTreeMap<String, PalletStorageUsage>,
+}
+
+impl StorageUsageReport {
+	fn note(&mut self, pallet: &str, item: &str, key: &[u8], value_len: usize) {
+		self.total.note(key, value_len);
+		let pallet = self.pallets.entry(pallet.into()).or_default();
+		pallet.total.note(key, value_len);
+		pallet.items.entry(item.into()).or_default().note(key, value_len);
+	}
+
+	/// Render the report as a table, pallets are ordered by decreasing total size.
+	pub fn render(&self, detailed: bool) -> String {
+		let by_size = |usages: Vec<(&String, &StorageUsage)>| {
+			let mut usages = usages;
+			usages.sort_by(|a, b| b.1.total_bytes().cmp(&a.1.total_bytes()).then(a.0.cmp(b.0)));
+			usages
+		};
+		let row = |name: &str, usage: &StorageUsage| format!(
+			"{:<48} {:>12} {:>14} {:>14} {:>14} {:>7.2}%\n",
+			name,
+			usage.keys,
+			usage.key_bytes,
+			usage.value_bytes,
+			usage.total_bytes(),
+			usage.total_bytes() as f64 * 100.0 / self.total.total_bytes().max(1) as f64,
+		);
+
+		let mut out = format!(
+			"{:<48} {:>12} {:>14} {:>14} {:>14} {:>8}\n",
+			"Pallet", "Keys", "Key bytes", "Value bytes", "Total bytes", "Share",
+		);
+		let pallets = self.pallets.iter().map(|(name, pallet)| (name, &pallet.total)).collect();
+		for (name, usage) in by_size(pallets) {
+			out.push_str(&row(name, usage));
+			if detailed {
+				for (item, usage) in by_size(self.pallets[name].items.iter().collect()) {
+					out.push_str(&row(&format!("  {}", item), usage));
+				}
+			}
+		}
+		out.push_str(&row("Total", &self.total));
+		out
+	}
+}
+
+/// The names of the pallets and of their storage items, by the hashed prefix of their keys.
+#[derive(Debug, Default, Clone)]
+pub struct StorageLabels {
+	pallets: HashMap<[u8; 16], String>,
+	items: HashMap<[u8; 32], (String, String)>,
+}
+
+impl StorageLabels {
+	/// Build the labels from the SCALE encoded `RuntimeMetadataPrefixed` of a runtime.
+	pub fn from_metadata(mut metadata: &[u8]) -> Result<Self, Error> {
+		let modules = match RuntimeMetadataPrefixed::decode(&mut metadata)?.1 {
+			RuntimeMetadata::V13(RuntimeMetadataV13 {
+				modules: DecodeDifferent::Decoded(modules),
+				..
+			}) => modules,
+			_ => return Err("Unsupported runtime metadata version".into()),
+		};
+
+		let mut labels = Self::default();
+		for module in modules {
+			let (name, storage) = match (module.name, module.storage) {
+				(DecodeDifferent::Decoded(name), Some(DecodeDifferent::Decoded(storage))) =>
+					(name, storage),
+				_ => continue,
+			};
+			let (prefix, entries) = match (storage.prefix, storage.entries) {
+				(DecodeDifferent::Decoded(prefix), DecodeDifferent::Decoded(entries)) =>
+					(prefix, entries),
+				_ => continue,
+			};
+
+			let pallet_prefix = twox_128(prefix.as_bytes());
+			for entry in entries {
+				if let DecodeDifferent::Decoded(entry_name) = entry.name {
+					let mut item_prefix = [0u8; 32];
+					item_prefix[..16].copy_from_slice(&pallet_prefix);
+					item_prefix[16..].copy_from_slice(&twox_128(entry_name.as_bytes()));
+					labels.items.insert(item_prefix, (name.clone(), entry_name));
+				}
+			}
+			labels.pallets.insert(pallet_prefix, name);
+		}
+
+		Ok(labels)
+	}
+
+	/// Returns the pallet and the storage item the given top trie `key` belongs to.
+	fn label<'a>(&'a self, key: &'a [u8]) -> (&'a str, &'a str) {
+		if key.starts_with(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			return (WELL_KNOWN, CHILD_TRIES)
+		}
+		if key.starts_with(b":") {
+			return (WELL_KNOWN, std::str::from_utf8(key).unwrap_or(UNKNOWN_ITEM))
+		}
+
+		if let Some((pallet, item)) = key.get(..32).and_then(|prefix| self.items.get(prefix)) {
+			return (pallet.as_str(), item.as_str())
+		}
+		match key.get(..16).and_then(|prefix| self.pallets.get(prefix)) {
+			Some(pallet) => (pallet.as_str(), UNKNOWN_ITEM),
+			None => (UNKNOWN, UNKNOWN_ITEM),
+		}
+	}
+}
+
+impl StorageInfoCmd {
+	/// Run the `storage-info` command
+	pub async fn run<B, BA, C>(&self, client: Arc<C>) -> Result<(), Error>
+	where
+		B: BlockT,
+		BA: sc_client_api::backend::Backend<B>,
+		C: UsageProvider<B> + StorageProvider<B, BA> + ProvideRuntimeApi<B>,
+		C::Api: Metadata<B>,
+		B::Hash: FromStr,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let block = match &self.input {
+			Some(input) => input.parse()?,
+			None => BlockId::Hash(client.usage_info().chain.best_hash),
+		};
+
+		let metadata = client.runtime_api()
+			.metadata(&block)
+			.map_err(|e| Error::Application(Box::new(e)))?;
+		let labels = StorageLabels::from_metadata(&metadata)?;
+
+		let report = storage_usage(&*client, &block, &labels)?;
+		print!("{}", report.render(self.detailed));
+
+		Ok(())
+	}
+}
+
+/// Walk the whole state at `block` and report its usage, by pallet and storage item.
+///
+/// The content of the default child tries is accounted to the well known keys.
+pub fn storage_usage<B, BA, C>(
+	client: &C,
+	block: &BlockId<B>,
+	labels: &StorageLabels,
+) -> Result<StorageUsageReport, Error>
+where
+	B: BlockT,
+	BA: sc_client_api::backend::Backend<B>,
+	C: StorageProvider<B, BA>,
+{
+	let mut report = StorageUsageReport::default();
+
+	for key in client.storage_keys_iter(block, None, None)? {
+		let value_len = client.storage(block, &key)?.map_or(0, |value| value.0.len());
+		let (pallet, item) = labels.label(&key.0);
+		report.note(pallet, item, &key.0, value_len);
+
+		let child_prefix = well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX;
+		if key.0.starts_with(child_prefix) {
+			let child_info = ChildInfo::new_default(&key.0[child_prefix.len()..]);
+			let empty_key = StorageKey(Vec::new());
+			for child_key in client.child_storage_keys(block, &child_info, &empty_key)? {
+				let value_len = client.child_storage(block, &child_info, &child_key)?
+					.map_or(0, |value| value.0.len());
+				report.note(WELL_KNOWN, CHILD_TRIES, &child_key.0, value_len);
+			}
+		}
+	}
+
+	Ok(report)
+}
+
+impl CliConfiguration for StorageInfoCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use frame_support::metadata::{
+		ExtrinsicMetadata, ModuleMetadata, StorageEntryMetadata, StorageEntryModifier,
+		StorageEntryType, StorageMetadata,
+	};
+
+	fn module(name: &str, prefix: &str, entries: &[&str]) -> ModuleMetadata {
+		let entries = entries.iter()
+			.map(|entry| StorageEntryMetadata {
+				name: DecodeDifferent::Decoded(entry.to_string()),
+				modifier: StorageEntryModifier::Optional,
+				ty: StorageEntryType::Plain(DecodeDifferent::Decoded("u32".into())),
+				default: DecodeDifferent::Decoded(Vec::new()),
+				documentation: DecodeDifferent::Decoded(Vec::new()),
+			})
+			.collect();
+
+		ModuleMetadata {
+			name: DecodeDifferent::Decoded(name.into()),
+			storage: Some(DecodeDifferent::Decoded(StorageMetadata {
+				prefix: DecodeDifferent::Decoded(prefix.into()),
+				entries: DecodeDifferent::Decoded(entries),
+			})),
+			calls: None,
+			event: None,
+			constants: DecodeDifferent::Decoded(Vec::new()),
+			errors: DecodeDifferent::Decoded(Vec::new()),
+			index: 0,
+		}
+	}
+
+	fn labels() -> StorageLabels {
+		let metadata: RuntimeMetadataPrefixed = RuntimeMetadataV13 {
+			modules: DecodeDifferent::Decoded(vec![
+				module("System", "System", &["Account", "Number"]),
+				module("Balances", "Balances", &["TotalIssuance"]),
+			]),
+			extrinsic: ExtrinsicMetadata { version: 4, signed_extensions: Vec::new() },
+		}.into();
+
+		StorageLabels::from_metadata(&metadata.encode()).unwrap()
+	}
+
+	fn key(pallet: &str, item: &str, suffix: &[u8]) -> Vec<u8> {
+		[&twox_128(pallet.as_bytes())[..], &twox_128(item.as_bytes())[..], suffix].concat()
+	}
+
+	#[test]
+	fn keys_are_labelled_with_their_pallet_and_item() {
+		let labels = labels();
+
+		assert_eq!(labels.label(&key("System", "Account", &[1, 2, 3])), ("System", "Account"));
+		assert_eq!(labels.label(&key("System", "Number", &[])), ("System", "Number"));
+		assert_eq!(
+			labels.label(&key("Balances", "TotalIssuance", &[])),
+			("Balances", "TotalIssuance"),
+		);
+		assert_eq!(labels.label(&key("Balances", "Locks", &[1])), ("Balances", UNKNOWN_ITEM));
+		assert_eq!(labels.label(&key("Staking", "Ledger", &[1])), (UNKNOWN, UNKNOWN_ITEM));
+		assert_eq!(labels.label(&[1, 2]), (UNKNOWN, UNKNOWN_ITEM));
+
+		assert_eq!(labels.label(well_known_keys::CODE), (WELL_KNOWN, ":code"));
+		let child_key = [well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, b"child"].concat();
+		assert_eq!(labels.label(&child_key), (WELL_KNOWN, CHILD_TRIES));
+	}
+
+	#[test]
+	fn unsupported_metadata_is_rejected() {
+		assert!(StorageLabels::from_metadata(&[]).is_err());
+		// An unknown version of the metadata.
+		assert!(StorageLabels::from_metadata(&(0u32, 99u8).encode()).is_err());
+	}
+
+	#[test]
+	fn usage_is_accumulated_by_pallet_and_item() {
+		let mut report = StorageUsageReport::default();
+		report.note("System", "Account", &[0; 40], 80);
+		report.note("System", "Account", &[0; 40], 80);
+		report.note("System", "Number", &[0; 32], 4);
+		report.note("Balances", "TotalIssuance", &[0; 32], 16);
+
+		assert_eq!(report.total, StorageUsage { keys: 4, key_bytes: 144, value_bytes: 180 });
+		let system = &report.pallets["System"];
+		assert_eq!(system.total, StorageUsage { keys: 3, key_bytes: 112, value_bytes: 164 });
+		assert_eq!(
+			system.items["Account"],
+			StorageUsage { keys: 2, key_bytes: 80, value_bytes: 160 },
+		);
+		assert_eq!(system.items["Number"].total_bytes(), 36);
+		assert_eq!(report.pallets["Balances"].total.total_bytes(), 48);
+
+		// The pallets are ordered by decreasing size, and their items only in the detailed table.
+		let rendered = report.render(false);
+		let lines = rendered.lines().map(|line| line.split_whitespace().next()).collect::<Vec<_>>();
+		assert_eq!(lines, vec![Some("Pallet"), Some("System"), Some("Balances"), Some("Total")]);
+		let total = rendered.lines().last().unwrap().split_whitespace().collect::<Vec<_>>();
+		assert_eq!(total, vec!["Total", "4", "144", "180", "324", "100.00%"]);
+
+		let detailed = report.render(true);
+		let lines = detailed.lines().map(|line| line.split_whitespace().next()).collect::<Vec<_>>();
+		assert_eq!(lines, vec![
+			Some("Pallet"),
+			Some("System"),
+			Some("Account"),
+			Some("Number"),
+			Some("Balances"),
+			Some("TotalIssuance"),
+			Some("Total"),
+		]);
+	}
+}
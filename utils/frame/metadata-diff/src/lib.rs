@@ -0,0 +1,433 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Metadata Diff
+//!
+//! Compare the metadata of two runtimes, e.g. before a runtime upgrade.
+//!
+//! The metadata is extracted from the wasm blob of a runtime with [`metadata_from_wasm`], then
+//! [`diff`] reports the pallets, calls, events, errors, storage items and constants which were
+//! added, removed or changed. The documentation is ignored.
+//!
+//! The index of pallets, calls, events and errors is part of their encoding, a change of index is
+//! reported with [`Change::IndexChanged`]. The changes which make the transactions built for the
+//! old runtime invalid for the new runtime are flagged by [`Change::breaks_transactions`].
+
+use std::fmt;
+use codec::{Decode, Encode};
+use frame_metadata::{
+	DecodeDifferent, ModuleMetadata, RuntimeMetadata, RuntimeMetadataPrefixed, META_RESERVED,
+};
+use sc_executor::{sp_wasm_interface::HostFunctions, WasmExecutionMethod, WasmExecutor};
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use sp_state_machine::BasicExternalities;
+
+/// The heap pages used to execute the runtimes.
+const HEAP_PAGES: u64 = 64;
+
+/// Errors of the metadata diff.
+#[derive(Debug)]
+pub enum Error {
+	/// The runtime failed to return its metadata.
+	Execution(String),
+	/// The metadata failed to decode.
+	Decode(codec::Error),
+	/// The version of the metadata is not supported.
+	UnsupportedVersion,
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::Execution(e) => write!(f, "Failed to get the metadata of the runtime: {}", e),
+			Error::Decode(e) => write!(f, "Failed to decode the metadata: {}", e),
+			Error::UnsupportedVersion => write!(f, "Unsupported metadata version"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<codec::Error> for Error {
+	fn from(e: codec::Error) -> Self {
+		Error::Decode(e)
+	}
+}
+
+/// The kind of a metadata item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+	/// A pallet.
+	Pallet,
+	/// A call of a pallet.
+	Call,
+	/// An event of a pallet.
+	Event,
+	/// An error of a pallet.
+	Error,
+	/// A storage item of a pallet.
+	Storage,
+	/// A constant of a pallet.
+	Constant,
+}
+
+impl fmt::Display for ItemKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ItemKind::Pallet => write!(f, "pallet"),
+			ItemKind::Call => write!(f, "call"),
+			ItemKind::Event => write!(f, "event"),
+			ItemKind::Error => write!(f, "error"),
+			ItemKind::Storage => write!(f, "storage"),
+			ItemKind::Constant => write!(f, "constant"),
+		}
+	}
+}
+
+/// A change of a metadata item between the old and the new metadata.
+///
+/// The items of a pallet are identified by the name of the pallet and their name, the name of a
+/// pallet is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+	/// The item is only in the new metadata.
+	Added { kind: ItemKind, pallet: String, name: String },
+	/// The item is only in the old metadata.
+	Removed { kind: ItemKind, pallet: String, name: String },
+	/// The signature of the item changed, i.e. the arguments of a call or an event, the type, the
+	/// modifier or the default value of a storage item, or the type or the value of a constant.
+	Changed { kind: ItemKind, pallet: String, name: String },
+	/// The index of the item changed.
+	IndexChanged { kind: ItemKind, pallet: String, name: String, old: u8, new: u8 },
+}
+
+impl Change {
+	/// The kind of the changed item.
+	pub fn kind(&self) -> ItemKind {
+		match self {
+			Change::Added { kind, .. } | Change::Removed { kind, .. } |
+			Change::Changed { kind, .. } | Change::IndexChanged { kind, .. } => *kind,
+		}
+	}
+
+	/// Whether the transactions built for the old runtime may be invalid for the new runtime,
+	/// i.e. a call or a pallet is removed or has a new index, or the arguments of a call changed.
+	pub fn breaks_transactions(&self) -> bool {
+		match self {
+			Change::Added { .. } => false,
+			Change::Removed { kind, .. } | Change::IndexChanged { kind, .. } =>
+				matches!(kind, ItemKind::Pallet | ItemKind::Call),
+			Change::Changed { kind, .. } => *kind == ItemKind::Call,
+		}
+	}
+}
+
+impl fmt::Display for Change {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let item = |f: &mut fmt::Formatter, kind: &ItemKind, pallet: &str, name: &str| {
+			if *kind == ItemKind::Pallet {
+				write!(f, "{} {}", kind, pallet)
+			} else {
+				write!(f, "{} {}::{}", kind, pallet, name)
+			}
+		};
+
+		match self {
+			Change::Added { kind, pallet, name } => {
+				item(f, kind, pallet, name)?;
+				write!(f, " added")
+			},
+			Change::Removed { kind, pallet, name } => {
+				item(f, kind, pallet, name)?;
+				write!(f, " removed")
+			},
+			Change::Changed { kind, pallet, name } => {
+				item(f, kind, pallet, name)?;
+				write!(f, " changed")
+			},
+			Change::IndexChanged { kind, pallet, name, old, new } => {
+				item(f, kind, pallet, name)?;
+				write!(f, " index changed from {} to {}", old, new)
+			},
+		}
+	}
+}
+
+/// Get the metadata of the runtime `code` by executing `Metadata_metadata`.
+pub fn metadata_from_wasm(code: &[u8]) -> Result<RuntimeMetadataPrefixed, Error> {
+	let executor = WasmExecutor::new(
+		WasmExecutionMethod::Interpreted,
+		Some(HEAP_PAGES),
+		sp_io::SubstrateHostFunctions::host_functions(),
+		1,
+		None,
+	);
+	let blob = RuntimeBlob::uncompress_if_needed(code)
+		.map_err(|e| Error::Execution(e.to_string()))?;
+
+	let mut ext = BasicExternalities::default();
+	let encoded = executor
+		.uncached_call(blob, &mut ext, true, "Metadata_metadata", &[])
+		.map_err(Error::Execution)?;
+
+	// The runtime returns an encoded `OpaqueMetadata`, i.e. the encoded metadata as bytes.
+	let metadata = Vec::<u8>::decode(&mut &encoded[..])?;
+	Ok(RuntimeMetadataPrefixed::decode(&mut &metadata[..])?)
+}
+
+/// Compare the `old` and the `new` metadata.
+///
+/// The pallets are identified by their name and the other items by their name within their pallet.
+pub fn diff(
+	old: &RuntimeMetadataPrefixed,
+	new: &RuntimeMetadataPrefixed,
+) -> Result<Vec<Change>, Error> {
+	let old = pallets(old)?;
+	let new = pallets(new)?;
+	let mut changes = Vec::new();
+
+	for old_pallet in &old {
+		let pallet = decoded(&old_pallet.name).clone();
+		let new_pallet = match new.iter().find(|p| decoded(&p.name) == &pallet) {
+			Some(new_pallet) => new_pallet,
+			None => {
+				let name = String::new();
+				changes.push(Change::Removed { kind: ItemKind::Pallet, pallet, name });
+				continue
+			},
+		};
+		if old_pallet.index != new_pallet.index {
+			changes.push(Change::IndexChanged {
+				kind: ItemKind::Pallet,
+				pallet: pallet.clone(),
+				name: String::new(),
+				old: old_pallet.index,
+				new: new_pallet.index,
+			});
+		}
+
+		let mut compare = |kind, old: Vec<(String, Vec<u8>)>, new: Vec<(String, Vec<u8>)>| {
+			diff_items(kind, &pallet, &old, &new, &mut changes)
+		};
+		compare(ItemKind::Call, calls(old_pallet), calls(new_pallet));
+		compare(ItemKind::Event, events(old_pallet), events(new_pallet));
+		compare(ItemKind::Error, errors(old_pallet), errors(new_pallet));
+		compare(ItemKind::Storage, storage(old_pallet), storage(new_pallet));
+		compare(ItemKind::Constant, constants(old_pallet), constants(new_pallet));
+	}
+
+	for new_pallet in &new {
+		let pallet = decoded(&new_pallet.name);
+		if !old.iter().any(|p| decoded(&p.name) == pallet) {
+			changes.push(Change::Added {
+				kind: ItemKind::Pallet,
+				pallet: pallet.clone(),
+				name: String::new(),
+			});
+		}
+	}
+
+	Ok(changes)
+}
+
+/// Compare the items of one kind of a pallet, given as their name and their signature.
+///
+/// The index of calls, events and errors is their position.
+fn diff_items(
+	kind: ItemKind,
+	pallet: &str,
+	old: &[(String, Vec<u8>)],
+	new: &[(String, Vec<u8>)],
+	changes: &mut Vec<Change>,
+) {
+	let indexed = matches!(kind, ItemKind::Call | ItemKind::Event | ItemKind::Error);
+	let change = |name: &str| (kind, pallet.to_string(), name.to_string());
+
+	for (old_index, (name, old_signature)) in old.iter().enumerate() {
+		let (kind, pallet, name) = change(name);
+		match new.iter().position(|(new_name, _)| new_name == &name) {
+			None => changes.push(Change::Removed { kind, pallet, name }),
+			Some(new_index) => {
+				if old_signature != &new[new_index].1 {
+					changes.push(Change::Changed {
+						kind,
+						pallet: pallet.clone(),
+						name: name.clone(),
+					});
+				}
+				if indexed && old_index != new_index {
+					changes.push(Change::IndexChanged {
+						kind,
+						pallet,
+						name,
+						old: old_index as u8,
+						new: new_index as u8,
+					});
+				}
+			},
+		}
+	}
+
+	for (name, _) in new {
+		if !old.iter().any(|(old_name, _)| old_name == name) {
+			let (kind, pallet, name) = change(name);
+			changes.push(Change::Added { kind, pallet, name });
+		}
+	}
+}
+
+/// The pallets of the metadata, with all their fields decoded.
+fn pallets(metadata: &RuntimeMetadataPrefixed) -> Result<Vec<ModuleMetadata>, Error> {
+	if metadata.0 != META_RESERVED {
+		return Err(Error::UnsupportedVersion)
+	}
+
+	// Metadata built in the same process is not decoded, a round trip decodes all the fields.
+	let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata.encode()[..])?;
+	match metadata.1 {
+		RuntimeMetadata::V13(metadata) => Ok(decoded(&metadata.modules).clone()),
+		_ => Err(Error::UnsupportedVersion),
+	}
+}
+
+/// The decoded value, the metadata returned by [`pallets`] is always decoded.
+fn decoded<B, O>(value: &DecodeDifferent<B, O>) -> &O {
+	match value {
+		DecodeDifferent::Decoded(value) => value,
+		DecodeDifferent::Encode(_) => unreachable!("the metadata is decoded; qed"),
+	}
+}
+
+fn calls(pallet: &ModuleMetadata) -> Vec<(String, Vec<u8>)> {
+	pallet.calls.iter().flat_map(|calls| decoded(calls))
+		.map(|call| (decoded(&call.name).clone(), call.arguments.encode()))
+		.collect()
+}
+
+fn events(pallet: &ModuleMetadata) -> Vec<(String, Vec<u8>)> {
+	pallet.event.iter().flat_map(|events| decoded(events))
+		.map(|event| (decoded(&event.name).clone(), event.arguments.encode()))
+		.collect()
+}
+
+fn errors(pallet: &ModuleMetadata) -> Vec<(String, Vec<u8>)> {
+	decoded(&pallet.errors).iter()
+		.map(|error| (decoded(&error.name).clone(), Vec::new()))
+		.collect()
+}
+
+fn storage(pallet: &ModuleMetadata) -> Vec<(String, Vec<u8>)> {
+	pallet.storage.iter().flat_map(|storage| decoded(&decoded(storage).entries))
+		.map(|entry| {
+			let signature = (&entry.modifier, &entry.ty, &entry.default).encode();
+			(decoded(&entry.name).clone(), signature)
+		})
+		.collect()
+}
+
+fn constants(pallet: &ModuleMetadata) -> Vec<(String, Vec<u8>)> {
+	decoded(&pallet.constants).iter()
+		.map(|constant| {
+			let signature = (&constant.ty, &constant.value).encode();
+			(decoded(&constant.name).clone(), signature)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_metadata::{
+		ErrorMetadata, ExtrinsicMetadata, FunctionArgumentMetadata, FunctionMetadata,
+		RuntimeMetadataV13,
+	};
+
+	fn call(name: &str, arguments: &[&str]) -> FunctionMetadata {
+		FunctionMetadata {
+			name: DecodeDifferent::Decoded(name.into()),
+			arguments: DecodeDifferent::Decoded(arguments.iter().map(|ty| {
+				FunctionArgumentMetadata {
+					name: DecodeDifferent::Decoded("arg".into()),
+					ty: DecodeDifferent::Decoded(ty.to_string()),
+				}
+			}).collect()),
+			documentation: DecodeDifferent::Decoded(vec![]),
+		}
+	}
+
+	fn pallet(name: &str, index: u8, calls: Vec<FunctionMetadata>) -> ModuleMetadata {
+		ModuleMetadata {
+			name: DecodeDifferent::Decoded(name.into()),
+			storage: None,
+			calls: Some(DecodeDifferent::Decoded(calls)),
+			event: None,
+			constants: DecodeDifferent::Decoded(vec![]),
+			errors: DecodeDifferent::Decoded(vec![ErrorMetadata {
+				name: DecodeDifferent::Decoded("Error".into()),
+				documentation: DecodeDifferent::Decoded(vec![]),
+			}]),
+			index,
+		}
+	}
+
+	fn metadata(pallets: Vec<ModuleMetadata>) -> RuntimeMetadataPrefixed {
+		RuntimeMetadataV13 {
+			modules: DecodeDifferent::Decoded(pallets),
+			extrinsic: ExtrinsicMetadata { version: 4, signed_extensions: vec![] },
+		}.into()
+	}
+
+	#[test]
+	fn same_metadata_has_no_changes() {
+		let old = metadata(vec![pallet("Balances", 0, vec![call("transfer", &["u64"])])]);
+		assert_eq!(diff(&old, &old).unwrap(), vec![]);
+	}
+
+	#[test]
+	fn changes_are_reported() {
+		let old = metadata(vec![
+			pallet("Balances", 0, vec![call("transfer", &["u64"]), call("burn", &[])]),
+			pallet("Sudo", 1, vec![]),
+		]);
+		let new = metadata(vec![
+			pallet("Balances", 0, vec![call("burn", &[]), call("transfer", &["u128"])]),
+			pallet("Assets", 1, vec![]),
+		]);
+
+		let call = |name: &str| (ItemKind::Call, "Balances".to_string(), name.to_string());
+		let changes = diff(&old, &new).unwrap();
+		assert_eq!(changes, vec![
+			{
+				let (kind, pallet, name) = call("transfer");
+				Change::Changed { kind, pallet, name }
+			},
+			{
+				let (kind, pallet, name) = call("transfer");
+				Change::IndexChanged { kind, pallet, name, old: 0, new: 1 }
+			},
+			{
+				let (kind, pallet, name) = call("burn");
+				Change::IndexChanged { kind, pallet, name, old: 1, new: 0 }
+			},
+			Change::Removed { kind: ItemKind::Pallet, pallet: "Sudo".into(), name: String::new() },
+			Change::Added { kind: ItemKind::Pallet, pallet: "Assets".into(), name: String::new() },
+		]);
+		assert!(changes.iter().take(4).all(Change::breaks_transactions));
+		assert!(!changes[4].breaks_transactions());
+		assert_eq!(changes[0].to_string(), "call Balances::transfer changed");
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Command line interface of the metadata diff.
+
+use std::{path::PathBuf, process};
+use structopt::StructOpt;
+
+/// Compare the metadata of two runtime wasm blobs.
+///
+/// Exits with the code 1 if a change breaks the transactions built for the old runtime.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "metadata-diff")]
+struct Opt {
+	/// The wasm blob of the old runtime.
+	#[structopt(parse(from_os_str))]
+	old: PathBuf,
+
+	/// The wasm blob of the new runtime.
+	#[structopt(parse(from_os_str))]
+	new: PathBuf,
+}
+
+fn metadata(path: &PathBuf) -> Result<frame_metadata::RuntimeMetadataPrefixed, String> {
+	let code = std::fs::read(path)
+		.map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+	frame_metadata_diff::metadata_from_wasm(&code)
+		.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn main() {
+	let opt = Opt::from_args();
+
+	let changes = metadata(&opt.old)
+		.and_then(|old| metadata(&opt.new).map(|new| (old, new)))
+		.and_then(|(old, new)| frame_metadata_diff::diff(&old, &new).map_err(|e| e.to_string()))
+		.unwrap_or_else(|e| {
+			eprintln!("{}", e);
+			process::exit(2)
+		});
+
+	if changes.is_empty() {
+		println!("No changes");
+		return
+	}
+
+	let mut breaking = false;
+	for change in &changes {
+		if change.breaks_transactions() {
+			breaking = true;
+			println!("{} [breaks transactions]", change);
+		} else {
+			println!("{}", change);
+		}
+	}
+
+	if breaking {
+		process::exit(1)
+	}
+}
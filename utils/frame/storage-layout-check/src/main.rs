@@ -0,0 +1,226 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Check that the storage layout of a pallet is unchanged between two runtime metadata.
+
+use codec::Decode;
+use frame_support::{
+	metadata::{DecodeDifferent, RuntimeMetadata, RuntimeMetadataPrefixed, StorageMetadata},
+	storage::migration::{storage_layout_diff, StorageLayoutDiff},
+};
+use std::{path::PathBuf, process};
+use structopt::StructOpt;
+
+/// Check that the storage layout of a pallet is unchanged between two runtime metadata, e.g.
+/// before and after porting the pallet from `decl_storage!` to `#[pallet::storage]`.
+///
+/// Exits with the code 1 if the storage layout changed.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "storage-layout-check")]
+struct Opt {
+	/// The SCALE encoded metadata of the old runtime, in binary or in hex as returned by the
+	/// `state_getMetadata` RPC.
+	#[structopt(parse(from_os_str))]
+	old: PathBuf,
+
+	/// The SCALE encoded metadata of the new runtime, in binary or in hex.
+	#[structopt(parse(from_os_str))]
+	new: PathBuf,
+
+	/// The name of the pallet in `construct_runtime!`.
+	#[structopt(long)]
+	pallet: String,
+
+	/// The name of the pallet in the new runtime, if it was renamed.
+	#[structopt(long)]
+	new_pallet: Option<String>,
+}
+
+/// Decode the metadata, given in binary or in hex.
+fn decode_metadata(content: &[u8]) -> Result<RuntimeMetadataPrefixed, String> {
+	let hex = std::str::from_utf8(content).ok()
+		.and_then(|content| sp_core::bytes::from_hex(content.trim()).ok());
+	let bytes = hex.as_deref().unwrap_or(content);
+	RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+		.map_err(|e| format!("Failed to decode the metadata: {}", e))
+}
+
+/// The storage metadata of the pallet `name`, `None` if the pallet has no storage.
+fn pallet_storage(
+	metadata: RuntimeMetadataPrefixed,
+	name: &str,
+) -> Result<Option<StorageMetadata>, String> {
+	let pallets = match metadata.1 {
+		RuntimeMetadata::V13(metadata) => match metadata.modules {
+			DecodeDifferent::Decoded(pallets) => pallets,
+			DecodeDifferent::Encode(_) => return Err("The metadata is not decoded".into()),
+		},
+		_ => return Err("Unsupported metadata version".into()),
+	};
+
+	let pallet = pallets.into_iter()
+		.find(|pallet| matches!(&pallet.name, DecodeDifferent::Decoded(n) if n == name))
+		.ok_or_else(|| format!("No pallet `{}` in the metadata", name))?;
+	match pallet.storage {
+		Some(DecodeDifferent::Decoded(storage)) => Ok(Some(storage)),
+		Some(DecodeDifferent::Encode(_)) => Err("The metadata is not decoded".into()),
+		None => Ok(None),
+	}
+}
+
+/// Compare the storage layouts of a pallet, a pallet without storage has an empty layout.
+fn check(
+	old: Option<StorageMetadata>,
+	new: Option<StorageMetadata>,
+) -> Vec<StorageLayoutDiff> {
+	let empty = |storage: &StorageMetadata| StorageMetadata {
+		prefix: storage.prefix.clone(),
+		entries: DecodeDifferent::Decoded(Vec::new()),
+	};
+	match (old, new) {
+		(Some(old), Some(new)) => storage_layout_diff(&old, &new),
+		(Some(old), None) => storage_layout_diff(&old, &empty(&old)),
+		(None, Some(new)) => storage_layout_diff(&empty(&new), &new),
+		(None, None) => Vec::new(),
+	}
+}
+
+fn main() {
+	let opt = Opt::from_args();
+
+	let storage = |path: &PathBuf, pallet: &str| std::fs::read(path)
+		.map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+		.and_then(|content| decode_metadata(&content))
+		.and_then(|metadata| pallet_storage(metadata, pallet))
+		.map_err(|e| format!("{}: {}", path.display(), e));
+
+	let new_pallet = opt.new_pallet.as_ref().unwrap_or(&opt.pallet);
+	let diff = storage(&opt.old, &opt.pallet)
+		.and_then(|old| storage(&opt.new, new_pallet).map(|new| check(old, new)))
+		.unwrap_or_else(|e| {
+			eprintln!("{}", e);
+			process::exit(2)
+		});
+
+	if diff.is_empty() {
+		println!("The storage layout is unchanged");
+		return
+	}
+
+	for change in &diff {
+		match change {
+			StorageLayoutDiff::Prefix(old, new) =>
+				println!("storage prefix changed from `{}` to `{}`", old, new),
+			StorageLayoutDiff::Removed(name) => println!("storage {} removed", name),
+			StorageLayoutDiff::Added(name) => println!("storage {} added", name),
+			StorageLayoutDiff::Changed(name) => println!("storage {} changed", name),
+		}
+	}
+	process::exit(1)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use frame_support::metadata::{
+		ExtrinsicMetadata, ModuleMetadata, RuntimeMetadataV13, StorageEntryMetadata,
+		StorageEntryModifier, StorageEntryType,
+	};
+
+	fn pallet(name: &str, entries: Option<Vec<(&str, &str)>>) -> ModuleMetadata {
+		let storage = entries.map(|entries| StorageMetadata {
+			prefix: DecodeDifferent::Decoded(name.into()),
+			entries: DecodeDifferent::Decoded(entries.into_iter().map(|(name, ty)| {
+				StorageEntryMetadata {
+					name: DecodeDifferent::Decoded(name.into()),
+					modifier: StorageEntryModifier::Default,
+					ty: StorageEntryType::Plain(DecodeDifferent::Decoded(ty.into())),
+					default: DecodeDifferent::Decoded(vec![0]),
+					documentation: DecodeDifferent::Decoded(vec![]),
+				}
+			}).collect()),
+		});
+
+		ModuleMetadata {
+			name: DecodeDifferent::Decoded(name.into()),
+			storage: storage.map(DecodeDifferent::Decoded),
+			calls: None,
+			event: None,
+			constants: DecodeDifferent::Decoded(vec![]),
+			errors: DecodeDifferent::Decoded(vec![]),
+			index: 0,
+		}
+	}
+
+	fn metadata(pallets: Vec<ModuleMetadata>) -> Vec<u8> {
+		let metadata: RuntimeMetadataPrefixed = RuntimeMetadataV13 {
+			modules: DecodeDifferent::Decoded(pallets),
+			extrinsic: ExtrinsicMetadata { version: 4, signed_extensions: vec![] },
+		}.into();
+		metadata.encode()
+	}
+
+	fn storage(metadata: &[u8], pallet: &str) -> Result<Option<StorageMetadata>, String> {
+		decode_metadata(metadata).and_then(|metadata| pallet_storage(metadata, pallet))
+	}
+
+	#[test]
+	fn metadata_is_decoded_from_binary_or_hex() {
+		let binary = metadata(vec![pallet("Example", Some(vec![("Foo", "u32")]))]);
+		let hex = format!("{}\n", sp_core::bytes::to_hex(&binary, false));
+
+		assert!(storage(&binary, "Example").unwrap().is_some());
+		assert_eq!(storage(hex.as_bytes(), "Example"), storage(&binary, "Example"));
+		assert_eq!(storage(&binary, "Other"), Err("No pallet `Other` in the metadata".into()));
+		assert!(decode_metadata(b"0xzz").is_err());
+	}
+
+	#[test]
+	fn storage_layout_changes_are_reported() {
+		let old = metadata(vec![
+			pallet("Example", Some(vec![("Foo", "u32"), ("Bar", "u32")])),
+			pallet("Empty", None),
+		]);
+		let new = metadata(vec![
+			pallet("Example", Some(vec![("Foo", "u32"), ("Bar", "u64"), ("Baz", "u32")])),
+			pallet("Empty", None),
+			pallet("Renamed", Some(vec![("Foo", "u32"), ("Bar", "u32")])),
+		]);
+		let check_pallets = |old_pallet, new_pallet| check(
+			storage(&old, old_pallet).unwrap(),
+			storage(&new, new_pallet).unwrap(),
+		);
+
+		assert_eq!(check_pallets("Empty", "Empty"), vec![]);
+		assert_eq!(
+			check_pallets("Example", "Example"),
+			vec![
+				StorageLayoutDiff::Changed("Bar".into()),
+				StorageLayoutDiff::Added("Baz".into()),
+			],
+		);
+		assert_eq!(
+			check_pallets("Example", "Renamed"),
+			vec![StorageLayoutDiff::Prefix("Example".into(), "Renamed".into())],
+		);
+		assert_eq!(
+			check_pallets("Example", "Empty"),
+			vec![StorageLayoutDiff::Removed("Foo".into()), StorageLayoutDiff::Removed("Bar".into())],
+		);
+	}
+}
@@ -57,6 +57,10 @@ pub struct TryRuntimeCmd {
 	)]
 	pub wasm_method: WasmExecutionMethod,
 
+	/// Report all the storage keys read and written by the migration.
+	#[structopt(long)]
+	pub storage_access_report: bool,
+
 	/// The state to use to run the migration.
 	#[structopt(subcommand)]
 	pub state: State,
@@ -179,7 +183,7 @@ impl TryRuntimeCmd {
 			builder.inject(&[(code_key, code)]).build().await?
 		};
 
-		let encoded_result = StateMachine::<_, _, NumberFor<B>, _>::new(
+		let mut state_machine = StateMachine::<_, _, NumberFor<B>, _>::new(
 			&ext.backend,
 			None,
 			&mut changes,
@@ -190,9 +194,18 @@ impl TryRuntimeCmd {
 			&sp_state_machine::backend::BackendRuntimeCode::new(&ext.backend)
 				.runtime_code()?,
 			sp_core::testing::TaskExecutor::new(),
-		)
-		.execute(execution.into())
-		.map_err(|e| format!("failed to execute 'TryRuntime_on_runtime_upgrade' due to {:?}", e))?;
+		);
+		if self.storage_access_report {
+			state_machine = state_machine.with_access_tracking(false);
+		}
+
+		let encoded_result = state_machine
+			.execute(execution.into())
+			.map_err(|e| format!("failed to execute 'TryRuntime_on_runtime_upgrade' due to {:?}", e))?;
+
+		if let Some(report) = state_machine.storage_access_report() {
+			log::info!("storage accessed by 'TryRuntime_on_runtime_upgrade': {}", report);
+		}
 
 		let (weight, total_weight) = <(u64, u64) as Decode>::decode(&mut &*encoded_result)
 			.map_err(|e| format!("failed to decode output due to {:?}", e))?;